@@ -80,7 +80,7 @@ pub fn as_oid(input: types::Value) -> Result<types::Oid, types::Error> {
 pub fn into_dat_value(input: types::Value) -> DatValue {
     match input {
         types::Value::Null => DatValue::null(),
-        types::Value::Boolean(value) => DatValue::from_i32(i32::from(value)),
+        types::Value::Boolean(value) => DatValue::from_bool(value),
         types::Value::Int64(value) => DatValue::from_i64(value),
         types::Value::Float64(value) => DatValue::from_f64(value),
         types::Value::Text(value) => DatValue::from_string(value),
@@ -94,6 +94,15 @@ pub fn from_dat_value(input: &DatValue) -> types::Value {
         return types::Value::Null;
     }
 
+    if let Some(value) = input.as_bool() {
+        return types::Value::Boolean(*value);
+    }
+    if let Some(value) = input.as_i8() {
+        return types::Value::Int64(i64::from(*value));
+    }
+    if let Some(value) = input.as_i16() {
+        return types::Value::Int64(i64::from(*value));
+    }
     if let Some(value) = input.as_i32() {
         return types::Value::Int64(i64::from(*value));
     }
@@ -115,6 +124,12 @@ pub fn from_dat_value(input: &DatValue) -> types::Value {
     if let Some(value) = input.as_u128() {
         return types::Value::ObjectId(ids::from_facade(*value));
     }
+    if let Some(value) = input.as_uuid() {
+        return types::Value::Text(value.format());
+    }
+    if let Some(value) = input.as_json() {
+        return types::Value::Text(value.to_string());
+    }
 
     types::Value::Text(format!("{input:?}"))
 }
@@ -2,7 +2,7 @@ use mududb::common::id::OID;
 use mududb::common::result::RS;
 use mududb::error::ErrorCode;
 use mududb::mudu_error;
-use mududb::sys_interface::async_api::{mudu_get, mudu_put, mudu_range};
+use mududb::sys_interface::async_api::{mudu_delete, mudu_get, mudu_put, mudu_range};
 
 fn kv_data_key(user_key: &str) -> String {
     format!("user/{user_key}")
@@ -52,6 +52,12 @@ pub async fn kv_update(xid: OID, user_key: String, value: String) -> RS<()> {
     mudu_put(xid, key.as_bytes(), value.as_bytes()).await
 }
 
+/**mudu-proc**/
+pub async fn kv_delete(xid: OID, user_key: String) -> RS<()> {
+    let key = kv_data_key(&user_key);
+    mudu_delete(xid, key.as_bytes()).await
+}
+
 /**mudu-proc**/
 pub async fn kv_scan(xid: OID, start_user_key: String, end_user_key: String) -> RS<Vec<String>> {
     let start_key = kv_data_key(&start_user_key);
@@ -561,3 +567,91 @@ mod mod_kv_read_modify_write {
 
     export!(GuestKvReadModifyWrite);
 }
+
+async fn mp2_kv_delete(param: Vec<u8>) -> Vec<u8> {
+    ::mududb::binding::procedure::procedure_invoke::invoke_procedure_async(
+        param,
+        mudu_inner_p2_kv_delete,
+    )
+    .await
+}
+
+pub async fn mudu_inner_p2_kv_delete(
+    param: ::mududb::contract::procedure::procedure_param::ProcedureParam,
+) -> ::mududb::common::result::RS<::mududb::contract::procedure::procedure_result::ProcedureResult>
+{
+    let res = kv_delete(
+        param.session_id(),
+        ::mududb::types::datum::value_to_typed::<String, _>(&param.param_list()[0], "String")?,
+    )
+    .await;
+    match res {
+        Ok(tuple) => {
+            let return_list = { vec![] };
+            Ok(::mududb::contract::procedure::procedure_result::ProcedureResult::new(return_list))
+        }
+        Err(e) => Err(e),
+    }
+}
+
+pub fn mudu_argv_desc_kv_delete()
+-> &'static ::mududb::contract::tuple::tuple_field_desc::TupleFieldDesc {
+    static ARGV_DESC: std::sync::OnceLock<
+        ::mududb::contract::tuple::tuple_field_desc::TupleFieldDesc,
+    > = std::sync::OnceLock::new();
+    ARGV_DESC.get_or_init(|| {
+        ::mududb::contract::tuple::tuple_field_desc::TupleFieldDesc::new(vec![
+            ::mududb::contract::tuple::datum_desc::DatumDesc::new(
+                "user_key".to_string(),
+                <String as ::mududb::types::datum::Datum>::dat_type().clone(),
+            ),
+        ])
+    })
+}
+
+pub fn mudu_result_desc_kv_delete()
+-> &'static ::mududb::contract::tuple::tuple_field_desc::TupleFieldDesc {
+    static RESULT_DESC: std::sync::OnceLock<
+        ::mududb::contract::tuple::tuple_field_desc::TupleFieldDesc,
+    > = std::sync::OnceLock::new();
+    RESULT_DESC
+        .get_or_init(|| ::mududb::contract::tuple::tuple_field_desc::TupleFieldDesc::new(vec![]))
+}
+
+pub fn mudu_proc_desc_kv_delete() -> &'static ::mududb::contract::procedure::proc_desc::ProcDesc {
+    static _PROC_DESC: std::sync::OnceLock<::mududb::contract::procedure::proc_desc::ProcDesc> =
+        std::sync::OnceLock::new();
+    _PROC_DESC.get_or_init(|| {
+        ::mududb::contract::procedure::proc_desc::ProcDesc::new(
+            "key_value".to_string(),
+            "kv_delete".to_string(),
+            mudu_argv_desc_kv_delete().clone(),
+            mudu_result_desc_kv_delete().clone(),
+            false,
+        )
+    })
+}
+
+mod mod_kv_delete {
+    wit_bindgen::generate!({
+        inline:
+        r##"package mudu:mp2-kv-delete;
+            world mudu-app-mp2-kv-delete {
+                export mp2-kv-delete: func(param:list<u8>) -> list<u8>;
+            }
+        "##,
+        async: true
+    });
+
+    #[allow(non_camel_case_types)]
+    #[allow(unused)]
+    struct GuestKvDelete {}
+
+    impl Guest for GuestKvDelete {
+        async fn mp2_kv_delete(param: Vec<u8>) -> Vec<u8> {
+            super::mp2_kv_delete(param).await
+        }
+    }
+
+    export!(GuestKvDelete);
+}
@@ -2,7 +2,7 @@ use mududb::common::id::OID;
 use mududb::common::result::RS;
 use mududb::error::ErrorCode;
 use mududb::mudu_error;
-use mududb::sys_interface::sync_api::{mudu_get, mudu_put, mudu_range};
+use mududb::sys_interface::sync_api::{mudu_delete, mudu_get, mudu_put, mudu_range};
 
 pub(crate) fn kv_data_key(user_key: &str) -> String {
     format!("user/{user_key}")
@@ -52,6 +52,12 @@ pub fn kv_update(xid: OID, user_key: String, value: String) -> RS<()> {
     mudu_put(xid, key.as_bytes(), value.as_bytes())
 }
 
+/**mudu-proc**/
+pub fn kv_delete(xid: OID, user_key: String) -> RS<()> {
+    let key = kv_data_key(&user_key);
+    mudu_delete(xid, key.as_bytes())
+}
+
 /**mudu-proc**/
 pub fn kv_scan(xid: OID, start_user_key: String, end_user_key: String) -> RS<Vec<String>> {
     let start_key = kv_data_key(&start_user_key);
@@ -80,7 +86,7 @@ pub fn kv_read_modify_write(xid: OID, user_key: String, append_value: String) ->
 
 #[cfg(test)]
 mod tests {
-    use super::{kv_insert, kv_read, kv_read_modify_write, kv_scan, kv_update};
+    use super::{kv_delete, kv_insert, kv_read, kv_read_modify_write, kv_scan, kv_update};
     use mududb::sys::env_var::temp_dir;
     use mududb::sys::sync::SMutex;
     use mududb::sys::time::system_time_now;
@@ -129,6 +135,11 @@ mod tests {
         assert_eq!(updated, "3-tail");
         assert_eq!(kv_read(xid, "a".to_string()).unwrap(), "3-tail");
 
+        kv_delete(xid, "a".to_string()).unwrap();
+        assert!(kv_read(xid, "a".to_string()).is_err());
+        let rows = kv_scan(xid, "a".to_string(), "z".to_string()).unwrap();
+        assert_eq!(rows, vec!["user/b=2".to_string()]);
+
         mudu_close(xid).unwrap();
     }
 
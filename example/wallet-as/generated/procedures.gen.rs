@@ -114,6 +114,12 @@ fn mudu_lang_value_from_dat_value(
 
     if value.is_null() {
         Value::Null
+    } else if let Some(value) = value.as_bool() {
+        Value::Boolean(*value)
+    } else if let Some(value) = value.as_i8() {
+        Value::Int64(i64::from(*value))
+    } else if let Some(value) = value.as_i16() {
+        Value::Int64(i64::from(*value))
     } else if let Some(value) = value.as_i32() {
         Value::Int64(i64::from(*value))
     } else if let Some(value) = value.as_i64() {
@@ -131,6 +137,10 @@ fn mudu_lang_value_from_dat_value(
             hi: (*value >> 64) as u64,
             lo: *value as u64,
         })
+    } else if let Some(value) = value.as_uuid() {
+        Value::Text(value.format())
+    } else if let Some(value) = value.as_json() {
+        Value::Text(value.to_string())
     } else {
         Value::Binary(vec![])
     }
@@ -144,7 +154,7 @@ fn mudu_lang_dat_value_from_value(
             ::mududb::types::dat_value::DatValue::null()
         }
         mudu_language_procedure_shim::mududb::component_shim::types::Value::Boolean(value) => {
-            ::mududb::types::dat_value::DatValue::from_i32(i32::from(value))
+            ::mududb::types::dat_value::DatValue::from_bool(value)
         }
         mudu_language_procedure_shim::mududb::component_shim::types::Value::Int64(value) => {
             ::mududb::types::dat_value::DatValue::from_i64(value)
@@ -31,6 +31,9 @@ pub enum FormatKind {
     /// Tuple binary format used inside pages, log entries and frames
     /// (see `doc/en/contract/tuple_binary_v1.md`).
     TupleBinary,
+    /// Paged on-disk primary index of a relation
+    /// (see `doc/en/contract/btree_index_v1.md`).
+    BTreeIndex,
 }
 
 impl fmt::Display for FormatKind {
@@ -43,14 +46,15 @@ impl fmt::Display for FormatKind {
             Self::ServerConfig => f.write_str("server config"),
             Self::FileLayout => f.write_str("file layout"),
             Self::TupleBinary => f.write_str("tuple binary"),
+            Self::BTreeIndex => f.write_str("btree index"),
         }
     }
 }
 
 /// Current version for the on-disk page format.
-pub const PAGE_CURRENT_VERSION: u32 = 1;
+pub const PAGE_CURRENT_VERSION: u32 = 2;
 /// Current version for the write-ahead log frame format.
-pub const LOG_FRAME_CURRENT_VERSION: u32 = 1;
+pub const LOG_FRAME_CURRENT_VERSION: u32 = 2;
 /// Current version for the TCP wire protocol frame format.
 pub const PROTOCOL_FRAME_CURRENT_VERSION: u32 = 1;
 /// Current version for the MPK manifest format.
@@ -61,6 +65,8 @@ pub const SERVER_CONFIG_CURRENT_VERSION: u32 = 1;
 pub const FILE_LAYOUT_CURRENT_VERSION: u32 = 1;
 /// Current version for the tuple binary format.
 pub const TUPLE_BINARY_CURRENT_VERSION: u32 = 1;
+/// Current version for the on-disk B+tree index format.
+pub const BTREE_INDEX_CURRENT_VERSION: u32 = 1;
 
 /// Supported version range for a format family, inclusive on both ends.
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -104,6 +110,7 @@ impl CompatibilityMatrix {
             FormatKind::ServerConfig => 0,            // text config, no magic
             FormatKind::FileLayout => 0,              // composite format, no single magic
             FormatKind::TupleBinary => 0,             // schema-driven format, no single magic
+            FormatKind::BTreeIndex => 0x4254_4958,    // BTIX
         }
     }
 
@@ -117,6 +124,7 @@ impl CompatibilityMatrix {
             FormatKind::ServerConfig => VersionRange::new(1, SERVER_CONFIG_CURRENT_VERSION),
             FormatKind::FileLayout => VersionRange::new(1, FILE_LAYOUT_CURRENT_VERSION),
             FormatKind::TupleBinary => VersionRange::new(1, TUPLE_BINARY_CURRENT_VERSION),
+            FormatKind::BTreeIndex => VersionRange::new(1, BTREE_INDEX_CURRENT_VERSION),
         }
     }
 
@@ -130,6 +138,7 @@ impl CompatibilityMatrix {
             FormatKind::ServerConfig => SERVER_CONFIG_CURRENT_VERSION,
             FormatKind::FileLayout => FILE_LAYOUT_CURRENT_VERSION,
             FormatKind::TupleBinary => TUPLE_BINARY_CURRENT_VERSION,
+            FormatKind::BTreeIndex => BTREE_INDEX_CURRENT_VERSION,
         }
     }
 
@@ -292,7 +301,8 @@ mod tests {
     #[test]
     fn matrix_rejects_unsupported_versions() {
         assert!(CompatibilityMatrix::is_supported(FormatKind::Page, 1));
-        assert!(!CompatibilityMatrix::is_supported(FormatKind::Page, 2));
+        assert!(CompatibilityMatrix::is_supported(FormatKind::Page, 2));
+        assert!(!CompatibilityMatrix::is_supported(FormatKind::Page, 3));
         assert!(!CompatibilityMatrix::is_supported(FormatKind::Page, 0));
 
         assert!(CompatibilityMatrix::is_supported(FormatKind::FileLayout, 1));
@@ -345,13 +355,14 @@ mod tests {
     #[test]
     fn all_format_kinds_have_consistent_metadata() {
         let cases = [
-            (FormatKind::Page, 0x5041_4745, 1, "page"),
-            (FormatKind::LogFrame, 0x4C47_464D, 1, "log frame"),
+            (FormatKind::Page, 0x5041_4745, 2, "page"),
+            (FormatKind::LogFrame, 0x4C47_464D, 2, "log frame"),
             (FormatKind::ProtocolFrame, 0x4D53_464D, 1, "protocol frame"),
             (FormatKind::MpkManifest, 0x4D50_4B4D, 1, "mpk manifest"),
             (FormatKind::ServerConfig, 0, 1, "server config"),
             (FormatKind::FileLayout, 0, 1, "file layout"),
             (FormatKind::TupleBinary, 0, 1, "tuple binary"),
+            (FormatKind::BTreeIndex, 0x4254_4958, 1, "btree index"),
         ];
         for (kind, magic, latest, display) in cases {
             assert_eq!(CompatibilityMatrix::magic(kind), magic);
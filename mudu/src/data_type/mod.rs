@@ -10,3 +10,4 @@ mod temporal_test;
 pub mod time;
 pub mod timestamp;
 pub mod timestamptz;
+pub mod uuid;
@@ -0,0 +1,81 @@
+use serde::{Deserialize, Serialize};
+
+#[derive(
+    Clone, Copy, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize,
+)]
+pub struct UuidValue {
+    bits: u128,
+}
+
+impl UuidValue {
+    pub fn from_u128(bits: u128) -> Self {
+        Self { bits }
+    }
+
+    pub fn to_u128(&self) -> u128 {
+        self.bits
+    }
+
+    /// Accepts the canonical hyphenated form (`xxxxxxxx-xxxx-xxxx-xxxx-xxxxxxxxxxxx`)
+    /// and the 32-digit simple form, in either letter case.
+    pub fn parse(s: &str) -> Result<Self, String> {
+        let invalid = || format!("invalid uuid {}", s);
+        let bytes = s.as_bytes();
+        let hex: Vec<u8> = match bytes.len() {
+            32 => bytes.to_vec(),
+            36 => {
+                if [8, 13, 18, 23].iter().any(|&i| bytes[i] != b'-') {
+                    return Err(invalid());
+                }
+                bytes.iter().copied().filter(|&b| b != b'-').collect()
+            }
+            _ => return Err(invalid()),
+        };
+        if hex.len() != 32 {
+            return Err(invalid());
+        }
+        let mut bits = 0u128;
+        for b in hex {
+            let digit = (b as char).to_digit(16).ok_or_else(invalid)?;
+            bits = (bits << 4) | digit as u128;
+        }
+        Ok(Self::from_u128(bits))
+    }
+
+    /// Formats as lowercase hyphenated text.
+    pub fn format(&self) -> String {
+        let s = format!("{:032x}", self.bits);
+        format!(
+            "{}-{}-{}-{}-{}",
+            &s[0..8],
+            &s[8..12],
+            &s[12..16],
+            &s[16..20],
+            &s[20..32]
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::UuidValue;
+
+    #[test]
+    fn uuid_roundtrip_in_canonical_form() {
+        let text = "123e4567-e89b-12d3-a456-426614174000";
+        let uuid = UuidValue::parse(text).unwrap();
+        assert_eq!(uuid.to_u128(), 0x123e4567_e89b_12d3_a456_426614174000);
+        assert_eq!(uuid.format(), text);
+        assert_eq!(
+            UuidValue::parse("123E4567E89B12D3A456426614174000").unwrap(),
+            uuid
+        );
+    }
+
+    #[test]
+    fn invalid_uuid_is_rejected() {
+        assert!(UuidValue::parse("123e4567-e89b-12d3-a456-42661417400").is_err());
+        assert!(UuidValue::parse("123e4567e-89b-12d3-a456-426614174000").is_err());
+        assert!(UuidValue::parse("g23e4567-e89b-12d3-a456-426614174000").is_err());
+    }
+}
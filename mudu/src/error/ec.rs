@@ -208,6 +208,14 @@ pub enum ErrorCode {
     CorruptedData = 50038,
     #[strum(message = "Incompatible protocol version")]
     IncompatibleProtocolVersion = 50039,
+    #[strum(message = "Page checksum mismatch")]
+    PageChecksumMismatch = 50040,
+    #[strum(message = "Encryption key mismatch")]
+    EncryptionKeyMismatch = 50041,
+    #[strum(message = "Read-only replica")]
+    ReadOnlyReplica = 50042,
+    #[strum(message = "Page size mismatch")]
+    PageSizeMismatch = 50043,
 }
 
 impl Display for ErrorCode {
@@ -270,7 +278,8 @@ impl ErrorCode {
             | ErrorCode::IndexOutOfRange
             | ErrorCode::UnsupportedOperation
             | ErrorCode::EntityNotFound
-            | ErrorCode::EntityAlreadyExists => Severity::User,
+            | ErrorCode::EntityAlreadyExists
+            | ErrorCode::ReadOnlyReplica => Severity::User,
 
             ErrorCode::TimedOut
             | ErrorCode::WouldBlock
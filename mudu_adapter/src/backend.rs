@@ -7,8 +7,10 @@ use mudu::common::result::RS;
 use mudu_binding::universal::uni_session_open_argv::UniSessionOpenArgv;
 use mudu_contract::database::entity::Entity;
 use mudu_contract::database::entity_set::RecordSet;
+use mudu_contract::database::kv_range::{KvRangeOptions, KvRangePage};
 use mudu_contract::database::sql_params::SQLParams;
 use mudu_contract::database::sql_stmt::SQLStmt;
+use std::time::Duration;
 
 /// Opens a session for `worker_id` using the configured backend.
 pub fn mudu_open(worker_id: OID) -> RS<OID> {
@@ -105,6 +107,165 @@ pub async fn mudu_put_async(session_id: OID, key: &[u8], value: &[u8]) -> RS<()>
     }
 }
 
+/// Stores `value` under `key` in `session_id` so that it expires `ttl`
+/// after the write commits.
+pub fn mudu_put_with_ttl(session_id: OID, key: &[u8], value: &[u8], ttl: Duration) -> RS<()> {
+    match config::driver() {
+        Driver::Sqlite => sqlite::mudu_put_with_ttl(session_id, key, value, ttl),
+        Driver::Postgres => postgres::mudu_put_with_ttl(session_id, key, value, ttl),
+        Driver::MySql => mysql::mudu_put_with_ttl(session_id, key, value, ttl),
+        Driver::Mudud => mududb::mudu_put_with_ttl(session_id, key, value, ttl),
+    }
+}
+
+/// Asynchronous version of [`mudu_put_with_ttl`].
+pub async fn mudu_put_with_ttl_async(
+    session_id: OID,
+    key: &[u8],
+    value: &[u8],
+    ttl: Duration,
+) -> RS<()> {
+    let _trace = mudu_utils::task_trace!();
+    match config::driver() {
+        Driver::Sqlite => sqlite::mudu_put_with_ttl_async(session_id, key, value, ttl).await,
+        Driver::Postgres => postgres::mudu_put_with_ttl_async(session_id, key, value, ttl).await,
+        Driver::MySql => mysql::mudu_put_with_ttl_async(session_id, key, value, ttl).await,
+        Driver::Mudud => mududb::mudu_put_with_ttl_async(session_id, key, value, ttl).await,
+    }
+}
+
+/// Removes `key` from `session_id`; deleting a missing key is not an error.
+pub fn mudu_delete(session_id: OID, key: &[u8]) -> RS<()> {
+    match config::driver() {
+        Driver::Sqlite => sqlite::mudu_delete(session_id, key),
+        Driver::Postgres => postgres::mudu_delete(session_id, key),
+        Driver::MySql => mysql::mudu_delete(session_id, key),
+        Driver::Mudud => mududb::mudu_delete(session_id, key),
+    }
+}
+
+/// Asynchronous version of [`mudu_delete`].
+pub async fn mudu_delete_async(session_id: OID, key: &[u8]) -> RS<()> {
+    let _trace = mudu_utils::task_trace!();
+    match config::driver() {
+        Driver::Sqlite => sqlite::mudu_delete_async(session_id, key).await,
+        Driver::Postgres => postgres::mudu_delete_async(session_id, key).await,
+        Driver::MySql => mysql::mudu_delete_async(session_id, key).await,
+        Driver::Mudud => mududb::mudu_delete_async(session_id, key).await,
+    }
+}
+
+/// Stores every key-value pair in `items` into `session_id` atomically.
+pub fn mudu_put_many(session_id: OID, items: &[(Vec<u8>, Vec<u8>)]) -> RS<()> {
+    match config::driver() {
+        Driver::Sqlite => sqlite::mudu_put_many(session_id, items),
+        Driver::Postgres => postgres::mudu_put_many(session_id, items),
+        Driver::MySql => mysql::mudu_put_many(session_id, items),
+        Driver::Mudud => mududb::mudu_put_many(session_id, items),
+    }
+}
+
+/// Asynchronous version of [`mudu_put_many`].
+pub async fn mudu_put_many_async(session_id: OID, items: &[(Vec<u8>, Vec<u8>)]) -> RS<()> {
+    let _trace = mudu_utils::task_trace!();
+    match config::driver() {
+        Driver::Sqlite => sqlite::mudu_put_many_async(session_id, items).await,
+        Driver::Postgres => postgres::mudu_put_many_async(session_id, items).await,
+        Driver::MySql => mysql::mudu_put_many_async(session_id, items).await,
+        Driver::Mudud => mududb::mudu_put_many_async(session_id, items).await,
+    }
+}
+
+/// Removes every key in `keys` from `session_id` atomically.
+pub fn mudu_delete_many(session_id: OID, keys: &[Vec<u8>]) -> RS<()> {
+    match config::driver() {
+        Driver::Sqlite => sqlite::mudu_delete_many(session_id, keys),
+        Driver::Postgres => postgres::mudu_delete_many(session_id, keys),
+        Driver::MySql => mysql::mudu_delete_many(session_id, keys),
+        Driver::Mudud => mududb::mudu_delete_many(session_id, keys),
+    }
+}
+
+/// Asynchronous version of [`mudu_delete_many`].
+pub async fn mudu_delete_many_async(session_id: OID, keys: &[Vec<u8>]) -> RS<()> {
+    let _trace = mudu_utils::task_trace!();
+    match config::driver() {
+        Driver::Sqlite => sqlite::mudu_delete_many_async(session_id, keys).await,
+        Driver::Postgres => postgres::mudu_delete_many_async(session_id, keys).await,
+        Driver::MySql => mysql::mudu_delete_many_async(session_id, keys).await,
+        Driver::Mudud => mududb::mudu_delete_many_async(session_id, keys).await,
+    }
+}
+
+/// Stores `value` under `key` if its current value equals `expected`;
+/// `None` means the key must be absent.
+pub fn mudu_cas(session_id: OID, key: &[u8], expected: Option<&[u8]>, value: &[u8]) -> RS<bool> {
+    match config::driver() {
+        Driver::Sqlite => sqlite::mudu_cas(session_id, key, expected, value),
+        Driver::Postgres => postgres::mudu_cas(session_id, key, expected, value),
+        Driver::MySql => mysql::mudu_cas(session_id, key, expected, value),
+        Driver::Mudud => mududb::mudu_cas(session_id, key, expected, value),
+    }
+}
+
+/// Asynchronous version of [`mudu_cas`].
+pub async fn mudu_cas_async(
+    session_id: OID,
+    key: &[u8],
+    expected: Option<&[u8]>,
+    value: &[u8],
+) -> RS<bool> {
+    let _trace = mudu_utils::task_trace!();
+    match config::driver() {
+        Driver::Sqlite => sqlite::mudu_cas_async(session_id, key, expected, value).await,
+        Driver::Postgres => postgres::mudu_cas_async(session_id, key, expected, value).await,
+        Driver::MySql => mysql::mudu_cas_async(session_id, key, expected, value).await,
+        Driver::Mudud => mududb::mudu_cas_async(session_id, key, expected, value).await,
+    }
+}
+
+/// Adds `delta` to the integer counter at `key` and returns the new value.
+pub fn mudu_incr(session_id: OID, key: &[u8], delta: i64) -> RS<i64> {
+    match config::driver() {
+        Driver::Sqlite => sqlite::mudu_incr(session_id, key, delta),
+        Driver::Postgres => postgres::mudu_incr(session_id, key, delta),
+        Driver::MySql => mysql::mudu_incr(session_id, key, delta),
+        Driver::Mudud => mududb::mudu_incr(session_id, key, delta),
+    }
+}
+
+/// Asynchronous version of [`mudu_incr`].
+pub async fn mudu_incr_async(session_id: OID, key: &[u8], delta: i64) -> RS<i64> {
+    let _trace = mudu_utils::task_trace!();
+    match config::driver() {
+        Driver::Sqlite => sqlite::mudu_incr_async(session_id, key, delta).await,
+        Driver::Postgres => postgres::mudu_incr_async(session_id, key, delta).await,
+        Driver::MySql => mysql::mudu_incr_async(session_id, key, delta).await,
+        Driver::Mudud => mududb::mudu_incr_async(session_id, key, delta).await,
+    }
+}
+
+/// Stores `value` under `key` only if the key is absent.
+pub fn mudu_put_if_absent(session_id: OID, key: &[u8], value: &[u8]) -> RS<bool> {
+    match config::driver() {
+        Driver::Sqlite => sqlite::mudu_put_if_absent(session_id, key, value),
+        Driver::Postgres => postgres::mudu_put_if_absent(session_id, key, value),
+        Driver::MySql => mysql::mudu_put_if_absent(session_id, key, value),
+        Driver::Mudud => mududb::mudu_put_if_absent(session_id, key, value),
+    }
+}
+
+/// Asynchronous version of [`mudu_put_if_absent`].
+pub async fn mudu_put_if_absent_async(session_id: OID, key: &[u8], value: &[u8]) -> RS<bool> {
+    let _trace = mudu_utils::task_trace!();
+    match config::driver() {
+        Driver::Sqlite => sqlite::mudu_put_if_absent_async(session_id, key, value).await,
+        Driver::Postgres => postgres::mudu_put_if_absent_async(session_id, key, value).await,
+        Driver::MySql => mysql::mudu_put_if_absent_async(session_id, key, value).await,
+        Driver::Mudud => mududb::mudu_put_if_absent_async(session_id, key, value).await,
+    }
+}
+
 /// Scans the key range `[start_key, end_key)` in `session_id`.
 pub fn mudu_range(
     session_id: OID,
@@ -134,6 +295,46 @@ pub async fn mudu_range_async(
     }
 }
 
+/// Scans one page of the key range `[start_key, end_key)` in `session_id`
+/// with limit, order, prefix, keys-only and continuation options.
+pub fn mudu_range_page(
+    session_id: OID,
+    start_key: &[u8],
+    end_key: &[u8],
+    options: &KvRangeOptions,
+) -> RS<KvRangePage> {
+    match config::driver() {
+        Driver::Sqlite => sqlite::mudu_range_page(session_id, start_key, end_key, options),
+        Driver::Postgres => postgres::mudu_range_page(session_id, start_key, end_key, options),
+        Driver::MySql => mysql::mudu_range_page(session_id, start_key, end_key, options),
+        Driver::Mudud => mududb::mudu_range_page(session_id, start_key, end_key, options),
+    }
+}
+
+/// Asynchronous version of [`mudu_range_page`].
+pub async fn mudu_range_page_async(
+    session_id: OID,
+    start_key: &[u8],
+    end_key: &[u8],
+    options: &KvRangeOptions,
+) -> RS<KvRangePage> {
+    let _trace = mudu_utils::task_trace!();
+    match config::driver() {
+        Driver::Sqlite => {
+            sqlite::mudu_range_page_async(session_id, start_key, end_key, options).await
+        }
+        Driver::Postgres => {
+            postgres::mudu_range_page_async(session_id, start_key, end_key, options).await
+        }
+        Driver::MySql => {
+            mysql::mudu_range_page_async(session_id, start_key, end_key, options).await
+        }
+        Driver::Mudud => {
+            mududb::mudu_range_page_async(session_id, start_key, end_key, options).await
+        }
+    }
+}
+
 /// Executes a query and returns a typed record set.
 pub fn mudu_query<R: Entity>(
     oid: OID,
@@ -4,11 +4,14 @@
 //! shared `mudu_kv` table.
 
 use crate::config::Driver;
-use crate::{config, sqlite};
+use crate::{config, sql, sqlite};
 use mudu::common::id::OID;
 use mudu::common::result::RS;
 use mudu::error::ErrorCode;
 use mudu::mudu_error;
+use mudu_contract::database::kv_atomic::{self, KvUpdate};
+use mudu_contract::database::kv_range::{KvRangeOptions, KvRangePage};
+use rusqlite::{OptionalExtension, TransactionBehavior, params_from_iter};
 
 /// Retrieves the value associated with `key` in the given session.
 pub fn get(session_id: OID, key: &[u8]) -> RS<Option<Vec<u8>>> {
@@ -65,57 +68,177 @@ pub async fn put_async(session_id: OID, key: &[u8], value: &[u8]) -> RS<()> {
     mudu_sys::task::async_::spawn_blocking(move || put(session_id, &key, &value)).await?
 }
 
-/// Returns all key-value pairs in `[start_key, end_key)` or `[start_key, ∞)`
-/// when `end_key` is empty.
-pub fn range(session_id: OID, start_key: &[u8], end_key: &[u8]) -> RS<Vec<(Vec<u8>, Vec<u8>)>> {
+/// Removes `key` from the given session; a missing key is not an error.
+pub fn delete(session_id: OID, key: &[u8]) -> RS<()> {
     if config::driver() != Driver::Sqlite {
         unreachable!("sqlite kv module should not be called for non-sqlite drivers");
     }
     ensure_session_exists(session_id)?;
     let conn = sqlite::open_connection()?;
-    let sql = if end_key.is_empty() {
-        "SELECT k, v FROM mudu_kv WHERE k >= ?1 ORDER BY k ASC"
-    } else {
-        "SELECT k, v FROM mudu_kv WHERE k >= ?1 AND k < ?2 ORDER BY k ASC"
-    };
-    let mut stmt = conn
-        .prepare(sql)
-        .map_err(|e| mudu_error!(ErrorCode::Database, "prepare kv range error", e))?;
-    let mut items = Vec::new();
-    if end_key.is_empty() {
-        let mut rows = stmt
-            .query([start_key])
-            .map_err(|e| mudu_error!(ErrorCode::Database, "execute kv range error", e))?;
-        while let Some(row) = rows
-            .next()
-            .map_err(|e| mudu_error!(ErrorCode::Database, "iterate kv range error", e))?
-        {
-            let key: Vec<u8> = row
-                .get(0)
-                .map_err(|e| mudu_error!(ErrorCode::Database, "", e))?;
-            let value: Vec<u8> = row
-                .get(1)
-                .map_err(|e| mudu_error!(ErrorCode::Database, "", e))?;
-            items.push((key, value));
+    conn.execute("DELETE FROM mudu_kv WHERE k = ?1", [key])
+        .map_err(|e| mudu_error!(ErrorCode::Database, "execute kv delete error", e))?;
+    Ok(())
+}
+
+/// Asynchronous version of [`delete`].
+pub async fn delete_async(session_id: OID, key: &[u8]) -> RS<()> {
+    let key = key.to_vec();
+    mudu_sys::task::async_::spawn_blocking(move || delete(session_id, &key)).await?
+}
+
+/// Stores every item in one SQLite transaction; a later item wins over an
+/// earlier one with the same key.
+pub fn put_many(session_id: OID, items: &[(Vec<u8>, Vec<u8>)]) -> RS<()> {
+    if config::driver() != Driver::Sqlite {
+        unreachable!("sqlite kv module should not be called for non-sqlite drivers");
+    }
+    ensure_session_exists(session_id)?;
+    let mut conn = sqlite::open_connection()?;
+    let tx = conn
+        .transaction()
+        .map_err(|e| mudu_error!(ErrorCode::Database, "begin kv put many error", e))?;
+    {
+        let mut stmt = tx
+            .prepare(
+                "INSERT INTO mudu_kv(k, v) VALUES(?1, ?2)
+                 ON CONFLICT(k) DO UPDATE SET v = excluded.v",
+            )
+            .map_err(|e| mudu_error!(ErrorCode::Database, "prepare kv put many error", e))?;
+        for (key, value) in items {
+            stmt.execute((key, value))
+                .map_err(|e| mudu_error!(ErrorCode::Database, "execute kv put many error", e))?;
         }
-    } else {
-        let mut rows = stmt
-            .query((start_key, end_key))
-            .map_err(|e| mudu_error!(ErrorCode::Database, "execute kv range error", e))?;
-        while let Some(row) = rows
-            .next()
-            .map_err(|e| mudu_error!(ErrorCode::Database, "iterate kv range error", e))?
-        {
-            let key: Vec<u8> = row
-                .get(0)
-                .map_err(|e| mudu_error!(ErrorCode::Database, "", e))?;
-            let value: Vec<u8> = row
-                .get(1)
-                .map_err(|e| mudu_error!(ErrorCode::Database, "", e))?;
-            items.push((key, value));
+    }
+    tx.commit()
+        .map_err(|e| mudu_error!(ErrorCode::Database, "commit kv put many error", e))
+}
+
+/// Asynchronous version of [`put_many`].
+pub async fn put_many_async(session_id: OID, items: &[(Vec<u8>, Vec<u8>)]) -> RS<()> {
+    let items = items.to_vec();
+    mudu_sys::task::async_::spawn_blocking(move || put_many(session_id, &items)).await?
+}
+
+/// Removes every key in one SQLite transaction.
+pub fn delete_many(session_id: OID, keys: &[Vec<u8>]) -> RS<()> {
+    if config::driver() != Driver::Sqlite {
+        unreachable!("sqlite kv module should not be called for non-sqlite drivers");
+    }
+    ensure_session_exists(session_id)?;
+    let mut conn = sqlite::open_connection()?;
+    let tx = conn
+        .transaction()
+        .map_err(|e| mudu_error!(ErrorCode::Database, "begin kv delete many error", e))?;
+    {
+        let mut stmt = tx
+            .prepare("DELETE FROM mudu_kv WHERE k = ?1")
+            .map_err(|e| mudu_error!(ErrorCode::Database, "prepare kv delete many error", e))?;
+        for key in keys {
+            stmt.execute([key])
+                .map_err(|e| mudu_error!(ErrorCode::Database, "execute kv delete many error", e))?;
         }
     }
-    Ok(items)
+    tx.commit()
+        .map_err(|e| mudu_error!(ErrorCode::Database, "commit kv delete many error", e))
+}
+
+/// Asynchronous version of [`delete_many`].
+pub async fn delete_many_async(session_id: OID, keys: &[Vec<u8>]) -> RS<()> {
+    let keys = keys.to_vec();
+    mudu_sys::task::async_::spawn_blocking(move || delete_many(session_id, &keys)).await?
+}
+
+/// Replaces the value of `key` with `value` when it currently equals
+/// `expected` (`None` meaning absent); returns whether it was replaced.
+pub fn cas(session_id: OID, key: &[u8], expected: Option<&[u8]>, value: &[u8]) -> RS<bool> {
+    update(session_id, key, |current| {
+        Ok(kv_atomic::compare_and_swap(current, expected, value))
+    })
+}
+
+/// Asynchronous version of [`cas`].
+pub async fn cas_async(
+    session_id: OID,
+    key: &[u8],
+    expected: Option<&[u8]>,
+    value: &[u8],
+) -> RS<bool> {
+    let key = key.to_vec();
+    let expected = expected.map(<[u8]>::to_vec);
+    let value = value.to_vec();
+    mudu_sys::task::async_::spawn_blocking(move || {
+        cas(session_id, &key, expected.as_deref(), &value)
+    })
+    .await?
+}
+
+/// Adds `delta` to the decimal counter stored under `key` and returns the
+/// new value; a missing key counts as zero.
+pub fn incr(session_id: OID, key: &[u8], delta: i64) -> RS<i64> {
+    update(session_id, key, |current| {
+        kv_atomic::increment(current, delta)
+    })
+}
+
+/// Asynchronous version of [`incr`].
+pub async fn incr_async(session_id: OID, key: &[u8], delta: i64) -> RS<i64> {
+    let key = key.to_vec();
+    mudu_sys::task::async_::spawn_blocking(move || incr(session_id, &key, delta)).await?
+}
+
+/// Stores `value` under `key` only when the key is absent; returns whether
+/// it was stored.
+pub fn put_if_absent(session_id: OID, key: &[u8], value: &[u8]) -> RS<bool> {
+    update(session_id, key, |current| {
+        Ok(kv_atomic::put_if_absent(current, value))
+    })
+}
+
+/// Asynchronous version of [`put_if_absent`].
+pub async fn put_if_absent_async(session_id: OID, key: &[u8], value: &[u8]) -> RS<bool> {
+    let key = key.to_vec();
+    let value = value.to_vec();
+    mudu_sys::task::async_::spawn_blocking(move || put_if_absent(session_id, &key, &value)).await?
+}
+
+/// Reads `key` and writes the value computed by `f` inside one immediate
+/// transaction, so no other writer can slip in between.
+fn update<T, F>(session_id: OID, key: &[u8], f: F) -> RS<T>
+where
+    F: FnOnce(Option<&[u8]>) -> RS<KvUpdate<T>>,
+{
+    if config::driver() != Driver::Sqlite {
+        unreachable!("sqlite kv module should not be called for non-sqlite drivers");
+    }
+    ensure_session_exists(session_id)?;
+    let mut conn = sqlite::open_connection()?;
+    let tx = conn
+        .transaction_with_behavior(TransactionBehavior::Immediate)
+        .map_err(|e| mudu_error!(ErrorCode::Database, "begin kv update error", e))?;
+    let current: Option<Vec<u8>> = tx
+        .query_row("SELECT v FROM mudu_kv WHERE k = ?1", [key], |row| {
+            row.get(0)
+        })
+        .optional()
+        .map_err(|e| mudu_error!(ErrorCode::Database, "read kv update error", e))?;
+    let (write, result) = f(current.as_deref())?;
+    if let Some(write) = write {
+        tx.execute(
+            "INSERT INTO mudu_kv(k, v) VALUES(?1, ?2)
+             ON CONFLICT(k) DO UPDATE SET v = excluded.v",
+            (key, write),
+        )
+        .map_err(|e| mudu_error!(ErrorCode::Database, "write kv update error", e))?;
+    }
+    tx.commit()
+        .map_err(|e| mudu_error!(ErrorCode::Database, "commit kv update error", e))?;
+    Ok(result)
+}
+
+/// Returns all key-value pairs in `[start_key, end_key)` or `[start_key, ∞)`
+/// when `end_key` is empty.
+pub fn range(session_id: OID, start_key: &[u8], end_key: &[u8]) -> RS<Vec<(Vec<u8>, Vec<u8>)>> {
+    Ok(range_page(session_id, start_key, end_key, &KvRangeOptions::default())?.into_items())
 }
 
 /// Asynchronous version of [`range`].
@@ -129,6 +252,66 @@ pub async fn range_async(
     mudu_sys::task::async_::spawn_blocking(move || range(session_id, &start_key, &end_key)).await?
 }
 
+/// Returns one page of the key-value pairs in `[start_key, end_key)` scanned
+/// with `options`.
+pub fn range_page(
+    session_id: OID,
+    start_key: &[u8],
+    end_key: &[u8],
+    options: &KvRangeOptions,
+) -> RS<KvRangePage> {
+    if config::driver() != Driver::Sqlite {
+        unreachable!("sqlite kv module should not be called for non-sqlite drivers");
+    }
+    ensure_session_exists(session_id)?;
+    let Some((start_key, end_key)) = options.bounds(start_key, end_key) else {
+        return Ok(KvRangePage::default());
+    };
+    let conn = sqlite::open_connection()?;
+    let sql_text = sql::kv_range_sql(!end_key.is_empty(), options, |idx| format!("?{}", idx));
+    let mut stmt = conn
+        .prepare(&sql_text)
+        .map_err(|e| mudu_error!(ErrorCode::Database, "prepare kv range error", e))?;
+    let params = if end_key.is_empty() {
+        vec![start_key]
+    } else {
+        vec![start_key, end_key]
+    };
+    let mut rows = stmt
+        .query(params_from_iter(params))
+        .map_err(|e| mudu_error!(ErrorCode::Database, "execute kv range error", e))?;
+    let mut items = Vec::new();
+    while let Some(row) = rows
+        .next()
+        .map_err(|e| mudu_error!(ErrorCode::Database, "iterate kv range error", e))?
+    {
+        let key: Vec<u8> = row
+            .get(0)
+            .map_err(|e| mudu_error!(ErrorCode::Database, "", e))?;
+        let value: Vec<u8> = row
+            .get(1)
+            .map_err(|e| mudu_error!(ErrorCode::Database, "", e))?;
+        items.push((key, value));
+    }
+    Ok(options.page(items))
+}
+
+/// Asynchronous version of [`range_page`].
+pub async fn range_page_async(
+    session_id: OID,
+    start_key: &[u8],
+    end_key: &[u8],
+    options: &KvRangeOptions,
+) -> RS<KvRangePage> {
+    let start_key = start_key.to_vec();
+    let end_key = end_key.to_vec();
+    let options = options.clone();
+    mudu_sys::task::async_::spawn_blocking(move || {
+        range_page(session_id, &start_key, &end_key, &options)
+    })
+    .await?
+}
+
 /// Verifies that `session_id` exists in the SQLite session table.
 pub fn ensure_session_exists(session_id: OID) -> RS<()> {
     if config::driver() != Driver::Sqlite {
@@ -9,6 +9,7 @@ use crate::kv;
 use crate::sqlite;
 use mudu::common::result::RS;
 use mudu::error::ErrorCode;
+use mudu_contract::database::kv_range::KvRangeOptions;
 use mudu_sys::env_var;
 use std::path::PathBuf;
 
@@ -62,6 +63,41 @@ fn kv_get_put_and_range_roundtrip() -> RS<()> {
     })
 }
 
+#[test]
+fn kv_range_page_applies_options() -> RS<()> {
+    with_sqlite_db("range_page", || {
+        let sid = sqlite::mudu_open()?;
+        kv::put(sid, b"a", b"0")?;
+        kv::put(sid, b"user/1", b"v1")?;
+        kv::put(sid, b"user/2", b"v2")?;
+        kv::put(sid, b"user/3", b"v3")?;
+
+        let options = KvRangeOptions::new()
+            .with_prefix(b"user/".to_vec())
+            .with_limit(2)
+            .with_reverse(true)
+            .with_keys_only(true);
+        let page = kv::range_page(sid, b"", b"", &options)?;
+        assert_eq!(
+            page.items(),
+            &[
+                (b"user/3".to_vec(), Vec::new()),
+                (b"user/2".to_vec(), Vec::new())
+            ]
+        );
+        let Some(token) = page.continuation().map(<[u8]>::to_vec) else {
+            panic!("expected a continuation token");
+        };
+
+        let page = kv::range_page(sid, b"", b"", &options.with_continuation(token))?;
+        assert_eq!(page.items(), &[(b"user/1".to_vec(), Vec::new())]);
+        assert_eq!(page.continuation(), None);
+
+        sqlite::mudu_close(sid)?;
+        Ok(())
+    })
+}
+
 #[test]
 fn kv_async_get_put_and_range_roundtrip() -> RS<()> {
     with_sqlite_db("async", || {
@@ -87,6 +123,86 @@ fn kv_async_get_put_and_range_roundtrip() -> RS<()> {
     })
 }
 
+#[test]
+fn kv_delete_and_multi_key_roundtrip() -> RS<()> {
+    with_sqlite_db("multi_key", || {
+        let sid = sqlite::mudu_open()?;
+
+        kv::put_many(
+            sid,
+            &[
+                (b"m1".to_vec(), b"v1".to_vec()),
+                (b"m2".to_vec(), b"v2".to_vec()),
+                (b"m3".to_vec(), b"v3".to_vec()),
+                (b"m1".to_vec(), b"v1-last".to_vec()),
+            ],
+        )?;
+        assert_eq!(kv::get(sid, b"m1")?, Some(b"v1-last".to_vec()));
+        assert_eq!(kv::range(sid, b"", b"")?.len(), 3);
+
+        kv::delete(sid, b"m2")?;
+        kv::delete(sid, b"missing")?;
+        assert!(kv::get(sid, b"m2")?.is_none());
+
+        kv::delete_many(sid, &[b"m1".to_vec(), b"m3".to_vec()])?;
+        assert!(kv::range(sid, b"", b"")?.is_empty());
+
+        mudu_sys::task::async_::block_on_tokio_current_thread(async move {
+            kv::put_many_async(sid, &[(b"n1".to_vec(), b"v".to_vec())]).await?;
+            kv::delete_async(sid, b"n1").await?;
+            kv::delete_many_async(sid, &[]).await?;
+            assert!(kv::get_async(sid, b"n1").await?.is_none());
+            Ok::<(), mudu::error::MuduError>(())
+        })??;
+
+        let err = match kv::put_many(sid + 1000, &[]) {
+            Ok(_) => panic!("expected entity not found error"),
+            Err(e) => e,
+        };
+        assert_eq!(err.ec(), ErrorCode::EntityNotFound);
+
+        sqlite::mudu_close(sid)?;
+        Ok(())
+    })
+}
+
+#[test]
+fn kv_atomic_updates_roundtrip() -> RS<()> {
+    with_sqlite_db("atomic", || {
+        let sid = sqlite::mudu_open()?;
+
+        assert_eq!(kv::incr(sid, b"n", 3)?, 3);
+        assert_eq!(kv::incr(sid, b"n", -5)?, -2);
+        assert_eq!(kv::get(sid, b"n")?, Some(b"-2".to_vec()));
+
+        assert!(kv::put_if_absent(sid, b"p", b"v1")?);
+        assert!(!kv::put_if_absent(sid, b"p", b"v2")?);
+        assert!(!kv::cas(sid, b"p", Some(b"v2"), b"v3")?);
+        assert!(kv::cas(sid, b"p", Some(b"v1"), b"v3")?);
+        assert!(kv::cas(sid, b"q", None, b"new")?);
+        assert_eq!(kv::get(sid, b"p")?, Some(b"v3".to_vec()));
+        assert_eq!(kv::get(sid, b"q")?, Some(b"new".to_vec()));
+
+        let err = match kv::incr(sid, b"p", 1) {
+            Ok(_) => panic!("expected invalid argument error"),
+            Err(e) => e,
+        };
+        assert_eq!(err.ec(), ErrorCode::InvalidArgument);
+        assert_eq!(kv::get(sid, b"p")?, Some(b"v3".to_vec()));
+
+        mudu_sys::task::async_::block_on_tokio_current_thread(async move {
+            assert_eq!(kv::incr_async(sid, b"n", 2).await?, 0);
+            assert!(kv::put_if_absent_async(sid, b"r", b"v").await?);
+            assert!(kv::cas_async(sid, b"r", Some(b"v"), b"w").await?);
+            assert_eq!(kv::get_async(sid, b"r").await?, Some(b"w".to_vec()));
+            Ok::<(), mudu::error::MuduError>(())
+        })??;
+
+        sqlite::mudu_close(sid)?;
+        Ok(())
+    })
+}
+
 #[test]
 fn ensure_session_exists_errors_when_session_missing() -> RS<()> {
     with_sqlite_db("missing_session", || {
@@ -15,10 +15,12 @@ use mudu_cli::client::async_client::{AsyncClient, AsyncClientImpl};
 use mudu_cli::client::client::SyncClient;
 use mudu_contract::database::entity::Entity;
 use mudu_contract::database::entity_set::RecordSet;
+use mudu_contract::database::kv_range::{KvRangeOptions, KvRangePage};
 use mudu_contract::database::sql_params::SQLParams;
 use mudu_contract::database::sql_stmt::SQLStmt;
 use mudu_contract::protocol::{
-    ClientRequest, GetRequest, PutRequest, RangeScanRequest, SessionCloseRequest,
+    CasRequest, ClientRequest, DeleteManyRequest, DeleteRequest, GetRequest, IncrRequest, KeyValue,
+    PutIfAbsentRequest, PutManyRequest, PutRequest, RangeScanRequest, SessionCloseRequest,
     SessionCreateRequest,
 };
 use mudu_contract::tuple::tuple_field_desc::TupleFieldDesc;
@@ -33,6 +35,7 @@ use std::collections::HashMap;
 use std::sync::Arc;
 use std::sync::OnceLock;
 use std::sync::mpsc::{self, Receiver, Sender, SyncSender};
+use std::time::Duration;
 
 struct MududSession {
     client: SyncClient,
@@ -52,8 +55,6 @@ struct AsyncMududSession {
     remote_session_id: u128,
 }
 
-type RangeResult = Vec<(Vec<u8>, Vec<u8>)>;
-
 struct QueryRows {
     row_desc: TupleFieldDesc,
     rows: Vec<TupleValue>,
@@ -78,13 +79,49 @@ enum AsyncCommand {
         session_id: OID,
         key: Vec<u8>,
         value: Vec<u8>,
+        ttl: Option<Duration>,
         response: SyncSender<RS<()>>,
     },
+    Delete {
+        session_id: OID,
+        key: Vec<u8>,
+        response: SyncSender<RS<()>>,
+    },
+    PutMany {
+        session_id: OID,
+        items: Vec<(Vec<u8>, Vec<u8>)>,
+        response: SyncSender<RS<()>>,
+    },
+    DeleteMany {
+        session_id: OID,
+        keys: Vec<Vec<u8>>,
+        response: SyncSender<RS<()>>,
+    },
+    Cas {
+        session_id: OID,
+        key: Vec<u8>,
+        expected: Option<Vec<u8>>,
+        value: Vec<u8>,
+        response: SyncSender<RS<bool>>,
+    },
+    Incr {
+        session_id: OID,
+        key: Vec<u8>,
+        delta: i64,
+        response: SyncSender<RS<i64>>,
+    },
+    PutIfAbsent {
+        session_id: OID,
+        key: Vec<u8>,
+        value: Vec<u8>,
+        response: SyncSender<RS<bool>>,
+    },
     Range {
         session_id: OID,
         start_key: Vec<u8>,
         end_key: Vec<u8>,
-        response: SyncSender<RS<RangeResult>>,
+        options: KvRangeOptions,
+        response: SyncSender<RS<KvRangePage>>,
     },
     Query {
         session_id: OID,
@@ -240,7 +277,7 @@ pub async fn mudu_get_async(session_id: OID, key: &[u8]) -> RS<Option<Vec<u8>>>
 /// Stores a value in a remote Mudud session.
 pub fn mudu_put(session_id: OID, key: &[u8], value: &[u8]) -> RS<()> {
     if config::mudud_async_session_loop() {
-        return async_put(session_id, key, value);
+        return async_put(session_id, key, value, None);
     }
 
     with_session(session_id, |session| {
@@ -253,15 +290,50 @@ pub fn mudu_put(session_id: OID, key: &[u8], value: &[u8]) -> RS<()> {
 /// Asynchronous version of [`mudu_put`].
 pub async fn mudu_put_async(session_id: OID, key: &[u8], value: &[u8]) -> RS<()> {
     let _trace = mudu_utils::task_trace!();
+    put_remote_async(session_id, key, value, None).await
+}
+
+/// Stores a value in a remote Mudud session that expires `ttl` after the
+/// write commits.
+pub fn mudu_put_with_ttl(session_id: OID, key: &[u8], value: &[u8], ttl: Duration) -> RS<()> {
+    if config::mudud_async_session_loop() {
+        return async_put(session_id, key, value, Some(ttl));
+    }
+
+    with_session(session_id, |session| {
+        session
+            .client
+            .put_with_ttl(session.remote_session_id, key.to_vec(), value.to_vec(), ttl)
+    })
+}
+
+/// Asynchronous version of [`mudu_put_with_ttl`].
+pub async fn mudu_put_with_ttl_async(
+    session_id: OID,
+    key: &[u8],
+    value: &[u8],
+    ttl: Duration,
+) -> RS<()> {
+    let _trace = mudu_utils::task_trace!();
+    put_remote_async(session_id, key, value, Some(ttl)).await
+}
+
+async fn put_remote_async(
+    session_id: OID,
+    key: &[u8],
+    value: &[u8],
+    ttl: Option<Duration>,
+) -> RS<()> {
     let session = async_session(session_id).await?;
     let mut session = session.lock().await;
     let remote_session_id = session.remote_session_id;
     let put = session
         .client
-        .put(PutRequest::new(
+        .put(put_request(
             remote_session_id,
             key.to_vec(),
             value.to_vec(),
+            ttl,
         ))
         .await?;
     if put.ok() {
@@ -274,52 +346,243 @@ pub async fn mudu_put_async(session_id: OID, key: &[u8], value: &[u8]) -> RS<()>
     }
 }
 
+/// Removes a key from a remote Mudud session.
+pub fn mudu_delete(session_id: OID, key: &[u8]) -> RS<()> {
+    if config::mudud_async_session_loop() {
+        return async_delete(session_id, key);
+    }
+
+    with_session(session_id, |session| {
+        session
+            .client
+            .delete(session.remote_session_id, key.to_vec())
+    })
+}
+
+/// Asynchronous version of [`mudu_delete`].
+pub async fn mudu_delete_async(session_id: OID, key: &[u8]) -> RS<()> {
+    let _trace = mudu_utils::task_trace!();
+    let session = async_session(session_id).await?;
+    let mut session = session.lock().await;
+    let remote_session_id = session.remote_session_id;
+    let delete = session
+        .client
+        .delete(DeleteRequest::new(remote_session_id, key.to_vec()))
+        .await?;
+    remote_write_result(delete.ok(), "remote delete operation returned failure")
+}
+
+/// Stores several values in a remote Mudud session atomically.
+pub fn mudu_put_many(session_id: OID, items: &[(Vec<u8>, Vec<u8>)]) -> RS<()> {
+    if config::mudud_async_session_loop() {
+        return async_put_many(session_id, items);
+    }
+
+    with_session(session_id, |session| {
+        session
+            .client
+            .put_many(session.remote_session_id, key_values(items.to_vec()))
+    })
+}
+
+/// Asynchronous version of [`mudu_put_many`].
+pub async fn mudu_put_many_async(session_id: OID, items: &[(Vec<u8>, Vec<u8>)]) -> RS<()> {
+    let _trace = mudu_utils::task_trace!();
+    let session = async_session(session_id).await?;
+    let mut session = session.lock().await;
+    let remote_session_id = session.remote_session_id;
+    let put = session
+        .client
+        .put_many(PutManyRequest::new(
+            remote_session_id,
+            key_values(items.to_vec()),
+        ))
+        .await?;
+    remote_write_result(put.ok(), "remote put many operation returned failure")
+}
+
+/// Removes several keys from a remote Mudud session atomically.
+pub fn mudu_delete_many(session_id: OID, keys: &[Vec<u8>]) -> RS<()> {
+    if config::mudud_async_session_loop() {
+        return async_delete_many(session_id, keys);
+    }
+
+    with_session(session_id, |session| {
+        session
+            .client
+            .delete_many(session.remote_session_id, keys.to_vec())
+    })
+}
+
+/// Asynchronous version of [`mudu_delete_many`].
+pub async fn mudu_delete_many_async(session_id: OID, keys: &[Vec<u8>]) -> RS<()> {
+    let _trace = mudu_utils::task_trace!();
+    let session = async_session(session_id).await?;
+    let mut session = session.lock().await;
+    let remote_session_id = session.remote_session_id;
+    let delete = session
+        .client
+        .delete_many(DeleteManyRequest::new(remote_session_id, keys.to_vec()))
+        .await?;
+    remote_write_result(delete.ok(), "remote delete many operation returned failure")
+}
+
+/// Compares and swaps a value in a remote Mudud session.
+pub fn mudu_cas(session_id: OID, key: &[u8], expected: Option<&[u8]>, value: &[u8]) -> RS<bool> {
+    if config::mudud_async_session_loop() {
+        return async_cas(session_id, key, expected, value);
+    }
+
+    with_session(session_id, |session| {
+        session.client.cas(
+            session.remote_session_id,
+            key.to_vec(),
+            expected.map(|expected| expected.to_vec()),
+            value.to_vec(),
+        )
+    })
+}
+
+/// Asynchronous version of [`mudu_cas`].
+pub async fn mudu_cas_async(
+    session_id: OID,
+    key: &[u8],
+    expected: Option<&[u8]>,
+    value: &[u8],
+) -> RS<bool> {
+    let _trace = mudu_utils::task_trace!();
+    let session = async_session(session_id).await?;
+    let mut session = session.lock().await;
+    let remote_session_id = session.remote_session_id;
+    let cas = session
+        .client
+        .cas(CasRequest::new(
+            remote_session_id,
+            key.to_vec(),
+            expected.map(|expected| expected.to_vec()),
+            value.to_vec(),
+        ))
+        .await?;
+    Ok(cas.swapped())
+}
+
+/// Increments an integer counter in a remote Mudud session.
+pub fn mudu_incr(session_id: OID, key: &[u8], delta: i64) -> RS<i64> {
+    if config::mudud_async_session_loop() {
+        return async_incr(session_id, key, delta);
+    }
+
+    with_session(session_id, |session| {
+        session
+            .client
+            .incr(session.remote_session_id, key.to_vec(), delta)
+    })
+}
+
+/// Asynchronous version of [`mudu_incr`].
+pub async fn mudu_incr_async(session_id: OID, key: &[u8], delta: i64) -> RS<i64> {
+    let _trace = mudu_utils::task_trace!();
+    let session = async_session(session_id).await?;
+    let mut session = session.lock().await;
+    let remote_session_id = session.remote_session_id;
+    let incr = session
+        .client
+        .incr(IncrRequest::new(remote_session_id, key.to_vec(), delta))
+        .await?;
+    Ok(incr.value())
+}
+
+/// Stores a value in a remote Mudud session only if the key is absent.
+pub fn mudu_put_if_absent(session_id: OID, key: &[u8], value: &[u8]) -> RS<bool> {
+    if config::mudud_async_session_loop() {
+        return async_put_if_absent(session_id, key, value);
+    }
+
+    with_session(session_id, |session| {
+        session
+            .client
+            .put_if_absent(session.remote_session_id, key.to_vec(), value.to_vec())
+    })
+}
+
+/// Asynchronous version of [`mudu_put_if_absent`].
+pub async fn mudu_put_if_absent_async(session_id: OID, key: &[u8], value: &[u8]) -> RS<bool> {
+    let _trace = mudu_utils::task_trace!();
+    let session = async_session(session_id).await?;
+    let mut session = session.lock().await;
+    let remote_session_id = session.remote_session_id;
+    let put = session
+        .client
+        .put_if_absent(PutIfAbsentRequest::new(
+            remote_session_id,
+            key.to_vec(),
+            value.to_vec(),
+        ))
+        .await?;
+    Ok(put.swapped())
+}
+
 /// Scans a range of keys in a remote Mudud session.
 pub fn mudu_range(
     session_id: OID,
     start_key: &[u8],
     end_key: &[u8],
 ) -> RS<Vec<(Vec<u8>, Vec<u8>)>> {
+    Ok(mudu_range_page(session_id, start_key, end_key, &KvRangeOptions::default())?.into_items())
+}
+
+/// Asynchronous version of [`mudu_range`].
+pub async fn mudu_range_async(
+    session_id: OID,
+    start_key: &[u8],
+    end_key: &[u8],
+) -> RS<Vec<(Vec<u8>, Vec<u8>)>> {
+    let page =
+        mudu_range_page_async(session_id, start_key, end_key, &KvRangeOptions::default()).await?;
+    Ok(page.into_items())
+}
+
+/// Scans one page of a range of keys in a remote Mudud session. The server
+/// applies the options, so only the page travels over the wire.
+pub fn mudu_range_page(
+    session_id: OID,
+    start_key: &[u8],
+    end_key: &[u8],
+    options: &KvRangeOptions,
+) -> RS<KvRangePage> {
     if config::mudud_async_session_loop() {
-        return async_range(session_id, start_key, end_key);
+        return async_range_page(session_id, start_key, end_key, options);
     }
 
     with_session(session_id, |session| {
-        let items = session.client.range_scan(
+        session.client.range_scan_page(
             session.remote_session_id,
             start_key.to_vec(),
             end_key.to_vec(),
-        )?;
-        Ok(items
-            .into_iter()
-            .map(|kv| (kv.key().to_vec(), kv.value().to_vec()))
-            .collect())
+            options.clone(),
+        )
     })
 }
 
-/// Asynchronous version of [`mudu_range`].
-pub async fn mudu_range_async(
+/// Asynchronous version of [`mudu_range_page`].
+pub async fn mudu_range_page_async(
     session_id: OID,
     start_key: &[u8],
     end_key: &[u8],
-) -> RS<Vec<(Vec<u8>, Vec<u8>)>> {
+    options: &KvRangeOptions,
+) -> RS<KvRangePage> {
     let _trace = mudu_utils::task_trace!();
     let session = async_session(session_id).await?;
     let mut session = session.lock().await;
     let remote_session_id = session.remote_session_id;
-    let items = session
+    let response = session
         .client
-        .range_scan(RangeScanRequest::new(
-            remote_session_id,
-            start_key.to_vec(),
-            end_key.to_vec(),
-        ))
+        .range_scan(
+            RangeScanRequest::new(remote_session_id, start_key.to_vec(), end_key.to_vec())
+                .with_options(options.clone()),
+        )
         .await?;
-    Ok(items
-        .into_items()
-        .into_iter()
-        .map(|kv| (kv.key().to_vec(), kv.value().to_vec()))
-        .collect())
+    Ok(response.into_page())
 }
 
 /// Executes a query on a remote Mudud session and returns the resulting record set.
@@ -540,7 +803,20 @@ fn async_get(session_id: OID, key: &[u8]) -> RS<Option<Vec<u8>>> {
     recv_response(rx)
 }
 
-fn async_put(session_id: OID, key: &[u8], value: &[u8]) -> RS<()> {
+fn put_request(
+    remote_session_id: u128,
+    key: Vec<u8>,
+    value: Vec<u8>,
+    ttl: Option<Duration>,
+) -> PutRequest {
+    let request = PutRequest::new(remote_session_id, key, value);
+    match ttl {
+        Some(ttl) => request.with_ttl(ttl),
+        None => request,
+    }
+}
+
+fn async_put(session_id: OID, key: &[u8], value: &[u8], ttl: Option<Duration>) -> RS<()> {
     let (tx, rx) = mpsc::sync_channel(1);
     async_manager()?
         .sender
@@ -548,6 +824,7 @@ fn async_put(session_id: OID, key: &[u8], value: &[u8]) -> RS<()> {
             session_id,
             key: key.to_vec(),
             value: value.to_vec(),
+            ttl,
             response: tx,
         })
         .map_err(|e| {
@@ -560,7 +837,130 @@ fn async_put(session_id: OID, key: &[u8], value: &[u8]) -> RS<()> {
     recv_response(rx)
 }
 
-fn async_range(session_id: OID, start_key: &[u8], end_key: &[u8]) -> RS<Vec<(Vec<u8>, Vec<u8>)>> {
+fn async_delete(session_id: OID, key: &[u8]) -> RS<()> {
+    let (tx, rx) = mpsc::sync_channel(1);
+    async_manager()?
+        .sender
+        .send(AsyncCommand::Delete {
+            session_id,
+            key: key.to_vec(),
+            response: tx,
+        })
+        .map_err(|e| {
+            mudu_error!(
+                ErrorCode::ChannelClosed,
+                "send mudud async delete command error",
+                e
+            )
+        })?;
+    recv_response(rx)
+}
+
+fn async_put_many(session_id: OID, items: &[(Vec<u8>, Vec<u8>)]) -> RS<()> {
+    let (tx, rx) = mpsc::sync_channel(1);
+    async_manager()?
+        .sender
+        .send(AsyncCommand::PutMany {
+            session_id,
+            items: items.to_vec(),
+            response: tx,
+        })
+        .map_err(|e| {
+            mudu_error!(
+                ErrorCode::ChannelClosed,
+                "send mudud async put many command error",
+                e
+            )
+        })?;
+    recv_response(rx)
+}
+
+fn async_delete_many(session_id: OID, keys: &[Vec<u8>]) -> RS<()> {
+    let (tx, rx) = mpsc::sync_channel(1);
+    async_manager()?
+        .sender
+        .send(AsyncCommand::DeleteMany {
+            session_id,
+            keys: keys.to_vec(),
+            response: tx,
+        })
+        .map_err(|e| {
+            mudu_error!(
+                ErrorCode::ChannelClosed,
+                "send mudud async delete many command error",
+                e
+            )
+        })?;
+    recv_response(rx)
+}
+
+fn async_cas(session_id: OID, key: &[u8], expected: Option<&[u8]>, value: &[u8]) -> RS<bool> {
+    let (tx, rx) = mpsc::sync_channel(1);
+    async_manager()?
+        .sender
+        .send(AsyncCommand::Cas {
+            session_id,
+            key: key.to_vec(),
+            expected: expected.map(|expected| expected.to_vec()),
+            value: value.to_vec(),
+            response: tx,
+        })
+        .map_err(|e| {
+            mudu_error!(
+                ErrorCode::ChannelClosed,
+                "send mudud async cas command error",
+                e
+            )
+        })?;
+    recv_response(rx)
+}
+
+fn async_incr(session_id: OID, key: &[u8], delta: i64) -> RS<i64> {
+    let (tx, rx) = mpsc::sync_channel(1);
+    async_manager()?
+        .sender
+        .send(AsyncCommand::Incr {
+            session_id,
+            key: key.to_vec(),
+            delta,
+            response: tx,
+        })
+        .map_err(|e| {
+            mudu_error!(
+                ErrorCode::ChannelClosed,
+                "send mudud async incr command error",
+                e
+            )
+        })?;
+    recv_response(rx)
+}
+
+fn async_put_if_absent(session_id: OID, key: &[u8], value: &[u8]) -> RS<bool> {
+    let (tx, rx) = mpsc::sync_channel(1);
+    async_manager()?
+        .sender
+        .send(AsyncCommand::PutIfAbsent {
+            session_id,
+            key: key.to_vec(),
+            value: value.to_vec(),
+            response: tx,
+        })
+        .map_err(|e| {
+            mudu_error!(
+                ErrorCode::ChannelClosed,
+                "send mudud async put if absent command error",
+                e
+            )
+        })?;
+    recv_response(rx)
+}
+
+fn async_range_page(
+    session_id: OID,
+    start_key: &[u8],
+    end_key: &[u8],
+    options: &KvRangeOptions,
+) -> RS<KvRangePage> {
     let (tx, rx) = mpsc::sync_channel(1);
     async_manager()?
         .sender
@@ -568,6 +968,7 @@ fn async_range(session_id: OID, start_key: &[u8], end_key: &[u8]) -> RS<Vec<(Vec
             session_id,
             start_key: start_key.to_vec(),
             end_key: end_key.to_vec(),
+            options: options.clone(),
             response: tx,
         })
         .map_err(|e| {
@@ -646,6 +1047,21 @@ fn async_batch(session_id: OID, app_name: String, sql_text: String) -> RS<u64> {
     recv_response(rx)
 }
 
+fn key_values(items: Vec<(Vec<u8>, Vec<u8>)>) -> Vec<KeyValue> {
+    items
+        .into_iter()
+        .map(|(key, value)| KeyValue::new(key, value))
+        .collect()
+}
+
+fn remote_write_result(ok: bool, message: &str) -> RS<()> {
+    if ok {
+        Ok(())
+    } else {
+        Err(mudu_error!(ErrorCode::Network, message))
+    }
+}
+
 fn recv_response<T>(rx: Receiver<RS<T>>) -> RS<T> {
     rx.recv()
         .map_err(|e| mudu_error!(ErrorCode::Thread, "receive mudud async response error", e))?
@@ -771,6 +1187,7 @@ async fn handle_async_command(
             session_id,
             key,
             value,
+            ttl,
             response,
         } => {
             let result = async {
@@ -782,7 +1199,7 @@ async fn handle_async_command(
                 })?;
                 let put = session
                     .client
-                    .put(PutRequest::new(session.remote_session_id, key, value))
+                    .put(put_request(session.remote_session_id, key, value, ttl))
                     .await?;
                 if put.ok() {
                     Ok(())
@@ -796,10 +1213,153 @@ async fn handle_async_command(
             .await;
             let _ = response.send(result);
         }
+        AsyncCommand::Delete {
+            session_id,
+            key,
+            response,
+        } => {
+            let result = async {
+                let session = sessions.get_mut(&session_id).ok_or_else(|| {
+                    mudu_error!(
+                        ErrorCode::EntityNotFound,
+                        format!("session {} does not exist", session_id)
+                    )
+                })?;
+                let delete = session
+                    .client
+                    .delete(DeleteRequest::new(session.remote_session_id, key))
+                    .await?;
+                remote_write_result(delete.ok(), "remote delete operation returned failure")
+            }
+            .await;
+            let _ = response.send(result);
+        }
+        AsyncCommand::PutMany {
+            session_id,
+            items,
+            response,
+        } => {
+            let result = async {
+                let session = sessions.get_mut(&session_id).ok_or_else(|| {
+                    mudu_error!(
+                        ErrorCode::EntityNotFound,
+                        format!("session {} does not exist", session_id)
+                    )
+                })?;
+                let put = session
+                    .client
+                    .put_many(PutManyRequest::new(
+                        session.remote_session_id,
+                        key_values(items),
+                    ))
+                    .await?;
+                remote_write_result(put.ok(), "remote put many operation returned failure")
+            }
+            .await;
+            let _ = response.send(result);
+        }
+        AsyncCommand::DeleteMany {
+            session_id,
+            keys,
+            response,
+        } => {
+            let result = async {
+                let session = sessions.get_mut(&session_id).ok_or_else(|| {
+                    mudu_error!(
+                        ErrorCode::EntityNotFound,
+                        format!("session {} does not exist", session_id)
+                    )
+                })?;
+                let delete = session
+                    .client
+                    .delete_many(DeleteManyRequest::new(session.remote_session_id, keys))
+                    .await?;
+                remote_write_result(delete.ok(), "remote delete many operation returned failure")
+            }
+            .await;
+            let _ = response.send(result);
+        }
+        AsyncCommand::Cas {
+            session_id,
+            key,
+            expected,
+            value,
+            response,
+        } => {
+            let result = async {
+                let session = sessions.get_mut(&session_id).ok_or_else(|| {
+                    mudu_error!(
+                        ErrorCode::EntityNotFound,
+                        format!("session {} does not exist", session_id)
+                    )
+                })?;
+                let response = session
+                    .client
+                    .cas(CasRequest::new(
+                        session.remote_session_id,
+                        key,
+                        expected,
+                        value,
+                    ))
+                    .await?;
+                Ok(response.swapped())
+            }
+            .await;
+            let _ = response.send(result);
+        }
+        AsyncCommand::Incr {
+            session_id,
+            key,
+            delta,
+            response,
+        } => {
+            let result = async {
+                let session = sessions.get_mut(&session_id).ok_or_else(|| {
+                    mudu_error!(
+                        ErrorCode::EntityNotFound,
+                        format!("session {} does not exist", session_id)
+                    )
+                })?;
+                let response = session
+                    .client
+                    .incr(IncrRequest::new(session.remote_session_id, key, delta))
+                    .await?;
+                Ok(response.value())
+            }
+            .await;
+            let _ = response.send(result);
+        }
+        AsyncCommand::PutIfAbsent {
+            session_id,
+            key,
+            value,
+            response,
+        } => {
+            let result = async {
+                let session = sessions.get_mut(&session_id).ok_or_else(|| {
+                    mudu_error!(
+                        ErrorCode::EntityNotFound,
+                        format!("session {} does not exist", session_id)
+                    )
+                })?;
+                let response = session
+                    .client
+                    .put_if_absent(PutIfAbsentRequest::new(
+                        session.remote_session_id,
+                        key,
+                        value,
+                    ))
+                    .await?;
+                Ok(response.swapped())
+            }
+            .await;
+            let _ = response.send(result);
+        }
         AsyncCommand::Range {
             session_id,
             start_key,
             end_key,
+            options,
             response,
         } => {
             let result = async {
@@ -811,16 +1371,12 @@ async fn handle_async_command(
                 })?;
                 Ok(session
                     .client
-                    .range_scan(RangeScanRequest::new(
-                        session.remote_session_id,
-                        start_key,
-                        end_key,
-                    ))
+                    .range_scan(
+                        RangeScanRequest::new(session.remote_session_id, start_key, end_key)
+                            .with_options(options),
+                    )
                     .await?
-                    .into_items()
-                    .into_iter()
-                    .map(|kv| (kv.key().to_vec(), kv.value().to_vec()))
-                    .collect())
+                    .into_page())
             }
             .await;
             let _ = response.send(result);
@@ -1085,6 +1641,35 @@ mod tests {
                     .contains(&format!("session {} does not exist", session_id))
             );
 
+            for result in [
+                mudu_delete(session_id, b"key"),
+                mudu_put_with_ttl(session_id, b"key", b"value", Duration::from_secs(1)),
+                mudu_put_many(session_id, &[(b"key".to_vec(), b"value".to_vec())]),
+                mudu_delete_many(session_id, &[b"key".to_vec()]),
+            ] {
+                let err = match result {
+                    Ok(_) => panic!("expected entity not found error"),
+                    Err(err) => err,
+                };
+                assert_eq!(err.ec(), ErrorCode::EntityNotFound);
+            }
+
+            for result in [
+                mudu_cas(session_id, b"key", None, b"value"),
+                mudu_put_if_absent(session_id, b"key", b"value"),
+            ] {
+                let err = match result {
+                    Ok(_) => panic!("expected entity not found error"),
+                    Err(err) => err,
+                };
+                assert_eq!(err.ec(), ErrorCode::EntityNotFound);
+            }
+            let err = match mudu_incr(session_id, b"counter", 1) {
+                Ok(_) => panic!("expected entity not found error"),
+                Err(err) => err,
+            };
+            assert_eq!(err.ec(), ErrorCode::EntityNotFound);
+
             let err = match mudu_range(session_id, b"start", b"end") {
                 Ok(_) => panic!("expected entity not found error"),
                 Err(err) => err,
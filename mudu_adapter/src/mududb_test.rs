@@ -12,12 +12,13 @@ use crate::config;
 use crate::mududb::{
     mudu_batch, mudu_batch_async, mudu_close, mudu_close_async, mudu_command, mudu_command_async,
     mudu_get, mudu_get_async, mudu_open, mudu_open_async, mudu_put, mudu_put_async, mudu_query,
-    mudu_query_async, mudu_range, mudu_range_async,
+    mudu_query_async, mudu_range, mudu_range_async, mudu_range_page_async,
 };
 use mudu::common::result::RS;
 use mudu::error::ErrorCode;
 use mudu_binding::universal::uni_oid::UniOid;
 use mudu_binding::universal::uni_session_open_argv::UniSessionOpenArgv;
+use mudu_contract::database::kv_range::KvRangeOptions;
 use mudu_contract::database::sql_stmt_text::SQLStmtText;
 
 fn with_connection_env<T>(value: &str, f: impl FnOnce() -> RS<T>) -> RS<T> {
@@ -204,6 +205,13 @@ fn native_async_operations_report_entity_not_found_for_missing_session() -> RS<(
             };
             assert_session_not_found(err, session_id);
 
+            let options = KvRangeOptions::new().with_limit(10);
+            let err = match mudu_range_page_async(session_id, b"start", b"end", &options).await {
+                Ok(_) => panic!("expected entity not found error"),
+                Err(e) => e,
+            };
+            assert_session_not_found(err, session_id);
+
             let query_stmt = SQLStmtText::new("SELECT 1".to_string());
             let err = match mudu_query_async::<i32>(session_id, &query_stmt, &()).await {
                 Ok(_) => panic!("expected entity not found error"),
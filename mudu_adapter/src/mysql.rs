@@ -2,7 +2,7 @@
 
 use crate::config;
 use crate::result_set::LocalResultSet;
-use crate::sql::{datum_type_for_id, replace_placeholders};
+use crate::sql::{datum_type_for_id, kv_range_sql, replace_placeholders};
 use crate::state;
 use lazy_static::lazy_static;
 use mudu::common::id::OID;
@@ -11,6 +11,8 @@ use mudu::error::ErrorCode;
 use mudu::mudu_error;
 use mudu_contract::database::entity::Entity;
 use mudu_contract::database::entity_set::RecordSet;
+use mudu_contract::database::kv_atomic::{self, KvUpdate};
+use mudu_contract::database::kv_range::{KvRangeOptions, KvRangePage};
 use mudu_contract::database::sql_params::SQLParams;
 use mudu_contract::database::sql_stmt::SQLStmt;
 use mudu_contract::tuple::datum_desc::DatumDesc;
@@ -32,6 +34,7 @@ use mysql_async::{
 use scc::HashMap as SccHashMap;
 use std::collections::HashMap;
 use std::sync::Arc;
+use std::time::Duration;
 
 type MySqlConnRef = Arc<SMutex<mysql::PooledConn>>;
 
@@ -200,27 +203,172 @@ pub async fn mudu_put_async(session_id: OID, key: &[u8], value: &[u8]) -> RS<()>
     Ok(())
 }
 
+/// Rejects puts with a TTL: the MySQL backend stores values without expiry.
+pub fn mudu_put_with_ttl(session_id: OID, _key: &[u8], _value: &[u8], _ttl: Duration) -> RS<()> {
+    Err(mudu_error!(
+        ErrorCode::NotImplemented,
+        format!("mysql session {} does not support kv ttl", session_id)
+    ))
+}
+
+/// Asynchronous version of [`mudu_put_with_ttl`].
+pub async fn mudu_put_with_ttl_async(
+    session_id: OID,
+    key: &[u8],
+    value: &[u8],
+    ttl: Duration,
+) -> RS<()> {
+    mudu_put_with_ttl(session_id, key, value, ttl)
+}
+
+/// Removes a key from a MySQL session.
+pub fn mudu_delete(session_id: OID, key: &[u8]) -> RS<()> {
+    with_session(session_id, |conn| {
+        conn.exec_drop("DELETE FROM mudu_kv WHERE k = ?", (key.to_vec(),))
+            .map_err(|e| mudu_error!(ErrorCode::Database, "mysql kv delete error", e))?;
+        Ok(())
+    })
+}
+
+/// Asynchronous version of [`mudu_delete`].
+pub async fn mudu_delete_async(session_id: OID, key: &[u8]) -> RS<()> {
+    let _trace = mudu_utils::task_trace!();
+    let session = with_async_session(session_id).await?;
+    let mut session = session.lock().await;
+    session
+        .conn
+        .exec_drop("DELETE FROM mudu_kv WHERE k = ?", (key.to_vec(),))
+        .await
+        .map_err(|e| mudu_error!(ErrorCode::Database, "mysql kv delete error", e))?;
+    Ok(())
+}
+
+/// Stores several values in a MySQL session with a single statement.
+pub fn mudu_put_many(session_id: OID, items: &[(Vec<u8>, Vec<u8>)]) -> RS<()> {
+    with_session(session_id, |conn| {
+        if items.is_empty() {
+            return Ok(());
+        }
+        conn.exec_drop(put_many_sql(items.len()), put_many_params(items))
+            .map_err(|e| mudu_error!(ErrorCode::Database, "mysql kv put many error", e))?;
+        Ok(())
+    })
+}
+
+/// Asynchronous version of [`mudu_put_many`].
+pub async fn mudu_put_many_async(session_id: OID, items: &[(Vec<u8>, Vec<u8>)]) -> RS<()> {
+    let _trace = mudu_utils::task_trace!();
+    let session = with_async_session(session_id).await?;
+    if items.is_empty() {
+        return Ok(());
+    }
+    let mut session = session.lock().await;
+    session
+        .conn
+        .exec_drop(put_many_sql(items.len()), put_many_params(items))
+        .await
+        .map_err(|e| mudu_error!(ErrorCode::Database, "mysql kv put many error", e))?;
+    Ok(())
+}
+
+/// Removes several keys from a MySQL session with a single statement.
+pub fn mudu_delete_many(session_id: OID, keys: &[Vec<u8>]) -> RS<()> {
+    with_session(session_id, |conn| {
+        if keys.is_empty() {
+            return Ok(());
+        }
+        conn.exec_drop(delete_many_sql(keys.len()), keys.to_vec())
+            .map_err(|e| mudu_error!(ErrorCode::Database, "mysql kv delete many error", e))?;
+        Ok(())
+    })
+}
+
+/// Asynchronous version of [`mudu_delete_many`].
+pub async fn mudu_delete_many_async(session_id: OID, keys: &[Vec<u8>]) -> RS<()> {
+    let _trace = mudu_utils::task_trace!();
+    let session = with_async_session(session_id).await?;
+    if keys.is_empty() {
+        return Ok(());
+    }
+    let mut session = session.lock().await;
+    session
+        .conn
+        .exec_drop(delete_many_sql(keys.len()), keys.to_vec())
+        .await
+        .map_err(|e| mudu_error!(ErrorCode::Database, "mysql kv delete many error", e))?;
+    Ok(())
+}
+
+/// Compares and swaps a value in a MySQL session.
+pub fn mudu_cas(session_id: OID, key: &[u8], expected: Option<&[u8]>, value: &[u8]) -> RS<bool> {
+    with_session(session_id, |conn| {
+        update(conn, key, |current| {
+            Ok(kv_atomic::compare_and_swap(current, expected, value))
+        })
+    })
+}
+
+/// Asynchronous version of [`mudu_cas`].
+pub async fn mudu_cas_async(
+    session_id: OID,
+    key: &[u8],
+    expected: Option<&[u8]>,
+    value: &[u8],
+) -> RS<bool> {
+    let _trace = mudu_utils::task_trace!();
+    let session = with_async_session(session_id).await?;
+    let mut session = session.lock().await;
+    update_async(&mut session.conn, key, |current| {
+        Ok(kv_atomic::compare_and_swap(current, expected, value))
+    })
+    .await
+}
+
+/// Increments a counter in a MySQL session.
+pub fn mudu_incr(session_id: OID, key: &[u8], delta: i64) -> RS<i64> {
+    with_session(session_id, |conn| {
+        update(conn, key, |current| kv_atomic::increment(current, delta))
+    })
+}
+
+/// Asynchronous version of [`mudu_incr`].
+pub async fn mudu_incr_async(session_id: OID, key: &[u8], delta: i64) -> RS<i64> {
+    let _trace = mudu_utils::task_trace!();
+    let session = with_async_session(session_id).await?;
+    let mut session = session.lock().await;
+    update_async(&mut session.conn, key, |current| {
+        kv_atomic::increment(current, delta)
+    })
+    .await
+}
+
+/// Stores a value in a MySQL session unless the key exists.
+pub fn mudu_put_if_absent(session_id: OID, key: &[u8], value: &[u8]) -> RS<bool> {
+    with_session(session_id, |conn| {
+        update(conn, key, |current| {
+            Ok(kv_atomic::put_if_absent(current, value))
+        })
+    })
+}
+
+/// Asynchronous version of [`mudu_put_if_absent`].
+pub async fn mudu_put_if_absent_async(session_id: OID, key: &[u8], value: &[u8]) -> RS<bool> {
+    let _trace = mudu_utils::task_trace!();
+    let session = with_async_session(session_id).await?;
+    let mut session = session.lock().await;
+    update_async(&mut session.conn, key, |current| {
+        Ok(kv_atomic::put_if_absent(current, value))
+    })
+    .await
+}
+
 /// Scans a range of keys in a MySQL session.
 pub fn mudu_range(
     session_id: OID,
     start_key: &[u8],
     end_key: &[u8],
 ) -> RS<Vec<(Vec<u8>, Vec<u8>)>> {
-    with_session(session_id, |conn| {
-        if end_key.is_empty() {
-            conn.exec(
-                "SELECT k, v FROM mudu_kv WHERE k >= ? ORDER BY k ASC",
-                (start_key.to_vec(),),
-            )
-            .map_err(|e| mudu_error!(ErrorCode::Database, "mysql kv range error", e))
-        } else {
-            conn.exec(
-                "SELECT k, v FROM mudu_kv WHERE k >= ? AND k < ? ORDER BY k ASC",
-                (start_key.to_vec(), end_key.to_vec()),
-            )
-            .map_err(|e| mudu_error!(ErrorCode::Database, "mysql kv range error", e))
-        }
-    })
+    Ok(mudu_range_page(session_id, start_key, end_key, &KvRangeOptions::default())?.into_items())
 }
 
 /// Asynchronous version of [`mudu_range`].
@@ -229,28 +377,60 @@ pub async fn mudu_range_async(
     start_key: &[u8],
     end_key: &[u8],
 ) -> RS<Vec<(Vec<u8>, Vec<u8>)>> {
+    let page =
+        mudu_range_page_async(session_id, start_key, end_key, &KvRangeOptions::default()).await?;
+    Ok(page.into_items())
+}
+
+/// Scans one page of a range of keys in a MySQL session.
+pub fn mudu_range_page(
+    session_id: OID,
+    start_key: &[u8],
+    end_key: &[u8],
+    options: &KvRangeOptions,
+) -> RS<KvRangePage> {
+    with_session(session_id, |conn| {
+        let Some((start_key, end_key)) = options.bounds(start_key, end_key) else {
+            return Ok(KvRangePage::default());
+        };
+        let sql = kv_range_sql(!end_key.is_empty(), options, |_| "?".to_string());
+        let params = if end_key.is_empty() {
+            vec![start_key]
+        } else {
+            vec![start_key, end_key]
+        };
+        let items: Vec<(Vec<u8>, Vec<u8>)> = conn
+            .exec(sql, params)
+            .map_err(|e| mudu_error!(ErrorCode::Database, "mysql kv range error", e))?;
+        Ok(options.page(items))
+    })
+}
+
+/// Asynchronous version of [`mudu_range_page`].
+pub async fn mudu_range_page_async(
+    session_id: OID,
+    start_key: &[u8],
+    end_key: &[u8],
+    options: &KvRangeOptions,
+) -> RS<KvRangePage> {
     let _trace = mudu_utils::task_trace!();
     let session = with_async_session(session_id).await?;
     let mut session = session.lock().await;
-    if end_key.is_empty() {
-        session
-            .conn
-            .exec(
-                "SELECT k, v FROM mudu_kv WHERE k >= ? ORDER BY k ASC",
-                (start_key.to_vec(),),
-            )
-            .await
-            .map_err(|e| mudu_error!(ErrorCode::Database, "mysql kv range error", e))
+    let Some((start_key, end_key)) = options.bounds(start_key, end_key) else {
+        return Ok(KvRangePage::default());
+    };
+    let sql = kv_range_sql(!end_key.is_empty(), options, |_| "?".to_string());
+    let params = if end_key.is_empty() {
+        vec![start_key]
     } else {
-        session
-            .conn
-            .exec(
-                "SELECT k, v FROM mudu_kv WHERE k >= ? AND k < ? ORDER BY k ASC",
-                (start_key.to_vec(), end_key.to_vec()),
-            )
-            .await
-            .map_err(|e| mudu_error!(ErrorCode::Database, "mysql kv range error", e))
-    }
+        vec![start_key, end_key]
+    };
+    let items: Vec<(Vec<u8>, Vec<u8>)> = session
+        .conn
+        .exec(sql, params)
+        .await
+        .map_err(|e| mudu_error!(ErrorCode::Database, "mysql kv range error", e))?;
+    Ok(options.page(items))
 }
 
 /// Executes a query on a MySQL session and returns the resulting record set.
@@ -374,6 +554,99 @@ fn ensure_session_exists(session_id: OID) -> RS<()> {
     }
 }
 
+// Rows of one multi-row insert are applied in order, so a repeated key keeps
+// the value of its last occurrence.
+fn put_many_sql(count: usize) -> String {
+    format!(
+        "INSERT INTO mudu_kv(k, v) VALUES {}
+         ON DUPLICATE KEY UPDATE v = VALUES(v)",
+        vec!["(?, ?)"; count].join(", ")
+    )
+}
+
+fn put_many_params(items: &[(Vec<u8>, Vec<u8>)]) -> Vec<Vec<u8>> {
+    items
+        .iter()
+        .flat_map(|(key, value)| [key.clone(), value.clone()])
+        .collect()
+}
+
+fn delete_many_sql(count: usize) -> String {
+    format!(
+        "DELETE FROM mudu_kv WHERE k IN ({})",
+        vec!["?"; count].join(", ")
+    )
+}
+
+// `INSERT IGNORE` reports zero affected rows for an existing key whatever the
+// client's found-rows setting, unlike `ON DUPLICATE KEY UPDATE`.
+const INSERT_IF_ABSENT_SQL: &str = "INSERT IGNORE INTO mudu_kv(k, v) VALUES(?, ?)";
+
+const UPDATE_IF_EQUAL_SQL: &str = "UPDATE mudu_kv SET v = ? WHERE k = ? AND v = ?";
+
+// Atomic updates are optimistic: the write only lands while the key still
+// holds the value `f` saw, otherwise the key is read again and `f` re-run.
+// An unchanged value is never written, so affected rows stay meaningful.
+fn update<T, F>(conn: &mut mysql::PooledConn, key: &[u8], f: F) -> RS<T>
+where
+    F: Fn(Option<&[u8]>) -> RS<KvUpdate<T>>,
+{
+    loop {
+        let current: Option<Vec<u8>> = conn
+            .exec_first("SELECT v FROM mudu_kv WHERE k = ?", (key.to_vec(),))
+            .map_err(|e| mudu_error!(ErrorCode::Database, "mysql kv update read error", e))?;
+        let (write, result) = f(current.as_deref())?;
+        let Some(write) = write else {
+            return Ok(result);
+        };
+        if current.as_ref() == Some(&write) {
+            return Ok(result);
+        }
+        match current {
+            None => conn.exec_drop(INSERT_IF_ABSENT_SQL, (key.to_vec(), write)),
+            Some(current) => conn.exec_drop(UPDATE_IF_EQUAL_SQL, (write, key.to_vec(), current)),
+        }
+        .map_err(|e| mudu_error!(ErrorCode::Database, "mysql kv update write error", e))?;
+        if conn.affected_rows() == 1 {
+            return Ok(result);
+        }
+    }
+}
+
+/// Asynchronous version of [`update`].
+async fn update_async<T, F>(conn: &mut AsyncConn, key: &[u8], f: F) -> RS<T>
+where
+    F: Fn(Option<&[u8]>) -> RS<KvUpdate<T>>,
+{
+    loop {
+        let current: Option<Vec<u8>> = conn
+            .exec_first("SELECT v FROM mudu_kv WHERE k = ?", (key.to_vec(),))
+            .await
+            .map_err(|e| mudu_error!(ErrorCode::Database, "mysql kv update read error", e))?;
+        let (write, result) = f(current.as_deref())?;
+        let Some(write) = write else {
+            return Ok(result);
+        };
+        if current.as_ref() == Some(&write) {
+            return Ok(result);
+        }
+        match current {
+            None => {
+                conn.exec_drop(INSERT_IF_ABSENT_SQL, (key.to_vec(), write))
+                    .await
+            }
+            Some(current) => {
+                conn.exec_drop(UPDATE_IF_EQUAL_SQL, (write, key.to_vec(), current))
+                    .await
+            }
+        }
+        .map_err(|e| mudu_error!(ErrorCode::Database, "mysql kv update write error", e))?;
+        if conn.affected_rows() == 1 {
+            return Ok(result);
+        }
+    }
+}
+
 fn with_session<R, F>(session_id: OID, f: F) -> RS<R>
 where
     F: FnOnce(&mut mysql::PooledConn) -> RS<R>,
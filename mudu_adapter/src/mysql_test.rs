@@ -8,6 +8,7 @@ use crate::config;
 use crate::mysql;
 use mudu::common::result::RS;
 use mudu::error::ErrorCode;
+use std::time::Duration;
 
 fn with_connection_env<T>(value: &str, f: impl FnOnce() -> RS<T>) -> RS<T> {
     let prev = mudu_sys::env_var::var("MUDU_CONNECTION");
@@ -79,3 +80,51 @@ fn mudu_close_reports_entity_not_found_for_unknown_session() -> RS<()> {
     assert_eq!(err.ec(), ErrorCode::EntityNotFound);
     Ok(())
 }
+
+#[test]
+fn kv_writes_report_entity_not_found_for_unknown_session() -> RS<()> {
+    let _guard = config::test_lock().lock()?;
+    config::reset_db_path_override_for_test();
+    let session_id = 0xDEAD_BEEF_u128;
+    for result in [
+        mysql::mudu_delete(session_id, b"k"),
+        mysql::mudu_put_many(session_id, &[(b"k".to_vec(), b"v".to_vec())]),
+        mysql::mudu_delete_many(session_id, &[b"k".to_vec()]),
+    ] {
+        let err = match result {
+            Ok(_) => panic!("expected entity not found error"),
+            Err(e) => e,
+        };
+        assert_eq!(err.ec(), ErrorCode::EntityNotFound);
+    }
+    Ok(())
+}
+
+#[test]
+fn kv_atomic_updates_report_entity_not_found_for_unknown_session() -> RS<()> {
+    let _guard = config::test_lock().lock()?;
+    config::reset_db_path_override_for_test();
+    let session_id = 0xDEAD_BEEF_u128;
+    for err in [
+        mysql::mudu_cas(session_id, b"k", None, b"v").err(),
+        mysql::mudu_incr(session_id, b"k", 1).err(),
+        mysql::mudu_put_if_absent(session_id, b"k", b"v").err(),
+    ] {
+        let Some(err) = err else {
+            panic!("expected entity not found error");
+        };
+        assert_eq!(err.ec(), ErrorCode::EntityNotFound);
+    }
+    Ok(())
+}
+
+#[test]
+fn kv_put_with_ttl_is_not_implemented() -> RS<()> {
+    let _guard = config::test_lock().lock()?;
+    config::reset_db_path_override_for_test();
+    let Err(err) = mysql::mudu_put_with_ttl(1, b"k", b"v", Duration::from_secs(1)) else {
+        panic!("expected not implemented error");
+    };
+    assert_eq!(err.ec(), ErrorCode::NotImplemented);
+    Ok(())
+}
@@ -2,7 +2,7 @@
 
 use crate::config;
 use crate::result_set::LocalResultSet;
-use crate::sql::{datum_type_for_id, replace_placeholders};
+use crate::sql::{datum_type_for_id, kv_range_sql, replace_placeholders};
 use crate::state;
 use lazy_static::lazy_static;
 use mudu::common::id::OID;
@@ -11,6 +11,8 @@ use mudu::error::ErrorCode;
 use mudu::mudu_error;
 use mudu_contract::database::entity::Entity;
 use mudu_contract::database::entity_set::RecordSet;
+use mudu_contract::database::kv_atomic::{self, KvUpdate};
+use mudu_contract::database::kv_range::{KvRangeOptions, KvRangePage};
 use mudu_contract::database::sql_params::SQLParams;
 use mudu_contract::database::sql_stmt::SQLStmt;
 use mudu_contract::tuple::datum_desc::DatumDesc;
@@ -26,6 +28,7 @@ use postgres::{Client, NoTls, Row};
 use scc::HashMap as SccHashMap;
 use std::collections::HashMap;
 use std::sync::Arc;
+use std::time::Duration;
 use tokio_postgres::{Client as AsyncClient, NoTls as AsyncNoTls};
 
 type PgClientRef = Arc<SMutex<Client>>;
@@ -224,66 +227,226 @@ pub async fn mudu_put_async(session_id: OID, key: &[u8], value: &[u8]) -> RS<()>
     Ok(())
 }
 
+/// Rejects puts with a TTL: the PostgreSQL backend stores values without expiry.
+pub fn mudu_put_with_ttl(session_id: OID, _key: &[u8], _value: &[u8], _ttl: Duration) -> RS<()> {
+    Err(mudu_error!(
+        ErrorCode::NotImplemented,
+        format!("postgres session {} does not support kv ttl", session_id)
+    ))
+}
+
+/// Asynchronous version of [`mudu_put_with_ttl`].
+pub async fn mudu_put_with_ttl_async(
+    session_id: OID,
+    key: &[u8],
+    value: &[u8],
+    ttl: Duration,
+) -> RS<()> {
+    mudu_put_with_ttl(session_id, key, value, ttl)
+}
+
+/// Removes a key from a PostgreSQL session.
+pub fn mudu_delete(session_id: OID, key: &[u8]) -> RS<()> {
+    with_session(session_id, |client| {
+        client
+            .execute("DELETE FROM mudu_kv WHERE k = $1", &[&key])
+            .map_err(|e| mudu_error!(ErrorCode::Database, "postgres kv delete error", e))?;
+        Ok(())
+    })
+}
+
+/// Asynchronous version of [`mudu_delete`].
+pub async fn mudu_delete_async(session_id: OID, key: &[u8]) -> RS<()> {
+    let _trace = mudu_utils::task_trace!();
+    let session = with_async_session(session_id).await?;
+    session
+        .client
+        .execute("DELETE FROM mudu_kv WHERE k = $1", &[&key])
+        .await
+        .map_err(|e| mudu_error!(ErrorCode::Database, "postgres kv delete error", e))?;
+    Ok(())
+}
+
+/// Stores several values in a PostgreSQL session with a single statement.
+pub fn mudu_put_many(session_id: OID, items: &[(Vec<u8>, Vec<u8>)]) -> RS<()> {
+    let (keys, values) = split_last_write_wins(items);
+    with_session(session_id, |client| {
+        if keys.is_empty() {
+            return Ok(());
+        }
+        client
+            .execute(PUT_MANY_SQL, &[&keys, &values])
+            .map_err(|e| mudu_error!(ErrorCode::Database, "postgres kv put many error", e))?;
+        Ok(())
+    })
+}
+
+/// Asynchronous version of [`mudu_put_many`].
+pub async fn mudu_put_many_async(session_id: OID, items: &[(Vec<u8>, Vec<u8>)]) -> RS<()> {
+    let _trace = mudu_utils::task_trace!();
+    let (keys, values) = split_last_write_wins(items);
+    let session = with_async_session(session_id).await?;
+    if keys.is_empty() {
+        return Ok(());
+    }
+    session
+        .client
+        .execute(PUT_MANY_SQL, &[&keys, &values])
+        .await
+        .map_err(|e| mudu_error!(ErrorCode::Database, "postgres kv put many error", e))?;
+    Ok(())
+}
+
+/// Removes several keys from a PostgreSQL session with a single statement.
+pub fn mudu_delete_many(session_id: OID, keys: &[Vec<u8>]) -> RS<()> {
+    with_session(session_id, |client| {
+        if keys.is_empty() {
+            return Ok(());
+        }
+        client
+            .execute("DELETE FROM mudu_kv WHERE k = ANY($1)", &[&keys])
+            .map_err(|e| mudu_error!(ErrorCode::Database, "postgres kv delete many error", e))?;
+        Ok(())
+    })
+}
+
+/// Asynchronous version of [`mudu_delete_many`].
+pub async fn mudu_delete_many_async(session_id: OID, keys: &[Vec<u8>]) -> RS<()> {
+    let _trace = mudu_utils::task_trace!();
+    let session = with_async_session(session_id).await?;
+    if keys.is_empty() {
+        return Ok(());
+    }
+    session
+        .client
+        .execute("DELETE FROM mudu_kv WHERE k = ANY($1)", &[&keys])
+        .await
+        .map_err(|e| mudu_error!(ErrorCode::Database, "postgres kv delete many error", e))?;
+    Ok(())
+}
+
+/// Compares and swaps a value in a PostgreSQL session.
+pub fn mudu_cas(session_id: OID, key: &[u8], expected: Option<&[u8]>, value: &[u8]) -> RS<bool> {
+    with_session(session_id, |client| {
+        update(client, key, |current| {
+            Ok(kv_atomic::compare_and_swap(current, expected, value))
+        })
+    })
+}
+
+/// Asynchronous version of [`mudu_cas`].
+pub async fn mudu_cas_async(
+    session_id: OID,
+    key: &[u8],
+    expected: Option<&[u8]>,
+    value: &[u8],
+) -> RS<bool> {
+    let _trace = mudu_utils::task_trace!();
+    let session = with_async_session(session_id).await?;
+    update_async(&session.client, key, |current| {
+        Ok(kv_atomic::compare_and_swap(current, expected, value))
+    })
+    .await
+}
+
+/// Increments a counter in a PostgreSQL session.
+pub fn mudu_incr(session_id: OID, key: &[u8], delta: i64) -> RS<i64> {
+    with_session(session_id, |client| {
+        update(client, key, |current| kv_atomic::increment(current, delta))
+    })
+}
+
+/// Asynchronous version of [`mudu_incr`].
+pub async fn mudu_incr_async(session_id: OID, key: &[u8], delta: i64) -> RS<i64> {
+    let _trace = mudu_utils::task_trace!();
+    let session = with_async_session(session_id).await?;
+    update_async(&session.client, key, |current| {
+        kv_atomic::increment(current, delta)
+    })
+    .await
+}
+
+/// Stores a value in a PostgreSQL session unless the key exists.
+pub fn mudu_put_if_absent(session_id: OID, key: &[u8], value: &[u8]) -> RS<bool> {
+    with_session(session_id, |client| {
+        update(client, key, |current| {
+            Ok(kv_atomic::put_if_absent(current, value))
+        })
+    })
+}
+
+/// Asynchronous version of [`mudu_put_if_absent`].
+pub async fn mudu_put_if_absent_async(session_id: OID, key: &[u8], value: &[u8]) -> RS<bool> {
+    let _trace = mudu_utils::task_trace!();
+    let session = with_async_session(session_id).await?;
+    update_async(&session.client, key, |current| {
+        Ok(kv_atomic::put_if_absent(current, value))
+    })
+    .await
+}
+
 /// Scans a range of keys in a PostgreSQL session.
 pub fn mudu_range(
     session_id: OID,
     start_key: &[u8],
     end_key: &[u8],
 ) -> RS<Vec<(Vec<u8>, Vec<u8>)>> {
+    Ok(mudu_range_page(session_id, start_key, end_key, &KvRangeOptions::default())?.into_items())
+}
+
+/// Asynchronous version of [`mudu_range`].
+pub async fn mudu_range_async(
+    session_id: OID,
+    start_key: &[u8],
+    end_key: &[u8],
+) -> RS<Vec<(Vec<u8>, Vec<u8>)>> {
+    let page =
+        mudu_range_page_async(session_id, start_key, end_key, &KvRangeOptions::default()).await?;
+    Ok(page.into_items())
+}
+
+/// Scans one page of a range of keys in a PostgreSQL session.
+pub fn mudu_range_page(
+    session_id: OID,
+    start_key: &[u8],
+    end_key: &[u8],
+    options: &KvRangeOptions,
+) -> RS<KvRangePage> {
     with_session(session_id, |client| {
+        let Some((start_key, end_key)) = options.bounds(start_key, end_key) else {
+            return Ok(KvRangePage::default());
+        };
+        let sql = kv_range_sql(!end_key.is_empty(), options, |idx| format!("${}", idx));
         let rows = if end_key.is_empty() {
-            client
-                .query(
-                    "SELECT k, v FROM mudu_kv WHERE k >= $1 ORDER BY k ASC",
-                    &[&start_key],
-                )
-                .map_err(|e| mudu_error!(ErrorCode::Database, "postgres kv range error", e))?
+            client.query(&sql, &[&start_key])
         } else {
-            client
-                .query(
-                    "SELECT k, v FROM mudu_kv WHERE k >= $1 AND k < $2 ORDER BY k ASC",
-                    &[&start_key, &end_key],
-                )
-                .map_err(|e| mudu_error!(ErrorCode::Database, "postgres kv range error", e))?
-        };
-        Ok(rows
-            .into_iter()
-            .map(|row| (row.get::<usize, Vec<u8>>(0), row.get::<usize, Vec<u8>>(1)))
-            .collect())
+            client.query(&sql, &[&start_key, &end_key])
+        }
+        .map_err(|e| mudu_error!(ErrorCode::Database, "postgres kv range error", e))?;
+        Ok(options.page(kv_rows(rows)))
     })
 }
 
-/// Asynchronous version of [`mudu_range`].
-pub async fn mudu_range_async(
+/// Asynchronous version of [`mudu_range_page`].
+pub async fn mudu_range_page_async(
     session_id: OID,
     start_key: &[u8],
     end_key: &[u8],
-) -> RS<Vec<(Vec<u8>, Vec<u8>)>> {
+    options: &KvRangeOptions,
+) -> RS<KvRangePage> {
     let _trace = mudu_utils::task_trace!();
     let session = with_async_session(session_id).await?;
+    let Some((start_key, end_key)) = options.bounds(start_key, end_key) else {
+        return Ok(KvRangePage::default());
+    };
+    let sql = kv_range_sql(!end_key.is_empty(), options, |idx| format!("${}", idx));
     let rows = if end_key.is_empty() {
-        session
-            .client
-            .query(
-                "SELECT k, v FROM mudu_kv WHERE k >= $1 ORDER BY k ASC",
-                &[&start_key],
-            )
-            .await
-            .map_err(|e| mudu_error!(ErrorCode::Database, "postgres kv range error", e))?
+        session.client.query(&sql, &[&start_key]).await
     } else {
-        session
-            .client
-            .query(
-                "SELECT k, v FROM mudu_kv WHERE k >= $1 AND k < $2 ORDER BY k ASC",
-                &[&start_key, &end_key],
-            )
-            .await
-            .map_err(|e| mudu_error!(ErrorCode::Database, "postgres kv range error", e))?
-    };
-    Ok(rows
-        .into_iter()
-        .map(|row| (row.get::<usize, Vec<u8>>(0), row.get::<usize, Vec<u8>>(1)))
-        .collect())
+        session.client.query(&sql, &[&start_key, &end_key]).await
+    }
+    .map_err(|e| mudu_error!(ErrorCode::Database, "postgres kv range error", e))?;
+    Ok(options.page(kv_rows(rows)))
 }
 
 /// Executes a query on a PostgreSQL session and returns the resulting record set.
@@ -405,6 +568,102 @@ fn ensure_session_exists(session_id: OID) -> RS<()> {
     }
 }
 
+const PUT_MANY_SQL: &str = "INSERT INTO mudu_kv(k, v)
+     SELECT * FROM unnest($1::bytea[], $2::bytea[])
+     ON CONFLICT(k) DO UPDATE SET v = EXCLUDED.v";
+
+fn kv_rows(rows: Vec<Row>) -> Vec<(Vec<u8>, Vec<u8>)> {
+    rows.into_iter()
+        .map(|row| (row.get::<usize, Vec<u8>>(0), row.get::<usize, Vec<u8>>(1)))
+        .collect()
+}
+
+// `ON CONFLICT DO UPDATE` rejects a key that appears twice in one statement,
+// so keep only the last value written for each key.
+fn split_last_write_wins(items: &[(Vec<u8>, Vec<u8>)]) -> (Vec<&[u8]>, Vec<&[u8]>) {
+    let mut position = HashMap::with_capacity(items.len());
+    let mut keys: Vec<&[u8]> = Vec::with_capacity(items.len());
+    let mut values: Vec<&[u8]> = Vec::with_capacity(items.len());
+    for (key, value) in items {
+        match position.get(key.as_slice()) {
+            Some(&index) => values[index] = value.as_slice(),
+            None => {
+                position.insert(key.as_slice(), keys.len());
+                keys.push(key.as_slice());
+                values.push(value.as_slice());
+            }
+        }
+    }
+    (keys, values)
+}
+
+const INSERT_IF_ABSENT_SQL: &str =
+    "INSERT INTO mudu_kv(k, v) VALUES($1, $2) ON CONFLICT(k) DO NOTHING";
+
+const UPDATE_IF_EQUAL_SQL: &str = "UPDATE mudu_kv SET v = $2 WHERE k = $1 AND v = $3";
+
+// Atomic updates are optimistic: the write only lands while the key still
+// holds the value `f` saw, otherwise the key is read again and `f` re-run.
+fn update<T, F>(client: &mut Client, key: &[u8], f: F) -> RS<T>
+where
+    F: Fn(Option<&[u8]>) -> RS<KvUpdate<T>>,
+{
+    loop {
+        let rows = client
+            .query("SELECT v FROM mudu_kv WHERE k = $1", &[&key])
+            .map_err(|e| mudu_error!(ErrorCode::Database, "postgres kv update read error", e))?;
+        let current = rows.first().map(|row| row.get::<usize, Vec<u8>>(0));
+        let (write, result) = f(current.as_deref())?;
+        let Some(write) = write else {
+            return Ok(result);
+        };
+        if current.as_ref() == Some(&write) {
+            return Ok(result);
+        }
+        let applied = match &current {
+            None => client.execute(INSERT_IF_ABSENT_SQL, &[&key, &write]),
+            Some(current) => client.execute(UPDATE_IF_EQUAL_SQL, &[&key, &write, current]),
+        }
+        .map_err(|e| mudu_error!(ErrorCode::Database, "postgres kv update write error", e))?;
+        if applied == 1 {
+            return Ok(result);
+        }
+    }
+}
+
+/// Asynchronous version of [`update`].
+async fn update_async<T, F>(client: &AsyncClient, key: &[u8], f: F) -> RS<T>
+where
+    F: Fn(Option<&[u8]>) -> RS<KvUpdate<T>>,
+{
+    loop {
+        let rows = client
+            .query("SELECT v FROM mudu_kv WHERE k = $1", &[&key])
+            .await
+            .map_err(|e| mudu_error!(ErrorCode::Database, "postgres kv update read error", e))?;
+        let current = rows.first().map(|row| row.get::<usize, Vec<u8>>(0));
+        let (write, result) = f(current.as_deref())?;
+        let Some(write) = write else {
+            return Ok(result);
+        };
+        if current.as_ref() == Some(&write) {
+            return Ok(result);
+        }
+        let applied = match &current {
+            None => client.execute(INSERT_IF_ABSENT_SQL, &[&key, &write]).await,
+            Some(current) => {
+                client
+                    .execute(UPDATE_IF_EQUAL_SQL, &[&key, &write, current])
+                    .await
+            }
+        }
+        .map_err(|e| mudu_error!(ErrorCode::Database, "postgres kv update write error", e))?;
+        if applied == 1 {
+            return Ok(result);
+        }
+    }
+}
+
 fn with_session<R, F>(session_id: OID, f: F) -> RS<R>
 where
     F: FnOnce(&mut Client) -> RS<R>,
@@ -79,3 +79,40 @@ fn mudu_close_reports_entity_not_found_for_unknown_session() -> RS<()> {
     assert_eq!(err.ec(), ErrorCode::EntityNotFound);
     Ok(())
 }
+
+#[test]
+fn kv_writes_report_entity_not_found_for_unknown_session() -> RS<()> {
+    let _guard = config::test_lock().lock()?;
+    config::reset_db_path_override_for_test();
+    let session_id = 0xDEAD_BEEF_u128;
+    for result in [
+        postgres::mudu_delete(session_id, b"k"),
+        postgres::mudu_put_many(session_id, &[(b"k".to_vec(), b"v".to_vec())]),
+        postgres::mudu_delete_many(session_id, &[b"k".to_vec()]),
+    ] {
+        let err = match result {
+            Ok(_) => panic!("expected entity not found error"),
+            Err(e) => e,
+        };
+        assert_eq!(err.ec(), ErrorCode::EntityNotFound);
+    }
+    Ok(())
+}
+
+#[test]
+fn kv_atomic_updates_report_entity_not_found_for_unknown_session() -> RS<()> {
+    let _guard = config::test_lock().lock()?;
+    config::reset_db_path_override_for_test();
+    let session_id = 0xDEAD_BEEF_u128;
+    for err in [
+        postgres::mudu_cas(session_id, b"k", None, b"v").err(),
+        postgres::mudu_incr(session_id, b"k", 1).err(),
+        postgres::mudu_put_if_absent(session_id, b"k", b"v").err(),
+    ] {
+        let Some(err) = err else {
+            panic!("expected entity not found error");
+        };
+        assert_eq!(err.ec(), ErrorCode::EntityNotFound);
+    }
+    Ok(())
+}
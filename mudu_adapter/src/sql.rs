@@ -3,6 +3,7 @@
 use mudu::common::result::RS;
 use mudu::error::ErrorCode;
 use mudu::mudu_error;
+use mudu_contract::database::kv_range::KvRangeOptions;
 use mudu_contract::database::sql_params::SQLParams;
 use mudu_contract::tuple::datum_desc::DatumDesc;
 use mudu_contract::tuple::tuple_field_desc::TupleFieldDesc;
@@ -173,3 +174,26 @@ pub fn replace_placeholders(sql_text: &str, params: &dyn SQLParams) -> RS<String
     out.push_str(&sql_text[start..]);
     Ok(out)
 }
+
+/// Builds the `mudu_kv` query reading one page of a range scan in scan order.
+/// The query binds the start key and, when `end_bounded`, the end key; the
+/// `placeholder` closure renders the 1-based bind parameter of the dialect.
+pub fn kv_range_sql(
+    end_bounded: bool,
+    options: &KvRangeOptions,
+    placeholder: impl Fn(usize) -> String,
+) -> String {
+    let mut sql = format!("SELECT k, v FROM mudu_kv WHERE k >= {}", placeholder(1));
+    if end_bounded {
+        sql.push_str(&format!(" AND k < {}", placeholder(2)));
+    }
+    sql.push_str(if options.reverse() {
+        " ORDER BY k DESC"
+    } else {
+        " ORDER BY k ASC"
+    });
+    if let Some(limit) = options.fetch_limit() {
+        sql.push_str(&format!(" LIMIT {}", limit));
+    }
+    sql
+}
@@ -11,6 +11,7 @@ use mudu::error::ErrorCode;
 use mudu::mudu_error;
 use mudu_contract::database::entity::Entity;
 use mudu_contract::database::entity_set::RecordSet;
+use mudu_contract::database::kv_range::{KvRangeOptions, KvRangePage};
 use mudu_contract::database::sql_params::SQLParams;
 use mudu_contract::database::sql_stmt::SQLStmt;
 use mudu_contract::tuple::tuple_field_desc::TupleFieldDesc;
@@ -19,7 +20,7 @@ use mudu_sys::time::system_time_now;
 use rusqlite::Connection;
 use rusqlite::params_from_iter;
 use std::sync::Arc;
-use std::time::UNIX_EPOCH;
+use std::time::{Duration, UNIX_EPOCH};
 
 /// Opens a SQLite connection using the configured database path.
 pub fn open_connection() -> RS<Connection> {
@@ -120,6 +121,95 @@ pub async fn mudu_put_async(session_id: OID, key: &[u8], value: &[u8]) -> RS<()>
     mudu_sys::task::async_::spawn_blocking(move || crate::kv::put(session_id, &key, &value)).await?
 }
 
+/// Rejects puts with a TTL: the SQLite backend stores values without expiry.
+pub fn mudu_put_with_ttl(session_id: OID, _key: &[u8], _value: &[u8], _ttl: Duration) -> RS<()> {
+    Err(mudu_error!(
+        ErrorCode::NotImplemented,
+        format!("sqlite session {} does not support kv ttl", session_id)
+    ))
+}
+
+/// Asynchronous version of [`mudu_put_with_ttl`].
+pub async fn mudu_put_with_ttl_async(
+    session_id: OID,
+    key: &[u8],
+    value: &[u8],
+    ttl: Duration,
+) -> RS<()> {
+    mudu_put_with_ttl(session_id, key, value, ttl)
+}
+
+/// Removes a key from a SQLite session.
+pub fn mudu_delete(session_id: OID, key: &[u8]) -> RS<()> {
+    crate::kv::delete(session_id, key)
+}
+
+/// Asynchronous version of [`mudu_delete`].
+pub async fn mudu_delete_async(session_id: OID, key: &[u8]) -> RS<()> {
+    let _trace = mudu_utils::task_trace!();
+    crate::kv::delete_async(session_id, key).await
+}
+
+/// Stores several values in a SQLite session atomically.
+pub fn mudu_put_many(session_id: OID, items: &[(Vec<u8>, Vec<u8>)]) -> RS<()> {
+    crate::kv::put_many(session_id, items)
+}
+
+/// Asynchronous version of [`mudu_put_many`].
+pub async fn mudu_put_many_async(session_id: OID, items: &[(Vec<u8>, Vec<u8>)]) -> RS<()> {
+    let _trace = mudu_utils::task_trace!();
+    crate::kv::put_many_async(session_id, items).await
+}
+
+/// Removes several keys from a SQLite session atomically.
+pub fn mudu_delete_many(session_id: OID, keys: &[Vec<u8>]) -> RS<()> {
+    crate::kv::delete_many(session_id, keys)
+}
+
+/// Asynchronous version of [`mudu_delete_many`].
+pub async fn mudu_delete_many_async(session_id: OID, keys: &[Vec<u8>]) -> RS<()> {
+    let _trace = mudu_utils::task_trace!();
+    crate::kv::delete_many_async(session_id, keys).await
+}
+
+/// Compares and swaps a value in a SQLite session.
+pub fn mudu_cas(session_id: OID, key: &[u8], expected: Option<&[u8]>, value: &[u8]) -> RS<bool> {
+    crate::kv::cas(session_id, key, expected, value)
+}
+
+/// Asynchronous version of [`mudu_cas`].
+pub async fn mudu_cas_async(
+    session_id: OID,
+    key: &[u8],
+    expected: Option<&[u8]>,
+    value: &[u8],
+) -> RS<bool> {
+    let _trace = mudu_utils::task_trace!();
+    crate::kv::cas_async(session_id, key, expected, value).await
+}
+
+/// Increments a counter in a SQLite session.
+pub fn mudu_incr(session_id: OID, key: &[u8], delta: i64) -> RS<i64> {
+    crate::kv::incr(session_id, key, delta)
+}
+
+/// Asynchronous version of [`mudu_incr`].
+pub async fn mudu_incr_async(session_id: OID, key: &[u8], delta: i64) -> RS<i64> {
+    let _trace = mudu_utils::task_trace!();
+    crate::kv::incr_async(session_id, key, delta).await
+}
+
+/// Stores a value in a SQLite session unless the key exists.
+pub fn mudu_put_if_absent(session_id: OID, key: &[u8], value: &[u8]) -> RS<bool> {
+    crate::kv::put_if_absent(session_id, key, value)
+}
+
+/// Asynchronous version of [`mudu_put_if_absent`].
+pub async fn mudu_put_if_absent_async(session_id: OID, key: &[u8], value: &[u8]) -> RS<bool> {
+    let _trace = mudu_utils::task_trace!();
+    crate::kv::put_if_absent_async(session_id, key, value).await
+}
+
 /// Scans a range of keys in a SQLite session.
 pub fn mudu_range(
     session_id: OID,
@@ -144,6 +234,27 @@ pub async fn mudu_range_async(
     .await?
 }
 
+/// Scans one page of a range of keys in a SQLite session.
+pub fn mudu_range_page(
+    session_id: OID,
+    start_key: &[u8],
+    end_key: &[u8],
+    options: &KvRangeOptions,
+) -> RS<KvRangePage> {
+    crate::kv::range_page(session_id, start_key, end_key, options)
+}
+
+/// Asynchronous version of [`mudu_range_page`].
+pub async fn mudu_range_page_async(
+    session_id: OID,
+    start_key: &[u8],
+    end_key: &[u8],
+    options: &KvRangeOptions,
+) -> RS<KvRangePage> {
+    let _trace = mudu_utils::task_trace!();
+    crate::kv::range_page_async(session_id, start_key, end_key, options).await
+}
+
 /// Executes a query on a SQLite session and returns the resulting record set.
 pub fn mudu_query<R: Entity>(
     oid: OID,
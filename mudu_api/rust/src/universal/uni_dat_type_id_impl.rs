@@ -9,6 +9,9 @@ use mudu_type::dat_type_id::DatTypeID;
 impl UniDatTypeId {
     pub fn uni_to(self) -> RS<DatTypeID> {
         let ty_id = match self {
+            Self::Bool => DatTypeID::Bool,
+            Self::I8 => DatTypeID::I8,
+            Self::I16 => DatTypeID::I16,
             Self::I32 => DatTypeID::I32,
             Self::I64 => DatTypeID::I64,
             Self::OID => DatTypeID::U128,
@@ -24,6 +27,8 @@ impl UniDatTypeId {
             Self::Time => DatTypeID::Time,
             Self::Timestamp => DatTypeID::Timestamp,
             Self::TimestampTz => DatTypeID::TimestampTz,
+            Self::Uuid => DatTypeID::Uuid,
+            Self::Json => DatTypeID::Json,
             _ => {
                 return Err(mudu_error!(
                     ErrorCode::InvalidType,
@@ -36,6 +41,9 @@ impl UniDatTypeId {
 
     pub fn uni_from(ty: DatTypeID) -> RS<Self> {
         let uni_ty = match ty {
+            DatTypeID::Bool => Self::Bool,
+            DatTypeID::I8 => Self::I8,
+            DatTypeID::I16 => Self::I16,
             DatTypeID::I32 => Self::I32,
             DatTypeID::I64 => Self::I64,
             DatTypeID::U128 => Self::OID,
@@ -51,6 +59,8 @@ impl UniDatTypeId {
             DatTypeID::Time => Self::Time,
             DatTypeID::Timestamp => Self::Timestamp,
             DatTypeID::TimestampTz => Self::TimestampTz,
+            DatTypeID::Uuid => Self::Uuid,
+            DatTypeID::Json => Self::Json,
         };
         Ok(uni_ty)
     }
@@ -75,6 +85,9 @@ mod tests {
     #[test]
     fn supported_uni_to_mudu_roundtrip() {
         let cases = [
+            (UniDatTypeId::Bool, DatTypeID::Bool),
+            (UniDatTypeId::I8, DatTypeID::I8),
+            (UniDatTypeId::I16, DatTypeID::I16),
             (UniDatTypeId::I32, DatTypeID::I32),
             (UniDatTypeId::I64, DatTypeID::I64),
             (UniDatTypeId::OID, DatTypeID::U128),
@@ -90,6 +103,8 @@ mod tests {
             (UniDatTypeId::Time, DatTypeID::Time),
             (UniDatTypeId::Timestamp, DatTypeID::Timestamp),
             (UniDatTypeId::TimestampTz, DatTypeID::TimestampTz),
+            (UniDatTypeId::Uuid, DatTypeID::Uuid),
+            (UniDatTypeId::Json, DatTypeID::Json),
         ];
         for (uni, dat) in cases {
             assert_eq!(uni.uni_to().unwrap(), dat);
@@ -100,6 +115,9 @@ mod tests {
     #[test]
     fn supported_uni_from_covers_all_dat_type_ids() {
         let cases = [
+            DatTypeID::Bool,
+            DatTypeID::I8,
+            DatTypeID::I16,
             DatTypeID::I32,
             DatTypeID::I64,
             DatTypeID::U128,
@@ -115,6 +133,8 @@ mod tests {
             DatTypeID::Time,
             DatTypeID::Timestamp,
             DatTypeID::TimestampTz,
+            DatTypeID::Uuid,
+            DatTypeID::Json,
         ];
         for dat in cases {
             assert!(UniDatTypeId::uni_from(dat).is_ok());
@@ -124,11 +144,8 @@ mod tests {
     #[test]
     fn unsupported_uni_to_returns_invalid_type() {
         let unsupported = [
-            UniDatTypeId::Bool,
             UniDatTypeId::U8,
-            UniDatTypeId::I8,
             UniDatTypeId::U16,
-            UniDatTypeId::I16,
             UniDatTypeId::U32,
             UniDatTypeId::U64,
             UniDatTypeId::Char,
@@ -30,7 +30,8 @@ impl UniDatType {
             UniDatType::Scalar(scalar) => scalar_with_params_to(scalar, params)?,
             UniDatType::Array(inner) => {
                 let ty = *inner;
-                let inner_ty = ty.uni_to()?;
+                // Type parameters such as `VARCHAR(10)[]` belong to the element type.
+                let inner_ty = ty.uni_to_with_params(params)?;
                 let array_kind = DTPKind::Array(Box::new(DTPArray::new(inner_ty)));
                 DatType::from_id_param(DatTypeID::Array, Some(array_kind))
             }
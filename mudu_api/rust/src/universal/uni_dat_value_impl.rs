@@ -6,8 +6,10 @@ use mudu::data_type::numeric::Numeric;
 use mudu::data_type::time::TimeValue;
 use mudu::data_type::timestamp::TimestampValue;
 use mudu::data_type::timestamptz::TimestampTzValue;
+use mudu::data_type::uuid::UuidValue;
 use mudu::error::ErrorCode;
 use mudu::mudu_error;
+use mudu::utils::json::{JsonValue, from_json_str};
 use mudu_type::dat_type_id::DatTypeID;
 use mudu_type::dat_value::DatValue;
 use mudu_type::datum::DatumDyn;
@@ -16,36 +18,21 @@ impl UniDatValue {
     pub fn uni_to(self) -> RS<DatValue> {
         let value = match self {
             UniDatValue::Scalar(value) => match value {
-                UniScalarValue::Bool(_) => {
-                    return Err(mudu_error!(
-                        ErrorCode::InvalidType,
-                        "scalar bool is not supported"
-                    ));
-                }
+                UniScalarValue::Bool(v) => DatValue::from_bool(v),
                 UniScalarValue::U8(_) => {
                     return Err(mudu_error!(
                         ErrorCode::InvalidType,
                         "scalar u8 is not supported"
                     ));
                 }
-                UniScalarValue::I8(_) => {
-                    return Err(mudu_error!(
-                        ErrorCode::InvalidType,
-                        "scalar i8 is not supported"
-                    ));
-                }
+                UniScalarValue::I8(v) => DatValue::from_i8(v as i8),
                 UniScalarValue::U16(_) => {
                     return Err(mudu_error!(
                         ErrorCode::InvalidType,
                         "scalar u16 is not supported"
                     ));
                 }
-                UniScalarValue::I16(_) => {
-                    return Err(mudu_error!(
-                        ErrorCode::InvalidType,
-                        "scalar i16 is not supported"
-                    ));
-                }
+                UniScalarValue::I16(v) => DatValue::from_i16(v),
                 UniScalarValue::U32(_) => {
                     return Err(mudu_error!(
                         ErrorCode::InvalidType,
@@ -111,6 +98,22 @@ impl UniDatValue {
                         )
                     })?,
                 ),
+                UniScalarValue::Uuid(v) => {
+                    DatValue::from_uuid(UuidValue::parse(v.as_str()).map_err(|e| {
+                        mudu_error!(
+                            ErrorCode::TypeConversionFailed,
+                            format!("invalid uuid {}", e)
+                        )
+                    })?)
+                }
+                UniScalarValue::Json(v) => {
+                    DatValue::from_json(from_json_str::<JsonValue>(v.as_str()).map_err(|e| {
+                        mudu_error!(
+                            ErrorCode::TypeConversionFailed,
+                            format!("invalid json {}", e)
+                        )
+                    })?)
+                }
             },
             UniDatValue::Array(inner) => {
                 let mut vec = Vec::with_capacity(inner.len());
@@ -136,6 +139,15 @@ impl UniDatValue {
     pub fn uni_from(dat_value: DatValue) -> RS<UniDatValue> {
         let id = dat_value.dat_type_id()?;
         let mu_v = match id {
+            DatTypeID::Bool => {
+                UniDatValue::from_scalar(UniScalarValue::Bool(*dat_value.expect_bool()))
+            }
+            DatTypeID::I8 => {
+                UniDatValue::from_scalar(UniScalarValue::I8(*dat_value.expect_i8() as u8))
+            }
+            DatTypeID::I16 => {
+                UniDatValue::from_scalar(UniScalarValue::I16(*dat_value.expect_i16()))
+            }
             DatTypeID::I32 => {
                 UniDatValue::from_scalar(UniScalarValue::I32(*dat_value.expect_i32()))
             }
@@ -178,6 +190,12 @@ impl UniDatValue {
                     .format(6)
                     .map_err(|e| mudu_error!(ErrorCode::TypeConversionFailed, e))?,
             )),
+            DatTypeID::Uuid => {
+                UniDatValue::from_scalar(UniScalarValue::Uuid(dat_value.expect_uuid().format()))
+            }
+            DatTypeID::Json => {
+                UniDatValue::from_scalar(UniScalarValue::Json(dat_value.expect_json().to_string()))
+            }
             DatTypeID::Array => {
                 let array = dat_value.into_array();
                 let mut vec = Vec::with_capacity(array.len());
@@ -237,16 +255,22 @@ mod tests {
         assert_scalar_uni_to_from_roundtrip(UniDatValue::Scalar(UniScalarValue::from_string(
             "hello".to_string(),
         )));
+        assert_scalar_uni_to_from_roundtrip(UniDatValue::Scalar(UniScalarValue::from_bool(true)));
+        assert_scalar_uni_to_from_roundtrip(UniDatValue::Scalar(UniScalarValue::from_i8(0xf8)));
+        assert_scalar_uni_to_from_roundtrip(UniDatValue::Scalar(UniScalarValue::from_i16(-16)));
+        assert_scalar_uni_to_from_roundtrip(UniDatValue::Scalar(UniScalarValue::from_uuid(
+            "123e4567-e89b-12d3-a456-426614174000".to_string(),
+        )));
+        assert_scalar_uni_to_from_roundtrip(UniDatValue::Scalar(UniScalarValue::from_json(
+            r#"{"a":[1,"b",null]}"#.to_string(),
+        )));
     }
 
     #[test]
     fn unsupported_scalar_uni_to_returns_invalid_type() {
         let unsupported = vec![
-            UniDatValue::Scalar(UniScalarValue::from_bool(true)),
             UniDatValue::Scalar(UniScalarValue::from_u8(1)),
-            UniDatValue::Scalar(UniScalarValue::from_i8(1)),
             UniDatValue::Scalar(UniScalarValue::from_u16(1)),
-            UniDatValue::Scalar(UniScalarValue::from_i16(-1)),
             UniDatValue::Scalar(UniScalarValue::from_u32(1)),
             UniDatValue::Scalar(UniScalarValue::from_u64(1)),
             UniDatValue::Scalar(UniScalarValue::from_char('x')),
@@ -279,6 +303,8 @@ mod tests {
             UniDatValue::Scalar(UniScalarValue::from_timestamptz(
                 "2026-05-20 14:30:45".to_string(),
             )),
+            UniDatValue::Scalar(UniScalarValue::from_uuid("not-a-uuid".to_string())),
+            UniDatValue::Scalar(UniScalarValue::from_json("{\"a\":".to_string())),
         ];
         for value in invalid_values {
             let err = value.clone().uni_to().unwrap_err();
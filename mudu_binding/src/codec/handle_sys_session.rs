@@ -12,7 +12,10 @@ use mudu::common::id::OID;
 use mudu::common::result::RS;
 use mudu::common::serde_utils::{deserialize_from, serialize_to_vec};
 use mudu::error::MuduError;
+use mudu_contract::database::kv_namespace::{KvNamespaceOp, KvNamespaceOpResult};
+use mudu_contract::database::kv_range::{KvRangeOptions, KvRangePage};
 use std::mem::size_of;
+use std::time::Duration;
 
 const ERROR_MAGIC: &[u8; 4] = b"MERR";
 
@@ -49,6 +52,14 @@ fn read_bytes(input: &[u8], offset: &mut usize, len: usize) -> RS<Vec<u8>> {
     Ok(bytes)
 }
 
+fn read_i64_be(input: &[u8], offset: &mut usize) -> RS<i64> {
+    let bytes = read_bytes(input, offset, size_of::<i64>())?;
+    let bytes = bytes
+        .try_into()
+        .map_err(|_| mudu::mudu_error!(mudu::error::ErrorCode::Decode, "invalid i64 bytes"))?;
+    Ok(i64::from_be_bytes(bytes))
+}
+
 fn decode_error_result(input: &[u8]) -> RS<()> {
     if input.len() < ERROR_MAGIC.len() || &input[..ERROR_MAGIC.len()] != ERROR_MAGIC {
         return Ok(());
@@ -157,6 +168,43 @@ pub fn deserialize_put_param(input: &[u8]) -> RS<(Vec<u8>, Vec<u8>)> {
 }
 
 pub fn deserialize_session_put_param(input: &[u8]) -> RS<(OID, Vec<u8>, Vec<u8>)> {
+    let (session_id, key, value, _) = read_session_put_param(input)?;
+    Ok((session_id, key, value))
+}
+
+/// Encodes a put whose key expires `ttl` after the write commits. The TTL
+/// follows the plain put parameter, so a decoder that does not know it
+/// still reads the key and value.
+pub fn serialize_session_put_with_ttl_param(
+    session_id: OID,
+    key: &[u8],
+    value: &[u8],
+    ttl: Duration,
+) -> Vec<u8> {
+    let mut output = serialize_session_put_param(session_id, key, value);
+    let ttl_ms = ttl.as_millis().min(u64::MAX as u128) as u64;
+    output.extend_from_slice(&ttl_ms.to_be_bytes());
+    output
+}
+
+/// A decoded session put: session id, key, value and optional time to live.
+pub type SessionPutWithTtlParam = (OID, Vec<u8>, Vec<u8>, Option<Duration>);
+
+pub fn deserialize_session_put_with_ttl_param(input: &[u8]) -> RS<SessionPutWithTtlParam> {
+    let (session_id, key, value, mut offset) = read_session_put_param(input)?;
+    let ttl = if offset < input.len() {
+        let bytes = read_bytes(input, &mut offset, size_of::<u64>())?;
+        let bytes = bytes
+            .try_into()
+            .map_err(|_| mudu::mudu_error!(mudu::error::ErrorCode::Decode, "invalid ttl bytes"))?;
+        Some(Duration::from_millis(u64::from_be_bytes(bytes)))
+    } else {
+        None
+    };
+    Ok((session_id, key, value, ttl))
+}
+
+fn read_session_put_param(input: &[u8]) -> RS<(OID, Vec<u8>, Vec<u8>, usize)> {
     if input.len() < size_of::<u128>() {
         return Err(mudu::mudu_error!(
             mudu::error::ErrorCode::Decode,
@@ -170,7 +218,7 @@ pub fn deserialize_session_put_param(input: &[u8]) -> RS<(OID, Vec<u8>, Vec<u8>)
     let key = read_bytes(input, &mut offset, key_len)?;
     let value_len = read_u32_be(input, &mut offset)? as usize;
     let value = read_bytes(input, &mut offset, value_len)?;
-    Ok((session_id, key, value))
+    Ok((session_id, key, value, offset))
 }
 
 pub fn serialize_put_result() -> Vec<u8> {
@@ -213,6 +261,257 @@ pub fn deserialize_delete_result(input: &[u8]) -> RS<()> {
     deserialize_put_result(input)
 }
 
+pub fn serialize_put_many_param(items: &[(Vec<u8>, Vec<u8>)]) -> Vec<u8> {
+    serialize_session_put_many_param(0, items)
+}
+
+pub fn serialize_session_put_many_param(session_id: OID, items: &[(Vec<u8>, Vec<u8>)]) -> Vec<u8> {
+    let mut output = vec![0u8; size_of::<u128>()];
+    write_u128(&mut output, session_id);
+    write_u32_be(&mut output, items.len() as u32);
+    for (key, value) in items {
+        write_u32_be(&mut output, key.len() as u32);
+        output.extend_from_slice(key);
+        write_u32_be(&mut output, value.len() as u32);
+        output.extend_from_slice(value);
+    }
+    output
+}
+
+pub fn deserialize_put_many_param(input: &[u8]) -> RS<Vec<(Vec<u8>, Vec<u8>)>> {
+    Ok(deserialize_session_put_many_param(input)?.1)
+}
+
+/// A decoded multi-key put: session id and the key/value pairs to write.
+pub type SessionPutManyParam = (OID, Vec<(Vec<u8>, Vec<u8>)>);
+
+pub fn deserialize_session_put_many_param(input: &[u8]) -> RS<SessionPutManyParam> {
+    if input.len() < size_of::<u128>() {
+        return Err(mudu::mudu_error!(
+            mudu::error::ErrorCode::Decode,
+            "unexpected end of buffer"
+        ));
+    }
+    let mut offset = 0;
+    let session_id = read_u128(&input[offset..offset + size_of::<u128>()]);
+    offset += size_of::<u128>();
+    let count = read_u32_be(input, &mut offset)? as usize;
+    let mut items = Vec::with_capacity(count);
+    for _ in 0..count {
+        let key_len = read_u32_be(input, &mut offset)? as usize;
+        let key = read_bytes(input, &mut offset, key_len)?;
+        let value_len = read_u32_be(input, &mut offset)? as usize;
+        let value = read_bytes(input, &mut offset, value_len)?;
+        items.push((key, value));
+    }
+    Ok((session_id, items))
+}
+
+pub fn serialize_put_many_result() -> Vec<u8> {
+    serialize_put_result()
+}
+
+pub fn deserialize_put_many_result(input: &[u8]) -> RS<()> {
+    deserialize_put_result(input)
+}
+
+pub fn serialize_delete_many_param(keys: &[Vec<u8>]) -> Vec<u8> {
+    serialize_session_delete_many_param(0, keys)
+}
+
+pub fn serialize_session_delete_many_param(session_id: OID, keys: &[Vec<u8>]) -> Vec<u8> {
+    let mut output = vec![0u8; size_of::<u128>()];
+    write_u128(&mut output, session_id);
+    write_u32_be(&mut output, keys.len() as u32);
+    for key in keys {
+        write_u32_be(&mut output, key.len() as u32);
+        output.extend_from_slice(key);
+    }
+    output
+}
+
+pub fn deserialize_delete_many_param(input: &[u8]) -> RS<Vec<Vec<u8>>> {
+    Ok(deserialize_session_delete_many_param(input)?.1)
+}
+
+pub fn deserialize_session_delete_many_param(input: &[u8]) -> RS<(OID, Vec<Vec<u8>>)> {
+    if input.len() < size_of::<u128>() {
+        return Err(mudu::mudu_error!(
+            mudu::error::ErrorCode::Decode,
+            "unexpected end of buffer"
+        ));
+    }
+    let mut offset = 0;
+    let session_id = read_u128(&input[offset..offset + size_of::<u128>()]);
+    offset += size_of::<u128>();
+    let count = read_u32_be(input, &mut offset)? as usize;
+    let mut keys = Vec::with_capacity(count);
+    for _ in 0..count {
+        let key_len = read_u32_be(input, &mut offset)? as usize;
+        keys.push(read_bytes(input, &mut offset, key_len)?);
+    }
+    Ok((session_id, keys))
+}
+
+pub fn serialize_delete_many_result() -> Vec<u8> {
+    serialize_put_result()
+}
+
+pub fn deserialize_delete_many_result(input: &[u8]) -> RS<()> {
+    deserialize_put_result(input)
+}
+
+pub fn serialize_cas_param(key: &[u8], expected: Option<&[u8]>, value: &[u8]) -> Vec<u8> {
+    serialize_session_cas_param(0, key, expected, value)
+}
+
+pub fn serialize_session_cas_param(
+    session_id: OID,
+    key: &[u8],
+    expected: Option<&[u8]>,
+    value: &[u8],
+) -> Vec<u8> {
+    let mut output = serialize_session_get_param(session_id, key);
+    match expected {
+        Some(expected) => {
+            output.push(1);
+            write_u32_be(&mut output, expected.len() as u32);
+            output.extend_from_slice(expected);
+        }
+        None => output.push(0),
+    }
+    write_u32_be(&mut output, value.len() as u32);
+    output.extend_from_slice(value);
+    output
+}
+
+/// A decoded compare-and-swap: key, expected value and new value.
+pub type CasParam = (Vec<u8>, Option<Vec<u8>>, Vec<u8>);
+
+/// A decoded session compare-and-swap: session id followed by a [`CasParam`].
+pub type SessionCasParam = (OID, Vec<u8>, Option<Vec<u8>>, Vec<u8>);
+
+pub fn deserialize_cas_param(input: &[u8]) -> RS<CasParam> {
+    let (_, key, expected, value) = deserialize_session_cas_param(input)?;
+    Ok((key, expected, value))
+}
+
+pub fn deserialize_session_cas_param(input: &[u8]) -> RS<SessionCasParam> {
+    if input.len() < size_of::<u128>() {
+        return Err(mudu::mudu_error!(
+            mudu::error::ErrorCode::Decode,
+            "unexpected end of buffer"
+        ));
+    }
+    let mut offset = 0;
+    let session_id = read_u128(&input[offset..offset + size_of::<u128>()]);
+    offset += size_of::<u128>();
+    let key_len = read_u32_be(input, &mut offset)? as usize;
+    let key = read_bytes(input, &mut offset, key_len)?;
+    let expected = match read_bytes(input, &mut offset, 1)?[0] {
+        0 => None,
+        1 => {
+            let expected_len = read_u32_be(input, &mut offset)? as usize;
+            Some(read_bytes(input, &mut offset, expected_len)?)
+        }
+        _ => {
+            return Err(mudu::mudu_error!(
+                mudu::error::ErrorCode::Decode,
+                "invalid cas expected tag"
+            ));
+        }
+    };
+    let value_len = read_u32_be(input, &mut offset)? as usize;
+    let value = read_bytes(input, &mut offset, value_len)?;
+    Ok((session_id, key, expected, value))
+}
+
+pub fn serialize_cas_result(swapped: bool) -> Vec<u8> {
+    vec![u8::from(swapped)]
+}
+
+pub fn deserialize_cas_result(input: &[u8]) -> RS<bool> {
+    decode_error_result(input)?;
+    match input {
+        [0] => Ok(false),
+        [1] => Ok(true),
+        _ => Err(mudu::mudu_error!(
+            mudu::error::ErrorCode::Decode,
+            "invalid cas result"
+        )),
+    }
+}
+
+pub fn serialize_incr_param(key: &[u8], delta: i64) -> Vec<u8> {
+    serialize_session_incr_param(0, key, delta)
+}
+
+pub fn serialize_session_incr_param(session_id: OID, key: &[u8], delta: i64) -> Vec<u8> {
+    let mut output = serialize_session_get_param(session_id, key);
+    output.extend_from_slice(&delta.to_be_bytes());
+    output
+}
+
+pub fn deserialize_incr_param(input: &[u8]) -> RS<(Vec<u8>, i64)> {
+    let (_, key, delta) = deserialize_session_incr_param(input)?;
+    Ok((key, delta))
+}
+
+pub fn deserialize_session_incr_param(input: &[u8]) -> RS<(OID, Vec<u8>, i64)> {
+    if input.len() < size_of::<u128>() {
+        return Err(mudu::mudu_error!(
+            mudu::error::ErrorCode::Decode,
+            "unexpected end of buffer"
+        ));
+    }
+    let mut offset = 0;
+    let session_id = read_u128(&input[offset..offset + size_of::<u128>()]);
+    offset += size_of::<u128>();
+    let key_len = read_u32_be(input, &mut offset)? as usize;
+    let key = read_bytes(input, &mut offset, key_len)?;
+    let delta = read_i64_be(input, &mut offset)?;
+    Ok((session_id, key, delta))
+}
+
+pub fn serialize_incr_result(value: i64) -> Vec<u8> {
+    value.to_be_bytes().to_vec()
+}
+
+pub fn deserialize_incr_result(input: &[u8]) -> RS<i64> {
+    decode_error_result(input)?;
+    if input.len() != size_of::<i64>() {
+        return Err(mudu::mudu_error!(
+            mudu::error::ErrorCode::Decode,
+            "invalid incr result"
+        ));
+    }
+    read_i64_be(input, &mut 0)
+}
+
+pub fn serialize_put_if_absent_param(key: &[u8], value: &[u8]) -> Vec<u8> {
+    serialize_session_put_param(0, key, value)
+}
+
+pub fn serialize_session_put_if_absent_param(session_id: OID, key: &[u8], value: &[u8]) -> Vec<u8> {
+    serialize_session_put_param(session_id, key, value)
+}
+
+pub fn deserialize_put_if_absent_param(input: &[u8]) -> RS<(Vec<u8>, Vec<u8>)> {
+    deserialize_put_param(input)
+}
+
+pub fn deserialize_session_put_if_absent_param(input: &[u8]) -> RS<(OID, Vec<u8>, Vec<u8>)> {
+    deserialize_session_put_param(input)
+}
+
+pub fn serialize_put_if_absent_result(written: bool) -> Vec<u8> {
+    serialize_cas_result(written)
+}
+
+pub fn deserialize_put_if_absent_result(input: &[u8]) -> RS<bool> {
+    deserialize_cas_result(input)
+}
+
 pub fn serialize_range_param(start_key: &[u8], end_key: &[u8]) -> Vec<u8> {
     serialize_session_range_param(0, start_key, end_key)
 }
@@ -237,6 +536,38 @@ pub fn deserialize_range_param(input: &[u8]) -> RS<(Vec<u8>, Vec<u8>)> {
 }
 
 pub fn deserialize_session_range_param(input: &[u8]) -> RS<(OID, Vec<u8>, Vec<u8>)> {
+    let (session_id, start, end, _) = read_session_range_param(input)?;
+    Ok((session_id, start, end))
+}
+
+/// Encodes a range scan with options. The options follow the plain range
+/// parameter, so a decoder that does not know them still reads the bounds.
+pub fn serialize_session_range_page_param(
+    session_id: OID,
+    start_key: &[u8],
+    end_key: &[u8],
+    options: &KvRangeOptions,
+) -> Vec<u8> {
+    let mut output = serialize_session_range_param(session_id, start_key, end_key);
+    if !options.is_default() {
+        output.extend(serialize_to_vec(options).unwrap_or_default());
+    }
+    output
+}
+
+pub fn deserialize_session_range_page_param(
+    input: &[u8],
+) -> RS<(OID, Vec<u8>, Vec<u8>, KvRangeOptions)> {
+    let (session_id, start, end, offset) = read_session_range_param(input)?;
+    let options = if offset < input.len() {
+        deserialize_from::<KvRangeOptions>(&input[offset..])?.0
+    } else {
+        KvRangeOptions::default()
+    };
+    Ok((session_id, start, end, options))
+}
+
+fn read_session_range_param(input: &[u8]) -> RS<(OID, Vec<u8>, Vec<u8>, usize)> {
     if input.len() < size_of::<u128>() {
         return Err(mudu::mudu_error!(
             mudu::error::ErrorCode::Decode,
@@ -250,7 +581,7 @@ pub fn deserialize_session_range_param(input: &[u8]) -> RS<(OID, Vec<u8>, Vec<u8
     let start = read_bytes(input, &mut offset, start_len)?;
     let end_len = read_u32_be(input, &mut offset)? as usize;
     let end = read_bytes(input, &mut offset, end_len)?;
-    Ok((session_id, start, end))
+    Ok((session_id, start, end, offset))
 }
 
 pub fn serialize_open_param() -> Vec<u8> {
@@ -323,15 +654,73 @@ pub fn serialize_range_result(items: &[(Vec<u8>, Vec<u8>)]) -> Vec<u8> {
 }
 
 pub fn deserialize_range_result(input: &[u8]) -> RS<Vec<(Vec<u8>, Vec<u8>)>> {
+    decode_error_result(input)?;
+    read_range_items(input, &mut 0)
+}
+
+/// Encodes a page of a range scan: the plain range result followed by the
+/// continuation token, if any.
+pub fn serialize_range_page_result(page: &KvRangePage) -> Vec<u8> {
+    let mut output = serialize_range_result(page.items());
+    if let Some(token) = page.continuation() {
+        write_u32_be(&mut output, token.len() as u32);
+        output.extend_from_slice(token);
+    }
+    output
+}
+
+pub fn deserialize_range_page_result(input: &[u8]) -> RS<KvRangePage> {
     decode_error_result(input)?;
     let mut offset = 0;
-    let count = read_u32_be(input, &mut offset)? as usize;
+    let items = read_range_items(input, &mut offset)?;
+    let continuation = if offset < input.len() {
+        let token_len = read_u32_be(input, &mut offset)? as usize;
+        Some(read_bytes(input, &mut offset, token_len)?)
+    } else {
+        None
+    };
+    Ok(KvRangePage::new(items, continuation))
+}
+
+/// Encodes a KV namespace operation: the session id followed by the
+/// serialized operation.
+pub fn serialize_session_kv_namespace_param(session_id: OID, op: &KvNamespaceOp) -> Vec<u8> {
+    let mut output = vec![0u8; size_of::<u128>()];
+    write_u128(&mut output, session_id);
+    output.extend(serialize_to_vec(op).unwrap_or_default());
+    output
+}
+
+pub fn deserialize_session_kv_namespace_param(input: &[u8]) -> RS<(OID, KvNamespaceOp)> {
+    if input.len() < size_of::<u128>() {
+        return Err(mudu::mudu_error!(
+            mudu::error::ErrorCode::Decode,
+            "unexpected end of buffer"
+        ));
+    }
+    let session_id = read_u128(&input[..size_of::<u128>()]);
+    let (op, _) = deserialize_from::<KvNamespaceOp>(&input[size_of::<u128>()..])?;
+    Ok((session_id, op))
+}
+
+pub fn serialize_kv_namespace_result(result: &KvNamespaceOpResult) -> Vec<u8> {
+    serialize_to_vec(result).unwrap_or_default()
+}
+
+pub fn deserialize_kv_namespace_result(input: &[u8]) -> RS<KvNamespaceOpResult> {
+    decode_error_result(input)?;
+    let (result, _) = deserialize_from::<KvNamespaceOpResult>(input)?;
+    Ok(result)
+}
+
+fn read_range_items(input: &[u8], offset: &mut usize) -> RS<Vec<(Vec<u8>, Vec<u8>)>> {
+    let count = read_u32_be(input, offset)? as usize;
     let mut items = Vec::with_capacity(count);
     for _ in 0..count {
-        let key_len = read_u32_be(input, &mut offset)? as usize;
-        let key = read_bytes(input, &mut offset, key_len)?;
-        let value_len = read_u32_be(input, &mut offset)? as usize;
-        let value = read_bytes(input, &mut offset, value_len)?;
+        let key_len = read_u32_be(input, offset)? as usize;
+        let key = read_bytes(input, offset, key_len)?;
+        let value_len = read_u32_be(input, offset)? as usize;
+        let value = read_bytes(input, offset, value_len)?;
         items.push((key, value));
     }
     Ok(items)
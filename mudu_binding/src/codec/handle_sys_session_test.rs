@@ -87,6 +87,31 @@ fn put_param_roundtrip_and_truncation() {
     assert_eq!(err.ec(), ErrorCode::Decode);
 }
 
+#[test]
+fn put_with_ttl_param_roundtrip() {
+    let ttl = Duration::from_millis(2500);
+    let payload = serialize_session_put_with_ttl_param(0x42u128, b"k", b"v", ttl);
+    let (sid, key, value, got) = deserialize_session_put_with_ttl_param(&payload).unwrap();
+    assert_eq!(
+        (sid, key.as_slice(), value.as_slice()),
+        (0x42, &b"k"[..], &b"v"[..])
+    );
+    assert_eq!(got, Some(ttl));
+    assert_eq!(
+        deserialize_session_put_param(&payload).unwrap(),
+        (0x42, b"k".to_vec(), b"v".to_vec())
+    );
+
+    let plain = serialize_session_put_param(0x42u128, b"k", b"v");
+    assert_eq!(
+        deserialize_session_put_with_ttl_param(&plain).unwrap().3,
+        None
+    );
+
+    let err = deserialize_session_put_with_ttl_param(&payload[..payload.len() - 1]).unwrap_err();
+    assert_eq!(err.ec(), ErrorCode::Decode);
+}
+
 #[test]
 fn put_result_ok_invalid_and_error() {
     let ok = serialize_put_result();
@@ -115,6 +140,79 @@ fn delete_param_and_result_roundtrip() {
     assert!(deserialize_delete_result(&ok).is_ok());
 }
 
+#[test]
+fn put_many_param_roundtrip_and_truncation() {
+    let items = vec![(b"a".to_vec(), b"1".to_vec()), (b"b".to_vec(), Vec::new())];
+    let payload = serialize_session_put_many_param(0x42u128, &items);
+    let (sid, got_items) = deserialize_session_put_many_param(&payload).unwrap();
+    assert_eq!(sid, 0x42u128);
+    assert_eq!(got_items, items);
+
+    let payload2 = serialize_put_many_param(&[]);
+    assert!(deserialize_put_many_param(&payload2).unwrap().is_empty());
+
+    let err = deserialize_put_many_param(&payload[..payload.len() - 1]).unwrap_err();
+    assert_eq!(err.ec(), ErrorCode::Decode);
+
+    assert!(deserialize_put_many_result(&serialize_put_many_result()).is_ok());
+}
+
+#[test]
+fn delete_many_param_roundtrip_and_truncation() {
+    let keys = vec![b"a".to_vec(), b"bc".to_vec()];
+    let payload = serialize_session_delete_many_param(0x43u128, &keys);
+    let (sid, got_keys) = deserialize_session_delete_many_param(&payload).unwrap();
+    assert_eq!(sid, 0x43u128);
+    assert_eq!(got_keys, keys);
+    assert_eq!(
+        deserialize_delete_many_param(&serialize_delete_many_param(&keys)).unwrap(),
+        keys
+    );
+
+    let err = deserialize_delete_many_param(&payload[..10]).unwrap_err();
+    assert_eq!(err.ec(), ErrorCode::Decode);
+
+    assert!(deserialize_delete_many_result(&serialize_delete_many_result()).is_ok());
+}
+
+#[test]
+fn cas_param_roundtrip_and_truncation() {
+    let payload = serialize_session_cas_param(0x44u128, b"k", Some(b"old"), b"new");
+    let (sid, key, expected, value) = deserialize_session_cas_param(&payload).unwrap();
+    assert_eq!(sid, 0x44u128);
+    assert_eq!(key, b"k");
+    assert_eq!(expected, Some(b"old".to_vec()));
+    assert_eq!(value, b"new");
+    assert_eq!(
+        deserialize_cas_param(&serialize_cas_param(b"k", None, b"v")).unwrap(),
+        (b"k".to_vec(), None, b"v".to_vec())
+    );
+
+    let err = deserialize_cas_param(&payload[..payload.len() - 1]).unwrap_err();
+    assert_eq!(err.ec(), ErrorCode::Decode);
+
+    assert!(deserialize_cas_result(&serialize_cas_result(true)).unwrap());
+    assert!(!deserialize_put_if_absent_result(&serialize_put_if_absent_result(false)).unwrap());
+    assert!(deserialize_cas_result(&[2]).is_err());
+}
+
+#[test]
+fn incr_param_and_result_roundtrip() {
+    let payload = serialize_session_incr_param(0x45u128, b"n", -7);
+    assert_eq!(
+        deserialize_session_incr_param(&payload).unwrap(),
+        (0x45u128, b"n".to_vec(), -7)
+    );
+    let err = deserialize_incr_param(&payload[..payload.len() - 1]).unwrap_err();
+    assert_eq!(err.ec(), ErrorCode::Decode);
+
+    assert_eq!(
+        deserialize_incr_result(&serialize_incr_result(i64::MIN)).unwrap(),
+        i64::MIN
+    );
+    assert!(deserialize_incr_result(&[0, 1]).is_err());
+}
+
 #[test]
 fn range_param_roundtrip_and_truncation() {
     let start = b"a";
@@ -188,6 +286,46 @@ fn close_result_ok_invalid_and_error() {
     assert_eq!(err.ec(), ErrorCode::Thread);
 }
 
+#[test]
+fn range_page_param_and_result_roundtrip() {
+    let options = KvRangeOptions::new()
+        .with_limit(10)
+        .with_reverse(true)
+        .with_keys_only(true)
+        .with_continuation(b"m".to_vec());
+    let payload = serialize_session_range_page_param(7, b"a", b"z", &options);
+    let (sid, start, end, got) = deserialize_session_range_page_param(&payload).unwrap();
+    assert_eq!(
+        (sid, start.as_slice(), end.as_slice()),
+        (7, &b"a"[..], &b"z"[..])
+    );
+    assert_eq!(got, options);
+    assert_eq!(
+        deserialize_session_range_param(&payload).unwrap(),
+        (7, b"a".to_vec(), b"z".to_vec())
+    );
+
+    let plain = serialize_session_range_param(7, b"a", b"z");
+    assert_eq!(
+        serialize_session_range_page_param(7, b"a", b"z", &KvRangeOptions::default()),
+        plain
+    );
+    assert!(deserialize_session_range_page_param(&plain)
+        .unwrap()
+        .3
+        .is_default());
+
+    let page = KvRangePage::new(vec![(b"k".to_vec(), b"v".to_vec())], Some(b"k".to_vec()));
+    let payload = serialize_range_page_result(&page);
+    assert_eq!(deserialize_range_page_result(&payload).unwrap(), page);
+    assert_eq!(deserialize_range_result(&payload).unwrap(), page.items());
+
+    let last = KvRangePage::new(Vec::new(), None);
+    let payload = serialize_range_page_result(&last);
+    assert_eq!(payload, serialize_range_result(&[]));
+    assert_eq!(deserialize_range_page_result(&payload).unwrap(), last);
+}
+
 #[test]
 fn range_result_roundtrip_and_errors() {
     let items: Vec<(Vec<u8>, Vec<u8>)> = vec![
@@ -205,3 +343,31 @@ fn range_result_roundtrip_and_errors() {
     let err = deserialize_range_result(&payload).unwrap_err();
     assert_eq!(err.ec(), ErrorCode::Decode);
 }
+
+#[test]
+fn kv_namespace_param_and_result_roundtrip() {
+    use mudu_contract::database::kv_namespace::{KvNamespaceOp, KvNamespaceOpResult};
+
+    let op = KvNamespaceOp::Range {
+        name: "carts".to_string(),
+        start_key: b"a".to_vec(),
+        end_key: b"z".to_vec(),
+        options: KvRangeOptions::new().with_limit(2),
+    };
+    let payload = serialize_session_kv_namespace_param(0x46u128, &op);
+    assert_eq!(
+        deserialize_session_kv_namespace_param(&payload).unwrap(),
+        (0x46u128, op)
+    );
+    let err = deserialize_session_kv_namespace_param(&payload[..4]).unwrap_err();
+    assert_eq!(err.ec(), ErrorCode::Decode);
+
+    let result = KvNamespaceOpResult::Names(vec!["carts".to_string()]);
+    assert_eq!(
+        deserialize_kv_namespace_result(&serialize_kv_namespace_result(&result)).unwrap(),
+        result
+    );
+    let payload = serialize_error_result(mudu::mudu_error!(ErrorCode::InvalidArgument, "bad"));
+    let err = deserialize_kv_namespace_result(&payload).unwrap_err();
+    assert_eq!(err.ec(), ErrorCode::InvalidArgument);
+}
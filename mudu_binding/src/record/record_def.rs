@@ -286,7 +286,7 @@ mod tests {
     fn row_desc_propagates_unsupported_scalar() {
         let record = RecordDef::new(
             "bad".to_string(),
-            vec![field("flag", UniDatType::Scalar(UniScalar::Bool))],
+            vec![field("count", UniDatType::Scalar(UniScalar::U8))],
         );
         let err = record.row_desc().unwrap_err();
         assert_eq!(err.ec(), ErrorCode::InvalidType);
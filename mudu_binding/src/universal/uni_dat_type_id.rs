@@ -57,4 +57,8 @@ pub enum UniDatTypeId {
     Timestamp = 21,
 
     TimestampTz = 22,
+
+    Uuid = 23,
+
+    Json = 24,
 }
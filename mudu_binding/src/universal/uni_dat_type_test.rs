@@ -112,6 +112,16 @@ fn array_uni_to_and_from_roundtrip() {
     assert!(matches!(back, UniDatType::Array(_)));
 }
 
+#[test]
+fn array_params_apply_to_element_type() {
+    let uni = UniDatType::from_array(Box::new(scalar(UniScalar::String)));
+    let dat = uni.uni_to_with_params(i64_param(10)).unwrap();
+    assert_eq!(dat.dat_type_id(), DatTypeID::Array);
+    let elem = dat.expect_array_param().dat_type();
+    assert_eq!(elem.dat_type_id(), DatTypeID::String);
+    assert_eq!(elem.as_string_param().unwrap().length(), 10);
+}
+
 #[test]
 fn record_uni_to_and_from_roundtrip() {
     let uni = UniDatType::from_record(UniRecordType {
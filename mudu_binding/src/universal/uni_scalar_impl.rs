@@ -10,36 +10,21 @@ use mudu_type::dat_type_id::DatTypeID;
 impl UniScalar {
     pub fn uni_to(self) -> RS<DatType> {
         let ty = match self {
-            UniScalar::Bool => {
-                return Err(mudu_error!(
-                    ErrorCode::InvalidType,
-                    "scalar bool is not supported"
-                ));
-            }
+            UniScalar::Bool => DatType::default_for(DatTypeID::Bool),
             UniScalar::U8 => {
                 return Err(mudu_error!(
                     ErrorCode::InvalidType,
                     "scalar u8 is not supported"
                 ));
             }
-            UniScalar::I8 => {
-                return Err(mudu_error!(
-                    ErrorCode::InvalidType,
-                    "scalar i8 is not supported"
-                ));
-            }
+            UniScalar::I8 => DatType::default_for(DatTypeID::I8),
             UniScalar::U16 => {
                 return Err(mudu_error!(
                     ErrorCode::InvalidType,
                     "scalar u16 is not supported"
                 ));
             }
-            UniScalar::I16 => {
-                return Err(mudu_error!(
-                    ErrorCode::InvalidType,
-                    "scalar i16 is not supported"
-                ));
-            }
+            UniScalar::I16 => DatType::default_for(DatTypeID::I16),
             UniScalar::U32 => {
                 return Err(mudu_error!(
                     ErrorCode::InvalidType,
@@ -71,12 +56,17 @@ impl UniScalar {
             UniScalar::Time => DatType::default_for(DatTypeID::Time),
             UniScalar::Timestamp => DatType::default_for(DatTypeID::Timestamp),
             UniScalar::TimestampTz => DatType::default_for(DatTypeID::TimestampTz),
+            UniScalar::Uuid => DatType::default_for(DatTypeID::Uuid),
+            UniScalar::Json => DatType::default_for(DatTypeID::Json),
         };
         Ok(ty)
     }
 
     pub fn uni_from(ty: DatType) -> RS<Self> {
         let uni_scalar = match ty.dat_type_id() {
+            DatTypeID::Bool => Self::Bool,
+            DatTypeID::I8 => Self::I8,
+            DatTypeID::I16 => Self::I16,
             DatTypeID::I32 => Self::I32,
             DatTypeID::I64 => Self::I64,
             DatTypeID::I128 => Self::I128,
@@ -89,6 +79,8 @@ impl UniScalar {
             DatTypeID::Time => Self::Time,
             DatTypeID::Timestamp => Self::Timestamp,
             DatTypeID::TimestampTz => Self::TimestampTz,
+            DatTypeID::Uuid => Self::Uuid,
+            DatTypeID::Json => Self::Json,
             DatTypeID::Array => {
                 return Err(mudu_error!(
                     ErrorCode::InvalidType,
@@ -127,6 +119,9 @@ mod tests {
     #[test]
     fn supported_uni_to_mudu_roundtrip() {
         let cases = [
+            (UniScalar::Bool, DatTypeID::Bool),
+            (UniScalar::I8, DatTypeID::I8),
+            (UniScalar::I16, DatTypeID::I16),
             (UniScalar::I32, DatTypeID::I32),
             (UniScalar::I64, DatTypeID::I64),
             (UniScalar::I128, DatTypeID::I128),
@@ -140,6 +135,8 @@ mod tests {
             (UniScalar::Time, DatTypeID::Time),
             (UniScalar::Timestamp, DatTypeID::Timestamp),
             (UniScalar::TimestampTz, DatTypeID::TimestampTz),
+            (UniScalar::Uuid, DatTypeID::Uuid),
+            (UniScalar::Json, DatTypeID::Json),
         ];
         for (uni, expected_id) in cases {
             let dat = uni.uni_to().unwrap();
@@ -151,11 +148,8 @@ mod tests {
     #[test]
     fn unsupported_uni_to_returns_invalid_type() {
         let unsupported = [
-            UniScalar::Bool,
             UniScalar::U8,
-            UniScalar::I8,
             UniScalar::U16,
-            UniScalar::I16,
             UniScalar::U32,
             UniScalar::U64,
             UniScalar::Char,
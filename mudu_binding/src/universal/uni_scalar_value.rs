@@ -40,6 +40,10 @@ pub enum UniScalarValue {
     Timestamp(String),
 
     TimestampTz(String),
+
+    Uuid(String),
+
+    Json(String),
 }
 
 impl Default for UniScalarValue {
@@ -408,6 +412,42 @@ impl UniScalarValue {
             _ => unsafe { std::hint::unreachable_unchecked() },
         }
     }
+
+    pub fn from_uuid(inner: String) -> Self {
+        Self::Uuid(inner)
+    }
+
+    pub fn as_uuid(&self) -> Option<&String> {
+        match self {
+            Self::Uuid(inner) => Some(inner),
+            _ => None,
+        }
+    }
+
+    pub fn expect_uuid(&self) -> &String {
+        match self {
+            Self::Uuid(inner) => inner,
+            _ => unsafe { std::hint::unreachable_unchecked() },
+        }
+    }
+
+    pub fn from_json(inner: String) -> Self {
+        Self::Json(inner)
+    }
+
+    pub fn as_json(&self) -> Option<&String> {
+        match self {
+            Self::Json(inner) => Some(inner),
+            _ => None,
+        }
+    }
+
+    pub fn expect_json(&self) -> &String {
+        match self {
+            Self::Json(inner) => inner,
+            _ => unsafe { std::hint::unreachable_unchecked() },
+        }
+    }
 }
 
 impl serde::Serialize for UniScalarValue {
@@ -517,6 +557,16 @@ impl serde::Serialize for UniScalarValue {
                 serialize_seq.serialize_element(&19u32)?;
                 serialize_seq.serialize_element(&inner)?;
             }
+
+            UniScalarValue::Uuid(inner) => {
+                serialize_seq.serialize_element(&20u32)?;
+                serialize_seq.serialize_element(&inner)?;
+            }
+
+            UniScalarValue::Json(inner) => {
+                serialize_seq.serialize_element(&21u32)?;
+                serialize_seq.serialize_element(&inner)?;
+            }
         }
         serialize_seq.end()
     }
@@ -686,6 +736,20 @@ impl<'de> serde::de::Visitor<'de> for UniScalarValueVisitor {
                 Ok(Self::Value::TimestampTz(value))
             }
 
+            20 => {
+                let value = seq
+                    .next_element::<String>()?
+                    .map_or_else(|| Err(A::Error::invalid_length(1, &self)), Ok)?;
+                Ok(Self::Value::Uuid(value))
+            }
+
+            21 => {
+                let value = seq
+                    .next_element::<String>()?
+                    .map_or_else(|| Err(A::Error::invalid_length(1, &self)), Ok)?;
+                Ok(Self::Value::Json(value))
+            }
+
             _ => Err(Error::invalid_value(Unexpected::Map, &self)),
         }
     }
@@ -817,6 +881,12 @@ mod tests {
                 UniScalarValue::from_timestamptz("2026-05-20T14:30:00+08:00".to_string()),
                 Box::new(|v| v.as_timestamptz() == Some(&"2026-05-20T14:30:00+08:00".to_string())),
             ),
+            (
+                UniScalarValue::from_uuid("123e4567-e89b-12d3-a456-426614174000".to_string()),
+                Box::new(|v| {
+                    v.as_uuid() == Some(&"123e4567-e89b-12d3-a456-426614174000".to_string())
+                }),
+            ),
         ];
 
         for (value, predicate) in cases {
@@ -849,6 +919,7 @@ mod tests {
         assert!(i32_value.as_time().is_none());
         assert!(i32_value.as_timestamp().is_none());
         assert!(i32_value.as_timestamptz().is_none());
+        assert!(i32_value.as_uuid().is_none());
 
         let string_value = UniScalarValue::from_string("x".to_string());
         assert!(string_value.as_i32().is_none());
@@ -891,6 +962,7 @@ mod tests {
             UniScalarValue::from_time("12:34:56.123456".to_string()),
             UniScalarValue::from_timestamp("2026-05-20 14:30:45.123456".to_string()),
             UniScalarValue::from_timestamptz("2026-05-20T14:30:45.123456+08:00".to_string()),
+            UniScalarValue::from_uuid("123e4567-e89b-12d3-a456-426614174000".to_string()),
         ];
 
         for value in cases {
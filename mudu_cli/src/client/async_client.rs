@@ -5,21 +5,36 @@ use mudu::common::result::RS;
 use mudu::error::ErrorCode;
 use mudu::mudu_error;
 use mudu_contract::protocol::{
-    ClientRequest, Frame, FrameHeader, GetRequest, GetResponse, HEADER_LEN, MessageType,
-    ProcedureInvokeRequest, ProcedureInvokeResponse, PutRequest, PutResponse, RangeScanRequest,
-    RangeScanResponse, ServerPerfDigest, ServerResponse, SessionCloseRequest, SessionCloseResponse,
-    SessionCreateRequest, SessionCreateResponse, decode_error_response, decode_get_response,
-    decode_procedure_invoke_response, decode_put_response, decode_range_scan_response,
-    decode_server_response, decode_session_close_response, decode_session_create_response,
-    encode_batch_request, encode_client_request_with_message_type,
-    encode_client_request_with_message_type_and_trace, encode_get_request,
-    encode_procedure_invoke_request_with_trace, encode_put_request, encode_range_scan_request,
-    encode_session_close_request, encode_session_create_request,
+    CasRequest, CasResponse, CdcSubscribeRequest, CdcSubscribeResponse, ClientRequest,
+    DeleteManyRequest, DeleteRequest, DeleteResponse, Frame, FrameHeader, GetRequest, GetResponse,
+    HEADER_LEN, IncrRequest, IncrResponse, KvNamespaceCreateRequest, KvNamespaceDropRequest,
+    KvNamespaceListRequest, KvNamespaceListResponse, KvNamespaceResponse, MessageType,
+    ProcedureInvokeRequest, ProcedureInvokeResponse, PutIfAbsentRequest, PutManyRequest,
+    PutRequest, PutResponse, RangeScanRequest, RangeScanResponse, ServerPerfDigest, ServerResponse,
+    SessionCloseRequest, SessionCloseResponse, SessionCreateRequest, SessionCreateResponse,
+    WalFetchRequest, WalFetchResponse, WatchAckRequest, WatchCancelRequest, WatchEvent,
+    WatchRegisterRequest, WatchRegisterResponse, WatchResponse, decode_cas_response,
+    decode_cdc_subscribe_response, decode_delete_response, decode_error_response,
+    decode_get_response, decode_incr_response, decode_kv_namespace_list_response,
+    decode_kv_namespace_response, decode_procedure_invoke_response, decode_put_response,
+    decode_range_scan_response, decode_server_response, decode_session_close_response,
+    decode_session_create_response, decode_wal_fetch_response, decode_watch_event,
+    decode_watch_register_response, decode_watch_response, encode_batch_request,
+    encode_cas_request, encode_cdc_subscribe_request, encode_client_request_with_message_type,
+    encode_client_request_with_message_type_and_trace, encode_delete_many_request,
+    encode_delete_request, encode_get_request, encode_incr_request,
+    encode_kv_namespace_create_request, encode_kv_namespace_drop_request,
+    encode_kv_namespace_list_request, encode_procedure_invoke_request_with_trace,
+    encode_put_if_absent_request, encode_put_many_request, encode_put_request,
+    encode_range_scan_request, encode_session_close_request, encode_session_create_request,
+    encode_wal_fetch_request, encode_watch_ack_request, encode_watch_cancel_request,
+    encode_watch_register_request,
 };
 use mudu_sys::net::AsyncTcpStream;
 use mudu_sys::perf::{PerfSpan, TraceContext, TxnStage, next_trace_id, should_sample};
 use mudu_sys::time::instant_now;
 use mudu_sys::tokio::io::{AsyncReadExt, AsyncWriteExt};
+use std::collections::VecDeque;
 
 /// Async interface for sending MuduDB protocol requests over TCP.
 #[async_trait]
@@ -45,12 +60,133 @@ pub trait AsyncClient: Send {
     async fn create_session(&mut self, request: SessionCreateRequest) -> RS<SessionCreateResponse>;
     /// Close an existing session.
     async fn close_session(&mut self, request: SessionCloseRequest) -> RS<SessionCloseResponse>;
+    /// Read the committed changes of a table a change data capture consumer
+    /// has not consumed yet.
+    async fn cdc_subscribe(&mut self, _request: CdcSubscribeRequest) -> RS<CdcSubscribeResponse> {
+        Err(mudu_error!(
+            ErrorCode::NotImplemented,
+            "change data capture is not supported by this client"
+        ))
+    }
+    /// Send a KV delete request.
+    async fn delete(&mut self, _request: DeleteRequest) -> RS<DeleteResponse> {
+        Err(mudu_error!(
+            ErrorCode::NotImplemented,
+            "kv delete is not supported by this client"
+        ))
+    }
+    /// Send a multi-key KV put request.
+    async fn put_many(&mut self, _request: PutManyRequest) -> RS<PutResponse> {
+        Err(mudu_error!(
+            ErrorCode::NotImplemented,
+            "multi-key kv put is not supported by this client"
+        ))
+    }
+    /// Send a multi-key KV delete request.
+    async fn delete_many(&mut self, _request: DeleteManyRequest) -> RS<DeleteResponse> {
+        Err(mudu_error!(
+            ErrorCode::NotImplemented,
+            "multi-key kv delete is not supported by this client"
+        ))
+    }
+    /// Send a KV compare-and-swap request.
+    async fn cas(&mut self, _request: CasRequest) -> RS<CasResponse> {
+        Err(mudu_error!(
+            ErrorCode::NotImplemented,
+            "kv compare-and-swap is not supported by this client"
+        ))
+    }
+    /// Send a KV counter increment request.
+    async fn incr(&mut self, _request: IncrRequest) -> RS<IncrResponse> {
+        Err(mudu_error!(
+            ErrorCode::NotImplemented,
+            "kv increment is not supported by this client"
+        ))
+    }
+    /// Send a KV put-if-absent request.
+    async fn put_if_absent(&mut self, _request: PutIfAbsentRequest) -> RS<CasResponse> {
+        Err(mudu_error!(
+            ErrorCode::NotImplemented,
+            "kv put-if-absent is not supported by this client"
+        ))
+    }
+    /// Create a KV namespace.
+    async fn kv_namespace_create(
+        &mut self,
+        _request: KvNamespaceCreateRequest,
+    ) -> RS<KvNamespaceResponse> {
+        Err(mudu_error!(
+            ErrorCode::NotImplemented,
+            "kv namespaces are not supported by this client"
+        ))
+    }
+    /// Drop a KV namespace and its keys.
+    async fn kv_namespace_drop(
+        &mut self,
+        _request: KvNamespaceDropRequest,
+    ) -> RS<KvNamespaceResponse> {
+        Err(mudu_error!(
+            ErrorCode::NotImplemented,
+            "kv namespaces are not supported by this client"
+        ))
+    }
+    /// List the KV namespaces of the app the session is bound to.
+    async fn kv_namespace_list(
+        &mut self,
+        _request: KvNamespaceListRequest,
+    ) -> RS<KvNamespaceListResponse> {
+        Err(mudu_error!(
+            ErrorCode::NotImplemented,
+            "kv namespaces are not supported by this client"
+        ))
+    }
+    /// Fetch the worker log frames a read replica has not applied yet.
+    async fn wal_fetch(&mut self, _request: WalFetchRequest) -> RS<WalFetchResponse> {
+        Err(mudu_error!(
+            ErrorCode::NotImplemented,
+            "log shipping is not supported by this client"
+        ))
+    }
+    /// Register a watch; its events arrive through `next_watch_event`.
+    async fn watch_register(
+        &mut self,
+        _request: WatchRegisterRequest,
+    ) -> RS<WatchRegisterResponse> {
+        Err(mudu_error!(
+            ErrorCode::NotImplemented,
+            "watches are not supported by this client"
+        ))
+    }
+    /// Acknowledge the events of a watch up to a sequence number.
+    async fn watch_ack(&mut self, _request: WatchAckRequest) -> RS<WatchResponse> {
+        Err(mudu_error!(
+            ErrorCode::NotImplemented,
+            "watches are not supported by this client"
+        ))
+    }
+    /// Cancel a watch.
+    async fn watch_cancel(&mut self, _request: WatchCancelRequest) -> RS<WatchResponse> {
+        Err(mudu_error!(
+            ErrorCode::NotImplemented,
+            "watches are not supported by this client"
+        ))
+    }
+    /// Wait for the next event the server pushed for a watch of this
+    /// connection.
+    async fn next_watch_event(&mut self) -> RS<WatchEvent> {
+        Err(mudu_error!(
+            ErrorCode::NotImplemented,
+            "watches are not supported by this client"
+        ))
+    }
 }
 
 /// Async TCP client implementation using io_uring.
 pub struct AsyncClientImpl {
     stream: AsyncTcpStream,
     next_request_id: u64,
+    // Watch events that arrived while waiting for a response.
+    watch_events: VecDeque<WatchEvent>,
 }
 
 impl AsyncClientImpl {
@@ -73,6 +209,7 @@ impl AsyncClientImpl {
         Ok(Self {
             stream,
             next_request_id: 1,
+            watch_events: VecDeque::new(),
         })
     }
 
@@ -97,27 +234,38 @@ impl AsyncClientImpl {
 
         let frame = {
             let _recv = PerfSpan::new(TxnStage::ClientNetworkRecv, trace_id);
-            let mut header = [0u8; HEADER_LEN];
-            self.stream
-                .read_exact(&mut header)
-                .await
-                .map_err(|e| mudu_error!(ErrorCode::Network, "read response header error", e))?;
-            let payload_len = FrameHeader::decode_header_bytes(&header)?.payload_len() as usize;
-            let mut frame_bytes = Vec::with_capacity(HEADER_LEN + payload_len);
-            frame_bytes.extend_from_slice(&header);
-            if payload_len > 0 {
-                let mut body = vec![0u8; payload_len];
-                self.stream.read_exact(&mut body).await.map_err(|e| {
-                    mudu_error!(ErrorCode::Network, "read response payload error", e)
-                })?;
-                frame_bytes.extend_from_slice(&body);
+            loop {
+                let frame = self.read_frame().await?;
+                if frame.header().message_type() != MessageType::WatchEvent {
+                    break frame;
+                }
+                self.watch_events.push_back(decode_watch_event(&frame)?);
             }
-            Frame::decode(&frame_bytes)?
         };
         self.ensure_success_frame(&frame)?;
         Ok(frame)
     }
 
+    async fn read_frame(&mut self) -> RS<Frame> {
+        let mut header = [0u8; HEADER_LEN];
+        self.stream
+            .read_exact(&mut header)
+            .await
+            .map_err(|e| mudu_error!(ErrorCode::Network, "read response header error", e))?;
+        let payload_len = FrameHeader::decode_header_bytes(&header)?.payload_len() as usize;
+        let mut frame_bytes = Vec::with_capacity(HEADER_LEN + payload_len);
+        frame_bytes.extend_from_slice(&header);
+        if payload_len > 0 {
+            let mut body = vec![0u8; payload_len];
+            self.stream
+                .read_exact(&mut body)
+                .await
+                .map_err(|e| mudu_error!(ErrorCode::Network, "read response payload error", e))?;
+            frame_bytes.extend_from_slice(&body);
+        }
+        Frame::decode(&frame_bytes)
+    }
+
     fn ensure_success_frame(&self, frame: &Frame) -> RS<()> {
         if frame.header().message_type() == MessageType::Error {
             let error = decode_error_response(frame)?;
@@ -241,12 +389,75 @@ impl AsyncClient for AsyncClientImpl {
         decode_put_response(&frame)
     }
 
+    async fn delete(&mut self, request: DeleteRequest) -> RS<DeleteResponse> {
+        let payload = encode_delete_request(self.take_request_id(), &request)?;
+        let frame = self.send_and_receive(&payload, 0).await?;
+        decode_delete_response(&frame)
+    }
+
+    async fn put_many(&mut self, request: PutManyRequest) -> RS<PutResponse> {
+        let payload = encode_put_many_request(self.take_request_id(), &request)?;
+        let frame = self.send_and_receive(&payload, 0).await?;
+        decode_put_response(&frame)
+    }
+
+    async fn delete_many(&mut self, request: DeleteManyRequest) -> RS<DeleteResponse> {
+        let payload = encode_delete_many_request(self.take_request_id(), &request)?;
+        let frame = self.send_and_receive(&payload, 0).await?;
+        decode_delete_response(&frame)
+    }
+
+    async fn cas(&mut self, request: CasRequest) -> RS<CasResponse> {
+        let payload = encode_cas_request(self.take_request_id(), &request)?;
+        let frame = self.send_and_receive(&payload, 0).await?;
+        decode_cas_response(&frame)
+    }
+
+    async fn incr(&mut self, request: IncrRequest) -> RS<IncrResponse> {
+        let payload = encode_incr_request(self.take_request_id(), &request)?;
+        let frame = self.send_and_receive(&payload, 0).await?;
+        decode_incr_response(&frame)
+    }
+
+    async fn put_if_absent(&mut self, request: PutIfAbsentRequest) -> RS<CasResponse> {
+        let payload = encode_put_if_absent_request(self.take_request_id(), &request)?;
+        let frame = self.send_and_receive(&payload, 0).await?;
+        decode_cas_response(&frame)
+    }
+
     async fn range_scan(&mut self, request: RangeScanRequest) -> RS<RangeScanResponse> {
         let payload = encode_range_scan_request(self.take_request_id(), &request)?;
         let frame = self.send_and_receive(&payload, 0).await?;
         decode_range_scan_response(&frame)
     }
 
+    async fn kv_namespace_create(
+        &mut self,
+        request: KvNamespaceCreateRequest,
+    ) -> RS<KvNamespaceResponse> {
+        let payload = encode_kv_namespace_create_request(self.take_request_id(), &request)?;
+        let frame = self.send_and_receive(&payload, 0).await?;
+        decode_kv_namespace_response(&frame)
+    }
+
+    async fn kv_namespace_drop(
+        &mut self,
+        request: KvNamespaceDropRequest,
+    ) -> RS<KvNamespaceResponse> {
+        let payload = encode_kv_namespace_drop_request(self.take_request_id(), &request)?;
+        let frame = self.send_and_receive(&payload, 0).await?;
+        decode_kv_namespace_response(&frame)
+    }
+
+    async fn kv_namespace_list(
+        &mut self,
+        request: KvNamespaceListRequest,
+    ) -> RS<KvNamespaceListResponse> {
+        let payload = encode_kv_namespace_list_request(self.take_request_id(), &request)?;
+        let frame = self.send_and_receive(&payload, 0).await?;
+        decode_kv_namespace_list_response(&frame)
+    }
+
     async fn invoke_procedure(
         &mut self,
         request: ProcedureInvokeRequest,
@@ -311,6 +522,54 @@ impl AsyncClient for AsyncClientImpl {
         let frame = self.send_and_receive(&payload, 0).await?;
         decode_session_close_response(&frame)
     }
+
+    async fn cdc_subscribe(&mut self, request: CdcSubscribeRequest) -> RS<CdcSubscribeResponse> {
+        let payload = encode_cdc_subscribe_request(self.take_request_id(), &request)?;
+        let frame = self.send_and_receive(&payload, 0).await?;
+        decode_cdc_subscribe_response(&frame)
+    }
+
+    async fn wal_fetch(&mut self, request: WalFetchRequest) -> RS<WalFetchResponse> {
+        let payload = encode_wal_fetch_request(self.take_request_id(), &request)?;
+        let frame = self.send_and_receive(&payload, 0).await?;
+        decode_wal_fetch_response(&frame)
+    }
+
+    async fn watch_register(&mut self, request: WatchRegisterRequest) -> RS<WatchRegisterResponse> {
+        let payload = encode_watch_register_request(self.take_request_id(), &request)?;
+        let frame = self.send_and_receive(&payload, 0).await?;
+        decode_watch_register_response(&frame)
+    }
+
+    async fn watch_ack(&mut self, request: WatchAckRequest) -> RS<WatchResponse> {
+        let payload = encode_watch_ack_request(self.take_request_id(), &request)?;
+        let frame = self.send_and_receive(&payload, 0).await?;
+        decode_watch_response(&frame)
+    }
+
+    async fn watch_cancel(&mut self, request: WatchCancelRequest) -> RS<WatchResponse> {
+        let payload = encode_watch_cancel_request(self.take_request_id(), &request)?;
+        let frame = self.send_and_receive(&payload, 0).await?;
+        decode_watch_response(&frame)
+    }
+
+    async fn next_watch_event(&mut self) -> RS<WatchEvent> {
+        if let Some(event) = self.watch_events.pop_front() {
+            return Ok(event);
+        }
+        let frame = self.read_frame().await?;
+        if frame.header().message_type() == MessageType::WatchEvent {
+            return decode_watch_event(&frame);
+        }
+        self.ensure_success_frame(&frame)?;
+        Err(mudu_error!(
+            ErrorCode::Network,
+            format!(
+                "unexpected {:?} frame while waiting for a watch event",
+                frame.header().message_type()
+            )
+        ))
+    }
 }
 
 #[cfg(test)]
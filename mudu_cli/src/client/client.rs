@@ -3,26 +3,44 @@
 use mudu::common::result::RS;
 use mudu::error::ErrorCode;
 use mudu::mudu_error;
+use mudu_contract::database::kv_namespace::KvNamespace;
+use mudu_contract::database::kv_range::{KvRangeOptions, KvRangePage};
 use mudu_contract::protocol::{
-    ClientRequest, Frame, FrameHeader, GetRequest, HEADER_LEN, KeyValue, MessageType,
-    ProcedureInvokeRequest, PutRequest, RangeScanRequest, ServerPerfDigest, ServerResponse,
-    SessionCloseRequest, SessionCreateRequest, decode_error_response, decode_get_response,
+    CasRequest, CdcSubscribeRequest, CdcSubscribeResponse, ClientRequest, DeleteManyRequest,
+    DeleteRequest, Frame, FrameHeader, GetRequest, HEADER_LEN, IncrRequest, KeyValue,
+    KvNamespaceCreateRequest, KvNamespaceDropRequest, KvNamespaceListRequest, MessageType,
+    ProcedureInvokeRequest, PutIfAbsentRequest, PutManyRequest, PutRequest, RangeScanRequest,
+    ServerPerfDigest, ServerResponse, SessionCloseRequest, SessionCreateRequest, WatchAckRequest,
+    WatchCancelRequest, WatchEvent, WatchRegisterRequest, WatchRegisterResponse,
+    decode_cas_response, decode_cdc_subscribe_response, decode_delete_response,
+    decode_error_response, decode_get_response, decode_incr_response,
+    decode_kv_namespace_list_response, decode_kv_namespace_response,
     decode_procedure_invoke_response, decode_put_response, decode_range_scan_response,
     decode_server_response, decode_session_close_response, decode_session_create_response,
-    encode_batch_request, encode_client_request_with_message_type_and_trace, encode_get_request,
-    encode_procedure_invoke_request, encode_put_request, encode_range_scan_request,
-    encode_session_close_request, encode_session_create_request,
+    decode_watch_event, decode_watch_register_response, decode_watch_response,
+    encode_batch_request, encode_cas_request, encode_cdc_subscribe_request,
+    encode_client_request_with_message_type_and_trace, encode_delete_many_request,
+    encode_delete_request, encode_get_request, encode_incr_request,
+    encode_kv_namespace_create_request, encode_kv_namespace_drop_request,
+    encode_kv_namespace_list_request, encode_procedure_invoke_request,
+    encode_put_if_absent_request, encode_put_many_request, encode_put_request,
+    encode_range_scan_request, encode_session_close_request, encode_session_create_request,
+    encode_watch_ack_request, encode_watch_cancel_request, encode_watch_register_request,
 };
 use mudu_sys::net::sync::{SStdTcpStream, connect_tcp};
 use mudu_sys::perf::{PerfSpan, TraceContext, TxnStage, next_trace_id, should_sample};
 use mudu_sys::time::instant_now;
+use std::collections::VecDeque;
 use std::io::{Read, Write};
 use std::net::SocketAddr;
+use std::time::Duration;
 
 /// Synchronous blocking TCP client for MuduDB.
 pub struct SyncClient {
     stream: SStdTcpStream,
     next_request_id: u64,
+    // Watch events that arrived while waiting for a response.
+    watch_events: VecDeque<WatchEvent>,
 }
 
 impl SyncClient {
@@ -36,6 +54,7 @@ impl SyncClient {
         Ok(Self {
             stream,
             next_request_id: 1,
+            watch_events: VecDeque::new(),
         })
     }
 
@@ -95,11 +114,23 @@ impl SyncClient {
         key: impl Into<Vec<u8>>,
         value: impl Into<Vec<u8>>,
     ) -> RS<()> {
+        self.send_put(&PutRequest::new(session_id, key.into(), value.into()))
+    }
+
+    /// Put a key-value pair that expires `ttl` after its write commits.
+    pub fn put_with_ttl(
+        &mut self,
+        session_id: u128,
+        key: impl Into<Vec<u8>>,
+        value: impl Into<Vec<u8>>,
+        ttl: Duration,
+    ) -> RS<()> {
+        self.send_put(&PutRequest::new(session_id, key.into(), value.into()).with_ttl(ttl))
+    }
+
+    fn send_put(&mut self, request: &PutRequest) -> RS<()> {
         let request_id = self.take_request_id();
-        let payload = encode_put_request(
-            request_id,
-            &PutRequest::new(session_id, key.into(), value.into()),
-        )?;
+        let payload = encode_put_request(request_id, request)?;
         let frame = self.send_and_receive(&payload)?;
         self.ensure_success_frame(&frame)?;
         if decode_put_response(&frame)?.ok() {
@@ -112,6 +143,102 @@ impl SyncClient {
         }
     }
 
+    /// Delete a key; deleting a missing key succeeds.
+    pub fn delete(&mut self, session_id: u128, key: impl Into<Vec<u8>>) -> RS<()> {
+        let request_id = self.take_request_id();
+        let payload =
+            encode_delete_request(request_id, &DeleteRequest::new(session_id, key.into()))?;
+        let frame = self.send_and_receive(&payload)?;
+        self.ensure_success_frame(&frame)?;
+        if decode_delete_response(&frame)?.ok() {
+            Ok(())
+        } else {
+            Err(mudu_error!(
+                ErrorCode::Network,
+                "remote delete operation returned failure"
+            ))
+        }
+    }
+
+    /// Put several key-value pairs atomically.
+    pub fn put_many(&mut self, session_id: u128, items: Vec<KeyValue>) -> RS<()> {
+        let request_id = self.take_request_id();
+        let payload = encode_put_many_request(request_id, &PutManyRequest::new(session_id, items))?;
+        let frame = self.send_and_receive(&payload)?;
+        self.ensure_success_frame(&frame)?;
+        if decode_put_response(&frame)?.ok() {
+            Ok(())
+        } else {
+            Err(mudu_error!(
+                ErrorCode::Network,
+                "remote put many operation returned failure"
+            ))
+        }
+    }
+
+    /// Delete several keys atomically.
+    pub fn delete_many(&mut self, session_id: u128, keys: Vec<Vec<u8>>) -> RS<()> {
+        let request_id = self.take_request_id();
+        let payload =
+            encode_delete_many_request(request_id, &DeleteManyRequest::new(session_id, keys))?;
+        let frame = self.send_and_receive(&payload)?;
+        self.ensure_success_frame(&frame)?;
+        if decode_delete_response(&frame)?.ok() {
+            Ok(())
+        } else {
+            Err(mudu_error!(
+                ErrorCode::Network,
+                "remote delete many operation returned failure"
+            ))
+        }
+    }
+
+    /// Replace the value of `key` with `value` only if it currently equals
+    /// `expected`; `None` requires the key to be absent.
+    pub fn cas(
+        &mut self,
+        session_id: u128,
+        key: impl Into<Vec<u8>>,
+        expected: Option<Vec<u8>>,
+        value: impl Into<Vec<u8>>,
+    ) -> RS<bool> {
+        let request_id = self.take_request_id();
+        let payload = encode_cas_request(
+            request_id,
+            &CasRequest::new(session_id, key.into(), expected, value.into()),
+        )?;
+        let frame = self.send_and_receive(&payload)?;
+        self.ensure_success_frame(&frame)?;
+        Ok(decode_cas_response(&frame)?.swapped())
+    }
+
+    /// Add `delta` to the integer counter stored at `key` and return the new value.
+    pub fn incr(&mut self, session_id: u128, key: impl Into<Vec<u8>>, delta: i64) -> RS<i64> {
+        let request_id = self.take_request_id();
+        let payload =
+            encode_incr_request(request_id, &IncrRequest::new(session_id, key.into(), delta))?;
+        let frame = self.send_and_receive(&payload)?;
+        self.ensure_success_frame(&frame)?;
+        Ok(decode_incr_response(&frame)?.value())
+    }
+
+    /// Store `value` only if `key` is absent; returns whether it was stored.
+    pub fn put_if_absent(
+        &mut self,
+        session_id: u128,
+        key: impl Into<Vec<u8>>,
+        value: impl Into<Vec<u8>>,
+    ) -> RS<bool> {
+        let request_id = self.take_request_id();
+        let payload = encode_put_if_absent_request(
+            request_id,
+            &PutIfAbsentRequest::new(session_id, key.into(), value.into()),
+        )?;
+        let frame = self.send_and_receive(&payload)?;
+        self.ensure_success_frame(&frame)?;
+        Ok(decode_cas_response(&frame)?.swapped())
+    }
+
     /// Scan a key range.
     pub fn range_scan(
         &mut self,
@@ -129,6 +256,154 @@ impl SyncClient {
         Ok(decode_range_scan_response(&frame)?.into_items())
     }
 
+    /// Scan one page of a key range with limit, order, prefix, keys-only and
+    /// continuation options.
+    pub fn range_scan_page(
+        &mut self,
+        session_id: u128,
+        start_key: impl Into<Vec<u8>>,
+        end_key: impl Into<Vec<u8>>,
+        options: KvRangeOptions,
+    ) -> RS<KvRangePage> {
+        let request_id = self.take_request_id();
+        let payload = encode_range_scan_request(
+            request_id,
+            &RangeScanRequest::new(session_id, start_key.into(), end_key.into())
+                .with_options(options),
+        )?;
+        let frame = self.send_and_receive(&payload)?;
+        self.ensure_success_frame(&frame)?;
+        Ok(decode_range_scan_response(&frame)?.into_page())
+    }
+
+    /// Create the KV namespace `namespace` of the app the session is bound
+    /// to, spread over the partitions of `partition_rule` when one is given.
+    pub fn kv_namespace_create(
+        &mut self,
+        session_id: u128,
+        namespace: &str,
+        partition_rule: Option<String>,
+    ) -> RS<()> {
+        let mut request = KvNamespaceCreateRequest::new(session_id, namespace);
+        if let Some(partition_rule) = partition_rule {
+            request = request.with_partition_rule(partition_rule);
+        }
+        let request_id = self.take_request_id();
+        let payload = encode_kv_namespace_create_request(request_id, &request)?;
+        self.send_kv_namespace_request(&payload, "create")
+    }
+
+    /// Drop the KV namespace `namespace` together with its keys.
+    pub fn kv_namespace_drop(&mut self, session_id: u128, namespace: &str) -> RS<()> {
+        let request_id = self.take_request_id();
+        let payload = encode_kv_namespace_drop_request(
+            request_id,
+            &KvNamespaceDropRequest::new(session_id, namespace),
+        )?;
+        self.send_kv_namespace_request(&payload, "drop")
+    }
+
+    fn send_kv_namespace_request(&mut self, payload: &[u8], operation: &str) -> RS<()> {
+        let frame = self.send_and_receive(payload)?;
+        self.ensure_success_frame(&frame)?;
+        if decode_kv_namespace_response(&frame)?.ok() {
+            Ok(())
+        } else {
+            Err(mudu_error!(
+                ErrorCode::Network,
+                format!(
+                    "remote kv namespace {} operation returned failure",
+                    operation
+                )
+            ))
+        }
+    }
+
+    /// List the KV namespaces of the app the session is bound to.
+    pub fn kv_namespace_list(&mut self, session_id: u128) -> RS<Vec<KvNamespace>> {
+        let request_id = self.take_request_id();
+        let payload =
+            encode_kv_namespace_list_request(request_id, &KvNamespaceListRequest::new(session_id))?;
+        let frame = self.send_and_receive(&payload)?;
+        self.ensure_success_frame(&frame)?;
+        Ok(decode_kv_namespace_list_response(&frame)?.into_namespaces())
+    }
+
+    /// Get a value by key from a KV namespace.
+    pub fn get_in_namespace(
+        &mut self,
+        session_id: u128,
+        namespace: &str,
+        key: impl Into<Vec<u8>>,
+    ) -> RS<Option<Vec<u8>>> {
+        let request_id = self.take_request_id();
+        let payload = encode_get_request(
+            request_id,
+            &GetRequest::new(session_id, key.into()).with_namespace(namespace),
+        )?;
+        let frame = self.send_and_receive(&payload)?;
+        self.ensure_success_frame(&frame)?;
+        Ok(decode_get_response(&frame)?.into_value())
+    }
+
+    /// Put a key-value pair into a KV namespace.
+    pub fn put_in_namespace(
+        &mut self,
+        session_id: u128,
+        namespace: &str,
+        key: impl Into<Vec<u8>>,
+        value: impl Into<Vec<u8>>,
+    ) -> RS<()> {
+        self.send_put(
+            &PutRequest::new(session_id, key.into(), value.into()).with_namespace(namespace),
+        )
+    }
+
+    /// Delete a key from a KV namespace.
+    pub fn delete_in_namespace(
+        &mut self,
+        session_id: u128,
+        namespace: &str,
+        key: impl Into<Vec<u8>>,
+    ) -> RS<()> {
+        let request_id = self.take_request_id();
+        let payload = encode_delete_request(
+            request_id,
+            &DeleteRequest::new(session_id, key.into()).with_namespace(namespace),
+        )?;
+        let frame = self.send_and_receive(&payload)?;
+        self.ensure_success_frame(&frame)?;
+        if decode_delete_response(&frame)?.ok() {
+            Ok(())
+        } else {
+            Err(mudu_error!(
+                ErrorCode::Network,
+                "remote delete operation returned failure"
+            ))
+        }
+    }
+
+    /// Scan one page of a key range of a KV namespace.
+    pub fn range_scan_in_namespace(
+        &mut self,
+        session_id: u128,
+        namespace: &str,
+        start_key: impl Into<Vec<u8>>,
+        end_key: impl Into<Vec<u8>>,
+        options: KvRangeOptions,
+    ) -> RS<KvRangePage> {
+        let request_id = self.take_request_id();
+        let payload = encode_range_scan_request(
+            request_id,
+            &RangeScanRequest::new(session_id, start_key.into(), end_key.into())
+                .with_options(options)
+                .with_namespace(namespace),
+        )?;
+        let frame = self.send_and_receive(&payload)?;
+        self.ensure_success_frame(&frame)?;
+        Ok(decode_range_scan_response(&frame)?.into_page())
+    }
+
     /// Invoke a stored procedure.
     pub fn invoke_procedure(
         &mut self,
@@ -166,6 +441,68 @@ impl SyncClient {
         Ok(decode_session_close_response(&frame)?.closed())
     }
 
+    /// Read the committed changes of a table a change data capture consumer
+    /// has not consumed yet.
+    pub fn cdc_subscribe(&mut self, request: &CdcSubscribeRequest) -> RS<CdcSubscribeResponse> {
+        let request_id = self.take_request_id();
+        let payload = encode_cdc_subscribe_request(request_id, request)?;
+        let frame = self.send_and_receive(&payload)?;
+        self.ensure_success_frame(&frame)?;
+        decode_cdc_subscribe_response(&frame)
+    }
+
+    /// Register a watch; its events arrive through `next_watch_event`.
+    pub fn watch_register(&mut self, request: &WatchRegisterRequest) -> RS<WatchRegisterResponse> {
+        let request_id = self.take_request_id();
+        let payload = encode_watch_register_request(request_id, request)?;
+        let frame = self.send_and_receive(&payload)?;
+        self.ensure_success_frame(&frame)?;
+        decode_watch_register_response(&frame)
+    }
+
+    /// Acknowledge the events of a watch up to `seq`, which lets the server
+    /// push further events.
+    pub fn watch_ack(&mut self, session_id: u128, watch_id: u64, seq: u64) -> RS<()> {
+        let request_id = self.take_request_id();
+        let payload =
+            encode_watch_ack_request(request_id, &WatchAckRequest::new(session_id, watch_id, seq))?;
+        let frame = self.send_and_receive(&payload)?;
+        self.ensure_success_frame(&frame)?;
+        decode_watch_response(&frame).map(|_| ())
+    }
+
+    /// Cancel a watch.
+    pub fn watch_cancel(&mut self, session_id: u128, watch_id: u64) -> RS<()> {
+        let request_id = self.take_request_id();
+        let payload = encode_watch_cancel_request(
+            request_id,
+            &WatchCancelRequest::new(session_id, watch_id),
+        )?;
+        let frame = self.send_and_receive(&payload)?;
+        self.ensure_success_frame(&frame)?;
+        decode_watch_response(&frame).map(|_| ())
+    }
+
+    /// Block until the server pushes the next event for a watch of this
+    /// connection.
+    pub fn next_watch_event(&mut self) -> RS<WatchEvent> {
+        if let Some(event) = self.watch_events.pop_front() {
+            return Ok(event);
+        }
+        let frame = self.read_frame()?;
+        if frame.header().message_type() == MessageType::WatchEvent {
+            return decode_watch_event(&frame);
+        }
+        self.ensure_success_frame(&frame)?;
+        Err(mudu_error!(
+            ErrorCode::Network,
+            format!(
+                "unexpected {:?} frame while waiting for a watch event",
+                frame.header().message_type()
+            )
+        ))
+    }
+
     fn take_request_id(&mut self) -> u64 {
         let request_id = self.next_request_id;
         self.next_request_id += 1;
@@ -263,7 +600,16 @@ impl SyncClient {
         self.stream
             .flush()
             .map_err(|e| mudu_error!(ErrorCode::Network, "flush request frame error", e))?;
+        loop {
+            let frame = self.read_frame()?;
+            if frame.header().message_type() != MessageType::WatchEvent {
+                return Ok(frame);
+            }
+            self.watch_events.push_back(decode_watch_event(&frame)?);
+        }
+    }
 
+    fn read_frame(&mut self) -> RS<Frame> {
         let mut header = [0u8; HEADER_LEN];
         self.stream
             .read_exact(&mut header)
@@ -300,10 +646,18 @@ impl SyncClient {
 mod tests {
     use super::*;
     use mudu_contract::protocol::{
-        GetResponse, ProcedureInvokeResponse, PutResponse, RangeScanResponse, SessionCloseResponse,
-        SessionCreateResponse, encode_get_response, encode_procedure_invoke_response,
-        encode_put_response, encode_range_scan_response, encode_session_close_response,
-        encode_session_create_response,
+        CasResponse, CdcOp, CdcPosition, DeleteResponse, GetResponse, IncrResponse, KvChange,
+        KvNamespaceListResponse, KvNamespaceResponse, ProcedureInvokeResponse, PutResponse,
+        RangeScanResponse, SessionCloseResponse, SessionCreateResponse, WatchChange, WatchResponse,
+        WatchTarget, decode_cas_request, decode_delete_many_request, decode_delete_request,
+        decode_get_request, decode_incr_request, decode_kv_namespace_create_request,
+        decode_kv_namespace_list_request, decode_put_if_absent_request, decode_put_many_request,
+        decode_put_request, decode_range_scan_request, decode_watch_ack_request,
+        decode_watch_register_request, encode_cas_response, encode_delete_response,
+        encode_get_response, encode_incr_response, encode_kv_namespace_list_response,
+        encode_kv_namespace_response, encode_procedure_invoke_response, encode_put_response,
+        encode_range_scan_response, encode_session_close_response, encode_session_create_response,
+        encode_watch_event, encode_watch_register_response, encode_watch_response,
     };
     use mudu_sys::net::sync::StdTcpListener;
     use mudu_sys::task::sync::spawn_thread;
@@ -409,6 +763,232 @@ mod tests {
         server.join().unwrap();
     }
 
+    #[test]
+    fn client_delete_and_multi_key_decode() {
+        let Some(listener) = bind_test_listener() else {
+            return;
+        };
+        let addr = listener.local_addr().unwrap();
+        let server = spawn_thread(move || {
+            let (mut socket, _) = listener.accept().unwrap();
+            for _ in 0..3 {
+                let mut header = [0u8; HEADER_LEN];
+                socket.read_exact(&mut header).unwrap();
+                let payload_len = FrameHeader::decode_header_bytes(&header)
+                    .unwrap()
+                    .payload_len() as usize;
+                let mut body = vec![0u8; payload_len];
+                socket.read_exact(&mut body).unwrap();
+                let mut request = Vec::from(header);
+                request.extend_from_slice(&body);
+                let frame = Frame::decode(&request).unwrap();
+                let request_id = frame.header().request_id();
+                let response = match frame.header().message_type() {
+                    MessageType::Delete => {
+                        assert_eq!(decode_delete_request(&frame).unwrap().key(), b"k");
+                        encode_delete_response(request_id, &DeleteResponse::new(true))
+                    }
+                    MessageType::PutMany => {
+                        assert_eq!(decode_put_many_request(&frame).unwrap().items().len(), 2);
+                        encode_put_response(request_id, &PutResponse::new(true))
+                    }
+                    MessageType::DeleteMany => {
+                        assert_eq!(decode_delete_many_request(&frame).unwrap().keys().len(), 2);
+                        encode_delete_response(request_id, &DeleteResponse::new(true))
+                    }
+                    other => unreachable!("unexpected message type {:?}", other),
+                }
+                .unwrap();
+                socket.write_all(&response).unwrap();
+            }
+        })
+        .unwrap();
+
+        let mut client = SyncClient::connect(addr).unwrap();
+        client.delete(7, b"k".to_vec()).unwrap();
+        client
+            .put_many(
+                7,
+                vec![
+                    KeyValue::new(b"a".to_vec(), b"1".to_vec()),
+                    KeyValue::new(b"b".to_vec(), b"2".to_vec()),
+                ],
+            )
+            .unwrap();
+        client
+            .delete_many(7, vec![b"a".to_vec(), b"b".to_vec()])
+            .unwrap();
+        server.join().unwrap();
+    }
+
+    #[test]
+    fn client_atomic_updates_decode() {
+        let Some(listener) = bind_test_listener() else {
+            return;
+        };
+        let addr = listener.local_addr().unwrap();
+        let server = spawn_thread(move || {
+            let (mut socket, _) = listener.accept().unwrap();
+            for _ in 0..3 {
+                let mut header = [0u8; HEADER_LEN];
+                socket.read_exact(&mut header).unwrap();
+                let payload_len = FrameHeader::decode_header_bytes(&header)
+                    .unwrap()
+                    .payload_len() as usize;
+                let mut body = vec![0u8; payload_len];
+                socket.read_exact(&mut body).unwrap();
+                let mut request = Vec::from(header);
+                request.extend_from_slice(&body);
+                let frame = Frame::decode(&request).unwrap();
+                let request_id = frame.header().request_id();
+                let response = match frame.header().message_type() {
+                    MessageType::Cas => {
+                        let request = decode_cas_request(&frame).unwrap();
+                        assert_eq!(request.expected(), Some(b"1".as_slice()));
+                        encode_cas_response(request_id, &CasResponse::new(false))
+                    }
+                    MessageType::Incr => {
+                        assert_eq!(decode_incr_request(&frame).unwrap().delta(), -2);
+                        encode_incr_response(request_id, &IncrResponse::new(3))
+                    }
+                    MessageType::PutIfAbsent => {
+                        assert_eq!(decode_put_if_absent_request(&frame).unwrap().key(), b"k");
+                        encode_cas_response(request_id, &CasResponse::new(true))
+                    }
+                    other => unreachable!("unexpected message type {:?}", other),
+                }
+                .unwrap();
+                socket.write_all(&response).unwrap();
+            }
+        })
+        .unwrap();
+
+        let mut client = SyncClient::connect(addr).unwrap();
+        assert!(
+            !client
+                .cas(7, b"k".to_vec(), Some(b"1".to_vec()), b"2".to_vec())
+                .unwrap()
+        );
+        assert_eq!(client.incr(7, b"n".to_vec(), -2).unwrap(), 3);
+        assert!(
+            client
+                .put_if_absent(7, b"k".to_vec(), b"v".to_vec())
+                .unwrap()
+        );
+        server.join().unwrap();
+    }
+
+    #[test]
+    fn client_range_scan_page_decode() {
+        let Some(listener) = bind_test_listener() else {
+            return;
+        };
+        let addr = listener.local_addr().unwrap();
+        let server = spawn_thread(move || {
+            let (mut socket, _) = listener.accept().unwrap();
+            let mut header = [0u8; HEADER_LEN];
+            socket.read_exact(&mut header).unwrap();
+            let payload_len = FrameHeader::decode_header_bytes(&header)
+                .unwrap()
+                .payload_len() as usize;
+            let mut body = vec![0u8; payload_len];
+            socket.read_exact(&mut body).unwrap();
+            let mut request = Vec::from(header);
+            request.extend_from_slice(&body);
+            let frame = Frame::decode(&request).unwrap();
+            let request = decode_range_scan_request(&frame).unwrap();
+            assert_eq!(request.options().limit(), 1);
+            assert!(request.options().reverse());
+            let response = encode_range_scan_response(
+                frame.header().request_id(),
+                &RangeScanResponse::new(vec![KeyValue::new(b"b".to_vec(), b"2".to_vec())])
+                    .with_continuation(Some(b"b".to_vec())),
+            )
+            .unwrap();
+            socket.write_all(&response).unwrap();
+        })
+        .unwrap();
+
+        let mut client = SyncClient::connect(addr).unwrap();
+        let options = KvRangeOptions::new().with_limit(1).with_reverse(true);
+        let page = client
+            .range_scan_page(7, b"a".to_vec(), b"z".to_vec(), options)
+            .unwrap();
+        assert_eq!(page.items(), &[(b"b".to_vec(), b"2".to_vec())]);
+        assert_eq!(page.continuation(), Some(b"b".as_slice()));
+        server.join().unwrap();
+    }
+
+    #[test]
+    fn client_kv_namespace_decode() {
+        let Some(listener) = bind_test_listener() else {
+            return;
+        };
+        let addr = listener.local_addr().unwrap();
+        let server = spawn_thread(move || {
+            let (mut socket, _) = listener.accept().unwrap();
+            for _ in 0..4 {
+                let mut header = [0u8; HEADER_LEN];
+                socket.read_exact(&mut header).unwrap();
+                let payload_len = FrameHeader::decode_header_bytes(&header)
+                    .unwrap()
+                    .payload_len() as usize;
+                let mut body = vec![0u8; payload_len];
+                socket.read_exact(&mut body).unwrap();
+                let mut request = Vec::from(header);
+                request.extend_from_slice(&body);
+                let frame = Frame::decode(&request).unwrap();
+                let request_id = frame.header().request_id();
+                let namespace = KvNamespace::new("app", "users").unwrap();
+                let response = match frame.header().message_type() {
+                    MessageType::KvNamespaceCreate => {
+                        let request = decode_kv_namespace_create_request(&frame).unwrap();
+                        assert_eq!(request.namespace(), "users");
+                        assert_eq!(request.partition_rule(), Some("by_key"));
+                        encode_kv_namespace_response(request_id, &KvNamespaceResponse::new(true))
+                    }
+                    MessageType::Put => {
+                        let request = decode_put_request(&frame).unwrap();
+                        assert_eq!(request.namespace(), Some("users"));
+                        encode_put_response(request_id, &PutResponse::new(true))
+                    }
+                    MessageType::Get => {
+                        let request = decode_get_request(&frame).unwrap();
+                        assert_eq!(request.namespace(), Some("users"));
+                        encode_get_response(request_id, &GetResponse::new(Some(b"v".to_vec())))
+                    }
+                    MessageType::KvNamespaceList => {
+                        let request = decode_kv_namespace_list_request(&frame).unwrap();
+                        assert_eq!(request.session_id(), 7);
+                        encode_kv_namespace_list_response(
+                            request_id,
+                            &KvNamespaceListResponse::new(vec![namespace]),
+                        )
+                    }
+                    other => unreachable!("unexpected message type {:?}", other),
+                }
+                .unwrap();
+                socket.write_all(&response).unwrap();
+            }
+        })
+        .unwrap();
+
+        let mut client = SyncClient::connect(addr).unwrap();
+        let namespace = KvNamespace::new("app", "users").unwrap();
+        client
+            .kv_namespace_create(7, "users", Some("by_key".to_string()))
+            .unwrap();
+        client
+            .put_in_namespace(7, "users", b"k".to_vec(), b"v".to_vec())
+            .unwrap();
+        assert_eq!(
+            client.get_in_namespace(7, "users", b"k".to_vec()).unwrap(),
+            Some(b"v".to_vec())
+        );
+        assert_eq!(client.kv_namespace_list(7).unwrap(), vec![namespace]);
+        server.join().unwrap();
+    }
+
     #[test]
     fn client_procedure_invoke_decode() {
         let Some(listener) = bind_test_listener() else {
@@ -498,4 +1078,75 @@ mod tests {
         assert!(closed);
         server.join().unwrap();
     }
+
+    #[test]
+    fn client_buffers_watch_events_pushed_before_a_response() {
+        let Some(listener) = bind_test_listener() else {
+            return;
+        };
+        let addr = listener.local_addr().unwrap();
+        let event = WatchEvent::new(
+            5,
+            1,
+            vec![WatchChange::Kv(KvChange::new(
+                1,
+                40,
+                9,
+                CdcOp::Put,
+                b"user-1".to_vec(),
+                Some(b"v".to_vec()),
+            ))],
+            vec![CdcPosition::new(1, 41)],
+        );
+        let pushed = event.clone();
+        let server = spawn_thread(move || {
+            let (mut socket, _) = listener.accept().unwrap();
+            for _ in 0..2 {
+                let mut header = [0u8; HEADER_LEN];
+                socket.read_exact(&mut header).unwrap();
+                let payload_len = FrameHeader::decode_header_bytes(&header)
+                    .unwrap()
+                    .payload_len() as usize;
+                let mut body = vec![0u8; payload_len];
+                socket.read_exact(&mut body).unwrap();
+                let mut request = Vec::from(header);
+                request.extend_from_slice(&body);
+                let frame = Frame::decode(&request).unwrap();
+                let request_id = frame.header().request_id();
+                let response = match frame.header().message_type() {
+                    MessageType::WatchRegister => {
+                        let request = decode_watch_register_request(&frame).unwrap();
+                        assert_eq!(request.target(), &WatchTarget::Prefix(b"user-".to_vec()));
+                        // The event overtakes the response to the ack below.
+                        let mut response = encode_watch_register_response(
+                            request_id,
+                            &WatchRegisterResponse::new(5, vec![CdcPosition::new(1, 40)]),
+                        )
+                        .unwrap();
+                        response.extend(encode_watch_event(&pushed).unwrap());
+                        response
+                    }
+                    MessageType::WatchAck => {
+                        assert_eq!(decode_watch_ack_request(&frame).unwrap().seq(), 1);
+                        encode_watch_response(request_id, &WatchResponse::new(true)).unwrap()
+                    }
+                    other => unreachable!("unexpected message type {:?}", other),
+                };
+                socket.write_all(&response).unwrap();
+            }
+        })
+        .unwrap();
+
+        let mut client = SyncClient::connect(addr).unwrap();
+        let registered = client
+            .watch_register(&WatchRegisterRequest::new(
+                7,
+                WatchTarget::Prefix(b"user-".to_vec()),
+            ))
+            .unwrap();
+        assert_eq!(registered.watch_id(), 5);
+        client.watch_ack(7, 5, 1).unwrap();
+        assert_eq!(client.next_watch_event().unwrap(), event);
+        server.join().unwrap();
+    }
 }
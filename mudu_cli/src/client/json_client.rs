@@ -11,15 +11,20 @@ use mudu::mudu_error;
 use mudu_binding::universal::uni_dat_value::UniDatValue;
 use mudu_binding::universal::uni_oid::UniOid;
 use mudu_binding::universal::uni_scalar_value::UniScalarValue;
+use mudu_contract::database::kv_range::KvRangeOptions;
 use mudu_contract::protocol::{
-    ClientRequest, GetRequest, KeyValue, ProcedureInvokeRequest, PutRequest, RangeScanRequest,
-    ServerResponse,
+    CasRequest, CdcPosition, ClientRequest, DeleteManyRequest, DeleteRequest, GetRequest,
+    IncrRequest, KeyValue, KvNamespaceCreateRequest, KvNamespaceDropRequest,
+    KvNamespaceListRequest, ProcedureInvokeRequest, PutIfAbsentRequest, PutManyRequest, PutRequest,
+    RangeScanRequest, ServerResponse, WatchAckRequest, WatchCancelRequest, WatchChange, WatchEvent,
+    WatchRegisterRequest, WatchTarget,
 };
 use mudu_type::dat_type_id::DatTypeID;
 use mudu_type::datum::DatumDyn;
 use serde::Deserialize;
 use serde::de::{self, Deserializer};
 use serde_json::{Value, json};
+use std::time::Duration;
 
 /// JSON wrapper around an async client.
 ///
@@ -65,55 +70,324 @@ where
     }
 
     /// Put a key-value item from a JSON request body.
+    ///
+    /// An optional `ttl_ms` makes the key expire that many milliseconds after
+    /// the write commits. `namespace` selects a KV namespace of the app the
+    /// session is bound to, as it does for `get`, `delete` and `range`.
     pub async fn put(&mut self, request: Value) -> RS<Value> {
         let request = serde_json::from_value::<JsonPutRequest>(request)
             .map_err(|e| mudu_error!(ErrorCode::Decode, "decode json put request error", e))?;
+        let namespace = request.namespace;
+        let mut put = PutRequest::new(
+            request.oid.to_oid(),
+            json_value_to_universal_bytes(request.key)?,
+            json_value_to_universal_bytes(request.value)?,
+        );
+        if let Some(ttl_ms) = request.ttl_ms {
+            put = put.with_ttl(Duration::from_millis(ttl_ms));
+        }
+        if let Some(namespace) = namespace {
+            put = put.with_namespace(namespace);
+        }
+        let response = self.inner.put(put).await?;
+        Ok(json!({ "ok": response.ok() }))
+    }
+
+    /// Get a value by key from a JSON request body.
+    pub async fn get(&mut self, request: Value) -> RS<Value> {
+        let request = serde_json::from_value::<JsonGetRequest>(request)
+            .map_err(|e| mudu_error!(ErrorCode::Decode, "decode json get request error", e))?;
+        let mut get = GetRequest::new(
+            request.oid.to_oid(),
+            json_value_to_universal_bytes(request.key)?,
+        );
+        if let Some(namespace) = request.namespace {
+            get = get.with_namespace(namespace);
+        }
+        let response = self.inner.get(get).await?;
+        match response.into_value() {
+            Some(value) => universal_bytes_to_json_value(&value),
+            None => Ok(Value::Null),
+        }
+    }
+
+    /// Delete a key from a JSON request body.
+    pub async fn delete(&mut self, request: Value) -> RS<Value> {
+        let request = serde_json::from_value::<JsonGetRequest>(request)
+            .map_err(|e| mudu_error!(ErrorCode::Decode, "decode json delete request error", e))?;
+        let mut delete = DeleteRequest::new(
+            request.oid.to_oid(),
+            json_value_to_universal_bytes(request.key)?,
+        );
+        if let Some(namespace) = request.namespace {
+            delete = delete.with_namespace(namespace);
+        }
+        let response = self.inner.delete(delete).await?;
+        Ok(json!({ "ok": response.ok() }))
+    }
+
+    /// Put several key-value items atomically from a JSON request body.
+    pub async fn put_many(&mut self, request: Value) -> RS<Value> {
+        let request = serde_json::from_value::<JsonPutManyRequest>(request)
+            .map_err(|e| mudu_error!(ErrorCode::Decode, "decode json put many request error", e))?;
+        let items = request
+            .items
+            .into_iter()
+            .map(|item| {
+                Ok(KeyValue::new(
+                    json_value_to_universal_bytes(item.key)?,
+                    json_value_to_universal_bytes(item.value)?,
+                ))
+            })
+            .collect::<RS<Vec<_>>>()?;
+        let response = self
+            .inner
+            .put_many(PutManyRequest::new(request.oid.to_oid(), items))
+            .await?;
+        Ok(json!({ "ok": response.ok() }))
+    }
+
+    /// Delete several keys atomically from a JSON request body.
+    pub async fn delete_many(&mut self, request: Value) -> RS<Value> {
+        let request = serde_json::from_value::<JsonDeleteManyRequest>(request).map_err(|e| {
+            mudu_error!(
+                ErrorCode::Decode,
+                "decode json delete many request error",
+                e
+            )
+        })?;
+        let keys = request
+            .keys
+            .into_iter()
+            .map(json_value_to_universal_bytes)
+            .collect::<RS<Vec<_>>>()?;
         let response = self
             .inner
-            .put(PutRequest::new(
+            .delete_many(DeleteManyRequest::new(request.oid.to_oid(), keys))
+            .await?;
+        Ok(json!({ "ok": response.ok() }))
+    }
+
+    /// Compare-and-swap a key from a JSON request body; a null `expected`
+    /// requires the key to be absent.
+    pub async fn cas(&mut self, request: Value) -> RS<Value> {
+        let request = serde_json::from_value::<JsonCasRequest>(request)
+            .map_err(|e| mudu_error!(ErrorCode::Decode, "decode json cas request error", e))?;
+        let expected = match request.expected {
+            Value::Null => None,
+            expected => Some(json_value_to_universal_bytes(expected)?),
+        };
+        let response = self
+            .inner
+            .cas(CasRequest::new(
                 request.oid.to_oid(),
                 json_value_to_universal_bytes(request.key)?,
+                expected,
                 json_value_to_universal_bytes(request.value)?,
             ))
             .await?;
-        Ok(json!({ "ok": response.ok() }))
+        Ok(json!({ "swapped": response.swapped() }))
     }
 
-    /// Get a value by key from a JSON request body.
-    pub async fn get(&mut self, request: Value) -> RS<Value> {
-        let request = serde_json::from_value::<JsonGetRequest>(request)
-            .map_err(|e| mudu_error!(ErrorCode::Decode, "decode json get request error", e))?;
+    /// Increment an integer counter from a JSON request body.
+    pub async fn incr(&mut self, request: Value) -> RS<Value> {
+        let request = serde_json::from_value::<JsonIncrRequest>(request)
+            .map_err(|e| mudu_error!(ErrorCode::Decode, "decode json incr request error", e))?;
         let response = self
             .inner
-            .get(GetRequest::new(
+            .incr(IncrRequest::new(
                 request.oid.to_oid(),
                 json_value_to_universal_bytes(request.key)?,
+                request.delta,
             ))
             .await?;
-        match response.into_value() {
-            Some(value) => universal_bytes_to_json_value(&value),
-            None => Ok(Value::Null),
+        Ok(json!({ "value": response.value() }))
+    }
+
+    /// Put a key-value item only if the key is absent, from a JSON request body.
+    pub async fn put_if_absent(&mut self, request: Value) -> RS<Value> {
+        let request = serde_json::from_value::<JsonPutRequest>(request).map_err(|e| {
+            mudu_error!(
+                ErrorCode::Decode,
+                "decode json put if absent request error",
+                e
+            )
+        })?;
+        if request.ttl_ms.is_some() {
+            return Err(mudu_error!(
+                ErrorCode::InvalidArgument,
+                "put if absent does not accept ttl_ms"
+            ));
         }
+        if request.namespace.is_some() {
+            return Err(mudu_error!(
+                ErrorCode::InvalidArgument,
+                "put if absent does not accept a namespace"
+            ));
+        }
+        let response = self
+            .inner
+            .put_if_absent(PutIfAbsentRequest::new(
+                request.oid.to_oid(),
+                json_value_to_universal_bytes(request.key)?,
+                json_value_to_universal_bytes(request.value)?,
+            ))
+            .await?;
+        Ok(json!({ "swapped": response.swapped() }))
     }
 
     /// Scan a key range from a JSON request body.
+    ///
+    /// A plain request returns an array of `{key, value}` items. A request
+    /// carrying `limit`, `reverse`, `prefix`, `keys_only` or `continuation`
+    /// returns `{"items": [...], "continuation": token}`, where `token` is
+    /// `null` on the last page and is passed back as `continuation` to read
+    /// the next one.
     pub async fn range(&mut self, request: Value) -> RS<Value> {
         let request = serde_json::from_value::<JsonRangeRequest>(request)
             .map_err(|e| mudu_error!(ErrorCode::Decode, "decode json range request error", e))?;
+        let options = request.options()?;
+        let mut range = RangeScanRequest::new(
+            request.oid.to_oid(),
+            json_value_to_universal_bytes(request.start_key)?,
+            json_value_to_universal_bytes(request.end_key)?,
+        )
+        .with_options(options.clone());
+        if let Some(namespace) = request.namespace {
+            range = range.with_namespace(namespace);
+        }
+        let response = self.inner.range_scan(range).await?;
+        let continuation = response.continuation().map(encode_base64_bytes);
+        let items = response
+            .into_items()
+            .into_iter()
+            .map(|item| {
+                if options.keys_only() {
+                    Ok(json!({ "key": universal_bytes_to_json_value(item.key())? }))
+                } else {
+                    key_value_to_json(item)
+                }
+            })
+            .collect::<RS<Vec<_>>>()?;
+        if options.is_default() {
+            return Ok(Value::Array(items));
+        }
+        Ok(json!({ "items": items, "continuation": continuation }))
+    }
+
+    /// Create the KV namespace `namespace` of the app the session is bound
+    /// to; an optional `partition_rule` spreads its keys over the rule's
+    /// partitions.
+    pub async fn kv_namespace_create(&mut self, request: Value) -> RS<Value> {
+        let request = serde_json::from_value::<JsonKvNamespaceRequest>(request).map_err(|e| {
+            mudu_error!(
+                ErrorCode::Decode,
+                "decode json kv namespace create request error",
+                e
+            )
+        })?;
+        let mut create = KvNamespaceCreateRequest::new(request.oid.to_oid(), request.namespace);
+        if let Some(partition_rule) = request.partition_rule {
+            create = create.with_partition_rule(partition_rule);
+        }
+        let response = self.inner.kv_namespace_create(create).await?;
+        Ok(json!({ "ok": response.ok() }))
+    }
+
+    /// Drop the KV namespace `namespace` of the app the session is bound to.
+    pub async fn kv_namespace_drop(&mut self, request: Value) -> RS<Value> {
+        let request = serde_json::from_value::<JsonKvNamespaceRequest>(request).map_err(|e| {
+            mudu_error!(
+                ErrorCode::Decode,
+                "decode json kv namespace drop request error",
+                e
+            )
+        })?;
         let response = self
             .inner
-            .range_scan(RangeScanRequest::new(
+            .kv_namespace_drop(KvNamespaceDropRequest::new(
                 request.oid.to_oid(),
-                json_value_to_universal_bytes(request.start_key)?,
-                json_value_to_universal_bytes(request.end_key)?,
+                request.namespace,
             ))
             .await?;
-        let items = response
-            .into_items()
-            .into_iter()
-            .map(key_value_to_json)
-            .collect::<RS<Vec<_>>>()?;
-        Ok(Value::Array(items))
+        Ok(json!({ "ok": response.ok() }))
+    }
+
+    /// List the KV namespace names of the app the session is bound to.
+    pub async fn kv_namespace_list(&mut self, request: Value) -> RS<Value> {
+        let request =
+            serde_json::from_value::<JsonKvNamespaceListRequest>(request).map_err(|e| {
+                mudu_error!(
+                    ErrorCode::Decode,
+                    "decode json kv namespace list request error",
+                    e
+                )
+            })?;
+        let response = self
+            .inner
+            .kv_namespace_list(KvNamespaceListRequest::new(request.oid.to_oid()))
+            .await?;
+        Ok(Value::Array(
+            response
+                .namespaces()
+                .iter()
+                .map(|namespace| json!(namespace.name()))
+                .collect(),
+        ))
+    }
+
+    /// Watch a key, key prefix or table key range and return the first
+    /// `events` events the server pushes for it, acknowledging each one.
+    ///
+    /// `key` is a universal value like the key of `get`, `prefix` raw key
+    /// bytes like the prefix of `range`, and `table` with optional `start`
+    /// and `end` key objects selects table rows. `from` takes the
+    /// `positions` of an earlier result to resume right after it.
+    pub async fn watch(&mut self, request: Value) -> RS<Value> {
+        let request = serde_json::from_value::<JsonWatchRequest>(request)
+            .map_err(|e| mudu_error!(ErrorCode::Decode, "decode json watch request error", e))?;
+        let session_id = request.oid.to_oid();
+        let mut register = WatchRegisterRequest::new(session_id, request.target()?)
+            .with_max_in_flight(request.max_in_flight)
+            .with_max_changes(request.max_changes);
+        if let Some(from) = request.from {
+            register = register.with_from(
+                from.into_iter()
+                    .map(|position| CdcPosition::new(position.worker_id, position.lsn))
+                    .collect(),
+            );
+        }
+        let registered = self.inner.watch_register(register).await?;
+        let watch_id = registered.watch_id();
+        let mut positions = registered.positions().to_vec();
+        let mut events = Vec::new();
+        let mut failed = false;
+        while events.len() < request.events.max(1) as usize {
+            let event = self.inner.next_watch_event().await?;
+            if event.watch_id() != watch_id {
+                continue;
+            }
+            positions = event.positions().to_vec();
+            failed = event.error().is_some();
+            events.push(watch_event_to_json(&event));
+            if failed {
+                // The server already dropped a watch that failed.
+                break;
+            }
+            self.inner
+                .watch_ack(WatchAckRequest::new(session_id, watch_id, event.seq()))
+                .await?;
+        }
+        if !failed {
+            self.inner
+                .watch_cancel(WatchCancelRequest::new(session_id, watch_id))
+                .await?;
+        }
+        Ok(json!({
+            "watch_id": watch_id,
+            "events": events,
+            "positions": cdc_positions_to_json(&positions),
+        }))
     }
 
     /// Invoke a stored procedure from a JSON request body.
@@ -152,6 +426,8 @@ struct JsonCommandRequest {
 struct JsonGetRequest {
     oid: UniOid,
     key: Value,
+    #[serde(default)]
+    namespace: Option<String>,
 }
 
 #[derive(Debug, Deserialize)]
@@ -159,6 +435,62 @@ struct JsonPutRequest {
     oid: UniOid,
     key: Value,
     value: Value,
+    #[serde(default)]
+    ttl_ms: Option<u64>,
+    #[serde(default)]
+    namespace: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+struct JsonKvNamespaceRequest {
+    oid: UniOid,
+    namespace: String,
+    #[serde(default)]
+    partition_rule: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+struct JsonKvNamespaceListRequest {
+    oid: UniOid,
+}
+
+#[derive(Debug, Deserialize)]
+struct JsonKeyValue {
+    key: Value,
+    value: Value,
+}
+
+#[derive(Debug, Deserialize)]
+struct JsonPutManyRequest {
+    oid: UniOid,
+    items: Vec<JsonKeyValue>,
+}
+
+#[derive(Debug, Deserialize)]
+struct JsonDeleteManyRequest {
+    oid: UniOid,
+    keys: Vec<Value>,
+}
+
+#[derive(Debug, Deserialize)]
+struct JsonCasRequest {
+    oid: UniOid,
+    key: Value,
+    #[serde(default)]
+    expected: Value,
+    value: Value,
+}
+
+#[derive(Debug, Deserialize)]
+struct JsonIncrRequest {
+    oid: UniOid,
+    key: Value,
+    #[serde(default = "default_incr_delta")]
+    delta: i64,
+}
+
+fn default_incr_delta() -> i64 {
+    1
 }
 
 #[derive(Debug, Deserialize)]
@@ -166,6 +498,92 @@ struct JsonRangeRequest {
     oid: UniOid,
     start_key: Value,
     end_key: Value,
+    #[serde(default)]
+    limit: u32,
+    #[serde(default)]
+    reverse: bool,
+    #[serde(default)]
+    prefix: Option<Value>,
+    #[serde(default)]
+    keys_only: bool,
+    #[serde(default)]
+    continuation: Option<Value>,
+    #[serde(default)]
+    namespace: Option<String>,
+}
+
+impl JsonRangeRequest {
+    // The prefix and the token are raw key bytes rather than universal
+    // values: a string is taken as its UTF-8 bytes, `{"base64": ...}` as the
+    // decoded bytes.
+    fn options(&self) -> RS<KvRangeOptions> {
+        let mut options = KvRangeOptions::new()
+            .with_limit(self.limit)
+            .with_reverse(self.reverse)
+            .with_keys_only(self.keys_only);
+        if let Some(prefix) = &self.prefix {
+            options = options.with_prefix(decode_raw_bytes(prefix)?);
+        }
+        if let Some(token) = &self.continuation {
+            options = options.with_continuation(decode_raw_bytes(token)?);
+        }
+        Ok(options)
+    }
+}
+
+#[derive(Debug, Deserialize)]
+struct JsonWatchRequest {
+    oid: UniOid,
+    #[serde(default)]
+    key: Option<Value>,
+    #[serde(default)]
+    prefix: Option<Value>,
+    #[serde(default)]
+    table: Option<String>,
+    #[serde(default)]
+    start: Option<Value>,
+    #[serde(default)]
+    end: Option<Value>,
+    #[serde(default)]
+    from: Option<Vec<JsonCdcPosition>>,
+    #[serde(default = "default_watch_events")]
+    events: u32,
+    #[serde(default)]
+    max_in_flight: u32,
+    #[serde(default)]
+    max_changes: u32,
+}
+
+fn default_watch_events() -> u32 {
+    1
+}
+
+impl JsonWatchRequest {
+    fn target(&self) -> RS<WatchTarget> {
+        match (&self.key, &self.prefix, &self.table) {
+            (Some(key), None, None) => Ok(WatchTarget::Key(json_value_to_universal_bytes(
+                key.clone(),
+            )?)),
+            (None, Some(prefix), None) => Ok(WatchTarget::Prefix(decode_raw_bytes(prefix)?)),
+            (None, None, Some(table)) => Ok(WatchTarget::TableRange {
+                table: table.clone(),
+                start: self.start.clone(),
+                end: self.end.clone(),
+            }),
+            _ => Err(mudu_error!(
+                ErrorCode::InvalidArgument,
+                "a watch needs exactly one of key, prefix and table"
+            )),
+        }
+    }
+}
+
+// Worker ids are 128-bit, so they travel as strings like session ids.
+#[derive(Debug, Deserialize)]
+struct JsonCdcPosition {
+    #[serde(deserialize_with = "deserialize_u128_session_id")]
+    worker_id: u128,
+    lsn: u64,
 }
 
 #[derive(Debug, Deserialize)]
@@ -273,6 +691,8 @@ fn uni_dat_value_to_json_value(value: UniDatValue) -> RS<Value> {
             UniScalarValue::Time(v) => Ok(Value::String(v)),
             UniScalarValue::Timestamp(v) => Ok(Value::String(v)),
             UniScalarValue::TimestampTz(v) => Ok(Value::String(v)),
+            UniScalarValue::Uuid(v) => Ok(Value::String(v)),
+            UniScalarValue::Json(v) => Ok(serde_json::from_str(&v).unwrap_or(Value::String(v))),
         },
         UniDatValue::Array(items) | UniDatValue::Record(items) => items
             .into_iter()
@@ -305,6 +725,29 @@ fn decode_json_bytes(value: Value) -> RS<Vec<u8>> {
         .map_err(|e| mudu_error!(ErrorCode::Encode, "encode json payload error", e))
 }
 
+fn decode_raw_bytes(value: &Value) -> RS<Vec<u8>> {
+    match value {
+        Value::String(text) => Ok(text.as_bytes().to_vec()),
+        Value::Object(object) if object.len() == 1 => {
+            let encoded = object
+                .get("base64")
+                .and_then(Value::as_str)
+                .ok_or_else(|| mudu_error!(ErrorCode::Decode, "base64 payload must be a string"))?;
+            base64::engine::general_purpose::STANDARD
+                .decode(encoded)
+                .map_err(|e| mudu_error!(ErrorCode::Decode, "decode base64 payload error", e))
+        }
+        _ => Err(mudu_error!(
+            ErrorCode::Decode,
+            "raw key bytes must be a string or a base64 object"
+        )),
+    }
+}
+
+fn encode_base64_bytes(bytes: &[u8]) -> Value {
+    json!({ "base64": base64::engine::general_purpose::STANDARD.encode(bytes) })
+}
+
 fn encode_json_bytes(bytes: &[u8]) -> RS<Value> {
     match serde_json::from_slice::<Value>(bytes) {
         Ok(value) => Ok(value),
@@ -321,6 +764,60 @@ fn key_value_to_json(key_value: KeyValue) -> RS<Value> {
     }))
 }
 
+fn cdc_positions_to_json(positions: &[CdcPosition]) -> Value {
+    Value::Array(
+        positions
+            .iter()
+            .map(|position| {
+                json!({
+                    "worker_id": position.worker_id().to_string(),
+                    "lsn": position.lsn(),
+                })
+            })
+            .collect(),
+    )
+}
+
+// Keys and values of KV changes are shown like `range` items; bytes that are
+// not a universal value, such as keys written by a procedure, as base64.
+fn kv_bytes_to_json(bytes: &[u8]) -> Value {
+    universal_bytes_to_json_value(bytes).unwrap_or_else(|_| encode_base64_bytes(bytes))
+}
+
+fn watch_event_to_json(event: &WatchEvent) -> Value {
+    let changes = event
+        .changes()
+        .iter()
+        .map(|change| match change {
+            WatchChange::Kv(change) => json!({
+                "worker_id": change.worker_id().to_string(),
+                "lsn": change.lsn(),
+                "xid": change.xid(),
+                "op": change.op(),
+                "key": kv_bytes_to_json(change.key()),
+                "value": change.value().map(kv_bytes_to_json),
+            }),
+            WatchChange::Row(change) => json!({
+                "worker_id": change.worker_id().to_string(),
+                "lsn": change.lsn(),
+                "xid": change.xid(),
+                "op": change.op(),
+                "key": change.key(),
+                "row": change.row(),
+            }),
+        })
+        .collect::<Vec<_>>();
+    let mut json = json!({
+        "seq": event.seq(),
+        "changes": changes,
+        "positions": cdc_positions_to_json(event.positions()),
+    });
+    if let Some(error) = event.error() {
+        json["error"] = json!(error);
+    }
+    json
+}
+
 fn server_response_to_json(response: &ServerResponse) -> RS<Value> {
     let columns = response
         .row_desc()
@@ -366,10 +863,12 @@ mod tests {
     use super::*;
     use crate::client::async_client::AsyncClient;
     use async_trait::async_trait;
+    use mudu_contract::database::kv_namespace::KvNamespace;
     use mudu_contract::protocol::{
-        GetResponse, KeyValue, ProcedureInvokeResponse, PutResponse, RangeScanResponse,
-        ServerResponse, SessionCloseRequest, SessionCloseResponse, SessionCreateRequest,
-        SessionCreateResponse,
+        CasResponse, CdcOp, DeleteResponse, GetResponse, IncrResponse, KeyValue, KvChange,
+        KvNamespaceListResponse, KvNamespaceResponse, ProcedureInvokeResponse, PutResponse,
+        RangeScanResponse, ServerResponse, SessionCloseRequest, SessionCloseResponse,
+        SessionCreateRequest, SessionCreateResponse, WatchRegisterResponse, WatchResponse,
     };
     use mudu_contract::tuple::datum_desc::DatumDesc;
     use mudu_contract::tuple::tuple_field_desc::TupleFieldDesc;
@@ -386,6 +885,17 @@ mod tests {
         last_put: Option<PutRequest>,
         last_range: Option<RangeScanRequest>,
         last_invoke: Option<ProcedureInvokeRequest>,
+        last_delete: Option<DeleteRequest>,
+        last_put_many: Option<PutManyRequest>,
+        last_delete_many: Option<DeleteManyRequest>,
+        last_cas: Option<CasRequest>,
+        last_incr: Option<IncrRequest>,
+        last_put_if_absent: Option<PutIfAbsentRequest>,
+        last_kv_namespace_create: Option<KvNamespaceCreateRequest>,
+        last_kv_namespace_drop: Option<KvNamespaceDropRequest>,
+        last_watch_register: Option<WatchRegisterRequest>,
+        watch_acks: Vec<u64>,
+        watch_cancelled: bool,
     }
 
     impl MockAsyncIoUringTcpClient {
@@ -398,6 +908,17 @@ mod tests {
                 last_put: None,
                 last_range: None,
                 last_invoke: None,
+                last_delete: None,
+                last_put_many: None,
+                last_delete_many: None,
+                last_cas: None,
+                last_incr: None,
+                last_put_if_absent: None,
+                last_kv_namespace_create: None,
+                last_kv_namespace_drop: None,
+                last_watch_register: None,
+                watch_acks: Vec::new(),
+                watch_cancelled: false,
             }
         }
     }
@@ -451,7 +972,39 @@ mod tests {
             Ok(PutResponse::new(true))
         }
 
+        async fn delete(&mut self, request: DeleteRequest) -> RS<DeleteResponse> {
+            self.last_delete = Some(request);
+            Ok(DeleteResponse::new(true))
+        }
+
+        async fn put_many(&mut self, request: PutManyRequest) -> RS<PutResponse> {
+            self.last_put_many = Some(request);
+            Ok(PutResponse::new(true))
+        }
+
+        async fn delete_many(&mut self, request: DeleteManyRequest) -> RS<DeleteResponse> {
+            self.last_delete_many = Some(request);
+            Ok(DeleteResponse::new(true))
+        }
+
+        async fn cas(&mut self, request: CasRequest) -> RS<CasResponse> {
+            self.last_cas = Some(request);
+            Ok(CasResponse::new(true))
+        }
+
+        async fn incr(&mut self, request: IncrRequest) -> RS<IncrResponse> {
+            let value = request.delta() + 10;
+            self.last_incr = Some(request);
+            Ok(IncrResponse::new(value))
+        }
+
+        async fn put_if_absent(&mut self, request: PutIfAbsentRequest) -> RS<CasResponse> {
+            self.last_put_if_absent = Some(request);
+            Ok(CasResponse::new(false))
+        }
+
         async fn range_scan(&mut self, request: RangeScanRequest) -> RS<RangeScanResponse> {
+            let continuation = (request.options().limit() != 0).then(|| b"tok".to_vec());
             self.last_range = Some(request);
             Ok(RangeScanResponse::new(vec![
                 KeyValue::new(
@@ -462,6 +1015,33 @@ mod tests {
                     json_value_to_universal_bytes(json!({"base64": "/wA="})).unwrap(),
                     json_value_to_universal_bytes(json!({"base64": "AQI="})).unwrap(),
                 ),
+            ])
+            .with_continuation(continuation))
+        }
+
+        async fn kv_namespace_create(
+            &mut self,
+            request: KvNamespaceCreateRequest,
+        ) -> RS<KvNamespaceResponse> {
+            self.last_kv_namespace_create = Some(request);
+            Ok(KvNamespaceResponse::new(true))
+        }
+
+        async fn kv_namespace_drop(
+            &mut self,
+            request: KvNamespaceDropRequest,
+        ) -> RS<KvNamespaceResponse> {
+            self.last_kv_namespace_drop = Some(request);
+            Ok(KvNamespaceResponse::new(true))
+        }
+
+        async fn kv_namespace_list(
+            &mut self,
+            _request: KvNamespaceListRequest,
+        ) -> RS<KvNamespaceListResponse> {
+            Ok(KvNamespaceListResponse::new(vec![
+                KvNamespace::new("shop", "orders").unwrap(),
+                KvNamespace::new("shop", "users").unwrap(),
             ]))
         }
 
@@ -486,6 +1066,42 @@ mod tests {
         ) -> RS<SessionCloseResponse> {
             Ok(SessionCloseResponse::new(true))
         }
+
+        async fn watch_register(
+            &mut self,
+            request: WatchRegisterRequest,
+        ) -> RS<WatchRegisterResponse> {
+            self.last_watch_register = Some(request);
+            Ok(WatchRegisterResponse::new(3, vec![CdcPosition::new(1, 10)]))
+        }
+
+        async fn watch_ack(&mut self, request: WatchAckRequest) -> RS<WatchResponse> {
+            self.watch_acks.push(request.seq());
+            Ok(WatchResponse::new(true))
+        }
+
+        async fn watch_cancel(&mut self, request: WatchCancelRequest) -> RS<WatchResponse> {
+            assert_eq!(request.watch_id(), 3);
+            self.watch_cancelled = true;
+            Ok(WatchResponse::new(true))
+        }
+
+        async fn next_watch_event(&mut self) -> RS<WatchEvent> {
+            let seq = self.watch_acks.len() as u64 + 1;
+            Ok(WatchEvent::new(
+                3,
+                seq,
+                vec![WatchChange::Kv(KvChange::new(
+                    1,
+                    10 + seq,
+                    seq,
+                    CdcOp::Put,
+                    json_value_to_universal_bytes(json!("user-1")).unwrap(),
+                    Some(json_value_to_universal_bytes(json!(seq)).unwrap()),
+                ))],
+                vec![CdcPosition::new(1, 11 + seq)],
+            ))
+        }
     }
 
     #[test]
@@ -575,6 +1191,37 @@ mod tests {
                 ])
             );
 
+            let page = client
+                .range(json!({
+                    "oid": {"h": 0, "l": 7},
+                    "start_key": "a",
+                    "end_key": "z",
+                    "limit": 2,
+                    "reverse": true,
+                    "prefix": "us",
+                    "keys_only": true,
+                    "continuation": {"base64": "dG9r"}
+                }))
+                .await
+                .unwrap();
+            assert_eq!(
+                page,
+                json!({
+                    "items": [{"key": "a"}, {"key": {"base64": "/wA="}}],
+                    "continuation": {"base64": "dG9r"}
+                })
+            );
+            let options = client.inner.last_range.as_ref().unwrap().options().clone();
+            assert_eq!(
+                options,
+                KvRangeOptions::new()
+                    .with_limit(2)
+                    .with_reverse(true)
+                    .with_prefix(b"us".to_vec())
+                    .with_keys_only(true)
+                    .with_continuation(b"tok".to_vec())
+            );
+
             let invoke = client
                 .invoke(json!({
                     "session_id": 7,
@@ -606,6 +1253,116 @@ mod tests {
         .unwrap();
     }
 
+    #[test]
+    fn json_client_maps_delete_and_multi_key_payloads() {
+        mudu_sys::task::async_::block_on_tokio_current_thread(async move {
+            let mut client = JsonClient::new(MockAsyncIoUringTcpClient::new());
+
+            let delete = client
+                .delete(json!({
+                    "oid": {"h": 0, "l": 7},
+                    "key": "k1"
+                }))
+                .await
+                .unwrap();
+            assert_eq!(delete, json!({"ok": true}));
+
+            let put_many = client
+                .put_many(json!({
+                    "oid": {"h": 0, "l": 7},
+                    "items": [
+                        {"key": "k1", "value": 1},
+                        {"key": {"base64": "/wA="}, "value": "v2"}
+                    ]
+                }))
+                .await
+                .unwrap();
+            assert_eq!(put_many, json!({"ok": true}));
+
+            let delete_many = client
+                .delete_many(json!({
+                    "oid": {"h": 0, "l": 7},
+                    "keys": ["k1", {"base64": "/wA="}]
+                }))
+                .await
+                .unwrap();
+            assert_eq!(delete_many, json!({"ok": true}));
+
+            let inner = client.into_inner();
+            assert_eq!(
+                universal_bytes_to_json_value(inner.last_delete.unwrap().key()).unwrap(),
+                json!("k1")
+            );
+            let put_many = inner.last_put_many.unwrap();
+            assert_eq!(put_many.session_id(), 7);
+            assert_eq!(put_many.items().len(), 2);
+            assert_eq!(
+                universal_bytes_to_json_value(put_many.items()[1].key()).unwrap(),
+                json!({"base64": "/wA="})
+            );
+            let keys = inner.last_delete_many.unwrap().into_keys();
+            assert_eq!(keys.len(), 2);
+            assert_eq!(
+                universal_bytes_to_json_value(&keys[0]).unwrap(),
+                json!("k1")
+            );
+        })
+        .unwrap();
+    }
+
+    #[test]
+    fn json_client_maps_atomic_update_payloads() {
+        mudu_sys::task::async_::block_on_tokio_current_thread(async move {
+            let mut client = JsonClient::new(MockAsyncIoUringTcpClient::new());
+
+            let cas = client
+                .cas(json!({
+                    "oid": {"h": 0, "l": 7},
+                    "key": "k1",
+                    "value": "v2"
+                }))
+                .await
+                .unwrap();
+            assert_eq!(cas, json!({"swapped": true}));
+
+            let incr = client
+                .incr(json!({
+                    "oid": {"h": 0, "l": 7},
+                    "key": "counter",
+                    "delta": -3
+                }))
+                .await
+                .unwrap();
+            assert_eq!(incr, json!({"value": 7}));
+
+            let put_if_absent = client
+                .put_if_absent(json!({
+                    "oid": {"h": 0, "l": 7},
+                    "key": "k1",
+                    "value": "v1"
+                }))
+                .await
+                .unwrap();
+            assert_eq!(put_if_absent, json!({"swapped": false}));
+
+            let inner = client.into_inner();
+            let cas = inner.last_cas.unwrap();
+            assert_eq!(cas.expected(), None);
+            assert_eq!(
+                universal_bytes_to_json_value(cas.value()).unwrap(),
+                json!("v2")
+            );
+            let incr = inner.last_incr.unwrap();
+            assert_eq!(incr.session_id(), 7);
+            assert_eq!(incr.delta(), -3);
+            assert_eq!(
+                universal_bytes_to_json_value(inner.last_put_if_absent.unwrap().key()).unwrap(),
+                json!("k1")
+            );
+        })
+        .unwrap();
+    }
+
     #[test]
     fn json_client_accepts_large_universal_oid() {
         mudu_sys::task::async_::block_on_tokio_current_thread(async move {
@@ -624,4 +1381,145 @@ mod tests {
         })
         .unwrap();
     }
+
+    #[test]
+    fn json_client_maps_put_ttl() {
+        mudu_sys::task::async_::block_on_tokio_current_thread(async move {
+            let mut client = JsonClient::new(MockAsyncIoUringTcpClient::new());
+            client
+                .put(json!({
+                    "oid": {"h": 0, "l": 7},
+                    "key": "k",
+                    "value": "v",
+                    "ttl_ms": 1500
+                }))
+                .await
+                .unwrap();
+            let err = client
+                .put_if_absent(json!({
+                    "oid": {"h": 0, "l": 7},
+                    "key": "k",
+                    "value": "v",
+                    "ttl_ms": 1500
+                }))
+                .await
+                .unwrap_err();
+            assert_eq!(err.ec(), ErrorCode::InvalidArgument);
+            let inner = client.into_inner();
+            assert_eq!(
+                inner.last_put.unwrap().ttl(),
+                Some(Duration::from_millis(1500))
+            );
+        })
+        .unwrap();
+    }
+
+    #[test]
+    fn json_client_maps_kv_namespace_payloads() {
+        mudu_sys::task::async_::block_on_tokio_current_thread(async move {
+            let mut client = JsonClient::new(MockAsyncIoUringTcpClient::new());
+            let created = client
+                .kv_namespace_create(json!({
+                    "oid": {"h": 0, "l": 7},
+                    "namespace": "users",
+                    "partition_rule": "by_key"
+                }))
+                .await
+                .unwrap();
+            assert_eq!(created, json!({"ok": true}));
+            let listed = client
+                .kv_namespace_list(json!({"oid": {"h": 0, "l": 7}}))
+                .await
+                .unwrap();
+            assert_eq!(listed, json!(["orders", "users"]));
+            client
+                .kv_namespace_drop(json!({"oid": {"h": 0, "l": 7}, "namespace": "orders"}))
+                .await
+                .unwrap();
+
+            client
+                .put(json!({
+                    "oid": {"h": 0, "l": 7},
+                    "namespace": "users",
+                    "key": "k",
+                    "value": "v"
+                }))
+                .await
+                .unwrap();
+            client
+                .get(json!({"oid": {"h": 0, "l": 7}, "key": "k"}))
+                .await
+                .unwrap();
+            client
+                .delete(json!({"oid": {"h": 0, "l": 7}, "namespace": "users", "key": "k"}))
+                .await
+                .unwrap();
+            let err = client
+                .put_if_absent(json!({
+                    "oid": {"h": 0, "l": 7},
+                    "namespace": "users",
+                    "key": "k",
+                    "value": "v"
+                }))
+                .await
+                .unwrap_err();
+            assert_eq!(err.ec(), ErrorCode::InvalidArgument);
+
+            let inner = client.into_inner();
+            let create = inner.last_kv_namespace_create.unwrap();
+            assert_eq!(create.namespace(), "users");
+            assert_eq!(create.partition_rule(), Some("by_key"));
+            assert_eq!(inner.last_kv_namespace_drop.unwrap().namespace(), "orders");
+            assert_eq!(inner.last_put.unwrap().namespace(), Some("users"));
+            assert_eq!(inner.last_get.unwrap().namespace(), None);
+            assert_eq!(inner.last_delete.unwrap().namespace(), Some("users"));
+        })
+        .unwrap();
+    }
+
+    #[test]
+    fn json_client_maps_watch_events() {
+        mudu_sys::task::async_::block_on_tokio_current_thread(async move {
+            let mut client = JsonClient::new(MockAsyncIoUringTcpClient::new());
+            let response = client
+                .watch(json!({
+                    "oid": {"h": 0, "l": 7},
+                    "prefix": "user-",
+                    "events": 2,
+                    "from": [{"worker_id": "1", "lsn": 10}]
+                }))
+                .await
+                .unwrap();
+            assert_eq!(response["watch_id"], json!(3));
+            assert_eq!(response["events"][1]["seq"], json!(2));
+            assert_eq!(
+                response["events"][0]["changes"][0],
+                json!({
+                    "worker_id": "1",
+                    "lsn": 11,
+                    "xid": 1,
+                    "op": "put",
+                    "key": "user-1",
+                    "value": 1,
+                })
+            );
+            assert_eq!(
+                response["positions"],
+                json!([{"worker_id": "1", "lsn": 13}])
+            );
+            let err = client
+                .watch(json!({"oid": {"h": 0, "l": 7}, "key": "k", "prefix": "k"}))
+                .await
+                .unwrap_err();
+            assert_eq!(err.ec(), ErrorCode::InvalidArgument);
+
+            let inner = client.into_inner();
+            let register = inner.last_watch_register.unwrap();
+            assert_eq!(register.target(), &WatchTarget::Prefix(b"user-".to_vec()));
+            assert_eq!(register.from(), Some([CdcPosition::new(1, 10)].as_slice()));
+            assert_eq!(inner.watch_acks, vec![1, 2]);
+            assert!(inner.watch_cancelled);
+        })
+        .unwrap();
+    }
 }
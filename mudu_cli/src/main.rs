@@ -20,8 +20,9 @@ use mudu_binding::procedure::procedure_invoke;
 use mudu_cli::client::async_client::{AsyncClient, AsyncClientImpl};
 use mudu_cli::client::json_client::JsonClient;
 use mudu_cli::management::{
-    fetch_app_detail, fetch_app_list, fetch_proc_desc, fetch_server_topology, install_app_package,
-    route_partition, uninstall_app,
+    backup, fetch_app_detail, fetch_app_list, fetch_proc_desc, fetch_replication_status,
+    fetch_server_topology, install_app_package, promote_replica, restore_backup, route_partition,
+    uninstall_app,
 };
 use mudu_contract::procedure::procedure_param::ProcedureParam;
 use mudu_contract::protocol::{ProcedureInvokeRequest, SessionCloseRequest, SessionCreateRequest};
@@ -38,6 +39,12 @@ Examples:
   mcli --addr 127.0.0.1:9527 command --json '{\"app_name\":\"demo\",\"sql\":\"select 1\"}'
   mcli --addr 127.0.0.1:9527 shell --app demo
   mcli --addr 127.0.0.1:9527 put --json-file put.json
+  mcli --addr 127.0.0.1:9527 delete-many --json '{\"keys\":[\"user-1\",\"user-2\"]}'
+  mcli --addr 127.0.0.1:9527 incr --json '{\"key\":\"visits\",\"delta\":1}'
+  mcli --addr 127.0.0.1:9527 range --json '{\"start_key\":\"a\",\"end_key\":\"z\",\"limit\":100}'
+  mcli --addr 127.0.0.1:9527 kv-namespace-create --json '{\"app_name\":\"shop\",\"namespace\":\"carts\"}'
+  mcli --addr 127.0.0.1:9527 put --json '{\"app_name\":\"shop\",\"namespace\":\"carts\",\"key\":\"u1\",\"value\":\"v\"}'
+  mcli --addr 127.0.0.1:9527 watch --json '{\"prefix\":\"user-\",\"events\":3}'
   cat invoke.json | mcli --addr 127.0.0.1:9527 invoke --json-file -
   mcli --http-addr 127.0.0.1:8300 app-install --mpk target/wasm32-wasip2/release/key-value.mpk
   mcli --addr 127.0.0.1:9527 --http-addr 127.0.0.1:8300 app-invoke --app kv --module key_value --proc kv_read --json '{\"user_key\":\"user-1\"}'
@@ -45,7 +52,11 @@ Examples:
   mcli --http-addr 127.0.0.1:8300 app-detail --app wallet
   mcli --http-addr 127.0.0.1:8300 app-uninstall --app wallet
   mcli --http-addr 127.0.0.1:8300 server-topology
-  mcli --http-addr 127.0.0.1:8300 partition-route --rule-name user_rule --key user-100";
+  mcli --http-addr 127.0.0.1:8300 partition-route --rule-name user_rule --key user-100
+  mcli --http-addr 127.0.0.1:8300 backup --out /var/backups/mududb-1
+  mcli --http-addr 127.0.0.1:8300 restore --from /var/backups/mududb-1 --data-dir /var/lib/mududb-restored
+  mcli --http-addr 127.0.0.1:8310 replication-status
+  mcli --http-addr 127.0.0.1:8310 replica-promote";
 
 /// Top-level command-line arguments for `mcli`.
 #[derive(Parser, Debug)]
@@ -93,8 +104,28 @@ enum Commands {
     Put(JsonRequestArgs),
     /// Get a key using a JSON request body.
     Get(JsonRequestArgs),
+    /// Delete a key using a JSON request body.
+    Delete(JsonRequestArgs),
+    /// Put several key-value items atomically using a JSON request body.
+    PutMany(JsonRequestArgs),
+    /// Delete several keys atomically using a JSON request body.
+    DeleteMany(JsonRequestArgs),
+    /// Compare-and-swap a key using a JSON request body.
+    Cas(JsonRequestArgs),
+    /// Increment an integer counter using a JSON request body.
+    Incr(JsonRequestArgs),
+    /// Put a key-value item only if the key is absent, using a JSON request body.
+    PutIfAbsent(JsonRequestArgs),
     /// Scan a key range using a JSON request body.
     Range(JsonRequestArgs),
+    /// Create a KV namespace of an app using a JSON request body.
+    KvNamespaceCreate(JsonRequestArgs),
+    /// Drop a KV namespace and its keys using a JSON request body.
+    KvNamespaceDrop(JsonRequestArgs),
+    /// List the KV namespaces of an app using a JSON request body.
+    KvNamespaceList(JsonRequestArgs),
+    /// Watch a key, prefix or table key range and print the pushed changes, using a JSON request body.
+    Watch(JsonRequestArgs),
     /// Invoke a procedure using a JSON request body.
     Invoke(JsonRequestArgs),
     /// Install a .mpk package through the HTTP management API.
@@ -111,6 +142,14 @@ enum Commands {
     ServerTopology,
     /// Route a partition key/range via HTTP management API.
     PartitionRoute(PartitionRouteArgs),
+    /// Take an online backup of the server via HTTP management API.
+    Backup(BackupArgs),
+    /// Restore a backup into an empty data directory via HTTP management API.
+    Restore(RestoreArgs),
+    /// Show how far a read replica lags behind its primary via HTTP management API.
+    ReplicationStatus,
+    /// Promote a read replica to accept writes via HTTP management API.
+    ReplicaPromote,
 }
 
 /// Arguments for subcommands that take an inline JSON body or a JSON file.
@@ -188,6 +227,31 @@ struct PartitionRouteArgs {
     end: Option<Vec<String>>,
 }
 
+/// Arguments for the `backup` subcommand.
+#[derive(Args, Debug)]
+struct BackupArgs {
+    #[arg(
+        long,
+        help = "Empty or missing directory on the server host to write the backup to."
+    )]
+    out: String,
+}
+
+/// Arguments for the `restore` subcommand.
+#[derive(Args, Debug)]
+struct RestoreArgs {
+    #[arg(
+        long,
+        help = "Backup directory on the server host written by `backup`."
+    )]
+    from: String,
+    #[arg(
+        long = "data-dir",
+        help = "Empty or missing data directory on the server host to restore into."
+    )]
+    data_dir: String,
+}
+
 /// Trait for connecting a [`JsonClient`] during command dispatch.
 #[async_trait]
 pub(crate) trait JsonClientConnect: Send + Sync {
@@ -306,7 +370,7 @@ where
                 )
             })?;
             let session_id = client
-                .create_session(SessionCreateRequest::new(None))
+                .create_session(kv_session_create_request(&request))
                 .await
                 .map_err(|e| {
                     mudu_error!(
@@ -335,7 +399,7 @@ where
                 )
             })?;
             let session_id = client
-                .create_session(SessionCreateRequest::new(None))
+                .create_session(kv_session_create_request(&request))
                 .await
                 .map_err(|e| {
                     mudu_error!(
@@ -355,7 +419,68 @@ where
                 .await;
             response
         }
-        Commands::Range(args) => {
+        Commands::Delete(args) => {
+            let request = load_json_request(args)?;
+            let mut client = async_connector.connect(&addr).await.map_err(|e| {
+                mudu_error!(
+                    ErrorCode::Network,
+                    format!("connect {} failed: {}", addr, e)
+                )
+            })?;
+            let session_id = client
+                .create_session(kv_session_create_request(&request))
+                .await
+                .map_err(|e| {
+                    mudu_error!(
+                        ErrorCode::Network,
+                        format!("session-create for delete failed: {}", e)
+                    )
+                })?
+                .session_id();
+            let request = with_oid(request, session_id)?;
+            let mut client = JsonClient::new(client);
+            let response = client.delete(request).await.map_err(|e| {
+                mudu_error!(ErrorCode::Network, format!("delete request failed: {}", e))
+            })?;
+            let _ = client
+                .into_inner()
+                .close_session(SessionCloseRequest::new(session_id))
+                .await;
+            response
+        }
+        Commands::PutMany(args) => {
+            let request = load_json_request(args)?;
+            let mut client = async_connector.connect(&addr).await.map_err(|e| {
+                mudu_error!(
+                    ErrorCode::Network,
+                    format!("connect {} failed: {}", addr, e)
+                )
+            })?;
+            let session_id = client
+                .create_session(SessionCreateRequest::new(None))
+                .await
+                .map_err(|e| {
+                    mudu_error!(
+                        ErrorCode::Network,
+                        format!("session-create for put-many failed: {}", e)
+                    )
+                })?
+                .session_id();
+            let request = with_oid(request, session_id)?;
+            let mut client = JsonClient::new(client);
+            let response = client.put_many(request).await.map_err(|e| {
+                mudu_error!(
+                    ErrorCode::Network,
+                    format!("put-many request failed: {}", e)
+                )
+            })?;
+            let _ = client
+                .into_inner()
+                .close_session(SessionCloseRequest::new(session_id))
+                .await;
+            response
+        }
+        Commands::DeleteMany(args) => {
             let request = load_json_request(args)?;
             let mut client = async_connector.connect(&addr).await.map_err(|e| {
                 mudu_error!(
@@ -366,6 +491,128 @@ where
             let session_id = client
                 .create_session(SessionCreateRequest::new(None))
                 .await
+                .map_err(|e| {
+                    mudu_error!(
+                        ErrorCode::Network,
+                        format!("session-create for delete-many failed: {}", e)
+                    )
+                })?
+                .session_id();
+            let request = with_oid(request, session_id)?;
+            let mut client = JsonClient::new(client);
+            let response = client.delete_many(request).await.map_err(|e| {
+                mudu_error!(
+                    ErrorCode::Network,
+                    format!("delete-many request failed: {}", e)
+                )
+            })?;
+            let _ = client
+                .into_inner()
+                .close_session(SessionCloseRequest::new(session_id))
+                .await;
+            response
+        }
+        Commands::Cas(args) => {
+            let request = load_json_request(args)?;
+            let mut client = async_connector.connect(&addr).await.map_err(|e| {
+                mudu_error!(
+                    ErrorCode::Network,
+                    format!("connect {} failed: {}", addr, e)
+                )
+            })?;
+            let session_id = client
+                .create_session(SessionCreateRequest::new(None))
+                .await
+                .map_err(|e| {
+                    mudu_error!(
+                        ErrorCode::Network,
+                        format!("session-create for cas failed: {}", e)
+                    )
+                })?
+                .session_id();
+            let request = with_oid(request, session_id)?;
+            let mut client = JsonClient::new(client);
+            let response = client.cas(request).await.map_err(|e| {
+                mudu_error!(ErrorCode::Network, format!("cas request failed: {}", e))
+            })?;
+            let _ = client
+                .into_inner()
+                .close_session(SessionCloseRequest::new(session_id))
+                .await;
+            response
+        }
+        Commands::Incr(args) => {
+            let request = load_json_request(args)?;
+            let mut client = async_connector.connect(&addr).await.map_err(|e| {
+                mudu_error!(
+                    ErrorCode::Network,
+                    format!("connect {} failed: {}", addr, e)
+                )
+            })?;
+            let session_id = client
+                .create_session(SessionCreateRequest::new(None))
+                .await
+                .map_err(|e| {
+                    mudu_error!(
+                        ErrorCode::Network,
+                        format!("session-create for incr failed: {}", e)
+                    )
+                })?
+                .session_id();
+            let request = with_oid(request, session_id)?;
+            let mut client = JsonClient::new(client);
+            let response = client.incr(request).await.map_err(|e| {
+                mudu_error!(ErrorCode::Network, format!("incr request failed: {}", e))
+            })?;
+            let _ = client
+                .into_inner()
+                .close_session(SessionCloseRequest::new(session_id))
+                .await;
+            response
+        }
+        Commands::PutIfAbsent(args) => {
+            let request = load_json_request(args)?;
+            let mut client = async_connector.connect(&addr).await.map_err(|e| {
+                mudu_error!(
+                    ErrorCode::Network,
+                    format!("connect {} failed: {}", addr, e)
+                )
+            })?;
+            let session_id = client
+                .create_session(SessionCreateRequest::new(None))
+                .await
+                .map_err(|e| {
+                    mudu_error!(
+                        ErrorCode::Network,
+                        format!("session-create for put-if-absent failed: {}", e)
+                    )
+                })?
+                .session_id();
+            let request = with_oid(request, session_id)?;
+            let mut client = JsonClient::new(client);
+            let response = client.put_if_absent(request).await.map_err(|e| {
+                mudu_error!(
+                    ErrorCode::Network,
+                    format!("put-if-absent request failed: {}", e)
+                )
+            })?;
+            let _ = client
+                .into_inner()
+                .close_session(SessionCloseRequest::new(session_id))
+                .await;
+            response
+        }
+        Commands::Range(args) => {
+            let request = load_json_request(args)?;
+            let mut client = async_connector.connect(&addr).await.map_err(|e| {
+                mudu_error!(
+                    ErrorCode::Network,
+                    format!("connect {} failed: {}", addr, e)
+                )
+            })?;
+            let session_id = client
+                .create_session(kv_session_create_request(&request))
+                .await
                 .map_err(|e| {
                     mudu_error!(
                         ErrorCode::Network,
@@ -384,6 +631,131 @@ where
                 .await;
             response
         }
+        Commands::KvNamespaceCreate(args) => {
+            let request = load_json_request(args)?;
+            let mut client = async_connector.connect(&addr).await.map_err(|e| {
+                mudu_error!(
+                    ErrorCode::Network,
+                    format!("connect {} failed: {}", addr, e)
+                )
+            })?;
+            let session_id = client
+                .create_session(kv_session_create_request(&request))
+                .await
+                .map_err(|e| {
+                    mudu_error!(
+                        ErrorCode::Network,
+                        format!("session-create for kv-namespace-create failed: {}", e)
+                    )
+                })?
+                .session_id();
+            let request = with_oid(request, session_id)?;
+            let mut client = JsonClient::new(client);
+            let response = client.kv_namespace_create(request).await.map_err(|e| {
+                mudu_error!(
+                    ErrorCode::Network,
+                    format!("kv-namespace-create request failed: {}", e)
+                )
+            })?;
+            let _ = client
+                .into_inner()
+                .close_session(SessionCloseRequest::new(session_id))
+                .await;
+            response
+        }
+        Commands::KvNamespaceDrop(args) => {
+            let request = load_json_request(args)?;
+            let mut client = async_connector.connect(&addr).await.map_err(|e| {
+                mudu_error!(
+                    ErrorCode::Network,
+                    format!("connect {} failed: {}", addr, e)
+                )
+            })?;
+            let session_id = client
+                .create_session(kv_session_create_request(&request))
+                .await
+                .map_err(|e| {
+                    mudu_error!(
+                        ErrorCode::Network,
+                        format!("session-create for kv-namespace-drop failed: {}", e)
+                    )
+                })?
+                .session_id();
+            let request = with_oid(request, session_id)?;
+            let mut client = JsonClient::new(client);
+            let response = client.kv_namespace_drop(request).await.map_err(|e| {
+                mudu_error!(
+                    ErrorCode::Network,
+                    format!("kv-namespace-drop request failed: {}", e)
+                )
+            })?;
+            let _ = client
+                .into_inner()
+                .close_session(SessionCloseRequest::new(session_id))
+                .await;
+            response
+        }
+        Commands::KvNamespaceList(args) => {
+            let request = load_json_request(args)?;
+            let mut client = async_connector.connect(&addr).await.map_err(|e| {
+                mudu_error!(
+                    ErrorCode::Network,
+                    format!("connect {} failed: {}", addr, e)
+                )
+            })?;
+            let session_id = client
+                .create_session(kv_session_create_request(&request))
+                .await
+                .map_err(|e| {
+                    mudu_error!(
+                        ErrorCode::Network,
+                        format!("session-create for kv-namespace-list failed: {}", e)
+                    )
+                })?
+                .session_id();
+            let request = with_oid(request, session_id)?;
+            let mut client = JsonClient::new(client);
+            let response = client.kv_namespace_list(request).await.map_err(|e| {
+                mudu_error!(
+                    ErrorCode::Network,
+                    format!("kv-namespace-list request failed: {}", e)
+                )
+            })?;
+            let _ = client
+                .into_inner()
+                .close_session(SessionCloseRequest::new(session_id))
+                .await;
+            response
+        }
+        Commands::Watch(args) => {
+            let request = load_json_request(args)?;
+            let mut client = async_connector.connect(&addr).await.map_err(|e| {
+                mudu_error!(
+                    ErrorCode::Network,
+                    format!("connect {} failed: {}", addr, e)
+                )
+            })?;
+            let session_id = client
+                .create_session(SessionCreateRequest::new(None))
+                .await
+                .map_err(|e| {
+                    mudu_error!(
+                        ErrorCode::Network,
+                        format!("session-create for watch failed: {}", e)
+                    )
+                })?
+                .session_id();
+            let request = with_oid(request, session_id)?;
+            let mut client = JsonClient::new(client);
+            let response = client.watch(request).await.map_err(|e| {
+                mudu_error!(ErrorCode::Network, format!("watch request failed: {}", e))
+            })?;
+            let _ = client
+                .into_inner()
+                .close_session(SessionCloseRequest::new(session_id))
+                .await;
+            response
+        }
         Commands::Invoke(args) => {
             let request = load_json_request(args)?;
             let mut client = async_connector.connect(&addr).await.map_err(|e| {
@@ -557,6 +929,50 @@ where
                 )
             })?
         }
+        Commands::Backup(args) => serde_json::to_value(
+            backup(&http_addr, &args.out)
+                .await
+                .map_err(|e| mudu_error!(ErrorCode::Network, e))?,
+        )
+        .map_err(|e| {
+            mudu_error!(
+                ErrorCode::Encode,
+                format!("serialize backup response failed: {}", e)
+            )
+        })?,
+        Commands::Restore(args) => serde_json::to_value(
+            restore_backup(&http_addr, &args.from, &args.data_dir)
+                .await
+                .map_err(|e| mudu_error!(ErrorCode::Network, e))?,
+        )
+        .map_err(|e| {
+            mudu_error!(
+                ErrorCode::Encode,
+                format!("serialize restore response failed: {}", e)
+            )
+        })?,
+        Commands::ReplicationStatus => serde_json::to_value(
+            fetch_replication_status(&http_addr)
+                .await
+                .map_err(|e| mudu_error!(ErrorCode::Network, e))?,
+        )
+        .map_err(|e| {
+            mudu_error!(
+                ErrorCode::Encode,
+                format!("serialize replication status failed: {}", e)
+            )
+        })?,
+        Commands::ReplicaPromote => serde_json::to_value(
+            promote_replica(&http_addr)
+                .await
+                .map_err(|e| mudu_error!(ErrorCode::Network, e))?,
+        )
+        .map_err(|e| {
+            mudu_error!(
+                ErrorCode::Encode,
+                format!("serialize promote response failed: {}", e)
+            )
+        })?,
     };
 
     Ok(output)
@@ -583,6 +999,17 @@ fn load_required_text(inline: Option<String>, file: Option<PathBuf>) -> RS<Strin
     }
 }
 
+/// Session open request for a KV command. An `app_name` in the request
+/// binds the session to that installed app, and a `namespace` in the request
+/// names one of the app's KV namespaces.
+fn kv_session_create_request(request: &Value) -> SessionCreateRequest {
+    let config_json = request
+        .get("app_name")
+        .and_then(Value::as_str)
+        .map(|app| json!({ "session_id": 0, "app": app }).to_string());
+    SessionCreateRequest::new(config_json)
+}
+
 fn with_oid(request: Value, session_id: u128) -> RS<Value> {
     let mut request = request
         .as_object()
@@ -1052,6 +1479,18 @@ mod tests {
         assert_eq!(request["key"], json!("user-1"));
     }
 
+    #[test]
+    fn kv_session_create_request_binds_app() {
+        let request = kv_session_create_request(&json!({"app_name": "shop", "key": "k"}));
+        let config: Value = serde_json::from_str(request.config_json().unwrap()).unwrap();
+        assert_eq!(config, json!({"session_id": 0, "app": "shop"}));
+        assert!(
+            kv_session_create_request(&json!({"key": "k"}))
+                .config_json()
+                .is_none()
+        );
+    }
+
     #[test]
     fn with_invoke_session_id_injects_session_id_string() {
         let request =
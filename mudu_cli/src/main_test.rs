@@ -14,12 +14,14 @@ use mudu_binding::procedure::procedure_invoke;
 use mudu_binding::universal::{uni_dat_value::UniDatValue, uni_scalar_value::UniScalarValue};
 use mudu_cli::client::async_client::{AsyncClient, AsyncClientImpl};
 use mudu_cli::client::json_client::JsonClient;
+use mudu_contract::database::kv_namespace::KvNamespace;
 use mudu_contract::procedure::procedure_result::ProcedureResult;
 use mudu_contract::protocol::{
-    ClientRequest, GetRequest, GetResponse, KeyValue, ProcedureInvokeRequest,
-    ProcedureInvokeResponse, PutRequest, PutResponse, RangeScanRequest, RangeScanResponse,
-    ServerResponse, SessionCloseRequest, SessionCloseResponse, SessionCreateRequest,
-    SessionCreateResponse,
+    CasRequest, CasResponse, ClientRequest, DeleteManyRequest, DeleteRequest, DeleteResponse,
+    GetRequest, GetResponse, IncrRequest, IncrResponse, KeyValue, KvNamespaceListRequest,
+    KvNamespaceListResponse, ProcedureInvokeRequest, ProcedureInvokeResponse, PutIfAbsentRequest,
+    PutManyRequest, PutRequest, PutResponse, RangeScanRequest, RangeScanResponse, ServerResponse,
+    SessionCloseRequest, SessionCloseResponse, SessionCreateRequest, SessionCreateResponse,
 };
 use mudu_contract::tuple::datum_desc::DatumDesc;
 use mudu_contract::tuple::tuple_field_desc::TupleFieldDesc;
@@ -40,9 +42,13 @@ use std::path::{Path, PathBuf};
 struct MockAsyncClient {
     command_response: Option<ServerResponse>,
     put_response: Option<PutResponse>,
+    delete_response: Option<DeleteResponse>,
+    cas_response: Option<CasResponse>,
+    incr_response: Option<IncrResponse>,
     get_response: Option<GetResponse>,
     range_response: Option<RangeScanResponse>,
     invoke_procedure_response: Option<ProcedureInvokeResponse>,
+    kv_namespace_list_response: Option<KvNamespaceListResponse>,
     session_id: u128,
 }
 
@@ -51,9 +57,13 @@ impl MockAsyncClient {
         Self {
             command_response: None,
             put_response: None,
+            delete_response: None,
+            cas_response: None,
+            incr_response: None,
             get_response: None,
             range_response: None,
             invoke_procedure_response: None,
+            kv_namespace_list_response: None,
             session_id: 42,
         }
     }
@@ -68,6 +78,21 @@ impl MockAsyncClient {
         self
     }
 
+    fn with_delete_response(mut self, response: DeleteResponse) -> Self {
+        self.delete_response = Some(response);
+        self
+    }
+
+    fn with_cas_response(mut self, response: CasResponse) -> Self {
+        self.cas_response = Some(response);
+        self
+    }
+
+    fn with_incr_response(mut self, response: IncrResponse) -> Self {
+        self.incr_response = Some(response);
+        self
+    }
+
     fn with_get_response(mut self, response: GetResponse) -> Self {
         self.get_response = Some(response);
         self
@@ -82,6 +107,11 @@ impl MockAsyncClient {
         self.invoke_procedure_response = Some(response);
         self
     }
+
+    fn with_kv_namespace_list_response(mut self, response: KvNamespaceListResponse) -> Self {
+        self.kv_namespace_list_response = Some(response);
+        self
+    }
 }
 
 #[async_trait]
@@ -114,12 +144,57 @@ impl AsyncClient for MockAsyncClient {
             .ok_or_else(|| mudu_error!(ErrorCode::Internal, "unexpected put"))
     }
 
+    async fn delete(&mut self, _request: DeleteRequest) -> RS<DeleteResponse> {
+        self.delete_response
+            .take()
+            .ok_or_else(|| mudu_error!(ErrorCode::Internal, "unexpected delete"))
+    }
+
+    async fn put_many(&mut self, _request: PutManyRequest) -> RS<PutResponse> {
+        self.put_response
+            .take()
+            .ok_or_else(|| mudu_error!(ErrorCode::Internal, "unexpected put_many"))
+    }
+
+    async fn delete_many(&mut self, _request: DeleteManyRequest) -> RS<DeleteResponse> {
+        self.delete_response
+            .take()
+            .ok_or_else(|| mudu_error!(ErrorCode::Internal, "unexpected delete_many"))
+    }
+
+    async fn cas(&mut self, _request: CasRequest) -> RS<CasResponse> {
+        self.cas_response
+            .take()
+            .ok_or_else(|| mudu_error!(ErrorCode::Internal, "unexpected cas"))
+    }
+
+    async fn incr(&mut self, _request: IncrRequest) -> RS<IncrResponse> {
+        self.incr_response
+            .take()
+            .ok_or_else(|| mudu_error!(ErrorCode::Internal, "unexpected incr"))
+    }
+
+    async fn put_if_absent(&mut self, _request: PutIfAbsentRequest) -> RS<CasResponse> {
+        self.cas_response
+            .take()
+            .ok_or_else(|| mudu_error!(ErrorCode::Internal, "unexpected put_if_absent"))
+    }
+
     async fn range_scan(&mut self, _request: RangeScanRequest) -> RS<RangeScanResponse> {
         self.range_response
             .take()
             .ok_or_else(|| mudu_error!(ErrorCode::Internal, "unexpected range_scan"))
     }
 
+    async fn kv_namespace_list(
+        &mut self,
+        _request: KvNamespaceListRequest,
+    ) -> RS<KvNamespaceListResponse> {
+        self.kv_namespace_list_response
+            .take()
+            .ok_or_else(|| mudu_error!(ErrorCode::Internal, "unexpected kv_namespace_list"))
+    }
+
     async fn invoke_procedure(
         &mut self,
         _request: ProcedureInvokeRequest,
@@ -328,6 +403,81 @@ fn run_put_subcommand_succeeds() {
     .unwrap();
 }
 
+#[test]
+fn run_delete_and_multi_key_subcommands_succeed() {
+    mudu_sys::task::async_::block_on_tokio_current_thread(async {
+        let commands = [
+            (
+                Commands::Delete(json_args(r#"{"key":"k"}"#)),
+                MockAsyncClient::new().with_delete_response(DeleteResponse::new(true)),
+            ),
+            (
+                Commands::PutMany(json_args(
+                    r#"{"items":[{"key":"k1","value":"v1"},{"key":"k2","value":"v2"}]}"#,
+                )),
+                MockAsyncClient::new().with_put_response(PutResponse::new(true)),
+            ),
+            (
+                Commands::DeleteMany(json_args(r#"{"keys":["k1","k2"]}"#)),
+                MockAsyncClient::new().with_delete_response(DeleteResponse::new(true)),
+            ),
+        ];
+        for (command, async_client) in commands {
+            let output = run_with_connectors(
+                cli(command),
+                &MockJsonConnector {
+                    client: MockAsyncClient::new(),
+                },
+                &MockAsyncConnector {
+                    client: async_client,
+                },
+            )
+            .await
+            .unwrap();
+            assert_eq!(output, json!({"ok": true}));
+        }
+    })
+    .unwrap();
+}
+
+#[test]
+fn run_atomic_update_subcommands_succeed() {
+    mudu_sys::task::async_::block_on_tokio_current_thread(async {
+        let commands = [
+            (
+                Commands::Cas(json_args(r#"{"key":"k","expected":"v1","value":"v2"}"#)),
+                MockAsyncClient::new().with_cas_response(CasResponse::new(true)),
+                json!({"swapped": true}),
+            ),
+            (
+                Commands::Incr(json_args(r#"{"key":"visits","delta":5}"#)),
+                MockAsyncClient::new().with_incr_response(IncrResponse::new(5)),
+                json!({"value": 5}),
+            ),
+            (
+                Commands::PutIfAbsent(json_args(r#"{"key":"k","value":"v"}"#)),
+                MockAsyncClient::new().with_cas_response(CasResponse::new(false)),
+                json!({"swapped": false}),
+            ),
+        ];
+        for (command, async_client, expected) in commands {
+            let output = run_with_connectors(
+                cli(command),
+                &MockJsonConnector {
+                    client: MockAsyncClient::new(),
+                },
+                &MockAsyncConnector {
+                    client: async_client,
+                },
+            )
+            .await
+            .unwrap();
+            assert_eq!(output, expected);
+        }
+    })
+    .unwrap();
+}
+
 #[test]
 fn run_get_subcommand_succeeds() {
     mudu_sys::task::async_::block_on_tokio_current_thread(async {
@@ -372,6 +522,29 @@ fn run_range_subcommand_succeeds() {
     .unwrap();
 }
 
+#[test]
+fn run_kv_namespace_list_subcommand_succeeds() {
+    mudu_sys::task::async_::block_on_tokio_current_thread(async {
+        let async_client = MockAsyncClient::new().with_kv_namespace_list_response(
+            KvNamespaceListResponse::new(vec![KvNamespace::new("shop", "carts").unwrap()]),
+        );
+        let command = Commands::KvNamespaceList(json_args(r#"{"app_name":"shop"}"#));
+        let output = run_with_connectors(
+            cli(command),
+            &MockJsonConnector {
+                client: MockAsyncClient::new(),
+            },
+            &MockAsyncConnector {
+                client: async_client,
+            },
+        )
+        .await
+        .unwrap();
+        assert_eq!(output, json!(["carts"]));
+    })
+    .unwrap();
+}
+
 #[test]
 fn run_invoke_subcommand_succeeds() {
     mudu_sys::task::async_::block_on_tokio_current_thread(async {
@@ -606,6 +779,46 @@ fn run_server_topology_subcommand_succeeds() {
     .unwrap();
 }
 
+#[cfg_attr(miri, ignore)]
+#[test]
+fn run_backup_subcommand_succeeds() {
+    mudu_sys::task::async_::block_on_tokio_current_thread(async {
+        let http_addr = start_mock_http_server(json!({
+            "ok": true,
+            "data": {
+                "out_dir": "/var/backups/mududb-1",
+                "file_count": 4,
+                "total_bytes": 8192,
+                "workers": [
+                    {
+                        "worker_id": {"h": 0, "l": 1},
+                        "checkpoint_lsn": 10,
+                        "barrier_lsn": 11,
+                        "end_lsn": 12
+                    }
+                ]
+            }
+        }));
+        let mut c = cli(Commands::Backup(BackupArgs {
+            out: "/var/backups/mududb-1".to_string(),
+        }));
+        c.http_addr = http_addr;
+
+        run_with_connectors(
+            c,
+            &MockJsonConnector {
+                client: MockAsyncClient::new(),
+            },
+            &MockAsyncConnector {
+                client: MockAsyncClient::new(),
+            },
+        )
+        .await
+        .unwrap();
+    })
+    .unwrap();
+}
+
 #[cfg_attr(miri, ignore)]
 #[test]
 fn run_partition_route_with_key_succeeds() {
@@ -1,7 +1,8 @@
 //! HTTP management API helpers used by the `mcli` CLI.
 //!
 //! These functions talk to the MuduDB management HTTP endpoints for app
-//! lifecycle, server topology and partition routing.
+//! lifecycle, server topology, partition routing, backups and read
+//! replicas.
 
 use base64::Engine;
 use mudu::common::id::OID;
@@ -17,6 +18,9 @@ type AppResult<T> = Result<T, String>;
 const HTTP_TIMEOUT_DEFAULT_SECS: u64 = 10;
 const HTTP_RETRY_COUNT: usize = 5;
 const HTTP_RETRY_INITIAL_DELAY: Duration = Duration::from_millis(100);
+// A backup or restore copies the whole data directory, which takes far
+// longer than the other management requests.
+const BACKUP_HTTP_TIMEOUT: Duration = Duration::from_secs(3600);
 
 /// Returns the HTTP request timeout. Under heavy instrumentation such as
 /// AddressSanitizer the management server may be too slow for the default 10 s,
@@ -164,6 +168,89 @@ pub struct PartitionRouteResponse {
     pub routes: Vec<PartitionRouteEntry>,
 }
 
+/// A worker's part of an online backup.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct WorkerBackupInfo {
+    /// Worker id.
+    #[serde(
+        serialize_with = "serialize_oid_as_unioid",
+        deserialize_with = "deserialize_oid_from_unioid"
+    )]
+    pub worker_id: OID,
+    /// LSN of the checkpoint the copied worker log starts from.
+    pub checkpoint_lsn: u64,
+    /// Log position the worker reached at the backup barrier, before any
+    /// worker copied a file.
+    pub barrier_lsn: u64,
+    /// LSN the copied worker log reaches.
+    pub end_lsn: u64,
+}
+
+/// Response from the backup endpoint.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct BackupResponse {
+    /// Directory the backup set was written to.
+    pub out_dir: String,
+    /// Number of files in the backup set.
+    pub file_count: usize,
+    /// Total size of the backup set in bytes.
+    pub total_bytes: u64,
+    /// Log range of every worker.
+    pub workers: Vec<WorkerBackupInfo>,
+}
+
+/// Response from the restore endpoint.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct RestoreResponse {
+    /// Data directory the backup was restored into.
+    pub data_dir: String,
+    /// Page files verified after the restore.
+    pub page_files: u64,
+    /// WAL chunks verified after the restore.
+    pub wal_chunks: u64,
+    /// Corrupt pages found by the verification.
+    pub corrupt_pages: usize,
+    /// Corrupt WAL frames found by the verification.
+    pub corrupt_frames: usize,
+    /// Whether the restored directory verified without corruption.
+    pub clean: bool,
+}
+
+/// Replication state of one worker of a read replica.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct ReplicaWorkerInfo {
+    /// Worker id.
+    #[serde(
+        serialize_with = "serialize_oid_as_unioid",
+        deserialize_with = "deserialize_oid_from_unioid"
+    )]
+    pub worker_id: OID,
+    /// End of the worker log on the replica.
+    pub applied_lsn: u64,
+    /// LSN the primary had committed up to at the last fetch.
+    pub primary_lsn: u64,
+    /// Frames the replica is behind the primary.
+    pub lag_lsn: u64,
+    /// Milliseconds since the worker last caught up with the primary.
+    pub lag_ms: Option<u64>,
+}
+
+/// Response from the replication status endpoint.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct ReplicationStatusResponse {
+    /// Whether the replica was promoted.
+    pub promoted: bool,
+    /// Replication state of every worker.
+    pub workers: Vec<ReplicaWorkerInfo>,
+}
+
+/// Response from the replica promote endpoint.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct ReplicaPromoteResponse {
+    /// False when the replica had been promoted already.
+    pub promoted: bool,
+}
+
 /// Fetch the full server topology from the HTTP management API.
 pub async fn fetch_server_topology(http_addr: &str) -> AppResult<ServerTopology> {
     let response = get_http_json(http_addr, "/mudu/server/topology").await?;
@@ -252,6 +339,54 @@ pub async fn route_partition(
     serde_json::from_value(data).map_err(|e| format!("decode partition route failed: {}", e))
 }
 
+/// Take an online backup of every worker into `out_dir` on the server host.
+pub async fn backup(http_addr: &str, out_dir: &str) -> AppResult<BackupResponse> {
+    let payload = json!({ "out_dir": out_dir });
+    let response =
+        post_http_json_with_timeout(http_addr, "/mudu/backup", payload, BACKUP_HTTP_TIMEOUT)
+            .await?;
+    let data = extract_http_api_data(response)?;
+    serde_json::from_value(data).map_err(|e| format!("decode backup response failed: {}", e))
+}
+
+/// Restore the backup in `backup_dir` into the empty `data_dir` on the
+/// server host and verify it. A server started on `data_dir` then recovers
+/// the backed-up state.
+pub async fn restore_backup(
+    http_addr: &str,
+    backup_dir: &str,
+    data_dir: &str,
+) -> AppResult<RestoreResponse> {
+    let payload = json!({
+        "backup_dir": backup_dir,
+        "data_dir": data_dir,
+    });
+    let response = post_http_json_with_timeout(
+        http_addr,
+        "/mudu/backup/restore",
+        payload,
+        BACKUP_HTTP_TIMEOUT,
+    )
+    .await?;
+    let data = extract_http_api_data(response)?;
+    serde_json::from_value(data).map_err(|e| format!("decode restore response failed: {}", e))
+}
+
+/// Fetch how far a read replica lags behind its primary.
+pub async fn fetch_replication_status(http_addr: &str) -> AppResult<ReplicationStatusResponse> {
+    let response = get_http_json(http_addr, "/mudu/replication/status").await?;
+    let data = extract_http_api_data(response)?;
+    serde_json::from_value(data).map_err(|e| format!("decode replication status failed: {}", e))
+}
+
+/// Promote a read replica so it stops following its primary and accepts
+/// writes.
+pub async fn promote_replica(http_addr: &str) -> AppResult<ReplicaPromoteResponse> {
+    let response = post_http_json(http_addr, "/mudu/replication/promote", json!({})).await?;
+    let data = extract_http_api_data(response)?;
+    serde_json::from_value(data).map_err(|e| format!("decode promote response failed: {}", e))
+}
+
 async fn get_http_json(http_addr: &str, path: &str) -> AppResult<Value> {
     let url = format!("http://{}{}", http_addr, path);
     let client = http_client()?;
@@ -264,6 +399,20 @@ async fn post_http_json(http_addr: &str, path: &str, payload: Value) -> AppResul
     send_json_request("POST", &url, || client.post(&url).json(&payload).send()).await
 }
 
+async fn post_http_json_with_timeout(
+    http_addr: &str,
+    path: &str,
+    payload: Value,
+    timeout: Duration,
+) -> AppResult<Value> {
+    let url = format!("http://{}{}", http_addr, path);
+    let client = http_client()?;
+    send_json_request("POST", &url, || {
+        client.post(&url).timeout(timeout).json(&payload).send()
+    })
+    .await
+}
+
 async fn delete_http_json(http_addr: &str, path: &str) -> AppResult<Value> {
     let url = format!("http://{}{}", http_addr, path);
     let client = http_client()?;
@@ -5,7 +5,7 @@
 
 use crate::management::{
     PartitionRouteResponse, WorkerTopology, fetch_app_detail, fetch_app_list, fetch_proc_desc,
-    fetch_server_topology, install_app_package, route_partition, uninstall_app,
+    fetch_server_topology, install_app_package, restore_backup, route_partition, uninstall_app,
 };
 use mudu::common::id::OID;
 use serde_json::json;
@@ -259,6 +259,25 @@ fn route_partition_with_range_returns_routes() {
     .unwrap();
 }
 
+#[cfg_attr(miri, ignore)]
+#[test]
+fn restore_backup_reports_server_error() {
+    mudu_sys::task::async_::block_on_tokio_current_thread(async {
+        let addr = start_mock_http_server(json!({
+            "ok": false,
+            "error": {
+                "code": 50012,
+                "name": "EntityAlreadyExists",
+                "message": "restore target /data is not empty"
+            },
+            "message": "fail to restore backup"
+        }));
+        let err = restore_backup(&addr, "/backup", "/data").await.unwrap_err();
+        assert!(err.contains("is not empty"));
+    })
+    .unwrap();
+}
+
 #[test]
 fn partition_route_response_round_trips_oid() {
     let response: PartitionRouteResponse = serde_json::from_value(json!({
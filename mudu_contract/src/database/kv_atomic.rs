@@ -0,0 +1,67 @@
+//! `database::kv_atomic` module.
+//!
+//! Semantics of the atomic single-key KV operations. Every backend reads the
+//! current value of the key under its write lock, applies one of these
+//! functions and writes the returned value, if any, before releasing it.
+
+use mudu::common::result::RS;
+use mudu::error::ErrorCode;
+use mudu::mudu_error;
+
+/// The value to write back, `None` to leave the key untouched, and the
+/// result handed to the caller.
+pub type KvUpdate<T> = (Option<Vec<u8>>, T);
+
+/// Writes `value` when the current value equals `expected`; `None` expects
+/// the key to be absent. Returns whether the value was written.
+pub fn compare_and_swap(
+    current: Option<&[u8]>,
+    expected: Option<&[u8]>,
+    value: &[u8],
+) -> KvUpdate<bool> {
+    if current == expected {
+        (Some(value.to_vec()), true)
+    } else {
+        (None, false)
+    }
+}
+
+/// Writes `value` when the key is absent. Returns whether it was written.
+pub fn put_if_absent(current: Option<&[u8]>, value: &[u8]) -> KvUpdate<bool> {
+    compare_and_swap(current, None, value)
+}
+
+/// Adds `delta` to the counter stored under the key, counting from zero when
+/// the key is absent. Returns the new counter value.
+pub fn increment(current: Option<&[u8]>, delta: i64) -> RS<KvUpdate<i64>> {
+    let base = match current {
+        Some(value) => decode_counter(value)?,
+        None => 0,
+    };
+    let next = base.checked_add(delta).ok_or_else(|| {
+        mudu_error!(
+            ErrorCode::InvalidArgument,
+            format!("kv counter overflow adding {} to {}", delta, base)
+        )
+    })?;
+    Ok((Some(encode_counter(next)), next))
+}
+
+/// Encodes a counter the way [`increment`] stores it: as decimal ASCII text.
+pub fn encode_counter(value: i64) -> Vec<u8> {
+    value.to_string().into_bytes()
+}
+
+/// Decodes a counter value written by [`increment`] or by a plain put of
+/// decimal text.
+pub fn decode_counter(value: &[u8]) -> RS<i64> {
+    std::str::from_utf8(value)
+        .ok()
+        .and_then(|text| text.parse::<i64>().ok())
+        .ok_or_else(|| {
+            mudu_error!(
+                ErrorCode::InvalidArgument,
+                "kv value is not a decimal 64-bit integer counter"
+            )
+        })
+}
@@ -0,0 +1,49 @@
+#[cfg(test)]
+#[allow(clippy::unwrap_used)]
+mod tests {
+    use crate::database::kv_atomic::{
+        compare_and_swap, decode_counter, encode_counter, increment, put_if_absent,
+    };
+    use mudu::error::ErrorCode;
+
+    #[test]
+    fn compare_and_swap_writes_only_on_match() {
+        assert_eq!(
+            compare_and_swap(Some(b"a"), Some(b"a"), b"b"),
+            (Some(b"b".to_vec()), true)
+        );
+        assert_eq!(
+            compare_and_swap(Some(b"a"), Some(b"x"), b"b"),
+            (None, false)
+        );
+        assert_eq!(compare_and_swap(Some(b"a"), None, b"b"), (None, false));
+        assert_eq!(
+            compare_and_swap(None, None, b"b"),
+            (Some(b"b".to_vec()), true)
+        );
+    }
+
+    #[test]
+    fn put_if_absent_keeps_existing_value() {
+        assert_eq!(put_if_absent(None, b"v"), (Some(b"v".to_vec()), true));
+        assert_eq!(put_if_absent(Some(b"old"), b"v"), (None, false));
+    }
+
+    #[test]
+    fn increment_counts_from_zero_and_stores_decimal_text() {
+        assert_eq!(increment(None, 5).unwrap(), (Some(b"5".to_vec()), 5));
+        assert_eq!(
+            increment(Some(b"5"), -7).unwrap(),
+            (Some(b"-2".to_vec()), -2)
+        );
+        assert_eq!(decode_counter(&encode_counter(i64::MIN)).unwrap(), i64::MIN);
+    }
+
+    #[test]
+    fn increment_rejects_non_counter_values_and_overflow() {
+        let err = increment(Some(b"abc"), 1).unwrap_err();
+        assert_eq!(err.ec(), ErrorCode::InvalidArgument);
+        let err = increment(Some(b"9223372036854775807"), 1).unwrap_err();
+        assert_eq!(err.ec(), ErrorCode::InvalidArgument);
+    }
+}
@@ -0,0 +1,146 @@
+//! `database::kv_namespace` module.
+//!
+//! Named KV key spaces. A namespace belongs to one app and is stored as its
+//! own relation, so two apps using the same key never see each other's
+//! values. Requests without a namespace keep using the worker key space.
+
+use crate::database::kv_range::KvRangeOptions;
+use mudu::common::app_info::AppInfo;
+use mudu::common::result::RS;
+use mudu::error::ErrorCode;
+use mudu::mudu_error;
+use serde::{Deserialize, Serialize};
+
+/// Prefix of the catalog tables that store KV namespaces.
+pub const KV_NAMESPACE_RELATION_PREFIX: &str = "__kv_ns.";
+
+/// A KV namespace, identified by the app that owns it and its name.
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+pub struct KvNamespace {
+    app_name: String,
+    name: String,
+}
+
+impl KvNamespace {
+    /// Returns the namespace `name` of the app `app_name`. Both parts may
+    /// only hold ASCII letters, digits, `_` and `-`.
+    pub fn new(app_name: impl Into<String>, name: impl Into<String>) -> RS<Self> {
+        let namespace = Self {
+            app_name: app_name.into(),
+            name: name.into(),
+        };
+        namespace.validate()?;
+        Ok(namespace)
+    }
+
+    /// Returns the namespace `name` scoped to the app described by `info`.
+    pub fn for_app(info: &AppInfo, name: impl Into<String>) -> RS<Self> {
+        Self::new(info.name.clone(), name)
+    }
+
+    pub fn app_name(&self) -> &str {
+        &self.app_name
+    }
+
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// Checks both parts of a namespace that was decoded rather than built
+    /// with [`KvNamespace::new`].
+    pub fn validate(&self) -> RS<()> {
+        validate_part("app name", &self.app_name)?;
+        validate_part("namespace name", &self.name)
+    }
+
+    /// Name of the catalog table that stores the namespace.
+    pub fn relation_name(&self) -> String {
+        format!("{}{}", Self::relation_prefix(&self.app_name), self.name)
+    }
+
+    /// Prefix shared by the relation names of every namespace of `app_name`.
+    pub fn relation_prefix(app_name: &str) -> String {
+        format!("{}{}.", KV_NAMESPACE_RELATION_PREFIX, app_name)
+    }
+
+    /// Parses a relation name built by [`KvNamespace::relation_name`];
+    /// returns `None` for any other table.
+    pub fn from_relation_name(relation_name: &str) -> Option<Self> {
+        let rest = relation_name.strip_prefix(KV_NAMESPACE_RELATION_PREFIX)?;
+        let (app_name, name) = rest.split_once('.')?;
+        Self::new(app_name, name).ok()
+    }
+}
+
+/// A KV namespace operation issued by a procedure through the `kv-namespace`
+/// system call. It names the namespace only: the host scopes it to the app
+/// of the calling procedure, so a procedure cannot reach another app's
+/// namespaces.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub enum KvNamespaceOp {
+    Create {
+        name: String,
+        partition_rule: Option<String>,
+    },
+    Drop {
+        name: String,
+    },
+    List,
+    Get {
+        name: String,
+        key: Vec<u8>,
+    },
+    Put {
+        name: String,
+        key: Vec<u8>,
+        value: Vec<u8>,
+    },
+    Delete {
+        name: String,
+        key: Vec<u8>,
+    },
+    Range {
+        name: String,
+        start_key: Vec<u8>,
+        end_key: Vec<u8>,
+        options: KvRangeOptions,
+    },
+}
+
+/// Result of a [`KvNamespaceOp`].
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub enum KvNamespaceOpResult {
+    /// `Create`, `Drop`, `Put` and `Delete` succeeded.
+    Done,
+    /// Namespace names returned by `List`, in name order.
+    Names(Vec<String>),
+    /// Value returned by `Get`.
+    Value(Option<Vec<u8>>),
+    /// Page returned by `Range`.
+    Page {
+        items: Vec<(Vec<u8>, Vec<u8>)>,
+        continuation: Option<Vec<u8>>,
+    },
+}
+
+fn validate_part(what: &str, part: &str) -> RS<()> {
+    if part.is_empty() {
+        return Err(mudu_error!(
+            ErrorCode::InvalidArgument,
+            format!("kv namespace {} is empty", what)
+        ));
+    }
+    if !part
+        .bytes()
+        .all(|byte| byte.is_ascii_alphanumeric() || byte == b'_' || byte == b'-')
+    {
+        return Err(mudu_error!(
+            ErrorCode::InvalidArgument,
+            format!(
+                "kv namespace {} {:?} may only hold letters, digits, '_' and '-'",
+                what, part
+            )
+        ));
+    }
+    Ok(())
+}
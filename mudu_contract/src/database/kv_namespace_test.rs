@@ -0,0 +1,52 @@
+#[cfg(test)]
+#[allow(clippy::unwrap_used)]
+mod tests {
+    use crate::database::kv_namespace::KvNamespace;
+    use mudu::common::app_info::AppInfo;
+    use mudu::error::ErrorCode;
+
+    #[test]
+    fn relation_name_round_trips() {
+        let namespace = KvNamespace::new("wallet", "balances").unwrap();
+        assert_eq!(namespace.relation_name(), "__kv_ns.wallet.balances");
+        assert!(
+            namespace
+                .relation_name()
+                .starts_with(&KvNamespace::relation_prefix("wallet"))
+        );
+        assert_eq!(
+            KvNamespace::from_relation_name(&namespace.relation_name()),
+            Some(namespace)
+        );
+        assert_eq!(KvNamespace::from_relation_name("orders"), None);
+        assert_eq!(KvNamespace::from_relation_name("__kv_ns.wallet"), None);
+    }
+
+    #[test]
+    fn for_app_uses_the_app_name() {
+        let info = AppInfo {
+            name: "kv".to_string(),
+            lang: "rust".to_string(),
+            version: "0.1.0".to_string(),
+            use_async: false,
+        };
+        let namespace = KvNamespace::for_app(&info, "cache").unwrap();
+        assert_eq!(namespace.app_name(), "kv");
+        assert_eq!(namespace.name(), "cache");
+    }
+
+    #[test]
+    fn invalid_parts_are_rejected() {
+        for (app_name, name) in [
+            ("", "n"),
+            ("a", ""),
+            ("a.b", "n"),
+            ("a", "n/m"),
+            ("a", "n m"),
+        ] {
+            let err = KvNamespace::new(app_name, name).unwrap_err();
+            assert_eq!(err.ec(), ErrorCode::InvalidArgument);
+        }
+        assert!(KvNamespace::new("app_1", "name-2").is_ok());
+    }
+}
@@ -0,0 +1,208 @@
+//! `database::kv_range` module.
+//!
+//! Options and paging of KV range scans. Every backend narrows the scanned
+//! bounds with [`KvRangeOptions::bounds`], reads the keys in the requested
+//! order and cuts the result into a page with [`KvRangeOptions::page`], so
+//! continuation tokens mean the same thing whichever backend serves them.
+
+use serde::{Deserialize, Serialize};
+
+/// Options of a KV range scan. The default scans every key of the range in
+/// ascending order and returns keys with their values.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct KvRangeOptions {
+    #[serde(default)]
+    limit: u32,
+    #[serde(default)]
+    reverse: bool,
+    #[serde(default)]
+    prefix: Option<Vec<u8>>,
+    #[serde(default)]
+    keys_only: bool,
+    #[serde(default)]
+    continuation: Option<Vec<u8>>,
+}
+
+/// A key and its value, as returned by a KV range scan.
+pub type KvRangeItem = (Vec<u8>, Vec<u8>);
+
+/// One page of a KV range scan.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct KvRangePage {
+    items: Vec<(Vec<u8>, Vec<u8>)>,
+    continuation: Option<Vec<u8>>,
+}
+
+impl KvRangeOptions {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns at most `limit` items per page; `0` means no limit.
+    pub fn with_limit(mut self, limit: u32) -> Self {
+        self.limit = limit;
+        self
+    }
+
+    /// Returns the keys in descending order.
+    pub fn with_reverse(mut self, reverse: bool) -> Self {
+        self.reverse = reverse;
+        self
+    }
+
+    /// Restricts the scan to keys starting with `prefix`.
+    pub fn with_prefix(mut self, prefix: Vec<u8>) -> Self {
+        self.prefix = Some(prefix);
+        self
+    }
+
+    /// Returns empty values instead of the stored ones.
+    pub fn with_keys_only(mut self, keys_only: bool) -> Self {
+        self.keys_only = keys_only;
+        self
+    }
+
+    /// Resumes a scan after the page that returned `token`.
+    pub fn with_continuation(mut self, token: Vec<u8>) -> Self {
+        self.continuation = Some(token);
+        self
+    }
+
+    pub fn limit(&self) -> u32 {
+        self.limit
+    }
+
+    pub fn reverse(&self) -> bool {
+        self.reverse
+    }
+
+    pub fn prefix(&self) -> Option<&[u8]> {
+        self.prefix.as_deref()
+    }
+
+    pub fn keys_only(&self) -> bool {
+        self.keys_only
+    }
+
+    pub fn continuation(&self) -> Option<&[u8]> {
+        self.continuation.as_deref()
+    }
+
+    /// True for the options of a plain, unbounded ascending scan.
+    pub fn is_default(&self) -> bool {
+        *self == Self::default()
+    }
+
+    /// The number of items a backend has to read to fill a page and tell
+    /// whether another one follows, `None` when the scan has no limit.
+    pub fn fetch_limit(&self) -> Option<usize> {
+        if self.limit == 0 {
+            None
+        } else {
+            Some(self.limit as usize + 1)
+        }
+    }
+
+    /// Narrows `[start_key, end_key)` to the keys the scan may still return
+    /// given the prefix and the continuation token. An empty end key means
+    /// the range is unbounded above. Returns `None` when no key is left.
+    pub fn bounds(&self, start_key: &[u8], end_key: &[u8]) -> Option<(Vec<u8>, Vec<u8>)> {
+        let mut start = start_key.to_vec();
+        let mut end = end_key.to_vec();
+        if let Some(prefix) = &self.prefix {
+            start = start.max(prefix.clone());
+            end = min_end(end, prefix_end(prefix));
+        }
+        if let Some(token) = &self.continuation {
+            if self.reverse {
+                if token.is_empty() {
+                    return None;
+                }
+                end = min_end(end, token.clone());
+            } else {
+                let mut after = token.clone();
+                after.push(0);
+                start = start.max(after);
+            }
+        }
+        if !end.is_empty() && start >= end {
+            return None;
+        }
+        Some((start, end))
+    }
+
+    /// Cuts `items`, read in scan order and possibly holding more than
+    /// `limit` entries, into a page. The page carries a continuation token
+    /// exactly when items were left out.
+    pub fn page(&self, mut items: Vec<(Vec<u8>, Vec<u8>)>) -> KvRangePage {
+        let mut continuation = None;
+        if self.limit != 0 && items.len() > self.limit as usize {
+            items.truncate(self.limit as usize);
+            continuation = items.last().map(|(key, _)| key.clone());
+        }
+        if self.keys_only {
+            for (_, value) in items.iter_mut() {
+                value.clear();
+            }
+        }
+        KvRangePage::new(items, continuation)
+    }
+
+    /// Like [`KvRangeOptions::page`] for items read in ascending key order
+    /// regardless of the scan direction.
+    pub fn page_ascending(&self, mut items: Vec<(Vec<u8>, Vec<u8>)>) -> KvRangePage {
+        if self.reverse {
+            items.reverse();
+        }
+        self.page(items)
+    }
+}
+
+impl KvRangePage {
+    pub fn new(items: Vec<(Vec<u8>, Vec<u8>)>, continuation: Option<Vec<u8>>) -> Self {
+        Self {
+            items,
+            continuation,
+        }
+    }
+
+    pub fn items(&self) -> &[(Vec<u8>, Vec<u8>)] {
+        &self.items
+    }
+
+    /// Token resuming the scan after this page, `None` on the last page.
+    pub fn continuation(&self) -> Option<&[u8]> {
+        self.continuation.as_deref()
+    }
+
+    pub fn into_items(self) -> Vec<(Vec<u8>, Vec<u8>)> {
+        self.items
+    }
+
+    pub fn into_parts(self) -> (Vec<KvRangeItem>, Option<Vec<u8>>) {
+        (self.items, self.continuation)
+    }
+}
+
+/// The smallest key greater than every key starting with `prefix`, or an
+/// empty key when there is none.
+fn prefix_end(prefix: &[u8]) -> Vec<u8> {
+    let mut end = prefix.to_vec();
+    while let Some(last) = end.pop() {
+        if last < u8::MAX {
+            end.push(last + 1);
+            return end;
+        }
+    }
+    Vec::new()
+}
+
+fn min_end(a: Vec<u8>, b: Vec<u8>) -> Vec<u8> {
+    if a.is_empty() {
+        b
+    } else if b.is_empty() {
+        a
+    } else {
+        a.min(b)
+    }
+}
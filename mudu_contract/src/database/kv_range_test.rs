@@ -0,0 +1,99 @@
+#[cfg(test)]
+#[allow(clippy::unwrap_used)]
+mod tests {
+    use crate::database::kv_range::KvRangeOptions;
+
+    fn items(keys: &[&[u8]]) -> Vec<(Vec<u8>, Vec<u8>)> {
+        keys.iter()
+            .map(|key| (key.to_vec(), [*key, b"-v"].concat()))
+            .collect()
+    }
+
+    #[test]
+    fn default_options_keep_the_range() {
+        let options = KvRangeOptions::default();
+        assert!(options.is_default());
+        assert_eq!(options.fetch_limit(), None);
+        assert_eq!(options.bounds(b"a", b""), Some((b"a".to_vec(), Vec::new())));
+        assert_eq!(options.bounds(b"b", b"b"), None);
+        let page = options.page(items(&[b"a", b"b"]));
+        assert_eq!(page.items(), items(&[b"a", b"b"]).as_slice());
+        assert_eq!(page.continuation(), None);
+    }
+
+    #[test]
+    fn prefix_narrows_the_range() {
+        let options = KvRangeOptions::new().with_prefix(b"user/".to_vec());
+        assert_eq!(
+            options.bounds(b"", b""),
+            Some((b"user/".to_vec(), b"user0".to_vec()))
+        );
+        assert_eq!(
+            options.bounds(b"user/b", b"user/m"),
+            Some((b"user/b".to_vec(), b"user/m".to_vec()))
+        );
+        assert_eq!(options.bounds(b"v", b""), None);
+
+        let options = KvRangeOptions::new().with_prefix(vec![b'a', 0xff]);
+        assert_eq!(
+            options.bounds(b"", b""),
+            Some((vec![b'a', 0xff], b"b".to_vec()))
+        );
+        let options = KvRangeOptions::new().with_prefix(vec![0xff, 0xff]);
+        assert_eq!(
+            options.bounds(b"", b""),
+            Some((vec![0xff, 0xff], Vec::new()))
+        );
+    }
+
+    #[test]
+    fn limit_pages_resume_after_the_token() {
+        let all = items(&[b"a", b"b", b"c"]);
+        let options = KvRangeOptions::new().with_limit(2);
+        assert_eq!(options.fetch_limit(), Some(3));
+        let page = options.page(all.clone());
+        assert_eq!(page.items(), &all[..2]);
+        assert_eq!(page.continuation(), Some(b"b".as_slice()));
+
+        let next = options
+            .clone()
+            .with_continuation(page.continuation().unwrap().to_vec());
+        assert_eq!(
+            next.bounds(b"a", b"z"),
+            Some((b"b\0".to_vec(), b"z".to_vec()))
+        );
+        let page = next.page(all[2..].to_vec());
+        assert_eq!(page.items(), &all[2..]);
+        assert_eq!(page.continuation(), None);
+    }
+
+    #[test]
+    fn reverse_pages_walk_down_from_the_token() {
+        let options = KvRangeOptions::new().with_limit(1).with_reverse(true);
+        let page = options.page_ascending(items(&[b"a", b"b"]));
+        assert_eq!(page.items(), items(&[b"b"]).as_slice());
+        assert_eq!(page.continuation(), Some(b"b".as_slice()));
+
+        let next = options.with_continuation(b"b".to_vec());
+        assert_eq!(
+            next.bounds(b"a", b"z"),
+            Some((b"a".to_vec(), b"b".to_vec()))
+        );
+        assert_eq!(next.bounds(b"b", b"z"), None);
+        assert_eq!(
+            next.clone().with_continuation(Vec::new()).bounds(b"", b""),
+            None
+        );
+    }
+
+    #[test]
+    fn keys_only_drops_values() {
+        let page = KvRangeOptions::new()
+            .with_keys_only(true)
+            .page(items(&[b"a", b"b"]));
+        assert_eq!(
+            page.into_items(),
+            vec![(b"a".to_vec(), Vec::new()), (b"b".to_vec(), Vec::new())]
+        );
+    }
+}
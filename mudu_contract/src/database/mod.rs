@@ -10,6 +10,15 @@ pub mod db_conn;
 pub mod entity;
 #[cfg(test)]
 mod entity_test;
+pub mod kv_atomic;
+#[cfg(test)]
+mod kv_atomic_test;
+pub mod kv_namespace;
+#[cfg(test)]
+mod kv_namespace_test;
+pub mod kv_range;
+#[cfg(test)]
+mod kv_range_test;
 
 pub mod sql;
 pub mod sql_stmt;
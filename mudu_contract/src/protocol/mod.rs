@@ -1,15 +1,19 @@
 //! `protocol::mod` module.
 #![allow(missing_docs)]
 
+use crate::database::kv_namespace::KvNamespace;
+use crate::database::kv_range::{KvRangeOptions, KvRangePage};
 use crate::tuple::tuple_field_desc::TupleFieldDesc;
 use crate::tuple::tuple_value::TupleValue;
 use mudu::common::result::RS;
 use mudu::error::ErrorCode;
 use mudu::error::MuduError;
 use mudu::mudu_error;
+use mudu::utils::json::JsonValue;
 use mudu_sys_contract::perf::TraceContext;
 use serde::de::DeserializeOwned;
 use serde::{Deserialize, Serialize};
+use std::time::Duration;
 
 pub mod format;
 pub mod migrate;
@@ -30,6 +34,22 @@ pub enum MessageType {
     ProcedureInvoke = 11,
     SessionCreate = 12,
     SessionClose = 13,
+    CdcSubscribe = 14,
+    WalFetch = 15,
+    Delete = 16,
+    PutMany = 17,
+    DeleteMany = 18,
+    Cas = 19,
+    Incr = 20,
+    PutIfAbsent = 21,
+    KvNamespaceCreate = 22,
+    KvNamespaceDrop = 23,
+    KvNamespaceList = 24,
+    WatchRegister = 25,
+    WatchAck = 26,
+    WatchCancel = 27,
+    /// Server-initiated: pushes the changes of a watch.
+    WatchEvent = 28,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
@@ -74,6 +94,21 @@ impl TryFrom<u32> for MessageType {
             11 => Ok(MessageType::ProcedureInvoke),
             12 => Ok(MessageType::SessionCreate),
             13 => Ok(MessageType::SessionClose),
+            14 => Ok(MessageType::CdcSubscribe),
+            15 => Ok(MessageType::WalFetch),
+            16 => Ok(MessageType::Delete),
+            17 => Ok(MessageType::PutMany),
+            18 => Ok(MessageType::DeleteMany),
+            19 => Ok(MessageType::Cas),
+            20 => Ok(MessageType::Incr),
+            21 => Ok(MessageType::PutIfAbsent),
+            22 => Ok(MessageType::KvNamespaceCreate),
+            23 => Ok(MessageType::KvNamespaceDrop),
+            24 => Ok(MessageType::KvNamespaceList),
+            25 => Ok(MessageType::WatchRegister),
+            26 => Ok(MessageType::WatchAck),
+            27 => Ok(MessageType::WatchCancel),
+            28 => Ok(MessageType::WatchEvent),
             _ => Err(mudu_error!(
                 ErrorCode::Parse,
                 format!("unknown message type {}", value)
@@ -138,13 +173,70 @@ impl ServerPerfDigest {
 pub struct GetRequest {
     session_id: u128,
     key: Vec<u8>,
+    #[serde(default)]
+    namespace: Option<String>,
 }
 
+/// Writes `value` under `key`; with a TTL the key expires that many
+/// milliseconds after the write commits.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct PutRequest {
     session_id: u128,
     key: Vec<u8>,
     value: Vec<u8>,
+    #[serde(default)]
+    ttl_ms: Option<u64>,
+    #[serde(default)]
+    namespace: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DeleteRequest {
+    session_id: u128,
+    key: Vec<u8>,
+    #[serde(default)]
+    namespace: Option<String>,
+}
+
+/// Writes every item in one transaction; a later item wins over an earlier
+/// one with the same key.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PutManyRequest {
+    session_id: u128,
+    items: Vec<KeyValue>,
+}
+
+/// Deletes every key in one transaction.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DeleteManyRequest {
+    session_id: u128,
+    keys: Vec<Vec<u8>>,
+}
+
+/// Replaces the value of `key` only when it currently equals `expected`;
+/// `None` expects the key to be absent.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CasRequest {
+    session_id: u128,
+    key: Vec<u8>,
+    expected: Option<Vec<u8>>,
+    value: Vec<u8>,
+}
+
+/// Adds `delta` to the decimal integer stored at `key`; a missing key
+/// counts as zero.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct IncrRequest {
+    session_id: u128,
+    key: Vec<u8>,
+    delta: i64,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PutIfAbsentRequest {
+    session_id: u128,
+    key: Vec<u8>,
+    value: Vec<u8>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -152,6 +244,35 @@ pub struct RangeScanRequest {
     session_id: u128,
     start_key: Vec<u8>,
     end_key: Vec<u8>,
+    #[serde(default)]
+    options: KvRangeOptions,
+    #[serde(default)]
+    namespace: Option<String>,
+}
+
+/// Creates a KV namespace of the app the session is bound to. With a
+/// partition rule, keys are routed to the rule's partitions by comparing
+/// their bytes with the rule's bounds.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct KvNamespaceCreateRequest {
+    session_id: u128,
+    namespace: String,
+    #[serde(default)]
+    partition_rule: Option<String>,
+}
+
+/// Drops a KV namespace of the session's app together with every key
+/// stored in it.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct KvNamespaceDropRequest {
+    session_id: u128,
+    namespace: String,
+}
+
+/// Lists the KV namespaces of the app the session is bound to.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct KvNamespaceListRequest {
+    session_id: u128,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -197,9 +318,40 @@ pub struct PutResponse {
     ok: bool,
 }
 
+/// Answers `Delete` and `DeleteMany`; deleting a missing key succeeds.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct DeleteResponse {
+    ok: bool,
+}
+
+/// Answers `Cas` and `PutIfAbsent`; `swapped` tells whether the value was
+/// written.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct CasResponse {
+    swapped: bool,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct IncrResponse {
+    value: i64,
+}
+
+/// Answers `KvNamespaceCreate` and `KvNamespaceDrop`.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct KvNamespaceResponse {
+    ok: bool,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct KvNamespaceListResponse {
+    namespaces: Vec<KvNamespace>,
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
 pub struct RangeScanResponse {
     items: Vec<KeyValue>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    continuation: Option<Vec<u8>>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
@@ -209,6 +361,173 @@ pub struct ProcedureInvokeResponse {
     server_perf_digest: Option<ServerPerfDigest>,
 }
 
+/// Where a change data capture consumer continues in one worker log: the
+/// LSN of the first entry it has not consumed yet.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+pub struct CdcPosition {
+    worker_id: u128,
+    lsn: u64,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct CdcSubscribeRequest {
+    consumer: String,
+    table: String,
+    /// Resume positions; `None` continues from the positions the server
+    /// keeps for the consumer.
+    from: Option<Vec<CdcPosition>>,
+    max_changes: u32,
+}
+
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum CdcOp {
+    /// An insert or update; the row holds every column after the change.
+    Put,
+    Delete,
+}
+
+/// One committed row change, decoded to JSON objects keyed by column name.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct CdcChange {
+    worker_id: u128,
+    lsn: u64,
+    xid: u64,
+    op: CdcOp,
+    key: JsonValue,
+    row: Option<JsonValue>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct CdcSubscribeResponse {
+    changes: Vec<CdcChange>,
+    /// Positions to resume from after `changes`, one per worker.
+    positions: Vec<CdcPosition>,
+}
+
+/// Asks a primary for the log frames of one worker that a read replica has
+/// not received yet.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct WalFetchRequest {
+    /// Name the primary keeps the replica's log position under.
+    replica: String,
+    worker_id: u128,
+    /// LSN of the first frame the replica needs; everything before it is in
+    /// the replica's log.
+    from_lsn: u64,
+    max_bytes: u32,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct WalFetchResponse {
+    worker_id: u128,
+    /// Encoded log frames from `from_lsn` on, ending at an entry boundary.
+    frames: Vec<u8>,
+    /// LSN below which the primary's log entries are committed; the replica
+    /// lags behind until its log reaches it.
+    primary_lsn: u64,
+}
+
+/// What a watch observes.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum WatchTarget {
+    /// One key of the worker key space.
+    Key(Vec<u8>),
+    /// Every key of the worker key space that starts with the bytes.
+    Prefix(Vec<u8>),
+    /// The rows of `table` whose primary key lies in `[start, end)`. A bound
+    /// is an object keyed by key column name, like [`CdcChange::key`]; a
+    /// missing bound leaves that side open.
+    TableRange {
+        table: String,
+        start: Option<JsonValue>,
+        end: Option<JsonValue>,
+    },
+}
+
+/// Registers a watch on the connection that owns `session_id`. The server
+/// then pushes `WatchEvent` frames with the committed changes the target
+/// sees, read from the worker logs like change data capture.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct WatchRegisterRequest {
+    session_id: u128,
+    target: WatchTarget,
+    /// Resume positions, e.g. those of the last event the client handled;
+    /// `None` starts at the changes committed from now on.
+    #[serde(default)]
+    from: Option<Vec<CdcPosition>>,
+    /// Events pushed before the server waits for an acknowledgement; 0
+    /// picks the server default.
+    #[serde(default)]
+    max_in_flight: u32,
+    /// Changes one event carries at most; 0 picks the server default.
+    #[serde(default)]
+    max_changes: u32,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct WatchRegisterResponse {
+    watch_id: u64,
+    /// Positions the watch starts at, one per worker.
+    positions: Vec<CdcPosition>,
+}
+
+/// Acknowledges the events of a watch up to and including `seq`, so the
+/// server may push more.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct WatchAckRequest {
+    session_id: u128,
+    watch_id: u64,
+    seq: u64,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct WatchCancelRequest {
+    session_id: u128,
+    watch_id: u64,
+}
+
+/// Answers `WatchAck` and `WatchCancel`.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct WatchResponse {
+    ok: bool,
+}
+
+/// One committed write of the worker key space.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct KvChange {
+    worker_id: u128,
+    lsn: u64,
+    xid: u64,
+    op: CdcOp,
+    key: Vec<u8>,
+    value: Option<Vec<u8>>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum WatchChange {
+    Kv(KvChange),
+    Row(CdcChange),
+}
+
+/// Changes pushed for one watch in a `WatchEvent` frame, which the server
+/// sends with request id 0. `seq` numbers the events of a watch from 1.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct WatchEvent {
+    watch_id: u64,
+    seq: u64,
+    changes: Vec<WatchChange>,
+    /// Positions after `changes`; registering again from them resumes the
+    /// watch after this event.
+    positions: Vec<CdcPosition>,
+    /// Set on the last event of a watch the server had to cancel, e.g.
+    /// because its table was dropped.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    error: Option<String>,
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
 pub struct ErrorResponse {
     #[serde(default)]
@@ -422,7 +741,22 @@ impl ServerResponse {
 
 impl GetRequest {
     pub fn new(session_id: u128, key: Vec<u8>) -> Self {
-        Self { session_id, key }
+        Self {
+            session_id,
+            key,
+            namespace: None,
+        }
+    }
+
+    /// Addresses the key in the session app's `namespace` instead of the
+    /// worker key space.
+    pub fn with_namespace(mut self, namespace: impl Into<String>) -> Self {
+        self.namespace = Some(namespace.into());
+        self
+    }
+
+    pub fn namespace(&self) -> Option<&str> {
+        self.namespace.as_deref()
     }
 
     pub fn session_id(&self) -> u128 {
@@ -440,9 +774,31 @@ impl PutRequest {
             session_id,
             key,
             value,
+            ttl_ms: None,
+            namespace: None,
         }
     }
 
+    /// Addresses the key in the session app's `namespace` instead of the
+    /// worker key space.
+    pub fn with_namespace(mut self, namespace: impl Into<String>) -> Self {
+        self.namespace = Some(namespace.into());
+        self
+    }
+
+    pub fn namespace(&self) -> Option<&str> {
+        self.namespace.as_deref()
+    }
+
+    pub fn with_ttl(mut self, ttl: Duration) -> Self {
+        self.ttl_ms = Some(ttl.as_millis().min(u64::MAX as u128) as u64);
+        self
+    }
+
+    pub fn ttl(&self) -> Option<Duration> {
+        self.ttl_ms.map(Duration::from_millis)
+    }
+
     pub fn session_id(&self) -> u128 {
         self.session_id
     }
@@ -460,173 +816,835 @@ impl PutRequest {
     }
 }
 
-impl RangeScanRequest {
-    pub fn new(session_id: u128, start_key: Vec<u8>, end_key: Vec<u8>) -> Self {
+impl DeleteRequest {
+    pub fn new(session_id: u128, key: Vec<u8>) -> Self {
         Self {
             session_id,
-            start_key,
-            end_key,
+            key,
+            namespace: None,
         }
     }
 
-    pub fn session_id(&self) -> u128 {
-        self.session_id
+    /// Addresses the key in the session app's `namespace` instead of the
+    /// worker key space.
+    pub fn with_namespace(mut self, namespace: impl Into<String>) -> Self {
+        self.namespace = Some(namespace.into());
+        self
     }
 
-    pub fn start_key(&self) -> &[u8] {
-        &self.start_key
+    pub fn namespace(&self) -> Option<&str> {
+        self.namespace.as_deref()
     }
 
-    pub fn end_key(&self) -> &[u8] {
-        &self.end_key
+    pub fn session_id(&self) -> u128 {
+        self.session_id
+    }
+
+    pub fn key(&self) -> &[u8] {
+        &self.key
     }
 }
 
-impl ProcedureInvokeRequest {
-    pub fn new(
-        session_id: u128,
-        procedure_name: impl Into<String>,
-        procedure_parameters: Vec<u8>,
-    ) -> Self {
-        Self {
-            session_id,
-            procedure_name: procedure_name.into(),
-            procedure_parameters,
-        }
+impl PutManyRequest {
+    pub fn new(session_id: u128, items: Vec<KeyValue>) -> Self {
+        Self { session_id, items }
     }
 
     pub fn session_id(&self) -> u128 {
         self.session_id
     }
 
-    pub fn procedure_name(&self) -> &str {
-        &self.procedure_name
+    pub fn items(&self) -> &[KeyValue] {
+        &self.items
     }
 
-    pub fn procedure_parameters(&self) -> &[u8] {
-        &self.procedure_parameters
+    pub fn into_items(self) -> Vec<KeyValue> {
+        self.items
     }
+}
 
-    pub fn procedure_parameters_owned(&self) -> Vec<u8> {
-        self.procedure_parameters.clone()
+impl DeleteManyRequest {
+    pub fn new(session_id: u128, keys: Vec<Vec<u8>>) -> Self {
+        Self { session_id, keys }
     }
-}
 
-impl SessionCreateRequest {
-    pub fn new(config_json: Option<String>) -> Self {
-        Self { config_json }
+    pub fn session_id(&self) -> u128 {
+        self.session_id
     }
 
-    pub fn config_json(&self) -> Option<&str> {
-        self.config_json.as_deref()
+    pub fn keys(&self) -> &[Vec<u8>] {
+        &self.keys
+    }
+
+    pub fn into_keys(self) -> Vec<Vec<u8>> {
+        self.keys
     }
 }
 
-impl KeyValue {
-    pub fn new(key: Vec<u8>, value: Vec<u8>) -> Self {
-        Self { key, value }
+impl CasRequest {
+    pub fn new(session_id: u128, key: Vec<u8>, expected: Option<Vec<u8>>, value: Vec<u8>) -> Self {
+        Self {
+            session_id,
+            key,
+            expected,
+            value,
+        }
+    }
+
+    pub fn session_id(&self) -> u128 {
+        self.session_id
     }
 
     pub fn key(&self) -> &[u8] {
         &self.key
     }
 
+    pub fn expected(&self) -> Option<&[u8]> {
+        self.expected.as_deref()
+    }
+
     pub fn value(&self) -> &[u8] {
         &self.value
     }
+
+    pub fn into_parts(self) -> (Vec<u8>, Option<Vec<u8>>, Vec<u8>) {
+        (self.key, self.expected, self.value)
+    }
 }
 
-impl GetResponse {
-    pub fn new(value: Option<Vec<u8>>) -> Self {
-        Self { value }
+impl IncrRequest {
+    pub fn new(session_id: u128, key: Vec<u8>, delta: i64) -> Self {
+        Self {
+            session_id,
+            key,
+            delta,
+        }
     }
 
-    pub fn value(&self) -> Option<&[u8]> {
-        self.value.as_deref()
+    pub fn session_id(&self) -> u128 {
+        self.session_id
     }
 
-    pub fn into_value(self) -> Option<Vec<u8>> {
-        self.value
+    pub fn key(&self) -> &[u8] {
+        &self.key
+    }
+
+    pub fn delta(&self) -> i64 {
+        self.delta
     }
 }
 
-impl PutResponse {
-    pub fn new(ok: bool) -> Self {
-        Self { ok }
+impl PutIfAbsentRequest {
+    pub fn new(session_id: u128, key: Vec<u8>, value: Vec<u8>) -> Self {
+        Self {
+            session_id,
+            key,
+            value,
+        }
     }
 
-    pub fn ok(&self) -> bool {
-        self.ok
+    pub fn session_id(&self) -> u128 {
+        self.session_id
     }
-}
 
-impl RangeScanResponse {
-    pub fn new(items: Vec<KeyValue>) -> Self {
-        Self { items }
+    pub fn key(&self) -> &[u8] {
+        &self.key
     }
 
-    pub fn items(&self) -> &[KeyValue] {
-        &self.items
+    pub fn value(&self) -> &[u8] {
+        &self.value
     }
 
-    pub fn into_items(self) -> Vec<KeyValue> {
-        self.items
+    pub fn into_parts(self) -> (Vec<u8>, Vec<u8>) {
+        (self.key, self.value)
     }
 }
 
-impl ProcedureInvokeResponse {
-    pub fn new(result: Vec<u8>) -> Self {
+impl RangeScanRequest {
+    pub fn new(session_id: u128, start_key: Vec<u8>, end_key: Vec<u8>) -> Self {
+        Self {
+            session_id,
+            start_key,
+            end_key,
+            options: KvRangeOptions::default(),
+            namespace: None,
+        }
+    }
+
+    /// Scans the session app's `namespace` instead of the worker key space.
+    pub fn with_namespace(mut self, namespace: impl Into<String>) -> Self {
+        self.namespace = Some(namespace.into());
+        self
+    }
+
+    pub fn namespace(&self) -> Option<&str> {
+        self.namespace.as_deref()
+    }
+
+    pub fn with_options(mut self, options: KvRangeOptions) -> Self {
+        self.options = options;
+        self
+    }
+
+    pub fn session_id(&self) -> u128 {
+        self.session_id
+    }
+
+    pub fn start_key(&self) -> &[u8] {
+        &self.start_key
+    }
+
+    pub fn end_key(&self) -> &[u8] {
+        &self.end_key
+    }
+
+    pub fn options(&self) -> &KvRangeOptions {
+        &self.options
+    }
+}
+
+impl KvNamespaceCreateRequest {
+    pub fn new(session_id: u128, namespace: impl Into<String>) -> Self {
+        Self {
+            session_id,
+            namespace: namespace.into(),
+            partition_rule: None,
+        }
+    }
+
+    /// Spreads the namespace over the partitions of the rule named
+    /// `partition_rule`, which must have a single string key.
+    pub fn with_partition_rule(mut self, partition_rule: impl Into<String>) -> Self {
+        self.partition_rule = Some(partition_rule.into());
+        self
+    }
+
+    pub fn session_id(&self) -> u128 {
+        self.session_id
+    }
+
+    pub fn namespace(&self) -> &str {
+        &self.namespace
+    }
+
+    pub fn partition_rule(&self) -> Option<&str> {
+        self.partition_rule.as_deref()
+    }
+}
+
+impl KvNamespaceDropRequest {
+    pub fn new(session_id: u128, namespace: impl Into<String>) -> Self {
+        Self {
+            session_id,
+            namespace: namespace.into(),
+        }
+    }
+
+    pub fn session_id(&self) -> u128 {
+        self.session_id
+    }
+
+    pub fn namespace(&self) -> &str {
+        &self.namespace
+    }
+}
+
+impl KvNamespaceListRequest {
+    pub fn new(session_id: u128) -> Self {
+        Self { session_id }
+    }
+
+    pub fn session_id(&self) -> u128 {
+        self.session_id
+    }
+}
+
+impl ProcedureInvokeRequest {
+    pub fn new(
+        session_id: u128,
+        procedure_name: impl Into<String>,
+        procedure_parameters: Vec<u8>,
+    ) -> Self {
+        Self {
+            session_id,
+            procedure_name: procedure_name.into(),
+            procedure_parameters,
+        }
+    }
+
+    pub fn session_id(&self) -> u128 {
+        self.session_id
+    }
+
+    pub fn procedure_name(&self) -> &str {
+        &self.procedure_name
+    }
+
+    pub fn procedure_parameters(&self) -> &[u8] {
+        &self.procedure_parameters
+    }
+
+    pub fn procedure_parameters_owned(&self) -> Vec<u8> {
+        self.procedure_parameters.clone()
+    }
+}
+
+impl SessionCreateRequest {
+    pub fn new(config_json: Option<String>) -> Self {
+        Self { config_json }
+    }
+
+    pub fn config_json(&self) -> Option<&str> {
+        self.config_json.as_deref()
+    }
+}
+
+impl KeyValue {
+    pub fn new(key: Vec<u8>, value: Vec<u8>) -> Self {
+        Self { key, value }
+    }
+
+    pub fn key(&self) -> &[u8] {
+        &self.key
+    }
+
+    pub fn value(&self) -> &[u8] {
+        &self.value
+    }
+
+    pub fn into_parts(self) -> (Vec<u8>, Vec<u8>) {
+        (self.key, self.value)
+    }
+}
+
+impl GetResponse {
+    pub fn new(value: Option<Vec<u8>>) -> Self {
+        Self { value }
+    }
+
+    pub fn value(&self) -> Option<&[u8]> {
+        self.value.as_deref()
+    }
+
+    pub fn into_value(self) -> Option<Vec<u8>> {
+        self.value
+    }
+}
+
+impl PutResponse {
+    pub fn new(ok: bool) -> Self {
+        Self { ok }
+    }
+
+    pub fn ok(&self) -> bool {
+        self.ok
+    }
+}
+
+impl DeleteResponse {
+    pub fn new(ok: bool) -> Self {
+        Self { ok }
+    }
+
+    pub fn ok(&self) -> bool {
+        self.ok
+    }
+}
+
+impl CasResponse {
+    pub fn new(swapped: bool) -> Self {
+        Self { swapped }
+    }
+
+    pub fn swapped(&self) -> bool {
+        self.swapped
+    }
+}
+
+impl IncrResponse {
+    pub fn new(value: i64) -> Self {
+        Self { value }
+    }
+
+    pub fn value(&self) -> i64 {
+        self.value
+    }
+}
+
+impl KvNamespaceResponse {
+    pub fn new(ok: bool) -> Self {
+        Self { ok }
+    }
+
+    pub fn ok(&self) -> bool {
+        self.ok
+    }
+}
+
+impl KvNamespaceListResponse {
+    pub fn new(namespaces: Vec<KvNamespace>) -> Self {
+        Self { namespaces }
+    }
+
+    pub fn namespaces(&self) -> &[KvNamespace] {
+        &self.namespaces
+    }
+
+    pub fn into_namespaces(self) -> Vec<KvNamespace> {
+        self.namespaces
+    }
+}
+
+impl RangeScanResponse {
+    pub fn new(items: Vec<KeyValue>) -> Self {
+        Self {
+            items,
+            continuation: None,
+        }
+    }
+
+    /// Marks the response as one page of a longer scan resumed with `token`.
+    pub fn with_continuation(mut self, token: Option<Vec<u8>>) -> Self {
+        self.continuation = token;
+        self
+    }
+
+    pub fn continuation(&self) -> Option<&[u8]> {
+        self.continuation.as_deref()
+    }
+
+    pub fn items(&self) -> &[KeyValue] {
+        &self.items
+    }
+
+    pub fn into_items(self) -> Vec<KeyValue> {
+        self.items
+    }
+
+    pub fn into_page(self) -> KvRangePage {
+        let items = self.items.into_iter().map(KeyValue::into_parts).collect();
+        KvRangePage::new(items, self.continuation)
+    }
+}
+
+impl ProcedureInvokeResponse {
+    pub fn new(result: Vec<u8>) -> Self {
+        Self {
+            result,
+            server_perf_digest: None,
+        }
+    }
+
+    pub fn result(&self) -> &[u8] {
+        &self.result
+    }
+
+    pub fn into_result(self) -> Vec<u8> {
+        self.result
+    }
+
+    pub fn with_server_perf_digest(mut self, digest: ServerPerfDigest) -> Self {
+        self.server_perf_digest = Some(digest);
+        self
+    }
+
+    pub fn server_perf_digest(&self) -> Option<&ServerPerfDigest> {
+        self.server_perf_digest.as_ref()
+    }
+}
+
+impl SessionCreateResponse {
+    pub fn new(session_id: u128) -> Self {
+        Self { session_id }
+    }
+
+    pub fn session_id(&self) -> u128 {
+        self.session_id
+    }
+}
+
+impl SessionCloseRequest {
+    pub fn new(session_id: u128) -> Self {
+        Self { session_id }
+    }
+
+    pub fn session_id(&self) -> u128 {
+        self.session_id
+    }
+}
+
+impl SessionCloseResponse {
+    pub fn new(closed: bool) -> Self {
+        Self { closed }
+    }
+
+    pub fn closed(&self) -> bool {
+        self.closed
+    }
+}
+
+impl CdcPosition {
+    pub fn new(worker_id: u128, lsn: u64) -> Self {
+        Self { worker_id, lsn }
+    }
+
+    pub fn worker_id(&self) -> u128 {
+        self.worker_id
+    }
+
+    pub fn lsn(&self) -> u64 {
+        self.lsn
+    }
+}
+
+impl CdcSubscribeRequest {
+    pub fn new(
+        consumer: impl Into<String>,
+        table: impl Into<String>,
+        from: Option<Vec<CdcPosition>>,
+        max_changes: u32,
+    ) -> Self {
+        Self {
+            consumer: consumer.into(),
+            table: table.into(),
+            from,
+            max_changes,
+        }
+    }
+
+    pub fn consumer(&self) -> &str {
+        &self.consumer
+    }
+
+    pub fn table(&self) -> &str {
+        &self.table
+    }
+
+    pub fn from(&self) -> Option<&[CdcPosition]> {
+        self.from.as_deref()
+    }
+
+    pub fn max_changes(&self) -> u32 {
+        self.max_changes
+    }
+}
+
+impl CdcChange {
+    pub fn new(
+        worker_id: u128,
+        lsn: u64,
+        xid: u64,
+        op: CdcOp,
+        key: JsonValue,
+        row: Option<JsonValue>,
+    ) -> Self {
+        Self {
+            worker_id,
+            lsn,
+            xid,
+            op,
+            key,
+            row,
+        }
+    }
+
+    pub fn worker_id(&self) -> u128 {
+        self.worker_id
+    }
+
+    /// LSN of the log entry holding the change.
+    pub fn lsn(&self) -> u64 {
+        self.lsn
+    }
+
+    pub fn xid(&self) -> u64 {
+        self.xid
+    }
+
+    pub fn op(&self) -> CdcOp {
+        self.op
+    }
+
+    pub fn key(&self) -> &JsonValue {
+        &self.key
+    }
+
+    pub fn row(&self) -> Option<&JsonValue> {
+        self.row.as_ref()
+    }
+}
+
+impl CdcSubscribeResponse {
+    pub fn new(changes: Vec<CdcChange>, positions: Vec<CdcPosition>) -> Self {
+        Self { changes, positions }
+    }
+
+    pub fn changes(&self) -> &[CdcChange] {
+        &self.changes
+    }
+
+    pub fn positions(&self) -> &[CdcPosition] {
+        &self.positions
+    }
+
+    pub fn into_parts(self) -> (Vec<CdcChange>, Vec<CdcPosition>) {
+        (self.changes, self.positions)
+    }
+}
+
+impl WalFetchRequest {
+    pub fn new(replica: impl Into<String>, worker_id: u128, from_lsn: u64, max_bytes: u32) -> Self {
         Self {
-            result,
-            server_perf_digest: None,
+            replica: replica.into(),
+            worker_id,
+            from_lsn,
+            max_bytes,
         }
     }
 
-    pub fn result(&self) -> &[u8] {
-        &self.result
+    pub fn replica(&self) -> &str {
+        &self.replica
     }
 
-    pub fn into_result(self) -> Vec<u8> {
-        self.result
+    pub fn worker_id(&self) -> u128 {
+        self.worker_id
     }
 
-    pub fn with_server_perf_digest(mut self, digest: ServerPerfDigest) -> Self {
-        self.server_perf_digest = Some(digest);
+    pub fn from_lsn(&self) -> u64 {
+        self.from_lsn
+    }
+
+    pub fn max_bytes(&self) -> u32 {
+        self.max_bytes
+    }
+}
+
+impl WalFetchResponse {
+    pub fn new(worker_id: u128, frames: Vec<u8>, primary_lsn: u64) -> Self {
+        Self {
+            worker_id,
+            frames,
+            primary_lsn,
+        }
+    }
+
+    pub fn worker_id(&self) -> u128 {
+        self.worker_id
+    }
+
+    pub fn frames(&self) -> &[u8] {
+        &self.frames
+    }
+
+    pub fn primary_lsn(&self) -> u64 {
+        self.primary_lsn
+    }
+
+    pub fn into_frames(self) -> Vec<u8> {
+        self.frames
+    }
+}
+
+impl WatchRegisterRequest {
+    pub fn new(session_id: u128, target: WatchTarget) -> Self {
+        Self {
+            session_id,
+            target,
+            from: None,
+            max_in_flight: 0,
+            max_changes: 0,
+        }
+    }
+
+    /// Resumes the watch from `from` instead of the current log ends.
+    pub fn with_from(mut self, from: Vec<CdcPosition>) -> Self {
+        self.from = Some(from);
         self
     }
 
-    pub fn server_perf_digest(&self) -> Option<&ServerPerfDigest> {
-        self.server_perf_digest.as_ref()
+    pub fn with_max_in_flight(mut self, max_in_flight: u32) -> Self {
+        self.max_in_flight = max_in_flight;
+        self
+    }
+
+    pub fn with_max_changes(mut self, max_changes: u32) -> Self {
+        self.max_changes = max_changes;
+        self
+    }
+
+    pub fn session_id(&self) -> u128 {
+        self.session_id
+    }
+
+    pub fn target(&self) -> &WatchTarget {
+        &self.target
+    }
+
+    pub fn from(&self) -> Option<&[CdcPosition]> {
+        self.from.as_deref()
+    }
+
+    pub fn max_in_flight(&self) -> u32 {
+        self.max_in_flight
+    }
+
+    pub fn max_changes(&self) -> u32 {
+        self.max_changes
     }
 }
 
-impl SessionCreateResponse {
-    pub fn new(session_id: u128) -> Self {
-        Self { session_id }
+impl WatchRegisterResponse {
+    pub fn new(watch_id: u64, positions: Vec<CdcPosition>) -> Self {
+        Self {
+            watch_id,
+            positions,
+        }
+    }
+
+    pub fn watch_id(&self) -> u64 {
+        self.watch_id
+    }
+
+    pub fn positions(&self) -> &[CdcPosition] {
+        &self.positions
+    }
+}
+
+impl WatchAckRequest {
+    pub fn new(session_id: u128, watch_id: u64, seq: u64) -> Self {
+        Self {
+            session_id,
+            watch_id,
+            seq,
+        }
     }
 
     pub fn session_id(&self) -> u128 {
         self.session_id
     }
+
+    pub fn watch_id(&self) -> u64 {
+        self.watch_id
+    }
+
+    pub fn seq(&self) -> u64 {
+        self.seq
+    }
 }
 
-impl SessionCloseRequest {
-    pub fn new(session_id: u128) -> Self {
-        Self { session_id }
+impl WatchCancelRequest {
+    pub fn new(session_id: u128, watch_id: u64) -> Self {
+        Self {
+            session_id,
+            watch_id,
+        }
     }
 
     pub fn session_id(&self) -> u128 {
         self.session_id
     }
+
+    pub fn watch_id(&self) -> u64 {
+        self.watch_id
+    }
 }
 
-impl SessionCloseResponse {
-    pub fn new(closed: bool) -> Self {
-        Self { closed }
+impl WatchResponse {
+    pub fn new(ok: bool) -> Self {
+        Self { ok }
     }
 
-    pub fn closed(&self) -> bool {
-        self.closed
+    pub fn ok(&self) -> bool {
+        self.ok
+    }
+}
+
+impl KvChange {
+    pub fn new(
+        worker_id: u128,
+        lsn: u64,
+        xid: u64,
+        op: CdcOp,
+        key: Vec<u8>,
+        value: Option<Vec<u8>>,
+    ) -> Self {
+        Self {
+            worker_id,
+            lsn,
+            xid,
+            op,
+            key,
+            value,
+        }
+    }
+
+    pub fn worker_id(&self) -> u128 {
+        self.worker_id
+    }
+
+    /// LSN of the log entry holding the change.
+    pub fn lsn(&self) -> u64 {
+        self.lsn
+    }
+
+    pub fn xid(&self) -> u64 {
+        self.xid
+    }
+
+    pub fn op(&self) -> CdcOp {
+        self.op
+    }
+
+    pub fn key(&self) -> &[u8] {
+        &self.key
+    }
+
+    /// The value written; `None` for a delete.
+    pub fn value(&self) -> Option<&[u8]> {
+        self.value.as_deref()
+    }
+}
+
+impl WatchEvent {
+    pub fn new(
+        watch_id: u64,
+        seq: u64,
+        changes: Vec<WatchChange>,
+        positions: Vec<CdcPosition>,
+    ) -> Self {
+        Self {
+            watch_id,
+            seq,
+            changes,
+            positions,
+            error: None,
+        }
+    }
+
+    pub fn with_error(mut self, error: impl Into<String>) -> Self {
+        self.error = Some(error.into());
+        self
+    }
+
+    pub fn watch_id(&self) -> u64 {
+        self.watch_id
+    }
+
+    pub fn seq(&self) -> u64 {
+        self.seq
+    }
+
+    pub fn changes(&self) -> &[WatchChange] {
+        &self.changes
+    }
+
+    pub fn positions(&self) -> &[CdcPosition] {
+        &self.positions
+    }
+
+    /// Why the server cancelled the watch; `None` while it is live.
+    pub fn error(&self) -> Option<&str> {
+        self.error.as_deref()
     }
 }
 
@@ -760,6 +1778,72 @@ pub fn decode_put_response(frame: &Frame) -> RS<PutResponse> {
     decode_payload(frame.payload(), "decode put response error")
 }
 
+pub fn encode_delete_request(request_id: u64, request: &DeleteRequest) -> RS<Vec<u8>> {
+    let payload = encode_payload(request, "encode delete request error")?;
+    Ok(Frame::new(MessageType::Delete, request_id, payload).encode())
+}
+
+pub fn decode_delete_request(frame: &Frame) -> RS<DeleteRequest> {
+    decode_payload(frame.payload(), "decode delete request error")
+}
+
+pub fn decode_delete_response(frame: &Frame) -> RS<DeleteResponse> {
+    decode_payload(frame.payload(), "decode delete response error")
+}
+
+pub fn encode_put_many_request(request_id: u64, request: &PutManyRequest) -> RS<Vec<u8>> {
+    let payload = encode_payload(request, "encode put many request error")?;
+    Ok(Frame::new(MessageType::PutMany, request_id, payload).encode())
+}
+
+pub fn decode_put_many_request(frame: &Frame) -> RS<PutManyRequest> {
+    decode_payload(frame.payload(), "decode put many request error")
+}
+
+pub fn encode_delete_many_request(request_id: u64, request: &DeleteManyRequest) -> RS<Vec<u8>> {
+    let payload = encode_payload(request, "encode delete many request error")?;
+    Ok(Frame::new(MessageType::DeleteMany, request_id, payload).encode())
+}
+
+pub fn decode_delete_many_request(frame: &Frame) -> RS<DeleteManyRequest> {
+    decode_payload(frame.payload(), "decode delete many request error")
+}
+
+pub fn encode_cas_request(request_id: u64, request: &CasRequest) -> RS<Vec<u8>> {
+    let payload = encode_payload(request, "encode cas request error")?;
+    Ok(Frame::new(MessageType::Cas, request_id, payload).encode())
+}
+
+pub fn decode_cas_request(frame: &Frame) -> RS<CasRequest> {
+    decode_payload(frame.payload(), "decode cas request error")
+}
+
+pub fn decode_cas_response(frame: &Frame) -> RS<CasResponse> {
+    decode_payload(frame.payload(), "decode cas response error")
+}
+
+pub fn encode_incr_request(request_id: u64, request: &IncrRequest) -> RS<Vec<u8>> {
+    let payload = encode_payload(request, "encode incr request error")?;
+    Ok(Frame::new(MessageType::Incr, request_id, payload).encode())
+}
+
+pub fn decode_incr_request(frame: &Frame) -> RS<IncrRequest> {
+    decode_payload(frame.payload(), "decode incr request error")
+}
+
+pub fn decode_incr_response(frame: &Frame) -> RS<IncrResponse> {
+    decode_payload(frame.payload(), "decode incr response error")
+}
+
+pub fn encode_put_if_absent_request(request_id: u64, request: &PutIfAbsentRequest) -> RS<Vec<u8>> {
+    let payload = encode_payload(request, "encode put if absent request error")?;
+    Ok(Frame::new(MessageType::PutIfAbsent, request_id, payload).encode())
+}
+
+pub fn decode_put_if_absent_request(frame: &Frame) -> RS<PutIfAbsentRequest> {
+    decode_payload(frame.payload(), "decode put if absent request error")
+}
+
 pub fn encode_range_scan_request(request_id: u64, request: &RangeScanRequest) -> RS<Vec<u8>> {
     let payload = encode_payload(request, "encode range scan request error")?;
     Ok(Frame::new(MessageType::RangeScan, request_id, payload).encode())
@@ -769,6 +1853,50 @@ pub fn decode_range_scan_request(frame: &Frame) -> RS<RangeScanRequest> {
     decode_payload(frame.payload(), "decode range scan request error")
 }
 
+pub fn encode_kv_namespace_create_request(
+    request_id: u64,
+    request: &KvNamespaceCreateRequest,
+) -> RS<Vec<u8>> {
+    let payload = encode_payload(request, "encode kv namespace create request error")?;
+    Ok(Frame::new(MessageType::KvNamespaceCreate, request_id, payload).encode())
+}
+
+pub fn decode_kv_namespace_create_request(frame: &Frame) -> RS<KvNamespaceCreateRequest> {
+    decode_payload(frame.payload(), "decode kv namespace create request error")
+}
+
+pub fn encode_kv_namespace_drop_request(
+    request_id: u64,
+    request: &KvNamespaceDropRequest,
+) -> RS<Vec<u8>> {
+    let payload = encode_payload(request, "encode kv namespace drop request error")?;
+    Ok(Frame::new(MessageType::KvNamespaceDrop, request_id, payload).encode())
+}
+
+pub fn decode_kv_namespace_drop_request(frame: &Frame) -> RS<KvNamespaceDropRequest> {
+    decode_payload(frame.payload(), "decode kv namespace drop request error")
+}
+
+pub fn encode_kv_namespace_list_request(
+    request_id: u64,
+    request: &KvNamespaceListRequest,
+) -> RS<Vec<u8>> {
+    let payload = encode_payload(request, "encode kv namespace list request error")?;
+    Ok(Frame::new(MessageType::KvNamespaceList, request_id, payload).encode())
+}
+
+pub fn decode_kv_namespace_list_request(frame: &Frame) -> RS<KvNamespaceListRequest> {
+    decode_payload(frame.payload(), "decode kv namespace list request error")
+}
+
+pub fn decode_kv_namespace_response(frame: &Frame) -> RS<KvNamespaceResponse> {
+    decode_payload(frame.payload(), "decode kv namespace response error")
+}
+
+pub fn decode_kv_namespace_list_response(frame: &Frame) -> RS<KvNamespaceListResponse> {
+    decode_payload(frame.payload(), "decode kv namespace list response error")
+}
+
 pub fn encode_procedure_invoke_request(
     request_id: u64,
     request: &ProcedureInvokeRequest,
@@ -837,11 +1965,42 @@ pub fn encode_put_response(request_id: u64, response: &PutResponse) -> RS<Vec<u8
     Ok(Frame::new(MessageType::Response, request_id, payload).encode())
 }
 
+pub fn encode_delete_response(request_id: u64, response: &DeleteResponse) -> RS<Vec<u8>> {
+    let payload = encode_payload(response, "encode delete response error")?;
+    Ok(Frame::new(MessageType::Response, request_id, payload).encode())
+}
+
+pub fn encode_cas_response(request_id: u64, response: &CasResponse) -> RS<Vec<u8>> {
+    let payload = encode_payload(response, "encode cas response error")?;
+    Ok(Frame::new(MessageType::Response, request_id, payload).encode())
+}
+
+pub fn encode_incr_response(request_id: u64, response: &IncrResponse) -> RS<Vec<u8>> {
+    let payload = encode_payload(response, "encode incr response error")?;
+    Ok(Frame::new(MessageType::Response, request_id, payload).encode())
+}
+
 pub fn encode_range_scan_response(request_id: u64, response: &RangeScanResponse) -> RS<Vec<u8>> {
     let payload = encode_payload(response, "encode range scan response error")?;
     Ok(Frame::new(MessageType::Response, request_id, payload).encode())
 }
 
+pub fn encode_kv_namespace_response(
+    request_id: u64,
+    response: &KvNamespaceResponse,
+) -> RS<Vec<u8>> {
+    let payload = encode_payload(response, "encode kv namespace response error")?;
+    Ok(Frame::new(MessageType::Response, request_id, payload).encode())
+}
+
+pub fn encode_kv_namespace_list_response(
+    request_id: u64,
+    response: &KvNamespaceListResponse,
+) -> RS<Vec<u8>> {
+    let payload = encode_payload(response, "encode kv namespace list response error")?;
+    Ok(Frame::new(MessageType::Response, request_id, payload).encode())
+}
+
 pub fn encode_procedure_invoke_response(
     request_id: u64,
     response: &ProcedureInvokeResponse,
@@ -874,6 +2033,106 @@ pub fn decode_session_close_response(frame: &Frame) -> RS<SessionCloseResponse>
     decode_payload(frame.payload(), "decode session close response error")
 }
 
+pub fn encode_cdc_subscribe_request(request_id: u64, request: &CdcSubscribeRequest) -> RS<Vec<u8>> {
+    let payload = encode_payload(request, "encode cdc subscribe request error")?;
+    Ok(Frame::new(MessageType::CdcSubscribe, request_id, payload).encode())
+}
+
+pub fn decode_cdc_subscribe_request(frame: &Frame) -> RS<CdcSubscribeRequest> {
+    decode_payload(frame.payload(), "decode cdc subscribe request error")
+}
+
+pub fn encode_cdc_subscribe_response(
+    request_id: u64,
+    response: &CdcSubscribeResponse,
+) -> RS<Vec<u8>> {
+    let payload = encode_payload(response, "encode cdc subscribe response error")?;
+    Ok(Frame::new(MessageType::Response, request_id, payload).encode())
+}
+
+pub fn decode_cdc_subscribe_response(frame: &Frame) -> RS<CdcSubscribeResponse> {
+    decode_payload(frame.payload(), "decode cdc subscribe response error")
+}
+
+pub fn encode_wal_fetch_request(request_id: u64, request: &WalFetchRequest) -> RS<Vec<u8>> {
+    let payload = encode_payload(request, "encode wal fetch request error")?;
+    Ok(Frame::new(MessageType::WalFetch, request_id, payload).encode())
+}
+
+pub fn decode_wal_fetch_request(frame: &Frame) -> RS<WalFetchRequest> {
+    decode_payload(frame.payload(), "decode wal fetch request error")
+}
+
+pub fn encode_wal_fetch_response(request_id: u64, response: &WalFetchResponse) -> RS<Vec<u8>> {
+    let payload = encode_payload(response, "encode wal fetch response error")?;
+    Ok(Frame::new(MessageType::Response, request_id, payload).encode())
+}
+
+pub fn decode_wal_fetch_response(frame: &Frame) -> RS<WalFetchResponse> {
+    decode_payload(frame.payload(), "decode wal fetch response error")
+}
+
+pub fn encode_watch_register_request(
+    request_id: u64,
+    request: &WatchRegisterRequest,
+) -> RS<Vec<u8>> {
+    let payload = encode_payload(request, "encode watch register request error")?;
+    Ok(Frame::new(MessageType::WatchRegister, request_id, payload).encode())
+}
+
+pub fn decode_watch_register_request(frame: &Frame) -> RS<WatchRegisterRequest> {
+    decode_payload(frame.payload(), "decode watch register request error")
+}
+
+pub fn encode_watch_register_response(
+    request_id: u64,
+    response: &WatchRegisterResponse,
+) -> RS<Vec<u8>> {
+    let payload = encode_payload(response, "encode watch register response error")?;
+    Ok(Frame::new(MessageType::Response, request_id, payload).encode())
+}
+
+pub fn decode_watch_register_response(frame: &Frame) -> RS<WatchRegisterResponse> {
+    decode_payload(frame.payload(), "decode watch register response error")
+}
+
+pub fn encode_watch_ack_request(request_id: u64, request: &WatchAckRequest) -> RS<Vec<u8>> {
+    let payload = encode_payload(request, "encode watch ack request error")?;
+    Ok(Frame::new(MessageType::WatchAck, request_id, payload).encode())
+}
+
+pub fn decode_watch_ack_request(frame: &Frame) -> RS<WatchAckRequest> {
+    decode_payload(frame.payload(), "decode watch ack request error")
+}
+
+pub fn encode_watch_cancel_request(request_id: u64, request: &WatchCancelRequest) -> RS<Vec<u8>> {
+    let payload = encode_payload(request, "encode watch cancel request error")?;
+    Ok(Frame::new(MessageType::WatchCancel, request_id, payload).encode())
+}
+
+pub fn decode_watch_cancel_request(frame: &Frame) -> RS<WatchCancelRequest> {
+    decode_payload(frame.payload(), "decode watch cancel request error")
+}
+
+pub fn encode_watch_response(request_id: u64, response: &WatchResponse) -> RS<Vec<u8>> {
+    let payload = encode_payload(response, "encode watch response error")?;
+    Ok(Frame::new(MessageType::Response, request_id, payload).encode())
+}
+
+pub fn decode_watch_response(frame: &Frame) -> RS<WatchResponse> {
+    decode_payload(frame.payload(), "decode watch response error")
+}
+
+/// Encodes a server-initiated `WatchEvent` frame; it carries request id 0.
+pub fn encode_watch_event(event: &WatchEvent) -> RS<Vec<u8>> {
+    let payload = encode_payload(event, "encode watch event error")?;
+    Ok(Frame::new(MessageType::WatchEvent, 0, payload).encode())
+}
+
+pub fn decode_watch_event(frame: &Frame) -> RS<WatchEvent> {
+    decode_payload(frame.payload(), "decode watch event error")
+}
+
 pub fn encode_error_response(request_id: u64, message: impl Into<String>) -> RS<Vec<u8>> {
     let payload = encode_payload(&ErrorResponse::new(message), "encode error response error")?;
     Ok(Frame::new(MessageType::Error, request_id, payload).encode())
@@ -4,6 +4,8 @@
 #[cfg(test)]
 mod tests {
     use super::super::*;
+    use crate::database::kv_namespace::KvNamespace;
+    use crate::database::kv_range::KvRangeOptions;
     use mudu_sys_contract::perf::{TraceContext, TxnStage};
 
     #[test]
@@ -222,13 +224,28 @@ mod tests {
             (11, MessageType::ProcedureInvoke),
             (12, MessageType::SessionCreate),
             (13, MessageType::SessionClose),
+            (14, MessageType::CdcSubscribe),
+            (15, MessageType::WalFetch),
+            (16, MessageType::Delete),
+            (17, MessageType::PutMany),
+            (18, MessageType::DeleteMany),
+            (19, MessageType::Cas),
+            (20, MessageType::Incr),
+            (21, MessageType::PutIfAbsent),
+            (22, MessageType::KvNamespaceCreate),
+            (23, MessageType::KvNamespaceDrop),
+            (24, MessageType::KvNamespaceList),
+            (25, MessageType::WatchRegister),
+            (26, MessageType::WatchAck),
+            (27, MessageType::WatchCancel),
+            (28, MessageType::WatchEvent),
         ];
         for (value, expected) in cases {
             assert_eq!(MessageType::try_from(value).unwrap(), expected);
             assert_eq!(u32::from(expected), value);
         }
         assert!(MessageType::try_from(0).is_err());
-        assert!(MessageType::try_from(14).is_err());
+        assert!(MessageType::try_from(29).is_err());
     }
 
     #[test]
@@ -374,6 +391,346 @@ mod tests {
         assert!(decoded.closed());
     }
 
+    #[test]
+    fn put_ttl_roundtrip() {
+        let request = PutRequest::new(9, b"k".to_vec(), b"v".to_vec())
+            .with_ttl(std::time::Duration::from_millis(1500));
+        let frame = Frame::decode(&encode_put_request(5, &request).unwrap()).unwrap();
+        let decoded = decode_put_request(&frame).unwrap();
+        assert_eq!(decoded.ttl(), Some(std::time::Duration::from_millis(1500)));
+        assert_eq!(decoded.into_parts(), (b"k".to_vec(), b"v".to_vec()));
+
+        let legacy = rmp_serde::to_vec(&(9u128, b"k".to_vec(), b"v".to_vec())).unwrap();
+        let request = decode_put_request(&Frame::new(MessageType::Put, 6, legacy)).unwrap();
+        assert_eq!(request.value(), b"v");
+        assert_eq!(request.ttl(), None);
+    }
+
+    #[test]
+    fn delete_and_multi_key_roundtrips() {
+        let frame = Frame::decode(
+            &encode_delete_request(6, &DeleteRequest::new(9, b"k".to_vec())).unwrap(),
+        )
+        .unwrap();
+        assert_eq!(frame.header().message_type(), MessageType::Delete);
+        let request = decode_delete_request(&frame).unwrap();
+        assert_eq!(request.session_id(), 9);
+        assert_eq!(request.key(), b"k");
+
+        let items = vec![
+            KeyValue::new(b"a".to_vec(), b"1".to_vec()),
+            KeyValue::new(b"b".to_vec(), b"2".to_vec()),
+        ];
+        let frame = Frame::decode(
+            &encode_put_many_request(7, &PutManyRequest::new(9, items.clone())).unwrap(),
+        )
+        .unwrap();
+        assert_eq!(frame.header().message_type(), MessageType::PutMany);
+        let request = decode_put_many_request(&frame).unwrap();
+        assert_eq!(request.session_id(), 9);
+        assert_eq!(request.items(), items.as_slice());
+        assert_eq!(
+            request.into_items()[1].clone().into_parts(),
+            (b"b".to_vec(), b"2".to_vec())
+        );
+
+        let keys = vec![b"a".to_vec(), b"b".to_vec()];
+        let frame = Frame::decode(
+            &encode_delete_many_request(8, &DeleteManyRequest::new(9, keys.clone())).unwrap(),
+        )
+        .unwrap();
+        assert_eq!(frame.header().message_type(), MessageType::DeleteMany);
+        let request = decode_delete_many_request(&frame).unwrap();
+        assert_eq!(request.session_id(), 9);
+        assert_eq!(request.keys(), keys.as_slice());
+        assert_eq!(request.into_keys(), keys);
+
+        let frame =
+            Frame::decode(&encode_delete_response(8, &DeleteResponse::new(true)).unwrap()).unwrap();
+        assert_eq!(frame.header().message_type(), MessageType::Response);
+        assert!(decode_delete_response(&frame).unwrap().ok());
+    }
+
+    #[test]
+    fn kv_namespace_roundtrips() {
+        let namespace = KvNamespace::new("wallet", "balances").unwrap();
+        let request = KvNamespaceCreateRequest::new(9, "balances").with_partition_rule("r");
+        let frame =
+            Frame::decode(&encode_kv_namespace_create_request(1, &request).unwrap()).unwrap();
+        assert_eq!(
+            frame.header().message_type(),
+            MessageType::KvNamespaceCreate
+        );
+        let decoded = decode_kv_namespace_create_request(&frame).unwrap();
+        assert_eq!(decoded.session_id(), 9);
+        assert_eq!(decoded.namespace(), "balances");
+        assert_eq!(decoded.partition_rule(), Some("r"));
+
+        let request = KvNamespaceDropRequest::new(9, "balances");
+        let frame = Frame::decode(&encode_kv_namespace_drop_request(2, &request).unwrap()).unwrap();
+        assert_eq!(frame.header().message_type(), MessageType::KvNamespaceDrop);
+        assert_eq!(
+            decode_kv_namespace_drop_request(&frame)
+                .unwrap()
+                .namespace(),
+            "balances"
+        );
+
+        let request = KvNamespaceListRequest::new(9);
+        let frame = Frame::decode(&encode_kv_namespace_list_request(3, &request).unwrap()).unwrap();
+        assert_eq!(frame.header().message_type(), MessageType::KvNamespaceList);
+        assert_eq!(
+            decode_kv_namespace_list_request(&frame)
+                .unwrap()
+                .session_id(),
+            9
+        );
+
+        let frame = Frame::decode(
+            &encode_kv_namespace_response(1, &KvNamespaceResponse::new(true)).unwrap(),
+        )
+        .unwrap();
+        assert!(decode_kv_namespace_response(&frame).unwrap().ok());
+        let response = KvNamespaceListResponse::new(vec![namespace.clone()]);
+        let frame =
+            Frame::decode(&encode_kv_namespace_list_response(3, &response).unwrap()).unwrap();
+        assert_eq!(
+            decode_kv_namespace_list_response(&frame)
+                .unwrap()
+                .into_namespaces(),
+            vec![namespace.clone()]
+        );
+
+        let request = GetRequest::new(9, b"k".to_vec()).with_namespace("balances");
+        let frame = Frame::decode(&encode_get_request(4, &request).unwrap()).unwrap();
+        assert_eq!(
+            decode_get_request(&frame).unwrap().namespace(),
+            Some("balances")
+        );
+        let request = PutRequest::new(9, b"k".to_vec(), b"v".to_vec()).with_namespace("balances");
+        let frame = Frame::decode(&encode_put_request(5, &request).unwrap()).unwrap();
+        let decoded = decode_put_request(&frame).unwrap();
+        assert_eq!(decoded.namespace(), Some("balances"));
+        assert_eq!(decoded.ttl(), None);
+        let request = DeleteRequest::new(9, b"k".to_vec()).with_namespace("balances");
+        let frame = Frame::decode(&encode_delete_request(6, &request).unwrap()).unwrap();
+        assert_eq!(
+            decode_delete_request(&frame).unwrap().namespace(),
+            Some("balances")
+        );
+        let request =
+            RangeScanRequest::new(9, b"a".to_vec(), Vec::new()).with_namespace("balances");
+        let frame = Frame::decode(&encode_range_scan_request(7, &request).unwrap()).unwrap();
+        assert_eq!(
+            decode_range_scan_request(&frame).unwrap().namespace(),
+            Some("balances")
+        );
+
+        let legacy = rmp_serde::to_vec(&(9u128, b"k".to_vec())).unwrap();
+        let request = decode_get_request(&Frame::new(MessageType::Get, 8, legacy)).unwrap();
+        assert_eq!(request.namespace(), None);
+    }
+
+    #[test]
+    fn range_scan_options_and_continuation_roundtrip() {
+        let options = KvRangeOptions::new()
+            .with_limit(2)
+            .with_reverse(true)
+            .with_prefix(b"user/".to_vec())
+            .with_keys_only(true)
+            .with_continuation(b"user/9".to_vec());
+        let request =
+            RangeScanRequest::new(9, b"a".to_vec(), b"z".to_vec()).with_options(options.clone());
+        let frame = Frame::decode(&encode_range_scan_request(3, &request).unwrap()).unwrap();
+        assert_eq!(
+            decode_range_scan_request(&frame).unwrap().options(),
+            &options
+        );
+
+        let legacy = rmp_serde::to_vec(&(9u128, b"a".to_vec(), b"z".to_vec())).unwrap();
+        let frame = Frame::new(MessageType::RangeScan, 4, legacy);
+        let request = decode_range_scan_request(&frame).unwrap();
+        assert_eq!(request.end_key(), b"z");
+        assert!(request.options().is_default());
+
+        let response = RangeScanResponse::new(vec![KeyValue::new(b"k".to_vec(), Vec::new())])
+            .with_continuation(Some(b"k".to_vec()));
+        let frame = Frame::decode(&encode_range_scan_response(3, &response).unwrap()).unwrap();
+        let decoded = decode_range_scan_response(&frame).unwrap();
+        assert_eq!(decoded.continuation(), Some(b"k".as_slice()));
+        assert_eq!(decoded, response);
+        let page = decoded.into_page();
+        assert_eq!(page.items(), &[(b"k".to_vec(), Vec::new())]);
+        assert_eq!(page.continuation(), Some(b"k".as_slice()));
+    }
+
+    #[test]
+    fn atomic_update_roundtrips() {
+        let request = CasRequest::new(9, b"k".to_vec(), Some(b"old".to_vec()), b"new".to_vec());
+        let frame = Frame::decode(&encode_cas_request(1, &request).unwrap()).unwrap();
+        assert_eq!(frame.header().message_type(), MessageType::Cas);
+        let request = decode_cas_request(&frame).unwrap();
+        assert_eq!(request.session_id(), 9);
+        assert_eq!(request.expected(), Some(b"old".as_slice()));
+        assert_eq!(
+            request.into_parts(),
+            (b"k".to_vec(), Some(b"old".to_vec()), b"new".to_vec())
+        );
+
+        let frame = Frame::decode(
+            &encode_incr_request(2, &IncrRequest::new(9, b"n".to_vec(), -3)).unwrap(),
+        )
+        .unwrap();
+        assert_eq!(frame.header().message_type(), MessageType::Incr);
+        let request = decode_incr_request(&frame).unwrap();
+        assert_eq!(request.key(), b"n");
+        assert_eq!(request.delta(), -3);
+
+        let frame = Frame::decode(
+            &encode_put_if_absent_request(
+                3,
+                &PutIfAbsentRequest::new(9, b"k".to_vec(), b"v".to_vec()),
+            )
+            .unwrap(),
+        )
+        .unwrap();
+        assert_eq!(frame.header().message_type(), MessageType::PutIfAbsent);
+        let request = decode_put_if_absent_request(&frame).unwrap();
+        assert_eq!(request.session_id(), 9);
+        assert_eq!(request.into_parts(), (b"k".to_vec(), b"v".to_vec()));
+
+        let frame =
+            Frame::decode(&encode_cas_response(3, &CasResponse::new(true)).unwrap()).unwrap();
+        assert_eq!(frame.header().message_type(), MessageType::Response);
+        assert!(decode_cas_response(&frame).unwrap().swapped());
+        let frame =
+            Frame::decode(&encode_incr_response(2, &IncrResponse::new(-3)).unwrap()).unwrap();
+        assert_eq!(decode_incr_response(&frame).unwrap().value(), -3);
+    }
+
+    #[test]
+    fn cdc_subscribe_roundtrip() {
+        let request =
+            CdcSubscribeRequest::new("audit", "orders", Some(vec![CdcPosition::new(7, 42)]), 100);
+        let frame = Frame::decode(&encode_cdc_subscribe_request(4, &request).unwrap()).unwrap();
+        assert_eq!(frame.header().message_type(), MessageType::CdcSubscribe);
+        assert_eq!(decode_cdc_subscribe_request(&frame).unwrap(), request);
+
+        let response = CdcSubscribeResponse::new(
+            vec![CdcChange::new(
+                7,
+                42,
+                9,
+                CdcOp::Put,
+                serde_json::json!({"id": 1}),
+                Some(serde_json::json!({"id": 1, "note": "x"})),
+            )],
+            vec![CdcPosition::new(7, 43)],
+        );
+        let frame = Frame::decode(&encode_cdc_subscribe_response(4, &response).unwrap()).unwrap();
+        assert_eq!(frame.header().message_type(), MessageType::Response);
+        let decoded = decode_cdc_subscribe_response(&frame).unwrap();
+        assert_eq!(decoded, response);
+        assert_eq!(decoded.changes()[0].op(), CdcOp::Put);
+        assert_eq!(decoded.positions()[0].lsn(), 43);
+    }
+
+    #[test]
+    fn watch_messages_roundtrip() {
+        let request = WatchRegisterRequest::new(
+            3,
+            WatchTarget::TableRange {
+                table: "orders".to_string(),
+                start: Some(serde_json::json!({"id": 10})),
+                end: None,
+            },
+        )
+        .with_from(vec![CdcPosition::new(7, 42)])
+        .with_max_in_flight(4)
+        .with_max_changes(50);
+        let frame = Frame::decode(&encode_watch_register_request(6, &request).unwrap()).unwrap();
+        assert_eq!(frame.header().message_type(), MessageType::WatchRegister);
+        let decoded = decode_watch_register_request(&frame).unwrap();
+        assert_eq!(decoded, request);
+        assert_eq!(decoded.from().unwrap()[0].lsn(), 42);
+        assert_eq!(decoded.max_in_flight(), 4);
+
+        let response = WatchRegisterResponse::new(1, vec![CdcPosition::new(7, 42)]);
+        let frame = Frame::decode(&encode_watch_register_response(6, &response).unwrap()).unwrap();
+        assert_eq!(frame.header().message_type(), MessageType::Response);
+        assert_eq!(decode_watch_register_response(&frame).unwrap(), response);
+
+        let ack = WatchAckRequest::new(3, 1, 2);
+        let frame = Frame::decode(&encode_watch_ack_request(7, &ack).unwrap()).unwrap();
+        assert_eq!(frame.header().message_type(), MessageType::WatchAck);
+        assert_eq!(decode_watch_ack_request(&frame).unwrap(), ack);
+
+        let cancel = WatchCancelRequest::new(3, 1);
+        let frame = Frame::decode(&encode_watch_cancel_request(8, &cancel).unwrap()).unwrap();
+        assert_eq!(frame.header().message_type(), MessageType::WatchCancel);
+        assert_eq!(decode_watch_cancel_request(&frame).unwrap(), cancel);
+        let frame =
+            Frame::decode(&encode_watch_response(8, &WatchResponse::new(true)).unwrap()).unwrap();
+        assert!(decode_watch_response(&frame).unwrap().ok());
+
+        let event = WatchEvent::new(
+            1,
+            2,
+            vec![
+                WatchChange::Kv(KvChange::new(
+                    7,
+                    42,
+                    9,
+                    CdcOp::Put,
+                    b"k".to_vec(),
+                    Some(b"v".to_vec()),
+                )),
+                WatchChange::Row(CdcChange::new(
+                    7,
+                    43,
+                    10,
+                    CdcOp::Delete,
+                    serde_json::json!({"id": 11}),
+                    None,
+                )),
+            ],
+            vec![CdcPosition::new(7, 44)],
+        );
+        let frame = Frame::decode(&encode_watch_event(&event).unwrap()).unwrap();
+        assert_eq!(frame.header().message_type(), MessageType::WatchEvent);
+        assert_eq!(frame.header().request_id(), 0);
+        let decoded = decode_watch_event(&frame).unwrap();
+        assert_eq!(decoded, event);
+        let WatchChange::Kv(change) = &decoded.changes()[0] else {
+            panic!("expected a kv change");
+        };
+        assert_eq!(change.value(), Some(&b"v"[..]));
+        assert!(decoded.error().is_none());
+
+        let failed = WatchEvent::new(1, 3, Vec::new(), Vec::new()).with_error("table dropped");
+        let frame = Frame::decode(&encode_watch_event(&failed).unwrap()).unwrap();
+        assert_eq!(
+            decode_watch_event(&frame).unwrap().error(),
+            Some("table dropped")
+        );
+    }
+
+    #[test]
+    fn wal_fetch_roundtrip() {
+        let request = WalFetchRequest::new("replica-1", 7, 42, 1 << 20);
+        let frame = Frame::decode(&encode_wal_fetch_request(5, &request).unwrap()).unwrap();
+        assert_eq!(frame.header().message_type(), MessageType::WalFetch);
+        assert_eq!(decode_wal_fetch_request(&frame).unwrap(), request);
+
+        let response = WalFetchResponse::new(7, vec![1, 2, 3], 50);
+        let frame = Frame::decode(&encode_wal_fetch_response(5, &response).unwrap()).unwrap();
+        assert_eq!(frame.header().message_type(), MessageType::Response);
+        let decoded = decode_wal_fetch_response(&frame).unwrap();
+        assert_eq!(decoded, response);
+        assert_eq!(decoded.primary_lsn(), 50);
+        assert_eq!(decoded.into_frames(), vec![1, 2, 3]);
+    }
+
     #[test]
     fn request_and_response_getters() {
         let range = RangeScanRequest::new(7, b"a".to_vec(), b"z".to_vec());
@@ -1,6 +1,7 @@
 //! `tuple::comparator` module.
 #![allow(missing_docs)]
 
+use std::borrow::Cow;
 use std::cmp::Ordering;
 use std::fmt::Debug;
 use std::hash::Hasher;
@@ -114,6 +115,10 @@ fn _hash_binary(id: DatTypeID, p: &DatType, val: &[u8], hasher: &mut dyn Hasher)
             e
         )
     })?;
+    if let Some(s) = _collated_string(p, &v_internal) {
+        p.collation().hash(s, hasher);
+        return Ok(());
+    }
     if let Some(h) = id.fn_hash() {
         h(&v_internal, hasher)
             .map_err(|e| mudu_error!(ErrorCode::HashFailed, "hash binary error", e))
@@ -123,7 +128,7 @@ fn _hash_binary(id: DatTypeID, p: &DatType, val: &[u8], hasher: &mut dyn Hasher)
 }
 
 fn _compare_binary<
-    F: Fn(&DatTypeID, &DatValue, &DatValue) -> RS<R> + 'static,
+    F: Fn(&DatTypeID, &DatType, &DatValue, &DatValue) -> RS<R> + 'static,
     R: Debug + Copy + Clone + 'static,
 >(
     id: DatTypeID,
@@ -136,12 +141,92 @@ fn _compare_binary<
     let r1 = recv(value1, param);
     let r2 = recv(value2, param);
     match (r1, r2) {
-        (Ok((v1, _)), Ok((v2, _))) => compare(&id, &v1, &v2),
+        (Ok((v1, _)), Ok((v2, _))) => compare(&id, param, &v1, &v2),
         _ => Err(mudu_error!(ErrorCode::InvalidTuple)),
     }
 }
 
-fn _compare_binary_equal(data_type: &DatTypeID, value1: &DatValue, value2: &DatValue) -> RS<bool> {
+/// Equality of one encoded field. Binary-collated fields compare their
+/// encodings directly; others decode and compare through the collation.
+pub fn binary_field_equal(param: &DatType, value1: &[u8], value2: &[u8]) -> RS<bool> {
+    if param.collation().is_binary() {
+        return Ok(value1 == value2);
+    }
+    _compare_binary(
+        param.dat_type_id(),
+        param,
+        value1,
+        value2,
+        &_compare_binary_equal,
+    )
+}
+
+/// Ordering of one encoded field under its type and collation.
+pub fn binary_field_compare(param: &DatType, value1: &[u8], value2: &[u8]) -> RS<Ordering> {
+    _compare_binary(
+        param.dat_type_id(),
+        param,
+        value1,
+        value2,
+        &_compare_binary_ordering,
+    )
+}
+
+/// Encoding of one field in the key form of its collation, see
+/// [`Collation::key_form`](mudu_type::collation::Collation::key_form).
+/// Fields equal under their collation have identical key forms.
+pub fn binary_field_key_form<'a>(param: &DatType, value: &'a [u8]) -> RS<Cow<'a, [u8]>> {
+    if param.collation().is_binary() {
+        return Ok(Cow::Borrowed(value));
+    }
+    let id = param.dat_type_id();
+    let (internal, _) = id.fn_recv()(value, param).map_err(|e| {
+        mudu_error!(
+            ErrorCode::TypeConversionFailed,
+            "convert data format error",
+            e
+        )
+    })?;
+    let Some(s) = _collated_string(param, &internal) else {
+        return Ok(Cow::Borrowed(value));
+    };
+    match param.collation().key_form(s) {
+        Cow::Borrowed(_) => Ok(Cow::Borrowed(value)),
+        Cow::Owned(key_form) => {
+            let binary = id.fn_send()(&DatValue::from_string(key_form), param).map_err(|e| {
+                mudu_error!(
+                    ErrorCode::TypeConversionFailed,
+                    "convert data format error",
+                    e
+                )
+            })?;
+            Ok(Cow::Owned(binary.as_ref().to_vec()))
+        }
+    }
+}
+
+/// Returns the string to compare through the column collation, or `None`
+/// when the default function of the type applies.
+fn _collated_string<'a>(param: &DatType, value: &'a DatValue) -> Option<&'a str> {
+    if param.collation().is_binary() {
+        None
+    } else {
+        value.as_string().map(|s| s.as_str())
+    }
+}
+
+fn _compare_binary_equal(
+    data_type: &DatTypeID,
+    param: &DatType,
+    value1: &DatValue,
+    value2: &DatValue,
+) -> RS<bool> {
+    if let (Some(s1), Some(s2)) = (
+        _collated_string(param, value1),
+        _collated_string(param, value2),
+    ) {
+        return Ok(param.collation().equal(s1, s2));
+    }
     let opt_equal = data_type.fn_equal();
     let f = match opt_equal {
         None => return Err(mudu_error!(ErrorCode::UnsupportedOperation)),
@@ -158,9 +243,16 @@ fn _compare_binary_equal(data_type: &DatTypeID, value1: &DatValue, value2: &DatV
 
 fn _compare_binary_ordering(
     data_type: &DatTypeID,
+    param: &DatType,
     value1: &DatValue,
     value2: &DatValue,
 ) -> RS<Ordering> {
+    if let (Some(s1), Some(s2)) = (
+        _collated_string(param, value1),
+        _collated_string(param, value2),
+    ) {
+        return Ok(param.collation().compare(s1, s2));
+    }
     let opt_order = data_type.fn_order();
     let f = match opt_order {
         None => return Err(mudu_error!(ErrorCode::UnsupportedOperation)),
@@ -184,7 +276,7 @@ fn _need_return_equal(equal: bool) -> bool {
 }
 
 fn _compare_opt_binary<
-    F: Fn(&DatTypeID, &DatValue, &DatValue) -> RS<R> + 'static,
+    F: Fn(&DatTypeID, &DatType, &DatValue, &DatValue) -> RS<R> + 'static,
     R: Debug + Copy + Clone + 'static,
 >(
     id: DatTypeID,
@@ -198,7 +290,7 @@ fn _compare_opt_binary<
 }
 
 fn _iter_value<
-    F: Fn(&DatTypeID, &DatValue, &DatValue) -> RS<R> + 'static,
+    F: Fn(&DatTypeID, &DatType, &DatValue, &DatValue) -> RS<R> + 'static,
     R: Debug + Copy + Clone + 'static,
     T: Fn(R) -> bool + 'static,
 >(
@@ -5,7 +5,8 @@
 mod tests {
     use crate::tuple::build_tuple::build_tuple;
     use crate::tuple::comparator::{
-        TupleComparator, tuple_compare, tuple_equal, tuple_hash, tuple_hash_finish,
+        binary_field_compare, binary_field_equal, binary_field_key_form, tuple_compare,
+        tuple_equal, tuple_hash, tuple_hash_finish, TupleComparator,
     };
     use crate::tuple::datum_convert::datum_to_binary;
     use crate::tuple::datum_desc::DatumDesc;
@@ -13,8 +14,11 @@ mod tests {
     use crate::tuple::tuple_binary_desc::TupleBinaryDesc;
     use mudu::common::buf::Buf;
     use mudu::error::ErrorCode;
+    use mudu_type::collation::Collation;
     use mudu_type::dat_type::DatType;
     use mudu_type::dat_type_id::DatTypeID;
+    use mudu_type::dtp_string::DTPString;
+    use std::borrow::Cow;
     use std::cmp::Ordering;
     use std::collections::hash_map::DefaultHasher;
     use std::hash::Hasher;
@@ -301,4 +305,139 @@ mod tests {
         let err = tuple_compare(&desc, &tuple, &other).unwrap_err();
         assert_eq!(err.ec(), ErrorCode::InvalidTuple);
     }
+
+    fn collated_type(collation: Collation) -> DatType {
+        DatType::from_string(DTPString::new(64).with_collation(collation))
+    }
+
+    fn make_string_tuple(s: &str, ty: &DatType) -> Buf {
+        let binary =
+            datum_to_binary(&s.to_string(), &DatumDesc::new("s".to_string(), ty.clone())).unwrap();
+        build_tuple(&[binary], &TupleBinaryDesc::from(vec![ty.clone()]).unwrap()).unwrap()
+    }
+
+    #[test]
+    fn tuple_compare_honors_ascii_ci_collation() {
+        let ty = collated_type(Collation::AsciiCi);
+        let desc = TupleBinaryDesc::from(vec![ty.clone()]).unwrap();
+        let upper = make_string_tuple("Alice@Example.com", &ty);
+        let lower = make_string_tuple("alice@example.com", &ty);
+        let bob = make_string_tuple("BOB", &ty);
+
+        assert_eq!(
+            tuple_compare(&desc, &upper, &lower).unwrap(),
+            Ordering::Equal
+        );
+        assert!(tuple_equal(&desc, &upper, &lower).unwrap());
+        assert_eq!(tuple_compare(&desc, &lower, &bob).unwrap(), Ordering::Less);
+
+        let mut hasher_upper = DefaultHasher::new();
+        let mut hasher_lower = DefaultHasher::new();
+        tuple_hash(&desc, &upper, &mut hasher_upper).unwrap();
+        tuple_hash(&desc, &lower, &mut hasher_lower).unwrap();
+        assert_eq!(hasher_upper.finish(), hasher_lower.finish());
+    }
+
+    #[test]
+    fn tuple_compare_honors_unicode_root_collation() {
+        let ty = collated_type(Collation::UnicodeRoot);
+        let desc = TupleBinaryDesc::from(vec![ty.clone()]).unwrap();
+        let emile = make_string_tuple("Émile", &ty);
+        let ernie = make_string_tuple("ernie", &ty);
+        assert_eq!(
+            tuple_compare(&desc, &emile, &ernie).unwrap(),
+            Ordering::Less
+        );
+        // Bytewise, 'É' (0xC3..) sorts after every ASCII letter.
+        let binary = collated_type(Collation::Binary);
+        let binary_desc = TupleBinaryDesc::from(vec![binary.clone()]).unwrap();
+        assert_eq!(
+            tuple_compare(
+                &binary_desc,
+                &make_string_tuple("Émile", &binary),
+                &make_string_tuple("ernie", &binary),
+            )
+            .unwrap(),
+            Ordering::Greater
+        );
+    }
+
+    #[test]
+    fn binary_field_equal_honors_collation() {
+        let ci = collated_type(Collation::AsciiCi);
+        let a = datum_to_binary(
+            &"MixedCase".to_string(),
+            &DatumDesc::new("s".to_string(), ci.clone()),
+        )
+        .unwrap();
+        let b = datum_to_binary(
+            &"mixedcase".to_string(),
+            &DatumDesc::new("s".to_string(), ci.clone()),
+        )
+        .unwrap();
+        assert!(binary_field_equal(&ci, &a, &b).unwrap());
+        assert!(!binary_field_equal(&collated_type(Collation::Binary), &a, &b).unwrap());
+    }
+
+    #[test]
+    fn binary_field_key_form_folds_ascii_ci_only() {
+        let ci = collated_type(Collation::AsciiCi);
+        let encode = |s: &str, ty: &DatType| {
+            datum_to_binary(&s.to_string(), &DatumDesc::new("s".to_string(), ty.clone())).unwrap()
+        };
+        let mixed = encode("MixedCase", &ci);
+        let lower = encode("mixedcase", &ci);
+        assert_eq!(
+            binary_field_key_form(&ci, &mixed).unwrap().as_ref(),
+            &lower[..]
+        );
+        assert!(matches!(
+            binary_field_key_form(&ci, &lower).unwrap(),
+            Cow::Borrowed(_)
+        ));
+        let binary = collated_type(Collation::Binary);
+        let mixed = encode("MixedCase", &binary);
+        assert_eq!(
+            binary_field_key_form(&binary, &mixed).unwrap().as_ref(),
+            &mixed[..]
+        );
+    }
+
+    #[test]
+    fn binary_field_compare_orders_by_type() {
+        let i32_type = DatType::new_no_param(DatTypeID::I32);
+        let encode = |v: i32| {
+            datum_to_binary(&v, &DatumDesc::new("x".to_string(), i32_type.clone())).unwrap()
+        };
+        assert_eq!(
+            binary_field_compare(&i32_type, &encode(-3), &encode(2)).unwrap(),
+            Ordering::Less
+        );
+        assert_eq!(
+            binary_field_compare(&i32_type, &encode(7), &encode(7)).unwrap(),
+            Ordering::Equal
+        );
+    }
+    #[test]
+    fn binary_field_compare_orders_through_collation() {
+        let ci = collated_type(Collation::AsciiCi);
+        let binary = collated_type(Collation::Binary);
+        let encode = |s: &str, ty: &DatType| {
+            datum_to_binary(&s.to_string(), &DatumDesc::new("s".to_string(), ty.clone())).unwrap()
+        };
+        // Bytewise "Bob" < "alice", but case-insensitively "alice" < "Bob".
+        assert_eq!(
+            binary_field_compare(&binary, &encode("Bob", &binary), &encode("alice", &binary))
+                .unwrap(),
+            Ordering::Less
+        );
+        assert_eq!(
+            binary_field_compare(&ci, &encode("Bob", &ci), &encode("alice", &ci)).unwrap(),
+            Ordering::Greater
+        );
+        assert_eq!(
+            binary_field_compare(&ci, &encode("ALICE", &ci), &encode("alice", &ci)).unwrap(),
+            Ordering::Equal
+        );
+    }
 }
@@ -32,6 +32,8 @@ impl_scalar! {
     (Time, "string"),
     (Timestamp, "string"),
     (TimestampTz, "string"),
+    (Uuid, "string"),
+    (Json, "string"),
 }
 
 impl_non_scalar! {
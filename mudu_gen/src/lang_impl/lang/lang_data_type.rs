@@ -40,6 +40,8 @@ pub fn csharp_default_value_expr(wit_ty: &UniDatType) -> RS<String> {
             UniScalar::Time => "string.Empty".to_string(),
             UniScalar::Timestamp => "string.Empty".to_string(),
             UniScalar::TimestampTz => "string.Empty".to_string(),
+            UniScalar::Uuid => "string.Empty".to_string(),
+            UniScalar::Json => "string.Empty".to_string(),
         }),
         UniDatType::Tuple(_) => Ok("default".to_string()),
         UniDatType::Array(_) => Ok("[]".to_string()),
@@ -74,6 +76,8 @@ pub fn csharp_is_reference_type(wit_ty: &UniDatType) -> bool {
                     | UniScalar::Time
                     | UniScalar::Timestamp
                     | UniScalar::TimestampTz
+                    | UniScalar::Uuid
+                    | UniScalar::Json
             )
         }
         UniDatType::Tuple(_) => false,
@@ -235,6 +239,10 @@ mod tests {
             csharp_default_value_expr(&UniDatType::Scalar(UniScalar::String))?,
             "string.Empty"
         );
+        assert_eq!(
+            csharp_default_value_expr(&UniDatType::Scalar(UniScalar::Uuid))?,
+            "string.Empty"
+        );
         assert_eq!(
             csharp_default_value_expr(&UniDatType::Scalar(UniScalar::Blob))?,
             "[]"
@@ -32,6 +32,8 @@ impl_scalar! {
     (Time, "String"),
     (Timestamp, "String"),
     (TimestampTz, "String"),
+    (Uuid, "String"),
+    (Json, "String"),
 }
 
 impl_non_scalar! {
@@ -661,7 +661,7 @@ impl WitParser {
             ts_const::ts_kind_name::S_U16 => UniDatType::Scalar(UniScalar::U16),
             ts_const::ts_kind_name::S_U32 => UniDatType::Scalar(UniScalar::U32),
             ts_const::ts_kind_name::S_U64 => UniDatType::Scalar(UniScalar::U64),
-            ts_const::ts_kind_name::S_S8 => UniDatType::Scalar(UniScalar::U8),
+            ts_const::ts_kind_name::S_S8 => UniDatType::Scalar(UniScalar::I8),
             ts_const::ts_kind_name::S_S16 => UniDatType::Scalar(UniScalar::I16),
             ts_const::ts_kind_name::S_S32 => UniDatType::Scalar(UniScalar::I32),
             ts_const::ts_kind_name::S_S64 => UniDatType::Scalar(UniScalar::I64),
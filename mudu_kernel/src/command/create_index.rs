@@ -0,0 +1,92 @@
+use crate::contract::cmd_exec::CmdExec;
+use crate::contract::meta_mgr::MetaMgr;
+use crate::x_engine::api::{
+    OptInsert, OptRead, Predicate, RangeData, VecDatum, VecSelTerm, XContract,
+};
+use crate::x_engine::x_param::PCreateIndex;
+use async_trait::async_trait;
+use mudu::common::result::RS;
+use mudu::error::ErrorCode as ER;
+use mudu::mudu_error;
+use std::ops::Bound;
+use std::sync::Arc;
+
+/// Creates the table of a secondary index and fills it from the rows the
+/// base table already has. Later writes to the base table keep it in sync.
+pub struct CreateIndex {
+    param: PCreateIndex,
+    x_contract: Arc<dyn XContract>,
+    meta_mgr: Arc<dyn MetaMgr>,
+}
+
+impl CreateIndex {
+    pub fn new(
+        param: PCreateIndex,
+        x_contract: Arc<dyn XContract>,
+        meta_mgr: Arc<dyn MetaMgr>,
+    ) -> Self {
+        Self {
+            param,
+            x_contract,
+            meta_mgr,
+        }
+    }
+}
+
+#[async_trait]
+impl CmdExec for CreateIndex {
+    async fn prepare(&self) -> RS<()> {
+        let index_name = self.param.schema.table_name();
+        if self.meta_mgr.get_table_by_name(index_name).await?.is_some() {
+            return Err(mudu_error!(
+                ER::EntityAlreadyExists,
+                format!("table {} already exists", index_name)
+            ));
+        }
+        Ok(())
+    }
+
+    async fn run(&self) -> RS<()> {
+        mudu_utils::scoped_task_trace!();
+        let schema = &self.param.schema;
+        let index_on = schema
+            .index_on()
+            .ok_or_else(|| mudu_error!(ER::InvalidState, "index schema has no base table"))?;
+        let tx_mgr = self.param.tx_mgr.clone();
+        let base_desc = self
+            .meta_mgr
+            .get_table_by_id(index_on.base_table_id)
+            .await?;
+        self.x_contract.create_table(tx_mgr.clone(), schema).await?;
+        let cursor = self
+            .x_contract
+            .read_range(
+                tx_mgr.clone(),
+                index_on.base_table_id,
+                &RangeData::new(Bound::Unbounded, Bound::Unbounded),
+                &Predicate::CNF(Vec::new()),
+                &VecSelTerm::new(index_on.source_attrs.clone()),
+                &OptRead::default(),
+            )
+            .await?;
+        while let Some(row) = cursor.next().await? {
+            let Some(key) = index_on.index_key(&base_desc, row.into_fields())? else {
+                continue;
+            };
+            self.x_contract
+                .insert(
+                    tx_mgr.clone(),
+                    schema.id(),
+                    &VecDatum::new(key),
+                    &VecDatum::new(Vec::new()),
+                    &OptInsert::default(),
+                )
+                .await?;
+        }
+        Ok(())
+    }
+
+    async fn affected_rows(&self) -> RS<u64> {
+        Ok(0)
+    }
+}
@@ -38,6 +38,11 @@ impl CmdExec for DropTable {
     async fn run(&self) -> RS<()> {
         mudu_utils::scoped_task_trace!();
         if let Some(table_id) = self.drop_param.oid {
+            for index in self.meta_mgr.list_table_indexes(table_id).await? {
+                self.x_contract
+                    .drop_table(self.drop_param.tx_mgr.clone(), index.id())
+                    .await?;
+            }
             self.x_contract
                 .drop_table(self.drop_param.tx_mgr.clone(), table_id)
                 .await?;
@@ -1,3 +1,4 @@
+pub mod create_index;
 pub mod create_partition_placement;
 #[cfg(test)]
 pub mod create_partition_placement_test;
@@ -23,6 +23,7 @@ pub fn install_compatibility_router() {
         FormatKind::LogFrame,
         FormatKind::ProtocolFrame,
         FormatKind::TupleBinary,
+        FormatKind::BTreeIndex,
     ];
 
     for component in components {
@@ -30,8 +31,8 @@ pub fn install_compatibility_router() {
         router.set_supported_window(component, 1, current);
     }
 
-    router.register(FormatKind::Page, crate::storage::page::migrate::identity());
-    router.register(FormatKind::LogFrame, crate::wal::migrate::identity());
+    router.register(FormatKind::Page, crate::storage::page::migrate::v1_to_v2());
+    router.register(FormatKind::LogFrame, crate::wal::migrate::v1_to_v2());
     router.register(
         FormatKind::ProtocolFrame,
         mudu_contract::protocol::migrate::identity(),
@@ -40,6 +41,10 @@ pub fn install_compatibility_router() {
         FormatKind::TupleBinary,
         mudu_contract::tuple::migrate::identity(),
     );
+    router.register(
+        FormatKind::BTreeIndex,
+        crate::index::disk_btree::migrate::identity(),
+    );
 
     // Installation can only fail if something else already installed a router.
     // We checked above, so the result can be ignored.
@@ -0,0 +1,63 @@
+use crate::contract::json_access::JsonAccess;
+use mudu::common::result::RS;
+use mudu::error::ErrorCode as ER;
+use mudu::mudu_error;
+use mudu_type::array::array_ops::array_element;
+use mudu_type::dat_type::DatType;
+use mudu_type::dat_value::DatValue;
+use mudu_type::datum::DatumDyn;
+
+/// How a query reads a column other than as a whole: through a JSON path,
+/// or as one element of an array.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum ColumnAccess {
+    Json(JsonAccess),
+    /// `column[index]`, 1-based.
+    Element(i64),
+}
+
+impl ColumnAccess {
+    /// The type of the value read from a column of type `column_type`.
+    pub fn result_type(&self, column_type: &DatType) -> RS<DatType> {
+        match self {
+            ColumnAccess::Json(json_path) => Ok(json_path.result_type()),
+            ColumnAccess::Element(_) => element_type(column_type).cloned(),
+        }
+    }
+
+    /// Reads the value from `value`, in the binary form of `column_type`.
+    /// A missing key, index or element is `None`.
+    pub fn eval(&self, column_type: &DatType, value: &[u8]) -> RS<Option<Vec<u8>>> {
+        match self {
+            ColumnAccess::Json(json_path) => json_path.eval(column_type, value),
+            ColumnAccess::Element(index) => {
+                let elem_type = element_type(column_type)?;
+                let array = decode(column_type, value)?;
+                match array_element(&array, *index) {
+                    Some(element) => Ok(Some(element.to_binary(elem_type)?.into())),
+                    None => Ok(None),
+                }
+            }
+        }
+    }
+}
+
+/// The element type of the array type `array_type`.
+pub fn element_type(array_type: &DatType) -> RS<&DatType> {
+    array_type
+        .as_array_param()
+        .map(|param| param.dat_type())
+        .ok_or_else(|| {
+            mudu_error!(
+                ER::InvalidType,
+                format!("{} is not an array", array_type.name())
+            )
+        })
+}
+
+/// Decodes a value from its binary form.
+pub fn decode(dat_type: &DatType, binary: &[u8]) -> RS<DatValue> {
+    let (value, _) =
+        dat_type.dat_type_id().fn_recv()(binary, dat_type).map_err(|e| e.to_m_err())?;
+    Ok(value)
+}
@@ -0,0 +1,60 @@
+#![allow(clippy::unwrap_used)]
+
+use crate::contract::column_access::ColumnAccess;
+use crate::contract::json_access::JsonAccess;
+use mudu::error::ErrorCode;
+use mudu::json_value;
+use mudu_type::array::new_array_type;
+use mudu_type::dat_type::DatType;
+use mudu_type::dat_type_id::DatTypeID;
+use mudu_type::dat_value::DatValue;
+use mudu_type::datum::DatumDyn;
+use mudu_type::json_path::JsonPath;
+
+#[test]
+fn element_reads_one_based_array_elements() {
+    let int_type = DatType::new_no_param(DatTypeID::I32);
+    let array_type = new_array_type(int_type.clone());
+    let array: Vec<u8> = DatValue::from_array(vec![DatValue::from_i32(10), DatValue::from_i32(20)])
+        .to_binary(&array_type)
+        .unwrap()
+        .into();
+
+    let second = ColumnAccess::Element(2);
+    assert_eq!(
+        second.result_type(&array_type).unwrap().dat_type_id(),
+        DatTypeID::I32
+    );
+    let value = second.eval(&array_type, &array).unwrap().unwrap();
+    let expected: Vec<u8> = DatValue::from_i32(20).to_binary(&int_type).unwrap().into();
+    assert_eq!(value, expected);
+    for missing in [0, 3, -1] {
+        assert!(ColumnAccess::Element(missing)
+            .eval(&array_type, &array)
+            .unwrap()
+            .is_none());
+    }
+
+    let err = second.result_type(&int_type).unwrap_err();
+    assert_eq!(err.ec(), ErrorCode::InvalidType);
+}
+
+#[test]
+fn json_reads_through_the_path() {
+    let json_type = DatType::default_for(DatTypeID::Json);
+    let doc: Vec<u8> = DatValue::from_json(json_value!({"a": "x"}))
+        .to_binary(&json_type)
+        .unwrap()
+        .into();
+    let access = ColumnAccess::Json(JsonAccess::new(JsonPath::parse("$.a").unwrap(), true));
+    assert_eq!(
+        access.result_type(&json_type).unwrap().dat_type_id(),
+        DatTypeID::String
+    );
+    let value = access.eval(&json_type, &doc).unwrap().unwrap();
+    let expected: Vec<u8> = DatValue::from_string("x".to_string())
+        .to_binary(&DatType::default_for(DatTypeID::String))
+        .unwrap()
+        .into();
+    assert_eq!(value, expected);
+}
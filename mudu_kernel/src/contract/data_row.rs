@@ -10,6 +10,14 @@ use std::sync::Arc;
 
 const UNCOMPRESSED_VERSION_COUNT: usize = 4;
 
+/// Commit xid and tombstone flag of one row version, as listed by
+/// [`DataRow::versions_sync`] or dropped by [`DataRow::collect_garbage_sync`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct VersionMeta {
+    pub xid: u64,
+    pub deleted: bool,
+}
+
 #[derive(Clone)]
 pub struct DataRow {
     inner: Arc<SMutex<DataRowInner>>,
@@ -104,6 +112,54 @@ impl DataRowInner {
 
         Ok(None)
     }
+
+    fn version_count(&self) -> usize {
+        if self.tuple.is_empty() {
+            0
+        } else {
+            self.delta.len() + 1
+        }
+    }
+
+    /// Returns the xid and deleted flag of the version at logical position
+    /// `index`, counting from the oldest retained version.
+    fn version_meta(&self, index: usize) -> VersionMeta {
+        let first_full = self.version_count() - self.tuple.len();
+        let (timestamp, deleted) = if index < first_full {
+            let delta = &self.delta[index];
+            (delta.timestamp(), delta.is_deleted())
+        } else {
+            let tuple = &self.tuple[index - first_full];
+            (tuple.timestamp(), tuple.is_deleted())
+        };
+        VersionMeta {
+            xid: timestamp.c_min(),
+            deleted,
+        }
+    }
+
+    fn collect_garbage(&mut self, horizon: u64) -> Vec<VersionMeta> {
+        let count = self.version_count();
+        // The newest version below the horizon is what every current and
+        // future snapshot sees at the latest; everything older is unreachable.
+        let Some(floor) = (0..count)
+            .rev()
+            .find(|index| self.version_meta(*index).xid < horizon)
+        else {
+            return vec![];
+        };
+        // A tombstone at the floor reads the same as no version at all.
+        let end = if self.version_meta(floor).deleted {
+            floor + 1
+        } else {
+            floor
+        };
+        let pruned: Vec<_> = (0..end).map(|index| self.version_meta(index)).collect();
+        let first_full = count - self.tuple.len();
+        self.delta.drain(..end.min(self.delta.len()));
+        self.tuple.drain(..end.saturating_sub(first_full));
+        pruned
+    }
 }
 
 impl DataRow {
@@ -151,6 +207,30 @@ impl DataRow {
         let mut guard = self.inner.lock()?;
         guard.write_version(version, prev_version)
     }
+
+    /// Drops the versions no snapshot can reach any more.
+    ///
+    /// `horizon` must not exceed the xid of the oldest snapshot still in use:
+    /// every version below it is treated as visible to all readers. A row whose
+    /// surviving history is only a tombstone is left empty, see
+    /// [`Self::is_empty_sync`].
+    pub fn collect_garbage_sync(&self, horizon: u64) -> RS<Vec<VersionMeta>> {
+        let mut guard = self.inner.lock()?;
+        Ok(guard.collect_garbage(horizon))
+    }
+
+    /// Lists the versions of the row from oldest to newest.
+    pub fn versions_sync(&self) -> RS<Vec<VersionMeta>> {
+        let guard = self.inner.lock()?;
+        Ok((0..guard.version_count())
+            .map(|index| guard.version_meta(index))
+            .collect())
+    }
+
+    pub fn is_empty_sync(&self) -> RS<bool> {
+        let guard = self.inner.lock()?;
+        Ok(guard.tuple.is_empty())
+    }
 }
 
 unsafe impl Send for DataRow {}
@@ -166,6 +246,7 @@ fn build_version_delta(newer: &VersionTuple, older: &VersionTuple) -> VersionDel
             older.tuple().clone(),
         )],
     )
+    .with_expires_at(older.expires_at())
 }
 
 fn apply_version_delta(version: &mut VersionTuple, delta: &VersionDelta) {
@@ -176,7 +257,7 @@ fn apply_version_delta(version: &mut VersionTuple, delta: &VersionDelta) {
     *version = if delta.is_deleted() {
         VersionTuple::new_delete(delta.timestamp().clone())
     } else {
-        VersionTuple::new(delta.timestamp().clone(), tuple)
+        VersionTuple::new(delta.timestamp().clone(), tuple).with_expires_at(delta.expires_at())
     };
 }
 
@@ -227,4 +308,80 @@ mod tests {
         assert_eq!(visible.tuple(), &vec![2]);
         assert_eq!(visible.timestamp().c_min(), 2);
     }
+
+    #[test]
+    fn delta_chain_restores_expiry_header() {
+        let row = DataRow::new(1);
+        for xid in 1..=6 {
+            let expires_at = (xid % 2 == 0).then_some(xid * 100);
+            row.write_sync(version(xid, &[xid as u8]).with_expires_at(expires_at), None)
+                .unwrap();
+        }
+
+        assert_eq!(
+            row.read_sync(&snapshot(1)).unwrap().unwrap().expires_at(),
+            None
+        );
+        let visible = row.read_sync(&snapshot(2)).unwrap().unwrap();
+        assert_eq!(visible.expires_at(), Some(200));
+        assert!(visible.is_expired(200));
+        assert!(!visible.is_expired(199));
+    }
+
+    #[test]
+    fn gc_keeps_newest_version_below_horizon() {
+        let row = DataRow::new(1);
+        for xid in 1..=6 {
+            row.write_sync(version(xid, &[xid as u8]), None).unwrap();
+        }
+
+        let pruned = row.collect_garbage_sync(3).unwrap();
+        assert_eq!(pruned.iter().map(|v| v.xid).collect::<Vec<_>>(), vec![1]);
+        assert_eq!(
+            row.read_sync(&snapshot(2)).unwrap().unwrap().tuple(),
+            &vec![2]
+        );
+        assert_eq!(
+            row.read_sync(&snapshot(5)).unwrap().unwrap().tuple(),
+            &vec![5]
+        );
+
+        let pruned = row.collect_garbage_sync(7).unwrap();
+        assert_eq!(
+            pruned.iter().map(|v| v.xid).collect::<Vec<_>>(),
+            vec![2, 3, 4, 5]
+        );
+        let guard = row.inner.lock().unwrap();
+        assert_eq!(guard.tuple.len(), 1);
+        assert!(guard.delta.is_empty());
+        assert_eq!(guard.tuple[0].tuple(), &vec![6]);
+    }
+
+    #[test]
+    fn gc_drops_tombstone_with_its_history() {
+        let row = DataRow::new(1);
+        row.write_sync(version(1, &[1]), None).unwrap();
+        row.write_sync(VersionTuple::new_delete(Timestamp::new(2, u64::MAX)), None)
+            .unwrap();
+
+        assert!(row.collect_garbage_sync(2).unwrap().is_empty());
+        assert!(!row.is_empty_sync().unwrap());
+
+        let pruned = row.collect_garbage_sync(3).unwrap();
+        assert_eq!(
+            pruned,
+            vec![
+                VersionMeta {
+                    xid: 1,
+                    deleted: false
+                },
+                VersionMeta {
+                    xid: 2,
+                    deleted: true
+                },
+            ]
+        );
+        assert!(row.is_empty_sync().unwrap());
+        assert!(row.read_sync(&snapshot(3)).unwrap().is_none());
+    }
 }
@@ -0,0 +1,132 @@
+use mudu::common::result::RS;
+use mudu::error::ErrorCode as ER;
+use mudu::mudu_error;
+use mudu::utils::json::JsonValue;
+use mudu_type::dat_type::DatType;
+use mudu_type::dat_type_id::DatTypeID;
+use mudu_type::dat_value::DatValue;
+use mudu_type::datum::DatumDyn;
+use mudu_type::json_path::{json_to_text, JsonPath};
+use serde::{Deserialize, Serialize};
+
+/// A JSON path into a `json` column, as written with `->`, `->>` or
+/// `json_extract`. The result is JSON, or text when the last step is `->>`.
+#[derive(Clone, Debug, Serialize, Deserialize, PartialEq, Eq)]
+pub struct JsonAccess {
+    path: JsonPath,
+    as_text: bool,
+}
+
+impl JsonAccess {
+    pub fn new(path: JsonPath, as_text: bool) -> Self {
+        Self { path, as_text }
+    }
+
+    pub fn path(&self) -> &JsonPath {
+        &self.path
+    }
+
+    pub fn as_text(&self) -> bool {
+        self.as_text
+    }
+
+    /// The type of the extracted value: `json`, or `string` for `->>`.
+    pub fn result_type(&self) -> DatType {
+        if self.as_text {
+            DatType::default_for(DatTypeID::String)
+        } else {
+            DatType::default_for(DatTypeID::Json)
+        }
+    }
+
+    /// Follows the path through the document `doc`, in the binary form of
+    /// `doc_type`. A missing key or index is `None`, as is a JSON `null`
+    /// read as text.
+    pub fn extract(&self, doc_type: &DatType, doc: &[u8]) -> RS<Option<JsonValue>> {
+        let doc = decode_json(doc_type, doc)?;
+        Ok(self
+            .path
+            .extract(&doc)
+            .filter(|value| !(self.as_text && value.is_null()))
+            .cloned())
+    }
+
+    /// [`JsonAccess::extract`], encoded in the binary form of
+    /// [`JsonAccess::result_type`].
+    pub fn eval(&self, doc_type: &DatType, doc: &[u8]) -> RS<Option<Vec<u8>>> {
+        let Some(value) = self.extract(doc_type, doc)? else {
+            return Ok(None);
+        };
+        let value = if self.as_text {
+            DatValue::from_string(json_to_text(&value).unwrap_or_default())
+        } else {
+            DatValue::from_json(value)
+        };
+        encode(&value, &self.result_type()).map(Some)
+    }
+
+    /// The `string` an index on this path stores for the document `doc`:
+    /// the text for `->>` and the JSON text with sorted object keys
+    /// otherwise, so that equal JSON values have equal keys.
+    pub fn index_key(&self, doc_type: &DatType, doc: &[u8]) -> RS<Option<Vec<u8>>> {
+        match self.extract(doc_type, doc)? {
+            Some(value) => self.index_key_of(&value).map(Some),
+            None => Ok(None),
+        }
+    }
+
+    /// The index key of an operand compared with this path, in the binary
+    /// form of [`JsonAccess::result_type`].
+    pub fn index_key_of_operand(&self, operand: &[u8]) -> RS<Vec<u8>> {
+        if self.as_text {
+            return Ok(operand.to_vec());
+        }
+        self.index_key_of(&decode_json(&self.result_type(), operand)?)
+    }
+
+    fn index_key_of(&self, value: &JsonValue) -> RS<Vec<u8>> {
+        let text = if self.as_text {
+            json_to_text(value).unwrap_or_default()
+        } else {
+            canonical(value).to_string()
+        };
+        encode(
+            &DatValue::from_string(text),
+            &DatType::default_for(DatTypeID::String),
+        )
+    }
+}
+
+/// Decodes a `json` value from its binary form.
+pub fn decode_json(dat_type: &DatType, binary: &[u8]) -> RS<JsonValue> {
+    if dat_type.dat_type_id() != DatTypeID::Json {
+        return Err(mudu_error!(
+            ER::InvalidType,
+            format!("{} is not json", dat_type.name())
+        ));
+    }
+    let (value, _) = DatTypeID::Json.fn_recv()(binary, dat_type).map_err(|e| e.to_m_err())?;
+    Ok(value.expect_json().clone())
+}
+
+/// `value` with the keys of every object in sorted order.
+fn canonical(value: &JsonValue) -> JsonValue {
+    match value {
+        JsonValue::Object(object) => {
+            let mut entries = object.iter().collect::<Vec<_>>();
+            entries.sort_by_key(|(key, _)| *key);
+            JsonValue::Object(
+                entries
+                    .into_iter()
+                    .map(|(key, value)| (key.clone(), canonical(value)))
+                    .collect(),
+            )
+        }
+        JsonValue::Array(array) => JsonValue::Array(array.iter().map(canonical).collect()),
+        value => value.clone(),
+    }
+}
+
+fn encode(value: &DatValue, dat_type: &DatType) -> RS<Vec<u8>> {
+    Ok(value.to_binary(dat_type)?.into())
+}
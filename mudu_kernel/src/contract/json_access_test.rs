@@ -0,0 +1,79 @@
+#![allow(clippy::unwrap_used)]
+
+use crate::contract::json_access::JsonAccess;
+use mudu::json_value;
+use mudu::utils::json::JsonValue;
+use mudu_type::dat_type::DatType;
+use mudu_type::dat_type_id::DatTypeID;
+use mudu_type::dat_value::DatValue;
+use mudu_type::datum::DatumDyn;
+use mudu_type::json_path::JsonPath;
+
+fn json_binary(value: JsonValue) -> Vec<u8> {
+    DatValue::from_json(value)
+        .to_binary(&DatType::default_for(DatTypeID::Json))
+        .unwrap()
+        .into()
+}
+
+fn decode(dat_type: &DatType, binary: &[u8]) -> DatValue {
+    dat_type.dat_type_id().fn_recv()(binary, dat_type)
+        .unwrap()
+        .0
+}
+
+#[test]
+fn eval_extracts_json_or_text() {
+    let json_type = DatType::default_for(DatTypeID::Json);
+    let doc = json_binary(json_value!({"name": "bob", "tags": ["a", {"b": null}]}));
+
+    let name = JsonAccess::new(JsonPath::parse("$.name").unwrap(), false);
+    let value = name.eval(&json_type, &doc).unwrap().unwrap();
+    assert_eq!(
+        decode(&name.result_type(), &value).expect_json(),
+        &json_value!("bob")
+    );
+
+    let name = JsonAccess::new(JsonPath::parse("$.name").unwrap(), true);
+    let value = name.eval(&json_type, &doc).unwrap().unwrap();
+    assert_eq!(decode(&name.result_type(), &value).expect_string(), "bob");
+
+    let tag = JsonAccess::new(JsonPath::parse("$.tags[-1]").unwrap(), true);
+    let value = tag.eval(&json_type, &doc).unwrap().unwrap();
+    assert_eq!(
+        decode(&tag.result_type(), &value).expect_string(),
+        "{\"b\":null}"
+    );
+
+    // Missing keys and JSON null read as text are NULL.
+    let missing = JsonAccess::new(JsonPath::parse("$.age").unwrap(), false);
+    assert!(missing.eval(&json_type, &doc).unwrap().is_none());
+    let null = JsonAccess::new(JsonPath::parse("$.tags[1].b").unwrap(), true);
+    assert!(null.eval(&json_type, &doc).unwrap().is_none());
+    let null = JsonAccess::new(JsonPath::parse("$.tags[1].b").unwrap(), false);
+    assert!(null.eval(&json_type, &doc).unwrap().is_some());
+
+    let err = name
+        .eval(&DatType::default_for(DatTypeID::String), &doc)
+        .unwrap_err();
+    assert_eq!(err.ec(), mudu::error::ErrorCode::InvalidType);
+}
+
+#[test]
+fn index_keys_of_documents_and_operands_agree() {
+    let json_type = DatType::default_for(DatTypeID::Json);
+    let doc = json_binary(json_value!({"n": {"b": 2, "a": 1}, "s": "x"}));
+
+    let n = JsonAccess::new(JsonPath::parse("$.n").unwrap(), false);
+    let key = n.index_key(&json_type, &doc).unwrap().unwrap();
+    let operand = json_binary(json_value!({"a": 1, "b": 2}));
+    assert_eq!(n.index_key_of_operand(&operand).unwrap(), key);
+
+    let s = JsonAccess::new(JsonPath::parse("$.s").unwrap(), true);
+    let key = s.index_key(&json_type, &doc).unwrap().unwrap();
+    let operand: Vec<u8> = DatValue::from_string("x".to_string())
+        .to_binary(&s.result_type())
+        .unwrap()
+        .into();
+    assert_eq!(s.index_key_of_operand(&operand).unwrap(), key);
+}
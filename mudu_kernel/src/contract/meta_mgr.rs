@@ -1,12 +1,14 @@
 use async_trait::async_trait;
 use mudu::common::id::OID;
 use mudu::error::ErrorCode;
+use std::path::Path;
 use std::sync::Arc;
 
 use crate::contract::partition_rule::PartitionRuleDesc;
 use crate::contract::partition_rule_binding::{PartitionPlacement, TablePartitionBinding};
 use crate::contract::schema_table::SchemaTable;
 use crate::contract::table_desc::TableDesc;
+use crate::storage::backup::BackupFile;
 use mudu::common::result::RS;
 
 #[async_trait]
@@ -74,4 +76,27 @@ pub trait MetaMgr: Send + Sync {
     async fn list_schemas(&self) -> RS<Vec<SchemaTable>> {
         Ok(Vec::new())
     }
+
+    /// Index tables whose `index_on` names `table_id` as their base table.
+    async fn list_table_indexes(&self, table_id: OID) -> RS<Vec<SchemaTable>> {
+        Ok(self
+            .list_schemas()
+            .await?
+            .into_iter()
+            .filter(|schema| {
+                schema
+                    .index_on()
+                    .is_some_and(|index| index.base_table_id == table_id)
+            })
+            .collect())
+    }
+
+    /// Copies the catalog relations into `backup_dir`, at their paths
+    /// relative to the data directory, while no DDL runs.
+    async fn backup_catalog(&self, _backup_dir: &Path) -> RS<Vec<BackupFile>> {
+        Err(mudu::mudu_error!(
+            ErrorCode::NotImplemented,
+            "backup catalog is not implemented"
+        ))
+    }
 }
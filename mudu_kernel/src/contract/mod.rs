@@ -22,14 +22,21 @@ pub mod partition_rule_binding_test;
 pub mod partition_rule_test;
 
 pub mod cmd_exec;
+pub mod column_access;
+#[cfg(test)]
+pub mod column_access_test;
 pub mod data_row;
 mod field_info;
 #[cfg(test)]
 pub mod field_info_test;
+pub mod json_access;
+#[cfg(test)]
+pub mod json_access_test;
 pub mod query_exec;
 pub mod schema_column;
 #[cfg(test)]
 pub mod schema_column_test;
+pub mod schema_index;
 pub mod schema_table;
 #[cfg(test)]
 pub mod schema_table_test;
@@ -0,0 +1,58 @@
+use crate::contract::json_access::JsonAccess;
+use crate::contract::table_desc::TableDesc;
+use mudu::common::buf::Buf;
+use mudu::common::id::{AttrIndex, OID};
+use mudu::common::result::RS;
+use serde::{Deserialize, Serialize};
+
+/// Marks a table as a secondary index of `base_table_id`.
+///
+/// An index table has key columns only: the indexed columns followed by the
+/// base primary key columns that are not indexed, so every base row has one
+/// index row. `source_attrs[i]` is the base table attribute copied into index
+/// column `i`; the first `indexed_count` of them are the indexed columns.
+/// `json_paths[i]`, when present, is the JSON path index column `i` extracts
+/// from its source attribute instead of copying it.
+#[derive(Clone, Debug, Serialize, Deserialize, PartialEq, Eq)]
+pub struct SchemaIndex {
+    pub base_table_id: OID,
+    pub source_attrs: Vec<AttrIndex>,
+    pub indexed_count: usize,
+    #[serde(default)]
+    pub json_paths: Vec<Option<JsonAccess>>,
+}
+
+impl SchemaIndex {
+    /// The JSON path of index column `column`, if it has one.
+    pub fn json_path(&self, column: usize) -> Option<&JsonAccess> {
+        self.json_paths.get(column).and_then(Option::as_ref)
+    }
+
+    /// The index key of a base row, from the base table fields `fields` of
+    /// `source_attrs`. Returns `None` for rows that are not indexed: those
+    /// with a NULL indexed column or a JSON path that does not exist.
+    pub fn index_key(
+        &self,
+        base_desc: &TableDesc,
+        fields: Vec<Option<Buf>>,
+    ) -> RS<Option<Vec<(AttrIndex, Buf)>>> {
+        let mut key = Vec::with_capacity(fields.len());
+        for (column, field) in fields.into_iter().enumerate() {
+            let Some(field) = field else {
+                return Ok(None);
+            };
+            let field = match self.json_path(column) {
+                Some(json_path) => {
+                    let doc_type = base_desc.get_attr(self.source_attrs[column]).type_desc();
+                    match json_path.index_key(doc_type, &field)? {
+                        Some(field) => field,
+                        None => return Ok(None),
+                    }
+                }
+                None => field,
+            };
+            key.push((column, field));
+        }
+        Ok(Some(key))
+    }
+}
@@ -1,5 +1,7 @@
 use crate::contract::field_info::FieldInfo;
 use crate::contract::schema_column::SchemaColumn;
+use crate::contract::schema_index::SchemaIndex;
+use crate::storage::compression::Compression;
 #[cfg(any(test, feature = "test", fuzzing))]
 use arbitrary::{Arbitrary, Unstructured};
 use mudu::common::id::{AttrIndex, DatumIndex, OID};
@@ -17,6 +19,12 @@ pub struct SchemaTable {
     columns: Vec<SchemaColumn>,
     key_indices: Vec<AttrIndex>,
     value_indices: Vec<AttrIndex>,
+    // Page codec from `WITH (compression = ...)`; `None` follows the server default.
+    #[serde(default)]
+    compression: Option<Compression>,
+    // Set on the hidden table that stores a secondary index.
+    #[serde(default)]
+    index_on: Option<SchemaIndex>,
 }
 
 // Build a tuple descriptor from a key/value column slice.
@@ -132,6 +140,8 @@ impl SchemaTable {
             columns,
             key_indices,
             value_indices,
+            compression: None,
+            index_on: None,
         };
         for (i, index) in s.key_indices.iter().copied().enumerate() {
             let sc = &mut s.columns[index];
@@ -146,10 +156,28 @@ impl SchemaTable {
         s
     }
 
+    pub fn with_compression(mut self, compression: Compression) -> Self {
+        self.compression = Some(compression);
+        self
+    }
+
+    pub fn with_index_on(mut self, index_on: SchemaIndex) -> Self {
+        self.index_on = Some(index_on);
+        self
+    }
+
     pub fn id(&self) -> OID {
         self.oid
     }
 
+    pub fn compression(&self) -> Option<Compression> {
+        self.compression
+    }
+
+    pub fn index_on(&self) -> Option<&SchemaIndex> {
+        self.index_on.as_ref()
+    }
+
     pub fn table_name(&self) -> &String {
         &self.table_name
     }
@@ -1,9 +1,13 @@
 #![allow(clippy::unwrap_used)]
 
+use crate::contract::json_access::JsonAccess;
 use crate::contract::schema_column::SchemaColumn;
+use crate::contract::schema_index::SchemaIndex;
 use crate::contract::schema_table::{schema_columns_to_tuple_desc, SchemaTable};
+use crate::storage::compression::Compression;
 use mudu_type::dat_type::DatType;
 use mudu_type::dat_type_id::DatTypeID;
+use mudu_type::json_path::JsonPath;
 
 fn make_col(name: &str, ty: DatTypeID) -> SchemaColumn {
     SchemaColumn::new(name.to_string(), ty, DatType::new_no_param(ty).to_info())
@@ -105,3 +109,66 @@ fn serde_roundtrip_preserves_schema() {
     assert_eq!(decoded.columns().len(), schema.columns().len());
     assert_eq!(decoded.id(), schema.id());
 }
+
+#[test]
+fn compression_option_round_trips_and_defaults_to_none() {
+    let schema = SchemaTable::new(
+        "t6".to_string(),
+        vec![make_col("k", DatTypeID::I32), make_col("v", DatTypeID::F64)],
+        vec![0],
+        vec![1],
+    );
+    assert_eq!(schema.compression(), None);
+    // Schemas stored before the option existed have no `compression` key.
+    // The JSON is edited as text: a `serde_json::Value` cannot hold u128 ids.
+    let legacy = serde_json::to_string(&schema)
+        .unwrap()
+        .replace(",\"compression\":null", "");
+    assert!(!legacy.contains("compression"));
+    let decoded: SchemaTable = serde_json::from_str(&legacy).unwrap();
+    assert_eq!(decoded.compression(), None);
+
+    let schema = schema.with_compression(Compression::Zstd);
+    let json = serde_json::to_string(&schema).unwrap();
+    let decoded: SchemaTable = serde_json::from_str(&json).unwrap();
+    assert_eq!(decoded.compression(), Some(Compression::Zstd));
+}
+
+#[test]
+fn index_on_round_trips_and_defaults_to_none() {
+    let schema = SchemaTable::new(
+        "t7".to_string(),
+        vec![make_col("v", DatTypeID::F64), make_col("k", DatTypeID::I32)],
+        vec![0, 1],
+        vec![],
+    );
+    assert!(schema.index_on().is_none());
+    let legacy = serde_json::to_string(&schema)
+        .unwrap()
+        .replace(",\"index_on\":null", "");
+    assert!(!legacy.contains("index_on"));
+    let decoded: SchemaTable = serde_json::from_str(&legacy).unwrap();
+    assert!(decoded.index_on().is_none());
+
+    let index_on = SchemaIndex {
+        base_table_id: 42,
+        source_attrs: vec![1, 0],
+        indexed_count: 1,
+        json_paths: vec![Some(JsonAccess::new(
+            JsonPath::parse("$.tags[0]").unwrap(),
+            true,
+        ))],
+    };
+    let schema = schema.with_index_on(index_on.clone());
+    let json = serde_json::to_string(&schema).unwrap();
+    let decoded: SchemaTable = serde_json::from_str(&json).unwrap();
+    assert_eq!(decoded.index_on(), Some(&index_on));
+    assert!(index_on.json_path(0).unwrap().as_text());
+    assert!(index_on.json_path(1).is_none());
+
+    // Indexes stored before JSON path indexes existed have no `json_paths`.
+    let legacy = json.replace(",\"json_paths\":[{\"path\":{\"steps\":[{\"Key\":\"tags\"},{\"Index\":0}]},\"as_text\":true}]", "");
+    assert!(!legacy.contains("json_paths"));
+    let decoded: SchemaTable = serde_json::from_str(&legacy).unwrap();
+    assert!(decoded.index_on().unwrap().json_paths.is_empty());
+}
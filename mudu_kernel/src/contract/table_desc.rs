@@ -1,6 +1,7 @@
 use mudu::common::id::{AttrIndex, OID};
 
 use crate::contract::field_info::FieldInfo;
+use crate::storage::compression::Compression;
 use mudu_contract::tuple::tuple_binary_desc::TupleBinaryDesc as TupleDesc;
 use std::collections::HashMap;
 
@@ -19,6 +20,7 @@ pub struct TableDesc {
     name2oid: HashMap<String, OID>,
     oid2col: HashMap<OID, FieldInfo>,
     column_oid: Vec<OID>,
+    compression: Option<Compression>,
 }
 
 pub struct TableDescParams {
@@ -33,6 +35,7 @@ pub struct TableDescParams {
     pub value_desc: TupleDesc,
     pub name2oid: HashMap<String, OID>,
     pub oid2col: HashMap<OID, FieldInfo>,
+    pub compression: Option<Compression>,
 }
 
 impl TableDesc {
@@ -51,6 +54,7 @@ impl TableDesc {
             oid2col: params.oid2col,
             name2oid: params.name2oid,
             column_oid,
+            compression: params.compression,
         }
     }
 
@@ -118,4 +122,9 @@ impl TableDesc {
     pub fn original_column_oid(&self) -> &Vec<OID> {
         &self.column_oid
     }
+
+    /// Page codec requested by the table, if it overrides the server default.
+    pub fn compression(&self) -> Option<Compression> {
+        self.compression
+    }
 }
@@ -49,6 +49,7 @@ impl TableInfo {
             value_desc: inner.value_tuple_desc.clone(),
             name2oid: inner.name2oid.clone(),
             oid2col: inner.oid2column.clone(),
+            compression: inner.schema_table.compression(),
         }));
         Ok(ret)
     }
@@ -7,9 +7,19 @@ impl VersionDelta {
             timestamp,
             deleted,
             update,
+            expires_at: None,
         }
     }
 
+    pub fn with_expires_at(mut self, expires_at: Option<u64>) -> Self {
+        self.expires_at = expires_at;
+        self
+    }
+
+    pub fn expires_at(&self) -> Option<u64> {
+        self.expires_at
+    }
+
     pub fn timestamp(&self) -> &Timestamp {
         &self.timestamp
     }
@@ -31,4 +41,5 @@ pub struct VersionDelta {
     timestamp: Timestamp,
     deleted: bool,
     update: Vec<UpdateDelta>,
+    expires_at: Option<u64>,
 }
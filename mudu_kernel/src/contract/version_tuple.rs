@@ -6,6 +6,9 @@ pub struct VersionTuple {
     timestamp: Timestamp,
     buf: Buf,
     deleted: bool,
+    // Row header of kv versions written with a TTL: the Unix millisecond
+    // deadline after which the version reads as absent.
+    expires_at: Option<u64>,
 }
 
 impl VersionTuple {
@@ -14,6 +17,7 @@ impl VersionTuple {
             timestamp,
             buf,
             deleted: false,
+            expires_at: None,
         }
     }
 
@@ -22,8 +26,15 @@ impl VersionTuple {
             timestamp,
             buf: Vec::new(),
             deleted: true,
+            expires_at: None,
         }
     }
+
+    pub fn with_expires_at(mut self, expires_at: Option<u64>) -> VersionTuple {
+        self.expires_at = expires_at;
+        self
+    }
+
     pub fn timestamp(&self) -> &Timestamp {
         &self.timestamp
     }
@@ -47,4 +58,14 @@ impl VersionTuple {
     pub fn is_deleted(&self) -> bool {
         self.deleted
     }
+
+    pub fn expires_at(&self) -> Option<u64> {
+        self.expires_at
+    }
+
+    /// Whether the version passed its deadline at `now_ms`.
+    pub fn is_expired(&self, now_ms: u64) -> bool {
+        self.expires_at
+            .is_some_and(|expires_at| expires_at <= now_ms)
+    }
 }
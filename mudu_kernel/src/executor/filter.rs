@@ -0,0 +1,116 @@
+use crate::contract::column_access::{decode, ColumnAccess};
+use crate::contract::json_access::decode_json;
+use crate::contract::query_exec::QueryExec;
+use crate::x_engine::api::TupleRow;
+use async_trait::async_trait;
+use mudu::common::result::RS;
+use mudu_contract::tuple::comparator::binary_field_compare;
+use mudu_contract::tuple::tuple_field_desc::TupleFieldDesc as TupleDesc;
+use mudu_type::array::array_ops::array_any_eq;
+use mudu_type::dat_type::DatType;
+use mudu_type::json_path::json_contains;
+use sql_parser::ast::expr_operator::ValueCompare;
+use std::sync::Arc;
+
+/// One predicate of a [`Filter`], over the input column at `position`.
+#[derive(Clone, Debug)]
+pub struct FilterPredicate {
+    pub position: usize,
+    pub column_type: DatType,
+    pub access: Option<ColumnAccess>,
+    pub op: ValueCompare,
+    pub operand: Vec<u8>,
+    pub operand_type: DatType,
+}
+
+/// Passes on the rows of its input for which every predicate holds. A
+/// predicate over a NULL value, or over a JSON path or array element that
+/// does not exist, does not hold.
+pub struct Filter {
+    input: Arc<dyn QueryExec>,
+    predicates: Vec<FilterPredicate>,
+}
+
+impl Filter {
+    pub fn new(input: Arc<dyn QueryExec>, predicates: Vec<FilterPredicate>) -> Self {
+        Self { input, predicates }
+    }
+}
+
+#[async_trait]
+impl QueryExec for Filter {
+    async fn open(&self) -> RS<()> {
+        self.input.open().await
+    }
+
+    async fn next(&self) -> RS<Option<TupleRow>> {
+        while let Some(row) = self.input.next().await? {
+            if self.matches(&row)? {
+                return Ok(Some(row));
+            }
+        }
+        Ok(None)
+    }
+
+    fn tuple_desc(&self) -> RS<TupleDesc> {
+        self.input.tuple_desc()
+    }
+}
+
+impl Filter {
+    fn matches(&self, row: &TupleRow) -> RS<bool> {
+        for predicate in &self.predicates {
+            if !predicate.matches(row)? {
+                return Ok(false);
+            }
+        }
+        Ok(true)
+    }
+}
+
+impl FilterPredicate {
+    fn matches(&self, row: &TupleRow) -> RS<bool> {
+        let Some(value) = row.fields().get(self.position).and_then(|v| v.as_deref()) else {
+            return Ok(false);
+        };
+        let (value, value_type) = match &self.access {
+            Some(access) => match access.eval(&self.column_type, value)? {
+                Some(value) => (value, access.result_type(&self.column_type)?),
+                None => return Ok(false),
+            },
+            None => (value.to_vec(), self.column_type.clone()),
+        };
+        let holds = match self.op {
+            ValueCompare::Contains | ValueCompare::ContainedBy => {
+                let value = decode_json(&self.operand_type, &value)?;
+                let operand = decode_json(&self.operand_type, &self.operand)?;
+                if matches!(self.op, ValueCompare::Contains) {
+                    json_contains(&value, &operand)
+                } else {
+                    json_contains(&operand, &value)
+                }
+            }
+            ValueCompare::AnyEq => {
+                let array = decode(&value_type, &value)?;
+                let operand = decode(&self.operand_type, &self.operand)?;
+                array_any_eq(&array, &self.operand_type, &operand)?
+            }
+            op => {
+                let ordering = binary_field_compare(&self.operand_type, &value, &self.operand)?;
+                match op {
+                    ValueCompare::EQ => ordering.is_eq(),
+                    ValueCompare::NE => ordering.is_ne(),
+                    ValueCompare::LT => ordering.is_lt(),
+                    ValueCompare::LE => ordering.is_le(),
+                    ValueCompare::GT => ordering.is_gt(),
+                    ValueCompare::GE => ordering.is_ge(),
+                    ValueCompare::Contains
+                    | ValueCompare::ContainedBy
+                    | ValueCompare::EqAny
+                    | ValueCompare::AnyEq => false,
+                }
+            }
+        };
+        Ok(holds)
+    }
+}
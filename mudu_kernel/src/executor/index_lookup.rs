@@ -0,0 +1,123 @@
+use crate::contract::meta_mgr::MetaMgr;
+use crate::contract::query_exec::QueryExec;
+use crate::executor::project_tuple_desc;
+use crate::x_engine::api::{
+    Predicate, RSCursor, RangeData, TupleRow, VecDatum, VecSelTerm, XContract,
+};
+use crate::x_engine::x_param::PIndexLookup;
+use async_trait::async_trait;
+use mudu::common::result::RS;
+use mudu::error::ErrorCode;
+use mudu::mudu_error;
+use mudu_contract::tuple::tuple_field_desc::TupleFieldDesc as TupleDesc;
+use mudu_sys::sync::async_::futures_mutex::FMutex;
+use std::ops::Bound;
+use std::sync::Arc;
+
+/// Reads the index rows matching a prefix of a secondary index, then the
+/// base table row of each through its primary key.
+pub struct IndexLookup {
+    tuple_desc: TupleDesc,
+    inner: FMutex<_IndexLookup>,
+}
+
+struct _IndexLookup {
+    param: PIndexLookup,
+    cursor: Option<Arc<dyn RSCursor>>,
+    x_contract: Arc<dyn XContract>,
+}
+
+impl IndexLookup {
+    pub async fn new(
+        param: PIndexLookup,
+        x_contract: Arc<dyn XContract>,
+        meta_mgr: Arc<dyn MetaMgr>,
+    ) -> RS<Self> {
+        let table_desc = meta_mgr.get_table_by_id(param.table_id).await?;
+        let tuple_desc = project_tuple_desc(&table_desc, &param.select);
+        Ok(Self {
+            tuple_desc,
+            inner: FMutex::new(_IndexLookup {
+                param,
+                cursor: None,
+                x_contract,
+            }),
+        })
+    }
+}
+
+#[async_trait]
+impl QueryExec for IndexLookup {
+    async fn open(&self) -> RS<()> {
+        let mut inner = self.inner.lock().await;
+        inner.open().await
+    }
+
+    async fn next(&self) -> RS<Option<TupleRow>> {
+        let mut inner = self.inner.lock().await;
+        inner.next().await
+    }
+
+    fn tuple_desc(&self) -> RS<TupleDesc> {
+        Ok(self.tuple_desc.clone())
+    }
+}
+
+impl _IndexLookup {
+    async fn open(&mut self) -> RS<()> {
+        let param = &self.param;
+        let select = param
+            .base_key
+            .iter()
+            .map(|(index_attr, _)| *index_attr)
+            .collect();
+        let cursor = self
+            .x_contract
+            .read_range(
+                param.tx_mgr.clone(),
+                param.index_id,
+                &RangeData::new(Bound::Unbounded, Bound::Unbounded),
+                &Predicate::KeyPrefixEq(param.prefix.clone()),
+                &VecSelTerm::new(select),
+                &param.opt_read,
+            )
+            .await?;
+        self.cursor = Some(cursor);
+        Ok(())
+    }
+
+    async fn next(&mut self) -> RS<Option<TupleRow>> {
+        let param = &self.param;
+        while let Some(cursor) = &self.cursor {
+            let Some(index_row) = cursor.next().await? else {
+                self.cursor = None;
+                break;
+            };
+            let key = index_row
+                .into_fields()
+                .into_iter()
+                .zip(param.base_key.iter())
+                .map(|(field, (_, base_attr))| field.map(|field| (*base_attr, field)))
+                .collect::<Option<Vec<_>>>()
+                .ok_or_else(|| mudu_error!(ErrorCode::InvalidTuple, "NULL key in index row"))?;
+            let row = self
+                .x_contract
+                .read_key(
+                    param.tx_mgr.clone(),
+                    param.table_id,
+                    &VecDatum::new(key),
+                    &param.select,
+                    &param.opt_read,
+                )
+                .await?;
+            if let Some(row) = row {
+                return Ok(Some(TupleRow::new_nullable(row)));
+            }
+        }
+        Ok(None)
+    }
+}
+
+unsafe impl Send for IndexLookup {}
+
+unsafe impl Sync for IndexLookup {}
@@ -3,8 +3,13 @@ use crate::x_engine::api::VecSelTerm;
 use mudu_contract::tuple::datum_desc::DatumDesc;
 use mudu_contract::tuple::tuple_field_desc::TupleFieldDesc;
 
+pub mod filter;
 pub mod index_access_key;
 pub mod index_access_range;
+pub mod index_lookup;
+pub mod project;
+pub mod sort;
+pub mod unnest;
 
 pub(crate) fn project_tuple_desc(table_desc: &TableDesc, select: &VecSelTerm) -> TupleFieldDesc {
     let fields = select
@@ -0,0 +1,65 @@
+use crate::contract::column_access::ColumnAccess;
+use crate::contract::query_exec::QueryExec;
+use crate::x_engine::api::TupleRow;
+use async_trait::async_trait;
+use mudu::common::result::RS;
+use mudu_contract::tuple::tuple_field_desc::TupleFieldDesc as TupleDesc;
+use mudu_type::dat_type::DatType;
+use std::sync::Arc;
+
+/// The JSON path or array element an output column of a [`Project`] reads
+/// from the input column of type `column_type` at the same position.
+#[derive(Clone, Debug)]
+pub struct ProjectPath {
+    pub column_type: DatType,
+    pub access: ColumnAccess,
+}
+
+/// Evaluates the JSON paths and array elements of the selected columns;
+/// columns without one are passed on as they are. Input columns past the output columns, which only
+/// carry filter or sort values, are dropped.
+pub struct Project {
+    input: Arc<dyn QueryExec>,
+    columns: Vec<Option<ProjectPath>>,
+    tuple_desc: TupleDesc,
+}
+
+impl Project {
+    pub fn new(
+        input: Arc<dyn QueryExec>,
+        columns: Vec<Option<ProjectPath>>,
+        tuple_desc: TupleDesc,
+    ) -> Self {
+        Self {
+            input,
+            columns,
+            tuple_desc,
+        }
+    }
+}
+
+#[async_trait]
+impl QueryExec for Project {
+    async fn open(&self) -> RS<()> {
+        self.input.open().await
+    }
+
+    async fn next(&self) -> RS<Option<TupleRow>> {
+        let Some(row) = self.input.next().await? else {
+            return Ok(None);
+        };
+        let mut fields = row.into_fields();
+        fields.truncate(self.columns.len());
+        for (field, column) in fields.iter_mut().zip(&self.columns) {
+            let (Some(path), Some(value)) = (column, field.as_deref()) else {
+                continue;
+            };
+            *field = path.access.eval(&path.column_type, value)?;
+        }
+        Ok(Some(TupleRow::new_nullable(fields)))
+    }
+
+    fn tuple_desc(&self) -> RS<TupleDesc> {
+        Ok(self.tuple_desc.clone())
+    }
+}
@@ -0,0 +1,127 @@
+use crate::contract::query_exec::QueryExec;
+use crate::x_engine::api::TupleRow;
+use async_trait::async_trait;
+use mudu::common::result::RS;
+use mudu_contract::tuple::comparator::binary_field_compare;
+use mudu_contract::tuple::tuple_field_desc::TupleFieldDesc as TupleDesc;
+use mudu_sys::sync::async_::futures_mutex::FMutex;
+use mudu_type::dat_type::DatType;
+use std::cmp::Ordering;
+use std::collections::VecDeque;
+use std::sync::Arc;
+
+/// One sort column of a [`Sort`], by its position in the input rows.
+#[derive(Clone, Debug)]
+pub struct SortKey {
+    pub position: usize,
+    pub dat_type: DatType,
+    pub descending: bool,
+    pub nulls_first: bool,
+}
+
+/// Sorts the rows of its input, comparing values through their type and
+/// collation. Input columns past `width`, which only carry sort keys, are
+/// dropped from the output.
+pub struct Sort {
+    tuple_desc: TupleDesc,
+    inner: FMutex<_Sort>,
+}
+
+struct _Sort {
+    input: Arc<dyn QueryExec>,
+    keys: Vec<SortKey>,
+    width: usize,
+    rows: VecDeque<TupleRow>,
+}
+
+impl Sort {
+    pub fn new(
+        input: Arc<dyn QueryExec>,
+        keys: Vec<SortKey>,
+        width: usize,
+        tuple_desc: TupleDesc,
+    ) -> Self {
+        Self {
+            tuple_desc,
+            inner: FMutex::new(_Sort {
+                input,
+                keys,
+                width,
+                rows: VecDeque::new(),
+            }),
+        }
+    }
+}
+
+#[async_trait]
+impl QueryExec for Sort {
+    async fn open(&self) -> RS<()> {
+        let mut inner = self.inner.lock().await;
+        inner.open().await
+    }
+
+    async fn next(&self) -> RS<Option<TupleRow>> {
+        let mut inner = self.inner.lock().await;
+        Ok(inner.rows.pop_front())
+    }
+
+    fn tuple_desc(&self) -> RS<TupleDesc> {
+        Ok(self.tuple_desc.clone())
+    }
+}
+
+impl _Sort {
+    async fn open(&mut self) -> RS<()> {
+        self.input.open().await?;
+        let mut rows = Vec::new();
+        while let Some(row) = self.input.next().await? {
+            rows.push(row);
+        }
+        let mut error = None;
+        rows.sort_by(|row1, row2| match compare_rows(&self.keys, row1, row2) {
+            Ok(ordering) => ordering,
+            Err(e) => {
+                error.get_or_insert(e);
+                Ordering::Equal
+            }
+        });
+        if let Some(e) = error {
+            return Err(e);
+        }
+        self.rows = rows
+            .into_iter()
+            .map(|row| {
+                let mut fields = row.into_fields();
+                fields.truncate(self.width);
+                TupleRow::new_nullable(fields)
+            })
+            .collect();
+        Ok(())
+    }
+}
+
+fn compare_rows(keys: &[SortKey], row1: &TupleRow, row2: &TupleRow) -> RS<Ordering> {
+    for key in keys {
+        let value1 = row1.fields().get(key.position).and_then(|v| v.as_deref());
+        let value2 = row2.fields().get(key.position).and_then(|v| v.as_deref());
+        let ordering = match (value1, value2) {
+            (None, None) => Ordering::Equal,
+            (None, Some(_)) if key.nulls_first => Ordering::Less,
+            (None, Some(_)) => Ordering::Greater,
+            (Some(_), None) if key.nulls_first => Ordering::Greater,
+            (Some(_), None) => Ordering::Less,
+            (Some(value1), Some(value2)) => {
+                let ordering = binary_field_compare(&key.dat_type, value1, value2)?;
+                if key.descending {
+                    ordering.reverse()
+                } else {
+                    ordering
+                }
+            }
+        };
+        if ordering.is_ne() {
+            return Ok(ordering);
+        }
+    }
+    Ok(Ordering::Equal)
+}
@@ -0,0 +1,87 @@
+use crate::contract::column_access::{decode, element_type};
+use crate::contract::query_exec::QueryExec;
+use crate::x_engine::api::TupleRow;
+use async_trait::async_trait;
+use mudu::common::result::RS;
+use mudu_contract::tuple::tuple_field_desc::TupleFieldDesc as TupleDesc;
+use mudu_sys::sync::async_::futures_mutex::FMutex;
+use mudu_type::array::array_ops::array_unnest;
+use mudu_type::dat_type::DatType;
+use mudu_type::datum::DatumDyn;
+use std::collections::VecDeque;
+use std::sync::Arc;
+
+/// Expands the array column at `position` of each input row into one row
+/// per element, in array order. Rows with a NULL or empty array produce no
+/// rows.
+pub struct Unnest {
+    tuple_desc: TupleDesc,
+    inner: FMutex<_Unnest>,
+}
+
+struct _Unnest {
+    input: Arc<dyn QueryExec>,
+    position: usize,
+    array_type: DatType,
+    rows: VecDeque<TupleRow>,
+}
+
+impl Unnest {
+    pub fn new(
+        input: Arc<dyn QueryExec>,
+        position: usize,
+        array_type: DatType,
+        tuple_desc: TupleDesc,
+    ) -> Self {
+        Self {
+            tuple_desc,
+            inner: FMutex::new(_Unnest {
+                input,
+                position,
+                array_type,
+                rows: VecDeque::new(),
+            }),
+        }
+    }
+}
+
+#[async_trait]
+impl QueryExec for Unnest {
+    async fn open(&self) -> RS<()> {
+        let inner = self.inner.lock().await;
+        inner.input.open().await
+    }
+
+    async fn next(&self) -> RS<Option<TupleRow>> {
+        let mut inner = self.inner.lock().await;
+        loop {
+            if let Some(row) = inner.rows.pop_front() {
+                return Ok(Some(row));
+            }
+            let Some(row) = inner.input.next().await? else {
+                return Ok(None);
+            };
+            inner.expand(row)?;
+        }
+    }
+
+    fn tuple_desc(&self) -> RS<TupleDesc> {
+        Ok(self.tuple_desc.clone())
+    }
+}
+
+impl _Unnest {
+    fn expand(&mut self, row: TupleRow) -> RS<()> {
+        let fields = row.into_fields();
+        let Some(Some(array)) = fields.get(self.position) else {
+            return Ok(());
+        };
+        let elem_type = element_type(&self.array_type)?;
+        for element in array_unnest(decode(&self.array_type, array)?)? {
+            let mut fields = fields.clone();
+            fields[self.position] = Some(element.to_binary(elem_type)?.into());
+            self.rows.push_back(TupleRow::new_nullable(fields));
+        }
+        Ok(())
+    }
+}
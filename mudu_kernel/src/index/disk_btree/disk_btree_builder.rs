@@ -0,0 +1,226 @@
+use std::path::Path;
+use std::sync::Arc;
+
+use mudu::common::result::RS;
+use mudu::error::ErrorCode;
+use mudu::mudu_error;
+use mudu_sys::contract::async_file::AsyncFile;
+use mudu_sys::contract::async_fs::AsyncFs;
+use mudu_sys::contract::file_options::FileOptions;
+
+use crate::index::disk_btree::disk_btree_index::slot_path;
+use crate::index::disk_btree::format::latest::{
+    encode_internal_record, encode_leaf_record, init_node_page, DiskBTreeMeta, MAX_KEY_SIZE,
+    MAX_RECORD_SIZE, META_PAGE_ID, NODE_KIND_INTERNAL, NODE_KIND_LEAF, NODE_KIND_META,
+};
+use crate::storage::encryption::{seal_page_image, Keyring, Sealer};
+use crate::storage::page::page_block_ref::PAGE_SIZE;
+use crate::storage::page::page_block_ref_mut::PageBlockRefMut;
+use crate::storage::page::page_header::NONE_PAGE_ID;
+use crate::storage::page::PageId;
+
+/// Writes a new build of a disk B+tree from entries pushed in ascending key
+/// order.
+///
+/// Leaves are written as they fill up and the internal levels are built from
+/// the first key of each leaf on [`Self::finish`]. The meta page is written
+/// last, after all nodes are synced, so a build interrupted by a crash never
+/// has a valid meta page and is ignored by [`DiskBTreeIndex::open_latest`].
+///
+/// [`DiskBTreeIndex::open_latest`]: crate::index::disk_btree::disk_btree_index::DiskBTreeIndex::open_latest
+pub struct DiskBTreeBuilder {
+    file: Arc<dyn AsyncFile>,
+    generation: u64,
+    next_page: u64,
+    leaf: NodeBuffer,
+    prev_leaf: PageId,
+    // First key and page of every written leaf, the input of the level above.
+    leaves: Vec<(Vec<u8>, PageId)>,
+    entry_count: u64,
+    sealer: Option<Sealer>,
+}
+
+struct NodeBuffer {
+    page_id: PageId,
+    page: Vec<u8>,
+    first_key: Option<Vec<u8>>,
+    records: u64,
+}
+
+impl NodeBuffer {
+    fn new(page_id: PageId, kind: u64) -> RS<Self> {
+        let mut page = vec![0u8; PAGE_SIZE];
+        init_node_page(&mut page, page_id, kind)?;
+        Ok(Self {
+            page_id,
+            page,
+            first_key: None,
+            records: 0,
+        })
+    }
+
+    /// Appends `record`; returns `false` when the page is full.
+    fn try_push(&mut self, key: &[u8], record: &[u8]) -> RS<bool> {
+        // Slots are ordered by timestamp, so the record ordinal keeps them in
+        // key order.
+        match PageBlockRefMut::new(&mut self.page).insert_record(self.records, 0, record) {
+            Ok(_) => {}
+            Err(err) if err.ec() == ErrorCode::InsufficientBufferSpace && self.records > 0 => {
+                return Ok(false);
+            }
+            Err(err) => return Err(err),
+        }
+        if self.first_key.is_none() {
+            self.first_key = Some(key.to_vec());
+        }
+        self.records += 1;
+        Ok(true)
+    }
+
+    /// First key and page id, as referenced from the parent level.
+    fn entry(&self) -> (Vec<u8>, PageId) {
+        (self.first_key.clone().unwrap_or_default(), self.page_id)
+    }
+}
+
+impl DiskBTreeBuilder {
+    /// Starts build `generation` of the index at `path`, truncating the file
+    /// slot of that generation. Pages are sealed with `keyring`, if any.
+    pub async fn create(
+        fs: &dyn AsyncFs,
+        path: &Path,
+        generation: u64,
+        keyring: Option<&Arc<Keyring>>,
+    ) -> RS<Self> {
+        let path = slot_path(path, generation);
+        if let Some(parent) = path.parent() {
+            fs.create_dir_all(parent).await?;
+        }
+        let file = fs
+            .open(
+                &path,
+                FileOptions::new(
+                    libc::O_CREAT | libc::O_RDWR | libc::O_TRUNC | libc::O_CLOEXEC,
+                    0o644,
+                ),
+            )
+            .await?;
+        let first_leaf = PageId::new(META_PAGE_ID.as_u64() + 1);
+        Ok(Self {
+            file,
+            generation,
+            next_page: first_leaf.as_u64() + 1,
+            leaf: NodeBuffer::new(first_leaf, NODE_KIND_LEAF)?,
+            prev_leaf: NONE_PAGE_ID,
+            leaves: Vec::new(),
+            entry_count: 0,
+            sealer: Sealer::for_keyring(keyring)?,
+        })
+    }
+
+    /// Appends an entry. Keys must be pushed in ascending order.
+    pub async fn push(&mut self, key: &[u8], value: &[u8]) -> RS<()> {
+        if key.len() > MAX_KEY_SIZE {
+            return Err(mudu_error!(
+                ErrorCode::InsufficientBufferSpace,
+                format!(
+                    "index key of {} bytes exceeds the limit of {} bytes",
+                    key.len(),
+                    MAX_KEY_SIZE
+                )
+            ));
+        }
+        let record = encode_leaf_record(key, value);
+        if record.len() > MAX_RECORD_SIZE {
+            return Err(mudu_error!(
+                ErrorCode::InsufficientBufferSpace,
+                format!(
+                    "index entry of {} bytes exceeds the page capacity of {} bytes",
+                    record.len(),
+                    MAX_RECORD_SIZE
+                )
+            ));
+        }
+        if !self.leaf.try_push(key, &record)? {
+            let next_leaf = self.alloc_page();
+            self.write_leaf(next_leaf).await?;
+            self.leaf = NodeBuffer::new(next_leaf, NODE_KIND_LEAF)?;
+            self.leaf.try_push(key, &record)?;
+        }
+        self.entry_count += 1;
+        Ok(())
+    }
+
+    /// Writes the internal levels and the meta page carrying `stamp`.
+    pub async fn finish(mut self, stamp: Vec<u8>) -> RS<DiskBTreeMeta> {
+        self.write_leaf(NONE_PAGE_ID).await?;
+
+        let mut level = std::mem::take(&mut self.leaves);
+        let mut height = 1;
+        while level.len() > 1 {
+            level = self.write_internal_level(level).await?;
+            height += 1;
+        }
+        let root_page = level
+            .first()
+            .map(|(_, page_id)| *page_id)
+            .ok_or_else(|| mudu_error!(ErrorCode::Internal, "index build has no root"))?;
+        self.file.fsync().await?;
+
+        let meta = DiskBTreeMeta {
+            generation: self.generation,
+            root_page,
+            height,
+            page_count: self.next_page,
+            entry_count: self.entry_count,
+            stamp,
+        };
+        let mut page = vec![0u8; PAGE_SIZE];
+        init_node_page(&mut page, META_PAGE_ID, NODE_KIND_META)?;
+        PageBlockRefMut::new(&mut page).insert_record(0, 0, &meta.encode())?;
+        self.write_page(META_PAGE_ID, &page).await?;
+        self.file.fsync().await?;
+        Ok(meta)
+    }
+
+    async fn write_internal_level(
+        &mut self,
+        children: Vec<(Vec<u8>, PageId)>,
+    ) -> RS<Vec<(Vec<u8>, PageId)>> {
+        let mut parents = Vec::new();
+        let mut node = NodeBuffer::new(self.alloc_page(), NODE_KIND_INTERNAL)?;
+        for (key, child) in children {
+            let record = encode_internal_record(child, &key);
+            if !node.try_push(&key, &record)? {
+                self.write_page(node.page_id, &node.page).await?;
+                parents.push(node.entry());
+                node = NodeBuffer::new(self.alloc_page(), NODE_KIND_INTERNAL)?;
+                node.try_push(&key, &record)?;
+            }
+        }
+        self.write_page(node.page_id, &node.page).await?;
+        parents.push(node.entry());
+        Ok(parents)
+    }
+
+    async fn write_leaf(&mut self, next_leaf: PageId) -> RS<()> {
+        PageBlockRefMut::new(&mut self.leaf.page).set_page_links(self.prev_leaf, next_leaf)?;
+        self.write_page(self.leaf.page_id, &self.leaf.page).await?;
+        self.prev_leaf = self.leaf.page_id;
+        self.leaves.push(self.leaf.entry());
+        Ok(())
+    }
+
+    async fn write_page(&self, page_id: PageId, page: &[u8]) -> RS<()> {
+        let stored = seal_page_image(self.sealer.as_ref(), page)?;
+        self.file
+            .write_all_at(page_id.as_u64() * PAGE_SIZE as u64, &stored)
+            .await
+    }
+
+    fn alloc_page(&mut self) -> PageId {
+        let page_id = PageId::new(self.next_page);
+        self.next_page += 1;
+        page_id
+    }
+}
@@ -0,0 +1,512 @@
+use std::cmp::Ordering;
+use std::collections::HashMap;
+use std::ops::Bound;
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+
+use mudu::common::result::RS;
+use mudu::compat::{self, FormatKind};
+use mudu_compat_migrate::{global, NoopOptionProvider};
+use mudu_sys::contract::async_file::AsyncFile;
+use mudu_sys::contract::async_fs::AsyncFs;
+use mudu_sys::contract::file_options::FileOptions;
+use mudu_sys::sync::SMutex;
+use tracing::debug;
+
+use crate::index::disk_btree::format::latest::{
+    corrupted, decode_internal_record, decode_leaf_record, DiskBTreeMeta, META_PAGE_ID,
+    NODE_KIND_INTERNAL, NODE_KIND_LEAF, NODE_KIND_META, VERSION,
+};
+use crate::index::index_key::compare_context::CompareContext;
+use crate::storage::encryption::{open_stored_page, Keyring};
+use crate::storage::page::page_block_ref::{PageBlockRef, PAGE_SIZE};
+use crate::storage::page::PageId;
+
+/// Number of file slots an index alternates between. A new build always goes
+/// to the slot the open build does not use.
+const SLOT_COUNT: u64 = 2;
+
+/// Returns the file of the slot that build `generation` of the index at
+/// `path` is written to.
+pub fn slot_path(path: &Path, generation: u64) -> PathBuf {
+    let mut name = path.as_os_str().to_os_string();
+    name.push(format!(".{}", generation % SLOT_COUNT));
+    PathBuf::from(name)
+}
+
+/// Returns the files of every slot of the index at `path`.
+pub fn slot_paths(path: &Path) -> impl Iterator<Item = PathBuf> + '_ {
+    (0..SLOT_COUNT).map(move |slot| slot_path(path, slot))
+}
+
+/// Read-only view of one build of a paged B+tree index.
+///
+/// Opening reads only the meta page; nodes are read from the file when a
+/// lookup or scan reaches them. Internal nodes stay cached because every
+/// lookup passes through them, while leaves are read on demand.
+///
+/// Keys are ordered by the comparator of `context`, the same order the
+/// in-memory [`BTreeIndex`] uses.
+///
+/// [`BTreeIndex`]: crate::index::btree::btree_index::BTreeIndex
+pub struct DiskBTreeIndex {
+    file: Arc<dyn AsyncFile>,
+    meta: DiskBTreeMeta,
+    context: CompareContext,
+    keyring: Option<Arc<Keyring>>,
+    internal_nodes: SMutex<HashMap<u64, Arc<Vec<u8>>>>,
+}
+
+/// Iterates the entries of a [`DiskBTreeIndex`] in key order, following the
+/// leaf chain.
+pub struct DiskBTreeCursor<'a> {
+    index: &'a DiskBTreeIndex,
+    leaf: Option<Arc<Vec<u8>>>,
+    slot: usize,
+}
+
+impl DiskBTreeIndex {
+    /// Opens the newest valid build of the index at `path`; sealed pages are
+    /// opened with `keyring`.
+    ///
+    /// Returns `None` when no build exists. A slot whose meta page cannot be
+    /// read, e.g. because a build was interrupted, is skipped.
+    pub async fn open_latest(
+        fs: &dyn AsyncFs,
+        path: &Path,
+        context: CompareContext,
+        keyring: Option<Arc<Keyring>>,
+    ) -> RS<Option<Self>> {
+        let mut latest: Option<(Arc<dyn AsyncFile>, DiskBTreeMeta)> = None;
+        for slot in 0..SLOT_COUNT {
+            let slot_path = slot_path(path, slot);
+            if !fs.path_exists(&slot_path).await? {
+                continue;
+            }
+            let file = fs
+                .open(
+                    &slot_path,
+                    FileOptions::new(libc::O_RDONLY | libc::O_CLOEXEC, 0),
+                )
+                .await?;
+            let meta = match read_meta(file.as_ref(), keyring.as_deref()).await {
+                Ok(meta) => meta,
+                Err(err) => {
+                    debug!(path = %slot_path.display(), error = ?err, "skip unreadable index build");
+                    continue;
+                }
+            };
+            if latest
+                .as_ref()
+                .is_none_or(|(_, newest)| newest.generation < meta.generation)
+            {
+                latest = Some((file, meta));
+            }
+        }
+        Ok(latest.map(|(file, meta)| Self {
+            file,
+            meta,
+            context,
+            keyring,
+            internal_nodes: SMutex::new(HashMap::new()),
+        }))
+    }
+
+    pub fn meta(&self) -> &DiskBTreeMeta {
+        &self.meta
+    }
+
+    pub async fn get(&self, key: &[u8]) -> RS<Option<Vec<u8>>> {
+        let leaf = self
+            .read_node(self.find_leaf(Some(key)).await?, NODE_KIND_LEAF)
+            .await?;
+        let page = PageBlockRef::new(&leaf);
+        let slot = self.lower_bound(&page, key)?;
+        if slot < page.slot_count()? {
+            let (found, value) = decode_leaf_record(page.record_bytes(slot)?)?;
+            if self.compare(found, key)?.is_eq() {
+                return Ok(Some(value.to_vec()));
+            }
+        }
+        Ok(None)
+    }
+
+    /// Returns a cursor positioned at the first entry inside `lower`.
+    pub async fn seek(&self, lower: Bound<&[u8]>) -> RS<DiskBTreeCursor<'_>> {
+        let key = match lower {
+            Bound::Included(key) | Bound::Excluded(key) => Some(key),
+            Bound::Unbounded => None,
+        };
+        let leaf = self
+            .read_node(self.find_leaf(key).await?, NODE_KIND_LEAF)
+            .await?;
+        let page = PageBlockRef::new(&leaf);
+        let mut slot = match key {
+            Some(key) => self.lower_bound(&page, key)?,
+            None => 0,
+        };
+        if let Bound::Excluded(key) = lower {
+            if slot < page.slot_count()? {
+                let (found, _) = decode_leaf_record(page.record_bytes(slot)?)?;
+                if self.compare(found, key)?.is_eq() {
+                    slot += 1;
+                }
+            }
+        }
+        Ok(DiskBTreeCursor {
+            index: self,
+            leaf: Some(leaf),
+            slot,
+        })
+    }
+
+    /// Returns up to `limit` entries inside `bounds`, in key order.
+    pub async fn range(
+        &self,
+        bounds: (Bound<&[u8]>, Bound<&[u8]>),
+        limit: usize,
+    ) -> RS<Vec<(Vec<u8>, Vec<u8>)>> {
+        let mut cursor = self.seek(bounds.0).await?;
+        let mut entries = Vec::new();
+        while entries.len() < limit {
+            let Some((key, value)) = cursor.next().await? else {
+                break;
+            };
+            let inside = match bounds.1 {
+                Bound::Included(upper) => self.compare(&key, upper)?.is_le(),
+                Bound::Excluded(upper) => self.compare(&key, upper)?.is_lt(),
+                Bound::Unbounded => true,
+            };
+            if !inside {
+                break;
+            }
+            entries.push((key, value));
+        }
+        Ok(entries)
+    }
+
+    pub fn compare(&self, left: &[u8], right: &[u8]) -> RS<Ordering> {
+        (self.context.comparator.compare)(left, right, &self.context.desc)
+    }
+
+    /// Descends from the root to the leaf that covers `key`, or to the first
+    /// leaf when `key` is `None`.
+    async fn find_leaf(&self, key: Option<&[u8]>) -> RS<PageId> {
+        let mut page_id = self.meta.root_page;
+        for _ in 1..self.meta.height {
+            let node = self.read_node(page_id, NODE_KIND_INTERNAL).await?;
+            let page = PageBlockRef::new(&node);
+            let count = page.slot_count()?;
+            if count == 0 {
+                return Err(corrupted(format!(
+                    "internal node {} has no children",
+                    page_id.as_u64()
+                )));
+            }
+            // Child `i` covers the keys from separator `i` up to separator
+            // `i + 1`; the first child also covers anything smaller.
+            let mut child = 0;
+            if let Some(key) = key {
+                let (mut low, mut high) = (1, count);
+                while low < high {
+                    let mid = low + (high - low) / 2;
+                    let (_, separator) = decode_internal_record(page.record_bytes(mid)?)?;
+                    if self.compare(separator, key)?.is_le() {
+                        low = mid + 1;
+                    } else {
+                        high = mid;
+                    }
+                }
+                child = low - 1;
+            }
+            page_id = decode_internal_record(page.record_bytes(child)?)?.0;
+        }
+        Ok(page_id)
+    }
+
+    /// Index of the first leaf record whose key is not less than `key`.
+    fn lower_bound(&self, page: &PageBlockRef<'_>, key: &[u8]) -> RS<usize> {
+        let (mut low, mut high) = (0, page.slot_count()?);
+        while low < high {
+            let mid = low + (high - low) / 2;
+            let (found, _) = decode_leaf_record(page.record_bytes(mid)?)?;
+            if self.compare(found, key)?.is_lt() {
+                low = mid + 1;
+            } else {
+                high = mid;
+            }
+        }
+        Ok(low)
+    }
+
+    async fn read_node(&self, page_id: PageId, kind: u64) -> RS<Arc<Vec<u8>>> {
+        if page_id == META_PAGE_ID || page_id.as_u64() >= self.meta.page_count {
+            return Err(corrupted(format!(
+                "node reference to page {} outside of {} pages",
+                page_id.as_u64(),
+                self.meta.page_count
+            )));
+        }
+        if kind == NODE_KIND_INTERNAL {
+            if let Some(node) = self.internal_nodes.lock()?.get(&page_id.as_u64()) {
+                return Ok(node.clone());
+            }
+        }
+        let node =
+            Arc::new(read_page(self.file.as_ref(), page_id, kind, self.keyring.as_deref()).await?);
+        if kind == NODE_KIND_INTERNAL {
+            self.internal_nodes
+                .lock()?
+                .insert(page_id.as_u64(), node.clone());
+        }
+        Ok(node)
+    }
+}
+
+impl DiskBTreeCursor<'_> {
+    pub async fn next(&mut self) -> RS<Option<(Vec<u8>, Vec<u8>)>> {
+        loop {
+            let Some(leaf) = self.leaf.clone() else {
+                return Ok(None);
+            };
+            let page = PageBlockRef::new(&leaf);
+            if self.slot < page.slot_count()? {
+                let (key, value) = decode_leaf_record(page.record_bytes(self.slot)?)?;
+                self.slot += 1;
+                return Ok(Some((key.to_vec(), value.to_vec())));
+            }
+            self.slot = 0;
+            self.leaf = match page.active_next_page()? {
+                Some(next) => Some(self.index.read_node(next, NODE_KIND_LEAF).await?),
+                None => None,
+            };
+        }
+    }
+}
+
+async fn read_meta(file: &dyn AsyncFile, keyring: Option<&Keyring>) -> RS<DiskBTreeMeta> {
+    let page = read_page(file, META_PAGE_ID, NODE_KIND_META, keyring).await?;
+    let page = PageBlockRef::new(&page);
+    if page.slot_count()? != 1 {
+        return Err(corrupted("meta page must hold exactly one record"));
+    }
+    DiskBTreeMeta::decode(page.record_bytes(0)?)
+}
+
+/// Reads an index page, checks it and brings it to the current page and index
+/// format versions.
+async fn read_page(
+    file: &dyn AsyncFile,
+    page_id: PageId,
+    kind: u64,
+    keyring: Option<&Keyring>,
+) -> RS<Vec<u8>> {
+    let mut raw = file
+        .read_exact_at(page_id.as_u64() * PAGE_SIZE as u64, PAGE_SIZE)
+        .await?;
+    open_stored_page(keyring, &mut raw)?;
+    let page = PageBlockRef::try_new(&raw)?;
+    page.validate_layout()?;
+    let header = page.header()?;
+    if header.page_id() != page_id || header.flags() != kind {
+        return Err(corrupted(format!(
+            "page {} holds node kind {} of page {}, expected node kind {}",
+            page_id.as_u64(),
+            header.flags(),
+            header.page_id().as_u64(),
+            kind
+        )));
+    }
+    let version = header.tuple_format_version();
+    compat::check_version(FormatKind::BTreeIndex, version)?;
+    if version == VERSION {
+        return Ok(page.page().to_vec());
+    }
+    global::upgrade_to_current(
+        FormatKind::BTreeIndex,
+        version,
+        page.page(),
+        &NoopOptionProvider,
+    )
+    .map_err(|e| e.into_mudu_error())
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(
+        clippy::unwrap_used,
+        clippy::expect_used,
+        clippy::panic,
+        clippy::todo,
+        clippy::unimplemented
+    )]
+
+    use std::hash::Hasher;
+
+    use mudu_contract::tuple::comparator::TupleComparator;
+    use mudu_contract::tuple::tuple_binary_desc::TupleBinaryDesc;
+    use mudu_sys::default_sys_io_context;
+    use mudu_type::dat_type::DatType;
+    use mudu_type::dat_type_id::DatTypeID;
+    use project_root::get_project_root;
+
+    use super::*;
+    use crate::index::disk_btree::disk_btree_builder::DiskBTreeBuilder;
+
+    fn bytes_compare(left: &[u8], right: &[u8], _desc: &TupleBinaryDesc) -> RS<Ordering> {
+        Ok(left.cmp(right))
+    }
+
+    fn bytes_equal(left: &[u8], right: &[u8], _desc: &TupleBinaryDesc) -> RS<bool> {
+        Ok(left == right)
+    }
+
+    fn bytes_hash(tuple: &[u8], _desc: &TupleBinaryDesc, hasher: &mut dyn Hasher) -> RS<()> {
+        hasher.write(tuple);
+        Ok(())
+    }
+
+    fn bytes_hash_finish(tuple: &[u8], desc: &TupleBinaryDesc, hasher: &mut dyn Hasher) -> RS<u64> {
+        bytes_hash(tuple, desc, hasher)?;
+        Ok(hasher.finish())
+    }
+
+    fn context() -> CompareContext {
+        CompareContext {
+            result: Ok(()),
+            comparator: TupleComparator {
+                compare: bytes_compare,
+                equal: bytes_equal,
+                hash_cal_one: bytes_hash,
+                hash_cal_finish: bytes_hash_finish,
+            },
+            desc: TupleBinaryDesc::from(vec![DatType::new_no_param(DatTypeID::I32)]).unwrap(),
+        }
+    }
+
+    fn temp_index_path() -> PathBuf {
+        get_project_root()
+            .unwrap()
+            .join("target")
+            .join("tmp")
+            .join(format!("btix-{}.bti", mudu_sys::random::uuid_v4()))
+    }
+
+    fn key(n: u32) -> Vec<u8> {
+        n.to_be_bytes().to_vec()
+    }
+
+    async fn build(path: &Path, generation: u64, keys: impl Iterator<Item = u32>) -> DiskBTreeMeta {
+        let fs = default_sys_io_context().fs();
+        let mut builder = DiskBTreeBuilder::create(fs.as_ref(), path, generation, None)
+            .await
+            .unwrap();
+        for n in keys {
+            builder.push(&key(n), &[n as u8; 100]).await.unwrap();
+        }
+        builder
+            .finish(generation.to_le_bytes().to_vec())
+            .await
+            .unwrap()
+    }
+
+    #[test]
+    fn lookups_and_scans_span_multiple_levels() {
+        mudu_sys::task::async_::block_on_tokio_current_thread(async move {
+            let path = temp_index_path();
+            // Even keys only, so every odd key is a miss between two entries.
+            let meta = build(&path, 1, (0..20_000).step_by(2)).await;
+            assert_eq!(meta.entry_count, 10_000);
+            assert!(meta.height >= 3);
+
+            let fs = default_sys_io_context().fs();
+            let index = DiskBTreeIndex::open_latest(fs.as_ref(), &path, context(), None)
+                .await
+                .unwrap()
+                .unwrap();
+            assert_eq!(index.meta(), &meta);
+            for n in [0, 2, 4_000, 10_002, 19_998] {
+                assert_eq!(index.get(&key(n)).await.unwrap(), Some(vec![n as u8; 100]));
+            }
+            for n in [1, 4_001, 19_999, 30_000] {
+                assert_eq!(index.get(&key(n)).await.unwrap(), None);
+            }
+
+            let keys = |entries: Vec<(Vec<u8>, Vec<u8>)>| -> Vec<Vec<u8>> {
+                entries.into_iter().map(|(key, _)| key).collect()
+            };
+            let entries = index
+                .range(
+                    (Bound::Excluded(&key(100)), Bound::Included(&key(108))),
+                    usize::MAX,
+                )
+                .await
+                .unwrap();
+            assert_eq!(keys(entries), vec![key(102), key(104), key(106), key(108)]);
+            let entries = index
+                .range((Bound::Included(&key(99)), Bound::Unbounded), 3)
+                .await
+                .unwrap();
+            assert_eq!(keys(entries), vec![key(100), key(102), key(104)]);
+
+            let mut cursor = index.seek(Bound::Unbounded).await.unwrap();
+            let mut count = 0;
+            while let Some((found, _)) = cursor.next().await.unwrap() {
+                assert_eq!(found, key(count * 2));
+                count += 1;
+            }
+            assert_eq!(count, 10_000);
+        })
+        .unwrap()
+    }
+
+    #[test]
+    fn open_latest_skips_an_interrupted_build() {
+        mudu_sys::task::async_::block_on_tokio_current_thread(async move {
+            let path = temp_index_path();
+            let fs = default_sys_io_context().fs();
+            build(&path, 1, 0..10).await;
+            build(&path, 2, 0..20).await;
+            let index = DiskBTreeIndex::open_latest(fs.as_ref(), &path, context(), None)
+                .await
+                .unwrap()
+                .unwrap();
+            assert_eq!(index.meta().generation, 2);
+            assert_eq!(index.meta().entry_count, 20);
+
+            // Build 3 reuses the slot of build 1 and never writes its meta
+            // page, so build 2 stays the latest.
+            let mut builder = DiskBTreeBuilder::create(fs.as_ref(), &path, 3, None)
+                .await
+                .unwrap();
+            builder.push(&key(1), b"torn").await.unwrap();
+            drop(builder);
+            let index = DiskBTreeIndex::open_latest(fs.as_ref(), &path, context(), None)
+                .await
+                .unwrap()
+                .unwrap();
+            assert_eq!(index.meta().generation, 2);
+            assert_eq!(index.get(&key(19)).await.unwrap(), Some(vec![19u8; 100]));
+
+            let empty_path = temp_index_path();
+            assert!(
+                DiskBTreeIndex::open_latest(fs.as_ref(), &empty_path, context(), None)
+                    .await
+                    .unwrap()
+                    .is_none()
+            );
+            build(&empty_path, 1, 0..0).await;
+            let empty = DiskBTreeIndex::open_latest(fs.as_ref(), &empty_path, context(), None)
+                .await
+                .unwrap()
+                .unwrap();
+            assert_eq!(empty.get(&key(0)).await.unwrap(), None);
+
+            for slot in 0..SLOT_COUNT {
+                let _ = mudu_sys::fs::sync::remove_file(slot_path(&path, slot));
+                let _ = mudu_sys::fs::sync::remove_file(slot_path(&empty_path, slot));
+            }
+        })
+        .unwrap()
+    }
+}
@@ -0,0 +1,228 @@
+use crate::storage::page::page_block_ref::{PAGE_SIZE, RECORD_ALIGN};
+use crate::storage::page::page_block_ref_mut::PageBlockRefMut;
+use crate::storage::page::page_header::PAGE_HEADER_SIZE;
+use crate::storage::page::page_tailer::PAGE_TAILER_SIZE;
+use crate::storage::page::record_slot::RECORD_SLOT_SIZE;
+use crate::storage::page::PageId;
+use byteorder::{ByteOrder, LittleEndian};
+use mudu::common::result::RS;
+use mudu::compat::{self, FormatKind, BTREE_INDEX_CURRENT_VERSION};
+use mudu::error::MuduError;
+
+/// Magic value for the ASCII tag `BTIX`, stored at the head of the meta record.
+pub const BTREE_INDEX_MAGIC: u32 = 0x4254_4958;
+
+/// Current on-disk version of the B+tree index format.
+///
+/// The canonical value is defined in [`mudu::compat::BTREE_INDEX_CURRENT_VERSION`].
+/// Every index page carries it in the `tuple_format_version` header field.
+pub const VERSION: u32 = BTREE_INDEX_CURRENT_VERSION;
+
+/// Page 0 of an index file holds the meta record.
+pub const META_PAGE_ID: PageId = PageId::new(0);
+
+// Node kinds, stored in the `flags` field of the page header.
+pub const NODE_KIND_META: u64 = 1;
+pub const NODE_KIND_INTERNAL: u64 = 2;
+pub const NODE_KIND_LEAF: u64 = 3;
+
+/// Largest record that fits into an empty index page.
+pub const MAX_RECORD_SIZE: usize =
+    (PAGE_SIZE - PAGE_HEADER_SIZE - PAGE_TAILER_SIZE - RECORD_SLOT_SIZE) / RECORD_ALIGN
+        * RECORD_ALIGN;
+
+/// Largest key accepted by the index.
+///
+/// The limit keeps at least two separator records in every internal page, so
+/// each internal level is smaller than the one below it.
+pub const MAX_KEY_SIZE: usize = 1024;
+
+// Meta record layout v1:
+// magic(u32) + version(u32) + generation(u64) + root_page(u64) + height(u32)
+// + reserved(u32) + page_count(u64) + entry_count(u64) + stamp_len(u32)
+// + stamp bytes.
+const META_FIXED_SIZE: usize = 52;
+
+// Leaf record layout v1: key_len(u32) + key bytes + value bytes.
+const LEAF_KEY_LEN_SIZE: usize = 4;
+
+// Internal record layout v1: child_page(u64) + separator key bytes.
+const INTERNAL_CHILD_SIZE: usize = 8;
+
+/// Contents of the meta record on page 0.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DiskBTreeMeta {
+    /// Build sequence number; the newest valid build of an index wins.
+    pub generation: u64,
+    pub root_page: PageId,
+    /// Number of node levels; a height of 1 means the root is a leaf.
+    pub height: u32,
+    pub page_count: u64,
+    pub entry_count: u64,
+    /// Caller-defined bytes identifying the data the index was built from.
+    pub stamp: Vec<u8>,
+}
+
+impl DiskBTreeMeta {
+    pub fn encode(&self) -> Vec<u8> {
+        let mut out = vec![0u8; META_FIXED_SIZE + self.stamp.len()];
+        LittleEndian::write_u32(&mut out[0..4], BTREE_INDEX_MAGIC);
+        LittleEndian::write_u32(&mut out[4..8], VERSION);
+        LittleEndian::write_u64(&mut out[8..16], self.generation);
+        LittleEndian::write_u64(&mut out[16..24], self.root_page.as_u64());
+        LittleEndian::write_u32(&mut out[24..28], self.height);
+        LittleEndian::write_u64(&mut out[32..40], self.page_count);
+        LittleEndian::write_u64(&mut out[40..48], self.entry_count);
+        LittleEndian::write_u32(&mut out[48..52], self.stamp.len() as u32);
+        out[META_FIXED_SIZE..].copy_from_slice(&self.stamp);
+        out
+    }
+
+    pub fn decode(input: &[u8]) -> RS<Self> {
+        if input.len() < META_FIXED_SIZE {
+            return Err(corrupted(format!(
+                "meta record requires {} bytes, got {}",
+                META_FIXED_SIZE,
+                input.len()
+            )));
+        }
+        compat::check_magic_and_version(
+            FormatKind::BTreeIndex,
+            LittleEndian::read_u32(&input[0..4]),
+            LittleEndian::read_u32(&input[4..8]),
+        )?;
+        let stamp_len = LittleEndian::read_u32(&input[48..52]) as usize;
+        if input.len() != META_FIXED_SIZE + stamp_len {
+            return Err(corrupted(format!(
+                "meta record stamp length {} does not match record size {}",
+                stamp_len,
+                input.len()
+            )));
+        }
+        let meta = Self {
+            generation: LittleEndian::read_u64(&input[8..16]),
+            root_page: PageId::new(LittleEndian::read_u64(&input[16..24])),
+            height: LittleEndian::read_u32(&input[24..28]),
+            page_count: LittleEndian::read_u64(&input[32..40]),
+            entry_count: LittleEndian::read_u64(&input[40..48]),
+            stamp: input[META_FIXED_SIZE..].to_vec(),
+        };
+        if meta.height == 0
+            || meta.root_page == META_PAGE_ID
+            || meta.root_page.as_u64() >= meta.page_count
+        {
+            return Err(corrupted(format!(
+                "meta record has root page {} and height {} in {} pages",
+                meta.root_page.as_u64(),
+                meta.height,
+                meta.page_count
+            )));
+        }
+        Ok(meta)
+    }
+}
+
+/// Formats `page` as an empty index node of `kind`.
+pub fn init_node_page(page: &mut [u8], page_id: PageId, kind: u64) -> RS<()> {
+    let mut block = PageBlockRefMut::new(page);
+    block.init_empty_with_tuple_meta(page_id, VERSION, 0, 0)?;
+    block.set_page_flags(kind)
+}
+
+pub fn encode_leaf_record(key: &[u8], value: &[u8]) -> Vec<u8> {
+    let mut out = Vec::with_capacity(LEAF_KEY_LEN_SIZE + key.len() + value.len());
+    out.extend_from_slice(&(key.len() as u32).to_le_bytes());
+    out.extend_from_slice(key);
+    out.extend_from_slice(value);
+    out
+}
+
+/// Splits a leaf record into its key and value.
+pub fn decode_leaf_record(record: &[u8]) -> RS<(&[u8], &[u8])> {
+    if record.len() < LEAF_KEY_LEN_SIZE {
+        return Err(corrupted("leaf record is truncated"));
+    }
+    let key_len = LittleEndian::read_u32(&record[..LEAF_KEY_LEN_SIZE]) as usize;
+    let rest = &record[LEAF_KEY_LEN_SIZE..];
+    if key_len > rest.len() {
+        return Err(corrupted(format!(
+            "leaf record key length {} exceeds record size {}",
+            key_len,
+            record.len()
+        )));
+    }
+    Ok(rest.split_at(key_len))
+}
+
+pub fn encode_internal_record(child: PageId, key: &[u8]) -> Vec<u8> {
+    let mut out = Vec::with_capacity(INTERNAL_CHILD_SIZE + key.len());
+    out.extend_from_slice(&child.as_u64().to_le_bytes());
+    out.extend_from_slice(key);
+    out
+}
+
+/// Splits an internal record into its child page and separator key.
+pub fn decode_internal_record(record: &[u8]) -> RS<(PageId, &[u8])> {
+    if record.len() < INTERNAL_CHILD_SIZE {
+        return Err(corrupted("internal record is truncated"));
+    }
+    let child = LittleEndian::read_u64(&record[..INTERNAL_CHILD_SIZE]);
+    Ok((PageId::new(child), &record[INTERNAL_CHILD_SIZE..]))
+}
+
+pub(crate) fn corrupted(detail: impl Into<String>) -> MuduError {
+    compat::corrupted(FormatKind::BTreeIndex, detail).into_mudu_error()
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(
+        clippy::unwrap_used,
+        clippy::expect_used,
+        clippy::panic,
+        clippy::todo,
+        clippy::unimplemented
+    )]
+
+    use super::*;
+    use mudu::error::ErrorCode;
+
+    #[test]
+    fn meta_record_round_trips() {
+        let meta = DiskBTreeMeta {
+            generation: 3,
+            root_page: PageId::new(9),
+            height: 2,
+            page_count: 10,
+            entry_count: 400,
+            stamp: b"stamp".to_vec(),
+        };
+        assert_eq!(DiskBTreeMeta::decode(&meta.encode()).unwrap(), meta);
+
+        let mut bad_magic = meta.encode();
+        bad_magic[0] ^= 0xff;
+        let err = DiskBTreeMeta::decode(&bad_magic).unwrap_err();
+        assert_eq!(err.ec(), ErrorCode::CorruptedData);
+
+        let mut future = meta.encode();
+        LittleEndian::write_u32(&mut future[4..8], VERSION + 1);
+        let err = DiskBTreeMeta::decode(&future).unwrap_err();
+        assert_eq!(err.ec(), ErrorCode::UnsupportedFormatVersion);
+    }
+
+    #[test]
+    fn node_records_round_trip() {
+        let leaf = encode_leaf_record(b"key", b"value");
+        assert_eq!(
+            decode_leaf_record(&leaf).unwrap(),
+            (&b"key"[..], &b"value"[..])
+        );
+        assert!(decode_leaf_record(&leaf[..6]).is_err());
+
+        let internal = encode_internal_record(PageId::new(5), b"sep");
+        assert_eq!(
+            decode_internal_record(&internal).unwrap(),
+            (PageId::new(5), &b"sep"[..])
+        );
+    }
+}
@@ -0,0 +1,39 @@
+//! Migration handlers for the on-disk B+tree index format.
+//!
+//! Handlers for `FormatKind::BTreeIndex` operate on one **complete index
+//! page** (meta, internal or leaf node), after the page itself has been brought
+//! to the current page format.  The index version of a page is the
+//! `tuple_format_version` field of its page header.
+
+use mudu_compat_migrate::handler::{clone_rollback, clone_upgrade, MigrateHandler};
+
+/// Returns a placeholder identity handler that clones the input page unchanged.
+///
+/// This will be replaced by real `v1 -> v2` handlers when the index format
+/// evolves.
+pub fn identity() -> MigrateHandler {
+    MigrateHandler {
+        from: 1,
+        to: 1,
+        upgrade: clone_upgrade,
+        rollback: clone_rollback,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use mudu::compat::FormatKind;
+    use mudu_compat_migrate::{CompatibilityRouter, MigrateError, NoopOptionProvider};
+
+    #[test]
+    fn identity_handler_clones_input() -> Result<(), MigrateError> {
+        let mut router = CompatibilityRouter::new();
+        router.set_supported_window(FormatKind::BTreeIndex, 1, 1);
+        router.register(FormatKind::BTreeIndex, identity());
+        let input = vec![0x42u8, 0x54, 0x49, 0x58];
+        let output = router.migrate(FormatKind::BTreeIndex, 1, 1, &input, &NoopOptionProvider)?;
+        assert_eq!(output, input);
+        Ok(())
+    }
+}
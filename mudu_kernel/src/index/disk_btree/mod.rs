@@ -0,0 +1,10 @@
+//! Paged on-disk B+tree used as the persistent primary index of relations.
+//!
+//! Nodes are stored in the standard slotted page layout of
+//! [`crate::storage::page`]; the format is described in
+//! `doc/en/contract/btree_index_v1.md`.
+
+pub mod disk_btree_builder;
+pub mod disk_btree_index;
+pub mod format;
+pub mod migrate;
@@ -3,4 +3,5 @@
 #![allow(missing_docs)]
 
 pub mod btree;
+pub mod disk_btree;
 pub mod index_key;
@@ -36,6 +36,8 @@ use crate::meta::schema_catalog::{
     delete_schema_from_catalog, load_schemas_from_catalog, open_schema_catalog,
     write_schema_to_catalog,
 };
+use crate::storage::backup::BackupFile;
+use crate::storage::encryption::Keyring;
 use crate::storage::relation::relation::Relation;
 
 type MetaMgrRegistry = HashMap<String, Vec<Weak<MetaMgrImpl>>>;
@@ -74,6 +76,8 @@ pub struct MetaMgrImpl {
     catalog: SMutex<Option<CatalogRelation>>,
     next_catalog_xid: AtomicU64,
     async_runtime: Option<Arc<dyn AsyncIoProvider>>,
+    // Seals the catalog relations; `None` stores them in plaintext.
+    keyring: Option<Arc<Keyring>>,
     id2table: scc::HashMap<OID, TableInfo>,
     name2id: scc::HashMap<String, OID>,
     table: scc::HashMap<String, TableInfo>,
@@ -98,13 +102,27 @@ impl MetaMgrImpl {
             mudu_sys::fs::sync::create_dir_all(&path)?;
         }
 
-        let schema_catalog = open_schema_catalog(&self.path, self.async_runtime.clone()).await?;
-        let partition_rule_catalog =
-            open_partition_rule_catalog(&self.path, self.async_runtime.clone()).await?;
-        let partition_binding_catalog =
-            open_partition_binding_catalog(&self.path, self.async_runtime.clone()).await?;
-        let partition_placement_catalog =
-            open_partition_placement_catalog(&self.path, self.async_runtime.clone()).await?;
+        let schema_catalog =
+            open_schema_catalog(&self.path, self.async_runtime.clone(), self.keyring.clone())
+                .await?;
+        let partition_rule_catalog = open_partition_rule_catalog(
+            &self.path,
+            self.async_runtime.clone(),
+            self.keyring.clone(),
+        )
+        .await?;
+        let partition_binding_catalog = open_partition_binding_catalog(
+            &self.path,
+            self.async_runtime.clone(),
+            self.keyring.clone(),
+        )
+        .await?;
+        let partition_placement_catalog = open_partition_placement_catalog(
+            &self.path,
+            self.async_runtime.clone(),
+            self.keyring.clone(),
+        )
+        .await?;
         for schema in load_schemas_from_catalog(&schema_catalog).await? {
             self.apply_create_table_local(&schema)?;
         }
@@ -131,12 +149,13 @@ impl MetaMgrImpl {
     }
 
     pub async fn new<P: AsRef<Path>>(path: P) -> RS<Self> {
-        Self::new_with_async_runtime(path, None).await
+        Self::new_with_async_runtime(path, None, None).await
     }
 
     pub async fn new_with_async_runtime<P: AsRef<Path>>(
         path: P,
         async_runtime: Option<Arc<dyn AsyncIoProvider>>,
+        keyring: Option<Arc<Keyring>>,
     ) -> RS<Self> {
         let path = PathBuf::from(path.as_ref());
         let path_string = path.to_string_lossy().to_string();
@@ -147,6 +166,7 @@ impl MetaMgrImpl {
             catalog: SMutex::new(None),
             next_catalog_xid: AtomicU64::new(now_catalog_xid()),
             async_runtime,
+            keyring,
             id2table: Default::default(),
             name2id: Default::default(),
             table: Default::default(),
@@ -194,6 +214,23 @@ impl MetaMgrImpl {
         Ok(schemas)
     }
 
+    pub fn list_table_indexes_inner(&self, table_id: OID) -> RS<Vec<SchemaTable>> {
+        let mut schemas = Vec::new();
+        self.id2table.iter_sync(|_oid, table_info| {
+            if let Ok(schema) = table_info.schema() {
+                if schema
+                    .index_on()
+                    .is_some_and(|index| index.base_table_id == table_id)
+                {
+                    schemas.push(schema.as_ref().clone());
+                }
+            }
+            true
+        });
+        schemas.sort_by_key(|schema| schema.id());
+        Ok(schemas)
+    }
+
     pub fn lookup_partition_rule_by_id(&self, oid: OID) -> Option<PartitionRuleDesc> {
         self.rule_by_id
             .get_sync(&oid)
@@ -535,6 +572,28 @@ impl MetaMgr for MetaMgrImpl {
     async fn list_schemas(&self) -> RS<Vec<SchemaTable>> {
         self.list_schemas_inner()
     }
+
+    async fn list_table_indexes(&self, table_id: OID) -> RS<Vec<SchemaTable>> {
+        self.list_table_indexes_inner(table_id)
+    }
+
+    async fn backup_catalog(&self, backup_dir: &Path) -> RS<Vec<BackupFile>> {
+        let _ddl_guard = self.ddl_lock.lock().await;
+        let catalog = self.catalog_relation()?;
+        // The catalog lives in `<data dir>/meta`.
+        let meta_path = Path::new(&self.path);
+        let root = meta_path.parent().unwrap_or(meta_path);
+        let mut files = Vec::new();
+        for relation in [
+            &catalog.schema_catalog,
+            &catalog.partition_rule_catalog,
+            &catalog.partition_binding_catalog,
+            &catalog.partition_placement_catalog,
+        ] {
+            files.extend(relation.backup_files(root, backup_dir).await?);
+        }
+        Ok(files)
+    }
 }
 
 unsafe impl Sync for MetaMgrImpl {}
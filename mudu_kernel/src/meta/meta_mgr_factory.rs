@@ -1,5 +1,6 @@
 use crate::contract::meta_mgr::MetaMgr;
 use crate::meta::meta_mgr::MetaMgrImpl;
+use crate::storage::encryption::Keyring;
 use mudu::common::result::RS;
 use mudu_sys::contract::async_io_provider::AsyncIoProvider;
 use std::path::PathBuf;
@@ -9,16 +10,20 @@ pub struct MetaMgrFactory {}
 
 impl MetaMgrFactory {
     pub async fn create(path: String) -> RS<Arc<dyn MetaMgr>> {
-        Self::create_with_async_runtime(path, None).await
+        Self::create_with_async_runtime(path, None, None).await
     }
 
+    /// Creates the meta manager of the data directory `path`; its catalog is
+    /// sealed with `keyring`, if any.
     pub async fn create_with_async_runtime(
         path: String,
         async_runtime: Option<Arc<dyn AsyncIoProvider>>,
+        keyring: Option<Arc<Keyring>>,
     ) -> RS<Arc<dyn MetaMgr>> {
         let mut path = PathBuf::from(path);
         path.push("meta");
-        let meta_mgr = Arc::new(MetaMgrImpl::new_with_async_runtime(path, async_runtime).await?);
+        let meta_mgr =
+            Arc::new(MetaMgrImpl::new_with_async_runtime(path, async_runtime, keyring).await?);
         meta_mgr.register_global()?;
         Ok(meta_mgr)
     }
@@ -16,6 +16,7 @@ use crate::contract::schema_table::SchemaTable;
 use crate::contract::table_desc::TableDesc;
 use crate::contract::table_info::TableInfo;
 use crate::server::worker_snapshot::WorkerSnapshot;
+use crate::storage::encryption::Keyring;
 use crate::storage::relation::relation::Relation;
 
 pub const PARTITION_BINDING_CATALOG_PARTITION_ID: OID = 0;
@@ -54,6 +55,7 @@ pub fn partition_binding_catalog_desc() -> RS<Arc<TableDesc>> {
 pub async fn open_partition_binding_catalog(
     path: &str,
     async_runtime: Option<Arc<dyn AsyncIoProvider>>,
+    keyring: Option<Arc<Keyring>>,
 ) -> RS<Relation> {
     let desc = partition_binding_catalog_desc()?;
     match async_runtime {
@@ -64,6 +66,7 @@ pub async fn open_partition_binding_catalog(
                 PARTITION_BINDING_CATALOG_PARTITION_ID,
                 path.to_string(),
                 desc.as_ref(),
+                keyring,
             )
             .await
         }
@@ -73,6 +76,7 @@ pub async fn open_partition_binding_catalog(
                 PARTITION_BINDING_CATALOG_PARTITION_ID,
                 path.to_string(),
                 desc.as_ref(),
+                keyring,
             )
             .await
         }
@@ -243,7 +247,9 @@ mod tests {
     fn load_bindings_rejects_key_value_table_id_mismatch() {
         mudu_sys::task::async_::block_on_tokio_current_thread(async {
             let path = catalog_path("mismatch");
-            let relation = open_partition_binding_catalog(&path, None).await.unwrap();
+            let relation = open_partition_binding_catalog(&path, None, None)
+                .await
+                .unwrap();
 
             let key = encode_partition_binding_catalog_key(0x10).unwrap();
             let binding = TablePartitionBinding {
@@ -266,7 +272,9 @@ mod tests {
     fn write_and_load_binding_roundtrip() {
         mudu_sys::task::async_::block_on_tokio_current_thread(async {
             let path = catalog_path("roundtrip");
-            let relation = open_partition_binding_catalog(&path, None).await.unwrap();
+            let relation = open_partition_binding_catalog(&path, None, None)
+                .await
+                .unwrap();
 
             let binding = TablePartitionBinding {
                 table_id: 0x42,
@@ -290,7 +298,9 @@ mod tests {
         mudu_sys::task::async_::block_on_tokio_current_thread(async {
             let path = catalog_path("metadata");
             let desc = partition_binding_catalog_desc().unwrap();
-            let relation = open_partition_binding_catalog(&path, None).await.unwrap();
+            let relation = open_partition_binding_catalog(&path, None, None)
+                .await
+                .unwrap();
 
             assert_eq!(relation.table_id(), PARTITION_BINDING_CATALOG_TABLE_ID);
             assert_eq!(
@@ -16,6 +16,7 @@ use crate::contract::schema_table::SchemaTable;
 use crate::contract::table_desc::TableDesc;
 use crate::contract::table_info::TableInfo;
 use crate::server::worker_snapshot::WorkerSnapshot;
+use crate::storage::encryption::Keyring;
 use crate::storage::relation::relation::Relation;
 
 pub const PARTITION_RULE_CATALOG_PARTITION_ID: OID = 0;
@@ -54,6 +55,7 @@ pub fn partition_rule_catalog_desc() -> RS<Arc<TableDesc>> {
 pub async fn open_partition_rule_catalog(
     path: &str,
     async_runtime: Option<Arc<dyn AsyncIoProvider>>,
+    keyring: Option<Arc<Keyring>>,
 ) -> RS<Relation> {
     let desc = partition_rule_catalog_desc()?;
     match async_runtime {
@@ -64,6 +66,7 @@ pub async fn open_partition_rule_catalog(
                 PARTITION_RULE_CATALOG_PARTITION_ID,
                 path.to_string(),
                 desc.as_ref(),
+                keyring,
             )
             .await
         }
@@ -73,6 +76,7 @@ pub async fn open_partition_rule_catalog(
                 PARTITION_RULE_CATALOG_PARTITION_ID,
                 path.to_string(),
                 desc.as_ref(),
+                keyring,
             )
             .await
         }
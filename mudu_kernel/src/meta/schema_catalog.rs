@@ -15,6 +15,7 @@ use crate::contract::schema_table::SchemaTable;
 use crate::contract::table_desc::TableDesc;
 use crate::contract::table_info::TableInfo;
 use crate::server::worker_snapshot::WorkerSnapshot;
+use crate::storage::encryption::Keyring;
 use crate::storage::relation::relation::Relation;
 
 pub const SCHEMA_CATALOG_PARTITION_ID: OID = 0;
@@ -53,6 +54,7 @@ pub fn schema_catalog_desc() -> RS<Arc<TableDesc>> {
 pub async fn open_schema_catalog(
     path: &str,
     async_runtime: Option<Arc<dyn AsyncIoProvider>>,
+    keyring: Option<Arc<Keyring>>,
 ) -> RS<Relation> {
     let desc = schema_catalog_desc()?;
     match async_runtime {
@@ -63,6 +65,7 @@ pub async fn open_schema_catalog(
                 SCHEMA_CATALOG_PARTITION_ID,
                 path.to_string(),
                 desc.as_ref(),
+                keyring,
             )
             .await
         }
@@ -72,6 +75,7 @@ pub async fn open_schema_catalog(
                 SCHEMA_CATALOG_PARTITION_ID,
                 path.to_string(),
                 desc.as_ref(),
+                keyring,
             )
             .await
         }
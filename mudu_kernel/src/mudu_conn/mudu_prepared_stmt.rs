@@ -73,11 +73,11 @@ mod tests {
     use crate::contract::meta_mgr::MetaMgr;
     use crate::server::message_bus_api::MessageBusRef;
     use crate::server::worker_local::{WorkerExecute, WorkerLocal};
-    use crate::server::worker_snapshot::KvItem;
     use crate::x_engine::api::XContract;
     use async_trait::async_trait;
     use futures::executor::block_on;
     use mudu::common::result::RS;
+    use mudu_contract::database::kv_range::{KvRangeOptions, KvRangePage};
     use std::fmt;
     use std::sync::atomic::{AtomicUsize, Ordering};
     use std::sync::Arc;
@@ -187,7 +187,8 @@ mod tests {
             _session_id: OID,
             _start_key: &[u8],
             _end_key: &[u8],
-        ) -> RS<Vec<KvItem>> {
+            _options: &KvRangeOptions,
+        ) -> RS<KvRangePage> {
             unimplemented!()
         }
 
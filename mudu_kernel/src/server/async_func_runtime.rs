@@ -13,6 +13,12 @@ pub trait AsyncFuncInvoker: Send + Sync {
         procedure_parameters: Vec<u8>,
         worker_local: WorkerLocalRef,
     ) -> RS<Vec<u8>>;
+
+    /// Whether the app `app_name` is installed. Sessions can only be bound
+    /// to installed apps.
+    async fn app_installed(&self, _app_name: &str) -> RS<bool> {
+        Ok(false)
+    }
 }
 
 pub type AsyncFuncInvokerPtr = Arc<dyn AsyncFuncInvoker>;
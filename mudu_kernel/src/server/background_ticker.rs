@@ -0,0 +1,73 @@
+use mudu_sys::time::instant_now;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+
+/// Decides when the worker loop starts the next step of a background job
+/// such as version GC or checkpointing.
+///
+/// At most one step is in flight per ticker, so a slow step delays the next
+/// one instead of piling up tasks.
+pub(crate) struct BackgroundTicker {
+    interval: Duration,
+    next_run: Instant,
+    in_flight: Arc<AtomicBool>,
+}
+
+/// Marks a background step as in flight until dropped.
+pub(crate) struct BackgroundRun {
+    in_flight: Arc<AtomicBool>,
+}
+
+impl BackgroundTicker {
+    pub(crate) fn new(interval: Duration) -> Self {
+        Self {
+            interval,
+            next_run: *instant_now() + interval,
+            in_flight: Arc::new(AtomicBool::new(false)),
+        }
+    }
+
+    /// Returns a run marker when a step is due and none is in flight.
+    pub(crate) fn poll(&mut self) -> Option<BackgroundRun> {
+        let now = *instant_now();
+        if now < self.next_run || self.in_flight.swap(true, Ordering::AcqRel) {
+            return None;
+        }
+        self.next_run = now + self.interval;
+        Some(BackgroundRun {
+            in_flight: self.in_flight.clone(),
+        })
+    }
+
+    /// Time left until the next step is due, or `None` while a step is in
+    /// flight: its completion wakes the loop anyway.
+    pub(crate) fn next_timeout(&self) -> Option<Duration> {
+        if self.in_flight.load(Ordering::Acquire) {
+            return None;
+        }
+        Some(self.next_run.saturating_duration_since(*instant_now()))
+    }
+}
+
+impl Drop for BackgroundRun {
+    fn drop(&mut self) {
+        self.in_flight.store(false, Ordering::Release);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::BackgroundTicker;
+    use std::time::Duration;
+
+    #[test]
+    fn ticker_allows_one_step_in_flight() {
+        let mut ticker = BackgroundTicker::new(Duration::ZERO);
+        let run = ticker.poll();
+        assert!(run.is_some());
+        assert!(ticker.poll().is_none());
+        drop(run);
+        assert!(ticker.poll().is_some());
+    }
+}
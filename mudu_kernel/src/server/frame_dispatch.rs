@@ -40,21 +40,37 @@ pub async fn dispatch_frame_async(
     match frame.header().message_type() {
         MessageType::Get
         | MessageType::Put
+        | MessageType::Delete
+        | MessageType::PutMany
+        | MessageType::DeleteMany
+        | MessageType::Cas
+        | MessageType::Incr
+        | MessageType::PutIfAbsent
         | MessageType::RangeScan
+        | MessageType::KvNamespaceCreate
+        | MessageType::KvNamespaceDrop
+        | MessageType::KvNamespaceList
         | MessageType::Query
         | MessageType::Execute
         | MessageType::Batch
         | MessageType::ProcedureInvoke
         | MessageType::SessionCreate
-        | MessageType::SessionClose => unreachable!(),
-        MessageType::Handshake | MessageType::Auth | MessageType::Response | MessageType::Error => {
-            Err(mudu_error!(
-                ErrorCode::Parse,
-                format!(
-                    "unsupported client message type {:?}",
-                    frame.header().message_type()
-                )
-            ))
-        }
+        | MessageType::SessionClose
+        | MessageType::CdcSubscribe
+        | MessageType::WalFetch
+        | MessageType::WatchRegister
+        | MessageType::WatchAck
+        | MessageType::WatchCancel => unreachable!(),
+        MessageType::Handshake
+        | MessageType::Auth
+        | MessageType::Response
+        | MessageType::Error
+        | MessageType::WatchEvent => Err(mudu_error!(
+            ErrorCode::Parse,
+            format!(
+                "unsupported client message type {:?}",
+                frame.header().message_type()
+            )
+        )),
     }
 }
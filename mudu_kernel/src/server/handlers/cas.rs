@@ -0,0 +1,23 @@
+use async_trait::async_trait;
+use mudu::common::result::RS;
+use mudu_contract::protocol::{decode_cas_request, Frame, MessageType};
+
+use crate::server::async_func_task::HandleResult;
+use crate::server::message_dispatcher::MessageHandler;
+use crate::server::request_ctx::RequestCtx;
+
+pub(in crate::server) struct CasHandler;
+
+#[async_trait]
+impl MessageHandler for CasHandler {
+    fn message_type(&self) -> MessageType {
+        MessageType::Cas
+    }
+
+    async fn handle(&self, ctx: &RequestCtx, frame: &Frame) -> RS<HandleResult> {
+        let request = decode_cas_request(frame)?;
+        let session_id = request.session_id();
+        let (key, expected, value) = request.into_parts();
+        ctx.cas(session_id, key, expected, value).await
+    }
+}
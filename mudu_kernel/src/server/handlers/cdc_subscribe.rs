@@ -0,0 +1,21 @@
+use async_trait::async_trait;
+use mudu::common::result::RS;
+use mudu_contract::protocol::{decode_cdc_subscribe_request, Frame, MessageType};
+
+use crate::server::async_func_task::HandleResult;
+use crate::server::message_dispatcher::MessageHandler;
+use crate::server::request_ctx::RequestCtx;
+
+pub(in crate::server) struct CdcSubscribeHandler;
+
+#[async_trait]
+impl MessageHandler for CdcSubscribeHandler {
+    fn message_type(&self) -> MessageType {
+        MessageType::CdcSubscribe
+    }
+
+    async fn handle(&self, ctx: &RequestCtx, frame: &Frame) -> RS<HandleResult> {
+        let request = decode_cdc_subscribe_request(frame)?;
+        ctx.cdc_subscribe(&request).await
+    }
+}
@@ -0,0 +1,22 @@
+use async_trait::async_trait;
+use mudu::common::result::RS;
+use mudu_contract::protocol::{decode_delete_request, Frame, MessageType};
+
+use crate::server::async_func_task::HandleResult;
+use crate::server::message_dispatcher::MessageHandler;
+use crate::server::request_ctx::RequestCtx;
+
+pub(in crate::server) struct DeleteHandler;
+
+#[async_trait]
+impl MessageHandler for DeleteHandler {
+    fn message_type(&self) -> MessageType {
+        MessageType::Delete
+    }
+
+    async fn handle(&self, ctx: &RequestCtx, frame: &Frame) -> RS<HandleResult> {
+        let request = decode_delete_request(frame)?;
+        ctx.delete(request.session_id(), request.namespace(), request.key())
+            .await
+    }
+}
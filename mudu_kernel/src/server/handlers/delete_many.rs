@@ -0,0 +1,22 @@
+use async_trait::async_trait;
+use mudu::common::result::RS;
+use mudu_contract::protocol::{decode_delete_many_request, Frame, MessageType};
+
+use crate::server::async_func_task::HandleResult;
+use crate::server::message_dispatcher::MessageHandler;
+use crate::server::request_ctx::RequestCtx;
+
+pub(in crate::server) struct DeleteManyHandler;
+
+#[async_trait]
+impl MessageHandler for DeleteManyHandler {
+    fn message_type(&self) -> MessageType {
+        MessageType::DeleteMany
+    }
+
+    async fn handle(&self, ctx: &RequestCtx, frame: &Frame) -> RS<HandleResult> {
+        let request = decode_delete_many_request(frame)?;
+        let session_id = request.session_id();
+        ctx.delete_many(session_id, request.into_keys()).await
+    }
+}
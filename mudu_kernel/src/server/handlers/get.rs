@@ -16,6 +16,7 @@ impl MessageHandler for GetHandler {
 
     async fn handle(&self, ctx: &RequestCtx, frame: &Frame) -> RS<HandleResult> {
         let request = decode_get_request(frame)?;
-        ctx.get(request.session_id(), request.key()).await
+        ctx.get(request.session_id(), request.namespace(), request.key())
+            .await
     }
 }
@@ -23,7 +23,6 @@ use crate::server::routing::SessionOpenConfig;
 use crate::server::worker_local::{WorkerExecute, WorkerLocal};
 use crate::server::worker_registry::WorkerIdentity;
 use crate::server::worker_registry::WorkerRegistry;
-use crate::server::worker_snapshot::KvItem;
 use crate::x_engine::api::{
     AlterTable, OptDelete, OptInsert, OptRead, OptUpdate, Predicate, RSCursor, RangeData, VecDatum,
     VecSelTerm, XContract,
@@ -33,6 +32,7 @@ use async_trait::async_trait;
 use mudu::common::buf::Buf;
 use mudu::common::id::OID;
 use mudu::common::result::RS;
+use mudu_contract::database::kv_range::{KvRangeOptions, KvRangePage};
 use mudu_contract::database::result_set::ResultSetAsync;
 use mudu_contract::database::sql_params::SQLParams;
 use mudu_contract::database::sql_stmt::SQLStmt;
@@ -241,7 +241,8 @@ impl WorkerLocal for NullWorkerRuntime {
         _session_id: OID,
         _start_key: &[u8],
         _end_key: &[u8],
-    ) -> RS<Vec<KvItem>> {
+        _options: &KvRangeOptions,
+    ) -> RS<KvRangePage> {
         unimplemented!()
     }
     async fn query(
@@ -0,0 +1,26 @@
+use async_trait::async_trait;
+use mudu::common::result::RS;
+use mudu_contract::protocol::{decode_incr_request, Frame, MessageType};
+
+use crate::server::async_func_task::HandleResult;
+use crate::server::message_dispatcher::MessageHandler;
+use crate::server::request_ctx::RequestCtx;
+
+pub(in crate::server) struct IncrHandler;
+
+#[async_trait]
+impl MessageHandler for IncrHandler {
+    fn message_type(&self) -> MessageType {
+        MessageType::Incr
+    }
+
+    async fn handle(&self, ctx: &RequestCtx, frame: &Frame) -> RS<HandleResult> {
+        let request = decode_incr_request(frame)?;
+        ctx.incr(
+            request.session_id(),
+            request.key().to_vec(),
+            request.delta(),
+        )
+        .await
+    }
+}
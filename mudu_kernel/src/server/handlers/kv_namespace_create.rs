@@ -0,0 +1,26 @@
+use async_trait::async_trait;
+use mudu::common::result::RS;
+use mudu_contract::protocol::{decode_kv_namespace_create_request, Frame, MessageType};
+
+use crate::server::async_func_task::HandleResult;
+use crate::server::message_dispatcher::MessageHandler;
+use crate::server::request_ctx::RequestCtx;
+
+pub(in crate::server) struct KvNamespaceCreateHandler;
+
+#[async_trait]
+impl MessageHandler for KvNamespaceCreateHandler {
+    fn message_type(&self) -> MessageType {
+        MessageType::KvNamespaceCreate
+    }
+
+    async fn handle(&self, ctx: &RequestCtx, frame: &Frame) -> RS<HandleResult> {
+        let request = decode_kv_namespace_create_request(frame)?;
+        ctx.kv_namespace_create(
+            request.session_id(),
+            request.namespace(),
+            request.partition_rule(),
+        )
+        .await
+    }
+}
@@ -0,0 +1,21 @@
+use async_trait::async_trait;
+use mudu::common::result::RS;
+use mudu_contract::protocol::{decode_kv_namespace_list_request, Frame, MessageType};
+
+use crate::server::async_func_task::HandleResult;
+use crate::server::message_dispatcher::MessageHandler;
+use crate::server::request_ctx::RequestCtx;
+
+pub(in crate::server) struct KvNamespaceListHandler;
+
+#[async_trait]
+impl MessageHandler for KvNamespaceListHandler {
+    fn message_type(&self) -> MessageType {
+        MessageType::KvNamespaceList
+    }
+
+    async fn handle(&self, ctx: &RequestCtx, frame: &Frame) -> RS<HandleResult> {
+        let request = decode_kv_namespace_list_request(frame)?;
+        ctx.kv_namespace_list(request.session_id()).await
+    }
+}
@@ -1,24 +1,52 @@
 mod batch;
+mod cas;
+mod cdc_subscribe;
+mod delete;
+mod delete_many;
 mod execute;
 mod get;
 mod handshake;
+mod incr;
+mod kv_namespace_create;
+mod kv_namespace_drop;
+mod kv_namespace_list;
 mod procedure_invoke;
 mod put;
+mod put_if_absent;
+mod put_many;
 mod query;
 mod range_scan;
 mod session_close;
 mod session_create;
+mod wal_fetch;
+mod watch_ack;
+mod watch_cancel;
+mod watch_register;
 
 #[cfg(test)]
 mod handshake_test;
 
 pub(in crate::server) use batch::BatchHandler;
+pub(in crate::server) use cas::CasHandler;
+pub(in crate::server) use cdc_subscribe::CdcSubscribeHandler;
+pub(in crate::server) use delete::DeleteHandler;
+pub(in crate::server) use delete_many::DeleteManyHandler;
 pub(in crate::server) use execute::ExecuteHandler;
 pub(in crate::server) use get::GetHandler;
 pub(in crate::server) use handshake::HandshakeHandler;
+pub(in crate::server) use incr::IncrHandler;
+pub(in crate::server) use kv_namespace_create::KvNamespaceCreateHandler;
+pub(in crate::server) use kv_namespace_drop::KvNamespaceDropHandler;
+pub(in crate::server) use kv_namespace_list::KvNamespaceListHandler;
 pub(in crate::server) use procedure_invoke::ProcedureInvokeHandler;
 pub(in crate::server) use put::PutHandler;
+pub(in crate::server) use put_if_absent::PutIfAbsentHandler;
+pub(in crate::server) use put_many::PutManyHandler;
 pub(in crate::server) use query::QueryHandler;
 pub(in crate::server) use range_scan::RangeScanHandler;
 pub(in crate::server) use session_close::SessionCloseHandler;
 pub(in crate::server) use session_create::SessionCreateHandler;
+pub(in crate::server) use wal_fetch::WalFetchHandler;
+pub(in crate::server) use watch_ack::WatchAckHandler;
+pub(in crate::server) use watch_cancel::WatchCancelHandler;
+pub(in crate::server) use watch_register::WatchRegisterHandler;
@@ -17,7 +17,10 @@ impl MessageHandler for PutHandler {
     async fn handle(&self, ctx: &RequestCtx, frame: &Frame) -> RS<HandleResult> {
         let request = decode_put_request(frame)?;
         let session_id = request.session_id();
+        let ttl = request.ttl();
+        let namespace = request.namespace().map(str::to_string);
         let (key, value) = request.into_parts();
-        ctx.put(session_id, key, value).await
+        ctx.put(session_id, namespace.as_deref(), key, value, ttl)
+            .await
     }
 }
@@ -0,0 +1,23 @@
+use async_trait::async_trait;
+use mudu::common::result::RS;
+use mudu_contract::protocol::{decode_put_if_absent_request, Frame, MessageType};
+
+use crate::server::async_func_task::HandleResult;
+use crate::server::message_dispatcher::MessageHandler;
+use crate::server::request_ctx::RequestCtx;
+
+pub(in crate::server) struct PutIfAbsentHandler;
+
+#[async_trait]
+impl MessageHandler for PutIfAbsentHandler {
+    fn message_type(&self) -> MessageType {
+        MessageType::PutIfAbsent
+    }
+
+    async fn handle(&self, ctx: &RequestCtx, frame: &Frame) -> RS<HandleResult> {
+        let request = decode_put_if_absent_request(frame)?;
+        let session_id = request.session_id();
+        let (key, value) = request.into_parts();
+        ctx.put_if_absent(session_id, key, value).await
+    }
+}
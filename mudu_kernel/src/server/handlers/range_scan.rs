@@ -16,7 +16,13 @@ impl MessageHandler for RangeScanHandler {
 
     async fn handle(&self, ctx: &RequestCtx, frame: &Frame) -> RS<HandleResult> {
         let request = decode_range_scan_request(frame)?;
-        ctx.range_scan(request.session_id(), request.start_key(), request.end_key())
-            .await
+        ctx.range_scan(
+            request.session_id(),
+            request.namespace(),
+            request.start_key(),
+            request.end_key(),
+            request.options(),
+        )
+        .await
     }
 }
@@ -0,0 +1,114 @@
+//! Per-key TTL of the worker kv store.
+//!
+//! The TTL travels out of band: a staged write logs it in
+//! [`XLInsert::ttl_ms`] and leaves the value untouched. Every commit is
+//! stamped with a time from the worker's [`CommitClock`], logged as
+//! [`XLEntry::commit_ms`], and the deadline of each value the commit writes
+//! is that commit time plus its TTL. The deadline ends up in the row header
+//! of the kv version, see [`VersionTuple::expires_at`]. Replay and replicas
+//! compute it from the logged commit time, so they never consult their own
+//! wall clock to decide when a key expires.
+//!
+//! Reads skip expired versions; the worker loop's sweeper then deletes
+//! expired keys for good, see [`KV_TTL_SWEEP_BATCH_ROWS`].
+//!
+//! [`XLInsert::ttl_ms`]: crate::wal::xl_data_op::XLInsert::ttl_ms
+//! [`XLEntry::commit_ms`]: crate::wal::xl_entry::XLEntry::commit_ms
+//! [`VersionTuple::expires_at`]: crate::contract::version_tuple::VersionTuple::expires_at
+
+use mudu::common::result::RS;
+use mudu::error::ErrorCode;
+use mudu::mudu_error;
+use mudu_sys::time::system_time_now;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::time::{Duration, UNIX_EPOCH};
+
+/// Kv rows one sweeper step visits before handing control back to the
+/// worker loop.
+pub(crate) const KV_TTL_SWEEP_BATCH_ROWS: usize = 256;
+
+/// Current wall-clock time in Unix milliseconds.
+pub(crate) fn now_ms() -> u64 {
+    system_time_now()
+        .duration_since(UNIX_EPOCH)
+        .unwrap_or_default()
+        .as_millis()
+        .min(u64::MAX as u128) as u64
+}
+
+/// The TTL of a write in milliseconds; a TTL below one millisecond is
+/// rejected.
+pub(crate) fn ttl_ms(ttl: Duration) -> RS<u64> {
+    let ttl_ms = ttl.as_millis().min(u64::MAX as u128) as u64;
+    if ttl_ms == 0 {
+        return Err(mudu_error!(
+            ErrorCode::InvalidArgument,
+            "kv ttl must be at least one millisecond"
+        ));
+    }
+    Ok(ttl_ms)
+}
+
+/// Deadline of a value written with `ttl_ms` by a commit at `commit_ms`.
+pub(crate) fn deadline(commit_ms: u64, ttl_ms: u64) -> u64 {
+    commit_ms.saturating_add(ttl_ms)
+}
+
+/// Source of commit times.
+///
+/// Commit times never go backwards, even when the wall clock does or when
+/// replay observed commits stamped by a faster clock: a later commit never
+/// gets an earlier deadline than the same TTL written before it.
+#[derive(Debug, Default)]
+pub(crate) struct CommitClock {
+    last_ms: AtomicU64,
+}
+
+impl CommitClock {
+    /// Commit time of a transaction committing now.
+    pub(crate) fn stamp(&self) -> u64 {
+        let now_ms = now_ms();
+        let last_ms = self.last_ms.fetch_max(now_ms, Ordering::Relaxed);
+        last_ms.max(now_ms)
+    }
+
+    /// Accounts for a commit time read back from the log.
+    pub(crate) fn observe(&self, commit_ms: u64) {
+        self.last_ms.fetch_max(commit_ms, Ordering::Relaxed);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{deadline, now_ms, ttl_ms, CommitClock};
+    use mudu::common::result::RS;
+    use std::time::Duration;
+
+    #[test]
+    fn ttl_counts_from_commit_time() -> RS<()> {
+        let ttl_ms = ttl_ms(Duration::from_millis(50))?;
+        assert_eq!(deadline(1_000, ttl_ms), 1_050);
+        assert_eq!(deadline(u64::MAX, ttl_ms), u64::MAX);
+        Ok(())
+    }
+
+    #[test]
+    fn zero_ttl_is_rejected() {
+        assert!(ttl_ms(Duration::ZERO).is_err());
+        assert!(ttl_ms(Duration::from_micros(999)).is_err());
+    }
+
+    #[test]
+    fn commit_clock_never_goes_backwards() {
+        let clock = CommitClock::default();
+        let first = clock.stamp();
+        assert!(first <= now_ms());
+        assert!(clock.stamp() >= first);
+
+        let future = now_ms() + 3_600_000;
+        clock.observe(future);
+        assert_eq!(clock.stamp(), future);
+        clock.observe(first);
+        assert_eq!(clock.stamp(), future);
+    }
+}
@@ -3,7 +3,7 @@ use crate::server::frame_dispatch::dispatch_frame_async;
 use crate::server::protocol_codec::{read_next_frame, write_response};
 use crate::server::worker::WorkerRuntime;
 use mudu::common::result::RS;
-use mudu_contract::protocol::encode_merror_response;
+use mudu_contract::protocol::{encode_merror_response, MessageType};
 use mudu_sys::io::socket::{close, IoSocket};
 use mudu_sys::server::worker_task::WorkerTaskFuture;
 use mudu_sys::task::async_::current_poll_task_id;
@@ -14,6 +14,7 @@ use std::os::fd::RawFd;
 use std::sync::Arc;
 use tracing::trace;
 
+use mudu_sys::sync::async_::futures_mutex::{FMutex, FMutexGuard};
 use mudu_sys::sync::SMutex;
 
 fn watch_conn(key: &str, value: &str) {
@@ -59,6 +60,33 @@ async fn run_connection_worker_task(
     let _ = connections.lock()?.remove(&conn_id);
     r
 }
+
+/// Writes the watch events due on the watched connections of `worker`.
+/// The connection's write gate keeps an event from interleaving with a
+/// response or from reaching a socket the connection task closed.
+pub(in crate::server) async fn push_watch_events(
+    worker: WorkerRuntime,
+    connections: Arc<SMutex<HashMap<u64, RawFd>>>,
+) -> RS<()> {
+    mudu_utils::scoped_task_trace!();
+    for conn_id in worker.watched_connections()? {
+        let Some(fd) = connections.lock()?.get(&conn_id).copied() else {
+            continue;
+        };
+        let gate = worker.watch_write_gate(conn_id)?;
+        let _gate = gate.lock().await;
+        if !worker.has_watches(conn_id)? {
+            continue;
+        }
+        let socket = IoSocket::from_raw_fd(fd);
+        for event in worker.poll_watch_events_async(conn_id).await? {
+            trace!(conn_id, bytes = event.len(), "pushing watch event");
+            write_response(&socket, &event).await?;
+        }
+    }
+    Ok(())
+}
+
 async fn _run_connection_worker_task(
     worker: WorkerRuntime,
     conn_id: u64,
@@ -68,6 +96,9 @@ async fn _run_connection_worker_task(
 ) -> RS<()> {
     mudu_utils::scoped_task_trace!();
     let mut read_buf = Vec::with_capacity(8192);
+    // Taken by the first watch registration; from then on every write and
+    // the close hold it, see `push_watch_events`.
+    let mut write_gate: Option<Arc<FMutex<()>>> = None;
     trace!(
         conn_id,
         remote_addr = %remote_addr,
@@ -92,6 +123,7 @@ async fn _run_connection_worker_task(
             Ok(None) => {
                 watch_conn("conn.phase", "close_socket");
                 trace!(conn_id, "connection closed by peer");
+                let _gate = lock_write_gate(&write_gate).await;
                 close(socket).await?;
                 watch_conn("conn.phase", "close_connection_sessions");
                 worker.close_connection_sessions(conn_id)?;
@@ -100,7 +132,11 @@ async fn _run_connection_worker_task(
             Err(err) => {
                 watch_conn("conn.phase", "read_frame_error_close_socket");
                 trace!(conn_id, error = %err, "read protocol frame failed");
+                let _gate = lock_write_gate(&write_gate).await;
                 let _ = close(socket).await;
+                if write_gate.is_some() {
+                    worker.close_connection_sessions(conn_id)?;
+                }
                 return Err(err);
             }
         };
@@ -119,7 +155,12 @@ async fn _run_connection_worker_task(
             payload_len = frame.header().payload_len(),
             "received protocol frame"
         );
-        match dispatch_frame_async(&worker, conn_id, &frame).await {
+        if write_gate.is_none() && frame.header().message_type() == MessageType::WatchRegister {
+            write_gate = Some(worker.watch_write_gate(conn_id)?);
+        }
+        let result = dispatch_frame_async(&worker, conn_id, &frame).await;
+        let _gate = lock_write_gate(&write_gate).await;
+        match result {
             Ok(HandleResult::Response(response)) => {
                 watch_conn("conn.phase", "write_response");
                 trace!(
@@ -148,3 +189,10 @@ async fn _run_connection_worker_task(
     trace!(conn_id, "io_uring connection worker stopped");
     Ok(())
 }
+
+async fn lock_write_gate(write_gate: &Option<Arc<FMutex<()>>>) -> Option<FMutexGuard<'_, ()>> {
+    match write_gate {
+        Some(gate) => Some(gate.lock().await),
+        None => None,
+    }
+}
@@ -44,6 +44,7 @@ pub(crate) fn sync_serve_iouring(
             "invalid io_uring worker count"
         ));
     }
+    cfg.cfg().check_page_size()?;
     let sys = SysIoContext::iouring();
     let conn_id_alloc = Arc::new(AtomicU64::new(1));
     let mailboxes: Vec<_> = (0..cfg.cfg().worker_count())
@@ -109,9 +110,20 @@ pub(crate) fn sync_serve_iouring(
                 )
             })?;
         let worker_registry = cfg.deps().worker_registry();
+        let backups = cfg.deps().backup_coordinator();
         let data_dir = cfg.cfg().data_dir().to_string();
         let log_dir = cfg.cfg().log_dir().to_string();
         let log_chunk_size = cfg.cfg().log_chunk_size();
+        let history_retention = cfg.cfg().history_retention();
+        let checkpoint_policy = cfg.cfg().checkpoint_policy();
+        let async_commit_flush_interval = cfg.cfg().async_commit_flush_interval();
+        let buffer_pool_bytes = cfg.cfg().buffer_pool_bytes();
+        let page_compression = cfg.cfg().page_compression();
+        let wal_compression = cfg.cfg().wal_compression();
+        let keyring = cfg.deps().keyring();
+        let wal_archive_dir = cfg.cfg().wal_archive_dir().map(str::to_string);
+        let recovery_target = cfg.cfg().recovery_target();
+        let read_replica = cfg.cfg().read_replica();
         let log_batching = cfg.deps().log_batching();
         let worker_count = cfg.cfg().worker_count();
         let server_instance_id = cfg.cfg().server_instance_id();
@@ -170,9 +182,20 @@ pub(crate) fn sync_serve_iouring(
                             log_dir: log_dir.clone(),
                             data_dir: data_dir.clone(),
                             log_chunk_size,
+                            history_retention,
+                            checkpoint_policy,
+                            async_commit_flush_interval,
+                            buffer_pool_bytes,
+                            page_compression,
+                            wal_compression,
+                            keyring,
+                            wal_archive_dir,
+                            recovery_target,
+                            read_replica,
                             log_batching,
                             procedure_runtime,
                             registry: worker_registry,
+                            backups,
                             async_runtime,
                             server_instance_id,
                         });
@@ -356,7 +379,9 @@ fn log_worker_stats(stats: &[WorkerLoopStats]) {
             accept_submit={}, mailbox_submit={}, recv_submit={}, send_submit={}, \
             log_write_submit={}, cqe_accept={}, cqe_mailbox={}, cqe_recv={}, cqe_send={}, \
             cqe_log_write={}, cqe_close={}, recv_queue_push={}, recv_queue_pop={}, \
-            send_queue_push={}, send_queue_pop={}, mailbox_drained={}, local_register={}",
+            send_queue_push={}, send_queue_pop={}, mailbox_drained={}, local_register={}, \n\
+            buffer_pool_hits={}, buffer_pool_misses={}, buffer_pool_evictions={}, \
+            page_compression_ratio={:.2}, wal_compression_ratio={:.2}",
             stat.worker_id,
             stat.submit_calls,
             stat.wait_cqe_calls,
@@ -377,6 +402,11 @@ fn log_worker_stats(stats: &[WorkerLoopStats]) {
             stat.send_queue_pop,
             stat.mailbox_drained,
             stat.local_register,
+            stat.buffer_pool_hits,
+            stat.buffer_pool_misses,
+            stat.buffer_pool_evictions,
+            stat.page_compression_ratio,
+            stat.wal_compression_ratio,
         );
     }
 }
@@ -1,9 +1,10 @@
+use crate::server::background_ticker::BackgroundTicker;
 #[cfg(test)]
 use crate::server::callback_registry::{
     AsyncCallback, CallbackDomain, CallbackEventKey, CallbackId, CallbackRegistry, CallbackTrigger,
     PendingCallback,
 };
-use crate::server::connection_worker_task::spawn_connection_worker_task;
+use crate::server::connection_worker_task::{push_watch_events, spawn_connection_worker_task};
 use crate::server::inflight_op::{AcceptOp, InflightOp};
 use crate::server::loop_mailbox::{
     drain_messages, handle_read_completion, submit_read_if_needed, LoopMailboxSubmitCtx,
@@ -22,10 +23,14 @@ use crate::server::session_bound_worker_runtime::{
     as_worker_local_ref, new_session_bound_worker_runtime,
 };
 use crate::server::task;
+use crate::server::version_gc::VERSION_GC_INTERVAL;
 use crate::server::worker::WorkerRuntime;
+use crate::server::worker_checkpoint::CHECKPOINT_POLL_INTERVAL;
 use crate::server::worker_local::{set_current_worker_local, unset_current_worker_local};
 use crate::server::worker_loop_stats::WorkerLoopStats;
 use crate::server::worker_mailbox::WorkerMailboxMsg;
+use crate::server::worker_watch::WATCH_PUSH_INTERVAL;
+use crate::storage::encryption::REENCRYPT_INTERVAL;
 use mudu_sys::io::worker_ring::{
     set_current_worker_ring, unset_current_worker_ring, WorkerLocalRing,
 };
@@ -90,6 +95,12 @@ pub(in crate::server) struct WorkerRingLoop {
     accept_submitted: bool,
     stop: Arc<AtomicBool>,
     stats: WorkerLoopStats,
+    version_gc: BackgroundTicker,
+    checkpoint: BackgroundTicker,
+    // Runs only for a worker with an encryption keyring.
+    reencrypt: Option<BackgroundTicker>,
+    watch_push: BackgroundTicker,
+    async_commit_flush: BackgroundTicker,
 }
 
 #[cfg(test)]
@@ -168,6 +179,10 @@ impl WorkerRingLoop {
             worker_local_ring,
         } = args;
         let worker_id = worker.worker_index();
+        let async_commit_flush_interval = worker.async_commit_flush_interval();
+        let reencrypt = worker
+            .is_encrypted()
+            .then(|| BackgroundTicker::new(REENCRYPT_INTERVAL));
         let log = worker.worker_log()?.map(|backend| {
             new_xl_batch_worker_log(
                 backend.clone(),
@@ -209,6 +224,11 @@ impl WorkerRingLoop {
                 worker_id,
                 ..WorkerLoopStats::default()
             },
+            version_gc: BackgroundTicker::new(VERSION_GC_INTERVAL),
+            checkpoint: BackgroundTicker::new(CHECKPOINT_POLL_INTERVAL),
+            reencrypt,
+            watch_push: BackgroundTicker::new(WATCH_PUSH_INTERVAL),
+            async_commit_flush: BackgroundTicker::new(async_commit_flush_interval),
         })
     }
 
@@ -758,8 +778,11 @@ mod tests {
     use super::*;
     use crate::server::callback_registry::{CallbackDomain, CallbackEventKey, CallbackTrigger};
     use crate::server::worker::WorkerRuntimeParams;
+    use crate::server::worker_backup::BackupCoordinator;
     use crate::server::worker_registry::load_or_create_worker_registry;
+    use crate::storage::buffer_pool::DEFAULT_BUFFER_POOL_BYTES;
     use crate::wal::worker_log::WorkerLogBatching;
+    use crate::x_engine::tx_mgr::DEFAULT_ASYNC_COMMIT_FLUSH_INTERVAL;
     use mudu_sys::env_var::temp_dir;
     use mudu_sys::imp::native::linux::io_uring::file::{close, flush, open, read, write};
     use mudu_sys::io::socket::{
@@ -785,9 +808,20 @@ mod tests {
             log_dir: dir.clone(),
             data_dir: dir.clone(),
             log_chunk_size: 4096,
+            history_retention: Duration::ZERO,
+            checkpoint_policy: Default::default(),
+            async_commit_flush_interval: DEFAULT_ASYNC_COMMIT_FLUSH_INTERVAL,
+            buffer_pool_bytes: DEFAULT_BUFFER_POOL_BYTES,
+            page_compression: Default::default(),
+            wal_compression: Default::default(),
+            keyring: None,
+            wal_archive_dir: None,
+            recovery_target: Default::default(),
+            read_replica: false,
             log_batching: WorkerLogBatching::default(),
             procedure_runtime: None,
             registry,
+            backups: Arc::new(BackupCoordinator::new()),
             async_runtime: None,
             server_instance_id: 0,
         })
@@ -12,8 +12,8 @@ pub(super) struct WorkerRingLoopRecoveryHandler {
 
 #[async_trait]
 impl WorkerLogRecoveryHandler<XLBatch> for WorkerRingLoopRecoveryHandler {
-    async fn handle_entry(&self, entry: XLBatch, _start_lsn: LSN) -> RS<()> {
-        self.worker.replay_log_batch(entry).await
+    async fn handle_entry(&self, entry: XLBatch, start_lsn: LSN) -> RS<()> {
+        self.worker.replay_log_batch(entry, start_lsn).await
     }
 
     fn finish(&self) -> RS<()> {
@@ -40,8 +40,8 @@ impl WorkerLogRecoverySource for WorkerRingLoopRecoverySource {
 }
 
 impl WorkerRingLoop {
-    /// Replays persisted worker-log chunks before the worker starts serving
-    /// live traffic.
+    /// Replays persisted worker-log chunks after the restored checkpoint
+    /// before the worker starts serving live traffic.
     pub(super) fn recover_worker_log_on_loop(&mut self) -> RS<()> {
         let log = match self.log.take() {
             Some(log) => log,
@@ -50,9 +50,14 @@ impl WorkerRingLoop {
         let worker_id = self.worker.worker_id();
         trace!(worker_id, "worker_ring_loop recover_worker_log start");
         let backend = log.backend().clone();
+        let start_lsn = self.worker.log_recovery_start_lsn();
+        let worker = self.worker.clone();
         let recovery = async move {
             let mut source = WorkerRingLoopRecoverySource { backend };
-            let result = log.recover(&mut source).await;
+            let result = match log.recover_from(&mut source, start_lsn).await {
+                Ok(()) => worker.finish_recovery_target_async().await,
+                Err(err) => Err(err),
+            };
             Ok((log, result))
         };
         let (log, result) = self.drive_local_future(recovery, "worker log recovery")?;
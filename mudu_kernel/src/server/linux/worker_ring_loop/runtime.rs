@@ -6,7 +6,8 @@ impl WorkerRingLoop {
     /// Each iteration:
     /// 1. reacts to shutdown,
     /// 2. drains mailbox work,
-    /// 3. advances connection tasks and log flushing,
+    /// 3. advances connection tasks, log flushing, version GC, checkpoints,
+    ///    re-encryption and watch event pushes,
     /// 4. submits any missing io_uring operations,
     /// 5. waits for and dispatches completions.
     pub(super) fn run_service_loop(&mut self) -> RS<WorkerLoopStats> {
@@ -26,10 +27,18 @@ impl WorkerRingLoop {
             if self.shutting_down {
                 self.force_flush_log()?;
             }
+            self.worker_local_ring.fire_due_timers()?;
             self.worker_local_ring
                 .worker_task_registry()
                 .drain_completions();
             self.poll_ready_worker_tasks()?;
+            if !self.shutting_down {
+                self.spawn_version_gc_if_due();
+                self.spawn_checkpoint_if_due();
+                self.spawn_reencrypt_if_due();
+                self.spawn_watch_push_if_due()?;
+                self.spawn_async_commit_flush_if_due();
+            }
             self.submit_mailbox_read_if_needed()?;
             self.submit_accept_if_needed()?;
             self.submit_user_ring_io_if_needed()?;
@@ -153,11 +162,128 @@ impl WorkerRingLoop {
 
     fn finish_shutdown(&mut self) -> RS<WorkerLoopStats> {
         self.ring.exit();
+        let buffer_pool = self.worker.buffer_pool_stats()?;
+        self.stats.buffer_pool_hits = buffer_pool.hits;
+        self.stats.buffer_pool_misses = buffer_pool.misses;
+        self.stats.buffer_pool_evictions = buffer_pool.evictions;
+        self.stats.page_compression_ratio = self.worker.page_compression_stats().ratio();
+        self.stats.wal_compression_ratio = self.worker.wal_compression_stats()?.ratio();
         Ok(self.stats.clone())
     }
 
+    /// Spawns the next version GC step, followed by a kv TTL sweeper step,
+    /// as a system task. Each step visits a bounded number of rows, so
+    /// request handling interleaves with GC.
+    fn spawn_version_gc_if_due(&mut self) {
+        let Some(run) = self.version_gc.poll() else {
+            return;
+        };
+        let worker = self.worker.clone();
+        self.spawn(None, async move {
+            let _run = run;
+            worker.collect_version_garbage_async().await?;
+            worker.sweep_expired_kv_async().await.map(|_| ())
+        });
+    }
+
+    /// Spawns a checkpoint check as a system task. The checkpoint itself
+    /// only runs once the configured interval or log volume is reached. The
+    /// same task applies replicated log frames and serves a pending online
+    /// backup.
+    fn spawn_checkpoint_if_due(&mut self) {
+        let Some(run) = self.checkpoint.poll() else {
+            return;
+        };
+        let worker = self.worker.clone();
+        self.spawn(None, async move {
+            let _run = run;
+            worker.replicate_async().await?;
+            worker.backup_if_requested_async().await?;
+            worker.checkpoint_if_due_async().await.map(|_| ())
+        });
+    }
+
+    /// Spawns the next re-encryption step as a system task while pages
+    /// sealed with an older key may remain.
+    fn spawn_reencrypt_if_due(&mut self) {
+        let Some(run) = self.reencrypt.as_mut().and_then(BackgroundTicker::poll) else {
+            return;
+        };
+        let worker = self.worker.clone();
+        self.spawn(None, async move {
+            let _run = run;
+            worker.reencrypt_step_async().await.map(|_| ())
+        });
+    }
+
+    /// Spawns a system task that pushes the due watch events of every
+    /// watched connection. A connection task cannot wait for a read and a
+    /// timer at once, so the push runs beside it.
+    fn spawn_watch_push_if_due(&mut self) -> RS<()> {
+        if self.worker.watched_connections()?.is_empty() {
+            return Ok(());
+        }
+        let Some(run) = self.watch_push.poll() else {
+            return Ok(());
+        };
+        let worker = self.worker.clone();
+        let connections = self.connection_task_fds.clone();
+        self.spawn(None, async move {
+            let _run = run;
+            push_watch_events(worker, connections).await
+        });
+        Ok(())
+    }
+
+    /// Spawns a system task that fsyncs the worker log when commits with
+    /// asynchronous durability wrote to it since the last fsync.
+    fn spawn_async_commit_flush_if_due(&mut self) {
+        let Some(run) = self.async_commit_flush.poll() else {
+            return;
+        };
+        let worker = self.worker.clone();
+        self.spawn(None, async move {
+            let _run = run;
+            worker.flush_async_commits_async().await.map(|_| ())
+        });
+    }
+
     fn wait_for_cqe(&mut self) -> RS<Result<mudu_sys::io::iouring::Cqe, i32>> {
-        if let Some(timeout) = self.log_flush_wait_timeout()? {
+        // Wake up for the next version GC step, checkpoint check,
+        // re-encryption step, watch push or async commit flush even when no
+        // I/O completes.
+        let gc_timeout = if self.shutting_down {
+            None
+        } else {
+            let watch_push_timeout = if self.worker.watched_connections()?.is_empty() {
+                None
+            } else {
+                self.watch_push.next_timeout()
+            };
+            [
+                self.version_gc.next_timeout(),
+                self.checkpoint.next_timeout(),
+                self.reencrypt
+                    .as_ref()
+                    .and_then(BackgroundTicker::next_timeout),
+                watch_push_timeout,
+                self.async_commit_flush.next_timeout(),
+            ]
+            .into_iter()
+            .flatten()
+            .min()
+        };
+        // Sleeps, lock waits and statement timeouts of worker tasks run on
+        // the ring's timers, which keep firing while shutting down.
+        let timeout = [
+            self.log_flush_wait_timeout()?,
+            gc_timeout,
+            self.worker_local_ring.next_timer_timeout()?,
+        ]
+        .into_iter()
+        .flatten()
+        .min();
+        if let Some(timeout) = timeout {
             trace!(
                 timeout_us = timeout.as_micros() as u64,
                 "worker_ring_loop wait_for_cqe_timeout"
@@ -2,8 +2,12 @@ use std::sync::OnceLock;
 
 use crate::server::async_func_task::HandleResult;
 use crate::server::handlers::{
-    BatchHandler, ExecuteHandler, GetHandler, HandshakeHandler, ProcedureInvokeHandler, PutHandler,
-    QueryHandler, RangeScanHandler, SessionCloseHandler, SessionCreateHandler,
+    BatchHandler, CasHandler, CdcSubscribeHandler, DeleteHandler, DeleteManyHandler,
+    ExecuteHandler, GetHandler, HandshakeHandler, IncrHandler, KvNamespaceCreateHandler,
+    KvNamespaceDropHandler, KvNamespaceListHandler, ProcedureInvokeHandler, PutHandler,
+    PutIfAbsentHandler, PutManyHandler, QueryHandler, RangeScanHandler, SessionCloseHandler,
+    SessionCreateHandler, WalFetchHandler, WatchAckHandler, WatchCancelHandler,
+    WatchRegisterHandler,
 };
 use crate::server::request_ctx::RequestCtx;
 use async_trait::async_trait;
@@ -35,10 +39,24 @@ impl MessageDispatcher {
         register(&mut handlers, Box::new(BatchHandler));
         register(&mut handlers, Box::new(GetHandler));
         register(&mut handlers, Box::new(PutHandler));
+        register(&mut handlers, Box::new(DeleteHandler));
+        register(&mut handlers, Box::new(PutManyHandler));
+        register(&mut handlers, Box::new(DeleteManyHandler));
+        register(&mut handlers, Box::new(CasHandler));
+        register(&mut handlers, Box::new(IncrHandler));
+        register(&mut handlers, Box::new(PutIfAbsentHandler));
         register(&mut handlers, Box::new(RangeScanHandler));
+        register(&mut handlers, Box::new(KvNamespaceCreateHandler));
+        register(&mut handlers, Box::new(KvNamespaceDropHandler));
+        register(&mut handlers, Box::new(KvNamespaceListHandler));
         register(&mut handlers, Box::new(ProcedureInvokeHandler));
         register(&mut handlers, Box::new(SessionCreateHandler));
         register(&mut handlers, Box::new(SessionCloseHandler));
+        register(&mut handlers, Box::new(CdcSubscribeHandler));
+        register(&mut handlers, Box::new(WalFetchHandler));
+        register(&mut handlers, Box::new(WatchRegisterHandler));
+        register(&mut handlers, Box::new(WatchAckHandler));
+        register(&mut handlers, Box::new(WatchCancelHandler));
         Self { handlers }
     }
 
@@ -9,6 +9,8 @@
 #![allow(clippy::module_inception)]
 pub mod async_func_runtime;
 mod async_func_task;
+#[cfg(target_os = "linux")]
+mod background_ticker;
 #[cfg(all(test, target_os = "linux"))]
 #[path = "linux/callback_registry.rs"]
 mod callback_registry;
@@ -21,6 +23,7 @@ mod handlers;
 #[cfg(target_os = "linux")]
 #[path = "linux/inflight_op.rs"]
 mod inflight_op;
+mod kv_ttl;
 #[cfg(target_os = "linux")]
 #[path = "linux/loop_mailbox.rs"]
 mod loop_mailbox;
@@ -54,17 +57,25 @@ mod server_iouring;
 pub mod server_launch;
 pub mod server_runtime_deps;
 mod session_bound_worker_runtime;
+pub mod session_options;
+mod ssi_mgr;
 mod task;
+pub mod version_gc;
 #[cfg(test)]
 #[allow(clippy::unwrap_used, clippy::expect_used, clippy::panic)]
 pub(crate) mod test_meta_mgr;
 pub mod worker;
+pub mod worker_backup;
+pub mod worker_cdc;
+pub mod worker_checkpoint;
 pub mod worker_local;
 mod worker_loop_stats;
 #[cfg(target_os = "linux")]
 #[path = "linux/worker_mailbox.rs"]
 mod worker_mailbox;
+pub mod worker_recovery_target;
 pub mod worker_registry;
+pub mod worker_replication;
 #[cfg(target_os = "linux")]
 #[path = "linux/worker_ring_loop.rs"]
 mod worker_ring_loop;
@@ -72,5 +83,6 @@ mod worker_session_manager;
 pub mod worker_snapshot;
 mod worker_storage;
 mod worker_tx_manager;
+pub mod worker_watch;
 pub mod x_contract;
 mod x_lock_mgr;
@@ -7,7 +7,7 @@ use mudu::common::result::RS;
 use mudu::error::ErrorCode;
 use mudu::mudu_error;
 use mudu_contract::tuple::build_tuple::build_tuple;
-use mudu_contract::tuple::comparator::tuple_compare;
+use mudu_contract::tuple::comparator::{binary_field_key_form, tuple_compare};
 use mudu_contract::tuple::tuple_binary_desc::TupleBinaryDesc;
 use mudu_type::dat_type_id::DatTypeID;
 use mudu_type::datum::DatumDyn;
@@ -157,6 +157,83 @@ impl PartitionRouter {
         }
         Ok(partitions)
     }
+
+    /// Routes a raw KV key of a namespace bound to `rule`. Keys compare with
+    /// the rule's bounds byte by byte, see [`ensure_kv_partition_rule`].
+    pub fn route_kv_key(&self, rule: &PartitionRuleDesc, key: &[u8]) -> RS<OID> {
+        ensure_kv_partition_rule(rule)?;
+        for partition in &rule.partitions {
+            let after_start = match &partition.start {
+                PartitionBound::Unbounded => true,
+                PartitionBound::Value(values) => key >= kv_bound(values)?.as_slice(),
+            };
+            let before_end = match &partition.end {
+                PartitionBound::Unbounded => true,
+                PartitionBound::Value(values) => key < kv_bound(values)?.as_slice(),
+            };
+            if after_start && before_end {
+                return Ok(partition.partition_id);
+            }
+        }
+        Err(mudu_error!(
+            ErrorCode::EntityNotFound,
+            format!("no partition matched rule {} kv key", rule.name)
+        ))
+    }
+
+    /// Returns the partitions of `rule` that may hold keys of
+    /// `[start_key, end_key)`; an empty end key is unbounded.
+    pub fn route_kv_range(
+        &self,
+        rule: &PartitionRuleDesc,
+        start_key: &[u8],
+        end_key: &[u8],
+    ) -> RS<Vec<OID>> {
+        ensure_kv_partition_rule(rule)?;
+        let mut partitions = Vec::new();
+        for partition in &rule.partitions {
+            let start_ok = match &partition.start {
+                PartitionBound::Unbounded => true,
+                PartitionBound::Value(_) if end_key.is_empty() => true,
+                PartitionBound::Value(values) => end_key > kv_bound(values)?.as_slice(),
+            };
+            let end_ok = match &partition.end {
+                PartitionBound::Unbounded => true,
+                PartitionBound::Value(values) => start_key < kv_bound(values)?.as_slice(),
+            };
+            if start_ok && end_ok {
+                partitions.push(partition.partition_id);
+            }
+        }
+        Ok(partitions)
+    }
+}
+
+/// Checks that `rule` can partition a KV namespace: raw keys have no type,
+/// so the rule needs a single string key whose bounds are used as bytes.
+pub(crate) fn ensure_kv_partition_rule(rule: &PartitionRuleDesc) -> RS<()> {
+    if rule.key_types != [DatTypeID::String] {
+        return Err(mudu_error!(
+            ErrorCode::InvalidArgument,
+            format!(
+                "partition rule {} must have a single string key to partition kv keys",
+                rule.name
+            )
+        ));
+    }
+    Ok(())
+}
+
+fn kv_bound(values: &[Vec<u8>]) -> RS<Vec<u8>> {
+    let [value] = values else {
+        return Err(mudu_error!(
+            ErrorCode::InvalidTuple,
+            "partition bound width mismatch"
+        ));
+    };
+    let text = std::str::from_utf8(value)
+        .map_err(|e| mudu_error!(ErrorCode::Decode, "partition bound text is not utf8", e))?;
+    Ok(strip_text_literal_quotes(text.trim()).into_bytes())
 }
 
 fn build_rule_tuple_desc(key_types: &[DatTypeID]) -> RS<TupleBinaryDesc> {
@@ -247,7 +324,18 @@ fn build_route_tuple(table_desc: &TableDesc, ref_attrs: &[usize], key: &VecDatum
             })?;
         values.push(binary);
     }
-    build_tuple(&values, &build_route_tuple_desc(table_desc, ref_attrs)?)
+    let route_desc = build_route_tuple_desc(table_desc, ref_attrs)?;
+    // Route on key forms, like the key tuples the partition stores, so keys
+    // equal under their collation land on one partition.
+    let values = values
+        .iter()
+        .enumerate()
+        .map(|(index, value)| {
+            let ty = route_desc.get_field_desc(index).type_obj();
+            binary_field_key_form(ty, value).map(|value| value.into_owned())
+        })
+        .collect::<RS<Vec<_>>>()?;
+    build_tuple(&values, &route_desc)
 }
 
 fn build_route_bound_tuple(
@@ -299,6 +387,11 @@ fn textual_to_binary(
         .map_err(|e| mudu_error!(ErrorCode::Decode, "partition bound text is not utf8", e))?;
     let normalized = strip_text_literal_quotes(text.trim());
     let datum: Box<dyn DatumDyn> = match data_type_id {
+        DatTypeID::Bool => Box::new(<bool as mudu_type::datum::Datum>::from_textual(
+            &normalized,
+        )?),
+        DatTypeID::I8 => Box::new(<i8 as mudu_type::datum::Datum>::from_textual(&normalized)?),
+        DatTypeID::I16 => Box::new(<i16 as mudu_type::datum::Datum>::from_textual(&normalized)?),
         DatTypeID::I32 => Box::new(<i32 as mudu_type::datum::Datum>::from_textual(&normalized)?),
         DatTypeID::I64 => Box::new(<i64 as mudu_type::datum::Datum>::from_textual(&normalized)?),
         DatTypeID::I128 => Box::new(<i128 as mudu_type::datum::Datum>::from_textual(
@@ -309,9 +402,13 @@ fn textual_to_binary(
         )?),
         DatTypeID::F32 => Box::new(<f32 as mudu_type::datum::Datum>::from_textual(&normalized)?),
         DatTypeID::F64 => Box::new(<f64 as mudu_type::datum::Datum>::from_textual(&normalized)?),
-        DatTypeID::String => Box::new(<String as mudu_type::datum::Datum>::from_textual(
-            &normalized,
-        )?),
+        // The bound is the literal's text with its quotes stripped, not the
+        // JSON textual form `from_textual` expects.
+        DatTypeID::String => Box::new(normalized),
+        DatTypeID::Uuid => Box::new(
+            mudu::data_type::uuid::UuidValue::parse(&normalized)
+                .map_err(|e| mudu_error!(ErrorCode::TypeConversionFailed, e))?,
+        ),
         _ => {
             return Err(mudu_error!(
                 ErrorCode::NotImplemented,
@@ -349,7 +446,10 @@ mod tests {
     use async_trait::async_trait;
     use mudu::common::id::AttrIndex;
     use mudu::error::ErrorCode;
+    use mudu_type::collation::Collation;
+    use mudu_type::dat_type::DatType;
     use mudu_type::dt_info::DTInfo;
+    use mudu_type::dtp_string::DTPString;
 
     struct TestMetaMgr {
         table_desc: Arc<TableDesc>,
@@ -510,6 +610,25 @@ mod tests {
         )
     }
 
+    fn kv_rule() -> PartitionRuleDesc {
+        PartitionRuleDesc::new_range(
+            "kv".to_string(),
+            vec![DatTypeID::String],
+            vec![
+                RangePartitionDef::new(
+                    "p1".to_string(),
+                    PartitionBound::Unbounded,
+                    PartitionBound::Value(vec![v("'m'")]),
+                ),
+                RangePartitionDef::new(
+                    "p2".to_string(),
+                    PartitionBound::Value(vec![v("'m'")]),
+                    PartitionBound::Unbounded,
+                ),
+            ],
+        )
+    }
+
     fn partitioned_meta_mgr(
         table_desc: Arc<TableDesc>,
         rule: PartitionRuleDesc,
@@ -746,4 +865,84 @@ mod tests {
             .unwrap_err();
         assert_eq!(err.ec(), ErrorCode::NotImplemented);
     }
+
+    #[test]
+    fn route_kv_key_and_range_compare_bytes() {
+        let rule = kv_rule();
+        let ids: Vec<OID> = rule.partitions.iter().map(|p| p.partition_id).collect();
+        let router = PartitionRouter::new(Arc::new(TestMetaMgr::default()));
+
+        assert_eq!(router.route_kv_key(&rule, b"").unwrap(), ids[0]);
+        assert_eq!(router.route_kv_key(&rule, b"lzz").unwrap(), ids[0]);
+        assert_eq!(router.route_kv_key(&rule, b"m").unwrap(), ids[1]);
+        assert_eq!(router.route_kv_key(&rule, &[0xff]).unwrap(), ids[1]);
+
+        assert_eq!(
+            router.route_kv_range(&rule, b"a", b"c").unwrap(),
+            vec![ids[0]]
+        );
+        assert_eq!(
+            router.route_kv_range(&rule, b"a", b"m").unwrap(),
+            vec![ids[0]]
+        );
+        assert_eq!(router.route_kv_range(&rule, b"a", b"n").unwrap(), ids);
+        assert_eq!(
+            router.route_kv_range(&rule, b"m", b"").unwrap(),
+            vec![ids[1]]
+        );
+        assert_eq!(router.route_kv_range(&rule, b"", b"").unwrap(), ids);
+    }
+
+    #[test]
+    fn route_kv_key_rejects_non_string_rules() {
+        let router = PartitionRouter::new(Arc::new(TestMetaMgr::default()));
+        let err = router.route_kv_key(&single_col_rule(), b"15").unwrap_err();
+        assert_eq!(err.ec(), ErrorCode::InvalidArgument);
+        let err = router
+            .route_kv_range(&multi_col_rule(), b"", b"")
+            .unwrap_err();
+        assert_eq!(err.ec(), ErrorCode::InvalidArgument);
+    }
+
+    #[test]
+    fn route_exact_partition_routes_collation_equal_keys_together() {
+        mudu_sys::task::async_::block_on_tokio_current_thread(async move {
+            let ty = DatType::from_string(DTPString::new(64).with_collation(Collation::AsciiCi));
+            let table_desc = TableInfo::new(SchemaTable::new(
+                "users".to_string(),
+                vec![SchemaColumn::new(
+                    "name".to_string(),
+                    DatTypeID::String,
+                    DTInfo::from_opt_object(&ty),
+                )],
+                vec![0],
+                vec![],
+            ))
+            .unwrap()
+            .table_desc()
+            .unwrap();
+            let rule = kv_rule();
+            let p2 = rule.partitions[1].partition_id;
+            let router = PartitionRouter::new(Arc::new(partitioned_meta_mgr(
+                table_desc.clone(),
+                rule,
+                vec![0],
+            )));
+
+            // Bytewise, "Zed" sorts before the 'm' bound and "zed" after it.
+            for name in ["Zed", "zed"] {
+                let key = name.to_string().to_binary(&ty).unwrap().into();
+                let matched = router
+                    .route_exact_partition(
+                        table_desc.id(),
+                        table_desc.as_ref(),
+                        &VecDatum::new(vec![(0, key)]),
+                    )
+                    .await
+                    .unwrap();
+                assert_eq!(matched, Some(p2));
+            }
+        })
+        .unwrap()
+    }
 }
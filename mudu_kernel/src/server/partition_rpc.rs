@@ -41,6 +41,27 @@ pub enum PartitionRpcRequest {
         key: Vec<u8>,
         values: Vec<(AttrIndex, Vec<u8>)>,
     },
+    /// Reads the raw value of a KV namespace key.
+    KvGet {
+        table_id: OID,
+        partition_id: OID,
+        key: Vec<u8>,
+    },
+    /// Writes a KV namespace key, replacing any value it has.
+    KvPut {
+        table_id: OID,
+        partition_id: OID,
+        key: Vec<u8>,
+        value: Vec<u8>,
+    },
+    /// Reads the raw items of a KV namespace in `[start_key, end_key)`; an
+    /// empty end key is unbounded.
+    KvRange {
+        table_id: OID,
+        partition_id: OID,
+        start_key: Vec<u8>,
+        end_key: Vec<u8>,
+    },
     ApplyCrossPartitionTx {
         tx_id: OID,
         coordinator_worker_id: OID,
@@ -57,6 +78,9 @@ pub enum PartitionRpcResponse {
     Insert,
     Delete(usize),
     Update(usize),
+    KvGet(Option<Vec<u8>>),
+    KvPut,
+    KvRange(Vec<(Vec<u8>, Vec<u8>)>),
     ApplyCrossPartitionTx,
     Err(String),
 }
@@ -2,24 +2,33 @@ use mudu::common::id::OID;
 use mudu::common::result::RS;
 use mudu::error::ErrorCode;
 use mudu::mudu_error;
+use mudu_contract::database::kv_namespace::KvNamespace;
+use mudu_contract::database::kv_range::KvRangeOptions;
 use mudu_contract::database::result_set::ResultSetAsync;
 use mudu_contract::protocol::{
-    encode_get_response, encode_procedure_invoke_response, encode_put_response,
+    encode_cas_response, encode_cdc_subscribe_response, encode_delete_response,
+    encode_get_response, encode_incr_response, encode_kv_namespace_list_response,
+    encode_kv_namespace_response, encode_procedure_invoke_response, encode_put_response,
     encode_range_scan_response, encode_server_response, encode_session_close_response,
-    encode_session_create_response, GetResponse, KeyValue, ProcedureInvokeResponse, PutResponse,
-    RangeScanResponse, ServerPerfDigest, ServerResponse, SessionCloseResponse,
-    SessionCreateResponse,
+    encode_session_create_response, encode_wal_fetch_response, encode_watch_register_response,
+    encode_watch_response, CasResponse, CdcSubscribeRequest, DeleteResponse, GetResponse,
+    IncrResponse, KeyValue, KvNamespaceListResponse, KvNamespaceResponse, ProcedureInvokeResponse,
+    PutResponse, RangeScanResponse, ServerPerfDigest, ServerResponse, SessionCloseResponse,
+    SessionCreateResponse, WalFetchRequest, WatchAckRequest, WatchCancelRequest,
+    WatchRegisterRequest, WatchResponse,
 };
 use mudu_contract::tuple::tuple_field_desc::TupleFieldDesc;
 use mudu_sys::perf::TxnStage;
 use mudu_sys::time::instant_now;
 use std::sync::Arc;
+use std::time::Duration;
 
 use crate::server::async_func_task::HandleResult;
 use crate::server::request_response_worker::WorkerRuntimeRef;
 use crate::server::routing::parse_session_open_config;
 use crate::server::routing::SessionOpenConfig;
 use crate::server::worker_registry::WorkerRegistry;
+use crate::server::worker_snapshot::KvItem;
 
 #[derive(Clone)]
 pub(in crate::server) struct RequestCtx {
@@ -65,8 +74,21 @@ impl RequestCtx {
         )
     }
 
-    pub(in crate::server) async fn get(&self, session_id: OID, key: &[u8]) -> RS<HandleResult> {
-        let value = self.worker.get_async(session_id, key).await?;
+    pub(in crate::server) async fn get(
+        &self,
+        session_id: OID,
+        namespace: Option<&str>,
+        key: &[u8],
+    ) -> RS<HandleResult> {
+        let value = match namespace {
+            Some(name) => {
+                let namespace = self.session_namespace(session_id, name)?;
+                self.worker
+                    .kv_namespace_get_async(session_id, &namespace, key)
+                    .await?
+            }
+            None => self.worker.get_async(session_id, key).await?,
+        };
         Ok(HandleResult::Response(encode_get_response(
             self.request_id,
             &GetResponse::new(value),
@@ -76,13 +98,34 @@ impl RequestCtx {
     pub(in crate::server) async fn put(
         &self,
         session_id: OID,
+        namespace: Option<&str>,
         key: Vec<u8>,
         value: Vec<u8>,
+        ttl: Option<Duration>,
     ) -> RS<HandleResult> {
         let trace = mudu_utils::task_trace!();
         trace.watch("put.stage", "request_ctx_put_start");
         trace.watch("put.session_id", &session_id.to_string());
-        self.worker.put_async(session_id, key, value).await?;
+        match (namespace, ttl) {
+            (Some(_), Some(_)) => {
+                return Err(mudu_error!(
+                    ErrorCode::InvalidArgument,
+                    "a put into a kv namespace cannot carry a ttl"
+                ))
+            }
+            (Some(name), None) => {
+                let namespace = self.session_namespace(session_id, name)?;
+                self.worker
+                    .kv_namespace_put_async(session_id, &namespace, key, value)
+                    .await?
+            }
+            (None, Some(ttl)) => {
+                self.worker
+                    .put_with_ttl_async(session_id, key, value, ttl)
+                    .await?
+            }
+            (None, None) => self.worker.put_async(session_id, key, value).await?,
+        }
         trace.watch("put.stage", "request_ctx_put_encode_response");
         Ok(HandleResult::Response(encode_put_response(
             self.request_id,
@@ -90,6 +133,104 @@ impl RequestCtx {
         )?))
     }
 
+    pub(in crate::server) async fn delete(
+        &self,
+        session_id: OID,
+        namespace: Option<&str>,
+        key: &[u8],
+    ) -> RS<HandleResult> {
+        match namespace {
+            Some(name) => {
+                let namespace = self.session_namespace(session_id, name)?;
+                self.worker
+                    .kv_namespace_delete_async(session_id, &namespace, key)
+                    .await?
+            }
+            None => self.worker.delete_async(session_id, key).await?,
+        }
+        Ok(HandleResult::Response(encode_delete_response(
+            self.request_id,
+            &DeleteResponse::new(true),
+        )?))
+    }
+
+    pub(in crate::server) async fn put_many(
+        &self,
+        session_id: OID,
+        items: Vec<KeyValue>,
+    ) -> RS<HandleResult> {
+        let items = items
+            .into_iter()
+            .map(|item| {
+                let (key, value) = item.into_parts();
+                KvItem { key, value }
+            })
+            .collect();
+        self.worker.put_many_async(session_id, items).await?;
+        Ok(HandleResult::Response(encode_put_response(
+            self.request_id,
+            &PutResponse::new(true),
+        )?))
+    }
+
+    pub(in crate::server) async fn delete_many(
+        &self,
+        session_id: OID,
+        keys: Vec<Vec<u8>>,
+    ) -> RS<HandleResult> {
+        self.worker.delete_many_async(session_id, keys).await?;
+        Ok(HandleResult::Response(encode_delete_response(
+            self.request_id,
+            &DeleteResponse::new(true),
+        )?))
+    }
+
+    pub(in crate::server) async fn cas(
+        &self,
+        session_id: OID,
+        key: Vec<u8>,
+        expected: Option<Vec<u8>>,
+        value: Vec<u8>,
+    ) -> RS<HandleResult> {
+        let swapped = self
+            .worker
+            .cas_async(session_id, key, expected, value)
+            .await?;
+        Ok(HandleResult::Response(encode_cas_response(
+            self.request_id,
+            &CasResponse::new(swapped),
+        )?))
+    }
+
+    pub(in crate::server) async fn incr(
+        &self,
+        session_id: OID,
+        key: Vec<u8>,
+        delta: i64,
+    ) -> RS<HandleResult> {
+        let value = self.worker.incr_async(session_id, key, delta).await?;
+        Ok(HandleResult::Response(encode_incr_response(
+            self.request_id,
+            &IncrResponse::new(value),
+        )?))
+    }
+
+    pub(in crate::server) async fn put_if_absent(
+        &self,
+        session_id: OID,
+        key: Vec<u8>,
+        value: Vec<u8>,
+    ) -> RS<HandleResult> {
+        let swapped = self
+            .worker
+            .put_if_absent_async(session_id, key, value)
+            .await?;
+        Ok(HandleResult::Response(encode_cas_response(
+            self.request_id,
+            &CasResponse::new(swapped),
+        )?))
+    }
+
     pub(in crate::server) async fn invoke_procedure(
         &self,
         request: mudu_contract::protocol::ProcedureInvokeRequest,
@@ -130,21 +271,79 @@ impl RequestCtx {
     pub(in crate::server) async fn range_scan(
         &self,
         session_id: OID,
+        namespace: Option<&str>,
         start_key: &[u8],
         end_key: &[u8],
+        options: &KvRangeOptions,
     ) -> RS<HandleResult> {
-        let items = self
-            .worker
-            .range_async(session_id, start_key, end_key)
-            .await?;
+        let page = match namespace {
+            Some(name) => {
+                let namespace = self.session_namespace(session_id, name)?;
+                self.worker
+                    .kv_namespace_range_async(session_id, &namespace, start_key, end_key, options)
+                    .await?
+            }
+            None => {
+                self.worker
+                    .range_async(session_id, start_key, end_key, options)
+                    .await?
+            }
+        };
+        let (items, continuation) = page.into_parts();
         Ok(HandleResult::Response(encode_range_scan_response(
             self.request_id,
             &RangeScanResponse::new(
                 items
                     .into_iter()
-                    .map(|item| KeyValue::new(item.key, item.value))
+                    .map(|(key, value)| KeyValue::new(key, value))
                     .collect(),
-            ),
+            )
+            .with_continuation(continuation),
+        )?))
+    }
+
+    /// Resolves the namespace `name` of the app the session is bound to.
+    fn session_namespace(&self, session_id: OID, name: &str) -> RS<KvNamespace> {
+        KvNamespace::new(self.worker.session_app(session_id)?, name)
+    }
+
+    pub(in crate::server) async fn kv_namespace_create(
+        &self,
+        session_id: OID,
+        name: &str,
+        partition_rule: Option<&str>,
+    ) -> RS<HandleResult> {
+        let namespace = self.session_namespace(session_id, name)?;
+        self.worker
+            .kv_namespace_create_async(session_id, &namespace, partition_rule)
+            .await?;
+        Ok(HandleResult::Response(encode_kv_namespace_response(
+            self.request_id,
+            &KvNamespaceResponse::new(true),
+        )?))
+    }
+
+    pub(in crate::server) async fn kv_namespace_drop(
+        &self,
+        session_id: OID,
+        name: &str,
+    ) -> RS<HandleResult> {
+        let namespace = self.session_namespace(session_id, name)?;
+        self.worker
+            .kv_namespace_drop_async(session_id, &namespace)
+            .await?;
+        Ok(HandleResult::Response(encode_kv_namespace_response(
+            self.request_id,
+            &KvNamespaceResponse::new(true),
+        )?))
+    }
+
+    pub(in crate::server) async fn kv_namespace_list(&self, session_id: OID) -> RS<HandleResult> {
+        let app_name = self.worker.session_app(session_id)?;
+        let namespaces = self.worker.kv_namespace_list_async(&app_name).await?;
+        Ok(HandleResult::Response(encode_kv_namespace_list_response(
+            self.request_id,
+            &KvNamespaceListResponse::new(namespaces),
         )?))
     }
 
@@ -222,6 +421,9 @@ impl RequestCtx {
         config: SessionOpenConfig,
     ) -> RS<HandleResult> {
         if config.target_worker_index() == self.worker.worker_index() {
+            if let Some(app) = config.app() {
+                self.worker.ensure_app_installed(app).await?;
+            }
             Ok(HandleResult::Response(encode_session_create_response(
                 self.request_id,
                 &SessionCreateResponse::new(
@@ -252,6 +454,55 @@ impl RequestCtx {
         )?))
     }
 
+    pub(in crate::server) async fn cdc_subscribe(
+        &self,
+        request: &CdcSubscribeRequest,
+    ) -> RS<HandleResult> {
+        let response = self.worker.cdc_subscribe(request).await?;
+        Ok(HandleResult::Response(encode_cdc_subscribe_response(
+            self.request_id,
+            &response,
+        )?))
+    }
+
+    pub(in crate::server) async fn wal_fetch(&self, request: &WalFetchRequest) -> RS<HandleResult> {
+        let response = self.worker.wal_fetch(request).await?;
+        Ok(HandleResult::Response(encode_wal_fetch_response(
+            self.request_id,
+            &response,
+        )?))
+    }
+
+    pub(in crate::server) async fn watch_register(
+        &self,
+        request: &WatchRegisterRequest,
+    ) -> RS<HandleResult> {
+        let response = self.worker.watch_register(self.conn_id, request).await?;
+        Ok(HandleResult::Response(encode_watch_register_response(
+            self.request_id,
+            &response,
+        )?))
+    }
+
+    pub(in crate::server) async fn watch_ack(&self, request: &WatchAckRequest) -> RS<HandleResult> {
+        self.worker.watch_ack(self.conn_id, request)?;
+        Ok(HandleResult::Response(encode_watch_response(
+            self.request_id,
+            &WatchResponse::new(true),
+        )?))
+    }
+
+    pub(in crate::server) async fn watch_cancel(
+        &self,
+        request: &WatchCancelRequest,
+    ) -> RS<HandleResult> {
+        self.worker.watch_cancel(self.conn_id, request)?;
+        Ok(HandleResult::Response(encode_watch_response(
+            self.request_id,
+            &WatchResponse::new(true),
+        )?))
+    }
+
     fn encode_server_response(&self, response: ServerResponse) -> RS<HandleResult> {
         Ok(HandleResult::Response(encode_server_response(
             self.request_id,
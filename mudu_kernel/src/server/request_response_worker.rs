@@ -3,7 +3,13 @@ use crate::server::worker_registry::WorkerRegistry;
 use async_trait::async_trait;
 use mudu::common::id::OID;
 use mudu::common::result::RS;
-use mudu_contract::protocol::{ProcedureInvokeRequest, ProcedureInvokeResponse};
+use mudu::error::ErrorCode;
+use mudu::mudu_error;
+use mudu_contract::protocol::{
+    CdcSubscribeRequest, CdcSubscribeResponse, ProcedureInvokeRequest, ProcedureInvokeResponse,
+    WalFetchRequest, WalFetchResponse, WatchAckRequest, WatchCancelRequest, WatchRegisterRequest,
+    WatchRegisterResponse,
+};
 use std::sync::Arc;
 
 use crate::server::routing::SessionOpenConfig;
@@ -20,11 +26,70 @@ pub trait RequestResponseWorker: Send + Sync {
 
     fn close_session_for_connection(&self, conn_id: u64, session_id: OID) -> RS<bool>;
 
+    /// Fails unless `app` is installed, so a session can be bound to it.
+    async fn ensure_app_installed(&self, app: &str) -> RS<()> {
+        Err(mudu_error!(
+            ErrorCode::NotImplemented,
+            format!("cannot bind a session to app {}", app)
+        ))
+    }
+
+    /// The app a session was bound to when it was opened.
+    fn session_app(&self, session_id: OID) -> RS<String> {
+        Err(mudu_error!(
+            ErrorCode::NotImplemented,
+            format!("session {} is not bound to an app", session_id)
+        ))
+    }
+
     async fn handle_procedure_request(
         &self,
         conn_id: u64,
         request: &ProcedureInvokeRequest,
     ) -> RS<ProcedureInvokeResponse>;
+
+    /// Returns the committed changes a change data capture consumer has not
+    /// consumed yet.
+    async fn cdc_subscribe(&self, _request: &CdcSubscribeRequest) -> RS<CdcSubscribeResponse> {
+        Err(mudu_error!(
+            ErrorCode::NotImplemented,
+            "change data capture is not supported"
+        ))
+    }
+
+    /// Returns worker log frames a read replica has not received yet.
+    async fn wal_fetch(&self, _request: &WalFetchRequest) -> RS<WalFetchResponse> {
+        Err(mudu_error!(
+            ErrorCode::NotImplemented,
+            "log shipping is not supported"
+        ))
+    }
+
+    /// Registers a watch whose events are pushed on the connection.
+    async fn watch_register(
+        &self,
+        _conn_id: u64,
+        _request: &WatchRegisterRequest,
+    ) -> RS<WatchRegisterResponse> {
+        Err(mudu_error!(
+            ErrorCode::NotImplemented,
+            "watches are not supported"
+        ))
+    }
+
+    fn watch_ack(&self, _conn_id: u64, _request: &WatchAckRequest) -> RS<()> {
+        Err(mudu_error!(
+            ErrorCode::NotImplemented,
+            "watches are not supported"
+        ))
+    }
+
+    fn watch_cancel(&self, _conn_id: u64, _request: &WatchCancelRequest) -> RS<()> {
+        Err(mudu_error!(
+            ErrorCode::NotImplemented,
+            "watches are not supported"
+        ))
+    }
 }
 
 pub trait WorkerRuntimeApi: RequestResponseWorker + WorkerLocal {}
@@ -1,4 +1,6 @@
+use crate::server::session_options::SessionOptionsUpdate;
 use crate::server::worker_registry::WorkerRegistry;
+use crate::x_engine::tx_mgr::{DurabilityMode, IsolationLevel};
 use mudu::common::id::OID;
 use mudu::common::result::RS;
 use mudu::error::ErrorCode;
@@ -13,11 +15,13 @@ pub enum RoutingMode {
     RemoteHash,
 }
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, PartialEq, Eq)]
 pub struct SessionOpenConfig {
     session_id: OID,
     worker_id: OID,
     target_worker_index: usize,
+    options: SessionOptionsUpdate,
+    app: Option<String>,
 }
 
 #[derive(Debug, Deserialize)]
@@ -26,6 +30,52 @@ struct RawSessionOpenConfig {
     session_id: OID,
     #[serde(default, deserialize_with = "deserialize_opt_oid_json")]
     worker_id: Option<OID>,
+    #[serde(default)]
+    isolation: Option<RawIsolationLevel>,
+    #[serde(default)]
+    read_only: Option<bool>,
+    #[serde(default)]
+    lock_timeout_ms: Option<u64>,
+    #[serde(default)]
+    statement_timeout_ms: Option<u64>,
+    #[serde(default)]
+    durability: Option<RawDurabilityMode>,
+    #[serde(default)]
+    app: Option<String>,
+}
+
+#[derive(Debug, Clone, Copy, Deserialize)]
+#[serde(rename_all = "snake_case")]
+enum RawIsolationLevel {
+    #[serde(alias = "snapshot_isolation", alias = "repeatable_read")]
+    Snapshot,
+    Serializable,
+}
+
+#[derive(Debug, Clone, Copy, Deserialize)]
+#[serde(rename_all = "snake_case")]
+enum RawDurabilityMode {
+    #[serde(alias = "fsync")]
+    Sync,
+    Async,
+}
+
+impl RawSessionOpenConfig {
+    fn options(&self) -> SessionOptionsUpdate {
+        SessionOptionsUpdate {
+            isolation_level: self.isolation.map(|isolation| match isolation {
+                RawIsolationLevel::Snapshot => IsolationLevel::SnapshotIsolation,
+                RawIsolationLevel::Serializable => IsolationLevel::Serializable,
+            }),
+            read_only: self.read_only,
+            lock_timeout_ms: self.lock_timeout_ms,
+            statement_timeout_ms: self.statement_timeout_ms,
+            durability: self.durability.map(|durability| match durability {
+                RawDurabilityMode::Sync => DurabilityMode::Sync,
+                RawDurabilityMode::Async => DurabilityMode::Async,
+            }),
+        }
+    }
 }
 
 #[derive(Debug, Deserialize)]
@@ -72,9 +122,23 @@ impl SessionOpenConfig {
             session_id,
             worker_id,
             target_worker_index,
+            options: SessionOptionsUpdate::default(),
+            app: None,
         }
     }
 
+    pub fn with_options(mut self, options: SessionOptionsUpdate) -> Self {
+        self.options = options;
+        self
+    }
+
+    /// Binds the session to the installed app `app`; KV namespace requests
+    /// of the session address that app's namespaces.
+    pub fn with_app(mut self, app: Option<String>) -> Self {
+        self.app = app;
+        self
+    }
+
     pub fn session_id(&self) -> OID {
         self.session_id
     }
@@ -86,6 +150,14 @@ impl SessionOpenConfig {
     pub fn target_worker_index(&self) -> usize {
         self.target_worker_index
     }
+
+    pub fn options(&self) -> &SessionOptionsUpdate {
+        &self.options
+    }
+
+    pub fn app(&self) -> Option<&str> {
+        self.app.as_deref()
+    }
 }
 
 pub fn parse_session_open_config(
@@ -99,13 +171,16 @@ pub fn parse_session_open_config(
             let parsed: RawSessionOpenConfig = serde_json::from_str(raw).map_err(|e| {
                 mudu_error!(ErrorCode::Parse, "parse session open config json error", e)
             })?;
+            let options = parsed.options();
             let worker_id = parsed.worker_id.unwrap_or(default_worker_id);
             if worker_id == 0 {
                 return Ok(SessionOpenConfig::new(
                     parsed.session_id,
                     default_worker_id,
                     default_worker_index,
-                ));
+                )
+                .with_options(options)
+                .with_app(parsed.app));
             }
             let target_worker_index =
                 registry
@@ -116,11 +191,11 @@ pub fn parse_session_open_config(
                             format!("no such worker id {}", worker_id)
                         )
                     })?;
-            Ok(SessionOpenConfig::new(
-                parsed.session_id,
-                worker_id,
-                target_worker_index,
-            ))
+            Ok(
+                SessionOpenConfig::new(parsed.session_id, worker_id, target_worker_index)
+                    .with_options(options)
+                    .with_app(parsed.app),
+            )
         }
         None => Ok(SessionOpenConfig::new(
             0,
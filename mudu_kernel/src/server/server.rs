@@ -12,12 +12,20 @@ use crate::server::message_bus_state::WorkerMessageBusState;
 use crate::server::session_bound_worker_runtime::{
     as_worker_local_ref, new_session_bound_worker_runtime,
 };
+use crate::server::version_gc::VERSION_GC_INTERVAL;
 use crate::server::worker::{WorkerRuntime, WorkerRuntimeParams};
+use crate::server::worker_backup::BackupCoordinator;
+use crate::server::worker_checkpoint::{CheckpointPolicy, CHECKPOINT_POLL_INTERVAL};
 use crate::server::worker_local::{set_current_worker_local, unset_current_worker_local};
+use crate::server::worker_recovery_target::RecoveryTarget;
 use crate::server::worker_registry::{WorkerIdentity, WorkerRegistry};
+use crate::server::worker_watch::WATCH_PUSH_INTERVAL;
+use crate::storage::compression::Compression;
+use crate::storage::encryption::{Keyring, REENCRYPT_INTERVAL};
+use crate::wal::log_frame::decode_entries_with_pending;
 use crate::wal::worker_log::WorkerLogBatching;
-use crate::wal::worker_log::{decode_frames, WorkerLogBackend};
-use crate::wal::xl_batch::decode_xl_batches;
+use crate::wal::worker_log::{decode_frames, retain_frames_from, WorkerLogBackend};
+use crate::wal::xl_batch::XLBatch;
 use async_trait::async_trait;
 use crossbeam_queue::SegQueue;
 
@@ -25,7 +33,7 @@ use mudu::common::id::OID;
 use mudu::common::result::RS;
 use mudu::error::ErrorCode;
 use mudu::mudu_error;
-use mudu_contract::protocol::encode_merror_response;
+use mudu_contract::protocol::{encode_merror_response, MessageType};
 use mudu_sys::net::{AsyncTcpListener, AsyncTcpStream};
 use mudu_sys::sync::async_::stop_flag::{stop_channel, StopRx, StopTx};
 use mudu_sys::tokio;
@@ -48,7 +56,7 @@ use std::time::Duration;
 use crate::server::server_launch::{ServerLaunch, WorkerTcpBackendConfig};
 use mudu_sys::task::sync::SJoinHandle;
 
-use tracing::trace;
+use tracing::{debug, trace};
 
 /// Backend entry point for the `client` transport.
 ///
@@ -201,10 +209,21 @@ struct WorkerBuildConfig {
     log_dir: String,
     data_dir: String,
     log_chunk_size: u64,
+    history_retention: Duration,
+    checkpoint_policy: CheckpointPolicy,
+    async_commit_flush_interval: Duration,
+    buffer_pool_bytes: u64,
+    page_compression: Compression,
+    wal_compression: Compression,
+    keyring: Option<Arc<Keyring>>,
+    wal_archive_dir: Option<String>,
+    recovery_target: RecoveryTarget,
+    read_replica: bool,
     log_batching: WorkerLogBatching,
     procedure_runtime: Option<AsyncFuncInvokerPtr>,
     worker_identity: WorkerIdentity,
     worker_registry: Arc<WorkerRegistry>,
+    backups: Arc<BackupCoordinator>,
     async_runtime: Option<Arc<dyn AsyncIoProvider>>,
 }
 
@@ -228,10 +247,21 @@ impl WorkerBuildConfig {
             log_dir: server_cfg.log_dir().to_string(),
             data_dir: server_cfg.data_dir().to_string(),
             log_chunk_size: server_cfg.log_chunk_size(),
+            history_retention: server_cfg.history_retention(),
+            checkpoint_policy: server_cfg.checkpoint_policy(),
+            async_commit_flush_interval: server_cfg.async_commit_flush_interval(),
+            buffer_pool_bytes: server_cfg.buffer_pool_bytes(),
+            page_compression: server_cfg.page_compression(),
+            wal_compression: server_cfg.wal_compression(),
+            keyring: deps.keyring(),
+            wal_archive_dir: server_cfg.wal_archive_dir().map(str::to_string),
+            recovery_target: server_cfg.recovery_target(),
+            read_replica: server_cfg.read_replica(),
             log_batching: deps.log_batching(),
             procedure_runtime: deps.procedure_runtime_for_worker(worker_id),
             worker_identity,
             worker_registry: deps.worker_registry(),
+            backups: deps.backup_coordinator(),
             async_runtime: deps.async_runtime(),
         })
     }
@@ -243,9 +273,20 @@ impl WorkerBuildConfig {
             log_dir: self.log_dir,
             data_dir: self.data_dir,
             log_chunk_size: self.log_chunk_size,
+            history_retention: self.history_retention,
+            checkpoint_policy: self.checkpoint_policy,
+            async_commit_flush_interval: self.async_commit_flush_interval,
+            buffer_pool_bytes: self.buffer_pool_bytes,
+            page_compression: self.page_compression,
+            wal_compression: self.wal_compression,
+            keyring: self.keyring,
+            wal_archive_dir: self.wal_archive_dir,
+            recovery_target: self.recovery_target,
+            read_replica: self.read_replica,
             log_batching: self.log_batching,
             procedure_runtime: self.procedure_runtime,
             registry: self.worker_registry,
+            backups: self.backups,
             async_runtime: self.async_runtime,
             server_instance_id: self.server_instance_id,
         })
@@ -391,6 +432,7 @@ fn sync_serve_tokio(
     if cfg.cfg().worker_count() == 0 {
         return Err(mudu_error!(ErrorCode::Parse, "invalid tokio worker count"));
     }
+    cfg.cfg().check_page_size()?;
     let conn_id_alloc = Arc::new(AtomicU64::new(1));
     let bus_mailboxes: Vec<_> = (0..cfg.cfg().worker_count())
         .map(|_| Arc::new(SegQueue::<Envelope>::new()))
@@ -591,6 +633,24 @@ async fn run_worker_loop_tokio(args: TokioWorkerLoopArgs) -> RS<()> {
     if let Some(tx) = rpc_ready_tx {
         let _ = tx.send(Ok(()));
     }
+    let _ = spawn_local_detached(
+        &format!("tokio_version_gc_{}", worker.worker_id()),
+        run_version_gc_tokio(worker.clone(), stop.clone()),
+    );
+    let _ = spawn_local_detached(
+        &format!("tokio_checkpoint_{}", worker.worker_id()),
+        run_checkpoint_tokio(worker.clone(), stop.clone()),
+    );
+    let _ = spawn_local_detached(
+        &format!("tokio_async_commit_flush_{}", worker.worker_id()),
+        run_async_commit_flush_tokio(worker.clone(), stop.clone()),
+    );
+    if worker.is_encrypted() {
+        let _ = spawn_local_detached(
+            &format!("tokio_reencrypt_{}", worker.worker_id()),
+            run_reencrypt_tokio(worker.clone(), stop.clone()),
+        );
+    }
     while !stop.load(Ordering::Relaxed) {
         if stop_rx.is_stopped() {
             break;
@@ -651,24 +711,125 @@ async fn run_worker_loop_tokio(args: TokioWorkerLoopArgs) -> RS<()> {
     Ok(())
 }
 
+/// Runs version GC and kv TTL sweeper steps between request handling on the
+/// worker's local Tokio runtime until the worker stops.
+async fn run_version_gc_tokio(worker: WorkerRuntime, stop: Arc<AtomicBool>) -> RS<()> {
+    while !stop.load(Ordering::Relaxed) {
+        mudu_sys::sleep(VERSION_GC_INTERVAL).await?;
+        if let Err(err) = worker.collect_version_garbage_async().await {
+            debug!(
+                worker_id = worker.worker_id(),
+                error = ?err,
+                "version gc step failed"
+            );
+        }
+        if let Err(err) = worker.sweep_expired_kv_async().await {
+            debug!(
+                worker_id = worker.worker_id(),
+                error = ?err,
+                "kv ttl sweep step failed"
+            );
+        }
+    }
+    Ok(())
+}
+
+/// Checks on the worker's local Tokio runtime whether a checkpoint is due,
+/// and serves pending online backups and replicated log frames, until the
+/// worker stops.
+async fn run_checkpoint_tokio(worker: WorkerRuntime, stop: Arc<AtomicBool>) -> RS<()> {
+    while !stop.load(Ordering::Relaxed) {
+        mudu_sys::sleep(CHECKPOINT_POLL_INTERVAL).await?;
+        if let Err(err) = worker.replicate_async().await {
+            debug!(
+                worker_id = worker.worker_id(),
+                error = ?err,
+                "applying replicated log frames failed"
+            );
+        }
+        if let Err(err) = worker.backup_if_requested_async().await {
+            debug!(
+                worker_id = worker.worker_id(),
+                error = ?err,
+                "backup failed"
+            );
+        }
+        if let Err(err) = worker.checkpoint_if_due_async().await {
+            debug!(
+                worker_id = worker.worker_id(),
+                error = ?err,
+                "checkpoint failed"
+            );
+        }
+    }
+    Ok(())
+}
+
+/// Fsyncs the worker log for commits with asynchronous durability on the
+/// worker's local Tokio runtime until the worker stops.
+async fn run_async_commit_flush_tokio(worker: WorkerRuntime, stop: Arc<AtomicBool>) -> RS<()> {
+    while !stop.load(Ordering::Relaxed) {
+        mudu_sys::sleep(worker.async_commit_flush_interval()).await?;
+        if let Err(err) = worker.flush_async_commits_async().await {
+            debug!(
+                worker_id = worker.worker_id(),
+                error = ?err,
+                "async commit flush failed"
+            );
+        }
+    }
+    Ok(())
+}
+
+/// Runs re-encryption steps between request handling on the worker's local
+/// Tokio runtime until the worker stops.
+async fn run_reencrypt_tokio(worker: WorkerRuntime, stop: Arc<AtomicBool>) -> RS<()> {
+    while !stop.load(Ordering::Relaxed) {
+        mudu_sys::sleep(REENCRYPT_INTERVAL).await?;
+        if let Err(err) = worker.reencrypt_step_async().await {
+            debug!(
+                worker_id = worker.worker_id(),
+                error = ?err,
+                "re-encryption step failed"
+            );
+        }
+    }
+    Ok(())
+}
+
 async fn recover_worker_log_tokio(worker: &WorkerRuntime) -> RS<()> {
     let Some(log) = worker.worker_log()? else {
         return Ok(());
     };
     let fs = log.fs();
+    let start_lsn = worker.log_recovery_start_lsn();
     let chunk_paths = log.chunk_paths_sorted().await?;
+    let mut pending_frames = Vec::new();
+    let mut pending_start_lsn = None;
     for path in chunk_paths {
         let bytes = fs.read_all(&path).await?;
         if bytes.is_empty() {
             continue;
         }
-        let frames = decode_frames(&bytes)?;
-        let batches = decode_xl_batches(&frames)?;
-        for batch in batches {
-            worker.replay_log_batch(batch).await?;
+        let mut frames = decode_frames(&bytes)?;
+        retain_frames_from(&mut frames, start_lsn)?;
+        let batches = decode_entries_with_pending::<XLBatch>(
+            log.keyring(),
+            &frames,
+            &mut pending_frames,
+            &mut pending_start_lsn,
+        )?;
+        for (batch_lsn, batch) in batches {
+            worker.replay_log_batch(batch, batch_lsn).await?;
         }
     }
-    Ok(())
+    if !pending_frames.is_empty() {
+        return Err(mudu_error!(
+            ErrorCode::Decode,
+            "trailing partial xl batch frames"
+        ));
+    }
+    worker.finish_recovery_target_async().await
 }
 
 async fn handle_tokio_connection(
@@ -686,6 +847,8 @@ async fn handle_tokio_connection(
         .map_err(|e| mudu_error!(ErrorCode::Network, "set tokio connection nodelay error", e))?;
     let mut read_buf: Vec<u8> = Vec::with_capacity(8192);
     let mut chunk = vec![0u8; 8192];
+    // Whether the connection has watches whose events must be pushed.
+    let mut watching = false;
     loop {
         if stop.load(Ordering::Relaxed) || stop_rx.is_stopped() {
             break;
@@ -700,6 +863,15 @@ async fn handle_tokio_connection(
                 }
                 continue;
             }
+            slept = mudu_sys::sleep(WATCH_PUSH_INTERVAL), if watching => {
+                slept?;
+                for payload in worker.poll_watch_events_async(conn_id).await? {
+                    stream.write_all(&payload).await.map_err(|e| {
+                        mudu_error!(ErrorCode::Network, "write tokio tcp watch event error", e)
+                    })?;
+                }
+                continue;
+            }
         };
         if read == 0 {
             break;
@@ -728,6 +900,12 @@ async fn handle_tokio_connection(
                     })?;
                 }
             }
+            if matches!(
+                frame.header().message_type(),
+                MessageType::WatchRegister | MessageType::WatchCancel | MessageType::SessionClose
+            ) {
+                watching = worker.has_watches(conn_id)?;
+            }
         }
     }
     worker.close_connection_sessions(conn_id)?;
@@ -762,9 +940,14 @@ mod tests {
     )]
 
     use super::*;
+    use crate::server::routing::RoutingMode;
+    use crate::server::server_cfg::ServerCfg;
+    use crate::server::server_runtime_deps::ServerRuntimeDeps;
     use mudu_contract::protocol::encode_get_request;
     use mudu_contract::protocol::GetRequest;
     use mudu_contract::protocol::HEADER_LEN;
+    use mudu_sys::env_var::temp_dir;
+    use mudu_utils::oid::gen_oid;
 
     #[test]
     fn try_decode_next_frame_waits_for_full_payload() {
@@ -779,4 +962,68 @@ mod tests {
         assert_eq!(decoded.0.header().request_id(), 1);
         assert_eq!(decoded.1, encoded.len());
     }
+
+    async fn start_worker(cfg: ServerCfg) -> WorkerRuntime {
+        let deps = ServerRuntimeDeps::from_cfg(&cfg).unwrap();
+        let launch = ServerLaunch::new(cfg, deps);
+        let worker = WorkerBuildConfig::from_server_config(&launch, 0)
+            .unwrap()
+            .build_worker()
+            .await
+            .unwrap();
+        worker.initialize().await.unwrap();
+        worker.bootstrap_storage_async().await.unwrap();
+        recover_worker_log_tokio(&worker).await.unwrap();
+        worker
+    }
+
+    #[test]
+    #[cfg_attr(miri, ignore)]
+    fn worker_recovers_sealed_log_with_keyring_from_server_deps() {
+        mudu_sys::task::async_::block_on_tokio_current_thread(async move {
+            let dir = temp_dir()
+                .join(format!("server_encryption_test_{}", gen_oid()))
+                .to_string_lossy()
+                .into_owned();
+            mudu_sys::fs::sync::create_dir_all(&dir).unwrap();
+            let key_file = format!("{}/keys", dir);
+            mudu_sys::fs::sync::sync_write(&key_file, format!("1 {}\n", "5a".repeat(32))).unwrap();
+            let cfg = || {
+                ServerCfg::new(
+                    1,
+                    "127.0.0.1".to_string(),
+                    0,
+                    dir.clone(),
+                    dir.clone(),
+                    RoutingMode::ConnectionId,
+                )
+                .unwrap()
+                .with_encryption_key_file(Some(key_file.clone()))
+            };
+
+            let worker = start_worker(cfg()).await;
+            assert!(worker.is_encrypted());
+            let session_id = worker.create_session(1).unwrap();
+            let local = new_session_bound_worker_runtime(worker.clone(), session_id);
+            local
+                .put_async(session_id, b"key".to_vec(), b"sealed-value".to_vec())
+                .await
+                .unwrap();
+            for path in worker.log_layout().chunk_paths_sorted().await.unwrap() {
+                let bytes = mudu_sys::fs::sync::sync_read_all(&path).unwrap();
+                assert!(!bytes
+                    .windows(b"sealed-value".len())
+                    .any(|window| window == b"sealed-value"));
+            }
+            drop(local);
+            drop(worker);
+
+            let worker = start_worker(cfg()).await;
+            assert_eq!(
+                worker.get_async(b"key").await.unwrap(),
+                Some(b"sealed-value".to_vec())
+            );
+        })
+        .unwrap()
+    }
 }
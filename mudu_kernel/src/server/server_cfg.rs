@@ -1,10 +1,20 @@
 use crate::server::message_bus_api::ServerInstanceId;
 use crate::server::routing::RoutingMode;
+use crate::server::worker_checkpoint::CheckpointPolicy;
+use crate::server::worker_recovery_target::RecoveryTarget;
+use crate::storage::buffer_pool::DEFAULT_BUFFER_POOL_BYTES;
+use crate::storage::compression::Compression;
+use crate::storage::encryption::{load_key_file, Keyring};
 use crate::storage::page::page_block_ref::DEFAULT_PAGE_SIZE;
+use crate::storage::page_size::check_data_dir_page_size;
+use crate::x_engine::tx_mgr::DEFAULT_ASYNC_COMMIT_FLUSH_INTERVAL;
 use mudu::common::result::RS;
 use mudu::error::ErrorCode;
 use mudu::mudu_error;
 use mudu_utils::oid::gen_oid;
+use std::path::Path;
+use std::sync::Arc;
+use std::time::Duration;
 
 /// Configuration shared by both execution paths of the `client` backend.
 ///
@@ -21,8 +31,18 @@ pub struct ServerCfg {
     data_dir: String,
     log_dir: String,
     log_chunk_size: u64,
+    history_retention: Duration,
+    checkpoint_policy: CheckpointPolicy,
+    async_commit_flush_interval: Duration,
+    buffer_pool_bytes: u64,
+    page_compression: Compression,
+    wal_compression: Compression,
     routing_mode: RoutingMode,
     page_size: usize,
+    encryption_key_file: Option<String>,
+    wal_archive_dir: Option<String>,
+    recovery_target: RecoveryTarget,
+    read_replica: bool,
 }
 
 impl ServerCfg {
@@ -50,8 +70,18 @@ impl ServerCfg {
             data_dir,
             log_dir,
             log_chunk_size: 64 * 1024 * 1024,
+            history_retention: Duration::ZERO,
+            checkpoint_policy: CheckpointPolicy::default(),
+            async_commit_flush_interval: DEFAULT_ASYNC_COMMIT_FLUSH_INTERVAL,
+            buffer_pool_bytes: DEFAULT_BUFFER_POOL_BYTES,
+            page_compression: Compression::None,
+            wal_compression: Compression::None,
             routing_mode,
             page_size: DEFAULT_PAGE_SIZE,
+            encryption_key_file: None,
+            wal_archive_dir: None,
+            recovery_target: RecoveryTarget::default(),
+            read_replica: false,
         })
     }
 
@@ -60,6 +90,91 @@ impl ServerCfg {
         self
     }
 
+    /// Keeps the state as of every commit timestamp a worker handed out within
+    /// the last `history_retention` readable by `AS OF SYSTEM TIME` queries.
+    ///
+    /// The window is measured on each worker's own clock and starts over when
+    /// the server restarts. A wall-clock `AS OF SYSTEM TIME` is mapped to the
+    /// latest commit timestamp the worker handed out by then. Commit
+    /// timestamps are allocated per worker, so `AS OF SYSTEM TIME` reads are
+    /// rejected on partitions owned by another worker. The default of zero
+    /// lets version GC prune everything no active transaction can see.
+    pub fn with_history_retention(mut self, history_retention: Duration) -> Self {
+        self.history_retention = history_retention;
+        self
+    }
+
+    /// Takes a worker checkpoint at least every `interval`; `Duration::ZERO`
+    /// disables the time trigger.
+    pub fn with_checkpoint_interval(mut self, interval: Duration) -> Self {
+        self.checkpoint_policy.interval = interval;
+        self
+    }
+
+    /// Takes a worker checkpoint once `log_bytes` bytes were logged since the
+    /// previous one; `0` disables the size trigger.
+    pub fn with_checkpoint_log_bytes(mut self, log_bytes: u64) -> Self {
+        self.checkpoint_policy.log_bytes = log_bytes;
+        self
+    }
+
+    /// Fsyncs the worker log every `interval` while commits with
+    /// asynchronous durability are not yet durable; such commits can be lost
+    /// on an operating system crash for about this long.
+    pub fn with_async_commit_flush_interval(mut self, interval: Duration) -> Self {
+        self.async_commit_flush_interval = interval;
+        self
+    }
+
+    /// Caps the relation pages each worker keeps cached at
+    /// `buffer_pool_bytes` bytes.
+    pub fn with_buffer_pool_bytes(mut self, buffer_pool_bytes: u64) -> Self {
+        self.buffer_pool_bytes = buffer_pool_bytes;
+        self
+    }
+
+    /// Compresses the relation pages of tables that do not choose a codec
+    /// with `WITH (compression = ...)`.
+    pub fn with_page_compression(mut self, page_compression: Compression) -> Self {
+        self.page_compression = page_compression;
+        self
+    }
+
+    /// Compresses the payload of worker log frames.
+    pub fn with_wal_compression(mut self, wal_compression: Compression) -> Self {
+        self.wal_compression = wal_compression;
+        self
+    }
+
+    /// Seals pages, log frames and checkpoint state with the keys of
+    /// `encryption_key_file`; data is written in plaintext without one.
+    pub fn with_encryption_key_file(mut self, encryption_key_file: Option<String>) -> Self {
+        self.encryption_key_file = encryption_key_file;
+        self
+    }
+
+    /// Copies every completed worker log chunk to `wal_archive_dir`, which
+    /// point-in-time recovery replays from.
+    pub fn with_wal_archive_dir(mut self, wal_archive_dir: Option<String>) -> Self {
+        self.wal_archive_dir = wal_archive_dir;
+        self
+    }
+
+    /// Stops worker log recovery at `recovery_target` after copying the
+    /// archived chunks back into the log directory, then seals the log with
+    /// a checkpoint. Only the first start with a target applies it.
+    pub fn with_recovery_target(mut self, recovery_target: RecoveryTarget) -> Self {
+        self.recovery_target = recovery_target;
+        self
+    }
+
+    /// Serves a read replica: workers reject commits and apply the worker
+    /// log frames a follower ships from the primary, until promoted.
+    pub fn with_read_replica(mut self, read_replica: bool) -> Self {
+        self.read_replica = read_replica;
+        self
+    }
+
     pub fn with_multi_port(mut self, multi_port: bool) -> Self {
         self.multi_port = multi_port;
         self
@@ -69,7 +184,7 @@ impl ServerCfg {
     ///
     /// `page_size` must be a power of two and at least `DEFAULT_PAGE_SIZE`.
     /// This value is a `Persistent` config: changing it for an existing data
-    /// directory requires a migration tool.
+    /// directory requires the offline page-size migration.
     pub fn with_page_size(mut self, page_size: usize) -> RS<Self> {
         if page_size < DEFAULT_PAGE_SIZE {
             return Err(mudu_error!(
@@ -143,6 +258,30 @@ impl ServerCfg {
         self.log_chunk_size
     }
 
+    pub fn history_retention(&self) -> Duration {
+        self.history_retention
+    }
+
+    pub fn checkpoint_policy(&self) -> CheckpointPolicy {
+        self.checkpoint_policy
+    }
+
+    pub fn async_commit_flush_interval(&self) -> Duration {
+        self.async_commit_flush_interval
+    }
+
+    pub fn buffer_pool_bytes(&self) -> u64 {
+        self.buffer_pool_bytes
+    }
+
+    pub fn page_compression(&self) -> Compression {
+        self.page_compression
+    }
+
+    pub fn wal_compression(&self) -> Compression {
+        self.wal_compression
+    }
+
     pub fn routing_mode(&self) -> RoutingMode {
         self.routing_mode
     }
@@ -150,4 +289,36 @@ impl ServerCfg {
     pub fn page_size(&self) -> usize {
         self.page_size
     }
+
+    pub fn encryption_key_file(&self) -> Option<&str> {
+        self.encryption_key_file.as_deref()
+    }
+
+    pub fn wal_archive_dir(&self) -> Option<&str> {
+        self.wal_archive_dir.as_deref()
+    }
+
+    pub fn recovery_target(&self) -> RecoveryTarget {
+        self.recovery_target
+    }
+
+    pub fn read_replica(&self) -> bool {
+        self.read_replica
+    }
+
+    /// Loads the configured key file and checks it against the data
+    /// directory; `None` without a key file.
+    pub fn load_encryption_keys(&self) -> RS<Option<Arc<Keyring>>> {
+        self.encryption_key_file
+            .as_ref()
+            .map(|key_file| load_key_file(Path::new(key_file), Path::new(&self.data_dir)))
+            .transpose()
+    }
+
+    /// Checks the configured page size against the one recorded for the data
+    /// directory, recording it for a new directory. Must run before any
+    /// storage is opened.
+    pub fn check_page_size(&self) -> RS<()> {
+        check_data_dir_page_size(Path::new(&self.data_dir), self.page_size)
+    }
 }
@@ -7,7 +7,9 @@ use mudu::mudu_error;
 use crate::server::async_func_runtime::AsyncFuncInvokerPtr;
 use crate::server::procedure_runtimes::ProcedureRuntimes;
 use crate::server::server_cfg::ServerCfg;
+use crate::server::worker_backup::BackupCoordinator;
 use crate::server::worker_registry::{load_or_create_worker_registry, WorkerRegistry};
+use crate::storage::encryption::Keyring;
 use crate::wal::worker_log::WorkerLogBatching;
 use mudu_sys::contract::async_io_provider::AsyncIoProvider;
 
@@ -16,16 +18,21 @@ pub struct ServerRuntimeDeps {
     log_batching: WorkerLogBatching,
     procedure_runtimes: ProcedureRuntimes,
     worker_registry: Arc<WorkerRegistry>,
+    backups: Arc<BackupCoordinator>,
+    keyring: Option<Arc<Keyring>>,
     async_runtime: Option<Arc<dyn AsyncIoProvider>>,
 }
 
 impl ServerRuntimeDeps {
     pub fn from_cfg(cfg: &ServerCfg) -> RS<Self> {
         let worker_registry = load_or_create_worker_registry(cfg.log_dir(), cfg.worker_count())?;
+        let keyring = cfg.load_encryption_keys()?;
         Ok(Self {
             log_batching: WorkerLogBatching::default(),
             procedure_runtimes: ProcedureRuntimes::default(),
             worker_registry,
+            backups: Arc::new(BackupCoordinator::new()),
+            keyring,
             async_runtime: None,
         })
     }
@@ -82,6 +89,18 @@ impl ServerRuntimeDeps {
         self.worker_registry.clone()
     }
 
+    /// The server's online backups, shared by its workers and its
+    /// management API.
+    pub fn backup_coordinator(&self) -> Arc<BackupCoordinator> {
+        self.backups.clone()
+    }
+
+    /// The keyring loaded from the configured key file; `None` when the
+    /// server stores its data in plaintext.
+    pub fn keyring(&self) -> Option<Arc<Keyring>> {
+        self.keyring.clone()
+    }
+
     pub fn async_runtime(&self) -> Option<Arc<dyn AsyncIoProvider>> {
         self.async_runtime.clone()
     }
@@ -11,11 +11,18 @@ use mudu::common::id::OID;
 use mudu::common::result::RS;
 use mudu::error::ErrorCode;
 use mudu::mudu_error;
+use mudu_contract::database::kv_namespace::KvNamespace;
+use mudu_contract::database::kv_range::{KvRangeOptions, KvRangePage};
 use mudu_contract::database::result_set::ResultSetAsync;
 use mudu_contract::database::sql_params::SQLParams;
 use mudu_contract::database::sql_stmt::SQLStmt;
-use mudu_contract::protocol::{ProcedureInvokeRequest, ProcedureInvokeResponse};
+use mudu_contract::protocol::{
+    CdcSubscribeRequest, CdcSubscribeResponse, ProcedureInvokeRequest, ProcedureInvokeResponse,
+    WalFetchRequest, WalFetchResponse, WatchAckRequest, WatchCancelRequest, WatchRegisterRequest,
+    WatchRegisterResponse,
+};
 use std::sync::Arc;
+use std::time::Duration;
 
 use crate::x_engine::api::XContract;
 
@@ -91,10 +98,126 @@ impl WorkerLocal for SessionBoundWorkerRuntime {
             .await
     }
 
+    async fn put_with_ttl_async(
+        &self,
+        session_id: OID,
+        key: Vec<u8>,
+        value: Vec<u8>,
+        ttl: Duration,
+    ) -> RS<()> {
+        self.worker
+            .put_with_ttl_in_session_async(session_id, key, value, ttl)
+            .await
+    }
+
     async fn delete_async(&self, session_id: OID, key: &[u8]) -> RS<()> {
         self.worker.delete_in_session_async(session_id, key).await
     }
 
+    async fn put_many_async(&self, session_id: OID, items: Vec<KvItem>) -> RS<()> {
+        self.worker
+            .put_many_in_session_async(session_id, items)
+            .await
+    }
+
+    async fn delete_many_async(&self, session_id: OID, keys: Vec<Vec<u8>>) -> RS<()> {
+        self.worker
+            .delete_many_in_session_async(session_id, keys)
+            .await
+    }
+
+    async fn cas_async(
+        &self,
+        session_id: OID,
+        key: Vec<u8>,
+        expected: Option<Vec<u8>>,
+        value: Vec<u8>,
+    ) -> RS<bool> {
+        self.worker
+            .compare_and_swap_in_session_async(session_id, key, expected, value)
+            .await
+    }
+
+    async fn incr_async(&self, session_id: OID, key: Vec<u8>, delta: i64) -> RS<i64> {
+        self.worker
+            .increment_in_session_async(session_id, key, delta)
+            .await
+    }
+
+    async fn put_if_absent_async(&self, session_id: OID, key: Vec<u8>, value: Vec<u8>) -> RS<bool> {
+        self.worker
+            .put_if_absent_in_session_async(session_id, key, value)
+            .await
+    }
+
+    async fn kv_namespace_create_async(
+        &self,
+        session_id: OID,
+        namespace: &KvNamespace,
+        partition_rule: Option<&str>,
+    ) -> RS<()> {
+        self.worker
+            .kv_namespace_create_in_session_async(session_id, namespace, partition_rule)
+            .await
+    }
+
+    async fn kv_namespace_drop_async(&self, session_id: OID, namespace: &KvNamespace) -> RS<()> {
+        self.worker
+            .kv_namespace_drop_in_session_async(session_id, namespace)
+            .await
+    }
+
+    async fn kv_namespace_list_async(&self, app_name: &str) -> RS<Vec<KvNamespace>> {
+        self.worker.kv_namespace_list_async(app_name).await
+    }
+
+    async fn kv_namespace_get_async(
+        &self,
+        session_id: OID,
+        namespace: &KvNamespace,
+        key: &[u8],
+    ) -> RS<Option<Vec<u8>>> {
+        self.worker
+            .kv_namespace_get_in_session(session_id, namespace, key)
+            .await
+    }
+
+    async fn kv_namespace_put_async(
+        &self,
+        session_id: OID,
+        namespace: &KvNamespace,
+        key: Vec<u8>,
+        value: Vec<u8>,
+    ) -> RS<()> {
+        self.worker
+            .kv_namespace_put_in_session_async(session_id, namespace, key, value)
+            .await
+    }
+
+    async fn kv_namespace_delete_async(
+        &self,
+        session_id: OID,
+        namespace: &KvNamespace,
+        key: &[u8],
+    ) -> RS<()> {
+        self.worker
+            .kv_namespace_delete_in_session_async(session_id, namespace, key)
+            .await
+    }
+
+    async fn kv_namespace_range_async(
+        &self,
+        session_id: OID,
+        namespace: &KvNamespace,
+        start_key: &[u8],
+        end_key: &[u8],
+        options: &KvRangeOptions,
+    ) -> RS<KvRangePage> {
+        self.worker
+            .kv_namespace_range_in_session(session_id, namespace, start_key, end_key, options)
+            .await
+    }
+
     async fn get_async(&self, session_id: OID, key: &[u8]) -> RS<Option<Vec<u8>>> {
         self.worker.get_in_session(session_id, key).await
     }
@@ -104,9 +227,10 @@ impl WorkerLocal for SessionBoundWorkerRuntime {
         session_id: OID,
         start_key: &[u8],
         end_key: &[u8],
-    ) -> RS<Vec<KvItem>> {
+        options: &KvRangeOptions,
+    ) -> RS<KvRangePage> {
         self.worker
-            .range_in_session(session_id, start_key, end_key)
+            .range_in_session(session_id, start_key, end_key, options)
             .await
     }
 
@@ -150,6 +274,14 @@ impl RequestResponseWorker for SessionBoundWorkerRuntime {
         self.worker.close_session(conn_id, session_id)
     }
 
+    async fn ensure_app_installed(&self, app: &str) -> RS<()> {
+        self.worker.ensure_app_installed(app).await
+    }
+
+    fn session_app(&self, session_id: OID) -> RS<String> {
+        self.worker.session_app(session_id)
+    }
+
     async fn handle_procedure_request(
         &self,
         conn_id: u64,
@@ -157,4 +289,28 @@ impl RequestResponseWorker for SessionBoundWorkerRuntime {
     ) -> RS<ProcedureInvokeResponse> {
         self.worker.handle_procedure_request(conn_id, request).await
     }
+
+    async fn cdc_subscribe(&self, request: &CdcSubscribeRequest) -> RS<CdcSubscribeResponse> {
+        self.worker.cdc_subscribe_async(request).await
+    }
+
+    async fn wal_fetch(&self, request: &WalFetchRequest) -> RS<WalFetchResponse> {
+        self.worker.wal_fetch_async(request).await
+    }
+
+    async fn watch_register(
+        &self,
+        conn_id: u64,
+        request: &WatchRegisterRequest,
+    ) -> RS<WatchRegisterResponse> {
+        self.worker.watch_register_async(conn_id, request).await
+    }
+
+    fn watch_ack(&self, conn_id: u64, request: &WatchAckRequest) -> RS<()> {
+        self.worker.watch_ack(conn_id, request)
+    }
+
+    fn watch_cancel(&self, conn_id: u64, request: &WatchCancelRequest) -> RS<()> {
+        self.worker.watch_cancel(conn_id, request)
+    }
 }
@@ -0,0 +1,129 @@
+use crate::x_engine::tx_mgr::{DurabilityMode, IsolationLevel, TxOptions};
+use std::time::Duration;
+
+/// Transaction settings attached to a session.
+///
+/// Every transaction the session begins copies these settings, so a change
+/// only affects transactions that start afterwards.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct SessionOptions {
+    isolation_level: IsolationLevel,
+    read_only: bool,
+    lock_timeout: Option<Duration>,
+    statement_timeout: Option<Duration>,
+    durability: DurabilityMode,
+}
+
+/// Partial change of [`SessionOptions`] carried by a session open request.
+///
+/// Fields left as `None` keep the current value of the session.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct SessionOptionsUpdate {
+    pub isolation_level: Option<IsolationLevel>,
+    pub read_only: Option<bool>,
+    /// Milliseconds commit waits for conflicting commit locks; `0` does not wait.
+    pub lock_timeout_ms: Option<u64>,
+    /// Milliseconds a statement may run; `0` disables the limit.
+    pub statement_timeout_ms: Option<u64>,
+    pub durability: Option<DurabilityMode>,
+}
+
+impl SessionOptions {
+    pub fn isolation_level(&self) -> IsolationLevel {
+        self.isolation_level
+    }
+
+    pub fn read_only(&self) -> bool {
+        self.read_only
+    }
+
+    pub fn lock_timeout(&self) -> Option<Duration> {
+        self.lock_timeout
+    }
+
+    pub fn statement_timeout(&self) -> Option<Duration> {
+        self.statement_timeout
+    }
+
+    pub fn durability(&self) -> DurabilityMode {
+        self.durability
+    }
+
+    pub fn tx_options(&self) -> TxOptions {
+        TxOptions {
+            isolation_level: self.isolation_level,
+            read_only: self.read_only,
+            lock_timeout: self.lock_timeout,
+            durability: self.durability,
+        }
+    }
+
+    pub fn apply(&mut self, update: &SessionOptionsUpdate) {
+        if let Some(isolation_level) = update.isolation_level {
+            self.isolation_level = isolation_level;
+        }
+        if let Some(read_only) = update.read_only {
+            self.read_only = read_only;
+        }
+        if let Some(millis) = update.lock_timeout_ms {
+            self.lock_timeout = non_zero_millis(millis);
+        }
+        if let Some(millis) = update.statement_timeout_ms {
+            self.statement_timeout = non_zero_millis(millis);
+        }
+        if let Some(durability) = update.durability {
+            self.durability = durability;
+        }
+    }
+}
+
+impl SessionOptionsUpdate {
+    pub fn is_empty(&self) -> bool {
+        *self == Self::default()
+    }
+}
+
+fn non_zero_millis(millis: u64) -> Option<Duration> {
+    (millis != 0).then(|| Duration::from_millis(millis))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{SessionOptions, SessionOptionsUpdate};
+    use crate::x_engine::tx_mgr::{DurabilityMode, IsolationLevel};
+    use std::time::Duration;
+
+    #[test]
+    fn apply_only_changes_provided_fields() {
+        let mut options = SessionOptions::default();
+        options.apply(&SessionOptionsUpdate {
+            isolation_level: Some(IsolationLevel::Serializable),
+            lock_timeout_ms: Some(50),
+            ..SessionOptionsUpdate::default()
+        });
+        options.apply(&SessionOptionsUpdate {
+            durability: Some(DurabilityMode::Async),
+            ..SessionOptionsUpdate::default()
+        });
+
+        assert_eq!(options.isolation_level(), IsolationLevel::Serializable);
+        assert_eq!(options.lock_timeout(), Some(Duration::from_millis(50)));
+        assert_eq!(options.durability(), DurabilityMode::Async);
+        assert!(!options.read_only());
+        assert_eq!(options.statement_timeout(), None);
+    }
+
+    #[test]
+    fn zero_timeout_clears_limit() {
+        let mut options = SessionOptions::default();
+        options.apply(&SessionOptionsUpdate {
+            statement_timeout_ms: Some(100),
+            ..SessionOptionsUpdate::default()
+        });
+        options.apply(&SessionOptionsUpdate {
+            statement_timeout_ms: Some(0),
+            ..SessionOptionsUpdate::default()
+        });
+        assert_eq!(options.statement_timeout(), None);
+    }
+}
@@ -0,0 +1,270 @@
+use crate::x_engine::tx_mgr::{PhysicalRelationId, TxReadSet};
+use mudu::common::result::RS;
+use mudu::error::ErrorCode;
+use mudu::mudu_error;
+use mudu_sys::sync::SMutex;
+use std::collections::{BTreeMap, BTreeSet};
+
+/// Serializable snapshot isolation (SSI) bookkeeping for one worker.
+///
+/// Only serializable transactions are registered. A rw-antidependency
+/// `R -> W` exists when `R` read a key that a concurrent transaction `W`
+/// wrote. Every such edge is detected when the later of the two
+/// transactions commits: the committer's read set is checked against the
+/// write sets of concurrent committed transactions and its write set against
+/// their read sets.
+///
+/// A transaction is rejected at commit when it would become a pivot (both an
+/// incoming and an outgoing edge), or when it would turn an already committed
+/// transaction into a pivot. Committed records are retained until every
+/// transaction that overlapped with them has finished.
+pub struct SsiMgr {
+    state: SMutex<SsiState>,
+}
+
+#[derive(Default)]
+struct SsiState {
+    next_seq: u64,
+    // xid -> begin sequence of active serializable transactions.
+    active: BTreeMap<u64, u64>,
+    committed: Vec<SsiCommittedTx>,
+}
+
+struct SsiCommittedTx {
+    commit_seq: u64,
+    reads: TxReadSet,
+    writes: BTreeSet<(PhysicalRelationId, Vec<u8>)>,
+    in_conflict: bool,
+    out_conflict: bool,
+}
+
+impl SsiMgr {
+    pub fn new() -> Self {
+        Self {
+            state: SMutex::new(SsiState::default()),
+        }
+    }
+
+    /// Registers a serializable transaction at begin.
+    pub fn begin(&self, xid: u64) -> RS<()> {
+        let mut state = self.state.lock()?;
+        state.next_seq += 1;
+        let begin_seq = state.next_seq;
+        state.active.insert(xid, begin_seq);
+        Ok(())
+    }
+
+    /// Forgets an active transaction that aborted or was already validated.
+    pub fn finish(&self, xid: u64) -> RS<()> {
+        let mut state = self.state.lock()?;
+        if state.active.remove(&xid).is_some() {
+            state.prune();
+        }
+        Ok(())
+    }
+
+    /// Validates the commit of `xid` and records it as committed on success.
+    ///
+    /// Transactions that were not registered through [`Self::begin`] are not
+    /// checked.
+    pub fn validate_commit(
+        &self,
+        xid: u64,
+        reads: TxReadSet,
+        writes: &[(PhysicalRelationId, Vec<u8>)],
+    ) -> RS<()> {
+        let mut state = self.state.lock()?;
+        let Some(begin_seq) = state.active.get(&xid).copied() else {
+            return Ok(());
+        };
+        let writes: BTreeSet<_> = writes.iter().cloned().collect();
+        let mut in_conflict = false;
+        let mut out_conflict = false;
+        // (index into committed, new in_conflict, new out_conflict)
+        let mut touched = Vec::new();
+        for (index, other) in state.committed.iter().enumerate() {
+            if other.commit_seq <= begin_seq {
+                continue;
+            }
+            // `xid` read something `other` overwrote: xid -> other.
+            let reads_other_write = other
+                .writes
+                .iter()
+                .any(|(relation_id, key)| reads.covers(*relation_id, key));
+            // `other` read something `xid` overwrites: other -> xid.
+            let other_reads_write = writes
+                .iter()
+                .any(|(relation_id, key)| other.reads.covers(*relation_id, key));
+            if reads_other_write || other_reads_write {
+                out_conflict |= reads_other_write;
+                in_conflict |= other_reads_write;
+                touched.push((index, reads_other_write, other_reads_write));
+            }
+        }
+        let committed_pivot = touched.iter().any(|(index, other_in, other_out)| {
+            let other = &state.committed[*index];
+            (other.in_conflict || *other_in) && (other.out_conflict || *other_out)
+        });
+        if (in_conflict && out_conflict) || committed_pivot {
+            state.active.remove(&xid);
+            state.prune();
+            return Err(mudu_error!(
+                ErrorCode::Transaction,
+                format!(
+                    "could not serialize transaction {} due to read/write dependencies among transactions",
+                    xid
+                )
+            ));
+        }
+        for (index, other_in, other_out) in touched {
+            let other = &mut state.committed[index];
+            other.in_conflict |= other_in;
+            other.out_conflict |= other_out;
+        }
+        state.active.remove(&xid);
+        state.next_seq += 1;
+        let commit_seq = state.next_seq;
+        state.committed.push(SsiCommittedTx {
+            commit_seq,
+            reads,
+            writes,
+            in_conflict,
+            out_conflict,
+        });
+        state.prune();
+        Ok(())
+    }
+
+    #[cfg(test)]
+    fn retained_committed(&self) -> usize {
+        self.state
+            .lock()
+            .map(|state| state.committed.len())
+            .unwrap_or_default()
+    }
+}
+
+impl Default for SsiMgr {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl SsiState {
+    fn prune(&mut self) {
+        // A committed record can only gain new edges from transactions that
+        // began before it committed.
+        match self.active.values().min().copied() {
+            Some(oldest_begin) => self
+                .committed
+                .retain(|committed| committed.commit_seq > oldest_begin),
+            None => self.committed.clear(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(
+        clippy::unwrap_used,
+        clippy::expect_used,
+        clippy::panic,
+        clippy::todo,
+        clippy::unimplemented
+    )]
+
+    use super::SsiMgr;
+    use crate::x_engine::tx_mgr::{PhysicalRelationId, TxReadSet};
+    use std::ops::Bound;
+
+    const R: PhysicalRelationId = PhysicalRelationId {
+        table_id: 1,
+        partition_id: 0,
+    };
+
+    fn reads(keys: &[&[u8]]) -> TxReadSet {
+        let mut read_set = TxReadSet::default();
+        for key in keys {
+            read_set.add_key(R, key.to_vec());
+        }
+        read_set
+    }
+
+    fn writes(keys: &[&[u8]]) -> Vec<(PhysicalRelationId, Vec<u8>)> {
+        keys.iter().map(|key| (R, key.to_vec())).collect()
+    }
+
+    #[test]
+    fn write_skew_aborts_second_committer() {
+        let mgr = SsiMgr::new();
+        mgr.begin(1).unwrap();
+        mgr.begin(2).unwrap();
+        mgr.validate_commit(1, reads(&[b"x", b"y"]), &writes(&[b"x"]))
+            .unwrap();
+        let err = mgr
+            .validate_commit(2, reads(&[b"x", b"y"]), &writes(&[b"y"]))
+            .unwrap_err();
+        assert!(err.to_string().contains("could not serialize"));
+    }
+
+    #[test]
+    fn disjoint_read_write_sets_commit() {
+        let mgr = SsiMgr::new();
+        mgr.begin(1).unwrap();
+        mgr.begin(2).unwrap();
+        mgr.validate_commit(1, reads(&[b"x"]), &writes(&[b"x"]))
+            .unwrap();
+        mgr.validate_commit(2, reads(&[b"y"]), &writes(&[b"y"]))
+            .unwrap();
+        assert_eq!(mgr.retained_committed(), 0);
+    }
+
+    #[test]
+    fn range_read_detects_phantom_write_skew() {
+        let mgr = SsiMgr::new();
+        mgr.begin(1).unwrap();
+        mgr.begin(2).unwrap();
+        let mut range_a = TxReadSet::default();
+        range_a.add_range(
+            R,
+            Bound::Included(b"a".to_vec()),
+            Bound::Excluded(b"m".to_vec()),
+        );
+        let range_b = range_a.clone();
+        mgr.validate_commit(1, range_a, &writes(&[b"c"])).unwrap();
+        assert!(mgr.validate_commit(2, range_b, &writes(&[b"d"])).is_err());
+    }
+
+    #[test]
+    fn transactions_that_do_not_overlap_are_not_checked() {
+        let mgr = SsiMgr::new();
+        mgr.begin(1).unwrap();
+        mgr.validate_commit(1, reads(&[b"x", b"y"]), &writes(&[b"x"]))
+            .unwrap();
+        mgr.begin(2).unwrap();
+        mgr.validate_commit(2, reads(&[b"x", b"y"]), &writes(&[b"y"]))
+            .unwrap();
+    }
+
+    #[test]
+    fn unregistered_transactions_are_not_checked() {
+        let mgr = SsiMgr::new();
+        mgr.begin(1).unwrap();
+        mgr.validate_commit(1, reads(&[b"x", b"y"]), &writes(&[b"x"]))
+            .unwrap();
+        mgr.validate_commit(2, reads(&[b"x", b"y"]), &writes(&[b"y"]))
+            .unwrap();
+    }
+
+    #[test]
+    fn finish_releases_retained_records() {
+        let mgr = SsiMgr::new();
+        mgr.begin(1).unwrap();
+        mgr.begin(2).unwrap();
+        mgr.validate_commit(1, reads(&[b"x"]), &writes(&[b"x"]))
+            .unwrap();
+        assert_eq!(mgr.retained_committed(), 1);
+        mgr.finish(2).unwrap();
+        assert_eq!(mgr.retained_committed(), 0);
+    }
+}
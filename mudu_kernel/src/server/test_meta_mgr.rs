@@ -9,6 +9,8 @@ use mudu::error::ErrorCode;
 use mudu::mudu_error;
 
 use crate::contract::meta_mgr::MetaMgr;
+use crate::contract::partition_rule::PartitionRuleDesc;
+use crate::contract::partition_rule_binding::TablePartitionBinding;
 use crate::contract::schema_table::SchemaTable;
 use crate::contract::table_desc::TableDesc;
 use crate::contract::table_info::TableInfo;
@@ -16,6 +18,8 @@ use crate::contract::table_info::TableInfo;
 pub(crate) struct TestMetaMgr {
     schemas: SMutex<HashMap<OID, SchemaTable>>,
     tables: SMutex<HashMap<OID, Arc<TableDesc>>>,
+    rules: SMutex<HashMap<OID, PartitionRuleDesc>>,
+    bindings: SMutex<HashMap<OID, TablePartitionBinding>>,
 }
 
 impl TestMetaMgr {
@@ -23,6 +27,8 @@ impl TestMetaMgr {
         Self {
             schemas: SMutex::new(HashMap::new()),
             tables: SMutex::new(HashMap::new()),
+            rules: SMutex::new(HashMap::new()),
+            bindings: SMutex::new(HashMap::new()),
         }
     }
 }
@@ -65,9 +71,54 @@ impl MetaMgr for TestMetaMgr {
     async fn drop_table(&self, table_id: OID) -> RS<()> {
         self.schemas.lock().unwrap().remove(&table_id);
         self.tables.lock().unwrap().remove(&table_id);
+        self.bindings.lock().unwrap().remove(&table_id);
         Ok(())
     }
 
+    async fn create_partition_rule(&self, rule: &PartitionRuleDesc) -> RS<()> {
+        self.rules.lock().unwrap().insert(rule.oid, rule.clone());
+        Ok(())
+    }
+
+    async fn get_partition_rule_by_id(&self, oid: OID) -> RS<PartitionRuleDesc> {
+        self.rules
+            .lock()
+            .unwrap()
+            .get(&oid)
+            .cloned()
+            .ok_or_else(|| {
+                mudu_error!(
+                    ErrorCode::EntityNotFound,
+                    format!("no such partition rule {}", oid)
+                )
+            })
+    }
+
+    async fn get_partition_rule_by_name(&self, name: &str) -> RS<Option<PartitionRuleDesc>> {
+        Ok(self
+            .rules
+            .lock()
+            .unwrap()
+            .values()
+            .find(|rule| rule.name == name)
+            .cloned())
+    }
+
+    async fn bind_table_partition(&self, binding: &TablePartitionBinding) -> RS<()> {
+        self.bindings
+            .lock()
+            .unwrap()
+            .insert(binding.table_id, binding.clone());
+        Ok(())
+    }
+
+    async fn get_table_partition_binding(
+        &self,
+        table_id: OID,
+    ) -> RS<Option<TablePartitionBinding>> {
+        Ok(self.bindings.lock().unwrap().get(&table_id).cloned())
+    }
+
     async fn list_schemas(&self) -> RS<Vec<SchemaTable>> {
         Ok(self.schemas.lock().unwrap().values().cloned().collect())
     }
@@ -0,0 +1,16 @@
+use std::time::Duration;
+
+/// Time between two version GC steps of a worker.
+pub(crate) const VERSION_GC_INTERVAL: Duration = Duration::from_millis(100);
+/// Rows one GC step visits before handing control back to the worker loop.
+pub(crate) const VERSION_GC_BATCH_ROWS: usize = 256;
+
+/// Outcome of one version GC step.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct VersionGcStats {
+    pub pruned_versions: usize,
+    pub removed_rows: usize,
+    /// Set when the step finished a sweep over all relations and kv rows of
+    /// the worker; the next step starts over from the first relation.
+    pub sweep_done: bool,
+}
@@ -1,36 +1,69 @@
 use crate::contract::meta_mgr::MetaMgr;
 use crate::mudu_conn::mudu_conn_core::MuduConnCore;
 use crate::server::async_func_runtime::AsyncFuncInvokerPtr;
+use crate::server::kv_ttl::{self, KV_TTL_SWEEP_BATCH_ROWS};
 use crate::server::message_bus_api::ServerInstanceId;
 use crate::server::routing::SessionOpenConfig;
 use crate::server::session_bound_worker_runtime::{
     as_worker_local_ref, new_session_bound_worker_runtime,
 };
+use crate::server::session_options::SessionOptionsUpdate;
+use crate::server::version_gc::{VersionGcStats, VERSION_GC_BATCH_ROWS};
+use crate::server::worker_backup::{BackupCoordinator, BackupRequest};
+use crate::server::worker_cdc::poll_changes;
+use crate::server::worker_checkpoint::{CheckpointPolicy, CheckpointStats};
 use crate::server::worker_local::{
     set_current_worker_local, try_current_worker_local, unset_current_worker_local, WorkerExecute,
     WorkerLocalRef,
 };
-use crate::server::worker_registry::{WorkerIdentity, WorkerRegistry};
+use crate::server::worker_recovery_target::RecoveryTarget;
+use crate::server::worker_registry::{identity_marker_paths, WorkerIdentity, WorkerRegistry};
+use crate::server::worker_replication::{
+    enable_replica, read_log_frames, record_applied, take_shipped_frames, ReplicaReplayHandler,
+};
 use crate::server::worker_session_manager::{SessionContext, WorkerSessionManager};
 use crate::server::worker_snapshot::KvItem;
+use crate::server::worker_watch::{poll_watch_events, Watch, WatchRegistry};
 use crate::server::x_contract::{WorkerXContract, WorkerXContractWorkerLogParams};
-use crate::wal::worker_log::{ChunkedWorkerLogBackend, WorkerLogBatching, WorkerLogLayout};
+use crate::storage::backup::{copy_into_backup, BackupFile, WorkerBackup};
+use crate::storage::buffer_pool::BufferPoolStats;
+use crate::storage::compression::{Compression, CompressionStatsSnapshot};
+use crate::storage::encryption::{Keyring, ReencryptStats, KEY_CHECK_FILE, REENCRYPT_BATCH_PAGES};
+use crate::storage::page_size::PAGE_SIZE_META_FILE;
+use crate::wal::lsn::LSN;
+use crate::wal::typed_worker_log::WorkerLogRecoveryHandler;
+use crate::wal::worker_log::{
+    ChunkedWorkerLogBackend, WorkerLogBackend, WorkerLogBatching, WorkerLogLayout,
+};
 use crate::wal::xl_batch::XLBatch;
 use crate::x_engine::api::XContract;
-use crate::x_engine::tx_mgr::TxMgr;
+use crate::x_engine::tx_mgr::{PhysicalRelationId, TxMgr};
 use mudu::common::id::OID;
 use mudu::common::result::RS;
-use mudu::error::ErrorCode;
+use mudu::error::{ErrorCode, MuduError};
 use mudu::mudu_error;
+use mudu_contract::database::kv_atomic;
+use mudu_contract::database::kv_namespace::KvNamespace;
+use mudu_contract::database::kv_range::{KvRangeOptions, KvRangePage};
 use mudu_contract::database::result_set::ResultSetAsync;
 use mudu_contract::database::sql_params::SQLParams;
 use mudu_contract::database::sql_stmt::SQLStmt;
-use mudu_contract::protocol::{ProcedureInvokeRequest, ProcedureInvokeResponse};
+use mudu_contract::protocol::{
+    CdcSubscribeRequest, CdcSubscribeResponse, ProcedureInvokeRequest, ProcedureInvokeResponse,
+    WalFetchRequest, WalFetchResponse, WatchAckRequest, WatchCancelRequest, WatchRegisterRequest,
+    WatchRegisterResponse,
+};
 use mudu_sys::contract::async_io_provider::AsyncIoProvider;
+use mudu_sys::sync::async_::futures_mutex::FMutex;
 use mudu_utils::task_trace;
 use std::collections::BTreeMap;
+use std::future::Future;
+use std::ops::Bound;
+use std::path::Path;
 use std::sync::atomic::AtomicUsize;
 use std::sync::Arc;
+use std::time::Duration;
+use tracing::info;
 
 #[derive(Clone)]
 /// Per-worker execution context used by the `client` backend.
@@ -55,6 +88,9 @@ pub struct WorkerRuntime {
     procedure_runtime: Option<AsyncFuncInvokerPtr>,
     session_manager: Arc<WorkerSessionManager>,
     registry: Arc<WorkerRegistry>,
+    watches: Arc<WatchRegistry>,
+    backups: Arc<BackupCoordinator>,
+    async_commit_flush_interval: Duration,
 }
 
 /// Backward-compatible name for callers that still refer to the historical
@@ -67,9 +103,29 @@ pub struct WorkerRuntimeParams {
     pub log_dir: String,
     pub data_dir: String,
     pub log_chunk_size: u64,
+    pub history_retention: Duration,
+    pub checkpoint_policy: CheckpointPolicy,
+    /// Time between fsyncs of the worker log for commits with asynchronous
+    /// durability.
+    pub async_commit_flush_interval: Duration,
+    pub buffer_pool_bytes: u64,
+    pub page_compression: Compression,
+    pub wal_compression: Compression,
+    /// Seals the worker's relations, catalog, checkpoints and log; `None`
+    /// stores them in plaintext.
+    pub keyring: Option<Arc<Keyring>>,
+    /// Archive directory of the worker log; see
+    /// [`WorkerLogLayout::with_archive_dir`].
+    pub wal_archive_dir: Option<String>,
+    pub recovery_target: RecoveryTarget,
+    /// Serve a read replica: commits are rejected and the worker log is fed
+    /// from the primary, see [`crate::server::worker_replication`].
+    pub read_replica: bool,
     pub log_batching: WorkerLogBatching,
     pub procedure_runtime: Option<AsyncFuncInvokerPtr>,
     pub registry: Arc<WorkerRegistry>,
+    /// The server's online backups; shared by all its workers.
+    pub backups: Arc<BackupCoordinator>,
     pub async_runtime: Option<Arc<dyn AsyncIoProvider>>,
     pub server_instance_id: ServerInstanceId,
 }
@@ -87,9 +143,20 @@ impl WorkerRuntime {
             log_dir,
             data_dir,
             log_chunk_size,
+            history_retention,
+            checkpoint_policy,
+            async_commit_flush_interval,
+            buffer_pool_bytes,
+            page_compression,
+            wal_compression,
+            keyring,
+            wal_archive_dir,
+            recovery_target,
+            read_replica,
             log_batching,
             procedure_runtime,
             registry,
+            backups,
             async_runtime,
             server_instance_id,
         } = config;
@@ -110,8 +177,15 @@ impl WorkerRuntime {
                     "worker registry has no default global worker"
                 )
             })?;
-        let log_layout =
-            WorkerLogLayout::new(log_dir, worker_id, log_chunk_size)?.with_batching(log_batching);
+        // Log frames and pages written by older versions are upgraded on read.
+        crate::compat::install_compatibility_router();
+        let mut log_layout = WorkerLogLayout::new(log_dir, worker_id, log_chunk_size)?
+            .with_batching(log_batching)
+            .with_compression(wal_compression)
+            .with_keyring(keyring);
+        if let Some(wal_archive_dir) = wal_archive_dir {
+            log_layout = log_layout.with_archive_dir(wal_archive_dir);
+        }
 
         let contract = Arc::new(
             WorkerXContract::with_worker_log_and_data_dir_and_runtime(
@@ -129,6 +203,15 @@ impl WorkerRuntime {
             )
             .await?,
         );
+        contract.set_history_retention(history_retention);
+        contract.set_checkpoint_policy(checkpoint_policy)?;
+        contract.set_recovery_target(recovery_target)?;
+        contract.set_buffer_pool_bytes(buffer_pool_bytes);
+        contract.set_page_compression(page_compression)?;
+        if read_replica {
+            enable_replica(contract.data_dir())?;
+            contract.set_replica(true);
+        }
         let session_manager = Arc::new(WorkerSessionManager::new(
             active_sessions,
             contract.meta_mgr(),
@@ -145,6 +228,9 @@ impl WorkerRuntime {
             procedure_runtime,
             session_manager,
             registry,
+            watches: Arc::new(WatchRegistry::new()),
+            backups,
+            async_commit_flush_interval,
         })
     }
 
@@ -152,6 +238,11 @@ impl WorkerRuntime {
         self.server_instance_id
     }
 
+    /// Whether the worker seals what it writes with an encryption keyring.
+    pub fn is_encrypted(&self) -> bool {
+        self.log_layout.keyring().is_some()
+    }
+
     pub async fn delete_async(&self, key: &[u8]) -> RS<()> {
         self.contract.worker_delete_async(key).await
     }
@@ -211,10 +302,15 @@ impl WorkerRuntime {
     }
 
     pub fn close_session(&self, conn_id: u64, session_id: OID) -> RS<bool> {
-        self.session_manager.close_session(conn_id, session_id)
+        let closed = self.session_manager.close_session(conn_id, session_id)?;
+        if closed {
+            self.watches.remove_session(conn_id, session_id)?;
+        }
+        Ok(closed)
     }
 
     pub fn close_connection_sessions(&self, conn_id: u64) -> RS<()> {
+        self.watches.remove_connection(conn_id)?;
         self.session_manager.close_connection_sessions(conn_id)
     }
 
@@ -230,6 +326,85 @@ impl WorkerRuntime {
         self.session_manager.session_context(session_id)
     }
 
+    /// Changes the session options for transactions the session begins
+    /// later. A transaction that is already open keeps its options.
+    pub fn update_session_options(&self, session_id: OID, update: &SessionOptionsUpdate) -> RS<()> {
+        self.session_manager
+            .update_session_options(session_id, update)
+    }
+
+    /// The app the session was bound to when it was opened.
+    pub fn session_app(&self, session_id: OID) -> RS<String> {
+        self.session_manager.session_app(session_id)
+    }
+
+    /// Fails unless `app` is installed in the procedure runtime; a session
+    /// may only be bound to such an app.
+    pub async fn ensure_app_installed(&self, app: &str) -> RS<()> {
+        let procedure_runtime = self.procedure_runtime.as_ref().ok_or_else(|| {
+            mudu_error!(
+                ErrorCode::NotImplemented,
+                "procedure runtime is not configured"
+            )
+        })?;
+        if procedure_runtime.app_installed(app).await? {
+            Ok(())
+        } else {
+            Err(mudu_error!(
+                ErrorCode::EntityNotFound,
+                format!("no such application {}", app)
+            ))
+        }
+    }
+
+    fn begin_session_worker_tx(&self, session_id: OID) -> RS<Arc<dyn TxMgr>> {
+        let options = self.session_manager.session_options(session_id)?;
+        self.contract
+            .worker_begin_tx_with_options(options.tx_options())
+    }
+
+    fn ensure_session_writable(&self, session_id: OID) -> RS<()> {
+        if self
+            .session_manager
+            .session_options(session_id)?
+            .read_only()
+        {
+            return Err(mudu_error!(
+                ErrorCode::Transaction,
+                format!("cannot write in read-only session {}", session_id)
+            ));
+        }
+        Ok(())
+    }
+
+    /// Runs one SQL statement under the session's statement timeout.
+    ///
+    /// A statement that runs out of time is dropped wherever it stopped, so
+    /// part of its writes may already be staged: the session transaction is
+    /// rolled back with it.
+    async fn run_with_statement_timeout<T>(
+        &self,
+        oid: OID,
+        statement: impl Future<Output = RS<T>>,
+    ) -> RS<T> {
+        let Some(timeout) = self
+            .session_manager
+            .session_options(oid)?
+            .statement_timeout()
+        else {
+            return statement.await;
+        };
+        // On the io_uring worker loop the timer is one of the worker ring's.
+        if let Some(result) = mudu_sys::timeout(timeout, statement).await {
+            return result;
+        }
+        if self.session_manager.has_session_tx(oid)? {
+            let tx_manager = self.session_manager.take_session_tx(oid)?;
+            self.contract.worker_rollback_tx(tx_manager)?;
+        }
+        Err(statement_timeout_error(timeout))
+    }
+
     pub async fn get_for_connection(
         &self,
         conn_id: u64,
@@ -261,9 +436,11 @@ impl WorkerRuntime {
         session_id: OID,
         start_key: &[u8],
         end_key: &[u8],
-    ) -> RS<Vec<KvItem>> {
+        options: &KvRangeOptions,
+    ) -> RS<KvRangePage> {
         self.ensure_session_owned_by_connection(conn_id, session_id)?;
-        self.range_in_session(session_id, start_key, end_key).await
+        self.range_in_session(session_id, start_key, end_key, options)
+            .await
     }
 
     pub(crate) async fn execute_tx_async(
@@ -274,7 +451,7 @@ impl WorkerRuntime {
         match instruction {
             WorkerExecute::BeginTx => self
                 .session_manager
-                .begin_session_tx(session_id, self.contract.worker_begin_tx()?),
+                .begin_session_tx(session_id, self.begin_session_worker_tx(session_id)?),
             WorkerExecute::CommitTx => {
                 let tx_manager = self.session_manager.take_session_tx(session_id)?;
                 self.contract.worker_commit_tx_async(tx_manager).await
@@ -292,14 +469,43 @@ impl WorkerRuntime {
         session_id: OID,
         key: Vec<u8>,
         value: Vec<u8>,
+    ) -> RS<()> {
+        self.stage_put_in_session_async(session_id, key, value, None)
+            .await
+    }
+
+    /// Puts a value that expires `ttl` after the write commits, see
+    /// [`crate::server::kv_ttl`].
+    pub(crate) async fn put_with_ttl_in_session_async(
+        &self,
+        session_id: OID,
+        key: Vec<u8>,
+        value: Vec<u8>,
+        ttl: Duration,
+    ) -> RS<()> {
+        let ttl_ms = kv_ttl::ttl_ms(ttl)?;
+        self.stage_put_in_session_async(session_id, key, value, Some(ttl_ms))
+            .await
+    }
+
+    async fn stage_put_in_session_async(
+        &self,
+        session_id: OID,
+        key: Vec<u8>,
+        value: Vec<u8>,
+        ttl_ms: Option<u64>,
     ) -> RS<()> {
         let trace = task_trace!();
         trace.watch("put.stage", "worker_put_in_session_start");
+        self.ensure_session_writable(session_id)?;
         let handled = self
             .session_manager
             .with_session_tx(session_id, |tx_manager| match tx_manager {
                 Some(tx_manager) => {
-                    tx_manager.put(key.clone(), value.clone());
+                    match ttl_ms {
+                        Some(ttl_ms) => tx_manager.put_with_ttl(key.clone(), value.clone(), ttl_ms),
+                        None => tx_manager.put(key.clone(), value.clone()),
+                    }
                     Ok(true)
                 }
                 None => Ok(false),
@@ -309,11 +515,19 @@ impl WorkerRuntime {
             Ok(())
         } else {
             trace.watch("put.stage", "worker_put_in_session_autocommit");
-            self.contract.worker_put_async(key, value).await
+            match ttl_ms {
+                Some(ttl_ms) => {
+                    self.contract
+                        .worker_put_with_ttl_async(key, value, ttl_ms)
+                        .await
+                }
+                None => self.contract.worker_put_async(key, value).await,
+            }
         }
     }
 
     pub(crate) async fn delete_in_session_async(&self, session_id: OID, key: &[u8]) -> RS<()> {
+        self.ensure_session_writable(session_id)?;
         let key_vec = key.to_vec();
         let handled = self
             .session_manager
@@ -331,6 +545,129 @@ impl WorkerRuntime {
         }
     }
 
+    pub(crate) async fn put_many_in_session_async(
+        &self,
+        session_id: OID,
+        items: Vec<KvItem>,
+    ) -> RS<()> {
+        self.write_many_in_session_async(session_id, |tx_manager| {
+            for item in &items {
+                tx_manager.put(item.key.clone(), item.value.clone());
+            }
+        })
+        .await
+    }
+
+    pub(crate) async fn delete_many_in_session_async(
+        &self,
+        session_id: OID,
+        keys: Vec<Vec<u8>>,
+    ) -> RS<()> {
+        self.write_many_in_session_async(session_id, |tx_manager| {
+            for key in &keys {
+                tx_manager.delete(key.clone());
+            }
+        })
+        .await
+    }
+
+    /// Applies a multi-key write as one unit: staged into the open session
+    /// transaction, or else committed in a transaction of its own so no
+    /// reader sees part of it.
+    async fn write_many_in_session_async<F>(&self, session_id: OID, stage: F) -> RS<()>
+    where
+        F: Fn(&Arc<dyn TxMgr>),
+    {
+        self.ensure_session_writable(session_id)?;
+        let handled = self
+            .session_manager
+            .with_session_tx(session_id, |tx_manager| match tx_manager {
+                Some(tx_manager) => {
+                    stage(&tx_manager);
+                    Ok(true)
+                }
+                None => Ok(false),
+            })?;
+        if handled {
+            return Ok(());
+        }
+        let tx_manager = self.begin_session_worker_tx(session_id)?;
+        stage(&tx_manager);
+        self.contract.worker_commit_tx_async(tx_manager).await
+    }
+
+    pub(crate) async fn compare_and_swap_in_session_async(
+        &self,
+        session_id: OID,
+        key: Vec<u8>,
+        expected: Option<Vec<u8>>,
+        value: Vec<u8>,
+    ) -> RS<bool> {
+        self.update_in_session_async(session_id, key, move |current| {
+            Ok(kv_atomic::compare_and_swap(
+                current.as_deref(),
+                expected.as_deref(),
+                &value,
+            ))
+        })
+        .await
+    }
+
+    pub(crate) async fn increment_in_session_async(
+        &self,
+        session_id: OID,
+        key: Vec<u8>,
+        delta: i64,
+    ) -> RS<i64> {
+        self.update_in_session_async(session_id, key, move |current| {
+            kv_atomic::increment(current.as_deref(), delta)
+        })
+        .await
+    }
+
+    pub(crate) async fn put_if_absent_in_session_async(
+        &self,
+        session_id: OID,
+        key: Vec<u8>,
+        value: Vec<u8>,
+    ) -> RS<bool> {
+        self.update_in_session_async(session_id, key, move |current| {
+            Ok(kv_atomic::put_if_absent(current.as_deref(), &value))
+        })
+        .await
+    }
+
+    /// Applies a single-key read-modify-write. Inside a session transaction
+    /// the read goes through the transaction and the write is staged, so a
+    /// concurrent commit of the key fails the transaction's write-write
+    /// conflict check; otherwise the contract applies it under the key lock.
+    async fn update_in_session_async<T, F>(&self, session_id: OID, key: Vec<u8>, update: F) -> RS<T>
+    where
+        F: FnOnce(Option<Vec<u8>>) -> RS<(Option<Vec<u8>>, T)>,
+    {
+        self.ensure_session_writable(session_id)?;
+        match self.session_manager.with_session_tx(session_id, Ok)? {
+            Some(tx_manager) => {
+                let current = self.get_in_session(session_id, &key).await?;
+                let (write, output) = update(current)?;
+                if let Some(value) = write {
+                    tx_manager.put(key, value);
+                }
+                Ok(output)
+            }
+            None => {
+                let lock_timeout = self
+                    .session_manager
+                    .session_options(session_id)?
+                    .tx_options()
+                    .lock_timeout;
+                self.contract
+                    .worker_kv_update_async(key, lock_timeout, update)
+                    .await
+            }
+        }
+    }
+
     pub(crate) async fn get_in_session(&self, session_id: OID, key: &[u8]) -> RS<Option<Vec<u8>>> {
         let tx_manager = self.session_manager.with_session_tx(session_id, Ok)?;
         let staged = tx_manager
@@ -340,6 +677,7 @@ impl WorkerRuntime {
             Some(value) => Ok(value),
             None => match tx_manager {
                 Some(tx_manager) => {
+                    tx_manager.record_read(PhysicalRelationId::WORKER_KV, key);
                     self.contract
                         .worker_get_with_snapshot_async(&tx_manager.snapshot(), key)
                         .await
@@ -354,27 +692,51 @@ impl WorkerRuntime {
         session_id: OID,
         start_key: &[u8],
         end_key: &[u8],
-    ) -> RS<Vec<KvItem>> {
+        options: &KvRangeOptions,
+    ) -> RS<KvRangePage> {
         let tx_manager = self.session_manager.with_session_tx(session_id, Ok)?;
+        let Some((start_key, end_key)) = options.bounds(start_key, end_key) else {
+            return Ok(KvRangePage::default());
+        };
+        let (start_key, end_key) = (start_key.as_slice(), end_key.as_slice());
         let staged = tx_manager
             .as_ref()
             .map(|tx_manager| tx_manager.staged_items_in_range(start_key, end_key))
             .unwrap_or_default();
 
+        // Staged writes override at most `staged.len()` stored rows, so
+        // reading that many more than a page needs keeps the merged page
+        // exact while the scan still stops early.
+        let reverse = options.reverse();
+        let limit = options
+            .fetch_limit()
+            .map(|fetch_limit| fetch_limit + staged.len());
         let mut merged = BTreeMap::new();
         let base_items = match tx_manager {
             Some(tx_manager) => {
+                let end = if end_key.is_empty() {
+                    Bound::Unbounded
+                } else {
+                    Bound::Excluded(end_key)
+                };
+                tx_manager.record_range_read(
+                    PhysicalRelationId::WORKER_KV,
+                    Bound::Included(start_key),
+                    end,
+                );
                 self.contract
                     .worker_range_scan_with_snapshot_async(
                         &tx_manager.snapshot(),
                         start_key,
                         end_key,
+                        reverse,
+                        limit,
                     )
                     .await?
             }
             None => {
                 self.contract
-                    .worker_range_scan_async(start_key, end_key)
+                    .worker_range_scan_async(start_key, end_key, reverse, limit)
                     .await?
             }
         };
@@ -384,10 +746,131 @@ impl WorkerRuntime {
         for (key, value) in staged {
             merged.insert(key, value);
         }
-        Ok(merged
-            .into_iter()
-            .filter_map(|(key, value)| value.map(|value| KvItem { key, value }))
-            .collect())
+        Ok(options.page_ascending(
+            merged
+                .into_iter()
+                .filter_map(|(key, value)| value.map(|value| (key, value)))
+                .collect(),
+        ))
+    }
+
+    pub(crate) async fn kv_namespace_create_in_session_async(
+        &self,
+        session_id: OID,
+        namespace: &KvNamespace,
+        partition_rule: Option<&str>,
+    ) -> RS<()> {
+        self.ensure_session_writable(session_id)?;
+        self.contract
+            .kv_namespace_create_async(namespace, partition_rule)
+            .await
+    }
+
+    pub(crate) async fn kv_namespace_drop_in_session_async(
+        &self,
+        session_id: OID,
+        namespace: &KvNamespace,
+    ) -> RS<()> {
+        self.ensure_session_writable(session_id)?;
+        self.contract.kv_namespace_drop_async(namespace).await
+    }
+
+    pub(crate) async fn kv_namespace_list_async(&self, app_name: &str) -> RS<Vec<KvNamespace>> {
+        self.contract.kv_namespace_list_async(app_name).await
+    }
+
+    pub(crate) async fn kv_namespace_get_in_session(
+        &self,
+        session_id: OID,
+        namespace: &KvNamespace,
+        key: &[u8],
+    ) -> RS<Option<Vec<u8>>> {
+        self.with_kv_namespace_tx(session_id, false, |tx_manager| async move {
+            self.contract
+                .kv_namespace_get_async(tx_manager.as_ref(), namespace, key)
+                .await
+        })
+        .await
+    }
+
+    pub(crate) async fn kv_namespace_put_in_session_async(
+        &self,
+        session_id: OID,
+        namespace: &KvNamespace,
+        key: Vec<u8>,
+        value: Vec<u8>,
+    ) -> RS<()> {
+        self.with_kv_namespace_tx(session_id, true, |tx_manager| async move {
+            self.contract
+                .kv_namespace_put_async(tx_manager.as_ref(), namespace, key, value)
+                .await
+        })
+        .await
+    }
+
+    pub(crate) async fn kv_namespace_delete_in_session_async(
+        &self,
+        session_id: OID,
+        namespace: &KvNamespace,
+        key: &[u8],
+    ) -> RS<()> {
+        self.with_kv_namespace_tx(session_id, true, |tx_manager| async move {
+            self.contract
+                .kv_namespace_delete_async(tx_manager.as_ref(), namespace, key)
+                .await
+        })
+        .await
+    }
+
+    pub(crate) async fn kv_namespace_range_in_session(
+        &self,
+        session_id: OID,
+        namespace: &KvNamespace,
+        start_key: &[u8],
+        end_key: &[u8],
+        options: &KvRangeOptions,
+    ) -> RS<KvRangePage> {
+        let Some((start_key, end_key)) = options.bounds(start_key, end_key) else {
+            return Ok(KvRangePage::default());
+        };
+        let items = self
+            .with_kv_namespace_tx(session_id, false, |tx_manager| async move {
+                self.contract
+                    .kv_namespace_range_async(tx_manager.as_ref(), namespace, &start_key, &end_key)
+                    .await
+            })
+            .await?;
+        Ok(options.page_ascending(items))
+    }
+
+    /// Runs a namespace operation in the open session transaction, or else
+    /// in a transaction of its own that commits writes and discards reads.
+    async fn with_kv_namespace_tx<T, F, Fut>(&self, session_id: OID, write: bool, op: F) -> RS<T>
+    where
+        F: FnOnce(Arc<dyn TxMgr>) -> Fut,
+        Fut: Future<Output = RS<T>>,
+    {
+        if write {
+            self.ensure_session_writable(session_id)?;
+        }
+        if let Some(tx_manager) = self.session_manager.with_session_tx(session_id, Ok)? {
+            return op(tx_manager).await;
+        }
+        let tx_manager = self.begin_session_worker_tx(session_id)?;
+        match op(tx_manager.clone()).await {
+            Ok(output) if write => {
+                self.contract.worker_commit_tx_async(tx_manager).await?;
+                Ok(output)
+            }
+            Ok(output) => {
+                self.contract.worker_rollback_tx(tx_manager)?;
+                Ok(output)
+            }
+            Err(error) => {
+                self.contract.worker_rollback_tx(tx_manager)?;
+                Err(error)
+            }
+        }
     }
 
     fn ensure_session_owned_by_connection(&self, conn_id: u64, session_id: OID) -> RS<()> {
@@ -403,7 +886,7 @@ impl WorkerRuntime {
         let trace = task_trace!();
         trace.watch("procedure.kernel.handle.stage", "enter");
         trace.watch("procedure.kernel.handle.conn_id", &conn_id.to_string());
-        let session_id = request.session_id() as OID;
+        let session_id = request.session_id();
         trace.watch(
             "procedure.kernel.handle.session_id",
             &session_id.to_string(),
@@ -571,14 +1054,17 @@ impl WorkerRuntime {
             false
         } else {
             self.session_manager
-                .begin_session_tx(oid, self.contract.worker_begin_tx()?)?;
+                .begin_session_tx(oid, self.begin_session_worker_tx(oid)?)?;
             true
         };
         let tx_mgr = self
             .sql_tx_mgr(oid)?
             .ok_or_else(|| mudu_error!(ErrorCode::Internal, "session transaction is missing"))?;
-        let result = self.run_sql_query_with_tx(core, sql, param, tx_mgr).await;
-        if started_tx {
+        let result = self
+            .run_with_statement_timeout(oid, self.run_sql_query_with_tx(core, sql, param, tx_mgr))
+            .await;
+        // A statement timeout already rolled the transaction back.
+        if started_tx && self.session_manager.has_session_tx(oid)? {
             let tx_manager = self.session_manager.take_session_tx(oid)?;
             if result.is_ok() {
                 self.contract.worker_commit_tx_async(tx_manager).await?;
@@ -622,7 +1108,7 @@ impl WorkerRuntime {
         } else {
             trace.watch("procedure.worker_execute.stage", "session_begin_tx_start");
             self.session_manager
-                .begin_session_tx(oid, self.contract.worker_begin_tx()?)?;
+                .begin_session_tx(oid, self.begin_session_worker_tx(oid)?)?;
             trace.watch("procedure.worker_execute.stage", "session_begin_tx_done");
             true
         };
@@ -630,8 +1116,11 @@ impl WorkerRuntime {
             .sql_tx_mgr(oid)?
             .ok_or_else(|| mudu_error!(ErrorCode::Internal, "session transaction is missing"))?;
         trace.watch("procedure.worker_execute.stage", "run_sql_execute_start");
-        let result = self.run_sql_execute_with_tx(core, sql, param, tx_mgr).await;
-        if started_tx {
+        let result = self
+            .run_with_statement_timeout(oid, self.run_sql_execute_with_tx(core, sql, param, tx_mgr))
+            .await;
+        // A statement timeout already rolled the transaction back.
+        if started_tx && self.session_manager.has_session_tx(oid)? {
             let tx_manager = self.session_manager.take_session_tx(oid)?;
             if result.is_ok() {
                 trace.watch("procedure.worker_execute.stage", "session_commit_start");
@@ -686,7 +1175,7 @@ impl WorkerRuntime {
             false
         } else {
             self.session_manager
-                .begin_session_tx(oid, self.contract.worker_begin_tx()?)?;
+                .begin_session_tx(oid, self.begin_session_worker_tx(oid)?)?;
             true
         };
         let tx_mgr = self
@@ -694,13 +1183,16 @@ impl WorkerRuntime {
             .ok_or_else(|| mudu_error!(ErrorCode::Internal, "session transaction is missing"))?;
         let mut total = 0;
         for stmt in stmts {
-            match core
-                .execute(stmt, Box::new(()), tx_mgr.clone(), self.contract.clone())
+            match self
+                .run_with_statement_timeout(
+                    oid,
+                    core.execute(stmt, Box::new(()), tx_mgr.clone(), self.contract.clone()),
+                )
                 .await
             {
                 Ok(affected) => total += affected,
                 Err(err) => {
-                    if started_tx {
+                    if started_tx && self.session_manager.has_session_tx(oid)? {
                         let tx_manager = self.session_manager.take_session_tx(oid)?;
                         self.contract.worker_rollback_tx(tx_manager)?;
                     }
@@ -715,51 +1207,337 @@ impl WorkerRuntime {
         Ok(total)
     }
 
-    pub async fn replay_log_batch(&self, batch: XLBatch) -> RS<()> {
-        self.contract.replay_worker_log_batch(batch).await
+    /// Replays the worker log entry starting at `start_lsn`.
+    pub async fn replay_log_batch(&self, batch: XLBatch, start_lsn: LSN) -> RS<()> {
+        self.contract
+            .replay_worker_log_batch_at(batch, start_lsn)
+            .await
+    }
+
+    /// LSN that worker log replay starts from; entries before it are covered
+    /// by the checkpoint restored at initialization.
+    pub fn log_recovery_start_lsn(&self) -> LSN {
+        self.contract.log_recovery_start_lsn()
     }
 
     pub fn finish_log_recovery(&self) -> RS<()> {
         self.contract.finish_worker_log_recovery()
     }
 
+    /// Seals a point-in-time recovery once the worker log was replayed; does
+    /// nothing without a recovery target.
+    pub async fn finish_recovery_target_async(&self) -> RS<()> {
+        self.contract.finish_recovery_target_async().await
+    }
+
     pub async fn recover_cross_partition_transactions(&self) -> RS<()> {
         self.contract
             .recover_pending_cross_partition_records_async()
             .await
     }
 
-    pub fn open_session_with_config(&self, conn_id: u64, config: SessionOpenConfig) -> RS<OID> {
-        if config.target_worker_index() != self.worker_index()
-            || config.worker_id() != self.worker_id()
-        {
+    /// Runs one bounded MVCC version GC step; the worker loop calls this
+    /// periodically so that GC never holds the loop for long.
+    pub async fn collect_version_garbage_async(&self) -> RS<VersionGcStats> {
+        self.contract
+            .collect_version_garbage_async(VERSION_GC_BATCH_ROWS)
+            .await
+    }
+
+    /// Runs one bounded step of the kv TTL sweeper; the worker loop calls
+    /// this after each version GC step.
+    pub async fn sweep_expired_kv_async(&self) -> RS<usize> {
+        self.contract
+            .sweep_expired_kv_async(KV_TTL_SWEEP_BATCH_ROWS)
+            .await
+    }
+
+    /// Takes a checkpoint when the checkpoint policy says one is due.
+    pub async fn checkpoint_if_due_async(&self) -> RS<Option<CheckpointStats>> {
+        self.contract.checkpoint_if_due_async().await
+    }
+
+    /// Time between two calls of [`Self::flush_async_commits_async`] by the
+    /// worker loop.
+    pub fn async_commit_flush_interval(&self) -> Duration {
+        self.async_commit_flush_interval
+    }
+
+    /// Fsyncs the worker log when commits with asynchronous durability are
+    /// not durable yet; the worker loop calls this periodically.
+    pub async fn flush_async_commits_async(&self) -> RS<bool> {
+        self.contract.flush_async_commits_async().await
+    }
+
+    /// Returns the committed changes of a table that a change data capture
+    /// consumer has not consumed yet, from the logs of all workers.
+    pub async fn cdc_subscribe_async(
+        &self,
+        request: &CdcSubscribeRequest,
+    ) -> RS<CdcSubscribeResponse> {
+        let Some(log) = self.worker_log()? else {
             return Err(mudu_error!(
-                ErrorCode::Internal,
-                format!(
-                    "session open landed on worker index {} worker id {}, expected worker index {} worker id {}",
-                    self.worker_index(),
-                    self.worker_id(),
-                    config.target_worker_index(),
-                    config.worker_id()
-                )
+                ErrorCode::NotImplemented,
+                "change data capture needs the worker log"
+            ));
+        };
+        poll_changes(
+            log.fs().as_ref(),
+            self.contract.data_dir(),
+            self.log_layout.log_dir(),
+            log.keyring(),
+            &self.registry,
+            self.meta_mgr().as_ref(),
+            request,
+        )
+        .await
+    }
+
+    /// Registers a watch on a session of `conn_id`. Its events are pushed on
+    /// the connection, see [`WorkerRuntime::poll_watch_events_async`].
+    pub async fn watch_register_async(
+        &self,
+        conn_id: u64,
+        request: &WatchRegisterRequest,
+    ) -> RS<WatchRegisterResponse> {
+        let session_id = request.session_id();
+        self.ensure_session_owned_by_connection(conn_id, session_id)?;
+        if self.worker_log()?.is_none() {
+            return Err(mudu_error!(
+                ErrorCode::NotImplemented,
+                "watches need the worker log"
             ));
         }
-        if config.session_id() == 0 {
-            self.create_session(conn_id)
-        } else {
-            self.ensure_session_owned_by_connection(conn_id, config.session_id())?;
-            Ok(config.session_id())
-        }
+        let watch = Watch::new(
+            self.meta_mgr().as_ref(),
+            self.contract.data_dir(),
+            &self.registry,
+            session_id,
+            request.target(),
+            request.from(),
+            request.max_in_flight(),
+            request.max_changes(),
+        )
+        .await?;
+        let positions = watch.positions();
+        let watch_id = self.watches.register(conn_id, watch)?;
+        Ok(WatchRegisterResponse::new(watch_id, positions))
     }
-}
 
-// Miri cannot execute FFI calls into the tree-sitter C parser, which is
-// initialized when creating a WorkerRuntime session. The individual tests in
-// this module are skipped under Miri; worker behavior is still exercised by
-// normal `cargo test` runs.
-#[cfg(test)]
-mod tests {
-    #![allow(
+    /// Acknowledges the events of a watch up to `seq`, which lets the server
+    /// push further events.
+    pub fn watch_ack(&self, conn_id: u64, request: &WatchAckRequest) -> RS<()> {
+        let session_id = request.session_id();
+        self.ensure_session_owned_by_connection(conn_id, session_id)?;
+        self.watches
+            .ack(conn_id, session_id, request.watch_id(), request.seq())
+    }
+
+    pub fn watch_cancel(&self, conn_id: u64, request: &WatchCancelRequest) -> RS<()> {
+        let session_id = request.session_id();
+        self.ensure_session_owned_by_connection(conn_id, session_id)?;
+        self.watches.cancel(conn_id, session_id, request.watch_id())
+    }
+
+    pub fn has_watches(&self, conn_id: u64) -> RS<bool> {
+        self.watches.has_watches(conn_id)
+    }
+
+    pub(crate) fn watched_connections(&self) -> RS<Vec<u64>> {
+        self.watches.watched_connections()
+    }
+
+    /// Lock held while writing a frame to `conn_id`, so that pushed events
+    /// never interleave with a response.
+    pub(crate) fn watch_write_gate(&self, conn_id: u64) -> RS<Arc<FMutex<()>>> {
+        self.watches.write_gate(conn_id)
+    }
+
+    /// Returns the encoded `WatchEvent` frames due on `conn_id`.
+    pub async fn poll_watch_events_async(&self, conn_id: u64) -> RS<Vec<Vec<u8>>> {
+        let Some(log) = self.worker_log()? else {
+            return Ok(Vec::new());
+        };
+        poll_watch_events(
+            log.fs().as_ref(),
+            self.contract.data_dir(),
+            self.log_layout.log_dir(),
+            log.keyring(),
+            &self.registry,
+            self.meta_mgr().as_ref(),
+            &self.watches,
+            conn_id,
+        )
+        .await
+    }
+
+    /// Returns log frames of a worker for a read replica of this server.
+    pub async fn wal_fetch_async(&self, request: &WalFetchRequest) -> RS<WalFetchResponse> {
+        let Some(log) = self.worker_log()? else {
+            return Err(mudu_error!(
+                ErrorCode::NotImplemented,
+                "log shipping needs the worker log"
+            ));
+        };
+        read_log_frames(
+            log.fs().as_ref(),
+            self.contract.data_dir(),
+            self.log_layout.log_dir(),
+            &self.registry,
+            request,
+        )
+        .await
+    }
+
+    /// On a read replica, appends the log frames shipped from the primary
+    /// since the last call and replays them; leaves replica mode once the
+    /// replica is promoted and everything shipped before is applied.
+    pub async fn replicate_async(&self) -> RS<()> {
+        if !self.contract.is_replica() {
+            return Ok(());
+        }
+        let Some(log) = self.worker_log()? else {
+            return Ok(());
+        };
+        let data_dir = self.contract.data_dir();
+        let shipped = take_shipped_frames(data_dir, self.worker_id, log.position()?.lsn)?;
+        if !shipped.frames.is_empty() {
+            let handler = ReplicaReplayHandler::new(self.clone());
+            for (start_lsn, batch) in self
+                .contract
+                .append_shipped_frames_async(&shipped.frames)
+                .await?
+            {
+                handler.handle_entry(batch, start_lsn).await?;
+            }
+            record_applied(data_dir, self.worker_id, log.position()?.lsn)?;
+        }
+        if shipped.promoted {
+            self.contract.set_replica(false);
+            info!(
+                worker_id = self.worker_id,
+                lsn = log.position()?.lsn.as_u64(),
+                "read replica worker promoted"
+            );
+        }
+        Ok(())
+    }
+
+    /// Copies this worker's share of a pending online backup of its server,
+    /// if one is requested.
+    pub async fn backup_if_requested_async(&self) -> RS<()> {
+        let Some(request) = self.backups.take_request(self.worker_id)? else {
+            return Ok(());
+        };
+        let data_dir = self.contract.data_dir().to_string();
+        let result = self.backup_share_async(&data_dir, &request).await;
+        self.backups.complete_request(result)
+    }
+
+    async fn backup_share_async(
+        &self,
+        data_dir: &str,
+        request: &BackupRequest,
+    ) -> RS<(Option<WorkerBackup>, Vec<BackupFile>)> {
+        let backup_dir = request.backup_dir.as_path();
+        // Checkpoints and waits for every worker before copying anything.
+        let barrier = self.backups.reach_barrier(self.worker_id);
+        let (worker, mut files) = match self.contract.backup_async(backup_dir, barrier).await? {
+            Some((worker, worker_files)) => (Some(worker), worker_files),
+            None => (None, Vec::new()),
+        };
+        let fs = mudu_sys::default_sys_io_context().fs();
+        let log_dir = self.log_layout.log_dir();
+        let identity = WorkerIdentity {
+            worker_index: self.worker_index,
+            worker_id: self.worker_id,
+            partition_ids: self.partition_ids.clone(),
+        };
+        for path in identity_marker_paths(log_dir, &identity) {
+            files.push(copy_into_backup(fs.as_ref(), log_dir, &path, backup_dir).await?);
+        }
+        if request.copy_catalog {
+            files.extend(self.contract.meta_mgr().backup_catalog(backup_dir).await?);
+            let data_dir = Path::new(data_dir);
+            for name in [KEY_CHECK_FILE, PAGE_SIZE_META_FILE] {
+                let path = data_dir.join(name);
+                if fs.path_exists(&path).await? {
+                    files.push(copy_into_backup(fs.as_ref(), data_dir, &path, backup_dir).await?);
+                }
+            }
+        }
+        Ok((worker, files))
+    }
+
+    /// Runs one bounded step of rewriting the relation pages that are not
+    /// sealed with the active encryption key.
+    pub async fn reencrypt_step_async(&self) -> RS<ReencryptStats> {
+        self.contract
+            .reencrypt_step_async(REENCRYPT_BATCH_PAGES)
+            .await
+    }
+
+    /// Hit, miss and eviction counters of the worker's page buffer pool.
+    pub fn buffer_pool_stats(&self) -> RS<BufferPoolStats> {
+        self.contract.buffer_pool_stats()
+    }
+
+    pub fn page_compression_stats(&self) -> CompressionStatsSnapshot {
+        self.contract.page_compression_stats()
+    }
+
+    pub fn wal_compression_stats(&self) -> RS<CompressionStatsSnapshot> {
+        self.contract.wal_compression_stats()
+    }
+
+    pub fn open_session_with_config(&self, conn_id: u64, config: SessionOpenConfig) -> RS<OID> {
+        if config.target_worker_index() != self.worker_index()
+            || config.worker_id() != self.worker_id()
+        {
+            return Err(mudu_error!(
+                ErrorCode::Internal,
+                format!(
+                    "session open landed on worker index {} worker id {}, expected worker index {} worker id {}",
+                    self.worker_index(),
+                    self.worker_id(),
+                    config.target_worker_index(),
+                    config.worker_id()
+                )
+            ));
+        }
+        let session_id = if config.session_id() == 0 {
+            self.create_session(conn_id)?
+        } else {
+            self.ensure_session_owned_by_connection(conn_id, config.session_id())?;
+            config.session_id()
+        };
+        if !config.options().is_empty() {
+            self.update_session_options(session_id, config.options())?;
+        }
+        if let Some(app) = config.app() {
+            self.session_manager.bind_session_app(session_id, app)?;
+        }
+        Ok(session_id)
+    }
+}
+
+fn statement_timeout_error(timeout: Duration) -> MuduError {
+    mudu_error!(
+        ErrorCode::TimedOut,
+        format!(
+            "canceling statement due to statement timeout of {} ms",
+            timeout.as_millis()
+        )
+    )
+}
+
+// Miri cannot execute FFI calls into the tree-sitter C parser, which is
+// initialized when creating a WorkerRuntime session. The individual tests in
+// this module are skipped under Miri; worker behavior is still exercised by
+// normal `cargo test` runs.
+#[cfg(test)]
+mod tests {
+    #![allow(
         clippy::unwrap_used,
         clippy::expect_used,
         clippy::panic,
@@ -776,8 +1554,12 @@ mod tests {
     use crate::server::worker_local::{WorkerExecute, WorkerLocal};
     use crate::server::worker_registry::{load_or_create_worker_registry, WorkerRegistry};
     use crate::server::x_contract::WorkerXContractParams;
+    use crate::storage::buffer_pool::DEFAULT_BUFFER_POOL_BYTES;
     use crate::storage::time_series::time_series_file::TimeSeriesFile;
     use crate::x_engine::api::XContract;
+    use crate::x_engine::tx_mgr::{
+        DurabilityMode, IsolationLevel, DEFAULT_ASYNC_COMMIT_FLUSH_INTERVAL,
+    };
     use async_trait::async_trait;
     use mudu_sys::env_var::temp_dir;
     use mudu_sys::sync::SMutex;
@@ -807,6 +1589,10 @@ mod tests {
             ));
             Ok(procedure_parameters)
         }
+
+        async fn app_installed(&self, app_name: &str) -> RS<bool> {
+            Ok(app_name == "app")
+        }
     }
 
     fn test_registry(worker_count: usize) -> (String, Arc<WorkerRegistry>) {
@@ -833,9 +1619,20 @@ mod tests {
             log_dir: log_dir.to_string(),
             data_dir: data_dir.to_string(),
             log_chunk_size: 4096,
+            history_retention: Duration::ZERO,
+            checkpoint_policy: CheckpointPolicy::default(),
+            async_commit_flush_interval: DEFAULT_ASYNC_COMMIT_FLUSH_INTERVAL,
+            buffer_pool_bytes: DEFAULT_BUFFER_POOL_BYTES,
+            page_compression: Default::default(),
+            wal_compression: Default::default(),
+            keyring: None,
+            wal_archive_dir: None,
+            recovery_target: Default::default(),
+            read_replica: false,
             log_batching: WorkerLogBatching::default(),
             procedure_runtime,
             registry,
+            backups: Arc::new(BackupCoordinator::new()),
             async_runtime: None,
             server_instance_id: 0,
         })
@@ -900,6 +1697,30 @@ mod tests {
         .unwrap()
     }
 
+    #[test]
+    #[cfg_attr(miri, ignore)]
+    fn worker_binds_sessions_to_installed_apps() {
+        mudu_sys::task::async_::block_on_tokio_current_thread(async move {
+            let runtime = Arc::new(RecordingProcedureRuntime::default());
+            let (log_dir, registry) = test_registry(1);
+            let worker = test_worker(0, 1, &log_dir, &log_dir, registry, Some(runtime)).await;
+
+            worker.ensure_app_installed("app").await.unwrap();
+            let err = worker.ensure_app_installed("other").await.unwrap_err();
+            assert!(err.to_string().contains("no such application"));
+
+            let config = SessionOpenConfig::new(0, worker.worker_id(), worker.worker_index())
+                .with_app(Some("app".to_string()));
+            let session_id = worker.open_session_with_config(1, config).unwrap();
+            assert_eq!(worker.session_app(session_id).unwrap(), "app");
+
+            let unbound = worker.create_session(1).unwrap();
+            let err = worker.session_app(unbound).unwrap_err();
+            assert!(err.to_string().contains("not bound to an app"));
+        })
+        .unwrap()
+    }
+
     #[test]
     #[cfg_attr(miri, ignore)]
     fn worker_session_lifecycle_is_connection_scoped() {
@@ -955,7 +1776,12 @@ mod tests {
                 Some(b"1".to_vec())
             );
             assert_eq!(
-                local.range_async(opened, b"a", b"z").await.unwrap().len(),
+                local
+                    .range_async(opened, b"a", b"z", &KvRangeOptions::default())
+                    .await
+                    .unwrap()
+                    .items()
+                    .len(),
                 2
             );
             local
@@ -1054,9 +1880,20 @@ mod tests {
             log_dir: log_dir.clone(),
             data_dir: log_dir.clone(),
             log_chunk_size: 4096,
+            history_retention: Duration::ZERO,
+            checkpoint_policy: CheckpointPolicy::default(),
+            async_commit_flush_interval: DEFAULT_ASYNC_COMMIT_FLUSH_INTERVAL,
+            buffer_pool_bytes: DEFAULT_BUFFER_POOL_BYTES,
+            page_compression: Default::default(),
+            wal_compression: Default::default(),
+            keyring: None,
+            wal_archive_dir: None,
+            recovery_target: Default::default(),
+            read_replica: false,
             log_batching: WorkerLogBatching::default(),
             procedure_runtime: None,
             registry,
+            backups: Arc::new(BackupCoordinator::new()),
             async_runtime: None,
             server_instance_id: 0,
         })
@@ -1098,6 +1935,434 @@ mod tests {
         );
     }
 
+    #[test]
+    #[cfg_attr(miri, ignore)]
+    fn worker_put_many_and_delete_many_apply_every_key() {
+        mudu_sys::task::async_::block_on_tokio_current_thread(async move {
+            let (log_dir, registry) = test_registry(1);
+            let worker = test_worker(0, 1, &log_dir, &log_dir, registry, None).await;
+
+            let session_id = worker.create_session(1).unwrap();
+            let local = new_session_bound_worker_runtime(worker.clone(), session_id);
+            let local: &dyn WorkerLocal = local.as_ref();
+
+            local
+                .put_many_async(
+                    session_id,
+                    vec![
+                        KvItem {
+                            key: b"a".to_vec(),
+                            value: b"1".to_vec(),
+                        },
+                        KvItem {
+                            key: b"b".to_vec(),
+                            value: b"2".to_vec(),
+                        },
+                        KvItem {
+                            key: b"c".to_vec(),
+                            value: b"3".to_vec(),
+                        },
+                    ],
+                )
+                .await
+                .unwrap();
+            assert_eq!(worker.get_async(b"a").await.unwrap(), Some(b"1".to_vec()));
+            assert_eq!(worker.get_async(b"c").await.unwrap(), Some(b"3".to_vec()));
+
+            local
+                .delete_many_async(session_id, vec![b"a".to_vec(), b"c".to_vec()])
+                .await
+                .unwrap();
+            let page = local
+                .range_async(session_id, b"a", b"z", &KvRangeOptions::default())
+                .await
+                .unwrap();
+            assert_eq!(page.into_items(), vec![(b"b".to_vec(), b"2".to_vec())]);
+
+            local.put_many_async(session_id, Vec::new()).await.unwrap();
+            local
+                .delete_many_async(session_id, Vec::new())
+                .await
+                .unwrap();
+        })
+        .unwrap()
+    }
+
+    #[test]
+    #[cfg_attr(miri, ignore)]
+    fn worker_range_options_page_over_staged_and_committed_keys() {
+        mudu_sys::task::async_::block_on_tokio_current_thread(async move {
+            let (log_dir, registry) = test_registry(1);
+            let worker = test_worker(0, 1, &log_dir, &log_dir, registry, None).await;
+
+            let session_id = worker.create_session(1).unwrap();
+            let local = new_session_bound_worker_runtime(worker.clone(), session_id);
+            let local: &dyn WorkerLocal = local.as_ref();
+            for key in [b"a/1", b"a/2", b"b/1"] {
+                local
+                    .put_async(session_id, key.to_vec(), b"v".to_vec())
+                    .await
+                    .unwrap();
+            }
+            local
+                .execute_async(session_id, WorkerExecute::BeginTx)
+                .await
+                .unwrap();
+            local
+                .put_async(session_id, b"a/3".to_vec(), b"staged".to_vec())
+                .await
+                .unwrap();
+            local.delete_async(session_id, b"a/2").await.unwrap();
+
+            let keys = |page: &KvRangePage| {
+                page.items()
+                    .iter()
+                    .map(|(key, _)| key.clone())
+                    .collect::<Vec<_>>()
+            };
+            let options = KvRangeOptions::new().with_limit(1);
+            let first = local
+                .range_async(session_id, b"", b"", &options)
+                .await
+                .unwrap();
+            assert_eq!(keys(&first), vec![b"a/1".to_vec()]);
+            let token = first.continuation().unwrap().to_vec();
+            let second = local
+                .range_async(session_id, b"", b"", &options.with_continuation(token))
+                .await
+                .unwrap();
+            assert_eq!(keys(&second), vec![b"a/3".to_vec()]);
+            assert_eq!(second.items()[0].1, b"staged".to_vec());
+
+            let options = KvRangeOptions::new()
+                .with_prefix(b"a/".to_vec())
+                .with_reverse(true)
+                .with_keys_only(true);
+            let page = local
+                .range_async(session_id, b"", b"", &options)
+                .await
+                .unwrap();
+            assert_eq!(page.continuation(), None);
+            assert_eq!(
+                page.into_items(),
+                vec![(b"a/3".to_vec(), Vec::new()), (b"a/1".to_vec(), Vec::new())]
+            );
+
+            let options = KvRangeOptions::new().with_reverse(true).with_limit(1);
+            let first = local
+                .range_async(session_id, b"a/", b"a/9", &options)
+                .await
+                .unwrap();
+            assert_eq!(keys(&first), vec![b"a/3".to_vec()]);
+            let token = first.continuation().unwrap().to_vec();
+            let second = local
+                .range_async(session_id, b"a/", b"a/9", &options.with_continuation(token))
+                .await
+                .unwrap();
+            assert_eq!(keys(&second), vec![b"a/1".to_vec()]);
+        })
+        .unwrap()
+    }
+
+    #[test]
+    #[cfg_attr(miri, ignore)]
+    fn worker_put_many_inside_tx_is_hidden_until_commit() {
+        mudu_sys::task::async_::block_on_tokio_current_thread(async move {
+            let (log_dir, registry) = test_registry(1);
+            let worker = test_worker(0, 1, &log_dir, &log_dir, registry, None).await;
+
+            let session_a = worker.create_session(1).unwrap();
+            let session_b = worker.create_session(2).unwrap();
+            let local_a = new_session_bound_worker_runtime(worker.clone(), session_a);
+            let local_b = new_session_bound_worker_runtime(worker.clone(), session_b);
+
+            worker
+                .execute_tx_async(session_a, WorkerExecute::BeginTx)
+                .await
+                .unwrap();
+            local_a
+                .put_many_async(
+                    session_a,
+                    vec![
+                        KvItem {
+                            key: b"x".to_vec(),
+                            value: b"1".to_vec(),
+                        },
+                        KvItem {
+                            key: b"y".to_vec(),
+                            value: b"2".to_vec(),
+                        },
+                    ],
+                )
+                .await
+                .unwrap();
+
+            assert_eq!(
+                local_a.get_async(session_a, b"y").await.unwrap(),
+                Some(b"2".to_vec())
+            );
+            assert_eq!(local_b.get_async(session_b, b"x").await.unwrap(), None);
+
+            worker
+                .execute_tx_async(session_a, WorkerExecute::CommitTx)
+                .await
+                .unwrap();
+
+            assert_eq!(
+                local_b.get_async(session_b, b"x").await.unwrap(),
+                Some(b"1".to_vec())
+            );
+            assert_eq!(
+                local_b.get_async(session_b, b"y").await.unwrap(),
+                Some(b"2".to_vec())
+            );
+        })
+        .unwrap()
+    }
+
+    #[test]
+    #[cfg_attr(miri, ignore)]
+    fn worker_kv_namespace_writes_follow_session_tx() {
+        mudu_sys::task::async_::block_on_tokio_current_thread(async move {
+            let (log_dir, registry) = test_registry(1);
+            let worker = test_worker(0, 1, &log_dir, &log_dir, registry, None).await;
+            worker.initialize().await.unwrap();
+
+            let session_a = worker.create_session(1).unwrap();
+            let session_b = worker.create_session(2).unwrap();
+            let local_a = new_session_bound_worker_runtime(worker.clone(), session_a);
+            let local_b = new_session_bound_worker_runtime(worker.clone(), session_b);
+            let namespace = KvNamespace::new("app", "users").unwrap();
+            local_a
+                .kv_namespace_create_async(session_a, &namespace, None)
+                .await
+                .unwrap();
+
+            local_a
+                .kv_namespace_put_async(session_a, &namespace, b"k".to_vec(), b"1".to_vec())
+                .await
+                .unwrap();
+            assert_eq!(local_b.get_async(session_b, b"k").await.unwrap(), None);
+            assert_eq!(
+                local_b
+                    .kv_namespace_get_async(session_b, &namespace, b"k")
+                    .await
+                    .unwrap(),
+                Some(b"1".to_vec())
+            );
+
+            worker
+                .execute_tx_async(session_a, WorkerExecute::BeginTx)
+                .await
+                .unwrap();
+            local_a
+                .kv_namespace_put_async(session_a, &namespace, b"l".to_vec(), b"2".to_vec())
+                .await
+                .unwrap();
+            assert_eq!(
+                local_b
+                    .kv_namespace_get_async(session_b, &namespace, b"l")
+                    .await
+                    .unwrap(),
+                None
+            );
+            worker
+                .execute_tx_async(session_a, WorkerExecute::CommitTx)
+                .await
+                .unwrap();
+
+            let page = local_b
+                .kv_namespace_range_async(
+                    session_b,
+                    &namespace,
+                    b"",
+                    b"",
+                    &KvRangeOptions::new().with_limit(1),
+                )
+                .await
+                .unwrap();
+            assert_eq!(page.items(), &[(b"k".to_vec(), b"1".to_vec())]);
+            assert_eq!(page.continuation(), Some(b"k".as_slice()));
+            assert_eq!(
+                local_b.kv_namespace_list_async("app").await.unwrap(),
+                vec![namespace]
+            );
+        })
+        .unwrap()
+    }
+
+    #[test]
+    #[cfg_attr(miri, ignore)]
+    fn worker_atomic_updates_apply_in_autocommit() {
+        mudu_sys::task::async_::block_on_tokio_current_thread(async move {
+            let (log_dir, registry) = test_registry(1);
+            let worker = test_worker(0, 1, &log_dir, &log_dir, registry, None).await;
+
+            let session_id = worker.create_session(1).unwrap();
+            let local = new_session_bound_worker_runtime(worker.clone(), session_id);
+            let local: &dyn WorkerLocal = local.as_ref();
+
+            assert_eq!(
+                local
+                    .incr_async(session_id, b"n".to_vec(), 5)
+                    .await
+                    .unwrap(),
+                5
+            );
+            assert_eq!(
+                local
+                    .incr_async(session_id, b"n".to_vec(), -2)
+                    .await
+                    .unwrap(),
+                3
+            );
+            assert_eq!(worker.get_async(b"n").await.unwrap(), Some(b"3".to_vec()));
+
+            assert!(local
+                .put_if_absent_async(session_id, b"k".to_vec(), b"v1".to_vec())
+                .await
+                .unwrap());
+            assert!(!local
+                .put_if_absent_async(session_id, b"k".to_vec(), b"v2".to_vec())
+                .await
+                .unwrap());
+            assert!(!local
+                .cas_async(
+                    session_id,
+                    b"k".to_vec(),
+                    Some(b"v0".to_vec()),
+                    b"v3".to_vec()
+                )
+                .await
+                .unwrap());
+            assert!(local
+                .cas_async(
+                    session_id,
+                    b"k".to_vec(),
+                    Some(b"v1".to_vec()),
+                    b"v3".to_vec()
+                )
+                .await
+                .unwrap());
+            assert_eq!(worker.get_async(b"k").await.unwrap(), Some(b"v3".to_vec()));
+
+            assert!(local
+                .incr_async(session_id, b"k".to_vec(), 1)
+                .await
+                .is_err());
+        })
+        .unwrap()
+    }
+
+    #[test]
+    #[cfg_attr(miri, ignore)]
+    fn worker_atomic_updates_inside_tx_are_hidden_until_commit() {
+        mudu_sys::task::async_::block_on_tokio_current_thread(async move {
+            let (log_dir, registry) = test_registry(1);
+            let worker = test_worker(0, 1, &log_dir, &log_dir, registry, None).await;
+
+            let session_a = worker.create_session(1).unwrap();
+            let session_b = worker.create_session(2).unwrap();
+            let local_a = new_session_bound_worker_runtime(worker.clone(), session_a);
+            let local_b = new_session_bound_worker_runtime(worker.clone(), session_b);
+
+            worker
+                .execute_tx_async(session_a, WorkerExecute::BeginTx)
+                .await
+                .unwrap();
+            assert_eq!(
+                local_a
+                    .incr_async(session_a, b"n".to_vec(), 2)
+                    .await
+                    .unwrap(),
+                2
+            );
+            assert_eq!(
+                local_a
+                    .incr_async(session_a, b"n".to_vec(), 2)
+                    .await
+                    .unwrap(),
+                4
+            );
+            assert!(local_a
+                .cas_async(session_a, b"c".to_vec(), None, b"x".to_vec())
+                .await
+                .unwrap());
+            assert_eq!(local_b.get_async(session_b, b"n").await.unwrap(), None);
+            assert_eq!(local_b.get_async(session_b, b"c").await.unwrap(), None);
+
+            worker
+                .execute_tx_async(session_a, WorkerExecute::CommitTx)
+                .await
+                .unwrap();
+
+            assert_eq!(
+                local_b.get_async(session_b, b"n").await.unwrap(),
+                Some(b"4".to_vec())
+            );
+            assert_eq!(
+                local_b.get_async(session_b, b"c").await.unwrap(),
+                Some(b"x".to_vec())
+            );
+        })
+        .unwrap()
+    }
+
+    #[test]
+    #[cfg_attr(miri, ignore)]
+    fn worker_put_with_ttl_expires_after_commit() {
+        mudu_sys::task::async_::block_on_tokio_current_thread(async move {
+            let (log_dir, registry) = test_registry(1);
+            let worker = test_worker(0, 1, &log_dir, &log_dir, registry, None).await;
+
+            let session_id = worker.create_session(1).unwrap();
+            let local = new_session_bound_worker_runtime(worker.clone(), session_id);
+            let ttl = Duration::from_millis(20);
+
+            worker
+                .execute_tx_async(session_id, WorkerExecute::BeginTx)
+                .await
+                .unwrap();
+            local
+                .put_with_ttl_async(session_id, b"t".to_vec(), b"v".to_vec(), ttl)
+                .await
+                .unwrap();
+            // The TTL only starts once the transaction commits.
+            mudu_sys::task::sync::sleep_blocking(Duration::from_millis(40));
+            assert_eq!(
+                local.get_async(session_id, b"t").await.unwrap(),
+                Some(b"v".to_vec())
+            );
+            worker
+                .execute_tx_async(session_id, WorkerExecute::CommitTx)
+                .await
+                .unwrap();
+            assert_eq!(worker.get_async(b"t").await.unwrap(), Some(b"v".to_vec()));
+
+            mudu_sys::task::sync::sleep_blocking(Duration::from_millis(40));
+            assert_eq!(local.get_async(session_id, b"t").await.unwrap(), None);
+            let page = local
+                .range_async(session_id, b"", b"", &KvRangeOptions::default())
+                .await
+                .unwrap();
+            assert!(page.items().is_empty());
+            assert_eq!(worker.sweep_expired_kv_async().await.unwrap(), 1);
+
+            // The TTL lives outside the value, so any binary value reads
+            // back as written.
+            let binary = b"\xffmudu.ttl\x00\x01\x00\x00\x00\x00\x00\x00\x00\x01v".to_vec();
+            local
+                .put_async(session_id, b"r".to_vec(), binary.clone())
+                .await
+                .unwrap();
+            assert_eq!(
+                local.get_async(session_id, b"r").await.unwrap(),
+                Some(binary)
+            );
+        })
+        .unwrap()
+    }
+
     #[test]
     #[cfg_attr(miri, ignore)]
     fn worker_delete_inside_tx_is_visible_to_same_session_only_after_commit() {
@@ -1243,14 +2508,11 @@ mod tests {
                 .await
                 .unwrap();
 
-            let rows = local_a.range_async(session_a, b"a", b"z").await.unwrap();
-            assert_eq!(
-                rows,
-                vec![KvItem {
-                    key: b"a".to_vec(),
-                    value: b"1".to_vec()
-                }]
-            );
+            let page = local_a
+                .range_async(session_a, b"a", b"z", &KvRangeOptions::default())
+                .await
+                .unwrap();
+            assert_eq!(page.into_items(), vec![(b"a".to_vec(), b"1".to_vec())]);
         })
         .unwrap()
     }
@@ -1297,4 +2559,276 @@ mod tests {
         })
         .unwrap()
     }
+
+    async fn run_kv_write_skew(isolation_level: IsolationLevel) -> (RS<()>, RS<()>) {
+        let (log_dir, registry) = test_registry(1);
+        let worker = test_worker(0, 1, &log_dir, &log_dir, registry, None).await;
+
+        let session_a = worker.create_session(1).unwrap();
+        let session_b = worker.create_session(2).unwrap();
+        let local_a = new_session_bound_worker_runtime(worker.clone(), session_a);
+        let local_b = new_session_bound_worker_runtime(worker.clone(), session_b);
+        local_a
+            .put_async(session_a, b"x".to_vec(), b"1".to_vec())
+            .await
+            .unwrap();
+        local_a
+            .put_async(session_a, b"y".to_vec(), b"1".to_vec())
+            .await
+            .unwrap();
+        for session_id in [session_a, session_b] {
+            worker
+                .update_session_options(
+                    session_id,
+                    &SessionOptionsUpdate {
+                        isolation_level: Some(isolation_level),
+                        ..SessionOptionsUpdate::default()
+                    },
+                )
+                .unwrap();
+            worker
+                .execute_tx_async(session_id, WorkerExecute::BeginTx)
+                .await
+                .unwrap();
+        }
+        for (local, session_id) in [(&local_a, session_a), (&local_b, session_b)] {
+            assert_eq!(
+                local.get_async(session_id, b"x").await.unwrap(),
+                Some(b"1".to_vec())
+            );
+            assert_eq!(
+                local.get_async(session_id, b"y").await.unwrap(),
+                Some(b"1".to_vec())
+            );
+        }
+        local_a
+            .put_async(session_a, b"x".to_vec(), b"0".to_vec())
+            .await
+            .unwrap();
+        local_b
+            .put_async(session_b, b"y".to_vec(), b"0".to_vec())
+            .await
+            .unwrap();
+
+        let first = worker
+            .execute_tx_async(session_a, WorkerExecute::CommitTx)
+            .await;
+        let second = worker
+            .execute_tx_async(session_b, WorkerExecute::CommitTx)
+            .await;
+        (first, second)
+    }
+
+    #[test]
+    #[cfg_attr(miri, ignore)]
+    fn worker_snapshot_isolation_allows_write_skew() {
+        mudu_sys::task::async_::block_on_tokio_current_thread(async move {
+            let (first, second) = run_kv_write_skew(IsolationLevel::SnapshotIsolation).await;
+            first.unwrap();
+            second.unwrap();
+        })
+        .unwrap()
+    }
+
+    #[test]
+    #[cfg_attr(miri, ignore)]
+    fn worker_serializable_rejects_write_skew() {
+        mudu_sys::task::async_::block_on_tokio_current_thread(async move {
+            let (first, second) = run_kv_write_skew(IsolationLevel::Serializable).await;
+            first.unwrap();
+            let err = second.unwrap_err();
+            assert!(err.to_string().contains("could not serialize"));
+        })
+        .unwrap()
+    }
+
+    #[test]
+    #[cfg_attr(miri, ignore)]
+    fn worker_reopen_updates_session_options() {
+        mudu_sys::task::async_::block_on_tokio_current_thread(async move {
+            let (log_dir, registry) = test_registry(1);
+            let worker = test_worker(0, 1, &log_dir, &log_dir, registry, None).await;
+
+            let config = SessionOpenConfig::new(0, worker.worker_id(), worker.worker_index())
+                .with_options(SessionOptionsUpdate {
+                    read_only: Some(true),
+                    durability: Some(DurabilityMode::Async),
+                    ..SessionOptionsUpdate::default()
+                });
+            let session_id = worker.open_session_with_config(1, config).unwrap();
+            let local = new_session_bound_worker_runtime(worker.clone(), session_id);
+            let err = local
+                .put_async(session_id, b"k".to_vec(), b"v".to_vec())
+                .await
+                .unwrap_err();
+            assert!(err.to_string().contains("read-only"));
+
+            let reopen =
+                SessionOpenConfig::new(session_id, worker.worker_id(), worker.worker_index())
+                    .with_options(SessionOptionsUpdate {
+                        read_only: Some(false),
+                        ..SessionOptionsUpdate::default()
+                    });
+            assert_eq!(
+                worker.open_session_with_config(1, reopen).unwrap(),
+                session_id
+            );
+            let options = worker.session_manager.session_options(session_id).unwrap();
+            assert!(!options.read_only());
+            assert_eq!(options.durability(), DurabilityMode::Async);
+
+            worker
+                .execute_tx_async(session_id, WorkerExecute::BeginTx)
+                .await
+                .unwrap();
+            local
+                .put_async(session_id, b"k".to_vec(), b"v".to_vec())
+                .await
+                .unwrap();
+            worker
+                .execute_tx_async(session_id, WorkerExecute::CommitTx)
+                .await
+                .unwrap();
+            assert_eq!(worker.get_async(b"k").await.unwrap(), Some(b"v".to_vec()));
+        })
+        .unwrap()
+    }
+
+    #[test]
+    #[cfg_attr(miri, ignore)]
+    fn worker_session_options_flow_into_new_transactions() {
+        mudu_sys::task::async_::block_on_tokio_current_thread(async move {
+            let (log_dir, registry) = test_registry(1);
+            let worker = test_worker(0, 1, &log_dir, &log_dir, registry, None).await;
+
+            let session_id = worker.create_session(1).unwrap();
+            worker
+                .update_session_options(
+                    session_id,
+                    &SessionOptionsUpdate {
+                        lock_timeout_ms: Some(20),
+                        ..SessionOptionsUpdate::default()
+                    },
+                )
+                .unwrap();
+            let tx = worker.begin_session_worker_tx(session_id).unwrap();
+            assert_eq!(
+                tx.options().lock_timeout,
+                Some(std::time::Duration::from_millis(20))
+            );
+            worker.contract.worker_rollback_tx(tx).unwrap();
+        })
+        .unwrap()
+    }
+
+    #[test]
+    #[cfg_attr(miri, ignore)]
+    fn worker_flushes_async_commits_once() {
+        mudu_sys::task::async_::block_on_tokio_current_thread(async move {
+            let (log_dir, registry) = test_registry(1);
+            let worker = test_worker(0, 1, &log_dir, &log_dir, registry, None).await;
+            worker.initialize().await.unwrap();
+            let config = SessionOpenConfig::new(0, worker.worker_id(), worker.worker_index())
+                .with_options(SessionOptionsUpdate {
+                    durability: Some(DurabilityMode::Async),
+                    ..SessionOptionsUpdate::default()
+                });
+            let session_id = worker.open_session_with_config(1, config).unwrap();
+            let local = new_session_bound_worker_runtime(worker.clone(), session_id);
+
+            assert!(!worker.flush_async_commits_async().await.unwrap());
+            local
+                .put_many_async(
+                    session_id,
+                    vec![KvItem {
+                        key: b"k".to_vec(),
+                        value: b"v".to_vec(),
+                    }],
+                )
+                .await
+                .unwrap();
+            assert!(worker.flush_async_commits_async().await.unwrap());
+            assert!(!worker.flush_async_commits_async().await.unwrap());
+        })
+        .unwrap()
+    }
+
+    #[test]
+    #[cfg(target_os = "linux")]
+    #[cfg_attr(miri, ignore)]
+    #[allow(clippy::arc_with_non_send_sync)]
+    fn io_uring_statement_timeout_rolls_back_session_tx() {
+        use mudu_sys::io::worker_ring::{
+            set_current_worker_ring, unset_current_worker_ring, WorkerLocalRing,
+        };
+        use std::cell::RefCell;
+        use std::rc::Rc;
+
+        let (log_dir, registry) = test_registry(1);
+        let worker = Rc::new(
+            mudu_sys::task::async_::block_on_tokio_current_thread(async move {
+                test_worker(0, 1, &log_dir, &log_dir, registry, None).await
+            })
+            .unwrap(),
+        );
+        let session_id = worker.create_session(1).unwrap();
+        let ring = Arc::new(WorkerLocalRing::new());
+        set_current_worker_ring(ring.clone());
+        worker
+            .update_session_options(
+                session_id,
+                &SessionOptionsUpdate {
+                    lock_timeout_ms: Some(20),
+                    statement_timeout_ms: Some(20),
+                    ..SessionOptionsUpdate::default()
+                },
+            )
+            .unwrap();
+
+        let outcome = Rc::new(RefCell::new(None));
+        let task_worker = worker.clone();
+        let task_outcome = outcome.clone();
+        ring.worker_task_registry().spawn(
+            Some(1),
+            Box::pin(async move {
+                task_worker
+                    .execute_tx_async(session_id, WorkerExecute::BeginTx)
+                    .await?;
+                task_worker
+                    .put_in_session_async(session_id, b"k".to_vec(), b"v".to_vec())
+                    .await?;
+                let started = *mudu_sys::time::instant_now();
+                let result = task_worker
+                    .run_with_statement_timeout(session_id, async {
+                        mudu_sys::sleep(Duration::from_secs(3_600)).await
+                    })
+                    .await;
+                *task_outcome.borrow_mut() = Some((result, started.elapsed()));
+                Ok(())
+            }),
+        );
+        let tasks = ring.worker_task_registry();
+        while !tasks.is_empty() {
+            ring.fire_due_timers().unwrap();
+            tasks.drain_completions();
+            for completed in tasks.poll_ready() {
+                completed.into_result().unwrap();
+            }
+            if let Some(timeout) = ring.next_timer_timeout().unwrap() {
+                mudu_sys::task::sync::sleep_blocking(timeout);
+            }
+        }
+        unset_current_worker_ring();
+
+        let (result, elapsed) = outcome.borrow_mut().take().unwrap();
+        assert_eq!(result.unwrap_err().ec(), ErrorCode::TimedOut);
+        assert!(elapsed >= Duration::from_millis(20));
+        assert!(!worker.session_manager.has_session_tx(session_id).unwrap());
+        let value = mudu_sys::task::async_::block_on_tokio_current_thread(async move {
+            worker.get_in_session(session_id, b"k").await
+        })
+        .unwrap()
+        .unwrap();
+        assert_eq!(value, None);
+    }
 }
@@ -0,0 +1,356 @@
+//! Online backups of a running server.
+//!
+//! A server owns one [`BackupCoordinator`], shared by its workers and its
+//! management API. [`BackupCoordinator::run_backup`] registers a backup job
+//! and waits for every worker to serve it: each worker copies its share on its
+//! own loop, at its next checkpoint poll (see
+//! `WorkerRuntime::backup_if_requested_async`), because only the owning
+//! worker may touch its relations and log.
+//!
+//! The workers meet at a barrier before any of them copies a file. Each
+//! worker first takes a checkpoint and flushes its log, and then waits,
+//! keeping its checkpoint gate, until every worker has done the same. Only
+//! then do the workers copy their relations, checkpoints and logs, and the
+//! default global worker the catalog. The manifest records, per worker, the
+//! checkpoint LSN and the log position it reached at the barrier. Once all
+//! workers are done the manifest is written, which completes the backup set.
+
+use std::collections::BTreeSet;
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+
+use mudu::common::id::OID;
+use mudu::common::result::RS;
+use mudu::error::{ErrorCode, MuduError};
+use mudu::mudu_error;
+use mudu_sys::sync::SMutex;
+use mudu_sys::time::instant_now;
+
+use crate::server::worker_registry::WorkerRegistry;
+use crate::storage::backup::{BackupFile, BackupManifest, WorkerBackup};
+
+const BACKUP_WAIT_INTERVAL: Duration = Duration::from_millis(100);
+const BACKUP_WAIT_TIMEOUT: Duration = Duration::from_secs(600);
+
+struct BackupJob {
+    backup_dir: PathBuf,
+    catalog_worker_id: OID,
+    // Workers that have not started their share yet.
+    unclaimed: BTreeSet<OID>,
+    // Workers that have not reached the barrier yet.
+    before_barrier: BTreeSet<OID>,
+    // Workers that have not finished their share yet.
+    unfinished: usize,
+    workers: Vec<WorkerBackup>,
+    files: Vec<BackupFile>,
+    error: Option<MuduError>,
+}
+
+/// A worker's share of a pending backup.
+pub(crate) struct BackupRequest {
+    pub backup_dir: PathBuf,
+    /// The worker copies the catalog as well.
+    pub copy_catalog: bool,
+}
+
+/// The online backup state of one server; at most one backup runs at a time.
+#[derive(Default)]
+pub struct BackupCoordinator {
+    job: SMutex<Option<BackupJob>>,
+}
+
+impl BackupCoordinator {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Claims `worker_id`'s share of the pending backup, if any.
+    pub(crate) fn take_request(&self, worker_id: OID) -> RS<Option<BackupRequest>> {
+        let mut job = self.job.lock()?;
+        let Some(job) = job.as_mut() else {
+            return Ok(None);
+        };
+        if !job.unclaimed.remove(&worker_id) {
+            return Ok(None);
+        }
+        Ok(Some(BackupRequest {
+            backup_dir: job.backup_dir.clone(),
+            copy_catalog: job.catalog_worker_id == worker_id,
+        }))
+    }
+
+    /// Marks `worker_id` as checkpointed and waits until every worker of the
+    /// backup is. Fails when another worker's share failed or the backup was
+    /// given up.
+    pub(crate) async fn reach_barrier(&self, worker_id: OID) -> RS<()> {
+        let deadline = instant_now() + BACKUP_WAIT_TIMEOUT;
+        let mut reached = false;
+        loop {
+            {
+                let mut job = self.job.lock()?;
+                let Some(job) = job.as_mut() else {
+                    return Err(mudu_error!(
+                        ErrorCode::InvalidState,
+                        "backup was given up before the barrier"
+                    ));
+                };
+                if !reached {
+                    job.before_barrier.remove(&worker_id);
+                    reached = true;
+                }
+                if job.error.is_some() {
+                    return Err(mudu_error!(
+                        ErrorCode::InvalidState,
+                        "backup failed on another worker"
+                    ));
+                }
+                if job.before_barrier.is_empty() {
+                    return Ok(());
+                }
+                if instant_now() >= deadline {
+                    return Err(mudu_error!(
+                        ErrorCode::TimedOut,
+                        format!(
+                            "{} workers did not reach the backup barrier",
+                            job.before_barrier.len()
+                        )
+                    ));
+                }
+            }
+            mudu_sys::sleep(BACKUP_WAIT_INTERVAL).await?;
+        }
+    }
+
+    /// Records the outcome of a share claimed with
+    /// [`BackupCoordinator::take_request`].
+    pub(crate) fn complete_request(
+        &self,
+        result: RS<(Option<WorkerBackup>, Vec<BackupFile>)>,
+    ) -> RS<()> {
+        let mut job = self.job.lock()?;
+        let Some(job) = job.as_mut() else {
+            return Ok(());
+        };
+        job.unfinished = job.unfinished.saturating_sub(1);
+        match result {
+            Ok((worker, files)) => {
+                job.workers.extend(worker);
+                job.files.extend(files);
+            }
+            Err(err) => {
+                job.error.get_or_insert(err);
+            }
+        }
+        Ok(())
+    }
+
+    /// Takes an online backup into `backup_dir`, which must be empty or
+    /// missing.
+    ///
+    /// Returns once every worker of `registry` has copied its share and the
+    /// manifest is written.
+    pub async fn run_backup(
+        &self,
+        registry: &WorkerRegistry,
+        backup_dir: &Path,
+    ) -> RS<BackupManifest> {
+        if mudu_sys::fs::sync::path_exists(backup_dir)
+            && !mudu_sys::fs::sync::read_dir_entries(backup_dir)?.is_empty()
+        {
+            return Err(mudu_error!(
+                ErrorCode::EntityAlreadyExists,
+                format!("backup directory {} is not empty", backup_dir.display())
+            ));
+        }
+        mudu_sys::fs::sync::create_dir_all(backup_dir)?;
+        let catalog_worker_id = registry.default_global_worker_id().ok_or_else(|| {
+            mudu_error!(
+                ErrorCode::InvalidState,
+                "worker registry has no default global worker"
+            )
+        })?;
+        let workers: BTreeSet<OID> = registry
+            .workers()
+            .iter()
+            .map(|worker| worker.worker_id)
+            .collect();
+        {
+            let mut job = self.job.lock()?;
+            if job.is_some() {
+                return Err(mudu_error!(
+                    ErrorCode::ResourceBusy,
+                    "a backup is already running"
+                ));
+            }
+            *job = Some(BackupJob {
+                backup_dir: backup_dir.to_path_buf(),
+                catalog_worker_id,
+                unfinished: workers.len(),
+                before_barrier: workers.clone(),
+                unclaimed: workers,
+                workers: Vec::new(),
+                files: Vec::new(),
+                error: None,
+            });
+        }
+
+        let job = self.wait_for_workers().await?;
+        if let Some(err) = job.error {
+            return Err(err);
+        }
+        let manifest = BackupManifest::new(job.workers, job.files);
+        let fs = mudu_sys::default_sys_io_context().fs();
+        manifest.write_async(fs.as_ref(), backup_dir).await?;
+        Ok(manifest)
+    }
+
+    /// Waits until every worker finished its share and removes the job. A
+    /// worker that never serves the job, e.g. because it stopped, fails the
+    /// backup after [`BACKUP_WAIT_TIMEOUT`].
+    async fn wait_for_workers(&self) -> RS<BackupJob> {
+        let deadline = instant_now() + BACKUP_WAIT_TIMEOUT;
+        loop {
+            {
+                let mut job = self.job.lock()?;
+                let finished = job.as_ref().is_none_or(|job| job.unfinished == 0);
+                if finished || instant_now() >= deadline {
+                    let job = job.take().ok_or_else(|| {
+                        mudu_error!(ErrorCode::Internal, "backup job disappeared")
+                    })?;
+                    if job.unfinished != 0 {
+                        return Err(mudu_error!(
+                            ErrorCode::TimedOut,
+                            format!("{} workers did not finish the backup", job.unfinished)
+                        ));
+                    }
+                    return Ok(job);
+                }
+            }
+            mudu_sys::sleep(BACKUP_WAIT_INTERVAL).await?;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used, clippy::expect_used)]
+
+    use super::*;
+    use crate::server::worker_registry::WorkerIdentity;
+    use mudu_sys::task::async_::block_on_tokio_current_thread;
+
+    fn registry() -> WorkerRegistry {
+        WorkerRegistry::new(vec![
+            WorkerIdentity {
+                worker_index: 0,
+                worker_id: 1,
+                partition_ids: vec![11],
+            },
+            WorkerIdentity {
+                worker_index: 1,
+                worker_id: 2,
+                partition_ids: vec![12],
+            },
+        ])
+        .unwrap()
+    }
+
+    fn backup_dir() -> PathBuf {
+        let dir = project_root::get_project_root()
+            .unwrap()
+            .join("target")
+            .join("tmp")
+            .join(format!("backup_barrier_{}", mudu_sys::random::uuid_v4()));
+        mudu_sys::fs::sync::create_dir_all(&dir).unwrap();
+        dir
+    }
+
+    fn share(worker_id: OID) -> (Option<WorkerBackup>, Vec<BackupFile>) {
+        (
+            Some(WorkerBackup {
+                worker_id,
+                checkpoint_lsn: 1,
+                barrier_lsn: 2,
+                end_lsn: 3,
+            }),
+            Vec::new(),
+        )
+    }
+
+    #[test]
+    fn workers_copy_only_after_every_worker_reached_the_barrier() {
+        let dir = backup_dir();
+        let coordinator = BackupCoordinator::new();
+        let registry = registry();
+        let backup_dir = dir.clone();
+        let manifest = block_on_tokio_current_thread(async move {
+            let backup = coordinator.run_backup(&registry, &backup_dir);
+            let workers = async {
+                while coordinator.take_request(1).unwrap().is_none() {
+                    mudu_sys::sleep(Duration::from_millis(1)).await.unwrap();
+                }
+                // Worker 1 waits at the barrier until worker 2 checkpointed.
+                let first = coordinator.reach_barrier(1);
+                let second = async {
+                    let request = coordinator.take_request(2).unwrap().unwrap();
+                    assert!(!request.copy_catalog);
+                    assert!(!coordinator
+                        .job
+                        .lock()
+                        .unwrap()
+                        .as_ref()
+                        .unwrap()
+                        .before_barrier
+                        .is_empty());
+                    coordinator.reach_barrier(2).await
+                };
+                let (first, second) = futures::join!(first, second);
+                first.unwrap();
+                second.unwrap();
+                coordinator.complete_request(Ok(share(1))).unwrap();
+                coordinator.complete_request(Ok(share(2))).unwrap();
+            };
+            let (manifest, ()) = futures::join!(backup, workers);
+            manifest
+        })
+        .unwrap()
+        .unwrap();
+        assert_eq!(manifest.workers.len(), 2);
+        assert!(manifest
+            .workers
+            .iter()
+            .all(|worker| worker.barrier_lsn == 2));
+        mudu_sys::fs::sync::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn a_failed_share_releases_workers_waiting_at_the_barrier() {
+        let dir = backup_dir();
+        let coordinator = BackupCoordinator::new();
+        let registry = registry();
+        let backup_dir = dir.clone();
+        let (backup, barrier) = block_on_tokio_current_thread(async move {
+            let backup = coordinator.run_backup(&registry, &backup_dir);
+            let workers = async {
+                while coordinator.take_request(1).unwrap().is_none() {
+                    mudu_sys::sleep(Duration::from_millis(1)).await.unwrap();
+                }
+                let _ = coordinator.take_request(2).unwrap().unwrap();
+                let waiting = coordinator.reach_barrier(1);
+                let failing = async {
+                    coordinator
+                        .complete_request(Err(mudu_error!(ErrorCode::Io, "checkpoint failed")))
+                        .unwrap();
+                };
+                let (barrier, ()) = futures::join!(waiting, failing);
+                coordinator.complete_request(Ok(share(1))).unwrap();
+                barrier
+            };
+            futures::join!(backup, workers)
+        })
+        .unwrap();
+        assert_eq!(barrier.unwrap_err().ec(), ErrorCode::InvalidState);
+        assert_eq!(backup.unwrap_err().ec(), ErrorCode::Io);
+        mudu_sys::fs::sync::remove_dir_all(&dir).unwrap();
+    }
+}
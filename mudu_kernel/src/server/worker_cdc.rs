@@ -0,0 +1,568 @@
+//! Change data capture.
+//!
+//! Consumers read the committed row changes of a table straight from the
+//! worker logs. At every checkpoint poll a worker publishes the LSN below
+//! which its log entries are committed and applied (see
+//! `WorkerXContract::sync_cdc_async`); [`poll_changes`] returns the entries
+//! below it, decoded to JSON.
+//!
+//! The server keeps each consumer's acknowledged position per worker. A
+//! request that passes `from` acknowledges everything before it, one without
+//! `from` acknowledges what the previous response returned. Every worker
+//! saves the positions of its log next to its chunks, and checkpoints keep
+//! the chunks an acknowledged position still needs. Read replicas keep
+//! their positions the same way (see `worker_replication`).
+
+use std::collections::{BTreeMap, BTreeSet, HashMap};
+use std::path::Path;
+use std::sync::OnceLock;
+use std::time::Duration;
+
+use mudu::common::id::OID;
+use mudu::common::result::RS;
+use mudu::error::ErrorCode;
+use mudu::mudu_error;
+use mudu::utils::json::JsonValue;
+use mudu_contract::protocol::{
+    CdcChange, CdcOp, CdcPosition, CdcSubscribeRequest, CdcSubscribeResponse,
+};
+use mudu_contract::tuple::tuple_field::TupleField;
+use mudu_sys::contract::async_fs::AsyncFs;
+use mudu_sys::sync::SMutex;
+use mudu_sys::time::instant_now;
+use serde::{Deserialize, Serialize};
+
+use crate::contract::meta_mgr::MetaMgr;
+use crate::contract::table_desc::TableDesc;
+use crate::executor::project_tuple_desc;
+use crate::server::worker_registry::WorkerRegistry;
+use crate::server::x_contract::utils::project_selected_fields;
+use crate::storage::encryption::Keyring;
+use crate::wal::log_frame::{decode_entries_with_pending, frame_lsn};
+use crate::wal::lsn::LSN;
+use crate::wal::worker_log::{decode_frames_allow_trailing, WorkerLogLayout};
+use crate::wal::xl_batch::XLBatch;
+use crate::wal::xl_data_op::XLWrite;
+use crate::wal::xl_entry::TxOp;
+use crate::x_engine::api::VecSelTerm;
+
+/// Changes one poll returns at most when the request sets no limit.
+pub const CDC_DEFAULT_MAX_CHANGES: u32 = 1000;
+
+const CDC_WAIT_INTERVAL: Duration = Duration::from_millis(100);
+
+pub(crate) const CDC_POSITIONS_MAGIC: u32 = 0x4D43_4443; // "MCDC"
+
+/// Consumer positions of one worker log, as saved next to its chunks.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub(crate) struct CdcConsumerPositions {
+    pub positions: BTreeMap<String, u64>,
+}
+
+#[derive(Default)]
+struct CdcState {
+    // LSN below which each worker's log entries are committed and applied.
+    visible: HashMap<OID, u64>,
+    // Acknowledged position of each consumer in each worker log.
+    consumers: BTreeMap<String, BTreeMap<OID, u64>>,
+    // Workers whose consumer positions changed since they saved them.
+    dirty: BTreeSet<OID>,
+}
+
+impl CdcState {
+    fn acknowledge(&mut self, consumer: &str, worker_id: OID, lsn: u64) {
+        let positions = self.consumers.entry(consumer.to_string()).or_default();
+        if positions.insert(worker_id, lsn) != Some(lsn) {
+            self.dirty.insert(worker_id);
+        }
+    }
+}
+
+fn cdc_states() -> &'static SMutex<HashMap<String, CdcState>> {
+    static STATES: OnceLock<SMutex<HashMap<String, CdcState>>> = OnceLock::new();
+    STATES.get_or_init(|| SMutex::new(HashMap::new()))
+}
+
+/// Makes the log entries of `worker_id` below `lsn` readable by consumers.
+pub(crate) fn publish_visible_lsn(data_dir: &str, worker_id: OID, lsn: LSN) -> RS<()> {
+    let mut states = cdc_states().lock()?;
+    let state = states.entry(data_dir.to_string()).or_default();
+    state.visible.insert(worker_id, lsn.as_u64());
+    Ok(())
+}
+
+/// Returns the LSN below which the log entries of `worker_id` are readable;
+/// `None` before the worker published one.
+pub(crate) fn visible_lsn(data_dir: &str, worker_id: OID) -> RS<Option<LSN>> {
+    let states = cdc_states().lock()?;
+    Ok(states
+        .get(data_dir)
+        .and_then(|state| state.visible.get(&worker_id).copied())
+        .map(LSN::new))
+}
+
+/// Records that `consumer` no longer needs the log of `worker_id` before
+/// `lsn`.
+pub(crate) fn acknowledge_position(
+    data_dir: &str,
+    consumer: &str,
+    worker_id: OID,
+    lsn: LSN,
+) -> RS<()> {
+    let mut states = cdc_states().lock()?;
+    let state = states.entry(data_dir.to_string()).or_default();
+    state.acknowledge(consumer, worker_id, lsn.as_u64());
+    Ok(())
+}
+
+/// Returns the oldest acknowledged position in the log of `worker_id`, the
+/// LSN from which its chunks must be kept.
+pub(crate) fn consumer_floor(data_dir: &str, worker_id: OID) -> RS<Option<LSN>> {
+    let states = cdc_states().lock()?;
+    let Some(state) = states.get(data_dir) else {
+        return Ok(None);
+    };
+    Ok(state
+        .consumers
+        .values()
+        .filter_map(|positions| positions.get(&worker_id).copied())
+        .min()
+        .map(LSN::new))
+}
+
+/// Adds the positions a worker saved before a restart; positions already
+/// acknowledged since then win.
+pub(crate) fn load_consumer_positions(
+    data_dir: &str,
+    worker_id: OID,
+    saved: CdcConsumerPositions,
+) -> RS<()> {
+    let mut states = cdc_states().lock()?;
+    let state = states.entry(data_dir.to_string()).or_default();
+    for (consumer, lsn) in saved.positions {
+        state
+            .consumers
+            .entry(consumer)
+            .or_default()
+            .entry(worker_id)
+            .or_insert(lsn);
+    }
+    Ok(())
+}
+
+/// Takes the positions in the log of `worker_id` if they changed since the
+/// worker last saved them.
+pub(crate) fn take_changed_positions(
+    data_dir: &str,
+    worker_id: OID,
+) -> RS<Option<CdcConsumerPositions>> {
+    let mut states = cdc_states().lock()?;
+    let Some(state) = states.get_mut(data_dir) else {
+        return Ok(None);
+    };
+    if !state.dirty.remove(&worker_id) {
+        return Ok(None);
+    }
+    let positions = state
+        .consumers
+        .iter()
+        .filter_map(|(consumer, positions)| {
+            positions
+                .get(&worker_id)
+                .map(|lsn| (consumer.clone(), *lsn))
+        })
+        .collect();
+    Ok(Some(CdcConsumerPositions { positions }))
+}
+
+/// Marks the positions in the log of `worker_id` as not saved, after saving
+/// them failed.
+pub(crate) fn mark_positions_changed(data_dir: &str, worker_id: OID) -> RS<()> {
+    let mut states = cdc_states().lock()?;
+    if let Some(state) = states.get_mut(data_dir) {
+        state.dirty.insert(worker_id);
+    }
+    Ok(())
+}
+
+/// Forgets `consumer`, so checkpoints no longer keep log chunks for it.
+/// Returns whether the consumer was known.
+pub fn drop_consumer(data_dir: &str, consumer: &str) -> RS<bool> {
+    let mut states = cdc_states().lock()?;
+    let Some(state) = states.get_mut(data_dir) else {
+        return Ok(false);
+    };
+    let Some(positions) = state.consumers.remove(consumer) else {
+        return Ok(false);
+    };
+    state.dirty.extend(positions.into_keys());
+    Ok(true)
+}
+
+/// Returns the committed changes of the requested table after the
+/// consumer's positions, from the worker logs of `registry` in `log_dir`,
+/// whose sealed frames are opened with `keyring`.
+///
+/// A worker that has not published its first visible LSN yet is skipped. A
+/// consumer new to a worker starts at the changes committed from now on.
+pub async fn poll_changes(
+    fs: &dyn AsyncFs,
+    data_dir: &str,
+    log_dir: &Path,
+    keyring: Option<&Keyring>,
+    registry: &WorkerRegistry,
+    meta_mgr: &dyn MetaMgr,
+    request: &CdcSubscribeRequest,
+) -> RS<CdcSubscribeResponse> {
+    if request.consumer().is_empty() {
+        return Err(mudu_error!(
+            ErrorCode::InvalidArgument,
+            "cdc consumer name is empty"
+        ));
+    }
+    let table = meta_mgr
+        .get_table_by_name(request.table())
+        .await?
+        .ok_or_else(|| {
+            mudu_error!(
+                ErrorCode::EntityNotFound,
+                format!("table {} does not exist", request.table())
+            )
+        })?;
+    let ranges = start_positions(data_dir, registry, request)?;
+    let limit = match request.max_changes() {
+        0 => CDC_DEFAULT_MAX_CHANGES,
+        limit => limit,
+    } as usize;
+
+    let mut changes = Vec::new();
+    let mut positions = Vec::with_capacity(ranges.len());
+    for (worker_id, from, visible) in ranges {
+        let next = if changes.len() >= limit {
+            from
+        } else {
+            read_worker_changes(
+                fs,
+                log_dir,
+                keyring,
+                worker_id,
+                from,
+                visible,
+                &table,
+                limit,
+                &mut changes,
+            )
+            .await?
+        };
+        positions.push(CdcPosition::new(worker_id, next));
+    }
+    if request.from().is_none() {
+        let mut states = cdc_states().lock()?;
+        let state = states.entry(data_dir.to_string()).or_default();
+        for position in &positions {
+            state.acknowledge(request.consumer(), position.worker_id(), position.lsn());
+        }
+    }
+    Ok(CdcSubscribeResponse::new(changes, positions))
+}
+
+/// Like [`poll_changes`], but waits up to `wait` for a change to arrive.
+#[allow(clippy::too_many_arguments)]
+pub async fn wait_for_changes(
+    fs: &dyn AsyncFs,
+    data_dir: &str,
+    log_dir: &Path,
+    keyring: Option<&Keyring>,
+    registry: &WorkerRegistry,
+    meta_mgr: &dyn MetaMgr,
+    request: &CdcSubscribeRequest,
+    wait: Duration,
+) -> RS<CdcSubscribeResponse> {
+    let deadline = instant_now() + wait;
+    let mut request = request.clone();
+    loop {
+        let response =
+            poll_changes(fs, data_dir, log_dir, keyring, registry, meta_mgr, &request).await?;
+        if !response.changes().is_empty() || instant_now() >= deadline {
+            return Ok(response);
+        }
+        // Nothing was returned, so the positions only moved past entries of
+        // other tables; later rounds go on from there.
+        if request.from().is_some() {
+            request = CdcSubscribeRequest::new(
+                request.consumer(),
+                request.table(),
+                Some(response.positions().to_vec()),
+                request.max_changes(),
+            );
+        }
+        mudu_sys::sleep(CDC_WAIT_INTERVAL).await?;
+    }
+}
+
+/// Resolves where the poll reads each worker log: `(worker, from, visible)`.
+fn start_positions(
+    data_dir: &str,
+    registry: &WorkerRegistry,
+    request: &CdcSubscribeRequest,
+) -> RS<Vec<(OID, u64, u64)>> {
+    let mut states = cdc_states().lock()?;
+    let state = states.entry(data_dir.to_string()).or_default();
+    for position in request.from().unwrap_or_default() {
+        if registry
+            .worker_index_by_worker_id(position.worker_id())
+            .is_none()
+        {
+            return Err(mudu_error!(
+                ErrorCode::InvalidArgument,
+                format!("unknown worker {} in cdc position", position.worker_id())
+            ));
+        }
+        state.acknowledge(request.consumer(), position.worker_id(), position.lsn());
+    }
+    let mut ranges = Vec::new();
+    for worker in registry.workers() {
+        let Some(visible) = state.visible.get(&worker.worker_id).copied() else {
+            continue;
+        };
+        let from = state
+            .consumers
+            .get(request.consumer())
+            .and_then(|positions| positions.get(&worker.worker_id).copied());
+        let from = match from {
+            Some(from) => from,
+            None => {
+                state.acknowledge(request.consumer(), worker.worker_id, visible);
+                visible
+            }
+        };
+        ranges.push((worker.worker_id, from, visible));
+    }
+    Ok(ranges)
+}
+
+/// Appends the changes of `table` in the log entries of `worker_id` within
+/// `[from, visible)` to `changes`, stopping at an entry boundary once
+/// `limit` is reached. Returns the position after the last entry read.
+#[allow(clippy::too_many_arguments)]
+async fn read_worker_changes(
+    fs: &dyn AsyncFs,
+    log_dir: &Path,
+    keyring: Option<&Keyring>,
+    worker_id: OID,
+    from: u64,
+    visible: u64,
+    table: &TableDesc,
+    limit: usize,
+    changes: &mut Vec<CdcChange>,
+) -> RS<u64> {
+    let decoder = ChangeDecoder::new(table);
+    read_committed_batches(
+        fs,
+        log_dir,
+        keyring,
+        worker_id,
+        from,
+        visible,
+        &mut |lsn, batch| {
+            if changes.len() >= limit {
+                return Ok(false);
+            }
+            decoder.decode(worker_id, lsn, batch, changes)?;
+            Ok(true)
+        },
+    )
+    .await
+}
+
+/// Hands the log batches of `worker_id` within `[from, visible)` to `visit`
+/// in LSN order, opening sealed frames with `keyring`. When `visit`
+/// declines a batch the read stops before it. Returns the position after the
+/// last batch visited.
+pub(crate) async fn read_committed_batches(
+    fs: &dyn AsyncFs,
+    log_dir: &Path,
+    keyring: Option<&Keyring>,
+    worker_id: OID,
+    from: u64,
+    visible: u64,
+    visit: &mut (dyn FnMut(LSN, XLBatch) -> RS<bool> + Send),
+) -> RS<u64> {
+    if from >= visible {
+        return Ok(from);
+    }
+    let layout = WorkerLogLayout::new_inner(log_dir, worker_id, 0);
+    let chunks = layout.chunks_from_lsn_async(fs, LSN::new(from)).await?;
+
+    let mut pending_frames = Vec::new();
+    let mut pending_start_lsn = None;
+    for (first_lsn, path) in &chunks {
+        if first_lsn.as_u64() >= visible {
+            break;
+        }
+        let bytes = fs.read_all(path).await?;
+        let mut frames = Vec::new();
+        for frame in decode_frames_allow_trailing(&bytes)? {
+            let lsn = frame_lsn(&frame)?.as_u64();
+            if lsn >= from && lsn < visible {
+                frames.push(frame);
+            }
+        }
+        let batches = decode_entries_with_pending::<XLBatch>(
+            keyring,
+            &frames,
+            &mut pending_frames,
+            &mut pending_start_lsn,
+        )?;
+        for (start_lsn, batch) in batches {
+            if !visit(start_lsn, batch)? {
+                return Ok(start_lsn.as_u64());
+            }
+        }
+    }
+    Ok(visible)
+}
+
+pub(crate) struct ChangeDecoder<'a> {
+    table: &'a TableDesc,
+    key_select: VecSelTerm,
+    row_select: VecSelTerm,
+}
+
+impl<'a> ChangeDecoder<'a> {
+    pub(crate) fn new(table: &'a TableDesc) -> Self {
+        Self {
+            table,
+            key_select: VecSelTerm::new(table.key_indices().clone()),
+            row_select: VecSelTerm::new((0..table.fields().len()).collect()),
+        }
+    }
+
+    fn decode(
+        &self,
+        worker_id: OID,
+        lsn: LSN,
+        batch: XLBatch,
+        changes: &mut Vec<CdcChange>,
+    ) -> RS<()> {
+        for entry in batch.entries {
+            if entry.ops.iter().any(|op| matches!(op, TxOp::Abort)) {
+                continue;
+            }
+            for op in entry.ops {
+                let TxOp::Write(write) = op else {
+                    continue;
+                };
+                if let Some(change) = self.decode_write(worker_id, lsn, entry.xid, write)? {
+                    changes.push(change);
+                }
+            }
+        }
+        Ok(())
+    }
+
+    /// Decodes one logged write; `None` when it belongs to another table or
+    /// carries no change of its own.
+    pub(crate) fn decode_write(
+        &self,
+        worker_id: OID,
+        lsn: LSN,
+        xid: u64,
+        write: XLWrite,
+    ) -> RS<Option<CdcChange>> {
+        if write.table_id() != self.table.id() {
+            return Ok(None);
+        }
+        let (op, key, row) = match write {
+            XLWrite::Insert(insert) => (
+                CdcOp::Put,
+                self.to_json(&self.key_select, &insert.key, &[])?,
+                Some(self.to_json(&self.row_select, &insert.key, &insert.value)?),
+            ),
+            XLWrite::Delete(delete) => (
+                CdcOp::Delete,
+                self.to_json(&self.key_select, &delete.key, &[])?,
+                None,
+            ),
+            // Updates are logged as inserts of the whole row.
+            XLWrite::Update(_) => return Ok(None),
+        };
+        Ok(Some(CdcChange::new(
+            worker_id,
+            lsn.as_u64(),
+            xid,
+            op,
+            key,
+            row,
+        )))
+    }
+
+    fn to_json(&self, select: &VecSelTerm, key: &[u8], value: &[u8]) -> RS<JsonValue> {
+        let fields = project_selected_fields(self.table, key, value, select)?;
+        let desc = project_tuple_desc(self.table, select);
+        TupleField::new_nullable(fields).to_json_value(desc.fields())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used, clippy::expect_used, clippy::panic)]
+
+    use super::*;
+
+    fn data_dir(name: &str) -> String {
+        format!("/cdc-test/{}/{}", name, mudu_utils::oid::gen_oid())
+    }
+
+    #[test]
+    fn floor_follows_the_oldest_acknowledged_position() {
+        let dir = data_dir("floor");
+        {
+            let mut states = cdc_states().lock().unwrap();
+            let state = states.entry(dir.clone()).or_default();
+            state.acknowledge("a", 1, 30);
+            state.acknowledge("b", 1, 10);
+            state.acknowledge("b", 2, 5);
+        }
+        assert_eq!(consumer_floor(&dir, 1).unwrap(), Some(LSN::new(10)));
+        assert_eq!(consumer_floor(&dir, 3).unwrap(), None);
+
+        let saved = take_changed_positions(&dir, 1).unwrap().unwrap();
+        assert_eq!(
+            saved.positions,
+            BTreeMap::from([("a".to_string(), 30), ("b".to_string(), 10)])
+        );
+        assert!(take_changed_positions(&dir, 1).unwrap().is_none());
+
+        assert!(drop_consumer(&dir, "b").unwrap());
+        assert!(!drop_consumer(&dir, "b").unwrap());
+        assert_eq!(consumer_floor(&dir, 1).unwrap(), Some(LSN::new(30)));
+        assert_eq!(
+            take_changed_positions(&dir, 2).unwrap().unwrap().positions,
+            BTreeMap::new()
+        );
+    }
+
+    #[test]
+    fn loaded_positions_do_not_override_newer_ones() {
+        let dir = data_dir("load");
+        {
+            let mut states = cdc_states().lock().unwrap();
+            states
+                .entry(dir.clone())
+                .or_default()
+                .acknowledge("a", 1, 50);
+        }
+        load_consumer_positions(
+            &dir,
+            1,
+            CdcConsumerPositions {
+                positions: BTreeMap::from([("a".to_string(), 20), ("b".to_string(), 7)]),
+            },
+        )
+        .unwrap();
+        assert_eq!(consumer_floor(&dir, 1).unwrap(), Some(LSN::new(7)));
+        drop_consumer(&dir, "b").unwrap();
+        assert_eq!(consumer_floor(&dir, 1).unwrap(), Some(LSN::new(50)));
+    }
+}
@@ -0,0 +1,235 @@
+use crate::storage::state_file::{read_state_file, write_state_file};
+use crate::wal::lsn::LSN;
+use crate::wal::worker_log::{ChunkedWorkerLogBackend, WorkerLogLayout, WorkerLogPosition};
+use mudu::common::result::RS;
+use mudu_sys::contract::async_fs::AsyncFs;
+use mudu_sys::sync::SMutex;
+use mudu_sys::time::{instant_now, Instant};
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::sync::Arc;
+use std::time::Duration;
+use tracing::debug;
+
+/// Time between two checks whether a worker is due for a checkpoint.
+pub(crate) const CHECKPOINT_POLL_INTERVAL: Duration = Duration::from_secs(1);
+
+const CHECKPOINT_MAGIC: u32 = 0x4D43_4B50; // "MCKP"
+
+/// When a worker takes a checkpoint.
+///
+/// A checkpoint is due once `interval` has passed or `log_bytes` bytes were
+/// appended to the worker log since the previous one. A zero value disables
+/// its trigger, and a worker that logged nothing skips the checkpoint.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct CheckpointPolicy {
+    pub interval: Duration,
+    pub log_bytes: u64,
+}
+
+impl Default for CheckpointPolicy {
+    fn default() -> Self {
+        Self {
+            interval: Duration::from_secs(300),
+            log_bytes: 256 * 1024 * 1024,
+        }
+    }
+}
+
+/// Outcome of one checkpoint.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct CheckpointStats {
+    /// Recovery replays the worker log from this LSN on.
+    pub lsn: LSN,
+    /// Worker log chunks removed because they hold only older entries.
+    pub removed_chunks: usize,
+}
+
+/// Latest version of a kv row. The kv store lives in memory only, so the
+/// checkpoint carries its rows.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct CheckpointKvRow {
+    pub key: Vec<u8>,
+    pub xid: u64,
+    pub value: Vec<u8>,
+    /// Deadline of a value written with a TTL, see [`crate::server::kv_ttl`].
+    #[serde(default)]
+    pub expires_at: Option<u64>,
+}
+
+/// Worker state that recovery starts from instead of the first log chunk.
+///
+/// Relation files are synced when the checkpoint is taken, so only the kv
+/// rows and the commit timestamp are stored here.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct WorkerCheckpoint {
+    lsn: u64,
+    next_ts: u64,
+    kv_rows: Vec<CheckpointKvRow>,
+}
+
+impl WorkerCheckpoint {
+    pub fn new(lsn: LSN, next_ts: u64, kv_rows: Vec<CheckpointKvRow>) -> Self {
+        Self {
+            lsn: lsn.as_u64(),
+            next_ts,
+            kv_rows,
+        }
+    }
+
+    pub fn lsn(&self) -> LSN {
+        LSN::new(self.lsn)
+    }
+
+    pub fn next_ts(&self) -> u64 {
+        self.next_ts
+    }
+
+    pub fn into_kv_rows(self) -> Vec<CheckpointKvRow> {
+        self.kv_rows
+    }
+
+    /// Writes the checkpoint next to the log chunks and removes the older
+    /// checkpoints once it is durable.
+    pub async fn write_async(&self, fs: &dyn AsyncFs, layout: &WorkerLogLayout) -> RS<()> {
+        let path = layout.checkpoint_path(self.lsn());
+        write_state_file(fs, &path, CHECKPOINT_MAGIC, self, layout.keyring()).await?;
+        for (lsn, older) in layout.checkpoint_paths_sorted_async(fs).await? {
+            if lsn < self.lsn() {
+                fs.remove_file_if_exists(&older).await?;
+            }
+        }
+        Ok(())
+    }
+
+    /// Loads the newest checkpoint of the log.
+    ///
+    /// A checkpoint that cannot be read was torn while being written; the
+    /// previous one and the log chunks it needs are still in place then.
+    pub async fn load_latest_async(fs: &dyn AsyncFs, layout: &WorkerLogLayout) -> RS<Option<Self>> {
+        for (_, path) in layout.checkpoint_paths_sorted_async(fs).await?.iter().rev() {
+            let keyring = layout.keyring().map(|keyring| keyring.as_ref());
+            match read_state_file::<Self>(fs, path, CHECKPOINT_MAGIC, keyring).await {
+                Ok(checkpoint) => return Ok(Some(checkpoint)),
+                Err(err) => {
+                    debug!(path = %path.display(), error = ?err, "skip unreadable checkpoint");
+                }
+            }
+        }
+        Ok(None)
+    }
+}
+
+/// Tracks commits from their worker log append until their storage apply
+/// finished, so a checkpoint knows which logged commits it contains.
+#[derive(Default)]
+pub(crate) struct CommitTickets {
+    inner: Arc<SMutex<TicketSet>>,
+}
+
+#[derive(Default)]
+struct TicketSet {
+    next_id: u64,
+    // Log position each active commit appends after; ids are handed out in
+    // position order.
+    active: BTreeMap<u64, WorkerLogPosition>,
+}
+
+/// Keeps its commit active until dropped.
+pub(crate) struct CommitTicket {
+    id: u64,
+    tickets: Arc<SMutex<TicketSet>>,
+}
+
+impl CommitTickets {
+    /// Registers a commit that is about to append to `log`.
+    pub(crate) fn enter(&self, log: &ChunkedWorkerLogBackend) -> RS<CommitTicket> {
+        let mut set = self.inner.lock()?;
+        let position = log.position()?;
+        let id = set.next_id;
+        set.next_id += 1;
+        let _ = set.active.insert(id, position);
+        Ok(CommitTicket {
+            id,
+            tickets: self.inner.clone(),
+        })
+    }
+
+    /// Returns the log position below which every commit is applied to
+    /// storage.
+    pub(crate) fn applied_position(&self, log: &ChunkedWorkerLogBackend) -> RS<WorkerLogPosition> {
+        let set = self.inner.lock()?;
+        match set.active.first_key_value() {
+            Some((_, oldest)) => Ok(*oldest),
+            None => log.position(),
+        }
+    }
+}
+
+impl Drop for CommitTicket {
+    fn drop(&mut self) {
+        if let Ok(mut set) = self.tickets.lock() {
+            let _ = set.active.remove(&self.id);
+        }
+    }
+}
+
+/// Decides whether the next checkpoint is due.
+pub(crate) struct CheckpointSchedule {
+    policy: CheckpointPolicy,
+    last_at: Instant,
+    last_log_bytes: u64,
+}
+
+impl CheckpointSchedule {
+    pub(crate) fn set_policy(&mut self, policy: CheckpointPolicy) {
+        self.policy = policy;
+    }
+
+    pub(crate) fn is_due(&self, log_bytes: u64) -> bool {
+        let logged = log_bytes.saturating_sub(self.last_log_bytes);
+        if logged == 0 {
+            return false;
+        }
+        let by_size = self.policy.log_bytes != 0 && logged >= self.policy.log_bytes;
+        let by_time = !self.policy.interval.is_zero()
+            && instant_now().saturating_duration_since(self.last_at) >= self.policy.interval;
+        by_size || by_time
+    }
+
+    pub(crate) fn mark_done(&mut self, log_bytes: u64) {
+        self.last_at = instant_now();
+        self.last_log_bytes = log_bytes;
+    }
+}
+
+impl Default for CheckpointSchedule {
+    fn default() -> Self {
+        Self {
+            policy: CheckpointPolicy::default(),
+            last_at: instant_now(),
+            last_log_bytes: 0,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{CheckpointPolicy, CheckpointSchedule};
+    use std::time::Duration;
+
+    #[test]
+    fn schedule_triggers_on_logged_bytes() {
+        let mut schedule = CheckpointSchedule::default();
+        schedule.set_policy(CheckpointPolicy {
+            interval: Duration::ZERO,
+            log_bytes: 100,
+        });
+        assert!(!schedule.is_due(0));
+        assert!(!schedule.is_due(99));
+        assert!(schedule.is_due(100));
+        schedule.mark_done(100);
+        assert!(!schedule.is_due(150));
+        assert!(schedule.is_due(200));
+    }
+}
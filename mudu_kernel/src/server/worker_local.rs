@@ -4,11 +4,14 @@ use crate::server::worker_snapshot::KvItem;
 use async_trait::async_trait;
 use mudu::common::id::OID;
 use mudu::common::result::RS;
+use mudu_contract::database::kv_namespace::KvNamespace;
+use mudu_contract::database::kv_range::{KvRangeOptions, KvRangePage};
 use mudu_contract::database::result_set::ResultSetAsync;
 use mudu_contract::database::sql_params::SQLParams;
 use mudu_contract::database::sql_stmt::SQLStmt;
 use std::cell::UnsafeCell;
 use std::sync::Arc;
+use std::time::Duration;
 
 use crate::x_engine::api::XContract;
 
@@ -49,8 +52,154 @@ pub trait WorkerLocal: Send + Sync {
 
     async fn put_async(&self, session_id: OID, key: Vec<u8>, value: Vec<u8>) -> RS<()>;
 
+    /// Writes `value` so that `key` expires `ttl` after the write commits.
+    async fn put_with_ttl_async(
+        &self,
+        _session_id: OID,
+        _key: Vec<u8>,
+        _value: Vec<u8>,
+        _ttl: Duration,
+    ) -> RS<()> {
+        Err(mudu::mudu_error!(
+            mudu::error::ErrorCode::NotImplemented,
+            "worker-local put with ttl is not supported"
+        ))
+    }
+
     async fn delete_async(&self, session_id: OID, key: &[u8]) -> RS<()>;
 
+    /// Writes all items as one unit. The default writes them one by one.
+    async fn put_many_async(&self, session_id: OID, items: Vec<KvItem>) -> RS<()> {
+        for item in items {
+            self.put_async(session_id, item.key, item.value).await?;
+        }
+        Ok(())
+    }
+
+    /// Deletes all keys as one unit. The default deletes them one by one.
+    async fn delete_many_async(&self, session_id: OID, keys: Vec<Vec<u8>>) -> RS<()> {
+        for key in keys {
+            self.delete_async(session_id, &key).await?;
+        }
+        Ok(())
+    }
+
+    /// Replaces the value of `key` with `value` when it currently equals `expected`
+    /// (`None` meaning absent). Returns whether the swap happened.
+    async fn cas_async(
+        &self,
+        _session_id: OID,
+        _key: Vec<u8>,
+        _expected: Option<Vec<u8>>,
+        _value: Vec<u8>,
+    ) -> RS<bool> {
+        Err(mudu::mudu_error!(
+            mudu::error::ErrorCode::NotImplemented,
+            "worker-local compare-and-swap is not supported"
+        ))
+    }
+
+    /// Adds `delta` to the decimal counter stored at `key` and returns the new value.
+    async fn incr_async(&self, _session_id: OID, _key: Vec<u8>, _delta: i64) -> RS<i64> {
+        Err(mudu::mudu_error!(
+            mudu::error::ErrorCode::NotImplemented,
+            "worker-local increment is not supported"
+        ))
+    }
+
+    /// Writes `value` only when `key` is absent. Returns whether it was written.
+    async fn put_if_absent_async(
+        &self,
+        _session_id: OID,
+        _key: Vec<u8>,
+        _value: Vec<u8>,
+    ) -> RS<bool> {
+        Err(mudu::mudu_error!(
+            mudu::error::ErrorCode::NotImplemented,
+            "worker-local put-if-absent is not supported"
+        ))
+    }
+
+    /// Creates a KV namespace, optionally spread over a partition rule.
+    async fn kv_namespace_create_async(
+        &self,
+        _session_id: OID,
+        _namespace: &KvNamespace,
+        _partition_rule: Option<&str>,
+    ) -> RS<()> {
+        Err(mudu::mudu_error!(
+            mudu::error::ErrorCode::NotImplemented,
+            "worker-local kv namespaces are not supported"
+        ))
+    }
+
+    /// Drops a KV namespace and every key stored in it.
+    async fn kv_namespace_drop_async(&self, _session_id: OID, _namespace: &KvNamespace) -> RS<()> {
+        Err(mudu::mudu_error!(
+            mudu::error::ErrorCode::NotImplemented,
+            "worker-local kv namespaces are not supported"
+        ))
+    }
+
+    /// Lists the KV namespaces of `app_name`.
+    async fn kv_namespace_list_async(&self, _app_name: &str) -> RS<Vec<KvNamespace>> {
+        Err(mudu::mudu_error!(
+            mudu::error::ErrorCode::NotImplemented,
+            "worker-local kv namespaces are not supported"
+        ))
+    }
+
+    async fn kv_namespace_get_async(
+        &self,
+        _session_id: OID,
+        _namespace: &KvNamespace,
+        _key: &[u8],
+    ) -> RS<Option<Vec<u8>>> {
+        Err(mudu::mudu_error!(
+            mudu::error::ErrorCode::NotImplemented,
+            "worker-local kv namespaces are not supported"
+        ))
+    }
+
+    async fn kv_namespace_put_async(
+        &self,
+        _session_id: OID,
+        _namespace: &KvNamespace,
+        _key: Vec<u8>,
+        _value: Vec<u8>,
+    ) -> RS<()> {
+        Err(mudu::mudu_error!(
+            mudu::error::ErrorCode::NotImplemented,
+            "worker-local kv namespaces are not supported"
+        ))
+    }
+
+    async fn kv_namespace_delete_async(
+        &self,
+        _session_id: OID,
+        _namespace: &KvNamespace,
+        _key: &[u8],
+    ) -> RS<()> {
+        Err(mudu::mudu_error!(
+            mudu::error::ErrorCode::NotImplemented,
+            "worker-local kv namespaces are not supported"
+        ))
+    }
+
+    async fn kv_namespace_range_async(
+        &self,
+        _session_id: OID,
+        _namespace: &KvNamespace,
+        _start_key: &[u8],
+        _end_key: &[u8],
+        _options: &KvRangeOptions,
+    ) -> RS<KvRangePage> {
+        Err(mudu::mudu_error!(
+            mudu::error::ErrorCode::NotImplemented,
+            "worker-local kv namespaces are not supported"
+        ))
+    }
+
     async fn get_async(&self, session_id: OID, key: &[u8]) -> RS<Option<Vec<u8>>>;
 
     async fn range_async(
@@ -58,7 +207,8 @@ pub trait WorkerLocal: Send + Sync {
         session_id: OID,
         start_key: &[u8],
         end_key: &[u8],
-    ) -> RS<Vec<KvItem>>;
+        options: &KvRangeOptions,
+    ) -> RS<KvRangePage>;
 
     async fn query(
         &self,
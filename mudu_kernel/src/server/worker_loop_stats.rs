@@ -21,4 +21,9 @@ pub(in crate::server) struct WorkerLoopStats {
     pub mailbox_submit: u64,
     pub mailbox_drained: u64,
     pub local_register: u64,
+    pub buffer_pool_hits: u64,
+    pub buffer_pool_misses: u64,
+    pub buffer_pool_evictions: u64,
+    pub page_compression_ratio: f64,
+    pub wal_compression_ratio: f64,
 }
@@ -0,0 +1,92 @@
+//! Point-in-time recovery.
+//!
+//! A worker restored from a base backup, with the archived log chunks copied
+//! back into its log directory, replays the log only up to a
+//! [`RecoveryTarget`]. Once replay finished, a checkpoint seals the log at its
+//! end, so the entries past the target are never replayed afterwards.
+
+use crate::wal::lsn::LSN;
+use crate::wal::xl_batch::XLBatch;
+
+/// Where worker log recovery stops.
+///
+/// With both bounds set an entry must satisfy both to be replayed.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct RecoveryTarget {
+    /// Log entries starting at this LSN or later are not replayed.
+    pub lsn: Option<u64>,
+    /// Transactions with a later timestamp, the xid their versions carry and
+    /// `AS OF SYSTEM TIME` reads compare against, are not replayed.
+    pub commit_ts: Option<u64>,
+}
+
+impl RecoveryTarget {
+    pub fn is_set(&self) -> bool {
+        self.lsn.is_some() || self.commit_ts.is_some()
+    }
+
+    /// Returns the part of `batch`, a log entry starting at `start_lsn`, that
+    /// recovery replays; `None` when nothing is left.
+    ///
+    /// Transaction timestamps are not ordered by LSN across concurrent
+    /// commits, so a timestamp target filters every entry rather than
+    /// stopping at the first later one.
+    pub(crate) fn retain(&self, mut batch: XLBatch, start_lsn: LSN) -> Option<XLBatch> {
+        if self.lsn.is_some_and(|lsn| start_lsn.as_u64() >= lsn) {
+            return None;
+        }
+        if let Some(commit_ts) = self.commit_ts {
+            batch.entries.retain(|entry| entry.xid <= commit_ts);
+        }
+        if batch.entries.is_empty() {
+            None
+        } else {
+            Some(batch)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used, clippy::expect_used, clippy::panic)]
+
+    use super::*;
+    use crate::wal::xl_entry::{TxOp, XLEntry};
+
+    fn batch(xids: &[u64]) -> XLBatch {
+        XLBatch::new(
+            xids.iter()
+                .map(|xid| XLEntry {
+                    xid: *xid,
+                    ops: vec![TxOp::Begin, TxOp::Commit],
+                    commit_ms: 0,
+                })
+                .collect(),
+        )
+    }
+
+    #[test]
+    fn retain_stops_at_target_lsn() {
+        let target = RecoveryTarget {
+            lsn: Some(10),
+            commit_ts: None,
+        };
+        assert_eq!(target.retain(batch(&[1]), LSN::new(9)), Some(batch(&[1])));
+        assert_eq!(target.retain(batch(&[2]), LSN::new(10)), None);
+        assert_eq!(target.retain(batch(&[3]), LSN::new(11)), None);
+    }
+
+    #[test]
+    fn retain_filters_entries_by_commit_ts() {
+        let target = RecoveryTarget {
+            lsn: None,
+            commit_ts: Some(5),
+        };
+        assert_eq!(
+            target.retain(batch(&[4, 6, 5]), LSN::new(0)),
+            Some(batch(&[4, 5]))
+        );
+        assert_eq!(target.retain(batch(&[7]), LSN::new(1)), None);
+        assert!(!RecoveryTarget::default().is_set());
+    }
+}
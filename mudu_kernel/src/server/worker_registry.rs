@@ -183,6 +183,17 @@ fn create_worker_identity(log_dir: &Path, worker_index: usize) -> RS<WorkerIdent
     })
 }
 
+/// Marker files under `log_dir` that record `worker`'s identity.
+pub(crate) fn identity_marker_paths(log_dir: &Path, worker: &WorkerIdentity) -> Vec<PathBuf> {
+    let mut paths = vec![log_dir.join(worker_marker_name(worker.worker_index, worker.worker_id))];
+    paths.extend(
+        worker.partition_ids.iter().map(|&partition_id| {
+            log_dir.join(partition_marker_name(worker.worker_id, partition_id))
+        }),
+    );
+    paths
+}
+
 fn validate_worker_identities(workers: &[WorkerIdentity], worker_count: usize) -> RS<()> {
     if workers.len() != worker_count {
         return Err(mudu_error!(
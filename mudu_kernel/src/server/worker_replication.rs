@@ -0,0 +1,447 @@
+//! Read replicas fed by worker log shipping.
+//!
+//! A replica starts from a base backup of the primary, so its workers have
+//! the primary's worker ids and its worker logs are prefixes of the
+//! primary's. A follower task of the replica asks the primary for the frames
+//! after the end of each worker log, which [`read_log_frames`] serves, and
+//! hands them over with [`queue_shipped_frames`]. At every checkpoint poll a
+//! worker appends the queued frames to its own log, at the LSNs the primary
+//! gave them, and replays them through the handler that replays its log at
+//! startup (see `WorkerRuntime::replicate_async`).
+//!
+//! A replica rejects commits until [`promote`] is called. Promotion stops the
+//! shipping; each worker applies the frames already queued and then accepts
+//! writes. A marker in the data directory keeps the replica promoted across
+//! restarts.
+//!
+//! The primary keeps each replica's position like a change data capture
+//! consumer position named `replica:<name>`, so checkpoints keep the chunks
+//! a replica has not received yet.
+
+use std::collections::{BTreeMap, HashMap};
+use std::path::{Path, PathBuf};
+use std::sync::OnceLock;
+
+use async_trait::async_trait;
+use mudu::common::id::OID;
+use mudu::common::result::RS;
+use mudu::error::ErrorCode;
+use mudu::mudu_error;
+use mudu_contract::protocol::{WalFetchRequest, WalFetchResponse};
+use mudu_sys::contract::async_fs::AsyncFs;
+use mudu_sys::sync::SMutex;
+use mudu_sys::time::{instant_now, Instant};
+
+use crate::server::worker::WorkerRuntime;
+use crate::server::worker_cdc::{acknowledge_position, visible_lsn};
+use crate::server::worker_registry::WorkerRegistry;
+use crate::storage::backup::write_synced;
+use crate::wal::log_frame::{check_frame, frame_len};
+use crate::wal::lsn::LSN;
+use crate::wal::typed_worker_log::WorkerLogRecoveryHandler;
+use crate::wal::worker_log::WorkerLogLayout;
+use crate::wal::xl_batch::XLBatch;
+
+/// Bytes of log frames one fetch returns at most when the request sets no
+/// limit.
+pub const REPLICA_FETCH_MAX_BYTES: u32 = 4 << 20;
+
+const REPLICA_CONSUMER_PREFIX: &str = "replica:";
+
+const PROMOTED_MARKER_FILE: &str = "replica.promoted";
+
+#[derive(Default)]
+struct ReplicaState {
+    promoted: bool,
+    workers: BTreeMap<OID, ReplicaWorker>,
+}
+
+#[derive(Default)]
+struct ReplicaWorker {
+    // End of the worker log; shipping goes on from there.
+    log_lsn: u64,
+    // Frames received from the primary and not appended yet.
+    pending: Vec<u8>,
+    // LSN below which the primary's entries were committed at the last fetch.
+    primary_lsn: u64,
+    // When the log last reached `primary_lsn`.
+    caught_up_at: Option<Instant>,
+}
+
+impl ReplicaWorker {
+    fn update_caught_up(&mut self) {
+        if self.log_lsn >= self.primary_lsn {
+            self.caught_up_at = Some(instant_now());
+        }
+    }
+}
+
+/// Frames a worker takes over from the follower at a checkpoint poll.
+pub(crate) struct ShippedFrames {
+    pub frames: Vec<u8>,
+    /// The replica was promoted; no frames follow these.
+    pub promoted: bool,
+}
+
+/// Replication state of one worker of a replica.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ReplicaWorkerStatus {
+    pub worker_id: OID,
+    /// End of the worker log on the replica.
+    pub applied_lsn: u64,
+    /// LSN below which the primary's entries were committed at the last
+    /// fetch.
+    pub primary_lsn: u64,
+    /// Milliseconds since the worker log last caught up with the primary;
+    /// `None` before it caught up once.
+    pub lag_ms: Option<u64>,
+}
+
+impl ReplicaWorkerStatus {
+    pub fn lag_lsn(&self) -> u64 {
+        self.primary_lsn.saturating_sub(self.applied_lsn)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ReplicationStatus {
+    pub promoted: bool,
+    pub workers: Vec<ReplicaWorkerStatus>,
+}
+
+fn replica_states() -> &'static SMutex<HashMap<String, ReplicaState>> {
+    static STATES: OnceLock<SMutex<HashMap<String, ReplicaState>>> = OnceLock::new();
+    STATES.get_or_init(|| SMutex::new(HashMap::new()))
+}
+
+fn replica_consumer(replica: &str) -> String {
+    format!("{}{}", REPLICA_CONSUMER_PREFIX, replica)
+}
+
+fn promoted_marker_path(data_dir: &Path) -> PathBuf {
+    data_dir.join(PROMOTED_MARKER_FILE)
+}
+
+/// Returns whether the replica serving `data_dir` was promoted; it starts
+/// as a primary then, whatever its configuration says.
+pub fn replica_promoted(data_dir: &Path) -> bool {
+    mudu_sys::fs::sync::path_exists(promoted_marker_path(data_dir))
+}
+
+/// Makes the server serving `data_dir` a read replica; called before its
+/// workers and its follower start.
+pub fn enable_replica(data_dir: &str) -> RS<()> {
+    let mut states = replica_states().lock()?;
+    states.entry(data_dir.to_string()).or_default();
+    Ok(())
+}
+
+/// Returns the workers whose frames the follower fetches next and the LSN
+/// to fetch from: every worker that has no frames queued. `None` once the
+/// replica is promoted or when `data_dir` is not served by one.
+pub fn fetch_positions(data_dir: &str) -> RS<Option<Vec<(OID, u64)>>> {
+    let states = replica_states().lock()?;
+    let Some(state) = states.get(data_dir) else {
+        return Ok(None);
+    };
+    if state.promoted {
+        return Ok(None);
+    }
+    Ok(Some(
+        state
+            .workers
+            .iter()
+            .filter(|(_, worker)| worker.pending.is_empty())
+            .map(|(worker_id, worker)| (*worker_id, worker.log_lsn))
+            .collect(),
+    ))
+}
+
+/// Queues the frames the primary returned for a worker; they are dropped
+/// once the replica is promoted.
+pub fn queue_shipped_frames(data_dir: &str, response: WalFetchResponse) -> RS<()> {
+    let mut states = replica_states().lock()?;
+    let Some(state) = states.get_mut(data_dir) else {
+        return Ok(());
+    };
+    if state.promoted {
+        return Ok(());
+    }
+    let Some(worker) = state.workers.get_mut(&response.worker_id()) else {
+        return Ok(());
+    };
+    worker.primary_lsn = response.primary_lsn();
+    worker.pending = response.into_frames();
+    if worker.pending.is_empty() {
+        worker.update_caught_up();
+    }
+    Ok(())
+}
+
+/// Takes the frames queued for `worker_id`, whose log ends at `log_lsn`.
+pub(crate) fn take_shipped_frames(
+    data_dir: &str,
+    worker_id: OID,
+    log_lsn: LSN,
+) -> RS<ShippedFrames> {
+    let mut states = replica_states().lock()?;
+    let state = states.entry(data_dir.to_string()).or_default();
+    let promoted = state.promoted;
+    let worker = state.workers.entry(worker_id).or_default();
+    worker.log_lsn = log_lsn.as_u64();
+    Ok(ShippedFrames {
+        frames: std::mem::take(&mut worker.pending),
+        promoted,
+    })
+}
+
+/// Records that the log of `worker_id` ends at `log_lsn` after applying
+/// shipped frames.
+pub(crate) fn record_applied(data_dir: &str, worker_id: OID, log_lsn: LSN) -> RS<()> {
+    let mut states = replica_states().lock()?;
+    if let Some(worker) = states
+        .get_mut(data_dir)
+        .and_then(|state| state.workers.get_mut(&worker_id))
+    {
+        worker.log_lsn = log_lsn.as_u64();
+        worker.update_caught_up();
+    }
+    Ok(())
+}
+
+/// Returns how far each worker of the replica serving `data_dir` lags
+/// behind the primary; `None` when `data_dir` is not served by a replica.
+pub fn replication_status(data_dir: &str) -> RS<Option<ReplicationStatus>> {
+    let states = replica_states().lock()?;
+    let Some(state) = states.get(data_dir) else {
+        return Ok(None);
+    };
+    let now = instant_now();
+    Ok(Some(ReplicationStatus {
+        promoted: state.promoted,
+        workers: state
+            .workers
+            .iter()
+            .map(|(worker_id, worker)| ReplicaWorkerStatus {
+                worker_id: *worker_id,
+                applied_lsn: worker.log_lsn,
+                primary_lsn: worker.primary_lsn,
+                lag_ms: worker.caught_up_at.map(|at| {
+                    if worker.log_lsn >= worker.primary_lsn {
+                        0
+                    } else {
+                        now.saturating_duration_since(at).as_millis() as u64
+                    }
+                }),
+            })
+            .collect(),
+    }))
+}
+
+/// Promotes the replica serving `data_dir`: shipping stops, and every
+/// worker accepts writes after applying the frames it already received.
+/// Returns `false` when it was promoted before.
+pub async fn promote(data_dir: &str) -> RS<bool> {
+    {
+        let states = replica_states().lock()?;
+        match states.get(data_dir) {
+            None => {
+                return Err(mudu_error!(
+                    ErrorCode::InvalidState,
+                    format!("{} is not served by a read replica", data_dir)
+                ))
+            }
+            Some(state) if state.promoted => return Ok(false),
+            Some(_) => {}
+        }
+    }
+    let fs = mudu_sys::default_sys_io_context().fs();
+    write_synced(
+        fs.as_ref(),
+        &promoted_marker_path(Path::new(data_dir)),
+        b"promoted",
+    )
+    .await?;
+    let mut states = replica_states().lock()?;
+    if let Some(state) = states.get_mut(data_dir) {
+        state.promoted = true;
+        for worker in state.workers.values_mut() {
+            worker.primary_lsn = worker.log_lsn.max(worker.primary_lsn);
+        }
+    }
+    Ok(true)
+}
+
+/// Returns the log frames of the requested worker from the requested LSN
+/// on, for a replica, from the worker logs of `registry` in `log_dir`.
+///
+/// Only frames below the LSN the worker last published as committed are
+/// shipped, and the response ends at an entry boundary. The request
+/// acknowledges the frames before `from_lsn`.
+pub async fn read_log_frames(
+    fs: &dyn AsyncFs,
+    data_dir: &str,
+    log_dir: &Path,
+    registry: &WorkerRegistry,
+    request: &WalFetchRequest,
+) -> RS<WalFetchResponse> {
+    if request.replica().is_empty() {
+        return Err(mudu_error!(
+            ErrorCode::InvalidArgument,
+            "replica name is empty"
+        ));
+    }
+    let worker_id = request.worker_id();
+    if registry.worker_index_by_worker_id(worker_id).is_none() {
+        return Err(mudu_error!(
+            ErrorCode::InvalidArgument,
+            format!("unknown worker {} in wal fetch request", worker_id)
+        ));
+    }
+    let from = LSN::new(request.from_lsn());
+    let Some(visible) = visible_lsn(data_dir, worker_id)? else {
+        return Ok(WalFetchResponse::new(worker_id, Vec::new(), from.as_u64()));
+    };
+    acknowledge_position(
+        data_dir,
+        &replica_consumer(request.replica()),
+        worker_id,
+        from,
+    )?;
+    if from >= visible {
+        return Ok(WalFetchResponse::new(
+            worker_id,
+            Vec::new(),
+            visible.as_u64(),
+        ));
+    }
+    let max_bytes = match request.max_bytes() {
+        0 => REPLICA_FETCH_MAX_BYTES,
+        max_bytes => max_bytes,
+    } as usize;
+
+    let layout = WorkerLogLayout::new_inner(log_dir, worker_id, 0);
+    let chunks = layout.chunks_from_lsn_async(fs, from).await?;
+    let mut frames = Vec::new();
+    // Length of `frames` up to the last complete entry.
+    let mut complete = 0;
+    let mut next = from;
+    'chunks: for (first_lsn, path) in &chunks {
+        if *first_lsn >= visible {
+            break;
+        }
+        let bytes = fs.read_all(path).await?;
+        let mut offset = 0;
+        while offset < bytes.len() {
+            // Stop at a frame that is not completely written yet; frames
+            // must follow each other without gaps anyway.
+            let Ok(len) = frame_len(&bytes[offset..]) else {
+                break;
+            };
+            let frame = &bytes[offset..offset + len];
+            offset += len;
+            let Ok(header) = check_frame(frame) else {
+                break;
+            };
+            let lsn = header.lsn();
+            if lsn < next {
+                continue;
+            }
+            if lsn != next || lsn >= visible {
+                break 'chunks;
+            }
+            frames.extend_from_slice(frame);
+            next = lsn.saturating_add(1);
+            if header.n_part() == 0 {
+                complete = frames.len();
+                if complete >= max_bytes {
+                    break 'chunks;
+                }
+            }
+        }
+    }
+    frames.truncate(complete);
+    Ok(WalFetchResponse::new(worker_id, frames, visible.as_u64()))
+}
+
+/// Replays shipped log entries the way worker log recovery does.
+pub(crate) struct ReplicaReplayHandler {
+    worker: WorkerRuntime,
+}
+
+impl ReplicaReplayHandler {
+    pub(crate) fn new(worker: WorkerRuntime) -> Self {
+        Self { worker }
+    }
+}
+
+#[async_trait]
+impl WorkerLogRecoveryHandler<XLBatch> for ReplicaReplayHandler {
+    async fn handle_entry(&self, entry: XLBatch, start_lsn: LSN) -> RS<()> {
+        self.worker.replay_log_batch(entry, start_lsn).await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used, clippy::expect_used, clippy::panic)]
+
+    use super::*;
+
+    fn data_dir(name: &str) -> String {
+        format!("replication-test-{}-{}", name, mudu_utils::gen_oid())
+    }
+
+    #[test]
+    fn follower_fetches_after_the_applied_frames() {
+        let data_dir = data_dir("fetch");
+        assert_eq!(fetch_positions(&data_dir).unwrap(), None);
+        enable_replica(&data_dir).unwrap();
+        let shipped = take_shipped_frames(&data_dir, 7, LSN::new(10)).unwrap();
+        assert!(shipped.frames.is_empty());
+        assert_eq!(fetch_positions(&data_dir).unwrap(), Some(vec![(7, 10)]));
+
+        queue_shipped_frames(&data_dir, WalFetchResponse::new(7, vec![1, 2], 12)).unwrap();
+        assert_eq!(fetch_positions(&data_dir).unwrap(), Some(vec![]));
+        let status = replication_status(&data_dir).unwrap().unwrap();
+        assert_eq!(status.workers[0].lag_lsn(), 2);
+        assert_eq!(status.workers[0].lag_ms, None);
+
+        let shipped = take_shipped_frames(&data_dir, 7, LSN::new(10)).unwrap();
+        assert_eq!(shipped.frames, vec![1, 2]);
+        record_applied(&data_dir, 7, LSN::new(12)).unwrap();
+        assert_eq!(fetch_positions(&data_dir).unwrap(), Some(vec![(7, 12)]));
+        let status = replication_status(&data_dir).unwrap().unwrap();
+        assert_eq!(status.workers[0].lag_lsn(), 0);
+        assert_eq!(status.workers[0].lag_ms, Some(0));
+    }
+
+    #[test]
+    fn promotion_stops_shipping() {
+        let dir = mudu_sys::env_var::temp_dir().join(data_dir("promote"));
+        let data_dir = dir.to_string_lossy().to_string();
+        enable_replica(&data_dir).unwrap();
+        let _ = take_shipped_frames(&data_dir, 7, LSN::new(3)).unwrap();
+        queue_shipped_frames(&data_dir, WalFetchResponse::new(7, vec![1], 4)).unwrap();
+
+        let promote_once = || {
+            let data_dir = data_dir.clone();
+            mudu_sys::task::async_::block_on_tokio_current_thread(async move {
+                promote(&data_dir).await
+            })
+            .unwrap()
+            .unwrap()
+        };
+        assert!(promote_once());
+        assert!(!promote_once());
+        assert!(replica_promoted(&dir));
+        assert_eq!(fetch_positions(&data_dir).unwrap(), None);
+
+        // Frames received before the promotion are still applied.
+        queue_shipped_frames(&data_dir, WalFetchResponse::new(7, vec![2], 5)).unwrap();
+        let shipped = take_shipped_frames(&data_dir, 7, LSN::new(3)).unwrap();
+        assert!(shipped.promoted);
+        assert_eq!(shipped.frames, vec![1]);
+        mudu_sys::fs::sync::remove_dir_all(&dir).unwrap();
+    }
+}
@@ -1,5 +1,6 @@
 use crate::contract::meta_mgr::MetaMgr;
 use crate::mudu_conn::mudu_conn_core::MuduConnCore;
+use crate::server::session_options::{SessionOptions, SessionOptionsUpdate};
 use crate::x_engine::tx_mgr::TxMgr;
 use mudu::common::id::OID;
 use mudu::common::result::RS;
@@ -23,6 +24,8 @@ pub(crate) struct WorkerSessionManager {
 
 pub(crate) struct SessionContext {
     tx_manager: SMutex<Option<Arc<dyn TxMgr>>>,
+    options: SMutex<SessionOptions>,
+    app: SMutex<Option<String>>,
     mudu_conn_core: Arc<MuduConnCore>,
 }
 
@@ -206,6 +209,37 @@ impl WorkerSessionManager {
         })
     }
 
+    /// Options used by transactions the session begins from now on.
+    pub(crate) fn session_options(&self, session_id: OID) -> RS<SessionOptions> {
+        self.session_context(session_id)?.options()
+    }
+
+    pub(crate) fn update_session_options(
+        &self,
+        session_id: OID,
+        update: &SessionOptionsUpdate,
+    ) -> RS<()> {
+        self.session_context(session_id)?.update_options(update)
+    }
+
+    /// Binds the session to `app`, whose KV namespaces it addresses.
+    pub(crate) fn bind_session_app(&self, session_id: OID, app: &str) -> RS<()> {
+        self.session_context(session_id)?.set_app(app)
+    }
+
+    /// The app the session is bound to.
+    pub(crate) fn session_app(&self, session_id: OID) -> RS<String> {
+        self.session_context(session_id)?.app()?.ok_or_else(|| {
+            mudu_error!(
+                ErrorCode::InvalidArgument,
+                format!(
+                    "session {} is not bound to an app; open it with an \"app\" to use kv namespaces",
+                    session_id
+                )
+            )
+        })
+    }
+
     pub(crate) fn with_session_tx<R, F>(&self, session_id: OID, f: F) -> RS<R>
     where
         F: FnOnce(Option<Arc<dyn TxMgr>>) -> RS<R>,
@@ -242,6 +276,8 @@ impl SessionContext {
     ) -> RS<Self> {
         Ok(Self {
             tx_manager: SMutex::new(None),
+            options: SMutex::new(SessionOptions::default()),
+            app: SMutex::new(None),
             mudu_conn_core: Arc::new(MuduConnCore::new(meta_mgr, async_runtime)?),
         })
     }
@@ -259,6 +295,24 @@ impl SessionContext {
         Ok(self.tx_manager.lock()?.take())
     }
 
+    pub(crate) fn options(&self) -> RS<SessionOptions> {
+        Ok(*self.options.lock()?)
+    }
+
+    pub(crate) fn update_options(&self, update: &SessionOptionsUpdate) -> RS<()> {
+        self.options.lock()?.apply(update);
+        Ok(())
+    }
+
+    pub(crate) fn app(&self) -> RS<Option<String>> {
+        Ok(self.app.lock()?.clone())
+    }
+
+    pub(crate) fn set_app(&self, app: &str) -> RS<()> {
+        *self.app.lock()? = Some(app.to_string());
+        Ok(())
+    }
+
     pub(crate) fn mudu_conn_core(&self) -> Arc<MuduConnCore> {
         self.mudu_conn_core.clone()
     }
@@ -1,9 +1,13 @@
 use crate::contract::snapshot::{RunningXList, Snapshot};
+use crate::server::kv_ttl;
 use mudu::common::result::RS;
 use mudu::error::ErrorCode;
 use mudu::mudu_error;
 use mudu_sys::sync::SMutex;
+use mudu_sys::time::{instant_now, Instant};
+use std::collections::{BTreeMap, VecDeque};
 use std::sync::atomic::{AtomicU64, Ordering};
+use std::time::Duration;
 
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct KvItem {
@@ -19,7 +23,31 @@ pub struct WorkerSnapshot {
 
 pub struct WorkerSnapshotMgr {
     next_ts: AtomicU64,
-    running: SMutex<Vec<u64>>,
+    // How long, in milliseconds, the state as of a timestamp stays readable
+    // by `AS OF SYSTEM TIME` reads after the timestamp was handed out.
+    history_retention_ms: AtomicU64,
+    // Origin of the millisecond clock of `ActiveTxs::handed_out`, and the
+    // wall-clock time in Unix milliseconds it corresponds to.
+    started: Instant,
+    started_unix_ms: u64,
+    active: SMutex<ActiveTxs>,
+}
+
+#[derive(Default)]
+struct ActiveTxs {
+    running: Vec<u64>,
+    // Oldest xid whose visibility each active transaction's snapshot still
+    // depends on, keyed by the transaction xid.
+    low_watermarks: BTreeMap<u64, u64>,
+    // Timestamps of in-flight `AS OF SYSTEM TIME` reads and how many reads
+    // use each one.
+    history_pins: BTreeMap<u64, usize>,
+    // Highest horizon handed to GC; versions below it may already be gone.
+    gc_horizon: u64,
+    // While history is retained: the first timestamp handed out in each
+    // millisecond of the retention window, as (millisecond, timestamp),
+    // oldest first.
+    handed_out: VecDeque<(u64, u64)>,
 }
 
 impl WorkerSnapshot {
@@ -43,28 +71,227 @@ impl WorkerSnapshot {
 impl WorkerSnapshotMgr {
     pub fn begin_tx(&self) -> RS<WorkerSnapshot> {
         let xid = self.next_ts.fetch_add(1, Ordering::Relaxed) + 1;
-        let mut running = self.running.lock()?;
+        let mut active = self.active.lock()?;
+        self.record_handed_out(&mut active, xid, self.now_ms());
         let snapshot = WorkerSnapshot {
             xid,
-            running: running.clone(),
+            running: active.running.clone(),
         };
-        insert_sorted_unique(&mut running, xid);
+        let low_watermark = active.running.first().copied().unwrap_or(xid);
+        insert_sorted_unique(&mut active.running, xid);
+        let _ = active.low_watermarks.insert(xid, low_watermark);
         Ok(snapshot)
     }
 
-    pub fn alloc_committed_ts(&self) -> u64 {
-        self.next_ts.fetch_add(1, Ordering::Relaxed) + 1
+    pub fn alloc_committed_ts(&self) -> RS<u64> {
+        self.alloc_committed_ts_at(self.now_ms())
+    }
+
+    fn alloc_committed_ts_at(&self, now_ms: u64) -> RS<u64> {
+        let ts = self.next_ts.fetch_add(1, Ordering::Relaxed) + 1;
+        if self.history_retention_ms.load(Ordering::Relaxed) != 0 {
+            let mut active = self.active.lock()?;
+            self.record_handed_out(&mut active, ts, now_ms);
+        }
+        Ok(ts)
     }
 
     pub fn observe_committed_ts(&self, xid: u64) {
         self.next_ts.fetch_max(xid, Ordering::Relaxed);
     }
 
+    /// Returns the latest timestamp handed out by this worker.
+    pub fn latest_ts(&self) -> u64 {
+        self.next_ts.load(Ordering::Relaxed)
+    }
+
+    /// Returns the xid below which every committed version is visible to all
+    /// active and future snapshots and to every readable history timestamp,
+    /// so only the newest such version of a row has to be kept.
+    pub fn gc_horizon(&self) -> RS<u64> {
+        self.gc_horizon_at(self.now_ms())
+    }
+
+    fn gc_horizon_at(&self, now_ms: u64) -> RS<u64> {
+        let mut active = self.active.lock()?;
+        Ok(self.advance_gc_horizon(&mut active, now_ms))
+    }
+
+    /// Keeps the state as of every timestamp handed out within the last
+    /// `retention` readable by `AS OF SYSTEM TIME` reads.
+    ///
+    /// Timestamps handed out before history was retained, including those
+    /// replayed from the log at startup, count as older than the window.
+    pub fn set_history_retention(&self, retention: Duration) {
+        let retention_ms = retention.as_millis().min(u64::MAX as u128) as u64;
+        self.history_retention_ms
+            .store(retention_ms, Ordering::Relaxed);
+    }
+
+    /// Returns a snapshot that reads the state as of commit timestamp
+    /// `timestamp` and keeps GC from pruning it until
+    /// [`Self::unpin_history`] is called with the same timestamp.
+    pub fn pin_history(&self, timestamp: u64) -> RS<WorkerSnapshot> {
+        self.pin_history_at(timestamp, self.now_ms())
+    }
+
+    fn pin_history_at(&self, timestamp: u64, now_ms: u64) -> RS<WorkerSnapshot> {
+        let latest = self.next_ts.load(Ordering::Relaxed);
+        if timestamp > latest {
+            return Err(mudu_error!(
+                ErrorCode::InvalidArgument,
+                format!(
+                    "AS OF SYSTEM TIME {} is ahead of the latest commit timestamp {}",
+                    timestamp, latest
+                )
+            ));
+        }
+        let mut active = self.active.lock()?;
+        let oldest = self
+            .advance_gc_horizon(&mut active, now_ms)
+            .saturating_sub(1);
+        if timestamp < oldest {
+            return Err(mudu_error!(
+                ErrorCode::InvalidArgument,
+                format!(
+                    "AS OF SYSTEM TIME {} is older than the retained history, the oldest readable timestamp is {}",
+                    timestamp, oldest
+                )
+            ));
+        }
+        *active.history_pins.entry(timestamp).or_insert(0) += 1;
+        // Versions carry the xid of their transaction, so transactions that
+        // are still running stay invisible like in a regular snapshot.
+        let running = active
+            .running
+            .iter()
+            .copied()
+            .take_while(|xid| *xid <= timestamp)
+            .collect();
+        Ok(WorkerSnapshot {
+            xid: timestamp,
+            running,
+        })
+    }
+
+    /// Returns the latest timestamp this worker handed out at or before the
+    /// wall-clock time `unix_micros`, microseconds since the Unix epoch.
+    ///
+    /// Times are measured on the worker's monotonic clock from the wall-clock
+    /// time it started at, so they cannot reach back before the start or
+    /// further than the history retention window.
+    pub fn commit_ts_at_wall_clock(&self, unix_micros: i64) -> RS<u64> {
+        self.commit_ts_at_wall_clock_at(unix_micros, self.now_ms(), kv_ttl::now_ms())
+    }
+
+    fn commit_ts_at_wall_clock_at(
+        &self,
+        unix_micros: i64,
+        now_ms: u64,
+        unix_now_ms: u64,
+    ) -> RS<u64> {
+        let unix_ms = unix_micros.div_euclid(1_000);
+        if unix_ms > unix_now_ms as i64 {
+            return Err(mudu_error!(
+                ErrorCode::InvalidArgument,
+                format!(
+                    "AS OF SYSTEM TIME {}us since the Unix epoch is in the future",
+                    unix_micros
+                )
+            ));
+        }
+        // The two clocks are read separately, so the present may map a
+        // millisecond past `now_ms`.
+        let at_ms = unix_ms
+            .saturating_sub_unsigned(self.started_unix_ms)
+            .min(now_ms as i64);
+        let active = self.active.lock()?;
+        let cutoff_ms = now_ms.saturating_sub(self.history_retention_ms.load(Ordering::Relaxed));
+        if at_ms < cutoff_ms as i64 {
+            return Err(mudu_error!(
+                ErrorCode::InvalidArgument,
+                format!(
+                    "AS OF SYSTEM TIME {}us since the Unix epoch is older than the retained history",
+                    unix_micros
+                )
+            ));
+        }
+        // Each entry holds the first timestamp handed out in its millisecond,
+        // so the one before the first later entry is the latest at `at_ms`.
+        let ts = active
+            .handed_out
+            .iter()
+            .find(|(ms, _)| *ms as i64 > at_ms)
+            .map(|(_, first)| first - 1)
+            .unwrap_or_else(|| self.next_ts.load(Ordering::Relaxed));
+        Ok(ts)
+    }
+
+    pub fn unpin_history(&self, timestamp: u64) -> RS<()> {
+        let mut active = self.active.lock()?;
+        match active.history_pins.get_mut(&timestamp) {
+            Some(count) if *count > 1 => *count -= 1,
+            Some(_) => {
+                let _ = active.history_pins.remove(&timestamp);
+            }
+            None => {
+                return Err(mudu_error!(
+                    ErrorCode::EntityNotFound,
+                    format!("history timestamp {} is not pinned", timestamp)
+                ));
+            }
+        }
+        Ok(())
+    }
+
+    fn advance_gc_horizon(&self, active: &mut ActiveTxs, now_ms: u64) -> u64 {
+        let cutoff_ms = now_ms.saturating_sub(self.history_retention_ms.load(Ordering::Relaxed));
+        while active
+            .handed_out
+            .front()
+            .is_some_and(|(ms, _)| *ms < cutoff_ms)
+        {
+            let _ = active.handed_out.pop_front();
+        }
+        // The state as of the last timestamp before the window, i.e. as of
+        // the start of the window, stays readable too.
+        let retained = match active.handed_out.front() {
+            Some((_, ts)) => *ts,
+            None => self.next_ts.load(Ordering::Relaxed) + 1,
+        };
+        let horizon = active
+            .low_watermarks
+            .values()
+            .copied()
+            .chain(active.history_pins.keys().map(|timestamp| timestamp + 1))
+            .fold(retained, u64::min);
+        // Widening the retention window cannot bring pruned versions back.
+        active.gc_horizon = active.gc_horizon.max(horizon);
+        active.gc_horizon
+    }
+
+    fn record_handed_out(&self, active: &mut ActiveTxs, ts: u64, now_ms: u64) {
+        if self.history_retention_ms.load(Ordering::Relaxed) == 0 {
+            active.handed_out.clear();
+            return;
+        }
+        match active.handed_out.back_mut() {
+            Some((ms, first)) if *ms >= now_ms => *first = (*first).min(ts),
+            _ => active.handed_out.push_back((now_ms, ts)),
+        }
+    }
+
+    fn now_ms(&self) -> u64 {
+        let elapsed = (*instant_now()).saturating_duration_since(*self.started);
+        elapsed.as_millis().min(u64::MAX as u128) as u64
+    }
+
     pub fn end_tx(&self, xid: u64) -> RS<()> {
-        let mut running = self.running.lock()?;
-        match running.binary_search(&xid) {
+        let mut active = self.active.lock()?;
+        match active.running.binary_search(&xid) {
             Ok(index) => {
-                running.remove(index);
+                active.running.remove(index);
+                let _ = active.low_watermarks.remove(&xid);
                 Ok(())
             }
             Err(_) => Err(mudu_error!(
@@ -79,7 +306,10 @@ impl Default for WorkerSnapshotMgr {
     fn default() -> Self {
         Self {
             next_ts: AtomicU64::new(0),
-            running: SMutex::new(Vec::new()),
+            history_retention_ms: AtomicU64::new(0),
+            started: instant_now(),
+            started_unix_ms: kv_ttl::now_ms(),
+            active: SMutex::new(ActiveTxs::default()),
         }
     }
 }
@@ -97,3 +327,111 @@ fn insert_sorted_unique(values: &mut Vec<u64>, value: u64) {
         Err(index) => values.insert(index, value),
     }
 }
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+
+    use super::WorkerSnapshotMgr;
+    use std::time::Duration;
+
+    #[test]
+    fn gc_horizon_follows_oldest_snapshot() {
+        let mgr = WorkerSnapshotMgr::default();
+        let _ = mgr.alloc_committed_ts();
+        assert_eq!(mgr.gc_horizon().unwrap(), 2);
+
+        let first = mgr.begin_tx().unwrap();
+        let second = mgr.begin_tx().unwrap();
+        assert_eq!(mgr.gc_horizon().unwrap(), first.xid());
+
+        // `second` still treats `first` as running, so versions committed by
+        // `first` must not become the floor of a row while `second` is active.
+        mgr.end_tx(first.xid()).unwrap();
+        assert_eq!(mgr.gc_horizon().unwrap(), first.xid());
+
+        mgr.end_tx(second.xid()).unwrap();
+        assert_eq!(mgr.gc_horizon().unwrap(), second.xid() + 1);
+    }
+
+    #[test]
+    fn history_retention_bounds_as_of_reads() {
+        let mgr = WorkerSnapshotMgr::default();
+        mgr.set_history_retention(Duration::from_millis(100));
+        // Timestamps 1..=10 are handed out at 0, 10, .., 90 ms.
+        for ms in 0..10 {
+            let _ = mgr.alloc_committed_ts_at(ms * 10).unwrap();
+        }
+        assert_eq!(mgr.gc_horizon_at(90).unwrap(), 1);
+        // At 155 ms the window starts at 55 ms, whose state is the one as of
+        // timestamp 6; timestamps 6..=10 stay readable.
+        assert_eq!(mgr.gc_horizon_at(155).unwrap(), 7);
+        assert!(mgr.pin_history_at(5, 155).is_err());
+        assert!(mgr.pin_history_at(11, 155).is_err());
+
+        let snapshot = mgr.pin_history_at(6, 155).unwrap();
+        assert!(snapshot.is_visible(6));
+        assert!(!snapshot.is_visible(7));
+        // The pinned read keeps its versions from being pruned.
+        assert_eq!(mgr.gc_horizon_at(1_000).unwrap(), 7);
+        mgr.unpin_history(6).unwrap();
+        assert_eq!(mgr.gc_horizon_at(1_000).unwrap(), 11);
+        assert!(mgr.pin_history_at(6, 1_000).is_err());
+    }
+
+    #[test]
+    fn history_retention_keeps_first_timestamp_of_each_millisecond() {
+        let mgr = WorkerSnapshotMgr::default();
+        mgr.set_history_retention(Duration::from_millis(10));
+        for _ in 0..3 {
+            let _ = mgr.alloc_committed_ts_at(5).unwrap();
+        }
+        let _ = mgr.alloc_committed_ts_at(8).unwrap();
+        // At 15 ms every timestamp of 5 ms is still inside the window.
+        assert_eq!(mgr.gc_horizon_at(15).unwrap(), 1);
+        assert!(mgr.pin_history_at(1, 15).is_ok());
+        mgr.unpin_history(1).unwrap();
+        // At 16 ms only the state as of timestamp 3, the last one before 8 ms,
+        // is still needed.
+        assert_eq!(mgr.gc_horizon_at(16).unwrap(), 4);
+    }
+
+    #[test]
+    fn wall_clock_maps_to_latest_timestamp_handed_out_by_then() {
+        let mgr = WorkerSnapshotMgr::default();
+        mgr.set_history_retention(Duration::from_millis(100));
+        // Timestamps 1..=10 are handed out at 0, 10, .., 90 ms.
+        for ms in 0..10 {
+            let _ = mgr.alloc_committed_ts_at(ms * 10).unwrap();
+        }
+        let now = |ms: u64| mgr.started_unix_ms + ms;
+        let unix_micros = |ms: u64| (now(ms) * 1_000) as i64;
+        assert_eq!(
+            mgr.commit_ts_at_wall_clock_at(unix_micros(0), 90, now(90))
+                .unwrap(),
+            1
+        );
+        assert_eq!(
+            mgr.commit_ts_at_wall_clock_at(unix_micros(55) + 999, 90, now(90))
+                .unwrap(),
+            6
+        );
+        assert_eq!(
+            mgr.commit_ts_at_wall_clock_at(unix_micros(95), 95, now(95))
+                .unwrap(),
+            10
+        );
+        assert!(mgr
+            .commit_ts_at_wall_clock_at(unix_micros(96), 95, now(95))
+            .is_err());
+        // At 155 ms the window starts at 55 ms.
+        assert!(mgr
+            .commit_ts_at_wall_clock_at(unix_micros(54), 155, now(155))
+            .is_err());
+        let ts = mgr
+            .commit_ts_at_wall_clock_at(unix_micros(55), 155, now(155))
+            .unwrap();
+        assert_eq!(ts, 6);
+        assert!(mgr.pin_history_at(ts, 155).is_ok());
+    }
+}
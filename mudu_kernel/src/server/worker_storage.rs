@@ -1,6 +1,8 @@
 use mudu_sys::sync::SMutex;
-use std::collections::{BTreeMap, Bound};
+use std::collections::{BTreeMap, BTreeSet, Bound};
 use std::ops::Bound::{Excluded, Included, Unbounded};
+use std::ops::RangeBounds;
+use std::path::Path;
 use std::sync::{Arc, OnceLock, Weak};
 
 use mudu::common::id::OID;
@@ -19,10 +21,17 @@ use crate::contract::table_desc::TableDesc;
 use crate::contract::timestamp::Timestamp;
 use crate::contract::version_tuple::VersionTuple;
 use crate::index::index_key::key_tuple::KeyTuple;
+use crate::server::kv_ttl::{self, CommitClock};
 use crate::server::partition_router::DEFAULT_UNPARTITIONED_TABLE_PARTITION_ID;
+use crate::server::version_gc::VersionGcStats;
+use crate::server::worker_checkpoint::CheckpointKvRow;
 use crate::server::worker_snapshot::{KvItem, WorkerSnapshot};
 #[cfg(test)]
 use crate::server::worker_tx_manager::WorkerTxManager;
+use crate::storage::backup::BackupFile;
+use crate::storage::buffer_pool::{BufferPool, BufferPoolStats};
+use crate::storage::compression::{Compression, CompressionStats, CompressionStatsSnapshot};
+use crate::storage::encryption::{Keyring, ReencryptStats};
 use crate::storage::relation::relation::Relation;
 use crate::wal::xl_batch::XLBatch;
 use crate::wal::xl_data_op::{XLDelete, XLInsert, XLWrite};
@@ -30,6 +39,9 @@ use crate::wal::xl_entry::TxOp;
 use crate::x_engine::tx_mgr::{PhysicalRelationId, TxMgr};
 use tracing::{info, trace};
 
+/// Number of kv keys a range scan takes from the key index at a time.
+const KV_RANGE_BATCH: usize = 256;
+
 type WorkerStorageRegistry = std::collections::HashMap<String, Vec<Weak<WorkerStorage>>>;
 
 fn storage_registry() -> &'static SMutex<WorkerStorageRegistry> {
@@ -42,6 +54,8 @@ pub(crate) struct PreparedWorkerCommit {
     xid: u64,
     relation_rows: BTreeMap<PhysicalRelationId, BTreeMap<Vec<u8>, Option<Vec<u8>>>>,
     kv_rows: BTreeMap<Vec<u8>, Option<Vec<u8>>>,
+    // Deadlines of the kv rows written with a TTL.
+    kv_expires_at: BTreeMap<Vec<u8>, u64>,
     batch: XLBatch,
 }
 
@@ -51,8 +65,38 @@ pub struct WorkerStorage {
     relation_path: String,
     async_runtime: Option<Arc<dyn AsyncIoProvider>>,
     relation_store: SccHashMap<PhysicalRelationId, Arc<Relation>>,
+    // Caches the relation file pages of this worker.
+    buffer_pool: Arc<BufferPool>,
+    // Seals the relation files of this worker; `None` stores them in
+    // plaintext.
+    keyring: Option<Arc<Keyring>>,
+    // Codec of relations whose table does not choose one.
+    page_compression: SMutex<Compression>,
+    page_compression_stats: Arc<CompressionStats>,
     kv_store: SccHashMap<Vec<u8>, DataRow>,
+    // The keys of `kv_store` in order, so range reads and the version GC and
+    // TTL sweeps walk keys without scanning and sorting the whole map.
+    kv_keys: SMutex<BTreeSet<Vec<u8>>>,
+    // Stamps commits with the time kv TTL deadlines count from.
+    commit_clock: CommitClock,
     applied_cross_tx: SccHashMap<OID, ()>,
+    // Where the next version GC step resumes; `None` starts a new sweep.
+    gc_cursor: SMutex<Option<VersionGcCursor>>,
+    // Last kv key the TTL sweeper visited; `None` starts a new sweep.
+    ttl_cursor: SMutex<Option<Vec<u8>>>,
+    // Relation the next re-encryption step resumes with; `None` starts over.
+    reencrypt_cursor: SMutex<Option<PhysicalRelationId>>,
+}
+
+/// Position of the version GC sweep: relations in id order, then kv rows.
+enum VersionGcCursor {
+    Relation {
+        id: PhysicalRelationId,
+        after: Option<Vec<u8>>,
+    },
+    Kv {
+        after: Option<Vec<u8>>,
+    },
 }
 
 impl WorkerStorage {
@@ -65,7 +109,7 @@ impl WorkerStorage {
 
     #[cfg(test)]
     pub fn new(mgr: Arc<dyn MetaMgr>, partition_id: OID, relation_path: String) -> Self {
-        Self::new_with_async_runtime(mgr, partition_id, relation_path, None)
+        Self::new_with_async_runtime(mgr, partition_id, relation_path, None, None)
     }
 
     pub fn new_with_async_runtime(
@@ -73,6 +117,7 @@ impl WorkerStorage {
         partition_id: OID,
         relation_path: String,
         async_runtime: Option<Arc<dyn AsyncIoProvider>>,
+        keyring: Option<Arc<Keyring>>,
     ) -> Self {
         Self {
             mgr,
@@ -80,11 +125,39 @@ impl WorkerStorage {
             relation_path,
             async_runtime,
             relation_store: SccHashMap::new(),
+            buffer_pool: Arc::new(BufferPool::default()),
+            keyring,
+            page_compression: SMutex::new(Compression::None),
+            page_compression_stats: Arc::new(CompressionStats::default()),
             kv_store: SccHashMap::new(),
+            kv_keys: SMutex::new(BTreeSet::new()),
+            commit_clock: CommitClock::default(),
             applied_cross_tx: SccHashMap::new(),
+            gc_cursor: SMutex::new(None),
+            ttl_cursor: SMutex::new(None),
+            reencrypt_cursor: SMutex::new(None),
         }
     }
 
+    pub fn set_buffer_pool_bytes(&self, capacity_bytes: u64) {
+        self.buffer_pool.set_capacity_bytes(capacity_bytes);
+    }
+
+    pub fn buffer_pool_stats(&self) -> RS<BufferPoolStats> {
+        self.buffer_pool.stats()
+    }
+
+    /// Sets the page codec of relations opened from now on whose table has no
+    /// `compression` option.
+    pub fn set_page_compression(&self, compression: Compression) -> RS<()> {
+        *self.page_compression.lock()? = compression;
+        Ok(())
+    }
+
+    pub fn page_compression_stats(&self) -> CompressionStatsSnapshot {
+        self.page_compression_stats.snapshot()
+    }
+
     fn physical_partition_id(&self, partition_id: Option<OID>) -> OID {
         partition_id.unwrap_or(self.default_partition_id)
     }
@@ -168,6 +241,7 @@ impl WorkerStorage {
             return Ok(staged);
         }
         trace.watch("storage.get.stage", "tx_lookup_miss");
+        txm.record_read(relation_id, key);
         let key = KeyTuple::from(key.to_vec());
         trace.watch("storage.get.stage", "visible_read");
         self.read_visible_relation_value(oid, partition_id, &key, &txm.snapshot())
@@ -187,6 +261,7 @@ impl WorkerStorage {
         value: Vec<u8>,
         txm: &dyn TxMgr,
     ) -> RS<()> {
+        ensure_tx_writable(txm)?;
         let key_tuple = KeyTuple::from(key.clone());
         let relation_id = self.relation_id(oid, self.physical_partition_id(partition_id));
 
@@ -208,6 +283,7 @@ impl WorkerStorage {
         key: &[u8],
         txm: &dyn TxMgr,
     ) -> RS<Option<Vec<u8>>> {
+        ensure_tx_writable(txm)?;
         let key_tuple = KeyTuple::from(key.to_vec());
         let relation_id = self.relation_id(oid, self.physical_partition_id(partition_id));
         self.ensure_no_relation_write_conflict(oid, partition_id, &key_tuple, &txm.snapshot())
@@ -215,6 +291,7 @@ impl WorkerStorage {
         let current = match txm.get_relation(relation_id, key) {
             Some(staged) => staged,
             None => {
+                txm.record_read(relation_id, key);
                 self.read_visible_relation_value(oid, partition_id, &key_tuple, &txm.snapshot())
                     .await?
             }
@@ -225,15 +302,6 @@ impl WorkerStorage {
         Ok(current)
     }
 
-    pub async fn range(
-        &self,
-        oid: OID,
-        bounds: (Bound<&[u8]>, Bound<&[u8]>),
-        txm: &dyn TxMgr,
-    ) -> RS<Vec<(Vec<u8>, Vec<u8>)>> {
-        self.range_on_partition(oid, None, bounds, txm).await
-    }
-
     pub async fn range_on_partition(
         &self,
         oid: OID,
@@ -246,6 +314,7 @@ impl WorkerStorage {
             .await?;
         let (start_key, end_key) = bounds_to_scan(&bounds);
         let relation_id = self.relation_id(oid, self.physical_partition_id(partition_id));
+        txm.record_range_read(relation_id, bounds.0, bounds.1);
         let staged_items = txm.staged_relation_items_in_range(relation_id, &start_key, &end_key);
 
         let mut merged = BTreeMap::new();
@@ -262,6 +331,32 @@ impl WorkerStorage {
             .collect())
     }
 
+    /// Reads `key` as of a history snapshot. Staged writes of the current
+    /// transaction are not visible and the read is not recorded for SSI.
+    pub async fn get_as_of_on_partition(
+        &self,
+        oid: OID,
+        partition_id: Option<OID>,
+        key: &[u8],
+        snapshot: &WorkerSnapshot,
+    ) -> RS<Option<Vec<u8>>> {
+        let key = KeyTuple::from(key.to_vec());
+        self.read_visible_relation_value(oid, partition_id, &key, snapshot)
+            .await
+    }
+
+    /// Range counterpart of [`Self::get_as_of_on_partition`].
+    pub async fn range_as_of_on_partition(
+        &self,
+        oid: OID,
+        partition_id: Option<OID>,
+        bounds: (Bound<&[u8]>, Bound<&[u8]>),
+        snapshot: &WorkerSnapshot,
+    ) -> RS<Vec<(Vec<u8>, Vec<u8>)>> {
+        self.range_visible_relation(oid, partition_id, bounds, snapshot)
+            .await
+    }
+
     pub async fn kv_get(
         &self,
         key: &[u8],
@@ -281,48 +376,389 @@ impl WorkerStorage {
                 None => None,
             },
         };
+        let now_ms = kv_ttl::now_ms();
         Ok(version
-            .filter(|version| !version.is_deleted())
+            .filter(|version| !version.is_deleted() && !version.is_expired(now_ms))
             .map(|version| version.tuple().clone()))
     }
 
+    /// Reads the visible kv rows of `[start_key, end_key)` in key order, or
+    /// in reverse key order when `reverse` is set. An empty end key is
+    /// unbounded. The scan stops once `limit` visible rows were read.
     pub async fn kv_range(
         &self,
         start_key: &[u8],
         end_key: &[u8],
         snapshot: Option<&WorkerSnapshot>,
+        reverse: bool,
+        limit: Option<usize>,
     ) -> RS<Vec<KvItem>> {
-        let mut rows = Vec::new();
-        self.kv_store.iter_sync(|key, row| {
-            let in_range = if end_key.is_empty() {
-                key.as_slice() >= start_key
-            } else {
-                key.as_slice() >= start_key && key.as_slice() < end_key
-            };
-            if in_range {
-                rows.push((key.clone(), row.clone()));
+        if !end_key.is_empty() && start_key >= end_key {
+            return Ok(Vec::new());
+        }
+        let mut lower = Included(start_key.to_vec());
+        let mut upper = if end_key.is_empty() {
+            Unbounded
+        } else {
+            Excluded(end_key.to_vec())
+        };
+        let batch = limit.unwrap_or(KV_RANGE_BATCH).clamp(1, KV_RANGE_BATCH);
+        let now_ms = kv_ttl::now_ms();
+        let mut items = Vec::new();
+        loop {
+            // The key index cannot stay locked across the row reads, so the
+            // keys are taken a batch at a time.
+            let keys = self.kv_keys_batch(&lower, &upper, reverse, batch)?;
+            let exhausted = keys.len() < batch;
+            if let Some(last) = keys.last() {
+                if reverse {
+                    upper = Excluded(last.clone());
+                } else {
+                    lower = Excluded(last.clone());
+                }
+            }
+            for key in keys {
+                let Some(row) = self
+                    .kv_store
+                    .get_sync(&key)
+                    .map(|entry| entry.get().clone())
+                else {
+                    continue;
+                };
+                let visible = match snapshot {
+                    Some(snapshot) => {
+                        let snapshot = snapshot.to_snapshot();
+                        row.read(&snapshot).await?
+                    }
+                    None => row.read_latest().await?,
+                };
+                let value = visible
+                    .filter(|version| !version.is_deleted() && !version.is_expired(now_ms))
+                    .map(|version| version.tuple().clone());
+                if let Some(value) = value {
+                    items.push(KvItem { key, value });
+                    if limit.is_some_and(|limit| items.len() >= limit) {
+                        return Ok(items);
+                    }
+                }
+            }
+            if exhausted {
+                return Ok(items);
+            }
+        }
+    }
+
+    /// Returns at most `max_keys` kv keys within `(lower, upper)`, the
+    /// smallest ones first or, when `reverse` is set, the largest ones first.
+    fn kv_keys_batch(
+        &self,
+        lower: &Bound<Vec<u8>>,
+        upper: &Bound<Vec<u8>>,
+        reverse: bool,
+        max_keys: usize,
+    ) -> RS<Vec<Vec<u8>>> {
+        let bounds = (
+            lower.as_ref().map(Vec::as_slice),
+            upper.as_ref().map(Vec::as_slice),
+        );
+        let keys = self.kv_keys.lock()?;
+        let range = keys.range::<[u8], _>(bounds);
+        Ok(if reverse {
+            range.rev().take(max_keys).cloned().collect()
+        } else {
+            range.take(max_keys).cloned().collect()
+        })
+    }
+
+    /// Data directory the relation files live under.
+    pub(crate) fn relation_path(&self) -> &str {
+        &self.relation_path
+    }
+
+    /// Partitions of the relations opened by this storage.
+    pub(crate) fn relation_partition_ids(&self) -> BTreeSet<OID> {
+        let mut partition_ids = BTreeSet::new();
+        self.relation_store.iter_sync(|id, _| {
+            partition_ids.insert(id.partition_id);
+            true
+        });
+        partition_ids
+    }
+
+    /// Runs one version GC step over at most `max_rows` rows.
+    ///
+    /// Only relations of `partitions` are swept: the relation files of other
+    /// partitions are written by the workers that own them. Versions below
+    /// `horizon` are pruned down to the newest one; steps resume where the
+    /// previous one stopped, so a sweep spreads over many short steps.
+    pub(crate) async fn collect_garbage_step_async(
+        &self,
+        horizon: u64,
+        partitions: &BTreeSet<OID>,
+        max_rows: usize,
+    ) -> RS<VersionGcStats> {
+        let mut stats = VersionGcStats::default();
+        let cursor = match self.gc_cursor.lock()?.take() {
+            Some(cursor) => cursor,
+            None => self.next_gc_relation(Unbounded, partitions),
+        };
+        let next = match cursor {
+            VersionGcCursor::Relation { id, after } => {
+                let relation = self
+                    .relation_store
+                    .get_async(&id)
+                    .await
+                    .map(|relation| relation.get().clone());
+                let resume = match relation {
+                    Some(relation) => {
+                        relation
+                            .collect_garbage(horizon, after.as_deref(), max_rows, &mut stats)
+                            .await?
+                    }
+                    None => None,
+                };
+                Some(match resume {
+                    Some(after) => VersionGcCursor::Relation {
+                        id,
+                        after: Some(after),
+                    },
+                    None => self.next_gc_relation(Excluded(&id), partitions),
+                })
+            }
+            VersionGcCursor::Kv { after } => self
+                .collect_kv_garbage(horizon, after, max_rows, &mut stats)?
+                .map(|after| VersionGcCursor::Kv { after: Some(after) }),
+        };
+        stats.sweep_done = next.is_none();
+        *self.gc_cursor.lock()? = next;
+        Ok(stats)
+    }
+
+    fn next_gc_relation(
+        &self,
+        lower: Bound<&PhysicalRelationId>,
+        partitions: &BTreeSet<OID>,
+    ) -> VersionGcCursor {
+        match self.next_relation(lower, partitions) {
+            Some(id) => VersionGcCursor::Relation { id, after: None },
+            None => VersionGcCursor::Kv { after: None },
+        }
+    }
+
+    /// The relation of `partitions` with the smallest id within `lower`.
+    fn next_relation(
+        &self,
+        lower: Bound<&PhysicalRelationId>,
+        partitions: &BTreeSet<OID>,
+    ) -> Option<PhysicalRelationId> {
+        let mut next: Option<PhysicalRelationId> = None;
+        self.relation_store.iter_sync(|id, _| {
+            if partitions.contains(&id.partition_id)
+                && (lower, Unbounded).contains(id)
+                && next.is_none_or(|next| *id < next)
+            {
+                next = Some(*id);
             }
             true
         });
+        next
+    }
 
-        let mut items = Vec::new();
-        for (key, row) in rows {
-            let visible = match snapshot {
-                Some(snapshot) => {
-                    let snapshot = snapshot.to_snapshot();
-                    row.read(&snapshot).await?
+    /// Runs one re-encryption step over at most `max_pages` relation pages.
+    ///
+    /// Like version GC, only relations of `partitions` are visited, in id
+    /// order, and a step resumes where the previous one stopped. `done` is set
+    /// once every such relation is sealed with the active key.
+    pub(crate) async fn reencrypt_step_async(
+        &self,
+        partitions: &BTreeSet<OID>,
+        max_pages: u64,
+    ) -> RS<ReencryptStats> {
+        let mut stats = ReencryptStats::default();
+        let mut lower = self.reencrypt_cursor.lock()?.take();
+        while stats.scanned_pages < max_pages {
+            let bound = match lower.as_ref() {
+                Some(id) => Included(id),
+                None => Unbounded,
+            };
+            let Some(id) = self.next_relation(bound, partitions) else {
+                stats.done = true;
+                return Ok(stats);
+            };
+            let relation = self
+                .relation_store
+                .get_async(&id)
+                .await
+                .map(|relation| relation.get().clone());
+            if let Some(relation) = relation {
+                let step = relation
+                    .reencrypt_pages(max_pages - stats.scanned_pages)
+                    .await?;
+                stats.add(step);
+                if !step.done {
+                    *self.reencrypt_cursor.lock()? = Some(id);
+                    return Ok(stats);
                 }
-                None => row.read_latest().await?,
+            }
+            // Relations after `id`: the smallest id above it.
+            lower = self.next_relation(Excluded(&id), partitions);
+            if lower.is_none() {
+                stats.done = true;
+                return Ok(stats);
+            }
+        }
+        *self.reencrypt_cursor.lock()? = lower;
+        Ok(stats)
+    }
+
+    fn collect_kv_garbage(
+        &self,
+        horizon: u64,
+        after: Option<Vec<u8>>,
+        max_rows: usize,
+        stats: &mut VersionGcStats,
+    ) -> RS<Option<Vec<u8>>> {
+        let (keys, has_more) = self.kv_keys_step(after.as_deref(), max_rows)?;
+        for key in &keys {
+            let Some(row) = self.kv_store.get_sync(key).map(|entry| entry.get().clone()) else {
+                continue;
             };
-            if let Some(visible) = visible.filter(|version| !version.is_deleted()) {
-                items.push(KvItem {
-                    key,
-                    value: visible.tuple().clone(),
+            let pruned = row.collect_garbage_sync(horizon)?;
+            stats.pruned_versions += pruned.len();
+            if !pruned.is_empty() && self.remove_empty_kv_row(key)? {
+                stats.removed_rows += 1;
+            }
+        }
+        Ok(if has_more { keys.last().cloned() } else { None })
+    }
+
+    /// Returns at most `max_rows` kv keys ordered after `after`, and whether
+    /// more keys follow them. Version GC and the TTL sweeper both step
+    /// through the kv store with it.
+    fn kv_keys_step(&self, after: Option<&[u8]>, max_rows: usize) -> RS<(Vec<Vec<u8>>, bool)> {
+        let lower = match after {
+            Some(after) => Excluded(after),
+            None => Unbounded,
+        };
+        let keys = self.kv_keys.lock()?;
+        let mut iter = keys.range::<[u8], _>((lower, Unbounded));
+        let step: Vec<Vec<u8>> = iter.by_ref().take(max_rows).cloned().collect();
+        let has_more = iter.next().is_some();
+        Ok((step, has_more))
+    }
+
+    /// Removes the kv row of `key` if no version is left in it.
+    ///
+    /// A writer may have added a version since the row was pruned, so the
+    /// emptiness check runs again under the map entry lock. The key index
+    /// stays locked meanwhile: a concurrent write re-indexes the key only
+    /// after it was dropped here.
+    fn remove_empty_kv_row(&self, key: &[u8]) -> RS<bool> {
+        let mut keys = self.kv_keys.lock()?;
+        let removed = self
+            .kv_store
+            .remove_if_sync(key, |row| row.is_empty_sync().unwrap_or(false))
+            .is_some();
+        if removed {
+            keys.remove(key);
+        }
+        Ok(removed)
+    }
+
+    fn insert_kv_row(&self, key: Vec<u8>, row: DataRow) -> RS<()> {
+        // The map entry goes first; see `remove_empty_kv_row`.
+        let _ = self.kv_store.insert_sync(key.clone(), row);
+        self.kv_keys.lock()?.insert(key);
+        Ok(())
+    }
+
+    /// Returns expired kv keys among at most `max_rows` rows, resuming where
+    /// the previous step stopped; the caller deletes them.
+    pub(crate) fn expired_kv_keys_step(&self, now_ms: u64, max_rows: usize) -> RS<Vec<Vec<u8>>> {
+        let after = self.ttl_cursor.lock()?.take();
+        let (mut keys, has_more) = self.kv_keys_step(after.as_deref(), max_rows)?;
+        if has_more {
+            *self.ttl_cursor.lock()? = keys.last().cloned();
+        }
+        keys.retain(|key| self.kv_expired(key, now_ms));
+        Ok(keys)
+    }
+
+    /// Whether the latest committed version of `key` is a value whose TTL
+    /// passed at `now_ms`.
+    pub(crate) fn kv_expired(&self, key: &[u8], now_ms: u64) -> bool {
+        self.kv_store
+            .get_sync(key)
+            .and_then(|entry| latest_version(entry.get()))
+            .is_some_and(|version| !version.is_deleted() && version.is_expired(now_ms))
+    }
+
+    /// Returns the latest version of every live kv row.
+    pub(crate) fn checkpoint_kv_rows(&self) -> Vec<CheckpointKvRow> {
+        let mut rows = Vec::new();
+        self.kv_store.iter_sync(|key, row| {
+            if let Some(version) = latest_version(row).filter(|version| !version.is_deleted()) {
+                rows.push(CheckpointKvRow {
+                    key: key.clone(),
+                    xid: version.timestamp().c_min(),
+                    value: version.tuple().clone(),
+                    expires_at: version.expires_at(),
                 });
             }
+            true
+        });
+        rows
+    }
+
+    /// Loads the kv rows of a checkpoint into the empty kv store.
+    pub(crate) fn restore_kv_rows(&self, rows: Vec<CheckpointKvRow>) -> RS<()> {
+        for row in rows {
+            self.write_kv_version(row.key, Some(row.value), row.xid, row.expires_at)?;
+        }
+        Ok(())
+    }
+
+    /// Makes the relation files durable for a checkpoint.
+    ///
+    /// Every relation is synced because the storages of all workers share
+    /// the files. The disk index is written only for `partitions`, whose
+    /// relations this worker writes, so the index matches the files.
+    pub(crate) async fn checkpoint_relations_async(&self, partitions: &BTreeSet<OID>) -> RS<()> {
+        let mut relations = Vec::new();
+        self.relation_store.iter_sync(|id, relation| {
+            relations.push((*id, relation.clone()));
+            true
+        });
+        for (id, relation) in relations {
+            if partitions.contains(&id.partition_id) {
+                relation.write_disk_index().await?;
+            } else {
+                relation.sync_files().await?;
+            }
+        }
+        Ok(())
+    }
+
+    /// Copies the relations of `partitions` into `backup_dir`, at their
+    /// paths relative to the data directory.
+    pub(crate) async fn backup_relations_async(
+        &self,
+        partitions: &BTreeSet<OID>,
+        backup_dir: &Path,
+    ) -> RS<Vec<BackupFile>> {
+        let mut relations = Vec::new();
+        self.relation_store.iter_sync(|id, relation| {
+            if partitions.contains(&id.partition_id) {
+                relations.push(relation.clone());
+            }
+            true
+        });
+        let root = Path::new(&self.relation_path);
+        let mut files = Vec::new();
+        for relation in relations {
+            files.extend(relation.backup_files(root, backup_dir).await?);
         }
-        items.sort_by(|left, right| left.key.cmp(&right.key));
-        Ok(items)
+        Ok(files)
     }
 
     #[cfg(test)]
@@ -366,8 +802,10 @@ impl WorkerStorage {
             xid,
             relation_rows: BTreeMap::new(),
             kv_rows: BTreeMap::from([(key, value)]),
+            kv_expires_at: BTreeMap::new(),
             batch,
         }
+        .with_commit_time(self.commit_clock.stamp())
     }
 
     pub(crate) async fn apply_prepared_commit_async(
@@ -412,17 +850,30 @@ impl WorkerStorage {
 
     pub(crate) async fn replay_batch(&self, batch: XLBatch) -> RS<()> {
         for entry in batch.entries {
+            self.commit_clock.observe(entry.commit_ms);
             for op in entry.ops {
                 match op {
                     TxOp::Write(XLWrite::Insert(insert))
                         if insert.table_id == 0 && insert.partition_id == 0 =>
                     {
-                        self.worker_put_local(insert.key, insert.value, entry.xid)?;
+                        if !self.kv_replay_applied(&insert.key, entry.xid) {
+                            let expires_at = insert
+                                .ttl_ms
+                                .map(|ttl_ms| kv_ttl::deadline(entry.commit_ms, ttl_ms));
+                            self.write_kv_version(
+                                insert.key,
+                                Some(insert.value),
+                                entry.xid,
+                                expires_at,
+                            )?;
+                        }
                     }
                     TxOp::Write(XLWrite::Delete(delete))
                         if delete.table_id == 0 && delete.partition_id == 0 =>
                     {
-                        self.worker_delete_local(delete.key, entry.xid)?;
+                        if !self.kv_replay_applied(&delete.key, entry.xid) {
+                            self.worker_delete_local(delete.key, entry.xid)?;
+                        }
                     }
                     TxOp::Write(XLWrite::Insert(insert)) => {
                         self.apply_relation_replay_insert_async(insert, entry.xid)
@@ -439,12 +890,57 @@ impl WorkerStorage {
         Ok(())
     }
 
+    /// Whether the kv row already holds a write of `xid` or a later one.
+    ///
+    /// Replay starts at the oldest commit a fuzzy checkpoint may have missed,
+    /// so it can meet writes the restored kv rows already contain.
+    fn kv_replay_applied(&self, key: &[u8], xid: u64) -> bool {
+        self.kv_store
+            .get_sync(key)
+            .and_then(|entry| latest_version(entry.get()))
+            .is_some_and(|version| version.timestamp().c_min() >= xid)
+    }
+
+    #[cfg(test)]
     pub(crate) fn worker_put_local(&self, key: Vec<u8>, value: Vec<u8>, xid: u64) -> RS<()> {
-        write_version_to_kv_store(&self.kv_store, key, Some(value), xid)
+        self.write_kv_version(key, Some(value), xid, None)
     }
 
     pub(crate) fn worker_delete_local(&self, key: Vec<u8>, xid: u64) -> RS<()> {
-        write_version_to_kv_store(&self.kv_store, key, None, xid)
+        self.write_kv_version(key, None, xid, None)
+    }
+
+    fn write_kv_version(
+        &self,
+        key: Vec<u8>,
+        value: Option<Vec<u8>>,
+        xid: u64,
+        expires_at: Option<u64>,
+    ) -> RS<()> {
+        let row = self.kv_row_or_new(&key);
+        row.write_sync(new_kv_version(xid, value, expires_at), None)?;
+        self.insert_kv_row(key, row)
+    }
+
+    async fn write_kv_version_async(
+        &self,
+        key: Vec<u8>,
+        value: Option<Vec<u8>>,
+        xid: u64,
+        expires_at: Option<u64>,
+    ) -> RS<()> {
+        scoped_task_trace!();
+        let row = self.kv_row_or_new(&key);
+        row.write(new_kv_version(xid, value, expires_at), None)
+            .await?;
+        self.insert_kv_row(key, row)
+    }
+
+    fn kv_row_or_new(&self, key: &[u8]) -> DataRow {
+        self.kv_store
+            .get_sync(key)
+            .map(|entry| entry.get().clone())
+            .unwrap_or_else(|| DataRow::new(0))
     }
 
     async fn prepare_commit_parts_async(
@@ -463,8 +959,10 @@ impl WorkerStorage {
             xid,
             relation_rows,
             kv_rows,
+            kv_expires_at: BTreeMap::new(),
             batch,
-        })
+        }
+        .with_commit_time(self.commit_clock.stamp()))
     }
 
     async fn ensure_no_relation_conflicts_async(
@@ -534,13 +1032,9 @@ impl WorkerStorage {
     async fn apply_kv_rows_async(&self, prepared: &PreparedWorkerCommit) -> RS<()> {
         scoped_task_trace!();
         for (key, value) in &prepared.kv_rows {
-            write_version_to_kv_store_async(
-                &self.kv_store,
-                key.clone(),
-                value.clone(),
-                prepared.xid,
-            )
-            .await?;
+            let expires_at = prepared.kv_expires_at.get(key).copied();
+            self.write_kv_version_async(key.clone(), value.clone(), prepared.xid, expires_at)
+                .await?;
         }
         Ok(())
     }
@@ -636,13 +1130,29 @@ impl WorkerStorage {
                     partition_id,
                     self.relation_path.clone(),
                     table_desc,
+                    self.keyring.clone(),
                 )
                 .await?,
             ),
             None => Arc::new(
-                Relation::new(oid, partition_id, self.relation_path.clone(), table_desc).await?,
+                Relation::new(
+                    oid,
+                    partition_id,
+                    self.relation_path.clone(),
+                    table_desc,
+                    self.keyring.clone(),
+                )
+                .await?,
             ),
         };
+        relation.set_buffer_pool(self.buffer_pool.clone()).await?;
+        let compression = match table_desc.compression() {
+            Some(compression) => compression,
+            None => *self.page_compression.lock()?,
+        };
+        relation
+            .set_compression(compression, self.page_compression_stats.clone())
+            .await?;
 
         let _ = self
             .relation_store
@@ -799,55 +1309,50 @@ impl PreparedWorkerCommit {
     pub(crate) fn batch(&self) -> &XLBatch {
         &self.batch
     }
-}
-
-fn new_value_version(xid: u64, value: Vec<u8>) -> VersionTuple {
-    VersionTuple::new(Timestamp::new(xid, u64::MAX), value)
-}
 
-fn write_version_to_kv_store(
-    kv_store: &SccHashMap<Vec<u8>, DataRow>,
-    key: Vec<u8>,
-    value: Option<Vec<u8>>,
-    xid: u64,
-) -> RS<()> {
-    let row = kv_store
-        .get_sync(&key)
-        .map(|entry| entry.get().clone())
-        .unwrap_or_else(|| DataRow::new(0));
-    let version = match value {
-        Some(value) => new_value_version(xid, value),
-        None => VersionTuple::new_delete(Timestamp::new(xid, u64::MAX)),
-    };
-    row.write_sync(version, None)?;
-    let _ = kv_store.insert_sync(key, row);
-    Ok(())
+    /// Stamps the batch to log with the commit time `commit_ms` and derives
+    /// from it the deadlines of the kv rows written with a TTL.
+    fn with_commit_time(mut self, commit_ms: u64) -> Self {
+        for entry in &mut self.batch.entries {
+            entry.commit_ms = commit_ms;
+            for op in &entry.ops {
+                if let TxOp::Write(XLWrite::Insert(insert)) = op {
+                    if insert.table_id == 0 && insert.partition_id == 0 {
+                        if let Some(ttl_ms) = insert.ttl_ms {
+                            self.kv_expires_at
+                                .insert(insert.key.clone(), kv_ttl::deadline(commit_ms, ttl_ms));
+                        }
+                    }
+                }
+            }
+        }
+        self
+    }
 }
 
-async fn write_version_to_kv_store_async(
-    kv_store: &SccHashMap<Vec<u8>, DataRow>,
-    key: Vec<u8>,
-    value: Option<Vec<u8>>,
-    xid: u64,
-) -> RS<()> {
-    scoped_task_trace!();
-    let row = kv_store
-        .get_sync(&key)
-        .map(|entry| entry.get().clone())
-        .unwrap_or_else(|| DataRow::new(0));
-    let version = match value {
-        Some(value) => new_value_version(xid, value),
+fn new_kv_version(xid: u64, value: Option<Vec<u8>>, expires_at: Option<u64>) -> VersionTuple {
+    match value {
+        Some(value) => {
+            VersionTuple::new(Timestamp::new(xid, u64::MAX), value).with_expires_at(expires_at)
+        }
         None => VersionTuple::new_delete(Timestamp::new(xid, u64::MAX)),
-    };
-    row.write(version, None).await?;
-    let _ = kv_store.insert_sync(key, row);
-    Ok(())
+    }
 }
 
 fn latest_version(row: &DataRow) -> Option<VersionTuple> {
     row.read_latest_sync().ok().flatten()
 }
 
+fn ensure_tx_writable(txm: &dyn TxMgr) -> RS<()> {
+    if txm.options().read_only {
+        return Err(mudu_error!(
+            ErrorCode::Transaction,
+            format!("cannot write in read-only transaction {}", txm.xid())
+        ));
+    }
+    Ok(())
+}
+
 fn bounds_to_scan(bounds: &(Bound<&[u8]>, Bound<&[u8]>)) -> (Vec<u8>, Vec<u8>) {
     let start = match bounds.0 {
         Included(key) | Excluded(key) => key.to_vec(),
@@ -874,6 +1379,7 @@ mod tests {
 
     use crate::contract::schema_column::SchemaColumn;
     use crate::server::test_meta_mgr::TestMetaMgr;
+    use crate::storage::time_series::time_series_file::TimeSeriesFile;
     use mudu::common::id::OID;
     use mudu_sys::common::provider_type::ProviderType;
     use mudu_sys::provider::create_io_provider;
@@ -1016,6 +1522,7 @@ mod tests {
                 .to_string_lossy()
                 .to_string(),
             Some(create_io_provider(ProviderType::Tokio)),
+            None,
         );
         storage.bootstrap_existing_tables_async().await?;
 
@@ -1106,8 +1613,9 @@ mod tests {
         storage.commit_tx(&mut new_tx).await?;
 
         let rows = storage
-            .range(
+            .range_on_partition(
                 oid,
+                None,
                 (
                     Included(i32_bytes(1).as_slice()),
                     Included(i32_bytes(9).as_slice()),
@@ -1218,7 +1726,9 @@ mod tests {
         let snapshot = WorkerSnapshot::new(2, vec![]);
         storage.worker_put_local(b"b".to_vec(), b"2".to_vec(), 3)?;
 
-        let rows = storage.kv_range(b"a", b"z", Some(&snapshot)).await?;
+        let rows = storage
+            .kv_range(b"a", b"z", Some(&snapshot), false, None)
+            .await?;
         assert_eq!(
             rows,
             vec![KvItem {
@@ -1287,6 +1797,7 @@ mod tests {
                     tuple_id: 0,
                     key: b"k".to_vec(),
                     value: b"v".to_vec(),
+                    ttl_ms: None,
                 })),
                 TxOp::Write(XLWrite::Insert(XLInsert {
                     table_id: oid,
@@ -1294,9 +1805,11 @@ mod tests {
                     tuple_id: 0,
                     key: i32_bytes(7),
                     value: i32_bytes(70),
+                    ttl_ms: None,
                 })),
                 TxOp::Commit,
             ],
+            commit_ms: 0,
         }]);
 
         storage.replay_batch(batch).await?;
@@ -1334,6 +1847,7 @@ mod tests {
                 })),
                 TxOp::Commit,
             ],
+            commit_ms: 0,
         }]);
 
         storage.replay_batch(batch).await?;
@@ -1358,6 +1872,7 @@ mod tests {
             tuple_id: 0,
             key: i32_bytes(9),
             value: i32_bytes(90),
+            ttl_ms: None,
         });
 
         storage
@@ -1410,9 +1925,11 @@ mod tests {
                     tuple_id: 0,
                     key: i32_bytes(5),
                     value: i32_bytes(50),
+                    ttl_ms: None,
                 })),
                 TxOp::Commit,
             ],
+            commit_ms: 0,
         }]);
 
         storage.replay_batch(batch).await?;
@@ -1426,4 +1943,139 @@ mod tests {
         );
         Ok(())
     }
+
+    async fn run_gc_sweep(storage: &WorkerStorage, horizon: u64) -> RS<VersionGcStats> {
+        let partitions = BTreeSet::from([0]);
+        let mut total = VersionGcStats::default();
+        while !total.sweep_done {
+            let step = storage
+                .collect_garbage_step_async(horizon, &partitions, 1)
+                .await?;
+            total.pruned_versions += step.pruned_versions;
+            total.removed_rows += step.removed_rows;
+            total.sweep_done = step.sweep_done;
+        }
+        Ok(total)
+    }
+
+    async fn _kv_steps_walk_keys_in_order() -> RS<()> {
+        let (storage, _) = test_storage().await?;
+        for (xid, key) in [b"c", b"a", b"d", b"b"].into_iter().enumerate() {
+            storage.worker_put_local(key.to_vec(), b"v".to_vec(), xid as u64 + 1)?;
+        }
+        storage.worker_delete_local(b"b".to_vec(), 5)?;
+        assert_eq!(
+            storage.kv_keys_step(None, 2)?,
+            (vec![b"a".to_vec(), b"b".to_vec()], true)
+        );
+        assert_eq!(
+            storage.kv_keys_step(Some(b"b"), 2)?,
+            (vec![b"c".to_vec(), b"d".to_vec()], false)
+        );
+
+        run_gc_sweep(&storage, 6).await?;
+        assert_eq!(
+            storage.kv_keys_step(None, 8)?.0,
+            vec![b"a".to_vec(), b"c".to_vec(), b"d".to_vec()]
+        );
+        storage.worker_put_local(b"b".to_vec(), b"w".to_vec(), 7)?;
+        let keys = |items: Vec<KvItem>| items.into_iter().map(|item| item.key).collect::<Vec<_>>();
+        assert_eq!(
+            keys(storage.kv_range(b"", b"", None, false, None).await?),
+            vec![b"a".to_vec(), b"b".to_vec(), b"c".to_vec(), b"d".to_vec()]
+        );
+        assert_eq!(
+            keys(storage.kv_range(b"b", b"d", None, false, None).await?),
+            vec![b"b".to_vec(), b"c".to_vec()]
+        );
+        assert!(storage
+            .kv_range(b"d", b"b", None, false, None)
+            .await?
+            .is_empty());
+        assert_eq!(
+            keys(storage.kv_range(b"", b"", None, true, Some(2)).await?),
+            vec![b"d".to_vec(), b"c".to_vec()]
+        );
+        assert_eq!(
+            keys(storage.kv_range(b"a", b"d", None, false, Some(2)).await?),
+            vec![b"a".to_vec(), b"b".to_vec()]
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn kv_steps_walk_keys_in_order() {
+        block_on(async move {
+            let r = _kv_steps_walk_keys_in_order().await;
+            assert!(r.is_ok())
+        })
+    }
+
+    #[test]
+    fn worker_storage_gc_prunes_unreachable_versions() {
+        block_on(async move {
+            let r = _worker_storage_gc_prunes_unreachable_versions().await;
+            assert!(r.is_ok())
+        })
+    }
+
+    async fn _worker_storage_gc_prunes_unreachable_versions() -> RS<()> {
+        let (storage, oid) = test_storage().await?;
+        let mut tx1 = begin_tx(1, vec![]);
+        storage.put(oid, i32_bytes(1), i32_bytes(11), &tx1).await?;
+        storage.put(oid, i32_bytes(2), i32_bytes(21), &tx1).await?;
+        storage.commit_tx(&mut tx1).await?;
+        let mut tx2 = begin_tx(2, vec![]);
+        storage.put(oid, i32_bytes(1), i32_bytes(12), &tx2).await?;
+        storage.commit_tx(&mut tx2).await?;
+        let mut tx3 = begin_tx(3, vec![]);
+        storage.remove(oid, &i32_bytes(2), &tx3).await?;
+        storage.commit_tx(&mut tx3).await?;
+        storage.worker_put_local(b"a".to_vec(), b"1".to_vec(), 4)?;
+        storage.worker_put_local(b"a".to_vec(), b"2".to_vec(), 5)?;
+        storage.worker_put_local(b"b".to_vec(), b"1".to_vec(), 4)?;
+        storage.worker_delete_local(b"b".to_vec(), 6)?;
+
+        // A snapshot at xid 2 is still active: it must keep reading k2.
+        let stats = run_gc_sweep(&storage, 3).await?;
+        assert_eq!(stats.pruned_versions, 1);
+        assert_eq!(stats.removed_rows, 0);
+        let tx = begin_tx(2, vec![]);
+        assert_eq!(
+            storage.get(oid, &i32_bytes(1), &tx).await?,
+            Some(i32_bytes(12))
+        );
+        assert_eq!(
+            storage.get(oid, &i32_bytes(2), &tx).await?,
+            Some(i32_bytes(21))
+        );
+
+        let stats = run_gc_sweep(&storage, 7).await?;
+        assert_eq!(stats.pruned_versions, 5);
+        assert_eq!(stats.removed_rows, 2);
+        let tx = begin_tx(7, vec![]);
+        assert_eq!(
+            storage.get(oid, &i32_bytes(1), &tx).await?,
+            Some(i32_bytes(12))
+        );
+        assert_eq!(storage.get(oid, &i32_bytes(2), &tx).await?, None);
+        assert_eq!(storage.kv_get(b"a", None).await?, Some(b"2".to_vec()));
+        assert!(!storage.kv_store.contains_sync(b"b".as_slice()));
+
+        // Pages cached dirty reach the file on sync.
+        storage
+            .get_relation_async(oid, None)
+            .await?
+            .sync_files()
+            .await?;
+        let key_file = TimeSeriesFile::open_ts_file_sync(
+            TimeSeriesFile::relation_file_path(&storage.relation_path, 0, oid, 0),
+            false,
+        )
+        .await?;
+        let records = key_file.scan_range(0, u64::MAX).await?;
+        assert_eq!(records.len(), 1);
+        assert_eq!(records[0].timestamp, 2);
+        Ok(())
+    }
 }
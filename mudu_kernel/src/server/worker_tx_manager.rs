@@ -2,10 +2,11 @@ use crate::server::worker_snapshot::WorkerSnapshot;
 use crate::wal::xl_batch::XLBatch;
 use crate::wal::xl_data_op::{XLDelete, XLInsert, XLWrite};
 use crate::wal::xl_entry::{TxOp, XLEntry};
-use crate::x_engine::tx_mgr::{PhysicalRelationId, TxMgr};
+use crate::x_engine::tx_mgr::{IsolationLevel, PhysicalRelationId, TxMgr, TxOptions, TxReadSet};
 use mudu_utils::task_trace;
 use std::cell::RefCell;
 use std::collections::BTreeMap;
+use std::ops::Bound;
 use tracing::trace;
 
 struct WorkerTxState {
@@ -18,17 +19,27 @@ struct WorkerTxState {
     // appending a new one. Only the last write to a key is kept.
     kv_log_index: BTreeMap<Vec<u8>, usize>,
     relation_log_index: BTreeMap<(PhysicalRelationId, Vec<u8>), usize>,
+    // Keys and ranges read from the snapshot. Only populated for
+    // serializable transactions.
+    read_set: TxReadSet,
 }
 
 pub struct WorkerTxManager {
     snapshot: WorkerSnapshot,
+    options: TxOptions,
     state: RefCell<WorkerTxState>,
 }
 
 impl WorkerTxManager {
+    #[cfg(test)]
     pub fn new(snapshot: WorkerSnapshot) -> Self {
+        Self::with_options(snapshot, TxOptions::default())
+    }
+
+    pub fn with_options(snapshot: WorkerSnapshot, options: TxOptions) -> Self {
         Self {
             snapshot,
+            options,
             state: RefCell::new(WorkerTxState {
                 stage_kv_write: BTreeMap::new(),
                 staged_relation_ops: BTreeMap::new(),
@@ -36,10 +47,31 @@ impl WorkerTxManager {
                 log_buffer: Vec::new(),
                 kv_log_index: BTreeMap::new(),
                 relation_log_index: BTreeMap::new(),
+                read_set: TxReadSet::default(),
             }),
         }
     }
 
+    fn stage_put(&self, key: Vec<u8>, value: Vec<u8>, ttl_ms: Option<u64>) {
+        let trace = task_trace!();
+        trace.watch("tx.state.op", "put:enter");
+        trace.watch("tx.state.xid", &self.snapshot.xid().to_string());
+        self.with_state_mut(|state| {
+            // The TTL only goes to the log record; staged values never
+            // expire, the clock starts when the transaction commits.
+            let op = TxOp::Write(XLWrite::Insert(XLInsert {
+                table_id: 0,
+                partition_id: 0,
+                tuple_id: 0,
+                key: key.clone(),
+                value: value.clone(),
+                ttl_ms,
+            }));
+            state.state_kv_write(key, Some(value), op);
+        });
+        trace.watch("tx.state.op", "put:done");
+    }
+
     fn with_state<R>(&self, f: impl FnOnce(&WorkerTxState) -> R) -> R {
         #[expect(
             clippy::expect_used,
@@ -73,25 +105,20 @@ impl TxMgr for WorkerTxManager {
         self.snapshot.xid()
     }
 
+    fn options(&self) -> TxOptions {
+        self.options
+    }
+
     fn snapshot(&self) -> WorkerSnapshot {
         self.snapshot.clone()
     }
 
     fn put(&self, key: Vec<u8>, value: Vec<u8>) {
-        let trace = task_trace!();
-        trace.watch("tx.state.op", "put:enter");
-        trace.watch("tx.state.xid", &self.snapshot.xid().to_string());
-        self.with_state_mut(|state| {
-            let op = TxOp::Write(XLWrite::Insert(XLInsert {
-                table_id: 0,
-                partition_id: 0,
-                tuple_id: 0,
-                key: key.clone(),
-                value: value.clone(),
-            }));
-            state.state_kv_write(key, Some(value), op);
-        });
-        trace.watch("tx.state.op", "put:done");
+        self.stage_put(key, value, None);
+    }
+
+    fn put_with_ttl(&self, key: Vec<u8>, value: Vec<u8>, ttl_ms: u64) {
+        self.stage_put(key, value, Some(ttl_ms));
     }
 
     fn delete(&self, key: Vec<u8>) {
@@ -131,6 +158,7 @@ impl TxMgr for WorkerTxManager {
                 tuple_id: 0,
                 key: key.clone(),
                 value: value.clone(),
+                ttl_ms: None,
             }));
             let key = (relation_id, key);
             state.stage_rel_write(key, Some(value), op);
@@ -238,13 +266,7 @@ impl TxMgr for WorkerTxManager {
             state.write_ops.clear();
             let mut write_ops = Vec::new();
             for key in state.stage_kv_write.keys() {
-                write_ops.push((
-                    PhysicalRelationId {
-                        table_id: 0,
-                        partition_id: 0,
-                    },
-                    key.clone(),
-                ));
+                write_ops.push((PhysicalRelationId::WORKER_KV, key.clone()));
             }
             for (relation_id, ops) in &state.staged_relation_ops {
                 for key in ops.keys() {
@@ -263,9 +285,42 @@ impl TxMgr for WorkerTxManager {
             ops.push(TxOp::Begin);
             ops.extend(state.log_buffer.clone());
             ops.push(TxOp::Commit);
-            XLBatch::new(vec![XLEntry { xid, ops }])
+            XLBatch::new(vec![XLEntry {
+                xid,
+                ops,
+                commit_ms: 0,
+            }])
         })
     }
+
+    fn record_read(&self, relation_id: PhysicalRelationId, key: &[u8]) {
+        if self.options.isolation_level != IsolationLevel::Serializable {
+            return;
+        }
+        self.with_state_mut(|state| state.read_set.add_key(relation_id, key.to_vec()));
+    }
+
+    fn record_range_read(
+        &self,
+        relation_id: PhysicalRelationId,
+        start: Bound<&[u8]>,
+        end: Bound<&[u8]>,
+    ) {
+        if self.options.isolation_level != IsolationLevel::Serializable {
+            return;
+        }
+        self.with_state_mut(|state| {
+            state.read_set.add_range(
+                relation_id,
+                start.map(<[u8]>::to_vec),
+                end.map(<[u8]>::to_vec),
+            )
+        });
+    }
+
+    fn read_set(&self) -> TxReadSet {
+        self.with_state(|state| state.read_set.clone())
+    }
 }
 
 fn is_key_in_range(key: &[u8], start_key: &[u8], end_key: &[u8]) -> bool {
@@ -0,0 +1,745 @@
+//! Watches: change notifications the server pushes to its clients.
+//!
+//! A client registers a watch on a key or key prefix of the worker key
+//! space, or on a primary key range of a table, for one of its sessions. The
+//! connection loop then periodically reads the committed log entries past
+//! the watch's positions, the same way change data capture does (see
+//! `worker_cdc`), and writes what matches as `WatchEvent` frames on the
+//! watch's connection.
+//!
+//! A watch stops receiving events once `max_in_flight` of them wait for an
+//! acknowledgement, so a slow client keeps its place in the log instead of
+//! growing a queue on the server. Every event carries the positions after
+//! it; registering again from them resumes the watch, e.g. after a
+//! reconnect, as long as the worker logs still hold those entries.
+
+use std::cmp::Ordering as CmpOrdering;
+use std::collections::{BTreeMap, HashMap};
+use std::path::Path;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::Arc;
+use std::time::Duration;
+
+use mudu::common::buf::Buf;
+use mudu::common::id::OID;
+use mudu::common::result::RS;
+use mudu::error::ErrorCode;
+use mudu::mudu_error;
+use mudu::utils::json::JsonValue;
+use mudu_contract::protocol::{
+    encode_watch_event, CdcOp, CdcPosition, KvChange, WatchChange, WatchEvent, WatchTarget,
+};
+use mudu_contract::tuple::build_tuple::build_tuple;
+use mudu_contract::tuple::comparator::tuple_compare;
+use mudu_sys::contract::async_fs::AsyncFs;
+use mudu_sys::sync::async_::futures_mutex::FMutex;
+use mudu_sys::sync::SMutex;
+
+use crate::contract::meta_mgr::MetaMgr;
+use crate::contract::table_desc::TableDesc;
+use crate::server::worker_cdc::{read_committed_batches, visible_lsn, ChangeDecoder};
+use crate::server::worker_registry::WorkerRegistry;
+use crate::storage::encryption::Keyring;
+use crate::wal::lsn::LSN;
+use crate::wal::xl_batch::XLBatch;
+use crate::wal::xl_data_op::XLWrite;
+use crate::wal::xl_entry::TxOp;
+use crate::x_engine::tx_mgr::PhysicalRelationId;
+
+/// Events pushed before a watch waits for an acknowledgement when the
+/// client sets no limit.
+pub const WATCH_DEFAULT_MAX_IN_FLIGHT: u32 = 8;
+
+/// Changes one event carries at most when the client sets no limit.
+pub const WATCH_DEFAULT_MAX_CHANGES: u32 = 256;
+
+/// How often a connection with watches looks for events to push.
+pub(crate) const WATCH_PUSH_INTERVAL: Duration = Duration::from_millis(100);
+
+/// The watches of one worker's connections.
+pub(crate) struct WatchRegistry {
+    next_watch_id: AtomicU64,
+    connections: SMutex<HashMap<u64, ConnectionWatches>>,
+}
+
+struct ConnectionWatches {
+    // Keeps pushed events from interleaving with the responses the
+    // connection loop writes.
+    write_gate: Arc<FMutex<()>>,
+    watches: BTreeMap<u64, Watch>,
+}
+
+#[derive(Debug, Clone)]
+pub(crate) struct Watch {
+    session_id: OID,
+    filter: WatchFilter,
+    // Next LSN to read in each worker log; a worker is added once it
+    // publishes its first visible LSN.
+    positions: BTreeMap<OID, u64>,
+    max_in_flight: u64,
+    max_changes: usize,
+    sent_seq: u64,
+    acked_seq: u64,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum WatchFilter {
+    Key(Vec<u8>),
+    Prefix(Vec<u8>),
+    TableRange {
+        table_id: OID,
+        start: Option<Buf>,
+        end: Option<Buf>,
+    },
+}
+
+impl Watch {
+    /// Builds the watch a client asked for on `session_id`, starting at
+    /// `from` or else at the changes committed from now on.
+    #[allow(clippy::too_many_arguments)]
+    pub(crate) async fn new(
+        meta_mgr: &dyn MetaMgr,
+        data_dir: &str,
+        registry: &WorkerRegistry,
+        session_id: OID,
+        target: &WatchTarget,
+        from: Option<&[CdcPosition]>,
+        max_in_flight: u32,
+        max_changes: u32,
+    ) -> RS<Self> {
+        let filter = match target {
+            WatchTarget::Key(key) => WatchFilter::Key(key.clone()),
+            WatchTarget::Prefix(prefix) => WatchFilter::Prefix(prefix.clone()),
+            WatchTarget::TableRange { table, start, end } => {
+                let desc = meta_mgr.get_table_by_name(table).await?.ok_or_else(|| {
+                    mudu_error!(
+                        ErrorCode::EntityNotFound,
+                        format!("table {} does not exist", table)
+                    )
+                })?;
+                WatchFilter::TableRange {
+                    table_id: desc.id(),
+                    start: start
+                        .as_ref()
+                        .map(|bound| encode_key_bound(&desc, bound))
+                        .transpose()?,
+                    end: end
+                        .as_ref()
+                        .map(|bound| encode_key_bound(&desc, bound))
+                        .transpose()?,
+                }
+            }
+        };
+        let mut positions = BTreeMap::new();
+        for position in from.unwrap_or_default() {
+            if registry
+                .worker_index_by_worker_id(position.worker_id())
+                .is_none()
+            {
+                return Err(mudu_error!(
+                    ErrorCode::InvalidArgument,
+                    format!("unknown worker {} in watch position", position.worker_id())
+                ));
+            }
+            positions.insert(position.worker_id(), position.lsn());
+        }
+        for worker in registry.workers() {
+            if positions.contains_key(&worker.worker_id) {
+                continue;
+            }
+            if let Some(visible) = visible_lsn(data_dir, worker.worker_id)? {
+                positions.insert(worker.worker_id, visible.as_u64());
+            }
+        }
+        Ok(Self {
+            session_id,
+            filter,
+            positions,
+            max_in_flight: match max_in_flight {
+                0 => WATCH_DEFAULT_MAX_IN_FLIGHT,
+                limit => limit,
+            } as u64,
+            max_changes: match max_changes {
+                0 => WATCH_DEFAULT_MAX_CHANGES,
+                limit => limit,
+            } as usize,
+            sent_seq: 0,
+            acked_seq: 0,
+        })
+    }
+
+    pub(crate) fn positions(&self) -> Vec<CdcPosition> {
+        to_cdc_positions(&self.positions)
+    }
+
+    fn has_credit(&self) -> bool {
+        self.sent_seq - self.acked_seq < self.max_in_flight
+    }
+}
+
+impl WatchRegistry {
+    pub(crate) fn new() -> Self {
+        Self {
+            next_watch_id: AtomicU64::new(1),
+            connections: SMutex::new(HashMap::new()),
+        }
+    }
+
+    /// Adds `watch` to the watches of `conn_id` and returns its id.
+    pub(crate) fn register(&self, conn_id: u64, watch: Watch) -> RS<u64> {
+        let watch_id = self.next_watch_id.fetch_add(1, Ordering::Relaxed);
+        let mut connections = self.connections.lock()?;
+        connections
+            .entry(conn_id)
+            .or_insert_with(ConnectionWatches::new)
+            .watches
+            .insert(watch_id, watch);
+        Ok(watch_id)
+    }
+
+    /// Acknowledges the events of a watch up to `seq`.
+    pub(crate) fn ack(&self, conn_id: u64, session_id: OID, watch_id: u64, seq: u64) -> RS<()> {
+        let mut connections = self.connections.lock()?;
+        let watch = find_watch(&mut connections, conn_id, session_id, watch_id)?;
+        if seq > watch.sent_seq {
+            return Err(mudu_error!(
+                ErrorCode::InvalidArgument,
+                format!(
+                    "watch {} acknowledged event {} before it was sent",
+                    watch_id, seq
+                )
+            ));
+        }
+        watch.acked_seq = watch.acked_seq.max(seq);
+        Ok(())
+    }
+
+    pub(crate) fn cancel(&self, conn_id: u64, session_id: OID, watch_id: u64) -> RS<()> {
+        let mut connections = self.connections.lock()?;
+        find_watch(&mut connections, conn_id, session_id, watch_id)?;
+        if let Some(connection) = connections.get_mut(&conn_id) {
+            connection.watches.remove(&watch_id);
+        }
+        Ok(())
+    }
+
+    /// Drops the watches of a session that closed.
+    pub(crate) fn remove_session(&self, conn_id: u64, session_id: OID) -> RS<()> {
+        let mut connections = self.connections.lock()?;
+        if let Some(connection) = connections.get_mut(&conn_id) {
+            connection
+                .watches
+                .retain(|_, watch| watch.session_id != session_id);
+        }
+        Ok(())
+    }
+
+    /// Drops every watch of a connection that closed.
+    pub(crate) fn remove_connection(&self, conn_id: u64) -> RS<()> {
+        self.connections.lock()?.remove(&conn_id);
+        Ok(())
+    }
+
+    pub(crate) fn has_watches(&self, conn_id: u64) -> RS<bool> {
+        Ok(self
+            .connections
+            .lock()?
+            .get(&conn_id)
+            .is_some_and(|connection| !connection.watches.is_empty()))
+    }
+
+    /// Connections that have at least one watch.
+    pub(crate) fn watched_connections(&self) -> RS<Vec<u64>> {
+        Ok(self
+            .connections
+            .lock()?
+            .iter()
+            .filter(|(_, connection)| !connection.watches.is_empty())
+            .map(|(conn_id, _)| *conn_id)
+            .collect())
+    }
+
+    /// The lock a writer on `conn_id` holds while it writes a frame.
+    pub(crate) fn write_gate(&self, conn_id: u64) -> RS<Arc<FMutex<()>>> {
+        let mut connections = self.connections.lock()?;
+        Ok(connections
+            .entry(conn_id)
+            .or_insert_with(ConnectionWatches::new)
+            .write_gate
+            .clone())
+    }
+
+    /// Watches of `conn_id` that may receive another event.
+    fn ready_watches(&self, conn_id: u64) -> RS<Vec<(u64, Watch)>> {
+        let connections = self.connections.lock()?;
+        let Some(connection) = connections.get(&conn_id) else {
+            return Ok(Vec::new());
+        };
+        Ok(connection
+            .watches
+            .iter()
+            .filter(|(_, watch)| watch.has_credit())
+            .map(|(watch_id, watch)| (*watch_id, watch.clone()))
+            .collect())
+    }
+
+    /// Moves a watch read from `from` on to `positions`; returns the event
+    /// to push when the read found changes. A watch cancelled meanwhile
+    /// gets nothing.
+    fn advance(
+        &self,
+        conn_id: u64,
+        watch_id: u64,
+        from: &BTreeMap<OID, u64>,
+        positions: BTreeMap<OID, u64>,
+        changes: Vec<WatchChange>,
+    ) -> RS<Option<WatchEvent>> {
+        let mut connections = self.connections.lock()?;
+        let Some(watch) = connections
+            .get_mut(&conn_id)
+            .and_then(|connection| connection.watches.get_mut(&watch_id))
+        else {
+            return Ok(None);
+        };
+        if &watch.positions != from {
+            return Ok(None);
+        }
+        watch.positions = positions;
+        if changes.is_empty() {
+            return Ok(None);
+        }
+        watch.sent_seq += 1;
+        Ok(Some(WatchEvent::new(
+            watch_id,
+            watch.sent_seq,
+            changes,
+            to_cdc_positions(&watch.positions),
+        )))
+    }
+
+    /// Cancels a watch the server can no longer serve; returns the event
+    /// that tells the client.
+    fn fail(&self, conn_id: u64, watch_id: u64, error: String) -> RS<Option<WatchEvent>> {
+        let mut connections = self.connections.lock()?;
+        let Some(watch) = connections
+            .get_mut(&conn_id)
+            .and_then(|connection| connection.watches.remove(&watch_id))
+        else {
+            return Ok(None);
+        };
+        Ok(Some(
+            WatchEvent::new(
+                watch_id,
+                watch.sent_seq + 1,
+                Vec::new(),
+                to_cdc_positions(&watch.positions),
+            )
+            .with_error(error),
+        ))
+    }
+}
+
+impl ConnectionWatches {
+    fn new() -> Self {
+        Self {
+            write_gate: Arc::new(FMutex::new(())),
+            watches: BTreeMap::new(),
+        }
+    }
+}
+
+fn find_watch(
+    connections: &mut HashMap<u64, ConnectionWatches>,
+    conn_id: u64,
+    session_id: OID,
+    watch_id: u64,
+) -> RS<&mut Watch> {
+    connections
+        .get_mut(&conn_id)
+        .and_then(|connection| connection.watches.get_mut(&watch_id))
+        .filter(|watch| watch.session_id == session_id)
+        .ok_or_else(|| {
+            mudu_error!(
+                ErrorCode::EntityNotFound,
+                format!("session {} has no watch {}", session_id, watch_id)
+            )
+        })
+}
+
+/// Reads the committed changes for the watches of `conn_id` that may
+/// receive another event and returns the encoded `WatchEvent` frames.
+#[allow(clippy::too_many_arguments)]
+pub(crate) async fn poll_watch_events(
+    fs: &dyn AsyncFs,
+    data_dir: &str,
+    log_dir: &Path,
+    keyring: Option<&Keyring>,
+    registry: &WorkerRegistry,
+    meta_mgr: &dyn MetaMgr,
+    watches: &WatchRegistry,
+    conn_id: u64,
+) -> RS<Vec<Vec<u8>>> {
+    let mut frames = Vec::new();
+    for (watch_id, watch) in watches.ready_watches(conn_id)? {
+        let event =
+            match read_watch_changes(fs, data_dir, log_dir, keyring, registry, meta_mgr, &watch)
+                .await
+            {
+                Ok((positions, changes)) => {
+                    watches.advance(conn_id, watch_id, &watch.positions, positions, changes)?
+                }
+                Err(err) => watches.fail(conn_id, watch_id, err.to_string())?,
+            };
+        if let Some(event) = event {
+            frames.push(encode_watch_event(&event)?);
+        }
+    }
+    Ok(frames)
+}
+
+async fn read_watch_changes(
+    fs: &dyn AsyncFs,
+    data_dir: &str,
+    log_dir: &Path,
+    keyring: Option<&Keyring>,
+    registry: &WorkerRegistry,
+    meta_mgr: &dyn MetaMgr,
+    watch: &Watch,
+) -> RS<(BTreeMap<OID, u64>, Vec<WatchChange>)> {
+    let table = match &watch.filter {
+        WatchFilter::TableRange { table_id, .. } => {
+            Some(meta_mgr.get_table_by_id(*table_id).await?)
+        }
+        _ => None,
+    };
+    let decoder = table.as_deref().map(ChangeDecoder::new);
+    let mut changes = Vec::new();
+    let mut positions = BTreeMap::new();
+    for worker in registry.workers() {
+        let worker_id = worker.worker_id;
+        let Some(visible) = visible_lsn(data_dir, worker_id)? else {
+            continue;
+        };
+        let Some(from) = watch.positions.get(&worker_id).copied() else {
+            // The worker published its first visible LSN after the watch
+            // was registered; start at it.
+            positions.insert(worker_id, visible.as_u64());
+            continue;
+        };
+        let next = if changes.len() >= watch.max_changes {
+            from
+        } else {
+            read_committed_batches(
+                fs,
+                log_dir,
+                keyring,
+                worker_id,
+                from,
+                visible.as_u64(),
+                &mut |lsn, batch| {
+                    if changes.len() >= watch.max_changes {
+                        return Ok(false);
+                    }
+                    watch.filter.collect(
+                        worker_id,
+                        lsn,
+                        batch,
+                        table.as_deref().zip(decoder.as_ref()),
+                        &mut changes,
+                    )?;
+                    Ok(true)
+                },
+            )
+            .await?
+        };
+        positions.insert(worker_id, next);
+    }
+    Ok((positions, changes))
+}
+
+impl WatchFilter {
+    /// Appends the committed writes of `batch` the watch sees to `changes`.
+    fn collect(
+        &self,
+        worker_id: OID,
+        lsn: LSN,
+        batch: XLBatch,
+        table: Option<(&TableDesc, &ChangeDecoder)>,
+        changes: &mut Vec<WatchChange>,
+    ) -> RS<()> {
+        for entry in batch.entries {
+            if entry.ops.iter().any(|op| matches!(op, TxOp::Abort)) {
+                continue;
+            }
+            for op in entry.ops {
+                let TxOp::Write(write) = op else {
+                    continue;
+                };
+                let change = match (self, table) {
+                    (WatchFilter::TableRange { start, end, .. }, Some((desc, decoder))) => {
+                        let key = match &write {
+                            XLWrite::Insert(insert) => &insert.key,
+                            XLWrite::Delete(delete) => &delete.key,
+                            XLWrite::Update(_) => continue,
+                        };
+                        if write.table_id() != desc.id()
+                            || !key_in_range(desc, key, start.as_deref(), end.as_deref())?
+                        {
+                            continue;
+                        }
+                        decoder
+                            .decode_write(worker_id, lsn, entry.xid, write)?
+                            .map(WatchChange::Row)
+                    }
+                    _ => self.match_kv_write(worker_id, lsn, entry.xid, write),
+                };
+                if let Some(change) = change {
+                    changes.push(change);
+                }
+            }
+        }
+        Ok(())
+    }
+
+    fn match_kv_write(
+        &self,
+        worker_id: OID,
+        lsn: LSN,
+        xid: u64,
+        write: XLWrite,
+    ) -> Option<WatchChange> {
+        if write.table_id() != PhysicalRelationId::WORKER_KV.table_id {
+            return None;
+        }
+        let (op, key, value) = match write {
+            XLWrite::Insert(insert) => (CdcOp::Put, insert.key, Some(insert.value)),
+            XLWrite::Delete(delete) => (CdcOp::Delete, delete.key, None),
+            XLWrite::Update(_) => return None,
+        };
+        let matches = match self {
+            WatchFilter::Key(watched) => key == *watched,
+            WatchFilter::Prefix(prefix) => key.starts_with(prefix),
+            WatchFilter::TableRange { .. } => false,
+        };
+        matches
+            .then(|| WatchChange::Kv(KvChange::new(worker_id, lsn.as_u64(), xid, op, key, value)))
+    }
+}
+
+fn key_in_range(
+    table: &TableDesc,
+    key: &[u8],
+    start: Option<&[u8]>,
+    end: Option<&[u8]>,
+) -> RS<bool> {
+    if let Some(start) = start {
+        if tuple_compare(table.key_desc(), key, start)? == CmpOrdering::Less {
+            return Ok(false);
+        }
+    }
+    if let Some(end) = end {
+        if tuple_compare(table.key_desc(), key, end)? != CmpOrdering::Less {
+            return Ok(false);
+        }
+    }
+    Ok(true)
+}
+
+/// Encodes a range bound, an object keyed by key column name, as a primary
+/// key of `table`.
+fn encode_key_bound(table: &TableDesc, bound: &JsonValue) -> RS<Buf> {
+    let object = bound.as_object().ok_or_else(|| {
+        mudu_error!(
+            ErrorCode::InvalidArgument,
+            "a watch range bound must be an object keyed by key column name"
+        )
+    })?;
+    let mut fields = Vec::new();
+    for field in table.key_info() {
+        let value = object.get(field.name()).ok_or_else(|| {
+            mudu_error!(
+                ErrorCode::InvalidArgument,
+                format!("watch range bound has no key column {}", field.name())
+            )
+        })?;
+        let ty = field.type_desc();
+        let internal = ty.dat_type_id().fn_input_json()(value, ty).map_err(|e| e.to_m_err())?;
+        let binary = ty.dat_type_id().fn_send()(&internal, ty).map_err(|e| e.to_m_err())?;
+        fields.push(binary.into());
+    }
+    build_tuple(&fields, table.key_desc())
+}
+
+fn to_cdc_positions(positions: &BTreeMap<OID, u64>) -> Vec<CdcPosition> {
+    positions
+        .iter()
+        .map(|(worker_id, lsn)| CdcPosition::new(*worker_id, *lsn))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used, clippy::expect_used, clippy::panic)]
+
+    use super::*;
+    use crate::wal::xl_data_op::{XLDelete, XLInsert};
+    use crate::wal::xl_entry::XLEntry;
+
+    fn watch(filter: WatchFilter, max_in_flight: u64) -> Watch {
+        Watch {
+            session_id: 5,
+            filter,
+            positions: BTreeMap::from([(1, 10)]),
+            max_in_flight,
+            max_changes: 16,
+            sent_seq: 0,
+            acked_seq: 0,
+        }
+    }
+
+    fn kv_put(key: &[u8], value: &[u8]) -> TxOp {
+        TxOp::Write(XLWrite::Insert(XLInsert {
+            table_id: 0,
+            partition_id: 0,
+            tuple_id: 0,
+            key: key.to_vec(),
+            value: value.to_vec(),
+            ttl_ms: None,
+        }))
+    }
+
+    fn kv_delete(key: &[u8]) -> TxOp {
+        TxOp::Write(XLWrite::Delete(XLDelete {
+            table_id: 0,
+            partition_id: 0,
+            tuple_id: 0,
+            key: key.to_vec(),
+        }))
+    }
+
+    fn kv_change(change: &WatchChange) -> &KvChange {
+        match change {
+            WatchChange::Kv(change) => change,
+            WatchChange::Row(_) => panic!("expected a kv change"),
+        }
+    }
+
+    #[test]
+    fn prefix_watch_sees_committed_kv_writes_only() {
+        let batch = XLBatch::new(vec![
+            XLEntry {
+                xid: 7,
+                ops: vec![
+                    TxOp::Begin,
+                    kv_put(b"user/1", b"a"),
+                    kv_put(b"order/1", b"b"),
+                    kv_delete(b"user/2"),
+                    TxOp::Commit,
+                ],
+                commit_ms: 0,
+            },
+            XLEntry {
+                xid: 8,
+                ops: vec![TxOp::Begin, kv_put(b"user/3", b"c"), TxOp::Abort],
+                commit_ms: 0,
+            },
+        ]);
+        let mut changes = Vec::new();
+        WatchFilter::Prefix(b"user/".to_vec())
+            .collect(1, LSN::new(42), batch, None, &mut changes)
+            .unwrap();
+        assert_eq!(changes.len(), 2);
+        let put = kv_change(&changes[0]);
+        assert_eq!(
+            (put.key(), put.value(), put.op(), put.lsn(), put.xid()),
+            (&b"user/1"[..], Some(&b"a"[..]), CdcOp::Put, 42, 7)
+        );
+        let delete = kv_change(&changes[1]);
+        assert_eq!((delete.key(), delete.value()), (&b"user/2"[..], None));
+
+        let mut changes = Vec::new();
+        let batch = XLBatch::new(vec![XLEntry {
+            xid: 9,
+            ops: vec![kv_put(b"user/10", b"x"), kv_put(b"user/1", b"y")],
+            commit_ms: 0,
+        }]);
+        WatchFilter::Key(b"user/1".to_vec())
+            .collect(1, LSN::new(43), batch, None, &mut changes)
+            .unwrap();
+        assert_eq!(changes.len(), 1);
+        assert_eq!(kv_change(&changes[0]).value(), Some(&b"y"[..]));
+    }
+
+    #[test]
+    fn watch_waits_for_acknowledgements_once_the_window_is_full() {
+        let registry = WatchRegistry::new();
+        let watch_id = registry
+            .register(3, watch(WatchFilter::Key(b"k".to_vec()), 2))
+            .unwrap();
+        assert!(registry.has_watches(3).unwrap());
+        assert_eq!(registry.watched_connections().unwrap(), vec![3]);
+
+        let change = || {
+            vec![WatchChange::Kv(KvChange::new(
+                1,
+                10,
+                1,
+                CdcOp::Put,
+                b"k".to_vec(),
+                None,
+            ))]
+        };
+        let mut from = BTreeMap::from([(1, 10)]);
+        for seq in 1..=2 {
+            let next = BTreeMap::from([(1, 10 + seq)]);
+            let event = registry
+                .advance(3, watch_id, &from, next.clone(), change())
+                .unwrap()
+                .unwrap();
+            assert_eq!(event.seq(), seq);
+            assert_eq!(event.positions(), &[CdcPosition::new(1, 10 + seq)]);
+            from = next;
+        }
+        assert!(registry.ready_watches(3).unwrap().is_empty());
+
+        assert!(registry.ack(3, 5, watch_id, 3).is_err());
+        assert!(registry.ack(3, 6, watch_id, 1).is_err());
+        registry.ack(3, 5, watch_id, 1).unwrap();
+        assert_eq!(registry.ready_watches(3).unwrap().len(), 1);
+
+        // A read that found nothing only moves the positions.
+        let next = BTreeMap::from([(1, 20)]);
+        assert!(registry
+            .advance(3, watch_id, &from, next.clone(), Vec::new())
+            .unwrap()
+            .is_none());
+        assert_eq!(registry.ready_watches(3).unwrap()[0].1.positions, next);
+
+        registry.cancel(3, 5, watch_id).unwrap();
+        assert!(registry.cancel(3, 5, watch_id).is_err());
+        assert!(!registry.has_watches(3).unwrap());
+    }
+
+    #[test]
+    fn failed_watch_is_cancelled_with_a_final_event() {
+        let registry = WatchRegistry::new();
+        let watch_id = registry
+            .register(4, watch(WatchFilter::Prefix(Vec::new()), 1))
+            .unwrap();
+        let event = registry
+            .fail(4, watch_id, "table dropped".to_string())
+            .unwrap()
+            .unwrap();
+        assert_eq!(event.seq(), 1);
+        assert_eq!(event.error(), Some("table dropped"));
+        assert!(!registry.has_watches(4).unwrap());
+
+        let other = registry
+            .register(4, watch(WatchFilter::Prefix(Vec::new()), 1))
+            .unwrap();
+        registry.remove_session(4, 5).unwrap();
+        assert!(registry.ack(4, 5, other, 0).is_err());
+        registry.remove_connection(4).unwrap();
+        assert!(registry.watched_connections().unwrap().is_empty());
+    }
+}
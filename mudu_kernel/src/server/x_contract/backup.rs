@@ -0,0 +1,77 @@
+use super::*;
+use crate::storage::backup::{copy_into_backup, BackupFile, WorkerBackup};
+use std::future::Future;
+use std::path::Path;
+
+impl WorkerXContract {
+    /// Copies this worker's share of an online backup into `backup_dir`.
+    ///
+    /// Takes a checkpoint and flushes the log first, then awaits `barrier`,
+    /// which resolves once every worker of the backup got that far. Only then
+    /// copies the synced relation files of the owned partitions, each under
+    /// its relation lock, and last the checkpoint and the log chunks after
+    /// it. Every commit in the copied relation files was appended to the log
+    /// before its apply, so the copied log reaches it. Holding
+    /// `checkpoint_gate` keeps a periodic checkpoint from removing the chunks
+    /// and checkpoint meanwhile. `None` when the contract runs without a
+    /// worker log.
+    pub async fn backup_async<B>(
+        &self,
+        backup_dir: &Path,
+        barrier: B,
+    ) -> RS<Option<(WorkerBackup, Vec<BackupFile>)>>
+    where
+        B: Future<Output = RS<()>>,
+    {
+        let Some(log) = self.log_cloned()? else {
+            barrier.await?;
+            return Ok(None);
+        };
+        let _gate = self.checkpoint_gate.lock().await;
+        let checkpoint = self.checkpoint_locked_async(&log).await?;
+        log.flush_async().await?;
+        let barrier_lsn = log.position()?.lsn;
+        barrier.await?;
+
+        let partitions = self.owned_relation_partitions_async().await?;
+        let mut files = self
+            .storage
+            .backup_relations_async(&partitions, backup_dir)
+            .await?;
+
+        log.flush_async().await?;
+        let end_lsn = log.position()?.lsn;
+        let layout = log.layout()?;
+        let fs = log.fs();
+        let log_dir = layout.log_dir();
+        files.push(
+            copy_into_backup(
+                fs.as_ref(),
+                log_dir,
+                &layout.checkpoint_path(checkpoint.lsn),
+                backup_dir,
+            )
+            .await?,
+        );
+        for path in layout.chunk_paths_sorted_async(fs.as_ref()).await? {
+            files.push(copy_into_backup(fs.as_ref(), log_dir, &path, backup_dir).await?);
+        }
+        debug!(
+            worker_id = self.worker_id,
+            checkpoint_lsn = checkpoint.lsn.as_u64(),
+            barrier_lsn = barrier_lsn.as_u64(),
+            end_lsn = end_lsn.as_u64(),
+            files = files.len(),
+            "worker backup taken"
+        );
+        Ok(Some((
+            WorkerBackup {
+                worker_id: self.worker_id,
+                checkpoint_lsn: checkpoint.lsn.as_u64(),
+                barrier_lsn: barrier_lsn.as_u64(),
+                end_lsn: end_lsn.as_u64(),
+            },
+            files,
+        )))
+    }
+}
@@ -0,0 +1,71 @@
+use super::*;
+use crate::server::worker_cdc::{
+    consumer_floor, load_consumer_positions, mark_positions_changed, publish_visible_lsn,
+    take_changed_positions, CdcConsumerPositions, CDC_POSITIONS_MAGIC,
+};
+use crate::storage::state_file::{read_state_file, write_state_file};
+use crate::wal::lsn::LSN;
+
+impl WorkerXContract {
+    /// Loads the change data capture consumer positions saved next to the
+    /// worker log.
+    pub(crate) async fn load_cdc_positions_async(&self, log: &ChunkedWorkerLogBackend) -> RS<()> {
+        let fs = log.fs();
+        let path = self.log_layout.cdc_positions_path();
+        if !fs.path_exists(&path).await? {
+            return Ok(());
+        }
+        let keyring = self.log_layout.keyring().map(|keyring| keyring.as_ref());
+        let saved = read_state_file::<CdcConsumerPositions>(
+            fs.as_ref(),
+            &path,
+            CDC_POSITIONS_MAGIC,
+            keyring,
+        )
+        .await?;
+        load_consumer_positions(self.data_dir(), self.worker_id, saved)
+    }
+
+    /// Makes the log entries below `applied_lsn` readable by change data
+    /// capture consumers, and saves the consumer positions that changed.
+    pub(crate) async fn sync_cdc_async(
+        &self,
+        log: &ChunkedWorkerLogBackend,
+        applied_lsn: LSN,
+    ) -> RS<()> {
+        publish_visible_lsn(self.data_dir(), self.worker_id, applied_lsn)?;
+        let Some(positions) = take_changed_positions(self.data_dir(), self.worker_id)? else {
+            return Ok(());
+        };
+        let fs = log.fs();
+        let path = self.log_layout.cdc_positions_path();
+        let result = write_state_file(
+            fs.as_ref(),
+            &path,
+            CDC_POSITIONS_MAGIC,
+            &positions,
+            self.log_layout.keyring(),
+        )
+        .await;
+        if result.is_err() {
+            mark_positions_changed(self.data_dir(), self.worker_id)?;
+        }
+        result
+    }
+
+    /// Returns the first chunk a checkpoint keeps for the change data
+    /// capture consumers; `u64::MAX` when none needs one.
+    pub(crate) async fn cdc_retained_chunk_sequence_async(
+        &self,
+        log: &ChunkedWorkerLogBackend,
+    ) -> RS<u64> {
+        let Some(floor) = consumer_floor(self.data_dir(), self.worker_id)? else {
+            return Ok(u64::MAX);
+        };
+        let sequence = self
+            .log_layout
+            .chunk_sequence_for_lsn_async(log.fs().as_ref(), floor)
+            .await?;
+        Ok(sequence.unwrap_or(0))
+    }
+}
@@ -0,0 +1,121 @@
+use super::*;
+use crate::server::worker_checkpoint::{
+    CheckpointPolicy, CheckpointStats, CommitTicket, WorkerCheckpoint,
+};
+use crate::wal::lsn::LSN;
+
+impl WorkerXContract {
+    pub fn set_checkpoint_policy(&self, policy: CheckpointPolicy) -> RS<()> {
+        self.checkpoint.lock()?.set_policy(policy);
+        Ok(())
+    }
+
+    /// LSN that worker log recovery replays from.
+    pub fn log_recovery_start_lsn(&self) -> LSN {
+        LSN::new(self.recovery_start_lsn.load(Ordering::Acquire))
+    }
+
+    /// Marks a commit as in flight from its log append until its storage
+    /// apply; `None` when the contract runs without a worker log. Fails on a
+    /// read replica.
+    pub(crate) fn enter_commit(
+        &self,
+        log: Option<&ChunkedWorkerLogBackend>,
+    ) -> RS<Option<CommitTicket>> {
+        self.ensure_writable()?;
+        log.map(|log| self.commit_tickets.enter(log)).transpose()
+    }
+
+    /// Takes a checkpoint when the checkpoint policy says one is due.
+    ///
+    /// In archive mode every chunk the log completed since the last call is
+    /// archived first, so the archive trails the log by about one chunk
+    /// rather than by a checkpoint interval. Change data capture consumers
+    /// see the entries applied up to this call.
+    pub async fn checkpoint_if_due_async(&self) -> RS<Option<CheckpointStats>> {
+        let Some(log) = self.log_cloned()? else {
+            return Ok(None);
+        };
+        let position = self.commit_tickets.applied_position(&log)?;
+        if self.log_layout.archive_dir().is_some() {
+            log.archive_chunks_before(position.chunk_sequence).await?;
+        }
+        self.sync_cdc_async(&log, position.lsn).await?;
+        if !self.checkpoint.lock()?.is_due(log.appended_bytes()?) {
+            return Ok(None);
+        }
+        self.checkpoint_async().await
+    }
+
+    /// Takes a fuzzy checkpoint and removes the log chunks it makes
+    /// unnecessary, unless a change data capture consumer still needs them.
+    ///
+    /// Commits keep running meanwhile. The checkpoint LSN is the position of
+    /// the oldest commit not yet applied to storage, so every entry below it
+    /// is in the synced relation files or the saved kv rows. Recovery replays
+    /// from that LSN; entries above it may already be applied, which replay
+    /// tolerates.
+    pub async fn checkpoint_async(&self) -> RS<Option<CheckpointStats>> {
+        let Some(log) = self.log_cloned()? else {
+            return Ok(None);
+        };
+        let _gate = self.checkpoint_gate.lock().await;
+        self.checkpoint_locked_async(&log).await.map(Some)
+    }
+
+    /// Takes a checkpoint; the caller holds `checkpoint_gate`.
+    pub(crate) async fn checkpoint_locked_async(
+        &self,
+        log: &ChunkedWorkerLogBackend,
+    ) -> RS<CheckpointStats> {
+        let log_bytes = log.appended_bytes()?;
+        let position = self.commit_tickets.applied_position(log)?;
+        let next_ts = self.snapshot_mgr.latest_ts();
+        let kv_rows = self.storage.checkpoint_kv_rows();
+        let partitions = self.owned_relation_partitions_async().await?;
+        self.storage.checkpoint_relations_async(&partitions).await?;
+
+        let layout = log.layout()?;
+        WorkerCheckpoint::new(position.lsn, next_ts, kv_rows)
+            .write_async(log.fs().as_ref(), &layout)
+            .await?;
+        let retained = self.cdc_retained_chunk_sequence_async(log).await?;
+        let removed_chunks = log
+            .remove_chunks_before(position.chunk_sequence.min(retained))
+            .await?;
+        self.checkpoint.lock()?.mark_done(log_bytes);
+        debug!(
+            worker_id = self.worker_id,
+            lsn = position.lsn.as_u64(),
+            removed_chunks,
+            "worker checkpoint taken"
+        );
+        Ok(CheckpointStats {
+            lsn: position.lsn,
+            removed_chunks,
+        })
+    }
+
+    /// Restores the newest checkpoint of the worker log, before recovery
+    /// replays the entries after it.
+    pub(crate) async fn restore_checkpoint_async(&self, log: &ChunkedWorkerLogBackend) -> RS<()> {
+        let layout = log.layout()?;
+        let Some(checkpoint) =
+            WorkerCheckpoint::load_latest_async(log.fs().as_ref(), &layout).await?
+        else {
+            return Ok(());
+        };
+        let lsn = checkpoint.lsn();
+        log.skip_to_lsn(lsn);
+        self.snapshot_mgr.observe_committed_ts(checkpoint.next_ts());
+        self.storage.restore_kv_rows(checkpoint.into_kv_rows())?;
+        self.recovery_start_lsn
+            .store(lsn.as_u64(), Ordering::Release);
+        debug!(
+            worker_id = self.worker_id,
+            lsn = lsn.as_u64(),
+            "worker checkpoint restored"
+        );
+        Ok(())
+    }
+}
@@ -0,0 +1,102 @@
+use super::utils::*;
+use super::*;
+use crate::contract::schema_index::SchemaIndex;
+
+/// One row of a secondary index, derived from a base table row.
+pub(crate) struct IndexRow {
+    index_id: OID,
+    key: Vec<u8>,
+    value: Vec<u8>,
+}
+
+impl WorkerXContract {
+    /// Index rows of every secondary index of `table_id` for the base row
+    /// `key`/`value`. Index keys are built like any other key, so they are in
+    /// the key form of the column collations. Rows with a NULL indexed column
+    /// or a missing indexed JSON path are not indexed.
+    pub(crate) async fn index_rows(
+        &self,
+        desc: &TableDesc,
+        table_id: OID,
+        key: &[u8],
+        value: &[u8],
+    ) -> RS<Vec<IndexRow>> {
+        let mut rows = Vec::new();
+        for schema in self.meta_mgr.list_table_indexes(table_id).await? {
+            let Some(index_on) = schema.index_on() else {
+                continue;
+            };
+            let index_desc = self.meta_mgr.get_table_by_id(schema.id()).await?;
+            if let Some(row) = index_row(desc, &index_desc, index_on, key, value)? {
+                rows.push(row);
+            }
+        }
+        Ok(rows)
+    }
+
+    pub(crate) async fn put_index_rows(&self, rows: Vec<IndexRow>, tx_mgr: &dyn TxMgr) -> RS<()> {
+        for row in rows {
+            self.storage
+                .put_on_partition(row.index_id, None, row.key, row.value, tx_mgr)
+                .await?;
+        }
+        Ok(())
+    }
+
+    pub(crate) async fn remove_index_rows(&self, rows: &[IndexRow], tx_mgr: &dyn TxMgr) -> RS<()> {
+        for row in rows {
+            self.storage
+                .remove_on_partition(row.index_id, None, &row.key, tx_mgr)
+                .await?;
+        }
+        Ok(())
+    }
+
+    /// Replaces the index rows of a base row whose value changed from `old`
+    /// to `new`; index rows whose key did not change are kept.
+    pub(crate) async fn update_index_rows(
+        &self,
+        old: Vec<IndexRow>,
+        new: Vec<IndexRow>,
+        tx_mgr: &dyn TxMgr,
+    ) -> RS<()> {
+        let unchanged = |row: &IndexRow, others: &[IndexRow]| {
+            others
+                .iter()
+                .any(|other| other.index_id == row.index_id && other.key == row.key)
+        };
+        let removed = old
+            .iter()
+            .filter(|row| !unchanged(row, &new))
+            .collect::<Vec<_>>();
+        for row in removed {
+            self.storage
+                .remove_on_partition(row.index_id, None, &row.key, tx_mgr)
+                .await?;
+        }
+        let added = new
+            .into_iter()
+            .filter(|row| !unchanged(row, &old))
+            .collect();
+        self.put_index_rows(added, tx_mgr).await
+    }
+}
+
+fn index_row(
+    desc: &TableDesc,
+    index_desc: &TableDesc,
+    index_on: &SchemaIndex,
+    key: &[u8],
+    value: &[u8],
+) -> RS<Option<IndexRow>> {
+    let select = VecSelTerm::new(index_on.source_attrs.clone());
+    let fields = project_selected_fields(desc, key, value, &select)?;
+    let Some(index_key) = index_on.index_key(desc, fields)? else {
+        return Ok(None);
+    };
+    Ok(Some(IndexRow {
+        index_id: index_desc.id(),
+        key: build_key_tuple(&VecDatum::new(index_key), index_desc)?,
+        value: build_value_tuple(&VecDatum::new(Vec::new()), index_desc)?,
+    }))
+}
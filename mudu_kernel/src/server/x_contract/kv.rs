@@ -1,22 +1,60 @@
 use super::utils::{single_delete_batch, single_put_batch};
 use super::*;
+use crate::server::kv_ttl;
+
+const COMMIT_LOCK_RETRY_INTERVAL: Duration = Duration::from_millis(1);
 
 impl WorkerXContract {
     pub fn worker_begin_tx(&self) -> RS<Arc<dyn TxMgr>> {
-        Ok(Arc::new(WorkerTxManager::new(
-            self.snapshot_mgr.begin_tx()?,
-        )))
+        self.worker_begin_tx_with_options(TxOptions::default())
+    }
+
+    pub fn worker_begin_tx_with_options(&self, options: TxOptions) -> RS<Arc<dyn TxMgr>> {
+        let snapshot = self.snapshot_mgr.begin_tx()?;
+        if options.isolation_level == IsolationLevel::Serializable {
+            self.ssi_mgr.begin(snapshot.xid())?;
+        }
+        Ok(Arc::new(WorkerTxManager::with_options(snapshot, options)))
     }
 
     pub fn worker_rollback_tx(&self, tx_mgr: Arc<dyn TxMgr>) -> RS<()> {
+        self.ssi_mgr.finish(tx_mgr.xid())?;
         self.snapshot_mgr.end_tx(tx_mgr.xid())
     }
 
+    /// Runs the SSI commit check for serializable transactions.
+    pub(crate) fn validate_serializable_commit(&self, tx: &dyn TxMgr) -> RS<()> {
+        if tx.isolation_level() != IsolationLevel::Serializable {
+            return Ok(());
+        }
+        self.ssi_mgr
+            .validate_commit(tx.xid(), tx.read_set(), &tx.write_ops())
+    }
+
     pub async fn worker_put_async(&self, key: Vec<u8>, value: Vec<u8>) -> RS<()> {
+        self.worker_put_ttl_async(key, value, None).await
+    }
+
+    /// Autocommit put of a value that expires `ttl_ms` after the commit.
+    pub async fn worker_put_with_ttl_async(
+        &self,
+        key: Vec<u8>,
+        value: Vec<u8>,
+        ttl_ms: u64,
+    ) -> RS<()> {
+        self.worker_put_ttl_async(key, value, Some(ttl_ms)).await
+    }
+
+    async fn worker_put_ttl_async(
+        &self,
+        key: Vec<u8>,
+        value: Vec<u8>,
+        ttl_ms: Option<u64>,
+    ) -> RS<()> {
         let trace = task_trace!();
         trace.watch("put.stage", "contract_worker_put_start");
         let (storage, log, prepared) = {
-            let xid = self.snapshot_mgr.alloc_committed_ts();
+            let xid = self.snapshot_mgr.alloc_committed_ts()?;
             trace.watch("put.xid", &xid.to_string());
             (
                 self.storage.clone(),
@@ -25,10 +63,12 @@ impl WorkerXContract {
                     xid,
                     key.clone(),
                     Some(value.clone()),
-                    single_put_batch(xid, key, value),
+                    single_put_batch(xid, key, value, ttl_ms),
                 ),
             )
         };
+        // Held until the commit is applied, see `CommitTickets`.
+        let _ticket = self.enter_commit(log.as_ref())?;
         if let Some(log) = log {
             trace.watch("put.stage", "contract_worker_put_wal_append_start");
             new_xl_batch_writer(log).append(prepared.batch()).await?;
@@ -41,7 +81,7 @@ impl WorkerXContract {
     pub async fn worker_delete_async(&self, key: &[u8]) -> RS<()> {
         let key = key.to_vec();
         let (storage, log, prepared) = {
-            let xid = self.snapshot_mgr.alloc_committed_ts();
+            let xid = self.snapshot_mgr.alloc_committed_ts()?;
             (
                 self.storage.clone(),
                 self.log_cloned()?,
@@ -53,12 +93,108 @@ impl WorkerXContract {
                 ),
             )
         };
+        let _ticket = self.enter_commit(log.as_ref())?;
         if let Some(log) = log {
             new_xl_batch_writer(log).append(prepared.batch()).await?;
         }
         storage.apply_prepared_commit_async(prepared).await
     }
 
+    /// Reads `key` and writes what `update` derives from its latest
+    /// committed value as one autocommit write. The key's commit lock is held
+    /// from the read until the write is applied, so neither a transaction
+    /// nor another atomic update commits the key in between.
+    pub async fn worker_kv_update_async<T, F>(
+        &self,
+        key: Vec<u8>,
+        lock_timeout: Option<Duration>,
+        update: F,
+    ) -> RS<T>
+    where
+        F: FnOnce(Option<Vec<u8>>) -> RS<(Option<Vec<u8>>, T)>,
+    {
+        let xid = self.snapshot_mgr.alloc_committed_ts()?;
+        let write_ops = vec![(PhysicalRelationId::WORKER_KV, key.clone())];
+        if !self
+            .acquire_commit_locks(xid, &write_ops, lock_timeout)
+            .await?
+        {
+            return Err(mudu_error!(
+                ErrorCode::Transaction,
+                format!("atomic update {} failed to acquire the key lock", xid)
+            ));
+        }
+        let result = async {
+            let current = self.storage.kv_get(&key, None).await?;
+            let (write, output) = update(current)?;
+            let Some(value) = write else {
+                return Ok(output);
+            };
+            let prepared = self.storage.prepare_worker_kv_autocommit(
+                xid,
+                key.clone(),
+                Some(value.clone()),
+                single_put_batch(xid, key, value, None),
+            );
+            let log = self.log_cloned()?;
+            let _ticket = self.enter_commit(log.as_ref())?;
+            if let Some(log) = log {
+                new_xl_batch_writer(log).append(prepared.batch()).await?;
+            }
+            self.storage.apply_prepared_commit_async(prepared).await?;
+            Ok(output)
+        }
+        .await;
+        self.tx_lock.release(xid as OID, &write_ops)?;
+        result
+    }
+
+    /// Runs one TTL sweeper step: deletes the expired keys among at most
+    /// `max_rows` kv rows and returns how many it deleted.
+    ///
+    /// Each delete is an ordinary logged commit taken under the key lock, so
+    /// recovery and replicas replay it; keys rewritten since they expired, or
+    /// locked by a committing transaction, are left to a later step. A replica
+    /// only applies the deletes of its primary.
+    pub async fn sweep_expired_kv_async(&self, max_rows: usize) -> RS<usize> {
+        if self.is_replica() {
+            return Ok(0);
+        }
+        let now_ms = kv_ttl::now_ms();
+        let mut deleted = 0;
+        for key in self.storage.expired_kv_keys_step(now_ms, max_rows)? {
+            let xid = self.snapshot_mgr.alloc_committed_ts()?;
+            let write_ops = vec![(PhysicalRelationId::WORKER_KV, key.clone())];
+            if !self.acquire_commit_locks(xid, &write_ops, None).await? {
+                continue;
+            }
+            let result: RS<bool> = async {
+                if !self.storage.kv_expired(&key, now_ms) {
+                    return Ok(false);
+                }
+                let prepared = self.storage.prepare_worker_kv_autocommit(
+                    xid,
+                    key.clone(),
+                    None,
+                    single_delete_batch(xid, key.clone()),
+                );
+                let log = self.log_cloned()?;
+                let _ticket = self.enter_commit(log.as_ref())?;
+                if let Some(log) = log {
+                    new_xl_batch_writer(log).append(prepared.batch()).await?;
+                }
+                self.storage.apply_prepared_commit_async(prepared).await?;
+                Ok(true)
+            }
+            .await;
+            self.tx_lock.release(xid as OID, &write_ops)?;
+            if result? {
+                deleted += 1;
+            }
+        }
+        Ok(deleted)
+    }
+
     pub async fn worker_get_async(&self, key: &[u8]) -> RS<Option<Vec<u8>>> {
         self.storage.kv_get(key, None).await
     }
@@ -71,12 +207,18 @@ impl WorkerXContract {
         self.storage.kv_get(key, Some(snapshot)).await
     }
 
+    /// Scans `[start_key, end_key)` in the direction given by `reverse`,
+    /// stopping after `limit` visible items.
     pub async fn worker_range_scan_async(
         &self,
         start_key: &[u8],
         end_key: &[u8],
+        reverse: bool,
+        limit: Option<usize>,
     ) -> RS<Vec<KvItem>> {
-        self.storage.kv_range(start_key, end_key, None).await
+        self.storage
+            .kv_range(start_key, end_key, None, reverse, limit)
+            .await
     }
 
     pub async fn worker_range_scan_with_snapshot_async(
@@ -84,9 +226,11 @@ impl WorkerXContract {
         snapshot: &WorkerSnapshot,
         start_key: &[u8],
         end_key: &[u8],
+        reverse: bool,
+        limit: Option<usize>,
     ) -> RS<Vec<KvItem>> {
         self.storage
-            .kv_range(start_key, end_key, Some(snapshot))
+            .kv_range(start_key, end_key, Some(snapshot), reverse, limit)
             .await
     }
 
@@ -111,6 +255,7 @@ impl WorkerXContract {
                 .await?;
             (self.storage.clone(), self.log_cloned()?, prepared)
         };
+        let ticket = self.enter_commit(log.as_ref())?;
         if let Some(log) = log {
             new_xl_batch_writer(log.clone())
                 .append(prepared.batch())
@@ -118,6 +263,7 @@ impl WorkerXContract {
             log.flush_async().await?;
         }
         storage.apply_prepared_commit_async(prepared).await?;
+        drop(ticket);
         self.snapshot_mgr.end_tx(xid)
     }
 
@@ -131,47 +277,58 @@ impl WorkerXContract {
         _t.watch("procedure.worker_commit.xid", &xid.to_string());
         _t.watch("procedure.worker_commit.stage", "is_empty_check");
         if tx.is_empty() {
+            // Read-only serializable transactions still take part in SSI
+            // validation: they can be the reader side of a dangerous structure.
             _t.watch("procedure.worker_commit.stage", "rollback_empty_tx");
-            return self.worker_rollback_tx(tx);
+            let validated = self.validate_serializable_commit(tx.as_ref());
+            self.worker_rollback_tx(tx)?;
+            return validated;
         }
         _t.watch("procedure.worker_commit.stage", "build_write_ops");
         tx.build_write_ops();
-        let (storage, log, prepared) = {
-            let write_ops = tx.write_ops();
-            _t.watch("procedure.worker_commit.stage", "tx_lock_try_lock");
-            let can_commit = self.tx_lock.try_lock_some(xid as OID, &write_ops)?;
-            if !can_commit {
-                _t.watch("procedure.worker_commit.stage", "tx_lock_failed");
-                return Err(mudu_error!(
-                    ErrorCode::Transaction,
-                    format!("transaction {} failed to acquire commit locks", xid)
-                ));
-            }
+        let write_ops = tx.write_ops();
+        _t.watch("procedure.worker_commit.stage", "tx_lock_try_lock");
+        let can_commit = self
+            .acquire_commit_locks(xid, &write_ops, tx.options().lock_timeout)
+            .await?;
+        if !can_commit {
+            _t.watch("procedure.worker_commit.stage", "tx_lock_failed");
+            self.worker_rollback_tx(tx)?;
+            return Err(mudu_error!(
+                ErrorCode::Transaction,
+                format!("transaction {} failed to acquire commit locks", xid)
+            ));
+        }
+        trace!("log flush {}", xid);
+        let result = async {
             _t.watch("procedure.worker_commit.stage", "prepare_commit_start");
             let prepared = self.storage.prepare_commit_async(tx.as_ref()).await?;
             _t.watch("procedure.worker_commit.stage", "prepare_commit_done");
-            (self.storage.clone(), self.log_cloned()?, prepared)
-        };
-        trace!("log flush {}", xid);
-        let result = async {
+            _t.watch("procedure.worker_commit.stage", "ssi_validate");
+            self.validate_serializable_commit(tx.as_ref())?;
+            let log = self.log_cloned()?;
+            let _ticket = self.enter_commit(log.as_ref())?;
             if let Some(log) = log {
                 _t.watch("procedure.worker_execute.stage", "wal_append_start");
                 new_xl_batch_writer(log.clone())
                     .append(prepared.batch())
                     .await?;
                 _t.watch("procedure.worker_execute.stage", "wal_append_done");
-                _t.watch("procedure.worker_execute.stage", "wal_flush_start");
-                log.flush_async().await?;
-                _t.watch("procedure.worker_execute.stage", "wal_flush_done");
+                if tx.options().durability == DurabilityMode::Sync {
+                    _t.watch("procedure.worker_execute.stage", "wal_flush_start");
+                    log.flush_async().await?;
+                    _t.watch("procedure.worker_execute.stage", "wal_flush_done");
+                } else {
+                    self.async_commits_unflushed.store(true, Ordering::Release);
+                }
             }
             _t.watch("procedure.worker_execute.stage", "storage_apply_start");
-            storage.apply_prepared_commit_async(prepared).await?;
+            self.storage.apply_prepared_commit_async(prepared).await?;
             _t.watch("procedure.worker_execute.stage", "storage_apply_done");
             Ok(())
         }
         .await;
         trace!("log flush done {}", xid);
-        let write_ops = tx.write_ops();
         _t.watch("procedure.worker_commit.stage", "tx_lock_release");
         self.tx_lock.release(xid as OID, &write_ops)?;
         _t.watch("procedure.worker_commit.stage", "rollback_tx_cleanup");
@@ -181,6 +338,48 @@ impl WorkerXContract {
         result
     }
 
+    /// Fsyncs the worker log when a commit with asynchronous durability
+    /// wrote to it since the last call. The worker loop calls this every
+    /// async commit flush interval, which bounds how long such a commit
+    /// stays unflushed. Returns whether the log was flushed.
+    pub async fn flush_async_commits_async(&self) -> RS<bool> {
+        if !self.async_commits_unflushed.swap(false, Ordering::AcqRel) {
+            return Ok(false);
+        }
+        let Some(log) = self.log_cloned()? else {
+            return Ok(false);
+        };
+        if let Err(err) = log.flush_async().await {
+            self.async_commits_unflushed.store(true, Ordering::Release);
+            return Err(err);
+        }
+        Ok(true)
+    }
+
+    /// Takes the commit locks for `write_ops`, retrying until `lock_timeout`
+    /// elapses.
+    async fn acquire_commit_locks(
+        &self,
+        xid: u64,
+        write_ops: &[(PhysicalRelationId, Vec<u8>)],
+        lock_timeout: Option<Duration>,
+    ) -> RS<bool> {
+        let deadline = lock_timeout.map(|timeout| *mudu_sys::time::instant_now() + timeout);
+        loop {
+            if self.tx_lock.try_lock_some(xid as OID, write_ops)? {
+                return Ok(true);
+            }
+            let Some(deadline) = deadline else {
+                return Ok(false);
+            };
+            if *mudu_sys::time::instant_now() >= deadline {
+                return Ok(false);
+            }
+            // Sleeps on the worker ring's timers on the io_uring worker loop.
+            mudu_sys::sleep(COMMIT_LOCK_RETRY_INTERVAL).await?;
+        }
+    }
+
     pub async fn replay_worker_log_batch(&self, batch: XLBatch) -> RS<()> {
         let max_xid = batch.entries.iter().map(|entry| entry.xid).max();
         if let Some(max_xid) = max_xid {
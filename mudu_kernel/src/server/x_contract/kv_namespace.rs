@@ -0,0 +1,367 @@
+//! KV namespaces: named key spaces of one app, each stored as a catalog
+//! table of its own with a binary key and value column. A namespace bound to
+//! a partition rule routes every key by its bytes, see
+//! [`PartitionRouter::route_kv_key`]. Like SQL writes, reads and writes of a
+//! partition owned by another worker go over partition RPC and commit there.
+//! Routing and partition RPC carry the raw key bytes; only the storage calls
+//! of the owning worker wrap them into the table's key tuples.
+
+use super::utils::kv_range_bounds;
+use super::*;
+use crate::contract::partition_rule::PartitionRuleDesc;
+use crate::contract::partition_rule_binding::TablePartitionBinding;
+use crate::contract::schema_column::SchemaColumn;
+use crate::server::partition_router::ensure_kv_partition_rule;
+use mudu_contract::database::kv_namespace::KvNamespace;
+use mudu_contract::tuple::build_tuple::build_tuple;
+use mudu_type::dat_type_id::DatTypeID;
+use mudu_type::dat_value::DatValue;
+use mudu_type::dt_info::DTInfo;
+
+impl WorkerXContract {
+    /// Creates `namespace`. With `partition_rule` its keys are spread over
+    /// the rule's partitions; the rule must have a single string key.
+    pub async fn kv_namespace_create_async(
+        &self,
+        namespace: &KvNamespace,
+        partition_rule: Option<&str>,
+    ) -> RS<()> {
+        self.ensure_writable()?;
+        namespace.validate()?;
+        let relation_name = namespace.relation_name();
+        if self
+            .meta_mgr
+            .get_table_by_name(&relation_name)
+            .await?
+            .is_some()
+        {
+            return Err(mudu_error!(
+                ErrorCode::EntityAlreadyExists,
+                format!(
+                    "kv namespace {} of app {} already exists",
+                    namespace.name(),
+                    namespace.app_name()
+                )
+            ));
+        }
+        let rule = match partition_rule {
+            Some(rule_name) => {
+                let rule = self
+                    .meta_mgr
+                    .get_partition_rule_by_name(rule_name)
+                    .await?
+                    .ok_or_else(|| {
+                        mudu_error!(
+                            ErrorCode::EntityNotFound,
+                            format!("no such partition rule {}", rule_name)
+                        )
+                    })?;
+                ensure_kv_partition_rule(&rule)?;
+                if rule.partitions.is_empty() {
+                    return Err(mudu_error!(
+                        ErrorCode::InvalidArgument,
+                        format!("partition rule {} has no partitions", rule_name)
+                    ));
+                }
+                Some(rule)
+            }
+            None => None,
+        };
+        let schema = kv_namespace_schema(relation_name);
+        self.storage.create_table_async(&schema).await?;
+        if let Some(rule) = rule {
+            self.meta_mgr
+                .bind_table_partition(&TablePartitionBinding {
+                    table_id: schema.id(),
+                    rule_id: rule.oid,
+                    ref_attr_indices: vec![0],
+                })
+                .await?;
+        }
+        Ok(())
+    }
+
+    /// Drops `namespace` together with every key stored in it.
+    pub async fn kv_namespace_drop_async(&self, namespace: &KvNamespace) -> RS<()> {
+        self.ensure_writable()?;
+        let table_id = self.kv_namespace_table_id(namespace).await?;
+        self.storage.drop_table_async(table_id).await
+    }
+
+    /// Lists the namespaces of `app_name` in name order.
+    pub async fn kv_namespace_list_async(&self, app_name: &str) -> RS<Vec<KvNamespace>> {
+        let mut namespaces = self
+            .meta_mgr
+            .list_schemas()
+            .await?
+            .iter()
+            .filter_map(|schema| KvNamespace::from_relation_name(schema.table_name()))
+            .filter(|namespace| namespace.app_name() == app_name)
+            .collect::<Vec<_>>();
+        namespaces.sort();
+        Ok(namespaces)
+    }
+
+    pub(crate) async fn kv_namespace_get_async(
+        &self,
+        tx_mgr: &dyn TxMgr,
+        namespace: &KvNamespace,
+        key: &[u8],
+    ) -> RS<Option<Vec<u8>>> {
+        let table_id = self.kv_namespace_table_id(namespace).await?;
+        let partition_id = self.kv_namespace_partition(table_id, key).await?;
+        match self.kv_namespace_remote_worker(partition_id).await? {
+            Some(worker_id) => {
+                self.remote_kv_get(worker_id, table_id, partition_id, key.to_vec())
+                    .await
+            }
+            None => {
+                self.kv_namespace_local_get(tx_mgr, table_id, partition_id, key)
+                    .await
+            }
+        }
+    }
+
+    pub(crate) async fn kv_namespace_put_async(
+        &self,
+        tx_mgr: &dyn TxMgr,
+        namespace: &KvNamespace,
+        key: Vec<u8>,
+        value: Vec<u8>,
+    ) -> RS<()> {
+        let table_id = self.kv_namespace_table_id(namespace).await?;
+        let partition_id = self.kv_namespace_partition(table_id, &key).await?;
+        match self.kv_namespace_remote_worker(partition_id).await? {
+            Some(worker_id) => {
+                self.remote_kv_put(worker_id, table_id, partition_id, key, value)
+                    .await
+            }
+            None => {
+                self.kv_namespace_local_put(tx_mgr, table_id, partition_id, &key, value)
+                    .await
+            }
+        }
+    }
+
+    pub(crate) async fn kv_namespace_delete_async(
+        &self,
+        tx_mgr: &dyn TxMgr,
+        namespace: &KvNamespace,
+        key: &[u8],
+    ) -> RS<()> {
+        let table_id = self.kv_namespace_table_id(namespace).await?;
+        let partition_id = self.kv_namespace_partition(table_id, key).await?;
+        let key = self.kv_namespace_key_tuple(table_id, key).await?;
+        match self.kv_namespace_remote_worker(partition_id).await? {
+            Some(worker_id) => self
+                .remote_delete(worker_id, table_id, partition_id, key)
+                .await
+                .map(|_| ()),
+            None => self
+                .storage
+                .remove_on_partition(table_id, Some(partition_id), &key, tx_mgr)
+                .await
+                .map(|_| ()),
+        }
+    }
+
+    /// Reads the items of `namespace` in `[start_key, end_key)` in key
+    /// order; an empty end key is unbounded.
+    pub(crate) async fn kv_namespace_range_async(
+        &self,
+        tx_mgr: &dyn TxMgr,
+        namespace: &KvNamespace,
+        start_key: &[u8],
+        end_key: &[u8],
+    ) -> RS<Vec<(Vec<u8>, Vec<u8>)>> {
+        let table_id = self.kv_namespace_table_id(namespace).await?;
+        let partitions = match self.kv_namespace_rule(table_id).await? {
+            Some(rule) => self
+                .partition_router
+                .route_kv_range(&rule, start_key, end_key)?,
+            None => vec![DEFAULT_UNPARTITIONED_TABLE_PARTITION_ID],
+        };
+        let mut items = Vec::new();
+        for partition_id in partitions {
+            let partition_items = match self.kv_namespace_remote_worker(partition_id).await? {
+                Some(worker_id) => {
+                    self.remote_kv_range(
+                        worker_id,
+                        table_id,
+                        partition_id,
+                        start_key.to_vec(),
+                        end_key.to_vec(),
+                    )
+                    .await?
+                }
+                None => {
+                    self.kv_namespace_local_range(
+                        tx_mgr,
+                        table_id,
+                        partition_id,
+                        start_key,
+                        end_key,
+                    )
+                    .await?
+                }
+            };
+            items.extend(partition_items);
+        }
+        items.sort_by(|left, right| left.0.cmp(&right.0));
+        Ok(items)
+    }
+
+    /// Reads `key` of the namespace table `table_id` from a partition of
+    /// this worker.
+    pub(crate) async fn kv_namespace_local_get(
+        &self,
+        tx_mgr: &dyn TxMgr,
+        table_id: OID,
+        partition_id: OID,
+        key: &[u8],
+    ) -> RS<Option<Vec<u8>>> {
+        let key = self.kv_namespace_key_tuple(table_id, key).await?;
+        self.storage
+            .get_on_partition(table_id, Some(partition_id), &key, tx_mgr)
+            .await
+    }
+
+    /// Stages `key` of the namespace table `table_id` on a partition of
+    /// this worker.
+    pub(crate) async fn kv_namespace_local_put(
+        &self,
+        tx_mgr: &dyn TxMgr,
+        table_id: OID,
+        partition_id: OID,
+        key: &[u8],
+        value: Vec<u8>,
+    ) -> RS<()> {
+        let key = self.kv_namespace_key_tuple(table_id, key).await?;
+        self.storage
+            .put_on_partition(table_id, Some(partition_id), key, value, tx_mgr)
+            .await
+    }
+
+    /// Reads the raw keys in `[start_key, end_key)` of the namespace table
+    /// `table_id` from a partition of this worker, in key order.
+    pub(crate) async fn kv_namespace_local_range(
+        &self,
+        tx_mgr: &dyn TxMgr,
+        table_id: OID,
+        partition_id: OID,
+        start_key: &[u8],
+        end_key: &[u8],
+    ) -> RS<Vec<(Vec<u8>, Vec<u8>)>> {
+        let desc = self.meta_mgr.get_table_by_id(table_id).await?;
+        let start_tuple = kv_key_tuple(&desc, start_key)?;
+        let end_tuple = if end_key.is_empty() {
+            Vec::new()
+        } else {
+            kv_key_tuple(&desc, end_key)?
+        };
+        let tuples = self
+            .storage
+            .range_on_partition(
+                table_id,
+                Some(partition_id),
+                kv_range_bounds(&start_tuple, &end_tuple),
+                tx_mgr,
+            )
+            .await?;
+        // Staged writes are merged in tuple byte order, which is not the
+        // order of the raw keys, so bound and sort once more on those.
+        let mut items = Vec::with_capacity(tuples.len());
+        for (tuple, value) in tuples {
+            let key = kv_key_bytes(&desc, &tuple)?;
+            if key.as_slice() >= start_key && (end_key.is_empty() || key.as_slice() < end_key) {
+                items.push((key, value));
+            }
+        }
+        items.sort_by(|left, right| left.0.cmp(&right.0));
+        Ok(items)
+    }
+
+    async fn kv_namespace_key_tuple(&self, table_id: OID, key: &[u8]) -> RS<Vec<u8>> {
+        let desc = self.meta_mgr.get_table_by_id(table_id).await?;
+        kv_key_tuple(&desc, key)
+    }
+
+    async fn kv_namespace_table_id(&self, namespace: &KvNamespace) -> RS<OID> {
+        namespace.validate()?;
+        self.meta_mgr
+            .get_table_by_name(&namespace.relation_name())
+            .await?
+            .map(|desc| desc.id())
+            .ok_or_else(|| {
+                mudu_error!(
+                    ErrorCode::EntityNotFound,
+                    format!(
+                        "no such kv namespace {} of app {}",
+                        namespace.name(),
+                        namespace.app_name()
+                    )
+                )
+            })
+    }
+
+    async fn kv_namespace_rule(&self, table_id: OID) -> RS<Option<PartitionRuleDesc>> {
+        match self.meta_mgr.get_table_partition_binding(table_id).await? {
+            Some(binding) => Ok(Some(
+                self.meta_mgr
+                    .get_partition_rule_by_id(binding.rule_id)
+                    .await?,
+            )),
+            None => Ok(None),
+        }
+    }
+
+    async fn kv_namespace_partition(&self, table_id: OID, key: &[u8]) -> RS<OID> {
+        match self.kv_namespace_rule(table_id).await? {
+            Some(rule) => self.partition_router.route_kv_key(&rule, key),
+            None => Ok(DEFAULT_UNPARTITIONED_TABLE_PARTITION_ID),
+        }
+    }
+
+    /// The worker owning `partition_id` when it is not this one.
+    async fn kv_namespace_remote_worker(&self, partition_id: OID) -> RS<Option<OID>> {
+        match self.resolve_partition_worker(partition_id).await? {
+            Some(worker_id) if self.worker_id != 0 && worker_id != self.worker_id => {
+                Ok(Some(worker_id))
+            }
+            _ => Ok(None),
+        }
+    }
+}
+
+/// Wraps a raw namespace key into a key tuple of the namespace table.
+fn kv_key_tuple(desc: &TableDesc, key: &[u8]) -> RS<Vec<u8>> {
+    let key_desc = desc.key_desc();
+    let ty = key_desc.get_field_desc(0).type_obj();
+    let binary = DatTypeID::Binary.fn_send()(&DatValue::from_binary(key.to_vec()), ty)
+        .map_err(|e| e.to_m_err())?;
+    build_tuple(&[binary.into()], key_desc)
+}
+
+/// The raw namespace key of a key tuple built by [`kv_key_tuple`].
+fn kv_key_bytes(desc: &TableDesc, tuple: &[u8]) -> RS<Vec<u8>> {
+    let field = desc.key_desc().get_field_desc(0);
+    let (value, _) = DatTypeID::Binary.fn_recv()(field.get(tuple)?, field.type_obj())
+        .map_err(|e| e.to_m_err())?;
+    Ok(value.into_binary())
+}
+
+fn kv_namespace_schema(relation_name: String) -> SchemaTable {
+    let column = |name: &str| {
+        SchemaColumn::new(
+            name.to_string(),
+            DatTypeID::Binary,
+            DTInfo::from_text(DatTypeID::Binary, String::new()),
+        )
+    };
+    SchemaTable::new(
+        relation_name,
+        vec![column("k"), column("v")],
+        vec![0],
+        vec![1],
+    )
+}
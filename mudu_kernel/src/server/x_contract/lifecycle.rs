@@ -35,6 +35,7 @@ impl WorkerXContract {
                 return Ok(());
             }
         }
+        self.prepare_recovery_target_async().await?;
         let log = match self.async_runtime.as_ref() {
             Some(runtime_io) => {
                 // When an io_uring runtime is configured the caller must have
@@ -56,6 +57,9 @@ impl WorkerXContract {
             }
         };
         mudu_sys::scoped_task_trace!();
+        self.restore_checkpoint_async(&log).await?;
+        self.check_recovery_target()?;
+        self.load_cdc_positions_async(&log).await?;
         let mut guard = self.log.lock()?;
         *guard = Some(log);
         Ok(())
@@ -101,6 +105,7 @@ impl WorkerXContract {
             partition_id,
             data_dir,
             async_runtime.clone(),
+            log_layout.keyring().cloned(),
         ));
         storage.register_global()?;
         Ok(Self {
@@ -116,7 +121,15 @@ impl WorkerXContract {
             active_sessions,
             async_runtime,
             snapshot_mgr: WorkerSnapshotMgr::default(),
+            ssi_mgr: SsiMgr::new(),
             tx_lock: XLockMgr::new(),
+            commit_tickets: CommitTickets::default(),
+            checkpoint: SMutex::new(CheckpointSchedule::default()),
+            checkpoint_gate: FMutex::new(()),
+            recovery_start_lsn: AtomicU64::new(0),
+            recovery_target: SMutex::new(RecoveryTarget::default()),
+            replica: AtomicBool::new(false),
+            async_commits_unflushed: AtomicBool::new(false),
         })
     }
 
@@ -164,12 +177,13 @@ impl WorkerXContract {
             async_runtime,
             server_instance_id,
         } = config;
-        let meta_mgr =
-            MetaMgrFactory::create_with_async_runtime(data_dir.clone(), async_runtime.clone())
-                .await
-                .map_err(|e| {
-                    mudu_error!(ErrorCode::Database, "create worker meta manager failed", e)
-                })?;
+        let meta_mgr = MetaMgrFactory::create_with_async_runtime(
+            data_dir.clone(),
+            async_runtime.clone(),
+            log_layout.keyring().cloned(),
+        )
+        .await
+        .map_err(|e| mudu_error!(ErrorCode::Database, "create worker meta manager failed", e))?;
         let worker = Self::with_log_and_data_dir(WorkerXContractParams {
             meta_mgr,
             log,
@@ -202,6 +216,34 @@ impl WorkerXContract {
             })
     }
 
+    /// Caps the relation pages this worker keeps cached.
+    pub fn set_buffer_pool_bytes(&self, capacity_bytes: u64) {
+        self.storage.set_buffer_pool_bytes(capacity_bytes);
+    }
+
+    pub fn buffer_pool_stats(&self) -> RS<BufferPoolStats> {
+        self.storage.buffer_pool_stats()
+    }
+
+    /// Sets the page codec of tables created or opened from now on that do
+    /// not choose one with `WITH (compression = ...)`.
+    pub fn set_page_compression(&self, compression: Compression) -> RS<()> {
+        self.storage.set_page_compression(compression)
+    }
+
+    pub fn page_compression_stats(&self) -> CompressionStatsSnapshot {
+        self.storage.page_compression_stats()
+    }
+
+    /// Compression of this worker's log frames; zero bytes before the log is
+    /// initialized.
+    pub fn wal_compression_stats(&self) -> RS<CompressionStatsSnapshot> {
+        Ok(self
+            .log_cloned()?
+            .map(|log| log.compression_stats())
+            .unwrap_or_default())
+    }
+
     pub fn worker_log(&self) -> RS<Option<ChunkedWorkerLogBackend>> {
         self.log_cloned()
     }
@@ -214,6 +256,10 @@ impl WorkerXContract {
         self.async_runtime.clone()
     }
 
+    pub fn data_dir(&self) -> &str {
+        self.storage.relation_path()
+    }
+
     pub(crate) async fn resolve_partition_worker(&self, partition_id: OID) -> RS<Option<OID>> {
         match self.meta_mgr.get_partition_worker(partition_id).await? {
             Some(worker_id) => Ok(Some(worker_id)),
@@ -224,6 +270,17 @@ impl WorkerXContract {
             None => Ok(None),
         }
     }
+
+    /// Relation partitions whose storage this worker owns and writes.
+    pub(crate) async fn owned_relation_partitions_async(&self) -> RS<BTreeSet<OID>> {
+        let mut partitions = BTreeSet::new();
+        for partition_id in self.storage.relation_partition_ids() {
+            if self.resolve_partition_worker(partition_id).await? == Some(self.worker_id) {
+                partitions.insert(partition_id);
+            }
+        }
+        Ok(partitions)
+    }
 }
 
 fn default_worker_storage_data_dir() -> String {
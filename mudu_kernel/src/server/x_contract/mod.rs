@@ -8,11 +8,12 @@ pub(crate) use mudu_contract::tuple::build_tuple::build_tuple;
 pub(crate) use mudu_contract::tuple::nullable_tuple::{NullableValue, TupleBuilder};
 pub(crate) use mudu_contract::tuple::tuple_binary::TupleBinary as TupleRaw;
 pub(crate) use mudu_contract::tuple::update_tuple::update_tuple;
+pub(crate) use mudu_sys::sync::async_::futures_mutex::FMutex;
 pub(crate) use mudu_sys::sync::SMutex;
 pub(crate) use mudu_utils::{gen_oid, scoped_task_trace, task_trace};
 pub(crate) use std::collections::{BTreeMap, BTreeSet};
 pub(crate) use std::ops::Bound;
-pub(crate) use std::sync::atomic::{AtomicBool, AtomicUsize, Ordering};
+pub(crate) use std::sync::atomic::{AtomicBool, AtomicU64, AtomicUsize, Ordering};
 pub(crate) use std::sync::Arc;
 pub(crate) use std::time::Duration;
 pub(crate) use tracing::{debug, trace};
@@ -31,19 +32,26 @@ pub(crate) use crate::server::partition_router::{
 pub(crate) use crate::server::partition_rpc::{
     PartitionRpcRequest, PartitionRpcResponse, RpcBound,
 };
+pub(crate) use crate::server::ssi_mgr::SsiMgr;
+pub(crate) use crate::server::worker_checkpoint::{CheckpointSchedule, CommitTickets};
+pub(crate) use crate::server::worker_recovery_target::RecoveryTarget;
 pub(crate) use crate::server::worker_snapshot::{KvItem, WorkerSnapshot, WorkerSnapshotMgr};
 pub(crate) use crate::server::worker_storage::WorkerStorage;
 pub(crate) use crate::server::worker_tx_manager::WorkerTxManager;
 pub(crate) use crate::server::x_lock_mgr::XLockMgr;
+pub(crate) use crate::storage::buffer_pool::BufferPoolStats;
+pub(crate) use crate::storage::compression::{Compression, CompressionStatsSnapshot};
 pub(crate) use crate::wal::worker_log::{ChunkedWorkerLogBackend, WorkerLogLayout};
 pub(crate) use crate::wal::xl_batch::{new_xl_batch_writer, XLBatch};
 pub(crate) use crate::wal::xl_data_op::{XLDelete, XLInsert, XLWrite};
 pub(crate) use crate::wal::xl_entry::{TxOp, XLEntry};
 pub(crate) use crate::x_engine::api::{
-    AlterTable, Filter, OptDelete, OptInsert, OptRead, OptUpdate, Predicate, RSCursor, RangeData,
-    TupleRow, VecDatum, VecSelTerm, XContract,
+    AlterTable, AsOf, Filter, OptDelete, OptInsert, OptRead, OptUpdate, Predicate, RSCursor,
+    RangeData, TupleRow, VecDatum, VecSelTerm, XContract,
+};
+pub(crate) use crate::x_engine::tx_mgr::{
+    DurabilityMode, IsolationLevel, PhysicalRelationId, TxMgr, TxOptions,
 };
-pub(crate) use crate::x_engine::tx_mgr::TxMgr;
 pub(crate) use mudu_sys::contract::async_io_provider::AsyncIoProvider;
 
 pub(crate) type DatBin = Buf;
@@ -67,10 +75,50 @@ pub struct WorkerXContract {
     /// provider but the backend performs steady-state I/O via this provider.
     async_runtime: Option<Arc<dyn AsyncIoProvider>>,
     snapshot_mgr: WorkerSnapshotMgr,
+    ssi_mgr: SsiMgr,
     tx_lock: XLockMgr,
+    commit_tickets: CommitTickets,
+    checkpoint: SMutex<CheckpointSchedule>,
+    // Held by a checkpoint and by a backup for their whole run, so the
+    // checkpoint a backup copies is not superseded while it copies.
+    checkpoint_gate: FMutex<()>,
+    // Worker log entries below this LSN are covered by the restored
+    // checkpoint.
+    recovery_start_lsn: AtomicU64,
+    // Where recovery stops replaying the worker log; unset outside of a
+    // point-in-time recovery.
+    recovery_target: SMutex<RecoveryTarget>,
+    // Set on a read replica until it is promoted; commits are rejected.
+    replica: AtomicBool,
+    // Set when a commit with asynchronous durability wrote to the worker log
+    // after its last fsync.
+    async_commits_unflushed: AtomicBool,
     // commit_gate: AsyncMutex<()>,
 }
 
+/// Snapshot a relation read runs against.
+#[derive(Clone, Copy)]
+pub(crate) enum ReadSource<'a> {
+    /// The transaction snapshot together with its staged writes.
+    Tx(&'a dyn TxMgr),
+    /// A pinned `AS OF SYSTEM TIME` snapshot; staged writes are not visible.
+    AsOf(&'a WorkerSnapshot),
+}
+
+impl ReadSource<'_> {
+    /// Commit timestamps are allocated per worker, so an `AS OF SYSTEM TIME`
+    /// timestamp only has a meaning for partitions owned by the reading worker.
+    pub(crate) fn ensure_local(&self) -> RS<()> {
+        match self {
+            ReadSource::Tx(_) => Ok(()),
+            ReadSource::AsOf(_) => Err(mudu_error!(
+                ErrorCode::NotImplemented,
+                "AS OF SYSTEM TIME reads are not implemented for remote partitions"
+            )),
+        }
+    }
+}
+
 pub struct VecCursor {
     inner: SMutex<VecCursorInner>,
 }
@@ -84,15 +132,24 @@ pub struct VecCursorInner {
 /// io_uring-only contract.
 pub type IoUringXContract = WorkerXContract;
 
+pub(crate) mod backup;
+pub(crate) mod cdc;
+pub(crate) mod checkpoint;
 pub(crate) mod cursor;
+pub(crate) mod index;
 pub(crate) mod kv;
+pub(crate) mod kv_namespace;
 pub(crate) mod lifecycle;
 pub(crate) mod ops;
 pub(crate) mod params;
+pub(crate) mod recovery_target;
+pub(crate) mod reencrypt;
+pub(crate) mod replication;
 pub(crate) mod rpc;
 #[allow(clippy::unwrap_used, clippy::expect_used, clippy::panic)]
 pub(crate) mod tests;
 pub(crate) mod trait_impl;
 pub(crate) mod utils;
+pub(crate) mod version_gc;
 
 pub use params::{WorkerXContractParams, WorkerXContractWorkerLogParams};
@@ -3,9 +3,7 @@ use super::*;
 
 impl WorkerXContract {
     pub(crate) fn _begin_tx(&self) -> RS<Arc<dyn TxMgr>> {
-        Ok(Arc::new(WorkerTxManager::new(
-            self.snapshot_mgr.begin_tx()?,
-        )))
+        self.worker_begin_tx()
     }
 
     pub(crate) async fn _insert(
@@ -72,16 +70,18 @@ impl WorkerXContract {
                 target_partition = ?target_partition,
                 "insert writing key locally"
             );
+            let index_rows = self.index_rows(&desc, table_id, &key, &value).await?;
             self.storage
                 .put_on_partition(table_id, target_partition, key, value, tx_mgr.as_ref())
-                .await
+                .await?;
+            self.put_index_rows(index_rows, tx_mgr.as_ref()).await
         }
     }
 
     pub(crate) async fn _read_key(
         &self,
         desc: Arc<TableDesc>,
-        tx_mgr: Arc<dyn TxMgr>,
+        source: ReadSource<'_>,
         table_id: OID,
         pred_key: &VecDatum,
         select: &VecSelTerm,
@@ -94,6 +94,7 @@ impl WorkerXContract {
         let opt_value = match target_partition {
             Some(partition_id) => match self.resolve_partition_worker(partition_id).await? {
                 Some(worker_id) if self.worker_id != 0 && worker_id != self.worker_id => {
+                    source.ensure_local()?;
                     self.remote_read_key(
                         worker_id,
                         table_id,
@@ -105,8 +106,7 @@ impl WorkerXContract {
                 }
                 _ => {
                     let result = self
-                        .storage
-                        .get_on_partition(table_id, Some(partition_id), &key, tx_mgr.as_ref())
+                        .read_local_key(table_id, Some(partition_id), &key, source)
                         .await?;
                     result
                         .map(|value| project_selected_fields(&desc, &key, &value, select))
@@ -114,10 +114,7 @@ impl WorkerXContract {
                 }
             },
             None => {
-                let result = self
-                    .storage
-                    .get_on_partition(table_id, None, &key, tx_mgr.as_ref())
-                    .await?;
+                let result = self.read_local_key(table_id, None, &key, source).await?;
                 result
                     .map(|value| project_selected_fields(&desc, &key, &value, select))
                     .transpose()?
@@ -132,7 +129,7 @@ impl WorkerXContract {
     pub(crate) async fn _read_range(
         &self,
         desc: Arc<TableDesc>,
-        tx_mgr: Arc<dyn TxMgr>,
+        source: ReadSource<'_>,
         table_id: OID,
         pred_key: &RangeData,
         pred_non_key: &Predicate,
@@ -151,6 +148,7 @@ impl WorkerXContract {
                 for partition_id in partitions {
                     match self.resolve_partition_worker(partition_id).await? {
                         Some(worker_id) if self.worker_id != 0 && worker_id != self.worker_id => {
+                            source.ensure_local()?;
                             if matches!(pred_non_key, Predicate::KeyPrefixEq(_)) {
                                 return Err(mudu_error!(
                                     ErrorCode::NotImplemented,
@@ -173,12 +171,11 @@ impl WorkerXContract {
                         }
                         _ => {
                             let rows = self
-                                .storage
-                                .range_on_partition(
+                                .read_local_range(
                                     table_id,
                                     Some(partition_id),
                                     (bound_key_as_ref(&start), bound_key_as_ref(&end)),
-                                    tx_mgr.as_ref(),
+                                    source,
                                 )
                                 .await?;
                             for (key, value) in rows {
@@ -195,11 +192,11 @@ impl WorkerXContract {
             }
             None => {
                 let rows = self
-                    .storage
-                    .range(
+                    .read_local_range(
                         table_id,
+                        None,
                         (bound_key_as_ref(&start), bound_key_as_ref(&end)),
-                        tx_mgr.as_ref(),
+                        source,
                     )
                     .await?;
                 for (key, value) in rows {
@@ -220,6 +217,48 @@ impl WorkerXContract {
         }))
     }
 
+    async fn read_local_key(
+        &self,
+        table_id: OID,
+        partition_id: Option<OID>,
+        key: &[u8],
+        source: ReadSource<'_>,
+    ) -> RS<Option<Vec<u8>>> {
+        match source {
+            ReadSource::Tx(tx_mgr) => {
+                self.storage
+                    .get_on_partition(table_id, partition_id, key, tx_mgr)
+                    .await
+            }
+            ReadSource::AsOf(snapshot) => {
+                self.storage
+                    .get_as_of_on_partition(table_id, partition_id, key, snapshot)
+                    .await
+            }
+        }
+    }
+
+    async fn read_local_range(
+        &self,
+        table_id: OID,
+        partition_id: Option<OID>,
+        bounds: (Bound<&[u8]>, Bound<&[u8]>),
+        source: ReadSource<'_>,
+    ) -> RS<Vec<(Vec<u8>, Vec<u8>)>> {
+        match source {
+            ReadSource::Tx(tx_mgr) => {
+                self.storage
+                    .range_on_partition(table_id, partition_id, bounds, tx_mgr)
+                    .await
+            }
+            ReadSource::AsOf(snapshot) => {
+                self.storage
+                    .range_as_of_on_partition(table_id, partition_id, bounds, snapshot)
+                    .await
+            }
+        }
+    }
+
     pub(crate) async fn _delete(
         &self,
         desc: Arc<TableDesc>,
@@ -249,6 +288,10 @@ impl WorkerXContract {
             .storage
             .remove_on_partition(table_id, target_partition, &key, tx_mgr.as_ref())
             .await?;
+        if let Some(value) = &deleted {
+            let index_rows = self.index_rows(&desc, table_id, &key, value).await?;
+            self.remove_index_rows(&index_rows, tx_mgr.as_ref()).await?;
+        }
         Ok(usize::from(deleted.is_some()))
     }
 
@@ -291,8 +334,12 @@ impl WorkerXContract {
             return Ok(0);
         };
         let updated = apply_value_update(&current, values, &desc)?;
+        let old_index_rows = self.index_rows(&desc, table_id, &key, &current).await?;
+        let new_index_rows = self.index_rows(&desc, table_id, &key, &updated).await?;
         self.storage
             .put_on_partition(table_id, target_partition, key, updated, tx_mgr.as_ref())
+            .await?;
+        self.update_index_rows(old_index_rows, new_index_rows, tx_mgr.as_ref())
             .await
             .map(|()| 1)
     }
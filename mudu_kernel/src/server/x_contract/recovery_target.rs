@@ -0,0 +1,113 @@
+use super::*;
+use crate::storage::backup::write_synced;
+use crate::wal::lsn::LSN;
+use mudu_sys::contract::async_fs::AsyncFs;
+use tracing::{info, warn};
+
+impl WorkerXContract {
+    /// Makes the next worker log recovery stop at `target`; set before
+    /// [`Self::initialize`].
+    pub fn set_recovery_target(&self, target: RecoveryTarget) -> RS<()> {
+        *self.recovery_target.lock()? = target;
+        Ok(())
+    }
+
+    fn log_fs(&self) -> Arc<dyn AsyncFs> {
+        match self.async_runtime.as_ref() {
+            Some(runtime_io) => runtime_io.fs_arc(),
+            None => mudu_sys::default_sys_io_context().fs(),
+        }
+    }
+
+    /// Runs before the worker log is opened. Drops a recovery target that an
+    /// earlier start already reached, and otherwise copies the archived
+    /// chunks back into the log directory, so that recovery replays them.
+    pub(crate) async fn prepare_recovery_target_async(&self) -> RS<()> {
+        let target = *self.recovery_target.lock()?;
+        if !target.is_set() {
+            return Ok(());
+        }
+        let fs = self.log_fs();
+        let marker = self.log_layout.recovery_target_marker_path();
+        if fs.path_exists(&marker).await? {
+            warn!(
+                worker_id = self.worker_id,
+                marker = %marker.display(),
+                "recovery target already reached, recovering the whole log"
+            );
+            *self.recovery_target.lock()? = RecoveryTarget::default();
+            return Ok(());
+        }
+        let restored = self
+            .log_layout
+            .restore_archived_chunks_async(fs.as_ref())
+            .await?;
+        debug!(
+            worker_id = self.worker_id,
+            restored_chunks = restored,
+            "archived worker log chunks restored"
+        );
+        Ok(())
+    }
+
+    /// Rejects a target LSN below the restored checkpoint: the checkpoint
+    /// already contains the entries after it.
+    pub(crate) fn check_recovery_target(&self) -> RS<()> {
+        let target = *self.recovery_target.lock()?;
+        let start_lsn = self.log_recovery_start_lsn().as_u64();
+        match target.lsn {
+            Some(lsn) if lsn < start_lsn => Err(mudu_error!(
+                ErrorCode::InvalidArgument,
+                format!(
+                    "recovery target lsn {} of worker {} is before its checkpoint at lsn {}",
+                    lsn, self.worker_id, start_lsn
+                )
+            )),
+            _ => Ok(()),
+        }
+    }
+
+    /// Replays the worker log entry starting at `start_lsn`, minus what lies
+    /// past the recovery target.
+    pub async fn replay_worker_log_batch_at(&self, batch: XLBatch, start_lsn: LSN) -> RS<()> {
+        let target = *self.recovery_target.lock()?;
+        let batch = if target.is_set() {
+            match target.retain(batch, start_lsn) {
+                Some(batch) => batch,
+                None => return Ok(()),
+            }
+        } else {
+            batch
+        };
+        self.replay_worker_log_batch(batch).await
+    }
+
+    /// Seals a point-in-time recovery after replay: a checkpoint at the end
+    /// of the log keeps the entries past the target from being replayed
+    /// again, and a marker makes later starts ignore the target.
+    pub async fn finish_recovery_target_async(&self) -> RS<()> {
+        let target = *self.recovery_target.lock()?;
+        if !target.is_set() {
+            return Ok(());
+        }
+        let Some(stats) = self.checkpoint_async().await? else {
+            return Ok(());
+        };
+        let fs = self.log_fs();
+        write_synced(
+            fs.as_ref(),
+            &self.log_layout.recovery_target_marker_path(),
+            stats.lsn.as_u64().to_string().as_bytes(),
+        )
+        .await?;
+        *self.recovery_target.lock()? = RecoveryTarget::default();
+        info!(
+            worker_id = self.worker_id,
+            target_lsn = ?target.lsn,
+            target_commit_ts = ?target.commit_ts,
+            checkpoint_lsn = stats.lsn.as_u64(),
+            "point-in-time recovery finished"
+        );
+        Ok(())
+    }
+}
@@ -0,0 +1,20 @@
+use super::*;
+use crate::storage::encryption::ReencryptStats;
+
+impl WorkerXContract {
+    /// Runs one background re-encryption step on the partitions this worker
+    /// owns, rewriting at most `max_pages` relation pages that are not sealed
+    /// with the active key.
+    pub async fn reencrypt_step_async(&self, max_pages: u64) -> RS<ReencryptStats> {
+        if self.log_layout.keyring().is_none() {
+            return Ok(ReencryptStats {
+                done: true,
+                ..Default::default()
+            });
+        }
+        let partitions = self.owned_relation_partitions_async().await?;
+        self.storage
+            .reencrypt_step_async(&partitions, max_pages)
+            .await
+    }
+}
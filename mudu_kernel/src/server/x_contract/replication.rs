@@ -0,0 +1,81 @@
+use super::*;
+use crate::wal::log_frame::{decode_entries_with_pending, frame_lsn, last_frame_lsn};
+use crate::wal::lsn::LSN;
+use crate::wal::worker_log::{decode_frames, WorkerLogBackend};
+
+impl WorkerXContract {
+    /// Makes the contract reject commits while it serves a read replica.
+    pub fn set_replica(&self, replica: bool) {
+        self.replica.store(replica, Ordering::Release);
+    }
+
+    pub fn is_replica(&self) -> bool {
+        self.replica.load(Ordering::Acquire)
+    }
+
+    pub(crate) fn ensure_writable(&self) -> RS<()> {
+        if self.is_replica() {
+            return Err(mudu_error!(
+                ErrorCode::ReadOnlyReplica,
+                format!(
+                    "worker {} serves a read replica and accepts no writes",
+                    self.worker_id
+                )
+            ));
+        }
+        Ok(())
+    }
+
+    /// Appends log frames shipped from the primary to the worker log, at the
+    /// LSNs they carry, and returns the entries they hold for replay.
+    ///
+    /// The frames must continue the log exactly and end at an entry
+    /// boundary.
+    pub(crate) async fn append_shipped_frames_async(
+        &self,
+        shipped: &[u8],
+    ) -> RS<Vec<(LSN, XLBatch)>> {
+        let Some(log) = self.log_cloned()? else {
+            return Err(mudu_error!(
+                ErrorCode::InvalidState,
+                "a read replica needs the worker log"
+            ));
+        };
+        let frames = decode_frames(shipped)?;
+        let Some(first) = frames.first() else {
+            return Ok(Vec::new());
+        };
+        let first_lsn = frame_lsn(first)?;
+        let log_lsn = log.position()?.lsn;
+        if first_lsn != log_lsn {
+            return Err(mudu_error!(
+                ErrorCode::InvalidState,
+                format!(
+                    "shipped frames start at lsn {} but the log of worker {} ends at lsn {}",
+                    first_lsn.as_u64(),
+                    self.worker_id,
+                    log_lsn.as_u64()
+                )
+            ));
+        }
+        let next_lsn = last_frame_lsn(&frames)?.saturating_add(1);
+        let mut pending_frames = Vec::new();
+        let mut pending_start_lsn = None;
+        let entries = decode_entries_with_pending::<XLBatch>(
+            log.keyring(),
+            &frames,
+            &mut pending_frames,
+            &mut pending_start_lsn,
+        )?;
+        if !pending_frames.is_empty() {
+            return Err(mudu_error!(
+                ErrorCode::Decode,
+                "shipped frames end inside a log entry"
+            ));
+        }
+        log.append_frames_async(frames).await?;
+        log.skip_to_lsn(next_lsn);
+        log.flush_async().await?;
+        Ok(entries)
+    }
+}
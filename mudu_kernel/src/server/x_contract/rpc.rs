@@ -209,6 +209,68 @@ impl WorkerXContract {
                 self.worker_commit_tx_async(tx_mgr).await?;
                 Ok(PartitionRpcResponse::Update(1))
             }
+            PartitionRpcRequest::KvGet {
+                table_id,
+                partition_id,
+                key,
+            } => {
+                debug!(
+                    worker_id = self.worker_id,
+                    table_id,
+                    partition_id,
+                    key_len = key.len(),
+                    "execute partition rpc kv_get"
+                );
+                let tx_mgr = self.worker_begin_tx()?;
+                let value = self
+                    .kv_namespace_local_get(tx_mgr.as_ref(), table_id, partition_id, &key)
+                    .await?;
+                self.worker_rollback_tx(tx_mgr)?;
+                Ok(PartitionRpcResponse::KvGet(value))
+            }
+            PartitionRpcRequest::KvPut {
+                table_id,
+                partition_id,
+                key,
+                value,
+            } => {
+                debug!(
+                    worker_id = self.worker_id,
+                    table_id,
+                    partition_id,
+                    key_len = key.len(),
+                    value_len = value.len(),
+                    "execute partition rpc kv_put"
+                );
+                let tx_mgr = self.worker_begin_tx()?;
+                self.kv_namespace_local_put(tx_mgr.as_ref(), table_id, partition_id, &key, value)
+                    .await?;
+                self.worker_commit_tx_async(tx_mgr).await?;
+                Ok(PartitionRpcResponse::KvPut)
+            }
+            PartitionRpcRequest::KvRange {
+                table_id,
+                partition_id,
+                start_key,
+                end_key,
+            } => {
+                debug!(
+                    worker_id = self.worker_id,
+                    table_id, partition_id, "execute partition rpc kv_range"
+                );
+                let tx_mgr = self.worker_begin_tx()?;
+                let items = self
+                    .kv_namespace_local_range(
+                        tx_mgr.as_ref(),
+                        table_id,
+                        partition_id,
+                        &start_key,
+                        &end_key,
+                    )
+                    .await?;
+                self.worker_rollback_tx(tx_mgr)?;
+                Ok(PartitionRpcResponse::KvRange(items))
+            }
             PartitionRpcRequest::ApplyCrossPartitionTx {
                 tx_id,
                 coordinator_worker_id: _,
@@ -432,6 +494,91 @@ impl WorkerXContract {
         }
     }
 
+    pub(crate) async fn remote_kv_get(
+        &self,
+        target_worker_id: OID,
+        table_id: OID,
+        partition_id: OID,
+        key: Vec<u8>,
+    ) -> RS<Option<Vec<u8>>> {
+        match self
+            .send_partition_rpc(
+                target_worker_id,
+                PartitionRpcRequest::KvGet {
+                    table_id,
+                    partition_id,
+                    key,
+                },
+            )
+            .await?
+        {
+            PartitionRpcResponse::KvGet(value) => Ok(value),
+            PartitionRpcResponse::Err(err) => Err(mudu_error!(ErrorCode::Internal, err)),
+            _ => Err(mudu_error!(
+                ErrorCode::Internal,
+                "unexpected kv_get rpc response"
+            )),
+        }
+    }
+
+    pub(crate) async fn remote_kv_put(
+        &self,
+        target_worker_id: OID,
+        table_id: OID,
+        partition_id: OID,
+        key: Vec<u8>,
+        value: Vec<u8>,
+    ) -> RS<()> {
+        match self
+            .send_partition_rpc(
+                target_worker_id,
+                PartitionRpcRequest::KvPut {
+                    table_id,
+                    partition_id,
+                    key,
+                    value,
+                },
+            )
+            .await?
+        {
+            PartitionRpcResponse::KvPut => Ok(()),
+            PartitionRpcResponse::Err(err) => Err(mudu_error!(ErrorCode::Internal, err)),
+            _ => Err(mudu_error!(
+                ErrorCode::Internal,
+                "unexpected kv_put rpc response"
+            )),
+        }
+    }
+
+    pub(crate) async fn remote_kv_range(
+        &self,
+        target_worker_id: OID,
+        table_id: OID,
+        partition_id: OID,
+        start_key: Vec<u8>,
+        end_key: Vec<u8>,
+    ) -> RS<Vec<(Vec<u8>, Vec<u8>)>> {
+        match self
+            .send_partition_rpc(
+                target_worker_id,
+                PartitionRpcRequest::KvRange {
+                    table_id,
+                    partition_id,
+                    start_key,
+                    end_key,
+                },
+            )
+            .await?
+        {
+            PartitionRpcResponse::KvRange(items) => Ok(items),
+            PartitionRpcResponse::Err(err) => Err(mudu_error!(ErrorCode::Internal, err)),
+            _ => Err(mudu_error!(
+                ErrorCode::Internal,
+                "unexpected kv_range rpc response"
+            )),
+        }
+    }
+
     async fn remote_apply_cross_partition_tx(
         &self,
         target_worker_id: OID,
@@ -479,11 +626,15 @@ impl WorkerXContract {
 
         let result = async {
             let _prepared = self.storage.prepare_commit_async(tx.as_ref()).await?;
+            self.validate_serializable_commit(tx.as_ref())?;
             let (participants, write_set) = self.build_cross_partition_tx_ops(tx.as_ref()).await?;
-            if let Some(log) = self.log_cloned()? {
+            let log = self.log_cloned()?;
+            let _ticket = self.enter_commit(log.as_ref())?;
+            if let Some(log) = log {
                 let batch = XLBatch::new(vec![XLEntry {
                     xid,
                     ops: cross_partition_wal_ops(&write_set),
+                    commit_ms: 0,
                 }]);
                 new_xl_batch_writer(log.clone()).append(&batch).await?;
                 log.flush_async().await?;
@@ -518,6 +669,7 @@ impl WorkerXContract {
                         tuple_id: 0,
                         key,
                         value,
+                        ttl_ms: None,
                     })),
                     None => write_set.push(XLWrite::Delete(XLDelete {
                         table_id: relation_id.table_id,
@@ -587,6 +739,7 @@ mod tests {
     use crate::server::test_meta_mgr::TestMetaMgr;
     use crate::x_engine::tx_mgr::PhysicalRelationId;
     use async_trait::async_trait;
+    use mudu_contract::database::kv_namespace::KvNamespace;
     use mudu_sys::env_var::temp_dir;
     use mudu_sys::sync::SMutex;
     use mudu_type::dat_type_id::DatTypeID;
@@ -680,6 +833,7 @@ mod tests {
                 tuple_id: 0,
                 key: b"a".to_vec(),
                 value: b"1".to_vec(),
+                ttl_ms: None,
             }),
             XLWrite::Delete(XLDelete {
                 table_id: 2,
@@ -705,6 +859,7 @@ mod tests {
                 tuple_id: 0,
                 key: b"a".to_vec(),
                 value: b"1".to_vec(),
+                ttl_ms: None,
             }),
             XLWrite::Insert(XLInsert {
                 table_id: 2,
@@ -712,6 +867,7 @@ mod tests {
                 tuple_id: 0,
                 key: b"b".to_vec(),
                 value: b"2".to_vec(),
+                ttl_ms: None,
             }),
             XLWrite::Delete(XLDelete {
                 table_id: 3,
@@ -772,6 +928,60 @@ mod tests {
         );
     }
 
+    #[tokio::test(flavor = "current_thread")]
+    async fn execute_partition_rpc_kv_put_get_and_range() {
+        let contract = make_contract().await;
+        let namespace = KvNamespace::new("app", "ns").unwrap();
+        contract
+            .kv_namespace_create_async(&namespace, None)
+            .await
+            .unwrap();
+        let table_id = contract
+            .meta_mgr
+            .get_table_by_name(&namespace.relation_name())
+            .await
+            .unwrap()
+            .unwrap()
+            .id();
+
+        for (key, value) in [(b"a", b"1"), (b"b", b"2"), (b"c", b"3")] {
+            let put = PartitionRpcRequest::KvPut {
+                table_id,
+                partition_id: 0,
+                key: key.to_vec(),
+                value: value.to_vec(),
+            };
+            assert_eq!(
+                contract.execute_partition_rpc(put).await.unwrap(),
+                PartitionRpcResponse::KvPut
+            );
+        }
+
+        let get = PartitionRpcRequest::KvGet {
+            table_id,
+            partition_id: 0,
+            key: b"b".to_vec(),
+        };
+        assert_eq!(
+            contract.execute_partition_rpc(get).await.unwrap(),
+            PartitionRpcResponse::KvGet(Some(b"2".to_vec()))
+        );
+
+        let range = PartitionRpcRequest::KvRange {
+            table_id,
+            partition_id: 0,
+            start_key: b"b".to_vec(),
+            end_key: Vec::new(),
+        };
+        assert_eq!(
+            contract.execute_partition_rpc(range).await.unwrap(),
+            PartitionRpcResponse::KvRange(vec![
+                (b"b".to_vec(), b"2".to_vec()),
+                (b"c".to_vec(), b"3".to_vec()),
+            ])
+        );
+    }
+
     #[tokio::test(flavor = "current_thread")]
     async fn execute_partition_rpc_insert_duplicate_returns_entity_already_exists() {
         let contract = make_contract().await;
@@ -925,6 +1135,7 @@ mod tests {
                 tuple_id: 0,
                 key,
                 value,
+                ttl_ms: None,
             })],
         };
         assert_eq!(
@@ -1340,6 +1551,7 @@ mod tests {
             tuple_id: 0,
             key: key.clone(),
             value,
+            ttl_ms: None,
         })];
 
         contract
@@ -1399,6 +1611,7 @@ mod tests {
             tuple_id: 0,
             key: b"rk".to_vec(),
             value: b"rv".to_vec(),
+            ttl_ms: None,
         })];
 
         contract
@@ -10,12 +10,18 @@ mod tests {
 
     use super::super::utils::{build_key_tuple, build_value_tuple};
     use super::super::*;
+    use crate::contract::partition_rule::{PartitionBound, PartitionRuleDesc, RangePartitionDef};
     use crate::contract::schema_column::SchemaColumn;
     use crate::contract::table_info::TableInfo;
+    use crate::server::kv_ttl;
     use crate::server::test_meta_mgr::TestMetaMgr;
-    use crate::wal::worker_log::{decode_frames, ChunkedWorkerLogBackend, WorkerLogLayout};
+    use crate::wal::worker_log::{
+        decode_frames, retain_frames_from, ChunkedWorkerLogBackend, WorkerLogBackend,
+        WorkerLogLayout,
+    };
     use crate::wal::xl_data_op::XLInsert;
     use crate::wal::xl_entry::TxOp;
+    use mudu_contract::database::kv_namespace::KvNamespace;
     use mudu_sys::env_var::temp_dir;
     use mudu_type::dat_type_id::DatTypeID;
     use mudu_type::dt_fn_param::DatType;
@@ -236,6 +242,7 @@ mod tests {
                     tuple_id: 0,
                     key: b"wk".to_vec(),
                     value: b"wv".to_vec(),
+                    ttl_ms: None,
                 })),
                 TxOp::Write(XLWrite::Insert(XLInsert {
                     table_id,
@@ -244,9 +251,11 @@ mod tests {
                     key: build_key_tuple(&key_row(3), &meta_table(&schema).unwrap()).unwrap(),
                     value: build_value_tuple(&value_row(30), &meta_table(&schema).unwrap())
                         .unwrap(),
+                    ttl_ms: None,
                 })),
                 TxOp::Commit,
             ],
+            commit_ms: 0,
         }]);
 
         contract.replay_worker_log_batch(batch).await.unwrap();
@@ -314,9 +323,11 @@ mod tests {
                         .unwrap(),
                     value: build_value_tuple(&value_row(80), &meta_table(&test_schema()).unwrap())
                         .unwrap(),
+                    ttl_ms: None,
                 })),
                 TxOp::Commit,
             ],
+            commit_ms: 0,
         }]);
 
         contract.replay_worker_log_batch(batch).await?;
@@ -403,6 +414,168 @@ mod tests {
         assert!(!tuple.is_empty());
     }
 
+    #[test]
+    fn xcontract_checkpoint_restores_kv_and_truncates_log() {
+        block_on(async move {
+            let layout = WorkerLogLayout::new(
+                temp_dir().join(format!("xcontract_checkpoint_{}", gen_oid())),
+                gen_oid(),
+                1024,
+            )?;
+            let contract = WorkerXContract::with_log_inner(
+                Arc::new(TestMetaMgr::new()),
+                None,
+                layout.clone(),
+                Default::default(),
+            )?;
+            contract.initialize().await?;
+            for i in 0..40u32 {
+                contract
+                    .worker_put_async(i.to_be_bytes().to_vec(), vec![i as u8; 64])
+                    .await?;
+            }
+            contract.worker_delete_async(&0u32.to_be_bytes()).await?;
+            let log = contract.worker_log()?.unwrap();
+            log.flush_async().await?;
+            let stats = contract.checkpoint_async().await?.unwrap();
+            assert!(stats.removed_chunks > 0);
+            contract
+                .worker_put_async(b"after".to_vec(), b"checkpoint".to_vec())
+                .await?;
+            log.flush_async().await?;
+            drop(contract);
+
+            let restored = WorkerXContract::with_log_inner(
+                Arc::new(TestMetaMgr::new()),
+                None,
+                layout.clone(),
+                Default::default(),
+            )?;
+            restored.initialize().await?;
+            assert_eq!(restored.log_recovery_start_lsn(), stats.lsn);
+            let log = restored.worker_log()?.unwrap();
+            for path in log.chunk_paths_sorted().await? {
+                let mut frames = decode_frames(&mudu_sys::fs::sync::read(path).unwrap())?;
+                retain_frames_from(&mut frames, restored.log_recovery_start_lsn())?;
+                for batch in crate::wal::xl_batch::decode_xl_batches(&frames)? {
+                    restored.replay_worker_log_batch(batch).await?;
+                }
+            }
+            assert_eq!(restored.worker_get_async(&0u32.to_be_bytes()).await?, None);
+            assert_eq!(
+                restored.worker_get_async(&39u32.to_be_bytes()).await?,
+                Some(vec![39; 64])
+            );
+            assert_eq!(
+                restored.worker_get_async(b"after").await?,
+                Some(b"checkpoint".to_vec())
+            );
+            // New commits continue after the entries of the removed chunks.
+            restored
+                .worker_put_async(b"next".to_vec(), b"commit".to_vec())
+                .await?;
+            assert!(log.position()?.lsn > stats.lsn);
+            Ok::<(), mudu::error::MuduError>(())
+        })
+        .unwrap()
+    }
+
+    #[test]
+    fn xcontract_kv_ttl_expires_and_is_swept() {
+        block_on(async move {
+            let layout = WorkerLogLayout::new(
+                temp_dir().join(format!("xcontract_kv_ttl_{}", gen_oid())),
+                gen_oid(),
+                4096,
+            )?;
+            let contract = WorkerXContract::with_log_inner(
+                Arc::new(TestMetaMgr::new()),
+                None,
+                layout.clone(),
+                Default::default(),
+            )?;
+            contract.initialize().await?;
+            contract
+                .worker_put_with_ttl_async(b"a".to_vec(), b"short".to_vec(), 20)
+                .await?;
+            contract
+                .worker_put_with_ttl_async(b"b".to_vec(), b"long".to_vec(), 3_600_000)
+                .await?;
+            contract
+                .worker_put_async(b"c".to_vec(), b"plain".to_vec())
+                .await?;
+            assert_eq!(
+                contract.worker_get_async(b"a").await?,
+                Some(b"short".to_vec())
+            );
+
+            mudu_sys::task::sync::sleep_blocking(Duration::from_millis(40));
+            assert_eq!(contract.worker_get_async(b"a").await?, None);
+            let items = contract
+                .worker_range_scan_async(b"", b"", false, None)
+                .await?;
+            assert_eq!(
+                items
+                    .into_iter()
+                    .map(|item| (item.key, item.value))
+                    .collect::<Vec<_>>(),
+                vec![
+                    (b"b".to_vec(), b"long".to_vec()),
+                    (b"c".to_vec(), b"plain".to_vec())
+                ]
+            );
+            assert_eq!(contract.sweep_expired_kv_async(16).await?, 1);
+            assert_eq!(contract.sweep_expired_kv_async(16).await?, 0);
+            let log = contract.worker_log()?.unwrap();
+            log.flush_async().await?;
+            drop(contract);
+
+            // The log keeps the value as written; the TTL and the commit time
+            // the deadline counts from travel beside it.
+            let mut logged = None;
+            for path in log.chunk_paths_sorted().await? {
+                let frames = decode_frames(&mudu_sys::fs::sync::read(path).unwrap())?;
+                for batch in crate::wal::xl_batch::decode_xl_batches(&frames)? {
+                    for entry in batch.entries {
+                        for op in entry.ops {
+                            if let TxOp::Write(XLWrite::Insert(insert)) = op {
+                                if insert.key == b"a" {
+                                    logged = Some((entry.commit_ms, insert.value, insert.ttl_ms));
+                                }
+                            }
+                        }
+                    }
+                }
+            }
+            let (commit_ms, value, ttl_ms) = logged.unwrap();
+            assert_eq!((value, ttl_ms), (b"short".to_vec(), Some(20)));
+            assert!(commit_ms > 0);
+            assert!(kv_ttl::deadline(commit_ms, 20) <= kv_ttl::now_ms());
+
+            let restored = WorkerXContract::with_log_inner(
+                Arc::new(TestMetaMgr::new()),
+                None,
+                layout,
+                Default::default(),
+            )?;
+            restored.initialize().await?;
+            for path in log.chunk_paths_sorted().await? {
+                let frames = decode_frames(&mudu_sys::fs::sync::read(path).unwrap())?;
+                for batch in crate::wal::xl_batch::decode_xl_batches(&frames)? {
+                    restored.replay_worker_log_batch(batch).await?;
+                }
+            }
+            assert_eq!(restored.worker_get_async(b"a").await?, None);
+            assert_eq!(
+                restored.worker_get_async(b"b").await?,
+                Some(b"long".to_vec())
+            );
+            assert_eq!(restored.sweep_expired_kv_async(16).await?, 0);
+            Ok::<(), mudu::error::MuduError>(())
+        })
+        .unwrap()
+    }
+
     #[test]
     fn iouring_xcontract_replay_applies_worker_kv_delete() {
         block_on(async move { _iouring_xcontract_replay_applies_worker_kv_delete().await })
@@ -442,6 +615,7 @@ mod tests {
                 )),
                 TxOp::Commit,
             ],
+            commit_ms: 0,
         }]);
 
         contract.replay_worker_log_batch(batch).await.unwrap();
@@ -571,6 +745,84 @@ mod tests {
         .unwrap();
     }
 
+    #[test]
+    fn xcontract_ascii_ci_keys_equal_under_collation_are_one_key() {
+        block_on(async move {
+            use mudu_type::collation::Collation;
+            use mudu_type::dtp_string::DTPString;
+
+            let ty = DatType::from_string(DTPString::new(64).with_collation(Collation::AsciiCi));
+            let schema = SchemaTable::new(
+                "accounts".to_string(),
+                vec![
+                    SchemaColumn::new(
+                        "owner".to_string(),
+                        DatTypeID::String,
+                        DTInfo::from_opt_object(&ty),
+                    ),
+                    SchemaColumn::new(
+                        "balance".to_string(),
+                        DatTypeID::I32,
+                        DTInfo::from_opt_object(&DatType::default_for(DatTypeID::I32)),
+                    ),
+                ],
+                vec![0],
+                vec![1],
+            );
+            let (contract, table_id) = make_contract_with_table(&schema).await?;
+            let owner = |v: &str| {
+                VecDatum::new(vec![(
+                    0,
+                    mudu_type::dt_function::send_binary(
+                        &mudu_type::dat_value::DatValue::from_string(v.to_string()),
+                        &ty,
+                    )
+                    .unwrap(),
+                )])
+            };
+
+            let tx = contract.begin_tx().await?;
+            contract
+                .insert(
+                    tx.clone(),
+                    table_id,
+                    &owner("Alice"),
+                    &value_row(10),
+                    &OptInsert::default(),
+                )
+                .await?;
+            contract.commit_tx(tx).await?;
+
+            let tx = contract.begin_tx().await?;
+            let err = contract
+                .insert(
+                    tx.clone(),
+                    table_id,
+                    &owner("alice"),
+                    &value_row(20),
+                    &OptInsert::default(),
+                )
+                .await
+                .unwrap_err();
+            assert_eq!(err.ec(), ErrorCode::EntityAlreadyExists);
+            contract.abort_tx(tx).await?;
+
+            let tx = contract.begin_tx().await?;
+            let row = contract
+                .read_key(
+                    tx,
+                    table_id,
+                    &owner("ALICE"),
+                    &VecSelTerm::new(vec![1]),
+                    &OptRead::default(),
+                )
+                .await?;
+            assert_eq!(row, Some(vec![Some(datum(10))]));
+            Ok::<(), mudu::error::MuduError>(())
+        })
+        .unwrap();
+    }
+
     #[test]
     fn xcontract_delete_missing_row_returns_zero() {
         block_on(async move {
@@ -594,6 +846,119 @@ mod tests {
         .unwrap();
     }
 
+    #[test]
+    fn xcontract_read_as_of_returns_retained_versions() {
+        block_on(async move {
+            let schema = test_schema();
+            let (contract, table_id) = make_contract_with_table(&schema).await?;
+            contract.set_history_retention(Duration::from_secs(60));
+
+            let insert_tx = contract.begin_tx().await?;
+            let inserted_at = insert_tx.xid();
+            contract
+                .insert(
+                    insert_tx.clone(),
+                    table_id,
+                    &key_row(1),
+                    &value_row(10),
+                    &OptInsert::default(),
+                )
+                .await?;
+            contract.commit_tx(insert_tx).await?;
+
+            let update_tx = contract.begin_tx().await?;
+            contract
+                .update(
+                    update_tx.clone(),
+                    table_id,
+                    &key_row(1),
+                    &Predicate::CNF(vec![]),
+                    &value_row(20),
+                    &OptUpdate {},
+                )
+                .await?;
+            contract.commit_tx(update_tx).await?;
+
+            let read_tx = contract.begin_tx().await?;
+            let select = VecSelTerm::new(vec![1]);
+            let old = contract
+                .read_key(
+                    read_tx.clone(),
+                    table_id,
+                    &key_row(1),
+                    &select,
+                    &OptRead::as_of(inserted_at),
+                )
+                .await?;
+            assert_eq!(old, Some(vec![Some(datum(10))]));
+            let before_insert = contract
+                .read_key(
+                    read_tx.clone(),
+                    table_id,
+                    &key_row(1),
+                    &select,
+                    &OptRead::as_of(inserted_at - 1),
+                )
+                .await?;
+            assert_eq!(before_insert, None);
+            let cursor = contract
+                .read_range(
+                    read_tx.clone(),
+                    table_id,
+                    &RangeData::new(std::ops::Bound::Unbounded, std::ops::Bound::Unbounded),
+                    &Predicate::CNF(Vec::new()),
+                    &select,
+                    &OptRead::as_of(inserted_at),
+                )
+                .await?;
+            assert!(cursor.next().await?.is_some());
+            assert!(cursor.next().await?.is_none());
+
+            // A wall-clock time maps to the latest commit timestamp by then.
+            let now_micros = (kv_ttl::now_ms() * 1_000) as i64;
+            let latest = contract
+                .read_key(
+                    read_tx.clone(),
+                    table_id,
+                    &key_row(1),
+                    &select,
+                    &OptRead::as_of_wall_clock(now_micros),
+                )
+                .await?;
+            assert_eq!(latest, Some(vec![Some(datum(20))]));
+            let err = contract
+                .read_key(
+                    read_tx.clone(),
+                    table_id,
+                    &key_row(1),
+                    &select,
+                    &OptRead::as_of_wall_clock(now_micros + 3_600_000_000),
+                )
+                .await
+                .unwrap_err();
+            assert_eq!(err.ec(), ErrorCode::InvalidArgument);
+            contract.abort_tx(read_tx).await?;
+
+            // Without retention the old version falls below the GC horizon.
+            contract.set_history_retention(Duration::ZERO);
+            let read_tx = contract.begin_tx().await?;
+            let err = contract
+                .read_key(
+                    read_tx.clone(),
+                    table_id,
+                    &key_row(1),
+                    &select,
+                    &OptRead::as_of(inserted_at),
+                )
+                .await
+                .unwrap_err();
+            assert_eq!(err.ec(), ErrorCode::InvalidArgument);
+            contract.abort_tx(read_tx).await?;
+            Ok::<(), mudu::error::MuduError>(())
+        })
+        .unwrap();
+    }
+
     #[test]
     fn xcontract_read_range_returns_matching_rows() {
         block_on(async move {
@@ -685,4 +1050,162 @@ mod tests {
         })
         .unwrap();
     }
+
+    #[test]
+    fn xcontract_kv_namespaces_are_isolated_and_partitioned() {
+        block_on(async move {
+            let meta_mgr = Arc::new(TestMetaMgr::new());
+            let contract = WorkerXContract::with_log(meta_mgr.clone(), None)?;
+            let wallet = KvNamespace::new("wallet", "data")?;
+            let shop = KvNamespace::new("shop", "data")?;
+            contract.kv_namespace_create_async(&wallet, None).await?;
+            contract.kv_namespace_create_async(&shop, None).await?;
+            let err = contract
+                .kv_namespace_create_async(&wallet, None)
+                .await
+                .unwrap_err();
+            assert_eq!(err.ec(), ErrorCode::EntityAlreadyExists);
+
+            let tx = contract.worker_begin_tx()?;
+            contract
+                .kv_namespace_put_async(tx.as_ref(), &wallet, b"k".to_vec(), b"w".to_vec())
+                .await?;
+            contract
+                .kv_namespace_put_async(tx.as_ref(), &shop, b"k".to_vec(), b"s".to_vec())
+                .await?;
+            contract.worker_commit_tx_async(tx).await?;
+            let tx = contract.worker_begin_tx()?;
+            assert_eq!(
+                contract
+                    .kv_namespace_get_async(tx.as_ref(), &wallet, b"k")
+                    .await?,
+                Some(b"w".to_vec())
+            );
+            assert_eq!(
+                contract
+                    .kv_namespace_get_async(tx.as_ref(), &shop, b"k")
+                    .await?,
+                Some(b"s".to_vec())
+            );
+            contract.worker_rollback_tx(tx)?;
+            assert_eq!(contract.worker_get_async(b"k").await?, None);
+
+            let rule = PartitionRuleDesc::new_range(
+                "kv_rule".to_string(),
+                vec![DatTypeID::String],
+                vec![
+                    RangePartitionDef::new(
+                        "low".to_string(),
+                        PartitionBound::Unbounded,
+                        PartitionBound::Value(vec![b"'m'".to_vec()]),
+                    ),
+                    RangePartitionDef::new(
+                        "high".to_string(),
+                        PartitionBound::Value(vec![b"'m'".to_vec()]),
+                        PartitionBound::Unbounded,
+                    ),
+                ],
+            );
+            meta_mgr.create_partition_rule(&rule).await?;
+            let spread = KvNamespace::new("wallet", "spread")?;
+            contract
+                .kv_namespace_create_async(&spread, Some("kv_rule"))
+                .await?;
+            let tx = contract.worker_begin_tx()?;
+            for key in [b"a", b"z", b"m"] {
+                contract
+                    .kv_namespace_put_async(tx.as_ref(), &spread, key.to_vec(), key.to_vec())
+                    .await?;
+            }
+            contract.worker_commit_tx_async(tx).await?;
+
+            let tx = contract.worker_begin_tx()?;
+            let keys = |items: Vec<(Vec<u8>, Vec<u8>)>| {
+                items.into_iter().map(|(key, _)| key).collect::<Vec<_>>()
+            };
+            assert_eq!(
+                keys(
+                    contract
+                        .kv_namespace_range_async(tx.as_ref(), &spread, b"", b"")
+                        .await?
+                ),
+                vec![b"a".to_vec(), b"m".to_vec(), b"z".to_vec()]
+            );
+            assert_eq!(
+                keys(
+                    contract
+                        .kv_namespace_range_async(tx.as_ref(), &spread, b"b", b"n")
+                        .await?
+                ),
+                vec![b"m".to_vec()]
+            );
+            let table_id = meta_mgr
+                .get_table_by_name(&spread.relation_name())
+                .await?
+                .unwrap()
+                .id();
+            let high = rule.partitions[1].partition_id;
+            assert_eq!(
+                contract
+                    .kv_namespace_local_get(tx.as_ref(), table_id, high, b"z")
+                    .await?,
+                Some(b"z".to_vec())
+            );
+            assert_eq!(
+                contract
+                    .kv_namespace_local_get(tx.as_ref(), table_id, high, b"a")
+                    .await?,
+                None
+            );
+            contract.worker_rollback_tx(tx)?;
+
+            let tx = contract.worker_begin_tx()?;
+            contract
+                .kv_namespace_delete_async(tx.as_ref(), &spread, b"z")
+                .await?;
+            contract.worker_commit_tx_async(tx).await?;
+            let tx = contract.worker_begin_tx()?;
+            assert_eq!(
+                contract
+                    .kv_namespace_get_async(tx.as_ref(), &spread, b"z")
+                    .await?,
+                None
+            );
+            contract.worker_rollback_tx(tx)?;
+
+            contract.kv_namespace_drop_async(&wallet).await?;
+            assert_eq!(
+                contract.kv_namespace_list_async("wallet").await?,
+                vec![spread.clone()]
+            );
+            let tx = contract.worker_begin_tx()?;
+            let err = contract
+                .kv_namespace_get_async(tx.as_ref(), &wallet, b"k")
+                .await
+                .unwrap_err();
+            assert_eq!(err.ec(), ErrorCode::EntityNotFound);
+            contract.worker_rollback_tx(tx)?;
+
+            let number_rule = PartitionRuleDesc::new_range(
+                "number_rule".to_string(),
+                vec![DatTypeID::I32],
+                vec![RangePartitionDef::new(
+                    "all".to_string(),
+                    PartitionBound::Unbounded,
+                    PartitionBound::Unbounded,
+                )],
+            );
+            meta_mgr.create_partition_rule(&number_rule).await?;
+            let err = contract
+                .kv_namespace_create_async(
+                    &KvNamespace::new("wallet", "numbers")?,
+                    Some("number_rule"),
+                )
+                .await
+                .unwrap_err();
+            assert_eq!(err.ec(), ErrorCode::InvalidArgument);
+            Ok::<(), mudu::error::MuduError>(())
+        })
+        .unwrap();
+    }
 }
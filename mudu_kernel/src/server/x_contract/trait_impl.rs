@@ -4,10 +4,12 @@ use super::*;
 #[async_trait]
 impl XContract for WorkerXContract {
     async fn create_table(&self, _tx_mgr: Arc<dyn TxMgr>, schema: &SchemaTable) -> RS<()> {
+        self.ensure_writable()?;
         self.storage.create_table_async(schema).await
     }
 
     async fn drop_table(&self, _tx_mgr: Arc<dyn TxMgr>, oid: OID) -> RS<()> {
+        self.ensure_writable()?;
         self.storage.drop_table_async(oid).await
     }
 
@@ -58,11 +60,33 @@ impl XContract for WorkerXContract {
         table_id: OID,
         pred_key: &VecDatum,
         select: &VecSelTerm,
-        _opt_read: &OptRead,
+        opt_read: &OptRead,
     ) -> RS<Option<Vec<Option<DatBin>>>> {
         let desc = self.meta_mgr.get_table_by_id(table_id).await?;
-        self._read_key(desc, tx_mgr, table_id, pred_key, select)
-            .await
+        let Some(as_of) = opt_read.as_of else {
+            return self
+                ._read_key(
+                    desc,
+                    ReadSource::Tx(tx_mgr.as_ref()),
+                    table_id,
+                    pred_key,
+                    select,
+                )
+                .await;
+        };
+        let timestamp = self.as_of_commit_ts(as_of)?;
+        let snapshot = self.snapshot_mgr.pin_history(timestamp)?;
+        let result = self
+            ._read_key(
+                desc,
+                ReadSource::AsOf(&snapshot),
+                table_id,
+                pred_key,
+                select,
+            )
+            .await;
+        self.snapshot_mgr.unpin_history(timestamp)?;
+        result
     }
 
     async fn read_range(
@@ -72,11 +96,36 @@ impl XContract for WorkerXContract {
         pred_key: &RangeData,
         pred_non_key: &Predicate,
         select: &VecSelTerm,
-        _opt_read: &OptRead,
+        opt_read: &OptRead,
     ) -> RS<Arc<dyn RSCursor>> {
         let desc = self.meta_mgr.get_table_by_id(table_id).await?;
-        self._read_range(desc, tx_mgr, table_id, pred_key, pred_non_key, select)
-            .await
+        let Some(as_of) = opt_read.as_of else {
+            return self
+                ._read_range(
+                    desc,
+                    ReadSource::Tx(tx_mgr.as_ref()),
+                    table_id,
+                    pred_key,
+                    pred_non_key,
+                    select,
+                )
+                .await;
+        };
+        // The cursor is materialized, so the pin only has to cover the scan.
+        let timestamp = self.as_of_commit_ts(as_of)?;
+        let snapshot = self.snapshot_mgr.pin_history(timestamp)?;
+        let result = self
+            ._read_range(
+                desc,
+                ReadSource::AsOf(&snapshot),
+                table_id,
+                pred_key,
+                pred_non_key,
+                select,
+            )
+            .await;
+        self.snapshot_mgr.unpin_history(timestamp)?;
+        result
     }
 
     async fn delete(
@@ -1,4 +1,6 @@
 use super::*;
+use mudu_contract::tuple::comparator::{binary_field_equal, binary_field_key_form};
+use std::borrow::Cow;
 
 pub(crate) fn ensure_supported_predicate(predicate: &Predicate) -> RS<()> {
     match predicate {
@@ -29,12 +31,14 @@ pub(crate) fn matches_predicate(
         Predicate::KeyPrefixEq(prefix) => {
             for (attr, expected) in prefix {
                 let field = desc.get_attr(*attr);
-                let Some(primary_index) = field.primary_index() else {
+                if field.primary_index().is_none() {
                     return Ok(false);
-                };
-                let field_desc = desc.key_desc().get_field_desc(primary_index);
+                }
+                // Key tuples keep fixed-length fields first, so a field is
+                // found by its datum index, not its primary key position.
+                let field_desc = desc.key_desc().get_field_desc(field.datum_index());
                 let actual = field_desc.get(key)?;
-                if actual != expected.as_slice() {
+                if !binary_field_equal(field_desc.type_obj(), actual, expected.as_slice())? {
                     return Ok(false);
                 }
             }
@@ -109,6 +113,18 @@ pub(crate) fn build_tuple_for<const IS_KEY: bool>(
         ));
     }
     if IS_KEY {
+        // Keys are encoded in the key form of their collation, so the write
+        // sets, staged writes, commit locks and partition routing, which all
+        // identify a key by its encoding, see keys equal under the collation
+        // as one key.
+        let values = values
+            .iter()
+            .enumerate()
+            .map(|(index, value)| {
+                let ty = tuple_desc.get_field_desc(index).type_obj();
+                binary_field_key_form(ty, value).map(Cow::into_owned)
+            })
+            .collect::<RS<Vec<_>>>()?;
         return build_tuple(&values, tuple_desc);
     }
 
@@ -200,6 +216,20 @@ pub(crate) fn rpc_bound_as_ref(bound: &RpcBound) -> Bound<&[u8]> {
     }
 }
 
+/// Bounds of the KV key range `[start_key, end_key)`; an empty end key is
+/// unbounded.
+pub(crate) fn kv_range_bounds<'a>(
+    start_key: &'a [u8],
+    end_key: &'a [u8],
+) -> (Bound<&'a [u8]>, Bound<&'a [u8]>) {
+    let end = if end_key.is_empty() {
+        Bound::Unbounded
+    } else {
+        Bound::Excluded(end_key)
+    };
+    (Bound::Included(start_key), end)
+}
+
 pub(crate) fn project_selected_fields(
     desc: &TableDesc,
     key: &[u8],
@@ -256,7 +286,12 @@ pub(crate) fn apply_value_update(
     Ok(updated)
 }
 
-pub(crate) fn single_put_batch(xid: u64, key: Vec<u8>, value: Vec<u8>) -> XLBatch {
+pub(crate) fn single_put_batch(
+    xid: u64,
+    key: Vec<u8>,
+    value: Vec<u8>,
+    ttl_ms: Option<u64>,
+) -> XLBatch {
     XLBatch::new(vec![XLEntry {
         xid,
         ops: vec![
@@ -267,9 +302,11 @@ pub(crate) fn single_put_batch(xid: u64, key: Vec<u8>, value: Vec<u8>) -> XLBatc
                 tuple_id: 0,
                 key,
                 value,
+                ttl_ms,
             })),
             crate::wal::xl_entry::TxOp::Commit,
         ],
+        commit_ms: 0,
     }])
 }
 
@@ -288,6 +325,7 @@ pub(crate) fn single_delete_batch(xid: u64, key: Vec<u8>) -> XLBatch {
             )),
             crate::wal::xl_entry::TxOp::Commit,
         ],
+        commit_ms: 0,
     }])
 }
 
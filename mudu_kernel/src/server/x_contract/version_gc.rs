@@ -0,0 +1,36 @@
+use super::*;
+use crate::server::version_gc::VersionGcStats;
+
+impl WorkerXContract {
+    /// Keeps the state as of every commit timestamp handed out within the last
+    /// `retention` readable by `AS OF SYSTEM TIME` reads.
+    pub fn set_history_retention(&self, retention: Duration) {
+        self.snapshot_mgr.set_history_retention(retention);
+    }
+
+    /// Maps the point in time of an `AS OF SYSTEM TIME` read to a commit
+    /// timestamp of this worker.
+    pub(crate) fn as_of_commit_ts(&self, as_of: AsOf) -> RS<u64> {
+        match as_of {
+            AsOf::CommitTs(timestamp) => Ok(timestamp),
+            AsOf::WallClock(unix_micros) => self.snapshot_mgr.commit_ts_at_wall_clock(unix_micros),
+        }
+    }
+
+    /// Runs one version GC step on the partitions this worker owns.
+    ///
+    /// The horizon is the oldest snapshot any transaction on this worker may
+    /// still read with, so pruned versions are unreachable for every session.
+    pub async fn collect_version_garbage_async(&self, max_rows: usize) -> RS<VersionGcStats> {
+        let horizon = self.snapshot_mgr.gc_horizon()?;
+        let mut partitions = BTreeSet::new();
+        for partition_id in self.storage.relation_partition_ids() {
+            if self.resolve_partition_worker(partition_id).await? == Some(self.worker_id) {
+                partitions.insert(partition_id);
+            }
+        }
+        self.storage
+            .collect_garbage_step_async(horizon, &partitions, max_rows)
+            .await
+    }
+}
@@ -1,26 +1,40 @@
+use crate::contract::column_access::{element_type, ColumnAccess};
+use crate::contract::json_access::JsonAccess;
 use crate::contract::meta_mgr::MetaMgr;
 use crate::contract::partition_rule::{PartitionBound, PartitionRuleDesc, RangePartitionDef};
 use crate::contract::partition_rule_binding::{PartitionPlacement, TablePartitionBinding};
 use crate::contract::schema_column::SchemaColumn;
+use crate::contract::schema_index::SchemaIndex;
 use crate::contract::schema_table::SchemaTable;
 use crate::contract::table_desc::TableDesc;
 use crate::executor::project_tuple_desc;
 use crate::sql::bound_stmt::{
-    BoundCommand, BoundCopyFrom, BoundCopyTo, BoundCreatePartitionPlacement,
-    BoundCreatePartitionRule, BoundCreateTable, BoundDelete, BoundDropTable, BoundInsert,
-    BoundInsertRow, BoundPredicate, BoundQuery, BoundSelect, BoundStmt, BoundUpdate,
+    BoundAccess, BoundCommand, BoundCopyFrom, BoundCopyTo, BoundCreateIndex,
+    BoundCreatePartitionPlacement, BoundCreatePartitionRule, BoundCreateTable, BoundDelete,
+    BoundDropTable, BoundFilter, BoundInsert, BoundInsertRow, BoundOrderTerm, BoundPredicate,
+    BoundQuery, BoundSelect, BoundStmt, BoundUnnest, BoundUpdate,
 };
 use crate::sql::copy_layout::CopyLayout;
 use crate::sql::value_codec::ValueCodec;
+use crate::storage::compression::Compression;
+use crate::x_engine::api::AsOf;
 use mudu::common::result::RS;
 use mudu::error::ErrorCode as ER;
 use mudu::mudu_error;
 use mudu_contract::database::sql_params::SQLParams;
+use mudu_contract::tuple::datum_desc::DatumDesc;
+use mudu_contract::tuple::tuple_field_desc::TupleFieldDesc;
+use mudu_type::collation::Collation;
+use mudu_type::dat_type::DatType;
 use mudu_type::dat_type_id::DatTypeID;
 use mudu_type::dt_info::DTInfo;
+use sql_parser::ast::as_of_time::AsOfTime;
 use sql_parser::ast::expr_compare::ExprCompare;
 use sql_parser::ast::expr_item::{ExprItem, ExprValue};
+use sql_parser::ast::expr_json::ExprJsonPath;
+use sql_parser::ast::expr_name::ExprName;
 use sql_parser::ast::expr_operator::ValueCompare;
+use sql_parser::ast::stmt_create_index::StmtCreateIndex;
 use sql_parser::ast::stmt_create_partition_placement::StmtCreatePartitionPlacement;
 use sql_parser::ast::stmt_create_partition_rule::{StmtCreatePartitionRule, StmtPartitionBound};
 use sql_parser::ast::stmt_create_table::StmtCreateTable;
@@ -32,6 +46,9 @@ use sql_parser::ast::stmt_update::{AssignedValue, StmtUpdate};
 use std::ops::Bound;
 use std::sync::Arc;
 
+/// Selected attributes, how each is read, and the one `unnest` expands.
+type SelectAttrs = (Vec<usize>, Vec<Option<BoundAccess>>, Option<BoundUnnest>);
+
 pub struct Binder {
     meta_mgr: Arc<dyn MetaMgr>,
 }
@@ -65,6 +82,9 @@ impl Binder {
             StmtCommand::CreateTable(stmt) => Ok(BoundCommand::CreateTable(
                 self.bind_create_table(stmt).await?,
             )),
+            StmtCommand::CreateIndex(stmt) => Ok(BoundCommand::CreateIndex(
+                self.bind_create_index(stmt).await?,
+            )),
             StmtCommand::DropTable(stmt) => {
                 Ok(BoundCommand::DropTable(self.bind_drop_table(stmt).await?))
             }
@@ -90,17 +110,70 @@ impl Binder {
         params: &dyn SQLParams,
     ) -> RS<BoundSelect> {
         let table_desc = self.get_table_by_name(stmt.get_table_reference()).await?;
-        let select_attrs = self.select_attrs(&table_desc, stmt.get_select_term_list())?;
+        let (select_attrs, select_paths, unnest) =
+            self.select_attrs(&table_desc, stmt.get_select_term_list())?;
         let tuple_desc = project_tuple_desc(
             &table_desc,
             &crate::x_engine::api::VecSelTerm::new(select_attrs.clone()),
         );
-        let predicate = self.bind_predicate(&table_desc, stmt.get_where_predicate(), params)?;
+        // JSON paths and array elements are always nullable: the key, index
+        // or element may be missing. `unnest` yields the array elements.
+        let mut fields = tuple_desc.into_fields();
+        for (position, field) in fields.iter_mut().enumerate() {
+            let result_type = match (&select_paths[position], &unnest) {
+                (Some(path), _) => path.access.result_type(&path.attr_type)?,
+                (None, Some(unnest)) if unnest.position == position => {
+                    element_type(&unnest.array_type)?.clone()
+                }
+                _ => continue,
+            };
+            *field = DatumDesc::new_nullable(field.name().to_string(), result_type, true);
+        }
+        let tuple_desc = TupleFieldDesc::new(fields);
+        let predicates = stmt.get_where_predicate();
+        let (predicate, filters) = match self
+            .bind_index_predicate(&table_desc, predicates, params)
+            .await?
+        {
+            Some(predicate) => (predicate, Vec::new()),
+            None => {
+                let scan_predicates = predicates
+                    .iter()
+                    .filter(|predicate| !self.is_filter_predicate(predicate))
+                    .cloned()
+                    .collect::<Vec<_>>();
+                (
+                    self.bind_predicate(&table_desc, &scan_predicates, params)?,
+                    self.bind_filters(&table_desc, predicates, params)?,
+                )
+            }
+        };
+        let order_by = stmt
+            .get_order_by()
+            .iter()
+            .map(|term| {
+                let attr = self.attr_index_by_name(&table_desc, term.field().name())?;
+                Ok(BoundOrderTerm {
+                    attr,
+                    dat_type: table_desc.get_attr(attr).type_desc().clone(),
+                    descending: term.descending(),
+                    nulls_first: term.nulls_first(),
+                })
+            })
+            .collect::<RS<Vec<_>>>()?;
         Ok(BoundSelect {
             table_id: table_desc.id(),
             select_attrs,
+            select_paths,
             tuple_desc,
             predicate,
+            filters,
+            as_of: stmt.get_as_of().map(|as_of| match as_of {
+                AsOfTime::CommitTs(timestamp) => AsOf::CommitTs(timestamp),
+                AsOfTime::WallClock(unix_micros) => AsOf::WallClock(unix_micros),
+            }),
+            order_by,
+            unnest,
         })
     }
 
@@ -124,12 +197,25 @@ impl Binder {
             .map(|index| index + value_offset)
             .collect();
         columns.append(&mut value_columns);
-        let schema = SchemaTable::new(
+        let mut schema = SchemaTable::new(
             stmt.table_name().clone(),
             columns,
             key_indices,
             value_indices,
         );
+        for (name, value) in stmt.table_options() {
+            match name.as_str() {
+                "compression" => {
+                    schema = schema.with_compression(value.parse::<Compression>()?);
+                }
+                _ => {
+                    return Err(mudu_error!(
+                        ER::InvalidArgument,
+                        format!("unknown table option {}", name)
+                    ));
+                }
+            }
+        }
         let partition_binding = if let Some(partition) = stmt.partition() {
             let rule = self
                 .meta_mgr
@@ -177,6 +263,110 @@ impl Binder {
         })
     }
 
+    async fn bind_create_index(&self, stmt: StmtCreateIndex) -> RS<BoundCreateIndex> {
+        let table_desc = self.get_table_by_name(stmt.table_name()).await?;
+        if self
+            .meta_mgr
+            .get_table_partition_binding(table_desc.id())
+            .await?
+            .is_some()
+        {
+            return Err(mudu_error!(
+                ER::NotImplemented,
+                "indexes on partitioned tables are not implemented"
+            ));
+        }
+        let mut source_attrs = Vec::with_capacity(stmt.columns().len());
+        let mut json_paths = Vec::with_capacity(stmt.columns().len());
+        for column in stmt.columns() {
+            if column.element().is_some() {
+                return Err(mudu_error!(
+                    ER::NotImplemented,
+                    "indexes on array elements are not implemented"
+                ));
+            }
+            let attr = self.attr_index_by_name(&table_desc, column.name())?;
+            let json_path = column
+                .json_path()
+                .map(|path| Self::json_access(&table_desc, attr, path))
+                .transpose()?;
+            if source_attrs
+                .iter()
+                .zip(&json_paths)
+                .any(|(a, p)| *a == attr && *p == json_path)
+            {
+                return Err(mudu_error!(
+                    ER::InvalidArgument,
+                    format!("column {} appears twice in the index", column.name())
+                ));
+            }
+            source_attrs.push(attr);
+            json_paths.push(json_path);
+        }
+        let indexed_count = source_attrs.len();
+        for attr in table_desc.key_indices() {
+            if !source_attrs
+                .iter()
+                .zip(&json_paths)
+                .any(|(a, p)| a == attr && p.is_none())
+            {
+                source_attrs.push(*attr);
+                json_paths.push(None);
+            }
+        }
+        // Index columns keep the type and collation of their base column;
+        // JSON path columns hold the text of the extracted value.
+        let columns = source_attrs
+            .iter()
+            .zip(&json_paths)
+            .map(|(attr, json_path)| {
+                let field = table_desc.get_attr(*attr);
+                let mut column = match json_path {
+                    Some(json_path) => {
+                        let ty = DatType::default_for(DatTypeID::String);
+                        SchemaColumn::new(
+                            format!("{}{}", field.name(), json_path.path()),
+                            ty.dat_type_id(),
+                            DTInfo::from_opt_object(&ty),
+                        )
+                    }
+                    None => {
+                        let ty = field.type_desc();
+                        SchemaColumn::new(
+                            field.name().clone(),
+                            ty.dat_type_id(),
+                            DTInfo::from_opt_object(ty),
+                        )
+                    }
+                };
+                column.set_nullable(false);
+                column
+            })
+            .collect::<Vec<_>>();
+        let index_name = match stmt.index_name() {
+            Some(name) => name.to_string(),
+            None => format!(
+                "{}_{}_idx",
+                stmt.table_name(),
+                stmt.columns()
+                    .iter()
+                    .map(|column| column.name().as_str())
+                    .collect::<Vec<_>>()
+                    .join("_")
+            ),
+        };
+        let key_indices = (0..columns.len()).collect();
+        let schema = SchemaTable::new(index_name, columns, key_indices, Vec::new()).with_index_on(
+            SchemaIndex {
+                base_table_id: table_desc.id(),
+                source_attrs,
+                indexed_count,
+                json_paths,
+            },
+        );
+        Ok(BoundCreateIndex { schema })
+    }
+
     fn bind_create_partition_rule(
         &self,
         stmt: StmtCreatePartitionRule,
@@ -452,6 +642,227 @@ impl Binder {
         })
     }
 
+    /// Binds a `WHERE` clause of equalities that are not all on primary key
+    /// columns to a lookup on a secondary index whose leading columns are
+    /// exactly the compared columns or JSON paths. Returns `None` if there is
+    /// no such index.
+    async fn bind_index_predicate(
+        &self,
+        table_desc: &TableDesc,
+        predicates: &[ExprCompare],
+        params: &dyn SQLParams,
+    ) -> RS<Option<BoundPredicate>> {
+        let mut items = Vec::with_capacity(predicates.len());
+        for predicate in predicates {
+            match self.field_literal_compare(predicate) {
+                Some((field, value, ValueCompare::EQ)) if field.element().is_none() => {
+                    let attr = self.attr_index_by_name(table_desc, field.name())?;
+                    let json_path = field
+                        .json_path()
+                        .map(|path| Self::json_access(table_desc, attr, path))
+                        .transpose()?;
+                    items.push((attr, json_path, value));
+                }
+                _ => return Ok(None),
+            }
+        }
+        if items.iter().all(|(attr, json_path, _)| {
+            json_path.is_none() && table_desc.get_attr(*attr).primary_index().is_some()
+        }) {
+            return Ok(None);
+        }
+        for index in self.meta_mgr.list_table_indexes(table_desc.id()).await? {
+            let Some(index_on) = index.index_on() else {
+                continue;
+            };
+            let Some(leading) = index_on.source_attrs.get(..items.len()) else {
+                continue;
+            };
+            let is_column = |index_attr: usize, attr: &usize, json_path: &Option<JsonAccess>| {
+                leading[index_attr] == *attr && index_on.json_path(index_attr) == json_path.as_ref()
+            };
+            let Some(positions) = items
+                .iter()
+                .map(|(attr, json_path, _)| {
+                    (0..leading.len()).find(|i| is_column(*i, attr, json_path))
+                })
+                .collect::<Option<Vec<_>>>()
+            else {
+                continue;
+            };
+            if !(0..leading.len()).all(|i| positions.contains(&i)) {
+                continue;
+            }
+            let mut param_index = 0;
+            let mut prefix = Vec::with_capacity(items.len());
+            for ((attr, json_path, value), index_attr) in items.iter().zip(positions) {
+                let binary = match json_path {
+                    Some(json_path) => ValueCodec::binary_from_expr(
+                        &ValueCodec::unquoted(value),
+                        &json_path.result_type(),
+                        params,
+                        &mut param_index,
+                    )?
+                    .map(|operand| json_path.index_key_of_operand(&operand))
+                    .transpose()?,
+                    None => ValueCodec::binary_from_expr(
+                        value,
+                        table_desc.get_attr(*attr).type_desc(),
+                        params,
+                        &mut param_index,
+                    )?,
+                };
+                let binary = binary.ok_or_else(|| {
+                    mudu_error!(
+                        ER::NotImplemented,
+                        "NULL index predicates are not implemented; use IS NULL"
+                    )
+                })?;
+                prefix.push((index_attr, binary));
+            }
+            let base_key = index_on
+                .source_attrs
+                .iter()
+                .enumerate()
+                .filter(|(index_attr, attr)| {
+                    index_on.json_path(*index_attr).is_none()
+                        && table_desc.get_attr(**attr).primary_index().is_some()
+                })
+                .map(|(index_attr, attr)| (index_attr, *attr))
+                .collect::<Vec<_>>();
+            return Ok(Some(BoundPredicate::IndexPrefixEq {
+                index_id: index.id(),
+                prefix,
+                base_key,
+            }));
+        }
+        Ok(None)
+    }
+
+    /// JSON paths, array elements, `@>`/`<@` and `= ANY` are checked on each
+    /// scanned row rather than bound to the scan.
+    fn is_filter_predicate(&self, predicate: &ExprCompare) -> bool {
+        match self.field_literal_compare(predicate) {
+            Some((field, _, op)) => {
+                field.json_path().is_some()
+                    || field.element().is_some()
+                    || matches!(
+                        op,
+                        ValueCompare::Contains
+                            | ValueCompare::ContainedBy
+                            | ValueCompare::EqAny
+                            | ValueCompare::AnyEq
+                    )
+            }
+            None => false,
+        }
+    }
+
+    /// Binds the filter predicates of `predicates`; placeholders are counted
+    /// over all of them.
+    fn bind_filters(
+        &self,
+        table_desc: &TableDesc,
+        predicates: &[ExprCompare],
+        params: &dyn SQLParams,
+    ) -> RS<Vec<BoundFilter>> {
+        let mut param_index = 0;
+        let mut filters = Vec::new();
+        for predicate in predicates {
+            let Some((field, value, op)) = self.field_literal_compare(predicate) else {
+                continue;
+            };
+            if !self.is_filter_predicate(predicate) {
+                if matches!(value, ExprValue::ValuePlaceholder) {
+                    param_index += 1;
+                }
+                continue;
+            }
+            let attr = self.attr_index_by_name(table_desc, field.name())?;
+            let attr_type = table_desc.get_attr(attr).type_desc().clone();
+            let access = Self::column_access(table_desc, attr, field)?;
+            let value_type = match &access {
+                Some(access) => access.result_type(&attr_type)?,
+                None => attr_type.clone(),
+            };
+            let operand_type = match op {
+                // `value = ANY(array)` compares the value with the elements.
+                ValueCompare::AnyEq => element_type(&value_type)?.clone(),
+                _ => value_type,
+            };
+            if matches!(op, ValueCompare::Contains | ValueCompare::ContainedBy)
+                && operand_type.dat_type_id() != DatTypeID::Json
+            {
+                return Err(mudu_error!(
+                    ER::InvalidType,
+                    format!(
+                        "@> and <@ compare json documents, not {}",
+                        operand_type.name()
+                    )
+                ));
+            }
+            // Text read through a JSON path and array elements have no quotes
+            // to compare with.
+            let value = match (&access, op) {
+                (Some(_), _) | (None, ValueCompare::AnyEq) => ValueCodec::unquoted(&value),
+                _ => value,
+            };
+            let operand =
+                ValueCodec::binary_from_expr(&value, &operand_type, params, &mut param_index)?
+                    .ok_or_else(|| {
+                        mudu_error!(
+                            ER::NotImplemented,
+                            "NULL predicates on json paths and arrays are not implemented; \
+                             use IS NULL"
+                        )
+                    })?;
+            filters.push(BoundFilter {
+                attr,
+                attr_type,
+                access,
+                op,
+                operand,
+                operand_type,
+            });
+        }
+        Ok(filters)
+    }
+
+    /// The JSON path or array element `field` reads from column `attr`.
+    fn column_access(
+        table_desc: &TableDesc,
+        attr: usize,
+        field: &ExprName,
+    ) -> RS<Option<ColumnAccess>> {
+        if let Some(path) = field.json_path() {
+            return Self::json_access(table_desc, attr, path)
+                .map(|path| Some(ColumnAccess::Json(path)));
+        }
+        match field.element() {
+            Some(index) => {
+                element_type(table_desc.get_attr(attr).type_desc())?;
+                Ok(Some(ColumnAccess::Element(index)))
+            }
+            None => Ok(None),
+        }
+    }
+
+    fn json_access(table_desc: &TableDesc, attr: usize, path: &ExprJsonPath) -> RS<JsonAccess> {
+        let field = table_desc.get_attr(attr);
+        if field.type_desc().dat_type_id() != DatTypeID::Json {
+            return Err(mudu_error!(
+                ER::InvalidType,
+                format!(
+                    "column {} of type {} has no json path {}",
+                    field.name(),
+                    field.type_desc().name(),
+                    path.path()
+                )
+            ));
+        }
+        Ok(JsonAccess::new(path.path().clone(), path.as_text()))
+    }
+
     fn bind_predicate(
         &self,
         table_desc: &TableDesc,
@@ -478,14 +889,20 @@ impl Binder {
         let mut end: Bound<Vec<(usize, Vec<u8>)>> = Bound::Unbounded;
 
         for predicate in predicates {
-            let (field_name, expr_value, op) =
+            let (field, expr_value, op) =
                 self.field_literal_compare(predicate).ok_or_else(|| {
                     mudu_error!(
                         ER::NotImplemented,
                         "only column/literal predicates are supported"
                     )
                 })?;
-            let attr = self.attr_index_by_name(table_desc, field_name)?;
+            if self.is_filter_predicate(predicate) {
+                return Err(mudu_error!(
+                    ER::NotImplemented,
+                    "json predicates are only implemented in select"
+                ));
+            }
+            let attr = self.attr_index_by_name(table_desc, field.name())?;
             let field = table_desc.get_attr(attr);
             if field.primary_index().is_none() {
                 return Err(mudu_error!(
@@ -507,7 +924,11 @@ impl Binder {
                 ValueCompare::GT => start = Bound::Excluded(vec![(attr, binary)]),
                 ValueCompare::LE => end = Bound::Included(vec![(attr, binary)]),
                 ValueCompare::LT => end = Bound::Excluded(vec![(attr, binary)]),
-                ValueCompare::NE => {
+                ValueCompare::NE
+                | ValueCompare::Contains
+                | ValueCompare::ContainedBy
+                | ValueCompare::EqAny
+                | ValueCompare::AnyEq => {
                     return Err(mudu_error!(
                         ER::NotImplemented,
                         "not-equal predicates are not implemented"
@@ -591,10 +1012,12 @@ impl Binder {
                 }
                 Ok(key)
             }
-            BoundPredicate::KeyPrefixEq { .. } => Err(mudu_error!(
-                ER::NotImplemented,
-                "update/delete require a complete primary key predicate"
-            )),
+            BoundPredicate::KeyPrefixEq { .. } | BoundPredicate::IndexPrefixEq { .. } => {
+                Err(mudu_error!(
+                    ER::NotImplemented,
+                    "update/delete require a complete primary key predicate"
+                ))
+            }
             BoundPredicate::True => Err(mudu_error!(
                 ER::NotImplemented,
                 "full-table update/delete is not implemented"
@@ -609,16 +1032,14 @@ impl Binder {
     fn field_literal_compare<'a>(
         &self,
         predicate: &'a ExprCompare,
-    ) -> Option<(&'a String, ExprValue, ValueCompare)> {
+    ) -> Option<(&'a ExprName, ExprValue, ValueCompare)> {
         match (predicate.left(), predicate.right()) {
             (ExprItem::ItemName(name), ExprItem::ItemValue(value)) => {
-                Some((name.name(), value.clone(), *predicate.op()))
+                Some((name, value.clone(), *predicate.op()))
+            }
+            (ExprItem::ItemValue(value), ExprItem::ItemName(name)) => {
+                Some((name, value.clone(), Self::reverse_compare(*predicate.op())))
             }
-            (ExprItem::ItemValue(value), ExprItem::ItemName(name)) => Some((
-                name.name(),
-                value.clone(),
-                Self::reverse_compare(*predicate.op()),
-            )),
             _ => None,
         }
     }
@@ -632,6 +1053,10 @@ impl Binder {
             .data_type()
             .clone()
             .uni_to_with_params(column.data_type_param().clone())?;
+        let ty = match column.collation() {
+            Some(name) => Self::with_collation(ty, name, column.column_name())?,
+            None => ty,
+        };
         let mut schema_column = SchemaColumn::new(
             column.column_name().clone(),
             ty.dat_type_id(),
@@ -643,15 +1068,58 @@ impl Binder {
         Ok(schema_column)
     }
 
+    fn with_collation(ty: DatType, name: &str, column_name: &str) -> RS<DatType> {
+        if ty.dat_type_id() != DatTypeID::String {
+            return Err(mudu_error!(
+                ER::InvalidType,
+                format!(
+                    "COLLATE {} is not allowed on column {} of type {}",
+                    name,
+                    column_name,
+                    ty.name()
+                )
+            ));
+        }
+        let collation = Collation::from_name(name)?;
+        let ty = if ty.has_no_param() {
+            DatType::default_for(DatTypeID::String)
+        } else {
+            ty
+        };
+        Ok(DatType::from_string(
+            ty.into_string_param().with_collation(collation),
+        ))
+    }
+
     fn select_attrs(
         &self,
         table_desc: &TableDesc,
         terms: &[sql_parser::ast::select_term::SelectTerm],
-    ) -> RS<Vec<usize>> {
-        terms
-            .iter()
-            .map(|term| self.attr_index_by_name(table_desc, term.field().name()))
-            .collect()
+    ) -> RS<SelectAttrs> {
+        let mut attrs = Vec::with_capacity(terms.len());
+        let mut paths = Vec::with_capacity(terms.len());
+        let mut unnest = None;
+        for (position, term) in terms.iter().enumerate() {
+            let attr = self.attr_index_by_name(table_desc, term.field().name())?;
+            let attr_type = table_desc.get_attr(attr).type_desc().clone();
+            let access = Self::column_access(table_desc, attr, term.field())?;
+            if term.unnest() {
+                if unnest.is_some() || access.is_some() {
+                    return Err(mudu_error!(
+                        ER::NotImplemented,
+                        "only one unnest of an array column is implemented"
+                    ));
+                }
+                element_type(&attr_type)?;
+                unnest = Some(BoundUnnest {
+                    position,
+                    array_type: attr_type.clone(),
+                });
+            }
+            attrs.push(attr);
+            paths.push(access.map(|access| BoundAccess { attr_type, access }));
+        }
+        Ok((attrs, paths, unnest))
     }
 
     fn attr_index_by_name(&self, table_desc: &TableDesc, name: &str) -> RS<usize> {
@@ -20,6 +20,7 @@ mod tests {
     use crate::contract::table_info::TableInfo;
     use crate::sql::binder::Binder;
     use crate::sql::bound_stmt::{BoundCommand, BoundPredicate, BoundQuery, BoundStmt};
+    use crate::storage::compression::Compression;
     use async_trait::async_trait;
     use mudu::common::id::OID;
     use mudu::common::result::RS;
@@ -27,6 +28,7 @@ mod tests {
     use mudu::error::ErrorCode;
     use mudu::mudu_error;
     use mudu_sys::sync::SMutex;
+    use mudu_type::collation::Collation;
     use mudu_type::dat_type::DatType;
     use mudu_type::dat_type_id::DatTypeID;
     use mudu_type::datum::DatumDyn;
@@ -270,6 +272,88 @@ mod tests {
         .unwrap()
     }
 
+    #[test]
+    #[cfg_attr(miri, ignore)]
+    fn bind_create_table_applies_table_options() {
+        mudu_sys::task::async_::block_on_tokio_current_thread(async move {
+            let bound = binder()
+                .bind(
+                    parse_stmt(
+                        "create table logs (id int primary key) with (compression = 'lz4');",
+                    ),
+                    &(),
+                )
+                .await
+                .unwrap();
+            let BoundStmt::Command(BoundCommand::CreateTable(create)) = bound else {
+                panic!("expected create table");
+            };
+            assert_eq!(create.schema.compression(), Some(Compression::Lz4));
+
+            for sql in [
+                "create table logs (id int primary key) with (compression = 'gzip');",
+                "create table logs (id int primary key) with (fillfactor = 90);",
+            ] {
+                let err = binder().bind(parse_stmt(sql), &()).await.unwrap_err();
+                assert_eq!(err.ec(), ErrorCode::InvalidArgument, "{}", sql);
+            }
+        })
+        .unwrap()
+    }
+
+    #[test]
+    #[cfg_attr(miri, ignore)]
+    fn bind_create_table_stores_column_collations() {
+        mudu_sys::task::async_::block_on_tokio_current_thread(async move {
+            let bound = binder()
+                .bind(
+                    parse_stmt(
+                        "create table users (\
+                            email varchar(128) collate nocase primary key, \
+                            name text collate unicode_root, \
+                            bio text)",
+                    ),
+                    &(),
+                )
+                .await
+                .unwrap();
+            let BoundStmt::Command(BoundCommand::CreateTable(create)) = bound else {
+                panic!("expected create table");
+            };
+            let collations: Vec<Collation> = create
+                .schema
+                .columns()
+                .iter()
+                .map(|c| c.type_param().to_dat_type().unwrap().collation())
+                .collect();
+            assert_eq!(
+                collations,
+                vec![
+                    Collation::AsciiCi,
+                    Collation::UnicodeRoot,
+                    Collation::Binary
+                ]
+            );
+            let email = create.schema.columns()[0]
+                .type_param()
+                .to_dat_type()
+                .unwrap();
+            assert_eq!(email.expect_string_param().length(), 128);
+
+            for sql in [
+                "create table t (id int collate nocase primary key)",
+                "create table t (id int primary key, name text collate latin1)",
+            ] {
+                assert!(
+                    binder().bind(parse_stmt(sql), &()).await.is_err(),
+                    "{}",
+                    sql
+                );
+            }
+        })
+        .unwrap()
+    }
+
     #[test]
     #[cfg_attr(miri, ignore)]
     fn bind_select_uses_key_prefix_eq_for_left_prefix_of_composite_primary_key() {
@@ -1,8 +1,12 @@
+use crate::contract::column_access::ColumnAccess;
 use crate::contract::partition_rule::PartitionRuleDesc;
 use crate::contract::partition_rule_binding::{PartitionPlacement, TablePartitionBinding};
 use crate::contract::schema_table::SchemaTable;
+use crate::x_engine::api::AsOf;
 use mudu::common::id::{AttrIndex, OID};
 use mudu_contract::tuple::tuple_field_desc::TupleFieldDesc;
+use mudu_type::dat_type::DatType;
+use sql_parser::ast::expr_operator::ValueCompare;
 use std::ops::Bound;
 
 #[derive(Clone, Debug)]
@@ -21,6 +25,7 @@ pub enum BoundCommand {
     CreatePartitionPlacement(BoundCreatePartitionPlacement),
     CreatePartitionRule(BoundCreatePartitionRule),
     CreateTable(BoundCreateTable),
+    CreateIndex(BoundCreateIndex),
     DropTable(BoundDropTable),
     Insert(BoundInsert),
     Update(BoundUpdate),
@@ -33,8 +38,50 @@ pub enum BoundCommand {
 pub struct BoundSelect {
     pub table_id: OID,
     pub select_attrs: Vec<AttrIndex>,
+    /// The JSON path or array element each selected attribute is read
+    /// through, if any.
+    pub select_paths: Vec<Option<BoundAccess>>,
     pub tuple_desc: TupleFieldDesc,
     pub predicate: BoundPredicate,
+    /// Predicates the scan cannot evaluate, checked on each scanned row.
+    pub filters: Vec<BoundFilter>,
+    pub as_of: Option<AsOf>,
+    pub order_by: Vec<BoundOrderTerm>,
+    /// The selected array expanded by `unnest`, one row per element.
+    pub unnest: Option<BoundUnnest>,
+}
+
+#[derive(Clone, Debug)]
+pub struct BoundAccess {
+    pub attr_type: DatType,
+    pub access: ColumnAccess,
+}
+
+#[derive(Clone, Debug)]
+pub struct BoundUnnest {
+    /// Position of the array in the select list.
+    pub position: usize,
+    pub array_type: DatType,
+}
+
+/// `attr [access] op operand`, with the operand in the binary form of
+/// `operand_type`.
+#[derive(Clone, Debug)]
+pub struct BoundFilter {
+    pub attr: AttrIndex,
+    pub attr_type: DatType,
+    pub access: Option<ColumnAccess>,
+    pub op: ValueCompare,
+    pub operand: Vec<u8>,
+    pub operand_type: DatType,
+}
+
+#[derive(Clone, Debug)]
+pub struct BoundOrderTerm {
+    pub attr: AttrIndex,
+    pub dat_type: DatType,
+    pub descending: bool,
+    pub nulls_first: bool,
 }
 
 #[derive(Clone, Debug)]
@@ -53,6 +100,11 @@ pub struct BoundCreateTable {
     pub partition_binding: Option<TablePartitionBinding>,
 }
 
+#[derive(Clone, Debug)]
+pub struct BoundCreateIndex {
+    pub schema: SchemaTable,
+}
+
 #[derive(Clone, Debug)]
 pub struct BoundDropTable {
     pub oid: Option<OID>,
@@ -112,4 +164,12 @@ pub enum BoundPredicate {
         start: Bound<Vec<(AttrIndex, Vec<u8>)>>,
         end: Bound<Vec<(AttrIndex, Vec<u8>)>>,
     },
+    /// Equality over a left prefix of the secondary index `index_id`,
+    /// by index attribute. `base_key` pairs the index attribute and the base
+    /// attribute of each base primary key column.
+    IndexPrefixEq {
+        index_id: OID,
+        prefix: Vec<(AttrIndex, Vec<u8>)>,
+        base_key: Vec<(AttrIndex, AttrIndex)>,
+    },
 }
@@ -36,6 +36,9 @@ mod current_tx;
 mod proj_field;
 #[cfg(test)]
 #[allow(clippy::unwrap_used, clippy::expect_used, clippy::panic)]
+mod sql_run_test;
+#[cfg(test)]
+#[allow(clippy::unwrap_used, clippy::expect_used, clippy::panic)]
 mod stmt_cmd_run_test;
 pub mod stmt_query;
 pub mod stmt_query_run;
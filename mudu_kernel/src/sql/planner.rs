@@ -1,3 +1,4 @@
+use crate::command::create_index::CreateIndex;
 use crate::command::create_partition_placement::CreatePartitionPlacement;
 use crate::command::create_partition_rule::CreatePartitionRule;
 use crate::command::create_table::CreateTable;
@@ -9,17 +10,22 @@ use crate::command::save_to_file::{SaveToFile, SaveToFileParams};
 use crate::command::update_key_value::UpdateKeyValue;
 use crate::contract::cmd_exec::CmdExec;
 use crate::contract::query_exec::QueryExec;
+use crate::executor::filter::{Filter, FilterPredicate};
+use crate::executor::project::{Project, ProjectPath};
+use crate::executor::sort::{Sort, SortKey};
+use crate::executor::unnest::Unnest;
 use crate::sql::bound_stmt::{
-    BoundCommand, BoundCopyFrom, BoundCopyTo, BoundCreatePartitionPlacement,
+    BoundCommand, BoundCopyFrom, BoundCopyTo, BoundCreateIndex, BoundCreatePartitionPlacement,
     BoundCreatePartitionRule, BoundCreateTable, BoundDelete, BoundDropTable, BoundInsert,
     BoundPredicate, BoundQuery, BoundSelect, BoundUpdate,
 };
 use crate::sql::plan_ctx::PlanCtx;
 use crate::x_engine::api::{OptRead, Predicate, RangeData, VecDatum, VecSelTerm};
 use crate::x_engine::x_param::{
-    PAccessKey, PAccessRange, PCreatePartitionPlacement, PCreatePartitionRule, PCreateTable,
-    PDeleteKeyValue, PDropTable, PInsertKeyValue, PUpdateKeyValue,
+    PAccessKey, PAccessRange, PCreateIndex, PCreatePartitionPlacement, PCreatePartitionRule,
+    PCreateTable, PDeleteKeyValue, PDropTable, PIndexLookup, PInsertKeyValue, PUpdateKeyValue,
 };
+use mudu::common::id::OID;
 use mudu::common::result::RS;
 use std::sync::Arc;
 
@@ -47,6 +53,7 @@ impl Planner {
                 Ok(Arc::new(self.plan_create_partition_rule(stmt)))
             }
             BoundCommand::CreateTable(stmt) => Ok(Arc::new(self.plan_create_table(stmt))),
+            BoundCommand::CreateIndex(stmt) => Ok(Arc::new(self.plan_create_index(stmt))),
             BoundCommand::DropTable(stmt) => Ok(Arc::new(self.plan_drop_table(stmt))),
             BoundCommand::Insert(stmt) => Ok(Arc::new(self.plan_insert(stmt))),
             BoundCommand::Update(stmt) => Ok(Arc::new(self.plan_update(stmt))),
@@ -57,20 +64,101 @@ impl Planner {
     }
 
     async fn plan_select(&self, stmt: BoundSelect) -> RS<Arc<dyn QueryExec>> {
-        let select = VecSelTerm::new(stmt.select_attrs.clone());
-        match stmt.predicate {
+        // Filter and sort columns that are not selected are read as extra
+        // trailing columns, which the sort or the projection drops again.
+        let width = stmt.select_attrs.len();
+        let mut select_attrs = stmt.select_attrs;
+        let mut position_of = |attr| match select_attrs.iter().position(|a| *a == attr) {
+            Some(position) => position,
+            None => {
+                select_attrs.push(attr);
+                select_attrs.len() - 1
+            }
+        };
+        let predicates = stmt
+            .filters
+            .into_iter()
+            .map(|filter| FilterPredicate {
+                position: position_of(filter.attr),
+                column_type: filter.attr_type,
+                access: filter.access,
+                op: filter.op,
+                operand: filter.operand,
+                operand_type: filter.operand_type,
+            })
+            .collect::<Vec<_>>();
+        let keys = stmt
+            .order_by
+            .into_iter()
+            .map(|term| SortKey {
+                position: position_of(term.attr),
+                dat_type: term.dat_type,
+                descending: term.descending,
+                nulls_first: term.nulls_first,
+            })
+            .collect::<Vec<_>>();
+        let paths = stmt
+            .select_paths
+            .into_iter()
+            .map(|path| {
+                path.map(|path| ProjectPath {
+                    column_type: path.attr_type,
+                    access: path.access,
+                })
+            })
+            .collect::<Vec<_>>();
+        let read_extra = select_attrs.len() > width;
+        let opt_read = OptRead { as_of: stmt.as_of };
+        let mut exec = self
+            .plan_scan(
+                stmt.table_id,
+                VecSelTerm::new(select_attrs),
+                stmt.predicate,
+                opt_read,
+            )
+            .await?;
+        if !predicates.is_empty() {
+            exec = Arc::new(Filter::new(exec, predicates));
+        }
+        // The sort drops the extra columns itself.
+        let drop_extra = read_extra && keys.is_empty();
+        if !keys.is_empty() {
+            exec = Arc::new(Sort::new(exec, keys, width, stmt.tuple_desc.clone()));
+        }
+        if drop_extra || paths.iter().any(Option::is_some) {
+            exec = Arc::new(Project::new(exec, paths, stmt.tuple_desc.clone()));
+        }
+        if let Some(unnest) = stmt.unnest {
+            exec = Arc::new(Unnest::new(
+                exec,
+                unnest.position,
+                unnest.array_type,
+                stmt.tuple_desc,
+            ));
+        }
+        Ok(exec)
+    }
+
+    async fn plan_scan(
+        &self,
+        table_id: OID,
+        select: VecSelTerm,
+        predicate: BoundPredicate,
+        opt_read: OptRead,
+    ) -> RS<Arc<dyn QueryExec>> {
+        match predicate {
             BoundPredicate::True => {
                 let exec = crate::executor::index_access_range::IndexAccessRange::new(
                     PAccessRange {
                         tx_mgr: self.ctx.tx_mgr.clone(),
-                        table_id: stmt.table_id,
+                        table_id,
                         pred_key: RangeData::new(
                             std::ops::Bound::Unbounded,
                             std::ops::Bound::Unbounded,
                         ),
                         pred_non_key: Predicate::CNF(Vec::new()),
                         select,
-                        opt_read: OptRead::default(),
+                        opt_read,
                     },
                     self.ctx.x_contract.clone(),
                     self.ctx.meta_mgr.clone(),
@@ -82,10 +170,10 @@ impl Planner {
                 let exec = crate::executor::index_access_key::IndexAccessKey::new(
                     PAccessKey {
                         tx_mgr: self.ctx.tx_mgr.clone(),
-                        table_id: stmt.table_id,
+                        table_id,
                         pred_key: VecDatum::new(key),
                         select,
-                        opt_read: OptRead::default(),
+                        opt_read,
                     },
                     self.ctx.x_contract.clone(),
                     self.ctx.meta_mgr.clone(),
@@ -97,14 +185,14 @@ impl Planner {
                 let exec = crate::executor::index_access_range::IndexAccessRange::new(
                     PAccessRange {
                         tx_mgr: self.ctx.tx_mgr.clone(),
-                        table_id: stmt.table_id,
+                        table_id,
                         pred_key: RangeData::new(
                             std::ops::Bound::Unbounded,
                             std::ops::Bound::Unbounded,
                         ),
                         pred_non_key: Predicate::KeyPrefixEq(prefix),
                         select,
-                        opt_read: OptRead::default(),
+                        opt_read,
                     },
                     self.ctx.x_contract.clone(),
                     self.ctx.meta_mgr.clone(),
@@ -116,11 +204,32 @@ impl Planner {
                 let exec = crate::executor::index_access_range::IndexAccessRange::new(
                     PAccessRange {
                         tx_mgr: self.ctx.tx_mgr.clone(),
-                        table_id: stmt.table_id,
+                        table_id,
                         pred_key: RangeData::new(start, end),
                         pred_non_key: Predicate::CNF(Vec::new()),
                         select,
-                        opt_read: OptRead::default(),
+                        opt_read,
+                    },
+                    self.ctx.x_contract.clone(),
+                    self.ctx.meta_mgr.clone(),
+                )
+                .await?;
+                Ok(Arc::new(exec))
+            }
+            BoundPredicate::IndexPrefixEq {
+                index_id,
+                prefix,
+                base_key,
+            } => {
+                let exec = crate::executor::index_lookup::IndexLookup::new(
+                    PIndexLookup {
+                        tx_mgr: self.ctx.tx_mgr.clone(),
+                        table_id,
+                        index_id,
+                        prefix,
+                        base_key,
+                        select,
+                        opt_read,
                     },
                     self.ctx.x_contract.clone(),
                     self.ctx.meta_mgr.clone(),
@@ -166,6 +275,17 @@ impl Planner {
         )
     }
 
+    fn plan_create_index(&self, stmt: BoundCreateIndex) -> CreateIndex {
+        CreateIndex::new(
+            PCreateIndex {
+                tx_mgr: self.ctx.tx_mgr.clone(),
+                schema: stmt.schema,
+            },
+            self.ctx.x_contract.clone(),
+            self.ctx.meta_mgr.clone(),
+        )
+    }
+
     fn plan_drop_table(&self, stmt: BoundDropTable) -> DropTable {
         DropTable::new(
             PDropTable {
@@ -544,10 +664,15 @@ mod tests {
                 .plan_query(BoundQuery::Select(BoundSelect {
                     table_id: meta_mgr.table_id(),
                     select_attrs: vec![0],
+                    select_paths: vec![None],
                     tuple_desc: TupleFieldDesc::new(Vec::new()),
                     predicate: BoundPredicate::KeyEq {
                         key: vec![(0, vec![1]), (1, vec![2])],
                     },
+                    filters: Vec::new(),
+                    as_of: None,
+                    order_by: Vec::new(),
+                    unnest: None,
                 }))
                 .await
                 .unwrap();
@@ -576,10 +701,15 @@ mod tests {
                 .plan_query(BoundQuery::Select(BoundSelect {
                     table_id: meta_mgr.table_id(),
                     select_attrs: vec![0],
+                    select_paths: vec![None],
                     tuple_desc: TupleFieldDesc::new(Vec::new()),
                     predicate: BoundPredicate::KeyPrefixEq {
                         prefix: vec![(0, vec![1])],
                     },
+                    filters: Vec::new(),
+                    as_of: None,
+                    order_by: Vec::new(),
+                    unnest: None,
                 }))
                 .await
                 .unwrap();
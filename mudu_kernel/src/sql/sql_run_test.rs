@@ -0,0 +1,613 @@
+// End-to-end SQL tests: statements are parsed, bound, planned and run
+// against a worker x-contract.
+#[cfg(test)]
+mod tests {
+    #![allow(
+        clippy::unwrap_used,
+        clippy::expect_used,
+        clippy::panic,
+        clippy::todo,
+        clippy::unimplemented
+    )]
+
+    use crate::mudu_conn::mudu_conn_core::MuduConnCore;
+    use crate::server::test_meta_mgr::TestMetaMgr;
+    use crate::server::x_contract::WorkerXContract;
+    use crate::x_engine::api::XContract;
+    use mudu::common::result::RS;
+    use mudu::error::ErrorCode;
+    use mudu_type::dat_value::DatValue;
+    use std::sync::Arc;
+
+    struct SqlHarness {
+        core: MuduConnCore,
+        x_contract: Arc<dyn XContract>,
+    }
+
+    impl SqlHarness {
+        fn new() -> Self {
+            let meta_mgr = Arc::new(TestMetaMgr::new());
+            let x_contract = Arc::new(WorkerXContract::with_log(meta_mgr.clone(), None).unwrap());
+            Self {
+                core: MuduConnCore::new(meta_mgr, None).unwrap(),
+                x_contract,
+            }
+        }
+
+        async fn execute(&self, sql: &str) -> RS<u64> {
+            let stmt = self.core.parse_one(&sql)?;
+            let tx = self.x_contract.begin_tx().await?;
+            let result = self
+                .core
+                .execute(stmt, Box::new(()), tx.clone(), self.x_contract.clone())
+                .await;
+            match result {
+                Ok(rows) => {
+                    self.x_contract.commit_tx(tx).await?;
+                    Ok(rows)
+                }
+                Err(e) => {
+                    self.x_contract.abort_tx(tx).await?;
+                    Err(e)
+                }
+            }
+        }
+
+        async fn query(&self, sql: &str) -> RS<Vec<Vec<DatValue>>> {
+            let stmt = self.core.parse_one(&sql)?;
+            let tx = self.x_contract.begin_tx().await?;
+            let result = self
+                .core
+                .query_rows(stmt, Box::new(()), tx.clone(), self.x_contract.clone())
+                .await;
+            self.x_contract.abort_tx(tx).await?;
+            let (rows, _desc) = result?;
+            Ok(rows.into_iter().map(|row| row.into()).collect())
+        }
+
+        async fn query_i32(&self, sql: &str) -> Vec<Option<i32>> {
+            self.query(sql)
+                .await
+                .unwrap()
+                .into_iter()
+                .map(|row| {
+                    let value = &row[0];
+                    (!value.is_null()).then(|| value.to_i32())
+                })
+                .collect()
+        }
+    }
+
+    fn run<F: std::future::Future<Output = ()> + Send + 'static>(fut: F) {
+        mudu_sys::task::async_::block_on_tokio_current_thread(fut).unwrap()
+    }
+
+    #[test]
+    #[cfg_attr(miri, ignore)]
+    fn order_by_sorts_through_the_collation() {
+        run(async {
+            let sql = SqlHarness::new();
+            sql.execute(
+                "create table people (id int primary key, label varchar(32) collate ascii_ci, \
+                 score int)",
+            )
+            .await
+            .unwrap();
+            sql.execute(
+                "insert into people (id, label, score) values \
+                 (1, 'bob', 3), (2, 'Alice', 1), (3, 'carol', 2), (4, 'Dave', 2)",
+            )
+            .await
+            .unwrap();
+            sql.execute("insert into people (id, label) values (5, 'eve')")
+                .await
+                .unwrap();
+
+            // Bytewise, 'Dave' would sort before 'bob'.
+            assert_eq!(
+                sql.query_i32("select id from people order by label").await,
+                vec![Some(2), Some(1), Some(3), Some(4), Some(5)]
+            );
+            assert_eq!(
+                sql.query_i32("select id from people order by label desc")
+                    .await,
+                vec![Some(5), Some(4), Some(3), Some(1), Some(2)]
+            );
+            // NULLs sort last ascending and first descending unless told
+            // otherwise; ties keep their scan order.
+            assert_eq!(
+                sql.query_i32("select score from people order by score, id desc")
+                    .await,
+                vec![Some(1), Some(2), Some(2), Some(3), None]
+            );
+            assert_eq!(
+                sql.query_i32("select id from people order by score desc, id")
+                    .await,
+                vec![Some(5), Some(1), Some(3), Some(4), Some(2)]
+            );
+            assert_eq!(
+                sql.query_i32("select id from people order by score nulls first, id desc")
+                    .await,
+                vec![Some(5), Some(2), Some(4), Some(3), Some(1)]
+            );
+
+            let err = sql
+                .query("select id from people order by missing")
+                .await
+                .unwrap_err();
+            assert_eq!(err.ec(), ErrorCode::EntityNotFound);
+        });
+    }
+
+    #[test]
+    #[cfg_attr(miri, ignore)]
+    fn ascii_ci_primary_keys_equal_under_the_collation_are_one_row() {
+        run(async {
+            let sql = SqlHarness::new();
+            sql.execute(
+                "create table handles (handle varchar(32) collate ascii_ci primary key, \
+                 owner int)",
+            )
+            .await
+            .unwrap();
+            sql.execute("insert into handles (handle, owner) values ('Alice', 1)")
+                .await
+                .unwrap();
+
+            let err = sql
+                .execute("insert into handles (handle, owner) values ('alice', 2)")
+                .await
+                .unwrap_err();
+            assert_eq!(err.ec(), ErrorCode::EntityAlreadyExists);
+
+            sql.execute("update handles set owner = 3 where handle = 'ALICE'")
+                .await
+                .unwrap();
+            assert_eq!(
+                sql.query_i32("select owner from handles where handle = 'aLiCe'")
+                    .await,
+                vec![Some(3)]
+            );
+            assert_eq!(
+                sql.query_i32("select owner from handles").await,
+                vec![Some(3)]
+            );
+        });
+    }
+
+    #[test]
+    #[cfg_attr(miri, ignore)]
+    fn secondary_index_lookups_compare_through_the_collation() {
+        run(async {
+            let sql = SqlHarness::new();
+            sql.execute(
+                "create table people (id int primary key, label varchar(32) collate ascii_ci, \
+                 score int)",
+            )
+            .await
+            .unwrap();
+            sql.execute(
+                "insert into people (id, label, score) values \
+                 (1, 'Alice', 10), (2, 'bob', 20), (3, 'ALICE', 30)",
+            )
+            .await
+            .unwrap();
+            sql.execute("insert into people (id, score) values (4, 40)")
+                .await
+                .unwrap();
+
+            // Without an index, non-key predicates are not supported.
+            let err = sql
+                .query("select id from people where label = 'alice'")
+                .await
+                .unwrap_err();
+            assert_eq!(err.ec(), ErrorCode::NotImplemented);
+
+            // Existing rows are indexed when the index is created, later
+            // writes keep it in sync.
+            sql.execute("create index people_label on people (label)")
+                .await
+                .unwrap();
+            sql.execute("insert into people (id, label, score) values (5, 'alice', 50)")
+                .await
+                .unwrap();
+            assert_eq!(
+                sql.query_i32("select id from people where label = 'alice' order by id")
+                    .await,
+                vec![Some(1), Some(3), Some(5)]
+            );
+            assert_eq!(
+                sql.query_i32("select score from people where label = 'aLiCe' AND id = 3")
+                    .await,
+                vec![Some(30)]
+            );
+
+            sql.execute("update people set label = 'Bob' where id = 3")
+                .await
+                .unwrap();
+            sql.execute("update people set score = 11 where id = 1")
+                .await
+                .unwrap();
+            sql.execute("delete from people where id = 5")
+                .await
+                .unwrap();
+            assert_eq!(
+                sql.query_i32("select score from people where label = 'ALICE'")
+                    .await,
+                vec![Some(11)]
+            );
+            assert_eq!(
+                sql.query_i32("select id from people where label = 'BOB' order by id")
+                    .await,
+                vec![Some(2), Some(3)]
+            );
+
+            let err = sql
+                .execute("create index people_label on people (score)")
+                .await
+                .unwrap_err();
+            assert_eq!(err.ec(), ErrorCode::EntityAlreadyExists);
+            let err = sql
+                .query("select id from people where score = 20")
+                .await
+                .unwrap_err();
+            assert_eq!(err.ec(), ErrorCode::NotImplemented);
+
+            // Dropping the table drops its indexes.
+            sql.execute("drop table people").await.unwrap();
+            let err = sql.query("select id from people_label").await.unwrap_err();
+            assert_eq!(err.ec(), ErrorCode::EntityNotFound);
+        });
+    }
+
+    #[test]
+    #[cfg_attr(miri, ignore)]
+    fn secondary_index_lookups_follow_base_table_writes() {
+        run(async {
+            let sql = SqlHarness::new();
+            sql.execute("create table people (id int primary key, label varchar(32), score int)")
+                .await
+                .unwrap();
+            sql.execute(
+                "insert into people (id, label, score) values \
+                 (1, 'alice', 10), (2, 'bob', 20), (3, 'alice', 30)",
+            )
+            .await
+            .unwrap();
+            sql.execute("insert into people (id, score) values (4, 40)")
+                .await
+                .unwrap();
+
+            // Without an index, non-key predicates are not supported.
+            let err = sql
+                .query("select id from people where label = 'alice'")
+                .await
+                .unwrap_err();
+            assert_eq!(err.ec(), ErrorCode::NotImplemented);
+
+            // Existing rows are indexed when the index is created, later
+            // writes keep it in sync.
+            sql.execute("create index people_label on people (label)")
+                .await
+                .unwrap();
+            sql.execute("insert into people (id, label, score) values (5, 'alice', 50)")
+                .await
+                .unwrap();
+            assert_eq!(
+                sql.query_i32("select id from people where label = 'alice'")
+                    .await,
+                vec![Some(1), Some(3), Some(5)]
+            );
+            assert_eq!(
+                sql.query_i32("select score from people where label = 'alice' AND id = 3")
+                    .await,
+                vec![Some(30)]
+            );
+
+            sql.execute("update people set label = 'bob' where id = 3")
+                .await
+                .unwrap();
+            sql.execute("update people set score = 11 where id = 1")
+                .await
+                .unwrap();
+            sql.execute("delete from people where id = 5")
+                .await
+                .unwrap();
+            assert_eq!(
+                sql.query_i32("select score from people where label = 'alice'")
+                    .await,
+                vec![Some(11)]
+            );
+            assert_eq!(
+                sql.query_i32("select id from people where label = 'bob'")
+                    .await,
+                vec![Some(2), Some(3)]
+            );
+
+            let err = sql
+                .execute("create index people_label on people (score)")
+                .await
+                .unwrap_err();
+            assert_eq!(err.ec(), ErrorCode::EntityAlreadyExists);
+            let err = sql
+                .query("select id from people where score = 20")
+                .await
+                .unwrap_err();
+            assert_eq!(err.ec(), ErrorCode::NotImplemented);
+
+            // Dropping the table drops its indexes.
+            sql.execute("drop table people").await.unwrap();
+            let err = sql.query("select id from people_label").await.unwrap_err();
+            assert_eq!(err.ec(), ErrorCode::EntityNotFound);
+        });
+    }
+
+    #[test]
+    #[cfg_attr(miri, ignore)]
+    fn json_paths_extract_filter_and_index_values() {
+        run(async {
+            let sql = SqlHarness::new();
+            sql.execute("create table docs (id int primary key, note varchar(32), doc json)")
+                .await
+                .unwrap();
+            sql.execute(
+                "insert into docs (id, note, doc) values \
+                 (1, 'a', '{\"name\":\"bob\",\"tags\":[\"x\",\"y\"],\"age\":30}'), \
+                 (2, 'b', '{\"name\":\"amy\",\"tags\":[\"y\"]}'), \
+                 (3, 'c', '{\"age\":null}')",
+            )
+            .await
+            .unwrap();
+
+            let rows = sql
+                .query("select id, doc -> 'name', doc ->> 'name' from docs")
+                .await
+                .unwrap();
+            let values = rows
+                .iter()
+                .map(|row| {
+                    (
+                        row[0].to_i32(),
+                        (!row[1].is_null()).then(|| row[1].expect_json().clone()),
+                        (!row[2].is_null()).then(|| row[2].expect_string().clone()),
+                    )
+                })
+                .collect::<Vec<_>>();
+            assert_eq!(
+                values,
+                vec![
+                    (1, Some(serde_json::json!("bob")), Some("bob".to_string())),
+                    (2, Some(serde_json::json!("amy")), Some("amy".to_string())),
+                    (3, None, None),
+                ]
+            );
+
+            // `json_extract` and chained arrows read the same paths; a JSON
+            // null read as text is NULL.
+            let rows = sql
+                .query(
+                    "select json_extract(doc, '$.tags[1]'), doc -> 'tags' ->> 0, \
+                     doc ->> 'age' from docs where id = 1",
+                )
+                .await
+                .unwrap();
+            assert_eq!(rows[0][0].expect_json(), &serde_json::json!("y"));
+            assert_eq!(rows[0][1].expect_string(), "x");
+            assert_eq!(rows[0][2].expect_string(), "30");
+            let rows = sql
+                .query("select doc -> 'age', doc ->> 'age' from docs where id = 3")
+                .await
+                .unwrap();
+            assert_eq!(rows[0][0].expect_json(), &serde_json::Value::Null);
+            assert!(rows[0][1].is_null());
+
+            // Path and containment predicates filter the scanned rows.
+            assert_eq!(
+                sql.query_i32("select id from docs where doc ->> 'name' = 'amy'")
+                    .await,
+                vec![Some(2)]
+            );
+            assert_eq!(
+                sql.query_i32("select id from docs where doc @> '{\"tags\":[\"y\"]}'")
+                    .await,
+                vec![Some(1), Some(2)]
+            );
+            assert_eq!(
+                sql.query_i32("select id from docs where '{\"name\":\"bob\"}' <@ doc AND id = 1")
+                    .await,
+                vec![Some(1)]
+            );
+            assert_eq!(
+                sql.query_i32("select id from docs where doc -> 'age' > '20'")
+                    .await,
+                vec![Some(1)]
+            );
+
+            // A path index is backfilled, maintained and used for lookups.
+            sql.execute("create index docs_name on docs ((doc ->> 'name'))")
+                .await
+                .unwrap();
+            sql.execute("insert into docs (id, note, doc) values (4, 'd', '{\"name\":\"bob\"}')")
+                .await
+                .unwrap();
+            sql.execute("update docs set doc = '{\"name\":\"amy\"}' where id = 1")
+                .await
+                .unwrap();
+            assert_eq!(
+                sql.query_i32("select id from docs where doc ->> 'name' = 'bob'")
+                    .await,
+                vec![Some(4)]
+            );
+            assert_eq!(
+                sql.query_i32("select id from docs where doc ->> 'name' = 'amy'")
+                    .await,
+                vec![Some(1), Some(2)]
+            );
+            sql.execute("create index docs_tags on docs (json_extract(doc, '$.tags'))")
+                .await
+                .unwrap();
+            assert_eq!(
+                sql.query_i32("select id from docs where doc -> 'tags' = '[\"y\"]'")
+                    .await,
+                vec![Some(2)]
+            );
+
+            // Paths only apply to json columns, and only select filters on them.
+            let err = sql.query("select note -> 'a' from docs").await.unwrap_err();
+            assert_eq!(err.ec(), ErrorCode::InvalidType);
+            let err = sql
+                .query("select id from docs where doc ->> 'name' @> '{}'")
+                .await
+                .unwrap_err();
+            assert_eq!(err.ec(), ErrorCode::InvalidType);
+            let err = sql
+                .execute("create index docs_note on docs ((note ->> 'a'))")
+                .await
+                .unwrap_err();
+            assert_eq!(err.ec(), ErrorCode::InvalidType);
+            let err = sql
+                .execute("delete from docs where doc ->> 'name' = 'amy'")
+                .await
+                .unwrap_err();
+            assert_eq!(err.ec(), ErrorCode::NotImplemented);
+        });
+    }
+
+    #[test]
+    #[cfg_attr(miri, ignore)]
+    fn arrays_and_records_are_written_read_and_filtered() {
+        run(async {
+            let sql = SqlHarness::new();
+            sql.execute(
+                "create table posts (id int primary key, tags text[], scores int[], \
+                 pos ROW(x int, y int, label text))",
+            )
+            .await
+            .unwrap();
+            sql.execute(
+                "insert into posts (id, tags, scores, pos) values \
+                 (1, ARRAY['a', 'b'], ARRAY[10, 20, 30], ROW(1, 2, 'home')), \
+                 (2, ARRAY['b'], ARRAY[], ROW(3, 4, 'work')), \
+                 (3, '[\"c\"]', '[5]', '{\"x\": 5, \"y\": 6, \"label\": \"park\"}')",
+            )
+            .await
+            .unwrap();
+
+            let rows = sql
+                .query("select id, tags, pos, scores[2] from posts")
+                .await
+                .unwrap();
+            let strings = |value: &DatValue| {
+                value
+                    .expect_array()
+                    .iter()
+                    .map(|v| v.expect_string().clone())
+                    .collect::<Vec<_>>()
+            };
+            assert_eq!(strings(&rows[0][1]), vec!["a", "b"]);
+            assert_eq!(strings(&rows[2][1]), vec!["c"]);
+            let pos = rows[0][2].expect_record();
+            assert_eq!(
+                (
+                    pos[0].to_i32(),
+                    pos[1].to_i32(),
+                    pos[2].expect_string().as_str()
+                ),
+                (1, 2, "home")
+            );
+            assert_eq!(rows[2][2].expect_record()[2].expect_string(), "park");
+            // Subscripts are 1-based; out of range is NULL.
+            assert_eq!(rows[0][3].to_i32(), 20);
+            assert!(rows[1][3].is_null());
+            assert!(rows[2][3].is_null());
+
+            // `= ANY` and element predicates filter the scanned rows.
+            assert_eq!(
+                sql.query_i32("select id from posts where 'b' = ANY(tags)")
+                    .await,
+                vec![Some(1), Some(2)]
+            );
+            assert_eq!(
+                sql.query_i32("select id from posts where 5 = ANY(scores)")
+                    .await,
+                vec![Some(3)]
+            );
+            assert_eq!(
+                sql.query_i32("select id from posts where scores[1] >= 5 AND id > 1")
+                    .await,
+                vec![Some(3)]
+            );
+
+            // `unnest` yields one row per element, in array order.
+            let rows = sql
+                .query("select id, unnest(scores) from posts")
+                .await
+                .unwrap();
+            let values = rows
+                .iter()
+                .map(|row| (row[0].to_i32(), row[1].to_i32()))
+                .collect::<Vec<_>>();
+            assert_eq!(values, vec![(1, 10), (1, 20), (1, 30), (3, 5)]);
+            let rows = sql
+                .query("select unnest(tags) from posts where id = 1")
+                .await
+                .unwrap();
+            assert_eq!(rows.len(), 2);
+            assert_eq!(rows[1][0].expect_string(), "b");
+
+            // Arrays are replaced as a whole.
+            sql.execute("update posts set scores = ARRAY[7, 8] where id = 2")
+                .await
+                .unwrap();
+            assert_eq!(
+                sql.query_i32("select scores[2] from posts where id = 2")
+                    .await,
+                vec![Some(8)]
+            );
+
+            for (bad, ec) in [
+                (
+                    "insert into posts (id, scores) values (4, ARRAY['x'])",
+                    ErrorCode::TypeConversionFailed,
+                ),
+                (
+                    "insert into posts (id, pos) values (4, ROW(1, 2))",
+                    ErrorCode::TypeConversionFailed,
+                ),
+                (
+                    "insert into posts (id, scores) values (4, ROW(1))",
+                    ErrorCode::TypeConversionFailed,
+                ),
+                (
+                    "insert into posts (id, scores) values (4, ARRAY[1, NULL])",
+                    ErrorCode::NotImplemented,
+                ),
+            ] {
+                assert_eq!(sql.execute(bad).await.unwrap_err().ec(), ec, "{}", bad);
+            }
+            for (bad, ec) in [
+                ("select id[1] from posts", ErrorCode::InvalidType),
+                (
+                    "select id from posts where 1 = ANY(id)",
+                    ErrorCode::InvalidType,
+                ),
+                ("select unnest(id) from posts", ErrorCode::InvalidType),
+                (
+                    "select unnest(tags), unnest(scores) from posts",
+                    ErrorCode::NotImplemented,
+                ),
+                (
+                    "delete from posts where 'a' = ANY(tags)",
+                    ErrorCode::NotImplemented,
+                ),
+            ] {
+                let err = if bad.starts_with("select") {
+                    sql.query(bad).await.unwrap_err()
+                } else {
+                    sql.execute(bad).await.unwrap_err()
+                };
+                assert_eq!(err.ec(), ec, "{}", bad);
+            }
+        });
+    }
+}
@@ -6,10 +6,11 @@ use crate::sql::stmt_query::StmtQuery;
 use futures::stream;
 use futures::Stream;
 use mudu::common::result::RS;
+use mudu::data_type::uuid::UuidValue;
 use mudu::error::ErrorCode as ER;
 use mudu::mudu_error;
 use mudu_type::dat_type_id::{DatTypeID as TypeID, DatTypeID};
-use pgwire::api::portal::Format;
+use pgwire::api::portal::{FieldFormat, Format};
 use pgwire::api::results::{DataRowEncoder, FieldInfo};
 use pgwire::api::Type as PGDataType;
 use pgwire::error::{PgWireError, PgWireResult};
@@ -80,11 +81,17 @@ fn to_pg_field_info(rd: &ProjList, format: &Format) -> RS<Vec<FieldInfo>> {
 
 fn dt_id_to_pg_type(dt: TypeID) -> RS<PGDataType> {
     match dt {
+        TypeID::Bool => Ok(PGDataType::BOOL),
+        TypeID::I8 | TypeID::I16 => Ok(PGDataType::INT2),
         TypeID::I32 => Ok(PGDataType::INT4),
         TypeID::I64 => Ok(PGDataType::INT8),
         TypeID::F32 => Ok(PGDataType::FLOAT4),
         TypeID::F64 => Ok(PGDataType::FLOAT8),
         TypeID::String => Ok(PGDataType::TEXT),
+        TypeID::Uuid => Ok(PGDataType::UUID),
+        TypeID::Json => Ok(PGDataType::JSONB),
+        // Arrays and records are sent in their textual (JSON) form.
+        TypeID::Array | TypeID::Record => Ok(PGDataType::TEXT),
         _ => Err(mudu_error!(
             ER::InvalidType,
             format!("unsupported projection type for pgwire: {:?}", dt)
@@ -122,11 +129,25 @@ async fn encode_pg_wire_row_data(
                     .map_err(|e| mudu_error!(ER::TypeConversionFailed, "recv error", e))?;
 
                 let r = match dat_type_id {
+                    DatTypeID::Bool => encoder.encode_field(&internal.to_bool()),
+                    DatTypeID::I8 => encoder.encode_field(&i16::from(internal.to_i8())),
+                    DatTypeID::I16 => encoder.encode_field(&internal.to_i16()),
                     DatTypeID::I32 => encoder.encode_field(&internal.to_i32()),
                     DatTypeID::I64 => encoder.encode_field(&internal.to_i64()),
                     DatTypeID::F32 => encoder.encode_field(&internal.to_f32()),
                     DatTypeID::F64 => encoder.encode_field(&internal.to_f64()),
                     DatTypeID::String => encoder.encode_field(internal.expect_string()),
+                    DatTypeID::Uuid => {
+                        encode_uuid_field(&mut encoder, &fields[idx], internal.expect_uuid())
+                    }
+                    DatTypeID::Json => encoder.encode_field(&internal.expect_json().to_string()),
+                    DatTypeID::Array | DatTypeID::Record => {
+                        let text = dat_type_id.fn_output()(&internal, field_desc.dat_type())
+                            .map_err(|e| {
+                                mudu_error!(ER::TypeConversionFailed, "output error", e)
+                            })?;
+                        encoder.encode_field(&text.into())
+                    }
                     _ => {
                         has_err = true;
                         results.push(Err(PgWireError::ApiError(Box::new(mudu_error!(
@@ -154,3 +175,16 @@ async fn encode_pg_wire_row_data(
 
     Ok(stream::iter(results))
 }
+
+/// Encodes a UUID field: its 16 bytes in binary format, the hyphenated form
+/// in text format.
+pub fn encode_uuid_field(
+    encoder: &mut DataRowEncoder,
+    field: &FieldInfo,
+    uuid: &UuidValue,
+) -> PgWireResult<()> {
+    match field.format() {
+        FieldFormat::Binary => encoder.encode_field(&uuid.to_u128().to_be_bytes().to_vec()),
+        FieldFormat::Text => encoder.encode_field(&uuid.format()),
+    }
+}
@@ -13,19 +13,26 @@ mod tests {
     use crate::sql::proj_field::ProjField;
     use crate::sql::proj_list::ProjList;
     use crate::sql::stmt_query::StmtQuery;
-    use crate::sql::stmt_query_run::run_query_stmt;
+    use crate::sql::stmt_query_run::{encode_uuid_field, run_query_stmt};
     use async_trait::async_trait;
     use futures::StreamExt;
     use mudu::common::id::OID;
     use mudu::common::result::RS;
+    use mudu::data_type::uuid::UuidValue;
     use mudu::error::ErrorCode;
+    use mudu::json_value;
     use mudu::mudu_error;
     use mudu_contract::tuple::datum_desc::DatumDesc;
     use mudu_contract::tuple::tuple_field::TupleField;
     use mudu_contract::tuple::tuple_field_desc::TupleFieldDesc;
     use mudu_sys::sync::SMutex;
+    use mudu_type::array::new_array_type;
     use mudu_type::dat_type::DatType;
     use mudu_type::dat_type_id::DatTypeID;
+    use mudu_type::dat_value::DatValue;
+    use mudu_type::datum::DatumDyn;
+    use pgwire::api::portal::FieldFormat;
+    use pgwire::api::results::{DataRowEncoder, FieldInfo};
     use pgwire::api::Type as PGDataType;
     use pgwire::error::PgWireError;
     use std::collections::VecDeque;
@@ -263,6 +270,160 @@ mod tests {
         .unwrap()
     }
 
+    #[test]
+    fn run_query_stmt_bool_small_int_and_uuid_row() {
+        mudu_sys::task::async_::block_on_tokio_current_thread(async move {
+            let ctx = TestSsnCtx::default();
+            let types = [
+                DatTypeID::Bool,
+                DatTypeID::I8,
+                DatTypeID::I16,
+                DatTypeID::Uuid,
+            ];
+            let proj_list = ProjList::new(
+                types
+                    .iter()
+                    .enumerate()
+                    .map(|(i, id)| ProjField::new(i, format!("c{}", i), DatType::default_for(*id)))
+                    .collect(),
+            );
+            let tuple_desc = TupleFieldDesc::new(
+                types
+                    .iter()
+                    .enumerate()
+                    .map(|(i, id)| DatumDesc::new(format!("c{}", i), DatType::default_for(*id)))
+                    .collect(),
+            );
+            let row = TupleField::new(vec![
+                vec![1u8],
+                vec![0xffu8],
+                300i16.to_be_bytes().to_vec(),
+                0x123e4567_e89b_12d3_a456_426614174000u128
+                    .to_be_bytes()
+                    .to_vec(),
+            ]);
+            let stmt = TestStmtQuery {
+                fail_realize: false,
+                fail_build: false,
+                exec: Arc::new(TestQueryExec {
+                    rows: SMutex::new(VecDeque::from(vec![row])),
+                    tuple_desc,
+                    open_error: false,
+                }),
+                proj_list,
+            };
+
+            let (fields, mut stream) = run_query_stmt(&stmt, &ctx).await.unwrap();
+            assert_eq!(*fields[0].datatype(), PGDataType::BOOL);
+            assert_eq!(*fields[1].datatype(), PGDataType::INT2);
+            assert_eq!(*fields[2].datatype(), PGDataType::INT2);
+            assert_eq!(*fields[3].datatype(), PGDataType::UUID);
+
+            let row = stream.next().await.unwrap().unwrap();
+            assert_eq!(row.field_count, 4);
+            let uuid = b"123e4567-e89b-12d3-a456-426614174000";
+            let mut expected = (uuid.len() as i32).to_be_bytes().to_vec();
+            expected.extend_from_slice(uuid);
+            assert!(row.data.ends_with(&expected));
+            assert!(stream.next().await.is_none());
+        })
+        .unwrap()
+    }
+
+    #[test]
+    fn encode_uuid_field_sends_sixteen_bytes_in_binary_format() {
+        let uuid = UuidValue::from_u128(0x123e4567_e89b_12d3_a456_426614174000);
+        let field = |format| FieldInfo::new("u".to_string(), None, None, PGDataType::UUID, format);
+        let binary = Arc::new(vec![field(FieldFormat::Binary)]);
+        let mut encoder = DataRowEncoder::new(binary.clone());
+        encode_uuid_field(&mut encoder, &binary[0], &uuid).unwrap();
+        let mut expected = 16i32.to_be_bytes().to_vec();
+        expected.extend_from_slice(&uuid.to_u128().to_be_bytes());
+        assert_eq!(encoder.take_row().data.as_ref(), expected.as_slice());
+
+        let text = Arc::new(vec![field(FieldFormat::Text)]);
+        let mut encoder = DataRowEncoder::new(text.clone());
+        encode_uuid_field(&mut encoder, &text[0], &uuid).unwrap();
+        let mut expected = 36i32.to_be_bytes().to_vec();
+        expected.extend_from_slice(uuid.format().as_bytes());
+        assert_eq!(encoder.take_row().data.as_ref(), expected.as_slice());
+    }
+
+    #[test]
+    fn run_query_stmt_json_row() {
+        mudu_sys::task::async_::block_on_tokio_current_thread(async move {
+            let ctx = TestSsnCtx::default();
+            let dat_type = DatType::default_for(DatTypeID::Json);
+            let proj_list =
+                ProjList::new(vec![ProjField::new(0, "c0".to_string(), dat_type.clone())]);
+            let tuple_desc =
+                TupleFieldDesc::new(vec![DatumDesc::new("c0".to_string(), dat_type.clone())]);
+            let binary = DatValue::from_json(json_value!({"tags": ["a"]}))
+                .to_binary(&dat_type)
+                .unwrap();
+            let stmt = TestStmtQuery {
+                fail_realize: false,
+                fail_build: false,
+                exec: Arc::new(TestQueryExec {
+                    rows: SMutex::new(VecDeque::from(vec![TupleField::new(vec![binary
+                        .as_ref()
+                        .to_vec()])])),
+                    tuple_desc,
+                    open_error: false,
+                }),
+                proj_list,
+            };
+
+            let (fields, mut stream) = run_query_stmt(&stmt, &ctx).await.unwrap();
+            assert_eq!(*fields[0].datatype(), PGDataType::JSONB);
+
+            let row = stream.next().await.unwrap().unwrap();
+            assert_eq!(row.field_count, 1);
+            assert!(stream.next().await.is_none());
+        })
+        .unwrap()
+    }
+
+    #[test]
+    fn run_query_stmt_array_row_as_text() {
+        mudu_sys::task::async_::block_on_tokio_current_thread(async move {
+            let ctx = TestSsnCtx::default();
+            let dat_type = new_array_type(DatType::new_no_param(DatTypeID::I32));
+            let proj_list =
+                ProjList::new(vec![ProjField::new(0, "c0".to_string(), dat_type.clone())]);
+            let tuple_desc =
+                TupleFieldDesc::new(vec![DatumDesc::new("c0".to_string(), dat_type.clone())]);
+            let binary = DatValue::from_array(vec![DatValue::from_i32(1), DatValue::from_i32(2)])
+                .to_binary(&dat_type)
+                .unwrap();
+            let stmt = TestStmtQuery {
+                fail_realize: false,
+                fail_build: false,
+                exec: Arc::new(TestQueryExec {
+                    rows: SMutex::new(VecDeque::from(vec![TupleField::new(vec![binary
+                        .as_ref()
+                        .to_vec()])])),
+                    tuple_desc,
+                    open_error: false,
+                }),
+                proj_list,
+            };
+
+            let (fields, mut stream) = run_query_stmt(&stmt, &ctx).await.unwrap();
+            assert_eq!(*fields[0].datatype(), PGDataType::TEXT);
+
+            let row = stream.next().await.unwrap().unwrap();
+            assert_eq!(row.field_count, 1);
+            // One text field: its length, then the array in its JSON form.
+            let text = b"[1,2]";
+            let mut expected = (text.len() as i32).to_be_bytes().to_vec();
+            expected.extend_from_slice(text);
+            assert_eq!(row.data.as_ref(), expected.as_slice());
+            assert!(stream.next().await.is_none());
+        })
+        .unwrap()
+    }
+
     #[test]
     fn run_query_stmt_multiple_rows_in_order() {
         mudu_sys::task::async_::block_on_tokio_current_thread(async move {
@@ -1,11 +1,14 @@
 use mudu::common::buf::Buf;
 use mudu::common::result::RS;
 use mudu::data_type::numeric::Numeric;
+use mudu::data_type::uuid::UuidValue;
 use mudu::error::ErrorCode as ER;
 use mudu::mudu_error;
+use mudu::utils::json::{from_json_str, JsonValue};
 use mudu_contract::database::sql_params::SQLParams;
 use mudu_type::dat_type_id::DatTypeID;
 use mudu_type::dat_typed::DatTyped;
+use mudu_type::dat_value::DatValue;
 use mudu_type::datum::DatumDyn;
 use mudu_type::dt_fn_param::DatType;
 use sql_parser::ast::expr_item::ExprValue;
@@ -44,9 +47,125 @@ impl ValueCodec {
                 .to_binary(dat_type)
                 .map(|binary| Some(binary.into()))
                 .map_err(|e| mudu_error!(ER::TypeConversionFailed, "literal type mismatch", e)),
+            ExprLiteral::Array(_) | ExprLiteral::Row(_) => {
+                match Self::value_from_literal(literal, dat_type)? {
+                    Some(value) => value.to_binary(dat_type).map(|binary| Some(binary.into())),
+                    None => Ok(None),
+                }
+            }
+        }
+    }
+
+    /// The value of `literal` as a `dat_type`; array elements and row
+    /// fields are coerced to their element and field types. Like the textual
+    /// form of arrays and records, their strings are stored without quotes.
+    fn value_from_literal(literal: &ExprLiteral, dat_type: &DatType) -> RS<Option<DatValue>> {
+        let value = match literal {
+            ExprLiteral::Null => return Ok(None),
+            ExprLiteral::DatumLiteral(typed) => {
+                let coerced = Self::coerce_literal(typed, dat_type)?;
+                if coerced.dat_type().dat_type_id() != dat_type.dat_type_id() {
+                    return Err(mudu_error!(
+                        ER::TypeConversionFailed,
+                        format!(
+                            "{} literal for a {} value",
+                            coerced.dat_type().name(),
+                            dat_type.name()
+                        )
+                    ));
+                }
+                coerced.dat_internal().clone()
+            }
+            ExprLiteral::Array(elements) => {
+                let elem_type = dat_type
+                    .as_array_param()
+                    .ok_or_else(|| {
+                        mudu_error!(
+                            ER::TypeConversionFailed,
+                            format!("array literal for a {} value", dat_type.name())
+                        )
+                    })?
+                    .dat_type();
+                let mut values = Vec::with_capacity(elements.len());
+                for element in elements {
+                    let element = Self::unquoted_literal(element);
+                    let value =
+                        Self::value_from_literal(&element, elem_type)?.ok_or_else(|| {
+                            mudu_error!(
+                                ER::NotImplemented,
+                                "NULL array elements are not implemented"
+                            )
+                        })?;
+                    values.push(value);
+                }
+                DatValue::from_array(values)
+            }
+            ExprLiteral::Row(fields) => {
+                let param = dat_type.as_record_param().ok_or_else(|| {
+                    mudu_error!(
+                        ER::TypeConversionFailed,
+                        format!("row literal for a {} value", dat_type.name())
+                    )
+                })?;
+                if fields.len() != param.fields().len() {
+                    return Err(mudu_error!(
+                        ER::TypeConversionFailed,
+                        format!(
+                            "row literal has {} fields, the record has {}",
+                            fields.len(),
+                            param.fields().len()
+                        )
+                    ));
+                }
+                let mut values = Vec::with_capacity(fields.len());
+                for (field, (name, field_type)) in fields.iter().zip(param.fields()) {
+                    let field = Self::unquoted_literal(field);
+                    let value = Self::value_from_literal(&field, field_type)?.ok_or_else(|| {
+                        mudu_error!(
+                            ER::NotImplemented,
+                            format!("NULL record field {} is not implemented", name)
+                        )
+                    })?;
+                    values.push(value);
+                }
+                DatValue::from_record(values)
+            }
+        };
+        Ok(Some(value))
+    }
+
+    /// `expr` with the quotes of a string literal removed. String literals
+    /// keep their quotes in the AST, which string columns store as they are;
+    /// JSON documents and the text JSON paths read compare without them.
+    pub(crate) fn unquoted(expr: &ExprValue) -> ExprValue {
+        match expr {
+            ExprValue::ValueLiteral(literal) => {
+                ExprValue::ValueLiteral(Self::unquoted_literal(literal))
+            }
+            expr => expr.clone(),
         }
     }
 
+    fn unquoted_literal(literal: &ExprLiteral) -> ExprLiteral {
+        match literal {
+            ExprLiteral::DatumLiteral(typed)
+                if typed.dat_type().dat_type_id() == DatTypeID::String =>
+            {
+                ExprLiteral::DatumLiteral(DatTyped::from_string(
+                    Self::literal_text(typed.dat_internal().expect_string()).to_string(),
+                ))
+            }
+            literal => literal.clone(),
+        }
+    }
+
+    fn literal_text(literal: &str) -> &str {
+        literal
+            .strip_prefix('\'')
+            .and_then(|text| text.strip_suffix('\''))
+            .unwrap_or(literal)
+    }
+
     fn coerce_literal(literal: &DatTyped, dat_type: &DatType) -> RS<DatTyped> {
         let source = literal.dat_type().dat_type_id();
         let target = dat_type.dat_type_id();
@@ -58,6 +177,42 @@ impl ValueCodec {
             (DatTypeID::I64, DatTypeID::I32) => {
                 DatTyped::from_i32(literal.dat_internal().to_i64() as i32)
             }
+            (DatTypeID::I64, DatTypeID::I16) => {
+                DatTyped::from_i16(i16::try_from(literal.dat_internal().to_i64()).map_err(|e| {
+                    mudu_error!(ER::TypeConversionFailed, "literal out of range for i16", e)
+                })?)
+            }
+            (DatTypeID::I64, DatTypeID::I8) => {
+                DatTyped::from_i8(i8::try_from(literal.dat_internal().to_i64()).map_err(|e| {
+                    mudu_error!(ER::TypeConversionFailed, "literal out of range for i8", e)
+                })?)
+            }
+            (DatTypeID::String, DatTypeID::Uuid) => DatTyped::from_uuid(
+                UuidValue::parse(literal.dat_internal().expect_string())
+                    .map_err(|e| mudu_error!(ER::TypeConversionFailed, e))?,
+            ),
+            (DatTypeID::String, DatTypeID::Json) => DatTyped::from_json(
+                from_json_str::<JsonValue>(Self::literal_text(
+                    literal.dat_internal().expect_string(),
+                ))
+                .map_err(|e| mudu_error!(ER::TypeConversionFailed, "invalid json literal", e))?,
+            ),
+            // Array and record literals are written in their textual form,
+            // e.g. '[1, 2, 3]' or '{"x": 1, "y": 2}'.
+            (DatTypeID::String, DatTypeID::Array | DatTypeID::Record) => DatTyped::new(
+                dat_type.clone(),
+                target.fn_input()(
+                    Self::literal_text(literal.dat_internal().expect_string()),
+                    dat_type,
+                )
+                .map_err(|e| {
+                    mudu_error!(
+                        ER::TypeConversionFailed,
+                        format!("invalid {} literal", target.name()),
+                        e
+                    )
+                })?,
+            ),
             (DatTypeID::I32, DatTypeID::I64) => {
                 DatTyped::from_i64(literal.dat_internal().to_i32() as i64)
             }
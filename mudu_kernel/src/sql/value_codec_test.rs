@@ -10,11 +10,18 @@ mod tests {
 
     use crate::sql::value_codec::ValueCodec;
     use mudu::data_type::numeric::Numeric;
+    use mudu::data_type::uuid::UuidValue;
+    use mudu::error::ErrorCode;
+    use mudu::json_value;
+    use mudu_type::array::new_array_type;
     use mudu_type::dat_type::DatType;
     use mudu_type::dat_type_id::DatTypeID;
     use mudu_type::dat_typed::DatTyped;
+    use mudu_type::dat_value::DatValue;
     use mudu_type::datum::DatumDyn;
+    use mudu_type::dtp_kind::DTPKind;
     use mudu_type::dtp_numeric::DTPNumeric;
+    use mudu_type::dtp_object::DTPRecord;
     use sql_parser::ast::expr_item::ExprValue;
     use sql_parser::ast::expr_literal::ExprLiteral;
 
@@ -126,6 +133,266 @@ mod tests {
         );
     }
 
+    #[test]
+    fn i64_literal_is_range_checked_for_small_int_columns() {
+        let mut param_index = 0;
+        let binary = ValueCodec::binary_from_expr(
+            &ExprValue::ValueLiteral(ExprLiteral::DatumLiteral(DatTyped::from_i64(-7))),
+            &DatType::default_for(DatTypeID::I16),
+            &(),
+            &mut param_index,
+        )
+        .unwrap()
+        .unwrap();
+        assert_eq!(
+            binary.as_slice(),
+            DatTyped::from_i16(-7)
+                .dat_internal()
+                .to_binary(&DatType::default_for(DatTypeID::I16))
+                .unwrap()
+                .as_ref()
+        );
+
+        let err = ValueCodec::binary_from_expr(
+            &ExprValue::ValueLiteral(ExprLiteral::DatumLiteral(DatTyped::from_i64(128))),
+            &DatType::default_for(DatTypeID::I8),
+            &(),
+            &mut param_index,
+        )
+        .unwrap_err();
+        assert!(err.to_string().contains("out of range for i8"));
+    }
+
+    #[test]
+    fn string_literal_is_parsed_for_uuid_columns() {
+        let text = "123e4567-e89b-12d3-a456-426614174000";
+        let ty = DatType::default_for(DatTypeID::Uuid);
+        let mut param_index = 0;
+        let binary = ValueCodec::binary_from_expr(
+            &ExprValue::ValueLiteral(ExprLiteral::DatumLiteral(DatTyped::from_string(
+                text.to_string(),
+            ))),
+            &ty,
+            &(),
+            &mut param_index,
+        )
+        .unwrap()
+        .unwrap();
+        assert_eq!(
+            binary.as_slice(),
+            DatTyped::from_uuid(UuidValue::parse(text).unwrap())
+                .dat_internal()
+                .to_binary(&ty)
+                .unwrap()
+                .as_ref()
+        );
+
+        let err = ValueCodec::binary_from_expr(
+            &ExprValue::ValueLiteral(ExprLiteral::DatumLiteral(DatTyped::from_string(
+                "not-a-uuid".to_string(),
+            ))),
+            &ty,
+            &(),
+            &mut param_index,
+        )
+        .unwrap_err();
+        assert!(err.to_string().contains("invalid uuid"));
+    }
+
+    #[test]
+    fn string_literal_is_parsed_for_array_columns() {
+        let ty = new_array_type(DatType::new_no_param(DatTypeID::I32));
+        let mut param_index = 0;
+        let binary = ValueCodec::binary_from_expr(
+            &ExprValue::ValueLiteral(ExprLiteral::DatumLiteral(DatTyped::from_string(
+                "[1, 2, 3]".to_string(),
+            ))),
+            &ty,
+            &(),
+            &mut param_index,
+        )
+        .unwrap()
+        .unwrap();
+        let expected = DatValue::from_array(vec![
+            DatValue::from_i32(1),
+            DatValue::from_i32(2),
+            DatValue::from_i32(3),
+        ]);
+        assert_eq!(binary.as_slice(), expected.to_binary(&ty).unwrap().as_ref());
+
+        // SQL string literals keep their quotes.
+        let quoted = ValueCodec::binary_from_expr(
+            &ExprValue::ValueLiteral(ExprLiteral::DatumLiteral(DatTyped::from_string(
+                "'[1, 2, 3]'".to_string(),
+            ))),
+            &ty,
+            &(),
+            &mut param_index,
+        )
+        .unwrap()
+        .unwrap();
+        assert_eq!(quoted, binary);
+
+        let err = ValueCodec::binary_from_expr(
+            &ExprValue::ValueLiteral(ExprLiteral::DatumLiteral(DatTyped::from_string(
+                "[1, \"x\"]".to_string(),
+            ))),
+            &ty,
+            &(),
+            &mut param_index,
+        )
+        .unwrap_err();
+        assert!(err.to_string().contains("invalid array literal"));
+    }
+
+    #[test]
+    fn array_and_row_literals_coerce_their_elements() {
+        let ty = new_array_type(DatType::new_no_param(DatTypeID::I32));
+        let literal = |literal| ExprValue::ValueLiteral(literal);
+        let int = |i| ExprLiteral::DatumLiteral(DatTyped::from_i64(i));
+        let string = |s: &str| ExprLiteral::DatumLiteral(DatTyped::from_string(s.to_string()));
+        let mut param_index = 0;
+        let binary = ValueCodec::binary_from_expr(
+            &literal(ExprLiteral::Array(vec![int(1), int(2)])),
+            &ty,
+            &(),
+            &mut param_index,
+        )
+        .unwrap()
+        .unwrap();
+        let expected = DatValue::from_array(vec![DatValue::from_i32(1), DatValue::from_i32(2)]);
+        assert_eq!(binary.as_slice(), expected.to_binary(&ty).unwrap().as_ref());
+
+        let record = DatType::from_id_param(
+            DatTypeID::Record,
+            Some(DTPKind::Record(Box::new(DTPRecord::new(
+                "row".to_string(),
+                vec![
+                    ("x".to_string(), DatType::new_no_param(DatTypeID::I32)),
+                    (
+                        "tags".to_string(),
+                        new_array_type(DatType::default_for(DatTypeID::String)),
+                    ),
+                ],
+            )))),
+        );
+        let binary = ValueCodec::binary_from_expr(
+            &literal(ExprLiteral::Row(vec![
+                int(7),
+                ExprLiteral::Array(vec![string("'a'"), string("'b'")]),
+            ])),
+            &record,
+            &(),
+            &mut param_index,
+        )
+        .unwrap()
+        .unwrap();
+        // Strings inside arrays and rows are stored without their quotes.
+        let expected = DatValue::from_record(vec![
+            DatValue::from_i32(7),
+            DatValue::from_array(vec![
+                DatValue::from_string("a".to_string()),
+                DatValue::from_string("b".to_string()),
+            ]),
+        ]);
+        assert_eq!(
+            binary.as_slice(),
+            expected.to_binary(&record).unwrap().as_ref()
+        );
+
+        for (bad, ty) in [
+            (ExprLiteral::Array(vec![string("'x'")]), &ty),
+            (ExprLiteral::Row(vec![int(1)]), &ty),
+            (ExprLiteral::Row(vec![int(1)]), &record),
+            (ExprLiteral::Array(vec![int(1)]), &record),
+        ] {
+            let err =
+                ValueCodec::binary_from_expr(&literal(bad), ty, &(), &mut param_index).unwrap_err();
+            assert_eq!(err.ec(), ErrorCode::TypeConversionFailed);
+        }
+        let err = ValueCodec::binary_from_expr(
+            &literal(ExprLiteral::Array(vec![int(1), ExprLiteral::Null])),
+            &ty,
+            &(),
+            &mut param_index,
+        )
+        .unwrap_err();
+        assert_eq!(err.ec(), ErrorCode::NotImplemented);
+    }
+
+    #[test]
+    fn string_literal_is_parsed_for_json_columns() {
+        let ty = DatType::default_for(DatTypeID::Json);
+        let mut param_index = 0;
+        let binary = ValueCodec::binary_from_expr(
+            &ExprValue::ValueLiteral(ExprLiteral::DatumLiteral(DatTyped::from_string(
+                r#"{"tags": ["a", "b"]}"#.to_string(),
+            ))),
+            &ty,
+            &(),
+            &mut param_index,
+        )
+        .unwrap()
+        .unwrap();
+        assert_eq!(
+            binary.as_slice(),
+            DatTyped::from_json(json_value!({"tags": ["a", "b"]}))
+                .dat_internal()
+                .to_binary(&ty)
+                .unwrap()
+                .as_ref()
+        );
+
+        let err = ValueCodec::binary_from_expr(
+            &ExprValue::ValueLiteral(ExprLiteral::DatumLiteral(DatTyped::from_string(
+                "{\"tags\":".to_string(),
+            ))),
+            &ty,
+            &(),
+            &mut param_index,
+        )
+        .unwrap_err();
+        assert!(err.to_string().contains("invalid json literal"));
+
+        // Parsed SQL string literals keep their quotes.
+        let quoted = ValueCodec::binary_from_expr(
+            &ExprValue::ValueLiteral(ExprLiteral::DatumLiteral(DatTyped::from_string(
+                r#"'{"tags": ["a", "b"]}'"#.to_string(),
+            ))),
+            &ty,
+            &(),
+            &mut param_index,
+        )
+        .unwrap()
+        .unwrap();
+        assert_eq!(quoted, binary);
+    }
+
+    #[test]
+    fn unquoted_strips_string_literal_quotes_only() {
+        let quoted = ExprValue::ValueLiteral(ExprLiteral::DatumLiteral(DatTyped::from_string(
+            "'bob'".to_string(),
+        )));
+        let ExprValue::ValueLiteral(ExprLiteral::DatumLiteral(text)) =
+            ValueCodec::unquoted(&quoted)
+        else {
+            panic!("expected a literal");
+        };
+        assert_eq!(text.dat_internal().expect_string(), "bob");
+
+        let number = ExprValue::ValueLiteral(ExprLiteral::DatumLiteral(DatTyped::from_i64(1)));
+        let ExprValue::ValueLiteral(ExprLiteral::DatumLiteral(same)) =
+            ValueCodec::unquoted(&number)
+        else {
+            panic!("expected a literal");
+        };
+        assert_eq!(same.dat_internal().to_i64(), 1);
+        assert!(matches!(
+            ValueCodec::unquoted(&ExprValue::ValuePlaceholder),
+            ExprValue::ValuePlaceholder
+        ));
+    }
+
     #[test]
     fn integer_literal_is_coerced_into_numeric_column_encoding() {
         let ty = DatType::from_numeric(DTPNumeric::new(9, 2));
@@ -0,0 +1,287 @@
+//! Backup sets of a data directory.
+//!
+//! An online backup (see `server::worker_backup`) copies the files a server
+//! needs to recover into a backup directory, under the same relative paths,
+//! and writes a [`BackupManifest`] last. The manifest lists every file with
+//! its length and checksum, and for every worker the checkpoint LSN its
+//! copied log starts from, the barrier LSN all workers had reached before
+//! any of them copied a file, and the LSN it reaches.
+//!
+//! [`restore_backup`] runs offline: it copies a backup set into an empty data
+//! directory, checking each file against the manifest, and verifies the
+//! result with [`verify_data_dir`].
+
+use std::path::Path;
+
+use mudu::common::crc::calc_crc;
+use mudu::common::id::OID;
+use mudu::common::result::RS;
+use mudu::error::ErrorCode;
+use mudu::mudu_error;
+use mudu_sys::contract::async_fs::AsyncFs;
+use mudu_sys::contract::file_options::FileOptions;
+use serde::{Deserialize, Serialize};
+
+use crate::storage::encryption::Keyring;
+use crate::storage::verify::{verify_data_dir, VerifyReport};
+
+/// Name of the manifest in a backup directory. It is written after every
+/// other file, so a directory without it holds an unfinished backup.
+pub const BACKUP_MANIFEST_FILE: &str = "backup.manifest";
+
+const BACKUP_FORMAT_VERSION: u32 = 1;
+
+/// A file of a backup set, relative to the backup directory.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct BackupFile {
+    pub path: String,
+    pub len: u64,
+    pub crc: u64,
+}
+
+/// The log range a worker contributed to a backup. Recovery restores the
+/// checkpoint at `checkpoint_lsn` and replays the copied log up to at least
+/// `end_lsn`, which covers every commit in the copied relation files.
+///
+/// `barrier_lsn` is the worker's flushed log position when it reached the
+/// backup barrier, right after its checkpoint; the backup is consistent
+/// across workers as of their barrier LSNs.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct WorkerBackup {
+    pub worker_id: OID,
+    pub checkpoint_lsn: u64,
+    #[serde(default)]
+    pub barrier_lsn: u64,
+    pub end_lsn: u64,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct BackupManifest {
+    pub format_version: u32,
+    pub workers: Vec<WorkerBackup>,
+    pub files: Vec<BackupFile>,
+}
+
+impl BackupManifest {
+    pub fn new(mut workers: Vec<WorkerBackup>, mut files: Vec<BackupFile>) -> Self {
+        workers.sort_by_key(|worker| worker.worker_id);
+        files.sort_by(|a, b| a.path.cmp(&b.path));
+        Self {
+            format_version: BACKUP_FORMAT_VERSION,
+            workers,
+            files,
+        }
+    }
+
+    pub fn total_bytes(&self) -> u64 {
+        self.files.iter().map(|file| file.len).sum()
+    }
+
+    pub fn load(backup_dir: &Path) -> RS<Self> {
+        let path = backup_dir.join(BACKUP_MANIFEST_FILE);
+        let bytes = mudu_sys::fs::sync::read(&path).map_err(|e| {
+            mudu_error!(
+                ErrorCode::Io,
+                format!("read backup manifest {} error", path.display()),
+                e
+            )
+        })?;
+        let manifest: Self = serde_json::from_slice(&bytes)
+            .map_err(|e| mudu_error!(ErrorCode::Decode, "decode backup manifest error", e))?;
+        if manifest.format_version != BACKUP_FORMAT_VERSION {
+            return Err(mudu_error!(
+                ErrorCode::Decode,
+                format!(
+                    "backup manifest has format version {}, expected {}",
+                    manifest.format_version, BACKUP_FORMAT_VERSION
+                )
+            ));
+        }
+        Ok(manifest)
+    }
+
+    pub async fn write_async(&self, fs: &dyn AsyncFs, backup_dir: &Path) -> RS<()> {
+        let bytes = serde_json::to_vec_pretty(self)
+            .map_err(|e| mudu_error!(ErrorCode::Encode, "encode backup manifest error", e))?;
+        write_synced(fs, &backup_dir.join(BACKUP_MANIFEST_FILE), &bytes).await
+    }
+}
+
+/// Copies `path`, which lies under `root`, to the same relative path under
+/// `backup_dir`.
+pub(crate) async fn copy_into_backup(
+    fs: &dyn AsyncFs,
+    root: &Path,
+    path: &Path,
+    backup_dir: &Path,
+) -> RS<BackupFile> {
+    let relative = path.strip_prefix(root).map_err(|e| {
+        mudu_error!(
+            ErrorCode::InvalidArgument,
+            format!("{} is not under {}", path.display(), root.display()),
+            e
+        )
+    })?;
+    let bytes = fs.read_all(path).await?;
+    write_synced(fs, &backup_dir.join(relative), &bytes).await?;
+    Ok(BackupFile {
+        path: relative.to_string_lossy().into_owned(),
+        len: bytes.len() as u64,
+        crc: calc_crc(&bytes),
+    })
+}
+
+pub(crate) async fn write_synced(fs: &dyn AsyncFs, path: &Path, bytes: &[u8]) -> RS<()> {
+    if let Some(parent) = path.parent() {
+        fs.create_dir_all(parent).await?;
+    }
+    let file = fs
+        .open(
+            path,
+            FileOptions::new(
+                libc::O_CREAT | libc::O_RDWR | libc::O_TRUNC | libc::O_CLOEXEC,
+                0o644,
+            ),
+        )
+        .await?;
+    file.write_all_at(0, bytes).await?;
+    file.fsync().await
+}
+
+/// Copies the backup set in `backup_dir` into `data_dir` and verifies the
+/// restored pages and WAL chunks, opening sealed ones with `keyring`.
+///
+/// `data_dir` must be empty or missing, and no server may own it. A file that
+/// does not match its manifest entry fails the restore; integrity problems of
+/// the restored directory are returned in the report.
+pub fn restore_backup(
+    backup_dir: &Path,
+    data_dir: &Path,
+    keyring: Option<&Keyring>,
+) -> RS<VerifyReport> {
+    let manifest = BackupManifest::load(backup_dir)?;
+    if mudu_sys::fs::sync::path_exists(data_dir)
+        && !mudu_sys::fs::sync::read_dir_entries(data_dir)?.is_empty()
+    {
+        return Err(mudu_error!(
+            ErrorCode::EntityAlreadyExists,
+            format!("restore target {} is not empty", data_dir.display())
+        ));
+    }
+    for file in &manifest.files {
+        let relative = Path::new(&file.path);
+        if relative.is_absolute() || relative.components().any(|c| c.as_os_str() == "..") {
+            return Err(mudu_error!(
+                ErrorCode::CorruptedData,
+                format!(
+                    "backup manifest lists file {} outside the backup",
+                    file.path
+                )
+            ));
+        }
+        let bytes = mudu_sys::fs::sync::read(backup_dir.join(relative))?;
+        if bytes.len() as u64 != file.len || calc_crc(&bytes) != file.crc {
+            return Err(mudu_error!(
+                ErrorCode::CorruptedData,
+                format!("backup file {} does not match the manifest", file.path)
+            ));
+        }
+        let target = data_dir.join(relative);
+        if let Some(parent) = target.parent() {
+            mudu_sys::fs::sync::create_dir_all(parent)?;
+        }
+        mudu_sys::fs::sync::write(&target, &bytes)?;
+    }
+    verify_data_dir(data_dir, keyring)
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(
+        clippy::unwrap_used,
+        clippy::expect_used,
+        clippy::panic,
+        clippy::todo,
+        clippy::unimplemented
+    )]
+
+    use super::*;
+    use mudu_sys::default_sys_io_context;
+    use project_root::get_project_root;
+    use std::path::PathBuf;
+
+    fn test_dir() -> PathBuf {
+        let dir = get_project_root()
+            .unwrap()
+            .join("target")
+            .join("tmp")
+            .join(format!("backup_{}", mudu_sys::random::uuid_v4()));
+        mudu_sys::fs::sync::create_dir_all(&dir).unwrap();
+        dir
+    }
+
+    fn back_up(data_dir: &Path, backup_dir: &Path, names: &[&str]) -> BackupManifest {
+        let fs = default_sys_io_context().fs();
+        let data_dir = data_dir.to_path_buf();
+        let backup_dir = backup_dir.to_path_buf();
+        let paths: Vec<PathBuf> = names.iter().map(|name| data_dir.join(name)).collect();
+        mudu_sys::task::async_::block_on_tokio_current_thread(async move {
+            let mut files = Vec::new();
+            for path in paths {
+                files.push(
+                    copy_into_backup(fs.as_ref(), &data_dir, &path, &backup_dir)
+                        .await
+                        .unwrap(),
+                );
+            }
+            let manifest = BackupManifest::new(Vec::new(), files);
+            manifest
+                .write_async(fs.as_ref(), &backup_dir)
+                .await
+                .unwrap();
+            manifest
+        })
+        .unwrap()
+    }
+
+    #[test]
+    fn restore_copies_the_backup_set() {
+        let dir = test_dir();
+        let data_dir = dir.join("data");
+        mudu_sys::fs::sync::create_dir_all(data_dir.join("meta")).unwrap();
+        mudu_sys::fs::sync::write(data_dir.join("worker.0.1.wid"), b"").unwrap();
+        mudu_sys::fs::sync::write(data_dir.join("meta").join("catalog"), b"catalog").unwrap();
+        let backup_dir = dir.join("backup");
+        let manifest = back_up(&data_dir, &backup_dir, &["worker.0.1.wid", "meta/catalog"]);
+        assert_eq!(BackupManifest::load(&backup_dir).unwrap(), manifest);
+        assert_eq!(manifest.files.len(), 2);
+        assert_eq!(manifest.total_bytes(), 7);
+
+        let restored = dir.join("restored");
+        let report = restore_backup(&backup_dir, &restored, None).unwrap();
+        assert!(report.is_clean());
+        assert_eq!(
+            mudu_sys::fs::sync::read(restored.join("meta").join("catalog")).unwrap(),
+            b"catalog"
+        );
+
+        let err = restore_backup(&backup_dir, &restored, None).unwrap_err();
+        assert_eq!(err.ec(), ErrorCode::EntityAlreadyExists);
+        mudu_sys::fs::sync::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn restore_rejects_a_modified_file() {
+        let dir = test_dir();
+        let data_dir = dir.join("data");
+        mudu_sys::fs::sync::create_dir_all(&data_dir).unwrap();
+        mudu_sys::fs::sync::write(data_dir.join("state"), b"state").unwrap();
+        let backup_dir = dir.join("backup");
+        back_up(&data_dir, &backup_dir, &["state"]);
+        mudu_sys::fs::sync::write(backup_dir.join("state"), b"stale").unwrap();
+
+        let err = restore_backup(&backup_dir, &dir.join("restored"), None).unwrap_err();
+        assert_eq!(err.ec(), ErrorCode::CorruptedData);
+        mudu_sys::fs::sync::remove_dir_all(&dir).unwrap();
+    }
+}
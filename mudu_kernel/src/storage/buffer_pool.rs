@@ -0,0 +1,550 @@
+//! Bounded page cache shared by the time-series files of one worker.
+//!
+//! Pages are evicted with a clock sweep once the resident bytes exceed the
+//! byte budget. Readers pin the pages they hold, and pinned pages are never
+//! evicted. Pages of relation files are cached dirty after their PL batch
+//! was appended, and are written back on eviction or file flush; a write-back
+//! makes the file's PL stream durable up to the page LSN first, so the data
+//! file never runs ahead of its log.
+
+use crate::storage::encryption::{seal_page_image, Sealer};
+use crate::storage::page::PageId;
+use crate::wal::lsn::LSN;
+use crate::wal::worker_log::ChunkedWorkerLogBackend;
+use mudu::common::result::RS;
+use mudu_sys::fs::SysFile;
+use mudu_sys::sync::SMutex;
+use std::collections::{HashMap, VecDeque};
+use std::ops::Deref;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::Arc;
+use tracing::trace;
+
+/// Byte budget of a buffer pool unless configured otherwise.
+pub const DEFAULT_BUFFER_POOL_BYTES: u64 = 256 * 1024 * 1024;
+
+type PageKey = (u64, PageId);
+
+/// How a page image offered to [`BufferPool::put`] relates to the file.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum PageImage {
+    /// Read from the file; a cached image is at least as new and is kept.
+    Read,
+    /// Just written to the file; it replaces the cached image.
+    WrittenThrough,
+    /// Newer than the file; written back once the PL stream is durable up
+    /// to the LSN.
+    Dirty(LSN),
+}
+
+impl PageImage {
+    fn dirty_lsn(self) -> Option<LSN> {
+        match self {
+            PageImage::Dirty(lsn) => Some(lsn),
+            PageImage::Read | PageImage::WrittenThrough => None,
+        }
+    }
+}
+
+/// Counters of a buffer pool since it was created.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct BufferPoolStats {
+    pub hits: u64,
+    pub misses: u64,
+    pub evictions: u64,
+    pub write_backs: u64,
+    pub resident_bytes: u64,
+}
+
+pub struct BufferPool {
+    capacity_bytes: AtomicU64,
+    next_file_id: AtomicU64,
+    state: Arc<SMutex<PoolState>>,
+    hits: AtomicU64,
+    misses: AtomicU64,
+    evictions: AtomicU64,
+    write_backs: AtomicU64,
+}
+
+#[derive(Default)]
+struct PoolState {
+    frames: HashMap<PageKey, Frame>,
+    // Clock order of the frames; keys of removed frames are skipped.
+    clock: VecDeque<PageKey>,
+    resident_bytes: u64,
+    files: HashMap<u64, PageSink>,
+}
+
+struct Frame {
+    data: Arc<Vec<u8>>,
+    pins: u32,
+    referenced: bool,
+    // PL stream LSN the page requires to be durable before write-back.
+    dirty_lsn: Option<LSN>,
+    writing: bool,
+}
+
+#[derive(Clone)]
+struct PageSink {
+    file: SysFile,
+    wal: Option<ChunkedWorkerLogBackend>,
+    durable_lsn: Arc<AtomicU64>,
+    sealer: Option<Arc<Sealer>>,
+}
+
+struct WriteBack {
+    key: PageKey,
+    data: Arc<Vec<u8>>,
+    lsn: LSN,
+    sink: PageSink,
+}
+
+/// A cached page that stays resident while the value is alive.
+pub struct PinnedPage {
+    key: PageKey,
+    data: Arc<Vec<u8>>,
+    state: Arc<SMutex<PoolState>>,
+}
+
+impl PinnedPage {
+    pub fn to_vec(&self) -> Vec<u8> {
+        self.data.as_ref().clone()
+    }
+}
+
+impl Deref for PinnedPage {
+    type Target = [u8];
+
+    fn deref(&self) -> &[u8] {
+        self.data.as_slice()
+    }
+}
+
+impl Drop for PinnedPage {
+    fn drop(&mut self) {
+        if let Ok(mut state) = self.state.lock() {
+            if let Some(frame) = state.frames.get_mut(&self.key) {
+                frame.pins = frame.pins.saturating_sub(1);
+            }
+        }
+    }
+}
+
+impl BufferPool {
+    pub fn new(capacity_bytes: u64) -> Self {
+        Self {
+            capacity_bytes: AtomicU64::new(capacity_bytes),
+            next_file_id: AtomicU64::new(1),
+            state: Arc::new(SMutex::new(PoolState::default())),
+            hits: AtomicU64::new(0),
+            misses: AtomicU64::new(0),
+            evictions: AtomicU64::new(0),
+            write_backs: AtomicU64::new(0),
+        }
+    }
+
+    pub fn capacity_bytes(&self) -> u64 {
+        self.capacity_bytes.load(Ordering::Relaxed)
+    }
+
+    /// Changes the byte budget; a smaller budget takes effect with the next
+    /// page that enters the pool.
+    pub fn set_capacity_bytes(&self, capacity_bytes: u64) {
+        self.capacity_bytes.store(capacity_bytes, Ordering::Relaxed);
+    }
+
+    pub fn stats(&self) -> RS<BufferPoolStats> {
+        let resident_bytes = self.state.lock()?.resident_bytes;
+        Ok(BufferPoolStats {
+            hits: self.hits.load(Ordering::Relaxed),
+            misses: self.misses.load(Ordering::Relaxed),
+            evictions: self.evictions.load(Ordering::Relaxed),
+            write_backs: self.write_backs.load(Ordering::Relaxed),
+            resident_bytes,
+        })
+    }
+
+    /// Registers a file whose pages enter the pool and returns its pool-local
+    /// id. Dirty pages of the file are written to `file` after `wal` is
+    /// durable up to their LSN, sealed by `sealer` when the file is
+    /// encrypted.
+    pub(crate) fn register_file(
+        &self,
+        file: SysFile,
+        wal: Option<ChunkedWorkerLogBackend>,
+        sealer: Option<Arc<Sealer>>,
+    ) -> RS<u64> {
+        let file_id = self.next_file_id.fetch_add(1, Ordering::Relaxed);
+        let _ = self.state.lock()?.files.insert(
+            file_id,
+            PageSink {
+                file,
+                wal,
+                durable_lsn: Arc::new(AtomicU64::new(0)),
+                sealer,
+            },
+        );
+        Ok(file_id)
+    }
+
+    /// Drops the pages of a file without writing them back and forgets the
+    /// file.
+    pub(crate) fn discard_file(&self, file_id: u64) -> RS<()> {
+        let mut state = self.state.lock()?;
+        let _ = state.files.remove(&file_id);
+        let keys: Vec<PageKey> = state
+            .frames
+            .keys()
+            .filter(|(id, _)| *id == file_id)
+            .copied()
+            .collect();
+        for key in keys {
+            if let Some(frame) = state.frames.remove(&key) {
+                state.resident_bytes -= frame.data.len() as u64;
+            }
+        }
+        Ok(())
+    }
+
+    /// Returns the cached page and pins it.
+    pub(crate) fn get(&self, file_id: u64, page_id: PageId) -> RS<Option<PinnedPage>> {
+        let key = (file_id, page_id);
+        let mut state = self.state.lock()?;
+        match state.frames.get_mut(&key) {
+            Some(frame) => {
+                frame.pins += 1;
+                frame.referenced = true;
+                let data = frame.data.clone();
+                drop(state);
+                self.hits.fetch_add(1, Ordering::Relaxed);
+                Ok(Some(PinnedPage {
+                    key,
+                    data,
+                    state: self.state.clone(),
+                }))
+            }
+            None => {
+                drop(state);
+                self.misses.fetch_add(1, Ordering::Relaxed);
+                Ok(None)
+            }
+        }
+    }
+
+    /// Caches a page image and returns it pinned. An image read from the
+    /// file never replaces a cached one.
+    pub(crate) async fn put(
+        &self,
+        file_id: u64,
+        page_id: PageId,
+        data: Vec<u8>,
+        image: PageImage,
+    ) -> RS<PinnedPage> {
+        let key = (file_id, page_id);
+        let data = {
+            let mut state = self.state.lock()?;
+            let len = data.len() as u64;
+            match state.frames.get_mut(&key) {
+                Some(frame) => {
+                    frame.pins += 1;
+                    frame.referenced = true;
+                    if image != PageImage::Read {
+                        let old_len = frame.data.len() as u64;
+                        frame.data = Arc::new(data);
+                        frame.dirty_lsn = image.dirty_lsn();
+                        let data = frame.data.clone();
+                        state.resident_bytes = state.resident_bytes - old_len + len;
+                        data
+                    } else {
+                        frame.data.clone()
+                    }
+                }
+                None => {
+                    let data = Arc::new(data);
+                    let _ = state.frames.insert(
+                        key,
+                        Frame {
+                            data: data.clone(),
+                            pins: 1,
+                            referenced: true,
+                            dirty_lsn: image.dirty_lsn(),
+                            writing: false,
+                        },
+                    );
+                    state.clock.push_back(key);
+                    state.resident_bytes += len;
+                    data
+                }
+            }
+        };
+        let page = PinnedPage {
+            key,
+            data,
+            state: self.state.clone(),
+        };
+        self.evict_to_budget().await?;
+        Ok(page)
+    }
+
+    /// Writes back every dirty page of a file.
+    pub(crate) async fn write_back_file(&self, file_id: u64) -> RS<()> {
+        let pending = {
+            let mut state = self.state.lock()?;
+            let Some(sink) = state.files.get(&file_id).cloned() else {
+                return Ok(());
+            };
+            let mut pending = Vec::new();
+            for (key, frame) in state.frames.iter_mut() {
+                if key.0 != file_id || frame.writing {
+                    continue;
+                }
+                if let Some(lsn) = frame.dirty_lsn {
+                    frame.writing = true;
+                    pending.push(WriteBack {
+                        key: *key,
+                        data: frame.data.clone(),
+                        lsn,
+                        sink: sink.clone(),
+                    });
+                }
+            }
+            pending
+        };
+        for write_back in pending {
+            self.write_back(write_back, false).await?;
+        }
+        Ok(())
+    }
+
+    async fn evict_to_budget(&self) -> RS<()> {
+        loop {
+            let write_back = {
+                let mut state = self.state.lock()?;
+                match self.select_victim(&mut state) {
+                    Victim::None => return Ok(()),
+                    Victim::Evicted => {
+                        self.evictions.fetch_add(1, Ordering::Relaxed);
+                        continue;
+                    }
+                    Victim::Dirty(write_back) => write_back,
+                }
+            };
+            self.write_back(write_back, true).await?;
+        }
+    }
+
+    // Advances the clock hand until a frame can leave the pool. Clean frames
+    // are removed right away; dirty frames are marked as being written and
+    // handed to the caller.
+    fn select_victim(&self, state: &mut PoolState) -> Victim {
+        let capacity = self.capacity_bytes();
+        if state.resident_bytes <= capacity {
+            return Victim::None;
+        }
+        // Two passes clear every reference bit once.
+        let mut budget = state.clock.len() * 2;
+        while budget > 0 {
+            budget -= 1;
+            let Some(key) = state.clock.pop_front() else {
+                break;
+            };
+            let Some(frame) = state.frames.get_mut(&key) else {
+                continue;
+            };
+            if frame.pins > 0 || frame.writing || frame.referenced {
+                frame.referenced = false;
+                state.clock.push_back(key);
+                continue;
+            }
+            // A dirty page of a discarded file has nowhere to go and is
+            // dropped like a clean one.
+            if let (Some(lsn), Some(sink)) = (frame.dirty_lsn, state.files.get(&key.0).cloned()) {
+                frame.writing = true;
+                let data = frame.data.clone();
+                state.clock.push_back(key);
+                return Victim::Dirty(WriteBack {
+                    key,
+                    data,
+                    lsn,
+                    sink,
+                });
+            }
+            if let Some(frame) = state.frames.remove(&key) {
+                state.resident_bytes -= frame.data.len() as u64;
+            }
+            return Victim::Evicted;
+        }
+        // Every resident page is pinned or being written.
+        Victim::None
+    }
+
+    async fn write_back(&self, write_back: WriteBack, evict: bool) -> RS<()> {
+        let WriteBack {
+            key,
+            data,
+            lsn,
+            sink,
+        } = write_back;
+        let result: RS<()> = async {
+            if let Some(wal) = sink.wal.as_ref() {
+                if sink.durable_lsn.load(Ordering::Acquire) < lsn.as_u64() {
+                    let target = wal.position()?.lsn;
+                    wal.flush_async().await?;
+                    sink.durable_lsn
+                        .fetch_max(target.as_u64(), Ordering::AcqRel);
+                }
+            }
+            let stored = seal_page_image(sink.sealer.as_deref(), &data)?;
+            sink.file
+                .write_all_at(page_offset(key.1, data.len()), &stored)
+                .await
+        }
+        .await;
+        trace!(file_id = key.0, page_id = %key.1, evict, "buffer pool write back");
+
+        let mut state = self.state.lock()?;
+        let mut evicted = false;
+        if let Some(frame) = state.frames.get_mut(&key) {
+            frame.writing = false;
+            // The page may have been replaced while it was being written.
+            if result.is_ok() && Arc::ptr_eq(&frame.data, &data) {
+                frame.dirty_lsn = None;
+                if evict && frame.pins == 0 {
+                    if let Some(frame) = state.frames.remove(&key) {
+                        state.resident_bytes -= frame.data.len() as u64;
+                    }
+                    evicted = true;
+                }
+            }
+        }
+        drop(state);
+        result?;
+        self.write_backs.fetch_add(1, Ordering::Relaxed);
+        if evicted {
+            self.evictions.fetch_add(1, Ordering::Relaxed);
+        }
+        Ok(())
+    }
+}
+
+impl Default for BufferPool {
+    fn default() -> Self {
+        Self::new(DEFAULT_BUFFER_POOL_BYTES)
+    }
+}
+
+enum Victim {
+    None,
+    Evicted,
+    Dirty(WriteBack),
+}
+
+// Every page of a file has the same size, so a cached page's own length
+// places it in the file.
+fn page_offset(page_id: PageId, page_size: usize) -> u64 {
+    page_id.as_u64() * page_size as u64
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+
+    use super::{BufferPool, PageImage};
+    use crate::storage::page::page_block_ref::PAGE_SIZE;
+    use crate::storage::page::PageId;
+    use crate::wal::lsn::LSN;
+    use mudu_sys::contract::file_options::FileOptions;
+    use mudu_sys::default_sys_io_context;
+    use mudu_sys::fs::SysFile;
+    use project_root::get_project_root;
+
+    #[test]
+    fn evicts_unpinned_pages_and_writes_back_dirty_ones() {
+        mudu_sys::task::async_::block_on_tokio_current_thread(async move {
+            let dir = get_project_root().unwrap().join("target").join("tmp");
+            let path = dir.join(format!("buffer-pool-{}.dat", mudu_sys::random::uuid_v4()));
+            let fs = default_sys_io_context().fs();
+            fs.create_dir_all(&dir).await.unwrap();
+            let file = SysFile::new(
+                fs.open(&path, FileOptions::read_write_create())
+                    .await
+                    .unwrap(),
+            );
+            let pool = BufferPool::new(2 * PAGE_SIZE as u64);
+            let file_id = pool.register_file(file.clone(), None, None).unwrap();
+
+            let pinned = pool
+                .put(file_id, PageId::new(0), vec![1; PAGE_SIZE], PageImage::Read)
+                .await
+                .unwrap();
+            let dirty = pool
+                .put(
+                    file_id,
+                    PageId::new(1),
+                    vec![2; PAGE_SIZE],
+                    PageImage::Dirty(LSN::new(0)),
+                )
+                .await
+                .unwrap();
+            drop(dirty);
+            let clean = pool
+                .put(file_id, PageId::new(2), vec![3; PAGE_SIZE], PageImage::Read)
+                .await
+                .unwrap();
+            drop(clean);
+
+            // Page 0 is pinned, so the dirty page 1 is written back and evicted.
+            let stats = pool.stats().unwrap();
+            assert_eq!(stats.resident_bytes, 2 * PAGE_SIZE as u64);
+            assert_eq!(stats.write_backs, 1);
+            assert_eq!(stats.evictions, 1);
+            assert!(pool.get(file_id, PageId::new(0)).unwrap().is_some());
+            assert!(pool.get(file_id, PageId::new(1)).unwrap().is_none());
+            assert_eq!(
+                file.read_exact_at(PAGE_SIZE as u64, PAGE_SIZE)
+                    .await
+                    .unwrap(),
+                vec![2; PAGE_SIZE]
+            );
+            assert_eq!(pinned[0], 1);
+            let stats = pool.stats().unwrap();
+            assert_eq!((stats.hits, stats.misses), (1, 1));
+            let _ = mudu_sys::fs::sync::remove_file(path);
+        })
+        .unwrap()
+    }
+
+    #[test]
+    fn written_through_images_replace_cached_ones() {
+        mudu_sys::task::async_::block_on_tokio_current_thread(async move {
+            let dir = get_project_root().unwrap().join("target").join("tmp");
+            let path = dir.join(format!("buffer-pool-{}.dat", mudu_sys::random::uuid_v4()));
+            let fs = default_sys_io_context().fs();
+            fs.create_dir_all(&dir).await.unwrap();
+            let file = SysFile::new(
+                fs.open(&path, FileOptions::read_write_create())
+                    .await
+                    .unwrap(),
+            );
+            let pool = BufferPool::new(4 * PAGE_SIZE as u64);
+            let file_id = pool.register_file(file, None, None).unwrap();
+            let page_id = PageId::new(0);
+
+            let put = |data: u8, image: PageImage| {
+                let pool = &pool;
+                async move {
+                    pool.put(file_id, page_id, vec![data; PAGE_SIZE], image)
+                        .await
+                        .unwrap()[0]
+                }
+            };
+            assert_eq!(put(1, PageImage::Read).await, 1);
+            assert_eq!(put(2, PageImage::Read).await, 1);
+            assert_eq!(put(3, PageImage::WrittenThrough).await, 3);
+            assert_eq!(pool.get(file_id, page_id).unwrap().unwrap()[0], 3);
+            assert_eq!(pool.stats().unwrap().write_backs, 0);
+            let _ = mudu_sys::fs::sync::remove_file(path);
+        })
+        .unwrap()
+    }
+}
@@ -0,0 +1,295 @@
+//! Block compression for time-series page records and worker log frames.
+//!
+//! A compressed block is the little-endian `u32` length of the raw bytes
+//! followed by the codec output. Callers only store a compressed block when it
+//! is smaller than the raw bytes, so incompressible data costs nothing but the
+//! marker that says it was left as is.
+//!
+//! Time-series pages record their codec in bits 8..12 of the page header
+//! `flags`, so pages written before compression was enabled, or with another
+//! codec, stay readable next to new ones. Every record of a page with a codec
+//! starts with a one-byte tag: [`RECORD_TAG_RAW`] or [`RECORD_TAG_COMPRESSED`].
+
+use byteorder::{ByteOrder, LittleEndian};
+use mudu::common::result::RS;
+use mudu::error::{ErrorCode, MuduError};
+use mudu::mudu_error;
+use serde::{Deserialize, Serialize};
+use std::borrow::Cow;
+use std::fmt::{Display, Formatter};
+use std::str::FromStr;
+use std::sync::atomic::{AtomicU64, Ordering};
+
+const PAGE_FLAGS_CODEC_SHIFT: u32 = 8;
+const PAGE_FLAGS_CODEC_MASK: u64 = 0xF << PAGE_FLAGS_CODEC_SHIFT;
+const BLOCK_LEN_SIZE: usize = 4;
+const ZSTD_LEVEL: i32 = 3;
+
+/// Tag of a record stored uncompressed on a page with a codec.
+pub const RECORD_TAG_RAW: u8 = 0;
+/// Tag of a record stored as a compressed block.
+pub const RECORD_TAG_COMPRESSED: u8 = 1;
+
+/// Codec used for relation pages or worker log frames.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Compression {
+    #[default]
+    None,
+    Lz4,
+    Zstd,
+}
+
+impl Compression {
+    /// Codec number stored in page header flags and log frame headers.
+    pub fn code(self) -> u8 {
+        match self {
+            Compression::None => 0,
+            Compression::Lz4 => 1,
+            Compression::Zstd => 2,
+        }
+    }
+
+    pub fn from_code(code: u8) -> RS<Self> {
+        match code {
+            0 => Ok(Compression::None),
+            1 => Ok(Compression::Lz4),
+            2 => Ok(Compression::Zstd),
+            _ => Err(mudu_error!(
+                ErrorCode::Decode,
+                format!("unknown compression codec {}", code)
+            )),
+        }
+    }
+
+    /// Page header flag bits that mark a page written with this codec.
+    pub fn page_flags(self) -> u64 {
+        (self.code() as u64) << PAGE_FLAGS_CODEC_SHIFT
+    }
+
+    pub fn from_page_flags(flags: u64) -> RS<Self> {
+        Self::from_code(((flags & PAGE_FLAGS_CODEC_MASK) >> PAGE_FLAGS_CODEC_SHIFT) as u8)
+    }
+
+    /// Compresses `raw` into a block, or returns `None` when the block would
+    /// not be smaller than `raw`.
+    pub fn compress(self, raw: &[u8]) -> RS<Option<Vec<u8>>> {
+        let body = match self {
+            Compression::None => return Ok(None),
+            Compression::Lz4 => lz4_flex::block::compress(raw),
+            Compression::Zstd => zstd::bulk::compress(raw, ZSTD_LEVEL)
+                .map_err(|e| mudu_error!(ErrorCode::Encode, "zstd compression error", e))?,
+        };
+        if BLOCK_LEN_SIZE + body.len() >= raw.len() {
+            return Ok(None);
+        }
+        let mut block = Vec::with_capacity(BLOCK_LEN_SIZE + body.len());
+        block.extend_from_slice(&(raw.len() as u32).to_le_bytes());
+        block.extend_from_slice(&body);
+        Ok(Some(block))
+    }
+
+    /// Restores the raw bytes of a block written by [`Self::compress`].
+    pub fn decompress(self, block: &[u8]) -> RS<Vec<u8>> {
+        if block.len() < BLOCK_LEN_SIZE {
+            return Err(mudu_error!(
+                ErrorCode::Decode,
+                "compressed block is truncated"
+            ));
+        }
+        let raw_len = LittleEndian::read_u32(&block[..BLOCK_LEN_SIZE]) as usize;
+        let body = &block[BLOCK_LEN_SIZE..];
+        let raw = match self {
+            Compression::None => {
+                return Err(mudu_error!(
+                    ErrorCode::Decode,
+                    "compressed block without a codec"
+                ));
+            }
+            Compression::Lz4 => lz4_flex::block::decompress(body, raw_len)
+                .map_err(|e| mudu_error!(ErrorCode::Decode, "lz4 decompression error", e))?,
+            Compression::Zstd => zstd::bulk::decompress(body, raw_len)
+                .map_err(|e| mudu_error!(ErrorCode::Decode, "zstd decompression error", e))?,
+        };
+        if raw.len() != raw_len {
+            return Err(mudu_error!(
+                ErrorCode::Decode,
+                format!(
+                    "compressed block expands to {} bytes, expected {}",
+                    raw.len(),
+                    raw_len
+                )
+            ));
+        }
+        Ok(raw)
+    }
+
+    /// Encodes a record payload for a page written with this codec.
+    pub fn encode_record(self, payload: &[u8]) -> RS<Vec<u8>> {
+        if self == Compression::None {
+            return Ok(payload.to_vec());
+        }
+        let mut record = Vec::with_capacity(payload.len() + 1);
+        match self.compress(payload)? {
+            Some(block) => {
+                record.push(RECORD_TAG_COMPRESSED);
+                record.extend_from_slice(&block);
+            }
+            None => {
+                record.push(RECORD_TAG_RAW);
+                record.extend_from_slice(payload);
+            }
+        }
+        Ok(record)
+    }
+
+    /// Decodes a record payload read from a page written with this codec.
+    pub fn decode_record(self, record: &[u8]) -> RS<Cow<'_, [u8]>> {
+        if self == Compression::None {
+            return Ok(Cow::Borrowed(record));
+        }
+        match record.split_first() {
+            Some((&RECORD_TAG_RAW, payload)) => Ok(Cow::Borrowed(payload)),
+            Some((&RECORD_TAG_COMPRESSED, block)) => Ok(Cow::Owned(self.decompress(block)?)),
+            Some((tag, _)) => Err(mudu_error!(
+                ErrorCode::Decode,
+                format!("unknown record compression tag {}", tag)
+            )),
+            None => Err(mudu_error!(ErrorCode::Decode, "compressed record is empty")),
+        }
+    }
+}
+
+impl Display for Compression {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Compression::None => f.write_str("none"),
+            Compression::Lz4 => f.write_str("lz4"),
+            Compression::Zstd => f.write_str("zstd"),
+        }
+    }
+}
+
+impl FromStr for Compression {
+    type Err = MuduError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_ascii_lowercase().as_str() {
+            "none" => Ok(Compression::None),
+            "lz4" => Ok(Compression::Lz4),
+            "zstd" => Ok(Compression::Zstd),
+            _ => Err(mudu_error!(
+                ErrorCode::InvalidArgument,
+                format!("unknown compression {}, expected none, lz4 or zstd", s)
+            )),
+        }
+    }
+}
+
+/// Bytes handed to a codec and bytes actually stored, since creation.
+#[derive(Debug, Default)]
+pub struct CompressionStats {
+    raw_bytes: AtomicU64,
+    stored_bytes: AtomicU64,
+}
+
+/// A point-in-time copy of [`CompressionStats`].
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct CompressionStatsSnapshot {
+    pub raw_bytes: u64,
+    pub stored_bytes: u64,
+}
+
+impl CompressionStats {
+    pub fn record(&self, raw_bytes: usize, stored_bytes: usize) {
+        self.raw_bytes
+            .fetch_add(raw_bytes as u64, Ordering::Relaxed);
+        self.stored_bytes
+            .fetch_add(stored_bytes as u64, Ordering::Relaxed);
+    }
+
+    pub fn snapshot(&self) -> CompressionStatsSnapshot {
+        CompressionStatsSnapshot {
+            raw_bytes: self.raw_bytes.load(Ordering::Relaxed),
+            stored_bytes: self.stored_bytes.load(Ordering::Relaxed),
+        }
+    }
+}
+
+impl CompressionStatsSnapshot {
+    /// Raw bytes per stored byte; `1.0` before anything was written.
+    pub fn ratio(&self) -> f64 {
+        if self.stored_bytes == 0 {
+            1.0
+        } else {
+            self.raw_bytes as f64 / self.stored_bytes as f64
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(
+        clippy::unwrap_used,
+        clippy::expect_used,
+        clippy::panic,
+        clippy::todo,
+        clippy::unimplemented
+    )]
+
+    use super::*;
+
+    fn compressible() -> Vec<u8> {
+        b"time series row payload ".repeat(40)
+    }
+
+    #[test]
+    fn records_round_trip_with_every_codec() {
+        for codec in [Compression::None, Compression::Lz4, Compression::Zstd] {
+            for payload in [compressible(), b"tiny".to_vec(), Vec::new()] {
+                let record = codec.encode_record(&payload).unwrap();
+                assert_eq!(codec.decode_record(&record).unwrap().as_ref(), payload);
+            }
+        }
+    }
+
+    #[test]
+    fn incompressible_records_are_stored_raw() {
+        let record = Compression::Lz4.encode_record(b"abc").unwrap();
+        assert_eq!(record, [&[RECORD_TAG_RAW][..], b"abc"].concat());
+        let record = Compression::Zstd.encode_record(&compressible()).unwrap();
+        assert_eq!(record[0], RECORD_TAG_COMPRESSED);
+        assert!(record.len() < compressible().len());
+    }
+
+    #[test]
+    fn page_flags_keep_other_bits() {
+        let flags = 0x3 | Compression::Zstd.page_flags();
+        assert_eq!(
+            Compression::from_page_flags(flags).unwrap(),
+            Compression::Zstd
+        );
+        assert_eq!(
+            Compression::from_page_flags(0x3).unwrap(),
+            Compression::None
+        );
+        assert!(Compression::from_page_flags(0xF << 8).is_err());
+    }
+
+    #[test]
+    fn parses_option_values() {
+        assert_eq!("LZ4".parse::<Compression>().unwrap(), Compression::Lz4);
+        assert_eq!("zstd".parse::<Compression>().unwrap(), Compression::Zstd);
+        assert_eq!(Compression::Lz4.to_string(), "lz4");
+        let err = "gzip".parse::<Compression>().unwrap_err();
+        assert_eq!(err.ec(), ErrorCode::InvalidArgument);
+    }
+
+    #[test]
+    fn stats_report_the_ratio() {
+        let stats = CompressionStats::default();
+        assert_eq!(stats.snapshot().ratio(), 1.0);
+        stats.record(400, 100);
+        assert_eq!(stats.snapshot().ratio(), 4.0);
+    }
+}
@@ -0,0 +1,648 @@
+//! Encryption at rest for relation pages, worker log frames and checkpoint
+//! state files.
+//!
+//! Data is sealed with AES-256-GCM under keys read from a key file. Every key
+//! has a numeric id that is stored next to the data it sealed, and the key
+//! with the highest id seals everything written from now on. A key is rotated
+//! by adding one with a higher id: data sealed with older keys stays readable
+//! while relation pages are rewritten under the new key in the background.
+//!
+//! Nonces come from a [`NonceSequence`], one per file writer: a random 96-bit
+//! start incremented for every seal. Seals of one sequence never share a
+//! nonce, and two sequences overlap as rarely as random nonces collide.
+//!
+//! A page keeps its header in plaintext, so it can be located, migrated and
+//! checksummed without the key; the header and the tailer LSN are
+//! authenticated as associated data. The envelope lives in the reserved
+//! header bytes: key id at 84, nonce at 88 and tag at 100. Key id `0` marks a
+//! plaintext page. The tailer checksum covers the page as stored.
+
+use crate::storage::page::page_header::{
+    PAGE_HEADER_OFF_KEY_ID, PAGE_HEADER_OFF_NONCE, PAGE_HEADER_OFF_TAG, PAGE_HEADER_SIZE,
+};
+use crate::storage::page::page_tailer::{PageTailer, PAGE_CHECKSUM_SIZE, PAGE_TAILER_SIZE};
+use aes_gcm::aead::rand_core::RngCore;
+use aes_gcm::aead::OsRng;
+use aes_gcm::{AeadInPlace, Aes256Gcm, KeyInit, Nonce, Tag};
+use byteorder::{ByteOrder, LittleEndian};
+use mudu::common::result::RS;
+use mudu::error::{ErrorCode, MuduError};
+use mudu::mudu_error;
+use serde::{Deserialize, Serialize};
+use std::borrow::Cow;
+use std::collections::BTreeMap;
+use std::fmt::{Debug, Formatter};
+use std::path::Path;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::Arc;
+use std::time::Duration;
+
+pub const KEY_LEN: usize = 32;
+pub const NONCE_LEN: usize = 12;
+pub const TAG_LEN: usize = 16;
+const KEY_ID_LEN: usize = 4;
+/// Bytes a sealed blob adds to its plaintext.
+pub const SEAL_OVERHEAD: usize = KEY_ID_LEN + NONCE_LEN + TAG_LEN;
+
+/// Name of the file in a data directory that records which keys sealed it.
+pub const KEY_CHECK_FILE: &str = "encryption.check";
+const KEY_CHECK_PLAINTEXT: &[u8] = b"mududb encryption key check";
+
+struct DataKey {
+    bytes: [u8; KEY_LEN],
+    cipher: Aes256Gcm,
+}
+
+/// The keys of a key file, by id.
+pub struct Keyring {
+    keys: BTreeMap<u32, DataKey>,
+}
+
+impl Keyring {
+    /// Parses a key file: one `<id> <64 hex digits>` pair per line. Blank
+    /// lines and lines starting with `#` are skipped. Ids start at 1.
+    pub fn parse(text: &str) -> RS<Self> {
+        let mut keys = BTreeMap::new();
+        for (index, line) in text.lines().enumerate() {
+            let line = line.trim();
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+            let invalid = |reason: &str| {
+                mudu_error!(
+                    ErrorCode::InvalidArgument,
+                    format!("key file line {}: {}", index + 1, reason)
+                )
+            };
+            let mut fields = line.split_whitespace();
+            let (Some(id), Some(hex_key), None) = (fields.next(), fields.next(), fields.next())
+            else {
+                return Err(invalid("expected `<id> <hex key>`"));
+            };
+            let id = id
+                .parse::<u32>()
+                .ok()
+                .filter(|id| *id != 0)
+                .ok_or_else(|| invalid("key id must be a positive integer"))?;
+            let mut bytes = [0u8; KEY_LEN];
+            hex::decode_to_slice(hex_key, &mut bytes)
+                .map_err(|_| invalid("key must be 64 hex digits"))?;
+            let cipher = Aes256Gcm::new_from_slice(&bytes)
+                .map_err(|_| invalid("key must be 64 hex digits"))?;
+            if keys.insert(id, DataKey { bytes, cipher }).is_some() {
+                return Err(invalid(&format!("duplicate key id {}", id)));
+            }
+        }
+        if keys.is_empty() {
+            return Err(mudu_error!(
+                ErrorCode::InvalidArgument,
+                "key file holds no keys"
+            ));
+        }
+        Ok(Self { keys })
+    }
+
+    pub fn load(path: &Path) -> RS<Self> {
+        let text = mudu_sys::fs::sync::read_to_string(path).map_err(|e| {
+            mudu_error!(
+                ErrorCode::Io,
+                format!("read key file {} error", path.display()),
+                e
+            )
+        })?;
+        Self::parse(&text)
+    }
+
+    /// Id of the key that seals new data.
+    pub fn active_key_id(&self) -> u32 {
+        self.keys.keys().next_back().copied().unwrap_or(0)
+    }
+
+    pub fn key_ids(&self) -> impl Iterator<Item = u32> + '_ {
+        self.keys.keys().copied()
+    }
+
+    fn clone_keys(&self) -> Self {
+        Self {
+            keys: self
+                .keys
+                .iter()
+                .map(|(id, key)| {
+                    (
+                        *id,
+                        DataKey {
+                            bytes: key.bytes,
+                            cipher: key.cipher.clone(),
+                        },
+                    )
+                })
+                .collect(),
+        }
+    }
+
+    // A keyring whose active key is `key_id`.
+    fn only(&self, key_id: u32) -> Self {
+        let mut keyring = self.clone_keys();
+        keyring.keys.retain(|id, _| *id == key_id);
+        keyring
+    }
+
+    fn cipher(&self, key_id: u32) -> RS<&Aes256Gcm> {
+        self.keys
+            .get(&key_id)
+            .map(|key| &key.cipher)
+            .ok_or_else(|| {
+                mudu_error!(
+                    ErrorCode::EncryptionKeyMismatch,
+                    format!(
+                        "data is sealed with key id {}, which the key file does not hold",
+                        key_id
+                    )
+                )
+            })
+    }
+
+    /// Decrypts a blob written by [`Sealer::seal`] with the same `aad`.
+    pub fn open(&self, aad: &[u8], blob: &[u8]) -> RS<Vec<u8>> {
+        if blob.len() < SEAL_OVERHEAD {
+            return Err(mudu_error!(
+                ErrorCode::CorruptedData,
+                format!("sealed blob of {} bytes is truncated", blob.len())
+            ));
+        }
+        let key_id = u32::from_be_bytes([blob[0], blob[1], blob[2], blob[3]]);
+        let nonce = &blob[KEY_ID_LEN..KEY_ID_LEN + NONCE_LEN];
+        let (body, tag) = blob[KEY_ID_LEN + NONCE_LEN..].split_at(blob.len() - SEAL_OVERHEAD);
+        let mut plaintext = body.to_vec();
+        self.cipher(key_id)?
+            .decrypt_in_place_detached(
+                Nonce::from_slice(nonce),
+                aad,
+                &mut plaintext,
+                Tag::from_slice(tag),
+            )
+            .map_err(|_| authentication_failed(key_id, "sealed blob"))?;
+        Ok(plaintext)
+    }
+
+    /// Decrypts a page sealed by [`Sealer::seal_page`] in place and clears
+    /// its envelope. Plaintext pages are left as they are.
+    pub fn open_page(&self, page: &mut [u8]) -> RS<()> {
+        let key_id = page_key_id(page)?;
+        if key_id == 0 {
+            return Ok(());
+        }
+        let mut nonce = [0u8; NONCE_LEN];
+        nonce.copy_from_slice(&page[PAGE_HEADER_OFF_NONCE..PAGE_HEADER_OFF_NONCE + NONCE_LEN]);
+        let mut tag = [0u8; TAG_LEN];
+        tag.copy_from_slice(&page[PAGE_HEADER_OFF_TAG..PAGE_HEADER_OFF_TAG + TAG_LEN]);
+        let aad = page_aad(page);
+        let body_end = page.len() - PAGE_TAILER_SIZE;
+        self.cipher(key_id)?
+            .decrypt_in_place_detached(
+                Nonce::from_slice(&nonce),
+                &aad,
+                &mut page[PAGE_HEADER_SIZE..body_end],
+                Tag::from_slice(&tag),
+            )
+            .map_err(|_| authentication_failed(key_id, "page"))?;
+        page[PAGE_HEADER_OFF_KEY_ID..PAGE_HEADER_OFF_TAG + TAG_LEN].fill(0);
+        reseal_tailer(page)
+    }
+}
+
+impl PartialEq for Keyring {
+    fn eq(&self, other: &Self) -> bool {
+        self.keys.len() == other.keys.len()
+            && self
+                .keys
+                .iter()
+                .zip(other.keys.iter())
+                .all(|((id, key), (other_id, other_key))| {
+                    id == other_id && key.bytes == other_key.bytes
+                })
+    }
+}
+
+impl Debug for Keyring {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Keyring")
+            .field("key_ids", &self.keys.keys().collect::<Vec<_>>())
+            .finish()
+    }
+}
+
+/// Nonces of one file writer.
+pub struct NonceSequence {
+    prefix: [u8; NONCE_LEN - 8],
+    counter: AtomicU64,
+}
+
+impl NonceSequence {
+    pub fn new() -> RS<Self> {
+        let mut start = [0u8; NONCE_LEN];
+        OsRng.try_fill_bytes(&mut start).map_err(|e| {
+            mudu_error!(
+                ErrorCode::External,
+                format!("draw random nonce error: {}", e)
+            )
+        })?;
+        let mut prefix = [0u8; NONCE_LEN - 8];
+        prefix.copy_from_slice(&start[..NONCE_LEN - 8]);
+        let counter = u64::from_be_bytes([
+            start[4], start[5], start[6], start[7], start[8], start[9], start[10], start[11],
+        ]);
+        Ok(Self {
+            prefix,
+            counter: AtomicU64::new(counter),
+        })
+    }
+
+    fn next(&self) -> [u8; NONCE_LEN] {
+        let counter = self.counter.fetch_add(1, Ordering::Relaxed);
+        let mut nonce = [0u8; NONCE_LEN];
+        nonce[..NONCE_LEN - 8].copy_from_slice(&self.prefix);
+        nonce[NONCE_LEN - 8..].copy_from_slice(&counter.to_be_bytes());
+        nonce
+    }
+}
+
+/// Seals data of one file with the active key of a keyring.
+pub struct Sealer {
+    keyring: Arc<Keyring>,
+    nonces: NonceSequence,
+}
+
+impl Sealer {
+    pub fn new(keyring: Arc<Keyring>) -> RS<Self> {
+        Ok(Self {
+            keyring,
+            nonces: NonceSequence::new()?,
+        })
+    }
+
+    /// A sealer for `keyring`, or `None` when encryption is off.
+    pub fn for_keyring(keyring: Option<&Arc<Keyring>>) -> RS<Option<Self>> {
+        keyring.cloned().map(Self::new).transpose()
+    }
+
+    pub fn keyring(&self) -> &Keyring {
+        &self.keyring
+    }
+
+    /// Encrypts `plaintext` into a blob: the big-endian key id, the nonce,
+    /// the ciphertext and the tag. `aad` is authenticated but not stored.
+    pub fn seal(&self, aad: &[u8], plaintext: &[u8]) -> RS<Vec<u8>> {
+        let key_id = self.keyring.active_key_id();
+        let nonce = self.nonces.next();
+        let mut blob = Vec::with_capacity(plaintext.len() + SEAL_OVERHEAD);
+        blob.extend_from_slice(&key_id.to_be_bytes());
+        blob.extend_from_slice(&nonce);
+        blob.extend_from_slice(plaintext);
+        let tag = self
+            .keyring
+            .cipher(key_id)?
+            .encrypt_in_place_detached(
+                Nonce::from_slice(&nonce),
+                aad,
+                &mut blob[KEY_ID_LEN + NONCE_LEN..],
+            )
+            .map_err(|_| mudu_error!(ErrorCode::Encode, "encrypt blob error"))?;
+        blob.extend_from_slice(&tag);
+        Ok(blob)
+    }
+
+    /// Encrypts the body of a plaintext page in place, stores the envelope in
+    /// its header and recomputes the tailer checksum.
+    pub fn seal_page(&self, page: &mut [u8]) -> RS<()> {
+        if page_key_id(page)? != 0 {
+            return Err(mudu_error!(ErrorCode::Internal, "page is sealed already"));
+        }
+        let key_id = self.keyring.active_key_id();
+        let nonce = self.nonces.next();
+        LittleEndian::write_u32(
+            &mut page[PAGE_HEADER_OFF_KEY_ID..PAGE_HEADER_OFF_KEY_ID + KEY_ID_LEN],
+            key_id,
+        );
+        page[PAGE_HEADER_OFF_NONCE..PAGE_HEADER_OFF_NONCE + NONCE_LEN].copy_from_slice(&nonce);
+        page[PAGE_HEADER_OFF_TAG..PAGE_HEADER_OFF_TAG + TAG_LEN].fill(0);
+        let aad = page_aad(page);
+        let body_end = page.len() - PAGE_TAILER_SIZE;
+        let tag = self
+            .keyring
+            .cipher(key_id)?
+            .encrypt_in_place_detached(
+                Nonce::from_slice(&nonce),
+                &aad,
+                &mut page[PAGE_HEADER_SIZE..body_end],
+            )
+            .map_err(|_| mudu_error!(ErrorCode::Encode, "encrypt page error"))?;
+        page[PAGE_HEADER_OFF_TAG..PAGE_HEADER_OFF_TAG + TAG_LEN].copy_from_slice(&tag);
+        reseal_tailer(page)
+    }
+}
+
+/// Returns `page` as it goes to disk: sealed when `sealer` is given.
+pub fn seal_page_image<'a>(sealer: Option<&Sealer>, page: &'a [u8]) -> RS<Cow<'a, [u8]>> {
+    match sealer {
+        Some(sealer) => {
+            let mut sealed = page.to_vec();
+            sealer.seal_page(&mut sealed)?;
+            Ok(Cow::Owned(sealed))
+        }
+        None => Ok(Cow::Borrowed(page)),
+    }
+}
+
+/// Decrypts a page read from disk with `keyring`; plaintext pages need none.
+pub fn open_stored_page(keyring: Option<&Keyring>, page: &mut [u8]) -> RS<()> {
+    let key_id = page_key_id(page)?;
+    if key_id == 0 {
+        return Ok(());
+    }
+    match keyring {
+        Some(keyring) => keyring.open_page(page),
+        None => Err(no_keyring(key_id)),
+    }
+}
+
+/// Decrypts a blob read from disk with `keyring`.
+pub fn open_stored_blob(keyring: Option<&Keyring>, aad: &[u8], blob: &[u8]) -> RS<Vec<u8>> {
+    match keyring {
+        Some(keyring) => keyring.open(aad, blob),
+        None => Err(no_keyring(blob_key_id(blob))),
+    }
+}
+
+/// Id of the key that sealed `page`; `0` for a plaintext page.
+pub fn page_key_id(page: &[u8]) -> RS<u32> {
+    if page.len() < PAGE_HEADER_SIZE + PAGE_TAILER_SIZE {
+        return Err(mudu_error!(
+            ErrorCode::Decode,
+            format!("page of {} bytes is truncated", page.len())
+        ));
+    }
+    Ok(LittleEndian::read_u32(
+        &page[PAGE_HEADER_OFF_KEY_ID..PAGE_HEADER_OFF_KEY_ID + KEY_ID_LEN],
+    ))
+}
+
+fn blob_key_id(blob: &[u8]) -> u32 {
+    match blob {
+        [a, b, c, d, ..] => u32::from_be_bytes([*a, *b, *c, *d]),
+        _ => 0,
+    }
+}
+
+// The header without the tag, followed by the tailer LSN.
+fn page_aad(page: &[u8]) -> Vec<u8> {
+    let tailer = page.len() - PAGE_TAILER_SIZE;
+    let mut aad = Vec::with_capacity(PAGE_HEADER_SIZE + PAGE_TAILER_SIZE);
+    aad.extend_from_slice(&page[..PAGE_HEADER_OFF_TAG]);
+    aad.extend_from_slice(&page[PAGE_HEADER_OFF_TAG + TAG_LEN..PAGE_HEADER_SIZE]);
+    aad.extend_from_slice(&page[tailer..page.len() - PAGE_CHECKSUM_SIZE]);
+    aad
+}
+
+fn reseal_tailer(page: &mut [u8]) -> RS<()> {
+    let mut tailer = PageTailer::decode(&page[page.len() - PAGE_TAILER_SIZE..])?;
+    tailer.seal(page)
+}
+
+fn authentication_failed(key_id: u32, what: &str) -> MuduError {
+    mudu_error!(
+        ErrorCode::CorruptedData,
+        format!(
+            "{} sealed with key id {} failed authentication: the data was modified or the key is wrong",
+            what, key_id
+        )
+    )
+}
+
+fn no_keyring(key_id: u32) -> MuduError {
+    mudu_error!(
+        ErrorCode::EncryptionKeyMismatch,
+        format!(
+            "data is sealed with key id {}, but no encryption key file is configured",
+            key_id
+        )
+    )
+}
+
+#[derive(Debug, Default, Serialize, Deserialize)]
+struct KeyCheckFile {
+    // A known plaintext sealed with each key that ever sealed data here.
+    sealed: BTreeMap<u32, Vec<u8>>,
+}
+
+/// Checks the keyring against the key check file of `data_dir` and records
+/// new keys in it.
+///
+/// Startup fails with [`ErrorCode::EncryptionKeyMismatch`] when a key id of
+/// the keyring was used here with a different key, or when none of the keys
+/// that sealed the directory is in the keyring.
+pub fn check_data_dir_keys(data_dir: &Path, keyring: &Keyring) -> RS<()> {
+    let path = data_dir.join(KEY_CHECK_FILE);
+    let mut check = if mudu_sys::fs::sync::path_exists(&path) {
+        let bytes = mudu_sys::fs::sync::read(&path)?;
+        rmp_serde::from_slice::<KeyCheckFile>(&bytes).map_err(|e| {
+            mudu_error!(
+                ErrorCode::Decode,
+                format!("decode key check file {} error", path.display()),
+                e
+            )
+        })?
+    } else {
+        KeyCheckFile::default()
+    };
+    let mut known = false;
+    for (key_id, sealed) in &check.sealed {
+        if !keyring.keys.contains_key(key_id) {
+            continue;
+        }
+        let opened = keyring.open(KEY_CHECK_FILE.as_bytes(), sealed);
+        if opened.ok().as_deref() != Some(KEY_CHECK_PLAINTEXT) {
+            return Err(mudu_error!(
+                ErrorCode::EncryptionKeyMismatch,
+                format!(
+                    "key id {} of the key file is not the key that sealed {}",
+                    key_id,
+                    data_dir.display()
+                )
+            ));
+        }
+        known = true;
+    }
+    if !check.sealed.is_empty() && !known {
+        return Err(mudu_error!(
+            ErrorCode::EncryptionKeyMismatch,
+            format!(
+                "{} is sealed with key ids {:?}, and the key file holds none of them",
+                data_dir.display(),
+                check.sealed.keys().collect::<Vec<_>>()
+            )
+        ));
+    }
+    let missing: Vec<u32> = keyring
+        .key_ids()
+        .filter(|key_id| !check.sealed.contains_key(key_id))
+        .collect();
+    if missing.is_empty() {
+        return Ok(());
+    }
+    for key_id in missing {
+        let sealer = Sealer::new(Arc::new(keyring.only(key_id)))?;
+        check.sealed.insert(
+            key_id,
+            sealer.seal(KEY_CHECK_FILE.as_bytes(), KEY_CHECK_PLAINTEXT)?,
+        );
+    }
+    let bytes = rmp_serde::to_vec(&check)
+        .map_err(|e| mudu_error!(ErrorCode::Encode, "encode key check file error", e))?;
+    mudu_sys::fs::sync::create_dir_all(data_dir)?;
+    mudu_sys::fs::sync::write(&path, bytes)?;
+    Ok(())
+}
+
+/// Loads `key_file` and checks it against `data_dir`.
+pub fn load_key_file(key_file: &Path, data_dir: &Path) -> RS<Arc<Keyring>> {
+    let keyring = Keyring::load(key_file)?;
+    check_data_dir_keys(data_dir, &keyring)?;
+    Ok(Arc::new(keyring))
+}
+
+/// Time between two background re-encryption steps of a worker.
+pub(crate) const REENCRYPT_INTERVAL: Duration = Duration::from_millis(200);
+/// Pages one re-encryption step visits before handing control back to the
+/// worker loop.
+pub(crate) const REENCRYPT_BATCH_PAGES: u64 = 64;
+
+/// Progress of rewriting relation pages under the active key.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct ReencryptStats {
+    pub scanned_pages: u64,
+    pub rewritten_pages: u64,
+    /// Every page was visited since the worker started.
+    pub done: bool,
+}
+
+impl ReencryptStats {
+    pub fn add(&mut self, other: ReencryptStats) {
+        self.scanned_pages += other.scanned_pages;
+        self.rewritten_pages += other.rewritten_pages;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(
+        clippy::unwrap_used,
+        clippy::expect_used,
+        clippy::panic,
+        clippy::todo,
+        clippy::unimplemented
+    )]
+
+    use super::*;
+    use crate::storage::page::page_block_ref::{PageBlockRef, PAGE_SIZE};
+    use crate::storage::page::page_block_ref_mut::PageBlockRefMut;
+    use crate::storage::page::PageId;
+    use project_root::get_project_root;
+
+    const KEY_1: &str = "1111111111111111111111111111111111111111111111111111111111111111";
+    const KEY_2: &str = "2222222222222222222222222222222222222222222222222222222222222222";
+
+    fn keyring(text: &str) -> Arc<Keyring> {
+        Arc::new(Keyring::parse(text).unwrap())
+    }
+
+    fn page() -> Vec<u8> {
+        let mut page = vec![0u8; PAGE_SIZE];
+        let mut block = PageBlockRefMut::new(&mut page);
+        block.init_empty(PageId::new(7)).unwrap();
+        block.insert_record(1, 1, b"secret payload").unwrap();
+        page
+    }
+
+    #[test]
+    fn parses_key_files() {
+        let keyring = keyring(&format!("# rotated\n1 {}\n\n2 {}\n", KEY_1, KEY_2));
+        assert_eq!(keyring.active_key_id(), 2);
+        assert_eq!(keyring.key_ids().collect::<Vec<_>>(), vec![1, 2]);
+        for text in [
+            String::new(),
+            format!("0 {}", KEY_1),
+            "1 abcd".to_string(),
+            format!("1 {}\n1 {}", KEY_1, KEY_2),
+        ] {
+            let err = Keyring::parse(&text).unwrap_err();
+            assert_eq!(err.ec(), ErrorCode::InvalidArgument);
+        }
+    }
+
+    #[test]
+    fn sealed_pages_keep_a_plaintext_header() {
+        let plain = page();
+        let sealer = Sealer::new(keyring(&format!("1 {}", KEY_1))).unwrap();
+        let sealed = seal_page_image(Some(&sealer), &plain).unwrap().into_owned();
+        assert_eq!(page_key_id(&sealed).unwrap(), 1);
+        assert!(!sealed
+            .windows(b"secret".len())
+            .any(|window| window == b"secret"));
+        let block = PageBlockRef::new(&sealed);
+        block.verify_checksum().unwrap();
+        assert_eq!(block.header_page_id().unwrap(), PageId::new(7));
+
+        let mut opened = sealed.clone();
+        sealer.keyring().open_page(&mut opened).unwrap();
+        assert_eq!(opened, plain);
+
+        // Every seal draws a new nonce; a changed header or another key
+        // fails authentication.
+        let resealed = seal_page_image(Some(&sealer), &plain).unwrap();
+        assert_ne!(resealed.as_ref(), sealed.as_slice());
+        let mut tampered = sealed.clone();
+        tampered[crate::storage::page::page_header::PAGE_HEADER_OFF_LSN] ^= 1;
+        let err = sealer.keyring().open_page(&mut tampered).unwrap_err();
+        assert_eq!(err.ec(), ErrorCode::CorruptedData);
+        let mut wrong_key = sealed.clone();
+        let err = keyring(&format!("1 {}", KEY_2))
+            .open_page(&mut wrong_key)
+            .unwrap_err();
+        assert_eq!(err.ec(), ErrorCode::CorruptedData);
+    }
+
+    #[test]
+    fn rotated_keyrings_read_old_blobs() {
+        let old = Sealer::new(keyring(&format!("1 {}", KEY_1))).unwrap();
+        let blob = old.seal(b"header", b"log payload").unwrap();
+        assert_eq!(blob.len(), b"log payload".len() + SEAL_OVERHEAD);
+        let rotated = keyring(&format!("1 {}\n2 {}", KEY_1, KEY_2));
+        assert_eq!(rotated.open(b"header", &blob).unwrap(), b"log payload");
+        assert!(rotated.open(b"other header", &blob).is_err());
+        let err = keyring(&format!("2 {}", KEY_2))
+            .open(b"header", &blob)
+            .unwrap_err();
+        assert_eq!(err.ec(), ErrorCode::EncryptionKeyMismatch);
+    }
+
+    #[test]
+    fn data_dir_rejects_a_wrong_key() {
+        let dir = get_project_root()
+            .unwrap()
+            .join("target")
+            .join("tmp")
+            .join(format!("encryption_{}", mudu_sys::random::uuid_v4()));
+        check_data_dir_keys(&dir, &keyring(&format!("1 {}", KEY_1))).unwrap();
+        check_data_dir_keys(&dir, &keyring(&format!("1 {}", KEY_1))).unwrap();
+        // Rotating adds the new key; dropping the old one afterwards is fine.
+        check_data_dir_keys(&dir, &keyring(&format!("1 {}\n2 {}", KEY_1, KEY_2))).unwrap();
+        check_data_dir_keys(&dir, &keyring(&format!("2 {}", KEY_2))).unwrap();
+
+        let err = check_data_dir_keys(&dir, &keyring(&format!("1 {}", KEY_2))).unwrap_err();
+        assert_eq!(err.ec(), ErrorCode::EncryptionKeyMismatch);
+        let err = check_data_dir_keys(&dir, &keyring(&format!("3 {}", KEY_1))).unwrap_err();
+        assert_eq!(err.ec(), ErrorCode::EncryptionKeyMismatch);
+        mudu_sys::fs::sync::remove_dir_all(&dir).unwrap();
+    }
+}
@@ -5,6 +5,14 @@
 
 #![allow(missing_docs)]
 
+pub mod backup;
+pub mod buffer_pool;
+pub mod compression;
+pub mod encryption;
 pub mod page;
+pub mod page_size;
+pub mod page_size_migration;
 pub mod relation;
+pub(crate) mod state_file;
 pub mod time_series;
+pub mod verify;
@@ -14,7 +14,7 @@ pub const NONE_PAGE_ID: PageId = PageId::MAX;
 /// sequence is `45 47 41 50`, which corresponds to `E G A P` if read byte by byte.
 pub const PAGE_HEADER_MAGIC: u32 = 0x5041_4745;
 
-// Layout v1:
+// Layout v1 and v2 (v2 only widens the tailer checksum, see `page::migrate`):
 // magic(u32) + version(u32)
 // + page_id(u64) + prev_page(u64) + next_page(u64) + lsn(u64) + flags(u64) + tuple_flags(u64)
 // + record_count(u32) + first_free_offset(u32) + free_bytes(u32) + last_record_offset(u32)
@@ -46,6 +46,10 @@ pub const PAGE_HEADER_OFF_LAST_RECORD_OFFSET: usize = 68;
 pub const PAGE_HEADER_OFF_TUPLE_FORMAT_VERSION: usize = 72;
 pub const PAGE_HEADER_OFF_TUPLE_SCHEMA_HASH: usize = 76;
 pub const PAGE_HEADER_OFF_RESERVED: usize = 84;
+// Encryption envelope inside the reserved bytes, see `storage::encryption`.
+pub const PAGE_HEADER_OFF_KEY_ID: usize = 84;
+pub const PAGE_HEADER_OFF_NONCE: usize = 88;
+pub const PAGE_HEADER_OFF_TAG: usize = 100;
 
 const U32_LEN: usize = 4;
 const U64_LEN: usize = 8;
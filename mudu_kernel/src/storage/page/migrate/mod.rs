@@ -8,37 +8,138 @@
 //! 128-byte page header.  When a page format evolves, fields outside the header
 //! (slot array, tailer, record payloads) may also change, so the migration unit
 //! must be the whole page.
+//!
+//! Version history:
+//!
+//! - `v1`: the tailer CRC32 covers `[0, page_len - 12)`, leaving the tailer LSN
+//!   unprotected, and is not checked when a page is read.
+//! - `v2`: the tailer CRC32 covers everything except the checksum field itself,
+//!   `[0, page_len - 4)`, and is verified on every page read.
 
-use mudu_compat_migrate::handler::{clone_rollback, clone_upgrade, MigrateHandler};
+use crate::storage::page::page_header::{PAGE_HEADER_OFF_VERSION, PAGE_HEADER_SIZE};
+use crate::storage::page::page_tailer::{PAGE_CHECKSUM_SIZE, PAGE_TAILER_SIZE};
+use byteorder::{ByteOrder, LittleEndian};
+use mudu::common::crc::crc32;
+use mudu::compat::FormatKind;
+use mudu_compat_migrate::handler::{MigrateHandler, MigrateOption};
+use mudu_compat_migrate::MigrateError;
 
-/// Returns a placeholder identity handler that clones the input page unchanged.
+/// Returns the `v1 -> v2` handler.
 ///
-/// This will be replaced by real `v1 -> v2` handlers when the page format
-/// evolves.  The input and output of the real handlers must be a complete page
-/// binary compatible with the target page version.
-pub fn identity() -> MigrateHandler {
+/// The upgrade re-seals the tailer checksum over the v2 range, but only when
+/// the v1 checksum is intact; a page that was already corrupt keeps its stale
+/// checksum so the read-time verification still reports it.
+pub fn v1_to_v2() -> MigrateHandler {
     MigrateHandler {
         from: 1,
-        to: 1,
-        upgrade: clone_upgrade,
-        rollback: clone_rollback,
+        to: 2,
+        upgrade: upgrade_v1_to_v2,
+        rollback: rollback_v2_to_v1,
     }
 }
 
+fn upgrade_v1_to_v2(old: &[u8], _option: Option<&MigrateOption>) -> Result<Vec<u8>, MigrateError> {
+    reseal(old, 1, 2, PAGE_TAILER_SIZE, PAGE_CHECKSUM_SIZE)
+}
+
+fn rollback_v2_to_v1(new: &[u8], _option: Option<&MigrateOption>) -> Result<Vec<u8>, MigrateError> {
+    reseal(new, 2, 1, PAGE_CHECKSUM_SIZE, PAGE_TAILER_SIZE)
+}
+
+/// Rewrites the header version of `page` and, if the checksum computed with
+/// the `from` coverage matches, recomputes it with the `to` coverage.
+fn reseal(
+    page: &[u8],
+    from: u32,
+    to: u32,
+    from_uncovered: usize,
+    to_uncovered: usize,
+) -> Result<Vec<u8>, MigrateError> {
+    if page.len() < PAGE_HEADER_SIZE + PAGE_TAILER_SIZE {
+        return Err(MigrateError::MigrationFailed {
+            component: FormatKind::Page,
+            from,
+            to,
+            step: 0,
+            source: format!("page of {} bytes is truncated", page.len()),
+        });
+    }
+    let mut page = page.to_vec();
+    let len = page.len();
+    let checksum_offset = len - PAGE_CHECKSUM_SIZE;
+    let stored = LittleEndian::read_u32(&page[checksum_offset..]);
+    let intact = stored == crc32(&page[..len - from_uncovered]);
+    LittleEndian::write_u32(
+        &mut page[PAGE_HEADER_OFF_VERSION..PAGE_HEADER_OFF_VERSION + 4],
+        to,
+    );
+    if intact {
+        let checksum = crc32(&page[..len - to_uncovered]);
+        LittleEndian::write_u32(&mut page[checksum_offset..], checksum);
+    }
+    Ok(page)
+}
+
 #[cfg(test)]
 mod tests {
+    #![allow(
+        clippy::unwrap_used,
+        clippy::expect_used,
+        clippy::panic,
+        clippy::todo,
+        clippy::unimplemented
+    )]
+
     use super::*;
-    use mudu::compat::FormatKind;
-    use mudu_compat_migrate::{CompatibilityRouter, MigrateError, NoopOptionProvider};
+    use crate::storage::page::page_block_ref::{PageBlockRef, PAGE_SIZE};
+    use crate::storage::page::page_block_ref_mut::PageBlockRefMut;
+    use crate::storage::page::PageId;
+    use mudu::error::ErrorCode;
+    use mudu_compat_migrate::{CompatibilityRouter, NoopOptionProvider};
 
-    #[test]
-    fn identity_handler_clones_input() -> Result<(), MigrateError> {
+    /// Builds a v1 page: same layout, version 1 and the checksum over the v1
+    /// range.
+    fn v1_page() -> Vec<u8> {
+        let mut page = vec![0u8; PAGE_SIZE];
+        let mut block = PageBlockRefMut::new(&mut page);
+        block.init_empty(PageId::new(3)).unwrap();
+        block.insert_record(10, 1, b"payload").unwrap();
+        reseal(&page, 2, 1, PAGE_CHECKSUM_SIZE, PAGE_TAILER_SIZE).unwrap()
+    }
+
+    fn router() -> CompatibilityRouter {
         let mut router = CompatibilityRouter::new();
-        router.set_supported_window(FormatKind::Page, 1, 1);
-        router.register(FormatKind::Page, identity());
-        let input = vec![0xABu8, 0xCD, 0xEF];
-        let output = router.migrate(FormatKind::Page, 1, 1, &input, &NoopOptionProvider)?;
-        assert_eq!(output, input);
-        Ok(())
+        router.set_supported_window(FormatKind::Page, 1, 2);
+        router.register(FormatKind::Page, v1_to_v2());
+        router
+    }
+
+    #[test]
+    fn upgrade_reseals_an_intact_v1_page() {
+        let old = v1_page();
+        let upgraded = router()
+            .migrate(FormatKind::Page, 1, 2, &old, &NoopOptionProvider)
+            .unwrap();
+        let page = PageBlockRef::new(&upgraded);
+        assert_eq!(page.header_version().unwrap(), 2);
+        page.verify_checksum().unwrap();
+        page.validate_layout().unwrap();
+        assert_eq!(page.record_bytes(0).unwrap(), b"payload");
+
+        let rolled_back = router()
+            .migrate(FormatKind::Page, 2, 1, &upgraded, &NoopOptionProvider)
+            .unwrap();
+        assert_eq!(rolled_back, old);
+    }
+
+    #[test]
+    fn upgrade_keeps_a_corrupt_v1_page_detectable() {
+        let mut old = v1_page();
+        old[PAGE_HEADER_SIZE] ^= 0x1;
+        let upgraded = router()
+            .migrate(FormatKind::Page, 1, 2, &old, &NoopOptionProvider)
+            .unwrap();
+        let err = PageBlockRef::new(&upgraded).verify_checksum().unwrap_err();
+        assert_eq!(err.ec(), ErrorCode::PageChecksumMismatch);
     }
 }
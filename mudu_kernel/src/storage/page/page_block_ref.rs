@@ -22,28 +22,43 @@ use std::borrow::Cow;
 
 /// Default database page size in bytes.
 ///
-/// This is the compile-time default used when no explicit page size is supplied
-/// and is kept as a constant because many internal helpers currently rely on
-/// `PAGE_SIZE` in const contexts (e.g. `[u8; PAGE_SIZE]`).
-///
-/// The page size is a runtime configuration exposed through
-/// `ServerCfg::page_size()` and `MuduDBCfg::page_size`. The value chosen at
-/// database creation time is persisted and must not be changed afterward unless
-/// an offline migration tool rewrites every page file.
+/// Relation page files take their page size from the database metadata
+/// (`storage::page_size`), which records the value chosen at database creation
+/// time. Directories created before the page size was recorded use this size.
+/// Changing it afterwards requires the offline page-size migration.
 ///
 /// Valid page sizes are powers of two and must be at least `DEFAULT_PAGE_SIZE`.
 pub const DEFAULT_PAGE_SIZE: usize = 4096;
 
-/// Backwards-compatible alias for code that has not yet been converted to use
-/// the runtime page size from `ServerCfg::page_size()`.
+/// Fixed page size for on-disk structures that do not follow the database
+/// page size, such as the disk B-tree index files, and for const contexts
+/// (e.g. `[u8; PAGE_SIZE]`).
 ///
-/// New code should prefer the runtime configuration when available. This alias
-/// remains for const contexts and for components that still assume the default
-/// page size.
+/// Page views derive their size from the buffer they wrap, so relation pages
+/// of any valid size go through the same code.
 pub const PAGE_SIZE: usize = DEFAULT_PAGE_SIZE;
 
 pub const RECORD_ALIGN: usize = 8;
 
+/// Checks that `page_size` is usable as a page size: a power of two no smaller
+/// than [`DEFAULT_PAGE_SIZE`].
+pub fn check_page_size(page_size: usize) -> RS<()> {
+    if !is_valid_page_size(page_size) {
+        return Err(mudu_error!(
+            ErrorCode::InvalidArgument,
+            format!(
+                "page size must be a power of two and at least {} bytes, got {}",
+                DEFAULT_PAGE_SIZE, page_size
+            )
+        ));
+    }
+    Ok(())
+}
+
+pub(crate) fn is_valid_page_size(page_size: usize) -> bool {
+    page_size >= DEFAULT_PAGE_SIZE && page_size.is_power_of_two()
+}
+
 pub(crate) fn align_up(value: usize, align: usize) -> usize {
     debug_assert!(align.is_power_of_two());
     (value + (align - 1)) & !(align - 1)
@@ -76,6 +91,22 @@ fn ensure_latest_page(page: &[u8]) -> RS<Cow<'_, [u8]>> {
         .map_err(|e| e.into_mudu_error())
 }
 
+/// Verifies a page read from disk and returns it in the current format version.
+///
+/// Older pages are migrated first, so the returned buffer can be handed to
+/// [`PageBlockRefMut`](crate::storage::page::page_block_ref_mut::PageBlockRefMut)
+/// and written back in the current format. A page whose tailer checksum does
+/// not match its content fails with [`ErrorCode::PageChecksumMismatch`].
+pub fn load_page(page: Vec<u8>) -> RS<Vec<u8>> {
+    let upgraded = match ensure_latest_page(&page)? {
+        Cow::Borrowed(_) => None,
+        Cow::Owned(upgraded) => Some(upgraded),
+    };
+    let page = upgraded.unwrap_or(page);
+    PageBlockRef::new(&page).verify_checksum()?;
+    Ok(page)
+}
+
 /// `PageBlock` exposes a slotted-page view with the following physical layout:
 /// `header / data / slot array / tailer`.
 ///
@@ -118,6 +149,11 @@ impl<'a> PageBlockRef<'a> {
         self.page.as_ref()
     }
 
+    /// Page size of the wrapped buffer; the whole buffer is one page.
+    pub fn page_size(&self) -> usize {
+        self.page.len()
+    }
+
     pub fn header(&self) -> RS<PageHeader> {
         self.check_page_len()?;
         PageHeader::decode(&self.page[..PAGE_HEADER_SIZE])
@@ -324,6 +360,15 @@ impl<'a> PageBlockRef<'a> {
         Ok((next != NONE_PAGE_ID).then_some(next))
     }
 
+    /// Checks the tailer checksum without walking the slot array.
+    ///
+    /// This is the check run on every page read from disk; [`Self::validate_layout`]
+    /// additionally validates the slot array and every record payload.
+    pub fn verify_checksum(&self) -> RS<()> {
+        self.ensure_header_layout()?;
+        self.tailer()?.validate_checksum(self.page())
+    }
+
     pub fn validate_layout(&self) -> RS<()> {
         let header = self.header()?;
         if header.magic() != PAGE_HEADER_MAGIC {
@@ -420,17 +465,16 @@ impl<'a> PageBlockRef<'a> {
                 )
             ));
         }
-        tailer.validate_checksum(self.page.as_ref())?;
+        tailer.validate_checksum(self.page())?;
         Ok(())
     }
 
     fn check_page_len(&self) -> RS<()> {
-        if self.page.len() < PAGE_SIZE {
+        if !is_valid_page_size(self.page.len()) {
             return Err(mudu_error!(
                 ErrorCode::Decode,
                 format!(
-                    "page block requires {} bytes, got {}",
-                    PAGE_SIZE,
+                    "page block length {} is not a valid page size",
                     self.page.len()
                 )
             ));
@@ -461,7 +505,7 @@ impl<'a> PageBlockRef<'a> {
     }
 
     fn tailer_offset(&self) -> usize {
-        PAGE_SIZE - PAGE_TAILER_SIZE
+        self.page.len() - PAGE_TAILER_SIZE
     }
 
     fn slot_region_start_for_count(&self, count: usize) -> usize {
@@ -1,4 +1,6 @@
-use crate::storage::page::page_block_ref::{align_up, PageBlockRef, PAGE_SIZE, RECORD_ALIGN};
+use crate::storage::page::page_block_ref::{
+    align_up, is_valid_page_size, PageBlockRef, RECORD_ALIGN,
+};
 use crate::storage::page::page_header::{PageHeader, PAGE_HEADER_SIZE};
 use crate::storage::page::page_tailer::{PageTailer, PAGE_TAILER_SIZE};
 use crate::storage::page::record_slot::{RecordSlot, RECORD_SLOT_SIZE};
@@ -60,12 +62,7 @@ impl<'a> PageBlockRefMut<'a> {
         header.set_tuple_flags(tuple_flags);
         header.encode(&mut self.page[..PAGE_HEADER_SIZE])?;
 
-        let tailer_offset = self.tailer_offset();
-        let mut tailer = PageTailer::default();
-        tailer.set_lsn(LSN::new(1));
-        tailer.refresh_checksum(self.page)?;
-        tailer.encode(&mut self.page[tailer_offset..tailer_offset + PAGE_TAILER_SIZE])?;
-        Ok(())
+        PageTailer::new(LSN::new(1), 0).seal(self.page)
     }
 
     pub fn header(&self) -> RS<PageHeader> {
@@ -122,6 +119,15 @@ impl<'a> PageBlockRefMut<'a> {
         Ok(())
     }
 
+    pub fn set_page_flags(&mut self, flags: u64) -> RS<()> {
+        let mut header = self.header()?;
+        header.set_lsn(header.lsn().saturating_add(1));
+        header.set_flags(flags);
+        header.encode(&mut self.page[..PAGE_HEADER_SIZE])?;
+        self.refresh_tailer_checksum()?;
+        Ok(())
+    }
+
     pub fn insert_record(&mut self, timestamp: u64, tuple_id: u64, payload: &[u8]) -> RS<usize> {
         self.check_page_len()?;
 
@@ -206,6 +212,24 @@ impl<'a> PageBlockRefMut<'a> {
         Ok(new_index)
     }
 
+    /// Lays the records of `source` out in this page, which may have a
+    /// different page size.
+    ///
+    /// The source header (page id, links, flags, tuple metadata) is carried
+    /// over and every slot keeps its key and flags. Fails with
+    /// [`ErrorCode::InsufficientBufferSpace`] when the records do not fit.
+    pub fn copy_from_page(&mut self, source: &PageBlockRef<'_>) -> RS<()> {
+        self.check_page_len()?;
+        let lsn = source.header_lsn()?;
+        let entries = (0..source.slot_count()?)
+            .map(|idx| Ok((source.slot(idx)?, source.record_bytes(idx)?.to_vec())))
+            .collect::<RS<Vec<_>>>()?;
+        self.page.fill(0);
+        self.page[..PAGE_HEADER_SIZE].copy_from_slice(&source.page()[..PAGE_HEADER_SIZE]);
+        PageTailer::new(lsn, 0).seal(self.page)?;
+        self.rebuild_from_entries(entries)
+    }
+
     pub fn compact(&mut self) -> RS<()> {
         let slots = self.read_all_slots()?;
         self.compact_with_slots(slots)
@@ -304,12 +328,11 @@ impl<'a> PageBlockRefMut<'a> {
     }
 
     fn check_page_len(&self) -> RS<()> {
-        if self.page.len() < PAGE_SIZE {
+        if !is_valid_page_size(self.page.len()) {
             return Err(mudu_error!(
                 ErrorCode::Encode,
                 format!(
-                    "page block requires {} bytes, got {}",
-                    PAGE_SIZE,
+                    "page block length {} is not a valid page size",
                     self.page.len()
                 )
             ));
@@ -318,7 +341,7 @@ impl<'a> PageBlockRefMut<'a> {
     }
 
     fn tailer_offset(&self) -> usize {
-        PAGE_SIZE - PAGE_TAILER_SIZE
+        self.page.len() - PAGE_TAILER_SIZE
     }
 
     fn slot_region_start_for_count(&self, count: usize) -> usize {
@@ -334,9 +357,7 @@ impl<'a> PageBlockRefMut<'a> {
         let mut tailer =
             PageTailer::decode(&self.page[tailer_offset..tailer_offset + PAGE_TAILER_SIZE])?;
         tailer.set_lsn(self.header()?.lsn());
-        tailer.refresh_checksum(self.page)?;
-        tailer.encode(&mut self.page[tailer_offset..tailer_offset + PAGE_TAILER_SIZE])?;
-        Ok(())
+        tailer.seal(self.page)
     }
 
     fn find_insert_position_by_key(&self, new_slot: &RecordSlot) -> RS<usize> {
@@ -405,6 +426,7 @@ mod tests {
     use super::PageBlockRefMut;
     use crate::storage::page::page_block_ref::{PageBlockRef, PAGE_SIZE, RECORD_ALIGN};
     use crate::storage::page::PageId;
+    use mudu::error::ErrorCode;
 
     #[test]
     fn init_empty_page_sets_layout_boundaries() {
@@ -509,4 +531,56 @@ mod tests {
         let msg = format!("{err:?}");
         assert!(msg.contains("checksum mismatch"));
     }
+
+    #[test]
+    fn larger_page_places_tailer_at_its_end() {
+        let mut raw = vec![0u8; PAGE_SIZE * 4];
+        let mut page = PageBlockRefMut::new(&mut raw);
+        page.init_empty(PageId::new(2)).unwrap();
+        page.insert_record(10, 1, &[7u8; PAGE_SIZE]).unwrap();
+
+        let ro = PageBlockRef::try_new(&raw).unwrap();
+        ro.validate_layout().unwrap();
+        assert_eq!(ro.page_size(), PAGE_SIZE * 4);
+        assert_eq!(ro.record_bytes(0).unwrap(), &[7u8; PAGE_SIZE][..]);
+
+        let mut odd = vec![0u8; PAGE_SIZE + 512];
+        let err = PageBlockRefMut::new(&mut odd)
+            .init_empty(PageId::new(2))
+            .unwrap_err();
+        assert_eq!(err.ec(), ErrorCode::Encode);
+    }
+
+    #[test]
+    fn copy_from_page_keeps_header_and_records() {
+        let mut small = vec![0u8; PAGE_SIZE];
+        let mut page = PageBlockRefMut::new(&mut small);
+        page.init_empty_with_tuple_meta(PageId::new(4), 1, 99, 0)
+            .unwrap();
+        page.set_page_links(PageId::new(3), PageId::new(5)).unwrap();
+        page.insert_record(20, 2, b"second").unwrap();
+        page.insert_record(10, 1, b"first").unwrap();
+
+        let mut large = vec![0u8; PAGE_SIZE * 2];
+        PageBlockRefMut::new(&mut large)
+            .copy_from_page(&PageBlockRef::try_new(&small).unwrap())
+            .unwrap();
+        let ro = PageBlockRef::try_new(&large).unwrap();
+        ro.validate_layout().unwrap();
+        assert_eq!(ro.header_page_id().unwrap(), PageId::new(4));
+        assert_eq!(ro.header_prev_page().unwrap(), PageId::new(3));
+        assert_eq!(ro.header_next_page().unwrap(), PageId::new(5));
+        assert_eq!(ro.header_tuple_schema_hash().unwrap(), 99);
+        assert_eq!(ro.record_bytes(0).unwrap(), b"first");
+        assert_eq!(ro.record_bytes(1).unwrap(), b"second");
+
+        let mut full = vec![0u8; PAGE_SIZE * 2];
+        let mut page = PageBlockRefMut::new(&mut full);
+        page.init_empty(PageId::new(1)).unwrap();
+        page.insert_record(10, 1, &[1u8; PAGE_SIZE]).unwrap();
+        let err = PageBlockRefMut::new(&mut small)
+            .copy_from_page(&PageBlockRef::try_new(&full).unwrap())
+            .unwrap_err();
+        assert_eq!(err.ec(), ErrorCode::InsufficientBufferSpace);
+    }
 }
@@ -23,11 +23,7 @@ fn build_valid_page() -> Vec<u8> {
     header.set_free_bytes((slot_start - PAGE_HEADER_SIZE) as u32);
     header.encode(&mut page).unwrap();
 
-    let checksum = PageTailer::checksum_for_page(&page).unwrap();
-    let tailer = PageTailer::new(LSN::new(0), checksum);
-    tailer
-        .encode(&mut page[PAGE_SIZE - PAGE_TAILER_SIZE..])
-        .unwrap();
+    PageTailer::new(LSN::new(0), 0).seal(&mut page).unwrap();
 
     page
 }
@@ -108,11 +104,7 @@ fn validate_layout_rejects_free_bytes_mismatch() {
     header.set_free_bytes(0); // wrong
     header.encode(&mut page).unwrap();
 
-    let checksum = PageTailer::checksum_for_page(&page).unwrap();
-    let tailer = PageTailer::new(LSN::new(0), checksum);
-    tailer
-        .encode(&mut page[PAGE_SIZE - PAGE_TAILER_SIZE..])
-        .unwrap();
+    PageTailer::new(LSN::new(0), 0).seal(&mut page).unwrap();
 
     let block = PageBlockRef::try_new(&page).unwrap();
     assert!(block.validate_layout().is_err());
@@ -4,9 +4,11 @@
 //! The physical byte offset of a page is calculated as:
 //!
 //! ```text
-//! offset = page_id.as_u64() * PAGE_SIZE
+//! offset = page_id.as_u64() * page_size
 //! ```
 //!
+//! where `page_size` is the database page size recorded in its metadata.
+//!
 //! `PageId` is intentionally a strong newtype over `u64` rather than a plain
 //! alias, so page identifiers cannot be accidentally mixed with other numeric
 //! quantities such as byte offsets, LSNs, or tuple counts.
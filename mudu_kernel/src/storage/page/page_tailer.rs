@@ -6,6 +6,8 @@ use mudu::error::ErrorCode;
 use mudu::mudu_error;
 
 pub const PAGE_TAILER_SIZE: usize = 12;
+/// Size of the CRC32 stored in the last bytes of the tailer.
+pub const PAGE_CHECKSUM_SIZE: usize = 4;
 
 #[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
 pub struct PageTailer {
@@ -53,8 +55,9 @@ impl PageTailer {
         Ok(())
     }
 
-    /// Computes the page CRC32 over the full page except for the trailing tailer.
-    /// The covered range is `[0, page.len() - PAGE_TAILER_SIZE)`.
+    /// Computes the page CRC32 over the full page except for the checksum field.
+    /// The covered range is `[0, page.len() - PAGE_CHECKSUM_SIZE)`, so the
+    /// tailer LSN is protected together with the header and the data area.
     pub fn checksum_for_page(page: &[u8]) -> RS<u32> {
         if page.len() < PAGE_TAILER_SIZE {
             return Err(mudu_error!(
@@ -67,19 +70,33 @@ impl PageTailer {
             ));
         }
 
-        Ok(crc32(&page[..page.len() - PAGE_TAILER_SIZE]))
+        Ok(crc32(&page[..page.len() - PAGE_CHECKSUM_SIZE]))
     }
 
-    pub fn refresh_checksum(&mut self, page: &[u8]) -> RS<()> {
+    /// Writes this tailer at the end of `page` and stores the checksum of the
+    /// resulting page in it.
+    pub fn seal(&mut self, page: &mut [u8]) -> RS<()> {
+        if page.len() < PAGE_TAILER_SIZE {
+            return Err(mudu_error!(
+                ErrorCode::Encode,
+                format!(
+                    "page tailer seal requires at least {} bytes, got {}",
+                    PAGE_TAILER_SIZE,
+                    page.len()
+                )
+            ));
+        }
+        let tailer_offset = page.len() - PAGE_TAILER_SIZE;
+        self.encode(&mut page[tailer_offset..])?;
         self.checksum = Self::checksum_for_page(page)?;
-        Ok(())
+        self.encode(&mut page[tailer_offset..])
     }
 
     pub fn validate_checksum(&self, page: &[u8]) -> RS<()> {
         let actual = Self::checksum_for_page(page)?;
         if self.checksum != actual {
             return Err(mudu_error!(
-                ErrorCode::Decode,
+                ErrorCode::PageChecksumMismatch,
                 format!(
                     "page checksum mismatch: stored={}, actual={}",
                     self.checksum, actual
@@ -118,6 +135,7 @@ mod tests {
 
     use super::{PageTailer, PAGE_TAILER_SIZE};
     use crate::wal::lsn::LSN;
+    use mudu::error::ErrorCode;
 
     #[test]
     fn page_tailer_encodes_to_fixed_12_bytes() {
@@ -137,13 +155,29 @@ mod tests {
     }
 
     #[test]
-    fn page_tailer_checksum_covers_page_except_tailer() {
+    fn page_tailer_checksum_covers_page_except_checksum_field() {
         let mut page = [0u8; 36];
-        page[..28].copy_from_slice(&[1u8; 28]);
-        page[28..].copy_from_slice(&[9u8; 8]);
+        page[..24].copy_from_slice(&[1u8; 24]);
+        page[24..].copy_from_slice(&[9u8; 12]);
 
         let checksum = PageTailer::checksum_for_page(&page).unwrap();
-        page[28..].copy_from_slice(&[7u8; 8]);
+        page[32..].copy_from_slice(&[7u8; 4]);
         assert_eq!(checksum, PageTailer::checksum_for_page(&page).unwrap());
+        page[24] = 7;
+        assert_ne!(checksum, PageTailer::checksum_for_page(&page).unwrap());
+    }
+
+    #[test]
+    fn sealed_tailer_validates_until_the_page_changes() {
+        let mut page = [3u8; 64];
+        let mut tailer = PageTailer::new(LSN::new(5), 0);
+        tailer.seal(&mut page).unwrap();
+        let decoded = PageTailer::decode(&page[64 - PAGE_TAILER_SIZE..]).unwrap();
+        assert_eq!(decoded, tailer);
+        decoded.validate_checksum(&page).unwrap();
+
+        page[64 - PAGE_TAILER_SIZE] ^= 0x1;
+        let err = decoded.validate_checksum(&page).unwrap_err();
+        assert_eq!(err.ec(), ErrorCode::PageChecksumMismatch);
     }
 }
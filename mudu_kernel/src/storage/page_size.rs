@@ -0,0 +1,169 @@
+//! Page size recorded for a data directory.
+//!
+//! The page size of relation page files is chosen when a data directory is
+//! created and recorded in its [`PAGE_SIZE_META_FILE`]. A directory that has
+//! no such file predates the record and uses [`DEFAULT_PAGE_SIZE`]. Opening a
+//! directory with a different configured page size fails with
+//! [`ErrorCode::PageSizeMismatch`]; the offline page-size migration
+//! (`storage::page_size_migration`) rewrites the page files instead.
+
+use std::io::Write;
+use std::path::Path;
+
+use mudu::common::result::RS;
+use mudu::error::ErrorCode;
+use mudu::mudu_error;
+use serde::{Deserialize, Serialize};
+
+use crate::storage::page::page_block_ref::{check_page_size, DEFAULT_PAGE_SIZE};
+
+/// Name of the page size record in a data directory.
+pub const PAGE_SIZE_META_FILE: &str = "page_size.meta";
+
+#[derive(Debug, Serialize, Deserialize)]
+struct PageSizeMeta {
+    page_size: u64,
+}
+
+/// Returns the page size recorded for `data_dir`, or [`DEFAULT_PAGE_SIZE`]
+/// when none is recorded.
+pub fn page_size_of(data_dir: &Path) -> RS<usize> {
+    let path = data_dir.join(PAGE_SIZE_META_FILE);
+    if !mudu_sys::fs::sync::path_exists(&path) {
+        return Ok(DEFAULT_PAGE_SIZE);
+    }
+    let bytes = mudu_sys::fs::sync::read(&path)?;
+    let meta = rmp_serde::from_slice::<PageSizeMeta>(&bytes).map_err(|e| {
+        mudu_error!(
+            ErrorCode::Decode,
+            format!("decode page size file {} error", path.display()),
+            e
+        )
+    })?;
+    let page_size = meta.page_size as usize;
+    check_page_size(page_size).map_err(|e| {
+        mudu_error!(
+            ErrorCode::CorruptedData,
+            format!("{} records an invalid page size", path.display()),
+            e
+        )
+    })?;
+    Ok(page_size)
+}
+
+/// Records `page_size` for `data_dir`, replacing the previous record.
+pub fn write_page_size(data_dir: &Path, page_size: usize) -> RS<()> {
+    check_page_size(page_size)?;
+    let bytes = rmp_serde::to_vec(&PageSizeMeta {
+        page_size: page_size as u64,
+    })
+    .map_err(|e| mudu_error!(ErrorCode::Encode, "encode page size file error", e))?;
+    mudu_sys::fs::sync::create_dir_all(data_dir)?;
+    write_synced(&data_dir.join(PAGE_SIZE_META_FILE), &bytes)
+}
+
+/// Replaces the content of `path` with `bytes` and syncs it.
+pub(crate) fn write_synced(path: &Path, bytes: &[u8]) -> RS<()> {
+    let mut file = mudu_sys::fs::sync::SOpenOptions::new()
+        .create(true)
+        .write(true)
+        .truncate(true)
+        .open(path)?;
+    file.write_all(bytes).map_err(|e| {
+        mudu_error!(
+            ErrorCode::Io,
+            format!("write file {} error", path.display()),
+            e
+        )
+    })?;
+    file.sync_all()
+}
+
+/// Checks the configured `page_size` against the one recorded for
+/// `data_dir`, and records it when the directory has no record yet.
+pub fn check_data_dir_page_size(data_dir: &Path, page_size: usize) -> RS<()> {
+    check_page_size(page_size)?;
+    let recorded = mudu_sys::fs::sync::path_exists(data_dir.join(PAGE_SIZE_META_FILE));
+    if !recorded && !has_pages(data_dir)? {
+        return write_page_size(data_dir, page_size);
+    }
+    let current = page_size_of(data_dir)?;
+    if current != page_size {
+        return Err(mudu_error!(
+            ErrorCode::PageSizeMismatch,
+            format!(
+                "{} uses {}-byte pages, but page_size is {}; \
+                 run `mudud migrate-page-size` to change it",
+                data_dir.display(),
+                current,
+                page_size
+            )
+        ));
+    }
+    if !recorded {
+        write_page_size(data_dir, page_size)?;
+    }
+    Ok(())
+}
+
+/// Whether `data_dir` already holds relation page files.
+fn has_pages(data_dir: &Path) -> RS<bool> {
+    let relation_dir = data_dir.join("relation");
+    if !mudu_sys::fs::sync::path_exists(&relation_dir) {
+        return Ok(false);
+    }
+    Ok(!mudu_sys::fs::sync::read_dir(&relation_dir)?.is_empty())
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(
+        clippy::unwrap_used,
+        clippy::expect_used,
+        clippy::panic,
+        clippy::todo,
+        clippy::unimplemented
+    )]
+
+    use super::*;
+    use project_root::get_project_root;
+    use std::path::PathBuf;
+
+    fn test_dir() -> PathBuf {
+        let dir = get_project_root()
+            .unwrap()
+            .join("target")
+            .join("tmp")
+            .join(format!("page_size_{}", mudu_sys::random::uuid_v4()));
+        mudu_sys::fs::sync::create_dir_all(&dir).unwrap();
+        dir
+    }
+
+    #[test]
+    fn new_directory_records_the_configured_page_size() {
+        let dir = test_dir();
+        assert_eq!(page_size_of(&dir).unwrap(), DEFAULT_PAGE_SIZE);
+        check_data_dir_page_size(&dir, 16384).unwrap();
+        assert_eq!(page_size_of(&dir).unwrap(), 16384);
+        check_data_dir_page_size(&dir, 16384).unwrap();
+
+        let err = check_data_dir_page_size(&dir, 8192).unwrap_err();
+        assert_eq!(err.ec(), ErrorCode::PageSizeMismatch);
+        mudu_sys::fs::sync::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn unrecorded_directory_with_pages_uses_the_default() {
+        let dir = test_dir();
+        mudu_sys::fs::sync::create_dir_all(dir.join("relation")).unwrap();
+        mudu_sys::fs::sync::write(dir.join("relation").join("1.2.0.dat"), b"").unwrap();
+
+        let err = check_data_dir_page_size(&dir, 8192).unwrap_err();
+        assert_eq!(err.ec(), ErrorCode::PageSizeMismatch);
+        check_data_dir_page_size(&dir, DEFAULT_PAGE_SIZE).unwrap();
+        assert!(mudu_sys::fs::sync::path_exists(
+            dir.join(PAGE_SIZE_META_FILE)
+        ));
+        mudu_sys::fs::sync::remove_dir_all(&dir).unwrap();
+    }
+}
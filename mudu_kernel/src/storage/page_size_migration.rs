@@ -0,0 +1,592 @@
+//! Offline page-size migration of a data directory.
+//!
+//! [`migrate_page_size`] moves the relation page files of a stopped server
+//! from the page size recorded for the directory to a new one:
+//!
+//! 1. The relation files, their PL streams (`relation_wal`) and the page size
+//!    record are copied into a backup directory, which is marked complete
+//!    last.
+//! 2. Every relation file replays its PL stream at the old page size, so all
+//!    logged page images are in the file.
+//! 3. Every page is laid out again at the same page id in a page of the new
+//!    size and sealed again with the keyring of the options, if any. A page whose records
+//!    do not fit a smaller page fails the migration.
+//! 4. The PL streams, which hold page images of the old size, and the disk
+//!    index builds, which are stamped with their LSNs, are removed; relations
+//!    rebuild their index from the files on the next start.
+//! 5. The new page size is recorded and the directory is verified with
+//!    [`verify_data_dir`].
+//!
+//! A failure after the backup puts the original files back. A dry run only
+//! checks that every page on disk fits the new size and writes nothing; pages
+//! still in a PL stream are checked by the real run.
+
+use std::fmt::{Display, Formatter};
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+
+use mudu::common::id::OID;
+use mudu::common::result::RS;
+use mudu::error::{ErrorCode, ResultExt};
+use mudu::mudu_error;
+use serde::{Deserialize, Serialize};
+
+use crate::storage::encryption::{open_stored_page, seal_page_image, Keyring, Sealer};
+use crate::storage::page::page_block_ref::{check_page_size, load_page, PageBlockRef};
+use crate::storage::page::page_block_ref_mut::PageBlockRefMut;
+use crate::storage::page_size::{page_size_of, write_page_size, write_synced, PAGE_SIZE_META_FILE};
+use crate::storage::time_series::time_series_file::{TimeSeriesFile, TimeSeriesFileIdentity};
+use crate::storage::verify::{verify_data_dir, VerifyReport};
+
+/// Written into the backup directory after every original file was copied;
+/// [`rollback_page_size_migration`] only restores a backup that has it.
+pub const PAGE_SIZE_BACKUP_MARKER: &str = "page_size_migration.backup";
+
+const RELATION_DIR: &str = "relation";
+const RELATION_WAL_DIR: &str = "relation_wal";
+
+#[derive(Clone, Debug, Default)]
+pub struct PageSizeMigrationOptions {
+    /// Checks that every page fits the new page size without writing
+    /// anything.
+    pub dry_run: bool,
+    /// Where the original files are copied; [`default_backup_dir`] when
+    /// `None`. It must be empty or missing.
+    pub backup_dir: Option<PathBuf>,
+    /// Opens sealed pages and seals the migrated ones; required when the
+    /// directory is encrypted.
+    pub keyring: Option<Arc<Keyring>>,
+}
+
+#[derive(Clone, Debug)]
+pub struct PageSizeMigrationReport {
+    pub from_page_size: usize,
+    pub to_page_size: usize,
+    pub dry_run: bool,
+    pub page_files: u64,
+    pub pages: u64,
+    /// Copy of the original files; `None` for a dry run.
+    pub backup_dir: Option<PathBuf>,
+    /// Verification of the migrated directory; `None` for a dry run.
+    pub verify: Option<VerifyReport>,
+}
+
+impl Display for PageSizeMigrationReport {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        if let Some(verify) = &self.verify {
+            writeln!(f, "{}", verify)?;
+        }
+        if let Some(backup_dir) = &self.backup_dir {
+            writeln!(f, "original files backed up in {}", backup_dir.display())?;
+        }
+        write!(
+            f,
+            "{} {} pages in {} files from {} to {} bytes",
+            if self.dry_run {
+                "dry run: checked"
+            } else {
+                "migrated"
+            },
+            self.pages,
+            self.page_files,
+            self.from_page_size,
+            self.to_page_size
+        )
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+struct BackupMarker {
+    from_page_size: usize,
+    to_page_size: usize,
+}
+
+/// Backup directory used when none is given: a sibling of `data_dir` named
+/// `<data_dir>.page_size_backup`.
+pub fn default_backup_dir(data_dir: &Path) -> PathBuf {
+    let data_dir = std::path::absolute(data_dir).unwrap_or_else(|_| data_dir.to_path_buf());
+    let mut name = data_dir.file_name().unwrap_or_default().to_os_string();
+    name.push(".page_size_backup");
+    data_dir.with_file_name(name)
+}
+
+/// Rewrites the relation page files of `data_dir` with `page_size`-byte
+/// pages. No server may own `data_dir`.
+///
+/// Fails with [`ErrorCode::CorruptedData`] when the migrated directory does
+/// not verify clean; the original files are back in place then.
+pub fn migrate_page_size(
+    data_dir: &Path,
+    page_size: usize,
+    options: &PageSizeMigrationOptions,
+) -> RS<PageSizeMigrationReport> {
+    crate::compat::install_compatibility_router();
+    check_page_size(page_size)?;
+    let from_page_size = page_size_of(data_dir)?;
+    if from_page_size == page_size {
+        return Err(mudu_error!(
+            ErrorCode::InvalidArgument,
+            format!(
+                "{} already uses {}-byte pages",
+                data_dir.display(),
+                page_size
+            )
+        ));
+    }
+    let mut report = PageSizeMigrationReport {
+        from_page_size,
+        to_page_size: page_size,
+        dry_run: options.dry_run,
+        page_files: 0,
+        pages: 0,
+        backup_dir: None,
+        verify: None,
+    };
+    if options.dry_run {
+        let sealer = Sealer::for_keyring(options.keyring.as_ref())?;
+        for path in relation_page_files(data_dir)? {
+            let bytes = mudu_sys::fs::sync::read(&path)?;
+            let pages = resize_pages(&path, &bytes, from_page_size, page_size, sealer.as_ref())?;
+            report.page_files += 1;
+            report.pages += (pages.len() / page_size) as u64;
+        }
+        return Ok(report);
+    }
+
+    let backup_dir = options
+        .backup_dir
+        .clone()
+        .unwrap_or_else(|| default_backup_dir(data_dir));
+    back_up_originals(data_dir, &backup_dir, from_page_size, page_size)?;
+    report.backup_dir = Some(backup_dir.clone());
+    let result = rewrite_data_dir(
+        data_dir,
+        from_page_size,
+        page_size,
+        options.keyring.as_ref(),
+        &mut report,
+    );
+    let failure = match result {
+        Ok(verify) if verify.is_clean() => {
+            report.verify = Some(verify);
+            return Ok(report);
+        }
+        Ok(verify) => mudu_error!(
+            ErrorCode::CorruptedData,
+            format!(
+                "the migrated data directory does not verify clean: {}",
+                verify
+            )
+        ),
+        Err(e) => e,
+    };
+    rollback_page_size_migration(data_dir, &backup_dir).map_err(|rollback| {
+        mudu_error!(
+            rollback.ec(),
+            format!(
+                "page size migration failed ({}), and rolling back from {} failed",
+                failure,
+                backup_dir.display()
+            ),
+            rollback
+        )
+    })?;
+    Err(failure)
+}
+
+/// Puts the files copied by [`migrate_page_size`] into `backup_dir` back into
+/// `data_dir`, undoing the migration. No server may own `data_dir`.
+pub fn rollback_page_size_migration(data_dir: &Path, backup_dir: &Path) -> RS<()> {
+    let marker_path = backup_dir.join(PAGE_SIZE_BACKUP_MARKER);
+    if !mudu_sys::fs::sync::path_exists(&marker_path) {
+        return Err(mudu_error!(
+            ErrorCode::NotFound,
+            format!(
+                "{} holds no complete page size migration backup",
+                backup_dir.display()
+            )
+        ));
+    }
+    for name in [RELATION_DIR, RELATION_WAL_DIR] {
+        let target = data_dir.join(name);
+        if mudu_sys::fs::sync::path_exists(&target) {
+            mudu_sys::fs::sync::remove_dir_all(&target)?;
+        }
+        let source = backup_dir.join(name);
+        if mudu_sys::fs::sync::path_exists(&source) {
+            copy_dir(&source, &target)?;
+        }
+    }
+    let meta = backup_dir.join(PAGE_SIZE_META_FILE);
+    if mudu_sys::fs::sync::path_exists(&meta) {
+        copy_file(&meta, &data_dir.join(PAGE_SIZE_META_FILE))
+    } else if mudu_sys::fs::sync::path_exists(data_dir.join(PAGE_SIZE_META_FILE)) {
+        mudu_sys::fs::sync::remove_file(data_dir.join(PAGE_SIZE_META_FILE))
+    } else {
+        Ok(())
+    }
+}
+
+fn back_up_originals(
+    data_dir: &Path,
+    backup_dir: &Path,
+    from_page_size: usize,
+    to_page_size: usize,
+) -> RS<()> {
+    if mudu_sys::fs::sync::path_exists(backup_dir)
+        && !mudu_sys::fs::sync::read_dir_entries(backup_dir)?.is_empty()
+    {
+        return Err(mudu_error!(
+            ErrorCode::EntityAlreadyExists,
+            format!("backup directory {} is not empty", backup_dir.display())
+        ));
+    }
+    mudu_sys::fs::sync::create_dir_all(backup_dir)?;
+    for name in [RELATION_DIR, RELATION_WAL_DIR] {
+        let source = data_dir.join(name);
+        if mudu_sys::fs::sync::path_exists(&source) {
+            copy_dir(&source, &backup_dir.join(name))?;
+        }
+    }
+    let meta = data_dir.join(PAGE_SIZE_META_FILE);
+    if mudu_sys::fs::sync::path_exists(&meta) {
+        copy_file(&meta, &backup_dir.join(PAGE_SIZE_META_FILE))?;
+    }
+    let marker = serde_json::to_vec_pretty(&BackupMarker {
+        from_page_size,
+        to_page_size,
+    })
+    .map_err(|e| mudu_error!(ErrorCode::Encode, "encode page size backup marker error", e))?;
+    write_synced(&backup_dir.join(PAGE_SIZE_BACKUP_MARKER), &marker)
+}
+
+fn rewrite_data_dir(
+    data_dir: &Path,
+    from_page_size: usize,
+    to_page_size: usize,
+    keyring: Option<&Arc<Keyring>>,
+    report: &mut PageSizeMigrationReport,
+) -> RS<VerifyReport> {
+    let files = relation_page_files(data_dir)?;
+    for path in &files {
+        recover_relation_file(data_dir, path, keyring)?;
+    }
+    let sealer = Sealer::for_keyring(keyring)?;
+    // Recovery may have created files that were only in a PL stream.
+    for path in relation_page_files(data_dir)? {
+        let bytes = mudu_sys::fs::sync::read(&path)?;
+        let pages = resize_pages(&path, &bytes, from_page_size, to_page_size, sealer.as_ref())?;
+        write_synced(&path, &pages)?;
+        report.page_files += 1;
+        report.pages += (pages.len() / to_page_size) as u64;
+    }
+    let relation_wal = data_dir.join(RELATION_WAL_DIR);
+    if mudu_sys::fs::sync::path_exists(&relation_wal) {
+        mudu_sys::fs::sync::remove_dir_all(&relation_wal)?;
+    }
+    for path in mudu_sys::fs::sync::read_dir(data_dir.join(RELATION_DIR))? {
+        if is_index_slot(&path) {
+            mudu_sys::fs::sync::remove_file(&path)?;
+        }
+    }
+    write_page_size(data_dir, to_page_size)?;
+    verify_data_dir(data_dir, keyring.map(Arc::as_ref))
+}
+
+/// Lays every `from_page_size`-byte page of `bytes` out in a
+/// `to_page_size`-byte page at the same page id.
+fn resize_pages(
+    path: &Path,
+    bytes: &[u8],
+    from_page_size: usize,
+    to_page_size: usize,
+    sealer: Option<&Sealer>,
+) -> RS<Vec<u8>> {
+    if !bytes.len().is_multiple_of(from_page_size) {
+        return Err(mudu_error!(
+            ErrorCode::CorruptedData,
+            format!(
+                "{} length {} is not aligned to page size {}",
+                path.display(),
+                bytes.len(),
+                from_page_size
+            )
+        ));
+    }
+    let mut resized = Vec::with_capacity(bytes.len() / from_page_size * to_page_size);
+    for (page_id, chunk) in bytes.chunks_exact(from_page_size).enumerate() {
+        let context = || format!("page {} of {}", page_id, path.display());
+        let mut page = load_page(chunk.to_vec()).with_context(context)?;
+        open_stored_page(sealer.map(Sealer::keyring), &mut page).with_context(context)?;
+        let mut target = vec![0u8; to_page_size];
+        PageBlockRefMut::new(&mut target)
+            .copy_from_page(&PageBlockRef::new(&page))
+            .with_context(|| format!("{} in a {}-byte page", context(), to_page_size))?;
+        resized.extend_from_slice(&seal_page_image(sealer, &target)?);
+    }
+    Ok(resized)
+}
+
+/// Replays the PL stream of the relation file at `path` into the file.
+fn recover_relation_file(data_dir: &Path, path: &Path, keyring: Option<&Arc<Keyring>>) -> RS<()> {
+    let identity = relation_file_identity(path)?;
+    let data_dir = data_dir.to_path_buf();
+    let keyring = keyring.cloned();
+    mudu_sys::task::async_::block_on_tokio_current_thread(async move {
+        // A zero schema hash skips the tuple schema check of the pages.
+        let file =
+            TimeSeriesFile::open_relation_file_sync(&data_dir, identity, 0, false, keyring).await?;
+        file.close().await
+    })?
+}
+
+fn relation_file_identity(path: &Path) -> RS<TimeSeriesFileIdentity> {
+    let invalid = || {
+        mudu_error!(
+            ErrorCode::InvalidFilename,
+            format!("{} is not a relation file name", path.display())
+        )
+    };
+    let name = path
+        .file_name()
+        .and_then(|name| name.to_str())
+        .ok_or_else(invalid)?;
+    let parts: Vec<&str> = name.split('.').collect();
+    let [partition_id, table_id, file_index, "dat"] = parts.as_slice() else {
+        return Err(invalid());
+    };
+    Ok(TimeSeriesFileIdentity {
+        partition_id: partition_id.parse::<OID>().map_err(|_| invalid())?,
+        table_id: table_id.parse::<OID>().map_err(|_| invalid())?,
+        file_index: file_index.parse::<u32>().map_err(|_| invalid())?,
+    })
+}
+
+fn relation_page_files(data_dir: &Path) -> RS<Vec<PathBuf>> {
+    let relation_dir = data_dir.join(RELATION_DIR);
+    if !mudu_sys::fs::sync::path_exists(&relation_dir) {
+        return Ok(Vec::new());
+    }
+    let mut paths: Vec<PathBuf> = mudu_sys::fs::sync::read_dir(&relation_dir)?
+        .into_iter()
+        .filter(|path| path.extension().is_some_and(|ext| ext == "dat"))
+        .collect();
+    paths.sort();
+    Ok(paths)
+}
+
+fn is_index_slot(path: &Path) -> bool {
+    path.file_name()
+        .and_then(|name| name.to_str())
+        .and_then(|name| name.rsplit_once('.'))
+        .is_some_and(|(stem, slot)| stem.ends_with(".bti") && slot.parse::<u64>().is_ok())
+}
+
+fn copy_dir(source: &Path, target: &Path) -> RS<()> {
+    mudu_sys::fs::sync::create_dir_all(target)?;
+    for entry in mudu_sys::fs::sync::read_dir_entries(source)? {
+        let path = entry.path();
+        let target = target.join(entry.file_name());
+        if entry.file_type()?.is_dir() {
+            copy_dir(&path, &target)?;
+        } else {
+            copy_file(&path, &target)?;
+        }
+    }
+    Ok(())
+}
+
+fn copy_file(source: &Path, target: &Path) -> RS<()> {
+    let bytes = mudu_sys::fs::sync::read(source)?;
+    write_synced(target, &bytes)
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(
+        clippy::unwrap_used,
+        clippy::expect_used,
+        clippy::panic,
+        clippy::todo,
+        clippy::unimplemented
+    )]
+
+    use super::*;
+    use crate::storage::page::page_block_ref::{DEFAULT_PAGE_SIZE, PAGE_SIZE};
+    use project_root::get_project_root;
+
+    fn test_dir() -> PathBuf {
+        let dir = get_project_root()
+            .unwrap()
+            .join("target")
+            .join("tmp")
+            .join(format!(
+                "page_size_migration_{}",
+                mudu_sys::random::uuid_v4()
+            ));
+        mudu_sys::fs::sync::create_dir_all(&dir).unwrap();
+        dir
+    }
+
+    fn identity() -> TimeSeriesFileIdentity {
+        TimeSeriesFileIdentity {
+            partition_id: 7,
+            table_id: 9,
+            file_index: 1,
+        }
+    }
+
+    fn write_rows(data_dir: &Path, rows: &[(u64, Vec<u8>)]) {
+        let data_dir = data_dir.to_path_buf();
+        let rows = rows.to_vec();
+        mudu_sys::task::async_::block_on_tokio_current_thread(async move {
+            let mut file =
+                TimeSeriesFile::open_relation_file_sync(&data_dir, identity(), 0, true, None)
+                    .await
+                    .unwrap();
+            for (timestamp, payload) in &rows {
+                file.insert(*timestamp, *timestamp, payload).await.unwrap();
+            }
+            file.close().await.unwrap();
+        })
+        .unwrap();
+    }
+
+    fn read_rows(data_dir: &Path, timestamps: &[u64]) -> Vec<Vec<u8>> {
+        let data_dir = data_dir.to_path_buf();
+        let timestamps = timestamps.to_vec();
+        mudu_sys::task::async_::block_on_tokio_current_thread(async move {
+            let file =
+                TimeSeriesFile::open_relation_file_sync(&data_dir, identity(), 0, false, None)
+                    .await
+                    .unwrap();
+            let mut rows = Vec::new();
+            for timestamp in timestamps {
+                rows.push(
+                    file.get(timestamp, timestamp)
+                        .await
+                        .unwrap()
+                        .unwrap()
+                        .payload,
+                );
+            }
+            file.close().await.unwrap();
+            rows
+        })
+        .unwrap()
+    }
+
+    fn relation_file(data_dir: &Path) -> PathBuf {
+        TimeSeriesFile::relation_file_path(data_dir, 7, 9, 1)
+    }
+
+    #[test]
+    fn migrates_relation_pages_and_rolls_back() {
+        let dir = test_dir();
+        let data_dir = dir.join("data");
+        let rows: Vec<(u64, Vec<u8>)> = (1..=12u64).map(|ts| (ts, vec![ts as u8; 900])).collect();
+        write_rows(&data_dir, &rows);
+        let original = mudu_sys::fs::sync::read(relation_file(&data_dir)).unwrap();
+        let timestamps: Vec<u64> = rows.iter().map(|(ts, _)| *ts).collect();
+
+        let dry_run = migrate_page_size(
+            &data_dir,
+            PAGE_SIZE * 2,
+            &PageSizeMigrationOptions {
+                dry_run: true,
+                backup_dir: None,
+                keyring: None,
+            },
+        )
+        .unwrap();
+        assert_eq!(dry_run.page_files, 1);
+        assert_eq!(dry_run.pages, (original.len() / PAGE_SIZE) as u64);
+        assert_eq!(
+            mudu_sys::fs::sync::read(relation_file(&data_dir)).unwrap(),
+            original
+        );
+        assert_eq!(page_size_of(&data_dir).unwrap(), DEFAULT_PAGE_SIZE);
+
+        let backup_dir = dir.join("backup");
+        let report = migrate_page_size(
+            &data_dir,
+            PAGE_SIZE * 2,
+            &PageSizeMigrationOptions {
+                dry_run: false,
+                backup_dir: Some(backup_dir.clone()),
+                keyring: None,
+            },
+        )
+        .unwrap();
+        assert!(report.verify.as_ref().unwrap().is_clean());
+        assert_eq!(page_size_of(&data_dir).unwrap(), PAGE_SIZE * 2);
+        let migrated = mudu_sys::fs::sync::read(relation_file(&data_dir)).unwrap();
+        assert_eq!(migrated.len(), original.len() * 2);
+        assert!(!mudu_sys::fs::sync::path_exists(
+            data_dir.join(RELATION_WAL_DIR)
+        ));
+        let expected: Vec<Vec<u8>> = rows.iter().map(|(_, payload)| payload.clone()).collect();
+        assert_eq!(read_rows(&data_dir, &timestamps), expected);
+
+        rollback_page_size_migration(&data_dir, &backup_dir).unwrap();
+        assert_eq!(page_size_of(&data_dir).unwrap(), DEFAULT_PAGE_SIZE);
+        assert_eq!(
+            mudu_sys::fs::sync::read(relation_file(&data_dir)).unwrap(),
+            original
+        );
+        assert_eq!(read_rows(&data_dir, &timestamps), expected);
+        mudu_sys::fs::sync::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn a_page_that_does_not_fit_fails_without_changes() {
+        let dir = test_dir();
+        let data_dir = dir.join("data");
+        write_page_size(&data_dir, PAGE_SIZE * 2).unwrap();
+        write_rows(&data_dir, &[(1, vec![1u8; PAGE_SIZE + 100])]);
+        let original = mudu_sys::fs::sync::read(relation_file(&data_dir)).unwrap();
+
+        let err = migrate_page_size(
+            &data_dir,
+            PAGE_SIZE,
+            &PageSizeMigrationOptions {
+                dry_run: true,
+                backup_dir: None,
+                keyring: None,
+            },
+        )
+        .unwrap_err();
+        assert_eq!(err.ec(), ErrorCode::InsufficientBufferSpace);
+
+        let backup_dir = dir.join("backup");
+        let err = migrate_page_size(
+            &data_dir,
+            PAGE_SIZE,
+            &PageSizeMigrationOptions {
+                dry_run: false,
+                backup_dir: Some(backup_dir.clone()),
+                keyring: None,
+            },
+        )
+        .unwrap_err();
+        assert_eq!(err.ec(), ErrorCode::InsufficientBufferSpace);
+        assert_eq!(page_size_of(&data_dir).unwrap(), PAGE_SIZE * 2);
+        assert_eq!(
+            mudu_sys::fs::sync::read(relation_file(&data_dir)).unwrap(),
+            original
+        );
+
+        let err = migrate_page_size(
+            &data_dir,
+            PAGE_SIZE * 4,
+            &PageSizeMigrationOptions {
+                dry_run: false,
+                backup_dir: Some(backup_dir),
+                keyring: None,
+            },
+        )
+        .unwrap_err();
+        assert_eq!(err.ec(), ErrorCode::EntityAlreadyExists);
+        mudu_sys::fs::sync::remove_dir_all(&dir).unwrap();
+    }
+}
@@ -0,0 +1,64 @@
+use mudu::common::id::{TupleID, OID};
+use mudu::common::result::RS;
+use mudu::error::ErrorCode;
+use mudu::mudu_error;
+use serde::{Deserialize, Serialize};
+use std::path::{Path, PathBuf};
+
+/// Identifies the relation files a disk index build was taken from: the PL
+/// stream LSNs of both files when the build started.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub(crate) struct DiskIndexStamp {
+    pub key_file_lsn: u64,
+    pub value_file_lsn: u64,
+    pub next_tuple_id: TupleID,
+}
+
+/// Value of a relation row in the disk index.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub(crate) struct DiskIndexRow {
+    pub tuple_id: TupleID,
+    /// `(xid, deleted)` of each version, oldest first.
+    pub versions: Vec<(u64, bool)>,
+}
+
+pub(crate) fn disk_index_path<P: AsRef<Path>>(
+    base_path: P,
+    partition_id: OID,
+    table_id: OID,
+) -> PathBuf {
+    let mut path_buf = base_path.as_ref().to_path_buf();
+    path_buf.push("relation");
+    path_buf.push(format!("{partition_id}.{table_id}.bti"));
+    path_buf
+}
+
+impl DiskIndexStamp {
+    pub(crate) fn encode(&self) -> RS<Vec<u8>> {
+        encode(self)
+    }
+
+    pub(crate) fn decode(bytes: &[u8]) -> RS<Self> {
+        decode(bytes)
+    }
+}
+
+impl DiskIndexRow {
+    pub(crate) fn encode(&self) -> RS<Vec<u8>> {
+        encode(self)
+    }
+
+    pub(crate) fn decode(bytes: &[u8]) -> RS<Self> {
+        decode(bytes)
+    }
+}
+
+fn encode<T: Serialize>(value: &T) -> RS<Vec<u8>> {
+    rmp_serde::to_vec(value)
+        .map_err(|e| mudu_error!(ErrorCode::Encode, "encode disk index value error", e))
+}
+
+fn decode<T: for<'de> Deserialize<'de>>(bytes: &[u8]) -> RS<T> {
+    rmp_serde::from_slice(bytes)
+        .map_err(|e| mudu_error!(ErrorCode::Decode, "decode disk index value error", e))
+}
@@ -1,6 +1,9 @@
 use mudu_sys::sync::async_::futures_mutex::FMutex;
 use std::cell::{Cell, UnsafeCell};
+use std::cmp::Ordering;
+use std::collections::HashSet;
 use std::ops::Bound;
+use std::path::{Path, PathBuf};
 use std::sync::Arc;
 
 use mudu::common::id::{TupleID, OID};
@@ -8,9 +11,10 @@ use mudu::common::result::RS;
 use mudu::error::ErrorCode;
 use mudu::mudu_error;
 use mudu_contract::tuple::comparator::TupleComparator;
+use mudu_contract::tuple::tuple_binary_desc::TupleBinaryDesc;
 use mudu_sys::contract::async_fs::AsyncFs;
 use mudu_sys::contract::async_io_provider::AsyncIoProvider;
-use mudu_sys::SysIoContext;
+use mudu_sys::{default_sys_io_context, SysIoContext};
 
 use crate::contract::data_row::DataRow;
 use crate::contract::snapshot::Snapshot;
@@ -18,9 +22,17 @@ use crate::contract::table_desc::TableDesc;
 use crate::contract::timestamp::Timestamp;
 use crate::contract::version_tuple::VersionTuple;
 use crate::index::btree::btree_index::BTreeIndex;
+use crate::index::disk_btree::disk_btree_builder::DiskBTreeBuilder;
+use crate::index::disk_btree::disk_btree_index::{slot_paths, DiskBTreeIndex};
 use crate::index::index_key::compare_context::CompareContext;
 use crate::index::index_key::key_tuple::KeyTuple;
+use crate::server::version_gc::VersionGcStats;
 use crate::server::worker_snapshot::WorkerSnapshot;
+use crate::storage::backup::{copy_into_backup, BackupFile};
+use crate::storage::buffer_pool::BufferPool;
+use crate::storage::compression::{Compression, CompressionStats};
+use crate::storage::encryption::{Keyring, ReencryptStats};
+use crate::storage::relation::disk_index::{disk_index_path, DiskIndexRow, DiskIndexStamp};
 use crate::storage::time_series::time_series_file::{TimeSeriesFile, TimeSeriesFileIdentity};
 use mudu_utils::scoped_task_trace;
 use tracing::trace;
@@ -46,10 +58,23 @@ unsafe impl Sync for Relation {}
 struct RelationInner {
     _table_id: OID,
     _partition_id: OID,
+    // Rows written since the last disk index build, and rows pruned by GC.
+    // Rows only in the disk index are read from it on demand.
     index: UnsafeCell<BTreeIndex<DataRow>>,
+    // Keys of the disk index whose rows GC removed since the build.
+    removed_keys: UnsafeCell<HashSet<Vec<u8>>>,
+    disk_index: UnsafeCell<Option<DiskBTreeIndex>>,
+    // Generation of the newest disk index build found or written.
+    disk_index_generation: Cell<u64>,
+    disk_index_path: PathBuf,
+    key_desc: TupleBinaryDesc,
     key_file: UnsafeCell<TimeSeriesFile>,
     value_file: UnsafeCell<TimeSeriesFile>,
     next_tuple_id: Cell<TupleID>,
+    fs: Arc<dyn AsyncFs>,
+    // Seals the relation files and disk index builds; `None` stores them in
+    // plaintext.
+    keyring: Option<Arc<Keyring>>,
 }
 
 unsafe impl Send for RelationInner {}
@@ -61,11 +86,12 @@ impl Relation {
         partition_id: OID,
         path: String,
         table_desc: &TableDesc,
+        keyring: Option<Arc<Keyring>>,
     ) -> RS<Self> {
         scoped_task_trace!();
         Ok(Self {
             access_lock: FMutex::new(()),
-            inner: RelationInner::new(table_id, partition_id, path, table_desc).await?,
+            inner: RelationInner::new(table_id, partition_id, path, table_desc, keyring).await?,
         })
     }
 
@@ -75,11 +101,20 @@ impl Relation {
         partition_id: OID,
         path: String,
         table_desc: &TableDesc,
+        keyring: Option<Arc<Keyring>>,
     ) -> RS<Self> {
         scoped_task_trace!();
         Ok(Self {
             access_lock: FMutex::new(()),
-            inner: RelationInner::new_with_fs(fs, table_id, partition_id, path, table_desc).await?,
+            inner: RelationInner::new_with_fs(
+                fs,
+                table_id,
+                partition_id,
+                path,
+                table_desc,
+                keyring,
+            )
+            .await?,
         })
     }
 
@@ -89,8 +124,17 @@ impl Relation {
         partition_id: OID,
         path: String,
         table_desc: &TableDesc,
+        keyring: Option<Arc<Keyring>>,
     ) -> RS<Self> {
-        Self::new_with_provider(sys.provider_arc(), table_id, partition_id, path, table_desc).await
+        Self::new_with_provider(
+            sys.provider_arc(),
+            table_id,
+            partition_id,
+            path,
+            table_desc,
+            keyring,
+        )
+        .await
     }
 
     pub async fn new_with_provider(
@@ -99,6 +143,7 @@ impl Relation {
         partition_id: OID,
         path: String,
         table_desc: &TableDesc,
+        keyring: Option<Arc<Keyring>>,
     ) -> RS<Self> {
         scoped_task_trace!();
         Ok(Self {
@@ -109,6 +154,7 @@ impl Relation {
                 partition_id,
                 path,
                 table_desc,
+                keyring,
             )
             .await?,
         })
@@ -172,6 +218,86 @@ impl Relation {
         drop(guard);
         result
     }
+
+    /// Prunes versions below `horizon` for at most `max_rows` rows whose key
+    /// sorts after `after`.
+    ///
+    /// Returns the last key visited when more rows remain, so the caller can
+    /// resume from there in a later step.
+    pub async fn collect_garbage(
+        &self,
+        horizon: u64,
+        after: Option<&[u8]>,
+        max_rows: usize,
+        stats: &mut VersionGcStats,
+    ) -> RS<Option<Vec<u8>>> {
+        scoped_task_trace!();
+        let guard = self.access_lock.lock().await;
+        let result = self
+            .inner
+            .collect_garbage(horizon, after, max_rows, stats)
+            .await;
+        drop(guard);
+        result
+    }
+
+    /// Caches the pages of the relation files in `buffer_pool`.
+    pub async fn set_buffer_pool(&self, buffer_pool: Arc<BufferPool>) -> RS<()> {
+        let guard = self.access_lock.lock().await;
+        let result = self.inner.set_buffer_pool(buffer_pool).await;
+        drop(guard);
+        result
+    }
+
+    /// Compresses the pages written to the relation files from now on.
+    pub async fn set_compression(
+        &self,
+        compression: Compression,
+        stats: Arc<CompressionStats>,
+    ) -> RS<()> {
+        let guard = self.access_lock.lock().await;
+        self.inner.set_compression(compression, stats);
+        drop(guard);
+        Ok(())
+    }
+
+    /// Syncs the key and value files to disk.
+    pub async fn sync_files(&self) -> RS<()> {
+        let guard = self.access_lock.lock().await;
+        let result = self.inner.sync_files().await;
+        drop(guard);
+        result
+    }
+
+    /// Rewrites up to `max_pages` pages of the relation files that are not
+    /// sealed with the active encryption key, key file first.
+    pub async fn reencrypt_pages(&self, max_pages: u64) -> RS<ReencryptStats> {
+        let guard = self.access_lock.lock().await;
+        let result = self.inner.reencrypt_pages(max_pages).await;
+        drop(guard);
+        result
+    }
+
+    /// Syncs the relation files and copies them, their PL streams and the
+    /// disk index builds into `backup_dir`, at their paths relative to
+    /// `root`. The relation takes no writes meanwhile, so the copies match.
+    pub async fn backup_files(&self, root: &Path, backup_dir: &Path) -> RS<Vec<BackupFile>> {
+        let guard = self.access_lock.lock().await;
+        let result = self.inner.backup_files(root, backup_dir).await;
+        drop(guard);
+        result
+    }
+
+    /// Syncs the relation files and writes the next build of the disk index
+    /// next to them, which the next open uses instead of scanning the key
+    /// file.
+    pub async fn write_disk_index(&self) -> RS<()> {
+        scoped_task_trace!();
+        let guard = self.access_lock.lock().await;
+        let result = self.inner.write_disk_index().await;
+        drop(guard);
+        result
+    }
 }
 
 #[cfg(test)]
@@ -191,6 +317,7 @@ impl RelationInner {
         partition_id: OID,
         path: String,
         table_desc: &TableDesc,
+        keyring: Option<Arc<Keyring>>,
     ) -> RS<Self> {
         scoped_task_trace!();
         let key_identity = TimeSeriesFileIdentity {
@@ -209,24 +336,37 @@ impl RelationInner {
         let relation = Self {
             _table_id: table_id,
             _partition_id: partition_id,
-            index: UnsafeCell::new(BTreeIndex::new(CompareContext {
-                result: Ok(()),
-                comparator: TupleComparator::new(),
-                desc: table_desc.key_desc().clone(),
-            })),
+            index: UnsafeCell::new(BTreeIndex::new(compare_context(table_desc.key_desc()))),
+            removed_keys: UnsafeCell::new(HashSet::new()),
+            disk_index: UnsafeCell::new(None),
+            disk_index_generation: Cell::new(0),
+            disk_index_path: disk_index_path(&path, partition_id, table_id),
+            key_desc: table_desc.key_desc().clone(),
             key_file: UnsafeCell::new(
-                TimeSeriesFile::open_relation_file(&path, key_identity, key_schema_hash, true)
-                    .await?,
+                TimeSeriesFile::open_relation_file(
+                    &path,
+                    key_identity,
+                    key_schema_hash,
+                    true,
+                    keyring.clone(),
+                )
+                .await?,
             ),
             value_file: UnsafeCell::new(
-                TimeSeriesFile::open_relation_file(&path, value_identity, value_schema_hash, true)
-                    .await?,
+                TimeSeriesFile::open_relation_file(
+                    &path,
+                    value_identity,
+                    value_schema_hash,
+                    true,
+                    keyring.clone(),
+                )
+                .await?,
             ),
             next_tuple_id: Cell::new(1),
+            fs: default_sys_io_context().fs(),
+            keyring,
         };
-        relation.rebuild_from_files_async().await.map_err(|e| {
-            mudu_error!(ErrorCode::Storage, "rebuild relation from files failed", e)
-        })?;
+        relation.load_index_async().await?;
         Ok(relation)
     }
 
@@ -236,8 +376,10 @@ impl RelationInner {
         partition_id: OID,
         path: String,
         table_desc: &TableDesc,
+        keyring: Option<Arc<Keyring>>,
     ) -> RS<Self> {
-        Self::new_with_provider_inner(fs, None, table_id, partition_id, path, table_desc).await
+        Self::new_with_provider_inner(fs, None, table_id, partition_id, path, table_desc, keyring)
+            .await
     }
 
     async fn new_with_provider(
@@ -246,6 +388,7 @@ impl RelationInner {
         partition_id: OID,
         path: String,
         table_desc: &TableDesc,
+        keyring: Option<Arc<Keyring>>,
     ) -> RS<Self> {
         Self::new_with_provider_inner(
             provider.fs_arc(),
@@ -254,6 +397,7 @@ impl RelationInner {
             partition_id,
             path,
             table_desc,
+            keyring,
         )
         .await
     }
@@ -265,6 +409,7 @@ impl RelationInner {
         partition_id: OID,
         path: String,
         table_desc: &TableDesc,
+        keyring: Option<Arc<Keyring>>,
     ) -> RS<Self> {
         scoped_task_trace!();
         trace!(table_id, partition_id, path = %path, "relation new_with_fs start");
@@ -284,11 +429,12 @@ impl RelationInner {
         let relation = Self {
             _table_id: table_id,
             _partition_id: partition_id,
-            index: UnsafeCell::new(BTreeIndex::new(CompareContext {
-                result: Ok(()),
-                comparator: TupleComparator::new(),
-                desc: table_desc.key_desc().clone(),
-            })),
+            index: UnsafeCell::new(BTreeIndex::new(compare_context(table_desc.key_desc()))),
+            removed_keys: UnsafeCell::new(HashSet::new()),
+            disk_index: UnsafeCell::new(None),
+            disk_index_generation: Cell::new(0),
+            disk_index_path: disk_index_path(&path, partition_id, table_id),
+            key_desc: table_desc.key_desc().clone(),
             key_file: UnsafeCell::new({
                 trace!(
                     table_id,
@@ -304,6 +450,7 @@ impl RelationInner {
                             key_identity,
                             key_schema_hash,
                             true,
+                            keyring.clone(),
                         )
                         .await?
                     }
@@ -314,6 +461,7 @@ impl RelationInner {
                             key_identity,
                             key_schema_hash,
                             true,
+                            keyring.clone(),
                         )
                         .await?
                     }
@@ -334,6 +482,7 @@ impl RelationInner {
                             value_identity,
                             value_schema_hash,
                             true,
+                            keyring.clone(),
                         )
                         .await?
                     }
@@ -344,25 +493,210 @@ impl RelationInner {
                             value_identity,
                             value_schema_hash,
                             true,
+                            keyring.clone(),
                         )
                         .await?
                     }
                 }
             }),
             next_tuple_id: Cell::new(1),
+            fs,
+            keyring,
         };
         trace!(
             table_id,
             partition_id,
-            "relation files opened, rebuilding from files"
+            "relation files opened, loading index"
         );
-        relation.rebuild_from_files_async().await.map_err(|e| {
-            mudu_error!(ErrorCode::Storage, "rebuild relation from files failed", e)
-        })?;
+        relation.load_index_async().await?;
         trace!(table_id, partition_id, "relation new_with_fs done");
         Ok(relation)
     }
 
+    /// Opens the disk index of the last checkpoint when it was built from the
+    /// current relation files, and rebuilds the in-memory index from the key
+    /// file otherwise.
+    ///
+    /// Opening the disk index reads only its meta page, so the relation comes
+    /// online without touching its rows.
+    async fn load_index_async(&self) -> RS<()> {
+        if let Some(disk_index) = DiskBTreeIndex::open_latest(
+            self.fs.as_ref(),
+            &self.disk_index_path,
+            compare_context(&self.key_desc),
+            self.keyring.clone(),
+        )
+        .await?
+        {
+            self.disk_index_generation.set(disk_index.meta().generation);
+            let stamp = DiskIndexStamp::decode(&disk_index.meta().stamp).ok();
+            if let Some(stamp) =
+                stamp.filter(|stamp| self.disk_index_matches(stamp).unwrap_or(false))
+            {
+                self.next_tuple_id.set(stamp.next_tuple_id);
+                *self.disk_index_mut() = Some(disk_index);
+                return Ok(());
+            }
+        }
+        self.rebuild_from_files_async()
+            .await
+            .map_err(|e| mudu_error!(ErrorCode::Storage, "rebuild relation from files failed", e))
+    }
+
+    fn disk_index_matches(&self, stamp: &DiskIndexStamp) -> RS<bool> {
+        Ok(
+            self.key_file().wal_lsn()?.map(|lsn| lsn.as_u64()) == Some(stamp.key_file_lsn)
+                && self.value_file().wal_lsn()?.map(|lsn| lsn.as_u64())
+                    == Some(stamp.value_file_lsn),
+        )
+    }
+
+    async fn set_buffer_pool(&self, buffer_pool: Arc<BufferPool>) -> RS<()> {
+        self.key_file_mut()
+            .set_buffer_pool(buffer_pool.clone())
+            .await?;
+        self.value_file_mut().set_buffer_pool(buffer_pool).await
+    }
+
+    fn set_compression(&self, compression: Compression, stats: Arc<CompressionStats>) {
+        self.key_file_mut()
+            .set_compression(compression, stats.clone());
+        self.value_file_mut().set_compression(compression, stats);
+    }
+
+    async fn sync_files(&self) -> RS<()> {
+        self.key_file().flush().await?;
+        self.value_file().flush().await
+    }
+
+    async fn reencrypt_pages(&self, max_pages: u64) -> RS<ReencryptStats> {
+        let mut stats = self.key_file_mut().reencrypt_pages(max_pages).await?;
+        let rest = max_pages - stats.scanned_pages;
+        if rest == 0 {
+            stats.done = false;
+            return Ok(stats);
+        }
+        let value_stats = self.value_file_mut().reencrypt_pages(rest).await?;
+        stats.add(value_stats);
+        stats.done = value_stats.done;
+        Ok(stats)
+    }
+
+    async fn backup_files(&self, root: &Path, backup_dir: &Path) -> RS<Vec<BackupFile>> {
+        self.sync_files().await?;
+        let mut paths = vec![
+            self.key_file().path().to_path_buf(),
+            self.value_file().path().to_path_buf(),
+        ];
+        paths.extend(self.key_file().wal_chunk_paths().await?);
+        paths.extend(self.value_file().wal_chunk_paths().await?);
+        for slot_path in slot_paths(&self.disk_index_path) {
+            if self.fs.path_exists(&slot_path).await? {
+                paths.push(slot_path);
+            }
+        }
+        let mut files = Vec::with_capacity(paths.len());
+        for path in paths {
+            files.push(copy_into_backup(self.fs.as_ref(), root, &path, backup_dir).await?);
+        }
+        Ok(files)
+    }
+
+    /// Builds the next generation of the disk index from the previous build
+    /// and the resident rows, then drops the resident rows: from here on they
+    /// are read from the new build.
+    async fn write_disk_index(&self) -> RS<()> {
+        self.sync_files().await?;
+        // The stamps are read after the sync: the files hold everything the
+        // PL streams logged up to them.
+        let (Some(key_file_lsn), Some(value_file_lsn)) =
+            (self.key_file().wal_lsn()?, self.value_file().wal_lsn()?)
+        else {
+            return Ok(());
+        };
+        let generation = self.disk_index_generation.get() + 1;
+        let mut builder = DiskBTreeBuilder::create(
+            self.fs.as_ref(),
+            &self.disk_index_path,
+            generation,
+            self.keyring.as_ref(),
+        )
+        .await?;
+        let resident = self
+            .index()
+            .range((Bound::Unbounded, Bound::Unbounded))?
+            .into_iter()
+            .map(|(key, row)| (key.clone(), row.clone()))
+            .collect::<Vec<_>>();
+        let mut resident = resident.into_iter().peekable();
+        let mut disk_cursor = match self.disk_index() {
+            Some(disk_index) => Some(disk_index.seek(Bound::Unbounded).await?),
+            None => None,
+        };
+        let mut disk_entry = match disk_cursor.as_mut() {
+            Some(cursor) => cursor.next().await?,
+            None => None,
+        };
+        loop {
+            // Resident rows replace their disk entries.
+            let order = match (resident.peek(), &disk_entry) {
+                (None, None) => break,
+                (Some(_), None) => Ordering::Less,
+                (None, Some(_)) => Ordering::Greater,
+                (Some((key, _)), Some((disk_key, _))) => {
+                    self.compare_keys(key.as_slice(), disk_key)?
+                }
+            };
+            if order.is_le() {
+                if let Some((key, row)) = resident.next() {
+                    builder
+                        .push(key.as_slice(), &encode_disk_row(&row)?)
+                        .await?;
+                }
+            } else if let Some((key, value)) = &disk_entry {
+                if !self.removed_keys().contains(key) {
+                    builder.push(key, value).await?;
+                }
+            }
+            if order.is_ge() {
+                disk_entry = match disk_cursor.as_mut() {
+                    Some(cursor) => cursor.next().await?,
+                    None => None,
+                };
+            }
+        }
+        drop(disk_cursor);
+        builder
+            .finish(
+                DiskIndexStamp {
+                    key_file_lsn: key_file_lsn.as_u64(),
+                    value_file_lsn: value_file_lsn.as_u64(),
+                    next_tuple_id: self.next_tuple_id.get(),
+                }
+                .encode()?,
+            )
+            .await?;
+        self.disk_index_generation.set(generation);
+
+        let disk_index = DiskBTreeIndex::open_latest(
+            self.fs.as_ref(),
+            &self.disk_index_path,
+            compare_context(&self.key_desc),
+            self.keyring.clone(),
+        )
+        .await?
+        .filter(|disk_index| disk_index.meta().generation == generation)
+        .ok_or_else(|| {
+            mudu_error!(
+                ErrorCode::Storage,
+                format!("disk index build {generation} is missing after it was written")
+            )
+        })?;
+        *self.disk_index_mut() = Some(disk_index);
+        self.removed_keys_mut().clear();
+        self.index_mut().clear()
+    }
+
     async fn rebuild_from_files_async(&self) -> RS<()> {
         let rows = self.key_file().scan_range(0, u64::MAX).await?;
         let mut max_tuple_id = 0;
@@ -418,7 +752,7 @@ impl RelationInner {
         snapshot: &WorkerSnapshot,
     ) -> RS<Option<(OID, VersionTuple)>> {
         scoped_task_trace!();
-        let row = match self.index().get(key)? {
+        let row = match self.lookup_row(key).await? {
             Some(row) => row,
             None => return Ok(None),
         };
@@ -427,7 +761,7 @@ impl RelationInner {
             .await?
             .ok_or_else(|| mudu_error!(ErrorCode::Internal, "missing tuple id"))?;
         let snapshot = snapshot.to_snapshot();
-        let visible = read_visible_version_async(row, &snapshot).await;
+        let visible = read_visible_version_async(&row, &snapshot).await;
         Ok(visible
             .filter(|version| !version.is_deleted())
             .map(|version| (tuple_id, version)))
@@ -452,17 +786,13 @@ impl RelationInner {
         bounds: (Bound<&[u8]>, Bound<&[u8]>),
         snapshot: &WorkerSnapshot,
     ) -> RS<Vec<(Vec<u8>, Vec<u8>)>> {
-        let begin_key = bounds.0.as_ref().map(|key| KeyTuple::from(key.to_vec()));
-        let end_key = bounds.1.as_ref().map(|key| KeyTuple::from(key.to_vec()));
-        let rows = self
-            .index()
-            .range((bound_key_ref(&begin_key), bound_key_ref(&end_key)))?;
+        let rows = self.rows_in_range(bounds, usize::MAX).await?;
 
         let snapshot = snapshot.to_snapshot();
         let mut items = Vec::new();
-        for (_key, row) in rows {
+        for (_key, row, _resident) in rows {
             if let Some(pair) =
-                visible_payloads_async(self.key_file(), self.value_file(), row, &snapshot).await?
+                visible_payloads_async(self.key_file(), self.value_file(), &row, &snapshot).await?
             {
                 items.push(pair);
             }
@@ -471,8 +801,8 @@ impl RelationInner {
     }
 
     async fn has_write_conflict(&self, key: &KeyTuple, snapshot: &WorkerSnapshot) -> RS<bool> {
-        let latest = match self.index().get(key)? {
-            Some(row) => latest_version_async(row).await,
+        let latest = match self.lookup_row(key).await? {
+            Some(row) => latest_version_async(&row).await,
             None => None,
         };
         Ok(latest
@@ -483,7 +813,7 @@ impl RelationInner {
     async fn write_row(&self, key: Vec<u8>, value: Option<Vec<u8>>, xid: u64) -> RS<()> {
         scoped_task_trace!();
         let key_tuple = KeyTuple::from(key.clone());
-        let row = match self.index().get(&key_tuple)?.cloned() {
+        let row = match self.lookup_row(&key_tuple).await? {
             Some(row) => row,
             None => {
                 let tuple_id = self.alloc_tuple_id();
@@ -510,10 +840,144 @@ impl RelationInner {
             None => VersionTuple::new_delete(timestamp),
         };
         row.write(version, None).await?;
+        self.removed_keys_mut().remove(&key);
         let _ = self.index_mut().insert(key_tuple, row)?;
         Ok(())
     }
 
+    async fn collect_garbage(
+        &self,
+        horizon: u64,
+        after: Option<&[u8]>,
+        max_rows: usize,
+        stats: &mut VersionGcStats,
+    ) -> RS<Option<Vec<u8>>> {
+        let lower = match after {
+            Some(key) => Bound::Excluded(key),
+            None => Bound::Unbounded,
+        };
+        let rows = self
+            .rows_in_range((lower, Bound::Unbounded), max_rows.saturating_add(1))
+            .await?;
+        let has_more = rows.len() > max_rows;
+
+        let mut last_key = None;
+        for (key, row, resident) in rows.into_iter().take(max_rows) {
+            let tuple_id = row
+                .tuple_id()
+                .await?
+                .ok_or_else(|| mudu_error!(ErrorCode::Internal, "missing tuple id"))?;
+            let pruned = row.collect_garbage_sync(horizon)?;
+            for version in &pruned {
+                // Key records of tombstones go too, otherwise rebuilding the
+                // index from the key file would bring the row back.
+                self.key_file_mut()
+                    .delete(version.xid, tuple_id as u64)
+                    .await?;
+                if !version.deleted {
+                    self.value_file_mut()
+                        .delete(version.xid, tuple_id as u64)
+                        .await?;
+                }
+            }
+            stats.pruned_versions += pruned.len();
+            // The index entry is dropped only after all file records are gone,
+            // so the key is never rebuilt under two tuple ids.
+            if !pruned.is_empty() && row.is_empty_sync()? {
+                let _ = self.index_mut().remove(&key)?;
+                if self.disk_index().is_some() {
+                    self.removed_keys_mut().insert(key.as_slice().to_vec());
+                }
+                stats.removed_rows += 1;
+            } else if !pruned.is_empty() && !resident {
+                // The pruned row differs from its disk entry now.
+                let _ = self.index_mut().insert(key.clone(), row)?;
+            }
+            last_key = Some(key);
+        }
+        Ok(if has_more {
+            last_key.map(|key| key.as_slice().to_vec())
+        } else {
+            None
+        })
+    }
+
+    /// Returns the row of `key`, reading it from the disk index when it is
+    /// not resident. Rows read from the disk index are not kept resident.
+    async fn lookup_row(&self, key: &KeyTuple) -> RS<Option<DataRow>> {
+        if let Some(row) = self.index().get(key)? {
+            return Ok(Some(row.clone()));
+        }
+        let Some(disk_index) = self.disk_index() else {
+            return Ok(None);
+        };
+        if self.removed_keys().contains(key.as_slice()) {
+            return Ok(None);
+        }
+        match disk_index.get(key.as_slice()).await? {
+            Some(value) => decode_disk_row(&value).map(Some),
+            None => Ok(None),
+        }
+    }
+
+    /// Returns up to `limit` rows inside `bounds` in key order, merging the
+    /// resident rows with the disk index. The flag tells whether the row is
+    /// resident.
+    async fn rows_in_range(
+        &self,
+        bounds: (Bound<&[u8]>, Bound<&[u8]>),
+        limit: usize,
+    ) -> RS<Vec<(KeyTuple, DataRow, bool)>> {
+        let begin_key = bounds.0.map(|key| KeyTuple::from(key.to_vec()));
+        let end_key = bounds.1.map(|key| KeyTuple::from(key.to_vec()));
+        let resident: Vec<(KeyTuple, DataRow, bool)> = self
+            .index()
+            .range((bound_key_ref(&begin_key), bound_key_ref(&end_key)))?
+            .into_iter()
+            .take(limit)
+            .map(|(key, row)| (key.clone(), row.clone(), true))
+            .collect();
+        let Some(disk_index) = self.disk_index() else {
+            return Ok(resident);
+        };
+        // Removed keys are skipped below, so read that many more entries to
+        // still find `limit` rows.
+        let disk_limit = limit.saturating_add(self.removed_keys().len());
+        let mut disk = Vec::new();
+        for (key, value) in disk_index.range(bounds, disk_limit).await? {
+            let key = KeyTuple::from(key);
+            if self.removed_keys().contains(key.as_slice()) || self.index().contains_key(&key)? {
+                continue;
+            }
+            disk.push((key, decode_disk_row(&value)?, false));
+        }
+
+        let mut rows = Vec::with_capacity(resident.len() + disk.len());
+        let mut resident = resident.into_iter().peekable();
+        let mut disk = disk.into_iter().peekable();
+        while rows.len() < limit {
+            let take_resident = match (resident.peek(), disk.peek()) {
+                (None, None) => break,
+                (Some(_), None) => true,
+                (None, Some(_)) => false,
+                (Some((key, _, _)), Some((disk_key, _, _))) => self
+                    .compare_keys(key.as_slice(), disk_key.as_slice())?
+                    .is_lt(),
+            };
+            let next = if take_resident {
+                resident.next()
+            } else {
+                disk.next()
+            };
+            rows.extend(next);
+        }
+        Ok(rows)
+    }
+
+    fn compare_keys(&self, left: &[u8], right: &[u8]) -> RS<Ordering> {
+        (TupleComparator::new().compare)(left, right, &self.key_desc)
+    }
+
     fn alloc_tuple_id(&self) -> TupleID {
         let tuple_id = self.next_tuple_id.get();
         self.next_tuple_id.set(tuple_id + 1);
@@ -541,6 +1005,28 @@ impl RelationInner {
         unsafe { &mut *self.index.get() }
     }
 
+    fn disk_index(&self) -> Option<&DiskBTreeIndex> {
+        // Safety: Relation is expected to be accessed from a single worker thread.
+        unsafe { &*self.disk_index.get() }.as_ref()
+    }
+
+    #[allow(clippy::mut_from_ref)]
+    fn disk_index_mut(&self) -> &mut Option<DiskBTreeIndex> {
+        // Safety: Relation is expected to be accessed from a single worker thread.
+        unsafe { &mut *self.disk_index.get() }
+    }
+
+    fn removed_keys(&self) -> &HashSet<Vec<u8>> {
+        // Safety: Relation is expected to be accessed from a single worker thread.
+        unsafe { &*self.removed_keys.get() }
+    }
+
+    #[allow(clippy::mut_from_ref)]
+    fn removed_keys_mut(&self) -> &mut HashSet<Vec<u8>> {
+        // Safety: Relation is expected to be accessed from a single worker thread.
+        unsafe { &mut *self.removed_keys.get() }
+    }
+
     fn key_file(&self) -> &TimeSeriesFile {
         // Safety: Relation is expected to be accessed from a single worker thread.
         unsafe { &*self.key_file.get() }
@@ -564,6 +1050,44 @@ impl RelationInner {
     }
 }
 
+fn compare_context(key_desc: &TupleBinaryDesc) -> CompareContext {
+    CompareContext {
+        result: Ok(()),
+        comparator: TupleComparator::new(),
+        desc: key_desc.clone(),
+    }
+}
+
+fn encode_disk_row(row: &DataRow) -> RS<Vec<u8>> {
+    let tuple_id = row
+        .tuple_id_sync()?
+        .ok_or_else(|| mudu_error!(ErrorCode::Internal, "missing tuple id"))?;
+    DiskIndexRow {
+        tuple_id: tuple_id as TupleID,
+        versions: row
+            .versions_sync()?
+            .into_iter()
+            .map(|version| (version.xid, version.deleted))
+            .collect(),
+    }
+    .encode()
+}
+
+fn decode_disk_row(value: &[u8]) -> RS<DataRow> {
+    let disk_row = DiskIndexRow::decode(value)?;
+    let row = DataRow::new(disk_row.tuple_id);
+    for (xid, deleted) in disk_row.versions {
+        let timestamp = Timestamp::new(xid, u64::MAX);
+        let version = if deleted {
+            VersionTuple::new_delete(timestamp)
+        } else {
+            VersionTuple::new(timestamp, Vec::new())
+        };
+        row.write_sync(version, None)?;
+    }
+    Ok(row)
+}
+
 fn tuple_schema_hash(
     role: u8,
     desc: &mudu_contract::tuple::tuple_binary_desc::TupleBinaryDesc,
@@ -719,9 +1243,15 @@ mod tests {
             let partition_id = 7;
             let path = relation_path();
 
-            let relation = Relation::new(table_id, partition_id, path.clone(), table_desc.as_ref())
-                .await
-                .unwrap();
+            let relation = Relation::new(
+                table_id,
+                partition_id,
+                path.clone(),
+                table_desc.as_ref(),
+                None,
+            )
+            .await
+            .unwrap();
             relation
                 .write_value(i32_bytes(1), i32_bytes(11), 1)
                 .await
@@ -733,9 +1263,15 @@ mod tests {
                 .unwrap();
             drop(relation);
 
-            let reopened = Relation::new(table_id, partition_id, path.clone(), table_desc.as_ref())
-                .await
-                .unwrap();
+            let reopened = Relation::new(
+                table_id,
+                partition_id,
+                path.clone(),
+                table_desc.as_ref(),
+                None,
+            )
+            .await
+            .unwrap();
             assert_eq!(
                 reopened
                     .visible_value(
@@ -771,6 +1307,7 @@ mod tests {
                 .write_value(i32_bytes(3), i32_bytes(33), 4)
                 .await
                 .unwrap();
+            reopened.sync_files().await.unwrap();
             let key_file = TimeSeriesFile::open_ts_file_sync(
                 TimeSeriesFile::relation_file_path(&path, partition_id, table_id, 0),
                 false,
@@ -786,4 +1323,174 @@ mod tests {
         })
         .unwrap()
     }
+
+    #[test]
+    fn compressed_pages_stay_readable_after_reopen_without_a_codec() {
+        mudu_sys::task::async_::block_on_tokio_current_thread(async move {
+            let schema = test_schema();
+            let table_desc = TableInfo::new(schema.clone())
+                .unwrap()
+                .table_desc()
+                .unwrap();
+            let table_id = schema.id();
+            let partition_id = 7;
+            let path = relation_path();
+            let stats = Arc::new(CompressionStats::default());
+
+            let relation = Relation::new(
+                table_id,
+                partition_id,
+                path.clone(),
+                table_desc.as_ref(),
+                None,
+            )
+            .await
+            .unwrap();
+            relation
+                .set_compression(Compression::Zstd, stats.clone())
+                .await
+                .unwrap();
+            for key in 0..64 {
+                relation
+                    .write_value(i32_bytes(key), i32_bytes(key * 10), key as u64 + 1)
+                    .await
+                    .unwrap();
+            }
+            relation.sync_files().await.unwrap();
+            drop(relation);
+            assert!(stats.snapshot().raw_bytes > 0);
+
+            let reopened = Relation::new(
+                table_id,
+                partition_id,
+                path.clone(),
+                table_desc.as_ref(),
+                None,
+            )
+            .await
+            .unwrap();
+            for key in [0, 31, 63] {
+                assert_eq!(read(&reopened, key, 64).await, Some(i32_bytes(key * 10)));
+            }
+        })
+        .unwrap()
+    }
+
+    async fn read(relation: &Relation, key: i32, xid: u64) -> Option<Vec<u8>> {
+        relation
+            .visible_value(
+                &KeyTuple::from(i32_bytes(key)),
+                &WorkerSnapshot::new(xid, vec![]),
+            )
+            .await
+            .unwrap()
+    }
+
+    #[test]
+    fn reopens_from_disk_index_until_files_change() {
+        mudu_sys::task::async_::block_on_tokio_current_thread(async move {
+            let schema = test_schema();
+            let table_desc = TableInfo::new(schema.clone())
+                .unwrap()
+                .table_desc()
+                .unwrap();
+            let table_id = schema.id();
+            let partition_id = 7;
+            let path = relation_path();
+            let relation = Relation::new(
+                table_id,
+                partition_id,
+                path.clone(),
+                table_desc.as_ref(),
+                None,
+            )
+            .await
+            .unwrap();
+            relation
+                .write_value(i32_bytes(1), i32_bytes(11), 1)
+                .await
+                .unwrap();
+            relation.write_delete(i32_bytes(1), 2).await.unwrap();
+            relation
+                .write_value(i32_bytes(2), i32_bytes(22), 3)
+                .await
+                .unwrap();
+            relation.write_disk_index().await.unwrap();
+            // The build holds every row, so none stays resident.
+            assert!(relation.inner.index().is_empty().unwrap());
+            assert_eq!(read(&relation, 2, 3).await, Some(i32_bytes(22)));
+            drop(relation);
+
+            let reopened = Relation::new(
+                table_id,
+                partition_id,
+                path.clone(),
+                table_desc.as_ref(),
+                None,
+            )
+            .await
+            .unwrap();
+            assert!(reopened.inner.disk_index().is_some());
+            assert!(reopened.inner.index().is_empty().unwrap());
+            assert_eq!(read(&reopened, 1, 1).await, Some(i32_bytes(11)));
+            assert_eq!(read(&reopened, 1, 2).await, None);
+            assert_eq!(read(&reopened, 2, 3).await, Some(i32_bytes(22)));
+            // Reads do not make rows resident.
+            assert!(reopened.inner.index().is_empty().unwrap());
+
+            // Writes go to resident rows, which shadow the disk index until
+            // the next build.
+            reopened
+                .write_value(i32_bytes(3), i32_bytes(33), 4)
+                .await
+                .unwrap();
+            reopened
+                .write_value(i32_bytes(2), i32_bytes(23), 5)
+                .await
+                .unwrap();
+            let range = reopened
+                .visible_range(
+                    (Bound::Unbounded, Bound::Unbounded),
+                    &WorkerSnapshot::new(5, vec![]),
+                )
+                .await
+                .unwrap();
+            assert_eq!(
+                range,
+                vec![(i32_bytes(2), i32_bytes(23)), (i32_bytes(3), i32_bytes(33))]
+            );
+            assert_eq!(reopened.inner.index().len().unwrap(), 2);
+            reopened.write_disk_index().await.unwrap();
+            assert_eq!(reopened.inner.disk_index().unwrap().meta().generation, 2);
+            assert_eq!(reopened.inner.disk_index().unwrap().meta().entry_count, 3);
+            assert_eq!(read(&reopened, 2, 4).await, Some(i32_bytes(22)));
+            assert_eq!(read(&reopened, 2, 5).await, Some(i32_bytes(23)));
+
+            // A write after the build makes it stale, so the next open
+            // rebuilds the index from the key file.
+            reopened
+                .write_value(i32_bytes(4), i32_bytes(44), 6)
+                .await
+                .unwrap();
+            drop(reopened);
+            let rebuilt = Relation::new(
+                table_id,
+                partition_id,
+                path.clone(),
+                table_desc.as_ref(),
+                None,
+            )
+            .await
+            .unwrap();
+            assert!(rebuilt.inner.disk_index().is_none());
+            assert_eq!(read(&rebuilt, 3, 4).await, Some(i32_bytes(33)));
+            assert_eq!(read(&rebuilt, 4, 6).await, Some(i32_bytes(44)));
+            assert_eq!(rebuilt.inner.next_tuple_id.get(), 5);
+
+            // The next build still advances the generation past the stale one.
+            rebuilt.write_disk_index().await.unwrap();
+            assert_eq!(rebuilt.inner.disk_index().unwrap().meta().generation, 3);
+        })
+        .unwrap()
+    }
 }
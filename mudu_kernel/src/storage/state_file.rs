@@ -0,0 +1,106 @@
+//! Checksummed state files written by checkpoints.
+//!
+//! A state file holds one msgpack-encoded value behind a magic number and a
+//! checksum, so a torn write is detected instead of being loaded. When
+//! written with an encryption keyring, the value is sealed behind the
+//! `SEALED_PAYLOAD_MARKER` byte, which never starts a msgpack value.
+
+use crate::storage::encryption::{open_stored_blob, Keyring, Sealer};
+use mudu::common::crc::calc_crc;
+use mudu::common::result::RS;
+use mudu::error::ErrorCode;
+use mudu::mudu_error;
+use mudu_sys::contract::async_fs::AsyncFs;
+use mudu_sys::contract::file_options::FileOptions;
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+use std::borrow::Cow;
+use std::path::Path;
+use std::sync::Arc;
+
+const STATE_FILE_HEADER_SIZE: usize = 12;
+const SEALED_PAYLOAD_MARKER: u8 = 0xC1;
+
+/// Writes `value` to `path`, replacing the previous content, and syncs it.
+/// The value is sealed with `keyring`'s active key, if any.
+pub(crate) async fn write_state_file<T: Serialize>(
+    fs: &dyn AsyncFs,
+    path: &Path,
+    magic: u32,
+    value: &T,
+    keyring: Option<&Arc<Keyring>>,
+) -> RS<()> {
+    let payload = rmp_serde::to_vec(value)
+        .map_err(|e| mudu_error!(ErrorCode::Encode, "encode state file error", e))?;
+    let payload = match Sealer::for_keyring(keyring)? {
+        Some(sealer) => {
+            let mut sealed = vec![SEALED_PAYLOAD_MARKER];
+            sealed.extend(sealer.seal(&magic.to_be_bytes(), &payload)?);
+            sealed
+        }
+        None => payload,
+    };
+    let mut bytes = Vec::with_capacity(STATE_FILE_HEADER_SIZE + payload.len());
+    bytes.extend_from_slice(&magic.to_be_bytes());
+    bytes.extend_from_slice(&calc_crc(&payload).to_be_bytes());
+    bytes.extend_from_slice(&payload);
+    if let Some(parent) = path.parent() {
+        fs.create_dir_all(parent).await?;
+    }
+    let file = fs
+        .open(
+            path,
+            FileOptions::new(
+                libc::O_CREAT | libc::O_RDWR | libc::O_TRUNC | libc::O_CLOEXEC,
+                0o644,
+            ),
+        )
+        .await?;
+    file.write_all_at(0, &bytes).await?;
+    file.fsync().await
+}
+
+/// Reads a value written by [`write_state_file`], opening a sealed one with
+/// `keyring`.
+pub(crate) async fn read_state_file<T: DeserializeOwned>(
+    fs: &dyn AsyncFs,
+    path: &Path,
+    magic: u32,
+    keyring: Option<&Keyring>,
+) -> RS<T> {
+    let bytes = fs.read_all(path).await?;
+    if bytes.len() < STATE_FILE_HEADER_SIZE {
+        return Err(mudu_error!(
+            ErrorCode::Decode,
+            format!("state file {} is truncated", path.display())
+        ));
+    }
+    let found_magic = u32::from_be_bytes(bytes[0..4].try_into().unwrap_or([0; 4]));
+    if found_magic != magic {
+        return Err(mudu_error!(
+            ErrorCode::Decode,
+            format!(
+                "state file {} has magic {:#x}, expected {:#x}",
+                path.display(),
+                found_magic,
+                magic
+            )
+        ));
+    }
+    let checksum = u64::from_be_bytes(bytes[4..12].try_into().unwrap_or([0; 8]));
+    let payload = &bytes[STATE_FILE_HEADER_SIZE..];
+    if calc_crc(payload) != checksum {
+        return Err(mudu_error!(
+            ErrorCode::Decode,
+            format!("state file {} checksum mismatch", path.display())
+        ));
+    }
+    let payload = match payload.split_first() {
+        Some((&SEALED_PAYLOAD_MARKER, blob)) => {
+            Cow::Owned(open_stored_blob(keyring, &magic.to_be_bytes(), blob)?)
+        }
+        _ => Cow::Borrowed(payload),
+    };
+    rmp_serde::from_slice(&payload)
+        .map_err(|e| mudu_error!(ErrorCode::Decode, "decode state file error", e))
+}
@@ -1,4 +1,8 @@
+use crate::storage::buffer_pool::BufferPool;
+use crate::storage::compression::{Compression, CompressionStats};
+use crate::storage::encryption::Sealer;
 use crate::storage::page::PageId;
+use crate::wal::lsn::LSN;
 use crate::wal::pl_batch::{new_pl_batch_writer, PLBatch};
 use crate::wal::pl_entry::{PLEntry, PLFileId, PLOp};
 use crate::wal::worker_log::ChunkedWorkerLogBackend;
@@ -8,7 +12,6 @@ use mudu::error::ErrorCode;
 use mudu::mudu_error;
 use mudu_sys::contract::async_fs::AsyncFs;
 use mudu_sys::fs::SysFile;
-use scc::HashMap;
 use serde::{Deserialize, Serialize};
 use std::path::{Path, PathBuf};
 use std::sync::Arc;
@@ -44,13 +47,31 @@ pub struct TimeSeriesFile {
     path: PathBuf,
     file: Option<SysFile>,
     wal_backend: Option<ChunkedWorkerLogBackend>,
-    page_cache: HashMap<PageId, Vec<u8>>,
+    // Pages are cached in a buffer pool, private to the file until a worker
+    // attaches its own pool.
+    buffer_pool: Arc<BufferPool>,
+    pool_file_id: u64,
+    // Size of every page of the file, from the data directory's page size
+    // record for relation files.
+    page_size: usize,
     page_count: PageId,
     head_page_id: Option<PageId>,
     tail_page_id: Option<PageId>,
     tuple_format_version: u32,
     tuple_schema_hash: u64,
     tuple_flags: u64,
+    // Codec of the pages written from now on; existing pages keep the codec
+    // recorded in their header flags.
+    compression: Compression,
+    compression_stats: Arc<CompressionStats>,
+    // Seals the pages this file writes through; `None` without a keyring.
+    // Cached pages are sealed by the buffer pool on write-back.
+    sealer: Option<Arc<Sealer>>,
+    // Pages before `reencrypt_end` were on disk when the file was opened and
+    // may be sealed with an older key; background re-encryption visits them
+    // from `reencrypt_cursor` on.
+    reencrypt_cursor: PageId,
+    reencrypt_end: PageId,
 }
 
 impl TimeSeriesFile {
@@ -62,6 +83,10 @@ impl TimeSeriesFile {
         self.identity.as_ref()
     }
 
+    pub fn page_size(&self) -> usize {
+        self.page_size
+    }
+
     pub fn page_count(&self) -> PageId {
         self.page_count
     }
@@ -74,6 +99,59 @@ impl TimeSeriesFile {
         self.tail_page_id
     }
 
+    /// Next LSN of the file's PL stream; `None` for standalone files.
+    ///
+    /// Any change to the file is logged there first, so the LSN identifies
+    /// the file contents.
+    pub fn wal_lsn(&self) -> RS<Option<LSN>> {
+        self.wal_backend
+            .as_ref()
+            .map(|backend| backend.position().map(|position| position.lsn))
+            .transpose()
+    }
+
+    /// Chunks of the file's PL stream, oldest first; empty for standalone
+    /// files.
+    pub async fn wal_chunk_paths(&self) -> RS<Vec<PathBuf>> {
+        match &self.wal_backend {
+            Some(backend) => {
+                backend
+                    .layout()?
+                    .chunk_paths_sorted_async(backend.fs().as_ref())
+                    .await
+            }
+            None => Ok(Vec::new()),
+        }
+    }
+
+    /// Moves the cached pages of this file to `buffer_pool`. Dirty pages are
+    /// written back first.
+    pub async fn set_buffer_pool(&mut self, buffer_pool: Arc<BufferPool>) -> RS<()> {
+        if Arc::ptr_eq(&self.buffer_pool, &buffer_pool) {
+            return Ok(());
+        }
+        self.buffer_pool.write_back_file(self.pool_file_id).await?;
+        self.buffer_pool.discard_file(self.pool_file_id)?;
+        self.pool_file_id = buffer_pool.register_file(
+            self.file_ref()?.clone(),
+            self.wal_backend.clone(),
+            self.sealer.clone(),
+        )?;
+        self.buffer_pool = buffer_pool;
+        Ok(())
+    }
+
+    pub fn compression(&self) -> Compression {
+        self.compression
+    }
+
+    /// Compresses the records of pages written from now on with
+    /// `compression`, counting raw and stored record bytes in `stats`.
+    pub fn set_compression(&mut self, compression: Compression, stats: Arc<CompressionStats>) {
+        self.compression = compression;
+        self.compression_stats = stats;
+    }
+
     fn file_ref(&self) -> RS<&SysFile> {
         self.file
             .as_ref()
@@ -86,15 +164,22 @@ impl TimeSeriesFile {
             .ok_or_else(|| mudu_error!(ErrorCode::Internal, "time series file is not open"))
     }
 
+    /// Writes back the dirty cached pages and syncs the file.
     pub async fn flush(&self) -> RS<()> {
+        self.buffer_pool.write_back_file(self.pool_file_id).await?;
         io::flush_file(self.file_ref()?).await
     }
 
     pub async fn close(mut self) -> RS<()> {
+        self.buffer_pool.write_back_file(self.pool_file_id).await?;
+        self.buffer_pool.discard_file(self.pool_file_id)?;
         io::close_file(self.take_file()?).await
     }
 
+    /// Closes the file without writing back dirty pages; the PL stream
+    /// replays them on the next open.
     pub fn close_sync(mut self) -> RS<()> {
+        self.buffer_pool.discard_file(self.pool_file_id)?;
         drop(self.take_file()?);
         Ok(())
     }
@@ -116,6 +201,7 @@ impl TimeSeriesFile {
                 }]))
                 .await?;
         }
+        self.buffer_pool.discard_file(self.pool_file_id)?;
         io::close_file(self.take_file()?).await?;
         match self.fs.as_ref() {
             Some(fs) => fs.remove_file_if_exists(&self.path).await,
@@ -124,6 +210,13 @@ impl TimeSeriesFile {
     }
 }
 
+impl Drop for TimeSeriesFile {
+    fn drop(&mut self) {
+        // Dirty pages of a relation file are in its PL stream already.
+        let _ = self.buffer_pool.discard_file(self.pool_file_id);
+    }
+}
+
 mod io;
 mod open;
 mod page;
@@ -1,8 +1,10 @@
 use super::FILE_MODE_644;
-use crate::storage::page::page_block_ref::PAGE_SIZE;
+use crate::storage::encryption::{open_stored_page, Keyring};
+use crate::storage::page::page_block_ref::load_page;
 use crate::storage::page::PageId;
 use mudu::common::result::RS;
 use mudu::error::ErrorCode;
+use mudu::error::ResultExt;
 use mudu::mudu_error;
 use mudu_sys::contract::async_fs::AsyncFs;
 use mudu_sys::contract::file_options::FileOptions;
@@ -12,9 +14,9 @@ use mudu_sys::fs::SysFile;
 use std::path::Path;
 use tracing::trace;
 
-pub(super) fn page_offset(page_id: PageId) -> RS<u64> {
+pub(super) fn page_offset(page_id: PageId, page_size: usize) -> RS<u64> {
     page_id
-        .checked_mul(PAGE_SIZE as u64)
+        .checked_mul(page_size as u64)
         .map(|offset| offset.as_u64())
         .ok_or_else(|| {
             mudu_error!(
@@ -35,6 +37,21 @@ pub(super) async fn read_file_exact(file: &SysFile, len: usize, offset: u64) ->
     file.read_exact_at(offset, len).await
 }
 
+/// Reads page `page_id`, verifying its checksum, migrating it to the current
+/// page format and decrypting it.
+pub(super) async fn read_page_verified(
+    file: &SysFile,
+    page_id: PageId,
+    page_size: usize,
+    keyring: Option<&Keyring>,
+) -> RS<Vec<u8>> {
+    let page = read_file_exact(file, page_size, page_offset(page_id, page_size)?).await?;
+    let mut page = load_page(page).with_context(|| format!("time series page {}", page_id))?;
+    open_stored_page(keyring, &mut page)
+        .with_context(|| format!("time series page {}", page_id))?;
+    Ok(page)
+}
+
 pub(super) async fn flush_file(file: &SysFile) -> RS<()> {
     file.fsync().await
 }
@@ -1,12 +1,16 @@
-use super::io::{open_rw, page_offset, read_file_exact};
+use super::io::{open_rw, read_page_verified};
 use super::wal::{
     append_file_create_async, new_relation_wal_backend, new_relation_wal_backend_with_provider,
     recover_relation_file, recover_relation_file_async,
 };
 use super::{TimeSeriesFile, TimeSeriesFileIdentity};
-use crate::storage::page::page_block_ref::{PageBlockRef, PAGE_SIZE};
+use crate::storage::buffer_pool::BufferPool;
+use crate::storage::compression::{Compression, CompressionStats};
+use crate::storage::encryption::{Keyring, Sealer};
+use crate::storage::page::page_block_ref::{check_page_size, PageBlockRef, DEFAULT_PAGE_SIZE};
 use crate::storage::page::page_header::NONE_PAGE_ID;
 use crate::storage::page::PageId;
+use crate::storage::page_size::page_size_of;
 use crate::wal::worker_log::ChunkedWorkerLogBackend;
 use mudu::common::id::OID;
 use mudu::common::result::RS;
@@ -18,7 +22,6 @@ use mudu_sys::default_sys_io_context;
 use mudu_sys::fs::SysFile;
 use mudu_sys::SysIoContext;
 use mudu_utils::scoped_task_trace;
-use scc::HashMap;
 use std::path::{Path, PathBuf};
 use std::sync::Arc;
 use tracing::{debug, trace};
@@ -43,6 +46,7 @@ impl TimeSeriesFile {
         identity: TimeSeriesFileIdentity,
         tuple_schema_hash: u64,
         create_if_missing: bool,
+        keyring: Option<Arc<Keyring>>,
     ) -> RS<Self> {
         scoped_task_trace!();
         Self::open_relation_file_with_sys_io_context(
@@ -51,6 +55,7 @@ impl TimeSeriesFile {
             identity,
             tuple_schema_hash,
             create_if_missing,
+            keyring,
         )
         .await
     }
@@ -61,6 +66,7 @@ impl TimeSeriesFile {
         identity: TimeSeriesFileIdentity,
         tuple_schema_hash: u64,
         create_if_missing: bool,
+        keyring: Option<Arc<Keyring>>,
     ) -> RS<Self> {
         Self::open_relation_file_with_fs_and_wal_provider(
             sys.fs(),
@@ -69,6 +75,7 @@ impl TimeSeriesFile {
             identity,
             tuple_schema_hash,
             create_if_missing,
+            keyring,
         )
         .await
     }
@@ -80,6 +87,7 @@ impl TimeSeriesFile {
         identity: TimeSeriesFileIdentity,
         tuple_schema_hash: u64,
         create_if_missing: bool,
+        keyring: Option<Arc<Keyring>>,
     ) -> RS<Self> {
         Self::open_relation_file_with_fs_and_wal_provider(
             fs,
@@ -88,6 +96,7 @@ impl TimeSeriesFile {
             identity,
             tuple_schema_hash,
             create_if_missing,
+            keyring,
         )
         .await
     }
@@ -99,6 +108,7 @@ impl TimeSeriesFile {
         identity: TimeSeriesFileIdentity,
         tuple_schema_hash: u64,
         create_if_missing: bool,
+        keyring: Option<Arc<Keyring>>,
     ) -> RS<Self> {
         scoped_task_trace!();
         trace!(
@@ -115,10 +125,24 @@ impl TimeSeriesFile {
             identity.table_id,
             identity.file_index,
         );
-        let wal_backend =
-            new_relation_wal_backend_with_provider(&base_path, &identity, wal_provider).await?;
+        let page_size = page_size_of(&base_path)?;
+        let wal_backend = new_relation_wal_backend_with_provider(
+            &base_path,
+            &identity,
+            wal_provider,
+            keyring.as_ref(),
+        )
+        .await?;
         trace!(path = %path.display(), "time_series recovering relation file");
-        recover_relation_file_async(fs.clone(), &base_path, &identity, &wal_backend).await?;
+        recover_relation_file_async(
+            fs.clone(),
+            &base_path,
+            &identity,
+            &wal_backend,
+            page_size,
+            keyring.as_ref(),
+        )
+        .await?;
         if create_if_missing && !fs.path_exists(&path).await? {
             trace!(path = %path.display(), "time_series appending create-file wal record");
             append_file_create_async(&wal_backend, &identity).await?;
@@ -130,7 +154,9 @@ impl TimeSeriesFile {
             Some(identity),
             Some(wal_backend),
             tuple_schema_hash,
+            page_size,
             create_if_missing,
+            keyring,
         )
         .await
     }
@@ -141,6 +167,7 @@ impl TimeSeriesFile {
         identity: TimeSeriesFileIdentity,
         tuple_schema_hash: u64,
         create_if_missing: bool,
+        keyring: Option<Arc<Keyring>>,
     ) -> RS<Self> {
         let base_path = base_path.as_ref().to_path_buf();
         let path = Self::relation_file_path(
@@ -149,8 +176,16 @@ impl TimeSeriesFile {
             identity.table_id,
             identity.file_index,
         );
-        let wal_backend = new_relation_wal_backend(&base_path, &identity).await?;
-        recover_relation_file(&base_path, &identity, &wal_backend).await?;
+        let page_size = page_size_of(&base_path)?;
+        let wal_backend = new_relation_wal_backend(&base_path, &identity, keyring.as_ref()).await?;
+        recover_relation_file(
+            &base_path,
+            &identity,
+            &wal_backend,
+            page_size,
+            keyring.as_ref(),
+        )
+        .await?;
         if create_if_missing && !mudu_sys::io::path::path_exists(&path).await? {
             append_file_create_async(&wal_backend, &identity).await?;
         }
@@ -159,7 +194,9 @@ impl TimeSeriesFile {
             Some(identity),
             Some(wal_backend),
             tuple_schema_hash,
+            page_size,
             create_if_missing,
+            keyring,
         )
         .await
     }
@@ -182,13 +219,26 @@ impl TimeSeriesFile {
         path: P,
         create_if_missing: bool,
     ) -> RS<Self> {
+        Self::open_ts_file_with_page_size(fs, path, DEFAULT_PAGE_SIZE, create_if_missing).await
+    }
+
+    /// Opens a standalone time-series file whose pages are `page_size` bytes.
+    pub async fn open_ts_file_with_page_size<P: AsRef<Path>>(
+        fs: Arc<dyn AsyncFs>,
+        path: P,
+        page_size: usize,
+        create_if_missing: bool,
+    ) -> RS<Self> {
+        check_page_size(page_size)?;
         Self::open_inner_with_fs(
             fs,
             path.as_ref().to_path_buf(),
             None,
             None,
             0,
+            page_size,
             create_if_missing,
+            None,
         )
         .await
     }
@@ -199,18 +249,23 @@ impl TimeSeriesFile {
             None,
             None,
             0,
+            DEFAULT_PAGE_SIZE,
             create_if_missing,
+            None,
         )
         .await
     }
 
+    #[allow(clippy::too_many_arguments)]
     async fn open_inner_with_fs(
         fs: Arc<dyn AsyncFs>,
         path: PathBuf,
         identity: Option<TimeSeriesFileIdentity>,
         wal_backend: Option<ChunkedWorkerLogBackend>,
         tuple_schema_hash: u64,
+        page_size: usize,
         create_if_missing: bool,
+        keyring: Option<Arc<Keyring>>,
     ) -> RS<Self> {
         scoped_task_trace!();
         let path = path.to_path_buf();
@@ -228,32 +283,49 @@ impl TimeSeriesFile {
         let file = open_rw(fs.as_ref(), &path, flags).await?;
         trace!(path = %path.display(), "time_series opened rw file, reading metadata len by fd");
         let len = file.file_len().await?;
-        if len % PAGE_SIZE as u64 != 0 {
+        if len % page_size as u64 != 0 {
             return Err(mudu_error!(
                 ErrorCode::Decode,
                 format!(
                     "time series file length {} is not aligned to page size {}",
-                    len, PAGE_SIZE
+                    len, page_size
                 )
             ));
         }
 
-        let page_count = PageId::from(len / PAGE_SIZE as u64);
-        let (head_page_id, tail_page_id) =
-            load_chain_metadata(&file, page_count, tuple_schema_hash).await?;
+        let page_count = PageId::from(len / page_size as u64);
+        let (head_page_id, tail_page_id) = load_chain_metadata(
+            &file,
+            page_count,
+            page_size,
+            tuple_schema_hash,
+            keyring.as_deref(),
+        )
+        .await?;
+        let sealer = Sealer::for_keyring(keyring.as_ref())?.map(Arc::new);
+        let buffer_pool = Arc::new(BufferPool::default());
+        let pool_file_id =
+            buffer_pool.register_file(file.clone(), wal_backend.clone(), sealer.clone())?;
         Ok(Self {
             fs: Some(fs),
             identity,
             path,
             file: Some(file),
             wal_backend,
-            page_cache: HashMap::new(),
+            buffer_pool,
+            pool_file_id,
+            page_size,
             page_count,
             head_page_id,
             tail_page_id,
             tuple_format_version: if tuple_schema_hash != 0 { 1 } else { 0 },
             tuple_schema_hash,
             tuple_flags: 0,
+            compression: Compression::None,
+            compression_stats: Arc::new(CompressionStats::default()),
+            sealer,
+            reencrypt_cursor: PageId::new(0),
+            reencrypt_end: page_count,
         })
     }
 
@@ -262,7 +334,9 @@ impl TimeSeriesFile {
         identity: Option<TimeSeriesFileIdentity>,
         wal_backend: Option<ChunkedWorkerLogBackend>,
         tuple_schema_hash: u64,
+        page_size: usize,
         create_if_missing: bool,
+        keyring: Option<Arc<Keyring>>,
     ) -> RS<Self> {
         let path = path.to_path_buf();
         if let Some(parent) = path.parent() {
@@ -276,32 +350,49 @@ impl TimeSeriesFile {
         };
         let file = open_rw(default_sys_io_context().fs().as_ref(), &path, flags).await?;
         let len = file.file_len().await?;
-        if len % PAGE_SIZE as u64 != 0 {
+        if len % page_size as u64 != 0 {
             return Err(mudu_error!(
                 ErrorCode::Decode,
                 format!(
                     "time series file length {} is not aligned to page size {}",
-                    len, PAGE_SIZE
+                    len, page_size
                 )
             ));
         }
 
-        let page_count = PageId::from(len / PAGE_SIZE as u64);
-        let (head_page_id, tail_page_id) =
-            load_chain_metadata(&file, page_count, tuple_schema_hash).await?;
+        let page_count = PageId::from(len / page_size as u64);
+        let (head_page_id, tail_page_id) = load_chain_metadata(
+            &file,
+            page_count,
+            page_size,
+            tuple_schema_hash,
+            keyring.as_deref(),
+        )
+        .await?;
+        let sealer = Sealer::for_keyring(keyring.as_ref())?.map(Arc::new);
+        let buffer_pool = Arc::new(BufferPool::default());
+        let pool_file_id =
+            buffer_pool.register_file(file.clone(), wal_backend.clone(), sealer.clone())?;
         Ok(Self {
             fs: None,
             identity,
             path,
             file: Some(file),
             wal_backend,
-            page_cache: HashMap::new(),
+            buffer_pool,
+            pool_file_id,
+            page_size,
             page_count,
             head_page_id,
             tail_page_id,
             tuple_format_version: if tuple_schema_hash != 0 { 1 } else { 0 },
             tuple_schema_hash,
             tuple_flags: 0,
+            compression: Compression::None,
+            compression_stats: Arc::new(CompressionStats::default()),
+            sealer,
+            reencrypt_cursor: PageId::new(0),
+            reencrypt_end: page_count,
         })
     }
 }
@@ -309,7 +400,9 @@ impl TimeSeriesFile {
 async fn load_chain_metadata(
     file: &SysFile,
     page_count: PageId,
+    page_size: usize,
     expected_schema_hash: u64,
+    keyring: Option<&Keyring>,
 ) -> RS<(Option<PageId>, Option<PageId>)> {
     if page_count == 0 {
         return Ok((None, None));
@@ -317,7 +410,7 @@ async fn load_chain_metadata(
 
     let mut headers = Vec::with_capacity(page_count.as_usize());
     for page_id in 0..page_count.as_u64() {
-        let buf = read_file_exact(file, PAGE_SIZE, page_offset(PageId::from(page_id))?).await?;
+        let buf = read_page_verified(file, PageId::from(page_id), page_size, keyring).await?;
         let page = PageBlockRef::try_new(&buf)?;
         page.validate_layout()?;
         let header = page.header()?;
@@ -388,7 +481,7 @@ async fn load_chain_metadata(
             }
         }
 
-        let buf = read_file_exact(file, PAGE_SIZE, page_offset(current)?).await?;
+        let buf = read_page_verified(file, current, page_size, keyring).await?;
         let page = PageBlockRef::try_new(&buf)?;
         if let Some((min_ts, page_max)) = page.timestamp_bounds()? {
             if let Some(prev_min) = prev_non_empty_min {
@@ -1,24 +1,30 @@
 use super::TimeSeriesRecord;
-use crate::storage::page::page_block_ref::PAGE_SIZE;
+use crate::storage::compression::Compression;
+use crate::storage::page::page_block_ref::PageBlockRef;
 use crate::storage::page::page_block_ref_mut::PageBlockRefMut;
 use crate::storage::page::PageId;
 use mudu::common::result::RS;
 
+/// Size and header fields of the pages a time-series file creates.
+#[derive(Clone, Copy, Debug)]
+pub(super) struct PageImageFormat {
+    pub page_size: usize,
+    pub tuple_format_version: u32,
+    pub tuple_schema_hash: u64,
+    pub tuple_flags: u64,
+    pub compression: Compression,
+}
+
+/// Builds a page holding `entries`, whose payloads are already encoded with
+/// `format.compression`.
 pub(super) fn build_entries_page_image(
     page_id: PageId,
     prev_page_id: PageId,
     next_page_id: PageId,
     entries: &[TimeSeriesRecord],
-    tuple_format_version: u32,
-    tuple_schema_hash: u64,
-    tuple_flags: u64,
+    format: &PageImageFormat,
 ) -> RS<Vec<u8>> {
-    let mut page_buf = empty_page_image(
-        page_id,
-        tuple_format_version,
-        tuple_schema_hash,
-        tuple_flags,
-    )?;
+    let mut page_buf = empty_page_image(page_id, format)?;
     {
         let mut page = PageBlockRefMut::new(&mut page_buf);
         page.set_page_links(prev_page_id, next_page_id)?;
@@ -30,27 +36,30 @@ pub(super) fn build_entries_page_image(
     Ok(page_buf)
 }
 
-pub(super) fn empty_page_image(
-    page_id: PageId,
-    tuple_format_version: u32,
-    tuple_schema_hash: u64,
-    tuple_flags: u64,
-) -> RS<Vec<u8>> {
-    let mut page_buf = vec![0u8; PAGE_SIZE];
+pub(super) fn empty_page_image(page_id: PageId, format: &PageImageFormat) -> RS<Vec<u8>> {
+    let mut page_buf = vec![0u8; format.page_size];
     {
         let mut page = PageBlockRefMut::new(&mut page_buf);
         page.init_empty_with_tuple_meta(
             page_id,
-            tuple_format_version,
-            tuple_schema_hash,
-            tuple_flags,
+            format.tuple_format_version,
+            format.tuple_schema_hash,
+            format.tuple_flags,
         )?;
+        if format.compression != Compression::None {
+            page.set_page_flags(format.compression.page_flags())?;
+        }
     }
     Ok(page_buf)
 }
 
-pub(super) fn page_entries_fit(entries: &[TimeSeriesRecord]) -> bool {
-    let mut buf = vec![0u8; PAGE_SIZE];
+/// Codec of the records on `page`.
+pub(super) fn page_compression(page: &PageBlockRef<'_>) -> RS<Compression> {
+    Compression::from_page_flags(page.header()?.flags())
+}
+
+pub(super) fn page_entries_fit(entries: &[TimeSeriesRecord], page_size: usize) -> bool {
+    let mut buf = vec![0u8; page_size];
     let mut page = PageBlockRefMut::new(&mut buf);
     if page.init_empty(PageId::new(0)).is_err() {
         return false;
@@ -71,7 +80,22 @@ mod tests {
     #![allow(clippy::unwrap_used, clippy::expect_used, clippy::panic)]
 
     use super::*;
-    use crate::storage::page::page_block_ref::{PageBlockRef, PAGE_SIZE};
+    use crate::storage::page::page_block_ref::PAGE_SIZE;
+    use crate::storage::page::page_header::NONE_PAGE_ID;
+
+    fn format(
+        tuple_format_version: u32,
+        tuple_schema_hash: u64,
+        tuple_flags: u64,
+    ) -> PageImageFormat {
+        PageImageFormat {
+            page_size: PAGE_SIZE,
+            tuple_format_version,
+            tuple_schema_hash,
+            tuple_flags,
+            compression: Compression::None,
+        }
+    }
 
     #[test]
     fn empty_page_image_has_page_size_and_valid_layout() {
@@ -81,9 +105,13 @@ mod tests {
         let tuple_flags = 1u64;
         let buf = empty_page_image(
             page_id,
-            tuple_format_version,
-            tuple_schema_hash,
-            tuple_flags,
+            &PageImageFormat {
+                page_size: PAGE_SIZE,
+                tuple_format_version,
+                tuple_schema_hash,
+                tuple_flags,
+                compression: Compression::None,
+            },
         )
         .unwrap();
 
@@ -129,9 +157,7 @@ mod tests {
             PageId::new(0),
             PageId::new(2),
             &entries,
-            5,
-            99,
-            7,
+            &format(5, 99, 7),
         )
         .unwrap();
 
@@ -156,9 +182,14 @@ mod tests {
 
     #[test]
     fn build_entries_page_image_empty_entries_valid() {
-        let buf =
-            build_entries_page_image(PageId::new(5), PageId::new(4), PageId::new(6), &[], 1, 2, 3)
-                .unwrap();
+        let buf = build_entries_page_image(
+            PageId::new(5),
+            PageId::new(4),
+            PageId::new(6),
+            &[],
+            &format(1, 2, 3),
+        )
+        .unwrap();
 
         assert_eq!(buf.len(), PAGE_SIZE);
         let page = PageBlockRef::try_new(&buf).unwrap();
@@ -168,9 +199,44 @@ mod tests {
         assert_eq!(page.header_next_page().unwrap(), PageId::new(6));
     }
 
+    #[test]
+    fn compressed_page_records_its_codec_in_the_header_flags() {
+        let mut format = format(1, 2, 0);
+        format.compression = Compression::Lz4;
+        let payload = b"compressible payload ".repeat(20);
+        let entries = vec![TimeSeriesRecord {
+            timestamp: 1,
+            tuple_id: 1,
+            payload: format.compression.encode_record(&payload).unwrap(),
+            page_id: PageId::new(0),
+            slot_index: 0,
+        }];
+        let buf = build_entries_page_image(
+            PageId::new(0),
+            NONE_PAGE_ID,
+            NONE_PAGE_ID,
+            &entries,
+            &format,
+        )
+        .unwrap();
+
+        let page = PageBlockRef::try_new(&buf).unwrap();
+        page.validate_layout().unwrap();
+        let compression = page_compression(&page).unwrap();
+        assert_eq!(compression, Compression::Lz4);
+        assert!(page.record_bytes(0).unwrap().len() < payload.len());
+        assert_eq!(
+            compression
+                .decode_record(page.record_bytes(0).unwrap())
+                .unwrap()
+                .as_ref(),
+            payload
+        );
+    }
+
     #[test]
     fn page_entries_fit_empty_returns_true() {
-        assert!(page_entries_fit(&[]));
+        assert!(page_entries_fit(&[], PAGE_SIZE));
     }
 
     #[test]
@@ -191,7 +257,7 @@ mod tests {
                 slot_index: 0,
             },
         ];
-        assert!(page_entries_fit(&entries));
+        assert!(page_entries_fit(&entries, PAGE_SIZE));
     }
 
     #[test]
@@ -203,7 +269,8 @@ mod tests {
             page_id: PageId::new(0),
             slot_index: 0,
         }];
-        assert!(!page_entries_fit(&entries));
+        assert!(!page_entries_fit(&entries, PAGE_SIZE));
+        assert!(page_entries_fit(&entries, PAGE_SIZE * 2));
     }
 
     #[test]
@@ -218,7 +285,7 @@ mod tests {
                 slot_index: 0,
             })
             .collect();
-        assert!(!page_entries_fit(&entries));
+        assert!(!page_entries_fit(&entries, PAGE_SIZE));
     }
 
     #[test]
@@ -232,7 +299,7 @@ mod tests {
                 page_id: PageId::new(0),
                 slot_index: 0,
             }];
-            if !page_entries_fit(&entries) {
+            if !page_entries_fit(&entries, PAGE_SIZE) {
                 break;
             }
             size += 1;
@@ -252,7 +319,7 @@ mod tests {
             page_id: PageId::new(0),
             slot_index: 0,
         }];
-        assert!(page_entries_fit(&exact));
-        assert!(!page_entries_fit(&too_large));
+        assert!(page_entries_fit(&exact, PAGE_SIZE));
+        assert!(!page_entries_fit(&too_large, PAGE_SIZE));
     }
 }
@@ -3,7 +3,8 @@ use super::io::{
     page_offset, remove_file_if_exists_async,
 };
 use super::TimeSeriesFile;
-use crate::storage::page::page_block_ref::PAGE_SIZE;
+use crate::storage::buffer_pool::PageImage;
+use crate::storage::encryption::seal_page_image;
 use crate::storage::page::PageId;
 use crate::wal::pl_batch::{new_pl_batch_writer, PLBatch};
 use crate::wal::pl_entry::{PLEntry, PLFileId, PLOp, PageUpdate};
@@ -11,7 +12,6 @@ use mudu::common::result::RS;
 use mudu::error::ErrorCode;
 use mudu::mudu_error;
 use mudu_utils::scoped_task_trace;
-use scc::HashMap;
 use tracing::trace;
 
 #[derive(Clone)]
@@ -79,7 +79,7 @@ impl TimeSeriesFile {
                 Some(fs) => fs.remove_file_if_exists(&self.path).await?,
                 None => remove_file_if_exists_async(&self.path).await?,
             }
-            self.page_cache = HashMap::new();
+            self.buffer_pool.discard_file(self.pool_file_id)?;
         }
         if let Some(page_count) = plan.next_page_count {
             self.page_count = page_count;
@@ -93,13 +93,23 @@ impl TimeSeriesFile {
         Ok(())
     }
 
+    // A logged page stays dirty in the buffer pool until it is written back;
+    // pages of standalone files are written through.
     pub(super) async fn apply_page_write(&self, page_id: PageId, page: &[u8]) -> RS<()> {
-        let _ = self.page_cache.remove_sync(&page_id);
-        self.file_ref()?
-            .write_all_at(page_offset(page_id)?, page)
-            .await
-            .map(|_| ())?;
-        let _ = self.page_cache.insert_sync(page_id, page.to_vec());
+        let image = match self.wal_backend.as_ref() {
+            Some(backend) => PageImage::Dirty(backend.position()?.lsn),
+            None => {
+                let stored = seal_page_image(self.sealer.as_deref(), page)?;
+                self.file_ref()?
+                    .write_all_at(page_offset(page_id, self.page_size)?, &stored)
+                    .await?;
+                PageImage::WrittenThrough
+            }
+        };
+        let _ = self
+            .buffer_pool
+            .put(self.pool_file_id, page_id, page.to_vec(), image)
+            .await?;
         Ok(())
     }
 
@@ -112,12 +122,12 @@ impl TimeSeriesFile {
             ops.push(PLOp::Create);
         }
         for write in &plan.page_writes {
-            if write.image.len() != PAGE_SIZE {
+            if write.image.len() != self.page_size {
                 return Err(mudu_error!(
                     ErrorCode::Encode,
                     format!(
                         "page write requires {} bytes, got {}",
-                        PAGE_SIZE,
+                        self.page_size,
                         write.image.len()
                     )
                 ));
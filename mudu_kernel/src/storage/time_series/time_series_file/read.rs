@@ -1,6 +1,9 @@
-use super::io::{page_offset, read_file_exact};
+use super::io::read_page_verified;
+use super::page::page_compression;
 use super::{TimeSeriesFile, TimeSeriesRecord};
-use crate::storage::page::page_block_ref::{PageBlockRef, PAGE_SIZE};
+use crate::storage::buffer_pool::{PageImage, PinnedPage};
+use crate::storage::encryption::Sealer;
+use crate::storage::page::page_block_ref::PageBlockRef;
 use crate::storage::page::PageId;
 use mudu::common::result::RS;
 use mudu::error::ErrorCode;
@@ -22,10 +25,13 @@ impl TimeSeriesFile {
                     continue;
                 }
                 if let Some(slot_index) = page.find_slot_index(timestamp, tuple_id)? {
+                    let payload = page_compression(&page)?
+                        .decode_record(page.record_bytes(slot_index)?)?
+                        .into_owned();
                     return Ok(Some(TimeSeriesRecord {
                         timestamp,
                         tuple_id,
-                        payload: page.record_bytes(slot_index)?.to_vec(),
+                        payload,
                         page_id,
                         slot_index,
                     }));
@@ -51,6 +57,7 @@ impl TimeSeriesFile {
                     break;
                 }
                 if min_ts <= end_ts && max_ts >= begin_ts {
+                    let compression = page_compression(&page)?;
                     let count = page.slot_count()?;
                     for slot_index in 0..count {
                         let slot = page.slot_ref(slot_index)?;
@@ -61,7 +68,9 @@ impl TimeSeriesFile {
                         rows.push(TimeSeriesRecord {
                             timestamp: ts,
                             tuple_id: slot.tuple_id(),
-                            payload: page.record_bytes(slot_index)?.to_vec(),
+                            payload: compression
+                                .decode_record(page.record_bytes(slot_index)?)?
+                                .into_owned(),
                             page_id,
                             slot_index,
                         });
@@ -79,7 +88,7 @@ impl TimeSeriesFile {
         Ok(rows)
     }
 
-    pub(super) async fn read_page(&self, page_id: PageId) -> RS<Vec<u8>> {
+    pub(super) async fn read_page(&self, page_id: PageId) -> RS<PinnedPage> {
         scoped_task_trace!();
         if page_id >= self.page_count {
             return Err(mudu_error!(
@@ -87,13 +96,14 @@ impl TimeSeriesFile {
                 format!("page {} out of range {}", page_id, self.page_count)
             ));
         }
-        if let Some(entry) = self.page_cache.get_sync(&page_id) {
-            return Ok(entry.get().clone());
+        if let Some(page) = self.buffer_pool.get(self.pool_file_id, page_id)? {
+            return Ok(page);
         }
 
-        let page = read_file_exact(self.file_ref()?, PAGE_SIZE, page_offset(page_id)?).await?;
-        let _ = self.page_cache.remove_sync(&page_id);
-        let _ = self.page_cache.insert_sync(page_id, page.clone());
-        Ok(page)
+        let keyring = self.sealer.as_deref().map(Sealer::keyring);
+        let page = read_page_verified(self.file_ref()?, page_id, self.page_size, keyring).await?;
+        self.buffer_pool
+            .put(self.pool_file_id, page_id, page, PageImage::Read)
+            .await
     }
 }
@@ -1,11 +1,14 @@
 use super::{TimeSeriesFile, TimeSeriesFileIdentity};
-use crate::storage::page::page_block_ref::PAGE_SIZE;
+use crate::storage::buffer_pool::BufferPool;
+use crate::storage::encryption::{page_key_id, Keyring, Sealer};
+use crate::storage::page::page_block_ref::{PageBlockRef, PAGE_SIZE};
 use crate::storage::page::PageId;
 use mudu_sys::common::provider_type::ProviderType;
 use mudu_sys::provider::create_io_provider;
 use mudu_sys::task::async_::block_on_async_current;
 use mudu_utils::log::log_setup;
 use project_root::get_project_root;
+use std::sync::Arc;
 
 fn temp_ts_path(name: &str) -> std::path::PathBuf {
     let root = get_project_root().unwrap();
@@ -169,6 +172,48 @@ fn insert_creates_multiple_pages_when_page_is_full() {
     .unwrap()
 }
 
+#[test]
+fn reencrypt_pages_seals_existing_pages_with_the_active_key() {
+    mudu_sys::task::async_::block_on_tokio_current_thread(async move {
+        let path = temp_ts_path("reencrypt");
+        let mut file = TimeSeriesFile::open_ts_file(&path, true).await.unwrap();
+        for idx in 0..16u64 {
+            file.insert(10_000 - idx, idx, &payload(idx as u8, 700))
+                .await
+                .unwrap();
+        }
+        // Pretend the file was opened with a keyring whose active key never
+        // sealed its pages.
+        let keyring = Arc::new(Keyring::parse(&format!("1 {}", "3c".repeat(32))).unwrap());
+        file.sealer = Some(Arc::new(Sealer::new(keyring.clone()).unwrap()));
+        file.reencrypt_end = file.page_count();
+        let page_count = file.page_count().as_u64();
+
+        let first = file.reencrypt_pages(1).await.unwrap();
+        assert_eq!(first.scanned_pages, 1);
+        assert_eq!(first.rewritten_pages, 1);
+        assert!(!first.done);
+        let rest = file.reencrypt_pages(page_count).await.unwrap();
+        assert_eq!(rest.rewritten_pages, page_count - 1);
+        assert!(rest.done);
+
+        let bytes = mudu_sys::fs::sync::read(&path).unwrap();
+        for page in bytes.chunks_exact(PAGE_SIZE) {
+            assert_eq!(page_key_id(page).unwrap(), 1);
+            let mut page = page.to_vec();
+            keyring.open_page(&mut page).unwrap();
+            PageBlockRef::try_new(&page).unwrap();
+        }
+        for idx in 0..16u64 {
+            let row = file.get(10_000 - idx, idx).await.unwrap().unwrap();
+            assert_eq!(row.payload, payload(idx as u8, 700));
+        }
+        file.close().await.unwrap();
+        let _ = mudu_sys::fs::sync::remove_file(path);
+    })
+    .unwrap()
+}
+
 #[test]
 fn cached_pages_are_reused_after_writes() {
     mudu_sys::task::async_::block_on_tokio_current_thread(async move {
@@ -316,7 +361,7 @@ async fn _wal_recovers_relation_file_after_data_loss() {
     );
 
     let mut file =
-        TimeSeriesFile::open_relation_file_sync(&base, identity.clone(), 0xfeed_beef, true)
+        TimeSeriesFile::open_relation_file_sync(&base, identity.clone(), 0xfeed_beef, true, None)
             .await
             .unwrap();
     file.insert(100, 1, b"alpha").await.unwrap();
@@ -325,9 +370,10 @@ async fn _wal_recovers_relation_file_after_data_loss() {
     file.close().await.unwrap();
     mudu_sys::fs::sync::remove_file(&path).unwrap();
 
-    let reopened = TimeSeriesFile::open_relation_file_sync(&base, identity, 0xfeed_beef, false)
-        .await
-        .unwrap();
+    let reopened =
+        TimeSeriesFile::open_relation_file_sync(&base, identity, 0xfeed_beef, false, None)
+            .await
+            .unwrap();
     assert_eq!(
         reopened.get(100, 1).await.unwrap().unwrap().payload,
         b"alpha".to_vec()
@@ -337,6 +383,59 @@ async fn _wal_recovers_relation_file_after_data_loss() {
     mudu_sys::fs::sync::remove_dir_all(base).unwrap();
 }
 
+#[test]
+fn relation_file_uses_the_recorded_page_size() {
+    block_on_async_current(async {
+        _relation_file_uses_the_recorded_page_size().await;
+    })
+}
+async fn _relation_file_uses_the_recorded_page_size() {
+    let base = temp_relation_base("page-size");
+    crate::storage::page_size::write_page_size(&base, PAGE_SIZE * 2).unwrap();
+    let identity = TimeSeriesFileIdentity {
+        partition_id: 3,
+        table_id: 5,
+        file_index: 0,
+    };
+    let path = TimeSeriesFile::relation_file_path(
+        &base,
+        identity.partition_id,
+        identity.table_id,
+        identity.file_index,
+    );
+
+    let mut file =
+        TimeSeriesFile::open_relation_file_sync(&base, identity.clone(), 0x5eed_0002, true, None)
+            .await
+            .unwrap();
+    assert_eq!(file.page_size(), PAGE_SIZE * 2);
+    // Larger than a default page.
+    file.insert(100, 1, &payload(7, PAGE_SIZE + 100))
+        .await
+        .unwrap();
+    file.insert(100, 2, b"small").await.unwrap();
+    file.close().await.unwrap();
+    let file_len = mudu_sys::fs::sync::metadata(&path).unwrap().len() as usize;
+    assert_eq!(file_len, PAGE_SIZE * 2);
+    mudu_sys::fs::sync::remove_file(&path).unwrap();
+
+    let reopened =
+        TimeSeriesFile::open_relation_file_sync(&base, identity, 0x5eed_0002, false, None)
+            .await
+            .unwrap();
+    assert_eq!(reopened.page_count(), PageId::new(1));
+    assert_eq!(
+        reopened.get(100, 1).await.unwrap().unwrap().payload,
+        payload(7, PAGE_SIZE + 100)
+    );
+    assert_eq!(
+        reopened.get(100, 2).await.unwrap().unwrap().payload,
+        b"small".to_vec()
+    );
+    reopened.close().await.unwrap();
+    mudu_sys::fs::sync::remove_dir_all(base).unwrap();
+}
+
 #[test]
 fn wal_recovers_empty_file_from_create_record() {
     block_on_async_current(async move {
@@ -357,13 +456,13 @@ async fn _wal_recovers_empty_file_from_create_record() {
         identity.file_index,
     );
 
-    let file = TimeSeriesFile::open_relation_file_sync(&base, identity.clone(), 0x1, true)
+    let file = TimeSeriesFile::open_relation_file_sync(&base, identity.clone(), 0x1, true, None)
         .await
         .unwrap();
     file.close_sync().unwrap();
     mudu_sys::fs::sync::remove_file(&path).unwrap();
 
-    let reopened = TimeSeriesFile::open_relation_file_sync(&base, identity, 0x1, false)
+    let reopened = TimeSeriesFile::open_relation_file_sync(&base, identity, 0x1, false, None)
         .await
         .unwrap();
     assert_eq!(reopened.page_count(), 0);
@@ -371,6 +470,53 @@ async fn _wal_recovers_empty_file_from_create_record() {
     mudu_sys::fs::sync::remove_dir_all(base).unwrap();
 }
 
+#[test]
+fn relation_file_pages_are_evicted_and_written_back() {
+    mudu_sys::task::async_::block_on_tokio_current_thread(async move {
+        let base = temp_relation_base("buffer-pool");
+        let identity = TimeSeriesFileIdentity {
+            partition_id: 29,
+            table_id: 37,
+            file_index: 0,
+        };
+        let path = TimeSeriesFile::relation_file_path(
+            &base,
+            identity.partition_id,
+            identity.table_id,
+            identity.file_index,
+        );
+        let pool = Arc::new(BufferPool::new(2 * PAGE_SIZE as u64));
+        let mut file = TimeSeriesFile::open_relation_file(&base, identity, 0, true, None)
+            .await
+            .unwrap();
+        file.set_buffer_pool(pool.clone()).await.unwrap();
+
+        for idx in 0..48u64 {
+            let data = payload((idx % 251) as u8, 700);
+            file.insert(10_000 - idx, idx, &data).await.unwrap();
+        }
+        assert!(file.page_count() > 2);
+        for idx in 0..48u64 {
+            let row = file.get(10_000 - idx, idx).await.unwrap().unwrap();
+            assert_eq!(row.payload, payload((idx % 251) as u8, 700));
+        }
+        let stats = pool.stats().unwrap();
+        assert!(stats.resident_bytes <= 2 * PAGE_SIZE as u64);
+        assert!(stats.evictions > 0);
+        assert!(stats.write_backs > 0);
+        assert!(stats.hits > 0 && stats.misses > 0);
+        file.close().await.unwrap();
+
+        // Opened as a plain file, so the pages come from the data file
+        // rather than from a PL replay.
+        let reopened = TimeSeriesFile::open_ts_file(&path, false).await.unwrap();
+        assert_eq!(reopened.scan_range(0, 10_000).await.unwrap().len(), 48);
+        reopened.close().await.unwrap();
+        mudu_sys::fs::sync::remove_dir_all(base).unwrap();
+    })
+    .unwrap()
+}
+
 #[test]
 fn wal_recovers_relation_file_after_data_loss_async() {
     mudu_sys::task::async_::block_on_tokio_current_thread(async move {
@@ -388,7 +534,7 @@ fn wal_recovers_relation_file_after_data_loss_async() {
         );
 
         let mut file =
-            TimeSeriesFile::open_relation_file(&base, identity.clone(), 0x1234_5678, true)
+            TimeSeriesFile::open_relation_file(&base, identity.clone(), 0x1234_5678, true, None)
                 .await
                 .unwrap();
         file.insert(100, 1, b"alpha").await.unwrap();
@@ -397,9 +543,10 @@ fn wal_recovers_relation_file_after_data_loss_async() {
         file.close().await.unwrap();
         mudu_sys::fs::sync::remove_file(&path).unwrap();
 
-        let reopened = TimeSeriesFile::open_relation_file(&base, identity, 0x1234_5678, false)
-            .await
-            .unwrap();
+        let reopened =
+            TimeSeriesFile::open_relation_file(&base, identity, 0x1234_5678, false, None)
+                .await
+                .unwrap();
         assert_eq!(
             reopened.get(100, 1).await.unwrap().unwrap().payload,
             b"alpha".to_vec()
@@ -434,6 +581,7 @@ fn wal_recovers_relation_file_with_injected_async_fs() {
             identity.clone(),
             0x55aa_aa55,
             true,
+            None,
         )
         .await
         .unwrap();
@@ -448,6 +596,7 @@ fn wal_recovers_relation_file_with_injected_async_fs() {
             identity,
             0x55aa_aa55,
             false,
+            None,
         )
         .await
         .unwrap();
@@ -484,9 +633,10 @@ async fn _wal_replays_terminal_delete_before_open() {
         identity.file_index,
     );
 
-    let mut file = TimeSeriesFile::open_relation_file_sync(&base, identity.clone(), 0x2, true)
-        .await
-        .unwrap();
+    let mut file =
+        TimeSeriesFile::open_relation_file_sync(&base, identity.clone(), 0x2, true, None)
+            .await
+            .unwrap();
     file.insert(42, 9, b"payload").await.unwrap();
     file.delete_file().await.unwrap();
 
@@ -496,7 +646,7 @@ async fn _wal_replays_terminal_delete_before_open() {
     stray.close().await.unwrap();
     assert!(path.exists());
 
-    let err = TimeSeriesFile::open_relation_file_sync(&base, identity, 0x2, false)
+    let err = TimeSeriesFile::open_relation_file_sync(&base, identity, 0x2, false, None)
         .await
         .err()
         .unwrap();
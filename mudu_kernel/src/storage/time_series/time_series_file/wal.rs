@@ -1,5 +1,6 @@
 use super::io::{ensure_time_series_file_exists_async, page_offset};
 use super::{TimeSeriesFile, TimeSeriesFileIdentity};
+use crate::storage::encryption::{seal_page_image, Keyring, Sealer};
 use crate::wal::lsn::LSN;
 use crate::wal::pl_batch::{
     new_pl_batch_worker_log, new_pl_batch_writer, NoopPLBatchRecoveryHandler, PLBatch,
@@ -16,17 +17,20 @@ use mudu_sys::contract::async_io_provider::AsyncIoProvider;
 use mudu_sys::contract::file_options::FileOptions;
 use mudu_sys::default_sys_io_context;
 use mudu_utils::scoped_task_trace;
+use std::borrow::Cow;
 use std::path::{Path, PathBuf};
 use std::sync::Arc;
 
 pub(super) async fn new_relation_wal_backend(
     base_path: &Path,
     identity: &TimeSeriesFileIdentity,
+    keyring: Option<&Arc<Keyring>>,
 ) -> RS<ChunkedWorkerLogBackend> {
     new_relation_wal_backend_with_provider(
         base_path,
         identity,
         default_sys_io_context().provider_arc(),
+        keyring,
     )
     .await
 }
@@ -35,6 +39,7 @@ pub(super) async fn new_relation_wal_backend_with_provider(
     base_path: &Path,
     identity: &TimeSeriesFileIdentity,
     provider: Arc<dyn AsyncIoProvider>,
+    keyring: Option<&Arc<Keyring>>,
 ) -> RS<ChunkedWorkerLogBackend> {
     scoped_task_trace!();
     // Each relation file gets its own physical-log stream so recovery can
@@ -44,7 +49,8 @@ pub(super) async fn new_relation_wal_backend_with_provider(
         log_dir,
         time_series_log_oid(identity),
         super::RELATION_WAL_CHUNK_SIZE,
-    )?;
+    )?
+    .with_keyring(keyring.cloned());
     ChunkedWorkerLogBackend::new_direct_with_provider(layout, provider).await
 }
 
@@ -59,8 +65,18 @@ pub(super) async fn recover_relation_file(
     base_path: &Path,
     identity: &TimeSeriesFileIdentity,
     backend: &ChunkedWorkerLogBackend,
+    page_size: usize,
+    keyring: Option<&Arc<Keyring>>,
 ) -> RS<()> {
-    recover_relation_file_async(default_sys_io_context().fs(), base_path, identity, backend).await
+    recover_relation_file_async(
+        default_sys_io_context().fs(),
+        base_path,
+        identity,
+        backend,
+        page_size,
+        keyring,
+    )
+    .await
 }
 
 pub(super) async fn recover_relation_file_async(
@@ -68,6 +84,8 @@ pub(super) async fn recover_relation_file_async(
     base_path: &Path,
     identity: &TimeSeriesFileIdentity,
     backend: &ChunkedWorkerLogBackend,
+    page_size: usize,
+    keyring: Option<&Arc<Keyring>>,
 ) -> RS<()> {
     scoped_task_trace!();
     let mut source = RelationWalRecoverySource {
@@ -87,12 +105,20 @@ pub(super) async fn recover_relation_file_async(
             table_id: identity.table_id,
             file_index: identity.file_index,
         },
+        sealer: Sealer::for_keyring(keyring)?,
+        page_size,
     });
     let log = new_pl_batch_worker_log(backend.clone(), NoopPLBatchRecoveryHandler);
     log.recover_async_with_handler(&mut source, &handler).await
 }
 
-async fn apply_recovered_entry_async(fs: &dyn AsyncFs, path: &Path, entry: &PLEntry) -> RS<()> {
+async fn apply_recovered_entry_async(
+    fs: &dyn AsyncFs,
+    path: &Path,
+    sealer: Option<&Sealer>,
+    page_size: usize,
+    entry: &PLEntry,
+) -> RS<()> {
     for op in &entry.ops {
         match op {
             PLOp::Create => ensure_time_series_file_exists_async(fs, path).await?,
@@ -100,9 +126,16 @@ async fn apply_recovered_entry_async(fs: &dyn AsyncFs, path: &Path, entry: &PLEn
             PLOp::PageUpdate(update) => {
                 ensure_time_series_file_exists_async(fs, path).await?;
                 let file = fs.open(path, FileOptions::read_write_create()).await?;
+                // The log holds plaintext page images; whole pages are sealed
+                // again on their way back to the data file.
+                let data = if update.offset == 0 && update.data.len() == page_size {
+                    seal_page_image(sealer, &update.data)?
+                } else {
+                    Cow::Borrowed(update.data.as_slice())
+                };
                 file.write_all_at(
-                    page_offset(update.page_id)? + update.offset as u64,
-                    &update.data,
+                    page_offset(update.page_id, page_size)? + update.offset as u64,
+                    &data,
                 )
                 .await?;
             }
@@ -132,6 +165,8 @@ struct RelationWalRecoveryHandler {
     fs: Arc<dyn AsyncFs>,
     path: PathBuf,
     file_id: PLFileId,
+    sealer: Option<Sealer>,
+    page_size: usize,
 }
 
 #[async_trait]
@@ -142,7 +177,14 @@ impl AsyncWorkerLogRecoveryHandler<PLBatch> for Arc<RelationWalRecoveryHandler>
             if item.file != self.file_id {
                 continue;
             }
-            apply_recovered_entry_async(self.fs.as_ref(), &self.path, item).await?;
+            apply_recovered_entry_async(
+                self.fs.as_ref(),
+                &self.path,
+                self.sealer.as_ref(),
+                self.page_size,
+                item,
+            )
+            .await?;
         }
         Ok(())
     }
@@ -1,7 +1,12 @@
-use super::page::{build_entries_page_image, empty_page_image, page_entries_fit};
+use super::io::{page_offset, read_file_exact};
+use super::page::{
+    build_entries_page_image, empty_page_image, page_compression, page_entries_fit, PageImageFormat,
+};
 use super::plan::{PlannedPageWrite, TimeSeriesFileMutationPlan};
 use super::{TimeSeriesFile, TimeSeriesRecord};
-use crate::storage::page::page_block_ref::{PageBlockRef, PAGE_SIZE};
+use crate::storage::compression::Compression;
+use crate::storage::encryption::{page_key_id, ReencryptStats};
+use crate::storage::page::page_block_ref::PageBlockRef;
 use crate::storage::page::page_block_ref_mut::PageBlockRefMut;
 use crate::storage::page::page_header::NONE_PAGE_ID;
 use crate::storage::page::PageId;
@@ -24,15 +29,11 @@ impl TimeSeriesFile {
         match self.find_insert_location(timestamp).await? {
             PageInsertLocation::EmptyFile => {
                 let page_id = self.page_count;
-                let mut page_buf = empty_page_image(
-                    page_id,
-                    self.tuple_format_version,
-                    self.tuple_schema_hash,
-                    self.tuple_flags,
-                )?;
+                let mut page_buf = empty_page_image(page_id, &self.page_image_format())?;
+                let record = self.encode_payload(self.compression, payload)?;
                 {
                     let mut page = PageBlockRefMut::new(&mut page_buf);
-                    page.insert_record(timestamp, tuple_id, payload)?;
+                    page.insert_record(timestamp, tuple_id, &record)?;
                 }
                 let mut plan = TimeSeriesFileMutationPlan::default();
                 plan.page_writes.push(PlannedPageWrite {
@@ -67,10 +68,11 @@ impl TimeSeriesFile {
                     return Ok(());
                 }
 
-                let mut page_buf = page_buf;
+                let record = self.encode_payload(page_compression(&page)?, payload)?;
+                let mut page_buf = page_buf.to_vec();
                 let insert_result = {
                     let mut page_mut = PageBlockRefMut::new(&mut page_buf);
-                    page_mut.insert_record(timestamp, tuple_id, payload)
+                    page_mut.insert_record(timestamp, tuple_id, &record)
                 };
                 match insert_result {
                     Ok(_) => self.write_page(page_id, &page_buf).await?,
@@ -86,19 +88,15 @@ impl TimeSeriesFile {
                 let next_page_buf = self.read_page(next_page_id).await?;
                 let next_page = PageBlockRef::try_new(&next_page_buf)?;
                 let prev_page_id = next_page.active_prev_page()?;
-                let mut new_page_buf = empty_page_image(
-                    page_id,
-                    self.tuple_format_version,
-                    self.tuple_schema_hash,
-                    self.tuple_flags,
-                )?;
+                let mut new_page_buf = empty_page_image(page_id, &self.page_image_format())?;
+                let record = self.encode_payload(self.compression, payload)?;
                 {
                     let mut page = PageBlockRefMut::new(&mut new_page_buf);
                     page.set_page_links(prev_page_id.unwrap_or(NONE_PAGE_ID), next_page_id)?;
-                    page.insert_record(timestamp, tuple_id, payload)?;
+                    page.insert_record(timestamp, tuple_id, &record)?;
                 }
 
-                let mut updated_next_buf = next_page_buf.clone();
+                let mut updated_next_buf = next_page_buf.to_vec();
                 {
                     let header = PageBlockRef::try_new(&updated_next_buf)?.header()?;
                     let mut page = PageBlockRefMut::new(&mut updated_next_buf);
@@ -116,7 +114,7 @@ impl TimeSeriesFile {
                 });
                 if let Some(prev_page_id) = prev_page_id {
                     let prev_page_buf = self.read_page(prev_page_id).await?;
-                    let mut updated_prev_buf = prev_page_buf.clone();
+                    let mut updated_prev_buf = prev_page_buf.to_vec();
                     let header = PageBlockRef::try_new(&updated_prev_buf)?.header()?;
                     {
                         let mut page = PageBlockRefMut::new(&mut updated_prev_buf);
@@ -137,19 +135,15 @@ impl TimeSeriesFile {
                 let prev_page_buf = self.read_page(prev_page_id).await?;
                 let prev_page = PageBlockRef::try_new(&prev_page_buf)?;
                 let next_page_id = prev_page.active_next_page()?;
-                let mut new_page_buf = empty_page_image(
-                    page_id,
-                    self.tuple_format_version,
-                    self.tuple_schema_hash,
-                    self.tuple_flags,
-                )?;
+                let mut new_page_buf = empty_page_image(page_id, &self.page_image_format())?;
+                let record = self.encode_payload(self.compression, payload)?;
                 {
                     let mut page = PageBlockRefMut::new(&mut new_page_buf);
                     page.set_page_links(prev_page_id, next_page_id.unwrap_or(NONE_PAGE_ID))?;
-                    page.insert_record(timestamp, tuple_id, payload)?;
+                    page.insert_record(timestamp, tuple_id, &record)?;
                 }
 
-                let mut updated_prev_buf = prev_page_buf.clone();
+                let mut updated_prev_buf = prev_page_buf.to_vec();
                 {
                     let header = PageBlockRef::try_new(&updated_prev_buf)?.header()?;
                     let mut page = PageBlockRefMut::new(&mut updated_prev_buf);
@@ -167,7 +161,7 @@ impl TimeSeriesFile {
                 });
                 if let Some(next_page_id) = next_page_id {
                     let next_page_buf = self.read_page(next_page_id).await?;
-                    let mut updated_next_buf = next_page_buf.clone();
+                    let mut updated_next_buf = next_page_buf.to_vec();
                     let header = PageBlockRef::try_new(&updated_next_buf)?.header()?;
                     {
                         let mut page = PageBlockRefMut::new(&mut updated_next_buf);
@@ -204,7 +198,7 @@ impl TimeSeriesFile {
                     continue;
                 }
                 if let Some(slot_index) = page.find_slot_index(timestamp, tuple_id)? {
-                    let mut page_buf = page_buf;
+                    let mut page_buf = page_buf.to_vec();
                     {
                         let mut page_mut = PageBlockRefMut::new(&mut page_buf);
                         page_mut.delete_record(slot_index)?;
@@ -223,9 +217,50 @@ impl TimeSeriesFile {
         Ok(false)
     }
 
+    /// Rewrites up to `max_pages` of the pages this file had when it was
+    /// opened, resuming where the previous call stopped, so that pages sealed
+    /// with an older key, or not sealed at all, get sealed with the active
+    /// key. Pages written since the open are sealed with it already.
+    ///
+    /// A page is rewritten through the PL stream like any other page write.
+    pub async fn reencrypt_pages(&mut self, max_pages: u64) -> RS<ReencryptStats> {
+        let mut stats = ReencryptStats::default();
+        let Some(active_key_id) = self
+            .sealer
+            .as_ref()
+            .map(|sealer| sealer.keyring().active_key_id())
+        else {
+            stats.done = true;
+            return Ok(stats);
+        };
+        while stats.scanned_pages < max_pages && self.reencrypt_cursor < self.reencrypt_end {
+            let page_id = self.reencrypt_cursor;
+            self.reencrypt_cursor = PageId::new(page_id.as_u64() + 1);
+            stats.scanned_pages += 1;
+            let stored = read_file_exact(
+                self.file_ref()?,
+                self.page_size,
+                page_offset(page_id, self.page_size)?,
+            )
+            .await?;
+            if page_key_id(&stored)? == active_key_id {
+                continue;
+            }
+            let image = self.read_page(page_id).await?.to_vec();
+            let mut plan = TimeSeriesFileMutationPlan::default();
+            plan.page_writes.push(PlannedPageWrite { page_id, image });
+            self.persist_plan(plan).await?;
+            stats.rewritten_pages += 1;
+        }
+        stats.done = self.reencrypt_cursor >= self.reencrypt_end;
+        Ok(stats)
+    }
+
     fn find_split_index(&self, entries: &[TimeSeriesRecord]) -> RS<usize> {
         for split_at in 1..entries.len() {
-            if page_entries_fit(&entries[..split_at]) && page_entries_fit(&entries[split_at..]) {
+            if page_entries_fit(&entries[..split_at], self.page_size)
+                && page_entries_fit(&entries[split_at..], self.page_size)
+            {
                 return Ok(split_at);
             }
         }
@@ -235,7 +270,9 @@ impl TimeSeriesFile {
         ))
     }
 
+    /// Returns the records of `page` with decoded payloads.
     fn page_entries(&self, page: &PageBlockRef<'_>, page_id: PageId) -> RS<Vec<TimeSeriesRecord>> {
+        let compression = page_compression(page)?;
         let count = page.slot_count()?;
         let mut entries = Vec::with_capacity(count);
         for slot_index in 0..count {
@@ -243,7 +280,9 @@ impl TimeSeriesFile {
             entries.push(TimeSeriesRecord {
                 timestamp: slot.timestamp(),
                 tuple_id: slot.tuple_id(),
-                payload: page.record_bytes(slot_index)?.to_vec(),
+                payload: compression
+                    .decode_record(page.record_bytes(slot_index)?)?
+                    .into_owned(),
                 page_id,
                 slot_index,
             });
@@ -251,6 +290,24 @@ impl TimeSeriesFile {
         Ok(entries)
     }
 
+    fn page_image_format(&self) -> PageImageFormat {
+        PageImageFormat {
+            page_size: self.page_size,
+            tuple_format_version: self.tuple_format_version,
+            tuple_schema_hash: self.tuple_schema_hash,
+            tuple_flags: self.tuple_flags,
+            compression: self.compression,
+        }
+    }
+
+    /// Encodes `payload` for a page written with `compression` and counts its
+    /// raw and stored bytes.
+    fn encode_payload(&self, compression: Compression, payload: &[u8]) -> RS<Vec<u8>> {
+        let record = compression.encode_record(payload)?;
+        self.compression_stats.record(payload.len(), record.len());
+        Ok(record)
+    }
+
     async fn update_in_page(
         &mut self,
         page_id: PageId,
@@ -259,10 +316,12 @@ impl TimeSeriesFile {
         tuple_id: u64,
         payload: &[u8],
     ) -> RS<()> {
-        let mut page_buf = self.read_page(page_id).await?;
+        let mut page_buf = self.read_page(page_id).await?.to_vec();
+        let compression = page_compression(&PageBlockRef::new(&page_buf))?;
+        let record = self.encode_payload(compression, payload)?;
         {
             let mut page_mut = PageBlockRefMut::new(&mut page_buf);
-            page_mut.update_record(slot_index, timestamp, tuple_id, payload)?;
+            page_mut.update_record(slot_index, timestamp, tuple_id, &record)?;
         }
         let mut plan = TimeSeriesFileMutationPlan::default();
         plan.page_writes.push(PlannedPageWrite {
@@ -281,11 +340,15 @@ impl TimeSeriesFile {
     ) -> RS<()> {
         let page_buf = self.read_page(page_id).await?;
         let page = PageBlockRef::try_new(&page_buf)?;
+        // Both halves are rewritten with the file's codec.
         let mut entries = self.page_entries(&page, page_id)?;
+        for entry in entries.iter_mut() {
+            entry.payload = self.compression.encode_record(&entry.payload)?;
+        }
         entries.push(TimeSeriesRecord {
             timestamp,
             tuple_id,
-            payload: payload.to_vec(),
+            payload: self.encode_payload(self.compression, payload)?,
             page_id,
             slot_index: 0,
         });
@@ -302,23 +365,20 @@ impl TimeSeriesFile {
         let header = page.header()?;
         let old_next_page_id = page.active_next_page()?;
         let new_page_id = self.page_count;
+        let format = self.page_image_format();
         let current_page_buf = build_entries_page_image(
             page_id,
             header.prev_page(),
             new_page_id,
             &upper_entries,
-            self.tuple_format_version,
-            self.tuple_schema_hash,
-            self.tuple_flags,
+            &format,
         )?;
         let new_page_buf = build_entries_page_image(
             new_page_id,
             page_id,
             old_next_page_id.unwrap_or(NONE_PAGE_ID),
             &lower_entries,
-            self.tuple_format_version,
-            self.tuple_schema_hash,
-            self.tuple_flags,
+            &format,
         )?;
 
         let mut plan = TimeSeriesFileMutationPlan::default();
@@ -332,7 +392,7 @@ impl TimeSeriesFile {
         });
         if let Some(next_page_id) = old_next_page_id {
             let next_page_buf = self.read_page(next_page_id).await?;
-            let mut updated_next_buf = next_page_buf.clone();
+            let mut updated_next_buf = next_page_buf.to_vec();
             let next_header = PageBlockRef::try_new(&updated_next_buf)?.header()?;
             {
                 let mut page = PageBlockRefMut::new(&mut updated_next_buf);
@@ -378,12 +438,12 @@ impl TimeSeriesFile {
 
     async fn write_page(&mut self, page_id: PageId, page: &[u8]) -> RS<()> {
         scoped_task_trace!();
-        if page.len() != PAGE_SIZE {
+        if page.len() != self.page_size {
             return Err(mudu_error!(
                 ErrorCode::Encode,
                 format!(
                     "page write requires {} bytes, got {}",
-                    PAGE_SIZE,
+                    self.page_size,
                     page.len()
                 )
             ));
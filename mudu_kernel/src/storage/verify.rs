@@ -0,0 +1,408 @@
+//! Offline integrity check of a data directory.
+//!
+//! [`verify_data_dir`] walks a data directory and checks every page file
+//! (relation `*.dat` files and primary index `*.bti.{0,1}` slots) page by page,
+//! and every WAL chunk (`*.xl`) frame by frame. Relation pages have the page
+//! size recorded for the directory; index slots always use `PAGE_SIZE`. It only reads files, so it is
+//! meant to run while no server owns the directory.
+//!
+//! Sealed pages and frames are opened with the keyring passed in. Without
+//! one, only their checksums and plaintext headers are checked, and
+//! they are counted as unopened in the report.
+
+use std::fmt::{Display, Formatter};
+use std::path::{Path, PathBuf};
+
+use mudu::common::result::RS;
+use mudu::error::ErrorCode;
+use mudu::mudu_error;
+
+use crate::storage::encryption::{page_key_id, Keyring};
+use crate::storage::page::page_block_ref::{load_page, PageBlockRef, PAGE_SIZE};
+use crate::storage::page::PageId;
+use crate::storage::page_size::page_size_of;
+use crate::wal::format::latest::{
+    check_frame, frame_len, split_frame, LOG_FRAME_HEADER_SIZE, LOG_FRAME_TAILER_SIZE,
+};
+
+/// A page that failed verification.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct CorruptPage {
+    pub path: PathBuf,
+    pub page_id: PageId,
+    pub reason: String,
+}
+
+/// A WAL frame that failed verification. Frames after it in the same chunk
+/// are not checked because their boundaries are unknown.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct CorruptFrame {
+    pub path: PathBuf,
+    pub offset: u64,
+    pub reason: String,
+}
+
+#[derive(Clone, Debug, Default)]
+pub struct VerifyReport {
+    pub page_files: u64,
+    pub pages: u64,
+    pub wal_chunks: u64,
+    pub frames: u64,
+    /// Sealed pages and frames checked without a key: their contents were
+    /// not decrypted.
+    pub unopened_sealed: u64,
+    /// Index slots left behind by an interrupted build. They are never opened,
+    /// so they are skipped rather than reported as corrupt.
+    pub incomplete_index_builds: Vec<PathBuf>,
+    pub corrupt_pages: Vec<CorruptPage>,
+    pub corrupt_frames: Vec<CorruptFrame>,
+}
+
+impl VerifyReport {
+    pub fn is_clean(&self) -> bool {
+        self.corrupt_pages.is_empty() && self.corrupt_frames.is_empty()
+    }
+}
+
+impl Display for VerifyReport {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        for page in &self.corrupt_pages {
+            writeln!(
+                f,
+                "corrupt page: {} page {}: {}",
+                page.path.display(),
+                page.page_id,
+                page.reason
+            )?;
+        }
+        for frame in &self.corrupt_frames {
+            writeln!(
+                f,
+                "corrupt frame: {} offset {}: {}",
+                frame.path.display(),
+                frame.offset,
+                frame.reason
+            )?;
+        }
+        for path in &self.incomplete_index_builds {
+            writeln!(f, "skipped incomplete index build: {}", path.display())?;
+        }
+        if self.unopened_sealed != 0 {
+            writeln!(
+                f,
+                "{} sealed pages and frames were not decrypted: no key file was given",
+                self.unopened_sealed
+            )?;
+        }
+        write!(
+            f,
+            "checked {} pages in {} files and {} frames in {} WAL chunks: {} corrupt pages, {} corrupt frames",
+            self.pages,
+            self.page_files,
+            self.frames,
+            self.wal_chunks,
+            self.corrupt_pages.len(),
+            self.corrupt_frames.len()
+        )
+    }
+}
+
+enum FileKind {
+    Pages,
+    IndexSlot,
+    WalChunk,
+}
+
+fn file_kind(path: &Path) -> Option<FileKind> {
+    let name = path.file_name()?.to_str()?;
+    if name.ends_with(".dat") {
+        return Some(FileKind::Pages);
+    }
+    if name.ends_with(".xl") {
+        return Some(FileKind::WalChunk);
+    }
+    let (stem, slot) = name.rsplit_once('.')?;
+    if stem.ends_with(".bti") && slot.parse::<u64>().is_ok() {
+        return Some(FileKind::IndexSlot);
+    }
+    None
+}
+
+/// Scans every page file and WAL chunk under `data_dir`, opening sealed ones
+/// with `keyring`.
+///
+/// Integrity problems are collected in the report; only I/O errors, such as
+/// an unreadable directory, fail the scan.
+pub fn verify_data_dir(data_dir: &Path, keyring: Option<&Keyring>) -> RS<VerifyReport> {
+    crate::compat::install_compatibility_router();
+    let page_size = page_size_of(data_dir)?;
+    let mut report = VerifyReport::default();
+    let mut pending = vec![data_dir.to_path_buf()];
+    while let Some(dir) = pending.pop() {
+        let mut entries = mudu_sys::fs::sync::read_dir_entries(&dir).map_err(|e| {
+            mudu_error!(
+                ErrorCode::Io,
+                format!("read directory {} error", dir.display()),
+                e
+            )
+        })?;
+        entries.sort_by_key(|entry| entry.path());
+        for entry in entries {
+            let path = entry.path();
+            if entry.file_type()?.is_dir() {
+                pending.push(path);
+                continue;
+            }
+            match file_kind(&path) {
+                Some(FileKind::Pages) => {
+                    verify_page_file(&path, page_size, false, keyring, &mut report)?
+                }
+                Some(FileKind::IndexSlot) => {
+                    verify_page_file(&path, PAGE_SIZE, true, keyring, &mut report)?
+                }
+                Some(FileKind::WalChunk) => verify_wal_chunk(&path, keyring, &mut report)?,
+                None => {}
+            }
+        }
+    }
+    Ok(report)
+}
+
+fn verify_page_file(
+    path: &Path,
+    page_size: usize,
+    index_slot: bool,
+    keyring: Option<&Keyring>,
+    report: &mut VerifyReport,
+) -> RS<()> {
+    let bytes = mudu_sys::fs::sync::read(path)?;
+    // The meta page of an index build is written last; a zeroed one marks a
+    // build that never finished.
+    if index_slot && bytes.len() >= page_size && bytes[..page_size].iter().all(|b| *b == 0) {
+        report.incomplete_index_builds.push(path.to_path_buf());
+        return Ok(());
+    }
+    report.page_files += 1;
+    let mut chunks = bytes.chunks_exact(page_size);
+    for (page_id, chunk) in chunks.by_ref().enumerate() {
+        report.pages += 1;
+        let page_id = PageId::new(page_id as u64);
+        match verify_page(chunk, page_id, keyring) {
+            Ok(opened) => {
+                if !opened {
+                    report.unopened_sealed += 1;
+                }
+            }
+            Err(e) => report.corrupt_pages.push(CorruptPage {
+                path: path.to_path_buf(),
+                page_id,
+                reason: e.to_string(),
+            }),
+        }
+    }
+    let trailing = chunks.remainder().len();
+    if trailing != 0 {
+        report.corrupt_pages.push(CorruptPage {
+            path: path.to_path_buf(),
+            page_id: PageId::new((bytes.len() / page_size) as u64),
+            reason: format!("partial page of {} bytes at the end of the file", trailing),
+        });
+    }
+    Ok(())
+}
+
+// Returns `false` for a sealed page that could not be opened for lack of a
+// keyring; its body was not checked.
+fn verify_page(page: &[u8], page_id: PageId, keyring: Option<&Keyring>) -> RS<bool> {
+    let mut page = load_page(page.to_vec())?;
+    let header_page_id = PageBlockRef::new(&page).header_page_id()?;
+    if header_page_id != page_id {
+        return Err(mudu_error!(
+            ErrorCode::CorruptedData,
+            format!("page header carries page id {}", header_page_id)
+        ));
+    }
+    if page_key_id(&page)? != 0 {
+        match keyring {
+            Some(keyring) => keyring.open_page(&mut page)?,
+            None => return Ok(false),
+        }
+    }
+    PageBlockRef::new(&page).validate_layout()?;
+    Ok(true)
+}
+
+fn verify_wal_chunk(path: &Path, keyring: Option<&Keyring>, report: &mut VerifyReport) -> RS<()> {
+    let bytes = mudu_sys::fs::sync::read(path)?;
+    report.wal_chunks += 1;
+    let mut offset = 0usize;
+    while offset < bytes.len() {
+        let remaining = &bytes[offset..];
+        // Recovery drops a trailing fragment too short to hold a frame, which
+        // is what a crash in the middle of an append leaves behind.
+        if remaining.len() < LOG_FRAME_HEADER_SIZE + LOG_FRAME_TAILER_SIZE {
+            break;
+        }
+        let checked = frame_len(remaining).and_then(|len| {
+            let frame = &remaining[..len];
+            let header = check_frame(frame)?;
+            if header.is_sealed() && keyring.is_none() {
+                return Ok((len, false));
+            }
+            split_frame(keyring, frame).map(|_| (len, true))
+        });
+        match checked {
+            Ok((len, opened)) => {
+                report.frames += 1;
+                if !opened {
+                    report.unopened_sealed += 1;
+                }
+                offset += len;
+            }
+            Err(e) => {
+                report.corrupt_frames.push(CorruptFrame {
+                    path: path.to_path_buf(),
+                    offset: offset as u64,
+                    reason: e.to_string(),
+                });
+                break;
+            }
+        }
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(
+        clippy::unwrap_used,
+        clippy::expect_used,
+        clippy::panic,
+        clippy::todo,
+        clippy::unimplemented
+    )]
+
+    use super::*;
+    use crate::storage::encryption::{Keyring, Sealer};
+    use crate::storage::page::page_block_ref_mut::PageBlockRefMut;
+    use crate::wal::format::latest::serialize_entry;
+    use project_root::get_project_root;
+    use std::sync::atomic::AtomicU64;
+    use std::sync::Arc;
+
+    fn test_dir() -> PathBuf {
+        let dir = get_project_root()
+            .unwrap()
+            .join("target")
+            .join("tmp")
+            .join(format!("verify_{}", mudu_sys::random::uuid_v4()));
+        mudu_sys::fs::sync::create_dir_all(dir.join("relation")).unwrap();
+        dir
+    }
+
+    fn page_file(pages: u64) -> Vec<u8> {
+        page_file_with_size(pages, PAGE_SIZE)
+    }
+
+    fn page_file_with_size(pages: u64, page_size: usize) -> Vec<u8> {
+        let mut bytes = vec![0u8; page_size * pages as usize];
+        for (page_id, chunk) in bytes.chunks_exact_mut(page_size).enumerate() {
+            let mut page = PageBlockRefMut::new(chunk);
+            page.init_empty(PageId::new(page_id as u64)).unwrap();
+            page.insert_record(1, page_id as u64, b"row").unwrap();
+        }
+        bytes
+    }
+
+    #[test]
+    fn reports_corrupt_pages_and_frames() {
+        let dir = test_dir();
+        let mut pages = page_file(3);
+        pages[PAGE_SIZE + 200] ^= 0x1;
+        mudu_sys::fs::sync::write(dir.join("relation").join("1.2.0.dat"), &pages).unwrap();
+        mudu_sys::fs::sync::write(dir.join("relation").join("1.2.1.dat"), page_file(2)).unwrap();
+        // An interrupted index build is skipped.
+        mudu_sys::fs::sync::write(
+            dir.join("relation").join("1.2.bti.1"),
+            vec![0u8; PAGE_SIZE * 2],
+        )
+        .unwrap();
+
+        let next_lsn = AtomicU64::new(1);
+        let mut chunk = Vec::new();
+        for value in 1..=3u64 {
+            let frames = serialize_entry(&value, 256, &next_lsn).unwrap();
+            chunk.extend_from_slice(&frames[0]);
+        }
+        let frame_size = chunk.len() / 3;
+        chunk[frame_size + LOG_FRAME_HEADER_SIZE] ^= 0x1;
+        mudu_sys::fs::sync::write(dir.join("abcd.0.xl"), &chunk).unwrap();
+
+        let report = verify_data_dir(&dir, None).unwrap();
+        assert!(!report.is_clean());
+        assert_eq!(report.page_files, 2);
+        assert_eq!(report.pages, 5);
+        assert_eq!(report.wal_chunks, 1);
+        assert_eq!(report.frames, 1);
+        assert_eq!(report.incomplete_index_builds.len(), 1);
+        assert_eq!(report.corrupt_pages.len(), 1);
+        assert_eq!(report.corrupt_pages[0].page_id, PageId::new(1));
+        assert!(report.corrupt_pages[0].reason.contains("checksum mismatch"));
+        assert_eq!(report.corrupt_frames.len(), 1);
+        assert_eq!(report.corrupt_frames[0].offset, frame_size as u64);
+        mudu_sys::fs::sync::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn sealed_pages_are_checked_without_a_key() {
+        let dir = test_dir();
+        let keyring = Arc::new(Keyring::parse(&format!("1 {}", "5a".repeat(32))).unwrap());
+        let sealer = Sealer::new(keyring.clone()).unwrap();
+        let mut pages = page_file(2);
+        for chunk in pages.chunks_exact_mut(PAGE_SIZE) {
+            sealer.seal_page(chunk).unwrap();
+        }
+        mudu_sys::fs::sync::write(dir.join("relation").join("1.2.0.dat"), &pages).unwrap();
+        let report = verify_data_dir(&dir, None).unwrap();
+        assert!(report.is_clean());
+        assert_eq!(report.unopened_sealed, 2);
+        let report = verify_data_dir(&dir, Some(&keyring)).unwrap();
+        assert!(report.is_clean());
+        assert_eq!(report.unopened_sealed, 0);
+
+        pages[PAGE_SIZE + 300] ^= 0x1;
+        mudu_sys::fs::sync::write(dir.join("relation").join("1.2.0.dat"), &pages).unwrap();
+        let report = verify_data_dir(&dir, None).unwrap();
+        assert_eq!(report.corrupt_pages.len(), 1);
+        assert_eq!(report.corrupt_pages[0].page_id, PageId::new(1));
+        mudu_sys::fs::sync::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn clean_directory_passes() {
+        let dir = test_dir();
+        mudu_sys::fs::sync::write(dir.join("relation").join("1.2.0.dat"), page_file(2)).unwrap();
+        let report = verify_data_dir(&dir, None).unwrap();
+        assert!(report.is_clean());
+        assert_eq!(report.pages, 2);
+        mudu_sys::fs::sync::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn relation_pages_use_the_recorded_page_size() {
+        let dir = test_dir();
+        mudu_sys::fs::sync::write(
+            dir.join("relation").join("1.2.0.dat"),
+            page_file_with_size(3, PAGE_SIZE * 2),
+        )
+        .unwrap();
+        let report = verify_data_dir(&dir, None).unwrap();
+        assert!(!report.is_clean());
+
+        crate::storage::page_size::write_page_size(&dir, PAGE_SIZE * 2).unwrap();
+        let report = verify_data_dir(&dir, None).unwrap();
+        assert!(report.is_clean());
+        assert_eq!(report.pages, 3);
+        mudu_sys::fs::sync::remove_dir_all(&dir).unwrap();
+    }
+}
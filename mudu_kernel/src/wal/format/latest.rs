@@ -1,3 +1,5 @@
+use crate::storage::compression::{Compression, CompressionStats};
+use crate::storage::encryption::{open_stored_blob, Keyring, Sealer, SEAL_OVERHEAD};
 use crate::wal::lsn::LSN;
 use mudu::common::crc::calc_crc;
 use mudu::common::result::RS;
@@ -17,6 +19,13 @@ const LOG_FRAME_MAGIC: u32 = 0x4C47_464D; // "LGFM"
 pub const VERSION: u32 = LOG_FRAME_CURRENT_VERSION;
 pub const LOG_FRAME_HEADER_SIZE: usize = 24;
 pub const LOG_FRAME_TAILER_SIZE: usize = 8;
+/// An entry may span at most this many frames: since v2 the header keeps the
+/// remaining part count in 24 bits, next to the one-byte `flags`.
+pub const LOG_FRAME_MAX_PARTS: u32 = 1 << 24;
+/// Set in `flags` when the payload is sealed with an encryption key; the low
+/// bits keep the codec of the payload inside the seal.
+pub const LOG_FRAME_FLAG_SEALED: u8 = 0x80;
+const LOG_FRAME_CODEC_MASK: u8 = 0x0F;
 
 #[derive(Debug, Eq, PartialEq, Clone, Copy)]
 pub struct LogFrameHeader {
@@ -24,6 +33,9 @@ pub struct LogFrameHeader {
     version: u32,
     lsn: LSN,
     size: u32,
+    // Codec of the payload, zero when it is stored raw, plus
+    // `LOG_FRAME_FLAG_SEALED`.
+    flags: u8,
     n_part: u32,
 }
 
@@ -34,12 +46,17 @@ pub struct LogFrameTailer {
 }
 
 impl LogFrameHeader {
-    fn new(lsn: LSN, n_part: u32, size: usize) -> Self {
+    fn new(lsn: LSN, n_part: u32, size: usize, compression: Compression, sealed: bool) -> Self {
+        let mut flags = compression.code();
+        if sealed {
+            flags |= LOG_FRAME_FLAG_SEALED;
+        }
         Self {
             magic: LOG_FRAME_MAGIC,
             version: VERSION,
             lsn,
             n_part,
+            flags,
             size: size as u32,
         }
     }
@@ -56,16 +73,26 @@ impl LogFrameHeader {
         self.lsn
     }
 
+    /// Size of the payload as stored, after compression and sealing.
     pub fn size(&self) -> u32 {
         self.size
     }
 
+    pub fn compression(&self) -> RS<Compression> {
+        Compression::from_code(self.flags & LOG_FRAME_CODEC_MASK)
+    }
+
+    pub fn is_sealed(&self) -> bool {
+        self.flags & LOG_FRAME_FLAG_SEALED != 0
+    }
+
     fn encode(&self, out: &mut Vec<u8>) {
         out.extend_from_slice(&self.magic.to_be_bytes());
         out.extend_from_slice(&self.version.to_be_bytes());
         out.extend_from_slice(&self.lsn.as_u64().to_be_bytes());
         out.extend_from_slice(&self.size.to_be_bytes());
-        out.extend_from_slice(&self.n_part.to_be_bytes());
+        out.push(self.flags);
+        out.extend_from_slice(&self.n_part.to_be_bytes()[1..]);
     }
 
     pub(crate) fn decode(input: &[u8]) -> RS<Self> {
@@ -92,13 +119,25 @@ fn peek_header(input: &[u8]) -> RS<LogFrameHeader> {
     let version = u32::from_be_bytes(input[4..8].try_into().unwrap_or([0; 4]));
     let lsn = u64::from_be_bytes(input[8..16].try_into().unwrap_or([0; 8]));
     let size = u32::from_be_bytes(input[16..20].try_into().unwrap_or([0; 4]));
-    let n_part = u32::from_be_bytes(input[20..24].try_into().unwrap_or([0; 4]));
     compat::check_magic(FormatKind::LogFrame, magic)?;
+    // v1 frames have no flags byte: the whole word is the part count.
+    let (flags, n_part) = if version == 1 {
+        (
+            0,
+            u32::from_be_bytes(input[20..24].try_into().unwrap_or([0; 4])),
+        )
+    } else {
+        (
+            input[20],
+            u32::from_be_bytes([0, input[21], input[22], input[23]]),
+        )
+    };
     Ok(LogFrameHeader {
         magic,
         version,
         lsn: lsn.into(),
         size,
+        flags,
         n_part,
     })
 }
@@ -141,29 +180,83 @@ pub fn serialize_entry<L: Serialize>(
     value: &L,
     max_part_size: usize,
     next_lsn: &AtomicU64,
+) -> RS<Vec<Vec<u8>>> {
+    serialize_entry_compressed(
+        value,
+        max_part_size,
+        next_lsn,
+        Compression::None,
+        None,
+        None,
+    )
+}
+
+/// Serializes `value` like [`serialize_entry`], compressing the payload of
+/// every frame with `compression`. A frame whose payload does not shrink is
+/// written raw. `stats`, if given, records the raw and stored payload sizes.
+/// With a `sealer`, every payload is encrypted after compression, with the
+/// frame header as associated data.
+pub fn serialize_entry_compressed<L: Serialize>(
+    value: &L,
+    max_part_size: usize,
+    next_lsn: &AtomicU64,
+    compression: Compression,
+    stats: Option<&CompressionStats>,
+    sealer: Option<&Sealer>,
 ) -> RS<Vec<Vec<u8>>> {
     let payload = rmp_serde::to_vec(value)
         .map_err(|e| mudu_error!(ErrorCode::Encode, "encode log entry to msgpack error", e))?;
-    if max_part_size <= LOG_FRAME_HEADER_SIZE + LOG_FRAME_TAILER_SIZE {
+    let overhead = if sealer.is_some() { SEAL_OVERHEAD } else { 0 };
+    if max_part_size <= LOG_FRAME_HEADER_SIZE + LOG_FRAME_TAILER_SIZE + overhead {
         return Err(mudu_error!(
             ErrorCode::Parse,
             "max_part_size must be larger than header + tailer"
         ));
     }
 
-    let max_payload_size = max_part_size - LOG_FRAME_HEADER_SIZE - LOG_FRAME_TAILER_SIZE;
+    let max_payload_size = max_part_size - LOG_FRAME_HEADER_SIZE - LOG_FRAME_TAILER_SIZE - overhead;
     let total_parts = payload.len().div_ceil(max_payload_size).max(1);
+    if total_parts > LOG_FRAME_MAX_PARTS as usize {
+        return Err(mudu_error!(
+            ErrorCode::Encode,
+            format!(
+                "log entry of {} bytes needs {} frames, more than {}",
+                payload.len(),
+                total_parts,
+                LOG_FRAME_MAX_PARTS
+            )
+        ));
+    }
     let mut result = Vec::with_capacity(total_parts);
     for (index, chunk) in payload.chunks(max_payload_size).enumerate() {
         let remaining = (total_parts - index - 1) as u32;
+        let compressed = compression.compress(chunk)?;
+        let (codec, body) = match &compressed {
+            Some(block) => (compression, block.as_slice()),
+            None => (Compression::None, chunk),
+        };
+        if let Some(stats) = stats {
+            stats.record(chunk.len(), body.len());
+        }
         let lsn: LSN = next_lsn.fetch_add(1, Ordering::SeqCst).into();
-        let header = LogFrameHeader::new(lsn, remaining, chunk.len());
-        let tailer = LogFrameTailer::new(remaining, chunk);
-        let mut frame =
-            Vec::with_capacity(LOG_FRAME_HEADER_SIZE + chunk.len() + LOG_FRAME_TAILER_SIZE);
+        let header = LogFrameHeader::new(
+            lsn,
+            remaining,
+            body.len() + overhead,
+            codec,
+            sealer.is_some(),
+        );
+        let mut frame = Vec::with_capacity(
+            LOG_FRAME_HEADER_SIZE + body.len() + overhead + LOG_FRAME_TAILER_SIZE,
+        );
         header.encode(&mut frame);
-        frame.extend_from_slice(chunk);
-        tailer.encode(&mut frame);
+        let sealed = match sealer {
+            Some(sealer) => Some(sealer.seal(&frame, body)?),
+            None => None,
+        };
+        let body = sealed.as_deref().unwrap_or(body);
+        frame.extend_from_slice(body);
+        LogFrameTailer::new(remaining, body).encode(&mut frame);
         result.push(frame);
     }
 
@@ -171,7 +264,7 @@ pub fn serialize_entry<L: Serialize>(
 }
 
 pub fn frame_lsn(frame: &[u8]) -> RS<LSN> {
-    Ok(split_frame(frame)?.0.lsn())
+    Ok(check_frame(frame)?.lsn())
 }
 
 pub fn frame_lsns(frames: &[Vec<u8>]) -> RS<Vec<LSN>> {
@@ -185,14 +278,31 @@ pub fn last_frame_lsn(frames: &[Vec<u8>]) -> RS<LSN> {
     frame_lsn(frame)
 }
 
-pub fn deserialize_entry<L: DeserializeOwned>(parts: &[Vec<u8>]) -> RS<L> {
-    let payload = deserialize_frames_payload(parts)?;
+/// Decodes the entry of `parts`; sealed frames are opened with `keyring`.
+pub fn deserialize_entry<L: DeserializeOwned>(
+    keyring: Option<&Keyring>,
+    parts: &[Vec<u8>],
+) -> RS<L> {
+    let payload = deserialize_frames_payload(keyring, parts)?;
     rmp_serde::from_slice(&payload)
         .map_err(|e| mudu_error!(ErrorCode::Decode, "decode log entry from msgpack error", e))
 }
 
-pub fn split_frame(frame: &[u8]) -> RS<(LogFrameHeader, Vec<u8>, LogFrameTailer)> {
-    split_frame_exact(frame)
+/// Splits a frame into its header, raw (opened and decompressed) payload and
+/// tailer. A sealed frame is opened with `keyring`.
+pub fn split_frame(
+    keyring: Option<&Keyring>,
+    frame: &[u8],
+) -> RS<(LogFrameHeader, Vec<u8>, LogFrameTailer)> {
+    split_frame_exact(keyring, frame)
+}
+
+/// Checks the length, part count and checksum of a frame without opening or
+/// decompressing its payload, so it also works on sealed frames without a
+/// key.
+pub fn check_frame(frame: &[u8]) -> RS<LogFrameHeader> {
+    let (_, header, _, _) = checked_frame(frame)?;
+    Ok(header)
 }
 
 pub fn frame_len(input: &[u8]) -> RS<usize> {
@@ -216,17 +326,18 @@ pub fn frame_len(input: &[u8]) -> RS<usize> {
 }
 
 pub fn decode_entries_with_pending<L: DeserializeOwned>(
+    keyring: Option<&Keyring>,
     frames: &[Vec<u8>],
     pending_frames: &mut Vec<Vec<u8>>,
     pending_start_lsn: &mut Option<LSN>,
 ) -> RS<Vec<(LSN, L)>> {
     let mut result = Vec::new();
     if pending_start_lsn.is_none() && !pending_frames.is_empty() {
-        let (header, _, _) = split_frame(&pending_frames[0])?;
+        let header = check_frame(&pending_frames[0])?;
         *pending_start_lsn = Some(header.lsn());
     }
     for frame in frames {
-        let (header, _, _) = split_frame(frame)?;
+        let header = check_frame(frame)?;
         if pending_frames.is_empty() {
             *pending_start_lsn = Some(header.lsn());
         }
@@ -235,7 +346,7 @@ pub fn decode_entries_with_pending<L: DeserializeOwned>(
             continue;
         }
 
-        let entry = deserialize_entry(pending_frames)?;
+        let entry = deserialize_entry(keyring, pending_frames)?;
         let start_lsn = pending_start_lsn.take().ok_or_else(|| {
             mudu_error!(
                 ErrorCode::Internal,
@@ -264,7 +375,26 @@ fn ensure_latest_frame(frame: &[u8]) -> RS<Cow<'_, [u8]>> {
     .map_err(|e| e.into_mudu_error())
 }
 
-fn split_frame_exact(frame: &[u8]) -> RS<(LogFrameHeader, Vec<u8>, LogFrameTailer)> {
+fn split_frame_exact(
+    keyring: Option<&Keyring>,
+    frame: &[u8],
+) -> RS<(LogFrameHeader, Vec<u8>, LogFrameTailer)> {
+    let (migrated, header, payload, tailer) = checked_frame(frame)?;
+    let payload = if header.is_sealed() {
+        open_stored_blob(keyring, &migrated[..LOG_FRAME_HEADER_SIZE], &payload)?
+    } else {
+        payload
+    };
+    let payload = match header.compression()? {
+        Compression::None => payload,
+        compression => compression.decompress(&payload)?,
+    };
+    Ok((header, payload, tailer))
+}
+
+type CheckedFrame<'a> = (Cow<'a, [u8]>, LogFrameHeader, Vec<u8>, LogFrameTailer);
+
+fn checked_frame(frame: &[u8]) -> RS<CheckedFrame<'_>> {
     let expected_len = frame_len(frame)?;
     if frame.len() != expected_len {
         return Err(mudu_error!(
@@ -293,10 +423,10 @@ fn split_frame_exact(frame: &[u8]) -> RS<(LogFrameHeader, Vec<u8>, LogFrameTaile
             "log frame payload checksum mismatch"
         ));
     }
-    Ok((header, payload, tailer))
+    Ok((migrated, header, payload, tailer))
 }
 
-fn deserialize_frames_payload(frames: &[Vec<u8>]) -> RS<Vec<u8>> {
+fn deserialize_frames_payload(keyring: Option<&Keyring>, frames: &[Vec<u8>]) -> RS<Vec<u8>> {
     if frames.is_empty() {
         return Err(mudu_error!(ErrorCode::Decode, "log frames are empty"));
     }
@@ -304,7 +434,7 @@ fn deserialize_frames_payload(frames: &[Vec<u8>]) -> RS<Vec<u8>> {
     let mut payload = Vec::new();
     let total_parts = frames.len();
     for (index, frame) in frames.iter().enumerate() {
-        let (header, body, _tailer) = split_frame(frame)?;
+        let (header, body, _tailer) = split_frame(keyring, frame)?;
         let expected_remaining = (total_parts - index - 1) as u32;
         if header.n_part() != expected_remaining {
             return Err(mudu_error!(
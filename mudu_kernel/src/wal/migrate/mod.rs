@@ -2,33 +2,210 @@
 //!
 //! The module lives next to the WAL frame encode/decode implementation so that
 //! the WAL crate owns its own compatibility story.
+//!
+//! Version history:
+//!
+//! - `v1`: header bytes `[20, 24)` hold the remaining part count as a `u32`.
+//! - `v2`: byte 20 holds `flags`, the codec of the payload, and bytes
+//!   `[21, 24)` the remaining part count as a 24-bit integer. `size` and the
+//!   tailer checksum cover the payload as stored. Bit `0x80` of `flags` marks
+//!   a payload sealed with an encryption key; v1 has no way to express it.
+
+use crate::storage::compression::Compression;
+use crate::wal::format::latest::{
+    LOG_FRAME_FLAG_SEALED, LOG_FRAME_HEADER_SIZE, LOG_FRAME_MAX_PARTS, LOG_FRAME_TAILER_SIZE,
+};
+use mudu::common::crc::calc_crc;
+use mudu::compat::FormatKind;
+use mudu_compat_migrate::handler::{MigrateHandler, MigrateOption};
+use mudu_compat_migrate::MigrateError;
 
-use mudu_compat_migrate::handler::{clone_rollback, clone_upgrade, MigrateHandler};
+const OFF_VERSION: usize = 4;
+const OFF_SIZE: usize = 16;
+const OFF_FLAGS: usize = 20;
 
-/// Returns a placeholder identity handler that clones the input binary unchanged.
-pub fn identity() -> MigrateHandler {
+/// Returns the `v1 -> v2` handler.
+///
+/// A v1 frame with fewer than 2^24 remaining parts already has a zero flags
+/// byte, so the upgrade only rewrites the version. The rollback expands a
+/// compressed payload back into a raw one and refuses sealed frames.
+pub fn v1_to_v2() -> MigrateHandler {
     MigrateHandler {
         from: 1,
-        to: 1,
-        upgrade: clone_upgrade,
-        rollback: clone_rollback,
+        to: 2,
+        upgrade: upgrade_v1_to_v2,
+        rollback: rollback_v2_to_v1,
+    }
+}
+
+fn failed(from: u32, to: u32, source: String) -> MigrateError {
+    MigrateError::MigrationFailed {
+        component: FormatKind::LogFrame,
+        from,
+        to,
+        step: 0,
+        source,
+    }
+}
+
+fn check_len(frame: &[u8], from: u32, to: u32) -> Result<(), MigrateError> {
+    if frame.len() < LOG_FRAME_HEADER_SIZE + LOG_FRAME_TAILER_SIZE {
+        return Err(failed(
+            from,
+            to,
+            format!("log frame of {} bytes is truncated", frame.len()),
+        ));
+    }
+    Ok(())
+}
+
+fn upgrade_v1_to_v2(old: &[u8], _option: Option<&MigrateOption>) -> Result<Vec<u8>, MigrateError> {
+    check_len(old, 1, 2)?;
+    let n_part = u32::from_be_bytes([old[20], old[21], old[22], old[23]]);
+    if n_part >= LOG_FRAME_MAX_PARTS {
+        return Err(failed(
+            1,
+            2,
+            format!("log frame has {} remaining parts", n_part),
+        ));
+    }
+    let mut frame = old.to_vec();
+    frame[OFF_VERSION..OFF_VERSION + 4].copy_from_slice(&2u32.to_be_bytes());
+    Ok(frame)
+}
+
+fn rollback_v2_to_v1(new: &[u8], _option: Option<&MigrateOption>) -> Result<Vec<u8>, MigrateError> {
+    check_len(new, 2, 1)?;
+    if new[OFF_FLAGS] & LOG_FRAME_FLAG_SEALED != 0 {
+        return Err(failed(
+            2,
+            1,
+            "log frame is sealed with an encryption key".to_string(),
+        ));
+    }
+    let compression =
+        Compression::from_code(new[OFF_FLAGS]).map_err(|e| failed(2, 1, e.to_string()))?;
+    let mut frame = new.to_vec();
+    frame[OFF_VERSION..OFF_VERSION + 4].copy_from_slice(&1u32.to_be_bytes());
+    frame[OFF_FLAGS] = 0;
+    if compression == Compression::None {
+        return Ok(frame);
+    }
+
+    let size = u32::from_be_bytes([new[16], new[17], new[18], new[19]]) as usize;
+    let payload_end = LOG_FRAME_HEADER_SIZE + size;
+    if new.len() != payload_end + LOG_FRAME_TAILER_SIZE {
+        return Err(failed(
+            2,
+            1,
+            format!("log frame of {} bytes has payload size {}", new.len(), size),
+        ));
     }
+    let raw = compression
+        .decompress(&new[LOG_FRAME_HEADER_SIZE..payload_end])
+        .map_err(|e| failed(2, 1, e.to_string()))?;
+    let tailer_n_part = &new[payload_end..payload_end + 4];
+    frame.truncate(LOG_FRAME_HEADER_SIZE);
+    frame[OFF_SIZE..OFF_SIZE + 4].copy_from_slice(&(raw.len() as u32).to_be_bytes());
+    frame.extend_from_slice(&raw);
+    frame.extend_from_slice(tailer_n_part);
+    frame.extend_from_slice(&(calc_crc(&raw) as u32).to_be_bytes());
+    Ok(frame)
 }
 
 #[cfg(test)]
 mod tests {
+    #![allow(
+        clippy::unwrap_used,
+        clippy::expect_used,
+        clippy::panic,
+        clippy::todo,
+        clippy::unimplemented
+    )]
+
     use super::*;
-    use mudu::compat::FormatKind;
-    use mudu_compat_migrate::{CompatibilityRouter, MigrateError, NoopOptionProvider};
+    use crate::storage::encryption::{Keyring, Sealer};
+    use crate::wal::format::latest::{serialize_entry, serialize_entry_compressed, split_frame};
+    use mudu_compat_migrate::{CompatibilityRouter, NoopOptionProvider};
+    use std::sync::atomic::AtomicU64;
+    use std::sync::Arc;
 
-    #[test]
-    fn identity_handler_clones_input() -> Result<(), MigrateError> {
+    fn router() -> CompatibilityRouter {
         let mut router = CompatibilityRouter::new();
-        router.set_supported_window(FormatKind::LogFrame, 1, 1);
-        router.register(FormatKind::LogFrame, identity());
-        let input = vec![0xABu8, 0xCD, 0xEF];
-        let output = router.migrate(FormatKind::LogFrame, 1, 1, &input, &NoopOptionProvider)?;
-        assert_eq!(output, input);
+        router.set_supported_window(FormatKind::LogFrame, 1, 2);
+        router.register(FormatKind::LogFrame, v1_to_v2());
+        router
+    }
+
+    fn entry() -> Vec<String> {
+        vec!["compressible log entry".to_string(); 32]
+    }
+
+    #[test]
+    fn raw_frames_only_change_version() -> Result<(), MigrateError> {
+        let frame = serialize_entry(&entry(), 4096, &AtomicU64::new(1))
+            .unwrap()
+            .remove(0);
+        let v1 = router().migrate(FormatKind::LogFrame, 2, 1, &frame, &NoopOptionProvider)?;
+        assert_eq!(&v1[OFF_VERSION..OFF_VERSION + 4], &1u32.to_be_bytes());
+        assert_eq!(v1[LOG_FRAME_HEADER_SIZE..], frame[LOG_FRAME_HEADER_SIZE..]);
+        let v2 = router().migrate(FormatKind::LogFrame, 1, 2, &v1, &NoopOptionProvider)?;
+        assert_eq!(v2, frame);
         Ok(())
     }
+
+    #[test]
+    fn rollback_expands_compressed_frames() -> Result<(), MigrateError> {
+        let next_lsn = AtomicU64::new(1);
+        let frame =
+            serialize_entry_compressed(&entry(), 4096, &next_lsn, Compression::Lz4, None, None)
+                .unwrap()
+                .remove(0);
+        assert_eq!(frame[OFF_FLAGS], Compression::Lz4.code());
+        let v1 = router().migrate(FormatKind::LogFrame, 2, 1, &frame, &NoopOptionProvider)?;
+        assert!(v1.len() > frame.len());
+        let v2 = router().migrate(FormatKind::LogFrame, 1, 2, &v1, &NoopOptionProvider)?;
+        assert_eq!(
+            split_frame(None, &v2).unwrap().1,
+            split_frame(None, &frame).unwrap().1
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn rollback_rejects_sealed_frames() {
+        let keyring = Arc::new(Keyring::parse(&format!("1 {}", "ab".repeat(32))).unwrap());
+        let sealer = Sealer::new(keyring).unwrap();
+        let frame = serialize_entry_compressed(
+            &entry(),
+            4096,
+            &AtomicU64::new(1),
+            Compression::Lz4,
+            None,
+            Some(&sealer),
+        )
+        .unwrap()
+        .remove(0);
+        assert_eq!(
+            frame[OFF_FLAGS],
+            Compression::Lz4.code() | LOG_FRAME_FLAG_SEALED
+        );
+        let err = router()
+            .migrate(FormatKind::LogFrame, 2, 1, &frame, &NoopOptionProvider)
+            .unwrap_err();
+        assert!(matches!(err, MigrateError::MigrationFailed { .. }));
+    }
+
+    #[test]
+    fn upgrade_rejects_part_counts_beyond_24_bits() {
+        let mut frame = serialize_entry(&1u64, 256, &AtomicU64::new(1))
+            .unwrap()
+            .remove(0);
+        frame[OFF_VERSION..OFF_VERSION + 4].copy_from_slice(&1u32.to_be_bytes());
+        frame[20..24].copy_from_slice(&LOG_FRAME_MAX_PARTS.to_be_bytes());
+        let err = router()
+            .migrate(FormatKind::LogFrame, 1, 2, &frame, &NoopOptionProvider)
+            .unwrap_err();
+        assert!(matches!(err, MigrateError::MigrationFailed { .. }));
+    }
 }
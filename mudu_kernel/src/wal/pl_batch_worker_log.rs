@@ -50,10 +50,12 @@ pub fn serialize_pl_batch(
     serialize_entry(batch, max_part_size, next_lsn)
 }
 
+/// Decodes an entry of a log written without an encryption keyring.
 pub fn deserialize_pl_batch(parts: &[Vec<u8>]) -> RS<PLBatch> {
-    deserialize_entry(parts)
+    deserialize_entry(None, parts)
 }
 
+/// Decodes the batches of a log written without an encryption keyring.
 pub fn decode_pl_batches(frames: &[Vec<u8>]) -> RS<Vec<PLBatch>> {
     let mut pending = Vec::new();
     let mut pending_start_lsn = None;
@@ -67,13 +69,17 @@ pub fn decode_pl_batches(frames: &[Vec<u8>]) -> RS<Vec<PLBatch>> {
     Ok(batches)
 }
 
+/// Decodes the batches of a log written without an encryption keyring,
+/// carrying a partial batch over to the next call.
 pub fn decode_pl_batches_with_pending(
     frames: &[Vec<u8>],
     pending: &mut Vec<Vec<u8>>,
     pending_start_lsn: &mut Option<LSN>,
 ) -> RS<Vec<PLBatch>> {
     let mut out: Vec<PLBatch> = Vec::new();
-    for (_, batch) in decode_entries_with_pending::<PLBatch>(frames, pending, pending_start_lsn)? {
+    for (_, batch) in
+        decode_entries_with_pending::<PLBatch>(None, frames, pending, pending_start_lsn)?
+    {
         out.push(batch);
     }
     Ok(out)
@@ -126,7 +132,7 @@ mod tests {
         let lsns = frame_lsns(&parts).unwrap();
         assert_eq!(parts.len(), 1);
         assert_eq!(lsns, vec![0]);
-        let (header, payload, tailer) = split_frame(&parts[0]).unwrap();
+        let (header, payload, tailer) = split_frame(None, &parts[0]).unwrap();
         assert_eq!(header.lsn(), 0);
         assert_eq!(header.n_part(), 0);
         assert_eq!(tailer.n_part(), 0);
@@ -144,7 +150,7 @@ mod tests {
         assert_eq!(lsns.len(), parts.len());
         for (index, part) in parts.iter().enumerate() {
             assert!(part.len() <= 180);
-            let (header, _, tailer) = split_frame(part).unwrap();
+            let (header, _, tailer) = split_frame(None, part).unwrap();
             let expected = (parts.len() - index - 1) as u32;
             assert_eq!(header.lsn(), lsns[index]);
             assert_eq!(header.n_part(), expected);
@@ -1,8 +1,8 @@
 use crate::wal::log_frame::decode_entries_with_pending;
 use crate::wal::lsn::LSN;
 use crate::wal::worker_log::{
-    decode_frames_allow_trailing, AsyncWorkerLogRecoverySource, WorkerLogBackend,
-    WorkerLogRecoverySource,
+    decode_frames_allow_trailing, retain_frames_from, AsyncWorkerLogRecoverySource,
+    WorkerLogBackend, WorkerLogRecoverySource,
 };
 use async_trait::async_trait;
 use mudu::common::result::RS;
@@ -104,6 +104,15 @@ where
     }
 
     pub async fn recover<S>(&self, source: &mut S) -> RS<()>
+    where
+        S: WorkerLogRecoverySource,
+    {
+        self.recover_from(source, LSN::new(0)).await
+    }
+
+    /// Replays the entries starting at `start_lsn`, skipping the older ones
+    /// a checkpoint already covers.
+    pub async fn recover_from<S>(&self, source: &mut S, start_lsn: LSN) -> RS<()>
     where
         S: WorkerLogRecoverySource,
     {
@@ -115,8 +124,10 @@ where
             if bytes.is_empty() {
                 continue;
             }
-            let frames = decode_frames_allow_trailing(&bytes)?;
+            let mut frames = decode_frames_allow_trailing(&bytes)?;
+            retain_frames_from(&mut frames, start_lsn)?;
             let entries = decode_entries_with_pending::<L>(
+                self.backend.keyring(),
                 &frames,
                 &mut pending_frames,
                 &mut pending_start_lsn,
@@ -150,6 +161,7 @@ where
             }
             let frames = decode_frames_allow_trailing(&bytes)?;
             let entries = decode_entries_with_pending::<L>(
+                self.backend.keyring(),
                 &frames,
                 &mut pending_frames,
                 &mut pending_start_lsn,
@@ -185,6 +197,7 @@ where
             }
             let frames = decode_frames_allow_trailing(&bytes)?;
             let entries = decode_entries_with_pending::<L>(
+                self.backend.keyring(),
                 &frames,
                 &mut pending_frames,
                 &mut pending_start_lsn,
@@ -1,6 +1,9 @@
-use crate::wal::log_frame::{frame_len, split_frame};
+use crate::storage::encryption::Keyring;
+use crate::wal::log_frame::{check_frame, frame_len, frame_lsn};
+use crate::wal::lsn::LSN;
 pub use crate::wal::worker_wal_backend::{
-    WorkerLogBatching, WorkerLogLayout, WorkerLogTail, WorkerWALBackend as ChunkedWorkerLogBackend,
+    WorkerLogBatching, WorkerLogLayout, WorkerLogPosition, WorkerLogTail,
+    WorkerWALBackend as ChunkedWorkerLogBackend,
 };
 use async_trait::async_trait;
 use mudu::common::result::RS;
@@ -17,6 +20,10 @@ pub trait WorkerLogBackend: Clone + Send + Sync {
 
     fn flush(&self) -> RS<()>;
     async fn flush_async(&self) -> RS<()>;
+
+    /// Opens the sealed frames of this log; `None` when it is written
+    /// without an encryption keyring.
+    fn keyring(&self) -> Option<&Keyring>;
 }
 
 #[async_trait]
@@ -38,7 +45,7 @@ pub fn decode_frames(payload: &[u8]) -> RS<Vec<Vec<u8>>> {
         let remaining = &payload[offset..];
         let next_frame_len = frame_len(remaining)?;
         let frame = &remaining[..next_frame_len];
-        split_frame(frame)?;
+        check_frame(frame)?;
         frames.push(frame.to_vec());
         offset += next_frame_len;
     }
@@ -62,9 +69,24 @@ pub fn decode_frames_allow_trailing(payload: &[u8]) -> RS<Vec<Vec<u8>>> {
             Err(e) => return Err(e),
         };
         let frame = &remaining[..next_frame_len];
-        split_frame(frame)?;
+        check_frame(frame)?;
         frames.push(frame.to_vec());
         offset += next_frame_len;
     }
     Ok(frames)
 }
+
+/// Drops the frames a checkpoint already covers.
+///
+/// The checkpoint LSN is an entry boundary, so the frames of one entry are
+/// either all kept or all dropped.
+pub fn retain_frames_from(frames: &mut Vec<Vec<u8>>, start_lsn: LSN) -> RS<()> {
+    let mut kept = Vec::with_capacity(frames.len());
+    for frame in frames.drain(..) {
+        if frame_lsn(&frame)? >= start_lsn {
+            kept.push(frame);
+        }
+    }
+    *frames = kept;
+    Ok(())
+}
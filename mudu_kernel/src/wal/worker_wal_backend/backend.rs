@@ -1,4 +1,6 @@
-use crate::wal::log_frame::serialize_entry;
+use crate::storage::compression::{Compression, CompressionStats, CompressionStatsSnapshot};
+use crate::storage::encryption::{Keyring, Sealer};
+use crate::wal::log_frame::serialize_entry_compressed;
 use crate::wal::worker_log::WorkerLogBackend;
 use async_trait::async_trait;
 use mudu::common::result::RS;
@@ -16,8 +18,9 @@ use std::sync::Arc;
 
 use super::batching::WorkerLogBatching;
 use super::flush::{EffectiveBatching, QueuedLogBatch, WaitLsn};
-use super::layout::{WorkerLogLayout, WorkerLogTail};
+use super::layout::{copy_chunk_if_changed, WorkerLogLayout, WorkerLogPosition, WorkerLogTail};
 use super::state::{AppendReservation, ChunkedWorkerLog};
+use crate::wal::lsn::LSN;
 
 pub(crate) type FlushTask =
     Option<std::pin::Pin<Box<dyn std::future::Future<Output = RS<()>> + Send>>>;
@@ -35,6 +38,10 @@ pub(crate) struct WorkerLogInner {
     pub(crate) io: Arc<dyn AsyncIoProvider>,
     pub(crate) log_queue: SMutex<Vec<QueuedLogBatch>>,
     pub(crate) batching: WorkerLogBatching,
+    pub(crate) compression: Compression,
+    pub(crate) compression_stats: CompressionStats,
+    // Seals frame payloads; `None` without an encryption keyring.
+    pub(crate) sealer: Option<Sealer>,
 
     pub(crate) active_sessions: Arc<AtomicUsize>,
     // next log sequence
@@ -125,6 +132,103 @@ impl WorkerWALBackend {
         Ok(queue.is_empty())
     }
 
+    /// Returns the current end of the log.
+    pub fn position(&self) -> RS<WorkerLogPosition> {
+        // `serialize_entry` assigns LSNs under the state lock, so no frame
+        // can be serialized between reading the LSN and the chunk sequence.
+        let guard = self
+            .inner
+            .state
+            .lock()
+            .map_err(|_| mudu_error!(ErrorCode::Internal, "worker kv log lock poisoned"))?;
+        Ok(WorkerLogPosition {
+            lsn: LSN::new(self.inner.next_lsn.load(Ordering::SeqCst)),
+            chunk_sequence: guard.write_sequence(),
+        })
+    }
+
+    /// Bytes appended to the log since it was opened.
+    pub fn appended_bytes(&self) -> RS<u64> {
+        let guard = self
+            .inner
+            .state
+            .lock()
+            .map_err(|_| mudu_error!(ErrorCode::Internal, "worker kv log lock poisoned"))?;
+        Ok(guard.appended_bytes())
+    }
+
+    /// Frame payload bytes before and after compression since the log was
+    /// opened.
+    pub fn compression_stats(&self) -> CompressionStatsSnapshot {
+        self.inner.compression_stats.snapshot()
+    }
+
+    /// Makes the next frame start at `lsn` or later.
+    ///
+    /// Only valid before the first append: a checkpoint may have removed the
+    /// chunks holding the highest LSNs, so the LSN scanned from the remaining
+    /// chunks can be behind the checkpoint.
+    pub fn skip_to_lsn(&self, lsn: LSN) {
+        self.inner.next_lsn.fetch_max(lsn.into(), Ordering::SeqCst);
+        self.inner.flush_waiter.skip_to(lsn);
+    }
+
+    /// Copies the chunks below `sequence` to the archive directory,
+    /// returning how many were copied; chunks archived before are skipped.
+    ///
+    /// Only chunks the log no longer appends to may be archived. Does
+    /// nothing when the layout has no archive directory.
+    pub async fn archive_chunks_before(&self, sequence: u64) -> RS<usize> {
+        let layout = self.layout()?;
+        let Some(archive_dir) = layout.archive_dir() else {
+            return Ok(0);
+        };
+        let fs = self.inner.io.fs();
+        fs.create_dir_all(archive_dir).await?;
+        let mut archived = 0;
+        for path in layout.chunk_paths_sorted_async(fs).await? {
+            if layout
+                .parse_chunk_sequence(&path)
+                .is_none_or(|chunk_sequence| chunk_sequence >= sequence)
+            {
+                break;
+            }
+            let Some(file_name) = path.file_name() else {
+                continue;
+            };
+            if copy_chunk_if_changed(fs, &path, &archive_dir.join(file_name)).await? {
+                archived += 1;
+            }
+        }
+        Ok(archived)
+    }
+
+    /// Removes the chunks below `sequence`, returning how many were removed.
+    ///
+    /// The newest chunk always stays, it marks where the log continues. In
+    /// archive mode the chunks are archived before they are removed.
+    pub async fn remove_chunks_before(&self, sequence: u64) -> RS<usize> {
+        self.archive_chunks_before(sequence).await?;
+        let layout = self.layout()?;
+        let fs = self.inner.io.fs();
+        let paths = layout.chunk_paths_sorted_async(fs).await?;
+        let Some((_, newer)) = paths.split_last() else {
+            return Ok(0);
+        };
+        let mut removed = 0;
+        for path in newer {
+            if layout
+                .parse_chunk_sequence(path)
+                .is_none_or(|chunk_sequence| chunk_sequence >= sequence)
+            {
+                break;
+            }
+            fs.remove_file_if_exists(path).await?;
+            removed += 1;
+        }
+        Ok(removed)
+    }
+
     pub async fn new(layout: WorkerLogLayout) -> RS<Self> {
         Self::new_with_sys_io_context(layout, default_sys_io_context()).await
     }
@@ -188,6 +292,9 @@ impl WorkerWALBackend {
                 io,
                 log_queue: SMutex::new(Default::default()),
                 batching: layout.batching(),
+                compression: layout.compression(),
+                compression_stats: CompressionStats::default(),
+                sealer: Sealer::for_keyring(layout.keyring())?,
                 active_sessions,
                 next_lsn: AtomicU64::new(tail.next_lsn.into()),
                 flush_waiter: WaitLsn::new(tail.next_lsn, vec![], Some(layout.log_oid)),
@@ -287,7 +394,18 @@ impl WorkerLogBackend for WorkerWALBackend {
             .state
             .lock()
             .map_err(|_| mudu_error!(ErrorCode::Internal, "worker kv log lock poisoned"))?;
-        serialize_entry(entry, guard.layout.frame_size_limit(), &self.inner.next_lsn)
+        serialize_entry_compressed(
+            entry,
+            guard.layout.frame_size_limit(),
+            &self.inner.next_lsn,
+            self.inner.compression,
+            Some(&self.inner.compression_stats),
+            self.inner.sealer.as_ref(),
+        )
+    }
+
+    fn keyring(&self) -> Option<&Keyring> {
+        self.inner.sealer.as_ref().map(Sealer::keyring)
     }
 
     async fn chunk_paths_sorted(&self) -> RS<Vec<PathBuf>> {
@@ -70,6 +70,12 @@ impl WaitLsn {
         }
     }
 
+    /// Moves the wait position forward to `lsn` on a log nothing has been
+    /// appended to yet.
+    pub(crate) fn skip_to(&self, lsn: LSN) {
+        self.next_wait_lsn.fetch_max(lsn.into(), Ordering::AcqRel);
+    }
+
     pub(crate) fn ready(&self, lsns: Vec<LSN>) -> RS<()> {
         if lsns.is_empty() {
             return Ok(());
@@ -600,6 +606,9 @@ mod tests {
                 io: default_sys_io_context().provider_arc(),
                 log_queue: SMutex::new(queue),
                 batching: layout.batching(),
+                compression: layout.compression(),
+                compression_stats: Default::default(),
+                sealer: None,
                 active_sessions: Arc::new(AtomicUsize::new(0)),
                 next_lsn: AtomicU64::new(0),
                 flush_waiter: WaitLsn::new(LSN::new(0), vec![], Some(layout.log_oid)),
@@ -1,13 +1,18 @@
-use crate::wal::log_frame::frame_len;
+use crate::storage::backup::write_synced;
+use crate::storage::compression::Compression;
+use crate::storage::encryption::Keyring;
+use crate::wal::log_frame::{frame_len, LogFrameHeader, LOG_FRAME_HEADER_SIZE};
 use crate::wal::lsn::LSN;
 use mudu::common::id::OID;
 use mudu::common::result::RS;
 use mudu::error::ErrorCode;
 use mudu::mudu_error;
 use mudu_sys::contract::async_fs::AsyncFs;
+use mudu_sys::contract::file_options::FileOptions;
 use mudu_sys::fs::async_ as fs;
 use short_uuid::ShortUuid;
 use std::path::{Path, PathBuf};
+use std::sync::Arc;
 use tracing::debug;
 use uuid::Uuid;
 
@@ -21,6 +26,14 @@ pub struct WorkerLogTail {
     pub next_lsn: LSN,
 }
 
+/// A point in the log: frames serialized after it was taken carry an LSN of
+/// at least `lsn` and are written to chunk `chunk_sequence` or a later one.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct WorkerLogPosition {
+    pub lsn: LSN,
+    pub chunk_sequence: u64,
+}
+
 #[derive(Clone, Debug)]
 pub struct WorkerLogLayout {
     log_dir: PathBuf,
@@ -28,6 +41,9 @@ pub struct WorkerLogLayout {
     chunk_size: u64,
     pub(crate) short_oid: String,
     batching: WorkerLogBatching,
+    compression: Compression,
+    keyring: Option<Arc<Keyring>>,
+    archive_dir: Option<PathBuf>,
 }
 
 impl Default for WorkerLogLayout {
@@ -44,6 +60,9 @@ impl WorkerLogLayout {
             chunk_size,
             short_oid: ShortUuid::from_uuid(&Uuid::from_u128(log_oid)).to_string(),
             batching: WorkerLogBatching::default(),
+            compression: Compression::None,
+            keyring: None,
+            archive_dir: None,
         }
     }
 
@@ -66,6 +85,35 @@ impl WorkerLogLayout {
         self
     }
 
+    /// Compresses the payload of every frame written from now on; frames
+    /// already in the log keep their codec.
+    pub fn with_compression(mut self, compression: Compression) -> Self {
+        self.compression = compression;
+        self
+    }
+
+    /// Seals the payload of every frame written from now on with the
+    /// keyring's current key.
+    pub fn with_keyring(mut self, keyring: Option<Arc<Keyring>>) -> Self {
+        self.keyring = keyring;
+        self
+    }
+
+    /// Copies every completed chunk to `archive_dir` before it may be
+    /// removed, so the log can be replayed past the newest checkpoint.
+    pub fn with_archive_dir<P: Into<PathBuf>>(mut self, archive_dir: P) -> Self {
+        self.archive_dir = Some(archive_dir.into());
+        self
+    }
+
+    pub fn log_dir(&self) -> &Path {
+        &self.log_dir
+    }
+
+    pub fn archive_dir(&self) -> Option<&Path> {
+        self.archive_dir.as_deref()
+    }
+
     pub fn log_oid(&self) -> OID {
         self.log_oid
     }
@@ -87,6 +135,14 @@ impl WorkerLogLayout {
         self.batching
     }
 
+    pub fn compression(&self) -> Compression {
+        self.compression
+    }
+
+    pub fn keyring(&self) -> Option<&Arc<Keyring>> {
+        self.keyring.as_ref()
+    }
+
     pub async fn scan_tail(&self) -> RS<WorkerLogTail> {
         fs::create_dir_all(&self.log_dir).await?;
         let mut max_sequence: Option<u64> = None;
@@ -144,7 +200,7 @@ impl WorkerLogLayout {
         Ok(entries.into_iter().map(|(_, path)| path).collect())
     }
 
-    fn parse_chunk_sequence(&self, path: &Path) -> Option<u64> {
+    pub(crate) fn parse_chunk_sequence(&self, path: &Path) -> Option<u64> {
         let file_name = path.file_name()?.to_str()?;
         let prefix = format!("{}.", self.short_oid);
         let suffix = ".xl";
@@ -237,19 +293,170 @@ impl WorkerLogLayout {
     async fn chunk_sequences_async(&self, fs: &dyn AsyncFs) -> RS<Vec<u64>> {
         let trace = mudu_utils::task_trace!();
         trace.watch("wal.layout.stage", "chunk_sequences_start");
+        // Checkpoints remove the oldest chunks, so the remaining sequences
+        // neither start at zero nor have to be contiguous.
         let mut sequences = Vec::new();
-        let mut sequence = 0u64;
-        loop {
-            trace.watch("wal.layout.sequence_probe", &sequence.to_string());
-            let path = self.chunk_path(sequence);
-            if !fs.path_exists(&path).await? {
-                trace.watch("wal.layout.stage", "chunk_sequences_done");
-                break;
+        for path in fs.read_dir(&self.log_dir).await? {
+            if let Some(sequence) = self.parse_chunk_sequence(path.as_path()) {
+                sequences.push(sequence);
             }
-            sequences.push(sequence);
-            sequence = sequence.saturating_add(1);
         }
+        sequences.sort_unstable();
         trace.watch("wal.layout.sequences", &sequences.len().to_string());
         Ok(sequences)
     }
+
+    /// Lists the archived chunks of this log, oldest first; empty without an
+    /// archive directory.
+    pub async fn archived_chunk_paths_sorted_async(&self, fs: &dyn AsyncFs) -> RS<Vec<PathBuf>> {
+        let Some(archive_dir) = self.archive_dir.as_deref() else {
+            return Ok(Vec::new());
+        };
+        if !fs.path_exists(archive_dir).await? {
+            return Ok(Vec::new());
+        }
+        let mut entries = Vec::<(u64, PathBuf)>::new();
+        for path in fs.read_dir(archive_dir).await? {
+            if let Some(sequence) = self.parse_chunk_sequence(path.as_path()) {
+                entries.push((sequence, path));
+            }
+        }
+        entries.sort_by_key(|(sequence, _)| *sequence);
+        Ok(entries.into_iter().map(|(_, path)| path).collect())
+    }
+
+    /// Copies the archived chunks that are missing from the log directory, or
+    /// shorter there, back into it, returning how many were copied.
+    ///
+    /// Runs before the log is opened, so the tail scan continues behind the
+    /// archived chunks and recovery replays them.
+    pub async fn restore_archived_chunks_async(&self, fs: &dyn AsyncFs) -> RS<usize> {
+        fs.create_dir_all(&self.log_dir).await?;
+        let mut restored = 0;
+        for archived in self.archived_chunk_paths_sorted_async(fs).await? {
+            let Some(file_name) = archived.file_name() else {
+                continue;
+            };
+            if copy_chunk_if_changed(fs, &archived, &self.log_dir.join(file_name)).await? {
+                restored += 1;
+            }
+        }
+        Ok(restored)
+    }
+
+    /// Returns the sequence of the chunk holding `lsn`, the last chunk whose
+    /// first frame is at `lsn` or before; `None` when `lsn` lies before every
+    /// chunk.
+    pub async fn chunk_sequence_for_lsn_async(
+        &self,
+        fs: &dyn AsyncFs,
+        lsn: LSN,
+    ) -> RS<Option<u64>> {
+        let mut found = None;
+        for path in self.chunk_paths_sorted_async(fs).await? {
+            let Some(first_lsn) = chunk_first_lsn(fs, &path).await? else {
+                continue;
+            };
+            if first_lsn > lsn {
+                break;
+            }
+            found = self.parse_chunk_sequence(&path);
+        }
+        Ok(found)
+    }
+
+    /// Returns the chunks holding the frames from `lsn` on, with the LSN of
+    /// their first frame. Fails when the chunk holding `lsn` was removed.
+    pub(crate) async fn chunks_from_lsn_async(
+        &self,
+        fs: &dyn AsyncFs,
+        lsn: LSN,
+    ) -> RS<Vec<(LSN, PathBuf)>> {
+        let mut chunks = Vec::new();
+        for path in self.chunk_paths_sorted_async(fs).await? {
+            if let Some(first_lsn) = chunk_first_lsn(fs, &path).await? {
+                chunks.push((first_lsn, path));
+            }
+        }
+        if let Some((first_lsn, _)) = chunks.first() {
+            if *first_lsn > lsn {
+                return Err(mudu_error!(
+                    ErrorCode::InvalidArgument,
+                    format!(
+                        "worker {} no longer has its log from lsn {}, it starts at lsn {}",
+                        self.log_oid,
+                        lsn.as_u64(),
+                        first_lsn.as_u64()
+                    )
+                ));
+            }
+        }
+        let start = chunks
+            .iter()
+            .rposition(|(first_lsn, _)| *first_lsn <= lsn)
+            .unwrap_or(0);
+        chunks.drain(..start);
+        Ok(chunks)
+    }
+
+    /// Marks that recovery reached its target and sealed the log with a
+    /// checkpoint; a restart afterwards recovers normally.
+    pub fn recovery_target_marker_path(&self) -> PathBuf {
+        self.log_dir.join(format!("{}.pitr", self.short_oid))
+    }
+
+    /// Holds the positions of the change data capture consumers in this log.
+    pub fn cdc_positions_path(&self) -> PathBuf {
+        self.log_dir.join(format!("{}.cdc", self.short_oid))
+    }
+
+    pub fn checkpoint_path(&self, lsn: LSN) -> PathBuf {
+        self.log_dir
+            .join(format!("{}.{}.ckpt", self.short_oid, lsn.as_u64()))
+    }
+
+    /// Lists the checkpoint files of this log, oldest first.
+    pub async fn checkpoint_paths_sorted_async(&self, fs: &dyn AsyncFs) -> RS<Vec<(LSN, PathBuf)>> {
+        fs.create_dir_all(&self.log_dir).await?;
+        let mut entries = Vec::new();
+        for path in fs.read_dir(&self.log_dir).await? {
+            if let Some(lsn) = self.parse_checkpoint_lsn(path.as_path()) {
+                entries.push((LSN::new(lsn), path));
+            }
+        }
+        entries.sort_by_key(|(lsn, _)| *lsn);
+        Ok(entries)
+    }
+
+    fn parse_checkpoint_lsn(&self, path: &Path) -> Option<u64> {
+        let file_name = path.file_name()?.to_str()?;
+        let lsn = file_name
+            .strip_prefix(&self.short_oid)?
+            .strip_prefix('.')?
+            .strip_suffix(".ckpt")?;
+        lsn.parse::<u64>().ok()
+    }
+}
+
+/// Copies the chunk `from` to `to` unless `to` already holds a chunk of the
+/// same length. Chunks only grow, so a length match means they are equal.
+pub(crate) async fn copy_chunk_if_changed(fs: &dyn AsyncFs, from: &Path, to: &Path) -> RS<bool> {
+    let len = fs.metadata_len(from).await?;
+    if fs.path_exists(to).await? && fs.metadata_len(to).await? == len {
+        return Ok(false);
+    }
+    let bytes = fs.read_all(from).await?;
+    write_synced(fs, to, &bytes).await?;
+    Ok(true)
+}
+
+/// Returns the LSN of the first frame in the chunk at `path`; `None` while
+/// the chunk holds no complete frame header.
+pub(crate) async fn chunk_first_lsn(fs: &dyn AsyncFs, path: &Path) -> RS<Option<LSN>> {
+    let file = fs.open(path, FileOptions::read_only()).await?;
+    if file.file_len().await? < LOG_FRAME_HEADER_SIZE as u64 {
+        return Ok(None);
+    }
+    let header = file.read_exact_at(0, LOG_FRAME_HEADER_SIZE).await?;
+    Ok(Some(LogFrameHeader::decode(&header)?.lsn()))
 }
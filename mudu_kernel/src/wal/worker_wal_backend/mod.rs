@@ -7,7 +7,7 @@ mod state;
 
 pub use backend::WorkerWALBackend;
 pub use batching::WorkerLogBatching;
-pub use layout::{WorkerLogLayout, WorkerLogTail};
+pub use layout::{WorkerLogLayout, WorkerLogPosition, WorkerLogTail};
 
 #[cfg(test)]
 #[allow(clippy::unwrap_used, clippy::expect_used, clippy::panic)]
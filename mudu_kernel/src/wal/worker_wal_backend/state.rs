@@ -11,6 +11,8 @@ pub(crate) struct ChunkedWorkerLog {
     current_file: Option<(PathBuf, SysFile)>,
     // next chunk sequence
     next_sequence: u64,
+    // bytes reserved since the log was opened
+    appended_bytes: u64,
 }
 
 pub(crate) struct AppendReservation {
@@ -27,6 +29,7 @@ impl ChunkedWorkerLog {
             current_size: tail.current_size,
             current_file: None,
             next_sequence: tail.next_sequence,
+            appended_bytes: 0,
         })
     }
 
@@ -41,6 +44,7 @@ impl ChunkedWorkerLog {
             });
         }
 
+        self.appended_bytes += payload_len;
         if payload_len > self.layout.chunk_size() {
             let sequence = self.next_sequence;
             self.next_sequence += 1;
@@ -76,6 +80,15 @@ impl ChunkedWorkerLog {
         })
     }
 
+    /// Sequence of the first chunk a frame reserved from now on can land in.
+    pub(crate) fn write_sequence(&self) -> u64 {
+        self.current_sequence.unwrap_or(self.next_sequence)
+    }
+
+    pub(crate) fn appended_bytes(&self) -> u64 {
+        self.appended_bytes
+    }
+
     pub(crate) fn current_path(&self) -> Option<PathBuf> {
         self.current_sequence
             .map(|sequence| self.layout.chunk_path(sequence))
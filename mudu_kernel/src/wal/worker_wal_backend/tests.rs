@@ -4,8 +4,8 @@ use super::*;
 
 use crate::wal::log_frame::split_frame;
 use crate::wal::lsn::LSN;
-use crate::wal::worker_log::decode_frames;
 use crate::wal::worker_log::WorkerLogBackend;
+use crate::wal::worker_log::{decode_frames, retain_frames_from};
 use crate::wal::xl_batch::{
     append_xl_batch_async, decode_xl_batches, decode_xl_batches_with_pending, serialize_batch,
     XLBatch,
@@ -31,9 +31,11 @@ fn sample_batch() -> XLBatch {
                 tuple_id: 0,
                 key: b"k1".to_vec(),
                 value: b"v1".to_vec(),
+                ttl_ms: None,
             })),
             TxOp::Commit,
         ],
+        commit_ms: 0,
     }])
 }
 
@@ -85,9 +87,11 @@ fn worker_log_decodes_multiple_frames_from_single_chunk_payload() {
                 tuple_id: 0,
                 key: b"k2".to_vec(),
                 value: b"v2".to_vec(),
+                ttl_ms: None,
             })),
             TxOp::Commit,
         ],
+        commit_ms: 0,
     }]);
     let mut bytes = Vec::new();
     let next_lsn = AtomicU64::new(0);
@@ -161,9 +165,11 @@ fn sample_xl_batch_1() -> XLBatch {
                 tuple_id: 0,
                 key: b"k".to_vec(),
                 value: vec![9u8; 512],
+                ttl_ms: None,
             })),
             TxOp::Commit,
         ],
+        commit_ms: 0,
     }])
 }
 
@@ -185,7 +191,7 @@ fn worker_log_serializes_frame_headers_with_monotonic_lsn() {
         let frames = serialize_batch(&batch, log.frame_size_limit().unwrap(), &next_lsn).unwrap();
         assert!(frames.len() > 1);
         for (index, frame) in frames.iter().enumerate() {
-            let (header, _, _) = split_frame(frame).unwrap();
+            let (header, _, _) = split_frame(None, frame).unwrap();
             assert_eq!(header.lsn(), LSN::new(index as u64));
         }
     });
@@ -248,6 +254,110 @@ fn worker_log_layout_scans_tail_async() {
     .unwrap()
 }
 
+#[test]
+fn worker_log_removes_chunks_before_position() {
+    mudu_sys::task::async_::block_on_async_current(async move {
+        let dir = temp_dir().join(format!("worker_log_truncate_{}", gen_oid()));
+        let layout = WorkerLogLayout::new(dir, gen_oid(), 64).unwrap();
+        let log = WorkerWALBackend::new(layout.clone()).await.unwrap();
+        for _ in 0..3 {
+            futures::executor::block_on(append_xl_batch_async(&log, &sample_batch())).unwrap();
+        }
+        log.flush_async().await.unwrap();
+        let position = log.position().unwrap();
+        let before = log.chunk_paths_sorted().await.unwrap();
+        assert!(before.len() > 1);
+        let removed = log
+            .remove_chunks_before(position.chunk_sequence)
+            .await
+            .unwrap();
+        assert_eq!(removed, before.len() - 1);
+        drop(log);
+
+        // The tail scan resumes behind the removed chunks.
+        let reopened = WorkerWALBackend::new(layout).await.unwrap();
+        assert_eq!(
+            reopened.chunk_paths_sorted().await.unwrap(),
+            before[before.len() - 1..]
+        );
+        assert_eq!(reopened.position().unwrap().lsn, position.lsn);
+    });
+}
+
+#[test]
+fn worker_log_archives_chunks_before_removing_them() {
+    mudu_sys::task::async_::block_on_async_current(async move {
+        let dir = temp_dir().join(format!("worker_log_archive_{}", gen_oid()));
+        let log_oid = gen_oid();
+        let layout = WorkerLogLayout::new(dir.join("log"), log_oid, 64)
+            .unwrap()
+            .with_archive_dir(dir.join("archive"));
+        let log = WorkerWALBackend::new(layout.clone()).await.unwrap();
+        for _ in 0..3 {
+            futures::executor::block_on(append_xl_batch_async(&log, &sample_batch())).unwrap();
+        }
+        log.flush_async().await.unwrap();
+        let position = log.position().unwrap();
+        let before = log.chunk_paths_sorted().await.unwrap();
+        let removed = log
+            .remove_chunks_before(position.chunk_sequence)
+            .await
+            .unwrap();
+        assert_eq!(removed, before.len() - 1);
+        let fs = log.fs();
+        let archived = layout
+            .archived_chunk_paths_sorted_async(fs.as_ref())
+            .await
+            .unwrap();
+        assert_eq!(archived.len(), removed);
+        // Archiving again copies nothing new.
+        assert_eq!(
+            log.archive_chunks_before(position.chunk_sequence)
+                .await
+                .unwrap(),
+            0
+        );
+        drop(log);
+
+        // A restored log directory gets the archived chunks back.
+        let restored = WorkerLogLayout::new(dir.join("restored"), log_oid, 64)
+            .unwrap()
+            .with_archive_dir(dir.join("archive"));
+        assert_eq!(
+            restored
+                .restore_archived_chunks_async(fs.as_ref())
+                .await
+                .unwrap(),
+            removed
+        );
+        assert_eq!(
+            restored
+                .chunk_paths_sorted_async(fs.as_ref())
+                .await
+                .unwrap()
+                .len(),
+            removed
+        );
+    });
+}
+
+#[test]
+fn worker_log_retains_frames_from_start_lsn() {
+    let first = sample_batch();
+    let second = XLBatch::new(vec![XLEntry {
+        xid: 2,
+        ops: vec![TxOp::Begin, TxOp::Commit],
+        commit_ms: 0,
+    }]);
+    let next_lsn = AtomicU64::new(0);
+    let mut frames = serialize_batch(&first, 4096, &next_lsn).unwrap();
+    let start_lsn = LSN::new(next_lsn.load(std::sync::atomic::Ordering::SeqCst));
+    frames.extend(serialize_batch(&second, 4096, &next_lsn).unwrap());
+
+    retain_frames_from(&mut frames, start_lsn).unwrap();
+    assert_eq!(decode_xl_batches(&frames).unwrap(), vec![second]);
+}
+
 #[test]
 fn direct_worker_log_does_not_queue_inside_worker_ring() {
     mudu_sys::task::async_::block_on_tokio_current_thread(async move {
@@ -28,8 +28,8 @@ use std::sync::atomic::AtomicU64;
 /// }
 ///
 /// impl WorkerLogRecoveryHandler<XLBatch> for RecoveryHandler {
-///     fn handle_entry(&self, entry: XLBatch, _start_lsn: LSN) -> RS<()> {
-///         self.worker.replay_log_batch(entry)
+///     fn handle_entry(&self, entry: XLBatch, start_lsn: LSN) -> RS<()> {
+///         self.worker.replay_log_batch(entry, start_lsn)
 ///     }
 /// }
 ///
@@ -78,10 +78,12 @@ pub fn serialize_batch(
     serialize_entry(batch, max_part_size, next_lsn)
 }
 
+/// Decodes an entry of a log written without an encryption keyring.
 pub fn deserialize_batch(parts: &[Vec<u8>]) -> RS<XLBatch> {
-    deserialize_entry(parts)
+    deserialize_entry(None, parts)
 }
 
+/// Decodes the batches of a log written without an encryption keyring.
 pub fn decode_xl_batches(frames: &[Vec<u8>]) -> RS<Vec<XLBatch>> {
     let mut pending = Vec::new();
     let mut pending_start_lsn = None;
@@ -95,13 +97,17 @@ pub fn decode_xl_batches(frames: &[Vec<u8>]) -> RS<Vec<XLBatch>> {
     Ok(batches)
 }
 
+/// Decodes the batches of a log written without an encryption keyring,
+/// carrying a partial batch over to the next call.
 pub fn decode_xl_batches_with_pending(
     frames: &[Vec<u8>],
     pending: &mut Vec<Vec<u8>>,
     pending_start_lsn: &mut Option<LSN>,
 ) -> RS<Vec<XLBatch>> {
     let mut out: Vec<XLBatch> = Vec::new();
-    for (_, batch) in decode_entries_with_pending::<XLBatch>(frames, pending, pending_start_lsn)? {
+    for (_, batch) in
+        decode_entries_with_pending::<XLBatch>(None, frames, pending, pending_start_lsn)?
+    {
         out.push(batch);
     }
     Ok(out)
@@ -142,9 +148,11 @@ mod tests {
                         tuple_id: xid as u64 + 10,
                         key: format!("key-{xid}").into_bytes(),
                         value: vec![xid as u8; payload_size],
+                        ttl_ms: None,
                     })),
                     TxOp::Commit,
                 ],
+                commit_ms: 0,
             });
         }
         XLBatch::new(entries)
@@ -158,7 +166,7 @@ mod tests {
         let lsns = frame_lsns(&parts).unwrap();
         assert_eq!(parts.len(), 1);
         assert_eq!(lsns, vec![0]);
-        let (header, payload, tailer) = split_frame(&parts[0]).unwrap();
+        let (header, payload, tailer) = split_frame(None, &parts[0]).unwrap();
         assert_eq!(header.lsn(), 0);
         assert_eq!(header.n_part(), 0);
         assert_eq!(tailer.n_part(), 0);
@@ -176,7 +184,7 @@ mod tests {
         assert_eq!(lsns.len(), parts.len());
         for (index, part) in parts.iter().enumerate() {
             assert!(part.len() <= 180);
-            let (header, _, tailer) = split_frame(part).unwrap();
+            let (header, _, tailer) = split_frame(None, part).unwrap();
             let expected = (parts.len() - index - 1) as u32;
             assert_eq!(header.lsn(), lsns[index]);
             assert_eq!(header.n_part(), expected);
@@ -237,6 +245,7 @@ mod tests {
                     tuple_id: 0,
                     key: b"k1".to_vec(),
                     value: b"v1".to_vec(),
+                    ttl_ms: None,
                 })),
                 TxOp::Write(XLWrite::Insert(XLInsert {
                     table_id: 9,
@@ -244,9 +253,11 @@ mod tests {
                     tuple_id: 0,
                     key: b"k2".to_vec(),
                     value: b"v2".to_vec(),
+                    ttl_ms: None,
                 })),
                 TxOp::Commit,
             ],
+            commit_ms: 0,
         }]);
 
         let next_lsn = AtomicU64::new(0);
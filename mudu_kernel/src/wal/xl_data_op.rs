@@ -27,6 +27,12 @@ pub struct XLInsert {
     ///
     /// Unlike updates, inserts persist the complete row payload here.
     pub value: Vec<u8>,
+    /// TTL in milliseconds of a worker-local KV value.
+    ///
+    /// The value expires this long after the commit time of its entry, see
+    /// [`crate::wal::xl_entry::XLEntry::commit_ms`]. `None` never expires.
+    #[serde(default)]
+    pub ttl_ms: Option<u64>,
 }
 
 /// Logical WAL payload for deleting one tuple from a table.
@@ -109,6 +115,7 @@ mod tests {
             tuple_id: 3,
             key: vec![4],
             value: vec![5, 6],
+            ttl_ms: None,
         }
     }
 
@@ -20,6 +20,13 @@ pub struct XLEntry {
     /// [`TxOp::Begin`] / [`TxOp::Commit`] together with zero or more logical
     /// row-level data operations in between.
     pub ops: Vec<TxOp>,
+    /// Commit time of the transaction in Unix milliseconds.
+    ///
+    /// Stamped once when the transaction commits; deadlines of KV values
+    /// written with a TTL count from it, so replay and replicas derive the
+    /// same deadlines without consulting their own clocks.
+    #[serde(default)]
+    pub commit_ms: u64,
 }
 
 /// Transaction operations captured in WAL.
@@ -77,6 +84,7 @@ mod tests {
             tuple_id: 1,
             key: vec![1],
             value: vec![2],
+            ttl_ms: None,
         })
     }
 
@@ -89,6 +97,7 @@ mod tests {
                 TxOp::Write(sample_insert_write()),
                 TxOp::Commit,
             ],
+            commit_ms: 0,
         };
         let encoded = rmp_serde::to_vec(&orig).unwrap();
         let decoded: XLEntry = rmp_serde::from_slice(&encoded).unwrap();
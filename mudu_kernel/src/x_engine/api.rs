@@ -64,7 +64,36 @@ pub enum AlterTable {}
 - optional parameter for read operation
  */
 #[derive(Clone, Debug, Default)]
-pub struct OptRead {}
+pub struct OptRead {
+    /// Point in time to read at instead of the transaction snapshot
+    /// (`AS OF SYSTEM TIME`). The read sees every version committed at or
+    /// before it and ignores the transaction's own staged writes.
+    pub as_of: Option<AsOf>,
+}
+
+/// Point in time of an `AS OF SYSTEM TIME` read.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum AsOf {
+    /// A commit timestamp handed out by the worker serving the read.
+    CommitTs(u64),
+    /// A wall-clock time in microseconds since the Unix epoch; the worker
+    /// reads as of the latest commit timestamp it handed out by then.
+    WallClock(i64),
+}
+
+impl OptRead {
+    pub fn as_of(timestamp: u64) -> Self {
+        Self {
+            as_of: Some(AsOf::CommitTs(timestamp)),
+        }
+    }
+
+    pub fn as_of_wall_clock(unix_micros: i64) -> Self {
+        Self {
+            as_of: Some(AsOf::WallClock(unix_micros)),
+        }
+    }
+}
 
 /**
 - optional parameter for update operation
@@ -1,7 +1,9 @@
 use crate::server::worker_snapshot::WorkerSnapshot;
 use crate::wal::xl_batch::XLBatch;
 use mudu::common::id::OID;
-use std::collections::BTreeMap;
+use std::collections::{BTreeMap, BTreeSet};
+use std::ops::Bound;
+use std::time::Duration;
 
 #[derive(Clone, Copy, Debug, Eq, PartialEq, Ord, PartialOrd, Hash)]
 pub struct PhysicalRelationId {
@@ -9,13 +11,130 @@ pub struct PhysicalRelationId {
     pub partition_id: OID,
 }
 
+impl PhysicalRelationId {
+    /// Relation id used for worker-local KV records.
+    pub const WORKER_KV: PhysicalRelationId = PhysicalRelationId {
+        table_id: 0,
+        partition_id: 0,
+    };
+}
+
+/// Isolation level of a transaction.
+///
+/// `SnapshotIsolation` only detects write-write conflicts at commit.
+/// `Serializable` additionally tracks the read set and aborts transactions
+/// that would form a dangerous structure of rw-antidependencies (SSI).
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub enum IsolationLevel {
+    #[default]
+    SnapshotIsolation,
+    Serializable,
+}
+
+/// When a commit is acknowledged relative to its WAL flush.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub enum DurabilityMode {
+    /// Commit returns after the WAL batch has been fsynced.
+    #[default]
+    Sync,
+    /// Commit returns after the WAL batch has been written. The worker fsyncs
+    /// the log every async commit flush interval (see
+    /// [`DEFAULT_ASYNC_COMMIT_FLUSH_INTERVAL`]), so an operating system crash
+    /// or power loss can lose the commits of about one interval. A crash of
+    /// the server process alone loses none.
+    Async,
+}
+
+/// Default time between two fsyncs of the worker log that make commits with
+/// [`DurabilityMode::Async`] durable.
+pub const DEFAULT_ASYNC_COMMIT_FLUSH_INTERVAL: Duration = Duration::from_millis(200);
+
+/// Options fixed for the lifetime of a transaction.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub struct TxOptions {
+    pub isolation_level: IsolationLevel,
+    pub read_only: bool,
+    /// How long commit waits for commit locks held by other transactions.
+    /// `None` fails at the first conflicting lock.
+    pub lock_timeout: Option<Duration>,
+    pub durability: DurabilityMode,
+}
+
+/// A key range of one relation, as `(relation, start, end)`.
+type ReadRange = (PhysicalRelationId, Bound<Vec<u8>>, Bound<Vec<u8>>);
+
+/// Keys and key ranges read by a serializable transaction.
+#[derive(Clone, Debug, Default)]
+pub struct TxReadSet {
+    keys: BTreeSet<(PhysicalRelationId, Vec<u8>)>,
+    ranges: Vec<ReadRange>,
+}
+
+impl TxReadSet {
+    pub fn add_key(&mut self, relation_id: PhysicalRelationId, key: Vec<u8>) {
+        self.keys.insert((relation_id, key));
+    }
+
+    pub fn add_range(
+        &mut self,
+        relation_id: PhysicalRelationId,
+        start: Bound<Vec<u8>>,
+        end: Bound<Vec<u8>>,
+    ) {
+        self.ranges.push((relation_id, start, end));
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.keys.is_empty() && self.ranges.is_empty()
+    }
+
+    /// Returns true when a write to `key` would change what this read set saw.
+    pub fn covers(&self, relation_id: PhysicalRelationId, key: &[u8]) -> bool {
+        if self.keys.contains(&(relation_id, key.to_vec())) {
+            return true;
+        }
+        self.ranges
+            .iter()
+            .any(|(id, start, end)| *id == relation_id && bound_contains(start, end, key))
+    }
+}
+
+fn bound_contains(start: &Bound<Vec<u8>>, end: &Bound<Vec<u8>>, key: &[u8]) -> bool {
+    let after_start = match start {
+        Bound::Included(start) => key >= start.as_slice(),
+        Bound::Excluded(start) => key > start.as_slice(),
+        Bound::Unbounded => true,
+    };
+    let before_end = match end {
+        Bound::Included(end) => key <= end.as_slice(),
+        Bound::Excluded(end) => key < end.as_slice(),
+        Bound::Unbounded => true,
+    };
+    after_start && before_end
+}
+
 pub trait TxMgr: Send + Sync {
     fn xid(&self) -> u64;
 
+    fn options(&self) -> TxOptions {
+        TxOptions::default()
+    }
+
+    fn isolation_level(&self) -> IsolationLevel {
+        self.options().isolation_level
+    }
+
     fn snapshot(&self) -> WorkerSnapshot;
 
     fn put(&self, key: Vec<u8>, value: Vec<u8>);
 
+    /// Like [`TxMgr::put`] for a value that expires `ttl_ms` after the
+    /// transaction commits. Transaction managers without expiry store it
+    /// like [`TxMgr::put`].
+    fn put_with_ttl(&self, key: Vec<u8>, value: Vec<u8>, _ttl_ms: u64) {
+        self.put(key, value)
+    }
+
     fn delete(&self, key: Vec<u8>);
 
     fn get(&self, key: &[u8]) -> Option<Option<Vec<u8>>>;
@@ -52,4 +171,20 @@ pub trait TxMgr: Send + Sync {
     fn build_write_ops(&self);
 
     fn xl_batch(&self) -> XLBatch;
+
+    /// Records a point read for serializable conflict detection.
+    fn record_read(&self, _relation_id: PhysicalRelationId, _key: &[u8]) {}
+
+    /// Records a range read for serializable conflict detection.
+    fn record_range_read(
+        &self,
+        _relation_id: PhysicalRelationId,
+        _start: Bound<&[u8]>,
+        _end: Bound<&[u8]>,
+    ) {
+    }
+
+    fn read_set(&self) -> TxReadSet {
+        TxReadSet::default()
+    }
 }
@@ -3,7 +3,7 @@ use crate::contract::partition_rule_binding::{PartitionPlacement, TablePartition
 use crate::contract::schema_table::SchemaTable;
 use crate::x_engine::api::{OptRead, Predicate, RangeData, VecDatum, VecSelTerm};
 use crate::x_engine::tx_mgr::TxMgr;
-use mudu::common::id::OID;
+use mudu::common::id::{AttrIndex, OID};
 use std::sync::Arc;
 
 #[derive(Clone)]
@@ -24,6 +24,18 @@ pub struct PAccessRange {
     pub opt_read: OptRead,
 }
 
+/// Lookup of base table rows through a prefix of a secondary index, see
+/// [`BoundPredicate::IndexPrefixEq`](crate::sql::bound_stmt::BoundPredicate::IndexPrefixEq).
+pub struct PIndexLookup {
+    pub tx_mgr: Arc<dyn TxMgr>,
+    pub table_id: OID,
+    pub index_id: OID,
+    pub prefix: Vec<(AttrIndex, Vec<u8>)>,
+    pub base_key: Vec<(AttrIndex, AttrIndex)>,
+    pub select: VecSelTerm,
+    pub opt_read: OptRead,
+}
+
 #[derive(Clone)]
 pub struct PCreatePartitionRule {
     pub tx_mgr: Arc<dyn TxMgr>,
@@ -43,6 +55,12 @@ pub struct PCreateTable {
     pub partition_binding: Option<TablePartitionBinding>,
 }
 
+#[derive(Clone)]
+pub struct PCreateIndex {
+    pub tx_mgr: Arc<dyn TxMgr>,
+    pub schema: SchemaTable,
+}
+
 #[derive(Clone)]
 pub struct PDropTable {
     pub tx_mgr: Arc<dyn TxMgr>,
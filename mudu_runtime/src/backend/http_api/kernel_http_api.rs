@@ -1,8 +1,11 @@
 #![allow(missing_docs)]
 
 use super::{
-    AsyncKernelInvokeClientFactory, HttpApi, KernelInvokeClientFactory, PartitionRouteEntry,
-    PartitionRouteRequest, PartitionRouteResponse, ServerTopology, WorkerTopology, find_app,
+    AsyncKernelInvokeClientFactory, BackupRequest, BackupResponse, CdcChangeInfo, CdcDropRequest,
+    CdcDropResponse, CdcPositionInfo, CdcSubscribeRequest, CdcSubscribeResponse, HttpApi,
+    KernelInvokeClientFactory, PartitionRouteEntry, PartitionRouteRequest, PartitionRouteResponse,
+    ReplicaPromoteResponse, ReplicaWorkerInfo, ReplicationStatusResponse, RestoreRequest,
+    RestoreResponse, ServerTopology, WorkerBackupInfo, WorkerTopology, find_app,
     parse_json_object_body, to_param,
 };
 use crate::backend::app_mgr::AppMgr;
@@ -14,6 +17,7 @@ use mudu::mudu_error;
 use mudu::utils::json::JsonValue;
 use mudu_binding::procedure::procedure_invoke;
 use mudu_contract::procedure::proc_desc::ProcDesc;
+use mudu_contract::protocol::{self, CdcPosition};
 use mudu_kernel::contract::meta_mgr::MetaMgr;
 use mudu_kernel::meta::meta_mgr_factory::MetaMgrFactory;
 use mudu_kernel::mudu_conn::mudu_conn_async::{
@@ -22,10 +26,20 @@ use mudu_kernel::mudu_conn::mudu_conn_async::{
 use mudu_kernel::server::partition_router::{
     DEFAULT_UNPARTITIONED_TABLE_PARTITION_ID, PartitionRouter,
 };
+use mudu_kernel::server::worker_backup::BackupCoordinator;
+use mudu_kernel::server::worker_cdc::{drop_consumer, wait_for_changes};
 use mudu_kernel::server::worker_registry::WorkerRegistry;
+use mudu_kernel::server::worker_replication::{promote, replication_status};
+use mudu_kernel::storage::backup::restore_backup;
+use mudu_kernel::storage::encryption::Keyring;
 use serde_json::Value;
 use std::ops::Bound;
+use std::path::Path;
 use std::sync::Arc;
+use std::time::Duration;
+
+// Upper bound of the time a cdc subscribe request waits for a change.
+const CDC_MAX_WAIT_MS: u64 = 30_000;
 
 pub struct KernelHttpApi {
     app_mgr: Arc<dyn AppMgr>,
@@ -36,6 +50,12 @@ pub struct KernelHttpApi {
     meta_mgr: Arc<dyn MetaMgr>,
     partition_router: PartitionRouter,
     client_factory: Arc<dyn AsyncKernelInvokeClientFactory>,
+    // Data directory of the workers in this process; cdc and replicas need it.
+    data_dir: Option<String>,
+    // Online backups of the server in this process.
+    backups: Option<Arc<BackupCoordinator>>,
+    // Keys of the server in this process; sealed logs and backups need them.
+    keyring: Option<Arc<Keyring>>,
 }
 
 impl KernelHttpApi {
@@ -43,10 +63,15 @@ impl KernelHttpApi {
         app_mgr: Arc<dyn AppMgr>,
         cfg: &MuduDBCfg,
         worker_registry: Arc<WorkerRegistry>,
+        backups: Arc<BackupCoordinator>,
+        keyring: Option<Arc<Keyring>>,
     ) -> RS<Self> {
-        let meta_mgr = MetaMgrFactory::create(cfg.db_path.clone())
-            .await
-            .map_err(|e| mudu_error!(ErrorCode::Database, "create http meta manager failed", e))?;
+        let meta_mgr =
+            MetaMgrFactory::create_with_async_runtime(cfg.db_path.clone(), None, keyring.clone())
+                .await
+                .map_err(|e| {
+                    mudu_error!(ErrorCode::Database, "create http meta manager failed", e)
+                })?;
         Ok(Self::with_client_factory(
             app_mgr,
             format!("{}:{}", cfg.listen_ip, cfg.tcp_listen_port),
@@ -55,7 +80,10 @@ impl KernelHttpApi {
             worker_registry,
             meta_mgr,
             Arc::new(KernelInvokeClientFactory),
-        ))
+        )
+        .with_data_dir(cfg.db_path.clone())
+        .with_backup_coordinator(backups)
+        .with_keyring(keyring))
     }
 
     pub fn with_client_factory(
@@ -78,9 +106,45 @@ impl KernelHttpApi {
             partition_router: PartitionRouter::new(meta_mgr.clone()),
             meta_mgr,
             client_factory,
+            data_dir: None,
+            backups: None,
+            keyring: None,
         }
     }
 
+    pub fn with_data_dir(mut self, data_dir: String) -> Self {
+        self.data_dir = Some(data_dir);
+        self
+    }
+
+    pub fn with_backup_coordinator(mut self, backups: Arc<BackupCoordinator>) -> Self {
+        self.backups = Some(backups);
+        self
+    }
+
+    pub fn with_keyring(mut self, keyring: Option<Arc<Keyring>>) -> Self {
+        self.keyring = keyring;
+        self
+    }
+
+    fn replica_data_dir(&self) -> RS<&str> {
+        self.data_dir.as_deref().ok_or_else(|| {
+            mudu_error!(
+                ErrorCode::NotImplemented,
+                "read replicas need the server data directory"
+            )
+        })
+    }
+
+    fn cdc_data_dir(&self) -> RS<&str> {
+        self.data_dir.as_deref().ok_or_else(|| {
+            mudu_error!(
+                ErrorCode::NotImplemented,
+                "change data capture needs the server data directory"
+            )
+        })
+    }
+
     async fn resolve_partition_worker(
         &self,
         partition_id: mudu::common::id::OID,
@@ -190,6 +254,131 @@ impl HttpApi for KernelHttpApi {
         self.app_mgr.uninstall(app_name.as_bytes().to_vec()).await
     }
 
+    async fn backup(&self, request: BackupRequest) -> RS<BackupResponse> {
+        let backups = self.backups.as_ref().ok_or_else(|| {
+            mudu_error!(
+                ErrorCode::NotImplemented,
+                "backup needs the server's backup coordinator"
+            )
+        })?;
+        let manifest = backups
+            .run_backup(&self.worker_registry, Path::new(&request.out_dir))
+            .await?;
+        Ok(BackupResponse {
+            out_dir: request.out_dir,
+            file_count: manifest.files.len(),
+            total_bytes: manifest.total_bytes(),
+            workers: manifest
+                .workers
+                .iter()
+                .map(|worker| WorkerBackupInfo {
+                    worker_id: worker.worker_id,
+                    checkpoint_lsn: worker.checkpoint_lsn,
+                    barrier_lsn: worker.barrier_lsn,
+                    end_lsn: worker.end_lsn,
+                })
+                .collect(),
+        })
+    }
+
+    async fn restore(&self, request: RestoreRequest) -> RS<RestoreResponse> {
+        let report = restore_backup(
+            Path::new(&request.backup_dir),
+            Path::new(&request.data_dir),
+            self.keyring.as_deref(),
+        )?;
+        Ok(RestoreResponse {
+            data_dir: request.data_dir,
+            page_files: report.page_files,
+            wal_chunks: report.wal_chunks,
+            corrupt_pages: report.corrupt_pages.len(),
+            corrupt_frames: report.corrupt_frames.len(),
+            clean: report.is_clean(),
+        })
+    }
+
+    async fn cdc_subscribe(&self, request: CdcSubscribeRequest) -> RS<CdcSubscribeResponse> {
+        let data_dir = self.cdc_data_dir()?;
+        let from = request.from.map(|positions| {
+            positions
+                .into_iter()
+                .map(|position| CdcPosition::new(position.worker_id, position.lsn))
+                .collect()
+        });
+        let kernel_request = protocol::CdcSubscribeRequest::new(
+            request.consumer,
+            request.table,
+            from,
+            request.max_changes,
+        );
+        let fs = mudu_sys::default_sys_io_context().fs();
+        let response = wait_for_changes(
+            fs.as_ref(),
+            data_dir,
+            Path::new(data_dir),
+            self.keyring.as_deref(),
+            &self.worker_registry,
+            self.meta_mgr.as_ref(),
+            &kernel_request,
+            Duration::from_millis(request.wait_ms.min(CDC_MAX_WAIT_MS)),
+        )
+        .await?;
+        let (changes, positions) = response.into_parts();
+        Ok(CdcSubscribeResponse {
+            changes: changes
+                .into_iter()
+                .map(|change| CdcChangeInfo {
+                    worker_id: change.worker_id(),
+                    lsn: change.lsn(),
+                    xid: change.xid(),
+                    op: change.op(),
+                    key: change.key().clone(),
+                    row: change.row().cloned(),
+                })
+                .collect(),
+            positions: positions
+                .into_iter()
+                .map(|position| CdcPositionInfo {
+                    worker_id: position.worker_id(),
+                    lsn: position.lsn(),
+                })
+                .collect(),
+        })
+    }
+
+    async fn cdc_drop(&self, request: CdcDropRequest) -> RS<CdcDropResponse> {
+        let dropped = drop_consumer(self.cdc_data_dir()?, &request.consumer)?;
+        Ok(CdcDropResponse {
+            consumer: request.consumer,
+            dropped,
+        })
+    }
+
+    async fn replication_status(&self) -> RS<ReplicationStatusResponse> {
+        let status = replication_status(self.replica_data_dir()?)?.ok_or_else(|| {
+            mudu_error!(ErrorCode::InvalidState, "this server is not a read replica")
+        })?;
+        Ok(ReplicationStatusResponse {
+            promoted: status.promoted,
+            workers: status
+                .workers
+                .iter()
+                .map(|worker| ReplicaWorkerInfo {
+                    worker_id: worker.worker_id,
+                    applied_lsn: worker.applied_lsn,
+                    primary_lsn: worker.primary_lsn,
+                    lag_lsn: worker.lag_lsn(),
+                    lag_ms: worker.lag_ms,
+                })
+                .collect(),
+        })
+    }
+
+    async fn promote_replica(&self) -> RS<ReplicaPromoteResponse> {
+        let promoted = promote(self.replica_data_dir()?).await?;
+        Ok(ReplicaPromoteResponse { promoted })
+    }
+
     async fn route_partition(&self, request: PartitionRouteRequest) -> RS<PartitionRouteResponse> {
         let rule = self
             .meta_mgr
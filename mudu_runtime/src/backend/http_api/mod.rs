@@ -36,6 +36,7 @@ use mudu_binding::procedure::procedure_invoke;
 use mudu_binding::universal::uni_oid::UniOid;
 use mudu_contract::procedure::proc_desc::ProcDesc;
 use mudu_contract::procedure::procedure_param::ProcedureParam;
+use mudu_contract::protocol::CdcOp;
 use mudu_contract::tuple::datum_desc::DatumDesc;
 use mudu_sys::net::sync::StdTcpListener;
 use mudu_utils::notifier::Waiter;
@@ -133,6 +134,133 @@ pub struct PartitionRouteResponse {
     pub routes: Vec<PartitionRouteEntry>,
 }
 
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct BackupRequest {
+    /// Directory the backup set is written to; it must be empty or missing.
+    pub out_dir: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct WorkerBackupInfo {
+    #[serde(
+        serialize_with = "serialize_oid_as_unioid",
+        deserialize_with = "deserialize_oid_from_unioid"
+    )]
+    pub worker_id: OID,
+    pub checkpoint_lsn: u64,
+    pub barrier_lsn: u64,
+    pub end_lsn: u64,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct BackupResponse {
+    pub out_dir: String,
+    pub file_count: usize,
+    pub total_bytes: u64,
+    pub workers: Vec<WorkerBackupInfo>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct RestoreRequest {
+    pub backup_dir: String,
+    /// Data directory to restore into; it must be empty or missing, so a
+    /// running server's own directory is rejected.
+    pub data_dir: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct RestoreResponse {
+    pub data_dir: String,
+    pub page_files: u64,
+    pub wal_chunks: u64,
+    pub corrupt_pages: usize,
+    pub corrupt_frames: usize,
+    pub clean: bool,
+}
+
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+pub struct CdcPositionInfo {
+    #[serde(
+        serialize_with = "serialize_oid_as_unioid",
+        deserialize_with = "deserialize_oid_from_unioid"
+    )]
+    pub worker_id: OID,
+    pub lsn: u64,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct CdcSubscribeRequest {
+    pub consumer: String,
+    pub table: String,
+    /// Resume positions; they acknowledge every change before them. Without
+    /// them the consumer continues where its previous response ended.
+    #[serde(default)]
+    pub from: Option<Vec<CdcPositionInfo>>,
+    #[serde(default)]
+    pub max_changes: u32,
+    /// How long to wait for a change when none is available yet.
+    #[serde(default)]
+    pub wait_ms: u64,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct CdcChangeInfo {
+    #[serde(
+        serialize_with = "serialize_oid_as_unioid",
+        deserialize_with = "deserialize_oid_from_unioid"
+    )]
+    pub worker_id: OID,
+    pub lsn: u64,
+    pub xid: u64,
+    pub op: CdcOp,
+    pub key: Value,
+    pub row: Option<Value>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct CdcSubscribeResponse {
+    pub changes: Vec<CdcChangeInfo>,
+    pub positions: Vec<CdcPositionInfo>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct CdcDropRequest {
+    pub consumer: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct CdcDropResponse {
+    pub consumer: String,
+    pub dropped: bool,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct ReplicaWorkerInfo {
+    #[serde(
+        serialize_with = "serialize_oid_as_unioid",
+        deserialize_with = "deserialize_oid_from_unioid"
+    )]
+    pub worker_id: OID,
+    pub applied_lsn: u64,
+    pub primary_lsn: u64,
+    pub lag_lsn: u64,
+    /// Time since the worker last held every frame the primary reported;
+    /// unset until it first catches up.
+    pub lag_ms: Option<u64>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct ReplicationStatusResponse {
+    pub promoted: bool,
+    pub workers: Vec<ReplicaWorkerInfo>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct ReplicaPromoteResponse {
+    /// False when the replica had been promoted already.
+    pub promoted: bool,
+}
+
 use crate::backend::app_mgr::AppMgr;
 use crate::backend::mudu_app_mgr::ListOption;
 use crate::service::app_list::AppListItem;
@@ -176,6 +304,48 @@ pub trait HttpApi: Send + Sync {
             format!("uninstall is not supported for {}", app_name)
         ))
     }
+
+    async fn backup(&self, _request: BackupRequest) -> RS<BackupResponse> {
+        Err(mudu_error!(
+            ErrorCode::NotImplemented,
+            "backup is not supported"
+        ))
+    }
+
+    async fn restore(&self, _request: RestoreRequest) -> RS<RestoreResponse> {
+        Err(mudu_error!(
+            ErrorCode::NotImplemented,
+            "restore is not supported"
+        ))
+    }
+
+    async fn cdc_subscribe(&self, _request: CdcSubscribeRequest) -> RS<CdcSubscribeResponse> {
+        Err(mudu_error!(
+            ErrorCode::NotImplemented,
+            "change data capture is not supported"
+        ))
+    }
+
+    async fn cdc_drop(&self, _request: CdcDropRequest) -> RS<CdcDropResponse> {
+        Err(mudu_error!(
+            ErrorCode::NotImplemented,
+            "change data capture is not supported"
+        ))
+    }
+
+    async fn replication_status(&self) -> RS<ReplicationStatusResponse> {
+        Err(mudu_error!(
+            ErrorCode::NotImplemented,
+            "read replicas are not supported"
+        ))
+    }
+
+    async fn promote_replica(&self) -> RS<ReplicaPromoteResponse> {
+        Err(mudu_error!(
+            ErrorCode::NotImplemented,
+            "read replicas are not supported"
+        ))
+    }
 }
 
 #[async_trait(?Send)]
@@ -271,6 +441,12 @@ pub(crate) fn configure_routes(cfg: &mut web::ServiceConfig, capabilities: HttpA
         .service(app_proc_detail)
         .service(server_topology)
         .service(partition_route)
+        .service(backup)
+        .service(restore)
+        .service(cdc_subscribe)
+        .service(cdc_drop)
+        .service(replication_status)
+        .service(replica_promote)
         .service(install);
     if capabilities.enable_invoke {
         cfg.service(invoke);
@@ -331,6 +507,82 @@ async fn partition_route(body: String, context: web::Data<HttpApiContext>) -> im
     }
 }
 
+#[post("/mudu/backup")]
+async fn backup(body: String, context: web::Data<HttpApiContext>) -> impl Responder {
+    let request = match serde_json::from_str::<BackupRequest>(&body) {
+        Ok(request) => request,
+        Err(e) => {
+            let err = mudu_error!(ErrorCode::Decode, "fail to parse backup request", e);
+            return http_err("fail to parse backup request", &err);
+        }
+    };
+    match context.api.backup(request).await {
+        Ok(response) => http_ok(serde_json::to_value(response).unwrap_or(Value::Null)),
+        Err(e) => http_err("fail to back up", &e),
+    }
+}
+
+#[post("/mudu/backup/restore")]
+async fn restore(body: String, context: web::Data<HttpApiContext>) -> impl Responder {
+    let request = match serde_json::from_str::<RestoreRequest>(&body) {
+        Ok(request) => request,
+        Err(e) => {
+            let err = mudu_error!(ErrorCode::Decode, "fail to parse restore request", e);
+            return http_err("fail to parse restore request", &err);
+        }
+    };
+    match context.api.restore(request).await {
+        Ok(response) => http_ok(serde_json::to_value(response).unwrap_or(Value::Null)),
+        Err(e) => http_err("fail to restore backup", &e),
+    }
+}
+
+#[post("/mudu/cdc/subscribe")]
+async fn cdc_subscribe(body: String, context: web::Data<HttpApiContext>) -> impl Responder {
+    let request = match serde_json::from_str::<CdcSubscribeRequest>(&body) {
+        Ok(request) => request,
+        Err(e) => {
+            let err = mudu_error!(ErrorCode::Decode, "fail to parse cdc subscribe request", e);
+            return http_err("fail to parse cdc subscribe request", &err);
+        }
+    };
+    match context.api.cdc_subscribe(request).await {
+        Ok(response) => http_ok(serde_json::to_value(response).unwrap_or(Value::Null)),
+        Err(e) => http_err("fail to read changes", &e),
+    }
+}
+
+#[post("/mudu/cdc/drop")]
+async fn cdc_drop(body: String, context: web::Data<HttpApiContext>) -> impl Responder {
+    let request = match serde_json::from_str::<CdcDropRequest>(&body) {
+        Ok(request) => request,
+        Err(e) => {
+            let err = mudu_error!(ErrorCode::Decode, "fail to parse cdc drop request", e);
+            return http_err("fail to parse cdc drop request", &err);
+        }
+    };
+    match context.api.cdc_drop(request).await {
+        Ok(response) => http_ok(serde_json::to_value(response).unwrap_or(Value::Null)),
+        Err(e) => http_err("fail to drop cdc consumer", &e),
+    }
+}
+
+#[get("/mudu/replication/status")]
+async fn replication_status(context: web::Data<HttpApiContext>) -> impl Responder {
+    match context.api.replication_status().await {
+        Ok(response) => http_ok(serde_json::to_value(response).unwrap_or(Value::Null)),
+        Err(e) => http_err("fail to get replication status", &e),
+    }
+}
+
+#[post("/mudu/replication/promote")]
+async fn replica_promote(context: web::Data<HttpApiContext>) -> impl Responder {
+    match context.api.promote_replica().await {
+        Ok(response) => http_ok(serde_json::to_value(response).unwrap_or(Value::Null)),
+        Err(e) => http_err("fail to promote replica", &e),
+    }
+}
+
 #[get("/mudu/server/topology")]
 async fn server_topology(context: web::Data<HttpApiContext>) -> impl Responder {
     match context.api.server_topology().await {
@@ -675,6 +927,100 @@ mod test {
         assert_eq!(uninstall_resp.status(), StatusCode::NOT_FOUND);
     }
 
+    #[actix_web::test]
+    async fn backup_routes_decode_requests() {
+        if cfg!(miri) {
+            // actix-router leaks route capture names via Box::leak; skip under Miri.
+            return;
+        }
+        let app = test::init_service(
+            App::new()
+                .app_data(web::Data::new(HttpApiContext {
+                    api: Arc::new(MockHttpApi),
+                }))
+                .configure(|cfg| configure_routes(cfg, HttpApiCapabilities::LEGACY)),
+        )
+        .await;
+
+        let backup_req = test::TestRequest::post()
+            .uri("/mudu/backup")
+            .set_payload(r#"{"out_dir":"/tmp/backup"}"#)
+            .to_request();
+        let backup_resp: Value = test::call_and_read_body_json(&app, backup_req).await;
+        assert_eq!(backup_resp["ok"], false);
+        assert_eq!(backup_resp["error"]["name"], "NotImplemented");
+
+        let restore_req = test::TestRequest::post()
+            .uri("/mudu/backup/restore")
+            .set_payload(r#"{"backup_dir":"/tmp/backup"}"#)
+            .to_request();
+        let restore_resp: Value = test::call_and_read_body_json(&app, restore_req).await;
+        assert_eq!(restore_resp["ok"], false);
+        assert_eq!(restore_resp["error"]["name"], "Decode");
+    }
+
+    #[actix_web::test]
+    async fn cdc_routes_decode_requests() {
+        if cfg!(miri) {
+            // actix-router leaks route capture names via Box::leak; skip under Miri.
+            return;
+        }
+        let app = test::init_service(
+            App::new()
+                .app_data(web::Data::new(HttpApiContext {
+                    api: Arc::new(MockHttpApi),
+                }))
+                .configure(|cfg| configure_routes(cfg, HttpApiCapabilities::LEGACY)),
+        )
+        .await;
+
+        let subscribe_req = test::TestRequest::post()
+            .uri("/mudu/cdc/subscribe")
+            .set_payload(r#"{"consumer":"audit","table":"orders","wait_ms":10}"#)
+            .to_request();
+        let subscribe_resp: Value = test::call_and_read_body_json(&app, subscribe_req).await;
+        assert_eq!(subscribe_resp["ok"], false);
+        assert_eq!(subscribe_resp["error"]["name"], "NotImplemented");
+
+        let drop_req = test::TestRequest::post()
+            .uri("/mudu/cdc/drop")
+            .set_payload(r#"{"name":"audit"}"#)
+            .to_request();
+        let drop_resp: Value = test::call_and_read_body_json(&app, drop_req).await;
+        assert_eq!(drop_resp["ok"], false);
+        assert_eq!(drop_resp["error"]["name"], "Decode");
+    }
+
+    #[actix_web::test]
+    async fn replication_routes_reach_the_api() {
+        if cfg!(miri) {
+            // actix-router leaks route capture names via Box::leak; skip under Miri.
+            return;
+        }
+        let app = test::init_service(
+            App::new()
+                .app_data(web::Data::new(HttpApiContext {
+                    api: Arc::new(MockHttpApi),
+                }))
+                .configure(|cfg| configure_routes(cfg, HttpApiCapabilities::LEGACY)),
+        )
+        .await;
+
+        let status_req = test::TestRequest::get()
+            .uri("/mudu/replication/status")
+            .to_request();
+        let status_resp: Value = test::call_and_read_body_json(&app, status_req).await;
+        assert_eq!(status_resp["ok"], false);
+        assert_eq!(status_resp["error"]["name"], "NotImplemented");
+
+        let promote_req = test::TestRequest::post()
+            .uri("/mudu/replication/promote")
+            .to_request();
+        let promote_resp: Value = test::call_and_read_body_json(&app, promote_req).await;
+        assert_eq!(promote_resp["ok"], false);
+        assert_eq!(promote_resp["error"]["name"], "NotImplemented");
+    }
+
     struct MockClient {
         session_id: u128,
         closed: bool,
@@ -261,7 +261,18 @@ fn run_kv_mpk_can_be_used_by_kernel_backend(server_mode: ServerMode) -> RS<()> {
         KernelRoutingMode::ConnectionId,
     )?
     .with_log_chunk_size(cfg.io_uring_log_chunk_size)
-    .with_page_size(cfg.page_size)?;
+    .with_page_size(cfg.page_size)?
+    .with_page_compression(cfg.page_compression)
+    .with_wal_compression(cfg.wal_compression)
+    .with_async_commit_flush_interval(cfg.wal_async_flush_interval())
+    .with_history_retention(cfg.history_retention())
+    .with_buffer_pool_bytes(cfg.buffer_pool_bytes)
+    .with_checkpoint_interval(cfg.checkpoint_interval())
+    .with_checkpoint_log_bytes(cfg.checkpoint_log_bytes)
+    .with_encryption_key_file(cfg.encryption_key_file.clone())
+    .with_wal_archive_dir(cfg.wal_archive_dir.clone())
+    .with_recovery_target(cfg.recovery_target())
+    .with_read_replica(cfg.read_replica());
     let server_deps = ServerRuntimeDeps::from_cfg(&server_cfg)?
         .with_worker_procedure_runtimes(procedure_runtimes);
     let server_launch = ServerLaunch::new(server_cfg, server_deps);
@@ -3,10 +3,13 @@ use crate::backend::http_api::{
     HttpApiCapabilities, KernelHttpApi, serve_http_api_on_listener_with_stop,
 };
 use crate::backend::mududb_cfg::MuduDBCfg;
+use crate::backend::replica_follower::spawn_replica_follower;
 use mudu::common::result::RS;
 use mudu::error::ErrorCode;
 use mudu::mudu_error;
+use mudu_kernel::server::worker_backup::BackupCoordinator;
 use mudu_kernel::server::worker_registry::WorkerRegistry;
+use mudu_kernel::storage::encryption::Keyring;
 use mudu_sys::task::sync::spawn_thread_named;
 use mudu_utils::notifier::Waiter;
 use mudu_utils::task_async::build_current_thread_runtime;
@@ -18,6 +21,8 @@ pub fn spawn_management_thread(
     cfg: MuduDBCfg,
     app_mgr: Arc<dyn AppMgr>,
     worker_registry: Arc<WorkerRegistry>,
+    backups: Arc<BackupCoordinator>,
+    keyring: Option<Arc<Keyring>>,
     stop: Waiter,
 ) -> RS<()> {
     let (startup_tx, startup_rx) = mpsc::channel();
@@ -53,13 +58,26 @@ pub fn spawn_management_thread(
             }
         };
         runtime.block_on(async move {
-            let api = match KernelHttpApi::new(app_mgr, &cfg, worker_registry).await {
-                Ok(api) => Arc::new(api),
-                Err(e) => {
+            let api =
+                match KernelHttpApi::new(app_mgr, &cfg, worker_registry, backups, keyring).await {
+                    Ok(api) => Arc::new(api),
+                    Err(e) => {
+                        let _ = startup_tx.send(Err(e));
+                        return;
+                    }
+                };
+            if cfg.read_replica() {
+                let primary = cfg.replica_of.clone().unwrap_or_default();
+                if let Err(e) = spawn_replica_follower(
+                    primary,
+                    cfg.replica_name(),
+                    cfg.db_path.clone(),
+                    stop.clone(),
+                ) {
                     let _ = startup_tx.send(Err(e));
                     return;
                 }
-            };
+            }
             let _ = startup_tx.send(Ok(()));
             info!(
                 listen_ip = %cfg.listen_ip,
@@ -9,6 +9,7 @@ use async_trait::async_trait;
 use mudu::common::id::OID;
 use mudu::common::result::RS;
 use mudu_kernel::server::async_func_runtime::AsyncFuncInvoker;
+use mudu_kernel::server::worker_backup::BackupCoordinator;
 use mudu_kernel::server::worker_local::WorkerLocalRef;
 use mudu_kernel::server::worker_registry::WorkerRegistry;
 use mudu_utils::notifier::notify_wait;
@@ -84,9 +85,16 @@ fn spawn_management_thread_rejects_invalid_address() {
     let (stop_tx, stop_rx) = notify_wait();
     let registry = Arc::new(WorkerRegistry::new(vec![]).unwrap());
 
-    let err = spawn_management_thread(cfg, Arc::new(MockAppMgr), registry, stop_rx)
-        .err()
-        .unwrap();
+    let err = spawn_management_thread(
+        cfg,
+        Arc::new(MockAppMgr),
+        registry,
+        Arc::new(BackupCoordinator::new()),
+        None,
+        stop_rx,
+    )
+    .err()
+    .unwrap();
     assert_eq!(err.ec(), mudu::error::ErrorCode::Parse);
 
     let _ = stop_tx;
@@ -99,7 +107,15 @@ fn spawn_management_thread_starts_and_stops() {
     let (stop_tx, stop_rx) = notify_wait();
     let registry = Arc::new(WorkerRegistry::new(vec![]).unwrap());
 
-    spawn_management_thread(cfg, Arc::new(MockAppMgr), registry, stop_rx).unwrap();
+    spawn_management_thread(
+        cfg,
+        Arc::new(MockAppMgr),
+        registry,
+        Arc::new(BackupCoordinator::new()),
+        None,
+        stop_rx,
+    )
+    .unwrap();
 
     stop_tx.notify_all();
 }
@@ -20,6 +20,7 @@ mod management_thread;
 pub mod mudu_app_mgr;
 /// MuduDB server configuration.
 pub mod mududb_cfg;
+mod replica_follower;
 mod session;
 mod session_ctx;
 #[cfg(test)]
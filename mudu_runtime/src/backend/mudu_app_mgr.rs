@@ -101,6 +101,11 @@ impl AsyncFuncInvoker for MuduProcInvoker {
             (Ok(_), Err(task_end_err)) => Err(task_end_err),
         }
     }
+
+    async fn app_installed(&self, app_name: &str) -> RS<bool> {
+        let runtime = self.runtime.read()?.clone();
+        Ok(runtime.app(app_name.to_string()).await.is_some())
+    }
 }
 
 /// Options for listing applications.
@@ -3,11 +3,18 @@ use crate::service::runtime_opt::ComponentTarget;
 use mudu::common::result::RS;
 use mudu::error::ErrorCode;
 use mudu::mudu_error;
+use mudu_kernel::server::worker_checkpoint::CheckpointPolicy;
+use mudu_kernel::server::worker_recovery_target::RecoveryTarget;
+use mudu_kernel::server::worker_replication::replica_promoted;
+use mudu_kernel::storage::buffer_pool::DEFAULT_BUFFER_POOL_BYTES;
+use mudu_kernel::storage::compression::Compression;
+use mudu_kernel::x_engine::tx_mgr::DEFAULT_ASYNC_COMMIT_FLUSH_INTERVAL;
 use serde::{Deserialize, Serialize};
 use serde_repr::{Deserialize_repr, Serialize_repr};
 use std::env::home_dir;
 use std::fmt::Display;
 use std::path::{Path, PathBuf};
+use std::time::Duration;
 
 /// Backend server execution mode.
 #[derive(Serialize_repr, Deserialize_repr, Eq, PartialEq, Debug, Clone, Copy, Default)]
@@ -97,6 +104,55 @@ pub struct MuduDBCfg {
     /// requires a migration tool that rewrites all data files.
     #[serde(default = "default_page_size")]
     pub page_size: usize,
+    /// Codec of relation pages (`none`, `lz4` or `zstd`) for tables that do
+    /// not set `WITH (compression = ...)`.
+    #[serde(default)]
+    pub page_compression: Compression,
+    /// Codec of worker log frame payloads (`none`, `lz4` or `zstd`).
+    #[serde(default)]
+    pub wal_compression: Compression,
+    /// Milliseconds between worker log fsyncs for commits with asynchronous
+    /// durability. Such a commit survives a process crash, but an operating
+    /// system crash or power loss can lose up to this much of them.
+    #[serde(default = "default_wal_async_flush_interval_ms")]
+    pub wal_async_flush_interval_ms: u64,
+    /// Milliseconds of history kept readable by `AS OF SYSTEM TIME` queries.
+    /// `0` lets version GC prune everything no active transaction can see.
+    #[serde(default)]
+    pub history_retention_ms: u64,
+    /// Bytes of relation pages each worker keeps cached.
+    #[serde(default = "default_buffer_pool_bytes")]
+    pub buffer_pool_bytes: u64,
+    /// Milliseconds between two worker checkpoints. `0` disables the time
+    /// trigger.
+    #[serde(default = "default_checkpoint_interval_ms")]
+    pub checkpoint_interval_ms: u64,
+    /// Bytes appended to a worker log that trigger a checkpoint. `0` disables
+    /// the size trigger.
+    #[serde(default = "default_checkpoint_log_bytes")]
+    pub checkpoint_log_bytes: u64,
+    /// Key file for encryption at rest. Without it, data is written in
+    /// plaintext.
+    #[serde(default)]
+    pub encryption_key_file: Option<String>,
+    /// Directory that every completed worker log chunk is copied to. Without
+    /// it, chunks are removed once a checkpoint covers them.
+    #[serde(default)]
+    pub wal_archive_dir: Option<String>,
+    /// Point-in-time recovery: replay the worker log only below this LSN.
+    #[serde(default)]
+    pub recovery_target_lsn: Option<u64>,
+    /// Point-in-time recovery: replay only transactions with a timestamp up
+    /// to this one.
+    #[serde(default)]
+    pub recovery_target_commit_ts: Option<u64>,
+    /// TCP address of the primary this server follows as a read replica.
+    #[serde(default)]
+    pub replica_of: Option<String>,
+    /// Name the replica registers with on the primary; defaults to its own
+    /// TCP listen address.
+    #[serde(default)]
+    pub replica_name: Option<String>,
 }
 
 impl Display for MuduDBCfg {
@@ -148,6 +204,51 @@ impl Display for MuduDBCfg {
             self.io_uring_log_chunk_size
         )?;
         writeln!(f, "  -> page size: {}", self.page_size)?;
+        writeln!(f, "  -> page compression: {}", self.page_compression)?;
+        writeln!(f, "  -> WAL compression: {}", self.wal_compression)?;
+        writeln!(
+            f,
+            "  -> WAL async flush interval: {}ms",
+            self.wal_async_flush_interval_ms
+        )?;
+        writeln!(f, "  -> history retention: {}ms", self.history_retention_ms)?;
+        writeln!(f, "  -> buffer pool bytes: {}", self.buffer_pool_bytes)?;
+        writeln!(
+            f,
+            "  -> checkpoint interval: {}ms",
+            self.checkpoint_interval_ms
+        )?;
+        writeln!(
+            f,
+            "  -> checkpoint log bytes: {}",
+            self.checkpoint_log_bytes
+        )?;
+        writeln!(
+            f,
+            "  -> encryption key file: {}",
+            self.encryption_key_file.as_deref().unwrap_or("none")
+        )?;
+        writeln!(
+            f,
+            "  -> WAL archive dir: {}",
+            self.wal_archive_dir.as_deref().unwrap_or("none")
+        )?;
+        let recovery_target = self.recovery_target();
+        if recovery_target.is_set() {
+            writeln!(
+                f,
+                "  -> recovery target: lsn={:?}, commit_ts={:?}",
+                recovery_target.lsn, recovery_target.commit_ts
+            )?;
+        }
+        if let Some(primary) = &self.replica_of {
+            writeln!(
+                f,
+                "  -> replica of: {} (as {})",
+                primary,
+                self.replica_name()
+            )?;
+        }
         writeln!(f, "-------------------")?;
         Ok(())
     }
@@ -176,6 +277,19 @@ impl Default for MuduDBCfg {
             routing_mode: RoutingMode::ConnectionId,
             io_uring_log_chunk_size: default_io_uring_log_chunk_size(),
             page_size: default_page_size(),
+            page_compression: Compression::None,
+            wal_compression: Compression::None,
+            wal_async_flush_interval_ms: default_wal_async_flush_interval_ms(),
+            history_retention_ms: 0,
+            buffer_pool_bytes: default_buffer_pool_bytes(),
+            checkpoint_interval_ms: default_checkpoint_interval_ms(),
+            checkpoint_log_bytes: default_checkpoint_log_bytes(),
+            encryption_key_file: None,
+            wal_archive_dir: None,
+            recovery_target_lsn: None,
+            recovery_target_commit_ts: None,
+            replica_of: None,
+            replica_name: None,
         }
     }
 }
@@ -204,6 +318,44 @@ impl MuduDBCfg {
         }
     }
 
+    /// Returns how often commits with asynchronous durability are fsynced.
+    pub fn wal_async_flush_interval(&self) -> Duration {
+        Duration::from_millis(self.wal_async_flush_interval_ms)
+    }
+
+    /// Returns how long superseded versions stay readable by
+    /// `AS OF SYSTEM TIME` queries.
+    pub fn history_retention(&self) -> Duration {
+        Duration::from_millis(self.history_retention_ms)
+    }
+
+    /// Returns how often each worker takes a checkpoint.
+    pub fn checkpoint_interval(&self) -> Duration {
+        Duration::from_millis(self.checkpoint_interval_ms)
+    }
+
+    /// Returns where worker log recovery stops; unset without a
+    /// point-in-time recovery.
+    pub fn recovery_target(&self) -> RecoveryTarget {
+        RecoveryTarget {
+            lsn: self.recovery_target_lsn,
+            commit_ts: self.recovery_target_commit_ts,
+        }
+    }
+
+    /// Returns the name this server registers with on its primary.
+    pub fn replica_name(&self) -> String {
+        self.replica_name
+            .clone()
+            .unwrap_or_else(|| format!("{}:{}", self.listen_ip, self.tcp_listen_port))
+    }
+
+    /// Returns true when the server starts as a read replica: it follows a
+    /// primary and has not been promoted.
+    pub fn read_replica(&self) -> bool {
+        self.replica_of.is_some() && !replica_promoted(Path::new(&self.db_path))
+    }
+
     /// Returns the mutability class of a known configuration field.
     ///
     /// Unknown field names return `ConfigMutability::RestartRequired` as a
@@ -247,6 +399,22 @@ fn default_page_size() -> usize {
     4096
 }
 
+fn default_wal_async_flush_interval_ms() -> u64 {
+    DEFAULT_ASYNC_COMMIT_FLUSH_INTERVAL.as_millis() as u64
+}
+
+fn default_buffer_pool_bytes() -> u64 {
+    DEFAULT_BUFFER_POOL_BYTES
+}
+
+fn default_checkpoint_interval_ms() -> u64 {
+    CheckpointPolicy::default().interval.as_millis() as u64
+}
+
+fn default_checkpoint_log_bytes() -> u64 {
+    CheckpointPolicy::default().log_bytes
+}
+
 /// Load a MuduDB configuration from the given path or the default location.
 pub fn load_mududb_cfg(opt_cfg_path: Option<String>) -> RS<MuduDBCfg> {
     let cfg_path = match opt_cfg_path {
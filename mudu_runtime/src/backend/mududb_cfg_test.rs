@@ -1,7 +1,8 @@
 #![allow(clippy::unwrap_used)]
 
 use super::{MuduDBCfg, ServerMode, load_mududb_cfg};
-use std::time::UNIX_EPOCH;
+use mudu_kernel::storage::compression::Compression;
+use std::time::{Duration, UNIX_EPOCH};
 
 fn temp_home() -> std::path::PathBuf {
     let nanos = mudu_sys::time::system_time_now()
@@ -23,6 +24,56 @@ fn display_contains_all_fields() {
     assert!(text.contains(&format!("Component target: {:?}", cfg.component_target())));
     assert!(text.contains(&format!("Server mode: {:?}", cfg.server_mode)));
     assert!(text.contains(&format!("page size: {}", cfg.page_size)));
+    assert!(text.contains("page compression: none"));
+    assert!(text.contains("WAL compression: none"));
+    assert!(text.contains("WAL async flush interval: 200ms"));
+    assert!(text.contains("history retention: 0ms"));
+    assert!(text.contains("encryption key file: none"));
+}
+
+#[test]
+fn compression_settings_parse_from_toml() {
+    let mut text = toml::to_string(&MuduDBCfg::default()).unwrap();
+    text = text
+        .replace("page_compression = \"none\"", "page_compression = \"lz4\"")
+        .replace("wal_compression = \"none\"", "wal_compression = \"zstd\"");
+    let cfg: MuduDBCfg = toml::from_str(&text).unwrap();
+    assert_eq!(cfg.page_compression, Compression::Lz4);
+    assert_eq!(cfg.wal_compression, Compression::Zstd);
+
+    let text = text.replace("wal_compression = \"zstd\"", "wal_compression = \"gzip\"");
+    assert!(toml::from_str::<MuduDBCfg>(&text).is_err());
+}
+
+#[test]
+fn encryption_key_file_parses_from_toml() {
+    let text = toml::to_string(&MuduDBCfg::default()).unwrap();
+    let cfg: MuduDBCfg = toml::from_str(&text).unwrap();
+    assert_eq!(cfg.encryption_key_file, None);
+
+    let text = format!("encryption_key_file = \"/etc/mududb/keys\"\n{}", text);
+    let cfg: MuduDBCfg = toml::from_str(&text).unwrap();
+    assert_eq!(cfg.encryption_key_file.as_deref(), Some("/etc/mududb/keys"));
+}
+
+#[test]
+fn recovery_target_parses_from_toml() {
+    let text = toml::to_string(&MuduDBCfg::default()).unwrap();
+    let cfg: MuduDBCfg = toml::from_str(&text).unwrap();
+    assert_eq!(cfg.wal_archive_dir, None);
+    assert!(!cfg.recovery_target().is_set());
+
+    let text = format!(
+        "wal_archive_dir = \"/var/lib/mududb/archive\"\nrecovery_target_lsn = 42\n{}",
+        text
+    );
+    let cfg: MuduDBCfg = toml::from_str(&text).unwrap();
+    assert_eq!(
+        cfg.wal_archive_dir.as_deref(),
+        Some("/var/lib/mududb/archive")
+    );
+    assert_eq!(cfg.recovery_target().lsn, Some(42));
+    assert_eq!(cfg.recovery_target().commit_ts, None);
 }
 
 #[test]
@@ -76,3 +127,74 @@ fn load_from_default_home_path_creates_default_config() {
     let _ = mudu_sys::fs::sync::remove_dir_all(home.join(".mududb"));
     let _ = mudu_sys::fs::sync::remove_dir_all(home);
 }
+
+#[test]
+fn replica_of_parses_from_toml() {
+    let text = toml::to_string(&MuduDBCfg::default()).unwrap();
+    let cfg: MuduDBCfg = toml::from_str(&text).unwrap();
+    assert_eq!(cfg.replica_of, None);
+    assert!(!cfg.read_replica());
+
+    let text = format!(
+        "replica_of = \"127.0.0.1:9527\"\nreplica_name = \"replica-1\"\n{}",
+        text
+    );
+    let cfg: MuduDBCfg = toml::from_str(&text).unwrap();
+    assert_eq!(cfg.replica_of.as_deref(), Some("127.0.0.1:9527"));
+    assert_eq!(cfg.replica_name(), "replica-1");
+}
+
+#[test]
+fn wal_async_flush_interval_parses_from_toml() {
+    let text = toml::to_string(&MuduDBCfg::default()).unwrap();
+    let text = text
+        .lines()
+        .filter(|line| !line.starts_with("wal_async_flush_interval_ms"))
+        .collect::<Vec<_>>()
+        .join("\n");
+    let cfg: MuduDBCfg = toml::from_str(&text).unwrap();
+    assert_eq!(cfg.wal_async_flush_interval(), Duration::from_millis(200));
+
+    let text = format!("wal_async_flush_interval_ms = 50\n{}", text);
+    let cfg: MuduDBCfg = toml::from_str(&text).unwrap();
+    assert_eq!(cfg.wal_async_flush_interval(), Duration::from_millis(50));
+}
+
+#[test]
+fn history_retention_parses_from_toml() {
+    let text = toml::to_string(&MuduDBCfg::default()).unwrap();
+    let text = text
+        .lines()
+        .filter(|line| !line.starts_with("history_retention_ms"))
+        .collect::<Vec<_>>()
+        .join("\n");
+    let cfg: MuduDBCfg = toml::from_str(&text).unwrap();
+    assert_eq!(cfg.history_retention(), Duration::ZERO);
+
+    let text = format!("history_retention_ms = 60000\n{}", text);
+    let cfg: MuduDBCfg = toml::from_str(&text).unwrap();
+    assert_eq!(cfg.history_retention(), Duration::from_secs(60));
+}
+
+#[test]
+fn buffer_pool_and_checkpoint_settings_parse_from_toml() {
+    let text = toml::to_string(&MuduDBCfg::default()).unwrap();
+    let text = text
+        .lines()
+        .filter(|line| !line.starts_with("buffer_pool_bytes") && !line.starts_with("checkpoint_"))
+        .collect::<Vec<_>>()
+        .join("\n");
+    let cfg: MuduDBCfg = toml::from_str(&text).unwrap();
+    assert_eq!(cfg.buffer_pool_bytes, 256 * 1024 * 1024);
+    assert_eq!(cfg.checkpoint_interval(), Duration::from_secs(300));
+    assert_eq!(cfg.checkpoint_log_bytes, 256 * 1024 * 1024);
+
+    let text = format!(
+        "buffer_pool_bytes = 1048576\ncheckpoint_interval_ms = 0\ncheckpoint_log_bytes = 4096\n{}",
+        text
+    );
+    let cfg: MuduDBCfg = toml::from_str(&text).unwrap();
+    assert_eq!(cfg.buffer_pool_bytes, 1024 * 1024);
+    assert_eq!(cfg.checkpoint_interval(), Duration::ZERO);
+    assert_eq!(cfg.checkpoint_log_bytes, 4096);
+}
@@ -0,0 +1,70 @@
+use mudu::common::result::RS;
+use mudu_cli::client::async_client::{AsyncClient, AsyncClientImpl};
+use mudu_contract::protocol::WalFetchRequest;
+use mudu_kernel::server::worker_replication::{
+    REPLICA_FETCH_MAX_BYTES, enable_replica, fetch_positions, queue_shipped_frames,
+};
+use mudu_sys::task::async_::{sleep, spawn_task};
+use mudu_utils::notifier::Waiter;
+use std::time::Duration;
+use tracing::{info, warn};
+
+const FETCH_INTERVAL: Duration = Duration::from_millis(100);
+const RECONNECT_DELAY: Duration = Duration::from_secs(1);
+
+/// Starts the task that fetches worker log frames from the primary at
+/// `primary` for the read replica serving `data_dir`. The task ends once the
+/// replica is promoted or `stop` fires.
+pub fn spawn_replica_follower(
+    primary: String,
+    replica_name: String,
+    data_dir: String,
+    stop: Waiter,
+) -> RS<()> {
+    enable_replica(&data_dir)?;
+    let _ = spawn_task(stop, "replica-follower", async move {
+        follow_primary(&primary, &replica_name, &data_dir).await
+    })?;
+    Ok(())
+}
+
+async fn follow_primary(primary: &str, replica_name: &str, data_dir: &str) {
+    loop {
+        match fetch_from_primary(primary, replica_name, data_dir).await {
+            Ok(()) => {
+                info!(primary, "replica promoted, stop following the primary");
+                return;
+            }
+            Err(e) => {
+                warn!(
+                    primary,
+                    "fetch worker log frames from the primary failed: {}", e
+                );
+                if sleep(RECONNECT_DELAY).await.is_err() {
+                    return;
+                }
+            }
+        }
+    }
+}
+
+/// Fetches frames until the replica is promoted; returns the first error of
+/// the connection otherwise.
+async fn fetch_from_primary(primary: &str, replica_name: &str, data_dir: &str) -> RS<()> {
+    let mut client = AsyncClientImpl::connect(primary).await?;
+    while let Some(positions) = fetch_positions(data_dir)? {
+        for (worker_id, from_lsn) in positions {
+            let response = client
+                .wal_fetch(WalFetchRequest::new(
+                    replica_name.to_string(),
+                    worker_id,
+                    from_lsn,
+                    REPLICA_FETCH_MAX_BYTES,
+                ))
+                .await?;
+            queue_shipped_frames(data_dir, response)?;
+        }
+        sleep(FETCH_INTERVAL).await?;
+    }
+    Ok(())
+}
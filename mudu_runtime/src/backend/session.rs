@@ -17,6 +17,7 @@ use pgwire::api::store::PortalStore;
 use pgwire::api::{ClientInfo, ClientPortalStore, Type};
 
 use mudu::common::id::OID;
+use mudu::data_type::uuid::UuidValue;
 use mudu::error::ErrorCode;
 use mudu::mudu_error;
 use pgwire::error::{ErrorInfo, PgWireError, PgWireResult};
@@ -26,6 +27,7 @@ use pgwire::messages::data::DataRow;
 use crate::backend::session_ctx::SessionCtx;
 use libsql::params::Params;
 use libsql::{Rows, Statement, Value};
+use mudu_kernel::sql::stmt_query_run::encode_uuid_field;
 use std::fmt::{Debug, Formatter};
 use std::sync::Arc;
 
@@ -366,8 +368,11 @@ pub(super) fn row_desc_from_stmt(
 pub(super) fn name_to_type(name: &str) -> PgWireResult<Type> {
     match name.to_uppercase().as_ref() {
         "INT" | "INTEGER" => Ok(Type::INT8),
+        "BOOLEAN" | "BOOL" => Ok(Type::BOOL),
+        "TINYINT" | "SMALLINT" => Ok(Type::INT2),
         "VARCHAR" => Ok(Type::VARCHAR),
         "TEXT" => Ok(Type::TEXT),
+        "UUID" => Ok(Type::UUID),
         "BINARY" | "BLOB" => Ok(Type::BYTEA),
         "FLOAT" | "REAL" | "DOUBLE" => Ok(Type::FLOAT8),
         _ => Err(PgWireError::UserError(Box::new(ErrorInfo::new(
@@ -385,22 +390,49 @@ pub(super) fn encode_row_data(
     stream::unfold((rows, schema), |(mut rows, schema)| async move {
         let row = rows.next().await.ok()??;
 
-        let ncols = schema.len();
         let mut encoder = DataRowEncoder::new(schema.clone());
-
-        for idx in 0..ncols {
+        for (idx, field) in schema.iter().enumerate() {
             let data = row.get_value(idx as i32).unwrap();
-            match data {
-                Value::Null => encoder.encode_field(&None::<i8>).unwrap(),
-                Value::Integer(i) => encoder.encode_field(&i).unwrap(),
-                Value::Real(f) => encoder.encode_field(&f).unwrap(),
-                Value::Text(t) => encoder
-                    .encode_field(&String::from_utf8_lossy(t.as_bytes()).as_ref())
-                    .unwrap(),
-                Value::Blob(b) => encoder.encode_field(&b).unwrap(),
+            if let Err(e) = encode_value(&mut encoder, field, data) {
+                return Some((Err(e), (rows, schema)));
             }
         }
 
         Some((Ok(encoder.take_row()), (rows, schema)))
     })
 }
+
+/// SQLite keeps booleans and small integers as integers and UUIDs as text,
+/// so these are converted to the column's declared PG type.
+fn encode_value(encoder: &mut DataRowEncoder, field: &FieldInfo, data: Value) -> PgWireResult<()> {
+    match data {
+        Value::Null => encoder.encode_field(&None::<i8>),
+        Value::Integer(i) => match field.datatype() {
+            &Type::BOOL => encoder.encode_field(&(i != 0)),
+            &Type::INT2 => {
+                let value = i16::try_from(i).map_err(|_| {
+                    PgWireError::UserError(Box::new(ErrorInfo::new(
+                        "ERROR".to_owned(),
+                        "22003".to_owned(),
+                        format!("value {i} out of range for smallint"),
+                    )))
+                })?;
+                encoder.encode_field(&value)
+            }
+            _ => encoder.encode_field(&i),
+        },
+        Value::Real(f) => encoder.encode_field(&f),
+        Value::Text(t) if field.datatype() == &Type::UUID => {
+            let uuid = UuidValue::parse(&t).map_err(|e| {
+                PgWireError::UserError(Box::new(ErrorInfo::new(
+                    "ERROR".to_owned(),
+                    "22P02".to_owned(),
+                    format!("invalid uuid {t}: {e}"),
+                )))
+            })?;
+            encode_uuid_field(encoder, field, &uuid)
+        }
+        Value::Text(t) => encoder.encode_field(&String::from_utf8_lossy(t.as_bytes()).as_ref()),
+        Value::Blob(b) => encoder.encode_field(&b),
+    }
+}
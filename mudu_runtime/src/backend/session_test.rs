@@ -46,6 +46,10 @@ mod tests {
         assert_eq!(name_to_type("TEXT").unwrap(), Type::TEXT);
         assert_eq!(name_to_type("BINARY").unwrap(), Type::BYTEA);
         assert_eq!(name_to_type("FLOAT").unwrap(), Type::FLOAT8);
+        assert_eq!(name_to_type("BOOLEAN").unwrap(), Type::BOOL);
+        assert_eq!(name_to_type("TINYINT").unwrap(), Type::INT2);
+        assert_eq!(name_to_type("smallint").unwrap(), Type::INT2);
+        assert_eq!(name_to_type("UUID").unwrap(), Type::UUID);
     }
 
     #[test]
@@ -163,6 +167,65 @@ mod tests {
         assert!(stream.next().await.is_none());
     }
 
+    #[tokio::test]
+    async fn encode_row_data_encodes_bool_small_int_and_uuid_columns() {
+        let (_dir, conn) = temp_connection().await;
+        conn.execute(
+            "CREATE TABLE typed_row (a BOOLEAN, b TINYINT, c SMALLINT, d UUID)",
+            (),
+        )
+        .await
+        .unwrap();
+        conn.execute(
+            "INSERT INTO typed_row (a, b, c, d) VALUES (?, ?, ?, ?)",
+            (1i64, 7i64, -300i64, "123e4567-e89b-12d3-a456-426614174000"),
+        )
+        .await
+        .unwrap();
+        let uuid_bits = 0x123e4567_e89b_12d3_a456_426614174000u128;
+
+        let stmt = conn
+            .prepare("SELECT a, b, c, d FROM typed_row")
+            .await
+            .unwrap();
+        let schema = Arc::new(row_desc_from_stmt(&stmt, &Format::UnifiedText).unwrap());
+        let types = schema
+            .iter()
+            .map(|f| f.datatype().clone())
+            .collect::<Vec<_>>();
+        assert_eq!(types, vec![Type::BOOL, Type::INT2, Type::INT2, Type::UUID]);
+        let rows = stmt.query(()).await.unwrap();
+        let mut stream = std::pin::pin!(encode_row_data(rows, schema));
+        let values = decode_data_row(&stream.next().await.unwrap().unwrap());
+        assert_eq!(values[0].as_deref(), Some(b"t".as_slice()));
+        assert_eq!(values[1].as_deref(), Some(b"7".as_slice()));
+        assert_eq!(values[2].as_deref(), Some(b"-300".as_slice()));
+        assert_eq!(
+            values[3].as_deref(),
+            Some(b"123e4567-e89b-12d3-a456-426614174000".as_slice())
+        );
+
+        let stmt = conn
+            .prepare("SELECT a, b, c, d FROM typed_row")
+            .await
+            .unwrap();
+        let schema = Arc::new(row_desc_from_stmt(&stmt, &Format::UnifiedBinary).unwrap());
+        let rows = stmt.query(()).await.unwrap();
+        let mut stream = std::pin::pin!(encode_row_data(rows, schema));
+        let values = decode_data_row(&stream.next().await.unwrap().unwrap());
+        assert_eq!(values[0].as_deref(), Some([1u8].as_slice()));
+        assert_eq!(values[1].as_deref(), Some(7i16.to_be_bytes().as_slice()));
+        assert_eq!(
+            values[2].as_deref(),
+            Some((-300i16).to_be_bytes().as_slice())
+        );
+        assert_eq!(
+            values[3].as_deref(),
+            Some(uuid_bits.to_be_bytes().as_slice())
+        );
+        assert!(stream.next().await.is_none());
+    }
+
     fn make_portal(
         sql: &str,
         param_types: Vec<Option<Type>>,
@@ -876,6 +876,67 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    #[cfg_attr(miri, ignore)]
+    fn async_client_reads_encrypted_data_after_restart_tokio() -> RS<()> {
+        mudu_sys::task::async_::build_multi_thread_runtime()
+            .unwrap()
+            .block_on(async move {
+                init_test_logging();
+                run_async_client_reads_encrypted_data_after_restart(ServerMode::Tokio).await
+            })
+    }
+
+    async fn run_async_client_reads_encrypted_data_after_restart(
+        server_mode: ServerMode,
+    ) -> RS<()> {
+        let _guard = SQL_ASYNC_BACKEND_TEST_LOCK.lock().await;
+        let Some(mut cfg) = test_cfg(server_mode) else {
+            return Ok(());
+        };
+        let key_file = temp_dir("mudu_sql_async_keys");
+        mudu_sys::fs::sync::sync_write(&key_file, format!("1 {}\n", "5a".repeat(32)))?;
+        cfg.encryption_key_file = Some(key_file.to_string_lossy().into_owned());
+
+        let addr = format!("127.0.0.1:{}", cfg.tcp_listen_port);
+        let (stop_notifier, server) = spawn_backend_server(cfg.clone()).await?;
+        let mut client = wait_for_client(&addr, Duration::from_secs(10)).await?;
+        exec_sql(
+            &mut client,
+            "CREATE TABLE secrets(id INT, note CHAR(16), PRIMARY KEY(id))",
+        )
+        .await?;
+        batch_sql(
+            &mut client,
+            "INSERT INTO secrets(id, note) VALUES (1, 'sealed');\
+             INSERT INTO secrets(id, note) VALUES (2, 'at rest');",
+        )
+        .await?;
+        stop_server(client, stop_notifier, server)?;
+
+        // Restart on the same data directory and key file; the sealed catalog,
+        // pages and log must open with the keys passed through the server deps.
+        let Some(restart_cfg) = test_cfg(server_mode) else {
+            return Ok(());
+        };
+        cfg.tcp_listen_port = restart_cfg.tcp_listen_port;
+        cfg.http_listen_port = restart_cfg.http_listen_port;
+        let addr = format!("127.0.0.1:{}", cfg.tcp_listen_port);
+        let (stop_notifier, server) = spawn_backend_server(cfg).await?;
+        let mut client = wait_for_client(&addr, Duration::from_secs(10)).await?;
+        let selected = query_sql(&mut client, "SELECT id, note FROM secrets WHERE id >= 1").await?;
+        assert_eq!(
+            response_rows_as_strings(&selected),
+            vec![
+                vec!["1".to_string(), "'sealed'".to_string()],
+                vec!["2".to_string(), "'at rest'".to_string()],
+            ]
+        );
+
+        stop_server(client, stop_notifier, server)?;
+        Ok(())
+    }
+
     /// Regression test for backend fail-fast behavior.
     ///
     /// When the backend fails during initialization (here by pointing the data
@@ -72,11 +72,24 @@ impl TokioBackend {
         )?
         .with_log_chunk_size(cfg.io_uring_log_chunk_size)
         .with_multi_port(cfg.tcp_multi_port)
-        .with_page_size(cfg.page_size)?;
+        .with_page_size(cfg.page_size)?
+        .with_page_compression(cfg.page_compression)
+        .with_wal_compression(cfg.wal_compression)
+        .with_async_commit_flush_interval(cfg.wal_async_flush_interval())
+        .with_history_retention(cfg.history_retention())
+        .with_buffer_pool_bytes(cfg.buffer_pool_bytes)
+        .with_checkpoint_interval(cfg.checkpoint_interval())
+        .with_checkpoint_log_bytes(cfg.checkpoint_log_bytes)
+        .with_encryption_key_file(cfg.encryption_key_file.clone())
+        .with_wal_archive_dir(cfg.wal_archive_dir.clone())
+        .with_recovery_target(cfg.recovery_target())
+        .with_read_replica(cfg.read_replica());
         let mut server_deps = ServerRuntimeDeps::from_cfg(&base_server_cfg)?
             .with_async_runtime(async_runtime.clone());
         let default_remote_addr = format!("{}:{}", cfg.listen_ip, cfg.tcp_listen_port);
         let worker_registry = server_deps.worker_registry();
+        let backups = server_deps.backup_coordinator();
+        let keyring = server_deps.keyring();
         let default_remote_worker_id = worker_registry.default_global_worker_id();
         set_default_remote_async_runtime(server_deps.async_runtime());
         set_default_remote_addr(Some(default_remote_addr.clone()));
@@ -92,7 +105,14 @@ impl TokioBackend {
         })??;
         server_deps = server_deps.with_worker_procedure_runtimes(procedure_runtimes);
         let server_launch = ServerLaunch::new(base_server_cfg, server_deps);
-        spawn_management_thread(cfg.clone(), app_mgr.clone(), worker_registry, stop.clone())?;
+        spawn_management_thread(
+            cfg.clone(),
+            app_mgr.clone(),
+            worker_registry,
+            backups,
+            keyring,
+            stop.clone(),
+        )?;
         let result =
             KernelTokioTcpBackend::sync_serve_with_stop_and_ready(server_launch, stop, ready);
         clear_default_remote_if_current(&default_remote_addr, default_remote_worker_id);
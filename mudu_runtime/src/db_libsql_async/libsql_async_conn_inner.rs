@@ -259,10 +259,15 @@ fn to_libsql_params(sql_param: &dyn SQLParams) -> RS<LibSQLParam> {
 fn _to_libsql_value(datum: &DatValue, ty: &DatType) -> RS<libsql::Value> {
     let id = ty.dat_type_id();
     let v = match id {
+        DatTypeID::Bool => libsql::Value::Integer(*datum.expect_bool() as _),
+        DatTypeID::I8 => libsql::Value::Integer(*datum.expect_i8() as _),
+        DatTypeID::I16 => libsql::Value::Integer(*datum.expect_i16() as _),
         DatTypeID::I32 => libsql::Value::Integer(*datum.expect_i32() as _),
         DatTypeID::I64 => libsql::Value::Integer(*datum.expect_i64() as _),
         DatTypeID::U128 => libsql::Value::Text(datum.expect_u128().to_string()),
         DatTypeID::I128 => libsql::Value::Text(datum.expect_i128().to_string()),
+        DatTypeID::Uuid => libsql::Value::Text(datum.expect_uuid().format()),
+        DatTypeID::Json => libsql::Value::Text(datum.expect_json().to_string()),
         DatTypeID::F32 => libsql::Value::Real(*datum.expect_f32() as _),
         DatTypeID::F64 => libsql::Value::Real(*datum.expect_f64() as _),
         DatTypeID::String => libsql::Value::Text(datum.expect_string().clone()),
@@ -1,8 +1,10 @@
 use async_trait::async_trait;
 use libsql::{Row, Rows};
 use mudu::common::result::RS;
+use mudu::data_type::uuid::UuidValue;
 use mudu::error::ErrorCode;
 use mudu::mudu_error;
+use mudu::utils::json::{JsonValue, from_json_str};
 use mudu_contract::database::result_set::ResultSetAsync;
 use mudu_contract::tuple::datum_desc::DatumDesc;
 use mudu_contract::tuple::tuple_field_desc::TupleFieldDesc;
@@ -115,6 +117,30 @@ fn libsql_db_row_to_tuple_item(row: Row, item_desc: &[DatumDesc]) -> RS<TupleVal
         let raw = row.get_value(n).unwrap();
         debug!("col={}, name={:?}, raw={:?}", n, row.column_name(n), raw);
         let internal = match desc.dat_type_id() {
+            DatTypeID::Bool => {
+                let val = row.get::<i64>(n).map_err(|e| {
+                    mudu_error!(ErrorCode::Database, "libsql db get item of row error", e)
+                })?;
+                DatValue::from_bool(val != 0)
+            }
+            DatTypeID::I8 => {
+                let val = row.get::<i64>(n).map_err(|e| {
+                    mudu_error!(ErrorCode::Database, "libsql db get item of row error", e)
+                })?;
+                let val = i8::try_from(val).map_err(|e| {
+                    mudu_error!(ErrorCode::Database, "libsql db i8 out of range", e)
+                })?;
+                DatValue::from_i8(val)
+            }
+            DatTypeID::I16 => {
+                let val = row.get::<i64>(n).map_err(|e| {
+                    mudu_error!(ErrorCode::Database, "libsql db get item of row error", e)
+                })?;
+                let val = i16::try_from(val).map_err(|e| {
+                    mudu_error!(ErrorCode::Database, "libsql db i16 out of range", e)
+                })?;
+                DatValue::from_i16(val)
+            }
             DatTypeID::I32 => {
                 let val = row.get::<i32>(n).map_err(|e| {
                     mudu_error!(ErrorCode::Database, "libsql db get item of row error", e)
@@ -145,6 +171,27 @@ fn libsql_db_row_to_tuple_item(row: Row, item_desc: &[DatumDesc]) -> RS<TupleVal
                 })?;
                 DatValue::from_i128(val)
             }
+            DatTypeID::Uuid => {
+                let val = row.get::<String>(n).map_err(|e| {
+                    mudu_error!(ErrorCode::Database, "libsql db get item of row error", e)
+                })?;
+                let val = UuidValue::parse(&val).map_err(|e| {
+                    mudu_error!(
+                        ErrorCode::Database,
+                        format!("libsql db uuid parse error, {}", e)
+                    )
+                })?;
+                DatValue::from_uuid(val)
+            }
+            DatTypeID::Json => {
+                let val = row.get::<String>(n).map_err(|e| {
+                    mudu_error!(ErrorCode::Database, "libsql db get item of row error", e)
+                })?;
+                let val = from_json_str::<JsonValue>(&val).map_err(|e| {
+                    mudu_error!(ErrorCode::Database, "libsql db json parse error", e)
+                })?;
+                DatValue::from_json(val)
+            }
             DatTypeID::F32 => {
                 let val = row.get::<f64>(n).map_err(|e| {
                     mudu_error!(ErrorCode::Database, "libsql db get item of row error", e)
@@ -253,6 +300,54 @@ mod tests {
         assert_eq!(vals[6].as_string().unwrap(), "hello");
     }
 
+    #[tokio::test]
+    async fn next_converts_bool_small_int_and_uuid() {
+        let (conn, _dir) = open_conn().await;
+        conn.execute_batch(
+            "CREATE TABLE t(b INTEGER, i8_col INTEGER, i16_col INTEGER, uuid_col TEXT);
+            INSERT INTO t VALUES (1, -8, 300, '123e4567-e89b-12d3-a456-426614174000');",
+        )
+        .await
+        .unwrap();
+
+        let rows = conn.query("SELECT * FROM t", ()).await.unwrap();
+        let desc = make_desc(vec![
+            field("b", DatTypeID::Bool),
+            field("i8_col", DatTypeID::I8),
+            field("i16_col", DatTypeID::I16),
+            field("uuid_col", DatTypeID::Uuid),
+        ]);
+        let rs = LibSQLAsyncResultSet::new(rows, desc, None);
+
+        let row = rs.next().await.unwrap().unwrap();
+        let vals = row.values();
+        assert!(vals[0].to_bool());
+        assert_eq!(vals[1].to_i8(), -8);
+        assert_eq!(vals[2].to_i16(), 300);
+        assert_eq!(
+            vals[3].expect_uuid().format(),
+            "123e4567-e89b-12d3-a456-426614174000"
+        );
+    }
+
+    #[tokio::test]
+    async fn next_parses_json_text() {
+        let (conn, _dir) = open_conn().await;
+        conn.execute_batch(
+            r#"CREATE TABLE t(doc TEXT);
+            INSERT INTO t VALUES ('{"tags": ["a", "b"]}');"#,
+        )
+        .await
+        .unwrap();
+
+        let rows = conn.query("SELECT * FROM t", ()).await.unwrap();
+        let desc = make_desc(vec![field("doc", DatTypeID::Json)]);
+        let rs = LibSQLAsyncResultSet::new(rows, desc, None);
+
+        let row = rs.next().await.unwrap().unwrap();
+        assert_eq!(row.values()[0].expect_json()["tags"][1], "b");
+    }
+
     #[tokio::test]
     async fn next_returns_none_when_exhausted_and_releases_lease() {
         let (conn, _dir) = open_conn().await;
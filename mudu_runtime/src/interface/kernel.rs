@@ -1,15 +1,18 @@
 #![allow(missing_docs)]
 
 use crate::async_utils::blocking::run_async;
+use mudu::common::app_info::AppInfo;
 use mudu::common::id::OID;
 use mudu::common::result::RS;
 use mudu::error::ErrorCode;
 use mudu::mudu_error;
 use mudu_binding::codec::handle_sys_session;
+use mudu_contract::database::kv_namespace::{KvNamespace, KvNamespaceOp, KvNamespaceOpResult};
 use mudu_contract::database::result_batch::ResultBatch;
 use mudu_contract::database::sql::Context;
 use mudu_contract::tuple::tuple_field_desc::TupleFieldDesc;
 use mudu_kernel::server::worker_local::WorkerLocalRef;
+use mudu_kernel::server::worker_snapshot::KvItem;
 use mudu_utils::task_trace;
 
 /// Execute a SQL query with parameters
@@ -171,9 +174,19 @@ pub fn put_internal_with_worker_local(
     put_in: &[u8],
     worker_local: Option<WorkerLocalRef>,
 ) -> RS<Vec<u8>> {
-    let (session_id, key, value) = handle_sys_session::deserialize_session_put_param(put_in)?;
+    let (session_id, key, value, ttl) =
+        handle_sys_session::deserialize_session_put_with_ttl_param(put_in)?;
     let worker_local = require_worker_local(worker_local)?;
-    run_async(async move { worker_local.put_async(session_id, key, value).await })??;
+    run_async(async move {
+        match ttl {
+            Some(ttl) => {
+                worker_local
+                    .put_with_ttl_async(session_id, key, value, ttl)
+                    .await
+            }
+            None => worker_local.put_async(session_id, key, value).await,
+        }
+    })??;
     Ok(handle_sys_session::serialize_put_result())
 }
 
@@ -192,6 +205,93 @@ pub fn delete_internal_with_worker_local(
     Ok(handle_sys_session::serialize_delete_result())
 }
 
+pub fn put_many_internal(put_many_in: &[u8]) -> Vec<u8> {
+    put_many_internal_with_worker_local(put_many_in, None)
+        .unwrap_or_else(handle_sys_session::serialize_error_result)
+}
+
+pub fn put_many_internal_with_worker_local(
+    put_many_in: &[u8],
+    worker_local: Option<WorkerLocalRef>,
+) -> RS<Vec<u8>> {
+    let (session_id, items) = handle_sys_session::deserialize_session_put_many_param(put_many_in)?;
+    let worker_local = require_worker_local(worker_local)?;
+    let items = kv_items(items);
+    run_async(async move { worker_local.put_many_async(session_id, items).await })??;
+    Ok(handle_sys_session::serialize_put_many_result())
+}
+
+pub fn delete_many_internal(delete_many_in: &[u8]) -> Vec<u8> {
+    delete_many_internal_with_worker_local(delete_many_in, None)
+        .unwrap_or_else(handle_sys_session::serialize_error_result)
+}
+
+pub fn delete_many_internal_with_worker_local(
+    delete_many_in: &[u8],
+    worker_local: Option<WorkerLocalRef>,
+) -> RS<Vec<u8>> {
+    let (session_id, keys) =
+        handle_sys_session::deserialize_session_delete_many_param(delete_many_in)?;
+    let worker_local = require_worker_local(worker_local)?;
+    run_async(async move { worker_local.delete_many_async(session_id, keys).await })??;
+    Ok(handle_sys_session::serialize_delete_many_result())
+}
+
+pub fn cas_internal(cas_in: &[u8]) -> Vec<u8> {
+    cas_internal_with_worker_local(cas_in, None)
+        .unwrap_or_else(handle_sys_session::serialize_error_result)
+}
+
+pub fn cas_internal_with_worker_local(
+    cas_in: &[u8],
+    worker_local: Option<WorkerLocalRef>,
+) -> RS<Vec<u8>> {
+    let (session_id, key, expected, value) =
+        handle_sys_session::deserialize_session_cas_param(cas_in)?;
+    let worker_local = require_worker_local(worker_local)?;
+    let swapped = run_async(async move {
+        worker_local
+            .cas_async(session_id, key, expected, value)
+            .await
+    })??;
+    Ok(handle_sys_session::serialize_cas_result(swapped))
+}
+
+pub fn incr_internal(incr_in: &[u8]) -> Vec<u8> {
+    incr_internal_with_worker_local(incr_in, None)
+        .unwrap_or_else(handle_sys_session::serialize_error_result)
+}
+
+pub fn incr_internal_with_worker_local(
+    incr_in: &[u8],
+    worker_local: Option<WorkerLocalRef>,
+) -> RS<Vec<u8>> {
+    let (session_id, key, delta) = handle_sys_session::deserialize_session_incr_param(incr_in)?;
+    let worker_local = require_worker_local(worker_local)?;
+    let value = run_async(async move { worker_local.incr_async(session_id, key, delta).await })??;
+    Ok(handle_sys_session::serialize_incr_result(value))
+}
+
+pub fn put_if_absent_internal(put_if_absent_in: &[u8]) -> Vec<u8> {
+    put_if_absent_internal_with_worker_local(put_if_absent_in, None)
+        .unwrap_or_else(handle_sys_session::serialize_error_result)
+}
+
+pub fn put_if_absent_internal_with_worker_local(
+    put_if_absent_in: &[u8],
+    worker_local: Option<WorkerLocalRef>,
+) -> RS<Vec<u8>> {
+    let (session_id, key, value) =
+        handle_sys_session::deserialize_session_put_if_absent_param(put_if_absent_in)?;
+    let worker_local = require_worker_local(worker_local)?;
+    let written = run_async(async move {
+        worker_local
+            .put_if_absent_async(session_id, key, value)
+            .await
+    })??;
+    Ok(handle_sys_session::serialize_put_if_absent_result(written))
+}
+
 pub fn range_internal(range_in: &[u8]) -> Vec<u8> {
     range_internal_with_worker_local(range_in, None)
         .unwrap_or_else(handle_sys_session::serialize_error_result)
@@ -201,15 +301,33 @@ pub fn range_internal_with_worker_local(
     range_in: &[u8],
     worker_local: Option<WorkerLocalRef>,
 ) -> RS<Vec<u8>> {
-    let (session_id, start, end) = handle_sys_session::deserialize_session_range_param(range_in)?;
+    let (session_id, start, end, options) =
+        handle_sys_session::deserialize_session_range_page_param(range_in)?;
+    let worker_local = require_worker_local(worker_local)?;
+    let page = run_async(async move {
+        worker_local
+            .range_async(session_id, &start, &end, &options)
+            .await
+    })??;
+    Ok(handle_sys_session::serialize_range_page_result(&page))
+}
+
+/// Runs a KV namespace operation of a procedure of `app_info`. The
+/// operation only names the namespace; the app is always the caller's.
+pub fn kv_namespace_internal_with_worker_local(
+    kv_namespace_in: &[u8],
+    app_info: Option<&AppInfo>,
+    worker_local: Option<WorkerLocalRef>,
+) -> RS<Vec<u8>> {
+    let (session_id, op) =
+        handle_sys_session::deserialize_session_kv_namespace_param(kv_namespace_in)?;
+    let app_info = require_app_info(app_info)?.clone();
     let worker_local = require_worker_local(worker_local)?;
     let result =
-        run_async(async move { worker_local.range_async(session_id, &start, &end).await })??;
-    let result = result
-        .into_iter()
-        .map(|item| (item.key, item.value))
-        .collect::<Vec<_>>();
-    Ok(handle_sys_session::serialize_range_result(&result))
+        run_async(
+            async move { run_kv_namespace_op(&app_info, session_id, op, worker_local).await },
+        )??;
+    Ok(handle_sys_session::serialize_kv_namespace_result(&result))
 }
 
 pub async fn async_get_internal(get_in: Vec<u8>) -> Vec<u8> {
@@ -280,15 +398,23 @@ pub async fn async_put_internal_with_worker_local(
     put_in: Vec<u8>,
     worker_local: Option<WorkerLocalRef>,
 ) -> Vec<u8> {
-    let result = handle_sys_session::deserialize_session_put_param(&put_in).and_then(
-        |(session_id, key, value)| {
+    let result = handle_sys_session::deserialize_session_put_with_ttl_param(&put_in).and_then(
+        |(session_id, key, value, ttl)| {
             let worker_local = require_worker_local(worker_local)?;
-            Ok((session_id, key, value, worker_local))
+            Ok((session_id, key, value, ttl, worker_local))
         },
     );
     match result {
-        Ok((session_id, key, value, worker_local)) => {
-            match worker_local.put_async(session_id, key, value).await {
+        Ok((session_id, key, value, ttl, worker_local)) => {
+            let put = match ttl {
+                Some(ttl) => {
+                    worker_local
+                        .put_with_ttl_async(session_id, key, value, ttl)
+                        .await
+                }
+                None => worker_local.put_async(session_id, key, value).await,
+            };
+            match put {
                 Ok(()) => handle_sys_session::serialize_put_result(),
                 Err(err) => handle_sys_session::serialize_error_result(err),
             }
@@ -322,6 +448,138 @@ pub async fn async_delete_internal_with_worker_local(
     }
 }
 
+pub async fn async_put_many_internal(put_many_in: Vec<u8>) -> Vec<u8> {
+    put_many_internal(&put_many_in)
+}
+
+pub async fn async_put_many_internal_with_worker_local(
+    put_many_in: Vec<u8>,
+    worker_local: Option<WorkerLocalRef>,
+) -> Vec<u8> {
+    let result = handle_sys_session::deserialize_session_put_many_param(&put_many_in).and_then(
+        |(session_id, items)| {
+            let worker_local = require_worker_local(worker_local)?;
+            Ok((session_id, items, worker_local))
+        },
+    );
+    match result {
+        Ok((session_id, items, worker_local)) => {
+            match worker_local
+                .put_many_async(session_id, kv_items(items))
+                .await
+            {
+                Ok(()) => handle_sys_session::serialize_put_many_result(),
+                Err(err) => handle_sys_session::serialize_error_result(err),
+            }
+        }
+        Err(err) => handle_sys_session::serialize_error_result(err),
+    }
+}
+
+pub async fn async_delete_many_internal(delete_many_in: Vec<u8>) -> Vec<u8> {
+    delete_many_internal(&delete_many_in)
+}
+
+pub async fn async_delete_many_internal_with_worker_local(
+    delete_many_in: Vec<u8>,
+    worker_local: Option<WorkerLocalRef>,
+) -> Vec<u8> {
+    let result = handle_sys_session::deserialize_session_delete_many_param(&delete_many_in)
+        .and_then(|(session_id, keys)| {
+            let worker_local = require_worker_local(worker_local)?;
+            Ok((session_id, keys, worker_local))
+        });
+    match result {
+        Ok((session_id, keys, worker_local)) => {
+            match worker_local.delete_many_async(session_id, keys).await {
+                Ok(()) => handle_sys_session::serialize_delete_many_result(),
+                Err(err) => handle_sys_session::serialize_error_result(err),
+            }
+        }
+        Err(err) => handle_sys_session::serialize_error_result(err),
+    }
+}
+
+pub async fn async_cas_internal(cas_in: Vec<u8>) -> Vec<u8> {
+    cas_internal(&cas_in)
+}
+
+pub async fn async_cas_internal_with_worker_local(
+    cas_in: Vec<u8>,
+    worker_local: Option<WorkerLocalRef>,
+) -> Vec<u8> {
+    let result = handle_sys_session::deserialize_session_cas_param(&cas_in).and_then(
+        |(session_id, key, expected, value)| {
+            let worker_local = require_worker_local(worker_local)?;
+            Ok((session_id, key, expected, value, worker_local))
+        },
+    );
+    match result {
+        Ok((session_id, key, expected, value, worker_local)) => {
+            match worker_local
+                .cas_async(session_id, key, expected, value)
+                .await
+            {
+                Ok(swapped) => handle_sys_session::serialize_cas_result(swapped),
+                Err(err) => handle_sys_session::serialize_error_result(err),
+            }
+        }
+        Err(err) => handle_sys_session::serialize_error_result(err),
+    }
+}
+
+pub async fn async_incr_internal(incr_in: Vec<u8>) -> Vec<u8> {
+    incr_internal(&incr_in)
+}
+
+pub async fn async_incr_internal_with_worker_local(
+    incr_in: Vec<u8>,
+    worker_local: Option<WorkerLocalRef>,
+) -> Vec<u8> {
+    let result = handle_sys_session::deserialize_session_incr_param(&incr_in).and_then(
+        |(session_id, key, delta)| {
+            let worker_local = require_worker_local(worker_local)?;
+            Ok((session_id, key, delta, worker_local))
+        },
+    );
+    match result {
+        Ok((session_id, key, delta, worker_local)) => {
+            match worker_local.incr_async(session_id, key, delta).await {
+                Ok(value) => handle_sys_session::serialize_incr_result(value),
+                Err(err) => handle_sys_session::serialize_error_result(err),
+            }
+        }
+        Err(err) => handle_sys_session::serialize_error_result(err),
+    }
+}
+
+pub async fn async_put_if_absent_internal(put_if_absent_in: Vec<u8>) -> Vec<u8> {
+    put_if_absent_internal(&put_if_absent_in)
+}
+
+pub async fn async_put_if_absent_internal_with_worker_local(
+    put_if_absent_in: Vec<u8>,
+    worker_local: Option<WorkerLocalRef>,
+) -> Vec<u8> {
+    let result = handle_sys_session::deserialize_session_put_if_absent_param(&put_if_absent_in)
+        .and_then(|(session_id, key, value)| {
+            let worker_local = require_worker_local(worker_local)?;
+            Ok((session_id, key, value, worker_local))
+        });
+    match result {
+        Ok((session_id, key, value, worker_local)) => {
+            match worker_local
+                .put_if_absent_async(session_id, key, value)
+                .await
+            {
+                Ok(written) => handle_sys_session::serialize_put_if_absent_result(written),
+                Err(err) => handle_sys_session::serialize_error_result(err),
+            }
+        }
+        Err(err) => handle_sys_session::serialize_error_result(err),
+    }
+}
+
 pub async fn async_range_internal(range_in: Vec<u8>) -> Vec<u8> {
     range_internal(&range_in)
 }
@@ -330,22 +588,19 @@ pub async fn async_range_internal_with_worker_local(
     range_in: Vec<u8>,
     worker_local: Option<WorkerLocalRef>,
 ) -> Vec<u8> {
-    let result = handle_sys_session::deserialize_session_range_param(&range_in).and_then(
-        |(session_id, start, end)| {
+    let result = handle_sys_session::deserialize_session_range_page_param(&range_in).and_then(
+        |(session_id, start, end, options)| {
             let worker_local = require_worker_local(worker_local)?;
-            Ok((session_id, start, end, worker_local))
+            Ok((session_id, start, end, options, worker_local))
         },
     );
     match result {
-        Ok((session_id, start, end, worker_local)) => {
-            match worker_local.range_async(session_id, &start, &end).await {
-                Ok(rows) => {
-                    let rows = rows
-                        .into_iter()
-                        .map(|item| (item.key, item.value))
-                        .collect::<Vec<_>>();
-                    handle_sys_session::serialize_range_result(&rows)
-                }
+        Ok((session_id, start, end, options, worker_local)) => {
+            match worker_local
+                .range_async(session_id, &start, &end, &options)
+                .await
+            {
+                Ok(page) => handle_sys_session::serialize_range_page_result(&page),
                 Err(err) => handle_sys_session::serialize_error_result(err),
             }
         }
@@ -353,6 +608,114 @@ pub async fn async_range_internal_with_worker_local(
     }
 }
 
+pub async fn async_kv_namespace_internal_with_worker_local(
+    kv_namespace_in: Vec<u8>,
+    app_info: Option<AppInfo>,
+    worker_local: Option<WorkerLocalRef>,
+) -> Vec<u8> {
+    let result = async {
+        let (session_id, op) =
+            handle_sys_session::deserialize_session_kv_namespace_param(&kv_namespace_in)?;
+        let app_info = require_app_info(app_info.as_ref())?;
+        let worker_local = require_worker_local(worker_local)?;
+        run_kv_namespace_op(app_info, session_id, op, worker_local).await
+    }
+    .await;
+    match result {
+        Ok(result) => handle_sys_session::serialize_kv_namespace_result(&result),
+        Err(err) => handle_sys_session::serialize_error_result(err),
+    }
+}
+
+async fn run_kv_namespace_op(
+    app_info: &AppInfo,
+    session_id: OID,
+    op: KvNamespaceOp,
+    worker_local: WorkerLocalRef,
+) -> RS<KvNamespaceOpResult> {
+    match op {
+        KvNamespaceOp::Create {
+            name,
+            partition_rule,
+        } => {
+            let namespace = KvNamespace::for_app(app_info, name)?;
+            worker_local
+                .kv_namespace_create_async(session_id, &namespace, partition_rule.as_deref())
+                .await?;
+            Ok(KvNamespaceOpResult::Done)
+        }
+        KvNamespaceOp::Drop { name } => {
+            let namespace = KvNamespace::for_app(app_info, name)?;
+            worker_local
+                .kv_namespace_drop_async(session_id, &namespace)
+                .await?;
+            Ok(KvNamespaceOpResult::Done)
+        }
+        KvNamespaceOp::List => {
+            let namespaces = worker_local.kv_namespace_list_async(&app_info.name).await?;
+            Ok(KvNamespaceOpResult::Names(
+                namespaces
+                    .iter()
+                    .map(|namespace| namespace.name().to_string())
+                    .collect(),
+            ))
+        }
+        KvNamespaceOp::Get { name, key } => {
+            let namespace = KvNamespace::for_app(app_info, name)?;
+            let value = worker_local
+                .kv_namespace_get_async(session_id, &namespace, &key)
+                .await?;
+            Ok(KvNamespaceOpResult::Value(value))
+        }
+        KvNamespaceOp::Put { name, key, value } => {
+            let namespace = KvNamespace::for_app(app_info, name)?;
+            worker_local
+                .kv_namespace_put_async(session_id, &namespace, key, value)
+                .await?;
+            Ok(KvNamespaceOpResult::Done)
+        }
+        KvNamespaceOp::Delete { name, key } => {
+            let namespace = KvNamespace::for_app(app_info, name)?;
+            worker_local
+                .kv_namespace_delete_async(session_id, &namespace, &key)
+                .await?;
+            Ok(KvNamespaceOpResult::Done)
+        }
+        KvNamespaceOp::Range {
+            name,
+            start_key,
+            end_key,
+            options,
+        } => {
+            let namespace = KvNamespace::for_app(app_info, name)?;
+            let (items, continuation) = worker_local
+                .kv_namespace_range_async(session_id, &namespace, &start_key, &end_key, &options)
+                .await?
+                .into_parts();
+            Ok(KvNamespaceOpResult::Page {
+                items,
+                continuation,
+            })
+        }
+    }
+}
+
+fn kv_items(items: Vec<(Vec<u8>, Vec<u8>)>) -> Vec<KvItem> {
+    items
+        .into_iter()
+        .map(|(key, value)| KvItem { key, value })
+        .collect()
+}
+
+fn require_app_info(app_info: Option<&AppInfo>) -> RS<&AppInfo> {
+    app_info.ok_or_else(|| {
+        mudu_error!(
+            ErrorCode::NotImplemented,
+            "kv namespaces are only available to procedures of an installed app"
+        )
+    })
+}
+
 fn require_worker_local(worker_local: Option<WorkerLocalRef>) -> RS<WorkerLocalRef> {
     worker_local.ok_or_else(|| {
         mudu_error!(
@@ -391,6 +754,51 @@ mod tests {
             err.to_string()
                 .contains("worker local interface is not configured")
         );
+
+        let put_many = handle_sys_session::serialize_session_put_many_param(
+            1,
+            &[(b"alpha".to_vec(), b"1".to_vec())],
+        );
+        let err = put_many_internal_with_worker_local(&put_many, None).unwrap_err();
+        assert!(
+            err.to_string()
+                .contains("worker local interface is not configured")
+        );
+
+        let delete_many =
+            handle_sys_session::serialize_session_delete_many_param(1, &[b"alpha".to_vec()]);
+        let err = delete_many_internal_with_worker_local(&delete_many, None).unwrap_err();
+        assert!(
+            err.to_string()
+                .contains("worker local interface is not configured")
+        );
+
+        let cas = handle_sys_session::serialize_session_cas_param(1, b"alpha", None, b"1");
+        let err = cas_internal_with_worker_local(&cas, None).unwrap_err();
+        assert!(
+            err.to_string()
+                .contains("worker local interface is not configured")
+        );
+
+        let incr = handle_sys_session::serialize_session_incr_param(1, b"alpha", 1);
+        let err = incr_internal_with_worker_local(&incr, None).unwrap_err();
+        assert!(
+            err.to_string()
+                .contains("worker local interface is not configured")
+        );
+
+        let put_if_absent =
+            handle_sys_session::serialize_session_put_if_absent_param(1, b"alpha", b"1");
+        let err = put_if_absent_internal_with_worker_local(&put_if_absent, None).unwrap_err();
+        assert!(
+            err.to_string()
+                .contains("worker local interface is not configured")
+        );
+
+        let kv_namespace =
+            handle_sys_session::serialize_session_kv_namespace_param(1, &KvNamespaceOp::List);
+        let err = kv_namespace_internal_with_worker_local(&kv_namespace, None, None).unwrap_err();
+        assert!(err.to_string().contains("procedures of an installed app"));
     }
 
     #[test]
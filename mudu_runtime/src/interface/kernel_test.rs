@@ -4,10 +4,14 @@ mod tests {
 
     use super::super::kernel::*;
     use async_trait::async_trait;
+    use mudu::common::app_info::AppInfo;
     use mudu::common::buf::Buf;
     use mudu::common::id::OID;
     use mudu::common::result::RS;
     use mudu_binding::codec::handle_sys_session;
+    use mudu_contract::database::kv_atomic;
+    use mudu_contract::database::kv_namespace::{KvNamespace, KvNamespaceOp, KvNamespaceOpResult};
+    use mudu_contract::database::kv_range::{KvRangeOptions, KvRangePage};
     use mudu_contract::database::result_set::ResultSetAsync;
     use mudu_contract::database::sql_params::SQLParams;
     use mudu_contract::database::sql_stmt::SQLStmt;
@@ -23,7 +27,6 @@ mod tests {
         SubscriptionId,
     };
     use mudu_kernel::server::worker_local::{WorkerExecute, WorkerLocal, WorkerLocalRef};
-    use mudu_kernel::server::worker_snapshot::KvItem;
     use mudu_kernel::x_engine::api::{
         AlterTable, OptDelete, OptInsert, OptRead, OptUpdate, Predicate, RSCursor, RangeData,
         VecDatum, VecSelTerm, XContract,
@@ -31,6 +34,7 @@ mod tests {
     use mudu_kernel::x_engine::tx_mgr::TxMgr;
     use std::collections::HashMap;
     use std::sync::Arc;
+    use std::time::Duration;
 
     use mudu_sys::sync::SMutex;
 
@@ -197,6 +201,8 @@ mod tests {
     struct FakeWorkerLocal {
         next_id: SMutex<u128>,
         store: SMutex<HashMap<Vec<u8>, Vec<u8>>>,
+        ttls: SMutex<HashMap<Vec<u8>, Duration>>,
+        namespaces: SMutex<HashMap<(String, Vec<u8>), Vec<u8>>>,
     }
 
     impl FakeWorkerLocal {
@@ -204,6 +210,8 @@ mod tests {
             Self {
                 next_id: SMutex::new(1),
                 store: SMutex::new(HashMap::new()),
+                ttls: SMutex::new(HashMap::new()),
+                namespaces: SMutex::new(HashMap::new()),
             }
         }
     }
@@ -245,10 +253,47 @@ mod tests {
             self.store.lock().unwrap().insert(key, value);
             Ok(())
         }
+        async fn put_with_ttl_async(
+            &self,
+            _session_id: OID,
+            key: Vec<u8>,
+            value: Vec<u8>,
+            ttl: Duration,
+        ) -> RS<()> {
+            self.ttls.lock().unwrap().insert(key.clone(), ttl);
+            self.store.lock().unwrap().insert(key, value);
+            Ok(())
+        }
         async fn delete_async(&self, _session_id: OID, key: &[u8]) -> RS<()> {
             self.store.lock().unwrap().remove(key);
             Ok(())
         }
+        async fn cas_async(
+            &self,
+            _session_id: OID,
+            key: Vec<u8>,
+            expected: Option<Vec<u8>>,
+            value: Vec<u8>,
+        ) -> RS<bool> {
+            let mut store = self.store.lock().unwrap();
+            let (write, swapped) = kv_atomic::compare_and_swap(
+                store.get(&key).map(Vec::as_slice),
+                expected.as_deref(),
+                &value,
+            );
+            if let Some(write) = write {
+                store.insert(key, write);
+            }
+            Ok(swapped)
+        }
+        async fn incr_async(&self, _session_id: OID, key: Vec<u8>, delta: i64) -> RS<i64> {
+            let mut store = self.store.lock().unwrap();
+            let (write, value) = kv_atomic::increment(store.get(&key).map(Vec::as_slice), delta)?;
+            if let Some(write) = write {
+                store.insert(key, write);
+            }
+            Ok(value)
+        }
         async fn get_async(&self, _session_id: OID, key: &[u8]) -> RS<Option<Vec<u8>>> {
             Ok(self.store.lock().unwrap().get(key).cloned())
         }
@@ -257,18 +302,19 @@ mod tests {
             _session_id: OID,
             start_key: &[u8],
             end_key: &[u8],
-        ) -> RS<Vec<KvItem>> {
+            options: &KvRangeOptions,
+        ) -> RS<KvRangePage> {
+            let Some((start_key, end_key)) = options.bounds(start_key, end_key) else {
+                return Ok(KvRangePage::default());
+            };
             let store = self.store.lock().unwrap();
-            let mut items: Vec<KvItem> = store
+            let mut items: Vec<(Vec<u8>, Vec<u8>)> = store
                 .iter()
-                .filter(|(k, _)| k.as_slice() >= start_key && k.as_slice() < end_key)
-                .map(|(k, v)| KvItem {
-                    key: k.clone(),
-                    value: v.clone(),
-                })
+                .filter(|(k, _)| *k >= &start_key && (end_key.is_empty() || *k < &end_key))
+                .map(|(k, v)| (k.clone(), v.clone()))
                 .collect();
-            items.sort_by(|a, b| a.key.cmp(&b.key));
-            Ok(items)
+            items.sort();
+            Ok(options.page_ascending(items))
         }
         async fn query(
             &self,
@@ -294,6 +340,45 @@ mod tests {
         ) -> RS<u64> {
             unimplemented!()
         }
+        async fn kv_namespace_list_async(&self, app_name: &str) -> RS<Vec<KvNamespace>> {
+            let mut namespaces: Vec<KvNamespace> = self
+                .namespaces
+                .lock()
+                .unwrap()
+                .keys()
+                .filter_map(|(relation, _)| KvNamespace::from_relation_name(relation))
+                .filter(|namespace| namespace.app_name() == app_name)
+                .collect();
+            namespaces.sort_by(|a, b| a.name().cmp(b.name()));
+            namespaces.dedup();
+            Ok(namespaces)
+        }
+        async fn kv_namespace_get_async(
+            &self,
+            _session_id: OID,
+            namespace: &KvNamespace,
+            key: &[u8],
+        ) -> RS<Option<Vec<u8>>> {
+            Ok(self
+                .namespaces
+                .lock()
+                .unwrap()
+                .get(&(namespace.relation_name(), key.to_vec()))
+                .cloned())
+        }
+        async fn kv_namespace_put_async(
+            &self,
+            _session_id: OID,
+            namespace: &KvNamespace,
+            key: Vec<u8>,
+            value: Vec<u8>,
+        ) -> RS<()> {
+            self.namespaces
+                .lock()
+                .unwrap()
+                .insert((namespace.relation_name(), key), value);
+            Ok(())
+        }
     }
 
     fn worker_local() -> WorkerLocalRef {
@@ -414,6 +499,202 @@ mod tests {
         );
     }
 
+    #[test]
+    fn put_with_ttl_reaches_worker_local() {
+        let fake = Arc::new(FakeWorkerLocal::new());
+        let ttl = Duration::from_secs(5);
+
+        let put_in =
+            handle_sys_session::serialize_session_put_with_ttl_param(1, b"alpha", b"1", ttl);
+        let put_out = put_internal_with_worker_local(&put_in, Some(fake.clone())).unwrap();
+        handle_sys_session::deserialize_put_result(&put_out).unwrap();
+        assert_eq!(
+            fake.ttls.lock().unwrap().get(b"alpha".as_slice()),
+            Some(&ttl)
+        );
+
+        let put_in = handle_sys_session::serialize_session_put_param(1, b"beta", b"2");
+        put_internal_with_worker_local(&put_in, Some(fake.clone())).unwrap();
+        assert_eq!(fake.ttls.lock().unwrap().get(b"beta".as_slice()), None);
+    }
+
+    #[tokio::test]
+    async fn async_put_with_ttl_reaches_worker_local() {
+        let fake = Arc::new(FakeWorkerLocal::new());
+        let ttl = Duration::from_millis(250);
+
+        let out = async_put_internal_with_worker_local(
+            handle_sys_session::serialize_session_put_with_ttl_param(1, b"alpha", b"1", ttl),
+            Some(fake.clone()),
+        )
+        .await;
+        handle_sys_session::deserialize_put_result(&out).unwrap();
+        assert_eq!(
+            fake.ttls.lock().unwrap().get(b"alpha".as_slice()),
+            Some(&ttl)
+        );
+    }
+
+    #[test]
+    fn multi_key_operations_with_worker_local_round_trip() {
+        let wl = worker_local();
+        let sid = 1u128;
+
+        let put_many_in = handle_sys_session::serialize_session_put_many_param(
+            sid,
+            &[
+                (b"a".to_vec(), b"1".to_vec()),
+                (b"b".to_vec(), b"2".to_vec()),
+                (b"c".to_vec(), b"3".to_vec()),
+            ],
+        );
+        let put_many_out =
+            put_many_internal_with_worker_local(&put_many_in, Some(wl.clone())).unwrap();
+        handle_sys_session::deserialize_put_many_result(&put_many_out).unwrap();
+
+        let delete_many_in = handle_sys_session::serialize_session_delete_many_param(
+            sid,
+            &[b"a".to_vec(), b"c".to_vec()],
+        );
+        let delete_many_out =
+            delete_many_internal_with_worker_local(&delete_many_in, Some(wl.clone())).unwrap();
+        handle_sys_session::deserialize_delete_many_result(&delete_many_out).unwrap();
+
+        let range_in = handle_sys_session::serialize_session_range_param(sid, b"a", b"z");
+        let range_out = range_internal_with_worker_local(&range_in, Some(wl.clone())).unwrap();
+        let items = handle_sys_session::deserialize_range_result(&range_out).unwrap();
+        assert_eq!(items, vec![(b"b".to_vec(), b"2".to_vec())]);
+    }
+
+    #[test]
+    fn range_page_with_worker_local_round_trip() {
+        let wl = worker_local();
+        let sid = 1u128;
+        let put_many_in = handle_sys_session::serialize_session_put_many_param(
+            sid,
+            &[
+                (b"a".to_vec(), b"1".to_vec()),
+                (b"b".to_vec(), b"2".to_vec()),
+                (b"c".to_vec(), b"3".to_vec()),
+            ],
+        );
+        put_many_internal_with_worker_local(&put_many_in, Some(wl.clone())).unwrap();
+
+        let options = KvRangeOptions::new().with_limit(2).with_reverse(true);
+        let range_in =
+            handle_sys_session::serialize_session_range_page_param(sid, b"a", b"", &options);
+        let range_out = range_internal_with_worker_local(&range_in, Some(wl.clone())).unwrap();
+        let page = handle_sys_session::deserialize_range_page_result(&range_out).unwrap();
+        assert_eq!(
+            page.items(),
+            &[
+                (b"c".to_vec(), b"3".to_vec()),
+                (b"b".to_vec(), b"2".to_vec())
+            ]
+        );
+        assert_eq!(page.continuation(), Some(b"b".as_slice()));
+
+        let options = options
+            .with_keys_only(true)
+            .with_continuation(b"b".to_vec());
+        let range_in =
+            handle_sys_session::serialize_session_range_page_param(sid, b"a", b"", &options);
+        let range_out = range_internal_with_worker_local(&range_in, Some(wl.clone())).unwrap();
+        let page = handle_sys_session::deserialize_range_page_result(&range_out).unwrap();
+        assert_eq!(page.items(), &[(b"a".to_vec(), Vec::new())]);
+        assert_eq!(page.continuation(), None);
+    }
+
+    #[test]
+    fn atomic_operations_with_worker_local_round_trip() {
+        let wl = worker_local();
+        let sid = 1u128;
+
+        let incr_in = handle_sys_session::serialize_session_incr_param(sid, b"n", 4);
+        let incr_out = incr_internal_with_worker_local(&incr_in, Some(wl.clone())).unwrap();
+        assert_eq!(
+            handle_sys_session::deserialize_incr_result(&incr_out).unwrap(),
+            4
+        );
+
+        let cas_in = handle_sys_session::serialize_session_cas_param(sid, b"n", Some(b"4"), b"9");
+        let cas_out = cas_internal_with_worker_local(&cas_in, Some(wl.clone())).unwrap();
+        assert!(handle_sys_session::deserialize_cas_result(&cas_out).unwrap());
+        let cas_out = cas_internal_with_worker_local(&cas_in, Some(wl.clone())).unwrap();
+        assert!(!handle_sys_session::deserialize_cas_result(&cas_out).unwrap());
+
+        // The fake relies on the trait default, which reports NotImplemented.
+        let put_if_absent_in =
+            handle_sys_session::serialize_session_put_if_absent_param(sid, b"p", b"1");
+        let err = put_if_absent_internal_with_worker_local(&put_if_absent_in, Some(wl.clone()))
+            .unwrap_err();
+        assert_eq!(err.ec(), mudu::error::ErrorCode::NotImplemented);
+    }
+
+    #[tokio::test]
+    async fn async_atomic_operations_with_worker_local_round_trip() {
+        let wl = worker_local();
+        let sid = 1u128;
+
+        let out = async_incr_internal_with_worker_local(
+            handle_sys_session::serialize_session_incr_param(sid, b"n", -2),
+            Some(wl.clone()),
+        )
+        .await;
+        assert_eq!(
+            handle_sys_session::deserialize_incr_result(&out).unwrap(),
+            -2
+        );
+
+        let out = async_cas_internal_with_worker_local(
+            handle_sys_session::serialize_session_cas_param(sid, b"c", None, b"v"),
+            Some(wl.clone()),
+        )
+        .await;
+        assert!(handle_sys_session::deserialize_cas_result(&out).unwrap());
+
+        let out = async_put_if_absent_internal_with_worker_local(
+            handle_sys_session::serialize_session_put_if_absent_param(sid, b"c", b"w"),
+            Some(wl.clone()),
+        )
+        .await;
+        assert!(handle_sys_session::deserialize_put_if_absent_result(&out).is_err());
+    }
+
+    #[tokio::test]
+    async fn async_multi_key_operations_with_worker_local_round_trip() {
+        let wl = worker_local();
+        let sid = 1u128;
+
+        let put_many_in = handle_sys_session::serialize_session_put_many_param(
+            sid,
+            &[
+                (b"x".to_vec(), b"1".to_vec()),
+                (b"y".to_vec(), b"2".to_vec()),
+            ],
+        );
+        let out = async_put_many_internal_with_worker_local(put_many_in, Some(wl.clone())).await;
+        handle_sys_session::deserialize_put_many_result(&out).unwrap();
+
+        let delete_many_in =
+            handle_sys_session::serialize_session_delete_many_param(sid, &[b"x".to_vec()]);
+        let out =
+            async_delete_many_internal_with_worker_local(delete_many_in, Some(wl.clone())).await;
+        handle_sys_session::deserialize_delete_many_result(&out).unwrap();
+
+        let range_in = handle_sys_session::serialize_session_range_param(sid, b"a", b"z");
+        let range_out = async_range_internal_with_worker_local(range_in, Some(wl.clone())).await;
+        let items = handle_sys_session::deserialize_range_result(&range_out).unwrap();
+        assert_eq!(items, vec![(b"y".to_vec(), b"2".to_vec())]);
+
+        let out = async_put_many_internal(handle_sys_session::serialize_session_put_many_param(
+            sid,
+            &[],
+        ))
+        .await;
+        assert!(handle_sys_session::deserialize_put_many_result(&out).is_err());
+    }
+
     #[test]
     fn kv_operations_without_worker_local_return_error_bytes() {
         let get_in = handle_sys_session::serialize_session_get_param(1, b"alpha");
@@ -465,6 +746,93 @@ mod tests {
         handle_sys_session::deserialize_close_result(&close_out).unwrap();
     }
 
+    fn app_info(name: &str) -> AppInfo {
+        AppInfo {
+            name: name.to_string(),
+            lang: "rust".to_string(),
+            version: "0.1.0".to_string(),
+            use_async: false,
+        }
+    }
+
+    #[test]
+    fn kv_namespace_operations_are_scoped_to_the_calling_app() {
+        let wl = worker_local();
+        let shop = app_info("shop");
+        let other = app_info("other");
+
+        let put_in = handle_sys_session::serialize_session_kv_namespace_param(
+            1,
+            &KvNamespaceOp::Put {
+                name: "carts".to_string(),
+                key: b"alice".to_vec(),
+                value: b"3".to_vec(),
+            },
+        );
+        let put_out =
+            kv_namespace_internal_with_worker_local(&put_in, Some(&shop), Some(wl.clone()))
+                .unwrap();
+        assert_eq!(
+            handle_sys_session::deserialize_kv_namespace_result(&put_out).unwrap(),
+            KvNamespaceOpResult::Done
+        );
+
+        let get_in = handle_sys_session::serialize_session_kv_namespace_param(
+            1,
+            &KvNamespaceOp::Get {
+                name: "carts".to_string(),
+                key: b"alice".to_vec(),
+            },
+        );
+        let get_out =
+            kv_namespace_internal_with_worker_local(&get_in, Some(&shop), Some(wl.clone()))
+                .unwrap();
+        assert_eq!(
+            handle_sys_session::deserialize_kv_namespace_result(&get_out).unwrap(),
+            KvNamespaceOpResult::Value(Some(b"3".to_vec()))
+        );
+        let get_out =
+            kv_namespace_internal_with_worker_local(&get_in, Some(&other), Some(wl.clone()))
+                .unwrap();
+        assert_eq!(
+            handle_sys_session::deserialize_kv_namespace_result(&get_out).unwrap(),
+            KvNamespaceOpResult::Value(None)
+        );
+
+        let list_in =
+            handle_sys_session::serialize_session_kv_namespace_param(1, &KvNamespaceOp::List);
+        let list_out =
+            kv_namespace_internal_with_worker_local(&list_in, Some(&shop), Some(wl.clone()))
+                .unwrap();
+        assert_eq!(
+            handle_sys_session::deserialize_kv_namespace_result(&list_out).unwrap(),
+            KvNamespaceOpResult::Names(vec!["carts".to_string()])
+        );
+    }
+
+    #[tokio::test]
+    async fn async_kv_namespace_operations_require_an_app() {
+        let wl = worker_local();
+        let list_in =
+            handle_sys_session::serialize_session_kv_namespace_param(1, &KvNamespaceOp::List);
+
+        let out =
+            async_kv_namespace_internal_with_worker_local(list_in.clone(), None, Some(wl.clone()))
+                .await;
+        assert!(handle_sys_session::deserialize_kv_namespace_result(&out).is_err());
+
+        let out = async_kv_namespace_internal_with_worker_local(
+            list_in,
+            Some(app_info("shop")),
+            Some(wl),
+        )
+        .await;
+        assert_eq!(
+            handle_sys_session::deserialize_kv_namespace_result(&out).unwrap(),
+            KvNamespaceOpResult::Names(Vec::new())
+        );
+    }
+
     #[tokio::test]
     async fn async_kv_operations_without_worker_local_return_error_bytes() {
         let get_in = handle_sys_session::serialize_session_get_param(1, b"alpha");
@@ -179,6 +179,7 @@ impl AppInstImplInner {
             Default::default(),
             param,
             worker_local,
+            Some(self.package_cfg.clone()),
         );
         if new_tx {
             if result.is_ok() {
@@ -221,6 +222,7 @@ impl AppInstImplInner {
             Default::default(),
             param,
             worker_local,
+            Some(self.package_cfg.clone()),
         )
         .await;
         if new_tx {
@@ -1,4 +1,5 @@
 use crate::interface::kernel;
+use mudu::common::app_info::AppInfo;
 use mudu_kernel::server::worker_local::WorkerLocalRef;
 
 pub fn host_query(query_in: Vec<u8>) -> Vec<u8> {
@@ -42,6 +43,43 @@ pub fn host_delete(delete_in: Vec<u8>, worker_local: Option<WorkerLocalRef>) ->
         .unwrap_or_else(mudu_binding::codec::handle_sys_session::serialize_error_result)
 }
 
+pub fn host_put_many(put_many_in: Vec<u8>, worker_local: Option<WorkerLocalRef>) -> Vec<u8> {
+    kernel::put_many_internal_with_worker_local(&put_many_in, worker_local)
+        .unwrap_or_else(mudu_binding::codec::handle_sys_session::serialize_error_result)
+}
+
+pub fn host_delete_many(delete_many_in: Vec<u8>, worker_local: Option<WorkerLocalRef>) -> Vec<u8> {
+    kernel::delete_many_internal_with_worker_local(&delete_many_in, worker_local)
+        .unwrap_or_else(mudu_binding::codec::handle_sys_session::serialize_error_result)
+}
+
+pub fn host_cas(cas_in: Vec<u8>, worker_local: Option<WorkerLocalRef>) -> Vec<u8> {
+    kernel::cas_internal_with_worker_local(&cas_in, worker_local)
+        .unwrap_or_else(mudu_binding::codec::handle_sys_session::serialize_error_result)
+}
+
+pub fn host_incr(incr_in: Vec<u8>, worker_local: Option<WorkerLocalRef>) -> Vec<u8> {
+    kernel::incr_internal_with_worker_local(&incr_in, worker_local)
+        .unwrap_or_else(mudu_binding::codec::handle_sys_session::serialize_error_result)
+}
+
+pub fn host_put_if_absent(
+    put_if_absent_in: Vec<u8>,
+    worker_local: Option<WorkerLocalRef>,
+) -> Vec<u8> {
+    kernel::put_if_absent_internal_with_worker_local(&put_if_absent_in, worker_local)
+        .unwrap_or_else(mudu_binding::codec::handle_sys_session::serialize_error_result)
+}
+
+pub fn host_kv_namespace(
+    kv_namespace_in: Vec<u8>,
+    app_info: Option<&AppInfo>,
+    worker_local: Option<WorkerLocalRef>,
+) -> Vec<u8> {
+    kernel::kv_namespace_internal_with_worker_local(&kv_namespace_in, app_info, worker_local)
+        .unwrap_or_else(mudu_binding::codec::handle_sys_session::serialize_error_result)
+}
+
 pub fn host_range(range_in: Vec<u8>, worker_local: Option<WorkerLocalRef>) -> Vec<u8> {
     kernel::range_internal_with_worker_local(&range_in, worker_local)
         .unwrap_or_else(mudu_binding::codec::handle_sys_session::serialize_error_result)
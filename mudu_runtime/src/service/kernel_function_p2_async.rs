@@ -1,4 +1,5 @@
 use crate::interface::kernel;
+use mudu::common::app_info::AppInfo;
 use mudu_kernel::server::worker_local::WorkerLocalRef;
 
 pub async fn async_host_query(query_in: Vec<u8>) -> Vec<u8> {
@@ -40,6 +41,44 @@ pub async fn async_host_delete(
     kernel::async_delete_internal_with_worker_local(delete_in, worker_local).await
 }
 
+pub async fn async_host_put_many(
+    put_many_in: Vec<u8>,
+    worker_local: Option<WorkerLocalRef>,
+) -> Vec<u8> {
+    kernel::async_put_many_internal_with_worker_local(put_many_in, worker_local).await
+}
+
+pub async fn async_host_delete_many(
+    delete_many_in: Vec<u8>,
+    worker_local: Option<WorkerLocalRef>,
+) -> Vec<u8> {
+    kernel::async_delete_many_internal_with_worker_local(delete_many_in, worker_local).await
+}
+
+pub async fn async_host_cas(cas_in: Vec<u8>, worker_local: Option<WorkerLocalRef>) -> Vec<u8> {
+    kernel::async_cas_internal_with_worker_local(cas_in, worker_local).await
+}
+
+pub async fn async_host_incr(incr_in: Vec<u8>, worker_local: Option<WorkerLocalRef>) -> Vec<u8> {
+    kernel::async_incr_internal_with_worker_local(incr_in, worker_local).await
+}
+
+pub async fn async_host_put_if_absent(
+    put_if_absent_in: Vec<u8>,
+    worker_local: Option<WorkerLocalRef>,
+) -> Vec<u8> {
+    kernel::async_put_if_absent_internal_with_worker_local(put_if_absent_in, worker_local).await
+}
+
+pub async fn async_host_kv_namespace(
+    kv_namespace_in: Vec<u8>,
+    app_info: Option<AppInfo>,
+    worker_local: Option<WorkerLocalRef>,
+) -> Vec<u8> {
+    kernel::async_kv_namespace_internal_with_worker_local(kv_namespace_in, app_info, worker_local)
+        .await
+}
+
 pub async fn async_host_range(range_in: Vec<u8>, worker_local: Option<WorkerLocalRef>) -> Vec<u8> {
     kernel::async_range_internal_with_worker_local(range_in, worker_local).await
 }
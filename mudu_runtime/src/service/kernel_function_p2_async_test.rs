@@ -1,14 +1,16 @@
 #[cfg(test)]
 mod tests {
     use crate::service::kernel_function_p2_async::{
-        async_host_batch, async_host_close, async_host_command, async_host_delete,
-        async_host_fetch, async_host_get, async_host_open, async_host_put, async_host_query,
-        async_host_range,
+        async_host_batch, async_host_cas, async_host_close, async_host_command, async_host_delete,
+        async_host_delete_many, async_host_fetch, async_host_get, async_host_incr,
+        async_host_kv_namespace, async_host_open, async_host_put, async_host_put_if_absent,
+        async_host_put_many, async_host_query, async_host_range,
     };
     use mudu::common::serde_utils::deserialize_from;
     use mudu_binding::codec::handle_sys_session;
     use mudu_binding::system::{command_invoke, query_invoke};
     use mudu_binding::universal::uni_error::UniError;
+    use mudu_contract::database::kv_namespace::KvNamespaceOp;
 
     const MERR_MAGIC: &[u8] = b"MERR";
 
@@ -69,6 +71,57 @@ mod tests {
         assert_worker_local_error(&output);
     }
 
+    #[tokio::test]
+    async fn async_host_put_many_without_worker_local_returns_decodable_error() {
+        let input = handle_sys_session::serialize_session_put_many_param(
+            1,
+            &[(b"alpha".to_vec(), b"1".to_vec())],
+        );
+        let output = async_host_put_many(input, None).await;
+        assert!(output.starts_with(MERR_MAGIC));
+        assert_worker_local_error(&output);
+    }
+
+    #[tokio::test]
+    async fn async_host_delete_many_without_worker_local_returns_decodable_error() {
+        let input =
+            handle_sys_session::serialize_session_delete_many_param(1, &[b"alpha".to_vec()]);
+        let output = async_host_delete_many(input, None).await;
+        assert!(output.starts_with(MERR_MAGIC));
+        assert_worker_local_error(&output);
+    }
+
+    #[tokio::test]
+    async fn async_host_atomic_updates_without_worker_local_return_decodable_error() {
+        let input = handle_sys_session::serialize_session_cas_param(1, b"alpha", None, b"1");
+        let output = async_host_cas(input, None).await;
+        assert!(output.starts_with(MERR_MAGIC));
+        assert_worker_local_error(&output);
+
+        let input = handle_sys_session::serialize_session_incr_param(1, b"alpha", 1);
+        let output = async_host_incr(input, None).await;
+        assert!(output.starts_with(MERR_MAGIC));
+        assert_worker_local_error(&output);
+
+        let input = handle_sys_session::serialize_session_put_if_absent_param(1, b"alpha", b"1");
+        let output = async_host_put_if_absent(input, None).await;
+        assert!(output.starts_with(MERR_MAGIC));
+        assert_worker_local_error(&output);
+    }
+
+    #[tokio::test]
+    async fn async_host_kv_namespace_without_app_returns_decodable_error() {
+        let input =
+            handle_sys_session::serialize_session_kv_namespace_param(1, &KvNamespaceOp::List);
+        let output = async_host_kv_namespace(input, None, None).await;
+        let err = decode_merr_payload(&output);
+        assert!(
+            err.err_msg.contains("procedures of an installed app"),
+            "unexpected error message: {}",
+            err.err_msg
+        );
+    }
+
     #[tokio::test]
     async fn async_host_range_without_worker_local_returns_decodable_error() {
         let input = handle_sys_session::serialize_session_range_param(1, b"a", b"z");
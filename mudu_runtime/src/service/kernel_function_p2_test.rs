@@ -1,13 +1,15 @@
 #[cfg(test)]
 mod tests {
     use crate::service::kernel_function_p2::{
-        host_batch, host_close, host_command, host_delete, host_fetch, host_get, host_open,
-        host_put, host_query, host_range,
+        host_batch, host_cas, host_close, host_command, host_delete, host_delete_many, host_fetch,
+        host_get, host_incr, host_kv_namespace, host_open, host_put, host_put_if_absent,
+        host_put_many, host_query, host_range,
     };
     use mudu::common::serde_utils::deserialize_from;
     use mudu_binding::codec::handle_sys_session;
     use mudu_binding::system::{command_invoke, query_invoke};
     use mudu_binding::universal::uni_error::UniError;
+    use mudu_contract::database::kv_namespace::KvNamespaceOp;
 
     const MERR_MAGIC: &[u8] = b"MERR";
 
@@ -68,6 +70,57 @@ mod tests {
         assert_worker_local_error(&output);
     }
 
+    #[test]
+    fn host_put_many_without_worker_local_returns_decodable_error() {
+        let input = handle_sys_session::serialize_session_put_many_param(
+            1,
+            &[(b"alpha".to_vec(), b"1".to_vec())],
+        );
+        let output = host_put_many(input, None);
+        assert!(output.starts_with(MERR_MAGIC));
+        assert_worker_local_error(&output);
+    }
+
+    #[test]
+    fn host_delete_many_without_worker_local_returns_decodable_error() {
+        let input =
+            handle_sys_session::serialize_session_delete_many_param(1, &[b"alpha".to_vec()]);
+        let output = host_delete_many(input, None);
+        assert!(output.starts_with(MERR_MAGIC));
+        assert_worker_local_error(&output);
+    }
+
+    #[test]
+    fn host_atomic_updates_without_worker_local_return_decodable_error() {
+        let input = handle_sys_session::serialize_session_cas_param(1, b"alpha", None, b"1");
+        let output = host_cas(input, None);
+        assert!(output.starts_with(MERR_MAGIC));
+        assert_worker_local_error(&output);
+
+        let input = handle_sys_session::serialize_session_incr_param(1, b"alpha", 1);
+        let output = host_incr(input, None);
+        assert!(output.starts_with(MERR_MAGIC));
+        assert_worker_local_error(&output);
+
+        let input = handle_sys_session::serialize_session_put_if_absent_param(1, b"alpha", b"1");
+        let output = host_put_if_absent(input, None);
+        assert!(output.starts_with(MERR_MAGIC));
+        assert_worker_local_error(&output);
+    }
+
+    #[test]
+    fn host_kv_namespace_without_app_returns_decodable_error() {
+        let input =
+            handle_sys_session::serialize_session_kv_namespace_param(1, &KvNamespaceOp::List);
+        let output = host_kv_namespace(input, None, None);
+        let err = decode_merr_payload(&output);
+        assert!(
+            err.err_msg.contains("procedures of an installed app"),
+            "unexpected error message: {}",
+            err.err_msg
+        );
+    }
+
     #[test]
     fn host_range_without_worker_local_returns_decodable_error() {
         let input = handle_sys_session::serialize_session_range_param(1, b"a", b"z");
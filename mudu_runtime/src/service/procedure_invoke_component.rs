@@ -3,6 +3,7 @@
 use crate::procedure::procedure::Procedure;
 use crate::service::runtime_opt::ComponentTarget;
 use crate::service::wasi_context_component::{WasiContextComponent, build_wasi_component_context};
+use mudu::common::app_info::AppInfo;
 use mudu::common::result::RS;
 use mudu::error::ErrorCode;
 use mudu::mudu_error;
@@ -27,10 +28,11 @@ impl ProcedureInvokeComponent {
         proc_opt: ProcOpt,
         param: ProcedureParam,
         worker_local: Option<WorkerLocalRef>,
+        app_info: Option<AppInfo>,
     ) -> RS<ProcedureResult> {
         let name = component_proc_name(component_target, procedure.proc_name())?;
         let name = to_kebab_case(&name);
-        let context = build_wasi_component_context(worker_local);
+        let context = build_wasi_component_context(worker_local).with_app_info(app_info);
         let instance_pre = procedure.instance().as_component_instance_pre().clone();
 
         let thread = mudu_sys::task::sync::spawn_thread(move || {
@@ -55,13 +57,14 @@ impl ProcedureInvokeComponent {
         proc_opt: ProcOpt,
         param: ProcedureParam,
         worker_local: Option<WorkerLocalRef>,
+        app_info: Option<AppInfo>,
     ) -> RS<ProcedureResult> {
         let trace = task_trace!();
         trace.watch("procedure.component.stage", "call_async_start");
         let name = component_proc_name(component_target, procedure.proc_name())?;
         let name = to_kebab_case(&name);
         trace.watch("procedure.component.name", &name);
-        let context = build_wasi_component_context(worker_local);
+        let context = build_wasi_component_context(worker_local).with_app_info(app_info);
         let p = procedure.instance().as_component_instance_pre();
         let this: Self = Self::new_async(context, p, name, proc_opt).await?;
         trace.watch("procedure.component.stage", "invoke_async_start");
@@ -270,6 +273,7 @@ mod tests {
             ProcOpt::default(),
             sample_param(),
             None,
+            None,
         );
 
         let err = result.unwrap_err();
@@ -287,6 +291,7 @@ mod tests {
             ProcOpt::default(),
             sample_param(),
             None,
+            None,
         );
         let err = result.unwrap_err();
         assert_eq!(err.ec(), ErrorCode::NotImplemented);
@@ -302,6 +307,7 @@ mod tests {
             ProcOpt::default(),
             sample_param(),
             None,
+            None,
         )
         .await;
 
@@ -325,6 +331,7 @@ mod tests {
             ProcOpt::default(),
             sample_param(),
             None,
+            None,
         );
 
         let result = result.expect("sync call should succeed");
@@ -1,3 +1,4 @@
+use mudu::common::app_info::AppInfo;
 use mudu_kernel::server::worker_local::WorkerLocalRef;
 use wasmtime::component::ResourceTable;
 use wasmtime_wasi::{WasiCtx, WasiCtxBuilder, WasiCtxView, WasiView};
@@ -7,6 +8,7 @@ pub struct WasiContextComponent {
     ctx: WasiCtx,
     table: ResourceTable,
     worker_local: Option<WorkerLocalRef>,
+    app_info: Option<AppInfo>,
 }
 
 impl WasiView for WasiContextComponent {
@@ -24,12 +26,24 @@ impl WasiContextComponent {
             ctx,
             table: Default::default(),
             worker_local,
+            app_info: None,
         }
     }
 
+    /// Sets the app whose procedure runs in this context; KV namespace
+    /// syscalls are scoped to it.
+    pub fn with_app_info(mut self, app_info: Option<AppInfo>) -> Self {
+        self.app_info = app_info;
+        self
+    }
+
     pub fn worker_local(&self) -> Option<WorkerLocalRef> {
         self.worker_local.clone()
     }
+
+    pub fn app_info(&self) -> Option<&AppInfo> {
+        self.app_info.as_ref()
+    }
 }
 
 pub fn build_wasi_component_context(worker_local: Option<WorkerLocalRef>) -> WasiContextComponent {
@@ -41,8 +55,9 @@ pub fn build_wasi_component_context(worker_local: Option<WorkerLocalRef>) -> Was
 pub mod sync_host {
     use super::WasiContextComponent;
     use crate::service::kernel_function_p2::{
-        host_batch, host_close, host_command, host_delete, host_fetch, host_get, host_open,
-        host_put, host_query, host_range,
+        host_batch, host_cas, host_close, host_command, host_delete, host_delete_many, host_fetch,
+        host_get, host_incr, host_kv_namespace, host_open, host_put, host_put_if_absent,
+        host_put_many, host_query, host_range,
     };
     use wasmtime::component::bindgen;
 
@@ -84,18 +99,43 @@ pub mod sync_host {
             host_delete(delete_in, self.worker_local())
         }
 
+        fn put_many(&mut self, put_many_in: Vec<u8>) -> Vec<u8> {
+            host_put_many(put_many_in, self.worker_local())
+        }
+
+        fn delete_many(&mut self, delete_many_in: Vec<u8>) -> Vec<u8> {
+            host_delete_many(delete_many_in, self.worker_local())
+        }
+
+        fn cas(&mut self, cas_in: Vec<u8>) -> Vec<u8> {
+            host_cas(cas_in, self.worker_local())
+        }
+
+        fn incr(&mut self, incr_in: Vec<u8>) -> Vec<u8> {
+            host_incr(incr_in, self.worker_local())
+        }
+
+        fn put_if_absent(&mut self, put_if_absent_in: Vec<u8>) -> Vec<u8> {
+            host_put_if_absent(put_if_absent_in, self.worker_local())
+        }
+
         fn range(&mut self, range_in: Vec<u8>) -> Vec<u8> {
             host_range(range_in, self.worker_local())
         }
+
+        fn kv_namespace(&mut self, kv_namespace_in: Vec<u8>) -> Vec<u8> {
+            host_kv_namespace(kv_namespace_in, self.app_info(), self.worker_local())
+        }
     }
 }
 
 pub mod async_host {
     use super::WasiContextComponent;
     use crate::service::kernel_function_p2_async::{
-        async_host_batch, async_host_close, async_host_command, async_host_delete,
-        async_host_fetch, async_host_get, async_host_open, async_host_put, async_host_query,
-        async_host_range,
+        async_host_batch, async_host_cas, async_host_close, async_host_command, async_host_delete,
+        async_host_delete_many, async_host_fetch, async_host_get, async_host_incr,
+        async_host_kv_namespace, async_host_open, async_host_put, async_host_put_if_absent,
+        async_host_put_many, async_host_query, async_host_range,
     };
     use wasmtime::component::{Accessor, HasData, HasSelf, bindgen};
 
@@ -183,6 +223,48 @@ pub mod async_host {
             async_host_delete(delete_in, worker).await
         }
 
+        async fn put_many(
+            accessor: &Accessor<WasiContextComponent, Self>,
+            put_many_in: Vec<u8>,
+        ) -> Vec<u8> {
+            let worker = accessor.with(|mut access| access.get().worker_local());
+
+            async_host_put_many(put_many_in, worker).await
+        }
+
+        async fn delete_many(
+            accessor: &Accessor<WasiContextComponent, Self>,
+            delete_many_in: Vec<u8>,
+        ) -> Vec<u8> {
+            let worker = accessor.with(|mut access| access.get().worker_local());
+
+            async_host_delete_many(delete_many_in, worker).await
+        }
+
+        async fn cas(accessor: &Accessor<WasiContextComponent, Self>, cas_in: Vec<u8>) -> Vec<u8> {
+            let worker = accessor.with(|mut access| access.get().worker_local());
+
+            async_host_cas(cas_in, worker).await
+        }
+
+        async fn incr(
+            accessor: &Accessor<WasiContextComponent, Self>,
+            incr_in: Vec<u8>,
+        ) -> Vec<u8> {
+            let worker = accessor.with(|mut access| access.get().worker_local());
+
+            async_host_incr(incr_in, worker).await
+        }
+
+        async fn put_if_absent(
+            accessor: &Accessor<WasiContextComponent, Self>,
+            put_if_absent_in: Vec<u8>,
+        ) -> Vec<u8> {
+            let worker = accessor.with(|mut access| access.get().worker_local());
+
+            async_host_put_if_absent(put_if_absent_in, worker).await
+        }
+
         async fn range(
             accessor: &Accessor<WasiContextComponent, Self>,
             range_in: Vec<u8>,
@@ -191,5 +273,17 @@ pub mod async_host {
 
             async_host_range(range_in, worker).await
         }
+
+        async fn kv_namespace(
+            accessor: &Accessor<WasiContextComponent, Self>,
+            kv_namespace_in: Vec<u8>,
+        ) -> Vec<u8> {
+            let (app_info, worker) = accessor.with(|mut access| {
+                let context = access.get();
+                (context.app_info().cloned(), context.worker_local())
+            });
+
+            async_host_kv_namespace(kv_namespace_in, app_info, worker).await
+        }
     }
 }
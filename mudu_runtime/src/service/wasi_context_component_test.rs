@@ -6,19 +6,21 @@ mod tests {
         WasiContextComponent, build_wasi_component_context, sync_host,
     };
     use async_trait::async_trait;
+    use mudu::common::app_info::AppInfo;
     use mudu::common::id::OID;
     use mudu::common::result::RS;
     use mudu::common::serde_utils::deserialize_from;
     use mudu_binding::codec::handle_sys_session;
     use mudu_binding::system::{command_invoke, query_invoke};
     use mudu_binding::universal::uni_error::UniError;
+    use mudu_contract::database::kv_namespace::KvNamespaceOp;
+    use mudu_contract::database::kv_range::{KvRangeOptions, KvRangePage};
     use mudu_contract::database::result_set::ResultSetAsync;
     use mudu_contract::database::sql_params::SQLParams;
     use mudu_contract::database::sql_stmt::SQLStmt;
     use mudu_kernel::contract::meta_mgr::MetaMgr;
     use mudu_kernel::server::message_bus_api::MessageBusRef;
     use mudu_kernel::server::worker_local::{WorkerExecute, WorkerLocal, WorkerLocalRef};
-    use mudu_kernel::server::worker_snapshot::KvItem;
     use mudu_kernel::x_engine::api::XContract;
     use std::sync::Arc;
     use sync_host::mududb::api::system::Host;
@@ -166,6 +168,28 @@ mod tests {
         assert_worker_local_error(&output);
     }
 
+    #[test]
+    fn sync_host_kv_namespace_is_scoped_to_the_context_app() {
+        let input =
+            handle_sys_session::serialize_session_kv_namespace_param(1, &KvNamespaceOp::List);
+        let mut ctx = build_wasi_component_context(None);
+        let err = decode_merr_payload(&ctx.kv_namespace(input.clone()));
+        assert!(
+            err.err_msg.contains("procedures of an installed app"),
+            "unexpected error message: {}",
+            err.err_msg
+        );
+
+        let mut ctx = build_wasi_component_context(None).with_app_info(Some(AppInfo {
+            name: "shop".to_string(),
+            lang: "rust".to_string(),
+            version: "0.1.0".to_string(),
+            use_async: false,
+        }));
+        assert_eq!(ctx.app_info().map(|info| info.name.as_str()), Some("shop"));
+        assert_worker_local_error(&ctx.kv_namespace(input));
+    }
+
     #[test]
     fn sync_host_query_malformed_input_does_not_panic() {
         let mut ctx = build_wasi_component_context(None);
@@ -219,7 +243,8 @@ mod tests {
             _session_id: OID,
             _start_key: &[u8],
             _end_key: &[u8],
-        ) -> RS<Vec<KvItem>> {
+            _options: &KvRangeOptions,
+        ) -> RS<KvRangePage> {
             unimplemented!()
         }
 
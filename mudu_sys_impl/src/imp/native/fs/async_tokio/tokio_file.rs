@@ -61,7 +61,12 @@ impl TokioFile {
             .map_err(|e| io_error_with_message(e, "seek tokio file for write error"))?;
         file.write_all(payload)
             .await
-            .map_err(|e| io_error_with_message(e, "write tokio file error"))
+            .map_err(|e| io_error_with_message(e, "write tokio file error"))?;
+        // A tokio file buffers the write and hands it to a blocking thread;
+        // flushing waits until it reached the file.
+        file.flush()
+            .await
+            .map_err(|e| io_error_with_message(e, "flush tokio file error"))
     }
 
     pub(crate) async fn fsync(&self) -> RS<()> {
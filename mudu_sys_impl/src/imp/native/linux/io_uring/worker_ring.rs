@@ -1,7 +1,11 @@
 use std::cell::UnsafeCell;
-use std::collections::{HashMap, VecDeque};
+use std::collections::{BTreeMap, HashMap, VecDeque};
+use std::future::Future;
+use std::pin::Pin;
 use std::sync::atomic::{AtomicU64, Ordering};
 use std::sync::{Arc, Mutex};
+use std::task::{Context, Poll, Waker};
+use std::time::{Duration, Instant};
 
 use crate::task::async_::try_this_task_id;
 use crate::task::id::TaskID;
@@ -61,8 +65,16 @@ pub struct WorkerLocalRing {
     pending: Mutex<VecDeque<u64>>,
     ops: Mutex<HashMap<u64, WorkerRingOp>>,
     op_tasks: Mutex<HashMap<u64, TaskID>>,
+    next_timer_id: AtomicU64,
+    // Wakers of the tasks sleeping on this ring, by deadline. The worker loop
+    // fires the due ones every iteration and bounds its completion wait by
+    // the earliest one.
+    timers: Mutex<BTreeMap<TimerKey, Waker>>,
 }
 
+/// Identifies a timer registered with [`WorkerLocalRing::add_timer`].
+pub type TimerKey = (Instant, u64);
+
 impl Default for WorkerLocalRing {
     fn default() -> Self {
         Self::new()
@@ -81,6 +93,8 @@ impl WorkerLocalRing {
             pending: Mutex::new(VecDeque::new()),
             ops: Mutex::new(HashMap::new()),
             op_tasks: Mutex::new(HashMap::new()),
+            next_timer_id: AtomicU64::new(1),
+            timers: Mutex::new(BTreeMap::new()),
         }
     }
 
@@ -161,6 +175,130 @@ impl WorkerLocalRing {
             guard.remove(&op_id);
         }
     }
+
+    /// Wakes `waker` once `deadline` passes.
+    pub fn add_timer(&self, deadline: Instant, waker: Waker) -> RS<TimerKey> {
+        let key = (deadline, self.next_timer_id.fetch_add(1, Ordering::Relaxed));
+        self.timers
+            .lock()
+            .map_err(|_| mudu_error!(ErrorCode::Internal, "worker local ring lock poisoned"))?
+            .insert(key, waker);
+        Ok(key)
+    }
+
+    pub fn cancel_timer(&self, key: TimerKey) {
+        if let Ok(mut guard) = self.timers.lock() {
+            guard.remove(&key);
+        }
+    }
+
+    /// Wakes the tasks whose timers are due and returns how many there were.
+    pub fn fire_due_timers(&self) -> RS<usize> {
+        let now = *crate::time::instant_now();
+        let due = {
+            let mut timers = self
+                .timers
+                .lock()
+                .map_err(|_| mudu_error!(ErrorCode::Internal, "worker local ring lock poisoned"))?;
+            let pending = timers.split_off(&(now, u64::MAX));
+            std::mem::replace(&mut *timers, pending)
+        };
+        let fired = due.len();
+        for waker in due.into_values() {
+            waker.wake();
+        }
+        Ok(fired)
+    }
+
+    /// Time left until the earliest timer is due.
+    pub fn next_timer_timeout(&self) -> RS<Option<Duration>> {
+        let timers = self
+            .timers
+            .lock()
+            .map_err(|_| mudu_error!(ErrorCode::Internal, "worker local ring lock poisoned"))?;
+        Ok(timers.first_key_value().map(|((deadline, _), _)| {
+            deadline.saturating_duration_since(*crate::time::instant_now())
+        }))
+    }
+}
+
+/// Future of [`sleep`]: completes once its deadline passes, woken by the
+/// timers of the current worker ring.
+pub struct RingSleep {
+    deadline: Instant,
+    timer: Option<TimerKey>,
+}
+
+impl Future for RingSleep {
+    type Output = RS<()>;
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        let this = &mut *self;
+        if *crate::time::instant_now() >= this.deadline {
+            this.cancel();
+            return Poll::Ready(Ok(()));
+        }
+        // Worker tasks get a new waker on every poll, so the timer is
+        // registered again with the current one.
+        let registered = with_current_ring(|ring| {
+            if let Some(key) = this.timer.take() {
+                ring.cancel_timer(key);
+            }
+            ring.add_timer(this.deadline, cx.waker().clone())
+        });
+        match registered {
+            Ok(key) => {
+                this.timer = Some(key);
+                Poll::Pending
+            }
+            Err(err) => Poll::Ready(Err(err)),
+        }
+    }
+}
+
+impl RingSleep {
+    fn cancel(&mut self) {
+        if let Some(key) = self.timer.take() {
+            let _ = with_current_ring(|ring| {
+                ring.cancel_timer(key);
+                Ok(())
+            });
+        }
+    }
+}
+
+impl Drop for RingSleep {
+    fn drop(&mut self) {
+        self.cancel();
+    }
+}
+
+/// Sleeps for `dur` on the timers of the current worker ring.
+pub fn sleep(dur: Duration) -> RingSleep {
+    RingSleep {
+        deadline: *crate::time::instant_now() + dur,
+        timer: None,
+    }
+}
+
+/// Runs `fut` until it completes or `dur` passes on the timers of the
+/// current worker ring; `None` means the time ran out and `fut` was dropped.
+pub async fn timeout<F>(dur: Duration, fut: F) -> Option<F::Output>
+where
+    F: Future,
+{
+    let mut fut = std::pin::pin!(fut);
+    let mut sleep = sleep(dur);
+    std::future::poll_fn(|cx| {
+        if let Poll::Ready(output) = fut.as_mut().poll(cx) {
+            return Poll::Ready(Some(output));
+        }
+        match Pin::new(&mut sleep).poll(cx) {
+            Poll::Ready(_) => Poll::Ready(None),
+            Poll::Pending => Poll::Pending,
+        }
+    })
+    .await
 }
 
 pub fn set_current_worker_ring(ring: Arc<WorkerLocalRing>) {
@@ -236,3 +374,63 @@ pub fn complete_user_ring_op(op: UserIoInflight, result: i32, ring: &WorkerLocal
     }
     Ok(())
 }
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used, clippy::arc_with_non_send_sync)]
+mod tests {
+    use super::*;
+    use std::cell::RefCell;
+    use std::rc::Rc;
+
+    /// Runs the worker tasks of `ring` the way the worker loop does until
+    /// all of them completed.
+    fn drive(ring: &WorkerLocalRing) {
+        let registry = ring.worker_task_registry();
+        while !registry.is_empty() {
+            ring.fire_due_timers().unwrap();
+            registry.drain_completions();
+            for completed in registry.poll_ready() {
+                completed.into_result().unwrap();
+            }
+            if let Some(timeout) = ring.next_timer_timeout().unwrap() {
+                crate::task::sync::sleep_blocking(timeout);
+            }
+        }
+    }
+
+    #[test]
+    fn ring_timers_end_sleeps_and_timeouts() {
+        let ring = Arc::new(WorkerLocalRing::new());
+        set_current_worker_ring(ring.clone());
+        let outcomes = Rc::new(RefCell::new(Vec::new()));
+        for (limit, nap) in [(10, 3_600_000), (3_600_000, 5)] {
+            let outcomes = outcomes.clone();
+            ring.worker_task_registry().spawn(
+                None,
+                Box::pin(async move {
+                    let started = *crate::time::instant_now();
+                    let output = timeout(
+                        Duration::from_millis(limit),
+                        sleep(Duration::from_millis(nap)),
+                    )
+                    .await;
+                    outcomes
+                        .borrow_mut()
+                        .push((limit, output.is_some(), started.elapsed()));
+                    Ok(())
+                }),
+            );
+        }
+        drive(&ring);
+        unset_current_worker_ring();
+
+        let mut outcomes = outcomes.borrow().clone();
+        outcomes.sort_by_key(|(limit, _, _)| *limit);
+        assert!(!outcomes[0].1);
+        assert!(outcomes[0].2 >= Duration::from_millis(10));
+        assert!(outcomes[1].1);
+        assert!(outcomes[1].2 >= Duration::from_millis(5));
+        // The sleep the timeout cut short took its timer with it.
+        assert_eq!(ring.next_timer_timeout().unwrap(), None);
+    }
+}
@@ -50,15 +50,26 @@ pub fn current_poll_task_id() -> Option<TaskID> {
 pub struct TaskAsync;
 
 impl TaskAsync {
+    /// Sleeps for `dur`: on the timers of the worker ring when the current
+    /// thread runs one, otherwise on the Tokio timer.
     pub async fn sleep(dur: Duration) -> RS<()> {
+        #[cfg(target_os = "linux")]
+        if crate::io::worker_ring::has_current_worker_ring() {
+            return crate::io::worker_ring::sleep(dur).await;
+        }
         tokio::time::sleep(dur).await;
         Ok(())
     }
 
+    /// Runs `fut` for at most `dur`, timed like [`TaskAsync::sleep`].
     pub async fn timeout<F>(dur: Duration, fut: F) -> Option<F::Output>
     where
         F: Future,
     {
+        #[cfg(target_os = "linux")]
+        if crate::io::worker_ring::has_current_worker_ring() {
+            return crate::io::worker_ring::timeout(dur, fut).await;
+        }
         tokio::time::timeout(dur, fut).await.ok()
     }
 }
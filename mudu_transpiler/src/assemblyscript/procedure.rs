@@ -101,7 +101,7 @@ impl AsValueType {
         use mudu_type::dat_type_id::DatTypeID;
 
         match self {
-            Self::Boolean => DatType::new_no_param(DatTypeID::I32),
+            Self::Boolean => DatType::new_no_param(DatTypeID::Bool),
             Self::Int64 => DatType::new_no_param(DatTypeID::I64),
             Self::Float64 => DatType::new_no_param(DatTypeID::F64),
             Self::Text => DatType::default_for(DatTypeID::String),
@@ -114,7 +114,7 @@ impl AsValueType {
     pub fn dat_type_expr(&self) -> &'static str {
         match self {
             Self::Boolean => {
-                "::mududb::types::dat_type::DatType::new_no_param(::mududb::types::dat_type_id::DatTypeID::I32)"
+                "::mududb::types::dat_type::DatType::new_no_param(::mududb::types::dat_type_id::DatTypeID::Bool)"
             }
             Self::Int64 => {
                 "::mududb::types::dat_type::DatType::new_no_param(::mududb::types::dat_type_id::DatTypeID::I64)"
@@ -120,6 +120,9 @@ impl RustType {
     pub fn to_dat_type(&self, custom_types: &UniTypeDesc) -> RS<DatType> {
         let dat_type = match self {
             RustType::Primitive(s) => match s.as_str() {
+                "bool" => DatType::default_for(DatTypeID::Bool),
+                "i8" => DatType::default_for(DatTypeID::I8),
+                "i16" => DatType::default_for(DatTypeID::I16),
                 "i32" => DatType::default_for(DatTypeID::I32),
                 "i64" => DatType::default_for(DatTypeID::I64),
                 "i128" => DatType::default_for(DatTypeID::I128),
@@ -136,6 +139,8 @@ impl RustType {
             RustType::Custom(s) => match s.as_str() {
                 "OID" => DatType::default_for(DatTypeID::U128),
                 "String" => DatType::default_for(DatTypeID::String),
+                "UuidValue" => DatType::default_for(DatTypeID::Uuid),
+                "JsonValue" => DatType::default_for(DatTypeID::Json),
                 _ => {
                     let ty = custom_types.types.get(s).map_or_else(
                         || {
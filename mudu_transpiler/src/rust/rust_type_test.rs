@@ -118,6 +118,24 @@ fn to_ret_type_str_extracts_strings() -> Result<(), Box<dyn Error>> {
 fn to_dat_type_maps_primitives_and_customs() -> Result<(), Box<dyn Error>> {
     let custom = custom_types();
 
+    assert_eq!(
+        RustType::Primitive("bool".to_string())
+            .to_dat_type(&custom)?
+            .dat_type_id(),
+        DatTypeID::Bool
+    );
+    assert_eq!(
+        RustType::Primitive("i8".to_string())
+            .to_dat_type(&custom)?
+            .dat_type_id(),
+        DatTypeID::I8
+    );
+    assert_eq!(
+        RustType::Primitive("i16".to_string())
+            .to_dat_type(&custom)?
+            .dat_type_id(),
+        DatTypeID::I16
+    );
     assert_eq!(
         RustType::Primitive("i128".to_string())
             .to_dat_type(&custom)?
@@ -154,6 +172,18 @@ fn to_dat_type_maps_primitives_and_customs() -> Result<(), Box<dyn Error>> {
             .dat_type_id(),
         DatTypeID::String
     );
+    assert_eq!(
+        RustType::Custom("UuidValue".to_string())
+            .to_dat_type(&custom)?
+            .dat_type_id(),
+        DatTypeID::Uuid
+    );
+    assert_eq!(
+        RustType::Custom("JsonValue".to_string())
+            .to_dat_type(&custom)?
+            .dat_type_id(),
+        DatTypeID::Json
+    );
     assert_eq!(
         RustType::Custom("MyI32".to_string())
             .to_dat_type(&custom)?
@@ -167,7 +197,7 @@ fn to_dat_type_maps_primitives_and_customs() -> Result<(), Box<dyn Error>> {
 fn to_dat_type_rejects_unknown_types() -> Result<(), Box<dyn Error>> {
     let custom = custom_types();
 
-    let err = RustType::Primitive("bool".to_string())
+    let err = RustType::Primitive("u16".to_string())
         .to_dat_type(&custom)
         .err()
         .ok_or("expected an error")?;
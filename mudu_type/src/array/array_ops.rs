@@ -0,0 +1,45 @@
+//! Array element access, `= ANY(array)` and `unnest`, with SQL semantics.
+
+use crate::dat_type::DatType;
+use crate::dat_value::DatValue;
+use mudu::common::result::RS;
+use mudu::error::ErrorCode;
+use mudu::mudu_error;
+
+/// `array[index]`: indexes are 1-based and out-of-range access yields
+/// `None` (SQL NULL) instead of an error.
+pub fn array_element(array: &DatValue, index: i64) -> Option<&DatValue> {
+    let values = array.as_array()?;
+    let index = usize::try_from(index).ok()?.checked_sub(1)?;
+    values.get(index)
+}
+
+/// `value = ANY(array)`, comparing elements with the equality function of
+/// `elem_type`.
+pub fn array_any_eq(array: &DatValue, elem_type: &DatType, value: &DatValue) -> RS<bool> {
+    let fn_equal = elem_type.dat_type_id().fn_equal().ok_or_else(|| {
+        mudu_error!(
+            ErrorCode::NotImplemented,
+            format!("type {} is not comparable", elem_type.name())
+        )
+    })?;
+    let values = array
+        .as_array()
+        .ok_or_else(|| mudu_error!(ErrorCode::InvalidType, "ANY expects an array value"))?;
+    for elem in values {
+        if fn_equal(elem, value)
+            .map_err(|e| mudu_error!(ErrorCode::InvalidType, "compare array element error", e))?
+        {
+            return Ok(true);
+        }
+    }
+    Ok(false)
+}
+
+/// `unnest(array)`: one value per element, in array order.
+pub fn array_unnest(array: DatValue) -> RS<Vec<DatValue>> {
+    array
+        .as_array()
+        .ok_or_else(|| mudu_error!(ErrorCode::InvalidType, "unnest expects an array value"))?;
+    Ok(array.into_array())
+}
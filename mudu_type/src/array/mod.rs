@@ -1,3 +1,4 @@
+pub mod array_ops;
 pub mod dat_val_array;
 
 use crate::dat_type::DatType;
@@ -0,0 +1,40 @@
+#[cfg(test)]
+mod tests {
+    use crate::array::array_ops::{array_any_eq, array_element, array_unnest};
+    use crate::dat_type::DatType;
+    use crate::dat_type_id::DatTypeID;
+    use crate::dat_value::DatValue;
+
+    fn i32_array(values: &[i32]) -> DatValue {
+        DatValue::from_array(values.iter().map(|v| DatValue::from_i32(*v)).collect())
+    }
+
+    #[test]
+    fn element_access_is_one_based_and_null_when_out_of_range() {
+        let array = i32_array(&[10, 20, 30]);
+        assert_eq!(array_element(&array, 1).unwrap().to_i32(), 10);
+        assert_eq!(array_element(&array, 3).unwrap().to_i32(), 30);
+        assert!(array_element(&array, 0).is_none());
+        assert!(array_element(&array, 4).is_none());
+        assert!(array_element(&array, -1).is_none());
+        assert!(array_element(&DatValue::from_i32(1), 1).is_none());
+    }
+
+    #[test]
+    fn any_eq_uses_element_equality() {
+        let array = i32_array(&[1, 2, 3]);
+        let ty = DatType::new_no_param(DatTypeID::I32);
+        assert!(array_any_eq(&array, &ty, &DatValue::from_i32(2)).unwrap());
+        assert!(!array_any_eq(&array, &ty, &DatValue::from_i32(4)).unwrap());
+        assert!(!array_any_eq(&i32_array(&[]), &ty, &DatValue::from_i32(1)).unwrap());
+        assert!(array_any_eq(&DatValue::from_i32(1), &ty, &DatValue::from_i32(1)).is_err());
+    }
+
+    #[test]
+    fn unnest_returns_elements_in_order() {
+        let values = array_unnest(i32_array(&[3, 1, 2])).unwrap();
+        let values: Vec<i32> = values.iter().map(|v| v.to_i32()).collect();
+        assert_eq!(values, vec![3, 1, 2]);
+        assert!(array_unnest(DatValue::from_i32(1)).is_err());
+    }
+}
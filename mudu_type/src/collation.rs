@@ -0,0 +1,102 @@
+//! Per-column string collations.
+
+use feruca::Collator;
+use mudu::common::result::RS;
+use mudu::error::ErrorCode;
+use mudu::mudu_error;
+use serde::{Deserialize, Serialize};
+use std::borrow::Cow;
+use std::cell::RefCell;
+use std::cmp::Ordering;
+use std::hash::Hasher;
+
+thread_local! {
+    // `Collator::collate` reuses scratch buffers, so keep one per thread.
+    static ROOT_COLLATOR: RefCell<Collator> = RefCell::new(Collator::default());
+}
+
+/// How string values of a column are ordered, compared for equality and
+/// hashed.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Collation {
+    /// Bytewise comparison of the UTF-8 encoding.
+    #[default]
+    Binary,
+    /// Bytewise comparison after folding ASCII letters to lower case;
+    /// non-ASCII characters compare as in [`Collation::Binary`].
+    AsciiCi,
+    /// Unicode Collation Algorithm with the CLDR root order. Strings with
+    /// identical sort keys are ordered bytewise, so equality stays exact.
+    UnicodeRoot,
+}
+
+impl Collation {
+    /// Resolves a collation name as written in `COLLATE <name>`, ignoring
+    /// ASCII case.
+    pub fn from_name(name: &str) -> RS<Self> {
+        match name.to_ascii_lowercase().as_str() {
+            "binary" | "c" | "posix" => Ok(Self::Binary),
+            "ascii_ci" | "nocase" => Ok(Self::AsciiCi),
+            "unicode" | "und" | "root" | "unicode_root" => Ok(Self::UnicodeRoot),
+            _ => Err(mudu_error!(
+                ErrorCode::Parse,
+                format!("unknown collation {}", name)
+            )),
+        }
+    }
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::Binary => "binary",
+            Self::AsciiCi => "ascii_ci",
+            Self::UnicodeRoot => "unicode_root",
+        }
+    }
+
+    pub fn is_binary(&self) -> bool {
+        *self == Self::Binary
+    }
+
+    pub fn compare(&self, s1: &str, s2: &str) -> Ordering {
+        match self {
+            Self::Binary => s1.cmp(s2),
+            Self::AsciiCi => s1
+                .bytes()
+                .map(|b| b.to_ascii_lowercase())
+                .cmp(s2.bytes().map(|b| b.to_ascii_lowercase())),
+            Self::UnicodeRoot => ROOT_COLLATOR.with(|c| c.borrow_mut().collate(s1, s2)),
+        }
+    }
+
+    pub fn equal(&self, s1: &str, s2: &str) -> bool {
+        match self {
+            Self::Binary | Self::UnicodeRoot => s1 == s2,
+            Self::AsciiCi => s1.eq_ignore_ascii_case(s2),
+        }
+    }
+
+    /// Hashes `s` so that strings equal under this collation hash equally.
+    pub fn hash(&self, s: &str, hasher: &mut dyn Hasher) {
+        match self {
+            Self::Binary | Self::UnicodeRoot => hasher.write(s.as_bytes()),
+            Self::AsciiCi => {
+                for b in s.bytes() {
+                    hasher.write_u8(b.to_ascii_lowercase());
+                }
+            }
+        }
+    }
+
+    /// The spelling a key is stored and identified under: two strings are
+    /// equal under this collation exactly when their key forms are equal
+    /// byte for byte.
+    pub fn key_form<'a>(&self, s: &'a str) -> Cow<'a, str> {
+        match self {
+            Self::AsciiCi if s.bytes().any(|b| b.is_ascii_uppercase()) => {
+                Cow::Owned(s.to_ascii_lowercase())
+            }
+            Self::Binary | Self::AsciiCi | Self::UnicodeRoot => Cow::Borrowed(s),
+        }
+    }
+}
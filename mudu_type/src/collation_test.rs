@@ -0,0 +1,72 @@
+#[cfg(test)]
+mod tests {
+    use crate::collation::Collation;
+    use std::borrow::Cow;
+    use std::cmp::Ordering;
+    use std::collections::hash_map::DefaultHasher;
+    use std::hash::Hasher;
+
+    fn hash_of(collation: Collation, s: &str) -> u64 {
+        let mut hasher = DefaultHasher::new();
+        collation.hash(s, &mut hasher);
+        hasher.finish()
+    }
+
+    #[test]
+    fn names_round_trip_and_aliases_resolve() {
+        for collation in [
+            Collation::Binary,
+            Collation::AsciiCi,
+            Collation::UnicodeRoot,
+        ] {
+            assert_eq!(Collation::from_name(collation.name()).unwrap(), collation);
+        }
+        assert_eq!(Collation::from_name("NOCASE").unwrap(), Collation::AsciiCi);
+        assert_eq!(Collation::from_name("C").unwrap(), Collation::Binary);
+        assert_eq!(Collation::from_name("und").unwrap(), Collation::UnicodeRoot);
+        assert!(Collation::from_name("latin1_swedish").is_err());
+        assert_eq!(Collation::default(), Collation::Binary);
+    }
+
+    #[test]
+    fn binary_is_bytewise() {
+        let c = Collation::Binary;
+        assert_eq!(c.compare("B", "a"), Ordering::Less);
+        assert!(!c.equal("Alice", "alice"));
+    }
+
+    #[test]
+    fn ascii_ci_folds_ascii_letters_only() {
+        let c = Collation::AsciiCi;
+        assert!(c.equal("Alice@Example.COM", "alice@example.com"));
+        assert_eq!(c.compare("Alice", "alice"), Ordering::Equal);
+        assert_eq!(c.compare("a", "B"), Ordering::Less);
+        assert_eq!(c.compare("bob", "ALICE"), Ordering::Greater);
+        assert!(!c.equal("É", "é"));
+        assert_eq!(
+            hash_of(c, "Alice@Example.COM"),
+            hash_of(c, "alice@example.com")
+        );
+    }
+
+    #[test]
+    fn unicode_root_orders_accents_and_case_by_letter() {
+        let c = Collation::UnicodeRoot;
+        let mut names = vec!["Peng", "Peña", "ernie", "Émile", "Bob"];
+        names.sort_by(|a, b| c.compare(a, b));
+        assert_eq!(names, vec!["Bob", "Émile", "ernie", "Peña", "Peng"]);
+        assert_eq!(c.compare("a", "A"), Ordering::Less);
+        assert_eq!(c.compare("same", "same"), Ordering::Equal);
+        assert!(!c.equal("a", "A"));
+    }
+
+    #[test]
+    fn key_form_is_equal_exactly_for_equal_strings() {
+        let c = Collation::AsciiCi;
+        assert_eq!(c.key_form("Alice@Example.COM"), "alice@example.com");
+        assert!(matches!(c.key_form("alice"), Cow::Borrowed(_)));
+        assert_ne!(c.key_form("É"), c.key_form("é"));
+        assert_eq!(Collation::Binary.key_form("Alice"), "Alice");
+        assert_eq!(Collation::UnicodeRoot.key_form("Émile"), "Émile");
+    }
+}
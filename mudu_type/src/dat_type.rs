@@ -1,3 +1,4 @@
+use crate::collation::Collation;
 use crate::dat_type_id::DatTypeID;
 
 use crate::dt_impl::dat_table::get_fn_param;
@@ -94,6 +95,12 @@ impl DatType {
         }
     }
 
+    /// Collation of a string type; every other type compares as binary.
+    pub fn collation(&self) -> Collation {
+        self.as_string_param()
+            .map_or(Collation::Binary, |p| p.collation())
+    }
+
     pub fn into_info(self) -> DTInfo {
         DTInfo {
             id: self.id,
@@ -154,6 +161,7 @@ impl Order for DatType {
 #[cfg(test)]
 mod tests {
     use super::DatType;
+    use crate::collation::Collation;
     use crate::dat_type_id::DatTypeID;
     use crate::dtp_numeric::DTPNumeric;
     use crate::dtp_string::DTPString;
@@ -171,6 +179,11 @@ mod tests {
             DatTypeID::U128,
             DatTypeID::I128,
             DatTypeID::Date,
+            DatTypeID::Bool,
+            DatTypeID::I8,
+            DatTypeID::I16,
+            DatTypeID::Uuid,
+            DatTypeID::Json,
         ];
         for id in ids {
             let original = DatType::new_no_param(id);
@@ -216,6 +229,21 @@ mod tests {
         assert_eq!(restored.as_timestamptz_param().unwrap().precision(), 5);
     }
 
+    #[test]
+    fn collation_survives_info_roundtrip() {
+        let original = DatType::from_string(DTPString::new(64).with_collation(Collation::AsciiCi));
+        let restored = DatType::from_info(&original.to_info()).unwrap();
+        assert_eq!(restored.collation(), Collation::AsciiCi);
+        assert_eq!(
+            DatType::default_for(DatTypeID::String).collation(),
+            Collation::Binary
+        );
+        assert_eq!(
+            DatType::new_no_param(DatTypeID::I32).collation(),
+            Collation::Binary
+        );
+    }
+
     #[test]
     fn name_returns_expected_strings() {
         assert_eq!(DatType::new_no_param(DatTypeID::I32).name(), "int");
@@ -225,6 +253,11 @@ mod tests {
         assert_eq!(DatType::new_no_param(DatTypeID::Date).name(), "date");
         assert_eq!(DatType::new_no_param(DatTypeID::U128).name(), "oid");
         assert_eq!(DatType::new_no_param(DatTypeID::I128).name(), "i128");
+        assert_eq!(DatType::new_no_param(DatTypeID::Bool).name(), "boolean");
+        assert_eq!(DatType::new_no_param(DatTypeID::I8).name(), "tinyint");
+        assert_eq!(DatType::new_no_param(DatTypeID::I16).name(), "smallint");
+        assert_eq!(DatType::new_no_param(DatTypeID::Uuid).name(), "uuid");
+        assert_eq!(DatType::new_no_param(DatTypeID::Json).name(), "json");
         assert_eq!(
             DatType::from_numeric(DTPNumeric::new(10, 2)).name(),
             "numeric"
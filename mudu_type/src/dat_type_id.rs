@@ -25,7 +25,7 @@ const SCALAR_ID_MAX: u32 = 1000;
 /// Data Type Identifier
 ///
 /// Types with the same ID share the same conversion functions and in-memory object representation (DatObject).
-/// Scalar types (i32, i64, f32, f64, String, Numeric, temporal types) can have default parameters.
+/// Scalar types (bool, i8..i64, f32, f64, String, Numeric, temporal types, UUID, JSON) can have default parameters.
 #[repr(u32)]
 #[derive(Hash, Eq, Ord, PartialEq, PartialOrd, Copy, Clone, Debug, Serialize, Deserialize)]
 #[cfg_attr(any(test, feature = "test"), derive(Arbitrary))]
@@ -43,6 +43,11 @@ pub enum DatTypeID {
     Time = 9,
     Timestamp = 10,
     TimestampTz = 11,
+    Bool = 12,
+    I8 = 13,
+    I16 = 14,
+    Uuid = 15,
+    Json = 16,
 
     // Complex types (start after scalar range)
     Array = SCALAR_ID_MAX + 1,
@@ -183,12 +188,17 @@ impl DatTypeID {
     pub fn has_param(&self) -> bool {
         !matches!(
             self,
-            DatTypeID::I32
+            DatTypeID::Bool
+                | DatTypeID::I8
+                | DatTypeID::I16
+                | DatTypeID::I32
                 | DatTypeID::I64
                 | DatTypeID::I128
                 | DatTypeID::F32
                 | DatTypeID::F64
                 | DatTypeID::U128
+                | DatTypeID::Uuid
+                | DatTypeID::Json
                 | DatTypeID::Date
         )
     }
@@ -235,6 +245,11 @@ mod tests {
             DatTypeID::Time,
             DatTypeID::Timestamp,
             DatTypeID::TimestampTz,
+            DatTypeID::Bool,
+            DatTypeID::I8,
+            DatTypeID::I16,
+            DatTypeID::Uuid,
+            DatTypeID::Json,
             DatTypeID::Array,
             DatTypeID::Record,
             DatTypeID::Binary,
@@ -267,6 +282,11 @@ mod tests {
         assert!(DatTypeID::Time.is_scalar_type());
         assert!(DatTypeID::Timestamp.is_scalar_type());
         assert!(DatTypeID::TimestampTz.is_scalar_type());
+        assert!(DatTypeID::Bool.is_scalar_type());
+        assert!(DatTypeID::I8.is_scalar_type());
+        assert!(DatTypeID::I16.is_scalar_type());
+        assert!(DatTypeID::Uuid.is_scalar_type());
+        assert!(DatTypeID::Json.is_scalar_type());
         assert!(!DatTypeID::Array.is_scalar_type());
         assert!(!DatTypeID::Record.is_scalar_type());
         assert!(!DatTypeID::Binary.is_scalar_type());
@@ -282,6 +302,11 @@ mod tests {
             DatTypeID::TimestampTz,
         ];
         let no_param = [
+            DatTypeID::Bool,
+            DatTypeID::I8,
+            DatTypeID::I16,
+            DatTypeID::Uuid,
+            DatTypeID::Json,
             DatTypeID::I32,
             DatTypeID::I64,
             DatTypeID::I128,
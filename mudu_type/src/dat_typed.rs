@@ -6,6 +6,8 @@ use mudu::data_type::numeric::Numeric;
 use mudu::data_type::time::TimeValue;
 use mudu::data_type::timestamp::TimestampValue;
 use mudu::data_type::timestamptz::TimestampTzValue;
+use mudu::data_type::uuid::UuidValue;
+use mudu::utils::json::JsonValue;
 
 #[derive(Clone, Debug)]
 pub struct DatTyped {
@@ -14,6 +16,24 @@ pub struct DatTyped {
 }
 
 impl DatTyped {
+    pub fn from_bool(val: bool) -> Self {
+        Self::new(
+            DatType::default_for(DatTypeID::Bool),
+            DatValue::from_bool(val),
+        )
+    }
+
+    pub fn from_i8(val: i8) -> Self {
+        Self::new(DatType::default_for(DatTypeID::I8), DatValue::from_i8(val))
+    }
+
+    pub fn from_i16(val: i16) -> Self {
+        Self::new(
+            DatType::default_for(DatTypeID::I16),
+            DatValue::from_i16(val),
+        )
+    }
+
     pub fn from_i32(val: i32) -> Self {
         Self::new(
             DatType::default_for(DatTypeID::I32),
@@ -42,6 +62,20 @@ impl DatTyped {
         )
     }
 
+    pub fn from_uuid(val: UuidValue) -> Self {
+        Self::new(
+            DatType::default_for(DatTypeID::Uuid),
+            DatValue::from_uuid(val),
+        )
+    }
+
+    pub fn from_json(val: JsonValue) -> Self {
+        Self::new(
+            DatType::default_for(DatTypeID::Json),
+            DatValue::from_json(val),
+        )
+    }
+
     pub fn from_f32(val: f32) -> Self {
         Self::new(
             DatType::default_for(DatTypeID::F32),
@@ -7,6 +7,8 @@ mod tests {
     use mudu::data_type::time::TimeValue;
     use mudu::data_type::timestamp::TimestampValue;
     use mudu::data_type::timestamptz::TimestampTzValue;
+    use mudu::data_type::uuid::UuidValue;
+    use mudu::json_value;
 
     #[test]
     fn from_i32() {
@@ -15,6 +17,36 @@ mod tests {
         assert_eq!(typed.dat_internal().to_i32(), 42);
     }
 
+    #[test]
+    fn from_bool_and_small_ints() {
+        let typed = DatTyped::from_bool(true);
+        assert_eq!(typed.dat_type().dat_type_id(), DatTypeID::Bool);
+        assert!(typed.dat_internal().to_bool());
+
+        let typed = DatTyped::from_i8(-8);
+        assert_eq!(typed.dat_type().dat_type_id(), DatTypeID::I8);
+        assert_eq!(typed.dat_internal().to_i8(), -8);
+
+        let typed = DatTyped::from_i16(-16);
+        assert_eq!(typed.dat_type().dat_type_id(), DatTypeID::I16);
+        assert_eq!(typed.dat_internal().to_i16(), -16);
+    }
+
+    #[test]
+    fn from_uuid() {
+        let uuid = UuidValue::parse("123e4567-e89b-12d3-a456-426614174000").unwrap();
+        let typed = DatTyped::from_uuid(uuid);
+        assert_eq!(typed.dat_type().dat_type_id(), DatTypeID::Uuid);
+        assert_eq!(*typed.dat_internal().expect_uuid(), uuid);
+    }
+
+    #[test]
+    fn from_json() {
+        let typed = DatTyped::from_json(json_value!({"a": [1, 2]}));
+        assert_eq!(typed.dat_type().dat_type_id(), DatTypeID::Json);
+        assert_eq!(typed.dat_internal().expect_json()["a"][1], 2);
+    }
+
     #[test]
     fn from_i64() {
         let typed = DatTyped::from_i64(99);
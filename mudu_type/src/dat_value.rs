@@ -9,8 +9,10 @@ use mudu::data_type::numeric::Numeric;
 use mudu::data_type::time::TimeValue;
 use mudu::data_type::timestamp::TimestampValue;
 use mudu::data_type::timestamptz::TimestampTzValue;
+use mudu::data_type::uuid::UuidValue;
 use mudu::error::ErrorCode;
 use mudu::mudu_error;
+use mudu::utils::json::JsonValue;
 use paste::paste;
 use serde::{Deserialize, Serialize};
 use std::fmt::Debug;
@@ -38,12 +40,17 @@ impl AsRef<DatValue> for DatValue {
 #[derive(Clone, Debug, Serialize, Deserialize)]
 enum ValueKind {
     Null,
+    Bool(bool),
+    I8(i8),
+    I16(i16),
     F32(f32),
     F64(f64),
     I32(i32),
     I64(i64),
     I128(i128),
     U128(u128),
+    Uuid(UuidValue),
+    Json(JsonValue),
     Numeric(Numeric),
     Date(DateValue),
     Time(TimeValue),
@@ -180,6 +187,18 @@ impl DatValue {
         *self.expect_f64()
     }
 
+    pub fn to_bool(&self) -> bool {
+        *self.expect_bool()
+    }
+
+    pub fn to_i8(&self) -> i8 {
+        *self.expect_i8()
+    }
+
+    pub fn to_i16(&self) -> i16 {
+        *self.expect_i16()
+    }
+
     pub fn to_i32(&self) -> i32 {
         *self.expect_i32()
     }
@@ -209,10 +228,15 @@ unsafe impl Send for ValueKind {}
 unsafe impl Sync for ValueKind {}
 
 impl_dat_value_methods! {
+    (bool, Bool, bool),
+    (i8, I8, i8),
+    (i16, I16, i16),
     (i32, I32, i32),
     (i64, I64, i64),
     (i128, I128, i128),
     (u128, U128, u128),
+    (UuidValue, Uuid, uuid),
+    (JsonValue, Json, json),
     (Numeric, Numeric, numeric),
     (DateValue, Date, date),
     (TimeValue, Time, time),
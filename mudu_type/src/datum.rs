@@ -10,8 +10,10 @@ use mudu::data_type::numeric::Numeric;
 use mudu::data_type::time::TimeValue;
 use mudu::data_type::timestamp::TimestampValue;
 use mudu::data_type::timestamptz::TimestampTzValue;
+use mudu::data_type::uuid::UuidValue;
 use mudu::error::ErrorCode;
 use mudu::mudu_error;
+use mudu::utils::json::JsonValue;
 use paste::paste;
 use std::any::Any;
 use std::clone::Clone;
@@ -262,10 +264,15 @@ macro_rules! impl_datum_trait {
 }
 
 impl_datum_trait!(
+    (Bool, bool, bool),
+    (I8, i8, i8),
+    (I16, i16, i16),
     (I32, i32, i32),
     (I64, i64, i64),
     (I128, i128, i128),
     (U128, u128, u128),
+    (Uuid, uuid, UuidValue),
+    (Json, json, JsonValue),
     (Numeric, numeric, Numeric),
     (Date, date, DateValue),
     (Time, time, TimeValue),
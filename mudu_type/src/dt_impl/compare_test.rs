@@ -15,6 +15,12 @@ fn comparable_type_ids() -> &'static [DatTypeID] {
         DatTypeID::String,
         DatTypeID::U128,
         DatTypeID::I128,
+        DatTypeID::Bool,
+        DatTypeID::I8,
+        DatTypeID::I16,
+        DatTypeID::Uuid,
+        DatTypeID::Json,
+        DatTypeID::Binary,
     ]
 }
 
@@ -148,6 +148,56 @@ lazy_static! {
             fixed_length: Some(size_of::<i64>() as u32),
             opt_fn_param: Some(dt_impl::fn_timestamptz_param::FN_TIMESTAMPTZ_PARAM),
         },
+        DatTypeDef {
+            id: DatTypeID::Bool,
+            type_name: "boolean".to_string(),
+            fn_base: dt_impl::fn_bool::FN_BOOL_CONVERT,
+            opt_fn_compare: Some(dt_impl::fn_bool::FN_BOOL_COMPARE),
+            #[cfg(any(test, feature = "test"))]
+            fn_arbitrary: dt_impl::fn_bool_arb::FN_BOOL_ARBITRARY,
+            fixed_length: Some(size_of::<u8>() as u32),
+            opt_fn_param: None,
+        },
+        DatTypeDef {
+            id: DatTypeID::I8,
+            type_name: "tinyint".to_string(),
+            fn_base: dt_impl::fn_i8::FN_I8_CONVERT,
+            opt_fn_compare: Some(dt_impl::fn_i8::FN_I8_COMPARE),
+            #[cfg(any(test, feature = "test"))]
+            fn_arbitrary: dt_impl::fn_i8_arb::FN_I8_ARBITRARY,
+            fixed_length: Some(size_of::<i8>() as u32),
+            opt_fn_param: None,
+        },
+        DatTypeDef {
+            id: DatTypeID::I16,
+            type_name: "smallint".to_string(),
+            fn_base: dt_impl::fn_i16::FN_I16_CONVERT,
+            opt_fn_compare: Some(dt_impl::fn_i16::FN_I16_COMPARE),
+            #[cfg(any(test, feature = "test"))]
+            fn_arbitrary: dt_impl::fn_i16_arb::FN_I16_ARBITRARY,
+            fixed_length: Some(size_of::<i16>() as u32),
+            opt_fn_param: None,
+        },
+        DatTypeDef {
+            id: DatTypeID::Uuid,
+            type_name: "uuid".to_string(),
+            fn_base: dt_impl::fn_uuid::FN_UUID_CONVERT,
+            opt_fn_compare: Some(dt_impl::fn_uuid::FN_UUID_COMPARE),
+            #[cfg(any(test, feature = "test"))]
+            fn_arbitrary: dt_impl::fn_uuid_arb::FN_UUID_ARBITRARY,
+            fixed_length: Some(size_of::<u128>() as u32),
+            opt_fn_param: None,
+        },
+        DatTypeDef {
+            id: DatTypeID::Json,
+            type_name: "json".to_string(),
+            fn_base: dt_impl::fn_json::FN_JSON_CONVERT,
+            opt_fn_compare: Some(dt_impl::fn_json::FN_JSON_COMPARE),
+            #[cfg(any(test, feature = "test"))]
+            fn_arbitrary: dt_impl::fn_json_arb::FN_JSON_ARBITRARY,
+            fixed_length: None,
+            opt_fn_param: None,
+        },
         DatTypeDef {
             id: DatTypeID::Array,
             type_name: "array".to_string(),
@@ -172,7 +222,7 @@ lazy_static! {
             id: DatTypeID::Binary,
             type_name: "binary".to_string(),
             fn_base: dt_impl::fn_binary::FN_BINARY_CONVERT,
-            opt_fn_compare: None,
+            opt_fn_compare: Some(dt_impl::fn_binary::FN_BINARY_COMPARE),
             #[cfg(any(test, feature = "test"))]
             fn_arbitrary: dt_impl::fn_binary_arb::FN_BINARY_ARBITRARY,
             fixed_length: None,
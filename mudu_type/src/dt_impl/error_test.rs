@@ -52,6 +52,27 @@ fn invalid_textual_input_paths_return_type_convert_failed() {
             DatType::new_no_param(DatTypeID::Binary),
             "{\"oops\":1}",
         ),
+        (
+            DatTypeID::Bool,
+            DatType::new_no_param(DatTypeID::Bool),
+            "\"true\"",
+        ),
+        (DatTypeID::I8, DatType::new_no_param(DatTypeID::I8), "128"),
+        (
+            DatTypeID::I16,
+            DatType::new_no_param(DatTypeID::I16),
+            "-32769",
+        ),
+        (
+            DatTypeID::Uuid,
+            DatType::new_no_param(DatTypeID::Uuid),
+            "\"not-a-uuid\"",
+        ),
+        (
+            DatTypeID::Json,
+            DatType::new_no_param(DatTypeID::Json),
+            "{\"a\": 1",
+        ),
         (
             DatTypeID::Numeric,
             DatType::from_numeric(DTPNumeric::new(9, 2)),
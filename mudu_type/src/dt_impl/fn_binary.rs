@@ -3,11 +3,14 @@ use crate::dat_json::DatJson;
 use crate::dat_textual::DatTextual;
 use crate::dat_type::DatType;
 use crate::dat_value::DatValue;
+use crate::dt_fn_compare::{ErrCompare, FnCompare};
 use crate::dt_fn_convert::FnBase;
 use crate::type_error::{TyEC, TyErr};
 use mudu::utils::bin_size::BinSize;
 use mudu::utils::json::{JsonNumber, JsonValue, from_json_str};
 use mudu::utils::msg_pack::{MsgPackInteger, MsgPackValue};
+use std::cmp::Ordering;
+use std::hash::Hasher;
 
 pub fn fn_binary_in(s: &str, dat_type: &DatType) -> Result<DatValue, TyErr> {
     let json_value: JsonValue =
@@ -168,6 +171,27 @@ pub fn fn_binary_default(_: &DatType) -> Result<DatValue, TyErr> {
     Ok(DatValue::from_binary(vec![]))
 }
 
+/// `FnOrder` orders two binary values by their bytes.
+pub fn fn_binary_order(v1: &DatValue, v2: &DatValue) -> Result<Ordering, ErrCompare> {
+    Ok(v1.expect_binary().cmp(v2.expect_binary()))
+}
+
+/// `FnEqual` compares two binary values byte by byte.
+pub fn fn_binary_equal(v1: &DatValue, v2: &DatValue) -> Result<bool, ErrCompare> {
+    Ok(v1.expect_binary().eq(v2.expect_binary()))
+}
+
+pub fn fn_binary_hash(v: &DatValue, hasher: &mut dyn Hasher) -> Result<(), ErrCompare> {
+    hasher.write(v.expect_binary());
+    Ok(())
+}
+
+pub const FN_BINARY_COMPARE: FnCompare = FnCompare {
+    order: fn_binary_order,
+    equal: fn_binary_equal,
+    hash: fn_binary_hash,
+};
+
 pub const FN_BINARY_CONVERT: FnBase = FnBase {
     input_textual: fn_binary_in,
     output_textual: fn_binary_out,
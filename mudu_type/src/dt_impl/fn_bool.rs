@@ -0,0 +1,160 @@
+use crate::dat_binary::DatBinary;
+use crate::dat_json::DatJson;
+use crate::dat_textual::DatTextual;
+use crate::dat_type::DatType;
+use crate::dat_value::DatValue;
+use crate::dt_fn_compare::{ErrCompare, FnCompare};
+use crate::dt_fn_convert::FnBase;
+use crate::type_error::{TyEC, TyErr};
+use mudu::utils::json::{JsonValue, from_json_str};
+use mudu::utils::msg_pack::MsgPackValue;
+use std::cmp::Ordering;
+use std::hash::Hasher;
+
+pub fn fn_bool_in_textual(v: &str, dt: &DatType) -> Result<DatValue, TyErr> {
+    let json = from_json_str::<JsonValue>(v)
+        .map_err(|e| TyErr::new(TyEC::TypeConvertFailed, e.to_string()))?;
+    fn_bool_in_json(&json, dt)
+}
+
+pub fn fn_bool_out_textual(v: &DatValue, dt: &DatType) -> Result<DatTextual, TyErr> {
+    let json = fn_bool_out_json(v, dt)?;
+    Ok(DatTextual::from(json.to_string()))
+}
+
+pub fn fn_bool_in_json(v: &JsonValue, _: &DatType) -> Result<DatValue, TyErr> {
+    match v.as_bool() {
+        Some(b) => Ok(DatValue::from_bool(b)),
+        None => Err(TyErr::new(
+            TyEC::TypeConvertFailed,
+            format!("cannot convert json {} to bool", v),
+        )),
+    }
+}
+
+pub fn fn_bool_out_json(v: &DatValue, _: &DatType) -> Result<DatJson, TyErr> {
+    Ok(DatJson::from(JsonValue::Bool(v.to_bool())))
+}
+
+pub fn fn_bool_in_msgpack(msg_pack: &MsgPackValue, _: &DatType) -> Result<DatValue, TyErr> {
+    match msg_pack.as_bool() {
+        Some(b) => Ok(DatValue::from_bool(b)),
+        None => Err(TyErr::new(
+            TyEC::TypeConvertFailed,
+            "cannot convert msg pack to bool".to_string(),
+        )),
+    }
+}
+
+pub fn fn_bool_out_msgpack(v: &DatValue, _: &DatType) -> Result<MsgPackValue, TyErr> {
+    Ok(MsgPackValue::Boolean(v.to_bool()))
+}
+
+pub fn fn_bool_len(_: &DatType) -> Result<Option<u32>, TyErr> {
+    Ok(Some(size_of::<u8>() as u32))
+}
+
+pub fn fn_bool_dat_output_len(_: &DatValue, ty: &DatType) -> Result<u32, TyErr> {
+    Ok(fn_bool_len(ty)?.unwrap())
+}
+
+pub fn fn_bool_send(v: &DatValue, _: &DatType) -> Result<DatBinary, TyErr> {
+    Ok(DatBinary::from(vec![v.to_bool() as u8]))
+}
+
+pub fn fn_bool_send_to(v: &DatValue, _: &DatType, buf: &mut [u8]) -> Result<u32, TyErr> {
+    if buf.is_empty() {
+        return Err(TyErr::new(
+            TyEC::InsufficientSpace,
+            "insufficient space".to_string(),
+        ));
+    }
+    buf[0] = v.to_bool() as u8;
+    Ok(size_of::<u8>() as u32)
+}
+
+/// Only the canonical encodings 0 and 1 are accepted, so that two equal
+/// values always have identical bytes on disk.
+pub fn fn_bool_recv(buf: &[u8], _: &DatType) -> Result<(DatValue, u32), TyErr> {
+    let b = match buf.first() {
+        Some(0) => false,
+        Some(1) => true,
+        Some(n) => {
+            return Err(TyErr::new(
+                TyEC::TypeConvertFailed,
+                format!("invalid bool byte {}", n),
+            ));
+        }
+        None => {
+            return Err(TyErr::new(
+                TyEC::InsufficientSpace,
+                "insufficient space".to_string(),
+            ));
+        }
+    };
+    Ok((DatValue::from_bool(b), size_of::<u8>() as u32))
+}
+
+pub fn fn_bool_default(_: &DatType) -> Result<DatValue, TyErr> {
+    Ok(DatValue::from_bool(bool::default()))
+}
+
+/// `FnOrder` returns ordering result of a comparison between two object values.
+pub fn fn_bool_order(v1: &DatValue, v2: &DatValue) -> Result<Ordering, ErrCompare> {
+    Ok(v1.to_bool().cmp(&v2.to_bool()))
+}
+
+/// `FnEqual` return equal result of a comparison between two object values.
+pub fn fn_bool_equal(v1: &DatValue, v2: &DatValue) -> Result<bool, ErrCompare> {
+    Ok(v1.to_bool().eq(&v2.to_bool()))
+}
+
+pub fn fn_bool_hash(v: &DatValue, hasher: &mut dyn Hasher) -> Result<(), ErrCompare> {
+    hasher.write_u8(v.to_bool() as u8);
+    Ok(())
+}
+
+pub const FN_BOOL_COMPARE: FnCompare = FnCompare {
+    order: fn_bool_order,
+    equal: fn_bool_equal,
+    hash: fn_bool_hash,
+};
+
+pub const FN_BOOL_CONVERT: FnBase = FnBase {
+    input_textual: fn_bool_in_textual,
+    output_textual: fn_bool_out_textual,
+    input_json: fn_bool_in_json,
+    output_json: fn_bool_out_json,
+    input_msg_pack: fn_bool_in_msgpack,
+    output_msg_pack: fn_bool_out_msgpack,
+    type_len: fn_bool_len,
+    data_len: fn_bool_dat_output_len,
+    receive: fn_bool_recv,
+    send: fn_bool_send,
+    send_to: fn_bool_send_to,
+    default: fn_bool_default,
+};
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::dat_type_id::DatTypeID;
+
+    #[test]
+    fn recv_rejects_non_canonical_bytes() {
+        let dt = DatType::new_no_param(DatTypeID::Bool);
+        let (v, len) = fn_bool_recv(&[1], &dt).unwrap();
+        assert!(v.to_bool());
+        assert_eq!(len, 1);
+        assert!(fn_bool_recv(&[2], &dt).is_err());
+        assert!(fn_bool_recv(&[], &dt).is_err());
+    }
+
+    #[test]
+    fn textual_uses_json_literals() {
+        let dt = DatType::new_no_param(DatTypeID::Bool);
+        let v = fn_bool_in_textual("true", &dt).unwrap();
+        assert_eq!(fn_bool_out_textual(&v, &dt).unwrap().as_str(), "true");
+        assert!(fn_bool_in_textual("1", &dt).is_err());
+    }
+}
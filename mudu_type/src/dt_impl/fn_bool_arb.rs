@@ -0,0 +1,23 @@
+use crate::dat_type::DatType;
+use crate::dat_type_id::DatTypeID;
+use crate::dat_value::DatValue;
+use crate::dt_fn_arbitrary::FnArbitrary;
+use arbitrary::{Arbitrary, Unstructured};
+
+pub fn fn_bool_arb_val(u: &mut Unstructured, _: &DatType) -> arbitrary::Result<DatValue> {
+    Ok(DatValue::from_bool(bool::arbitrary(u)?))
+}
+
+pub fn fn_bool_arb_printable(u: &mut Unstructured, _: &DatType) -> arbitrary::Result<String> {
+    Ok(bool::arbitrary(u)?.to_string())
+}
+
+pub fn fn_bool_arb_dt_param(_u: &mut Unstructured) -> arbitrary::Result<DatType> {
+    Ok(DatType::new_no_param(DatTypeID::Bool))
+}
+
+pub const FN_BOOL_ARBITRARY: FnArbitrary = FnArbitrary {
+    param: fn_bool_arb_dt_param,
+    value_object: fn_bool_arb_val,
+    value_print: fn_bool_arb_printable,
+};
@@ -0,0 +1,23 @@
+use crate::dat_type::DatType;
+use crate::dat_type_id::DatTypeID;
+use crate::dat_value::DatValue;
+use crate::dt_fn_arbitrary::FnArbitrary;
+use arbitrary::{Arbitrary, Unstructured};
+
+pub fn fn_i8_arb_val(u: &mut Unstructured, _: &DatType) -> arbitrary::Result<DatValue> {
+    Ok(DatValue::from_i8(i8::arbitrary(u)?))
+}
+
+pub fn fn_i8_arb_printable(u: &mut Unstructured, _: &DatType) -> arbitrary::Result<String> {
+    Ok(i8::arbitrary(u)?.to_string())
+}
+
+pub fn fn_i8_arb_dt_param(_u: &mut Unstructured) -> arbitrary::Result<DatType> {
+    Ok(DatType::new_no_param(DatTypeID::I8))
+}
+
+pub const FN_I8_ARBITRARY: FnArbitrary = FnArbitrary {
+    param: fn_i8_arb_dt_param,
+    value_object: fn_i8_arb_val,
+    value_print: fn_i8_arb_printable,
+};
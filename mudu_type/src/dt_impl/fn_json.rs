@@ -0,0 +1,360 @@
+use crate::dat_binary::DatBinary;
+use crate::dat_json::DatJson;
+use crate::dat_textual::DatTextual;
+use crate::dat_type::DatType;
+use crate::dat_value::DatValue;
+use crate::dt_fn_compare::{ErrCompare, FnCompare};
+use crate::dt_fn_convert::FnBase;
+use crate::type_error::{TyEC, TyErr};
+use mudu::utils::bin_size::BinSize;
+use mudu::utils::json::{JsonMap, JsonNumber, JsonValue, from_json_str};
+use mudu::utils::msg_pack::{
+    MsgPackUtf8String, MsgPackValue, msg_pack_binary_to_value, msg_pack_value_to_binary,
+};
+use std::cmp::Ordering;
+use std::hash::Hasher;
+
+/// Documents are validated on the way in, so every stored value is well-formed
+/// JSON.
+fn fn_json_in_textual(v: &str, dt: &DatType) -> Result<DatValue, TyErr> {
+    let json = from_json_str::<JsonValue>(v)
+        .map_err(|e| TyErr::new(TyEC::TypeConvertFailed, format!("invalid json: {}", e)))?;
+    fn_json_in_json(&json, dt)
+}
+
+fn fn_json_out_textual(v: &DatValue, _: &DatType) -> Result<DatTextual, TyErr> {
+    Ok(DatTextual::from(v.expect_json().to_string()))
+}
+
+fn fn_json_in_json(v: &JsonValue, _: &DatType) -> Result<DatValue, TyErr> {
+    Ok(DatValue::from_json(v.clone()))
+}
+
+fn fn_json_out_json(v: &DatValue, _: &DatType) -> Result<DatJson, TyErr> {
+    Ok(DatJson::from(v.expect_json().clone()))
+}
+
+fn fn_json_in_msgpack(msg_pack: &MsgPackValue, _: &DatType) -> Result<DatValue, TyErr> {
+    Ok(DatValue::from_json(msg_pack_to_json(msg_pack)?))
+}
+
+fn fn_json_out_msgpack(v: &DatValue, _: &DatType) -> Result<MsgPackValue, TyErr> {
+    Ok(json_to_msg_pack(v.expect_json()))
+}
+
+fn json_to_msg_pack(v: &JsonValue) -> MsgPackValue {
+    match v {
+        JsonValue::Null => MsgPackValue::Nil,
+        JsonValue::Bool(b) => MsgPackValue::Boolean(*b),
+        JsonValue::Number(n) => {
+            if let Some(i) = n.as_i64() {
+                MsgPackValue::from(i)
+            } else if let Some(u) = n.as_u64() {
+                MsgPackValue::from(u)
+            } else {
+                MsgPackValue::F64(n.as_f64().unwrap_or_default())
+            }
+        }
+        JsonValue::String(s) => MsgPackValue::String(MsgPackUtf8String::from(s.as_str())),
+        JsonValue::Array(array) => {
+            MsgPackValue::Array(array.iter().map(json_to_msg_pack).collect())
+        }
+        JsonValue::Object(map) => MsgPackValue::Map(
+            map.iter()
+                .map(|(k, v)| {
+                    (
+                        MsgPackValue::String(MsgPackUtf8String::from(k.as_str())),
+                        json_to_msg_pack(v),
+                    )
+                })
+                .collect(),
+        ),
+    }
+}
+
+fn msg_pack_to_json(v: &MsgPackValue) -> Result<JsonValue, TyErr> {
+    let unsupported = || {
+        TyErr::new(
+            TyEC::TypeConvertFailed,
+            format!("cannot convert msg pack {} to json", v),
+        )
+    };
+    let json = match v {
+        MsgPackValue::Nil => JsonValue::Null,
+        MsgPackValue::Boolean(b) => JsonValue::Bool(*b),
+        MsgPackValue::Integer(i) => match (i.as_i64(), i.as_u64()) {
+            (Some(i), _) => JsonValue::Number(JsonNumber::from(i)),
+            (None, Some(u)) => JsonValue::Number(JsonNumber::from(u)),
+            (None, None) => return Err(unsupported()),
+        },
+        MsgPackValue::F32(f) => {
+            JsonValue::Number(JsonNumber::from_f64(*f as f64).ok_or_else(unsupported)?)
+        }
+        MsgPackValue::F64(f) => {
+            JsonValue::Number(JsonNumber::from_f64(*f).ok_or_else(unsupported)?)
+        }
+        MsgPackValue::String(s) => {
+            JsonValue::String(s.as_str().ok_or_else(unsupported)?.to_string())
+        }
+        MsgPackValue::Array(array) => JsonValue::Array(
+            array
+                .iter()
+                .map(msg_pack_to_json)
+                .collect::<Result<Vec<_>, _>>()?,
+        ),
+        MsgPackValue::Map(entries) => {
+            let mut map = JsonMap::new();
+            for (k, v) in entries {
+                let key = k.as_str().ok_or_else(unsupported)?;
+                let _ = map.insert(key.to_string(), msg_pack_to_json(v)?);
+            }
+            JsonValue::Object(map)
+        }
+        MsgPackValue::Binary(_) | MsgPackValue::Ext(_, _) => return Err(unsupported()),
+    };
+    Ok(json)
+}
+
+fn fn_json_len(_: &DatType) -> Result<Option<u32>, TyErr> {
+    Ok(None)
+}
+
+fn header_size() -> usize {
+    BinSize::size_of()
+}
+
+/// The binary form is a length header followed by the MessagePack encoding
+/// of the document, which is much smaller than the JSON text for numbers and
+/// nested structures.
+fn encode_payload(v: &DatValue) -> Result<Vec<u8>, TyErr> {
+    msg_pack_value_to_binary(&json_to_msg_pack(v.expect_json()))
+        .map_err(|e| TyErr::new(TyEC::TypeConvertFailed, e.to_string()))
+}
+
+fn fn_json_dat_output_len(v: &DatValue, _: &DatType) -> Result<u32, TyErr> {
+    Ok((header_size() + encode_payload(v)?.len()) as u32)
+}
+
+fn fn_json_send(v: &DatValue, dt: &DatType) -> Result<DatBinary, TyErr> {
+    let len = fn_json_dat_output_len(v, dt)?;
+    let mut buf = vec![0; len as usize];
+    let _ = fn_json_send_to(v, dt, &mut buf)?;
+    Ok(DatBinary::from(buf))
+}
+
+fn fn_json_send_to(v: &DatValue, _: &DatType, buf: &mut [u8]) -> Result<u32, TyErr> {
+    let payload = encode_payload(v)?;
+    let total_len = header_size() + payload.len();
+    if buf.len() < total_len {
+        return Err(TyErr::new(
+            TyEC::InsufficientSpace,
+            "insufficient space".to_string(),
+        ));
+    }
+    BinSize::new(total_len as u32).copy_to_slice(&mut buf[0..header_size()]);
+    buf[header_size()..total_len].copy_from_slice(&payload);
+    Ok(total_len as u32)
+}
+
+fn fn_json_recv(buf: &[u8], _: &DatType) -> Result<(DatValue, u32), TyErr> {
+    if buf.len() < header_size() {
+        return Err(TyErr::new(
+            TyEC::InsufficientSpace,
+            "insufficient space".to_string(),
+        ));
+    }
+    let total_len = BinSize::from_slice(&buf[0..header_size()]).size() as usize;
+    if buf.len() < total_len || total_len < header_size() {
+        return Err(TyErr::new(
+            TyEC::InsufficientSpace,
+            "insufficient space".to_string(),
+        ));
+    }
+    let (msg_pack, used) = msg_pack_binary_to_value(&buf[header_size()..total_len])
+        .map_err(|e| TyErr::new(TyEC::TypeConvertFailed, e.to_string()))?;
+    if header_size() + used as usize != total_len {
+        return Err(TyErr::new(
+            TyEC::TypeConvertFailed,
+            "trailing bytes after json document".to_string(),
+        ));
+    }
+    Ok((
+        DatValue::from_json(msg_pack_to_json(&msg_pack)?),
+        total_len as u32,
+    ))
+}
+
+fn fn_json_default(_: &DatType) -> Result<DatValue, TyErr> {
+    Ok(DatValue::from_json(JsonValue::Null))
+}
+
+fn type_rank(v: &JsonValue) -> u8 {
+    match v {
+        JsonValue::Null => 0,
+        JsonValue::Bool(_) => 1,
+        JsonValue::Number(_) => 2,
+        JsonValue::String(_) => 3,
+        JsonValue::Array(_) => 4,
+        JsonValue::Object(_) => 5,
+    }
+}
+
+fn sorted_entries(map: &JsonMap<String, JsonValue>) -> Vec<(&String, &JsonValue)> {
+    let mut entries: Vec<_> = map.iter().collect();
+    entries.sort_by(|a, b| a.0.cmp(b.0));
+    entries
+}
+
+/// Total order consistent with `JsonValue` equality: values order by kind
+/// first, objects compare as key-sorted entry lists so key order never
+/// matters, and numbers fall back to their text so `1` and `1.0` stay
+/// distinct.
+fn json_cmp(a: &JsonValue, b: &JsonValue) -> Ordering {
+    match (a, b) {
+        (JsonValue::Bool(a), JsonValue::Bool(b)) => a.cmp(b),
+        (JsonValue::Number(a), JsonValue::Number(b)) => a
+            .as_f64()
+            .partial_cmp(&b.as_f64())
+            .unwrap_or(Ordering::Equal)
+            .then_with(|| a.to_string().cmp(&b.to_string())),
+        (JsonValue::String(a), JsonValue::String(b)) => a.cmp(b),
+        (JsonValue::Array(a), JsonValue::Array(b)) => {
+            for (x, y) in a.iter().zip(b.iter()) {
+                let ord = json_cmp(x, y);
+                if ord != Ordering::Equal {
+                    return ord;
+                }
+            }
+            a.len().cmp(&b.len())
+        }
+        (JsonValue::Object(a), JsonValue::Object(b)) => {
+            let (a, b) = (sorted_entries(a), sorted_entries(b));
+            for ((ka, va), (kb, vb)) in a.iter().zip(b.iter()) {
+                let ord = ka.cmp(kb).then_with(|| json_cmp(va, vb));
+                if ord != Ordering::Equal {
+                    return ord;
+                }
+            }
+            a.len().cmp(&b.len())
+        }
+        _ => type_rank(a).cmp(&type_rank(b)),
+    }
+}
+
+fn json_hash(v: &JsonValue, hasher: &mut dyn Hasher) {
+    hasher.write_u8(type_rank(v));
+    match v {
+        JsonValue::Null => {}
+        JsonValue::Bool(b) => hasher.write_u8(*b as u8),
+        JsonValue::Number(n) => hasher.write(n.to_string().as_bytes()),
+        JsonValue::String(s) => hasher.write(s.as_bytes()),
+        JsonValue::Array(array) => {
+            hasher.write_usize(array.len());
+            for item in array {
+                json_hash(item, hasher);
+            }
+        }
+        JsonValue::Object(map) => {
+            hasher.write_usize(map.len());
+            for (k, v) in sorted_entries(map) {
+                hasher.write(k.as_bytes());
+                json_hash(v, hasher);
+            }
+        }
+    }
+}
+
+fn fn_json_order(v1: &DatValue, v2: &DatValue) -> Result<Ordering, ErrCompare> {
+    Ok(json_cmp(v1.expect_json(), v2.expect_json()))
+}
+
+fn fn_json_equal(v1: &DatValue, v2: &DatValue) -> Result<bool, ErrCompare> {
+    Ok(v1.expect_json() == v2.expect_json())
+}
+
+fn fn_json_hash(v: &DatValue, hasher: &mut dyn Hasher) -> Result<(), ErrCompare> {
+    json_hash(v.expect_json(), hasher);
+    Ok(())
+}
+
+pub const FN_JSON_COMPARE: FnCompare = FnCompare {
+    order: fn_json_order,
+    equal: fn_json_equal,
+    hash: fn_json_hash,
+};
+
+pub const FN_JSON_CONVERT: FnBase = FnBase {
+    input_textual: fn_json_in_textual,
+    output_textual: fn_json_out_textual,
+    input_json: fn_json_in_json,
+    output_json: fn_json_out_json,
+    input_msg_pack: fn_json_in_msgpack,
+    output_msg_pack: fn_json_out_msgpack,
+    type_len: fn_json_len,
+    data_len: fn_json_dat_output_len,
+    receive: fn_json_recv,
+    send: fn_json_send,
+    send_to: fn_json_send_to,
+    default: fn_json_default,
+};
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::dat_type_id::DatTypeID;
+    use mudu::json_value;
+    use std::collections::hash_map::DefaultHasher;
+
+    fn hash_of(v: &JsonValue) -> u64 {
+        let mut hasher = DefaultHasher::new();
+        json_hash(v, &mut hasher);
+        hasher.finish()
+    }
+
+    #[test]
+    fn textual_input_rejects_malformed_documents() {
+        let dt = DatType::new_no_param(DatTypeID::Json);
+        let v = fn_json_in_textual(r#"{"a": [1, 2.5, "x", null]}"#, &dt).unwrap();
+        assert_eq!(
+            fn_json_out_textual(&v, &dt).unwrap().as_str(),
+            r#"{"a":[1,2.5,"x",null]}"#
+        );
+        assert!(fn_json_in_textual(r#"{"a": 1"#, &dt).is_err());
+        assert!(fn_json_in_textual("attrs", &dt).is_err());
+    }
+
+    #[test]
+    fn binary_roundtrip_is_smaller_than_text() {
+        let dt = DatType::new_no_param(DatTypeID::Json);
+        let doc = json_value!({"id": 123456, "tags": ["a", "b"], "score": -1.5, "ok": true});
+        let v = DatValue::from_json(doc.clone());
+        let binary = fn_json_send(&v, &dt).unwrap();
+        assert!(binary.as_ref().len() < doc.to_string().len());
+        let (decoded, used) = fn_json_recv(binary.as_ref(), &dt).unwrap();
+        assert_eq!(used as usize, binary.as_ref().len());
+        assert_eq!(decoded.expect_json(), &doc);
+        assert!(fn_json_recv(&binary.as_ref()[..binary.as_ref().len() - 1], &dt).is_err());
+    }
+
+    #[test]
+    fn compare_ignores_object_key_order() {
+        let a = json_value!({"x": 1, "y": [true, null]});
+        let b = json_value!({"y": [true, null], "x": 1});
+        assert_eq!(json_cmp(&a, &b), Ordering::Equal);
+        assert_eq!(hash_of(&a), hash_of(&b));
+
+        assert_eq!(
+            json_cmp(&json_value!(null), &json_value!(false)),
+            Ordering::Less
+        );
+        assert_eq!(json_cmp(&json_value!(2), &json_value!(10)), Ordering::Less);
+        assert_ne!(
+            json_cmp(&json_value!(1), &json_value!(1.0)),
+            Ordering::Equal
+        );
+        assert_eq!(
+            json_cmp(&json_value!([1, 2]), &json_value!([1, 2, 0])),
+            Ordering::Less
+        );
+    }
+}
@@ -0,0 +1,55 @@
+use crate::dat_type::DatType;
+use crate::dat_type_id::DatTypeID;
+use crate::dat_value::DatValue;
+use crate::dt_fn_arbitrary::FnArbitrary;
+use arbitrary::{Arbitrary, Unstructured};
+use mudu::utils::json::{JsonMap, JsonValue};
+
+fn arb_json(u: &mut Unstructured, depth: u32) -> arbitrary::Result<JsonValue> {
+    let kind = if depth == 0 {
+        u8::arbitrary(u)? % 4
+    } else {
+        u8::arbitrary(u)? % 6
+    };
+    let json = match kind {
+        0 => JsonValue::Null,
+        1 => JsonValue::Bool(bool::arbitrary(u)?),
+        2 => JsonValue::from(i64::arbitrary(u)?),
+        3 => JsonValue::String(String::arbitrary(u)?),
+        4 => {
+            let len = u8::arbitrary(u)? % 4;
+            let mut array = Vec::with_capacity(len as usize);
+            for _ in 0..len {
+                array.push(arb_json(u, depth - 1)?);
+            }
+            JsonValue::Array(array)
+        }
+        _ => {
+            let len = u8::arbitrary(u)? % 4;
+            let mut map = JsonMap::new();
+            for _ in 0..len {
+                let _ = map.insert(String::arbitrary(u)?, arb_json(u, depth - 1)?);
+            }
+            JsonValue::Object(map)
+        }
+    };
+    Ok(json)
+}
+
+pub fn fn_json_arb_val(u: &mut Unstructured, _: &DatType) -> arbitrary::Result<DatValue> {
+    Ok(DatValue::from_json(arb_json(u, 2)?))
+}
+
+pub fn fn_json_arb_printable(u: &mut Unstructured, _: &DatType) -> arbitrary::Result<String> {
+    Ok(arb_json(u, 2)?.to_string())
+}
+
+pub fn fn_json_arb_dt_param(_u: &mut Unstructured) -> arbitrary::Result<DatType> {
+    Ok(DatType::new_no_param(DatTypeID::Json))
+}
+
+pub const FN_JSON_ARBITRARY: FnArbitrary = FnArbitrary {
+    param: fn_json_arb_dt_param,
+    value_object: fn_json_arb_val,
+    value_print: fn_json_arb_printable,
+};
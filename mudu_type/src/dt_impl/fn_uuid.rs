@@ -0,0 +1,135 @@
+use crate::dat_binary::DatBinary;
+use crate::dat_json::DatJson;
+use crate::dat_textual::DatTextual;
+use crate::dat_type::DatType;
+use crate::dat_value::DatValue;
+use crate::dt_fn_compare::{ErrCompare, FnCompare};
+use crate::dt_fn_convert::FnBase;
+use crate::type_error::{TyEC, TyErr};
+use mudu::common::endian;
+use mudu::data_type::uuid::UuidValue;
+use mudu::utils::json::{JsonValue, from_json_str};
+use mudu::utils::msg_pack::{MsgPackUtf8String, MsgPackValue};
+use std::cmp::Ordering;
+use std::hash::Hasher;
+
+fn parse_uuid_str(value: &str) -> Result<UuidValue, TyErr> {
+    UuidValue::parse(value).map_err(|e| TyErr::new(TyEC::TypeConvertFailed, e))
+}
+
+fn fn_uuid_in_textual(v: &str, dt: &DatType) -> Result<DatValue, TyErr> {
+    let json = from_json_str::<JsonValue>(v)
+        .map_err(|e| TyErr::new(TyEC::TypeConvertFailed, e.to_string()))?;
+    fn_uuid_in_json(&json, dt)
+}
+
+fn fn_uuid_out_textual(v: &DatValue, dt: &DatType) -> Result<DatTextual, TyErr> {
+    let json = fn_uuid_out_json(v, dt)?;
+    Ok(DatTextual::from(json.to_string()))
+}
+
+fn fn_uuid_in_json(v: &JsonValue, _: &DatType) -> Result<DatValue, TyErr> {
+    match v.as_str() {
+        Some(s) => Ok(DatValue::from_uuid(parse_uuid_str(s)?)),
+        None => Err(TyErr::new(
+            TyEC::TypeConvertFailed,
+            format!("cannot convert json {} to uuid", v),
+        )),
+    }
+}
+
+fn fn_uuid_out_json(v: &DatValue, _: &DatType) -> Result<DatJson, TyErr> {
+    Ok(DatJson::from(JsonValue::String(v.expect_uuid().format())))
+}
+
+fn fn_uuid_in_msgpack(msg_pack: &MsgPackValue, _: &DatType) -> Result<DatValue, TyErr> {
+    match msg_pack.as_str() {
+        Some(s) => Ok(DatValue::from_uuid(parse_uuid_str(s)?)),
+        None => Err(TyErr::new(
+            TyEC::TypeConvertFailed,
+            "cannot convert msg pack to uuid".to_string(),
+        )),
+    }
+}
+
+fn fn_uuid_out_msgpack(v: &DatValue, _: &DatType) -> Result<MsgPackValue, TyErr> {
+    Ok(MsgPackValue::String(MsgPackUtf8String::from(
+        v.expect_uuid().format(),
+    )))
+}
+
+fn fn_uuid_len(_: &DatType) -> Result<Option<u32>, TyErr> {
+    Ok(Some(size_of::<u128>() as u32))
+}
+
+fn fn_uuid_dat_output_len(_: &DatValue, ty: &DatType) -> Result<u32, TyErr> {
+    Ok(fn_uuid_len(ty)?.unwrap())
+}
+
+fn fn_uuid_send(v: &DatValue, _: &DatType) -> Result<DatBinary, TyErr> {
+    let mut buf = vec![0; size_of::<u128>()];
+    endian::write_u128(&mut buf, v.expect_uuid().to_u128());
+    Ok(DatBinary::from(buf))
+}
+
+fn fn_uuid_send_to(v: &DatValue, _: &DatType, buf: &mut [u8]) -> Result<u32, TyErr> {
+    if buf.len() < size_of::<u128>() {
+        return Err(TyErr::new(
+            TyEC::InsufficientSpace,
+            "insufficient space".to_string(),
+        ));
+    }
+    endian::write_u128(buf, v.expect_uuid().to_u128());
+    Ok(size_of::<u128>() as u32)
+}
+
+fn fn_uuid_recv(buf: &[u8], _: &DatType) -> Result<(DatValue, u32), TyErr> {
+    if buf.len() < size_of::<u128>() {
+        return Err(TyErr::new(
+            TyEC::InsufficientSpace,
+            "insufficient space".to_string(),
+        ));
+    }
+    Ok((
+        DatValue::from_uuid(UuidValue::from_u128(endian::read_u128(buf))),
+        size_of::<u128>() as u32,
+    ))
+}
+
+fn fn_uuid_default(_: &DatType) -> Result<DatValue, TyErr> {
+    Ok(DatValue::from_uuid(UuidValue::default()))
+}
+
+fn fn_uuid_order(v1: &DatValue, v2: &DatValue) -> Result<Ordering, ErrCompare> {
+    Ok(v1.expect_uuid().cmp(v2.expect_uuid()))
+}
+
+fn fn_uuid_equal(v1: &DatValue, v2: &DatValue) -> Result<bool, ErrCompare> {
+    Ok(v1.expect_uuid() == v2.expect_uuid())
+}
+
+fn fn_uuid_hash(v: &DatValue, hasher: &mut dyn Hasher) -> Result<(), ErrCompare> {
+    hasher.write_u128(v.expect_uuid().to_u128());
+    Ok(())
+}
+
+pub const FN_UUID_COMPARE: FnCompare = FnCompare {
+    order: fn_uuid_order,
+    equal: fn_uuid_equal,
+    hash: fn_uuid_hash,
+};
+
+pub const FN_UUID_CONVERT: FnBase = FnBase {
+    input_textual: fn_uuid_in_textual,
+    output_textual: fn_uuid_out_textual,
+    input_json: fn_uuid_in_json,
+    output_json: fn_uuid_out_json,
+    input_msg_pack: fn_uuid_in_msgpack,
+    output_msg_pack: fn_uuid_out_msgpack,
+    type_len: fn_uuid_len,
+    data_len: fn_uuid_dat_output_len,
+    receive: fn_uuid_recv,
+    send: fn_uuid_send,
+    send_to: fn_uuid_send_to,
+    default: fn_uuid_default,
+};
@@ -0,0 +1,29 @@
+use crate::dat_type::DatType;
+use crate::dat_type_id::DatTypeID;
+use crate::dat_value::DatValue;
+use crate::dt_fn_arbitrary::FnArbitrary;
+use arbitrary::{Arbitrary, Unstructured};
+use mudu::data_type::uuid::UuidValue;
+
+pub fn fn_uuid_arb_val(u: &mut Unstructured, _: &DatType) -> arbitrary::Result<DatValue> {
+    Ok(DatValue::from_uuid(UuidValue::from_u128(u128::arbitrary(
+        u,
+    )?)))
+}
+
+pub fn fn_uuid_arb_printable(u: &mut Unstructured, _: &DatType) -> arbitrary::Result<String> {
+    Ok(format!(
+        "\"{}\"",
+        UuidValue::from_u128(u128::arbitrary(u)?).format()
+    ))
+}
+
+pub fn fn_uuid_arb_dt_param(_u: &mut Unstructured) -> arbitrary::Result<DatType> {
+    Ok(DatType::new_no_param(DatTypeID::Uuid))
+}
+
+pub const FN_UUID_ARBITRARY: FnArbitrary = FnArbitrary {
+    param: fn_uuid_arb_dt_param,
+    value_object: fn_uuid_arb_val,
+    value_print: fn_uuid_arb_printable,
+};
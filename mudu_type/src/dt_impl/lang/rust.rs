@@ -5,10 +5,15 @@ use std::collections::HashMap;
 
 lazy_static! {
     static ref _id_lang_type_name: Vec<(DatTypeID, &'static str)> = vec![
+        (DatTypeID::Bool, "bool"),
+        (DatTypeID::I8, "i8"),
+        (DatTypeID::I16, "i16"),
         (DatTypeID::I32, "i32"),
         (DatTypeID::I64, "i64"),
         (DatTypeID::I128, "i128"),
         (DatTypeID::U128, "OID"),
+        (DatTypeID::Uuid, "UuidValue"),
+        (DatTypeID::Json, "JsonValue"),
         (DatTypeID::F32, "f32"),
         (DatTypeID::F64, "f64"),
         (DatTypeID::String, "String"),
@@ -5,6 +5,11 @@ mod tests {
 
     #[test]
     fn dt_lang_name_to_id_known_types() {
+        assert_eq!(dt_lang_name_to_id("bool").unwrap().0, DatTypeID::Bool);
+        assert_eq!(dt_lang_name_to_id("i8").unwrap().0, DatTypeID::I8);
+        assert_eq!(dt_lang_name_to_id("i16").unwrap().0, DatTypeID::I16);
+        assert_eq!(dt_lang_name_to_id("UuidValue").unwrap().0, DatTypeID::Uuid);
+        assert_eq!(dt_lang_name_to_id("JsonValue").unwrap().0, DatTypeID::Json);
         assert_eq!(dt_lang_name_to_id("i32").unwrap().0, DatTypeID::I32);
         assert_eq!(dt_lang_name_to_id("i64").unwrap().0, DatTypeID::I64);
         assert_eq!(dt_lang_name_to_id("i128").unwrap().0, DatTypeID::I128);
@@ -21,6 +26,11 @@ mod tests {
 
     #[test]
     fn dt_id_to_lang_name_known_types() {
+        assert_eq!(dt_id_to_lang_name(DatTypeID::Bool).unwrap(), "bool");
+        assert_eq!(dt_id_to_lang_name(DatTypeID::I8).unwrap(), "i8");
+        assert_eq!(dt_id_to_lang_name(DatTypeID::I16).unwrap(), "i16");
+        assert_eq!(dt_id_to_lang_name(DatTypeID::Uuid).unwrap(), "UuidValue");
+        assert_eq!(dt_id_to_lang_name(DatTypeID::Json).unwrap(), "JsonValue");
         assert_eq!(dt_id_to_lang_name(DatTypeID::I32).unwrap(), "i32");
         assert_eq!(dt_id_to_lang_name(DatTypeID::I64).unwrap(), "i64");
         assert_eq!(dt_id_to_lang_name(DatTypeID::I128).unwrap(), "i128");
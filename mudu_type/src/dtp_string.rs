@@ -1,3 +1,4 @@
+use crate::collation::Collation;
 use crate::dat_type_id::DatTypeID;
 use crate::dt_param::{DTPDyn, DTPStatic};
 use mudu::common::cmp_order::Order;
@@ -9,17 +10,31 @@ use std::cmp::Ordering;
 #[derive(Debug, Clone, Serialize, Deserialize, Default)]
 pub struct DTPString {
     length: u32,
+    #[serde(default)]
+    collation: Collation,
 }
 
 impl DTPString {
     pub fn new(length: u32) -> Self {
-        Self { length }
+        Self {
+            length,
+            collation: Collation::default(),
+        }
+    }
+
+    pub fn with_collation(mut self, collation: Collation) -> Self {
+        self.collation = collation;
+        self
     }
 
     pub fn length(&self) -> u32 {
         self.length
     }
 
+    pub fn collation(&self) -> Collation {
+        self.collation
+    }
+
     pub fn compare(&self, other: &Self) -> Ordering {
         match (self.fixed_length(), other.fixed_length()) {
             (true, true) => Ordering::Equal,
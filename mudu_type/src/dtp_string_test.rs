@@ -1,5 +1,6 @@
 #[cfg(test)]
 mod tests {
+    use crate::collation::Collation;
     use crate::dt_param::DTPDyn;
     use crate::dtp_string::DTPString;
     use mudu::common::cmp_order::Order;
@@ -40,6 +41,22 @@ mod tests {
         assert_eq!(restored.length(), 7);
     }
 
+    #[test]
+    fn string_collation_defaults_to_binary_and_roundtrips() {
+        assert_eq!(DTPString::new(7).collation(), Collation::Binary);
+        let s = DTPString::new(7).with_collation(Collation::UnicodeRoot);
+        let json = s.se_to_json().unwrap();
+        let mut restored = DTPString::default();
+        restored.de_from_json(&json).unwrap();
+        assert_eq!(restored.collation(), Collation::UnicodeRoot);
+        assert_eq!(restored.length(), 7);
+
+        // Params serialized before collations existed still load.
+        let mut legacy = DTPString::default();
+        legacy.de_from_json(r#"{"length":3}"#).unwrap();
+        assert_eq!(legacy.collation(), Collation::Binary);
+    }
+
     #[test]
     fn string_name_is_non_empty() {
         let s = DTPString::new(3);
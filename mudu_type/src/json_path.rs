@@ -0,0 +1,208 @@
+//! JSON path extraction and containment, backing the SQL `->`, `->>` and
+//! `@>` operators and the `json_extract` function.
+
+use mudu::common::result::RS;
+use mudu::error::ErrorCode;
+use mudu::mudu_error;
+use mudu::utils::json::JsonValue;
+use serde::{Deserialize, Serialize};
+use std::fmt::{Display, Formatter};
+
+/// One step of a JSON path: an object key or an array index.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub enum JsonPathStep {
+    Key(String),
+    /// Negative indexes count from the end of the array.
+    Index(i64),
+}
+
+impl JsonPathStep {
+    /// Applies this step to `doc`, as `doc -> step` does.
+    ///
+    /// Returns `None` when the key or index does not exist or when the step
+    /// does not match the shape of `doc`.
+    pub fn apply<'a>(&self, doc: &'a JsonValue) -> Option<&'a JsonValue> {
+        match (self, doc) {
+            (JsonPathStep::Key(key), JsonValue::Object(map)) => map.get(key),
+            (JsonPathStep::Index(index), JsonValue::Array(array)) => {
+                let index = if *index < 0 {
+                    array.len() as i64 + *index
+                } else {
+                    *index
+                };
+                usize::try_from(index).ok().and_then(|i| array.get(i))
+            }
+            _ => None,
+        }
+    }
+}
+
+/// A parsed path such as `$.tags[0]."display name"`.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct JsonPath {
+    steps: Vec<JsonPathStep>,
+}
+
+impl JsonPath {
+    pub fn new(steps: Vec<JsonPathStep>) -> Self {
+        Self { steps }
+    }
+
+    pub fn steps(&self) -> &[JsonPathStep] {
+        &self.steps
+    }
+
+    /// Parses a path rooted at `$`.
+    ///
+    /// Keys follow a `.` and are either bare (up to the next `.` or `[`) or
+    /// double quoted with `\"` and `\\` escapes; indexes are written `[n]`.
+    pub fn parse(path: &str) -> RS<Self> {
+        let invalid = |reason: &str| {
+            mudu_error!(
+                ErrorCode::Parse,
+                format!("invalid json path {}: {}", path, reason)
+            )
+        };
+        let mut chars = path.chars().peekable();
+        if chars.next() != Some('$') {
+            return Err(invalid("path must start with $"));
+        }
+        let mut steps = vec![];
+        while let Some(c) = chars.next() {
+            match c {
+                '.' => {
+                    let mut key = String::new();
+                    if chars.peek() == Some(&'"') {
+                        chars.next();
+                        loop {
+                            match chars.next() {
+                                Some('"') => break,
+                                Some('\\') => match chars.next() {
+                                    Some(escaped @ ('"' | '\\')) => key.push(escaped),
+                                    _ => return Err(invalid("bad escape in quoted key")),
+                                },
+                                Some(c) => key.push(c),
+                                None => return Err(invalid("unterminated quoted key")),
+                            }
+                        }
+                    } else {
+                        while let Some(&c) = chars.peek() {
+                            if c == '.' || c == '[' {
+                                break;
+                            }
+                            key.push(c);
+                            chars.next();
+                        }
+                        if key.is_empty() {
+                            return Err(invalid("empty key"));
+                        }
+                    }
+                    steps.push(JsonPathStep::Key(key));
+                }
+                '[' => {
+                    let mut digits = String::new();
+                    loop {
+                        match chars.next() {
+                            Some(']') => break,
+                            Some(c) => digits.push(c),
+                            None => return Err(invalid("unterminated index")),
+                        }
+                    }
+                    let index = digits
+                        .trim()
+                        .parse::<i64>()
+                        .map_err(|_| invalid("index is not an integer"))?;
+                    steps.push(JsonPathStep::Index(index));
+                }
+                _ => return Err(invalid("expected . or [")),
+            }
+        }
+        Ok(Self::new(steps))
+    }
+
+    /// Follows every step from `doc`, returning `None` as soon as one misses.
+    pub fn extract<'a>(&self, doc: &'a JsonValue) -> Option<&'a JsonValue> {
+        self.steps.iter().try_fold(doc, |v, step| step.apply(v))
+    }
+}
+
+/// Writes the path in the form [`JsonPath::parse`] reads, quoting keys that
+/// cannot be written bare.
+impl Display for JsonPath {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "$")?;
+        for step in &self.steps {
+            match step {
+                JsonPathStep::Key(key)
+                    if !key.is_empty() && !key.starts_with('"') && !key.contains(['.', '[']) =>
+                {
+                    write!(f, ".{}", key)?
+                }
+                JsonPathStep::Key(key) => {
+                    write!(f, ".\"{}\"", key.replace('\\', "\\\\").replace('"', "\\\""))?
+                }
+                JsonPathStep::Index(index) => write!(f, "[{}]", index)?,
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Renders a JSON value the way `->>` does: strings without quotes, JSON
+/// `null` as SQL NULL (`None`) and everything else as JSON text.
+pub fn json_to_text(value: &JsonValue) -> Option<String> {
+    match value {
+        JsonValue::Null => None,
+        JsonValue::String(s) => Some(s.clone()),
+        other => Some(other.to_string()),
+    }
+}
+
+/// `doc ->> step`.
+pub fn json_arrow_text(doc: &JsonValue, step: &JsonPathStep) -> Option<String> {
+    step.apply(doc).and_then(json_to_text)
+}
+
+/// `json_extract(doc, path)`.
+pub fn json_extract(doc: &JsonValue, path: &str) -> RS<Option<JsonValue>> {
+    Ok(JsonPath::parse(path)?.extract(doc).cloned())
+}
+
+/// `doc @> other`, with PostgreSQL `jsonb` semantics: objects contain the
+/// keys of `other` with contained values, arrays contain every element of
+/// `other` somewhere, and a top-level array also contains a bare scalar
+/// that is one of its elements.
+pub fn json_contains(doc: &JsonValue, other: &JsonValue) -> bool {
+    match (doc, other) {
+        (JsonValue::Array(array), scalar) if !scalar.is_array() && !scalar.is_object() => {
+            array.iter().any(|item| json_scalar_eq(item, scalar))
+        }
+        _ => contains(doc, other),
+    }
+}
+
+fn contains(doc: &JsonValue, other: &JsonValue) -> bool {
+    match (doc, other) {
+        (JsonValue::Object(doc), JsonValue::Object(other)) => other
+            .iter()
+            .all(|(key, value)| doc.get(key).is_some_and(|v| contains(v, value))),
+        (JsonValue::Array(doc), JsonValue::Array(other)) => other
+            .iter()
+            .all(|value| doc.iter().any(|v| contains(v, value))),
+        _ => json_scalar_eq(doc, other),
+    }
+}
+
+/// Numbers compare by value, so `1` and `1.0` are the same scalar.
+fn json_scalar_eq(a: &JsonValue, b: &JsonValue) -> bool {
+    match (a, b) {
+        (JsonValue::Number(a), JsonValue::Number(b)) => {
+            match (a.as_i64(), b.as_i64(), a.as_u64(), b.as_u64()) {
+                (Some(a), Some(b), _, _) => a == b,
+                (_, _, Some(a), Some(b)) => a == b,
+                _ => a.as_f64() == b.as_f64(),
+            }
+        }
+        _ => a == b,
+    }
+}
@@ -0,0 +1,86 @@
+use crate::json_path::{
+    json_arrow_text, json_contains, json_extract, json_to_text, JsonPath, JsonPathStep,
+};
+use mudu::json_value;
+
+#[test]
+fn parse_accepts_keys_indexes_and_quoted_keys() {
+    let path = JsonPath::parse("$.tags[0].\"display name\"[-1]").unwrap();
+    assert_eq!(
+        path.steps(),
+        &[
+            JsonPathStep::Key("tags".to_string()),
+            JsonPathStep::Index(0),
+            JsonPathStep::Key("display name".to_string()),
+            JsonPathStep::Index(-1),
+        ]
+    );
+    assert!(JsonPath::parse("$").unwrap().steps().is_empty());
+}
+
+#[test]
+fn parse_rejects_malformed_paths() {
+    for path in ["", "a.b", "$.", "$..a", "$[x]", "$[1", "$.\"open", "$a"] {
+        assert!(
+            JsonPath::parse(path).is_err(),
+            "{} should be rejected",
+            path
+        );
+    }
+}
+
+#[test]
+fn extract_follows_steps_and_misses_cleanly() {
+    let doc = json_value!({"user": {"tags": ["a", "b", "c"]}});
+    assert_eq!(
+        json_extract(&doc, "$.user.tags[1]").unwrap(),
+        Some(json_value!("b"))
+    );
+    assert_eq!(
+        json_extract(&doc, "$.user.tags[-1]").unwrap(),
+        Some(json_value!("c"))
+    );
+    assert_eq!(json_extract(&doc, "$.user.tags[3]").unwrap(), None);
+    assert_eq!(json_extract(&doc, "$.user.tags.x").unwrap(), None);
+    assert_eq!(json_extract(&doc, "$.missing").unwrap(), None);
+}
+
+#[test]
+fn arrow_text_unquotes_strings_and_maps_null_to_none() {
+    let doc = json_value!({"name": "neo", "age": 3, "nick": null, "tags": [1]});
+    let key = |k: &str| JsonPathStep::Key(k.to_string());
+    assert_eq!(json_arrow_text(&doc, &key("name")), Some("neo".to_string()));
+    assert_eq!(json_arrow_text(&doc, &key("age")), Some("3".to_string()));
+    assert_eq!(json_arrow_text(&doc, &key("tags")), Some("[1]".to_string()));
+    assert_eq!(json_arrow_text(&doc, &key("nick")), None);
+    assert_eq!(json_to_text(&json_value!(true)), Some("true".to_string()));
+}
+
+#[test]
+fn contains_matches_postgres_jsonb_semantics() {
+    let doc = json_value!({"a": 1, "b": {"c": [1, 2, 3]}, "d": "x"});
+    assert!(json_contains(&doc, &json_value!({})));
+    assert!(json_contains(&doc, &json_value!({"a": 1.0})));
+    assert!(json_contains(&doc, &json_value!({"b": {"c": [3, 1]}})));
+    assert!(!json_contains(&doc, &json_value!({"b": {"c": [4]}})));
+    assert!(!json_contains(&doc, &json_value!({"a": "1"})));
+    assert!(!json_contains(&doc, &json_value!({"b": {"c": 1}})));
+
+    assert!(json_contains(&json_value!([1, 2]), &json_value!(2)));
+    assert!(json_contains(&json_value!([[1], 2]), &json_value!([[1]])));
+    assert!(!json_contains(&json_value!("a"), &json_value!(["a"])));
+}
+
+#[test]
+fn display_writes_a_path_parse_reads_back() {
+    let path = JsonPath::new(vec![
+        JsonPathStep::Key("tags".to_string()),
+        JsonPathStep::Index(-1),
+        JsonPathStep::Key("a.b".to_string()),
+        JsonPathStep::Key("\"q\\".to_string()),
+        JsonPathStep::Key(String::new()),
+    ]);
+    assert_eq!(path.to_string(), "$.tags[-1].\"a.b\".\"\\\"q\\\\\".\"\"");
+    assert_eq!(JsonPath::parse(&path.to_string()).unwrap(), path);
+    assert_eq!(JsonPath::new(vec![]).to_string(), "$");
+}
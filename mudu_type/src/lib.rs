@@ -1,4 +1,9 @@
 pub mod array;
+#[cfg(test)]
+mod array_ops_test;
+pub mod collation;
+#[cfg(test)]
+mod collation_test;
 pub mod dat_binary;
 #[cfg(test)]
 mod dat_binary_test;
@@ -73,6 +78,10 @@ pub mod dtp_timestamptz;
 #[cfg(test)]
 mod dtp_timestamptz_test;
 
+pub mod json_path;
+#[cfg(test)]
+mod json_path_test;
+
 pub mod record;
 pub mod scalar_type;
 
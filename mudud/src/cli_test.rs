@@ -1,7 +1,7 @@
 //! Tests for the `mudud` CLI argument parser.
 #![allow(missing_docs)]
 
-use crate::Args;
+use crate::{Args, Command};
 use clap::Parser;
 
 #[test]
@@ -23,3 +23,106 @@ fn args_parse_rejects_unknown_flag() {
     let result = Args::try_parse_from(["mudud", "--unknown"]);
     assert!(result.is_err());
 }
+
+#[test]
+fn args_parse_verify_key_file() -> Result<(), Box<dyn std::error::Error>> {
+    let args = Args::try_parse_from([
+        "mudud",
+        "verify",
+        "--key-file",
+        "/etc/mududb/keys",
+        "/var/lib/mududb",
+    ])?;
+    match args.command {
+        Some(Command::Verify { key_file, .. }) => {
+            assert_eq!(key_file, Some(std::path::PathBuf::from("/etc/mududb/keys")))
+        }
+        _ => return Err("missing verify command".into()),
+    }
+    Ok(())
+}
+
+#[test]
+fn args_parse_verify_subcommand() -> Result<(), Box<dyn std::error::Error>> {
+    let args = Args::try_parse_from(["mudud", "verify", "/var/lib/mududb"])?;
+    match args.command {
+        Some(Command::Verify { data_dir, key_file }) => {
+            assert_eq!(data_dir, std::path::PathBuf::from("/var/lib/mududb"));
+            assert_eq!(key_file, None);
+        }
+        _ => return Err("missing verify command".into()),
+    }
+    Ok(())
+}
+
+#[test]
+fn args_parse_restore_subcommand() -> Result<(), Box<dyn std::error::Error>> {
+    let args = Args::try_parse_from(["mudud", "restore", "/backup/mududb", "/var/lib/mududb"])?;
+    match args.command {
+        Some(Command::Restore {
+            backup_dir,
+            data_dir,
+            key_file,
+        }) => {
+            assert_eq!(backup_dir, std::path::PathBuf::from("/backup/mududb"));
+            assert_eq!(data_dir, std::path::PathBuf::from("/var/lib/mududb"));
+            assert_eq!(key_file, None);
+        }
+        _ => return Err("missing restore command".into()),
+    }
+    Ok(())
+}
+
+#[test]
+fn args_parse_migrate_page_size_subcommand() -> Result<(), Box<dyn std::error::Error>> {
+    let args = Args::try_parse_from([
+        "mudud",
+        "migrate-page-size",
+        "--page-size",
+        "16384",
+        "--dry-run",
+        "/var/lib/mududb",
+    ])?;
+    match args.command {
+        Some(Command::MigratePageSize {
+            data_dir,
+            page_size,
+            dry_run,
+            backup_dir,
+            rollback,
+            ..
+        }) => {
+            assert_eq!(data_dir, std::path::PathBuf::from("/var/lib/mududb"));
+            assert_eq!(page_size, Some(16384));
+            assert!(dry_run);
+            assert_eq!(backup_dir, None);
+            assert!(!rollback);
+        }
+        _ => return Err("missing migrate-page-size command".into()),
+    }
+
+    let args = Args::try_parse_from([
+        "mudud",
+        "migrate-page-size",
+        "--rollback",
+        "--backup-dir",
+        "/backup/pages",
+        "/var/lib/mududb",
+    ])?;
+    match args.command {
+        Some(Command::MigratePageSize {
+            page_size,
+            backup_dir,
+            rollback,
+            ..
+        }) => {
+            assert_eq!(page_size, None);
+            assert_eq!(backup_dir, Some(std::path::PathBuf::from("/backup/pages")));
+            assert!(rollback);
+        }
+        _ => return Err("missing migrate-page-size command".into()),
+    }
+
+    assert!(Args::try_parse_from(["mudud", "migrate-page-size", "/var/lib/mududb"]).is_err());
+    Ok(())
+}
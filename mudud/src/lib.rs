@@ -12,13 +12,21 @@
 #![warn(clippy::todo)]
 #![warn(clippy::unimplemented)]
 
-use clap::Parser;
+use clap::{Parser, Subcommand};
 use mudu::common::result::RS;
+use mudu_kernel::storage::backup::restore_backup;
+use mudu_kernel::storage::encryption::Keyring;
+use mudu_kernel::storage::page_size_migration::{
+    self, PageSizeMigrationOptions, default_backup_dir, rollback_page_size_migration,
+};
+use mudu_kernel::storage::verify::verify_data_dir;
 use mudu_runtime::backend::backend::Backend;
 use mudu_runtime::backend::mududb_cfg::{MuduDBCfg, load_mududb_cfg};
 use mudu_sys::task::async_::wait_for_shutdown_signal;
 use mudu_sys::task::sync::{SJoinHandle, spawn_thread_named};
 use mudu_utils::notifier::{Notifier, Waiter, notify_wait};
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
 use tracing::info;
 
 /// Command-line arguments for `mudud`.
@@ -28,6 +36,134 @@ pub struct Args {
     /// Path to mududb configuration TOML file.
     #[arg(long = "cfg", value_name = "FILE")]
     pub cfg_path: Option<String>,
+    /// Offline maintenance command; the server runs when none is given.
+    #[command(subcommand)]
+    pub command: Option<Command>,
+}
+
+/// Offline maintenance commands.
+#[derive(Debug, Subcommand)]
+pub enum Command {
+    /// Check every page file and WAL chunk in a data directory and report
+    /// corrupt pages and frames. The server must not be running.
+    Verify {
+        /// Data directory to scan.
+        #[arg(value_name = "DATA_DIR")]
+        data_dir: PathBuf,
+        /// Encryption key file used to open sealed pages and frames. Without
+        /// it, only their checksums and headers are checked.
+        #[arg(long = "key-file", value_name = "FILE")]
+        key_file: Option<PathBuf>,
+    },
+    /// Copy an online backup into an empty data directory and verify the
+    /// restored pages and WAL chunks. The server must not be running.
+    Restore {
+        /// Backup directory written by `mcli backup`.
+        #[arg(value_name = "BACKUP_DIR")]
+        backup_dir: PathBuf,
+        /// Data directory to restore into; it must be empty or missing.
+        #[arg(value_name = "DATA_DIR")]
+        data_dir: PathBuf,
+        /// Encryption key file used to open sealed pages and frames while
+        /// verifying.
+        #[arg(long = "key-file", value_name = "FILE")]
+        key_file: Option<PathBuf>,
+    },
+    /// Rewrite the relation pages of a data directory with a new page size,
+    /// or roll a migration back. The server must not be running.
+    MigratePageSize {
+        /// Data directory to migrate.
+        #[arg(value_name = "DATA_DIR")]
+        data_dir: PathBuf,
+        /// New page size in bytes; a power of two of at least 4096.
+        #[arg(
+            long = "page-size",
+            value_name = "BYTES",
+            required_unless_present = "rollback"
+        )]
+        page_size: Option<usize>,
+        /// Only check that every page fits the new page size.
+        #[arg(long = "dry-run", conflicts_with = "rollback")]
+        dry_run: bool,
+        /// Directory for the original files; it must be empty or missing.
+        /// Defaults to `<DATA_DIR>.page_size_backup`.
+        #[arg(long = "backup-dir", value_name = "DIR")]
+        backup_dir: Option<PathBuf>,
+        /// Restore the original files from the backup directory instead of
+        /// migrating.
+        #[arg(long = "rollback", conflicts_with = "page_size")]
+        rollback: bool,
+        /// Encryption key file used to open and seal pages.
+        #[arg(long = "key-file", value_name = "FILE")]
+        key_file: Option<PathBuf>,
+    },
+}
+
+/// Load the keys of `key_file`, if one is given, for the offline tools.
+fn load_keyring(key_file: Option<&Path>) -> RS<Option<Arc<Keyring>>> {
+    key_file
+        .map(|key_file| Keyring::load(key_file).map(Arc::new))
+        .transpose()
+}
+
+/// Scan `data_dir` and print the verification report, opening sealed data
+/// with the keys of `key_file` if one is given.
+///
+/// Returns `false` if any page or frame is corrupt.
+pub fn verify(data_dir: &Path, key_file: Option<&Path>) -> RS<bool> {
+    let keyring = load_keyring(key_file)?;
+    let report = verify_data_dir(data_dir, keyring.as_deref())?;
+    println!("{}", report);
+    Ok(report.is_clean())
+}
+
+/// Restore the backup in `backup_dir` into `data_dir` and print the
+/// verification report of the restored directory.
+///
+/// Returns `false` if any restored page or frame is corrupt.
+pub fn restore(backup_dir: &Path, data_dir: &Path, key_file: Option<&Path>) -> RS<bool> {
+    let keyring = load_keyring(key_file)?;
+    let report = restore_backup(backup_dir, data_dir, keyring.as_deref())?;
+    println!("{}", report);
+    Ok(report.is_clean())
+}
+
+/// Migrate the relation pages of `data_dir` to `page_size`, or roll back the
+/// migration whose original files are in `backup_dir` when `page_size` is
+/// `None`, and print the result.
+///
+/// Returns `false` if the migrated directory does not verify clean.
+pub fn migrate_page_size(
+    data_dir: &Path,
+    page_size: Option<usize>,
+    dry_run: bool,
+    backup_dir: Option<&Path>,
+    key_file: Option<&Path>,
+) -> RS<bool> {
+    let keyring = load_keyring(key_file)?;
+    let Some(page_size) = page_size else {
+        let backup_dir = backup_dir
+            .map(Path::to_path_buf)
+            .unwrap_or_else(|| default_backup_dir(data_dir));
+        rollback_page_size_migration(data_dir, &backup_dir)?;
+        println!(
+            "restored {} from {}",
+            data_dir.display(),
+            backup_dir.display()
+        );
+        return Ok(true);
+    };
+    let options = PageSizeMigrationOptions {
+        dry_run,
+        backup_dir: backup_dir.map(Path::to_path_buf),
+        keyring,
+    };
+    let report = page_size_migration::migrate_page_size(data_dir, page_size, &options)?;
+    println!("{}", report);
+    Ok(report
+        .verify
+        .as_ref()
+        .is_none_or(|verify| verify.is_clean()))
 }
 
 /// Load configuration and run the backend until shutdown.
@@ -2,7 +2,13 @@
 //!
 //! This is the main entry point for running a MuduDB server process. It loads
 //! the configuration, sets up logging, and drives the runtime backend until a
-//! shutdown signal is received.
+//! shutdown signal is received. `mudud verify [--key-file FILE] <DATA_DIR>`
+//! instead checks the data files of a stopped server, and
+//! `mudud restore [--key-file FILE] <BACKUP_DIR> <DATA_DIR>` restores an
+//! online backup into an empty data directory.
+//! `mudud migrate-page-size --page-size BYTES <DATA_DIR>` rewrites the
+//! relation pages of a stopped server with a new page size, and
+//! `--rollback` puts the original files back.
 
 #![warn(missing_docs)]
 #![deny(clippy::unwrap_used)]
@@ -14,12 +20,54 @@
 
 use clap::Parser;
 use mudu_utils::log::log_setup_ex;
-use mudud::{Args, serve};
+use mudud::{Args, Command, migrate_page_size, restore, serve, verify};
 use tracing::error;
 
 fn main() {
     log_setup_ex("info", "", false);
     let args = Args::parse();
+    let offline = match &args.command {
+        Some(Command::Verify { data_dir, key_file }) => {
+            Some(("verify", verify(data_dir, key_file.as_deref())))
+        }
+        Some(Command::Restore {
+            backup_dir,
+            data_dir,
+            key_file,
+        }) => Some((
+            "restore",
+            restore(backup_dir, data_dir, key_file.as_deref()),
+        )),
+        Some(Command::MigratePageSize {
+            data_dir,
+            page_size,
+            dry_run,
+            backup_dir,
+            rollback: _,
+            key_file,
+        }) => Some((
+            "migrate-page-size",
+            migrate_page_size(
+                data_dir,
+                *page_size,
+                *dry_run,
+                backup_dir.as_deref(),
+                key_file.as_deref(),
+            ),
+        )),
+        None => None,
+    };
+    if let Some((command, result)) = offline {
+        match result {
+            Ok(true) => {}
+            Ok(false) => std::process::exit(1),
+            Err(e) => {
+                error!("mududb {} error: {}", command, e);
+                std::process::exit(2);
+            }
+        }
+        return;
+    }
     let r = serve(args);
     match r {
         Ok(_) => {}
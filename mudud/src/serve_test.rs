@@ -21,6 +21,7 @@ fn serve_with_stop_creates_default_config_when_missing() -> Result<(), Box<dyn s
     let cfg_path = unique_path("missing_mududb_cfg");
     let args = Args {
         cfg_path: Some(cfg_path.clone()),
+        command: None,
     };
     let (_notifier, stop_waiter) = notify_wait();
 
@@ -58,6 +59,7 @@ enable_async = false
 
     let args = Args {
         cfg_path: Some(cfg_path.clone()),
+        command: None,
     };
     let (_notifier, stop_waiter) = notify_wait();
 
@@ -78,6 +80,7 @@ fn serve_with_stop_propagates_runner_error() -> Result<(), Box<dyn std::error::E
     let cfg_path = unique_path("runner_error_cfg");
     let args = Args {
         cfg_path: Some(cfg_path.clone()),
+        command: None,
     };
     let (_notifier, stop_waiter) = notify_wait();
 
@@ -98,6 +101,7 @@ fn serve_with_stop_propagates_config_decode_error() -> Result<(), Box<dyn std::e
     sync_write(&cfg_path, "this is not valid toml")?;
     let args = Args {
         cfg_path: Some(cfg_path.clone()),
+        command: None,
     };
     let (_notifier, stop_waiter) = notify_wait();
 
@@ -0,0 +1,9 @@
+/// Point in time an `AS OF SYSTEM TIME` clause reads at.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum AsOfTime {
+    /// A commit timestamp handed out by the worker serving the read.
+    CommitTs(u64),
+    /// A wall-clock time in microseconds since the Unix epoch, which each
+    /// worker maps to the latest of its commit timestamps at that time.
+    WallClock(i64),
+}
@@ -12,6 +12,7 @@ pub struct ColumnDef {
     data_type_param: Option<Vec<UniDatValue>>,
     opt_primary_key_index: Option<AttrIndex>,
     nullable: bool,
+    collation: Option<String>,
     index: AttrIndex,
 }
 
@@ -28,6 +29,7 @@ impl ColumnDef {
             data_type_param,
             opt_primary_key_index: None,
             nullable: true,
+            collation: None,
             index: AttrIndex::MAX,
         }
     }
@@ -80,6 +82,16 @@ impl ColumnDef {
         self.nullable = nullable;
     }
 
+    /// Return the collation name from a `COLLATE <name>` clause, if any.
+    pub fn collation(&self) -> Option<&str> {
+        self.collation.as_deref()
+    }
+
+    /// Set the collation name declared for this column.
+    pub fn set_collation(&mut self, collation: Option<String>) {
+        self.collation = collation;
+    }
+
     /// Set the table-level column index.
     pub fn set_index(&mut self, index: AttrIndex) {
         self.index = index;
@@ -0,0 +1,107 @@
+use crate::ast::ast_node::ASTNode;
+use crate::ast::expr_operator::JsonOperator;
+use crate::ast::expression::ExprType;
+use mudu_type::json_path::JsonPath;
+use std::fmt::{Debug, Formatter};
+
+/// JSON access expression (`->`, `->>`) with a document on the left and a
+/// key or index on the right.
+///
+/// Accesses into a column are folded into an [`ExprJsonPath`] on the column
+/// name instead; this node is left for the other operands.
+#[derive(Clone)]
+pub struct ExprJson {
+    op: JsonOperator,
+    left: ExprType,
+    right: ExprType,
+}
+
+impl ExprJson {
+    /// Create a new JSON access expression.
+    pub fn new(op: JsonOperator, left: ExprType, right: ExprType) -> Self {
+        Self { op, left, right }
+    }
+
+    /// Return the JSON operator.
+    pub fn op(&self) -> &JsonOperator {
+        &self.op
+    }
+
+    /// Return the JSON document operand.
+    pub fn left(&self) -> &ExprType {
+        &self.left
+    }
+
+    /// Return the key or index operand.
+    pub fn right(&self) -> &ExprType {
+        &self.right
+    }
+}
+
+impl Debug for ExprJson {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "json op: ")?;
+        self.op.fmt(f)?;
+        write!(f, "left: ")?;
+        self.left.fmt(f)?;
+        write!(f, "right: ")?;
+        self.right.fmt(f)?;
+        Ok(())
+    }
+}
+
+impl ASTNode for ExprJson {}
+
+/// A JSON path applied to a column, from `col -> 'a' ->> 0` or
+/// `json_extract(col, '$.a[0]')`.
+#[derive(Clone, Debug)]
+pub struct ExprJsonPath {
+    path: JsonPath,
+    as_text: bool,
+}
+
+impl ExprJsonPath {
+    /// Create a new path; `as_text` is set when the last step is `->>`.
+    pub fn new(path: JsonPath, as_text: bool) -> Self {
+        Self { path, as_text }
+    }
+
+    /// Return the path.
+    pub fn path(&self) -> &JsonPath {
+        &self.path
+    }
+
+    /// Return `true` if the result is text rather than JSON.
+    pub fn as_text(&self) -> bool {
+        self.as_text
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::ExprJson;
+    use crate::ast::expr_item::{ExprItem, ExprValue};
+    use crate::ast::expr_literal::ExprLiteral;
+    use crate::ast::expr_operator::JsonOperator;
+    use crate::ast::expression::ExprType;
+    use mudu_type::dat_typed::DatTyped;
+    use std::sync::Arc;
+
+    #[test]
+    fn json_expression_preserves_operands_and_operator() {
+        let left = ExprType::Value(Arc::new(ExprItem::ItemValue(ExprValue::ValueLiteral(
+            ExprLiteral::DatumLiteral(DatTyped::from_string("{\"a\":1}".to_string())),
+        ))));
+        let right = ExprType::Value(Arc::new(ExprItem::ItemValue(ExprValue::ValueLiteral(
+            ExprLiteral::DatumLiteral(DatTyped::from_string("a".to_string())),
+        ))));
+        let expr = ExprJson::new(JsonOperator::ArrowText, left, right);
+
+        assert!(matches!(expr.op(), JsonOperator::ArrowText));
+        assert!(matches!(expr.left(), ExprType::Value(_)));
+        assert!(matches!(expr.right(), ExprType::Value(_)));
+
+        let debug = format!("{expr:?}");
+        assert!(debug.contains("json op"));
+    }
+}
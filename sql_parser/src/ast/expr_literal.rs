@@ -10,15 +10,22 @@ pub enum ExprLiteral {
     Null,
     /// Typed datum literal (e.g., integer, string, numeric).
     DatumLiteral(DatTyped),
+    /// Array constructor (`ARRAY[1, 2]`); elements take the element type of
+    /// the target column.
+    Array(Vec<ExprLiteral>),
+    /// Row constructor (`ROW(1, 'a')`); fields take the field types of the
+    /// target record column, in order.
+    Row(Vec<ExprLiteral>),
 }
 
 impl ExprLiteral {
     /// Returns the concrete data type of the literal, if any.
     ///
-    /// `NULL` literals do not have a concrete data type and return `None`.
+    /// `NULL`, array and row literals do not have a concrete data type and
+    /// return `None`.
     pub fn dat_type(&self) -> Option<&DatTyped> {
         match self {
-            ExprLiteral::Null => None,
+            ExprLiteral::Null | ExprLiteral::Array(_) | ExprLiteral::Row(_) => None,
             ExprLiteral::DatumLiteral(typed) => Some(typed),
         }
     }
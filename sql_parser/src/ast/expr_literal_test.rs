@@ -21,3 +21,11 @@ fn datum_literal_preserves_underlying_type() {
     let typed = literal.dat_type().expect("datum literal has a type");
     assert_eq!(typed.dat_type().dat_type_id(), DatTypeID::I32);
 }
+
+#[test]
+fn array_and_row_literals_have_no_data_type() {
+    let array = ExprLiteral::Array(vec![ExprLiteral::DatumLiteral(DatTyped::from_i32(1))]);
+    assert!(array.dat_type().is_none());
+    let row = ExprLiteral::Row(vec![ExprLiteral::Null]);
+    assert!(row.dat_type().is_none());
+}
@@ -1,9 +1,13 @@
 use crate::ast::ast_node::ASTNode;
+use crate::ast::expr_json::ExprJsonPath;
 
-/// Named identifier expression (table, column, or alias name).
+/// Named identifier expression (table, column, or alias name), optionally
+/// followed by a JSON path into the column or an array subscript.
 #[derive(Clone, Debug)]
 pub struct ExprName {
     name: String,
+    json_path: Option<ExprJsonPath>,
+    element: Option<i64>,
 }
 
 impl ExprName {
@@ -11,6 +15,8 @@ impl ExprName {
     pub fn new() -> Self {
         Self {
             name: "".to_string(),
+            json_path: None,
+            element: None,
         }
     }
 
@@ -23,6 +29,27 @@ impl ExprName {
     pub fn name(&self) -> &String {
         &self.name
     }
+
+    /// Set the JSON path applied to the named column.
+    pub fn set_json_path(&mut self, json_path: ExprJsonPath) {
+        self.json_path = Some(json_path)
+    }
+
+    /// Return the JSON path applied to the named column, if any.
+    pub fn json_path(&self) -> Option<&ExprJsonPath> {
+        self.json_path.as_ref()
+    }
+
+    /// Set the 1-based subscript applied to the named array column.
+    pub fn set_element(&mut self, element: i64) {
+        self.element = Some(element)
+    }
+
+    /// Return the 1-based subscript applied to the named array column, if
+    /// any.
+    pub fn element(&self) -> Option<i64> {
+        self.element
+    }
 }
 
 impl Default for ExprName {
@@ -5,7 +5,9 @@
 #![allow(clippy::expect_used)]
 #![allow(clippy::panic)]
 
+use crate::ast::expr_json::ExprJsonPath;
 use crate::ast::expr_name::ExprName;
+use mudu_type::json_path::{JsonPath, JsonPathStep};
 
 #[test]
 fn new_creates_empty_name() {
@@ -25,3 +27,27 @@ fn set_name_updates_identifier() {
     name.set_name("user_id".to_string());
     assert_eq!(name.name(), "user_id");
 }
+
+#[test]
+fn json_path_defaults_to_none_and_can_be_set() {
+    let mut name = ExprName::new();
+    assert!(name.json_path().is_none());
+    name.set_json_path(ExprJsonPath::new(
+        JsonPath::new(vec![JsonPathStep::Key("a".to_string())]),
+        true,
+    ));
+    let json_path = name.json_path().unwrap();
+    assert_eq!(
+        json_path.path().steps(),
+        &[JsonPathStep::Key("a".to_string())]
+    );
+    assert!(json_path.as_text());
+}
+
+#[test]
+fn element_defaults_to_none_and_can_be_set() {
+    let mut name = ExprName::new();
+    assert!(name.element().is_none());
+    name.set_element(2);
+    assert_eq!(name.element(), Some(2));
+}
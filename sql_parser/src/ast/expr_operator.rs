@@ -12,6 +12,8 @@ pub enum Operator {
     OLogicalConnective(LogicalConnective),
     /// Arithmetic operator (`+`, `-`, `*`, `/`).
     OArithmetic(Arithmetic),
+    /// JSON access operator (`->`, `->>`).
+    OJson(JsonOperator),
 }
 
 /// Arithmetic operators.
@@ -27,6 +29,15 @@ pub enum Arithmetic {
     DIVIDE,
 }
 
+/// JSON access operators.
+#[derive(Clone, Copy, Debug)]
+pub enum JsonOperator {
+    /// Get a field or element as JSON (`->`).
+    Arrow,
+    /// Get a field or element as text (`->>`).
+    ArrowText,
+}
+
 /// Value comparison operators.
 #[derive(Copy, Clone, Debug)]
 pub enum ValueCompare {
@@ -42,6 +53,15 @@ pub enum ValueCompare {
     GT,
     /// Not equal (`!=`).
     NE,
+    /// Left JSON document contains the right one (`@>`).
+    Contains,
+    /// Left JSON document is contained in the right one (`<@`).
+    ContainedBy,
+    /// Left value equals an element of the right array (`= ANY(array)`).
+    EqAny,
+    /// An element of the left array equals the right value; [`ValueCompare::EqAny`]
+    /// with its operands swapped.
+    AnyEq,
 }
 
 /// Logical connective operators.
@@ -59,11 +79,15 @@ fn name2op(name: String) -> RS<Operator> {
         (">", Operator::OValueCompare(ValueCompare::GT)),
         (">=", Operator::OValueCompare(ValueCompare::GE)),
         ("!=", Operator::OValueCompare(ValueCompare::NE)),
+        ("@>", Operator::OValueCompare(ValueCompare::Contains)),
+        ("<@", Operator::OValueCompare(ValueCompare::ContainedBy)),
         ("AND", Operator::OLogicalConnective(LogicalConnective::AND)),
         ("-", Operator::OArithmetic(Arithmetic::MINUS)),
         ("+", Operator::OArithmetic(Arithmetic::PLUS)),
         ("*", Operator::OArithmetic(Arithmetic::MULTIPLE)),
         ("/", Operator::OArithmetic(Arithmetic::DIVIDE)),
+        ("->", Operator::OJson(JsonOperator::Arrow)),
+        ("->>", Operator::OJson(JsonOperator::ArrowText)),
     ];
     let map = HashMap::from(array);
     let opt_op = map.get(name.as_str());
@@ -90,6 +114,7 @@ impl Operator {
             Operator::OValueCompare(_) => None,
             Operator::OLogicalConnective(c) => Some(*c),
             &Operator::OArithmetic(_) => None,
+            Operator::OJson(_) => None,
         }
     }
 
@@ -116,6 +141,10 @@ impl ValueCompare {
             ValueCompare::GE => ValueCompare::LT,
             ValueCompare::GT => ValueCompare::LE,
             ValueCompare::NE => ValueCompare::NE,
+            ValueCompare::Contains => ValueCompare::ContainedBy,
+            ValueCompare::ContainedBy => ValueCompare::Contains,
+            ValueCompare::EqAny => ValueCompare::AnyEq,
+            ValueCompare::AnyEq => ValueCompare::EqAny,
         }
     }
 }
@@ -5,7 +5,9 @@
 #![allow(clippy::expect_used)]
 #![allow(clippy::panic)]
 
-use crate::ast::expr_operator::{Arithmetic, LogicalConnective, Operator, ValueCompare};
+use crate::ast::expr_operator::{
+    Arithmetic, JsonOperator, LogicalConnective, Operator, ValueCompare,
+};
 use mudu::error::ErrorCode;
 
 #[test]
@@ -60,6 +62,34 @@ fn from_name_parses_logical_and_arithmetic_operators() {
     ));
 }
 
+#[test]
+fn from_name_parses_json_operators() {
+    assert!(matches!(
+        Operator::from_name("->".to_string()).unwrap(),
+        Operator::OJson(JsonOperator::Arrow)
+    ));
+    assert!(matches!(
+        Operator::from_name("->>".to_string()).unwrap(),
+        Operator::OJson(JsonOperator::ArrowText)
+    ));
+    assert!(matches!(
+        Operator::from_name("@>".to_string()).unwrap(),
+        Operator::OValueCompare(ValueCompare::Contains)
+    ));
+    assert!(matches!(
+        Operator::from_name("<@".to_string()).unwrap(),
+        Operator::OValueCompare(ValueCompare::ContainedBy)
+    ));
+    assert!(matches!(
+        ValueCompare::revert_cmp_op(ValueCompare::Contains),
+        ValueCompare::ContainedBy
+    ));
+    assert!(Operator::from_name("->".to_string())
+        .unwrap()
+        .logical_connect()
+        .is_none());
+}
+
 #[test]
 fn from_name_rejects_unknown_operator() {
     let result = Operator::from_name("OR".to_string());
@@ -126,4 +156,12 @@ fn revert_cmp_op_reverses_direction() {
         ValueCompare::revert_cmp_op(ValueCompare::NE),
         ValueCompare::NE
     ));
+    assert!(matches!(
+        ValueCompare::revert_cmp_op(ValueCompare::EqAny),
+        ValueCompare::AnyEq
+    ));
+    assert!(matches!(
+        ValueCompare::revert_cmp_op(ValueCompare::AnyEq),
+        ValueCompare::EqAny
+    ));
 }
@@ -1,6 +1,7 @@
 use crate::ast::expr_arithmetic::ExprArithmetic;
 use crate::ast::expr_compare::ExprCompare;
 use crate::ast::expr_item::ExprItem;
+use crate::ast::expr_json::ExprJson;
 use crate::ast::expr_logical::ExprLogical;
 use std::sync::Arc;
 
@@ -15,4 +16,6 @@ pub enum ExprType {
     Value(Arc<ExprItem>),
     /// Arithmetic expression (`+`, `-`, `*`, `/`).
     Arithmetic(Arc<ExprArithmetic>),
+    /// JSON access expression (`->`, `->>`, `@>`).
+    Json(Arc<ExprJson>),
 }
@@ -4,6 +4,8 @@
 //! the tree-sitter parse tree, as well as the recursive-descent helpers used
 //! to convert tree-sitter nodes into typed AST nodes.
 
+/// Point in time of an `AS OF SYSTEM TIME` clause.
+pub mod as_of_time;
 /// Common trait implemented by all AST node types.
 pub mod ast_node;
 /// Comparison expression AST node (`=`, `<`, `>`, etc.).
@@ -12,6 +14,8 @@ pub mod expr_compare;
 mod expr_compare_test;
 /// Atomic expression items such as column names, literals, and placeholders.
 pub mod expr_item;
+/// JSON access expression AST node (`->`, `->>`, `@>`).
+pub mod expr_json;
 /// Literal expression AST node (`NULL`, typed datum literals).
 pub mod expr_literal;
 #[cfg(test)]
@@ -24,7 +28,7 @@ mod expr_logical_test;
 pub mod expr_name;
 #[cfg(test)]
 mod expr_name_test;
-/// SQL operators: comparison, logical, and arithmetic.
+/// SQL operators: comparison, logical, arithmetic, and JSON access.
 pub mod expr_operator;
 #[cfg(test)]
 mod expr_operator_test;
@@ -34,6 +38,8 @@ mod expr_visitor_test;
 /// Top-level expression enum aggregating all expression kinds.
 pub mod expression;
 
+/// `ORDER BY` term with direction and `NULLS` placement.
+pub mod order_term;
 /// SQL parser entry point and statement dispatch.
 pub mod parser;
 /// Select list term with optional alias.
@@ -58,6 +64,8 @@ mod parser_test;
 pub mod stmt_copy_from;
 /// `COPY ... TO` statement AST node.
 pub mod stmt_copy_to;
+/// `CREATE INDEX` statement AST node.
+pub mod stmt_create_index;
 /// `CREATE PARTITION PLACEMENT` statement AST node.
 pub mod stmt_create_partition_placement;
 /// `CREATE PARTITION RULE` statement AST node.
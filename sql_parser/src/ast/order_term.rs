@@ -0,0 +1,48 @@
+use crate::ast::expr_name::ExprName;
+
+/// A single term of an `ORDER BY` clause.
+#[derive(Clone, Debug)]
+pub struct OrderTerm {
+    field: ExprName,
+    descending: bool,
+    nulls_first: Option<bool>,
+}
+
+impl OrderTerm {
+    /// Create an ascending order term on `field` with the default `NULLS`
+    /// placement.
+    pub fn new(field: ExprName) -> Self {
+        Self {
+            field,
+            descending: false,
+            nulls_first: None,
+        }
+    }
+
+    /// Set whether the term sorts in descending order (`DESC`).
+    pub fn set_descending(&mut self, descending: bool) {
+        self.descending = descending;
+    }
+
+    /// Set an explicit `NULLS FIRST` (`true`) or `NULLS LAST` (`false`).
+    pub fn set_nulls_first(&mut self, nulls_first: Option<bool>) {
+        self.nulls_first = nulls_first;
+    }
+
+    /// Return the sorted field.
+    pub fn field(&self) -> &ExprName {
+        &self.field
+    }
+
+    /// Return whether the term sorts in descending order.
+    pub fn descending(&self) -> bool {
+        self.descending
+    }
+
+    /// Return whether NULLs sort first. Without an explicit `NULLS` clause,
+    /// NULLs sort as if larger than every value, as in PostgreSQL: last for
+    /// `ASC`, first for `DESC`.
+    pub fn nulls_first(&self) -> bool {
+        self.nulls_first.unwrap_or(self.descending)
+    }
+}
@@ -11,6 +11,7 @@ use mudu::error::ErrorCode;
 use mudu::mudu_error;
 use mudu_binding::universal::uni_dat_type::UniDatType;
 use mudu_binding::universal::uni_dat_value::UniDatValue;
+use mudu_binding::universal::uni_record_type::{UniRecordField, UniRecordType};
 use mudu_binding::universal::uni_scalar::UniScalar;
 use mudu_binding::universal::uni_scalar_value::UniScalarValue;
 use std::collections::HashMap;
@@ -36,7 +37,7 @@ impl SQLParser {
         let iter = node.children_by_field_name(ts_field_name::COLUMN_CONSTRAINT, &mut cursor);
         let mut index_map = HashMap::new();
         for n in iter {
-            self.visit_column_constraint(n, &mut column_def, &mut index_map)?;
+            self.visit_column_constraint(context, n, &mut column_def, &mut index_map)?;
         }
 
         stmt.add_column_def(column_def);
@@ -46,6 +47,7 @@ impl SQLParser {
 
     pub(crate) fn visit_column_constraint(
         &self,
+        context: &ParseContext,
         node: Node,
         column_def: &mut ColumnDef,
         index_map: &mut HashMap<String, AttrIndex>,
@@ -66,6 +68,19 @@ impl SQLParser {
         {
             column_def.set_nullable(false);
         }
+        if let Some(n) = node.child_by_field_name(ts_field_name::COLLATION) {
+            if column_def.collation().is_some() {
+                return Err(mudu_error!(
+                    ErrorCode::Parse,
+                    format!(
+                        "column {} has more than one COLLATE clause",
+                        column_def.column_name()
+                    )
+                ));
+            }
+            let collation = self.visit_identifier(context, n)?;
+            column_def.set_collation(Some(collation.trim_matches('"').to_string()));
+        }
         Ok(())
     }
 
@@ -76,7 +91,24 @@ impl SQLParser {
     ) -> RS<(UniDatType, Option<Vec<UniDatValue>>)> {
         let opt = node.child_by_field_name(ts_field_name::DATA_TYPE_KIND);
         let n = rs_option(opt, "")?;
-        self.visit_data_type_kind(context, n)
+        let (mut dat_type, params) = self.visit_data_type_kind(context, n)?;
+        let mut cursor = node.walk();
+        for child in node.children(&mut cursor) {
+            if child.kind_id() == ts_kind_id::ARRAY_SIZE_DEFINITION {
+                // `T ARRAY`, `T[]` and `T[n][m]` all declare arrays; like
+                // PostgreSQL, declared sizes are not enforced.
+                let mut bracket_cursor = child.walk();
+                let dimensions = child
+                    .children(&mut bracket_cursor)
+                    .filter(|c| c.kind() == "[")
+                    .count()
+                    .max(1);
+                for _ in 0..dimensions {
+                    dat_type = UniDatType::Array(Box::new(dat_type));
+                }
+            }
+        }
+        Ok((dat_type, params))
     }
 
     pub(crate) fn visit_data_type_kind(
@@ -84,10 +116,21 @@ impl SQLParser {
         context: &ParseContext,
         node: Node,
     ) -> RS<(UniDatType, Option<Vec<UniDatValue>>)> {
-        let opt_n = node.child(0);
-        let child = rs_option(opt_n, "no child in data type kind")?;
+        let Some(child) = node.child(0) else {
+            // A custom type name, which is a hidden identifier.
+            return Err(mudu_error!(
+                ErrorCode::NotImplemented,
+                format!(
+                    "Data type {} not yet implemented",
+                    ts_node_context_string(context.parse_str(), &node)?
+                )
+            ));
+        };
         let kind = child.kind_id();
         let ret = match kind {
+            ts_kind_id::KEYWORD_BOOLEAN => (UniDatType::Scalar(UniScalar::Bool), None),
+            ts_kind_id::TINYINT => (UniDatType::Scalar(UniScalar::I8), None),
+            ts_kind_id::SMALLINT => (UniDatType::Scalar(UniScalar::I16), None),
             ts_kind_id::INT => (UniDatType::Scalar(UniScalar::I32), None),
             ts_kind_id::BIGINT => (UniDatType::Scalar(UniScalar::I64), None),
             ts_kind_id::HUGEINT => (UniDatType::Scalar(UniScalar::I128), None),
@@ -106,6 +149,10 @@ impl SQLParser {
                 UniDatType::Scalar(UniScalar::Numeric),
                 self.visit_numeric_params(context, child)?,
             ),
+            ts_kind_id::KEYWORD_UUID => (UniDatType::Scalar(UniScalar::Uuid), None),
+            ts_kind_id::KEYWORD_JSON | ts_kind_id::KEYWORD_JSONB => {
+                (UniDatType::Scalar(UniScalar::Json), None)
+            }
             ts_kind_id::KEYWORD_DATE => (UniDatType::Scalar(UniScalar::Date), None),
             ts_kind_id::TIME => (
                 UniDatType::Scalar(UniScalar::Time),
@@ -131,6 +178,7 @@ impl SQLParser {
                 UniDatType::Scalar(UniScalar::TimestampTz),
                 self.visit_optional_precision_param(context, node)?,
             ),
+            ts_kind_id::RECORD_TYPE => (self.visit_record_type(context, child)?, None),
             _ => {
                 return Err(mudu_error!(
                     ErrorCode::NotImplemented,
@@ -142,6 +190,45 @@ impl SQLParser {
         Ok(ret)
     }
 
+    /// Parse a record column type, `ROW(name type, ...)`.
+    pub(crate) fn visit_record_type(&self, context: &ParseContext, node: Node) -> RS<UniDatType> {
+        let mut cursor = node.walk();
+        let mut record_fields = Vec::new();
+        for n in node.children_by_field_name(ts_field_name::RECORD_FIELD, &mut cursor) {
+            let opt = n.child_by_field_name(ts_field_name::NAME);
+            let field_name = self.visit_identifier(context, rs_option(opt, "")?)?;
+            if record_fields
+                .iter()
+                .any(|field: &UniRecordField| field.field_name == field_name)
+            {
+                return Err(mudu_error!(
+                    ErrorCode::Parse,
+                    format!("record field {} appears twice", field_name)
+                ));
+            }
+            let opt = n.child_by_field_name(ts_field_name::DATA_TYPE);
+            let (field_type, params) = self.visit_data_type(context, rs_option(opt, "")?)?;
+            // Record field types carry no type parameters.
+            if params.is_some() {
+                return Err(mudu_error!(
+                    ErrorCode::NotImplemented,
+                    format!(
+                        "type parameters of record field {} are not implemented",
+                        field_name
+                    )
+                ));
+            }
+            record_fields.push(UniRecordField {
+                field_name,
+                field_type,
+            });
+        }
+        Ok(UniDatType::Record(UniRecordType {
+            record_name: "row".to_string(),
+            record_fields,
+        }))
+    }
+
     pub(crate) fn visit_char_param(&self, context: &ParseContext, node: Node) -> RS<UniDatValue> {
         if let Some(n) = node.child_by_field_name(ts_field_name::LENGTH) {
             let s = ts_node_context_string(context.parse_str(), &n)?;
@@ -1,10 +1,14 @@
-//! DDL (CREATE/DROP TABLE) statement parser.
+//! DDL (CREATE/DROP TABLE, CREATE INDEX) statement parser.
 
 use super::context::ParseContext;
+use super::error::node_or_descendant_has_kind;
 use super::SQLParser;
+use crate::ast::expr_name::ExprName;
+use crate::ast::expression::ExprType;
+use crate::ast::stmt_create_index::StmtCreateIndex;
 use crate::ast::stmt_create_table::StmtCreateTable;
 use crate::ast::stmt_drop_table::StmtDropTable;
-use crate::ts_const::{ts_field_name, ts_kind_id};
+use crate::ts_const::{ts_field_name, ts_kind_id, ts_kind_name};
 use mudu::common::result::RS;
 use mudu::common::result_of::rs_option;
 use mudu::error::ErrorCode;
@@ -48,6 +52,76 @@ impl SQLParser {
         Ok(StmtDropTable::new(object, if_exist))
     }
 
+    /// Parse `CREATE INDEX [name] ON table (column, ...)`. Unique and
+    /// partial indexes, and descending columns, are not supported.
+    pub(crate) fn visit_create_index(
+        &self,
+        context: &ParseContext,
+        node: Node,
+    ) -> RS<StmtCreateIndex> {
+        if node.child_by_field_name(ts_field_name::UNIQUE).is_some() {
+            return Err(mudu_error!(
+                ErrorCode::NotImplemented,
+                "unique indexes are not implemented"
+            ));
+        }
+        if node.child_by_field_name(ts_field_name::WHERE).is_some() {
+            return Err(mudu_error!(
+                ErrorCode::NotImplemented,
+                "partial indexes are not implemented"
+            ));
+        }
+        let index_name = node
+            .child_by_field_name(ts_field_name::INDEX_NAME)
+            .map(|n| self.visit_identifier(context, n))
+            .transpose()?;
+        let opt = node.child_by_field_name(ts_field_name::OBJECT_REFERENCE);
+        let n_table = rs_option(opt, "no table in create index statement")?;
+        let table_name = self.visit_object_reference(context, n_table)?;
+        let opt = node.child_by_field_name(ts_field_name::COLUMNS);
+        let n_columns = rs_option(opt, "no columns in create index statement")?;
+        let mut columns = Vec::new();
+        let mut cursor = n_columns.walk();
+        for n in n_columns.children(&mut cursor) {
+            if n.kind_id() != ts_kind_id::COLUMN {
+                continue;
+            }
+            if node_or_descendant_has_kind(n, ts_kind_name::S_KEYWORD_DESC) {
+                return Err(mudu_error!(
+                    ErrorCode::NotImplemented,
+                    "descending index columns are not implemented"
+                ));
+            }
+            columns.push(self.visit_index_column(context, n)?);
+        }
+        Ok(StmtCreateIndex::new(index_name, table_name, columns))
+    }
+
+    /// Parse an index column: a column name, `json_extract(...)` or a
+    /// parenthesized JSON path such as `(attrs ->> 'name')`.
+    fn visit_index_column(&self, context: &ParseContext, node: Node) -> RS<ExprName> {
+        if let Some(n) = node.child_by_field_name(ts_field_name::NAME) {
+            let mut column = ExprName::new();
+            column.set_name(self.visit_identifier(context, n)?);
+            return Ok(column);
+        }
+        if let Some(n) = node.child_by_field_name(ts_field_name::INVOCATION) {
+            return self.visit_invocation(context, n);
+        }
+        let opt = node.child_by_field_name(ts_field_name::EXPRESSION);
+        let n_expression = rs_option(opt, "no column in index column")?;
+        let column = match self.visit_expression(context, n_expression)? {
+            ExprType::Value(item) => item.to_field().cloned(),
+            _ => None,
+        };
+        column.ok_or_else(|| {
+            mudu_error!(
+                ErrorCode::NotImplemented,
+                "index expressions other than json paths are not implemented"
+            )
+        })
+    }
+
     pub(crate) fn visit_create_table_statement(
         &self,
         context: &ParseContext,
@@ -47,6 +47,10 @@ impl SQLParser {
                 let stmt = self.visit_create_table_statement(context, child)?;
                 Ok(StmtType::Command(StmtCommand::CreateTable(stmt)))
             }
+            ts_kind_id::CREATE_INDEX => {
+                let stmt = self.visit_create_index(context, child)?;
+                Ok(StmtType::Command(StmtCommand::CreateIndex(stmt)))
+            }
             ts_kind_id::DROP_STATEMENT => {
                 let stmt = self.visit_drop_statement(context, child)?;
                 Ok(StmtType::Command(StmtCommand::DropTable(stmt)))
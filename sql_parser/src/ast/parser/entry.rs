@@ -4,6 +4,7 @@ use super::context::ParseContext;
 use super::partition::{
     parse_partition_placement_item, parse_range_partition_def, parse_table_partition_suffix,
 };
+use super::table_option::{find_table_options_position, parse_table_options_clause};
 use super::utils::{
     contains_ignore_ascii_case, find_keyword_position, find_matching_paren, split_top_level_csv,
     starts_with_ignore_ascii_case,
@@ -61,9 +62,11 @@ impl SQLParser {
         }
 
         if starts_with_ignore_ascii_case(normalized, "create table ")
-            && contains_ignore_ascii_case(normalized, " partition by global rule ")
+            && (contains_ignore_ascii_case(normalized, " partition by global rule ")
+                || create_table_suffix(normalized)
+                    .is_some_and(|suffix| find_table_options_position(suffix).is_some()))
         {
-            let stmt = self.parse_create_table_partitioned_custom(normalized)?;
+            let stmt = self.parse_create_table_custom(normalized)?;
             return Ok(Some(StmtList::new(vec![StmtType::Command(
                 StmtCommand::CreateTable(stmt),
             )])));
@@ -72,21 +75,20 @@ impl SQLParser {
         Ok(None)
     }
 
-    /// Parse a `CREATE TABLE ... PARTITION BY GLOBAL RULE ...` statement.
-    pub(crate) fn parse_create_table_partitioned_custom(&self, sql: &str) -> RS<StmtCreateTable> {
-        let close_index = find_matching_paren(
-            sql,
-            sql.find('(').ok_or_else(|| {
-                mudu_error!(
-                    ErrorCode::Parse,
-                    "partitioned create table has no column list"
-                )
-            })?,
-        )?;
-        let base_sql = sql[..=close_index].trim();
+    /// Parse a `CREATE TABLE` statement whose column list is followed by
+    /// `PARTITION BY GLOBAL RULE ...` and/or `WITH (...)` table options.
+    pub(crate) fn parse_create_table_custom(&self, sql: &str) -> RS<StmtCreateTable> {
+        let open_index = sql.find('(').ok_or_else(|| {
+            mudu_error!(
+                ErrorCode::Parse,
+                "partitioned create table has no column list"
+            )
+        })?;
+        let close_index = find_matching_paren(sql, open_index)?;
+        let base_sql = &sql[..=close_index];
         let suffix = sql[close_index + 1..].trim();
 
-        let mut stmt = match self.parse_standard(base_sql)?.stmts().first() {
+        let mut stmt = match self.parse_standard(base_sql.trim())?.stmts().first() {
             Some(StmtType::Command(StmtCommand::CreateTable(stmt))) => stmt.clone(),
             _ => {
                 return Err(mudu_error!(
@@ -95,8 +97,18 @@ impl SQLParser {
                 ));
             }
         };
-        let partition = parse_table_partition_suffix(suffix)?;
-        stmt.set_partition(partition);
+        let (partition_clause, options_clause) = match find_table_options_position(suffix) {
+            Some(position) => (suffix[..position].trim(), Some(&suffix[position..])),
+            None => (suffix, None),
+        };
+        if !partition_clause.is_empty() {
+            stmt.set_partition(parse_table_partition_suffix(partition_clause)?);
+        }
+        if let Some(clause) = options_clause {
+            for (name, value) in parse_table_options_clause(clause)? {
+                stmt.add_table_option(name, value);
+            }
+        }
         Ok(stmt)
     }
 
@@ -265,6 +277,12 @@ impl SQLParser {
     }
 }
 
+/// Returns the text after the column list of a `CREATE TABLE` statement.
+fn create_table_suffix(sql: &str) -> Option<&str> {
+    let close_index = find_matching_paren(sql, sql.find('(')?).ok()?;
+    Some(sql[close_index + 1..].trim())
+}
+
 #[cfg(all(test, not(miri)))]
 #[path = "entry_test.rs"]
 mod entry_test;
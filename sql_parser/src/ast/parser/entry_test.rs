@@ -1,9 +1,14 @@
 #![allow(clippy::unwrap_used)]
 #![allow(clippy::panic)]
 
+use crate::ast::as_of_time::AsOfTime;
+use crate::ast::expr_item::ExprValue;
+use crate::ast::expr_literal::ExprLiteral;
+use crate::ast::expr_operator::ValueCompare;
 use crate::ast::parser::SQLParser;
 use crate::ast::stmt_type::{StmtCommand, StmtType};
 use mudu::error::ErrorCode;
+use mudu_type::json_path::JsonPathStep;
 
 fn parse(sql: &str) -> crate::ast::stmt_list::StmtList {
     SQLParser::new().unwrap().parse(sql).unwrap()
@@ -96,6 +101,375 @@ fn create_table_partitioned_success_and_errors() {
     assert_eq!(bad.unwrap_err().ec(), ErrorCode::Parse);
 }
 
+#[test]
+#[cfg_attr(miri, ignore)]
+fn create_table_with_options() {
+    let sql = "create table t (id int primary key) WITH (Compression = 'lz4');";
+    let stmt = parse(sql).stmts().first().unwrap().clone();
+    let StmtType::Command(StmtCommand::CreateTable(table)) = stmt else {
+        panic!("expected create table");
+    };
+    assert!(table.partition().is_none());
+    assert_eq!(
+        table.table_options(),
+        &vec![("compression".to_string(), "lz4".to_string())]
+    );
+
+    let sql = "create table t (id int) partition by global rule sales references (id) \
+               with (compression = zstd);";
+    let stmt = parse(sql).stmts().first().unwrap().clone();
+    let StmtType::Command(StmtCommand::CreateTable(table)) = stmt else {
+        panic!("expected create table");
+    };
+    assert_eq!(table.partition().unwrap().rule_name(), "sales");
+    assert_eq!(table.table_options()[0].1, "zstd");
+
+    for bad in [
+        "create table t (id int) with (compression);",
+        "create table t (id int) with ();",
+        "create table t (id int) with (compression = lz4, compression = zstd);",
+        "create table t (id int) with (compression = lz4) extra;",
+    ] {
+        let err = SQLParser::new().unwrap().parse(bad).unwrap_err();
+        assert_eq!(err.ec(), ErrorCode::Parse, "{}", bad);
+    }
+}
+
+#[test]
+#[cfg_attr(miri, ignore)]
+fn create_table_column_collations_success_and_errors() {
+    let sql = "CREATE TABLE users (\
+        id INT PRIMARY KEY, \
+        email VARCHAR(128) COLLATE ascii_ci NOT NULL, \
+        name TEXT COLLATE \"unicode_root\", \
+        note TEXT DEFAULT 'collate me') with (compression = lz4);";
+    let stmt = parse(sql).stmts().first().unwrap().clone();
+    let StmtType::Command(StmtCommand::CreateTable(table)) = stmt else {
+        panic!("expected create table");
+    };
+    let columns = table.column_def();
+    assert_eq!(columns.len(), 4);
+    assert_eq!(columns[0].collation(), None);
+    assert_eq!(columns[1].column_name(), "email");
+    assert_eq!(columns[1].collation(), Some("ascii_ci"));
+    assert!(!columns[1].nullable());
+    assert_eq!(columns[2].collation(), Some("unicode_root"));
+    assert_eq!(columns[3].collation(), None);
+    assert_eq!(table.table_options()[0].1, "lz4");
+
+    for (bad, ec) in [
+        (
+            "create table t (id int, name text collate);",
+            ErrorCode::MlParse,
+        ),
+        (
+            "create table t (id int, name text collate nocase collate binary);",
+            ErrorCode::Parse,
+        ),
+        // Parsed as a column named `collate` of a custom type `nocase`.
+        (
+            "create table t (id int, collate nocase);",
+            ErrorCode::NotImplemented,
+        ),
+    ] {
+        let err = SQLParser::new().unwrap().parse(bad).unwrap_err();
+        assert_eq!(err.ec(), ec, "{}", bad);
+    }
+}
+
+#[test]
+#[cfg_attr(miri, ignore)]
+fn select_as_of_system_time_success_and_errors() {
+    let sql = "select v from t as of system time 42 where k = 1;";
+    let stmt = parse(sql).stmts().first().unwrap().clone();
+    let StmtType::Select(select) = stmt else {
+        panic!("expected select");
+    };
+    assert_eq!(select.get_as_of(), Some(AsOfTime::CommitTs(42)));
+    assert_eq!(select.get_table_reference(), "t");
+    assert_eq!(select.get_where_predicate().len(), 1);
+
+    let stmt = parse("SELECT v FROM t AS OF SYSTEM TIME 7")
+        .stmts()
+        .first()
+        .unwrap()
+        .clone();
+    let StmtType::Select(select) = stmt else {
+        panic!("expected select");
+    };
+    assert_eq!(select.get_as_of(), Some(AsOfTime::CommitTs(7)));
+
+    let stmt = parse("select v from t as of system time '2026-05-20 14:30:45.5+08:00'")
+        .stmts()
+        .first()
+        .unwrap()
+        .clone();
+    let StmtType::Select(select) = stmt else {
+        panic!("expected select");
+    };
+    assert_eq!(
+        select.get_as_of(),
+        Some(AsOfTime::WallClock(1_779_258_645_500_000))
+    );
+
+    let bad = SQLParser::new()
+        .unwrap()
+        .parse("select v from t as of system time 'yesterday';");
+    assert_eq!(bad.unwrap_err().ec(), ErrorCode::Parse);
+    let bad = SQLParser::new()
+        .unwrap()
+        .parse("select v from t as of system time -3;");
+    assert_eq!(bad.unwrap_err().ec(), ErrorCode::Parse);
+
+    // The clause is part of the grammar, so text that only looks like it
+    // inside a string literal is not taken for one.
+    let stmt = parse("select v from t where k = ' as of system time 9 '")
+        .stmts()
+        .first()
+        .unwrap()
+        .clone();
+    let StmtType::Select(select) = stmt else {
+        panic!("expected select");
+    };
+    assert_eq!(select.get_as_of(), None);
+    assert_eq!(select.get_where_predicate().len(), 1);
+
+    let bad = SQLParser::new()
+        .unwrap()
+        .parse("select v from t as of system time yesterday;");
+    assert_eq!(bad.unwrap_err().ec(), ErrorCode::MlParse);
+}
+
+#[test]
+#[cfg_attr(miri, ignore)]
+fn select_order_by_success_and_errors() {
+    let sql = "select a, b from t where a = 1 order by b desc, a nulls first, c asc;";
+    let stmt = parse(sql).stmts().first().unwrap().clone();
+    let StmtType::Select(select) = stmt else {
+        panic!("expected select");
+    };
+    let order_by = select.get_order_by();
+    assert_eq!(order_by.len(), 3);
+    assert_eq!(order_by[0].field().name(), "b");
+    assert!(order_by[0].descending());
+    assert!(order_by[0].nulls_first());
+    assert_eq!(order_by[1].field().name(), "a");
+    assert!(!order_by[1].descending());
+    assert!(order_by[1].nulls_first());
+    assert_eq!(order_by[2].field().name(), "c");
+    assert!(!order_by[2].nulls_first());
+    assert_eq!(select.get_where_predicate().len(), 1);
+
+    let bad = SQLParser::new()
+        .unwrap()
+        .parse("select a from t order by a using <;");
+    assert_eq!(bad.unwrap_err().ec(), ErrorCode::NotImplemented);
+    let bad = SQLParser::new()
+        .unwrap()
+        .parse("select a from t order by 1;");
+    assert_eq!(bad.unwrap_err().ec(), ErrorCode::NotImplemented);
+}
+
+#[test]
+#[cfg_attr(miri, ignore)]
+fn create_index_success_and_errors() {
+    let stmt = parse("create index people_label on people (label, score asc);")
+        .stmts()
+        .first()
+        .unwrap()
+        .clone();
+    let StmtType::Command(StmtCommand::CreateIndex(index)) = stmt else {
+        panic!("expected create index");
+    };
+    assert_eq!(index.index_name(), Some("people_label"));
+    assert_eq!(index.table_name(), "people");
+    assert_eq!(
+        index
+            .columns()
+            .iter()
+            .map(|column| column.name().as_str())
+            .collect::<Vec<_>>(),
+        vec!["label", "score"]
+    );
+    assert!(index.columns().iter().all(|c| c.json_path().is_none()));
+
+    let stmt =
+        parse("create index on docs ((attrs ->> 'name'), json_extract(attrs, '$.tags[0]'), id);")
+            .stmts()
+            .first()
+            .unwrap()
+            .clone();
+    let StmtType::Command(StmtCommand::CreateIndex(index)) = stmt else {
+        panic!("expected create index");
+    };
+    let columns = index.columns();
+    assert_eq!(columns.len(), 3);
+    let name = columns[0].json_path().unwrap();
+    assert_eq!(
+        name.path().steps(),
+        &[JsonPathStep::Key("name".to_string())]
+    );
+    assert!(name.as_text());
+    let tag = columns[1].json_path().unwrap();
+    assert_eq!(
+        tag.path().steps(),
+        &[
+            JsonPathStep::Key("tags".to_string()),
+            JsonPathStep::Index(0)
+        ]
+    );
+    assert!(!tag.as_text());
+    assert!(columns[2].json_path().is_none());
+
+    let stmt = parse("CREATE INDEX ON people (label)")
+        .stmts()
+        .first()
+        .unwrap()
+        .clone();
+    let StmtType::Command(StmtCommand::CreateIndex(index)) = stmt else {
+        panic!("expected create index");
+    };
+    assert_eq!(index.index_name(), None);
+
+    for bad in [
+        "create unique index on people (label);",
+        "create index on people (label) where score = 1;",
+        "create index on people (label desc);",
+        "create index on people ((score + 1));",
+    ] {
+        let err = SQLParser::new().unwrap().parse(bad).unwrap_err();
+        assert_eq!(err.ec(), ErrorCode::NotImplemented, "{}", bad);
+    }
+}
+
+#[test]
+#[cfg_attr(miri, ignore)]
+fn json_accesses_fold_into_column_paths() {
+    let stmt = parse(
+        "select attrs -> 'tags' ->> 0, json_extract(attrs, '$.\"a b\".c') from docs \
+         where attrs ->> 'name' = 'bob' AND attrs @> '{\"x\":1}';",
+    )
+    .stmts()
+    .first()
+    .unwrap()
+    .clone();
+    let StmtType::Select(select) = stmt else {
+        panic!("expected select");
+    };
+    let terms = select.get_select_term_list();
+    assert_eq!(terms.len(), 2);
+    let tag = terms[0].field().json_path().unwrap();
+    assert_eq!(terms[0].field().name(), "attrs");
+    assert_eq!(
+        tag.path().steps(),
+        &[
+            JsonPathStep::Key("tags".to_string()),
+            JsonPathStep::Index(0)
+        ]
+    );
+    assert!(tag.as_text());
+    let extracted = terms[1].field().json_path().unwrap();
+    assert_eq!(
+        extracted.path().steps(),
+        &[
+            JsonPathStep::Key("a b".to_string()),
+            JsonPathStep::Key("c".to_string())
+        ]
+    );
+    assert!(!extracted.as_text());
+
+    let predicates = select.get_where_predicate();
+    assert_eq!(predicates.len(), 2);
+    assert!(matches!(predicates[0].op(), ValueCompare::EQ));
+    let name = predicates[0].left().to_field().unwrap();
+    assert_eq!(
+        name.json_path().unwrap().path().steps(),
+        &[JsonPathStep::Key("name".to_string())]
+    );
+    assert!(matches!(predicates[1].op(), ValueCompare::Contains));
+    assert!(predicates[1]
+        .left()
+        .to_field()
+        .unwrap()
+        .json_path()
+        .is_none());
+
+    for (bad, ec) in [
+        (
+            "select attrs ->> 'a' -> 'b' from docs;",
+            ErrorCode::InvalidType,
+        ),
+        (
+            "select json_extract(attrs, 'a') from docs;",
+            ErrorCode::Parse,
+        ),
+        ("select json_extract(attrs) from docs;", ErrorCode::Parse),
+        ("select upper(name) from docs;", ErrorCode::NotImplemented),
+    ] {
+        let err = SQLParser::new().unwrap().parse(bad).unwrap_err();
+        assert_eq!(err.ec(), ec, "{}", bad);
+    }
+}
+
+#[test]
+#[cfg_attr(miri, ignore)]
+fn arrays_and_rows_parse_into_literals_elements_and_any() {
+    let stmt = parse("insert into t (id, tags, pos) values (1, ARRAY[1, 2], ROW(3, 'a'));")
+        .stmts()
+        .first()
+        .unwrap()
+        .clone();
+    let StmtType::Command(StmtCommand::Insert(insert)) = stmt else {
+        panic!("expected insert");
+    };
+    let row = &insert.values_list()[0];
+    let ExprValue::ValueLiteral(ExprLiteral::Array(elements)) = &row[1] else {
+        panic!("expected an array literal, got {:?}", row[1]);
+    };
+    assert_eq!(elements.len(), 2);
+    let ExprValue::ValueLiteral(ExprLiteral::Row(fields)) = &row[2] else {
+        panic!("expected a row literal, got {:?}", row[2]);
+    };
+    assert_eq!(fields.len(), 2);
+
+    let stmt = parse("select tags[2], unnest(tags) from t where 3 = ANY(tags) AND tags[1] = 5;")
+        .stmts()
+        .first()
+        .unwrap()
+        .clone();
+    let StmtType::Select(select) = stmt else {
+        panic!("expected select");
+    };
+    let terms = select.get_select_term_list();
+    assert_eq!(terms[0].field().element(), Some(2));
+    assert!(!terms[0].unnest());
+    assert_eq!(terms[1].field().name(), "tags");
+    assert!(terms[1].unnest());
+    let predicates = select.get_where_predicate();
+    assert!(matches!(predicates[0].op(), ValueCompare::EqAny));
+    assert_eq!(predicates[0].right().to_field().unwrap().name(), "tags");
+    assert!(matches!(predicates[1].op(), ValueCompare::EQ));
+    assert_eq!(predicates[1].left().to_field().unwrap().element(), Some(1));
+
+    for (bad, ec) in [
+        ("select tags[id] from t;", ErrorCode::NotImplemented),
+        ("select tags[1][2] from t;", ErrorCode::NotImplemented),
+        ("select tags[1] -> 'a' from t;", ErrorCode::NotImplemented),
+        (
+            "select id from t where 3 < ANY(tags);",
+            ErrorCode::NotImplemented,
+        ),
+        ("select id from t where 3 = ANY(tags, 1);", ErrorCode::Parse),
+        (
+            "insert into t (id, tags) values (1, ARRAY[id]);",
+            ErrorCode::NotImplemented,
+        ),
+    ] {
+        let err = SQLParser::new().unwrap().parse(bad).unwrap_err();
+        assert_eq!(err.ec(), ec, "{}", bad);
+    }
+}
+
 #[test]
 #[cfg_attr(miri, ignore)]
 fn invalid_standard_sql_returns_parse_error() {
@@ -2,22 +2,26 @@
 
 use super::context::ParseContext;
 use super::error::ts_node_context_string;
+use super::utils::string_literal_text;
 use super::SQLParser;
 use crate::ast::expr_arithmetic::ExprArithmetic;
 use crate::ast::expr_compare::ExprCompare;
 use crate::ast::expr_item::{ExprItem, ExprValue};
+use crate::ast::expr_json::{ExprJson, ExprJsonPath};
 use crate::ast::expr_literal::ExprLiteral;
 use crate::ast::expr_logical::ExprLogical;
 use crate::ast::expr_name::ExprName;
-use crate::ast::expr_operator::Operator;
+use crate::ast::expr_operator::{JsonOperator, Operator, ValueCompare};
 use crate::ast::expression::ExprType;
-use crate::ts_const::{ts_field_name, ts_kind_name};
+use crate::ts_const::{ts_field_name, ts_kind_id, ts_kind_name};
 use mudu::common::result::RS;
 use mudu::common::result_of::rs_option;
 use mudu::data_type::numeric::Numeric;
 use mudu::error::ErrorCode;
 use mudu::mudu_error;
+use mudu_type::dat_type_id::DatTypeID;
 use mudu_type::dat_typed::DatTyped;
+use mudu_type::json_path::{JsonPath, JsonPathStep};
 use std::str::FromStr;
 use std::sync::Arc;
 use tree_sitter::Node;
@@ -44,6 +48,50 @@ impl SQLParser {
             return Ok(ExprType::Value(Arc::new(ExprItem::ItemName(field))));
         }
 
+        let opt_invocation = node.child_by_field_name(ts_field_name::INVOCATION);
+        if let Some(n) = opt_invocation {
+            let field = self.visit_invocation(context, n)?;
+            return Ok(ExprType::Value(Arc::new(ExprItem::ItemName(field))));
+        }
+
+        let opt_array = node.child_by_field_name(ts_field_name::ARRAY);
+        if let Some(n) = opt_array {
+            let mut cursor = n.walk();
+            let children = n.children(&mut cursor).collect::<Vec<_>>();
+            if children.iter().any(|c| c.kind() == "(") {
+                return Err(mudu_error!(
+                    ErrorCode::NotImplemented,
+                    "ARRAY(subquery) is not implemented"
+                ));
+            }
+            let elements = children
+                .into_iter()
+                .filter(|c| c.kind_id() == ts_kind_id::EXPRESSION)
+                .collect::<Vec<_>>();
+            let literal = ExprLiteral::Array(self.visit_constructor_elements(context, elements)?);
+            return Ok(ExprType::Value(Arc::new(ExprItem::ItemValue(
+                ExprValue::ValueLiteral(literal),
+            ))));
+        }
+
+        let opt_row = node.child_by_field_name(ts_field_name::ROW);
+        if let Some(n) = opt_row {
+            let mut cursor = n.walk();
+            let fields = n
+                .children_by_field_name(ts_field_name::EXPRESSION, &mut cursor)
+                .collect::<Vec<_>>();
+            let literal = ExprLiteral::Row(self.visit_constructor_elements(context, fields)?);
+            return Ok(ExprType::Value(Arc::new(ExprItem::ItemValue(
+                ExprValue::ValueLiteral(literal),
+            ))));
+        }
+
+        let opt_subscript = node.child_by_field_name(ts_field_name::SUBSCRIPT);
+        if let Some(n) = opt_subscript {
+            let field = self.visit_subscript(context, n)?;
+            return Ok(ExprType::Value(Arc::new(ExprItem::ItemName(field))));
+        }
+
         let opt_expression = node.child_by_field_name(ts_field_name::EXPRESSION_IN_PARENTHESIS);
         if let Some(n) = opt_expression {
             return self.visit_expression(context, n);
@@ -88,15 +136,9 @@ impl SQLParser {
             let s = self.visit_string(context, n)?;
             DatTyped::from_string(s)
         } else if let Some(_n) = node.child_by_field_name("keyword_true") {
-            return Err(mudu_error!(
-                ErrorCode::NotImplemented,
-                "boolean literal true"
-            ));
+            DatTyped::from_bool(true)
         } else if let Some(_n) = node.child_by_field_name("keyword_false") {
-            return Err(mudu_error!(
-                ErrorCode::NotImplemented,
-                "boolean literal false"
-            ));
+            DatTyped::from_bool(false)
         } else {
             return Err(mudu_error!(
                 ErrorCode::Parse,
@@ -109,6 +151,73 @@ impl SQLParser {
         Ok(ExprLiteral::DatumLiteral(typed))
     }
 
+    /// Parse the elements of an `ARRAY[...]` or the fields of a `ROW(...)`,
+    /// which must be literals.
+    fn visit_constructor_elements(
+        &self,
+        context: &ParseContext,
+        nodes: Vec<Node>,
+    ) -> RS<Vec<ExprLiteral>> {
+        let mut literals = Vec::with_capacity(nodes.len());
+        for n in nodes {
+            let literal = match self.visit_expression(context, n)? {
+                ExprType::Value(item) => item.to_literal().cloned(),
+                _ => None,
+            };
+            let literal = literal.ok_or_else(|| {
+                mudu_error!(
+                    ErrorCode::NotImplemented,
+                    format!(
+                        "array and row elements other than literals are not implemented: {}",
+                        ts_node_context_string(context.parse_str(), &n).unwrap_or_default()
+                    )
+                )
+            })?;
+            literals.push(literal);
+        }
+        Ok(literals)
+    }
+
+    /// Parse `column[index]` into the element of the column; the index must
+    /// be an integer literal.
+    fn visit_subscript(&self, context: &ParseContext, node: Node) -> RS<ExprName> {
+        let opt = node.child_by_field_name(ts_field_name::EXPRESSION);
+        let n_array = rs_option(opt, "no array in subscript")?;
+        let opt = node.child_by_field_name(ts_field_name::INDEX);
+        let n_index = rs_option(opt, "no index in subscript")?;
+        let field = match self.visit_expression(context, n_array)? {
+            ExprType::Value(item) => item.to_field().cloned(),
+            _ => None,
+        };
+        let mut field = field
+            .filter(|field| field.json_path().is_none() && field.element().is_none())
+            .ok_or_else(|| {
+                mudu_error!(
+                    ErrorCode::NotImplemented,
+                    "subscripts of anything but an array column are not implemented"
+                )
+            })?;
+        let index = match self.visit_expression(context, n_index)? {
+            ExprType::Value(item) => match item.to_literal() {
+                Some(ExprLiteral::DatumLiteral(typed))
+                    if typed.dat_type().dat_type_id() == DatTypeID::I64 =>
+                {
+                    Some(typed.dat_internal().to_i64())
+                }
+                _ => None,
+            },
+            _ => None,
+        };
+        let index = index.ok_or_else(|| {
+            mudu_error!(
+                ErrorCode::NotImplemented,
+                "array subscripts other than integer literals are not implemented"
+            )
+        })?;
+        field.set_element(index);
+        Ok(field)
+    }
+
     /// Parse a qualified field reference into an [`ExprName`].
     pub(crate) fn visit_qualified_field(&self, context: &ParseContext, node: Node) -> RS<ExprName> {
         let opt = node.child_by_field_name(ts_field_name::IDENTIFIER_NAME);
@@ -133,6 +242,21 @@ impl SQLParser {
         let opt_right = node.child_by_field_name(ts_field_name::RIGHT);
         let right = rs_option(opt_right, "no right in binary expression")?;
         let expr_left = self.visit_expression(context, left)?;
+        if let Some(array) = self.visit_any(context, right)? {
+            let (Operator::OValueCompare(ValueCompare::EQ), ExprType::Value(value)) =
+                (op, expr_left)
+            else {
+                return Err(mudu_error!(
+                    ErrorCode::NotImplemented,
+                    "only value = ANY(array) is implemented"
+                ));
+            };
+            return Ok(ExprType::Compare(Arc::new(ExprCompare::new(
+                ValueCompare::EqAny,
+                (*value).clone(),
+                ExprItem::ItemName(array),
+            ))));
+        }
         let expr_right = self.visit_expression(context, right)?;
         let expr: ExprType = match op {
             Operator::OValueCompare(c) => {
@@ -148,11 +272,199 @@ impl SQLParser {
             Operator::OArithmetic(c) => {
                 ExprType::Arithmetic(Arc::new(ExprArithmetic::new(c, expr_left, expr_right)))
             }
+            Operator::OJson(c) => match Self::json_path_field(&expr_left, &expr_right, c)? {
+                Some(field) => ExprType::Value(Arc::new(ExprItem::ItemName(field))),
+                None => ExprType::Json(Arc::new(ExprJson::new(c, expr_left, expr_right))),
+            },
         };
 
         Ok(expr)
     }
 
+    /// Folds `column -> key` or `column ->> index` into the JSON path of the
+    /// column. Returns `None` if the document is not a column.
+    fn json_path_field(
+        left: &ExprType,
+        right: &ExprType,
+        op: JsonOperator,
+    ) -> RS<Option<ExprName>> {
+        let ExprType::Value(item) = left else {
+            return Ok(None);
+        };
+        let ExprItem::ItemName(field) = item.as_ref() else {
+            return Ok(None);
+        };
+        let step = match right {
+            ExprType::Value(item) => match item.to_literal() {
+                Some(ExprLiteral::DatumLiteral(typed)) => match typed.dat_type().dat_type_id() {
+                    DatTypeID::String => JsonPathStep::Key(
+                        string_literal_text(typed.dat_internal().expect_string()).to_string(),
+                    ),
+                    DatTypeID::I64 => JsonPathStep::Index(typed.dat_internal().to_i64()),
+                    _ => {
+                        return Err(mudu_error!(
+                            ErrorCode::Parse,
+                            "json key must be a string or an integer literal"
+                        ))
+                    }
+                },
+                _ => {
+                    return Err(mudu_error!(
+                        ErrorCode::NotImplemented,
+                        "json keys other than literals are not implemented"
+                    ))
+                }
+            },
+            _ => {
+                return Err(mudu_error!(
+                    ErrorCode::NotImplemented,
+                    "json keys other than literals are not implemented"
+                ))
+            }
+        };
+        Ok(Some(Self::with_json_steps(
+            field.clone(),
+            vec![step],
+            matches!(op, JsonOperator::ArrowText),
+        )?))
+    }
+
+    /// Appends `steps` to the JSON path of `field`.
+    fn with_json_steps(
+        mut field: ExprName,
+        mut steps: Vec<JsonPathStep>,
+        as_text: bool,
+    ) -> RS<ExprName> {
+        if field.element().is_some() {
+            return Err(mudu_error!(
+                ErrorCode::NotImplemented,
+                "json paths into array elements are not implemented"
+            ));
+        }
+        if let Some(json_path) = field.json_path() {
+            if json_path.as_text() {
+                return Err(mudu_error!(
+                    ErrorCode::InvalidType,
+                    format!("{} is text, not json", field.name())
+                ));
+            }
+            let mut path = json_path.path().steps().to_vec();
+            path.append(&mut steps);
+            steps = path;
+        }
+        field.set_json_path(ExprJsonPath::new(JsonPath::new(steps), as_text));
+        Ok(field)
+    }
+
+    /// Parse a function call. Only `json_extract(column, path)` is
+    /// supported; it is folded into the JSON path of the column.
+    pub(crate) fn visit_invocation(&self, context: &ParseContext, node: Node) -> RS<ExprName> {
+        let name = self.visit_invocation_name(context, node)?;
+        if !name.eq_ignore_ascii_case("json_extract") {
+            return Err(mudu_error!(
+                ErrorCode::NotImplemented,
+                format!("function {} is not implemented", name)
+            ));
+        }
+        let arguments = self.visit_invocation_arguments(context, node)?;
+        let [document, path] = arguments.as_slice() else {
+            return Err(mudu_error!(
+                ErrorCode::Parse,
+                "json_extract takes a document and a path"
+            ));
+        };
+        let field = Self::column_argument(document, "json_extract")?;
+        let path = match path {
+            ExprType::Value(item) => match item.to_literal() {
+                Some(ExprLiteral::DatumLiteral(typed))
+                    if typed.dat_type().dat_type_id() == DatTypeID::String =>
+                {
+                    JsonPath::parse(string_literal_text(typed.dat_internal().expect_string()))?
+                }
+                _ => {
+                    return Err(mudu_error!(
+                        ErrorCode::Parse,
+                        "the json_extract path must be a string literal"
+                    ))
+                }
+            },
+            _ => {
+                return Err(mudu_error!(
+                    ErrorCode::Parse,
+                    "the json_extract path must be a string literal"
+                ))
+            }
+        };
+        Self::with_json_steps(field, path.steps().to_vec(), false)
+    }
+
+    /// Parse the array column of an `ANY(column)` expression. Returns `None`
+    /// if the expression is not an `ANY` call.
+    fn visit_any(&self, context: &ParseContext, node: Node) -> RS<Option<ExprName>> {
+        self.visit_array_function(context, node, "any")
+    }
+
+    /// Parse the array column of a call `function(column)` of a function that
+    /// takes one array. Returns `None` if the expression is not a call of
+    /// `function`.
+    pub(crate) fn visit_array_function(
+        &self,
+        context: &ParseContext,
+        node: Node,
+        function: &str,
+    ) -> RS<Option<ExprName>> {
+        let Some(n) = node.child_by_field_name(ts_field_name::INVOCATION) else {
+            return Ok(None);
+        };
+        if !self
+            .visit_invocation_name(context, n)?
+            .eq_ignore_ascii_case(function)
+        {
+            return Ok(None);
+        }
+        let arguments = self.visit_invocation_arguments(context, n)?;
+        let [array] = arguments.as_slice() else {
+            return Err(mudu_error!(
+                ErrorCode::Parse,
+                format!("{} takes one array", function)
+            ));
+        };
+        Self::column_argument(array, function).map(Some)
+    }
+
+    fn visit_invocation_name(&self, context: &ParseContext, node: Node) -> RS<String> {
+        let mut cursor = node.walk();
+        let opt = node
+            .children(&mut cursor)
+            .find(|n| n.kind_id() == ts_kind_id::OBJECT_REFERENCE);
+        let n_name = rs_option(opt, "no function name in invocation")?;
+        self.visit_object_reference(context, n_name)
+    }
+
+    fn visit_invocation_arguments(&self, context: &ParseContext, node: Node) -> RS<Vec<ExprType>> {
+        let mut cursor = node.walk();
+        let mut arguments = Vec::new();
+        for n in node.children_by_field_name(ts_field_name::PARAMETER, &mut cursor) {
+            let opt = n.child_by_field_name(ts_field_name::EXPRESSION);
+            let n_expression = rs_option(opt, "function arguments must be expressions")?;
+            arguments.push(self.visit_expression(context, n_expression)?);
+        }
+        Ok(arguments)
+    }
+
+    fn column_argument(argument: &ExprType, function: &str) -> RS<ExprName> {
+        let field = match argument {
+            ExprType::Value(item) => item.to_field().cloned(),
+            _ => None,
+        };
+        field.ok_or_else(|| {
+            mudu_error!(
+                ErrorCode::NotImplemented,
+                format!("{} of anything but a column is not implemented", function)
+            )
+        })
+    }
+
     /// Parse an operator node into an [`Operator`].
     pub(crate) fn visit_operator(&self, context: &ParseContext, node: Node) -> RS<Operator> {
         let op_string = ts_node_context_string(context.parse_str(), &node)?;
@@ -50,5 +50,6 @@ mod expression;
 mod insert;
 mod partition;
 mod select;
+mod table_option;
 mod update_delete;
 mod utils;
@@ -1,15 +1,20 @@
 //! SELECT statement parser.
 
 use super::context::ParseContext;
-use super::error::ts_node_context_string;
+use super::error::{node_or_descendant_has_kind, ts_node_context_string};
+use super::utils::string_literal_text;
 use super::SQLParser;
+use crate::ast::as_of_time::AsOfTime;
 use crate::ast::expr_compare::ExprCompare;
 use crate::ast::expr_visitor::ExprVisitor;
+use crate::ast::expression::ExprType;
+use crate::ast::order_term::OrderTerm;
 use crate::ast::select_term::SelectTerm;
 use crate::ast::stmt_select::StmtSelect;
-use crate::ts_const::ts_field_name;
+use crate::ts_const::{ts_field_name, ts_kind_id, ts_kind_name};
 use mudu::common::result::RS;
 use mudu::common::result_of::rs_option;
+use mudu::data_type::timestamptz::TimestampTzValue;
 use mudu::error::ErrorCode;
 use mudu::mudu_error;
 use tree_sitter::Node;
@@ -69,6 +74,11 @@ impl SQLParser {
         let opt_n_relation = node.child_by_field_name(ts_field_name::RELATION);
         let n_relation = rs_option(opt_n_relation, "")?;
         self.visit_relation(context, n_relation, stmt)?;
+        let opt_n_as_of = node.child_by_field_name(ts_field_name::AS_OF);
+        if let Some(n_as_of) = opt_n_as_of {
+            let as_of = self.visit_as_of(context, n_as_of)?;
+            stmt.set_as_of(Some(as_of));
+        }
         let opt_n_where = node.child_by_field_name(ts_field_name::WHERE);
         if let Some(n_where) = opt_n_where {
             let where_predicate_list = self.visit_where(context, n_where)?;
@@ -76,10 +86,76 @@ impl SQLParser {
                 stmt.add_where_predicate(p);
             }
         }
+        let opt_n_order_by = node.child_by_field_name(ts_field_name::ORDER_BY);
+        if let Some(n_order_by) = opt_n_order_by {
+            let mut cursor = n_order_by.walk();
+            for n in n_order_by.children(&mut cursor) {
+                if n.kind_id() == ts_kind_id::ORDER_TARGET {
+                    stmt.add_order_term(self.visit_order_target(context, n)?);
+                }
+            }
+        }
 
         Ok(())
     }
 
+    /// Parse one `ORDER BY` target: a column, an optional `ASC`/`DESC` and
+    /// an optional `NULLS FIRST`/`NULLS LAST`.
+    pub(crate) fn visit_order_target(&self, context: &ParseContext, node: Node) -> RS<OrderTerm> {
+        let opt = node.child_by_field_name(ts_field_name::EXPRESSION);
+        let n_expression = rs_option(opt, "no expression in order by target")?;
+        let opt = n_expression.child_by_field_name(ts_field_name::QUALIFIED_FIELD);
+        let Some(n_field) = opt else {
+            return Err(mudu_error!(
+                ErrorCode::NotImplemented,
+                format!(
+                    "ORDER BY supports column names only, got '{}'",
+                    ts_node_context_string(context.parse_str(), &n_expression)?
+                )
+            ));
+        };
+        let mut term = OrderTerm::new(self.visit_qualified_field(context, n_field)?);
+        if let Some(n_direction) = node.child_by_field_name(ts_field_name::DIRECTION) {
+            let descending = n_direction
+                .child(0)
+                .is_some_and(|n| n.kind_id() == ts_kind_id::KEYWORD_DESC);
+            term.set_descending(descending);
+        } else if node_or_descendant_has_kind(node, ts_kind_name::S_KEYWORD_USING) {
+            return Err(mudu_error!(
+                ErrorCode::NotImplemented,
+                "ORDER BY ... USING is not implemented"
+            ));
+        }
+        if let Some(n_nulls) = node.child_by_field_name(ts_field_name::NULLS) {
+            term.set_nulls_first(Some(n_nulls.kind_id() == ts_kind_id::KEYWORD_FIRST));
+        }
+        Ok(term)
+    }
+
+    /// Parse `AS OF SYSTEM TIME <timestamp>`, where the timestamp is either
+    /// a commit timestamp or a string with a timestamp with time zone.
+    pub(crate) fn visit_as_of(&self, context: &ParseContext, node: Node) -> RS<AsOfTime> {
+        let opt = node.child_by_field_name(ts_field_name::TIMESTAMP);
+        let n_timestamp = rs_option(opt, "no timestamp in AS OF SYSTEM TIME")?;
+        let timestamp_text = ts_node_context_string(context.parse_str(), &n_timestamp)?;
+        let as_of = if timestamp_text.starts_with('\'') {
+            TimestampTzValue::parse(string_literal_text(&timestamp_text))
+                .ok()
+                .map(|timestamp| AsOfTime::WallClock(timestamp.epoch_micros_utc()))
+        } else {
+            timestamp_text.parse::<u64>().ok().map(AsOfTime::CommitTs)
+        };
+        as_of.ok_or_else(|| {
+            mudu_error!(
+                ErrorCode::Parse,
+                format!(
+                    "AS OF SYSTEM TIME expects an unsigned commit timestamp or a timestamp with time zone, got {}",
+                    timestamp_text
+                )
+            )
+        })
+    }
+
     pub(crate) fn visit_where(&self, context: &ParseContext, node: Node) -> RS<Vec<ExprCompare>> {
         let opt = node.child_by_field_name(ts_field_name::PREDICATE);
         let n_predicate = rs_option(opt, "")?;
@@ -169,13 +245,19 @@ impl SQLParser {
         node: Node,
         term: &mut SelectTerm,
     ) -> RS<()> {
-        let opt_identifier = node.child_by_field_name(ts_field_name::QUALIFIED_FIELD);
-        match opt_identifier {
-            Some(n) => {
-                let field = self.visit_qualified_field(context, n)?;
-                term.set_field(field);
-            }
-            None => return Err(mudu_error!(ErrorCode::NotImplemented)),
+        // Columns, JSON paths into columns, array elements and
+        // `unnest(column)` are the only projections.
+        if let Some(field) = self.visit_array_function(context, node, "unnest")? {
+            term.set_field(field);
+            term.set_unnest(true);
+            return Ok(());
+        }
+        match self.visit_expression(context, node)? {
+            ExprType::Value(item) => match item.to_field() {
+                Some(field) => term.set_field(field.clone()),
+                None => return Err(mudu_error!(ErrorCode::NotImplemented)),
+            },
+            _ => return Err(mudu_error!(ErrorCode::NotImplemented)),
         };
         Ok(())
     }
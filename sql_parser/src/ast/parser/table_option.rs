@@ -0,0 +1,77 @@
+use super::utils::{find_matching_paren, split_top_level_csv, starts_with_ignore_ascii_case};
+use mudu::common::result::RS;
+use mudu::error::ErrorCode;
+use mudu::mudu_error;
+
+/// Returns the position of a `WITH (...)` table options clause in the text
+/// that follows the column list of a `CREATE TABLE` statement.
+pub(crate) fn find_table_options_position(suffix: &str) -> Option<usize> {
+    let lower = suffix.to_ascii_lowercase();
+    lower.match_indices("with").map(|(pos, _)| pos).find(|pos| {
+        let at_word_start = lower[..*pos]
+            .chars()
+            .next_back()
+            .is_none_or(char::is_whitespace);
+        at_word_start && lower[pos + "with".len()..].trim_start().starts_with('(')
+    })
+}
+
+/// Parses `WITH (name = value, ...)` into lower-case option names and their
+/// values, with surrounding single quotes removed.
+pub(crate) fn parse_table_options_clause(input: &str) -> RS<Vec<(String, String)>> {
+    if !starts_with_ignore_ascii_case(input, "with") {
+        return Err(mudu_error!(
+            ErrorCode::Parse,
+            "expected WITH table options clause"
+        ));
+    }
+    let rest = input["with".len()..].trim_start();
+    if !rest.starts_with('(') {
+        return Err(mudu_error!(
+            ErrorCode::Parse,
+            "WITH table options must be wrapped in parentheses"
+        ));
+    }
+    let close_index = find_matching_paren(rest, 0)?;
+    if !rest[close_index + 1..].trim().is_empty() {
+        return Err(mudu_error!(
+            ErrorCode::Parse,
+            format!(
+                "unexpected text after table options: {}",
+                rest[close_index + 1..].trim()
+            )
+        ));
+    }
+    let mut options: Vec<(String, String)> = Vec::new();
+    for item in split_top_level_csv(&rest[1..close_index]) {
+        let (name, value) = item.split_once('=').ok_or_else(|| {
+            mudu_error!(
+                ErrorCode::Parse,
+                format!("table option {} must have the form name = value", item)
+            )
+        })?;
+        let name = name.trim().to_ascii_lowercase();
+        let value = value.trim();
+        let value = value
+            .strip_prefix('\'')
+            .and_then(|value| value.strip_suffix('\''))
+            .unwrap_or(value);
+        if name.is_empty() || value.is_empty() {
+            return Err(mudu_error!(
+                ErrorCode::Parse,
+                format!("invalid table option {}", item)
+            ));
+        }
+        if options.iter().any(|(existing, _)| *existing == name) {
+            return Err(mudu_error!(
+                ErrorCode::Parse,
+                format!("table option {} is set more than once", name)
+            ));
+        }
+        options.push((name, value.to_string()));
+    }
+    if options.is_empty() {
+        return Err(mudu_error!(ErrorCode::Parse, "table options are empty"));
+    }
+    Ok(options)
+}
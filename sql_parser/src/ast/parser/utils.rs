@@ -24,6 +24,15 @@ impl SQLParser {
     }
 }
 
+/// String literals keep their quotes in the AST; JSON keys and paths are
+/// the text between them.
+pub(crate) fn string_literal_text(literal: &str) -> &str {
+    literal
+        .strip_prefix('\'')
+        .and_then(|text| text.strip_suffix('\''))
+        .unwrap_or(literal)
+}
+
 pub(crate) fn starts_with_ignore_ascii_case(input: &str, prefix: &str) -> bool {
     input
         .get(..prefix.len())
@@ -11,7 +11,7 @@
 #[cfg(test)]
 mod tests {
     use crate::ast::expr_item::{ExprItem, ExprValue};
-    use crate::ast::expr_operator::{Arithmetic, ValueCompare};
+    use crate::ast::expr_operator::{Arithmetic, JsonOperator, ValueCompare};
     use crate::ast::expression::ExprType;
     use crate::ast::parser::SQLParser;
     use crate::ast::stmt_create_table::StmtCreateTable;
@@ -22,6 +22,7 @@ mod tests {
     use mudu_binding::universal::uni_dat_value::UniDatValue;
     use mudu_binding::universal::uni_scalar::UniScalar;
     use mudu_binding::universal::uni_scalar_value::UniScalarValue;
+    use mudu_type::json_path::JsonPathStep;
     use project_root::get_project_root;
 
     use std::path::Path;
@@ -135,6 +136,28 @@ mod tests {
         }
     }
 
+    #[test]
+    #[cfg_attr(miri, ignore)]
+    fn parse_boolean_literal_maps_to_bool() {
+        let stmts = parse_sql("select id from users where active = true;").unwrap();
+
+        let StmtType::Select(stmt) = &stmts[0] else {
+            panic!("expected select");
+        };
+        let predicate = &stmt.get_where_predicate()[0];
+        match predicate.right() {
+            ExprItem::ItemValue(ExprValue::ValueLiteral(literal)) => {
+                let typed = literal.dat_type().unwrap();
+                assert_eq!(
+                    typed.dat_type().dat_type_id(),
+                    mudu_type::dat_type_id::DatTypeID::Bool
+                );
+                assert!(typed.dat_internal().to_bool());
+            }
+            other => panic!("expected boolean literal on right side, got {other:?}"),
+        }
+    }
+
     #[test]
     #[cfg_attr(miri, ignore)]
     fn parse_decimal_literal_preserves_trailing_fractional_zeros() {
@@ -262,6 +285,53 @@ mod tests {
         }
     }
 
+    #[test]
+    #[cfg_attr(miri, ignore)]
+    fn parse_update_json_arrow_assignments() {
+        let stmts = parse_sql(
+            "update profile set name = attrs ->> 'name', tags = attrs -> 'tags' where id = 1;",
+        )
+        .unwrap();
+
+        let StmtType::Command(StmtCommand::Update(stmt)) = &stmts[0] else {
+            panic!("expected update");
+        };
+        // Accesses into a column fold into the column's JSON path.
+        let json_path = |index: usize| match stmt.get_set_values()[index].get_set_value() {
+            AssignedValue::Expression(ExprType::Value(item)) => {
+                let field = item.to_field().expect("expected a column");
+                assert_eq!(field.name(), "attrs");
+                field.json_path().expect("expected a json path").clone()
+            }
+            other => panic!("expected json assignment, got {other:?}"),
+        };
+        let name = json_path(0);
+        assert_eq!(
+            name.path().steps(),
+            &[JsonPathStep::Key("name".to_string())]
+        );
+        assert!(name.as_text());
+        let tags = json_path(1);
+        assert_eq!(
+            tags.path().steps(),
+            &[JsonPathStep::Key("tags".to_string())]
+        );
+        assert!(!tags.as_text());
+
+        // Other documents keep the operator.
+        let stmts = parse_sql("update profile set name = '{}' -> 'a' where id = 1;").unwrap();
+        let StmtType::Command(StmtCommand::Update(stmt)) = &stmts[0] else {
+            panic!("expected update");
+        };
+        match stmt.get_set_values()[0].get_set_value() {
+            AssignedValue::Expression(ExprType::Json(expr)) => {
+                assert!(matches!(expr.op(), JsonOperator::Arrow));
+                assert!(matches!(expr.left(), ExprType::Value(_)));
+            }
+            other => panic!("expected json assignment, got {other:?}"),
+        }
+    }
+
     #[test]
     #[cfg_attr(miri, ignore)]
     fn parse_delete_with_and_predicates() {
@@ -338,7 +408,7 @@ mod tests {
     #[test]
     #[cfg_attr(miri, ignore)]
     fn parse_create_table_with_unsupported_type_returns_error() {
-        let err = parse_sql("create table users (id boolean primary key);").unwrap_err();
+        let err = parse_sql("create table users (id inet primary key);").unwrap_err();
         assert!(err.to_string().contains("not yet implemented"));
     }
 
@@ -611,6 +681,152 @@ mod tests {
         assert!(amount.data_type_param().is_none());
     }
 
+    #[test]
+    #[cfg_attr(miri, ignore)]
+    fn parse_create_table_boolean_small_int_and_uuid_types() {
+        let stmt = parse_create_table(
+            "
+            CREATE TABLE device (
+                id UUID PRIMARY KEY,
+                enabled BOOLEAN,
+                level TINYINT,
+                port SMALLINT
+            );
+            ",
+        )
+        .unwrap();
+
+        let primary = stmt.primary_columns();
+        assert!(matches!(
+            primary[0].data_type(),
+            UniDatType::Scalar(UniScalar::Uuid)
+        ));
+        let columns = stmt.non_primary_columns();
+        assert!(matches!(
+            columns[0].data_type(),
+            UniDatType::Scalar(UniScalar::Bool)
+        ));
+        assert!(matches!(
+            columns[1].data_type(),
+            UniDatType::Scalar(UniScalar::I8)
+        ));
+        assert!(matches!(
+            columns[2].data_type(),
+            UniDatType::Scalar(UniScalar::I16)
+        ));
+        assert!(columns.iter().all(|c| c.data_type_param().is_none()));
+    }
+
+    #[test]
+    #[cfg_attr(miri, ignore)]
+    fn parse_create_table_array_types() {
+        let stmt = parse_create_table(
+            "
+            CREATE TABLE post (
+                id INT PRIMARY KEY,
+                scores INT[],
+                tags VARCHAR(16) ARRAY,
+                grid BIGINT[3][3]
+            );
+            ",
+        )
+        .unwrap();
+
+        let columns = stmt.non_primary_columns();
+        match columns[0].data_type() {
+            UniDatType::Array(inner) => {
+                assert!(matches!(**inner, UniDatType::Scalar(UniScalar::I32)))
+            }
+            other => panic!("expected int array, got {other:?}"),
+        }
+        assert!(columns[0].data_type_param().is_none());
+        match columns[1].data_type() {
+            UniDatType::Array(inner) => {
+                assert!(matches!(**inner, UniDatType::Scalar(UniScalar::String)))
+            }
+            other => panic!("expected varchar array, got {other:?}"),
+        }
+        assert!(columns[1].data_type_param().is_some());
+        match columns[2].data_type() {
+            UniDatType::Array(outer) => match &**outer {
+                UniDatType::Array(inner) => {
+                    assert!(matches!(**inner, UniDatType::Scalar(UniScalar::I64)))
+                }
+                other => panic!("expected nested array, got {other:?}"),
+            },
+            other => panic!("expected bigint array, got {other:?}"),
+        }
+    }
+
+    #[test]
+    #[cfg_attr(miri, ignore)]
+    fn parse_create_table_record_types() {
+        let stmt = parse_create_table(
+            "
+            CREATE TABLE place (
+                id INT PRIMARY KEY,
+                pos ROW(x INT, y INT, label TEXT),
+                trail ROW(x INT, y INT)[]
+            );
+            ",
+        )
+        .unwrap();
+
+        let columns = stmt.non_primary_columns();
+        match columns[0].data_type() {
+            UniDatType::Record(record) => {
+                let fields = record
+                    .record_fields
+                    .iter()
+                    .map(|f| f.field_name.as_str())
+                    .collect::<Vec<_>>();
+                assert_eq!(fields, vec!["x", "y", "label"]);
+                assert!(matches!(
+                    record.record_fields[2].field_type,
+                    UniDatType::Scalar(UniScalar::String)
+                ));
+            }
+            other => panic!("expected record, got {other:?}"),
+        }
+        match columns[1].data_type() {
+            UniDatType::Array(inner) => assert!(matches!(**inner, UniDatType::Record(_))),
+            other => panic!("expected record array, got {other:?}"),
+        }
+
+        for bad in [
+            "CREATE TABLE place (id INT PRIMARY KEY, pos ROW(x INT, x INT));",
+            "CREATE TABLE place (id INT PRIMARY KEY, pos ROW(name VARCHAR(8)));",
+        ] {
+            assert!(parse_create_table(bad).is_err(), "{}", bad);
+        }
+    }
+
+    #[test]
+    #[cfg_attr(miri, ignore)]
+    fn parse_create_table_json_types() {
+        let stmt = parse_create_table(
+            "
+            CREATE TABLE profile (
+                id INT PRIMARY KEY,
+                attrs JSON,
+                extra JSONB
+            );
+            ",
+        )
+        .unwrap();
+
+        let columns = stmt.non_primary_columns();
+        assert!(matches!(
+            columns[0].data_type(),
+            UniDatType::Scalar(UniScalar::Json)
+        ));
+        assert!(matches!(
+            columns[1].data_type(),
+            UniDatType::Scalar(UniScalar::Json)
+        ));
+        assert!(columns.iter().all(|c| c.data_type_param().is_none()));
+    }
+
     #[test]
     #[cfg_attr(miri, ignore)]
     fn test_create_table_ast_column_primary_key_index() {
@@ -5,6 +5,7 @@ use crate::ast::expr_name::ExprName;
 pub struct SelectTerm {
     field: ExprName,
     alias: String,
+    unnest: bool,
 }
 
 impl Default for SelectTerm {
@@ -19,6 +20,7 @@ impl SelectTerm {
         Self {
             field: ExprName::new(),
             alias: Default::default(),
+            unnest: false,
         }
     }
 
@@ -41,6 +43,16 @@ impl SelectTerm {
     pub fn field(&self) -> &ExprName {
         &self.field
     }
+
+    /// Mark the field as expanded by `unnest`, one output row per element.
+    pub fn set_unnest(&mut self, unnest: bool) {
+        self.unnest = unnest;
+    }
+
+    /// Return `true` if the field is expanded by `unnest`.
+    pub fn unnest(&self) -> bool {
+        self.unnest
+    }
 }
 
 #[cfg(test)]
@@ -74,6 +86,14 @@ mod tests {
         assert_eq!(term.field().name(), "col");
     }
 
+    #[test]
+    fn set_unnest_marks_the_term() {
+        let mut term = SelectTerm::new();
+        assert!(!term.unnest());
+        term.set_unnest(true);
+        assert!(term.unnest());
+    }
+
     #[test]
     fn set_alias_updates_alias() {
         let mut term = SelectTerm::new();
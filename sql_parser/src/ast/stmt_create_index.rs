@@ -0,0 +1,39 @@
+use crate::ast::ast_node::ASTNode;
+use crate::ast::expr_name::ExprName;
+use std::fmt::Debug;
+
+/// `CREATE INDEX` statement AST node.
+#[derive(Debug, Clone)]
+pub struct StmtCreateIndex {
+    index_name: Option<String>,
+    table_name: String,
+    columns: Vec<ExprName>,
+}
+
+impl StmtCreateIndex {
+    /// Create a new `CREATE INDEX` statement over `columns` of `table_name`.
+    pub fn new(index_name: Option<String>, table_name: String, columns: Vec<ExprName>) -> Self {
+        Self {
+            index_name,
+            table_name,
+            columns,
+        }
+    }
+
+    /// Return the index name, if one was given.
+    pub fn index_name(&self) -> Option<&str> {
+        self.index_name.as_deref()
+    }
+
+    /// Return the indexed table name.
+    pub fn table_name(&self) -> &str {
+        &self.table_name
+    }
+
+    /// Return the indexed columns, with their JSON paths, in index order.
+    pub fn columns(&self) -> &Vec<ExprName> {
+        &self.columns
+    }
+}
+
+impl ASTNode for StmtCreateIndex {}
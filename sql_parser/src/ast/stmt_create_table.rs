@@ -12,6 +12,7 @@ pub struct StmtCreateTable {
     primary_key_column_def: Vec<AttrIndex>,
     non_primary_key_column_def: Vec<AttrIndex>,
     partition: Option<StmtTablePartition>,
+    table_options: Vec<(String, String)>,
 }
 
 impl StmtCreateTable {
@@ -23,6 +24,7 @@ impl StmtCreateTable {
             primary_key_column_def: vec![],
             non_primary_key_column_def: vec![],
             partition: None,
+            table_options: vec![],
         }
     }
 
@@ -89,6 +91,16 @@ impl StmtCreateTable {
         self.partition = Some(partition);
     }
 
+    /// Return the `WITH (...)` table options as lower-case names and values.
+    pub fn table_options(&self) -> &Vec<(String, String)> {
+        &self.table_options
+    }
+
+    /// Add a `WITH (...)` table option.
+    pub fn add_table_option(&mut self, name: String, value: String) {
+        self.table_options.push((name, value));
+    }
+
     /// Recalculate primary and non-primary column indices from constraints.
     pub fn assign_index_for_columns(&mut self) {
         self.primary_key_column_def.clear();
@@ -60,6 +60,16 @@ fn partition_accessor_and_mutator() {
     assert_eq!(stmt.partition().unwrap().rule_name(), "rule");
 }
 
+#[test]
+fn table_options_keep_their_order() {
+    let mut stmt = StmtCreateTable::new("users".to_string());
+    assert!(stmt.table_options().is_empty());
+    stmt.add_table_option("compression".to_string(), "lz4".to_string());
+    stmt.add_table_option("fillfactor".to_string(), "90".to_string());
+    assert_eq!(stmt.table_options()[0].0, "compression");
+    assert_eq!(stmt.table_options()[1].1, "90");
+}
+
 #[test]
 fn assign_index_for_columns_separates_primary_and_non_primary() {
     let mut stmt = StmtCreateTable::new("users".to_string());
@@ -1,5 +1,7 @@
+use crate::ast::as_of_time::AsOfTime;
 use crate::ast::ast_node::ASTNode;
 use crate::ast::expr_compare::ExprCompare;
+use crate::ast::order_term::OrderTerm;
 use crate::ast::select_term::SelectTerm;
 use std::fmt::Debug;
 
@@ -10,6 +12,8 @@ pub struct StmtSelect {
     table_reference: String,
     // currently, we only support and logical connective expression
     where_predicate: Vec<ExprCompare>,
+    as_of: Option<AsOfTime>,
+    order_by: Vec<OrderTerm>,
 }
 
 impl Default for StmtSelect {
@@ -25,6 +29,8 @@ impl StmtSelect {
             select_term_list: vec![],
             table_reference: "".to_string(),
             where_predicate: vec![],
+            as_of: None,
+            order_by: vec![],
         }
     }
 
@@ -57,6 +63,26 @@ impl StmtSelect {
     pub fn get_table_reference(&self) -> &String {
         &self.table_reference
     }
+
+    /// Set the point in time of an `AS OF SYSTEM TIME` clause.
+    pub fn set_as_of(&mut self, as_of: Option<AsOfTime>) {
+        self.as_of = as_of;
+    }
+
+    /// Return the point in time of the `AS OF SYSTEM TIME` clause, if any.
+    pub fn get_as_of(&self) -> Option<AsOfTime> {
+        self.as_of
+    }
+
+    /// Add a term to the `ORDER BY` clause.
+    pub fn add_order_term(&mut self, term: OrderTerm) {
+        self.order_by.push(term);
+    }
+
+    /// Return the `ORDER BY` terms, most significant first.
+    pub fn get_order_by(&self) -> &Vec<OrderTerm> {
+        &self.order_by
+    }
 }
 
 impl ASTNode for StmtSelect {}
@@ -1,5 +1,6 @@
 use crate::ast::stmt_copy_from::StmtCopyFrom;
 use crate::ast::stmt_copy_to::StmtCopyTo;
+use crate::ast::stmt_create_index::StmtCreateIndex;
 use crate::ast::stmt_create_partition_placement::StmtCreatePartitionPlacement;
 use crate::ast::stmt_create_partition_rule::StmtCreatePartitionRule;
 use crate::ast::stmt_create_table::StmtCreateTable;
@@ -33,6 +34,8 @@ pub enum StmtCommand {
     CreatePartitionRule(StmtCreatePartitionRule),
     /// `CREATE TABLE` statement.
     CreateTable(StmtCreateTable),
+    /// `CREATE INDEX` statement.
+    CreateIndex(StmtCreateIndex),
     /// `DROP TABLE` statement.
     DropTable(StmtDropTable),
     /// `COPY ... TO` statement.
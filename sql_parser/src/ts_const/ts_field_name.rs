@@ -8,17 +8,21 @@
 pub const ALIAS: &str = "alias";
 pub const ALL_FIELDS: &str = "all_fields";
 pub const ARGUMENT: &str = "argument";
+pub const ARRAY: &str = "array";
+pub const AS_OF: &str = "as_of";
 pub const ASSIGNMENT: &str = "assignment";
 pub const BEGIN_TRANSACTION: &str = "begin_transaction";
 pub const BETWEEN_EXPRESSION: &str = "between_expression";
 pub const BINARY_EXPRESSION: &str = "binary_expression";
 pub const CACHE: &str = "cache";
 pub const COL_NAME: &str = "col_name";
+pub const COLLATION: &str = "collation";
 pub const COLUMN: &str = "column";
 pub const COLUMN_CONSTRAINT: &str = "column_constraint";
 pub const COLUMN_DEFINITIONS: &str = "column_definitions";
 pub const COLUMN_LIST: &str = "column_list";
 pub const COLUMN_NAME: &str = "column_name";
+pub const COLUMNS: &str = "columns";
 pub const COMMIT_TRANSACTION: &str = "commit_transaction";
 pub const CONFIGURATION_PARAMETER: &str = "configuration_parameter";
 pub const CONNECTION_LIMIT: &str = "connection_limit";
@@ -27,6 +31,7 @@ pub const CUSTOM_TYPE: &str = "custom_type";
 pub const DATA_TYPE: &str = "data_type";
 pub const DATA_TYPE_KIND: &str = "data_type_kind";
 pub const DECIMAL: &str = "decimal";
+pub const DIRECTION: &str = "direction";
 pub const DISTINCT: &str = "distinct";
 pub const END: &str = "end";
 pub const ENUM_ELEMENT: &str = "enum_element";
@@ -41,9 +46,11 @@ pub const IDENTIFIER_NAME: &str = "identifier_name";
 pub const IF_EXIST: &str = "if_exist";
 pub const IF_NOT_EXISTS: &str = "if_not_exists";
 pub const INCREMENT: &str = "increment";
+pub const INDEX: &str = "index";
 pub const INDEX_NAME: &str = "index_name";
 pub const INSERT_VALUES: &str = "insert_values";
 pub const INTEGER: &str = "integer";
+pub const INVOCATION: &str = "invocation";
 pub const KEY: &str = "key";
 pub const KEYWORD_FALSE: &str = "keyword_false";
 pub const KEYWORD_NULL: &str = "keyword_null";
@@ -55,6 +62,7 @@ pub const LITERAL: &str = "literal";
 pub const LOW: &str = "low";
 pub const NAME: &str = "name";
 pub const NEW_NAME: &str = "new_name";
+pub const NULLS: &str = "nulls";
 pub const OBJECT_NAME: &str = "object_name";
 pub const OBJECT_REFERENCE: &str = "object_reference";
 pub const OLD_NAME: &str = "old_name";
@@ -62,6 +70,7 @@ pub const OPERAND: &str = "operand";
 pub const OPERATION: &str = "operation";
 pub const OPERATOR: &str = "operator";
 pub const OPTION: &str = "option";
+pub const ORDER_BY: &str = "order_by";
 pub const PARAMETER: &str = "parameter";
 pub const PARAMETER_PLACEHOLDER: &str = "parameter_placeholder";
 pub const PASSWORD: &str = "password";
@@ -72,10 +81,12 @@ pub const PREDICATE: &str = "predicate";
 pub const PRIMARY_KEY: &str = "primary_key";
 pub const PRIMARY_KEY_CONSTRAINT: &str = "primary_key_constraint";
 pub const QUALIFIED_FIELD: &str = "qualified_field";
+pub const RECORD_FIELD: &str = "record_field";
 pub const RELATION: &str = "relation";
 pub const RESTART: &str = "restart";
 pub const RIGHT: &str = "right";
 pub const ROLLBACK_TRANSACTION: &str = "rollback_transaction";
+pub const ROW: &str = "row";
 pub const ROW_TERMINATED_CHAR: &str = "row_terminated_char";
 pub const SCALE: &str = "scale";
 pub const SCHEMA: &str = "schema";
@@ -88,10 +99,13 @@ pub const START: &str = "start";
 pub const STATEMENT: &str = "statement";
 pub const STMT_GUT: &str = "stmt_gut";
 pub const STRING: &str = "string";
+pub const SUBSCRIPT: &str = "subscript";
 pub const TABLE_NAME: &str = "table_name";
 pub const TIMEOUT: &str = "timeout";
+pub const TIMESTAMP: &str = "timestamp";
 pub const TYPE: &str = "type";
 pub const TYPED_ROW_VALUE_EXPR_LIST: &str = "typed_row_value_expr_list";
+pub const UNIQUE: &str = "unique";
 pub const VALID_UNTIL: &str = "valid_until";
 pub const VALUE: &str = "value";
 pub const WHERE: &str = "where";
@@ -53,94 +53,95 @@ pub const _USER_ACCESS_ROLE_CONFIG: u16 = 0;
 pub const _VACUUM_OPTION: u16 = 0;
 pub const _VACUUM_TABLE: u16 = 0;
 pub const _WITH_SETTINGS: u16 = 0;
-pub const ADD_COLUMN: u16 = 340;
-pub const ADD_CONSTRAINT: u16 = 341;
-pub const ALIAS_NAME: u16 = 408;
-pub const ALL_FIELDS: u16 = 400;
-pub const ALTER_COLUMN: u16 = 342;
-pub const ALTER_DATABASE: u16 = 350;
-pub const ALTER_INDEX: u16 = 353;
-pub const ALTER_ROLE: u16 = 351;
-pub const ALTER_SCHEMA: u16 = 349;
-pub const ALTER_SEQUENCE: u16 = 354;
-pub const ALTER_TABLE: u16 = 338;
-pub const ALTER_TYPE: u16 = 355;
-pub const ALTER_VIEW: u16 = 348;
-pub const ARRAY: u16 = 312;
-pub const ARRAY_SIZE_DEFINITION: u16 = 288;
-pub const ASSIGNMENT: u16 = 385;
-pub const BANG: u16 = 254;
-pub const BEGIN_TRANSACTION: u16 = 316;
-pub const BETWEEN_EXPRESSION: u16 = 419;
-pub const BIGINT: u16 = 294;
-pub const BINARY: u16 = 297;
-pub const BINARY_EXPRESSION: u16 = 417;
-pub const BIT: u16 = 296;
+pub const ADD_COLUMN: u16 = 355;
+pub const ADD_CONSTRAINT: u16 = 356;
+pub const ALIAS_NAME: u16 = 423;
+pub const ALL_FIELDS: u16 = 415;
+pub const ALTER_COLUMN: u16 = 357;
+pub const ALTER_DATABASE: u16 = 365;
+pub const ALTER_INDEX: u16 = 368;
+pub const ALTER_ROLE: u16 = 366;
+pub const ALTER_SCHEMA: u16 = 364;
+pub const ALTER_SEQUENCE: u16 = 369;
+pub const ALTER_TABLE: u16 = 353;
+pub const ALTER_TYPE: u16 = 370;
+pub const ALTER_VIEW: u16 = 363;
+pub const ARRAY: u16 = 326;
+pub const ARRAY_SIZE_DEFINITION: u16 = 302;
+pub const AS_OF: u16 = 425;
+pub const ASSIGNMENT: u16 = 400;
+pub const BANG: u16 = 264;
+pub const BEGIN_TRANSACTION: u16 = 330;
+pub const BETWEEN_EXPRESSION: u16 = 437;
+pub const BIGINT: u16 = 308;
+pub const BINARY: u16 = 311;
+pub const BINARY_EXPRESSION: u16 = 435;
+pub const BIT: u16 = 310;
 pub const CASE: u16 = 0;
-pub const CAST: u16 = 405;
-pub const CHANGE_COLUMN: u16 = 344;
-pub const CHANGE_OWNERSHIP: u16 = 368;
-pub const CHAR: u16 = 303;
-pub const COLUMN: u16 = 375;
-pub const COLUMN_CONSTRAINT: u16 = 390;
-pub const COLUMN_DEFINITION: u16 = 388;
-pub const COLUMN_DEFINITIONS: u16 = 387;
-pub const COLUMN_LIST: u16 = 374;
-pub const COLUMN_POSITION: u16 = 345;
-pub const COMMENT: u16 = 313;
-pub const COMMIT_TRANSACTION: u16 = 317;
-pub const CONSTRAINT: u16 = 394;
-pub const CONSTRAINTS: u16 = 393;
-pub const COPY_FROM: u16 = 321;
-pub const COPY_STMT: u16 = 320;
-pub const COPY_TO: u16 = 322;
+pub const CAST: u16 = 419;
+pub const CHANGE_COLUMN: u16 = 359;
+pub const CHANGE_OWNERSHIP: u16 = 383;
+pub const CHAR: u16 = 317;
+pub const COLUMN: u16 = 390;
+pub const COLUMN_CONSTRAINT: u16 = 405;
+pub const COLUMN_DEFINITION: u16 = 403;
+pub const COLUMN_DEFINITIONS: u16 = 402;
+pub const COLUMN_LIST: u16 = 389;
+pub const COLUMN_POSITION: u16 = 360;
+pub const COMMENT: u16 = 327;
+pub const COMMIT_TRANSACTION: u16 = 331;
+pub const CONSTRAINT: u16 = 409;
+pub const CONSTRAINTS: u16 = 408;
+pub const COPY_FROM: u16 = 335;
+pub const COPY_STMT: u16 = 334;
+pub const COPY_TO: u16 = 336;
 pub const CREATE_DATABASE: u16 = 0;
 pub const CREATE_FUNCTION: u16 = 0;
-pub const CREATE_INDEX: u16 = 0;
+pub const CREATE_INDEX: u16 = 348;
 pub const CREATE_MATERIALIZED_VIEW: u16 = 0;
 pub const CREATE_QUERY: u16 = 0;
 pub const CREATE_ROLE: u16 = 0;
 pub const CREATE_SCHEMA: u16 = 0;
 pub const CREATE_SEQUENCE: u16 = 0;
-pub const CREATE_TABLE_STATEMENT: u16 = 333;
+pub const CREATE_TABLE_STATEMENT: u16 = 347;
 pub const CREATE_TYPE: u16 = 0;
 pub const CREATE_VIEW: u16 = 0;
 pub const CROSS_JOIN: u16 = 0;
 pub const CTE: u16 = 0;
-pub const DATA_TYPE: u16 = 286;
-pub const DATA_TYPE_KIND: u16 = 287;
-pub const DATETIMEOFFSET: u16 = 307;
-pub const DDL_STMT: u16 = 324;
-pub const DECIMAL: u16 = 301;
-pub const DECIMAL_NUMBER: u16 = 428;
+pub const DATA_TYPE: u16 = 298;
+pub const DATA_TYPE_KIND: u16 = 299;
+pub const DATETIMEOFFSET: u16 = 321;
+pub const DDL_STMT: u16 = 338;
+pub const DECIMAL: u16 = 315;
+pub const DECIMAL_NUMBER: u16 = 446;
 pub const DELETE: u16 = 0;
-pub const DELETE_STATEMENT: u16 = 332;
-pub const DIRECTION: u16 = 271;
-pub const DISTINCT_FROM: u16 = 264;
-pub const DML_READ_STMT: u16 = 326;
-pub const DML_WRITE_STMT: u16 = 325;
+pub const DELETE_STATEMENT: u16 = 346;
+pub const DIRECTION: u16 = 283;
+pub const DISTINCT_FROM: u16 = 276;
+pub const DML_READ_STMT: u16 = 340;
+pub const DML_WRITE_STMT: u16 = 339;
 pub const DOLLAR_QUOTE: u16 = 0;
-pub const DOUBLE: u16 = 300;
-pub const DROP_COLUMN: u16 = 346;
-pub const DROP_DATABASE: u16 = 361;
-pub const DROP_INDEX: u16 = 365;
-pub const DROP_ROLE: u16 = 362;
-pub const DROP_SCHEMA: u16 = 360;
-pub const DROP_SEQUENCE: u16 = 364;
-pub const DROP_STATEMENT: u16 = 357;
-pub const DROP_TABLE: u16 = 358;
-pub const DROP_TYPE: u16 = 363;
-pub const DROP_VIEW: u16 = 359;
-pub const ENUM: u16 = 311;
+pub const DOUBLE: u16 = 314;
+pub const DROP_COLUMN: u16 = 361;
+pub const DROP_DATABASE: u16 = 376;
+pub const DROP_INDEX: u16 = 380;
+pub const DROP_ROLE: u16 = 377;
+pub const DROP_SCHEMA: u16 = 375;
+pub const DROP_SEQUENCE: u16 = 379;
+pub const DROP_STATEMENT: u16 = 372;
+pub const DROP_TABLE: u16 = 373;
+pub const DROP_TYPE: u16 = 378;
+pub const DROP_VIEW: u16 = 374;
+pub const ENUM: u16 = 325;
 pub const ENUM_ELEMENTS: u16 = 0;
 pub const EXISTS: u16 = 0;
-pub const EXPRESSION: u16 = 416;
-pub const FIELD: u16 = 402;
-pub const FILE_PATH: u16 = 323;
-pub const FILTER_EXPRESSION: u16 = 406;
-pub const FLOAT: u16 = 299;
+pub const EXPRESSION: u16 = 432;
+pub const FIELD: u16 = 417;
+pub const FILE_PATH: u16 = 337;
+pub const FILTER_EXPRESSION: u16 = 421;
+pub const FLOAT: u16 = 313;
 pub const FRAME_DEFINITION: u16 = 0;
-pub const FROM: u16 = 409;
+pub const FROM: u16 = 424;
 pub const FUNCTION_BODY: u16 = 0;
 pub const FUNCTION_COST: u16 = 0;
 pub const FUNCTION_DECLARATION: u16 = 0;
@@ -152,198 +153,199 @@ pub const FUNCTION_STRICTNESS: u16 = 0;
 pub const FUNCTION_SUPPORT: u16 = 0;
 pub const FUNCTION_VOLATILITY: u16 = 0;
 pub const GROUP_BY: u16 = 0;
-pub const HUGEINT: u16 = 295;
-pub const IDENTIFIER: u16 = 429;
+pub const HUGEINT: u16 = 309;
+pub const IDENTIFIER: u16 = 447;
 pub const IMPLICIT_CAST: u16 = 0;
 pub const INDEX_HINT: u16 = 0;
-pub const INSERT_STATEMENT: u16 = 370;
-pub const INSERT_VALUES: u16 = 371;
-pub const INT: u16 = 293;
-pub const INTEGER: u16 = 427;
+pub const INSERT_STATEMENT: u16 = 385;
+pub const INSERT_VALUES: u16 = 386;
+pub const INT: u16 = 307;
+pub const INTEGER: u16 = 445;
 pub const INTERVAL: u16 = 0;
 pub const INTERVAL_DEFINITIONS: u16 = 0;
-pub const INVOCATION: u16 = 407;
-pub const IS_NOT: u16 = 260;
+pub const INVOCATION: u16 = 422;
+pub const IS_NOT: u16 = 272;
 pub const JOIN: u16 = 0;
 pub const KEYWORD_ADD: u16 = 36;
 pub const KEYWORD_ADMIN: u16 = 0;
-pub const KEYWORD_AFTER: u16 = 111;
-pub const KEYWORD_ALL: u16 = 102;
+pub const KEYWORD_AFTER: u16 = 113;
+pub const KEYWORD_ALL: u16 = 104;
 pub const KEYWORD_ALTER: u16 = 29;
-pub const KEYWORD_ALWAYS: u16 = 73;
+pub const KEYWORD_ALWAYS: u16 = 75;
 pub const KEYWORD_ANALYZE: u16 = 31;
-pub const KEYWORD_AND: u16 = 61;
-pub const KEYWORD_ANY: u16 = 103;
-pub const KEYWORD_ARRAY: u16 = 215;
+pub const KEYWORD_AND: u16 = 63;
+pub const KEYWORD_ANY: u16 = 105;
+pub const KEYWORD_ARRAY: u16 = 223;
 pub const KEYWORD_AS: u16 = 52;
 pub const KEYWORD_ASC: u16 = 24;
 pub const KEYWORD_ATOMIC: u16 = 0;
-pub const KEYWORD_ATTRIBUTE: u16 = 131;
+pub const KEYWORD_ATTRIBUTE: u16 = 139;
 pub const KEYWORD_AUTHORIZATION: u16 = 0;
-pub const KEYWORD_AUTO_INCREMENT: u16 = 71;
+pub const KEYWORD_AUTO_INCREMENT: u16 = 73;
 pub const KEYWORD_AVG: u16 = 0;
 pub const KEYWORD_AVRO: u16 = 0;
-pub const KEYWORD_BEFORE: u16 = 112;
-pub const KEYWORD_BEGIN: u16 = 105;
-pub const KEYWORD_BETWEEN: u16 = 114;
-pub const KEYWORD_BIGINT: u16 = 279;
-pub const KEYWORD_BIGSERIAL: u16 = 274;
-pub const KEYWORD_BIN_PACK: u16 = 139;
-pub const KEYWORD_BINARY: u16 = 150;
-pub const KEYWORD_BIT: u16 = 149;
-pub const KEYWORD_BOOLEAN: u16 = 148;
-pub const KEYWORD_BOX2D: u16 = 207;
-pub const KEYWORD_BOX3D: u16 = 208;
-pub const KEYWORD_BRIN: u16 = 0;
-pub const KEYWORD_BTREE: u16 = 0;
+pub const KEYWORD_BEFORE: u16 = 114;
+pub const KEYWORD_BEGIN: u16 = 107;
+pub const KEYWORD_BETWEEN: u16 = 116;
+pub const KEYWORD_BIGINT: u16 = 291;
+pub const KEYWORD_BIGSERIAL: u16 = 286;
+pub const KEYWORD_BIN_PACK: u16 = 147;
+pub const KEYWORD_BINARY: u16 = 158;
+pub const KEYWORD_BIT: u16 = 157;
+pub const KEYWORD_BOOLEAN: u16 = 156;
+pub const KEYWORD_BOX2D: u16 = 215;
+pub const KEYWORD_BOX3D: u16 = 216;
+pub const KEYWORD_BRIN: u16 = 128;
+pub const KEYWORD_BTREE: u16 = 270;
 pub const KEYWORD_BY: u16 = 22;
-pub const KEYWORD_BYTEA: u16 = 192;
-pub const KEYWORD_CACHE: u16 = 142;
+pub const KEYWORD_BYTEA: u16 = 200;
+pub const KEYWORD_CACHE: u16 = 150;
 pub const KEYWORD_CACHED: u16 = 0;
 pub const KEYWORD_CALLED: u16 = 0;
-pub const KEYWORD_CASCADE: u16 = 78;
+pub const KEYWORD_CASCADE: u16 = 80;
 pub const KEYWORD_CASCADED: u16 = 0;
 pub const KEYWORD_CASE: u16 = 0;
-pub const KEYWORD_CAST: u16 = 56;
+pub const KEYWORD_CAST: u16 = 58;
 pub const KEYWORD_CHANGE: u16 = 30;
-pub const KEYWORD_CHAR: u16 = 281;
-pub const KEYWORD_CHARACTER: u16 = 257;
+pub const KEYWORD_CHAR: u16 = 293;
+pub const KEYWORD_CHARACTER: u16 = 268;
 pub const KEYWORD_CHECK: u16 = 0;
-pub const KEYWORD_COLLATE: u16 = 74;
+pub const KEYWORD_COLLATE: u16 = 76;
 pub const KEYWORD_COLUMN: u16 = 40;
 pub const KEYWORD_COLUMNS: u16 = 41;
-pub const KEYWORD_COMMENT: u16 = 141;
-pub const KEYWORD_COMMIT: u16 = 106;
-pub const KEYWORD_COMPUTE: u16 = 134;
-pub const KEYWORD_CONCURRENTLY: u16 = 120;
+pub const KEYWORD_COMMENT: u16 = 149;
+pub const KEYWORD_COMMIT: u16 = 108;
+pub const KEYWORD_COMPUTE: u16 = 142;
+pub const KEYWORD_CONCURRENTLY: u16 = 123;
 pub const KEYWORD_CONFLICT: u16 = 0;
-pub const KEYWORD_CONNECTION: u16 = 94;
-pub const KEYWORD_CONSTRAINT: u16 = 54;
+pub const KEYWORD_CONNECTION: u16 = 96;
+pub const KEYWORD_CONSTRAINT: u16 = 56;
 pub const KEYWORD_COPY: u16 = 6;
 pub const KEYWORD_COST: u16 = 0;
 pub const KEYWORD_CREATE: u16 = 28;
 pub const KEYWORD_CROSS: u16 = 0;
 pub const KEYWORD_CSV: u16 = 0;
-pub const KEYWORD_CURRENT: u16 = 115;
-pub const KEYWORD_CURRENT_TIMESTAMP: u16 = 127;
-pub const KEYWORD_CYCLE: u16 = 101;
-pub const KEYWORD_DATA: u16 = 82;
-pub const KEYWORD_DATABASE: u16 = 86;
-pub const KEYWORD_DATE: u16 = 194;
-pub const KEYWORD_DATETIME: u16 = 195;
-pub const KEYWORD_DATETIME2: u16 = 196;
-pub const KEYWORD_DATETIMEOFFSET: u16 = 198;
-pub const KEYWORD_DECIMAL: u16 = 171;
+pub const KEYWORD_CURRENT: u16 = 117;
+pub const KEYWORD_CURRENT_TIMESTAMP: u16 = 135;
+pub const KEYWORD_CYCLE: u16 = 103;
+pub const KEYWORD_DATA: u16 = 84;
+pub const KEYWORD_DATABASE: u16 = 88;
+pub const KEYWORD_DATE: u16 = 202;
+pub const KEYWORD_DATETIME: u16 = 203;
+pub const KEYWORD_DATETIME2: u16 = 204;
+pub const KEYWORD_DATETIMEOFFSET: u16 = 206;
+pub const KEYWORD_DECIMAL: u16 = 179;
 pub const KEYWORD_DECLARE: u16 = 0;
-pub const KEYWORD_DEFAULT: u16 = 77;
+pub const KEYWORD_DEFAULT: u16 = 79;
 pub const KEYWORD_DELAYED: u16 = 0;
 pub const KEYWORD_DELETE: u16 = 4;
 pub const KEYWORD_DELIMITED: u16 = 0;
 pub const KEYWORD_DESC: u16 = 23;
-pub const KEYWORD_DISTINCT: u16 = 53;
+pub const KEYWORD_DISTINCT: u16 = 55;
 pub const KEYWORD_DO: u16 = 0;
-pub const KEYWORD_DOUBLE: u16 = 176;
+pub const KEYWORD_DOUBLE: u16 = 184;
 pub const KEYWORD_DROP: u16 = 35;
 pub const KEYWORD_ELSE: u16 = 0;
-pub const KEYWORD_ENCRYPTED: u16 = 91;
+pub const KEYWORD_ENCRYPTED: u16 = 93;
 pub const KEYWORD_END: u16 = 0;
-pub const KEYWORD_ENGINE: u16 = 76;
-pub const KEYWORD_ENUM: u16 = 193;
+pub const KEYWORD_ENGINE: u16 = 78;
+pub const KEYWORD_ENUM: u16 = 201;
 pub const KEYWORD_ESCAPED: u16 = 0;
 pub const KEYWORD_EXCEPT: u16 = 0;
 pub const KEYWORD_EXCLUDE: u16 = 0;
-pub const KEYWORD_EXISTS: u16 = 70;
+pub const KEYWORD_EXISTS: u16 = 72;
 pub const KEYWORD_EXPLAIN: u16 = 32;
-pub const KEYWORD_EXTERNAL: u16 = 133;
-pub const KEYWORD_FALSE: u16 = 147;
+pub const KEYWORD_EXTERNAL: u16 = 141;
+pub const KEYWORD_FALSE: u16 = 155;
 pub const KEYWORD_FIELDS: u16 = 0;
-pub const KEYWORD_FILTER: u16 = 55;
-pub const KEYWORD_FIRST: u16 = 110;
-pub const KEYWORD_FLOAT: u16 = 175;
+pub const KEYWORD_FILTER: u16 = 57;
+pub const KEYWORD_FIRST: u16 = 112;
+pub const KEYWORD_FLOAT: u16 = 183;
 pub const KEYWORD_FOLLOWING: u16 = 0;
-pub const KEYWORD_FOR: u16 = 68;
-pub const KEYWORD_FORCE: u16 = 65;
-pub const KEYWORD_FOREIGN: u16 = 118;
+pub const KEYWORD_FOR: u16 = 70;
+pub const KEYWORD_FORCE: u16 = 67;
+pub const KEYWORD_FOREIGN: u16 = 121;
 pub const KEYWORD_FORMAT: u16 = 0;
 pub const KEYWORD_FROM: u16 = 15;
 pub const KEYWORD_FULL: u16 = 16;
 pub const KEYWORD_FUNCTION: u16 = 0;
-pub const KEYWORD_GENERATED: u16 = 72;
-pub const KEYWORD_GEOGRAPHY: u16 = 206;
-pub const KEYWORD_GEOMETRY: u16 = 205;
-pub const KEYWORD_GIN: u16 = 0;
-pub const KEYWORD_GIST: u16 = 0;
+pub const KEYWORD_GENERATED: u16 = 74;
+pub const KEYWORD_GEOGRAPHY: u16 = 214;
+pub const KEYWORD_GEOMETRY: u16 = 213;
+pub const KEYWORD_GIN: u16 = 127;
+pub const KEYWORD_GIST: u16 = 125;
 pub const KEYWORD_GROUP: u16 = 20;
 pub const KEYWORD_GROUPS: u16 = 0;
-pub const KEYWORD_HASH: u16 = 0;
+pub const KEYWORD_HASH: u16 = 124;
 pub const KEYWORD_HAVING: u16 = 0;
 pub const KEYWORD_HIGH_PRIORITY: u16 = 0;
-pub const KEYWORD_HUGEINT: u16 = 170;
-pub const KEYWORD_IF: u16 = 69;
+pub const KEYWORD_HUGEINT: u16 = 178;
+pub const KEYWORD_IF: u16 = 71;
 pub const KEYWORD_IGNORE: u16 = 0;
-pub const KEYWORD_IMAGE: u16 = 152;
+pub const KEYWORD_IMAGE: u16 = 160;
 pub const KEYWORD_IMMUTABLE: u16 = 0;
-pub const KEYWORD_IN: u16 = 60;
+pub const KEYWORD_IN: u16 = 62;
 pub const KEYWORD_INCREMENT: u16 = 44;
-pub const KEYWORD_INCREMENTAL: u16 = 140;
-pub const KEYWORD_INDEX: u16 = 67;
-pub const KEYWORD_INET: u16 = 178;
+pub const KEYWORD_INCREMENTAL: u16 = 148;
+pub const KEYWORD_INDEX: u16 = 267;
+pub const KEYWORD_INET: u16 = 186;
 pub const KEYWORD_INNER: u16 = 0;
 pub const KEYWORD_INPUT: u16 = 0;
 pub const KEYWORD_INSERT: u16 = 5;
-pub const KEYWORD_INT: u16 = 278;
+pub const KEYWORD_INT: u16 = 290;
 pub const KEYWORD_INTERSECT: u16 = 0;
-pub const KEYWORD_INTERVAL: u16 = 204;
+pub const KEYWORD_INTERVAL: u16 = 212;
 pub const KEYWORD_INTO: u16 = 10;
-pub const KEYWORD_IS: u16 = 63;
+pub const KEYWORD_IS: u16 = 65;
 pub const KEYWORD_JOIN: u16 = 0;
-pub const KEYWORD_JSON: u16 = 189;
-pub const KEYWORD_JSONB: u16 = 190;
+pub const KEYWORD_JSON: u16 = 197;
+pub const KEYWORD_JSONB: u16 = 198;
 pub const KEYWORD_JSONFILE: u16 = 0;
 pub const KEYWORD_KEY: u16 = 51;
 pub const KEYWORD_LANGUAGE: u16 = 0;
-pub const KEYWORD_LAST: u16 = 113;
+pub const KEYWORD_LAST: u16 = 115;
 pub const KEYWORD_LATERAL: u16 = 0;
 pub const KEYWORD_LEAKPROOF: u16 = 0;
 pub const KEYWORD_LEFT: u16 = 0;
-pub const KEYWORD_LIKE: u16 = 259;
+pub const KEYWORD_LIKE: u16 = 271;
 pub const KEYWORD_LIMIT: u16 = 25;
 pub const KEYWORD_LINES: u16 = 0;
-pub const KEYWORD_LOCAL: u16 = 126;
+pub const KEYWORD_LOCAL: u16 = 134;
 pub const KEYWORD_LOCATION: u16 = 0;
-pub const KEYWORD_LOGGED: u16 = 100;
+pub const KEYWORD_LOGGED: u16 = 102;
 pub const KEYWORD_LOW_PRIORITY: u16 = 0;
 pub const KEYWORD_MATCHED: u16 = 13;
 pub const KEYWORD_MATERIALIZED: u16 = 0;
 pub const KEYWORD_MAX: u16 = 0;
 pub const KEYWORD_MAXVALUE: u16 = 46;
-pub const KEYWORD_MEDIUMINT: u16 = 277;
+pub const KEYWORD_MEDIUMINT: u16 = 289;
 pub const KEYWORD_MERGE: u16 = 9;
-pub const KEYWORD_METADATA: u16 = 143;
+pub const KEYWORD_METADATA: u16 = 151;
 pub const KEYWORD_MIN: u16 = 0;
 pub const KEYWORD_MINVALUE: u16 = 45;
 pub const KEYWORD_MODIFY: u16 = 34;
-pub const KEYWORD_MONEY: u16 = 179;
-pub const KEYWORD_NAME: u16 = 210;
-pub const KEYWORD_NCHAR: u16 = 183;
-pub const KEYWORD_NO: u16 = 81;
+pub const KEYWORD_MONEY: u16 = 187;
+pub const KEYWORD_NAME: u16 = 218;
+pub const KEYWORD_NCHAR: u16 = 191;
+pub const KEYWORD_NO: u16 = 83;
 pub const KEYWORD_NONE: u16 = 47;
-pub const KEYWORD_NOSCAN: u16 = 144;
-pub const KEYWORD_NOT: u16 = 64;
+pub const KEYWORD_NOSCAN: u16 = 152;
+pub const KEYWORD_NOT: u16 = 66;
 pub const KEYWORD_NOTHING: u16 = 0;
-pub const KEYWORD_NOWAIT: u16 = 130;
-pub const KEYWORD_NULL: u16 = 145;
-pub const KEYWORD_NULLS: u16 = 109;
-pub const KEYWORD_NUMERIC: u16 = 172;
-pub const KEYWORD_NVARCHAR: u16 = 185;
+pub const KEYWORD_NOWAIT: u16 = 138;
+pub const KEYWORD_NULL: u16 = 153;
+pub const KEYWORD_NULLS: u16 = 111;
+pub const KEYWORD_NUMERIC: u16 = 180;
+pub const KEYWORD_NVARCHAR: u16 = 193;
+pub const KEYWORD_OF: u16 = 53;
 pub const KEYWORD_OFFSET: u16 = 26;
-pub const KEYWORD_OID: u16 = 209;
+pub const KEYWORD_OID: u16 = 217;
 pub const KEYWORD_ON: u16 = 17;
-pub const KEYWORD_ONLY: u16 = 116;
-pub const KEYWORD_OPTIMIZE: u16 = 137;
+pub const KEYWORD_ONLY: u16 = 119;
+pub const KEYWORD_OPTIMIZE: u16 = 145;
 pub const KEYWORD_OPTION: u16 = 0;
 pub const KEYWORD_OPTIONS: u16 = 0;
-pub const KEYWORD_OR: u16 = 62;
+pub const KEYWORD_OR: u16 = 64;
 pub const KEYWORD_ORC: u16 = 0;
 pub const KEYWORD_ORDER: u16 = 19;
 pub const KEYWORD_OTHERS: u16 = 0;
@@ -351,183 +353,188 @@ pub const KEYWORD_OUTER: u16 = 0;
 pub const KEYWORD_OVER: u16 = 0;
 pub const KEYWORD_OVERWRITE: u16 = 0;
 pub const KEYWORD_OWNED: u16 = 48;
-pub const KEYWORD_OWNER: u16 = 88;
-pub const KEYWORD_PARALLEL: u16 = 132;
+pub const KEYWORD_OWNER: u16 = 90;
+pub const KEYWORD_PARALLEL: u16 = 140;
 pub const KEYWORD_PARQUET: u16 = 0;
 pub const KEYWORD_PARTITION: u16 = 21;
 pub const KEYWORD_PARTITIONED: u16 = 0;
-pub const KEYWORD_PASSWORD: u16 = 90;
+pub const KEYWORD_PASSWORD: u16 = 92;
 pub const KEYWORD_PLPGSQL: u16 = 0;
 pub const KEYWORD_PRECEDING: u16 = 0;
-pub const KEYWORD_PRECISION: u16 = 177;
+pub const KEYWORD_PRECISION: u16 = 185;
 pub const KEYWORD_PRESERVE: u16 = 0;
 pub const KEYWORD_PRIMARY: u16 = 27;
 pub const KEYWORD_RANGE: u16 = 0;
 pub const KEYWORD_RCFILE: u16 = 0;
-pub const KEYWORD_REAL: u16 = 280;
+pub const KEYWORD_REAL: u16 = 292;
 pub const KEYWORD_RECURSIVE: u16 = 0;
-pub const KEYWORD_REFERENCES: u16 = 119;
-pub const KEYWORD_REGCLASS: u16 = 211;
-pub const KEYWORD_REGNAMESPACE: u16 = 212;
-pub const KEYWORD_REGPROC: u16 = 213;
-pub const KEYWORD_REGTYPE: u16 = 214;
-pub const KEYWORD_RENAME: u16 = 84;
+pub const KEYWORD_REFERENCES: u16 = 122;
+pub const KEYWORD_REGCLASS: u16 = 219;
+pub const KEYWORD_REGNAMESPACE: u16 = 220;
+pub const KEYWORD_REGPROC: u16 = 221;
+pub const KEYWORD_REGTYPE: u16 = 222;
+pub const KEYWORD_RENAME: u16 = 86;
 pub const KEYWORD_REPLACE: u16 = 0;
 pub const KEYWORD_REPLICATION: u16 = 0;
-pub const KEYWORD_RESET: u16 = 96;
+pub const KEYWORD_RESET: u16 = 98;
 pub const KEYWORD_RESTART: u16 = 50;
-pub const KEYWORD_RESTRICT: u16 = 79;
+pub const KEYWORD_RESTRICT: u16 = 81;
 pub const KEYWORD_RESTRICTED: u16 = 0;
 pub const KEYWORD_RETURN: u16 = 0;
 pub const KEYWORD_RETURNING: u16 = 0;
 pub const KEYWORD_RETURNS: u16 = 0;
-pub const KEYWORD_REWRITE: u16 = 138;
+pub const KEYWORD_REWRITE: u16 = 146;
 pub const KEYWORD_RIGHT: u16 = 0;
-pub const KEYWORD_ROLE: u16 = 95;
-pub const KEYWORD_ROLLBACK: u16 = 107;
-pub const KEYWORD_ROW: u16 = 0;
+pub const KEYWORD_ROLE: u16 = 97;
+pub const KEYWORD_ROLLBACK: u16 = 109;
+pub const KEYWORD_ROW: u16 = 118;
 pub const KEYWORD_ROWS: u16 = 0;
 pub const KEYWORD_SAFE: u16 = 0;
-pub const KEYWORD_SCHEMA: u16 = 87;
+pub const KEYWORD_SCHEMA: u16 = 89;
 pub const KEYWORD_SELECT: u16 = 3;
-pub const KEYWORD_SEPARATOR: u16 = 57;
+pub const KEYWORD_SEPARATOR: u16 = 59;
 pub const KEYWORD_SEQUENCE: u16 = 43;
 pub const KEYWORD_SEQUENCEFILE: u16 = 0;
-pub const KEYWORD_SERIAL: u16 = 273;
+pub const KEYWORD_SERIAL: u16 = 285;
 pub const KEYWORD_SET: u16 = 14;
 pub const KEYWORD_SETOF: u16 = 0;
-pub const KEYWORD_SIMILAR: u16 = 123;
-pub const KEYWORD_SMALLDATETIME: u16 = 197;
-pub const KEYWORD_SMALLINT: u16 = 276;
-pub const KEYWORD_SMALLMONEY: u16 = 180;
-pub const KEYWORD_SMALLSERIAL: u16 = 272;
-pub const KEYWORD_SOME: u16 = 104;
+pub const KEYWORD_SIMILAR: u16 = 131;
+pub const KEYWORD_SMALLDATETIME: u16 = 205;
+pub const KEYWORD_SMALLINT: u16 = 288;
+pub const KEYWORD_SMALLMONEY: u16 = 188;
+pub const KEYWORD_SMALLSERIAL: u16 = 284;
+pub const KEYWORD_SOME: u16 = 106;
 pub const KEYWORD_SORT: u16 = 0;
-pub const KEYWORD_SPGIST: u16 = 0;
+pub const KEYWORD_SPGIST: u16 = 126;
 pub const KEYWORD_SQL: u16 = 0;
 pub const KEYWORD_STABLE: u16 = 0;
 pub const KEYWORD_START: u16 = 49;
-pub const KEYWORD_STATISTICS: u16 = 136;
-pub const KEYWORD_STATS: u16 = 135;
+pub const KEYWORD_STATISTICS: u16 = 144;
+pub const KEYWORD_STATS: u16 = 143;
 pub const KEYWORD_STORED: u16 = 0;
 pub const KEYWORD_STRICT: u16 = 0;
-pub const KEYWORD_STRING: u16 = 187;
+pub const KEYWORD_STRING: u16 = 195;
 pub const KEYWORD_SUPPORT: u16 = 0;
+pub const KEYWORD_SYSTEM: u16 = 54;
 pub const KEYWORD_TABLE: u16 = 37;
 pub const KEYWORD_TABLES: u16 = 38;
 pub const KEYWORD_TABLESPACE: u16 = 42;
 pub const KEYWORD_TBLPROPERTIES: u16 = 0;
-pub const KEYWORD_TEMP: u16 = 97;
-pub const KEYWORD_TEMPORARY: u16 = 98;
+pub const KEYWORD_TEMP: u16 = 99;
+pub const KEYWORD_TEMPORARY: u16 = 100;
 pub const KEYWORD_TERMINATED: u16 = 0;
-pub const KEYWORD_TEXT: u16 = 186;
+pub const KEYWORD_TEXT: u16 = 194;
 pub const KEYWORD_TEXTFILE: u16 = 0;
-pub const KEYWORD_THEN: u16 = 59;
+pub const KEYWORD_THEN: u16 = 61;
 pub const KEYWORD_TIES: u16 = 0;
-pub const KEYWORD_TIME: u16 = 283;
-pub const KEYWORD_TIMESTAMP_BASE: u16 = 284;
-pub const KEYWORD_TIMESTAMPTZ_BASE: u16 = 285;
-pub const KEYWORD_TINYINT: u16 = 275;
-pub const KEYWORD_TO: u16 = 85;
-pub const KEYWORD_TRANSACTION: u16 = 108;
+pub const KEYWORD_TIME: u16 = 295;
+pub const KEYWORD_TIMESTAMP_BASE: u16 = 296;
+pub const KEYWORD_TIMESTAMPTZ_BASE: u16 = 297;
+pub const KEYWORD_TINYINT: u16 = 287;
+pub const KEYWORD_TO: u16 = 87;
+pub const KEYWORD_TRANSACTION: u16 = 110;
 pub const KEYWORD_TRIGGER: u16 = 0;
-pub const KEYWORD_TRUE: u16 = 146;
+pub const KEYWORD_TRUE: u16 = 154;
 pub const KEYWORD_TRUNCATE: u16 = 8;
-pub const KEYWORD_TYPE: u16 = 83;
+pub const KEYWORD_TYPE: u16 = 85;
 pub const KEYWORD_UNBOUNDED: u16 = 0;
 pub const KEYWORD_UNCACHED: u16 = 0;
 pub const KEYWORD_UNION: u16 = 0;
-pub const KEYWORD_UNIQUE: u16 = 117;
-pub const KEYWORD_UNLOGGED: u16 = 99;
+pub const KEYWORD_UNIQUE: u16 = 120;
+pub const KEYWORD_UNLOGGED: u16 = 101;
 pub const KEYWORD_UNSAFE: u16 = 0;
-pub const KEYWORD_UNSIGNED: u16 = 124;
-pub const KEYWORD_UNTIL: u16 = 93;
+pub const KEYWORD_UNSIGNED: u16 = 132;
+pub const KEYWORD_UNTIL: u16 = 95;
 pub const KEYWORD_UPDATE: u16 = 7;
 pub const KEYWORD_USE: u16 = 0;
-pub const KEYWORD_USER: u16 = 89;
-pub const KEYWORD_USING: u16 = 66;
-pub const KEYWORD_UUID: u16 = 188;
-pub const KEYWORD_VACUUM: u16 = 128;
-pub const KEYWORD_VALID: u16 = 92;
+pub const KEYWORD_USER: u16 = 91;
+pub const KEYWORD_USING: u16 = 68;
+pub const KEYWORD_UUID: u16 = 196;
+pub const KEYWORD_VACUUM: u16 = 136;
+pub const KEYWORD_VALID: u16 = 94;
 pub const KEYWORD_VALUE: u16 = 12;
 pub const KEYWORD_VALUES: u16 = 11;
-pub const KEYWORD_VARBINARY: u16 = 151;
-pub const KEYWORD_VARCHAR: u16 = 282;
-pub const KEYWORD_VARYING: u16 = 181;
+pub const KEYWORD_VARBINARY: u16 = 159;
+pub const KEYWORD_VARCHAR: u16 = 294;
+pub const KEYWORD_VARYING: u16 = 189;
 pub const KEYWORD_VERBOSE: u16 = 33;
 pub const KEYWORD_VIEW: u16 = 39;
 pub const KEYWORD_VOLATILE: u16 = 0;
-pub const KEYWORD_WAIT: u16 = 129;
-pub const KEYWORD_WHEN: u16 = 58;
+pub const KEYWORD_WAIT: u16 = 137;
+pub const KEYWORD_WHEN: u16 = 60;
 pub const KEYWORD_WHERE: u16 = 18;
 pub const KEYWORD_WINDOW: u16 = 0;
-pub const KEYWORD_WITH: u16 = 258;
-pub const KEYWORD_XML: u16 = 191;
-pub const KEYWORD_ZEROFILL: u16 = 125;
+pub const KEYWORD_WITH: u16 = 269;
+pub const KEYWORD_XML: u16 = 199;
+pub const KEYWORD_ZEROFILL: u16 = 133;
 pub const LATERAL_CROSS_JOIN: u16 = 0;
 pub const LATERAL_JOIN: u16 = 0;
-pub const LIMIT: u16 = 414;
-pub const LIST: u16 = 422;
-pub const LITERAL: u16 = 423;
-pub const LITERAL_STRING: u16 = 426;
-pub const MARGINALIA: u16 = 314;
-pub const MEDIUMINT: u16 = 292;
-pub const MODIFY_COLUMN: u16 = 343;
-pub const NATURAL_NUMBER: u16 = 253;
-pub const NCHAR: u16 = 305;
-pub const NOT_DISTINCT_FROM: u16 = 265;
-pub const NOT_IN: u16 = 420;
-pub const NOT_LIKE: u16 = 261;
-pub const NOT_SIMILAR_TO: u16 = 263;
-pub const NUMERIC: u16 = 302;
-pub const NVARCHAR: u16 = 306;
-pub const OBJECT_REFERENCE: u16 = 369;
-pub const OFFSET: u16 = 415;
-pub const ORDER_BY: u16 = 412;
-pub const ORDER_TARGET: u16 = 413;
+pub const LIMIT: u16 = 430;
+pub const LIST: u16 = 440;
+pub const LITERAL: u16 = 441;
+pub const LITERAL_STRING: u16 = 444;
+pub const MARGINALIA: u16 = 328;
+pub const MEDIUMINT: u16 = 306;
+pub const MODIFY_COLUMN: u16 = 358;
+pub const NATURAL_NUMBER: u16 = 263;
+pub const NCHAR: u16 = 319;
+pub const NOT_DISTINCT_FROM: u16 = 277;
+pub const NOT_IN: u16 = 438;
+pub const NOT_LIKE: u16 = 273;
+pub const NOT_SIMILAR_TO: u16 = 275;
+pub const NUMERIC: u16 = 316;
+pub const NVARCHAR: u16 = 320;
+pub const OBJECT_REFERENCE: u16 = 384;
+pub const OFFSET: u16 = 431;
+pub const ORDER_BY: u16 = 428;
+pub const ORDER_TARGET: u16 = 429;
 pub const ORDERED_COLUMN: u16 = 0;
-pub const ORDERED_COLUMNS: u16 = 398;
-pub const PARAMETER: u16 = 401;
+pub const ORDERED_COLUMNS: u16 = 413;
+pub const PARAMETER: u16 = 416;
 pub const PARTITION_BY: u16 = 0;
-pub const PRIMARY_KEY_CONSTRAINT: u16 = 396;
-pub const PROGRAM: u16 = 256;
-pub const QUALIFIED_FIELD: u16 = 403;
-pub const RELATION: u16 = 410;
-pub const RENAME_COLUMN: u16 = 347;
-pub const RENAME_OBJECT: u16 = 366;
+pub const PRIMARY_KEY_CONSTRAINT: u16 = 411;
+pub const PROGRAM: u16 = 266;
+pub const QUALIFIED_FIELD: u16 = 418;
+pub const RECORD_FIELD: u16 = 301;
+pub const RECORD_TYPE: u16 = 300;
+pub const RELATION: u16 = 426;
+pub const RENAME_COLUMN: u16 = 362;
+pub const RENAME_OBJECT: u16 = 381;
 pub const RETURNING: u16 = 0;
-pub const ROLLBACK_TRANSACTION: u16 = 318;
+pub const ROLLBACK_TRANSACTION: u16 = 332;
+pub const ROW: u16 = 433;
 pub const ROW_FORMAT: u16 = 0;
-pub const SELECT: u16 = 328;
-pub const SELECT_EXPRESSION: u16 = 329;
-pub const SELECT_STATEMENT: u16 = 327;
-pub const SET_CONFIGURATION: u16 = 352;
+pub const SELECT: u16 = 342;
+pub const SELECT_EXPRESSION: u16 = 343;
+pub const SELECT_STATEMENT: u16 = 341;
+pub const SET_CONFIGURATION: u16 = 367;
 pub const SET_OPERATION: u16 = 0;
-pub const SET_SCHEMA: u16 = 367;
-pub const SET_VALUES: u16 = 373;
-pub const SIMILAR_TO: u16 = 262;
-pub const SMALLINT: u16 = 291;
-pub const STATEMENT: u16 = 319;
-pub const STATEMENT_TRANSACTION: u16 = 315;
+pub const SET_SCHEMA: u16 = 382;
+pub const SET_VALUES: u16 = 388;
+pub const SIMILAR_TO: u16 = 274;
+pub const SMALLINT: u16 = 305;
+pub const STATEMENT: u16 = 333;
+pub const STATEMENT_TRANSACTION: u16 = 329;
 pub const STORAGE_LOCATION: u16 = 0;
 pub const STORED_AS: u16 = 0;
-pub const SUBQUERY: u16 = 421;
-pub const TABLE_OPTION: u16 = 386;
+pub const SUBQUERY: u16 = 439;
+pub const SUBSCRIPT: u16 = 434;
+pub const TABLE_OPTION: u16 = 401;
 pub const TABLE_PARTITION: u16 = 0;
 pub const TABLE_SORT: u16 = 0;
-pub const TERM: u16 = 330;
-pub const TIME: u16 = 308;
-pub const TIMESTAMP: u16 = 309;
-pub const TIMESTAMPTZ: u16 = 310;
-pub const TINYINT: u16 = 290;
-pub const TYPED_ROW_VALUE_EXPR_LIST: u16 = 372;
-pub const UNARY_EXPRESSION: u16 = 418;
-pub const UPDATE_STATEMENT: u16 = 376;
+pub const TERM: u16 = 344;
+pub const TIME: u16 = 322;
+pub const TIMESTAMP: u16 = 323;
+pub const TIMESTAMPTZ: u16 = 324;
+pub const TINYINT: u16 = 304;
+pub const TYPED_ROW_VALUE_EXPR_LIST: u16 = 387;
+pub const UNARY_EXPRESSION: u16 = 436;
+pub const UPDATE_STATEMENT: u16 = 391;
 pub const VALUES: u16 = 0;
-pub const VARBINARY: u16 = 298;
-pub const VARCHAR: u16 = 304;
-pub const WHEN_CLAUSE: u16 = 378;
-pub const WHERE: u16 = 411;
+pub const VARBINARY: u16 = 312;
+pub const VARCHAR: u16 = 318;
+pub const WHEN_CLAUSE: u16 = 393;
+pub const WHERE: u16 = 427;
 pub const WINDOW_CLAUSE: u16 = 0;
 pub const WINDOW_FRAME: u16 = 0;
 pub const WINDOW_FUNCTION: u16 = 0;
@@ -68,6 +68,7 @@ pub const S_ALTER_TYPE: &str = "alter_type";
 pub const S_ALTER_VIEW: &str = "alter_view";
 pub const S_ARRAY: &str = "array";
 pub const S_ARRAY_SIZE_DEFINITION: &str = "array_size_definition";
+pub const S_AS_OF: &str = "as_of";
 pub const S_ASSIGNMENT: &str = "assignment";
 pub const S_BANG: &str = "bang";
 pub const S_BEGIN_TRANSACTION: &str = "begin_transaction";
@@ -336,6 +337,7 @@ pub const S_KEYWORD_NULL: &str = "keyword_null";
 pub const S_KEYWORD_NULLS: &str = "keyword_nulls";
 pub const S_KEYWORD_NUMERIC: &str = "keyword_numeric";
 pub const S_KEYWORD_NVARCHAR: &str = "keyword_nvarchar";
+pub const S_KEYWORD_OF: &str = "keyword_of";
 pub const S_KEYWORD_OFFSET: &str = "keyword_offset";
 pub const S_KEYWORD_OID: &str = "keyword_oid";
 pub const S_KEYWORD_ON: &str = "keyword_on";
@@ -413,6 +415,7 @@ pub const S_KEYWORD_STORED: &str = "keyword_stored";
 pub const S_KEYWORD_STRICT: &str = "keyword_strict";
 pub const S_KEYWORD_STRING: &str = "keyword_string";
 pub const S_KEYWORD_SUPPORT: &str = "keyword_support";
+pub const S_KEYWORD_SYSTEM: &str = "keyword_system";
 pub const S_KEYWORD_TABLE: &str = "keyword_table";
 pub const S_KEYWORD_TABLES: &str = "keyword_tables";
 pub const S_KEYWORD_TABLESPACE: &str = "keyword_tablespace";
@@ -492,11 +495,14 @@ pub const S_PARTITION_BY: &str = "partition_by";
 pub const S_PRIMARY_KEY_CONSTRAINT: &str = "primary_key_constraint";
 pub const S_PROGRAM: &str = "program";
 pub const S_QUALIFIED_FIELD: &str = "qualified_field";
+pub const S_RECORD_FIELD: &str = "record_field";
+pub const S_RECORD_TYPE: &str = "record_type";
 pub const S_RELATION: &str = "relation";
 pub const S_RENAME_COLUMN: &str = "rename_column";
 pub const S_RENAME_OBJECT: &str = "rename_object";
 pub const S_RETURNING: &str = "returning";
 pub const S_ROLLBACK_TRANSACTION: &str = "rollback_transaction";
+pub const S_ROW: &str = "row";
 pub const S_ROW_FORMAT: &str = "row_format";
 pub const S_SELECT: &str = "select";
 pub const S_SELECT_EXPRESSION: &str = "select_expression";
@@ -512,6 +518,7 @@ pub const S_STATEMENT_TRANSACTION: &str = "statement_transaction";
 pub const S_STORAGE_LOCATION: &str = "storage_location";
 pub const S_STORED_AS: &str = "stored_as";
 pub const S_SUBQUERY: &str = "subquery";
+pub const S_SUBSCRIPT: &str = "subscript";
 pub const S_TABLE_OPTION: &str = "table_option";
 pub const S_TABLE_PARTITION: &str = "table_partition";
 pub const S_TABLE_SORT: &str = "table_sort";
@@ -373,6 +373,11 @@ pub const ARRAY_SEQ_SYMBOL: usize = 0;
 pub const ARRAY_SIZE_DEFINITIO_SEQ_CHOICE: usize = 0;
 pub const ARRAY_SIZE_DEFINITIO_SEQ_CHOICE_SEQ_CHOICE: usize = 1;
 pub const ARRAY_SIZE_DEFINITIO_SEQ_CHOICE_SEQ_SYMBOL: usize = 0;
+pub const AS_OF_SEQ_FIELD: usize = 4;
+pub const AS_OF_SEQ_SYMBOL_0: usize = 0;
+pub const AS_OF_SEQ_SYMBOL_1: usize = 1;
+pub const AS_OF_SEQ_SYMBOL_2: usize = 2;
+pub const AS_OF_SEQ_SYMBOL_3: usize = 3;
 pub const ASSIGNMENT_SEQ_FIELD_0: usize = 0;
 pub const ASSIGNMENT_SEQ_FIELD_2: usize = 2;
 pub const ASSIGNMENT_SEQ_STRING: usize = 1;
@@ -447,6 +452,8 @@ pub const CHAR_SEQ_SYMBOL: usize = 0;
 pub const COLUMN_CONSTRAINT_CHOICE_SEQ_CHOICE: usize = 0;
 pub const COLUMN_CONSTRAINT_CHOICE_SEQ_CHOICE_SEQ_SYMBOL_0: usize = 0;
 pub const COLUMN_CONSTRAINT_CHOICE_SEQ_CHOICE_SEQ_SYMBOL_1: usize = 1;
+pub const COLUMN_CONSTRAINT_CHOICE_SEQ_FIELD: usize = 1;
+pub const COLUMN_CONSTRAINT_CHOICE_SEQ_SYMBOL_0: usize = 0;
 pub const COLUMN_CONSTRAINT_CHOICE_SEQ_SYMBOL_1: usize = 1;
 pub const COLUMN_CONSTRAINT_CHOICE_SEQ_SYMBOL_2: usize = 2;
 pub const COLUMN_DEFINITION_SEQ_FIELD_0: usize = 0;
@@ -516,8 +523,8 @@ pub const CREATE_INDEX_SEQ_SEQ: usize = 7;
 pub const CREATE_INDEX_SEQ_SEQ_CHOICE: usize = 1;
 pub const CREATE_INDEX_SEQ_SEQ_CHOICE_SEQ_CHOICE: usize = 1;
 pub const CREATE_INDEX_SEQ_SEQ_CHOICE_SEQ_SYMBOL: usize = 0;
-pub const CREATE_INDEX_SEQ_SEQ_SYMBOL_0: usize = 0;
-pub const CREATE_INDEX_SEQ_SEQ_SYMBOL_2: usize = 2;
+pub const CREATE_INDEX_SEQ_SEQ_FIELD_0: usize = 0;
+pub const CREATE_INDEX_SEQ_SEQ_FIELD_2: usize = 2;
 pub const CREATE_INDEX_SEQ_SYMBOL_0: usize = 0;
 pub const CREATE_INDEX_SEQ_SYMBOL_2: usize = 2;
 pub const CREATE_INDEX_SEQ_SYMBOL_5: usize = 5;
@@ -777,6 +784,8 @@ pub const FRAME_DEFINITION_SEQ_CHOICE_SEQ_SYMBOL_0: usize = 0;
 pub const FRAME_DEFINITION_SEQ_CHOICE_SEQ_SYMBOL_1: usize = 1;
 pub const FROM_SEQ_CHOICE_1: usize = 1;
 pub const FROM_SEQ_CHOICE_3: usize = 3;
+pub const FROM_SEQ_CHOICE_4: usize = 4;
+pub const FROM_SEQ_CHOICE_5: usize = 5;
 pub const FROM_SEQ_FIELD: usize = 2;
 pub const FROM_SEQ_SYMBOL: usize = 0;
 pub const FUNCTION_BODY_CHOICE_SEQ_ALIAS: usize = 1;
@@ -988,14 +997,19 @@ pub const ORDER_BY_PREC_RIGHT_SEQ_SEQ_REPEAT_SEQ_SYMBOL: usize = 1;
 pub const ORDER_BY_PREC_RIGHT_SEQ_SEQ_SYMBOL: usize = 0;
 pub const ORDER_BY_PREC_RIGHT_SEQ_SYMBOL_0: usize = 0;
 pub const ORDER_BY_PREC_RIGHT_SEQ_SYMBOL_1: usize = 1;
-pub const ORDER_TARGET_SEQ_CHOICE: usize = 1;
-pub const ORDER_TARGET_SEQ_CHOICE_SEQ_CHOICE_0: usize = 0;
-pub const ORDER_TARGET_SEQ_CHOICE_SEQ_CHOICE_1: usize = 1;
+pub const ORDER_TARGET_SEQ_CHOICE_1: usize = 1;
+pub const ORDER_TARGET_SEQ_CHOICE_2: usize = 2;
+pub const ORDER_TARGET_SEQ_CHOICE_SEQ_CHOICE: usize = 0;
 pub const ORDER_TARGET_SEQ_CHOICE_SEQ_CHOICE_SEQ_CHOICE: usize = 1;
 pub const ORDER_TARGET_SEQ_CHOICE_SEQ_CHOICE_SEQ_SYMBOL: usize = 0;
-pub const ORDER_TARGET_SEQ_SYMBOL: usize = 0;
-pub const ORDERED_COLUMN_SEQ_CHOICE: usize = 1;
-pub const ORDERED_COLUMN_SEQ_FIELD: usize = 0;
+pub const ORDER_TARGET_SEQ_CHOICE_SEQ_FIELD: usize = 1;
+pub const ORDER_TARGET_SEQ_CHOICE_SEQ_SYMBOL: usize = 0;
+pub const ORDER_TARGET_SEQ_FIELD: usize = 0;
+pub const ORDERED_COLUMN_SEQ_CHOICE_0: usize = 0;
+pub const ORDERED_COLUMN_SEQ_CHOICE_1: usize = 1;
+pub const ORDERED_COLUMN_SEQ_CHOICE_SEQ_FIELD: usize = 1;
+pub const ORDERED_COLUMN_SEQ_CHOICE_SEQ_STRING_0: usize = 0;
+pub const ORDERED_COLUMN_SEQ_CHOICE_SEQ_STRING_2: usize = 2;
 pub const ORDERED_COLUMNS_SEQ_SEQ: usize = 1;
 pub const ORDERED_COLUMNS_SEQ_SEQ_ALIAS: usize = 0;
 pub const ORDERED_COLUMNS_SEQ_SEQ_REPEAT: usize = 1;
@@ -1023,6 +1037,17 @@ pub const QUALIFIED_FIELD_SEQ_CHOICE_SEQ_PREC_RIGHT_CHOICE_SEQ_STRING_2: usize =
 pub const QUALIFIED_FIELD_SEQ_CHOICE_SEQ_PREC_RIGHT_CHOICE_SEQ_SYMBOL: usize = 1;
 pub const QUALIFIED_FIELD_SEQ_CHOICE_SEQ_STRING: usize = 1;
 pub const QUALIFIED_FIELD_SEQ_FIELD: usize = 1;
+pub const RECORD_FIELD_SEQ_FIELD_0: usize = 0;
+pub const RECORD_FIELD_SEQ_FIELD_1: usize = 1;
+pub const RECORD_TYPE_SEQ_SEQ: usize = 1;
+pub const RECORD_TYPE_SEQ_SEQ_SEQ: usize = 1;
+pub const RECORD_TYPE_SEQ_SEQ_SEQ_FIELD: usize = 0;
+pub const RECORD_TYPE_SEQ_SEQ_SEQ_REPEAT: usize = 1;
+pub const RECORD_TYPE_SEQ_SEQ_SEQ_REPEAT_SEQ_FIELD: usize = 1;
+pub const RECORD_TYPE_SEQ_SEQ_SEQ_REPEAT_SEQ_STRING: usize = 0;
+pub const RECORD_TYPE_SEQ_SEQ_STRING_0: usize = 0;
+pub const RECORD_TYPE_SEQ_SEQ_STRING_2: usize = 2;
+pub const RECORD_TYPE_SEQ_SYMBOL: usize = 0;
 pub const RENAME_COLUMN_SEQ_CHOICE: usize = 1;
 pub const RENAME_COLUMN_SEQ_FIELD_2: usize = 2;
 pub const RENAME_COLUMN_SEQ_FIELD_4: usize = 4;
@@ -1035,6 +1060,15 @@ pub const RETURNING_SEQ_SYMBOL_0: usize = 0;
 pub const RETURNING_SEQ_SYMBOL_1: usize = 1;
 pub const ROLLBACK_TRANSACTION_SEQ_CHOICE: usize = 1;
 pub const ROLLBACK_TRANSACTION_SEQ_SYMBOL: usize = 0;
+pub const ROW_SEQ_SEQ: usize = 1;
+pub const ROW_SEQ_SEQ_CHOICE: usize = 1;
+pub const ROW_SEQ_SEQ_CHOICE_SEQ_FIELD: usize = 0;
+pub const ROW_SEQ_SEQ_CHOICE_SEQ_REPEAT: usize = 1;
+pub const ROW_SEQ_SEQ_CHOICE_SEQ_REPEAT_SEQ_FIELD: usize = 1;
+pub const ROW_SEQ_SEQ_CHOICE_SEQ_REPEAT_SEQ_STRING: usize = 0;
+pub const ROW_SEQ_SEQ_STRING_0: usize = 0;
+pub const ROW_SEQ_SEQ_STRING_2: usize = 2;
+pub const ROW_SEQ_SYMBOL: usize = 0;
 pub const ROW_FORMAT_SEQ_CHOICE_3: usize = 3;
 pub const ROW_FORMAT_SEQ_CHOICE_4: usize = 4;
 pub const ROW_FORMAT_SEQ_CHOICE_SEQ_CHOICE: usize = 4;
@@ -1101,6 +1135,10 @@ pub const STORED_AS_SEQ_SYMBOL_1: usize = 1;
 pub const SUBQUERY_SEQ_STRING_0: usize = 0;
 pub const SUBQUERY_SEQ_STRING_2: usize = 2;
 pub const SUBQUERY_SEQ_SYMBOL: usize = 1;
+pub const SUBSCRIPT_PREC_LEFT_SEQ_FIELD_0: usize = 0;
+pub const SUBSCRIPT_PREC_LEFT_SEQ_FIELD_2: usize = 2;
+pub const SUBSCRIPT_PREC_LEFT_SEQ_STRING_1: usize = 1;
+pub const SUBSCRIPT_PREC_LEFT_SEQ_STRING_3: usize = 3;
 pub const TABLE_OPTION_CHOICE_SEQ_FIELD_0: usize = 0;
 pub const TABLE_OPTION_CHOICE_SEQ_FIELD_2: usize = 2;
 pub const TABLE_OPTION_CHOICE_SEQ_STRING: usize = 1;
@@ -3,8 +3,11 @@ use mudu::common::result::RS;
 use mudu_binding::universal::uni_session_open_argv::UniSessionOpenArgv;
 use mudu_contract::database::entity::Entity;
 use mudu_contract::database::entity_set::RecordSet;
+use mudu_contract::database::kv_namespace::{KvNamespaceOp, KvNamespaceOpResult};
+use mudu_contract::database::kv_range::{KvRangeOptions, KvRangePage};
 use mudu_contract::database::sql_params::SQLParams;
 use mudu_contract::database::sql_stmt::SQLStmt;
+use std::time::Duration;
 
 /// Execute a query against the session.
 pub async fn mudu_query<R: Entity>(
@@ -50,6 +53,51 @@ pub async fn mudu_put(session_id: OID, key: &[u8], value: &[u8]) -> RS<()> {
     crate::inner_component_async::inner_put(session_id, key, value).await
 }
 
+/// Store a key-value pair that expires `ttl` after the write commits.
+pub async fn mudu_put_with_ttl(session_id: OID, key: &[u8], value: &[u8], ttl: Duration) -> RS<()> {
+    crate::inner_component_async::inner_put_with_ttl(session_id, key, value, ttl).await
+}
+
+/// Delete a key against the session.
+pub async fn mudu_delete(session_id: OID, key: &[u8]) -> RS<()> {
+    crate::inner_component_async::inner_delete(session_id, key).await
+}
+
+/// Store several key-value pairs against the session as one unit.
+pub async fn mudu_put_many(session_id: OID, items: &[(Vec<u8>, Vec<u8>)]) -> RS<()> {
+    crate::inner_component_async::inner_put_many(session_id, items).await
+}
+
+/// Delete several keys against the session as one unit.
+pub async fn mudu_delete_many(session_id: OID, keys: &[Vec<u8>]) -> RS<()> {
+    crate::inner_component_async::inner_delete_many(session_id, keys).await
+}
+
+/// Replace the value of a key when it equals `expected` (`None`: the key is absent).
+pub async fn mudu_cas(
+    session_id: OID,
+    key: &[u8],
+    expected: Option<&[u8]>,
+    value: &[u8],
+) -> RS<bool> {
+    crate::inner_component_async::inner_cas(session_id, key, expected, value).await
+}
+
+/// Add `delta` to the integer counter stored at a key and return the new value.
+pub async fn mudu_incr(session_id: OID, key: &[u8], delta: i64) -> RS<i64> {
+    crate::inner_component_async::inner_incr(session_id, key, delta).await
+}
+
+/// Store a key-value pair only when the key is absent.
+pub async fn mudu_put_if_absent(session_id: OID, key: &[u8], value: &[u8]) -> RS<bool> {
+    crate::inner_component_async::inner_put_if_absent(session_id, key, value).await
+}
+
+/// Run an operation on a KV namespace of the calling app.
+pub async fn mudu_kv_namespace(session_id: OID, op: &KvNamespaceOp) -> RS<KvNamespaceOpResult> {
+    crate::inner_component_async::inner_kv_namespace(session_id, op).await
+}
+
 /// Scan a key range against the session.
 pub async fn mudu_range(
     session_id: OID,
@@ -58,3 +106,14 @@ pub async fn mudu_range(
 ) -> RS<Vec<(Vec<u8>, Vec<u8>)>> {
     crate::inner_component_async::inner_range(session_id, start_key, end_key).await
 }
+
+/// Scan a key range against the session with limit, order, prefix, keys-only
+/// and continuation options.
+pub async fn mudu_range_page(
+    session_id: OID,
+    start_key: &[u8],
+    end_key: &[u8],
+    options: &KvRangeOptions,
+) -> RS<KvRangePage> {
+    crate::inner_component_async::inner_range_page(session_id, start_key, end_key, options).await
+}
@@ -36,6 +36,24 @@ use mudu_contract::database::entity::Entity;
     )
 )))]
 use mudu_contract::database::entity_set::RecordSet;
+#[cfg(not(any(
+    all(not(target_arch = "wasm32"), feature = "standalone-adapter"),
+    all(
+        target_arch = "wasm32",
+        feature = "component-model",
+        not(feature = "async")
+    )
+)))]
+use mudu_contract::database::kv_namespace::{KvNamespaceOp, KvNamespaceOpResult};
+#[cfg(not(any(
+    all(not(target_arch = "wasm32"), feature = "standalone-adapter"),
+    all(
+        target_arch = "wasm32",
+        feature = "component-model",
+        not(feature = "async")
+    )
+)))]
+use mudu_contract::database::kv_range::{KvRangeOptions, KvRangePage};
 use mudu_contract::database::result_batch::ResultBatch;
 use mudu_contract::database::sql::Context;
 #[cfg(not(any(
@@ -56,6 +74,15 @@ use mudu_contract::database::sql_params::SQLParams;
     )
 )))]
 use mudu_contract::database::sql_stmt::SQLStmt;
+#[cfg(not(any(
+    all(not(target_arch = "wasm32"), feature = "standalone-adapter"),
+    all(
+        target_arch = "wasm32",
+        feature = "component-model",
+        not(feature = "async")
+    )
+)))]
+use std::time::Duration;
 
 use crate::host;
 
@@ -194,6 +221,115 @@ pub fn mudu_put(_session_id: OID, _key: &[u8], _value: &[u8]) -> RS<()> {
     not_implemented("mudu_put")
 }
 
+#[cfg(not(any(
+    all(not(target_arch = "wasm32"), feature = "standalone-adapter"),
+    all(
+        target_arch = "wasm32",
+        feature = "component-model",
+        not(feature = "async")
+    )
+)))]
+/// Store a key-value pair that expires `ttl` after the write commits.
+pub fn mudu_put_with_ttl(_session_id: OID, _key: &[u8], _value: &[u8], _ttl: Duration) -> RS<()> {
+    not_implemented("mudu_put_with_ttl")
+}
+
+#[cfg(not(any(
+    all(not(target_arch = "wasm32"), feature = "standalone-adapter"),
+    all(
+        target_arch = "wasm32",
+        feature = "component-model",
+        not(feature = "async")
+    )
+)))]
+/// Delete a key against the session.
+pub fn mudu_delete(_session_id: OID, _key: &[u8]) -> RS<()> {
+    not_implemented("mudu_delete")
+}
+
+#[cfg(not(any(
+    all(not(target_arch = "wasm32"), feature = "standalone-adapter"),
+    all(
+        target_arch = "wasm32",
+        feature = "component-model",
+        not(feature = "async")
+    )
+)))]
+/// Store several key-value pairs against the session as one unit.
+pub fn mudu_put_many(_session_id: OID, _items: &[(Vec<u8>, Vec<u8>)]) -> RS<()> {
+    not_implemented("mudu_put_many")
+}
+
+#[cfg(not(any(
+    all(not(target_arch = "wasm32"), feature = "standalone-adapter"),
+    all(
+        target_arch = "wasm32",
+        feature = "component-model",
+        not(feature = "async")
+    )
+)))]
+/// Delete several keys against the session as one unit.
+pub fn mudu_delete_many(_session_id: OID, _keys: &[Vec<u8>]) -> RS<()> {
+    not_implemented("mudu_delete_many")
+}
+
+#[cfg(not(any(
+    all(not(target_arch = "wasm32"), feature = "standalone-adapter"),
+    all(
+        target_arch = "wasm32",
+        feature = "component-model",
+        not(feature = "async")
+    )
+)))]
+/// Replace the value of a key when it equals `expected` (`None`: the key is absent).
+pub fn mudu_cas(
+    _session_id: OID,
+    _key: &[u8],
+    _expected: Option<&[u8]>,
+    _value: &[u8],
+) -> RS<bool> {
+    not_implemented("mudu_cas")
+}
+
+#[cfg(not(any(
+    all(not(target_arch = "wasm32"), feature = "standalone-adapter"),
+    all(
+        target_arch = "wasm32",
+        feature = "component-model",
+        not(feature = "async")
+    )
+)))]
+/// Add `delta` to the integer counter stored at a key and return the new value.
+pub fn mudu_incr(_session_id: OID, _key: &[u8], _delta: i64) -> RS<i64> {
+    not_implemented("mudu_incr")
+}
+
+#[cfg(not(any(
+    all(not(target_arch = "wasm32"), feature = "standalone-adapter"),
+    all(
+        target_arch = "wasm32",
+        feature = "component-model",
+        not(feature = "async")
+    )
+)))]
+/// Store a key-value pair only when the key is absent.
+pub fn mudu_put_if_absent(_session_id: OID, _key: &[u8], _value: &[u8]) -> RS<bool> {
+    not_implemented("mudu_put_if_absent")
+}
+
+#[cfg(not(any(
+    all(not(target_arch = "wasm32"), feature = "standalone-adapter"),
+    all(
+        target_arch = "wasm32",
+        feature = "component-model",
+        not(feature = "async")
+    )
+)))]
+/// Run an operation on a KV namespace of the calling app.
+pub fn mudu_kv_namespace(_session_id: OID, _op: &KvNamespaceOp) -> RS<KvNamespaceOpResult> {
+    not_implemented("mudu_kv_namespace")
+}
+
 #[cfg(not(any(
     all(not(target_arch = "wasm32"), feature = "standalone-adapter"),
     all(
@@ -211,6 +347,25 @@ pub fn mudu_range(
     not_implemented("mudu_range")
 }
 
+#[cfg(not(any(
+    all(not(target_arch = "wasm32"), feature = "standalone-adapter"),
+    all(
+        target_arch = "wasm32",
+        feature = "component-model",
+        not(feature = "async")
+    )
+)))]
+/// Scan a key range against the session with limit, order, prefix, keys-only
+/// and continuation options.
+pub fn mudu_range_page(
+    _session_id: OID,
+    _start_key: &[u8],
+    _end_key: &[u8],
+    _options: &KvRangeOptions,
+) -> RS<KvRangePage> {
+    not_implemented("mudu_range_page")
+}
+
 /// Execute a query from a serialized byte payload.
 pub fn mudu_query_bytes(query_in: &[u8]) -> RS<Vec<u8>> {
     let (oid, stmt, params) = query_invoke::deserialize_query_param(query_in)?;
@@ -295,16 +450,69 @@ pub fn mudu_get_bytes(get_in: &[u8]) -> RS<Vec<u8>> {
 
 /// Store a key-value pair from a serialized byte payload.
 pub fn mudu_put_bytes(put_in: &[u8]) -> RS<Vec<u8>> {
-    let (session_id, key, value) = host::deserialize_session_put_param(put_in)?;
-    mudu_put(session_id, &key, &value)?;
+    let (session_id, key, value, ttl) = host::deserialize_session_put_with_ttl_param(put_in)?;
+    match ttl {
+        Some(ttl) => mudu_put_with_ttl(session_id, &key, &value, ttl)?,
+        None => mudu_put(session_id, &key, &value)?,
+    }
     Ok(host::serialize_put_result())
 }
 
+/// Delete a key from a serialized byte payload.
+pub fn mudu_delete_bytes(delete_in: &[u8]) -> RS<Vec<u8>> {
+    let (session_id, key) = host::deserialize_session_delete_param(delete_in)?;
+    mudu_delete(session_id, &key)?;
+    Ok(host::serialize_delete_result())
+}
+
+/// Store several key-value pairs from a serialized byte payload.
+pub fn mudu_put_many_bytes(put_many_in: &[u8]) -> RS<Vec<u8>> {
+    let (session_id, items) = host::deserialize_session_put_many_param(put_many_in)?;
+    mudu_put_many(session_id, &items)?;
+    Ok(host::serialize_put_many_result())
+}
+
+/// Delete several keys from a serialized byte payload.
+pub fn mudu_delete_many_bytes(delete_many_in: &[u8]) -> RS<Vec<u8>> {
+    let (session_id, keys) = host::deserialize_session_delete_many_param(delete_many_in)?;
+    mudu_delete_many(session_id, &keys)?;
+    Ok(host::serialize_delete_many_result())
+}
+
+/// Compare and swap a key from a serialized byte payload.
+pub fn mudu_cas_bytes(cas_in: &[u8]) -> RS<Vec<u8>> {
+    let (session_id, key, expected, value) = host::deserialize_session_cas_param(cas_in)?;
+    let swapped = mudu_cas(session_id, &key, expected.as_deref(), &value)?;
+    Ok(host::serialize_cas_result(swapped))
+}
+
+/// Increment a counter from a serialized byte payload.
+pub fn mudu_incr_bytes(incr_in: &[u8]) -> RS<Vec<u8>> {
+    let (session_id, key, delta) = host::deserialize_session_incr_param(incr_in)?;
+    let value = mudu_incr(session_id, &key, delta)?;
+    Ok(host::serialize_incr_result(value))
+}
+
+/// Store a key-value pair if absent from a serialized byte payload.
+pub fn mudu_put_if_absent_bytes(put_if_absent_in: &[u8]) -> RS<Vec<u8>> {
+    let (session_id, key, value) = host::deserialize_session_put_if_absent_param(put_if_absent_in)?;
+    let written = mudu_put_if_absent(session_id, &key, &value)?;
+    Ok(host::serialize_put_if_absent_result(written))
+}
+
+/// Run a KV namespace operation from a serialized byte payload.
+pub fn mudu_kv_namespace_bytes(kv_namespace_in: &[u8]) -> RS<Vec<u8>> {
+    let (session_id, op) = host::deserialize_session_kv_namespace_param(kv_namespace_in)?;
+    let result = mudu_kv_namespace(session_id, &op)?;
+    Ok(host::serialize_kv_namespace_result(&result))
+}
+
 /// Scan a key range from a serialized byte payload.
 pub fn mudu_range_bytes(range_in: &[u8]) -> RS<Vec<u8>> {
-    let (session_id, start_key, end_key) = host::deserialize_session_range_param(range_in)?;
-    let items = mudu_range(session_id, &start_key, &end_key)?;
-    Ok(host::serialize_range_result(&items))
+    let (session_id, start_key, end_key, options) =
+        host::deserialize_session_range_page_param(range_in)?;
+    let page = mudu_range_page(session_id, &start_key, &end_key, &options)?;
+    Ok(host::serialize_range_page_result(&page))
 }
 
 // These tests exercise the SQLite-backed adapter, which is unsupported under Miri.
@@ -601,6 +809,51 @@ mod tests {
         assert_eq!(err.ec(), mudu::error::ErrorCode::NotImplemented);
     }
 
+    #[cfg(not(feature = "standalone-adapter"))]
+    #[test]
+    fn mudu_delete_and_multi_key_bytes_return_not_implemented() {
+        let oid = next_oid();
+        let err = mudu_delete_bytes(&host::serialize_session_delete_param(oid, b"k")).unwrap_err();
+        assert_eq!(err.ec(), mudu::error::ErrorCode::NotImplemented);
+
+        let items = [(b"k".to_vec(), b"v".to_vec())];
+        let err =
+            mudu_put_many_bytes(&host::serialize_session_put_many_param(oid, &items)).unwrap_err();
+        assert_eq!(err.ec(), mudu::error::ErrorCode::NotImplemented);
+
+        let keys = [b"k".to_vec()];
+        let err = mudu_delete_many_bytes(&host::serialize_session_delete_many_param(oid, &keys))
+            .unwrap_err();
+        assert_eq!(err.ec(), mudu::error::ErrorCode::NotImplemented);
+    }
+
+    #[cfg(not(feature = "standalone-adapter"))]
+    #[test]
+    fn mudu_atomic_bytes_return_not_implemented() {
+        let oid = next_oid();
+        let err =
+            mudu_cas_bytes(&host::serialize_session_cas_param(oid, b"k", None, b"v")).unwrap_err();
+        assert_eq!(err.ec(), mudu::error::ErrorCode::NotImplemented);
+
+        let err = mudu_incr_bytes(&host::serialize_session_incr_param(oid, b"k", 1)).unwrap_err();
+        assert_eq!(err.ec(), mudu::error::ErrorCode::NotImplemented);
+
+        let err = mudu_put_if_absent_bytes(&host::serialize_session_put_if_absent_param(
+            oid, b"k", b"v",
+        ))
+        .unwrap_err();
+        assert_eq!(err.ec(), mudu::error::ErrorCode::NotImplemented);
+    }
+
+    #[test]
+    fn mudu_kv_namespace_bytes_returns_not_implemented() {
+        let oid = next_oid();
+        let op = mudu_contract::database::kv_namespace::KvNamespaceOp::List;
+        let err = mudu_kv_namespace_bytes(&host::serialize_session_kv_namespace_param(oid, &op))
+            .unwrap_err();
+        assert_eq!(err.ec(), mudu::error::ErrorCode::NotImplemented);
+    }
+
     #[cfg(not(feature = "standalone-adapter"))]
     #[test]
     fn mudu_range_bytes_returns_not_implemented() {
@@ -673,6 +926,49 @@ mod tests {
         });
     }
 
+    #[cfg(feature = "standalone-adapter")]
+    #[test]
+    fn mudu_delete_and_multi_key_bytes_reject_missing_session() {
+        with_adapter_test_db("delete_bytes", |_db_path| {
+            let oid = next_oid();
+            let err =
+                mudu_delete_bytes(&host::serialize_session_delete_param(oid, b"k")).unwrap_err();
+            assert_eq!(err.ec(), mudu::error::ErrorCode::EntityNotFound);
+
+            let items = [(b"k".to_vec(), b"v".to_vec())];
+            let err = mudu_put_many_bytes(&host::serialize_session_put_many_param(oid, &items))
+                .unwrap_err();
+            assert_eq!(err.ec(), mudu::error::ErrorCode::EntityNotFound);
+
+            let keys = [b"k".to_vec()];
+            let err =
+                mudu_delete_many_bytes(&host::serialize_session_delete_many_param(oid, &keys))
+                    .unwrap_err();
+            assert_eq!(err.ec(), mudu::error::ErrorCode::EntityNotFound);
+        });
+    }
+
+    #[cfg(feature = "standalone-adapter")]
+    #[test]
+    fn mudu_atomic_bytes_reject_missing_session() {
+        with_adapter_test_db("atomic_bytes", |_db_path| {
+            let oid = next_oid();
+            let err = mudu_cas_bytes(&host::serialize_session_cas_param(oid, b"k", None, b"v"))
+                .unwrap_err();
+            assert_eq!(err.ec(), mudu::error::ErrorCode::EntityNotFound);
+
+            let err =
+                mudu_incr_bytes(&host::serialize_session_incr_param(oid, b"k", 1)).unwrap_err();
+            assert_eq!(err.ec(), mudu::error::ErrorCode::EntityNotFound);
+
+            let err = mudu_put_if_absent_bytes(&host::serialize_session_put_if_absent_param(
+                oid, b"k", b"v",
+            ))
+            .unwrap_err();
+            assert_eq!(err.ec(), mudu::error::ErrorCode::EntityNotFound);
+        });
+    }
+
     #[cfg(feature = "standalone-adapter")]
     #[test]
     fn mudu_range_bytes_rejects_missing_session() {
@@ -3,8 +3,11 @@ use mudu::common::result::RS;
 use mudu_binding::universal::uni_session_open_argv::UniSessionOpenArgv;
 use mudu_contract::database::entity::Entity;
 use mudu_contract::database::entity_set::RecordSet;
+use mudu_contract::database::kv_namespace::{KvNamespaceOp, KvNamespaceOpResult};
+use mudu_contract::database::kv_range::{KvRangeOptions, KvRangePage};
 use mudu_contract::database::sql_params::SQLParams;
 use mudu_contract::database::sql_stmt::SQLStmt;
+use std::time::Duration;
 
 #[cfg(all(feature = "component-model", not(feature = "async")))]
 /// Execute a query against the session.
@@ -58,6 +61,54 @@ pub fn mudu_put(session_id: OID, key: &[u8], value: &[u8]) -> RS<()> {
     crate::inner_component::inner_put(session_id, key, value)
 }
 
+#[cfg(all(feature = "component-model", not(feature = "async")))]
+/// Store a key-value pair that expires `ttl` after the write commits.
+pub fn mudu_put_with_ttl(session_id: OID, key: &[u8], value: &[u8], ttl: Duration) -> RS<()> {
+    crate::inner_component::inner_put_with_ttl(session_id, key, value, ttl)
+}
+
+#[cfg(all(feature = "component-model", not(feature = "async")))]
+/// Delete a key against the session.
+pub fn mudu_delete(session_id: OID, key: &[u8]) -> RS<()> {
+    crate::inner_component::inner_delete(session_id, key)
+}
+
+#[cfg(all(feature = "component-model", not(feature = "async")))]
+/// Store several key-value pairs against the session as one unit.
+pub fn mudu_put_many(session_id: OID, items: &[(Vec<u8>, Vec<u8>)]) -> RS<()> {
+    crate::inner_component::inner_put_many(session_id, items)
+}
+
+#[cfg(all(feature = "component-model", not(feature = "async")))]
+/// Delete several keys against the session as one unit.
+pub fn mudu_delete_many(session_id: OID, keys: &[Vec<u8>]) -> RS<()> {
+    crate::inner_component::inner_delete_many(session_id, keys)
+}
+
+#[cfg(all(feature = "component-model", not(feature = "async")))]
+/// Replace the value of a key when it equals `expected` (`None`: the key is absent).
+pub fn mudu_cas(session_id: OID, key: &[u8], expected: Option<&[u8]>, value: &[u8]) -> RS<bool> {
+    crate::inner_component::inner_cas(session_id, key, expected, value)
+}
+
+#[cfg(all(feature = "component-model", not(feature = "async")))]
+/// Add `delta` to the integer counter stored at a key and return the new value.
+pub fn mudu_incr(session_id: OID, key: &[u8], delta: i64) -> RS<i64> {
+    crate::inner_component::inner_incr(session_id, key, delta)
+}
+
+#[cfg(all(feature = "component-model", not(feature = "async")))]
+/// Store a key-value pair only when the key is absent.
+pub fn mudu_put_if_absent(session_id: OID, key: &[u8], value: &[u8]) -> RS<bool> {
+    crate::inner_component::inner_put_if_absent(session_id, key, value)
+}
+
+#[cfg(all(feature = "component-model", not(feature = "async")))]
+/// Run an operation on a KV namespace of the calling app.
+pub fn mudu_kv_namespace(session_id: OID, op: &KvNamespaceOp) -> RS<KvNamespaceOpResult> {
+    crate::inner_component::inner_kv_namespace(session_id, op)
+}
+
 #[cfg(all(feature = "component-model", not(feature = "async")))]
 /// Scan a key range against the session.
 pub fn mudu_range(
@@ -67,3 +118,15 @@ pub fn mudu_range(
 ) -> RS<Vec<(Vec<u8>, Vec<u8>)>> {
     crate::inner_component::inner_range(session_id, start_key, end_key)
 }
+
+#[cfg(all(feature = "component-model", not(feature = "async")))]
+/// Scan a key range against the session with limit, order, prefix, keys-only
+/// and continuation options.
+pub fn mudu_range_page(
+    session_id: OID,
+    start_key: &[u8],
+    end_key: &[u8],
+    options: &KvRangeOptions,
+) -> RS<KvRangePage> {
+    crate::inner_component::inner_range_page(session_id, start_key, end_key, options)
+}
@@ -4,6 +4,8 @@ use mudu_binding::codec::handle_sys_session;
 use mudu_binding::universal::uni_session_open_argv::UniSessionOpenArgv;
 use mudu_contract::database::entity::Entity;
 use mudu_contract::database::entity_set::RecordSet;
+use mudu_contract::database::kv_namespace::{KvNamespaceOp, KvNamespaceOpResult};
+use mudu_contract::database::kv_range::{KvRangeOptions, KvRangePage};
 use mudu_contract::database::result_batch::ResultBatch;
 use mudu_contract::database::result_set::ResultSet;
 use mudu_contract::database::sql_params::SQLParams;
@@ -11,6 +13,7 @@ use mudu_contract::database::sql_stmt::SQLStmt;
 use mudu_contract::tuple::tuple_value::TupleValue;
 use mudu_sys::sync::SMutex;
 use std::sync::Arc;
+use std::time::Duration;
 
 /// Invoke the host `command` operation.
 pub fn invoke_host_command<F>(oid: OID, sql: &dyn SQLStmt, params: &dyn SQLParams, f: F) -> RS<u64>
@@ -104,6 +107,23 @@ pub fn deserialize_session_put_param(input: &[u8]) -> RS<(OID, Vec<u8>, Vec<u8>)
     handle_sys_session::deserialize_session_put_param(input)
 }
 
+/// Serialize session put with ttl param parameters.
+pub fn serialize_session_put_with_ttl_param(
+    session_id: OID,
+    key: &[u8],
+    value: &[u8],
+    ttl: Duration,
+) -> Vec<u8> {
+    handle_sys_session::serialize_session_put_with_ttl_param(session_id, key, value, ttl)
+}
+
+/// Deserialize session put with ttl param parameters/results.
+pub fn deserialize_session_put_with_ttl_param(
+    input: &[u8],
+) -> RS<(OID, Vec<u8>, Vec<u8>, Option<Duration>)> {
+    handle_sys_session::deserialize_session_put_with_ttl_param(input)
+}
+
 /// Serialize put result parameters.
 pub fn serialize_put_result() -> Vec<u8> {
     handle_sys_session::serialize_put_result()
@@ -114,6 +134,151 @@ pub fn deserialize_put_result(input: &[u8]) -> RS<()> {
     handle_sys_session::deserialize_put_result(input)
 }
 
+/// Serialize session delete param parameters.
+pub fn serialize_session_delete_param(session_id: OID, key: &[u8]) -> Vec<u8> {
+    handle_sys_session::serialize_session_delete_param(session_id, key)
+}
+
+/// Deserialize session delete param parameters/results.
+pub fn deserialize_session_delete_param(input: &[u8]) -> RS<(OID, Vec<u8>)> {
+    handle_sys_session::deserialize_session_delete_param(input)
+}
+
+/// Serialize delete result parameters.
+pub fn serialize_delete_result() -> Vec<u8> {
+    handle_sys_session::serialize_delete_result()
+}
+
+/// Deserialize delete result parameters/results.
+pub fn deserialize_delete_result(input: &[u8]) -> RS<()> {
+    handle_sys_session::deserialize_delete_result(input)
+}
+
+/// Serialize session put many param parameters.
+pub fn serialize_session_put_many_param(session_id: OID, items: &[(Vec<u8>, Vec<u8>)]) -> Vec<u8> {
+    handle_sys_session::serialize_session_put_many_param(session_id, items)
+}
+
+/// Deserialize session put many param parameters/results.
+pub fn deserialize_session_put_many_param(input: &[u8]) -> RS<(OID, Vec<(Vec<u8>, Vec<u8>)>)> {
+    handle_sys_session::deserialize_session_put_many_param(input)
+}
+
+/// Serialize put many result parameters.
+pub fn serialize_put_many_result() -> Vec<u8> {
+    handle_sys_session::serialize_put_many_result()
+}
+
+/// Deserialize put many result parameters/results.
+pub fn deserialize_put_many_result(input: &[u8]) -> RS<()> {
+    handle_sys_session::deserialize_put_many_result(input)
+}
+
+/// Serialize session delete many param parameters.
+pub fn serialize_session_delete_many_param(session_id: OID, keys: &[Vec<u8>]) -> Vec<u8> {
+    handle_sys_session::serialize_session_delete_many_param(session_id, keys)
+}
+
+/// Deserialize session delete many param parameters/results.
+pub fn deserialize_session_delete_many_param(input: &[u8]) -> RS<(OID, Vec<Vec<u8>>)> {
+    handle_sys_session::deserialize_session_delete_many_param(input)
+}
+
+/// Serialize delete many result parameters.
+pub fn serialize_delete_many_result() -> Vec<u8> {
+    handle_sys_session::serialize_delete_many_result()
+}
+
+/// Deserialize delete many result parameters/results.
+pub fn deserialize_delete_many_result(input: &[u8]) -> RS<()> {
+    handle_sys_session::deserialize_delete_many_result(input)
+}
+
+/// Serialize session cas param parameters.
+pub fn serialize_session_cas_param(
+    session_id: OID,
+    key: &[u8],
+    expected: Option<&[u8]>,
+    value: &[u8],
+) -> Vec<u8> {
+    handle_sys_session::serialize_session_cas_param(session_id, key, expected, value)
+}
+
+/// Deserialize session cas param parameters/results.
+pub fn deserialize_session_cas_param(input: &[u8]) -> RS<(OID, Vec<u8>, Option<Vec<u8>>, Vec<u8>)> {
+    handle_sys_session::deserialize_session_cas_param(input)
+}
+
+/// Serialize cas result parameters.
+pub fn serialize_cas_result(swapped: bool) -> Vec<u8> {
+    handle_sys_session::serialize_cas_result(swapped)
+}
+
+/// Deserialize cas result parameters/results.
+pub fn deserialize_cas_result(input: &[u8]) -> RS<bool> {
+    handle_sys_session::deserialize_cas_result(input)
+}
+
+/// Serialize session incr param parameters.
+pub fn serialize_session_incr_param(session_id: OID, key: &[u8], delta: i64) -> Vec<u8> {
+    handle_sys_session::serialize_session_incr_param(session_id, key, delta)
+}
+
+/// Deserialize session incr param parameters/results.
+pub fn deserialize_session_incr_param(input: &[u8]) -> RS<(OID, Vec<u8>, i64)> {
+    handle_sys_session::deserialize_session_incr_param(input)
+}
+
+/// Serialize incr result parameters.
+pub fn serialize_incr_result(value: i64) -> Vec<u8> {
+    handle_sys_session::serialize_incr_result(value)
+}
+
+/// Deserialize incr result parameters/results.
+pub fn deserialize_incr_result(input: &[u8]) -> RS<i64> {
+    handle_sys_session::deserialize_incr_result(input)
+}
+
+/// Serialize session put if absent param parameters.
+pub fn serialize_session_put_if_absent_param(session_id: OID, key: &[u8], value: &[u8]) -> Vec<u8> {
+    handle_sys_session::serialize_session_put_if_absent_param(session_id, key, value)
+}
+
+/// Deserialize session put if absent param parameters/results.
+pub fn deserialize_session_put_if_absent_param(input: &[u8]) -> RS<(OID, Vec<u8>, Vec<u8>)> {
+    handle_sys_session::deserialize_session_put_if_absent_param(input)
+}
+
+/// Serialize put if absent result parameters.
+pub fn serialize_put_if_absent_result(written: bool) -> Vec<u8> {
+    handle_sys_session::serialize_put_if_absent_result(written)
+}
+
+/// Deserialize put if absent result parameters/results.
+pub fn deserialize_put_if_absent_result(input: &[u8]) -> RS<bool> {
+    handle_sys_session::deserialize_put_if_absent_result(input)
+}
+
+/// Serialize session kv namespace param parameters.
+pub fn serialize_session_kv_namespace_param(session_id: OID, op: &KvNamespaceOp) -> Vec<u8> {
+    handle_sys_session::serialize_session_kv_namespace_param(session_id, op)
+}
+
+/// Deserialize session kv namespace param parameters/results.
+pub fn deserialize_session_kv_namespace_param(input: &[u8]) -> RS<(OID, KvNamespaceOp)> {
+    handle_sys_session::deserialize_session_kv_namespace_param(input)
+}
+
+/// Serialize kv namespace result parameters.
+pub fn serialize_kv_namespace_result(result: &KvNamespaceOpResult) -> Vec<u8> {
+    handle_sys_session::serialize_kv_namespace_result(result)
+}
+
+/// Deserialize kv namespace result parameters/results.
+pub fn deserialize_kv_namespace_result(input: &[u8]) -> RS<KvNamespaceOpResult> {
+    handle_sys_session::deserialize_kv_namespace_result(input)
+}
+
 /// Serialize range param parameters.
 pub fn serialize_range_param(start_key: &[u8], end_key: &[u8]) -> Vec<u8> {
     handle_sys_session::serialize_range_param(start_key, end_key)
@@ -134,6 +299,23 @@ pub fn deserialize_session_range_param(input: &[u8]) -> RS<(OID, Vec<u8>, Vec<u8
     handle_sys_session::deserialize_session_range_param(input)
 }
 
+/// Serialize session range page param parameters.
+pub fn serialize_session_range_page_param(
+    session_id: OID,
+    start_key: &[u8],
+    end_key: &[u8],
+    options: &KvRangeOptions,
+) -> Vec<u8> {
+    handle_sys_session::serialize_session_range_page_param(session_id, start_key, end_key, options)
+}
+
+/// Deserialize session range page param parameters/results.
+pub fn deserialize_session_range_page_param(
+    input: &[u8],
+) -> RS<(OID, Vec<u8>, Vec<u8>, KvRangeOptions)> {
+    handle_sys_session::deserialize_session_range_page_param(input)
+}
+
 /// Serialize open param parameters.
 pub fn serialize_open_param() -> Vec<u8> {
     handle_sys_session::serialize_open_param()
@@ -189,6 +371,16 @@ pub fn deserialize_range_result(input: &[u8]) -> RS<Vec<(Vec<u8>, Vec<u8>)>> {
     handle_sys_session::deserialize_range_result(input)
 }
 
+/// Serialize range page result parameters.
+pub fn serialize_range_page_result(page: &KvRangePage) -> Vec<u8> {
+    handle_sys_session::serialize_range_page_result(page)
+}
+
+/// Deserialize range page result parameters/results.
+pub fn deserialize_range_page_result(input: &[u8]) -> RS<KvRangePage> {
+    handle_sys_session::deserialize_range_page_result(input)
+}
+
 /// Invoke the host `get` operation.
 pub fn invoke_host_get<F>(key: &[u8], f: F) -> RS<Option<Vec<u8>>>
 where
@@ -249,6 +441,111 @@ where
     deserialize_put_result(&result)
 }
 
+/// Invoke the host `session put` operation with a TTL.
+pub fn invoke_host_session_put_with_ttl<F>(
+    session_id: OID,
+    key: &[u8],
+    value: &[u8],
+    ttl: Duration,
+    f: F,
+) -> RS<()>
+where
+    F: Fn(Vec<u8>) -> RS<Vec<u8>>,
+{
+    let param_binary = serialize_session_put_with_ttl_param(session_id, key, value, ttl);
+    let result = f(param_binary)?;
+    deserialize_put_result(&result)
+}
+
+/// Invoke the host `session delete` operation.
+pub fn invoke_host_session_delete<F>(session_id: OID, key: &[u8], f: F) -> RS<()>
+where
+    F: Fn(Vec<u8>) -> RS<Vec<u8>>,
+{
+    let param_binary = serialize_session_delete_param(session_id, key);
+    let result = f(param_binary)?;
+    deserialize_delete_result(&result)
+}
+
+/// Invoke the host `session put many` operation.
+pub fn invoke_host_session_put_many<F>(
+    session_id: OID,
+    items: &[(Vec<u8>, Vec<u8>)],
+    f: F,
+) -> RS<()>
+where
+    F: Fn(Vec<u8>) -> RS<Vec<u8>>,
+{
+    let param_binary = serialize_session_put_many_param(session_id, items);
+    let result = f(param_binary)?;
+    deserialize_put_many_result(&result)
+}
+
+/// Invoke the host `session delete many` operation.
+pub fn invoke_host_session_delete_many<F>(session_id: OID, keys: &[Vec<u8>], f: F) -> RS<()>
+where
+    F: Fn(Vec<u8>) -> RS<Vec<u8>>,
+{
+    let param_binary = serialize_session_delete_many_param(session_id, keys);
+    let result = f(param_binary)?;
+    deserialize_delete_many_result(&result)
+}
+
+/// Invoke the host `session cas` operation.
+pub fn invoke_host_session_cas<F>(
+    session_id: OID,
+    key: &[u8],
+    expected: Option<&[u8]>,
+    value: &[u8],
+    f: F,
+) -> RS<bool>
+where
+    F: Fn(Vec<u8>) -> RS<Vec<u8>>,
+{
+    let param_binary = serialize_session_cas_param(session_id, key, expected, value);
+    let result = f(param_binary)?;
+    deserialize_cas_result(&result)
+}
+
+/// Invoke the host `session incr` operation.
+pub fn invoke_host_session_incr<F>(session_id: OID, key: &[u8], delta: i64, f: F) -> RS<i64>
+where
+    F: Fn(Vec<u8>) -> RS<Vec<u8>>,
+{
+    let param_binary = serialize_session_incr_param(session_id, key, delta);
+    let result = f(param_binary)?;
+    deserialize_incr_result(&result)
+}
+
+/// Invoke the host `session put if absent` operation.
+pub fn invoke_host_session_put_if_absent<F>(
+    session_id: OID,
+    key: &[u8],
+    value: &[u8],
+    f: F,
+) -> RS<bool>
+where
+    F: Fn(Vec<u8>) -> RS<Vec<u8>>,
+{
+    let param_binary = serialize_session_put_if_absent_param(session_id, key, value);
+    let result = f(param_binary)?;
+    deserialize_put_if_absent_result(&result)
+}
+
+/// Invoke the host `session kv namespace` operation.
+pub fn invoke_host_session_kv_namespace<F>(
+    session_id: OID,
+    op: &KvNamespaceOp,
+    f: F,
+) -> RS<KvNamespaceOpResult>
+where
+    F: Fn(Vec<u8>) -> RS<Vec<u8>>,
+{
+    let param_binary = serialize_session_kv_namespace_param(session_id, op);
+    let result = f(param_binary)?;
+    deserialize_kv_namespace_result(&result)
+}
+
 /// Invoke the host `session range` operation.
 pub fn invoke_host_session_range<F>(
     session_id: OID,
@@ -264,6 +561,22 @@ where
     deserialize_range_result(&result)
 }
 
+/// Invoke the host `session range` operation with scan options.
+pub fn invoke_host_session_range_page<F>(
+    session_id: OID,
+    start_key: &[u8],
+    end_key: &[u8],
+    options: &KvRangeOptions,
+    f: F,
+) -> RS<KvRangePage>
+where
+    F: Fn(Vec<u8>) -> RS<Vec<u8>>,
+{
+    let param_binary = serialize_session_range_page_param(session_id, start_key, end_key, options);
+    let result = f(param_binary)?;
+    deserialize_range_page_result(&result)
+}
+
 /// Invoke the host `put` operation.
 pub fn invoke_host_put<F>(key: &[u8], value: &[u8], f: F) -> RS<()>
 where
@@ -405,6 +718,120 @@ where
     deserialize_put_result(&result)
 }
 
+/// Asynchronously invoke the host `session put` operation with a TTL.
+pub async fn async_invoke_host_session_put_with_ttl<F>(
+    session_id: OID,
+    key: &[u8],
+    value: &[u8],
+    ttl: Duration,
+    f: F,
+) -> RS<()>
+where
+    F: AsyncFn(Vec<u8>) -> RS<Vec<u8>>,
+{
+    let param_binary = serialize_session_put_with_ttl_param(session_id, key, value, ttl);
+    let result = f(param_binary).await?;
+    deserialize_put_result(&result)
+}
+
+/// Asynchronously invoke the host `session delete` operation.
+pub async fn async_invoke_host_session_delete<F>(session_id: OID, key: &[u8], f: F) -> RS<()>
+where
+    F: AsyncFn(Vec<u8>) -> RS<Vec<u8>>,
+{
+    let param_binary = serialize_session_delete_param(session_id, key);
+    let result = f(param_binary).await?;
+    deserialize_delete_result(&result)
+}
+
+/// Asynchronously invoke the host `session put many` operation.
+pub async fn async_invoke_host_session_put_many<F>(
+    session_id: OID,
+    items: &[(Vec<u8>, Vec<u8>)],
+    f: F,
+) -> RS<()>
+where
+    F: AsyncFn(Vec<u8>) -> RS<Vec<u8>>,
+{
+    let param_binary = serialize_session_put_many_param(session_id, items);
+    let result = f(param_binary).await?;
+    deserialize_put_many_result(&result)
+}
+
+/// Asynchronously invoke the host `session delete many` operation.
+pub async fn async_invoke_host_session_delete_many<F>(
+    session_id: OID,
+    keys: &[Vec<u8>],
+    f: F,
+) -> RS<()>
+where
+    F: AsyncFn(Vec<u8>) -> RS<Vec<u8>>,
+{
+    let param_binary = serialize_session_delete_many_param(session_id, keys);
+    let result = f(param_binary).await?;
+    deserialize_delete_many_result(&result)
+}
+
+/// Asynchronously invoke the host `session cas` operation.
+pub async fn async_invoke_host_session_cas<F>(
+    session_id: OID,
+    key: &[u8],
+    expected: Option<&[u8]>,
+    value: &[u8],
+    f: F,
+) -> RS<bool>
+where
+    F: AsyncFn(Vec<u8>) -> RS<Vec<u8>>,
+{
+    let param_binary = serialize_session_cas_param(session_id, key, expected, value);
+    let result = f(param_binary).await?;
+    deserialize_cas_result(&result)
+}
+
+/// Asynchronously invoke the host `session incr` operation.
+pub async fn async_invoke_host_session_incr<F>(
+    session_id: OID,
+    key: &[u8],
+    delta: i64,
+    f: F,
+) -> RS<i64>
+where
+    F: AsyncFn(Vec<u8>) -> RS<Vec<u8>>,
+{
+    let param_binary = serialize_session_incr_param(session_id, key, delta);
+    let result = f(param_binary).await?;
+    deserialize_incr_result(&result)
+}
+
+/// Asynchronously invoke the host `session put if absent` operation.
+pub async fn async_invoke_host_session_put_if_absent<F>(
+    session_id: OID,
+    key: &[u8],
+    value: &[u8],
+    f: F,
+) -> RS<bool>
+where
+    F: AsyncFn(Vec<u8>) -> RS<Vec<u8>>,
+{
+    let param_binary = serialize_session_put_if_absent_param(session_id, key, value);
+    let result = f(param_binary).await?;
+    deserialize_put_if_absent_result(&result)
+}
+
+/// Asynchronously invoke the host `session kv namespace` operation.
+pub async fn async_invoke_host_session_kv_namespace<F>(
+    session_id: OID,
+    op: &KvNamespaceOp,
+    f: F,
+) -> RS<KvNamespaceOpResult>
+where
+    F: AsyncFn(Vec<u8>) -> RS<Vec<u8>>,
+{
+    let param_binary = serialize_session_kv_namespace_param(session_id, op);
+    let result = f(param_binary).await?;
+    deserialize_kv_namespace_result(&result)
+}
+
 /// Asynchronously invoke the host `session range` operation.
 pub async fn async_invoke_host_session_range<F>(
     session_id: OID,
@@ -420,6 +847,22 @@ where
     deserialize_range_result(&result)
 }
 
+/// Asynchronously invoke the host `session range` operation with scan options.
+pub async fn async_invoke_host_session_range_page<F>(
+    session_id: OID,
+    start_key: &[u8],
+    end_key: &[u8],
+    options: &KvRangeOptions,
+    f: F,
+) -> RS<KvRangePage>
+where
+    F: AsyncFn(Vec<u8>) -> RS<Vec<u8>>,
+{
+    let param_binary = serialize_session_range_page_param(session_id, start_key, end_key, options);
+    let result = f(param_binary).await?;
+    deserialize_range_page_result(&result)
+}
+
 /// Asynchronously invoke the host `put` operation.
 pub async fn async_invoke_host_put<F>(key: &[u8], value: &[u8], f: F) -> RS<()>
 where
@@ -508,6 +951,130 @@ mod tests {
         );
     }
 
+    #[test]
+    fn kv_put_with_ttl_helper_roundtrip() {
+        let ttl = Duration::from_secs(30);
+        invoke_host_session_put_with_ttl(4, b"k", b"v", ttl, |input| {
+            assert_eq!(
+                deserialize_session_put_with_ttl_param(&input).unwrap(),
+                (4, b"k".to_vec(), b"v".to_vec(), Some(ttl))
+            );
+            Ok(serialize_put_result())
+        })
+        .unwrap();
+    }
+
+    #[test]
+    fn kv_range_page_helper_roundtrip() {
+        let options = KvRangeOptions::new()
+            .with_limit(1)
+            .with_prefix(b"user/".to_vec());
+        let page = invoke_host_session_range_page(4, b"a", b"z", &options, |input| {
+            assert_eq!(
+                deserialize_session_range_page_param(&input).unwrap(),
+                (4, b"a".to_vec(), b"z".to_vec(), options.clone())
+            );
+            Ok(serialize_range_page_result(&KvRangePage::new(
+                vec![(b"user/1".to_vec(), b"v".to_vec())],
+                Some(b"user/1".to_vec()),
+            )))
+        })
+        .unwrap();
+        assert_eq!(page.items(), &[(b"user/1".to_vec(), b"v".to_vec())]);
+        assert_eq!(page.continuation(), Some(b"user/1".as_slice()));
+
+        let legacy = deserialize_range_page_result(&serialize_range_result(&[(
+            b"a".to_vec(),
+            b"1".to_vec(),
+        )]))
+        .unwrap();
+        assert_eq!(legacy.continuation(), None);
+    }
+
+    #[test]
+    fn kv_delete_and_multi_key_helpers_roundtrip() {
+        invoke_host_session_delete(3, b"k", |input| {
+            assert_eq!(
+                deserialize_session_delete_param(&input).unwrap(),
+                (3, b"k".to_vec())
+            );
+            Ok(serialize_delete_result())
+        })
+        .unwrap();
+
+        let items = vec![
+            (b"a".to_vec(), b"1".to_vec()),
+            (b"b".to_vec(), b"2".to_vec()),
+        ];
+        invoke_host_session_put_many(4, &items, |input| {
+            assert_eq!(
+                deserialize_session_put_many_param(&input).unwrap(),
+                (4, items.clone())
+            );
+            Ok(serialize_put_many_result())
+        })
+        .unwrap();
+
+        let keys = vec![b"a".to_vec(), b"b".to_vec()];
+        invoke_host_session_delete_many(5, &keys, |input| {
+            assert_eq!(
+                deserialize_session_delete_many_param(&input).unwrap(),
+                (5, keys.clone())
+            );
+            Ok(serialize_delete_many_result())
+        })
+        .unwrap();
+    }
+
+    #[test]
+    fn kv_atomic_helpers_roundtrip() {
+        let swapped = invoke_host_session_cas(6, b"k", Some(b"old"), b"new", |input| {
+            assert_eq!(
+                deserialize_session_cas_param(&input).unwrap(),
+                (6, b"k".to_vec(), Some(b"old".to_vec()), b"new".to_vec())
+            );
+            Ok(serialize_cas_result(true))
+        })
+        .unwrap();
+        assert!(swapped);
+
+        let value = invoke_host_session_incr(7, b"n", 3, |input| {
+            assert_eq!(
+                deserialize_session_incr_param(&input).unwrap(),
+                (7, b"n".to_vec(), 3)
+            );
+            Ok(serialize_incr_result(10))
+        })
+        .unwrap();
+        assert_eq!(value, 10);
+
+        let written = invoke_host_session_put_if_absent(8, b"k", b"v", |input| {
+            assert_eq!(
+                deserialize_session_put_if_absent_param(&input).unwrap(),
+                (8, b"k".to_vec(), b"v".to_vec())
+            );
+            Ok(serialize_put_if_absent_result(false))
+        })
+        .unwrap();
+        assert!(!written);
+
+        let op = KvNamespaceOp::Get {
+            name: "carts".to_string(),
+            key: b"k".to_vec(),
+        };
+        let result = invoke_host_session_kv_namespace(9, &op, |input| {
+            assert_eq!(
+                deserialize_session_kv_namespace_param(&input).unwrap(),
+                (9, op.clone())
+            );
+            Ok(serialize_kv_namespace_result(&KvNamespaceOpResult::Value(
+                Some(b"v".to_vec()),
+            )))
+        })
+        .unwrap();
+        assert_eq!(result, KvNamespaceOpResult::Value(Some(b"v".to_vec())));
+    }
+
     #[test]
     fn open_and_open_argv_helpers_roundtrip() {
         let oid = invoke_host_open(|_| Ok(serialize_open_result(15))).unwrap();
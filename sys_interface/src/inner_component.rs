@@ -1,6 +1,9 @@
 use crate::host::{
     invoke_host_batch, invoke_host_close, invoke_host_command, invoke_host_open, invoke_host_query,
-    invoke_host_session_get, invoke_host_session_put, invoke_host_session_range,
+    invoke_host_session_cas, invoke_host_session_delete, invoke_host_session_delete_many,
+    invoke_host_session_get, invoke_host_session_incr, invoke_host_session_kv_namespace,
+    invoke_host_session_put, invoke_host_session_put_if_absent, invoke_host_session_put_many,
+    invoke_host_session_put_with_ttl, invoke_host_session_range, invoke_host_session_range_page,
 };
 use crate::inner_component::mududb::api::system;
 use mudu::common::id::OID;
@@ -8,8 +11,11 @@ use mudu::common::result::RS;
 use mudu_binding::universal::uni_session_open_argv::UniSessionOpenArgv;
 use mudu_contract::database::entity::Entity;
 use mudu_contract::database::entity_set::RecordSet;
+use mudu_contract::database::kv_namespace::{KvNamespaceOp, KvNamespaceOpResult};
+use mudu_contract::database::kv_range::{KvRangeOptions, KvRangePage};
 use mudu_contract::database::sql_params::SQLParams;
 use mudu_contract::database::sql_stmt::SQLStmt;
+use std::time::Duration;
 
 wit_bindgen::generate!({
     path:"wit/sync",
@@ -60,6 +66,52 @@ pub fn inner_put(session_id: OID, key: &[u8], value: &[u8]) -> RS<()> {
     invoke_host_session_put(session_id, key, value, |param| Ok(system::put(&param)))
 }
 
+/// Forward a `put` call with a TTL to the component-model host interface.
+/// The TTL rides on the `put` parameter, see
+/// [`crate::host::serialize_session_put_with_ttl_param`].
+pub fn inner_put_with_ttl(session_id: OID, key: &[u8], value: &[u8], ttl: Duration) -> RS<()> {
+    invoke_host_session_put_with_ttl(session_id, key, value, ttl, |param| Ok(system::put(&param)))
+}
+
+/// Forward a `delete` call to the component-model host interface.
+pub fn inner_delete(session_id: OID, key: &[u8]) -> RS<()> {
+    invoke_host_session_delete(session_id, key, |param| Ok(system::delete(&param)))
+}
+
+/// Forward a `put-many` call to the component-model host interface.
+pub fn inner_put_many(session_id: OID, items: &[(Vec<u8>, Vec<u8>)]) -> RS<()> {
+    invoke_host_session_put_many(session_id, items, |param| Ok(system::put_many(&param)))
+}
+
+/// Forward a `delete-many` call to the component-model host interface.
+pub fn inner_delete_many(session_id: OID, keys: &[Vec<u8>]) -> RS<()> {
+    invoke_host_session_delete_many(session_id, keys, |param| Ok(system::delete_many(&param)))
+}
+
+/// Forward a `cas` call to the component-model host interface.
+pub fn inner_cas(session_id: OID, key: &[u8], expected: Option<&[u8]>, value: &[u8]) -> RS<bool> {
+    invoke_host_session_cas(session_id, key, expected, value, |param| {
+        Ok(system::cas(&param))
+    })
+}
+
+/// Forward an `incr` call to the component-model host interface.
+pub fn inner_incr(session_id: OID, key: &[u8], delta: i64) -> RS<i64> {
+    invoke_host_session_incr(session_id, key, delta, |param| Ok(system::incr(&param)))
+}
+
+/// Forward a `put-if-absent` call to the component-model host interface.
+pub fn inner_put_if_absent(session_id: OID, key: &[u8], value: &[u8]) -> RS<bool> {
+    invoke_host_session_put_if_absent(session_id, key, value, |param| {
+        Ok(system::put_if_absent(&param))
+    })
+}
+
+/// Forward a `kv-namespace` call to the component-model host interface.
+pub fn inner_kv_namespace(session_id: OID, op: &KvNamespaceOp) -> RS<KvNamespaceOpResult> {
+    invoke_host_session_kv_namespace(session_id, op, |param| Ok(system::kv_namespace(&param)))
+}
+
 /// Forward a `range` call to the component-model host interface.
 pub fn inner_range(
     session_id: OID,
@@ -70,3 +122,15 @@ pub fn inner_range(
         Ok(system::range(&param))
     })
 }
+
+/// Forward a `range` call with scan options to the component-model host interface.
+pub fn inner_range_page(
+    session_id: OID,
+    start_key: &[u8],
+    end_key: &[u8],
+    options: &KvRangeOptions,
+) -> RS<KvRangePage> {
+    invoke_host_session_range_page(session_id, start_key, end_key, options, |param| {
+        Ok(system::range(&param))
+    })
+}
@@ -1,7 +1,12 @@
 use crate::host::{
     async_invoke_host_batch, async_invoke_host_close, async_invoke_host_command,
-    async_invoke_host_open, async_invoke_host_query, async_invoke_host_session_get,
-    async_invoke_host_session_put, async_invoke_host_session_range,
+    async_invoke_host_open, async_invoke_host_query, async_invoke_host_session_cas,
+    async_invoke_host_session_delete, async_invoke_host_session_delete_many,
+    async_invoke_host_session_get, async_invoke_host_session_incr,
+    async_invoke_host_session_kv_namespace, async_invoke_host_session_put,
+    async_invoke_host_session_put_if_absent, async_invoke_host_session_put_many,
+    async_invoke_host_session_put_with_ttl, async_invoke_host_session_range,
+    async_invoke_host_session_range_page,
 };
 use crate::inner_component_async::mududb::async_api::system;
 use mudu::common::id::OID;
@@ -9,8 +14,11 @@ use mudu::common::result::RS;
 use mudu_binding::universal::uni_session_open_argv::UniSessionOpenArgv;
 use mudu_contract::database::entity::Entity;
 use mudu_contract::database::entity_set::RecordSet;
+use mudu_contract::database::kv_namespace::{KvNamespaceOp, KvNamespaceOpResult};
+use mudu_contract::database::kv_range::{KvRangeOptions, KvRangePage};
 use mudu_contract::database::sql_params::SQLParams;
 use mudu_contract::database::sql_stmt::SQLStmt;
+use std::time::Duration;
 
 wit_bindgen::generate!({
     path:"wit/async",
@@ -75,6 +83,82 @@ pub async fn inner_put(session_id: OID, key: &[u8], value: &[u8]) -> RS<()> {
     .await
 }
 
+/// Forward a `put` call with a TTL to the component-model host interface.
+/// The TTL rides on the `put` parameter, see
+/// [`crate::host::serialize_session_put_with_ttl_param`].
+pub async fn inner_put_with_ttl(
+    session_id: OID,
+    key: &[u8],
+    value: &[u8],
+    ttl: Duration,
+) -> RS<()> {
+    async_invoke_host_session_put_with_ttl(session_id, key, value, ttl, async |param| {
+        Ok(system::put(param).await)
+    })
+    .await
+}
+
+/// Forward a `delete` call to the component-model host interface.
+pub async fn inner_delete(session_id: OID, key: &[u8]) -> RS<()> {
+    async_invoke_host_session_delete(session_id, key, async |param| {
+        Ok(system::delete(param).await)
+    })
+    .await
+}
+
+/// Forward a `put-many` call to the component-model host interface.
+pub async fn inner_put_many(session_id: OID, items: &[(Vec<u8>, Vec<u8>)]) -> RS<()> {
+    async_invoke_host_session_put_many(session_id, items, async |param| {
+        Ok(system::put_many(param).await)
+    })
+    .await
+}
+
+/// Forward a `delete-many` call to the component-model host interface.
+pub async fn inner_delete_many(session_id: OID, keys: &[Vec<u8>]) -> RS<()> {
+    async_invoke_host_session_delete_many(session_id, keys, async |param| {
+        Ok(system::delete_many(param).await)
+    })
+    .await
+}
+
+/// Forward a `cas` call to the component-model host interface.
+pub async fn inner_cas(
+    session_id: OID,
+    key: &[u8],
+    expected: Option<&[u8]>,
+    value: &[u8],
+) -> RS<bool> {
+    async_invoke_host_session_cas(session_id, key, expected, value, async |param| {
+        Ok(system::cas(param).await)
+    })
+    .await
+}
+
+/// Forward an `incr` call to the component-model host interface.
+pub async fn inner_incr(session_id: OID, key: &[u8], delta: i64) -> RS<i64> {
+    async_invoke_host_session_incr(session_id, key, delta, async |param| {
+        Ok(system::incr(param).await)
+    })
+    .await
+}
+
+/// Forward a `put-if-absent` call to the component-model host interface.
+pub async fn inner_put_if_absent(session_id: OID, key: &[u8], value: &[u8]) -> RS<bool> {
+    async_invoke_host_session_put_if_absent(session_id, key, value, async |param| {
+        Ok(system::put_if_absent(param).await)
+    })
+    .await
+}
+
+/// Forward a `kv-namespace` call to the component-model host interface.
+pub async fn inner_kv_namespace(session_id: OID, op: &KvNamespaceOp) -> RS<KvNamespaceOpResult> {
+    async_invoke_host_session_kv_namespace(session_id, op, async |param| {
+        Ok(system::kv_namespace(param).await)
+    })
+    .await
+}
+
 /// Forward a `range` call to the component-model host interface.
 pub async fn inner_range(
     session_id: OID,
@@ -86,3 +170,16 @@ pub async fn inner_range(
     })
     .await
 }
+
+/// Forward a `range` call with scan options to the component-model host interface.
+pub async fn inner_range_page(
+    session_id: OID,
+    start_key: &[u8],
+    end_key: &[u8],
+    options: &KvRangeOptions,
+) -> RS<KvRangePage> {
+    async_invoke_host_session_range_page(session_id, start_key, end_key, options, async |param| {
+        Ok(system::range(param).await)
+    })
+    .await
+}
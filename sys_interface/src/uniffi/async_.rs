@@ -65,6 +65,68 @@ pub async fn async_put(put_in: Vec<u8>) -> Result<Vec<u8>, SysInterfaceUniffiErr
         .map_err(binding_error)
 }
 
+#[::uniffi::export(async_runtime = "tokio")]
+/// UniFFI asynchronous `delete` entry point.
+pub async fn async_delete(delete_in: Vec<u8>) -> Result<Vec<u8>, SysInterfaceUniffiError> {
+    api_impl_async::mudu_delete_bytes(&delete_in)
+        .await
+        .map_err(binding_error)
+}
+
+#[::uniffi::export(async_runtime = "tokio")]
+/// UniFFI asynchronous `put_many` entry point.
+pub async fn async_put_many(put_many_in: Vec<u8>) -> Result<Vec<u8>, SysInterfaceUniffiError> {
+    api_impl_async::mudu_put_many_bytes(&put_many_in)
+        .await
+        .map_err(binding_error)
+}
+
+#[::uniffi::export(async_runtime = "tokio")]
+/// UniFFI asynchronous `delete_many` entry point.
+pub async fn async_delete_many(
+    delete_many_in: Vec<u8>,
+) -> Result<Vec<u8>, SysInterfaceUniffiError> {
+    api_impl_async::mudu_delete_many_bytes(&delete_many_in)
+        .await
+        .map_err(binding_error)
+}
+
+#[::uniffi::export(async_runtime = "tokio")]
+/// UniFFI asynchronous `cas` entry point.
+pub async fn async_cas(cas_in: Vec<u8>) -> Result<Vec<u8>, SysInterfaceUniffiError> {
+    api_impl_async::mudu_cas_bytes(&cas_in)
+        .await
+        .map_err(binding_error)
+}
+
+#[::uniffi::export(async_runtime = "tokio")]
+/// UniFFI asynchronous `incr` entry point.
+pub async fn async_incr(incr_in: Vec<u8>) -> Result<Vec<u8>, SysInterfaceUniffiError> {
+    api_impl_async::mudu_incr_bytes(&incr_in)
+        .await
+        .map_err(binding_error)
+}
+
+#[::uniffi::export(async_runtime = "tokio")]
+/// UniFFI asynchronous `put_if_absent` entry point.
+pub async fn async_put_if_absent(
+    put_if_absent_in: Vec<u8>,
+) -> Result<Vec<u8>, SysInterfaceUniffiError> {
+    api_impl_async::mudu_put_if_absent_bytes(&put_if_absent_in)
+        .await
+        .map_err(binding_error)
+}
+
+#[::uniffi::export(async_runtime = "tokio")]
+/// UniFFI asynchronous `kv_namespace` entry point.
+pub async fn async_kv_namespace(
+    kv_namespace_in: Vec<u8>,
+) -> Result<Vec<u8>, SysInterfaceUniffiError> {
+    api_impl_async::mudu_kv_namespace_bytes(&kv_namespace_in)
+        .await
+        .map_err(binding_error)
+}
+
 #[::uniffi::export(async_runtime = "tokio")]
 /// UniFFI asynchronous `range` entry point.
 pub async fn async_range(range_in: Vec<u8>) -> Result<Vec<u8>, SysInterfaceUniffiError> {
@@ -49,6 +49,48 @@ pub fn sync_put(put_in: Vec<u8>) -> Result<Vec<u8>, SysInterfaceUniffiError> {
     api_impl_sync::mudu_put_bytes(&put_in).map_err(binding_error)
 }
 
+#[::uniffi::export]
+/// UniFFI synchronous `delete` entry point.
+pub fn sync_delete(delete_in: Vec<u8>) -> Result<Vec<u8>, SysInterfaceUniffiError> {
+    api_impl_sync::mudu_delete_bytes(&delete_in).map_err(binding_error)
+}
+
+#[::uniffi::export]
+/// UniFFI synchronous `put_many` entry point.
+pub fn sync_put_many(put_many_in: Vec<u8>) -> Result<Vec<u8>, SysInterfaceUniffiError> {
+    api_impl_sync::mudu_put_many_bytes(&put_many_in).map_err(binding_error)
+}
+
+#[::uniffi::export]
+/// UniFFI synchronous `delete_many` entry point.
+pub fn sync_delete_many(delete_many_in: Vec<u8>) -> Result<Vec<u8>, SysInterfaceUniffiError> {
+    api_impl_sync::mudu_delete_many_bytes(&delete_many_in).map_err(binding_error)
+}
+
+#[::uniffi::export]
+/// UniFFI synchronous `cas` entry point.
+pub fn sync_cas(cas_in: Vec<u8>) -> Result<Vec<u8>, SysInterfaceUniffiError> {
+    api_impl_sync::mudu_cas_bytes(&cas_in).map_err(binding_error)
+}
+
+#[::uniffi::export]
+/// UniFFI synchronous `incr` entry point.
+pub fn sync_incr(incr_in: Vec<u8>) -> Result<Vec<u8>, SysInterfaceUniffiError> {
+    api_impl_sync::mudu_incr_bytes(&incr_in).map_err(binding_error)
+}
+
+#[::uniffi::export]
+/// UniFFI synchronous `put_if_absent` entry point.
+pub fn sync_put_if_absent(put_if_absent_in: Vec<u8>) -> Result<Vec<u8>, SysInterfaceUniffiError> {
+    api_impl_sync::mudu_put_if_absent_bytes(&put_if_absent_in).map_err(binding_error)
+}
+
+#[::uniffi::export]
+/// UniFFI synchronous `kv_namespace` entry point.
+pub fn sync_kv_namespace(kv_namespace_in: Vec<u8>) -> Result<Vec<u8>, SysInterfaceUniffiError> {
+    api_impl_sync::mudu_kv_namespace_bytes(&kv_namespace_in).map_err(binding_error)
+}
+
 #[::uniffi::export]
 /// UniFFI synchronous `range` entry point.
 pub fn sync_range(range_in: Vec<u8>) -> Result<Vec<u8>, SysInterfaceUniffiError> {
@@ -2,6 +2,7 @@
 // integration tests under Miri. They are still exercised by normal `cargo test`.
 #![cfg(all(not(target_arch = "wasm32"), feature = "standalone-adapter"))]
 
+use mudu_contract::database::kv_range::KvRangeOptions;
 use mudu_contract::database::sql_stmt_text::SQLStmtText;
 use mudu_sys::sync::{SMutex, SMutexGuard};
 use mudu_sys::time::system_time_now;
@@ -50,6 +51,40 @@ fn sync_standalone_kv_and_sql_wrappers_work() {
             (b"k2".to_vec(), b"v2".to_vec()),
         ]
     );
+    let options = KvRangeOptions::new().with_limit(1).with_reverse(true);
+    let page = sync_api::mudu_range_page(session_id, b"", b"", &options).unwrap();
+    assert_eq!(page.items(), &[(b"k2".to_vec(), b"v2".to_vec())]);
+    let token = page.continuation().unwrap().to_vec();
+    let page =
+        sync_api::mudu_range_page(session_id, b"", b"", &options.with_continuation(token)).unwrap();
+    assert_eq!(page.items(), &[(b"k1".to_vec(), b"v1".to_vec())]);
+    assert_eq!(page.continuation(), None);
+
+    sync_api::mudu_put_many(
+        session_id,
+        &[
+            (b"k3".to_vec(), b"v3".to_vec()),
+            (b"k4".to_vec(), b"v4".to_vec()),
+        ],
+    )
+    .unwrap();
+    sync_api::mudu_delete(session_id, b"k1").unwrap();
+    sync_api::mudu_delete_many(session_id, &[b"k2".to_vec(), b"k4".to_vec()]).unwrap();
+    assert_eq!(
+        sync_api::mudu_range(session_id, b"", b"").unwrap(),
+        vec![(b"k3".to_vec(), b"v3".to_vec())]
+    );
+
+    assert!(sync_api::mudu_put_if_absent(session_id, b"c", b"1").unwrap());
+    assert!(!sync_api::mudu_put_if_absent(session_id, b"c", b"2").unwrap());
+    assert_eq!(sync_api::mudu_incr(session_id, b"c", 4).unwrap(), 5);
+    assert!(!sync_api::mudu_cas(session_id, b"c", Some(b"4"), b"9").unwrap());
+    assert!(sync_api::mudu_cas(session_id, b"c", Some(b"5"), b"9").unwrap());
+    assert_eq!(
+        sync_api::mudu_get(session_id, b"c").unwrap(),
+        Some(b"9".to_vec())
+    );
+    sync_api::mudu_delete(session_id, b"c").unwrap();
 
     let setup = SQLStmtText::new(
         "CREATE TABLE demo(id INT PRIMARY KEY); INSERT INTO demo(id) VALUES (7);".to_string(),
@@ -151,6 +186,15 @@ fn async_standalone_kv_and_sql_wrappers_work() {
             async_api::mudu_range(session_id, b"k1", b"").await.unwrap(),
             vec![(b"k1".to_vec(), b"v1".to_vec())]
         );
+        let options = KvRangeOptions::new()
+            .with_prefix(b"k".to_vec())
+            .with_keys_only(true);
+        let page = async_api::mudu_range_page(session_id, b"", b"", &options)
+            .await
+            .unwrap();
+        assert_eq!(page.items(), &[(b"k1".to_vec(), Vec::new())]);
+        async_api::mudu_delete(session_id, b"k1").await.unwrap();
+        assert_eq!(async_api::mudu_get(session_id, b"k1").await.unwrap(), None);
 
         let setup = SQLStmtText::new(
             "CREATE TABLE demo(id INT PRIMARY KEY); INSERT INTO demo(id) VALUES (21);".to_string(),
@@ -59,7 +59,9 @@ fn generate_golden_v1_fixtures() {
     let lsn = AtomicU64::new(1);
     let frames = serialize_entry(&payload, 256, &lsn).unwrap();
     assert_eq!(frames.len(), 1, "log payload should fit in one frame");
-    mudu_sys::fs::sync::sync_write(fixture_path("log_frame_v1.bin"), &frames[0]).unwrap();
+    // `serialize_entry` writes the current frame version; keep the fixture v1.
+    let log_v1 = (mudu_kernel::wal::migrate::v1_to_v2().rollback)(&frames[0], None).unwrap();
+    mudu_sys::fs::sync::sync_write(fixture_path("log_frame_v1.bin"), &log_v1).unwrap();
 
     let frame = Frame::new(MessageType::Query, 42, b"select 1".to_vec());
     mudu_sys::fs::sync::sync_write(fixture_path("protocol_frame_v1.bin"), frame.encode()).unwrap();
@@ -74,9 +76,13 @@ fn golden_v1_roundtrips() {
     assert_eq!(header.page_id(), 42);
     assert_eq!(header.version(), 1);
 
+    // Log frames are now v2; the v1 fixture goes through the registered
+    // migration.
+    mudu_kernel::compat::install_compatibility_router();
     let log_bytes = mudu_sys::fs::sync::sync_read_all(fixture_path("log_frame_v1.bin"))
         .expect("missing log_frame_v1.bin; run generate_golden_v1_fixtures");
-    let payload: LogPayload = deserialize_entry(&[log_bytes]).expect("decode log frame payload");
+    let payload: LogPayload =
+        deserialize_entry(None, &[log_bytes]).expect("decode log frame payload");
     assert_eq!(payload.value, 12345);
     assert_eq!(payload.text, "golden");
 
@@ -102,7 +108,7 @@ fn corruption_rejects_bad_magic_version_and_truncation() {
     // Unsupported page header version.
     let mut bad_version =
         mudu_sys::fs::sync::sync_read_all(fixture_path("page_header_v1.bin")).unwrap();
-    bad_version[4..8].copy_from_slice(&2u32.to_le_bytes());
+    bad_version[4..8].copy_from_slice(&3u32.to_le_bytes());
     let err = PageHeader::decode(&bad_version).unwrap_err();
     assert_eq!(err.ec(), mudu::error::ErrorCode::UnsupportedFormatVersion);
     assert!(err.message().contains("unsupported page version"));
@@ -18,6 +18,7 @@ module.exports = grammar({
         [
             'binary_is',
             'unary_not',
+            'binary_json',
             'binary_exp',
             'binary_times',
             'binary_plus',
@@ -108,6 +109,8 @@ module.exports = grammar({
         keyword_restart: _ => make_keyword("restart"),
         keyword_key: _ => make_keyword("key"),
         keyword_as: _ => make_keyword("as"),
+        keyword_of: _ => make_keyword("of"),
+        keyword_system: _ => make_keyword("system"),
         keyword_distinct: _ => make_keyword("distinct"),
         keyword_constraint: _ => make_keyword("constraint"),
         keyword_filter: _ => make_keyword("filter"),
@@ -462,6 +465,7 @@ module.exports = grammar({
             $.keyword_inet,
 
             $.enum,
+            $.record_type,
 
             $.keyword_date,
             $.keyword_datetime,
@@ -488,6 +492,16 @@ module.exports = grammar({
             field("custom_type", $._identifier)
         ),
 
+        record_type: $ => seq(
+            $.keyword_row,
+            paren_list(field('record_field', $.record_field), true),
+        ),
+
+        record_field: $ => seq(
+            field('name', $.identifier),
+            field('data_type', $.data_type),
+        ),
+
         array_size_definition: $ => seq(
             choice(
                 seq($.keyword_array, optional($._array_size_definition)),
@@ -641,6 +655,7 @@ module.exports = grammar({
 
         ddl_stmt: $ => choice(
             $.create_table_statement,
+            $.create_index,
             $._alter_statement,
             $.drop_statement,
             $._rename_statement,
@@ -1054,19 +1069,19 @@ module.exports = grammar({
 
         create_index: $ => seq(
             $.keyword_create,
-            optional($.keyword_unique),
+            optional(field("unique", $.keyword_unique)),
             $.keyword_index,
             optional($.keyword_concurrently),
             optional(
                 seq(
                     optional($._if_not_exists),
-                    field("column", $.column),
+                    field("index_name", $.column),
                 ),
             ),
             $.keyword_on,
             optional($.keyword_only),
             seq(
-                $.object_reference,
+                field("object_reference", $.object_reference),
                 optional(
                     seq(
                         $.keyword_using,
@@ -1080,10 +1095,10 @@ module.exports = grammar({
                         ),
                     ),
                 ),
-                $.ordered_columns,
+                field("columns", $.ordered_columns),
             ),
             optional(
-                $.where,
+                field("where", $.where),
             ),
         ),
 
@@ -2092,6 +2107,7 @@ module.exports = grammar({
             $.keyword_auto_increment,
             $.direction,
             $._column_comment,
+            seq($.keyword_collate, field('collation', $.identifier)),
             seq(
                 optional(seq($.keyword_generated, $.keyword_always)),
                 $.keyword_as,
@@ -2109,7 +2125,8 @@ module.exports = grammar({
             $.cast,
             $.binary_expression,
             $.unary_expression,
-            $.array,
+            // `DEFAULT ARRAY[...] NOT NULL` is an array followed by a constraint
+            prec(2, $.array),
             $.invocation,
             $.keyword_current_timestamp,
             alias($.implicit_cast, $.cast),
@@ -2170,7 +2187,11 @@ module.exports = grammar({
         ordered_columns: $ => paren_list(alias($.ordered_column, $.column), true),
 
         ordered_column: $ => seq(
-            field('name', $.column),
+            choice(
+                field('name', $.column),
+                field('invocation', $.invocation),
+                wrapped_in_parenthesis(field('expression', $.expression)),
+            ),
             optional($.direction),
         ),
 
@@ -2464,7 +2485,17 @@ module.exports = grammar({
                 $.keyword_only,
             ),
             field("relation", $.relation),
+            optional(field("as_of", $.as_of)),
             optional(field("where", $.where)),
+            optional(field("order_by", $.order_by)),
+        ),
+
+        as_of: $ => seq(
+            $.keyword_as,
+            $.keyword_of,
+            $.keyword_system,
+            $.keyword_time,
+            field("timestamp", $.literal),
         ),
 
 
@@ -2611,25 +2642,25 @@ module.exports = grammar({
         )),
 
         order_target: $ => seq(
-            $.expression,
+            field("expression", $.expression),
             optional(
                 seq(
                     choice(
-                        $.direction,
+                        field("direction", $.direction),
                         seq(
                             $.keyword_using,
                             choice('<', '>', '<=', '>='),
                         ),
                     ),
-                    optional(
-                        seq(
-                            $.keyword_nulls,
-                            choice(
-                                $.keyword_first,
-                                $.keyword_last,
-                            ),
-                        ),
-                    ),
+                ),
+            ),
+            optional(
+                seq(
+                    $.keyword_nulls,
+                    field("nulls", choice(
+                        $.keyword_first,
+                        $.keyword_last,
+                    )),
                 ),
             ),
         ),
@@ -2655,12 +2686,28 @@ module.exports = grammar({
                 field("literal", $.literal),
                 field("parameter_placeholder", $.parameter),
                 field('qualified_field', $.qualified_field),
+                field('invocation', $.invocation),
+                field('array', $.array),
+                field('row', $.row),
+                field('subscript', $.subscript),
                 field('binary_expression', $.binary_expression),
                 field('between_expression', $.between_expression),
                 wrapped_in_parenthesis(field('expression_in_parenthesis', $.expression)),
             )
         ),
 
+        row: $ => seq(
+            $.keyword_row,
+            paren_list(field('expression', $.expression)),
+        ),
+
+        subscript: $ => prec.left('binary_json', seq(
+            field('expression', $.expression),
+            '[',
+            field('index', $.expression),
+            ']',
+        )),
+
         binary_expression: $ => choice(
             ...[
                 ['+', 'binary_plus'],
@@ -2677,10 +2724,12 @@ module.exports = grammar({
                 ['>=', 'binary_relation'],
                 ['>', 'binary_relation'],
                 ['<>', 'binary_relation'],
-                ['->', 'binary_relation'],
-                ['->>', 'binary_relation'],
-                ['#>', 'binary_relation'],
-                ['#>>', 'binary_relation'],
+                ['@>', 'binary_relation'],
+                ['<@', 'binary_relation'],
+                ['->', 'binary_json'],
+                ['->>', 'binary_json'],
+                ['#>', 'binary_json'],
+                ['#>>', 'binary_json'],
                 [$.keyword_is, 'binary_is'],
                 [$.is_not, 'binary_is'],
                 [$.keyword_like, 'pattern_matching'],